@@ -85,6 +85,9 @@ pub enum EntityCommand {
     },
     /// Your personal Zotero reference library
     Zotero {
+        /// Group library to use (group ID or name); defaults to your personal library
+        #[arg(long, global = true)]
+        library: Option<String>,
         #[command(subcommand)]
         cmd: ZoteroCommand,
     },
@@ -98,6 +101,21 @@ pub enum EntityCommand {
         #[command(subcommand)]
         cmd: DbCommand,
     },
+    /// Index synthetic papers into a scratch DB to try the `db` commands without PDFs
+    Demo {
+        /// Number of synthetic papers to generate
+        #[arg(long, default_value_t = 20)]
+        papers: usize,
+        /// Scratch directory (default: <data dir>/demo); its previous demo index is replaced
+        #[arg(long)]
+        dir: Option<PathBuf>,
+        /// Generator seed; the same seed always produces the same papers
+        #[arg(long)]
+        seed: Option<u64>,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
     /// Extract structured content from a PDF using local ONNX models
     Extract {
         /// Path to the input PDF file
@@ -127,6 +145,42 @@ pub enum EntityCommand {
         #[arg(long)]
         text_only: bool,
     },
+    /// Raw OpenAlex GET for parameters the other commands lack (prints JSON)
+    Raw {
+        /// Endpoint path, e.g. works, works/W2741809807, autocomplete/authors
+        path: String,
+        /// Query parameter as KEY=VALUE, repeatable (e.g. -q group_by=oa_status)
+        #[arg(long = "query", short = 'q', value_name = "KEY=VALUE")]
+        query: Vec<String>,
+    },
+    /// What's new in the last few days: watch hits, new citations of your papers, Zotero additions
+    Digest {
+        /// Selection (name or index) whose papers to check for new citations (repeatable)
+        #[arg(long = "selection", short = 's', value_name = "SELECTION")]
+        selections: Vec<String>,
+        /// Search query to list newly published matches for (repeatable)
+        #[arg(long = "watch", short = 'w', value_name = "QUERY")]
+        watches: Vec<String>,
+        /// Window in days, ending today (max 90)
+        #[arg(long, default_value_t = papers_core::digest::DEFAULT_DIGEST_DAYS)]
+        days: u32,
+        /// Also send works not announced before to the configured webhooks and email
+        #[arg(long)]
+        notify: bool,
+        /// Print the digest as JSON instead of Markdown
+        #[arg(long)]
+        json: bool,
+    },
+    /// Local OpenAlex response cache
+    Cache {
+        #[command(subcommand)]
+        cmd: CacheCommand,
+    },
+    /// Offline OpenAlex snapshot index (served when PAPERS_OPENALEX_SNAPSHOT is set)
+    Snapshot {
+        #[command(subcommand)]
+        cmd: SnapshotCommand,
+    },
     /// Manage papers CLI configuration
     Config {
         #[command(subcommand)]
@@ -137,6 +191,76 @@ pub enum EntityCommand {
         #[command(subcommand)]
         cmd: McpCommand,
     },
+    /// Serve MCP over HTTP (at /mcp, with /healthz) as a long-running service
+    Serve {
+        /// Address to bind [env: PAPERS_HOST, default 127.0.0.1; use 0.0.0.0 in containers]
+        #[arg(long)]
+        host: Option<String>,
+        /// Port to listen on [env: PAPERS_PORT, default 8000]
+        #[arg(long)]
+        port: Option<u16>,
+        /// Hide tools that write to Zotero, the DB, selections, or disk [env: PAPERS_READ_ONLY]
+        #[arg(long)]
+        read_only: bool,
+    },
+    /// Access audit log of a shared server's tool calls
+    Audit {
+        #[command(subcommand)]
+        cmd: AuditCommand,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum AuditCommand {
+    /// Print audit records as JSONL, oldest first
+    Export {
+        /// First day to include (YYYY-MM-DD, UTC)
+        #[arg(long)]
+        since: Option<String>,
+        /// Last day to include (YYYY-MM-DD, UTC)
+        #[arg(long)]
+        until: Option<String>,
+        /// Only calls by this user (tenant name)
+        #[arg(long)]
+        user: Option<String>,
+        /// Only calls to this tool
+        #[arg(long)]
+        tool: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum CacheCommand {
+    /// Show cache location, size, and entries per entity with their TTL
+    Stats {
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Remove cached responses
+    Clear {
+        /// Only clear this entity type (works, authors, domains, ..., or other)
+        #[arg(long, short)]
+        entity: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum SnapshotCommand {
+    /// Index a downloaded OpenAlex snapshot (the directory holding data/) for offline use
+    Index {
+        /// Snapshot directory, e.g. from `aws s3 sync s3://openalex ...`
+        source: PathBuf,
+        /// Where to write the index [default: $PAPERS_OPENALEX_SNAPSHOT]
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+    /// Show the index in use and its record counts
+    Info {
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
 }
 
 #[derive(Subcommand)]
@@ -146,6 +270,9 @@ pub enum McpCommand {
         /// Run with stdio transport (required by MCP clients)
         #[arg(long)]
         stdio: bool,
+        /// Hide tools that write to Zotero, the DB, selections, or disk [env: PAPERS_READ_ONLY]
+        #[arg(long)]
+        read_only: bool,
     },
 }
 
@@ -161,11 +288,26 @@ pub enum DbCommand {
         #[command(subcommand)]
         cmd: DbExhibitCommand,
     },
+    /// Display equations, kept as LaTeX
+    Equation {
+        #[command(subcommand)]
+        cmd: DbEquationCommand,
+    },
+    /// Inline citations linked to reference lists
+    Citation {
+        #[command(subcommand)]
+        cmd: DbCitationCommand,
+    },
     /// Indexed papers: list, add, search, and inspect
     Work {
         #[command(subcommand)]
         cmd: DbWorkCommand,
     },
+    /// Abstracts-only index: semantic search over papers without indexed full text
+    Abstract {
+        #[command(subcommand)]
+        cmd: DbAbstractCommand,
+    },
     /// Sections: read full section content in order
     Section {
         #[command(subcommand)]
@@ -176,26 +318,67 @@ pub enum DbCommand {
         #[command(subcommand)]
         cmd: DbTagCommand,
     },
+    /// Markdown notes for indexed papers in an Obsidian-style vault
+    Vault {
+        #[command(subcommand)]
+        cmd: DbVaultCommand,
+    },
+    /// Paper, chunk and exhibit counts, size on disk, and embedding model
+    Stats {
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Re-embed every chunk and exhibit with the current embedding model (after a model change)
+    Migrate {
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Poll Zotero: extract and index new items with PDFs, remove deleted ones
+    Watch {
+        /// Zotero collection key or name to follow (repeatable; default: `watch_collections` in config, else the whole library)
+        #[arg(long)]
+        collection: Vec<String>,
+        /// Seconds between polls
+        #[arg(long, default_value_t = 300)]
+        interval: u64,
+        /// Poll once and exit
+        #[arg(long)]
+        once: bool,
+        /// Output raw JSON (one line per poll)
+        #[arg(long)]
+        json: bool,
+    },
 }
 
 #[derive(Subcommand)]
 pub enum DbChunkCommand {
-    /// Semantic search over indexed paper chunks
+    /// Search over indexed paper chunks (semantic, keyword, or hybrid)
     Search {
         /// Natural language search query
         query: String,
         /// Scope to papers in a named selection
         #[arg(long)]
         selection: Option<String>,
+        /// Scope to the papers of a Zotero collection (key or name)
+        #[arg(long, conflicts_with = "selection")]
+        collection: Option<String>,
         /// Scope to a specific paper (DOI, item key, or title search)
         #[arg(long)]
         work: Option<String>,
         /// Scope to a specific chapter (1-based; requires --work)
         #[arg(long)]
         chapter_idx: Option<u16>,
+        /// Scope to a chapter by title instead (requires --work)
+        #[arg(long, conflicts_with = "chapter_idx")]
+        chapter: Option<String>,
         /// Scope to a specific section (1-based; requires --work and --chapter-idx)
         #[arg(long)]
         section_idx: Option<u16>,
+        /// Scope to a section by title instead, e.g. "3.2 Local System Solver" (requires --work)
+        #[arg(long, conflicts_with = "section_idx")]
+        section: Option<String>,
         /// Minimum publication year
         #[arg(long)]
         year_min: Option<u16>,
@@ -210,7 +393,13 @@ pub enum DbChunkCommand {
         tag: Option<Vec<String>>,
         /// Granularity: chapter | section | paragraph
         #[arg(long)]
-        depth: Option<String>,
+        depth: Option<papers_db::ChunkDepth>,
+        /// Ranking: semantic | keyword (BM25, exact terms) | hybrid (both, fused)
+        #[arg(long, default_value = "semantic")]
+        mode: papers_db::SearchMode,
+        /// Rerank a larger candidate pool with a cross-encoder (model: $PAPERS_RERANK_MODEL)
+        #[arg(long)]
+        rerank: bool,
         /// Maximum number of results
         #[arg(long, short = 'n', default_value = "5")]
         limit: u16,
@@ -218,6 +407,49 @@ pub enum DbChunkCommand {
         #[arg(long)]
         json: bool,
     },
+    /// Check a claim: find the most similar chunks and label each supporting, contradicting, or neutral
+    Verify {
+        /// The claim to check, e.g. a sentence from a draft
+        statement: String,
+        /// Scope to papers in a named selection
+        #[arg(long)]
+        selection: Option<String>,
+        /// Scope to a specific paper (DOI, item key, or title search)
+        #[arg(long)]
+        work: Option<String>,
+        /// Retrieval: semantic | keyword | hybrid
+        #[arg(long, default_value = "semantic")]
+        mode: papers_db::SearchMode,
+        /// Pick passages with a cross-encoder (model: $PAPERS_RERANK_MODEL)
+        #[arg(long)]
+        rerank: bool,
+        /// Maximum number of passages
+        #[arg(long, short = 'n', default_value = "8")]
+        limit: u16,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Suggest citations for a Markdown draft: the best-matching papers for each prose paragraph
+    Ground {
+        /// Markdown file to ground (default: read stdin)
+        file: Option<std::path::PathBuf>,
+        /// Scope to papers in a named selection
+        #[arg(long)]
+        selection: Option<String>,
+        /// Retrieval: semantic | keyword | hybrid
+        #[arg(long, default_value = "semantic")]
+        mode: papers_db::SearchMode,
+        /// Rank passages with a cross-encoder (model: $PAPERS_RERANK_MODEL)
+        #[arg(long)]
+        rerank: bool,
+        /// Candidate papers per paragraph
+        #[arg(long, short = 'n', default_value = "3")]
+        limit: u16,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
     /// Retrieve a specific chunk by ID with neighboring context
     Get {
         /// Chunk ID (e.g. YFACFA8C/ch1/s2/p3)
@@ -226,6 +458,25 @@ pub enum DbChunkCommand {
         #[arg(long)]
         json: bool,
     },
+    /// Record a chunk as read; `db work list` shows where to continue
+    MarkRead {
+        /// Chunk ID (e.g. YFACFA8C/ch1/s2/p3)
+        chunk_id: String,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Find the chunk(s) holding a Zotero annotation's highlighted text
+    Locate {
+        /// Zotero annotation key
+        annotation_key: String,
+        /// Maximum number of chunks
+        #[arg(long, short = 'n', default_value = "3")]
+        limit: u16,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
     /// List all chunks in reading order (all papers, or scoped with --work)
     List {
         /// Scope to a specific paper (DOI, item key, or title search)
@@ -246,6 +497,64 @@ pub enum DbChunkCommand {
     },
 }
 
+#[derive(Subcommand)]
+pub enum DbEquationCommand {
+    /// Find where equations are defined; returns their LaTeX with the surrounding text
+    Search {
+        /// What the equation defines (e.g. "update rule")
+        query: String,
+        /// Scope to papers in a named selection
+        #[arg(long)]
+        selection: Option<String>,
+        /// Scope to a specific paper (DOI, item key, or title search)
+        #[arg(long)]
+        work: Option<String>,
+        /// Maximum number of results
+        #[arg(long, short = 'n', default_value = "5")]
+        limit: u16,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum DbCitationCommand {
+    /// Every sentence in the indexed papers that cites a paper
+    Contexts {
+        /// DOI of the cited paper
+        doi: String,
+        /// Also match reference entries without a DOI by this title
+        /// (default: the paper's title, if it is indexed)
+        #[arg(long)]
+        title: Option<String>,
+        /// Only search citing papers in a named selection
+        #[arg(long)]
+        selection: Option<String>,
+        /// Maximum number of results
+        #[arg(long, short = 'n', default_value = "50")]
+        limit: u16,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Citation graph among indexed papers as GraphML or DOT
+    Graph {
+        /// Output format: graphml or dot
+        #[arg(long, short = 'f', default_value = "graphml")]
+        format: String,
+        /// Write to this file instead of stdout
+        #[arg(long, short = 'o')]
+        output: Option<PathBuf>,
+        /// Only include papers in a named selection
+        #[arg(long)]
+        selection: Option<String>,
+        /// Output raw JSON (content plus node and edge counts)
+        #[arg(long)]
+        json: bool,
+    },
+}
+
 #[derive(Subcommand)]
 pub enum DbExhibitCommand {
     /// Search for exhibits (figures, tables, algorithms)
@@ -260,7 +569,7 @@ pub enum DbExhibitCommand {
         work: Option<String>,
         /// Filter by type: "figure", "table", or "algorithm"
         #[arg(long)]
-        exhibit_type: Option<String>,
+        exhibit_type: Option<papers_db::ExhibitType>,
         /// Maximum number of results
         #[arg(long, short = 'n', default_value = "5")]
         limit: u16,
@@ -285,6 +594,9 @@ pub enum DbWorkCommand {
         /// Scope to papers in a named selection
         #[arg(long)]
         selection: Option<String>,
+        /// Scope to the papers of a Zotero collection (key or name)
+        #[arg(long, conflicts_with = "selection")]
+        collection: Option<String>,
         /// Minimum publication year
         #[arg(long)]
         year_min: Option<u16>,
@@ -302,7 +614,7 @@ pub enum DbWorkCommand {
         author: Option<Vec<String>>,
         /// Sort by: "year" (default) or "title"
         #[arg(long)]
-        sort: Option<String>,
+        sort: Option<papers_db::PaperSort>,
         /// Maximum number of results
         #[arg(long, short = 'n', default_value = "50")]
         limit: u16,
@@ -318,6 +630,24 @@ pub enum DbWorkCommand {
         #[arg(long)]
         json: bool,
     },
+    /// Show or record a paper's reading status (unread, reading, read), rating, and last chunk
+    /// read; lists every recorded paper when no paper is given
+    Status {
+        /// Paper: DOI, Zotero item key, or OpenAlex ID
+        paper_id: Option<String>,
+        /// Set the status: unread, reading, or read (filters the list when no paper is given)
+        #[arg(long)]
+        status: Option<String>,
+        /// Set the rating, 1 to 5 (0 clears it)
+        #[arg(long, requires = "paper_id")]
+        rating: Option<u8>,
+        /// Set the chunk where reading left off
+        #[arg(long, requires = "paper_id")]
+        chunk: Option<String>,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
     /// Semantic search returning one result per matching paper
     Search {
         /// Natural language search query
@@ -344,6 +674,44 @@ pub enum DbWorkCommand {
         #[arg(long)]
         json: bool,
     },
+    /// Rank other indexed papers by similarity to a paper (mean chunk embedding)
+    Similar {
+        /// Paper: DOI, item key, or title search
+        paper_id: String,
+        /// Only rank papers in a named selection
+        #[arg(long)]
+        selection: Option<String>,
+        /// Maximum number of results
+        #[arg(long, short = 'n', default_value = "10")]
+        limit: u16,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Cluster indexed papers into themes labelled by title terms
+    Cluster {
+        /// Number of clusters (default: chosen automatically)
+        #[arg(short = 'k')]
+        k: Option<u16>,
+        /// Only cluster papers in a named selection
+        #[arg(long)]
+        selection: Option<String>,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Indexed papers bucketed by year, with the most-cited flagged as milestones
+    Timeline {
+        /// Most-cited papers flagged as milestones per year (0 disables)
+        #[arg(long, default_value = "1")]
+        milestones: u16,
+        /// Only include papers in a named selection
+        #[arg(long)]
+        selection: Option<String>,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
     /// Index a paper (or all papers) into the RAG database.
     /// Extracts from PDF via papers-extract if not already cached.
     Add {
@@ -367,6 +735,10 @@ pub enum DbWorkCommand {
         /// Re-embed only (skip extraction, re-chunk and re-embed from cached reflow)
         #[arg(long)]
         embed_only: bool,
+        /// Chunking: "block" (default) or "sentence-window" to split long paragraphs at
+        /// sentence boundaries; sizes optional, e.g. "sentence-window(target=400,max=600,overlap=2)"
+        #[arg(long)]
+        chunking: Option<papers_db::ChunkingConfig>,
     },
     /// Remove a paper from the RAG index (deletes all chunks and exhibits)
     Remove {
@@ -376,6 +748,14 @@ pub enum DbWorkCommand {
         #[arg(long)]
         json: bool,
     },
+    /// Rebuild an indexed paper from its cached extraction (re-chunk and re-embed)
+    Reindex {
+        /// Paper: DOI, item key, or title search
+        paper_id: String,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
     /// Show the table of contents for an indexed paper
     Outline {
         /// Paper: DOI, item key, or title search
@@ -400,6 +780,39 @@ pub enum DbWorkCommand {
     },
 }
 
+#[derive(Subcommand)]
+pub enum DbAbstractCommand {
+    /// Fetch OpenAlex abstracts for selection entries and watched collections and embed them
+    Index {
+        /// Only index entries of a named selection (default: every selection plus `watch_collections`)
+        #[arg(long)]
+        selection: Option<String>,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Semantic search over indexed abstracts, one hit per paper
+    Search {
+        /// Natural language search query
+        query: String,
+        /// Scope to papers in a named selection
+        #[arg(long)]
+        selection: Option<String>,
+        /// Minimum publication year
+        #[arg(long)]
+        year_min: Option<u16>,
+        /// Maximum publication year
+        #[arg(long)]
+        year_max: Option<u16>,
+        /// Maximum number of results
+        #[arg(long, short = 'n', default_value = "10")]
+        limit: u16,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
 #[derive(Subcommand)]
 pub enum DbSectionCommand {
     /// Semantic search returning one result per matching section
@@ -415,6 +828,9 @@ pub enum DbSectionCommand {
         /// Scope to a chapter (1-based; requires --work)
         #[arg(long)]
         chapter_idx: Option<u16>,
+        /// Scope to a chapter by title instead (requires --work)
+        #[arg(long, conflicts_with = "chapter_idx")]
+        chapter: Option<String>,
         /// Minimum publication year
         #[arg(long)]
         year_min: Option<u16>,
@@ -448,11 +864,32 @@ pub enum DbSectionCommand {
         /// Paper: DOI, item key, or title search
         paper_id: String,
         /// Chapter index (1-based)
-        #[arg(long)]
-        chapter_idx: u16,
+        #[arg(long, requires = "section_idx")]
+        chapter_idx: Option<u16>,
         /// Section index (1-based within chapter)
+        #[arg(long, requires = "chapter_idx")]
+        section_idx: Option<u16>,
+        /// Section title instead of indices, e.g. "3.2 Local System Solver" or "local solver"
+        #[arg(long, conflicts_with = "section_idx", required_unless_present = "section_idx")]
+        section: Option<String>,
+        /// Inline referenced exhibits (caption, description, image path) after the chunk citing them
+        #[arg(long)]
+        exhibits: bool,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum DbVaultCommand {
+    /// Write or update per-paper notes (frontmatter, summary, annotations, links)
+    Sync {
+        /// Paper to sync (DOI, item key, or title search); all indexed papers if omitted
+        work: Option<String>,
+        /// Vault directory (default: PAPERS_VAULT_DIR or `papers config set vault`)
         #[arg(long)]
-        section_idx: u16,
+        dir: Option<String>,
         /// Output raw JSON
         #[arg(long)]
         json: bool,
@@ -474,7 +911,7 @@ pub enum DbTagCommand {
 
 #[derive(Subcommand)]
 pub enum SelectionCommand {
-    /// List all selections (marks active with *)
+    /// List all selections (marks active with *, default DB scope with [db scope])
     List {
         /// Output raw JSON
         #[arg(long)]
@@ -515,10 +952,10 @@ pub enum SelectionCommand {
         #[arg(long)]
         json: bool,
     },
-    /// Remove a paper from a selection (accepts 1-based index from `status`)
-    Remove {
-        /// Paper identifier: Zotero key, DOI, OpenAlex ID, title, or 1-based index
-        paper: String,
+    /// Add many papers to a selection at once (identifiers as for `add`; one per line on stdin if none given)
+    AddBulk {
+        /// Paper identifiers: Zotero keys, DOIs, OpenAlex IDs, or titles
+        papers: Vec<String>,
         /// Target selection name or index (default: active selection)
         #[arg(long)]
         selection: Option<String>,
@@ -526,8 +963,22 @@ pub enum SelectionCommand {
         #[arg(long)]
         json: bool,
     },
-    /// Per-paper status: Zotero, PDF, extracted, DB
-    Status {
+    /// Add every work matching a work-list query (up to --limit) to a selection
+    AddQuery {
+        /// Full-text search query
+        #[arg(long, short = 's')]
+        search: Option<String>,
+        /// Filter expression (comma-separated AND conditions, pipe for OR)
+        #[arg(long, short = 'f')]
+        filter: Option<String>,
+        /// Sort field with optional :desc suffix; decides which works fall within --limit
+        #[arg(long)]
+        sort: Option<String>,
+        #[command(flatten)]
+        work_filters: WorkFilterArgs,
+        /// Maximum works to add (max 500)
+        #[arg(long, short = 'n', default_value_t = 50)]
+        limit: u32,
         /// Target selection name or index (default: active selection)
         #[arg(long)]
         selection: Option<String>,
@@ -535,40 +986,150 @@ pub enum SelectionCommand {
         #[arg(long)]
         json: bool,
     },
-    /// Download open-access PDFs for entries missing a PDF
-    Find {
+    /// Add the works a paper cites to a selection (one backward snowballing step)
+    AddReferences {
+        /// Citing work: OpenAlex ID, DOI, or title
+        work: String,
+        /// Full-text search over the cited works
+        #[arg(long, short = 's')]
+        search: Option<String>,
+        /// Filter expression for the cited works (comma-separated AND conditions, pipe for OR)
+        #[arg(long, short = 'f')]
+        filter: Option<String>,
+        #[command(flatten)]
+        work_filters: WorkFilterArgs,
         /// Target selection name or index (default: active selection)
         #[arg(long)]
         selection: Option<String>,
-        /// Open DOI URLs in browser for papers without OA PDF
-        #[arg(long)]
-        open: bool,
         /// Output raw JSON
         #[arg(long)]
         json: bool,
     },
-    /// Sync selection to Zotero (create items, upload PDFs, upload extractions, add to collection)
-    Sync {
+    /// Add the works citing a paper to a selection (one forward snowballing step)
+    AddCiters {
+        /// Cited work: OpenAlex ID, DOI, or title
+        work: String,
+        /// Keep only citing works cited at least this many times
+        #[arg(long)]
+        min_citations: Option<u32>,
+        /// Filter expression for the citing works (comma-separated AND conditions, pipe for OR)
+        #[arg(long, short = 'f')]
+        filter: Option<String>,
+        /// Sort field with optional :desc suffix; decides which citers fall within --limit
+        #[arg(long, default_value = "cited_by_count:desc")]
+        sort: String,
+        #[command(flatten)]
+        work_filters: WorkFilterArgs,
+        /// Maximum works to add (max 500)
+        #[arg(long, short = 'n', default_value_t = 50)]
+        limit: u32,
         /// Target selection name or index (default: active selection)
         #[arg(long)]
         selection: Option<String>,
-        /// Skip confirmation prompt
-        #[arg(long, short = 'y')]
-        yes: bool,
         /// Output raw JSON
         #[arg(long)]
         json: bool,
     },
-    /// Batch DB operations on the active selection
-    Db {
-        #[command(subcommand)]
-        cmd: SelectionDbCommand,
-    },
-    /// Zotero collection operations on the active selection
-    Collection {
-        #[command(subcommand)]
+    /// Remove a paper from a selection (accepts 1-based index from `status`)
+    Remove {
+        /// Paper identifier: Zotero key, DOI, OpenAlex ID, title, or 1-based index
+        paper: String,
+        /// Target selection name or index (default: active selection)
+        #[arg(long)]
+        selection: Option<String>,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Set or clear the note on a selection entry (why it is in the list)
+    Note {
+        /// Paper identifier: Zotero key, DOI, OpenAlex ID, title, or 1-based index
+        paper: String,
+        /// Note text; omit to clear the note
+        note: Option<String>,
+        /// Target selection name or index (default: active selection)
+        #[arg(long)]
+        selection: Option<String>,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Add or remove tags on a selection entry
+    Tag {
+        /// Paper identifier: Zotero key, DOI, OpenAlex ID, title, or 1-based index
+        paper: String,
+        /// Tags to add (comma-separated)
+        #[arg(long, value_delimiter = ',')]
+        add: Vec<String>,
+        /// Tags to remove (comma-separated)
+        #[arg(long, value_delimiter = ',')]
+        remove: Vec<String>,
+        /// Target selection name or index (default: active selection)
+        #[arg(long)]
+        selection: Option<String>,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Scope the MCP DB search/list tools to a selection by default
+    Scope {
+        /// Selection name or 1-based index (omit to use active selection)
+        name: Option<String>,
+        /// Clear the default scope
+        #[arg(long, conflicts_with = "name")]
+        clear: bool,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Per-paper status: Zotero, PDF, extracted, DB
+    Status {
+        /// Target selection name or index (default: active selection)
+        #[arg(long)]
+        selection: Option<String>,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Download open-access PDFs for entries missing a PDF
+    Find {
+        /// Target selection name or index (default: active selection)
+        #[arg(long)]
+        selection: Option<String>,
+        /// Open DOI URLs in browser for papers without OA PDF
+        #[arg(long)]
+        open: bool,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Sync selection to Zotero (create items, upload PDFs, upload extractions, add to collection)
+    Sync {
+        /// Target selection name or index (default: active selection)
+        #[arg(long)]
+        selection: Option<String>,
+        /// Skip confirmation prompt
+        #[arg(long, short = 'y')]
+        yes: bool,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Batch DB operations on the active selection
+    Db {
+        #[command(subcommand)]
+        cmd: SelectionDbCommand,
+    },
+    /// Zotero collection operations on the active selection
+    Collection {
+        #[command(subcommand)]
         cmd: SelectionCollectionCommand,
     },
+    /// PRISMA-style screening: record decisions, track progress, flow summary
+    Screen {
+        #[command(subcommand)]
+        cmd: SelectionScreenCommand,
+    },
     /// Merge another selection's entries into the active selection
     Merge {
         /// Source selection name or 1-based index
@@ -580,6 +1141,100 @@ pub enum SelectionCommand {
         #[arg(long)]
         json: bool,
     },
+    /// Export the selection as BibTeX or CSL-JSON (Zotero data first, then OpenAlex)
+    Export {
+        /// Output format: bibtex or csl-json
+        #[arg(long, short = 'f', default_value = "bibtex")]
+        format: String,
+        /// Write to this file instead of stdout
+        #[arg(long, short = 'o')]
+        output: Option<PathBuf>,
+        /// Target selection name or index (default: active selection)
+        #[arg(long)]
+        selection: Option<String>,
+        /// Output raw JSON (content plus per-source record counts)
+        #[arg(long)]
+        json: bool,
+    },
+    /// Fill `\cite{?}` placeholders in a LaTeX file from the selection, check existing keys,
+    /// and list the BibTeX entries the .bib file is missing
+    Cite {
+        /// LaTeX file to read (default: read stdin)
+        file: Option<PathBuf>,
+        /// Existing BibTeX file; its keys are preferred over selection keys
+        #[arg(long)]
+        bib: Option<PathBuf>,
+        /// Write the LaTeX with placeholders filled to this file
+        #[arg(long, short = 'o')]
+        output: Option<PathBuf>,
+        /// Append the missing entries to the --bib file
+        #[arg(long, requires = "bib")]
+        append: bool,
+        /// Target selection name or index (default: active selection)
+        #[arg(long)]
+        selection: Option<String>,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Generate a static HTML site (filterable index, per-paper pages with abstract,
+    /// figures, and annotations) for sharing the selection as a reading list
+    Site {
+        /// Directory to write the site into
+        #[arg(long, short = 'o')]
+        out: PathBuf,
+        /// Target selection name or index (default: active selection)
+        #[arg(long)]
+        selection: Option<String>,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Estimated APC (article processing charge) spend for the selection
+    Apc {
+        /// Target selection name or index (default: active selection)
+        #[arg(long)]
+        selection: Option<String>,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// UN Sustainable Development Goal coverage of the selection (fetches each entry from OpenAlex)
+    Sdg {
+        /// Target selection name or index (default: active selection)
+        #[arg(long)]
+        selection: Option<String>,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Citation graph among the selection's works as GraphML or DOT (fetches each entry from OpenAlex)
+    Graph {
+        /// Output format: graphml or dot
+        #[arg(long, short = 'f', default_value = "graphml")]
+        format: String,
+        /// Write to this file instead of stdout
+        #[arg(long, short = 'o')]
+        output: Option<PathBuf>,
+        /// Target selection name or index (default: active selection)
+        #[arg(long)]
+        selection: Option<String>,
+        /// Output raw JSON (content plus node and edge counts)
+        #[arg(long)]
+        json: bool,
+    },
+    /// Selection works bucketed by year, with the most-cited flagged as milestones (fetches each entry from OpenAlex)
+    Timeline {
+        /// Most-cited works flagged as milestones per year (0 disables)
+        #[arg(long, default_value = "1")]
+        milestones: usize,
+        /// Target selection name or index (default: active selection)
+        #[arg(long)]
+        selection: Option<String>,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
     /// Rename the active selection
     Rename {
         /// New selection name (alphanumeric, - and _ only)
@@ -631,6 +1286,145 @@ pub enum SelectionCollectionCommand {
         #[arg(long)]
         json: bool,
     },
+    /// File every entry of the selection into a Zotero collection, creating the
+    /// collection and any missing items (from OpenAlex)
+    Sync {
+        /// Zotero collection key or exact name (default: named after the selection)
+        collection: Option<String>,
+        /// Target selection name or index (default: active selection)
+        #[arg(long)]
+        selection: Option<String>,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Create a selection from a Zotero collection (or add to it if it exists)
+    Import {
+        /// Zotero collection key (8 chars) or name search
+        collection: String,
+        /// Selection name (default: derived from the collection name)
+        #[arg(long)]
+        name: Option<String>,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum SelectionScreenCommand {
+    /// Record a screening decision for an entry
+    Decide {
+        /// Entry: 1-based index from `status`, Zotero key, DOI, OpenAlex ID, or title
+        paper: String,
+        /// Decision: include, exclude, or maybe
+        decision: String,
+        /// Screening stage: title_abstract or full_text
+        #[arg(long, default_value = "title_abstract")]
+        stage: String,
+        /// Reason for the decision (e.g. exclusion criterion)
+        #[arg(long)]
+        reason: Option<String>,
+        /// Reviewer name or initials
+        #[arg(long)]
+        reviewer: Option<String>,
+        /// Settle a reviewer conflict with this decision
+        #[arg(long)]
+        adjudicate: bool,
+        /// Target selection name or index (default: active selection)
+        #[arg(long)]
+        selection: Option<String>,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Show screening progress and per-entry decisions for a stage
+    Status {
+        /// Screening stage: title_abstract or full_text
+        #[arg(long, default_value = "title_abstract")]
+        stage: String,
+        /// Only list entries still awaiting a decision
+        #[arg(long)]
+        pending: bool,
+        /// Target selection name or index (default: active selection)
+        #[arg(long)]
+        selection: Option<String>,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Export a blinded CSV (no authors/venue/decisions) for an independent reviewer
+    Export {
+        /// Screening stage: title_abstract or full_text
+        #[arg(long, default_value = "title_abstract")]
+        stage: String,
+        /// Write CSV to this file instead of stdout
+        #[arg(long, short = 'o')]
+        output: Option<PathBuf>,
+        /// Target selection name or index (default: active selection)
+        #[arg(long)]
+        selection: Option<String>,
+        /// Output raw JSON instead of CSV
+        #[arg(long)]
+        json: bool,
+    },
+    /// Import a reviewer's decisions from CSV and report conflicts
+    Import {
+        /// CSV file with `key` and `decision` columns (optional `reason`, `reviewer`, `stage`)
+        file: PathBuf,
+        /// Reviewer for rows without a `reviewer` column
+        #[arg(long)]
+        reviewer: Option<String>,
+        /// Screening stage for rows without a `stage` column
+        #[arg(long, default_value = "title_abstract")]
+        stage: String,
+        /// Target selection name or index (default: active selection)
+        #[arg(long)]
+        selection: Option<String>,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// List entries where reviewers disagree
+    Conflicts {
+        /// Screening stage: title_abstract or full_text
+        #[arg(long, default_value = "title_abstract")]
+        stage: String,
+        /// Target selection name or index (default: active selection)
+        #[arg(long)]
+        selection: Option<String>,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// PRISMA flow summary (counts at each screening stage)
+    Prisma {
+        /// Target selection name or index (default: active selection)
+        #[arg(long)]
+        selection: Option<String>,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+/// Shared args for `work citations` / `work references`
+#[derive(Args, Clone)]
+pub struct CitationGraphArgs {
+    /// Work ID (OpenAlex ID, DOI, or title)
+    pub id: String,
+    /// Hops to expand from the root work (1-3)
+    #[arg(long, short = 'd', default_value_t = 1)]
+    pub depth: u32,
+    /// Maximum works fetched per expanded work, most-cited first (1-200)
+    #[arg(long, default_value_t = 25)]
+    pub per_work: u32,
+    /// Stop once this many unique works have been collected (1-1000)
+    #[arg(long, default_value_t = 200)]
+    pub max_works: usize,
+    /// Output raw JSON
+    #[arg(long)]
+    pub json: bool,
 }
 
 /// Shared args for all list commands
@@ -722,11 +1516,11 @@ pub struct WorkFilterArgs {
     #[arg(long)]
     pub citations: Option<String>,
 
-    /// Filter by country code of author institutions (e.g. "US", "GB")
+    /// Filter by country code of author institutions (e.g. "US", "GB", or a name like "germany")
     #[arg(long)]
     pub country: Option<String>,
 
-    /// Filter by continent of author institutions (e.g. "europe", "asia")
+    /// Filter by continent of author institutions (e.g. "europe", "north america")
     #[arg(long)]
     pub continent: Option<String>,
 
@@ -737,6 +1531,22 @@ pub struct WorkFilterArgs {
     /// Filter for open access works only
     #[arg(long)]
     pub open: bool,
+
+    /// Filter by language: ISO 639-1 code or name (e.g. "en", "german", "de|fr")
+    #[arg(long)]
+    pub language: Option<String>,
+
+    /// Exclude retracted works
+    #[arg(long)]
+    pub exclude_retracted: bool,
+
+    /// Exclude paratext (covers, tables of contents, front matter)
+    #[arg(long)]
+    pub exclude_paratext: bool,
+
+    /// Filter by UN Sustainable Development Goal number or name (e.g. "3", "SDG 13", "climate action")
+    #[arg(long)]
+    pub sdg: Option<String>,
 }
 
 /// Shorthand filter flags for `author list`.
@@ -746,11 +1556,11 @@ pub struct AuthorFilterArgs {
     #[arg(long)]
     pub institution: Option<String>,
 
-    /// Filter by country code of last known institution (e.g. "US", "GB")
+    /// Filter by country code of last known institution (e.g. "US", "GB", or a name like "germany")
     #[arg(long)]
     pub country: Option<String>,
 
-    /// Filter by continent of last known institution (e.g. "europe", "asia")
+    /// Filter by continent of last known institution (e.g. "europe", "north america")
     #[arg(long)]
     pub continent: Option<String>,
 
@@ -776,11 +1586,11 @@ pub struct SourceFilterArgs {
     #[arg(long)]
     pub publisher: Option<String>,
 
-    /// Filter by country code (e.g. "US", "GB")
+    /// Filter by country code (e.g. "US", "GB", or a name like "germany")
     #[arg(long)]
     pub country: Option<String>,
 
-    /// Filter by continent (e.g. "europe")
+    /// Filter by continent (e.g. "europe", "north america")
     #[arg(long)]
     pub continent: Option<String>,
 
@@ -804,11 +1614,11 @@ pub struct SourceFilterArgs {
 /// Shorthand filter flags for `institution list`.
 #[derive(Args, Clone, Default)]
 pub struct InstitutionFilterArgs {
-    /// Filter by country code (e.g. "US", "GB")
+    /// Filter by country code (e.g. "US", "GB", or a name like "germany")
     #[arg(long)]
     pub country: Option<String>,
 
-    /// Filter by continent (e.g. "europe", "asia")
+    /// Filter by continent (e.g. "europe", "north america")
     #[arg(long)]
     pub continent: Option<String>,
 
@@ -852,11 +1662,11 @@ pub struct TopicFilterArgs {
 /// Shorthand filter flags for `publisher list`.
 #[derive(Args, Clone, Default)]
 pub struct PublisherFilterArgs {
-    /// Filter by country code (e.g. "US", "GB"). Note: uses `country_codes` (plural).
+    /// Filter by country code (e.g. "US", "GB", or a name like "germany"). Note: uses `country_codes` (plural).
     #[arg(long)]
     pub country: Option<String>,
 
-    /// Filter by continent (e.g. "europe")
+    /// Filter by continent (e.g. "europe", "north america")
     #[arg(long)]
     pub continent: Option<String>,
 
@@ -872,11 +1682,11 @@ pub struct PublisherFilterArgs {
 /// Shorthand filter flags for `funder list`.
 #[derive(Args, Clone, Default)]
 pub struct FunderFilterArgs {
-    /// Filter by country code (e.g. "US", "GB")
+    /// Filter by country code (e.g. "US", "GB", or a name like "germany")
     #[arg(long)]
     pub country: Option<String>,
 
-    /// Filter by continent (e.g. "europe")
+    /// Filter by continent (e.g. "europe", "north america")
     #[arg(long)]
     pub continent: Option<String>,
 
@@ -954,6 +1764,17 @@ pub enum WorkCommand {
         #[arg(long)]
         json: bool,
     },
+    /// Get a work with Semantic Scholar's TLDR, influential citation count and recommendations
+    Enrich {
+        /// Work ID (OpenAlex ID, DOI, PMID, or PMCID)
+        id: String,
+        /// Number of recommended papers (0 to skip)
+        #[arg(long, short = 'n', default_value_t = 5)]
+        recommendations: u32,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
     /// Type-ahead search for works by title
     Autocomplete {
         /// Search query
@@ -962,41 +1783,149 @@ pub enum WorkCommand {
         #[arg(long)]
         json: bool,
     },
-    /// AI semantic search for similar works (requires OPENALEX_KEY)
-    Find {
-        /// Text to find similar works for
-        query: String,
-        /// Number of results (1-100)
-        #[arg(long, short = 'n')]
-        count: Option<u32>,
-        /// Filter expression (https://docs.openalex.org/api-entities/works/filter-works)
+    /// Count the works matching a filter per year, topic, institution, etc.
+    Stats {
+        /// Dimension: year, type, topic, subfield, field, domain, institution, country,
+        /// author, source, publisher, funder, oa_status, language, or sdg
+        #[arg(long, short = 'b', default_value = "year")]
+        by: String,
+        /// Full-text search query to narrow the works
+        #[arg(long, short = 's')]
+        search: Option<String>,
+        /// Filter expression (comma-separated AND conditions, pipe for OR)
         #[arg(long, short = 'f')]
         filter: Option<String>,
+        #[command(flatten)]
+        work_filters: WorkFilterArgs,
+        /// Largest groups to show for non-year dimensions
+        #[arg(long, short = 'n', default_value_t = 25)]
+        top: usize,
         /// Output raw JSON
         #[arg(long)]
         json: bool,
     },
-}
-
-#[derive(Subcommand)]
-pub enum AuthorCommand {
-    /// List authors with optional filter/sort
-    #[command(
-        after_help = "Advanced filtering: https://docs.openalex.org/api-entities/authors/filter-authors"
-    )]
-    List {
-        #[command(flatten)]
-        args: ListArgs,
+    /// Estimate APC (article processing charge) spend for the works matching a filter
+    Apc {
+        /// Full-text search query to narrow the works
+        #[arg(long, short = 's')]
+        search: Option<String>,
+        /// Filter expression (comma-separated AND conditions, pipe for OR)
+        #[arg(long, short = 'f')]
+        filter: Option<String>,
         #[command(flatten)]
-        filters: AuthorFilterArgs,
+        work_filters: WorkFilterArgs,
+        /// Maximum works to fetch and sum (max 10000); larger sets are extrapolated
+        #[arg(long, default_value_t = 1000)]
+        max_works: u32,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
     },
-    /// Full-text search for authors
-    Search {
-        /// Search query
-        query: String,
-        #[command(flatten)]
-        args: ListArgs,
-        #[command(flatten)]
+    /// Check a work's metadata for common problems
+    Quality {
+        /// Work ID (OpenAlex ID, DOI, or title)
+        id: String,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Find the published version of a preprint, or the preprint of a published work
+    PublishedVersion {
+        /// Work ID (OpenAlex ID, DOI, or title)
+        id: String,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Parse the reference list from a work's full text and match entries to OpenAlex
+    ReferencesExtracted {
+        /// Work ID (OpenAlex ID, DOI, PMID, or PMCID)
+        id: String,
+        /// Only parse; skip the OpenAlex lookups
+        #[arg(long)]
+        no_resolve: bool,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Find DOIs, arXiv IDs, OpenAlex IDs, and probable titles in free text (email, reference list)
+    ExtractIds {
+        /// Text file to scan (default: read stdin)
+        file: Option<std::path::PathBuf>,
+        /// Drop candidates below this confidence (0-1)
+        #[arg(long, default_value_t = 0.0)]
+        min_confidence: f64,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Format a citation for a work in APA, MLA, Chicago (author-date), or BibTeX
+    Cite {
+        /// Work ID (OpenAlex ID, DOI, or title)
+        id: String,
+        /// Citation style: apa, mla, chicago, or bibtex
+        #[arg(long, short = 's', default_value = "apa")]
+        style: String,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Related-work landscape for reviewing a submission: key prior works, recent closest matches, possible missed citations
+    ReviewContext {
+        /// File with the submission's title or abstract (default: read stdin)
+        file: Option<std::path::PathBuf>,
+        /// A work the submission cites (DOI or OpenAlex ID); repeat for each reference
+        #[arg(long = "reference", short = 'r')]
+        references: Vec<String>,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Works citing a work, optionally expanded over multiple hops
+    Citations {
+        #[command(flatten)]
+        graph: CitationGraphArgs,
+    },
+    /// Works referenced by a work, optionally expanded over multiple hops
+    References {
+        #[command(flatten)]
+        graph: CitationGraphArgs,
+    },
+    /// AI semantic search for similar works (requires OPENALEX_KEY)
+    Find {
+        /// Text to find similar works for
+        query: String,
+        /// Number of results (1-100)
+        #[arg(long, short = 'n')]
+        count: Option<u32>,
+        /// Filter expression (https://docs.openalex.org/api-entities/works/filter-works)
+        #[arg(long, short = 'f')]
+        filter: Option<String>,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum AuthorCommand {
+    /// List authors with optional filter/sort
+    #[command(
+        after_help = "Advanced filtering: https://docs.openalex.org/api-entities/authors/filter-authors"
+    )]
+    List {
+        #[command(flatten)]
+        args: ListArgs,
+        #[command(flatten)]
+        filters: AuthorFilterArgs,
+    },
+    /// Full-text search for authors
+    Search {
+        /// Search query
+        query: String,
+        #[command(flatten)]
+        args: ListArgs,
+        #[command(flatten)]
         filters: AuthorFilterArgs,
     },
     /// Get a single author by ID (OpenAlex ID or ORCID)
@@ -1005,6 +1934,32 @@ pub enum AuthorCommand {
         #[arg(long)]
         json: bool,
     },
+    /// Scholar-style profile: metrics, yearly counts, top works, co-authors, topics
+    Profile {
+        /// Author ID (OpenAlex ID, ORCID, or name)
+        id: String,
+        /// Number of most-cited works to show
+        #[arg(long, default_value_t = 5)]
+        top_works: u32,
+        /// Number of co-authors to show
+        #[arg(long, default_value_t = 10)]
+        top_coauthors: usize,
+        /// Number of topics to show
+        #[arg(long, default_value_t = 10)]
+        topics: usize,
+        #[arg(long)]
+        json: bool,
+    },
+    /// Institution timeline for an author (year spans)
+    Affiliations {
+        /// Author ID (OpenAlex ID, ORCID, or name)
+        id: String,
+        /// Show where the author was in this year
+        #[arg(long)]
+        year: Option<i32>,
+        #[arg(long)]
+        json: bool,
+    },
     /// Type-ahead search for authors
     Autocomplete {
         query: String,
@@ -1319,6 +2274,11 @@ pub enum ZoteroCommand {
         #[command(subcommand)]
         cmd: ZoteroGroupCommand,
     },
+    /// Item types and their valid fields and creator types
+    ItemType {
+        #[command(subcommand)]
+        cmd: ZoteroItemTypeCommand,
+    },
     /// Library settings (tagColors, feeds, etc.)
     Setting {
         #[command(subcommand)]
@@ -1334,6 +2294,24 @@ pub enum ZoteroCommand {
         #[command(subcommand)]
         cmd: ZoteroPermissionCommand,
     },
+    /// Raw Zotero API request for endpoints the other commands lack (prints JSON)
+    Raw {
+        /// Library-relative path (items/top, collections/<key>/items) or schema
+        /// endpoint (itemTypes, itemTypeFields, creatorFields, items/new)
+        path: String,
+        /// Query parameter as KEY=VALUE, repeatable (e.g. -q itemType=book)
+        #[arg(long = "query", short = 'q', value_name = "KEY=VALUE")]
+        query: Vec<String>,
+        /// HTTP method; anything but GET needs PAPERS_ZOTERO_RAW_WRITES=1
+        #[arg(long, short = 'X', default_value = "GET")]
+        method: String,
+        /// JSON request body (for writes)
+        #[arg(long)]
+        body: Option<String>,
+        /// Sent as If-Unmodified-Since-Version (for writes)
+        #[arg(long)]
+        version: Option<u64>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -1361,6 +2339,13 @@ pub enum ZoteroWorkCommand {
         /// Only items modified after this library version
         #[arg(long)]
         since: Option<u64>,
+        /// Export all matching items with Zotero's translators instead of listing
+        /// (bibtex, biblatex, csljson, ris, bib, …); ignores --limit
+        #[arg(long, value_name = "FORMAT")]
+        export: Option<String>,
+        /// CSL style for --export bib (e.g. apa, ieee)
+        #[arg(long)]
+        style: Option<String>,
         /// Output raw JSON
         #[arg(long)]
         json: bool,
@@ -1402,6 +2387,67 @@ pub enum ZoteroWorkCommand {
         #[arg(long)]
         json: bool,
     },
+    /// Add an OpenAlex work to the library (uses the web API; needs a write-enabled key)
+    Create {
+        /// OpenAlex work ID, DOI, or title
+        id: String,
+        /// Collection key or name to file the item under (repeatable)
+        #[arg(long = "collection")]
+        collections: Vec<String>,
+        /// Tag to attach (repeatable)
+        #[arg(long = "tag", short = 't')]
+        tags: Vec<String>,
+        /// Create even if an item with the same DOI already exists
+        #[arg(long)]
+        allow_duplicate: bool,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Update fields of an existing item
+    Update {
+        /// Item key (e.g. LF4MJWZK)
+        key: String,
+        /// Expected current item version; refuse the write if the item changed since
+        #[arg(long)]
+        version: Option<u64>,
+        #[arg(long)]
+        title: Option<String>,
+        #[arg(long = "abstract")]
+        abstract_note: Option<String>,
+        #[arg(long)]
+        doi: Option<String>,
+        #[arg(long)]
+        date: Option<String>,
+        #[arg(long)]
+        url: Option<String>,
+        /// Replacement for the `extra` field
+        #[arg(long)]
+        extra: Option<String>,
+        /// Tag to add (repeatable)
+        #[arg(long = "add-tag")]
+        add_tags: Vec<String>,
+        /// Tag to remove (repeatable)
+        #[arg(long = "remove-tag")]
+        remove_tags: Vec<String>,
+        /// Collection key or name to add the item to (repeatable)
+        #[arg(long = "add-collection")]
+        add_collections: Vec<String>,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Compare an item's title, year, DOI, venue and authors with OpenAlex
+    Diff {
+        /// Item key (e.g. LF4MJWZK), or an OpenAlex ID, DOI or title to look up in the library
+        id: String,
+        /// Write the OpenAlex values to the Zotero item
+        #[arg(long)]
+        apply: bool,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
     /// List collections the work belongs to
     Collections {
         /// Item key (e.g. LF4MJWZK) or a title/creator search string
@@ -1547,6 +2593,20 @@ pub enum ZoteroAttachmentCommand {
         /// Attachment key (e.g. LF4MJWZK) or a title/filename search string
         key: String,
     },
+    /// Search the text of every PDF via a local index, synced incrementally
+    FulltextSearch {
+        /// Words that must all appear in the PDF text
+        query: String,
+        /// Maximum number of results
+        #[arg(long, short = 'n', default_value = "20")]
+        limit: usize,
+        /// Fetch text added or changed since the last sync before searching
+        #[arg(long)]
+        sync: bool,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
 }
 
 #[derive(Subcommand)]
@@ -1670,6 +2730,13 @@ pub enum ZoteroCollectionCommand {
         /// Pagination offset (0-based)
         #[arg(long)]
         start: Option<u32>,
+        /// Export all matching items with Zotero's translators instead of listing
+        /// (bibtex, biblatex, csljson, ris, bib, …); ignores --limit
+        #[arg(long, value_name = "FORMAT")]
+        export: Option<String>,
+        /// CSL style for --export bib (e.g. apa, ieee)
+        #[arg(long)]
+        style: Option<String>,
         /// Output raw JSON
         #[arg(long)]
         json: bool,
@@ -1851,6 +2918,46 @@ pub enum ZoteroGroupCommand {
     },
 }
 
+#[derive(Subcommand)]
+pub enum ZoteroItemTypeCommand {
+    /// List all item types
+    List {
+        /// Locale for display names (e.g. de-DE)
+        #[arg(long)]
+        locale: Option<String>,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// List the fields valid for an item type
+    Fields {
+        /// Item type (e.g. journalArticle, book)
+        item_type: String,
+        /// Locale for display names (e.g. de-DE)
+        #[arg(long)]
+        locale: Option<String>,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// List the creator types valid for an item type (first is primary)
+    CreatorTypes {
+        /// Item type (e.g. journalArticle, book)
+        item_type: String,
+        /// Locale for display names (e.g. de-DE)
+        #[arg(long)]
+        locale: Option<String>,
+        /// Output raw JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Print an empty item template for an item type (JSON)
+    Template {
+        /// Item type (e.g. journalArticle, book)
+        item_type: String,
+    },
+}
+
 #[derive(Subcommand)]
 pub enum ZoteroSettingCommand {
     /// List all library settings (tagColors, etc.)
@@ -1899,6 +3006,34 @@ pub enum ConfigCommand {
         #[command(subcommand)]
         cmd: ConfigSetCommand,
     },
+    /// Show every papers.toml setting, its environment variable and current value
+    Show,
+    /// Manage named Zotero credentials (select one with PAPERS_ZOTERO_PROFILE)
+    ZoteroProfile {
+        #[command(subcommand)]
+        cmd: ZoteroProfileCommand,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum ZoteroProfileCommand {
+    /// Add or replace a profile
+    Set {
+        /// Profile name (letters, digits, '-' or '_'); a shared server's tenant uses the profile named after it
+        name: String,
+        /// Zotero user ID
+        #[arg(long)]
+        user_id: String,
+        /// Zotero API key
+        #[arg(long)]
+        api_key: String,
+    },
+    /// List profile names and user IDs
+    List,
+    /// Remove a profile
+    Remove {
+        name: String,
+    },
 }
 
 #[derive(Subcommand)]
@@ -1908,6 +3043,32 @@ pub enum ConfigSetCommand {
         /// Model name (e.g. embedding-gemma-300m)
         name: String,
     },
+    /// Set the vault directory for per-paper Markdown notes
+    Vault {
+        /// Directory (created on first sync); notes refresh on `db work add`
+        dir: String,
+    },
+    /// Set which PDF sources `work_text` tries
+    TextSources {
+        /// Comma-separated: zotero_local, zotero_remote, pmc, oa_urls, openalex_content,
+        /// unpaywall, link_resolver (omitted sources are not tried)
+        order: String,
+    },
+    /// Set the contact email sent with Unpaywall lookups
+    UnpaywallEmail {
+        email: String,
+    },
+    /// Set an institutional link resolver used to fetch PDFs
+    LinkResolver {
+        /// URL template with a `{doi}` placeholder
+        url: String,
+    },
+    /// Embed the abstract of every paper added to a selection into the abstracts index
+    AutoIndexAbstracts {
+        /// true or false
+        #[arg(action = clap::ArgAction::Set)]
+        enabled: bool,
+    },
 }
 
 #[cfg(test)]
@@ -1933,6 +3094,187 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_config_show() {
+        let cli = parse(&["papers", "config", "show"]);
+        assert!(matches!(cli.entity, EntityCommand::Config { cmd: ConfigCommand::Show }));
+    }
+
+    #[test]
+    fn test_parse_config_zotero_profile_set() {
+        let cli = parse(&["papers", "config", "zotero-profile", "set", "alice", "--user-id", "123", "--api-key", "k"]);
+        match cli.entity {
+            EntityCommand::Config {
+                cmd:
+                    ConfigCommand::ZoteroProfile {
+                        cmd: ZoteroProfileCommand::Set { name, user_id, api_key },
+                    },
+            } => {
+                assert_eq!(name, "alice");
+                assert_eq!(user_id, "123");
+                assert_eq!(api_key, "k");
+            }
+            _ => panic!("wrong variant"),
+        }
+    }
+
+    #[test]
+    fn test_parse_config_set_link_resolver() {
+        let cli = parse(&[
+            "papers", "config", "set", "link-resolver", "https://resolver.example.edu/?id=doi:{doi}",
+        ]);
+        match cli.entity {
+            EntityCommand::Config {
+                cmd:
+                    ConfigCommand::Set {
+                        cmd: ConfigSetCommand::LinkResolver { url },
+                    },
+            } => assert_eq!(url, "https://resolver.example.edu/?id=doi:{doi}"),
+            _ => panic!("wrong variant"),
+        }
+    }
+
+    #[test]
+    fn test_parse_config_set_auto_index_abstracts() {
+        let cli = parse(&["papers", "config", "set", "auto-index-abstracts", "true"]);
+        match cli.entity {
+            EntityCommand::Config {
+                cmd:
+                    ConfigCommand::Set {
+                        cmd: ConfigSetCommand::AutoIndexAbstracts { enabled },
+                    },
+            } => assert!(enabled),
+            _ => panic!("wrong variant"),
+        }
+        assert!(Cli::try_parse_from(["papers", "config", "set", "auto-index-abstracts", "maybe"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_raw_query_pairs() {
+        let cli = parse(&["papers", "raw", "works", "-q", "group_by=oa_status", "-q", "per-page=5"]);
+        match cli.entity {
+            EntityCommand::Raw { path, query } => {
+                assert_eq!(path, "works");
+                assert_eq!(query, vec!["group_by=oa_status", "per-page=5"]);
+            }
+            _ => panic!("wrong variant"),
+        }
+    }
+
+    #[test]
+    fn test_parse_zotero_library_after_subcommand() {
+        let cli = parse(&["papers", "zotero", "collection", "list", "--library", "Graphics Lab"]);
+        match cli.entity {
+            EntityCommand::Zotero {
+                library,
+                cmd: ZoteroCommand::Collection { cmd: ZoteroCollectionCommand::List { .. } },
+            } => assert_eq!(library.as_deref(), Some("Graphics Lab")),
+            _ => panic!("wrong variant"),
+        }
+    }
+
+    #[test]
+    fn test_parse_zotero_collection_works_export() {
+        let cli = parse(&[
+            "papers", "zotero", "collection", "works", "GPU Papers", "--export", "bib", "--style",
+            "apa",
+        ]);
+        match cli.entity {
+            EntityCommand::Zotero {
+                cmd:
+                    ZoteroCommand::Collection {
+                        cmd: ZoteroCollectionCommand::Works { key, export, style, .. },
+                    },
+                ..
+            } => {
+                assert_eq!(key, "GPU Papers");
+                assert_eq!(export.as_deref(), Some("bib"));
+                assert_eq!(style.as_deref(), Some("apa"));
+            }
+            _ => panic!("wrong variant"),
+        }
+    }
+
+    #[test]
+    fn test_parse_zotero_attachment_fulltext_search() {
+        let cli = parse(&["papers", "zotero", "attachment", "fulltext-search", "voxel grid", "--sync", "-n", "5"]);
+        match cli.entity {
+            EntityCommand::Zotero {
+                cmd:
+                    ZoteroCommand::Attachment {
+                        cmd: ZoteroAttachmentCommand::FulltextSearch { query, limit, sync, json },
+                    },
+                ..
+            } => {
+                assert_eq!(query, "voxel grid");
+                assert_eq!(limit, 5);
+                assert!(sync && !json);
+            }
+            _ => panic!("wrong variant"),
+        }
+    }
+
+    #[test]
+    fn test_parse_zotero_raw() {
+        let cli = parse(&["papers", "zotero", "raw", "itemTypeFields", "-q", "itemType=book"]);
+        match cli.entity {
+            EntityCommand::Zotero {
+                cmd: ZoteroCommand::Raw { path, query, method, body, version },
+                ..
+            } => {
+                assert_eq!(path, "itemTypeFields");
+                assert_eq!(query, vec!["itemType=book"]);
+                assert_eq!(method, "GET");
+                assert!(body.is_none() && version.is_none());
+            }
+            _ => panic!("wrong variant"),
+        }
+    }
+
+    #[test]
+    fn test_parse_zotero_item_type_fields() {
+        let cli = parse(&["papers", "zotero", "item-type", "fields", "book", "--locale", "de-DE"]);
+        match cli.entity {
+            EntityCommand::Zotero {
+                cmd:
+                    ZoteroCommand::ItemType {
+                        cmd: ZoteroItemTypeCommand::Fields { item_type, locale, json },
+                    },
+                ..
+            } => {
+                assert_eq!(item_type, "book");
+                assert_eq!(locale.as_deref(), Some("de-DE"));
+                assert!(!json);
+            }
+            _ => panic!("wrong variant"),
+        }
+    }
+
+    #[test]
+    fn test_parse_cache_clear_entity() {
+        let cli = parse(&["papers", "cache", "clear", "--entity", "works"]);
+        match cli.entity {
+            EntityCommand::Cache {
+                cmd: CacheCommand::Clear { entity },
+            } => assert_eq!(entity.as_deref(), Some("works")),
+            _ => panic!("wrong variant"),
+        }
+    }
+
+    #[test]
+    fn test_parse_snapshot_index() {
+        let cli = parse(&["papers", "snapshot", "index", "/data/openalex", "--out", "/data/index"]);
+        match cli.entity {
+            EntityCommand::Snapshot {
+                cmd: SnapshotCommand::Index { source, out },
+            } => {
+                assert_eq!(source, PathBuf::from("/data/openalex"));
+                assert_eq!(out, Some(PathBuf::from("/data/index")));
+            }
+            _ => panic!("wrong variant"),
+        }
+    }
+
     #[test]
     fn test_parse_db_chunk_search() {
         let cli = parse(&["papers", "db", "chunk", "search", "neural rendering"]);
@@ -1944,6 +3286,21 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_db_chunk_search_collection() {
+        let cli = parse(&["papers", "db", "chunk", "search", "neural rendering", "--collection", "GPU Papers"]);
+        match cli.entity {
+            EntityCommand::Db {
+                cmd: DbCommand::Chunk { cmd: DbChunkCommand::Search { collection, selection, .. } },
+            } => {
+                assert_eq!(collection.as_deref(), Some("GPU Papers"));
+                assert!(selection.is_none());
+            }
+            _ => panic!("wrong variant"),
+        }
+        assert!(Cli::try_parse_from(["papers", "db", "work", "list", "--collection", "A", "--selection", "b"]).is_err());
+    }
+
     #[test]
     fn test_parse_db_chunk_get() {
         let cli = parse(&["papers", "db", "chunk", "get", "YFACFA8C/ch1/s2/p3"]);
@@ -1955,6 +3312,134 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_db_chunk_locate() {
+        let cli = parse(&["papers", "db", "chunk", "locate", "QXNY8AX8", "-n", "5"]);
+        match cli.entity {
+            EntityCommand::Db {
+                cmd: DbCommand::Chunk { cmd: DbChunkCommand::Locate { annotation_key, limit, .. } },
+            } => {
+                assert_eq!(annotation_key, "QXNY8AX8");
+                assert_eq!(limit, 5);
+            }
+            _ => panic!("wrong variant"),
+        }
+    }
+
+    #[test]
+    fn test_parse_db_chunk_mark_read() {
+        let cli = parse(&["papers", "db", "chunk", "mark-read", "YFACFA8C/ch1/s2/p3"]);
+        match cli.entity {
+            EntityCommand::Db {
+                cmd: DbCommand::Chunk { cmd: DbChunkCommand::MarkRead { chunk_id, .. } },
+            } => assert_eq!(chunk_id, "YFACFA8C/ch1/s2/p3"),
+            _ => panic!("wrong variant"),
+        }
+    }
+
+    #[test]
+    fn test_parse_db_work_status() {
+        let cli = parse(&["papers", "db", "work", "status", "10.1145/3592433", "--status", "read", "--rating", "4"]);
+        match cli.entity {
+            EntityCommand::Db {
+                cmd: DbCommand::Work { cmd: DbWorkCommand::Status { paper_id, status, rating, chunk, .. } },
+            } => {
+                assert_eq!(paper_id.as_deref(), Some("10.1145/3592433"));
+                assert_eq!(status.as_deref(), Some("read"));
+                assert_eq!(rating, Some(4));
+                assert_eq!(chunk, None);
+            }
+            _ => panic!("wrong variant"),
+        }
+        assert!(Cli::try_parse_from(["papers", "db", "work", "status", "--rating", "3"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_selection_add_bulk() {
+        let cli = parse(&["papers", "selection", "add-bulk", "10.1145/3592433", "W2741809807", "--selection", "reading"]);
+        match cli.entity {
+            EntityCommand::Selection {
+                cmd: SelectionCommand::AddBulk { papers, selection, .. },
+            } => {
+                assert_eq!(papers, vec!["10.1145/3592433", "W2741809807"]);
+                assert_eq!(selection.as_deref(), Some("reading"));
+            }
+            _ => panic!("wrong variant"),
+        }
+    }
+
+    #[test]
+    fn test_parse_selection_collection_sync_and_import() {
+        let cli = parse(&["papers", "selection", "collection", "sync", "Thesis", "--selection", "reading"]);
+        match cli.entity {
+            EntityCommand::Selection {
+                cmd: SelectionCommand::Collection { cmd: SelectionCollectionCommand::Sync { collection, selection, .. } },
+            } => {
+                assert_eq!(collection.as_deref(), Some("Thesis"));
+                assert_eq!(selection.as_deref(), Some("reading"));
+            }
+            _ => panic!("wrong variant"),
+        }
+        let cli = parse(&["papers", "selection", "collection", "import", "GPU Papers", "--name", "gpu"]);
+        match cli.entity {
+            EntityCommand::Selection {
+                cmd: SelectionCommand::Collection { cmd: SelectionCollectionCommand::Import { collection, name, .. } },
+            } => {
+                assert_eq!(collection, "GPU Papers");
+                assert_eq!(name.as_deref(), Some("gpu"));
+            }
+            _ => panic!("wrong variant"),
+        }
+    }
+
+    #[test]
+    fn test_parse_selection_add_references() {
+        let cli = parse(&["papers", "selection", "add-references", "W2741809807", "--year", ">2015", "--topic", "sleep"]);
+        match cli.entity {
+            EntityCommand::Selection {
+                cmd: SelectionCommand::AddReferences { work, work_filters, selection, .. },
+            } => {
+                assert_eq!(work, "W2741809807");
+                assert_eq!(work_filters.year.as_deref(), Some(">2015"));
+                assert_eq!(work_filters.topic.as_deref(), Some("sleep"));
+                assert_eq!(selection, None);
+            }
+            _ => panic!("wrong variant"),
+        }
+    }
+
+    #[test]
+    fn test_parse_selection_add_citers() {
+        let cli = parse(&["papers", "selection", "add-citers", "W2741809807", "--min-citations", "10", "--year", "2018-2024", "-n", "20"]);
+        match cli.entity {
+            EntityCommand::Selection {
+                cmd: SelectionCommand::AddCiters { work, min_citations, sort, work_filters, limit, .. },
+            } => {
+                assert_eq!(work, "W2741809807");
+                assert_eq!(min_citations, Some(10));
+                assert_eq!(sort, "cited_by_count:desc");
+                assert_eq!(work_filters.year.as_deref(), Some("2018-2024"));
+                assert_eq!(limit, 20);
+            }
+            _ => panic!("wrong variant"),
+        }
+    }
+
+    #[test]
+    fn test_parse_db_abstract_search() {
+        let cli = parse(&["papers", "db", "abstract", "search", "sleep spindles", "-n", "3", "--year-min", "2015"]);
+        match cli.entity {
+            EntityCommand::Db {
+                cmd: DbCommand::Abstract { cmd: DbAbstractCommand::Search { query, limit, year_min, .. } },
+            } => {
+                assert_eq!(query, "sleep spindles");
+                assert_eq!(limit, 3);
+                assert_eq!(year_min, Some(2015));
+            }
+            _ => panic!("wrong variant"),
+        }
+    }
+
     #[test]
     fn test_parse_db_work_list() {
         let cli = parse(&["papers", "db", "work", "list"]);
@@ -2022,6 +3507,167 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_db_vault_sync() {
+        let cli = parse(&["papers", "db", "vault", "sync", "--dir", "/notes/papers"]);
+        match cli.entity {
+            EntityCommand::Db {
+                cmd: DbCommand::Vault { cmd: DbVaultCommand::Sync { work, dir, json } },
+            } => {
+                assert!(work.is_none());
+                assert_eq!(dir.as_deref(), Some("/notes/papers"));
+                assert!(!json);
+            }
+            _ => panic!("wrong variant"),
+        }
+    }
+
+    #[test]
+    fn test_parse_selection_site() {
+        let cli = parse(&["papers", "selection", "site", "-o", "site", "--selection", "2"]);
+        match cli.entity {
+            EntityCommand::Selection { cmd: SelectionCommand::Site { out, selection, json } } => {
+                assert_eq!(out, PathBuf::from("site"));
+                assert_eq!(selection.as_deref(), Some("2"));
+                assert!(!json);
+            }
+            _ => panic!("wrong variant"),
+        }
+    }
+
+    #[test]
+    fn test_parse_selection_cite() {
+        let cli = parse(&["papers", "selection", "cite", "intro.tex", "--bib", "refs.bib", "--append", "-o", "intro.filled.tex"]);
+        match cli.entity {
+            EntityCommand::Selection { cmd: SelectionCommand::Cite { file, bib, output, append, selection, json } } => {
+                assert_eq!(file, Some(PathBuf::from("intro.tex")));
+                assert_eq!(bib, Some(PathBuf::from("refs.bib")));
+                assert_eq!(output, Some(PathBuf::from("intro.filled.tex")));
+                assert!(append && !json);
+                assert_eq!(selection, None);
+            }
+            _ => panic!("wrong variant"),
+        }
+        assert!(Cli::try_parse_from(["papers", "selection", "cite", "--append"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_work_cite() {
+        let cli = parse(&["papers", "work", "cite", "10.7717/peerj.4375", "-s", "mla"]);
+        match cli.entity {
+            EntityCommand::Work { cmd: WorkCommand::Cite { id, style, json } } => {
+                assert_eq!(id, "10.7717/peerj.4375");
+                assert_eq!(style, "mla");
+                assert!(!json);
+            }
+            _ => panic!("wrong variant"),
+        }
+        let cli = parse(&["papers", "work", "cite", "W1"]);
+        assert!(matches!(cli.entity, EntityCommand::Work { cmd: WorkCommand::Cite { style, .. } } if style == "apa"));
+    }
+
+    #[test]
+    fn test_parse_work_review_context() {
+        let cli = parse(&["papers", "work", "review-context", "abstract.txt", "-r", "10.1145/3592433", "--reference", "W2"]);
+        match cli.entity {
+            EntityCommand::Work { cmd: WorkCommand::ReviewContext { file, references, json } } => {
+                assert_eq!(file, Some(PathBuf::from("abstract.txt")));
+                assert_eq!(references, ["10.1145/3592433", "W2"]);
+                assert!(!json);
+            }
+            _ => panic!("wrong variant"),
+        }
+    }
+
+    #[test]
+    fn test_parse_serve() {
+        let cli = parse(&["papers", "serve", "--host", "0.0.0.0", "--port", "9000"]);
+        match cli.entity {
+            EntityCommand::Serve { host, port, read_only } => {
+                assert_eq!(host.as_deref(), Some("0.0.0.0"));
+                assert_eq!(port, Some(9000));
+                assert!(!read_only);
+            }
+            _ => panic!("wrong variant"),
+        }
+    }
+
+    #[test]
+    fn test_parse_mcp_start_read_only() {
+        let cli = parse(&["papers", "mcp", "start", "--stdio", "--read-only"]);
+        match cli.entity {
+            EntityCommand::Mcp { cmd: McpCommand::Start { stdio, read_only } } => assert!(stdio && read_only),
+            _ => panic!("wrong variant"),
+        }
+    }
+
+    #[test]
+    fn test_parse_audit_export() {
+        let cli = parse(&["papers", "audit", "export", "--since", "2026-01-01", "--user", "alice"]);
+        match cli.entity {
+            EntityCommand::Audit { cmd: AuditCommand::Export { since, until, user, tool } } => {
+                assert_eq!(since.as_deref(), Some("2026-01-01"));
+                assert_eq!(until, None);
+                assert_eq!(user.as_deref(), Some("alice"));
+                assert_eq!(tool, None);
+            }
+            _ => panic!("wrong variant"),
+        }
+    }
+
+    #[test]
+    fn test_parse_digest() {
+        let cli = parse(&["papers", "digest", "-s", "mine", "--watch", "nerf", "-w", "gaussian splatting", "--days", "7"]);
+        match cli.entity {
+            EntityCommand::Digest { selections, watches, days, notify, json } => {
+                assert_eq!(selections, ["mine"]);
+                assert_eq!(watches, ["nerf", "gaussian splatting"]);
+                assert_eq!(days, 7);
+                assert!(!notify && !json);
+            }
+            _ => panic!("wrong variant"),
+        }
+        let cli = parse(&["papers", "digest"]);
+        assert!(matches!(cli.entity, EntityCommand::Digest { days: 1, notify: false, .. }));
+        let cli = parse(&["papers", "digest", "-w", "nerf", "--notify"]);
+        assert!(matches!(cli.entity, EntityCommand::Digest { notify: true, .. }));
+    }
+
+    #[test]
+    fn test_parse_db_chunk_verify() {
+        let cli = parse(&["papers", "db", "chunk", "verify", "Splatting is faster than NeRF", "--selection", "thesis", "--mode", "hybrid"]);
+        match cli.entity {
+            EntityCommand::Db {
+                cmd: DbCommand::Chunk { cmd: DbChunkCommand::Verify { statement, selection, work, mode, rerank, limit, json } },
+            } => {
+                assert_eq!(statement, "Splatting is faster than NeRF");
+                assert_eq!(selection.as_deref(), Some("thesis"));
+                assert_eq!(work, None);
+                assert_eq!(mode, papers_db::SearchMode::Hybrid);
+                assert!(!rerank && !json);
+                assert_eq!(limit, 8);
+            }
+            _ => panic!("wrong variant"),
+        }
+    }
+
+    #[test]
+    fn test_parse_db_chunk_ground() {
+        let cli = parse(&["papers", "db", "chunk", "ground", "draft.md", "--selection", "thesis", "-n", "5"]);
+        match cli.entity {
+            EntityCommand::Db {
+                cmd: DbCommand::Chunk { cmd: DbChunkCommand::Ground { file, selection, mode, rerank, limit, json } },
+            } => {
+                assert_eq!(file, Some(std::path::PathBuf::from("draft.md")));
+                assert_eq!(selection.as_deref(), Some("thesis"));
+                assert_eq!(mode, papers_db::SearchMode::Semantic);
+                assert!(!rerank && !json);
+                assert_eq!(limit, 5);
+            }
+            _ => panic!("wrong variant"),
+        }
+    }
+
     #[test]
     fn test_parse_db_tag_list() {
         let cli = parse(&["papers", "db", "tag", "list"]);
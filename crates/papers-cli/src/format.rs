@@ -1,4 +1,7 @@
-use papers_core::api::WorkGetResponse;
+use papers_core::api::{
+    AffiliationHistory, AffiliationSpan, AuthorProfile, CitationDirection, CitationGraphResponse, WorkGetResponse,
+};
+use papers_core::quality::{QualitySeverity, WorkQualityReport};
 use papers_core::summary::{
     AuthorSummary, DomainSummary, FieldSummary, FunderSummary, InstitutionSummary,
     PublisherSummary, SlimListResponse, SourceSummary, SubfieldSummary, TopicSummary, WorkSummary,
@@ -169,6 +172,9 @@ pub fn format_work_get(w: &Work) -> String {
 
 pub fn format_work_get_response(response: &WorkGetResponse, zotero_configured: bool) -> String {
     let mut out = format_work_get(&response.work);
+    if response.from_crossref {
+        out.push_str("\nSource: Crossref (not yet in OpenAlex)\n");
+    }
     if zotero_configured {
         out.push('\n');
         if let Some(z) = &response.zotero {
@@ -176,6 +182,9 @@ pub fn format_work_get_response(response: &WorkGetResponse, zotero_configured: b
             out.push_str(&format!("  Key:   {}\n", z.key));
             out.push_str(&format!("  Open:  {}\n", z.uri));
             out.push_str(&format!("  Type:  {}\n", z.item_type));
+            if z.matched_by == papers_core::text::ZoteroMatchMethod::Title {
+                out.push_str(&format!("  Match: title and year ({:.0}% confidence)\n", z.confidence * 100.0));
+            }
             out.push_str(&format!(
                 "  PDF:   {}\n",
                 if z.has_pdf { "Yes" } else { "No" }
@@ -190,6 +199,28 @@ pub fn format_work_get_response(response: &WorkGetResponse, zotero_configured: b
             out.push_str("Zotero: not in library\n");
         }
     }
+    if let Some(s2) = &response.semantic_scholar {
+        out.push_str("\nSemantic Scholar:\n");
+        if let Some(tldr) = &s2.tldr {
+            out.push_str(&format!("  TLDR:      {tldr}\n"));
+        }
+        if let (Some(total), Some(influential)) = (s2.citation_count, s2.influential_citation_count) {
+            out.push_str(&format!("  Citations: {total} ({influential} influential)\n"));
+        }
+        if let Some(url) = &s2.url {
+            out.push_str(&format!("  Page:      {url}\n"));
+        }
+        if !s2.recommendations.is_empty() {
+            out.push_str("  Related:\n");
+            for r in &s2.recommendations {
+                let year = r.year.map(|y| format!(" ({y})")).unwrap_or_default();
+                let doi = r.doi.as_deref().map(|d| format!("  doi:{d}")).unwrap_or_default();
+                out.push_str(&format!("    - {}{year}{doi}\n", r.title.as_deref().unwrap_or("?")));
+            }
+        }
+    } else if let Some(e) = &response.semantic_scholar_error {
+        out.push_str(&format!("\nSemantic Scholar: unavailable ({e})\n"));
+    }
     out
 }
 
@@ -266,6 +297,120 @@ pub fn format_author_get(a: &Author) -> String {
     out
 }
 
+pub fn format_author_profile(p: &AuthorProfile) -> String {
+    let mut out = String::new();
+    let name = p.display_name.as_deref().unwrap_or("?");
+    out.push_str(&format!("Author: {name}\n"));
+    out.push_str(&format!("ID:     {}\n", p.id));
+    if let Some(orcid) = &p.orcid {
+        out.push_str(&format!("ORCID:  {orcid}\n"));
+    }
+    if !p.last_known_institutions.is_empty() {
+        out.push_str(&format!("Institutions: {}\n", p.last_known_institutions.join(" · ")));
+    }
+
+    let mut stats = Vec::new();
+    if let Some(w) = p.works_count {
+        stats.push(format!("{w} works"));
+    }
+    if let Some(c) = p.cited_by_count {
+        stats.push(format!("{c} citations"));
+    }
+    if let Some(h) = p.h_index {
+        stats.push(format!("h-index: {h}"));
+    }
+    if let Some(i) = p.i10_index {
+        stats.push(format!("i10-index: {i}"));
+    }
+    if let Some(m) = p.two_yr_mean_citedness {
+        stats.push(format!("2yr mean citedness: {m:.2}"));
+    }
+    if !stats.is_empty() {
+        out.push_str(&format!("{}\n", stats.join(" · ")));
+    }
+
+    if !p.counts_by_year.is_empty() {
+        out.push_str("\nBy year:\n");
+        for c in &p.counts_by_year {
+            out.push_str(&format!(
+                "  {}  {:>5} works  {:>7} citations\n",
+                c.year,
+                c.works_count.unwrap_or(0),
+                c.cited_by_count.unwrap_or(0)
+            ));
+        }
+    }
+
+    if !p.top_works.is_empty() {
+        out.push_str("\nTop works:\n");
+        for (i, w) in p.top_works.iter().enumerate() {
+            let title = w.title.as_deref().unwrap_or("(untitled)");
+            let year = w
+                .publication_year
+                .map_or(String::new(), |y| format!(" ({y})"));
+            let cites = w.cited_by_count.unwrap_or(0);
+            out.push_str(&format!(" {:>2}  {title}{year} · {cites} citations\n", i + 1));
+        }
+    }
+
+    if !p.top_coauthors.is_empty() {
+        out.push_str("\nTop co-authors:\n");
+        for c in &p.top_coauthors {
+            out.push_str(&format!("  {} ({}) · {} shared works\n", c.display_name, c.id, c.shared_works));
+        }
+    }
+
+    if !p.topics.is_empty() {
+        out.push_str("\nTopics:\n");
+        for t in &p.topics {
+            let share = t.share.map_or(String::new(), |s| format!(" ({:.0}%)", s * 100.0));
+            out.push_str(&format!("  {} · {} works{share}\n", t.display_name, t.count));
+        }
+    }
+
+    out
+}
+
+fn affiliation_span_line(s: &AffiliationSpan) -> String {
+    let name = s.display_name.as_deref().unwrap_or("?");
+    let years = if s.start_year == s.end_year {
+        s.start_year.to_string()
+    } else {
+        format!("{}–{}", s.start_year, s.end_year)
+    };
+    let country = s
+        .country_code
+        .as_deref()
+        .map_or(String::new(), |c| format!(" ({c})"));
+    format!("  {years:<9}  {name}{country}\n")
+}
+
+pub fn format_affiliation_history(h: &AffiliationHistory) -> String {
+    let name = h.display_name.as_deref().unwrap_or("?");
+    let mut out = format!("Author: {name} ({})\n", h.id);
+    if h.spans.is_empty() {
+        out.push_str("No affiliations found.\n");
+        return out;
+    }
+    if h.source == "works" {
+        out.push_str("(rebuilt from works; author record has no affiliations)\n");
+    }
+    if let Some(y) = h.year {
+        out.push_str(&format!("\nIn {y}:\n"));
+        if h.at_year.is_empty() {
+            out.push_str("  (no known affiliation)\n");
+        }
+        for s in &h.at_year {
+            out.push_str(&affiliation_span_line(s));
+        }
+    }
+    out.push_str("\nHistory:\n");
+    for s in &h.spans {
+        out.push_str(&affiliation_span_line(s));
+    }
+    out
+}
+
 // ── Source ────────────────────────────────────────────────────────────────
 
 pub fn format_source_list(resp: &SlimListResponse<SourceSummary>) -> String {
@@ -748,6 +893,326 @@ pub fn format_autocomplete(resp: &AutocompleteResponse) -> String {
     out
 }
 
+// ── APC spend ─────────────────────────────────────────────────────────────
+
+/// Width of the longest bar in [`format_work_stats`].
+const STATS_BAR_WIDTH: usize = 30;
+
+pub fn format_work_stats(stats: &papers_core::stats::WorkStats) -> String {
+    let mut out = format!("{} matching works by {}", stats.total, stats.by.as_str());
+    if let Some(groups) = stats.groups_count.filter(|g| *g as usize > stats.series.len()) {
+        out.push_str(&format!(" (top {} of {groups} groups)", stats.series.len()));
+    }
+    out.push_str("\n\n");
+    if stats.series.is_empty() {
+        out.push_str("  No groups.\n");
+        return out;
+    }
+    let max = stats.series.iter().map(|p| p.count).max().unwrap_or(0).max(1);
+    let width = stats.series.iter().map(|p| p.label.chars().count()).max().unwrap_or(0).min(40);
+    for p in &stats.series {
+        let label: String = p.label.chars().take(40).collect();
+        let bar = "\u{2588}".repeat((p.count as f64 / max as f64 * STATS_BAR_WIDTH as f64).round() as usize);
+        out.push_str(&format!("  {label:<width$} {:>10}  {bar}\n", p.count));
+    }
+    out
+}
+
+pub fn format_apc_report(report: &papers_core::apc::ApcReport) -> String {
+    let mut out = match report.matched {
+        Some(matched) => format!("APC estimate for {} of {matched} matching works\n\n", report.works),
+        None => format!("APC estimate for {} works\n\n", report.works),
+    };
+    out.push_str(&format!(
+        "  Estimated spend:   ${} ({} charged works",
+        report.estimated_spend_usd, report.charged_works
+    ));
+    if let Some(mean) = report.mean_charge_usd {
+        out.push_str(&format!(", mean ${mean:.0}"));
+    }
+    out.push_str(")\n");
+    if let Some(extrapolated) = report.extrapolated_spend_usd {
+        out.push_str(&format!("  Extrapolated:      ${extrapolated} (all matching works)\n"));
+    }
+    out.push_str(&format!(
+        "  apc_paid total:    ${} ({} works)\n",
+        report.apc_paid_usd, report.with_apc_paid
+    ));
+    out.push_str(&format!(
+        "  apc_list total:    ${} ({} works)\n",
+        report.apc_list_usd, report.with_apc_list
+    ));
+    if !report.by_source.is_empty() {
+        out.push_str("\n  Top sources:\n");
+        for g in &report.by_source {
+            let name = g.name.as_deref().unwrap_or(&g.key);
+            out.push_str(&format!(
+                "    {:<40} {:>10}  ({} of {} works charged)\n",
+                name,
+                format!("${}", g.estimated_usd),
+                g.charged_works,
+                g.works
+            ));
+        }
+    }
+    if !report.by_year.is_empty() {
+        out.push_str("\n  By year:\n");
+        for g in &report.by_year {
+            out.push_str(&format!(
+                "    {}  {:>10}  ({} of {} works charged)\n",
+                g.key,
+                format!("${}", g.estimated_usd),
+                g.charged_works,
+                g.works
+            ));
+        }
+    }
+    if !report.unresolved.is_empty() {
+        out.push_str(&format!("\n  Not found in OpenAlex ({}):\n", report.unresolved.len()));
+        for label in &report.unresolved {
+            out.push_str(&format!("    - {label}\n"));
+        }
+    }
+    out
+}
+
+// ── Published version ─────────────────────────────────────────────────────
+
+pub fn format_published_version(report: &papers_core::versions::PublishedVersionReport) -> String {
+    use papers_core::versions::VersionRole;
+
+    let title = report.work.title.as_deref().unwrap_or("(untitled)");
+    let role = match report.role {
+        VersionRole::Preprint => "preprint",
+        VersionRole::Published => "published",
+    };
+    let mut out = format!("{title}\n{} ({role})\n\n", report.work.id);
+    match (&report.preprint_id, &report.published_id, report.confidence) {
+        (Some(pre), Some(publ), Some(conf)) if pre == publ => out.push_str(&format!(
+            "  Preprint and published version are the same OpenAlex record ({pre}, confidence {conf:.2})\n"
+        )),
+        (Some(pre), Some(publ), Some(conf)) => out.push_str(&format!(
+            "  Preprint:  {pre}\n  Published: {publ}\n  Confidence: {conf:.2}\n"
+        )),
+        _ => out.push_str("  No counterpart found.\n"),
+    }
+    if !report.merged_locations.is_empty() {
+        out.push_str("\n  Other-version locations in this record:\n");
+        for loc in &report.merged_locations {
+            out.push_str(&format!(
+                "    - {} ({}) {}\n",
+                loc.source.as_deref().unwrap_or("?"),
+                loc.version.as_deref().unwrap_or("?"),
+                loc.landing_page_url.as_deref().unwrap_or("")
+            ));
+        }
+    }
+    if !report.candidates.is_empty() {
+        out.push_str("\n  Candidates:\n");
+        for c in &report.candidates {
+            let evidence: Vec<&str> = c.evidence.iter().map(|e| e.as_str()).collect();
+            out.push_str(&format!(
+                "    {:.2}  {}  {} ({})  [{}]\n",
+                c.confidence,
+                c.work.id,
+                c.work.title.as_deref().unwrap_or("(untitled)"),
+                c.work.publication_year.map_or("?".to_string(), |y| y.to_string()),
+                evidence.join(", ")
+            ));
+        }
+    }
+    out
+}
+
+// ── Cache ─────────────────────────────────────────────────────────────────
+
+fn format_ttl(secs: u64) -> String {
+    match secs {
+        0 => "off".to_string(),
+        s if s % 86_400 == 0 => format!("{}d", s / 86_400),
+        s if s % 3_600 == 0 => format!("{}h", s / 3_600),
+        s if s % 60 == 0 => format!("{}m", s / 60),
+        s => format!("{s}s"),
+    }
+}
+
+fn format_size(bytes: u64) -> String {
+    if bytes >= 1024 * 1024 {
+        format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+    } else {
+        format!("{:.1} KB", bytes as f64 / 1024.0)
+    }
+}
+
+pub fn format_db_stats(stats: &papers_db::DbStats) -> String {
+    let mut out = format!(
+        "{}
+
+  Papers:    {}
+  Chunks:    {}
+  Exhibits:  {}
+  Abstracts: {}
+  Size:      {}
+  Model:     {} ({}-d)
+",
+        stats.path,
+        stats.papers,
+        stats.chunks,
+        stats.exhibits,
+        stats.abstracts,
+        format_size(stats.size_bytes),
+        stats.embed_model,
+        stats.embed_dim
+    );
+    if stats.needs_migration {
+        out.push_str(&format!(
+            "  Index:     {}-d (stale; run `papers db migrate`)\n",
+            stats.index_dim
+        ));
+    }
+    out
+}
+
+pub fn format_migrate_stats(stats: &papers_db::MigrateStats) -> String {
+    format!(
+        "Migrated {} ({}-d -> {}-d)
+
+  Chunks:    {}
+  Exhibits:  {}
+  Abstracts: {}
+  Backup:    {} (delete once satisfied)
+",
+        stats.path,
+        stats.from_dim,
+        stats.to_dim,
+        stats.chunks,
+        stats.exhibits,
+        stats.abstracts,
+        stats.backup_path
+    )
+}
+
+pub fn format_cache_stats(stats: &papers_core::CacheStats) -> String {
+    let limit = stats.max_bytes.map_or("unlimited".to_string(), format_size);
+    let mut out = format!(
+        "{}
+
+  Entries: {} ({} expired)
+  Size:    {} of {limit}
+
+",
+        stats.dir.display(),
+        stats.entries,
+        stats.expired,
+        format_size(stats.bytes)
+    );
+    for row in &stats.by_entity {
+        out.push_str(&format!(
+            "  {:<13} TTL {:>4}  {:>6} entries  {:>10}\n",
+            row.entity,
+            format_ttl(row.ttl_secs),
+            row.entries,
+            format_size(row.bytes)
+        ));
+    }
+    out
+}
+
+// ── Metadata quality ──────────────────────────────────────────────────────
+
+pub fn format_work_quality(report: &WorkQualityReport) -> String {
+    let title = report.title.as_deref().unwrap_or("(untitled)");
+    let mut out = format!("{title}\n{}\n", report.id);
+    if report.issues.is_empty() {
+        out.push_str("\nNo issues found.\n");
+        return out;
+    }
+    for issue in &report.issues {
+        let tag = match issue.severity {
+            QualitySeverity::Warning => "WARN",
+            QualitySeverity::Info => "INFO",
+        };
+        out.push_str(&format!("\n [{tag}] {}\n", issue.message));
+        out.push_str(&format!("        → {}\n", issue.suggestion));
+    }
+    out
+}
+
+pub fn format_metadata_diff(diff: &papers_core::metadata_diff::MetadataDiff) -> String {
+    use papers_core::metadata_diff::{DiffField, DiffStatus};
+    let short = diff.work_id.strip_prefix("https://openalex.org/").unwrap_or(&diff.work_id);
+    let mut out = format!(
+        "Zotero {} ({}, version {}) vs OpenAlex {short}\n\n",
+        diff.zotero_key, diff.item_type, diff.zotero_version
+    );
+    for f in &diff.fields {
+        let name = match f.field {
+            DiffField::Title => "Title",
+            DiffField::Year => "Year",
+            DiffField::Doi => "DOI",
+            DiffField::Venue => "Venue",
+            DiffField::Authors => "Authors",
+        };
+        let status = match f.status {
+            DiffStatus::Same => "same",
+            DiffStatus::Differs => "differs",
+            DiffStatus::MissingInZotero => "missing in Zotero",
+            DiffStatus::MissingInOpenalex => "missing in OpenAlex",
+        };
+        out.push_str(&format!("  {name:<8} {status}\n"));
+        if f.status != DiffStatus::Same {
+            out.push_str(&format!("           Zotero:   {}\n", f.zotero.as_deref().unwrap_or("—")));
+            out.push_str(&format!("           OpenAlex: {}\n", f.openalex.as_deref().unwrap_or("—")));
+        }
+    }
+    match (&diff.patch, diff.applied) {
+        (None, _) => out.push_str("\nNothing to fix.\n"),
+        (Some(_), true) => out.push_str(&format!("\nUpdated {} from OpenAlex.\n", diff.zotero_key)),
+        (Some(patch), false) => {
+            let fields: Vec<&str> = patch.as_object().into_iter().flat_map(|o| o.keys()).map(String::as_str).collect();
+            out.push_str(&format!("\nWould update: {} (pass --apply to write)\n", fields.join(", ")));
+        }
+    }
+    out
+}
+
+// ── Citation graph ────────────────────────────────────────────────────────
+
+pub fn format_citation_graph(resp: &CitationGraphResponse) -> String {
+    let label = match resp.direction {
+        CitationDirection::Citations => "citing",
+        CitationDirection::References => "referenced by",
+    };
+    let mut out = format!(
+        "{} works {label} {} · depth {}{}\n",
+        resp.count,
+        resp.root,
+        resp.depth,
+        if resp.truncated { " (truncated)" } else { "" }
+    );
+    for (i, node) in resp.works.iter().enumerate() {
+        let w = &node.work;
+        let title = w.title.as_deref().unwrap_or("(untitled)");
+        let year = w
+            .publication_year
+            .map_or(String::new(), |y| format!(" ({y})"));
+        out.push_str(&format!("\n {:>2}  {}{}\n", i + 1, title, year));
+        let short = w.id.strip_prefix("https://openalex.org/").unwrap_or(&w.id);
+        let mut meta_parts = vec![
+            short.to_string(),
+            format!("depth {} via {}", node.depth, node.via),
+        ];
+        if let Some(c) = w.cited_by_count {
+            meta_parts.push(format!("{c} citations"));
+        }
+        out.push_str(&format!("     {}\n", meta_parts.join(" · ")));
+        if !w.authors.is_empty() {
+            out.push_str(&format!("     {}\n", w.authors.join(" · ")));
+        }
+    }
+    out
+}
+
 // ── Find works ────────────────────────────────────────────────────────────
 
 pub fn format_find_works(resp: &FindWorksResponse) -> String {
@@ -1012,6 +1477,26 @@ pub fn format_zotero_attachment_list(resp: &PagedResponse<Item>) -> String {
     out
 }
 
+pub fn format_zotero_fulltext_search(result: &papers_core::zotero_fulltext::FulltextSearchResult) -> String {
+    let mut out = String::new();
+    if let Some(sync) = &result.sync {
+        out.push_str(&format!(
+            "Synced to library version {}: {} updated, {} removed\n",
+            sync.library_version, sync.updated, sync.removed
+        ));
+    }
+    out.push_str(&format!("{} of {} PDFs match \"{}\"\n", result.results.len(), result.indexed, result.query));
+    for (i, hit) in result.results.iter().enumerate() {
+        let title = hit.title.as_deref().unwrap_or("(untitled)");
+        out.push_str(&format!("\n {:>2}  [{}] {}\n", i + 1, hit.item_key, title));
+        out.push_str(&format!("     attachment: {} · {} matches\n", hit.attachment_key, hit.matches));
+        for snippet in &hit.snippets {
+            out.push_str(&format!("     > {snippet}\n"));
+        }
+    }
+    out
+}
+
 pub fn format_zotero_annotation_list(resp: &PagedResponse<Item>) -> String {
     if resp.items.is_empty() {
         return "No annotations.\n".to_string();
@@ -1231,6 +1716,16 @@ pub fn format_zotero_search_get(search: &SavedSearch) -> String {
     out
 }
 
+/// Format `(id, display name)` rows from the Zotero schema endpoints.
+pub fn format_zotero_schema_list(noun: &str, rows: &[(&str, &str)]) -> String {
+    let width = rows.iter().map(|(id, _)| id.len()).max().unwrap_or(0);
+    let mut out = format!("{} {noun}(s)\n\n", rows.len());
+    for (id, localized) in rows {
+        out.push_str(&format!("  {id:<width$}  {localized}\n"));
+    }
+    out
+}
+
 pub fn format_zotero_group_list(resp: &PagedResponse<Group>) -> String {
     let header = match resp.total_results {
         Some(n) if n > 0 => format!("Found {} results · showing {}\n", n, resp.items.len()),
@@ -1385,6 +1880,58 @@ pub struct SelectionListItem {
     pub name: String,
     pub item_count: usize,
     pub is_active: bool,
+    pub is_db_scope: bool,
+}
+
+pub fn format_sync_report(report: &papers_db::sync::SyncReport, library_version: u64) -> String {
+    let mut out = String::new();
+    for key in &report.indexed {
+        out.push_str(&format!("  [indexed] {key}\n"));
+    }
+    for key in &report.removed {
+        out.push_str(&format!("  [removed] {key}\n"));
+    }
+    for (key, err) in &report.failed {
+        out.push_str(&format!("  [fail] {key}: {err} (will retry)\n"));
+    }
+    out.push_str(&format!(
+        "Synced to library version {library_version}: {} indexed, {} removed, {} already indexed, {} failed\n",
+        report.indexed.len(),
+        report.removed.len(),
+        report.skipped.len(),
+        report.failed.len(),
+    ));
+    out
+}
+
+pub fn format_notify_report(report: &papers_core::notify::NotifyReport) -> String {
+    let mut out = String::new();
+    for delivery in &report.deliveries {
+        match &delivery.error {
+            Some(err) => out.push_str(&format!("  [fail] {}: {err}\n", delivery.url)),
+            None => out.push_str(&format!("  [sent] {}\n", delivery.url)),
+        }
+    }
+    out.push_str(&format!(
+        "Notified {} new work{} ({} already notified)\n",
+        report.notified.len(),
+        if report.notified.len() == 1 { "" } else { "s" },
+        report.already_notified,
+    ));
+    out
+}
+
+pub fn format_demo_summary(db_path: &str, papers: usize, chunks: usize, exhibits: usize) -> String {
+    format!(
+        "Indexed {papers} synthetic paper{} ({chunks} chunks, {exhibits} exhibits) into {db_path}\n\n\
+         Point the db commands at the demo index, then try:\n\
+         \x20 export PAPERS_DB_PATH={db_path}\n\
+         \x20 papers db work list\n\
+         \x20 papers db chunk search \"variance reduction in path tracing\"\n\
+         \x20 papers db work outline SYN00000\n\
+         \x20 papers db exhibit search \"sampling error\"\n",
+        if papers == 1 { "" } else { "s" }
+    )
 }
 
 pub fn format_selection_list(items: &[SelectionListItem]) -> String {
@@ -1395,12 +1942,13 @@ pub fn format_selection_list(items: &[SelectionListItem]) -> String {
     for (i, item) in items.iter().enumerate() {
         let active = if item.is_active { "* " } else { "  " };
         out.push_str(&format!(
-            "{}{:>2}  {} ({} item{})\n",
+            "{}{:>2}  {} ({} item{}){}\n",
             active,
             i + 1,
             item.name,
             item.item_count,
-            if item.item_count == 1 { "" } else { "s" }
+            if item.item_count == 1 { "" } else { "s" },
+            if item.is_db_scope { " [db scope]" } else { "" }
         ));
     }
     out
@@ -1427,10 +1975,117 @@ pub fn format_selection_add(
     format!("Added {title:?} to selection {selection_name:?}\n")
 }
 
+pub fn format_selection_add_query(summary: &papers_core::selection::BulkAddSummary) -> String {
+    let mut out = format!(
+        "Added {} of {} fetched works ({} matched) to selection {:?}\n",
+        summary.added.len(),
+        summary.fetched,
+        summary.matched,
+        summary.selection,
+    );
+    for label in &summary.added {
+        out.push_str(&format!("  + {label}\n"));
+    }
+    if !summary.duplicates.is_empty() {
+        out.push_str(&format!("Skipped {} already in the selection\n", summary.duplicates.len()));
+    }
+    out
+}
+
+pub fn format_selection_add_references(result: &papers_core::api::ReferenceAddSummary) -> String {
+    let summary = &result.summary;
+    let mut out = format!(
+        "Added {} of {} works cited by {} ({} matched filters) to selection {:?}\n",
+        summary.added.len(),
+        result.references,
+        result.root,
+        summary.matched,
+        summary.selection,
+    );
+    for label in &summary.added {
+        out.push_str(&format!("  + {label}\n"));
+    }
+    if !summary.duplicates.is_empty() {
+        out.push_str(&format!("Skipped {} already in the selection\n", summary.duplicates.len()));
+    }
+    out
+}
+
+pub fn format_selection_add_citers(result: &papers_core::api::CiterAddSummary) -> String {
+    let summary = &result.summary;
+    let mut out = format!(
+        "Added {} of {} fetched works citing {} ({} matched) to selection {:?}\n",
+        summary.added.len(),
+        summary.fetched,
+        result.root,
+        summary.matched,
+        summary.selection,
+    );
+    for label in &summary.added {
+        out.push_str(&format!("  + {label}\n"));
+    }
+    if !summary.duplicates.is_empty() {
+        out.push_str(&format!("Skipped {} already in the selection\n", summary.duplicates.len()));
+    }
+    out
+}
+
+pub fn format_selection_add_bulk(summary: &papers_core::selection::BulkAddSummary) -> String {
+    let mut out = format!(
+        "Added {} of {} papers to selection {:?}\n",
+        summary.added.len(),
+        summary.matched,
+        summary.selection,
+    );
+    for label in &summary.added {
+        out.push_str(&format!("  + {label}\n"));
+    }
+    if !summary.duplicates.is_empty() {
+        out.push_str(&format!("Skipped {} already in the selection\n", summary.duplicates.len()));
+    }
+    if !summary.unresolved.is_empty() {
+        out.push_str(&format!("Could not resolve {}:\n", summary.unresolved.len()));
+        for input in &summary.unresolved {
+            out.push_str(&format!("  ? {input}\n"));
+        }
+    }
+    out
+}
+
 pub fn format_selection_remove(title: &str, selection_name: &str) -> String {
     format!("Removed {title:?} from selection {selection_name:?}\n")
 }
 
+pub fn format_selection_note(
+    entry: &papers_core::selection::SelectionEntry,
+    selection_name: &str,
+) -> String {
+    let title = entry.title.as_deref().unwrap_or("(unknown)");
+    match entry.note.as_deref() {
+        Some(note) => format!("Noted {title:?} in selection {selection_name:?}: {note}\n"),
+        None => format!("Cleared note on {title:?} in selection {selection_name:?}\n"),
+    }
+}
+
+pub fn format_selection_tags(
+    entry: &papers_core::selection::SelectionEntry,
+    selection_name: &str,
+) -> String {
+    let title = entry.title.as_deref().unwrap_or("(unknown)");
+    if entry.tags.is_empty() {
+        format!("{title:?} in selection {selection_name:?} has no tags\n")
+    } else {
+        format!("Tagged {title:?} in selection {selection_name:?}: {}\n", entry.tags.join(", "))
+    }
+}
+
+pub fn format_selection_scope(scope: Option<&str>) -> String {
+    match scope {
+        Some(name) => format!("MCP DB tools now default to selection {name:?}\n"),
+        None => "Cleared the default DB scope\n".to_string(),
+    }
+}
+
 pub fn format_selection_set(sel_name: &str, total: usize, in_db: usize, has_pdf: usize) -> String {
     format!(
         "Selection set to {sel_name:?}: {total} work{}, {in_db} in DB, {has_pdf} has_pdf\n",
@@ -1618,6 +2273,55 @@ pub fn format_selection_collection_add(
     out
 }
 
+pub fn format_paper_status(paper_id: &str, record: &papers_core::reading_status::PaperStatus) -> String {
+    let mut out = format!("{paper_id}: {}", record.status.as_str());
+    if let Some(rating) = record.rating {
+        out.push_str(&format!(", rated {rating}/5"));
+    }
+    if let Some(chunk) = &record.last_chunk_id {
+        out.push_str(&format!(", last read {chunk}"));
+    }
+    out.push('\n');
+    out
+}
+
+pub fn format_selection_collection_sync(report: &papers_core::selection_zotero::CollectionSyncReport) -> String {
+    let created = if report.created_collection { " (created)" } else { "" };
+    let mut out = format!(
+        "Synced selection {:?} to collection {:?} [{}]{created}\n",
+        report.selection, report.collection_name, report.collection_key,
+    );
+    for label in &report.created_items {
+        out.push_str(&format!("  + {label} (new item)\n"));
+    }
+    for label in &report.added {
+        out.push_str(&format!("  + {label}\n"));
+    }
+    for label in &report.linked {
+        out.push_str(&format!("  = {label} (linked to existing item)\n"));
+    }
+    if report.already_in_collection > 0 {
+        out.push_str(&format!("{} already in the collection\n", report.already_in_collection));
+    }
+    if !report.failed.is_empty() {
+        out.push_str(&format!("Failed {}:\n", report.failed.len()));
+        for f in &report.failed {
+            out.push_str(&format!("  ! {}: {}\n", f.entry, f.error));
+        }
+    }
+    out
+}
+
+pub fn format_selection_collection_import(import: &papers_core::selection_zotero::CollectionImport) -> String {
+    let verb = if import.created_selection { "Created" } else { "Updated" };
+    let mut out = format!(
+        "{verb} selection {:?} from collection {:?} [{}]\n",
+        import.summary.selection, import.collection_name, import.collection_key,
+    );
+    out.push_str(&format_selection_add_bulk(&import.summary));
+    out
+}
+
 pub fn format_selection_merge(
     target: &str,
     source: &str,
@@ -1639,3 +2343,319 @@ pub fn format_selection_merge(
 pub fn format_selection_rename(old_name: &str, new_name: &str) -> String {
     format!("Renamed {old_name:?} → {new_name:?}\n")
 }
+
+pub fn format_selection_screen_decide(
+    title: &str,
+    record: &papers_core::screening::ScreeningRecord,
+) -> String {
+    let mut out = format!(
+        "{} {title:?} at {} screening",
+        match record.decision {
+            papers_core::screening::ScreeningDecision::Include => "Included",
+            papers_core::screening::ScreeningDecision::Exclude => "Excluded",
+            papers_core::screening::ScreeningDecision::Maybe => "Marked maybe",
+        },
+        record.stage.as_str(),
+    );
+    if let Some(reason) = &record.reason {
+        out.push_str(&format!(" ({reason})"));
+    }
+    out.push('\n');
+    out
+}
+
+pub fn format_selection_screen_status(
+    sel_name: &str,
+    progress: &papers_core::screening::ScreeningProgress,
+    statuses: &[papers_core::screening::ScreeningEntryStatus],
+    pending_only: bool,
+) -> String {
+    let mut out = format!(
+        "Screening {sel_name:?} — {}: {}/{} screened ({} included, {} excluded, {} maybe)\n",
+        progress.stage.as_str(),
+        progress.eligible - progress.unscreened,
+        progress.eligible,
+        progress.included,
+        progress.excluded,
+        progress.maybe,
+    );
+    for s in statuses.iter().filter(|s| s.is_eligible(progress.stage)) {
+        let record = s.record(progress.stage);
+        if pending_only && record.is_some() {
+            continue;
+        }
+        let title = s.entry.title.as_deref().unwrap_or("(unknown)");
+        let year = s.entry.year.map_or(String::new(), |y| format!(" ({y})"));
+        let decision = record.map_or("pending", |r| r.decision.as_str());
+        out.push_str(&format!("  {:>2}  [{:<7}]  {}{}\n", s.index, decision, title, year));
+        if let Some(reason) = record.and_then(|r| r.reason.as_deref()) {
+            out.push_str(&format!("                   {reason}\n"));
+        }
+    }
+    out
+}
+
+pub fn format_selection_screen_conflicts(conflicts: &[papers_core::screening::ScreeningConflict]) -> String {
+    if conflicts.is_empty() {
+        return "No reviewer conflicts.\n".to_string();
+    }
+    let mut out = String::new();
+    for c in conflicts {
+        let title = c.title.as_deref().unwrap_or("(unknown)");
+        let decisions: Vec<String> = c
+            .decisions
+            .iter()
+            .map(|(reviewer, d)| format!("{reviewer}: {}", d.as_str()))
+            .collect();
+        out.push_str(&format!("  {}  {}\n     {}\n", c.key, title, decisions.join(" · ")));
+    }
+    out.push_str(&format!(
+        "\n{} conflict{}. Settle with: papers selection screen decide <key> <decision> --adjudicate\n",
+        conflicts.len(),
+        if conflicts.len() == 1 { "" } else { "s" }
+    ));
+    out
+}
+
+pub fn format_selection_screen_import(
+    sel_name: &str,
+    report: &papers_core::screening::ScreeningImportReport,
+) -> String {
+    let mut out = format!(
+        "Imported {} decision{} into {sel_name:?}",
+        report.imported,
+        if report.imported == 1 { "" } else { "s" }
+    );
+    if report.blank > 0 {
+        out.push_str(&format!(" ({} blank row{} skipped)", report.blank, if report.blank == 1 { "" } else { "s" }));
+    }
+    out.push('\n');
+    if !report.unknown_keys.is_empty() {
+        out.push_str(&format!("  Unknown keys: {}\n", report.unknown_keys.join(", ")));
+    }
+    if !report.ineligible_keys.is_empty() {
+        out.push_str(&format!("  Not eligible for stage: {}\n", report.ineligible_keys.join(", ")));
+    }
+    if !report.conflicts.is_empty() {
+        out.push('\n');
+        out.push_str(&format_selection_screen_conflicts(&report.conflicts));
+    }
+    out
+}
+
+pub fn format_selection_prisma(flow: &papers_core::screening::PrismaFlow) -> String {
+    let ta = &flow.title_abstract;
+    let ft = &flow.full_text;
+    let mut out = format!("PRISMA flow for {:?}\n\n", flow.selection);
+    out.push_str(&format!("  Records identified:             {}\n", flow.identified));
+    out.push_str(&format!("  Records screened:               {}\n", ta.eligible - ta.unscreened));
+    out.push_str(&format!("    excluded:                     {}\n", ta.excluded));
+    if ta.maybe > 0 || ta.unscreened > 0 {
+        out.push_str(&format!("    unresolved (maybe/pending):   {}\n", ta.maybe + ta.unscreened));
+    }
+    out.push_str(&format!("  Reports assessed (full text):   {}\n", ft.eligible - ft.unscreened));
+    out.push_str(&format!("    excluded:                     {}\n", ft.excluded));
+    for (reason, count) in &flow.full_text_exclusion_reasons {
+        out.push_str(&format!("      {reason}: {count}\n"));
+    }
+    if ft.maybe > 0 || ft.unscreened > 0 {
+        out.push_str(&format!("    unresolved (maybe/pending):   {}\n", ft.maybe + ft.unscreened));
+    }
+    out.push_str(&format!("  Studies included:               {}\n", flow.included));
+    out
+}
+
+pub fn format_latex_cite(fill: &papers_core::latex_cite::CiteFill, appended: bool) -> String {
+    use papers_core::latex_cite::CiteKind;
+    let mut out = format!("{} citations: {} resolved, {} unresolved\n\n", fill.citations.len(), fill.resolved, fill.unresolved);
+    for c in &fill.citations {
+        let kind = match c.kind {
+            CiteKind::Placeholder => "placeholder",
+            CiteKind::Key => "key",
+            CiteKind::Mention => "mention",
+        };
+        let target = match (&c.key, c.candidates.is_empty()) {
+            (Some(key), _) => format!("\u{2192} {key}  {}", c.title.as_deref().unwrap_or("")),
+            (None, false) => format!("ambiguous: {}", c.candidates.join(", ")),
+            (None, true) => "not found".to_string(),
+        };
+        out.push_str(&format!("  line {:<4} {kind:<11} {:<28} {}\n", c.line, c.text, target.trim_end()));
+    }
+    if !fill.missing_keys.is_empty() {
+        let verb = if appended { "Added to" } else { "Missing from" };
+        out.push_str(&format!("\n{verb} the bibliography ({}): {}\n", fill.missing_keys.len(), fill.missing_keys.join(", ")));
+        if !appended {
+            out.push_str(&format!("\n{}", fill.missing_bibtex));
+        }
+    }
+    out
+}
+
+pub fn format_selection_sdg_report(report: &papers_core::sdg::SdgReport) -> String {
+    let mut out = format!(
+        "SDG coverage for {:?}: {} of {} works tagged\n\n",
+        report.selection, report.tagged, report.resolved
+    );
+    if report.goals.is_empty() {
+        out.push_str("  No Sustainable Development Goal tags.\n");
+    }
+    for goal in &report.goals {
+        out.push_str(&format!(
+            "  SDG {:>2}  {:<40} {:>4} works  {:>5.1}%  mean score {:.2}\n",
+            goal.number,
+            goal.name,
+            goal.works,
+            goal.share * 100.0,
+            goal.mean_score
+        ));
+    }
+    if !report.unresolved.is_empty() {
+        out.push_str(&format!("\n  Not found in OpenAlex ({}):\n", report.unresolved.len()));
+        for label in &report.unresolved {
+            out.push_str(&format!("    - {label}\n"));
+        }
+    }
+    out
+}
+
+pub fn format_timeline(timeline: &papers_core::timeline::Timeline) -> String {
+    let span = match (timeline.first_year, timeline.last_year) {
+        (Some(first), Some(last)) => format!(", {first}\u{2013}{last}"),
+        _ => String::new(),
+    };
+    let mut out = format!("Timeline for {:?}: {} papers{span}\n", timeline.scope, timeline.total);
+    let paper_line = |p: &papers_core::timeline::TimelinePaper| {
+        let marker = if p.milestone { "\u{2605}" } else { " " };
+        let cited = p.cited_by_count.map(|c| format!("  [{c} cited]")).unwrap_or_default();
+        format!(
+            "  {marker} {}{cited}  |  {}\n",
+            p.title.as_deref().unwrap_or("(untitled)"),
+            p.id
+        )
+    };
+    for year in &timeline.years {
+        out.push_str(&format!("\n{} ({})\n", year.year, year.count));
+        for paper in &year.papers {
+            out.push_str(&paper_line(paper));
+        }
+    }
+    if !timeline.undated.is_empty() {
+        out.push_str(&format!("\nUndated ({})\n", timeline.undated.len()));
+        for paper in &timeline.undated {
+            out.push_str(&paper_line(paper));
+        }
+    }
+    if !timeline.unresolved.is_empty() {
+        out.push_str(&format!("\n  Not found in OpenAlex ({}):\n", timeline.unresolved.len()));
+        for label in &timeline.unresolved {
+            out.push_str(&format!("    - {label}\n"));
+        }
+    }
+    out
+}
+
+pub fn format_identifier_candidates(candidates: &[papers_core::identifiers::IdentifierCandidate]) -> String {
+    if candidates.is_empty() {
+        return "No identifiers found.\n".to_string();
+    }
+    let mut out = String::new();
+    for c in candidates {
+        let kind = serde_json::to_value(c.kind).ok().and_then(|v| v.as_str().map(str::to_string)).unwrap_or_default();
+        out.push_str(&format!("[{:.2}] {kind:<8} {}\n", c.confidence, c.value));
+        if c.input != c.value {
+            out.push_str(&format!("       add as: {}\n", c.input));
+        }
+    }
+    out
+}
+
+pub fn format_work_citation(cited: &papers_core::cite::FormattedCitation) -> String {
+    format!("{}\n\nIn text: {}\n", cited.citation, cited.in_text)
+}
+
+pub fn format_review_context(context: &papers_core::review::ReviewContext) -> String {
+    let mut out = format!(
+        "Related work for \"{}\" ({} candidates)\n",
+        context.search_terms, context.candidates
+    );
+    let work_line = |w: &WorkSummary| {
+        let year = w.publication_year.map_or(String::new(), |y| format!(" ({y})"));
+        let cited = w.cited_by_count.map_or(String::new(), |c| format!(" · {c} citations"));
+        format!("{}{year}{cited} [{}]", w.title.as_deref().unwrap_or("(untitled)"), w.id)
+    };
+    if !context.clusters.is_empty() {
+        out.push_str("\nTopics:\n");
+        for c in &context.clusters {
+            let years = match (c.first_year, c.last_year) {
+                (Some(a), Some(b)) if a != b => format!(", {a}-{b}"),
+                (Some(a), _) => format!(", {a}"),
+                _ => String::new(),
+            };
+            out.push_str(&format!("  {} ({} works{years})\n", c.topic, c.count));
+        }
+    }
+    let recent_heading = format!("Recent closest (since {})", context.recent_since);
+    for (heading, works) in [("Key prior works", &context.key_prior_works), (&*recent_heading, &context.recent_closest)] {
+        out.push_str(&format!("\n{heading}:\n"));
+        if works.is_empty() {
+            out.push_str("  (none)\n");
+        }
+        for (i, w) in works.iter().enumerate() {
+            let cited = if w.in_references == Some(true) { " · cited" } else { "" };
+            out.push_str(&format!("  {:>2}. {}{cited}\n", i + 1, work_line(&w.work)));
+        }
+    }
+    if !context.possible_missed_citations.is_empty() {
+        out.push_str("\nPossible missed citations:\n");
+        for (i, m) in context.possible_missed_citations.iter().enumerate() {
+            out.push_str(&format!(
+                "  {:>2}. {} · cited by {} related works\n",
+                i + 1,
+                work_line(&m.work),
+                m.cited_by_related
+            ));
+        }
+    }
+    for warning in &context.warnings {
+        out.push_str(&format!("\nwarning: {warning}\n"));
+    }
+    out
+}
+
+pub fn format_extracted_references(result: &papers_core::references::ExtractedReferences) -> String {
+    let title = result.title.as_deref().unwrap_or(&result.work_id);
+    let mut out = format!(
+        "{} references in {title} ({} matched in OpenAlex)\n",
+        result.count, result.resolved
+    );
+    if result.references.is_empty() {
+        out.push_str("  No references section found in the extracted text.\n");
+    }
+    for (i, r) in result.references.iter().enumerate() {
+        let p = &r.parsed;
+        let label = p.number.map_or_else(|| (i + 1).to_string(), |n| n.to_string());
+        let year = p.year.map_or(String::new(), |y| format!(" ({y})"));
+        let title = p.title.as_deref().unwrap_or(&p.raw);
+        out.push_str(&format!("\n[{label}] {title}{year}\n"));
+        if !p.authors.is_empty() {
+            out.push_str(&format!("     {}\n", p.authors.join(" · ")));
+        }
+        let mut meta = Vec::new();
+        if let Some(doi) = &p.doi {
+            meta.push(doi.clone());
+        }
+        if let (Some(id), Some(by)) = (&r.openalex_id, &r.resolved_by) {
+            meta.push(format!("{id} (by {by})"));
+        }
+        if r.in_rag_index {
+            meta.push("in DB".to_string());
+        }
+        if !r.selections.is_empty() {
+            meta.push(format!("in {}", r.selections.join(", ")));
+        }
+        if !meta.is_empty() {
+            out.push_str(&format!("     {}\n", meta.join(" · ")));
+        }
+    }
+    out
+}
@@ -3,57 +3,77 @@ mod format;
 
 use clap::Parser;
 use cli::{
-    AuthorCommand, AuthorFilterArgs, Cli, ConfigCommand, ConfigSetCommand,
+    AuditCommand, AuthorCommand, AuthorFilterArgs, CacheCommand, Cli, ConfigCommand, ConfigSetCommand,
     DomainCommand, DomainFilterArgs, EntityCommand, FieldCommand, FieldFilterArgs, FunderCommand,
     FunderFilterArgs, InstitutionCommand, InstitutionFilterArgs, McpCommand, PublisherCommand,
     PublisherFilterArgs, DbChunkCommand, DbCommand,
-    DbExhibitCommand, DbSectionCommand, DbTagCommand, DbWorkCommand, SelectionCommand,
-    SelectionCollectionCommand, SelectionDbCommand,
+    DbAbstractCommand, DbCitationCommand, DbEquationCommand, DbExhibitCommand, DbSectionCommand, DbTagCommand, DbVaultCommand, DbWorkCommand,
+    SelectionCommand,
+    SelectionCollectionCommand, SelectionDbCommand, SelectionScreenCommand, SnapshotCommand,
     SourceCommand,
     SourceFilterArgs, SubfieldCommand, SubfieldFilterArgs, TopicCommand, TopicFilterArgs,
     WorkCommand, WorkFilterArgs, ZoteroAnnotationCommand, ZoteroAttachmentCommand,
     ZoteroCollectionCommand, ZoteroCommand, ZoteroDeletedCommand,
-    ZoteroGroupCommand, ZoteroNoteCommand, ZoteroPermissionCommand, ZoteroSearchCommand,
+    ZoteroGroupCommand, ZoteroItemTypeCommand, ZoteroNoteCommand, ZoteroPermissionCommand, ZoteroProfileCommand,
+    ZoteroSearchCommand,
     ZoteroSettingCommand, ZoteroTagCommand, ZoteroWorkCommand,
 };
-use papers_core::zotero::{resolve_collection_key, resolve_item_key, resolve_search_key};
+use papers_core::vault::{AnnotationExcerpt, VaultNote};
+use papers_core::zotero::{
+    export_top_items, resolve_collection_key, resolve_item_key, resolve_search_key,
+};
 use papers_core::{
-    AuthorListParams, DiskCache, DomainListParams, FieldListParams, FindWorksParams,
+    AuthorListParams, CachePolicy, DiskCache, DomainListParams, FieldListParams, FindWorksParams,
     FunderListParams, GetParams, InstitutionListParams, OpenAlexClient, PublisherListParams,
     SourceListParams, SubfieldListParams, TopicListParams, WorkListParams, filter::FilterError,
 };
 use papers_zotero::{
     CollectionListParams, DeletedParams, Item, ItemListParams, TagListParams, ZoteroClient,
 };
-use std::time::Duration;
 
+/// The Zotero client for `PAPERS_ZOTERO_PROFILE`, else the `ZOTERO_*` environment variables.
 async fn zotero_client() -> Result<ZoteroClient, papers_zotero::ZoteroError> {
-    ZoteroClient::from_env_prefer_local().await
+    papers_core::zotero_profiles::connect(None).await
 }
 
 /// Returns the Zotero client when available, `Ok(None)` when Zotero is simply
 /// not configured (env vars absent), or `Err` when Zotero is installed but not
 /// running (so the caller can surface the error).
 async fn optional_zotero() -> Result<Option<ZoteroClient>, papers_zotero::ZoteroError> {
-    match ZoteroClient::from_env_prefer_local().await {
+    match zotero_client().await {
         Ok(z) => Ok(Some(z)),
         Err(e @ papers_zotero::ZoteroError::NotRunning { .. }) => Err(e),
         Err(_) => Ok(None),
     }
 }
 
+/// Parse repeated `-q KEY=VALUE` arguments, exiting on malformed pairs.
+fn parse_query_pairs(query: &[String]) -> Vec<(String, String)> {
+    query
+        .iter()
+        .map(|q| match q.split_once('=') {
+            Some((k, v)) => (k.to_string(), v.to_string()),
+            None => exit_err(&format!("Invalid query parameter \"{q}\": expected KEY=VALUE")),
+        })
+        .collect()
+}
+
 fn work_list_params(args: &cli::ListArgs, wf: &WorkFilterArgs) -> WorkListParams {
     WorkListParams {
-        search: None,
-        filter: args.filter.clone(),
         sort: args.sort.clone(),
         per_page: Some(args.per_page),
         page: args.page,
         cursor: args.cursor.clone(),
         sample: args.sample,
         seed: args.seed,
-        select: None,
-        group_by: None,
+        ..work_filter_params(args.filter.clone(), wf)
+    }
+}
+
+/// Filter-only work params (no paging or sorting).
+fn work_filter_params(filter: Option<String>, wf: &WorkFilterArgs) -> WorkListParams {
+    WorkListParams {
+        filter,
         author: wf.author.clone(),
         topic: wf.topic.clone(),
         domain: wf.domain.clone(),
@@ -68,6 +88,11 @@ fn work_list_params(args: &cli::ListArgs, wf: &WorkFilterArgs) -> WorkListParams
         continent: wf.continent.clone(),
         r#type: wf.entity_type.clone(),
         open: if wf.open { Some(true) } else { None },
+        language: wf.language.clone(),
+        exclude_retracted: if wf.exclude_retracted { Some(true) } else { None },
+        exclude_paratext: if wf.exclude_paratext { Some(true) } else { None },
+        sdg: wf.sdg.clone(),
+        ..Default::default()
     }
 }
 
@@ -296,8 +321,30 @@ async fn run_extraction_for_key(
         return Ok(());
     }
 
-    // Find the PDF attachment in Zotero
-    let att = find_pdf_attachment(zotero, key).await?;
+    // Find the PDF attachment in Zotero; books and web snapshots without one
+    // are converted from their EPUB or HTML attachment instead, and PubMed
+    // records from their PMC open-access XML.
+    let att = match find_pdf_attachment(zotero, key).await {
+        Ok(att) => att,
+        Err(no_pdf) => {
+            if let Some(att) = find_text_attachment(zotero, key).await {
+                cache_text_attachment(zotero, key, &att).await?;
+            } else {
+                let pmcid = zotero
+                    .get_item(key)
+                    .await
+                    .ok()
+                    .and_then(|item| papers_core::text::zotero_item_pmcid(&item))
+                    .ok_or(no_pdf)?;
+                let markdown = papers_core::text::pmc_text(&pmcid)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                cache_markdown(key, &markdown)?;
+            }
+            enrich_extraction_meta(zotero, key).await;
+            return Ok(());
+        }
+    };
     let filename = att
         .data
         .filename
@@ -309,9 +356,13 @@ async fn run_extraction_for_key(
         .join(&att.key)
         .join(&filename);
 
-    if !local_path.exists() {
-        return Err(format!("PDF not found: {}", local_path.display()));
-    }
+    // Not synced to this machine: use the copy `work_text` kept when it
+    // downloaded the attachment through the Zotero API, if any.
+    let local_path = if local_path.exists() {
+        local_path
+    } else {
+        papers_core::text::cached_pdf_path(key).ok_or_else(|| format!("PDF not found: {}", local_path.display()))?
+    };
 
     // Run extraction in a blocking task (Pipeline is synchronous / CPU-bound)
     let pdf_path = local_path.clone();
@@ -384,14 +435,74 @@ async fn run_extraction_for_key(
     }).await.map_err(|e| format!("extraction task panicked: {e}"))?;
 
     result?;
+    enrich_extraction_meta(&zotero_clone, key).await;
+    Ok(())
+}
+
+/// First EPUB or HTML snapshot attachment of an item.
+async fn find_text_attachment(zotero: &ZoteroClient, item_key: &str) -> Option<Item> {
+    let att_params = ItemListParams {
+        item_type: Some("attachment".into()),
+        ..Default::default()
+    };
+    let children = zotero.list_item_children(item_key, &att_params).await.ok()?;
+    children.items.into_iter().find(|a| {
+        matches!(a.data.content_type.as_deref(), Some("application/epub+zip" | "text/html"))
+    })
+}
+
+/// Convert an EPUB or HTML attachment to text and write it to the extract
+/// cache (see [`cache_markdown`]).
+async fn cache_text_attachment(
+    zotero: &ZoteroClient,
+    key: &str,
+    att: &Item,
+) -> Result<(), String> {
+    let local_path = att.data.filename.as_ref().and_then(|filename| {
+        Some(dirs::home_dir()?.join("Zotero").join("storage").join(&att.key).join(filename))
+    });
+    let bytes = match local_path.filter(|p| p.exists()) {
+        Some(path) => std::fs::read(&path)
+            .map_err(|e| format!("failed to read {}: {e}", path.display()))?,
+        None => zotero.download_item_file(&att.key).await.map_err(|e| e.to_string())?,
+    };
+    let markdown = papers_core::text::extract_text_bytes(&bytes).map_err(|e| e.to_string())?;
+    cache_markdown(key, &markdown)
+}
+
+/// Write Markdown-style text to the extract cache, with a reflow document
+/// built from its headings.
+fn cache_markdown(key: &str, markdown: &str) -> Result<(), String> {
+    let reflow = papers_db::reflow_from_markdown(markdown);
+    let meta = papers_core::text::ExtractionMeta {
+        item_key: key.to_string(),
+        zotero_user_id: std::env::var("ZOTERO_USER_ID").ok(),
+        title: None,
+        authors: None,
+        item_type: None,
+        date: None,
+        doi: None,
+        url: None,
+        publication_title: None,
+        extracted_at: Some(chrono_free_iso_now()),
+        processing_mode: None,
+        pdf_source: None,
+    };
+    let meta_json = serde_json::to_string_pretty(&meta).map_err(|e| e.to_string())?;
+    let reflow_json = serde_json::to_string_pretty(&reflow).map_err(|e| e.to_string())?;
+    papers_core::extract_cache::write_extract_cache(key, &meta_json, "{}", &reflow_json, markdown)
+        .map_err(|e| format!("failed to write extract cache: {e}"))?;
+    Ok(())
+}
 
-    // Best-effort: enrich meta.json with Zotero metadata
+/// Best-effort: enrich the cached meta.json with Zotero metadata.
+async fn enrich_extraction_meta(zotero: &ZoteroClient, key: &str) {
     if let Some(cache_dir) = papers_core::extract_cache::extract_cache_dir(key) {
         let meta_path = cache_dir.join("meta.json");
         if let Ok(bytes) = std::fs::read(&meta_path) {
             if let Ok(mut meta) = serde_json::from_slice::<papers_core::text::ExtractionMeta>(&bytes) {
                 // Fetch item metadata from Zotero
-                if let Ok(item) = zotero_clone.get_item(key).await {
+                if let Ok(item) = zotero.get_item(key).await {
                     meta.title = item.data.title.clone();
                     meta.authors = Some(
                         item.data.creators.iter()
@@ -416,8 +527,6 @@ async fn run_extraction_for_key(
             }
         }
     }
-
-    Ok(())
 }
 
 /// ISO 8601 UTC timestamp without chrono dependency.
@@ -487,6 +596,7 @@ async fn smart_resolve_item_key(zotero: &ZoteroClient, input: &str) -> Result<St
 /// dispatch function (multiple `HashSet`/`Vec`/`HashMap` locals across many
 /// await points inside a single giant `match`).
 fn main() {
+    load_settings();
     std::thread::Builder::new()
         .stack_size(8 * 1024 * 1024)
         .name("papers-main".into())
@@ -502,10 +612,26 @@ fn main() {
         .expect("main thread panicked");
 }
 
+/// Export `papers.toml` values for environment variables that are not set
+/// (see [`papers_core::settings`]).
+fn load_settings() {
+    let settings = match papers_core::settings::Settings::load() {
+        Ok(s) => s,
+        Err(e) => {
+            let path = papers_core::settings::Settings::path().unwrap_or_default();
+            exit_err(&format!("Failed to load {}: {e}", path.display()))
+        }
+    };
+    for (name, value) in settings.unset_env_vars(|name| std::env::var_os(name).is_some()) {
+        // SAFETY: runs first thing in `main`, before any other thread exists.
+        unsafe { std::env::set_var(name, value) };
+    }
+}
+
 async fn papers_main() {
     let cli = Cli::parse();
     let mut client = OpenAlexClient::new();
-    if let Ok(cache) = DiskCache::default_location(Duration::from_secs(600)) {
+    if let Ok(cache) = DiskCache::default_location_with_policy(CachePolicy::from_env()) {
         client = client.with_cache(cache);
     }
 
@@ -543,9 +669,11 @@ async fn papers_main() {
                     .await
                     .unwrap_or_else(|e| exit_err(&e.to_string()));
                 let zotero_configured = zotero.is_some();
+                let crossref = papers_core::crossref::CrossrefClient::new();
                 match papers_core::api::work_get_response(
                     &client,
                     zotero.as_ref(),
+                    Some(&crossref),
                     &id,
                     &GetParams::default(),
                 )
@@ -576,6 +704,26 @@ async fn papers_main() {
                     Err(e) => exit_err(&e.to_string()),
                 }
             }
+            WorkCommand::Enrich { id, recommendations, json } => {
+                let zotero = optional_zotero()
+                    .await
+                    .unwrap_or_else(|e| exit_err(&e.to_string()));
+                let zotero_configured = zotero.is_some();
+                let crossref = papers_core::crossref::CrossrefClient::new();
+                let s2 = papers_core::semantic_scholar::SemanticScholarClient::new();
+                match papers_core::api::work_enrich(&client, zotero.as_ref(), Some(&crossref), &s2, &id, recommendations)
+                    .await
+                {
+                    Ok(response) => {
+                        if json {
+                            print_json(&response);
+                        } else {
+                            print!("{}", format::format_work_get_response(&response, zotero_configured));
+                        }
+                    }
+                    Err(e) => exit_err(&e.to_string()),
+                }
+            }
             WorkCommand::Autocomplete { query, json } => {
                 match papers_core::api::work_autocomplete(&client, &query).await {
                     Ok(resp) => {
@@ -588,6 +736,163 @@ async fn papers_main() {
                     Err(e) => exit_err(&e.to_string()),
                 }
             }
+            WorkCommand::Stats { by, search, filter, work_filters, top, json } => {
+                let by = match by.parse::<papers_core::stats::StatsDimension>() {
+                    Ok(by) => by,
+                    Err(e) => exit_err(&e.to_string()),
+                };
+                let mut params = work_filter_params(filter, &work_filters);
+                params.search = search;
+                match papers_core::api::work_stats(&client, &params, by, top).await {
+                    Ok(stats) => {
+                        if json {
+                            print_json(&stats);
+                        } else {
+                            print!("{}", format::format_work_stats(&stats));
+                        }
+                    }
+                    Err(e) => exit_err(&e.to_string()),
+                }
+            }
+
+            WorkCommand::Apc { search, filter, work_filters, max_works, json } => {
+                let mut params = work_filter_params(filter, &work_filters);
+                params.search = search;
+                match papers_core::api::work_apc_report(&client, &params, max_works).await {
+                    Ok(report) => {
+                        if json {
+                            print_json(&report);
+                        } else {
+                            print!("{}", format::format_apc_report(&report));
+                        }
+                    }
+                    Err(e) => exit_err(&e.to_string()),
+                }
+            }
+
+            WorkCommand::Quality { id, json } => {
+                match papers_core::api::work_quality(&client, &id).await {
+                    Ok(report) => {
+                        if json {
+                            print_json(&report);
+                        } else {
+                            print!("{}", format::format_work_quality(&report));
+                        }
+                    }
+                    Err(e) => exit_err(&e.to_string()),
+                }
+            }
+
+            WorkCommand::PublishedVersion { id, json } => {
+                match papers_core::api::work_published_version(&client, &id).await {
+                    Ok(report) => {
+                        if json {
+                            print_json(&report);
+                        } else {
+                            print!("{}", format::format_published_version(&report));
+                        }
+                    }
+                    Err(e) => exit_err(&e.to_string()),
+                }
+            }
+
+            WorkCommand::ExtractIds { file, min_confidence, json } => {
+                let text = match file {
+                    Some(path) => std::fs::read_to_string(&path)
+                        .unwrap_or_else(|e| exit_err(&format!("failed to read {}: {e}", path.display()))),
+                    None => std::io::read_to_string(std::io::stdin())
+                        .unwrap_or_else(|e| exit_err(&format!("failed to read stdin: {e}"))),
+                };
+                let mut candidates = papers_core::identifiers::extract_identifiers(&text);
+                candidates.retain(|c| c.confidence >= min_confidence);
+                if json {
+                    print_json(&candidates);
+                } else {
+                    print!("{}", format::format_identifier_candidates(&candidates));
+                }
+            }
+
+            WorkCommand::Cite { id, style, json } => {
+                let style: papers_core::cite::CitationStyle =
+                    style.parse().unwrap_or_else(|e: papers_core::cite::CiteError| exit_err(&e.to_string()));
+                match papers_core::cite::format_citation(&client, &id, style).await {
+                    Ok(cited) if json => print_json(&cited),
+                    Ok(cited) => print!("{}", format::format_work_citation(&cited)),
+                    Err(e) => exit_err(&e.to_string()),
+                }
+            }
+
+            WorkCommand::ReviewContext { file, references, json } => {
+                let text = match file {
+                    Some(path) => std::fs::read_to_string(&path)
+                        .unwrap_or_else(|e| exit_err(&format!("failed to read {}: {e}", path.display()))),
+                    None => std::io::read_to_string(std::io::stdin())
+                        .unwrap_or_else(|e| exit_err(&format!("failed to read stdin: {e}"))),
+                };
+                let context = papers_core::api::review_context(&client, &text, &references).await;
+                if json {
+                    print_json(&context);
+                } else {
+                    print!("{}", format::format_review_context(&context));
+                }
+            }
+
+            WorkCommand::ReferencesExtracted { id, no_resolve, json } => {
+                let zotero = optional_zotero().await.unwrap_or(None);
+                let mut result = papers_core::references::work_references_extracted(
+                    &client,
+                    zotero.as_ref(),
+                    &id,
+                    !no_resolve,
+                )
+                .await
+                .unwrap_or_else(|e| exit_err(&e.to_string()));
+                // Mark references indexed in the local DB (best-effort).
+                if let Ok(store) = papers_db::DbStore::open(&papers_db::DbStore::default_path()).await {
+                    for reference in &mut result.references {
+                        for id in reference.db_ids() {
+                            if papers_db::is_ingested(&store, &id).await {
+                                reference.in_rag_index = true;
+                                break;
+                            }
+                        }
+                    }
+                }
+                if json {
+                    print_json(&result);
+                } else {
+                    print!("{}", format::format_extracted_references(&result));
+                }
+            }
+
+            cmd @ (WorkCommand::Citations { .. } | WorkCommand::References { .. }) => {
+                let (graph, citations) = match cmd {
+                    WorkCommand::Citations { graph } => (graph, true),
+                    WorkCommand::References { graph } => (graph, false),
+                    _ => unreachable!(),
+                };
+                let params = papers_core::api::CitationGraphParams {
+                    depth: graph.depth,
+                    per_work: graph.per_work,
+                    max_works: graph.max_works,
+                };
+                let result = if citations {
+                    papers_core::api::work_citations(&client, &graph.id, &params).await
+                } else {
+                    papers_core::api::work_references(&client, &graph.id, &params).await
+                };
+                match result {
+                    Ok(resp) => {
+                        if graph.json {
+                            print_json(&resp);
+                        } else {
+                            print!("{}", format::format_citation_graph(&resp));
+                        }
+                    }
+                    Err(e) => exit_err(&e.to_string()),
+                }
+            }
+
             WorkCommand::Find {
                 query,
                 count,
@@ -655,6 +960,31 @@ async fn papers_main() {
                     Err(e) => exit_err(&e.to_string()),
                 }
             }
+            AuthorCommand::Profile { id, top_works, top_coauthors, topics, json } => {
+                let params = papers_core::api::AuthorProfileParams { top_works, top_coauthors, topics };
+                match papers_core::api::author_profile(&client, &id, &params).await {
+                    Ok(profile) => {
+                        if json {
+                            print_json(&profile);
+                        } else {
+                            print!("{}", format::format_author_profile(&profile));
+                        }
+                    }
+                    Err(e) => exit_err(&e.to_string()),
+                }
+            }
+            AuthorCommand::Affiliations { id, year, json } => {
+                match papers_core::api::author_affiliation_history(&client, &id, year).await {
+                    Ok(history) => {
+                        if json {
+                            print_json(&history);
+                        } else {
+                            print!("{}", format::format_affiliation_history(&history));
+                        }
+                    }
+                    Err(e) => exit_err(&e.to_string()),
+                }
+            }
             AuthorCommand::Autocomplete { query, json } => {
                 match papers_core::api::author_autocomplete(&client, &query).await {
                     Ok(resp) => {
@@ -1065,7 +1395,7 @@ async fn papers_main() {
             }
         },
 
-        EntityCommand::Zotero { cmd } => {
+        EntityCommand::Zotero { library, cmd } => {
             let zotero = zotero_client().await.unwrap_or_else(|e| match e {
                 papers_zotero::ZoteroError::NotRunning { path } => exit_err(&format!(
                     "Zotero is installed ({path}) but the local API is not enabled.\n\
@@ -1074,6 +1404,9 @@ async fn papers_main() {
                 )),
                 _ => exit_err("Zotero not configured. Set ZOTERO_USER_ID and ZOTERO_API_KEY."),
             });
+            let zotero = papers_core::zotero::scoped_client(zotero, library.as_deref())
+                .await
+                .unwrap_or_else(|e| exit_err(&e.to_string()));
             match cmd {
                 ZoteroCommand::Work { cmd } => match cmd {
                     ZoteroWorkCommand::List {
@@ -1084,6 +1417,8 @@ async fn papers_main() {
                         limit,
                         start,
                         since,
+                        export,
+                        style,
                         json,
                     } => {
                         let params = ItemListParams {
@@ -1094,8 +1429,17 @@ async fn papers_main() {
                             limit: Some(limit),
                             start,
                             since,
+                            style,
                             ..Default::default()
                         };
+                        if let Some(format) = export {
+                            let params = ItemListParams { limit: None, ..params };
+                            match export_top_items(&zotero, None, &params, &format).await {
+                                Ok(text) => println!("{text}"),
+                                Err(e) => exit_err(&e.to_string()),
+                            }
+                            return;
+                        }
                         match zotero.list_top_items(&params).await {
                             Ok(resp) => {
                                 if json {
@@ -1155,6 +1499,77 @@ async fn papers_main() {
                             Err(e) => exit_err(&e.to_string()),
                         }
                     }
+                    ZoteroWorkCommand::Create { id, collections, tags, allow_duplicate, json } => {
+                        match papers_core::zotero::create_item_from_work(
+                            &client,
+                            &zotero,
+                            &id,
+                            &collections,
+                            &tags,
+                            allow_duplicate,
+                        )
+                        .await
+                        {
+                            Ok(item) => {
+                                if json {
+                                    print_json(&item);
+                                } else {
+                                    println!("Created {}", item.key);
+                                    print!("{}", format::format_zotero_item_get(&item));
+                                }
+                            }
+                            Err(e) => exit_err(&e.to_string()),
+                        }
+                    }
+                    ZoteroWorkCommand::Update {
+                        key,
+                        version,
+                        title,
+                        abstract_note,
+                        doi,
+                        date,
+                        url,
+                        extra,
+                        add_tags,
+                        remove_tags,
+                        add_collections,
+                        json,
+                    } => {
+                        let update = papers_core::zotero::ZoteroItemUpdate {
+                            title,
+                            abstract_note,
+                            doi,
+                            date,
+                            url,
+                            extra,
+                            add_tags,
+                            remove_tags,
+                            add_collections,
+                        };
+                        match papers_core::zotero::update_item_fields(&zotero, &key, &update, version).await {
+                            Ok(item) => {
+                                if json {
+                                    print_json(&item);
+                                } else {
+                                    println!("Updated {} (version {})", item.key, item.version);
+                                    print!("{}", format::format_zotero_item_get(&item));
+                                }
+                            }
+                            Err(e) => exit_err(&e.to_string()),
+                        }
+                    }
+                    ZoteroWorkCommand::Diff { id, apply, json } => {
+                        match papers_core::metadata_diff::work_metadata_diff(&client, &zotero, &id, apply).await {
+                            Ok(diff) => {
+                                if json {
+                                    print_json(&diff);
+                                } else {
+                                    print!("{}", format::format_metadata_diff(&diff));
+                                }
+                            }
+                            Err(e) => exit_err(&e.to_string()),
+                        }
+                    }
                     ZoteroWorkCommand::Collections { key, json } => {
                         let key = resolve_item_key(&zotero, &key)
                             .await
@@ -1449,6 +1864,18 @@ async fn papers_main() {
                             Err(e) => exit_err(&e.to_string()),
                         }
                     }
+                    ZoteroAttachmentCommand::FulltextSearch { query, limit, sync, json } => {
+                        match papers_core::zotero_fulltext::search_library(&zotero, &query, limit, sync).await {
+                            Ok(result) => {
+                                if json {
+                                    print_json(&result);
+                                } else {
+                                    print!("{}", format::format_zotero_fulltext_search(&result));
+                                }
+                            }
+                            Err(e) => exit_err(&e.to_string()),
+                        }
+                    }
                 },
 
                 ZoteroCommand::Annotation { cmd } => match cmd {
@@ -1607,6 +2034,8 @@ async fn papers_main() {
                         direction,
                         limit,
                         start,
+                        export,
+                        style,
                         json,
                     } => {
                         let key = resolve_collection_key(&zotero, &key)
@@ -1621,8 +2050,17 @@ async fn papers_main() {
                             direction,
                             limit: Some(limit),
                             start,
+                            style,
                             ..Default::default()
                         };
+                        if let Some(format) = export {
+                            let params = ItemListParams { limit: None, ..params };
+                            match export_top_items(&zotero, Some(&key), &params, &format).await {
+                                Ok(text) => println!("{text}"),
+                                Err(e) => exit_err(&e.to_string()),
+                            }
+                            return;
+                        }
                         match zotero.list_collection_top_items(&key, &params).await {
                             Ok(resp) => {
                                 if json {
@@ -1910,6 +2348,57 @@ async fn papers_main() {
                     },
                 },
 
+                ZoteroCommand::ItemType { cmd } => match cmd {
+                    ZoteroItemTypeCommand::List { locale, json } => {
+                        match zotero.list_item_types(locale.as_deref()).await {
+                            Ok(types) if json => print_json(&types),
+                            Ok(types) => {
+                                let rows: Vec<_> = types
+                                    .iter()
+                                    .map(|t| (t.item_type.as_str(), t.localized.as_str()))
+                                    .collect();
+                                print!("{}", format::format_zotero_schema_list("item type", &rows));
+                            }
+                            Err(e) => exit_err(&e.to_string()),
+                        }
+                    }
+                    ZoteroItemTypeCommand::Fields { item_type, locale, json } => {
+                        match zotero.list_item_type_fields(&item_type, locale.as_deref()).await {
+                            Ok(fields) if json => print_json(&fields),
+                            Ok(fields) => {
+                                let rows: Vec<_> = fields
+                                    .iter()
+                                    .map(|f| (f.field.as_str(), f.localized.as_str()))
+                                    .collect();
+                                print!("{}", format::format_zotero_schema_list("field", &rows));
+                            }
+                            Err(e) => exit_err(&e.to_string()),
+                        }
+                    }
+                    ZoteroItemTypeCommand::CreatorTypes { item_type, locale, json } => {
+                        let result =
+                            zotero.list_item_type_creator_types(&item_type, locale.as_deref()).await;
+                        match result {
+                            Ok(types) if json => print_json(&types),
+                            Ok(types) => {
+                                let rows: Vec<_> = types
+                                    .iter()
+                                    .map(|t| (t.creator_type.as_str(), t.localized.as_str()))
+                                    .collect();
+                                let out = format::format_zotero_schema_list("creator type", &rows);
+                                print!("{out}");
+                            }
+                            Err(e) => exit_err(&e.to_string()),
+                        }
+                    }
+                    ZoteroItemTypeCommand::Template { item_type } => {
+                        match zotero.get_item_template(&item_type).await {
+                            Ok(template) => print_json(&template),
+                            Err(e) => exit_err(&e.to_string()),
+                        }
+                    }
+                },
+
                 ZoteroCommand::Setting { cmd } => match cmd {
                     ZoteroSettingCommand::List { json } => match zotero.get_settings().await {
                         Ok(resp) => {
@@ -1965,6 +2454,24 @@ async fn papers_main() {
                         }
                     }
                 },
+                ZoteroCommand::Raw { path, query, method, body, version } => {
+                    let body = body.map(|b| {
+                        serde_json::from_str(&b)
+                            .unwrap_or_else(|e| exit_err(&format!("Invalid --body JSON: {e}")))
+                    });
+                    let req = papers_core::raw::ZoteroRawRequest {
+                        method: Some(method),
+                        path,
+                        query: parse_query_pairs(&query),
+                        body,
+                        version,
+                    };
+                    let allow_writes = papers_core::raw::zotero_raw_writes_enabled();
+                    match papers_core::raw::zotero_raw(&zotero, &req, allow_writes).await {
+                        Ok(resp) => print_json(&resp),
+                        Err(e) => exit_err(&e.to_string()),
+                    }
+                }
             }
         }
 
@@ -1972,7 +2479,57 @@ async fn papers_main() {
             handle_selection_command(cmd, &client).await;
         }
         EntityCommand::Db { cmd } => {
-            handle_db_command(cmd).await;
+            handle_db_command(cmd, &client).await;
+        }
+        EntityCommand::Demo { papers, dir, seed, json } => {
+            let dir = dir.unwrap_or_else(papers_db::fixtures::demo_dir);
+            let defaults = papers_db::fixtures::CorpusSpec::default();
+            let spec = papers_db::fixtures::CorpusSpec {
+                papers,
+                seed: seed.unwrap_or(defaults.seed),
+                ..defaults
+            };
+            // Only clear what a previous demo wrote, so a mistyped --dir can't
+            // take anything else with it.
+            let cache_dir = dir.join("cache");
+            let db_path = dir.join("rag");
+            for sub in [&cache_dir, &db_path] {
+                if sub.exists() && let Err(e) = std::fs::remove_dir_all(sub) {
+                    exit_err(&format!("Failed to clear {}: {e}", sub.display()));
+                }
+            }
+            let corpus = papers_db::fixtures::write_corpus(&cache_dir, &spec)
+                .unwrap_or_else(|e| exit_err(&format!("Failed to write synthetic corpus: {e}")));
+            let db_path = db_path.to_string_lossy().into_owned();
+            let rag = match papers_db::DbStore::open(&db_path).await {
+                Ok(store) => store,
+                Err(e) => exit_err(&format!("Failed to open demo database: {e}")),
+            };
+
+            let (mut total_chunks, mut total_exhibits) = (0usize, 0usize);
+            for mut params in corpus {
+                // Synthetic item keys are reused across seeds; re-embed rather
+                // than trust a cached embedding of another seed's text.
+                params.force = true;
+                let key = params.item_key.clone();
+                if !json { print!("  [ingest] {key} {}... ", params.title); }
+                match papers_db::ingest_paper(&rag, params).await {
+                    Ok(stats) => {
+                        total_chunks += stats.chunks_added;
+                        total_exhibits += stats.exhibits_added;
+                        if !json { println!("{} chunks, {} exhibits", stats.chunks_added, stats.exhibits_added); }
+                    }
+                    Err(e) => exit_err(&format!("Failed to ingest {key}: {e}")),
+                }
+            }
+            if json {
+                print_json(&serde_json::json!({
+                    "db_path": db_path, "papers": papers,
+                    "total_chunks": total_chunks, "total_exhibits": total_exhibits,
+                }));
+            } else {
+                print!("{}", format::format_demo_summary(&db_path, papers, total_chunks, total_exhibits));
+            }
         }
         EntityCommand::Extract {
             pdf,
@@ -2034,15 +2591,134 @@ async fn papers_main() {
                 Err(e) => exit_err(&format!("{e}")),
             }
         }
-        EntityCommand::Config { cmd } => {
-            handle_config_command(cmd);
+        EntityCommand::Raw { path, query } => {
+            let pairs = parse_query_pairs(&query);
+            match papers_core::raw::openalex_raw(&client, &path, &pairs).await {
+                Ok(json) => print_json(&json),
+                Err(e) => exit_err(&e.to_string()),
+            }
         }
-        EntityCommand::Mcp { cmd } => {
-            handle_mcp_command(cmd).await;
+        EntityCommand::Digest { selections, watches, days, notify, json } => {
+            let selections: Vec<_> = selections
+                .iter()
+                .map(|s| {
+                    papers_core::selection::resolve_selection(s)
+                        .and_then(|name| papers_core::selection::load_selection(&name))
+                        .unwrap_or_else(|e| exit_err(&e.to_string()))
+                })
+                .collect();
+            let zotero = optional_zotero().await.unwrap_or(None);
+            let digest =
+                papers_core::api::digest_generate(&client, zotero.as_ref(), &selections, &watches, days).await;
+            if json {
+                print_json(&digest);
+            } else {
+                print!("{}", digest.markdown);
+            }
+            if notify {
+                let channels = papers_core::notify::configured_channels();
+                if channels.is_empty() {
+                    exit_err("no webhooks or email configured in config.json");
+                }
+                match papers_core::notify::notify_digest(&digest, &channels).await {
+                    Ok(report) => eprint!("{}", format::format_notify_report(&report)),
+                    Err(e) => exit_err(&format!("Failed to notify: {e}")),
+                }
+            }
         }
-    }
-}
-
+        EntityCommand::Cache { cmd } => match cmd {
+            CacheCommand::Stats { json } => match papers_core::api::cache_stats(&client) {
+                Some(stats) => {
+                    if json {
+                        print_json(&stats);
+                    } else {
+                        print!("{}", format::format_cache_stats(&stats));
+                    }
+                }
+                None => exit_err("OpenAlex response cache is disabled"),
+            },
+            CacheCommand::Clear { entity } => {
+                match papers_core::api::cache_clear(&client, entity.as_deref()) {
+                    Ok(removed) => println!("Removed {removed} cached responses"),
+                    Err(e) => exit_err(&e.to_string()),
+                }
+            }
+        },
+        EntityCommand::Snapshot { cmd } => match cmd {
+            SnapshotCommand::Index { source, out } => {
+                let out = out
+                    .or_else(|| papers_core::Snapshot::from_env().map(|s| s.dir().to_path_buf()))
+                    .unwrap_or_else(|| exit_err("Pass --out or set PAPERS_OPENALEX_SNAPSHOT"));
+                eprintln!("Indexing {} → {}", source.display(), out.display());
+                let result =
+                    tokio::task::spawn_blocking(move || papers_core::Snapshot::build(&source, &out))
+                        .await
+                        .unwrap();
+                match result {
+                    Ok(manifest) => {
+                        for (entity, count) in &manifest.entities {
+                            println!("{entity}: {count}");
+                        }
+                    }
+                    Err(e) => exit_err(&e.to_string()),
+                }
+            }
+            SnapshotCommand::Info { json } => {
+                let Some(snapshot) = papers_core::Snapshot::from_env() else {
+                    exit_err("PAPERS_OPENALEX_SNAPSHOT is not set; OpenAlex requests go to the API")
+                };
+                match snapshot.manifest() {
+                    Ok(manifest) if json => print_json(&manifest),
+                    Ok(manifest) => {
+                        println!("Index:  {}", snapshot.dir().display());
+                        println!("Source: {}", manifest.source);
+                        for (entity, count) in &manifest.entities {
+                            println!("  {entity}: {count}");
+                        }
+                    }
+                    Err(e) => exit_err(&e.to_string()),
+                }
+            }
+        },
+        EntityCommand::Config { cmd } => {
+            handle_config_command(cmd);
+        }
+        EntityCommand::Mcp { cmd } => {
+            handle_mcp_command(cmd).await;
+        }
+        EntityCommand::Serve { host, port, read_only } => {
+            let mut config =
+                papers_mcp::http::ServeConfig::from_env().unwrap_or_else(|e| exit_err(&e));
+            if let Some(host) = host {
+                config.host = host;
+            }
+            if let Some(port) = port {
+                config.port = port;
+            }
+            config.read_only |= read_only;
+            if let Err(e) = papers_mcp::http::serve_http(config).await {
+                exit_err(&format!("MCP server error: {e}"));
+            }
+        }
+        EntityCommand::Audit { cmd: AuditCommand::Export { since, until, user, tool } } => {
+            // Each tenant of a shared server has its own log.
+            let tenant = papers_core::tenant::configured_tenants()
+                .unwrap_or_default()
+                .into_iter()
+                .find(|t| user.as_deref() == Some(t.name.as_str()));
+            let dir = match tenant {
+                Some(tenant) => papers_core::audit::audit_dir_in(&tenant.data_dir()),
+                None => papers_core::audit::audit_dir(),
+            };
+            let filter = papers_core::audit::AuditFilter { since, until, user, tool };
+            let mut out = std::io::stdout().lock();
+            if let Err(e) = papers_core::audit::export(&dir, &filter, &mut out) {
+                exit_err(&format!("Failed to export audit log: {e}"));
+            }
+        }
+    }
+}
+
 async fn open_db_store() -> papers_db::DbStore {
     let path = papers_db::DbStore::default_path();
     match papers_db::DbStore::open(&path).await {
@@ -2051,22 +2727,133 @@ async fn open_db_store() -> papers_db::DbStore {
     }
 }
 
-async fn handle_db_command(cmd: DbCommand) {
+/// Embed the abstracts of entries just added to a selection when
+/// `auto_index_abstracts` is on. Best effort: failures are reported as
+/// warnings and never fail the selection command.
+async fn auto_index_abstracts(client: &OpenAlexClient, entries: &[papers_core::selection::SelectionEntry]) {
+    if entries.is_empty() || !papers_core::abstracts::auto_index_enabled() {
+        return;
+    }
+    let rag = match papers_db::DbStore::open(&papers_db::DbStore::default_path()).await {
+        Ok(store) => store,
+        Err(e) => {
+            eprintln!("warning: abstracts not indexed, failed to open RAG database: {e}");
+            return;
+        }
+    };
+    let batch = papers_core::abstracts::entry_abstracts(client, entries).await;
+    if let Err(e) = papers_db::index_abstracts(&rag, &batch.abstracts).await {
+        eprintln!("warning: abstracts not indexed: {e}");
+    }
+}
+
+/// Write or update the vault note for an indexed paper. Metadata and chapters
+/// come from the DB; the abstract and annotations come from Zotero when it's
+/// available, falling back to an "Abstract" chapter for the summary.
+async fn sync_vault_note(
+    rag: &papers_db::DbStore,
+    zotero: Option<&ZoteroClient>,
+    paper_id: &str,
+    item_key: Option<&str>,
+    dir: &std::path::Path,
+) -> Result<std::path::PathBuf, String> {
+    let outline = papers_db::query::get_paper_outline(rag, paper_id)
+        .await
+        .map_err(|e| e.to_string())?;
+    let item_key = match (item_key, zotero) {
+        (Some(key), _) => Some(key.to_string()),
+        (None, Some(z)) => smart_resolve_item_key(z, paper_id).await.ok(),
+        (None, None) => {
+            papers_core::zotero::looks_like_zotero_key(paper_id).then(|| paper_id.to_string())
+        }
+    };
+
+    let mut summary = None;
+    let mut annotations = Vec::new();
+    if let (Some(z), Some(key)) = (zotero, item_key.as_deref()) {
+        if let Ok(item) = z.get_item(key).await {
+            summary = item.data.abstract_note;
+        }
+        if let Ok(items) = papers_core::zotero::work_annotations(z, key).await {
+            annotations = items.iter().filter_map(AnnotationExcerpt::from_zotero).collect();
+        }
+    }
+    if summary.as_deref().is_none_or(|s| s.trim().is_empty())
+        && let Some(ch) =
+            outline.chapters.iter().find(|c| c.chapter_title.eq_ignore_ascii_case("abstract"))
+        && let Ok(chapter) = papers_db::query::get_chapter(rag, paper_id, ch.chapter_idx).await
+    {
+        let chunks: Vec<&str> =
+            chapter.sections.iter().flat_map(|s| &s.chunks).map(|c| c.text.trim()).collect();
+        summary = Some(chunks.join("\n\n"));
+    }
+
+    let note = VaultNote {
+        paper_id: paper_id.to_string(),
+        item_key,
+        title: outline.title,
+        authors: outline.authors,
+        year: outline.year,
+        venue: outline.venue,
+        doi: looks_like_doi(paper_id).then(|| paper_id.to_string()),
+        tags: outline.tags,
+        summary,
+        annotations,
+        chapters: outline.chapters.into_iter().map(|c| (c.chapter_idx, c.chapter_title)).collect(),
+    };
+    papers_core::vault::write_note(dir, &note).map_err(|e| e.to_string())
+}
+
+/// IDs of the papers in a Zotero collection (key or name), for `--collection`.
+async fn collection_paper_ids(collection: &str) -> Vec<String> {
+    let zotero = zotero_client().await.unwrap_or_else(|e| exit_err(&e.to_string()));
+    let ids = papers_core::zotero::collection_paper_ids(&zotero, collection)
+        .await
+        .unwrap_or_else(|e| exit_err(&e.to_string()));
+    if ids.is_empty() {
+        exit_err(&format!("collection {collection} has no items"));
+    }
+    ids
+}
+
+/// Zotero client for refreshing vault notes after ingest, or `None` when no
+/// vault is configured (so ingest doesn't probe Zotero needlessly).
+async fn vault_zotero() -> Option<Option<ZoteroClient>> {
+    papers_core::vault::configured_vault_dir()?;
+    Some(optional_zotero().await.ok().flatten())
+}
+
+/// Refresh the vault note for a freshly ingested paper. Failures are reported
+/// but don't fail the ingest.
+async fn refresh_vault_note(
+    rag: &papers_db::DbStore,
+    zotero: Option<&ZoteroClient>,
+    paper_id: &str,
+    item_key: &str,
+) {
+    let Some(dir) = papers_core::vault::configured_vault_dir() else { return };
+    if let Err(e) = sync_vault_note(rag, zotero, paper_id, Some(item_key), &dir).await {
+        eprintln!("  [vault] {paper_id}: {e}");
+    }
+}
+
+async fn handle_db_command(cmd: DbCommand, client: &OpenAlexClient) {
     match cmd {
         DbCommand::Chunk { cmd } => match cmd {
             DbChunkCommand::Search {
-                query, selection, work, chapter_idx, section_idx,
-                year_min, year_max, venue, tag, depth, limit, json,
+                query, selection, collection, work, chapter_idx, chapter, section_idx, section,
+                year_min, year_max, venue, tag, depth, mode, rerank, limit, json,
             } => {
                 let rag = open_db_store().await;
-            let paper_ids = match selection.as_deref() {
-                Some(sel) => match papers_core::selection::load_selection(sel) {
+            let paper_ids = match (collection.as_deref(), selection.as_deref()) {
+                (Some(c), _) => Some(collection_paper_ids(c).await),
+                (None, Some(sel)) => match papers_core::selection::load_selection(sel) {
                     Ok(s) => Some(s.entries.iter().flat_map(|e| {
                         e.doi.iter().chain(e.openalex_id.iter()).chain(e.zotero_key.iter()).cloned()
                     }).collect()),
                     Err(e) => exit_err(&e.to_string()),
                 },
-                None => match work {
+                (None, None) => match work {
                     Some(id) => {
                         let resolved = match papers_db::resolve_paper_id(&rag, &id).await {
                             Ok(r) => r,
@@ -2077,10 +2864,19 @@ async fn handle_db_command(cmd: DbCommand) {
                     None => None,
                 },
             };
+                let (chapter_idx, section_idx) = match paper_ids.as_deref() {
+                    Some([paper_id]) if selection.is_none() && collection.is_none() => papers_db::resolve_outline_scope(
+                        &rag, paper_id, chapter_idx, chapter.as_deref(), section_idx, section.as_deref(),
+                    )
+                    .await
+                    .unwrap_or_else(|e| exit_err(&e.to_string())),
+                    _ if chapter.is_some() || section.is_some() => exit_err("--chapter and --section require --work"),
+                    _ => (chapter_idx, section_idx),
+                };
                 let params = papers_db::SearchParams {
                     query, paper_ids, chapter_idx, section_idx,
                     filter_year_min: year_min, filter_year_max: year_max,
-                    filter_venue: venue, filter_tags: tag, filter_depth: depth, limit,
+                    filter_venue: venue, filter_tags: tag, filter_depth: depth, mode, rerank, limit,
                 };
                 match papers_db::query::search(&rag, params).await {
                     Ok(results) => { if json { print_json(&results); } else { format_db_search(&results); } }
@@ -2088,6 +2884,51 @@ async fn handle_db_command(cmd: DbCommand) {
                 }
             }
 
+            DbChunkCommand::Verify { statement, selection, work, mode, rerank, limit, json } => {
+                let rag = open_db_store().await;
+                let paper_ids = match selection.as_deref() {
+                    Some(sel) => match papers_core::selection::load_selection(sel) {
+                        Ok(s) => Some(s.entries.iter().flat_map(|e| {
+                            e.doi.iter().chain(e.openalex_id.iter()).chain(e.zotero_key.iter()).cloned()
+                        }).collect()),
+                        Err(e) => exit_err(&e.to_string()),
+                    },
+                    None => match work {
+                        Some(id) => match papers_db::resolve_paper_id(&rag, &id).await {
+                            Ok(r) => Some(vec![r]),
+                            Err(e) => exit_err(&e.to_string()),
+                        },
+                        None => None,
+                    },
+                };
+                let params = papers_db::VerifyParams { statement, paper_ids, mode, rerank, limit };
+                match papers_db::query::verify_claim(&rag, params).await {
+                    Ok(result) => { if json { print_json(&result); } else { format_db_verify(&result); } }
+                    Err(e) => exit_err(&e.to_string()),
+                }
+            }
+
+            DbChunkCommand::Ground { file, selection, mode, rerank, limit, json } => {
+                let markdown = match file {
+                    Some(path) => std::fs::read_to_string(&path)
+                        .unwrap_or_else(|e| exit_err(&format!("failed to read {}: {e}", path.display()))),
+                    None => std::io::read_to_string(std::io::stdin())
+                        .unwrap_or_else(|e| exit_err(&format!("failed to read stdin: {e}"))),
+                };
+                let rag = open_db_store().await;
+                let paper_ids = selection.as_deref().map(|sel| match papers_core::selection::load_selection(sel) {
+                    Ok(s) => s.entries.iter().flat_map(|e| {
+                        e.doi.iter().chain(e.openalex_id.iter()).chain(e.zotero_key.iter()).cloned()
+                    }).collect(),
+                    Err(e) => exit_err(&e.to_string()),
+                });
+                let params = papers_db::GroundDraftParams { markdown, paper_ids, mode, rerank, limit };
+                match papers_db::query::ground_draft(&rag, params).await {
+                    Ok(result) => { if json { print_json(&result); } else { format_db_ground(&result); } }
+                    Err(e) => exit_err(&e.to_string()),
+                }
+            }
+
             DbChunkCommand::Get { chunk_id, json } => {
                 let rag = open_db_store().await;
                 match papers_db::query::get_chunk(&rag, &chunk_id).await {
@@ -2096,6 +2937,50 @@ async fn handle_db_command(cmd: DbCommand) {
                 }
             }
 
+            DbChunkCommand::Locate { annotation_key, limit, json } => {
+                let zotero = zotero_client().await.unwrap_or_else(|e| exit_err(&e.to_string()));
+                let annotation = papers_core::zotero::resolve_annotation(&zotero, &annotation_key)
+                    .await
+                    .unwrap_or_else(|e| exit_err(&e.to_string()));
+                let rag = open_db_store().await;
+                let paper_id = papers_db::resolve_paper_id(&rag, &annotation.item_key)
+                    .await
+                    .unwrap_or_else(|e| exit_err(&e.to_string()));
+                let params = papers_db::LocatePassageParams {
+                    paper_id,
+                    text: annotation.text.clone().unwrap_or_default(),
+                    page_index: annotation.page_index,
+                    limit,
+                };
+                match papers_db::query::locate_passage(&rag, params).await {
+                    Ok(matches) => {
+                        if json {
+                            print_json(&serde_json::json!({ "annotation": annotation, "matches": matches }));
+                        } else {
+                            format_db_passage_matches(&matches);
+                        }
+                    }
+                    Err(e) => exit_err(&e.to_string()),
+                }
+            }
+
+            DbChunkCommand::MarkRead { chunk_id, json } => {
+                let rag = open_db_store().await;
+                match papers_db::query::mark_read(&rag, &chunk_id).await {
+                    Ok(state) => {
+                        if let Err(e) = papers_core::reading_status::record_chunk_read(&state.paper_id, &state.last_chunk_id) {
+                            exit_err(&e.to_string());
+                        }
+                        if json {
+                            print_json(&state);
+                        } else {
+                            println!("{}: {:.0}% read, continue at {}", state.paper_id, state.percent_complete, state.last_chunk_id);
+                        }
+                    }
+                    Err(e) => exit_err(&e.to_string()),
+                }
+            }
+
             DbChunkCommand::List { work, chapter_idx, section_idx, limit, json } => {
                 let rag = open_db_store().await;
                 let paper_id = match work {
@@ -2155,19 +3040,87 @@ async fn handle_db_command(cmd: DbCommand) {
             }
         },
 
+        DbCommand::Equation { cmd } => match cmd {
+            DbEquationCommand::Search { query, selection, work, limit, json } => {
+                let rag = open_db_store().await;
+                let paper_ids = match selection.as_deref() {
+                    Some(sel) => match papers_core::selection::load_selection(sel) {
+                        Ok(s) => Some(s.entries.iter().flat_map(|e| {
+                            e.doi.iter().chain(e.openalex_id.iter()).chain(e.zotero_key.iter()).cloned()
+                        }).collect()),
+                        Err(e) => exit_err(&e.to_string()),
+                    },
+                    None => match work {
+                        Some(id) => match papers_db::resolve_paper_id(&rag, &id).await {
+                            Ok(r) => Some(vec![r]),
+                            Err(e) => exit_err(&e.to_string()),
+                        },
+                        None => None,
+                    },
+                };
+                let params = papers_db::SearchEquationsParams { query, paper_ids, limit };
+                match papers_db::query::search_equations(&rag, params).await {
+                    Ok(results) => { if json { print_json(&results); } else { format_db_equations(&results); } }
+                    Err(e) => exit_err(&e.to_string()),
+                }
+            }
+        },
+
+        DbCommand::Citation { cmd } => match cmd {
+            DbCitationCommand::Contexts { doi, title, selection, limit, json } => {
+                let rag = open_db_store().await;
+                let paper_ids = match selection.as_deref() {
+                    Some(sel) => match papers_core::selection::load_selection(sel) {
+                        Ok(s) => Some(s.entries.iter().flat_map(|e| {
+                            e.doi.iter().chain(e.openalex_id.iter()).chain(e.zotero_key.iter()).cloned()
+                        }).collect()),
+                        Err(e) => exit_err(&e.to_string()),
+                    },
+                    None => None,
+                };
+                let params = papers_db::CitationContextsParams { doi, title, paper_ids, limit };
+                match papers_db::query::citation_contexts(&rag, params).await {
+                    Ok(results) => { if json { print_json(&results); } else { format_db_citation_contexts(&results); } }
+                    Err(e) => exit_err(&e.to_string()),
+                }
+            }
+            DbCitationCommand::Graph { format, output, selection, json } => {
+                use papers_core::graph::{GraphError, GraphExport, GraphFormat};
+                let format: GraphFormat = format.parse().unwrap_or_else(|e: GraphError| exit_err(&e.to_string()));
+                let rag = open_db_store().await;
+                let paper_ids = match selection.as_deref() {
+                    Some(sel) => match papers_core::selection::load_selection(sel) {
+                        Ok(s) => Some(s.entries.iter().flat_map(|e| {
+                            e.doi.iter().chain(e.openalex_id.iter()).chain(e.zotero_key.iter()).cloned()
+                        }).collect()),
+                        Err(e) => exit_err(&e.to_string()),
+                    },
+                    None => None,
+                };
+                match papers_db::query::citation_graph(&rag, paper_ids).await {
+                    Ok(graph) => {
+                        let scope = selection.as_deref().unwrap_or("index");
+                        write_graph_export(&GraphExport::new(scope, format, &graph, Vec::new()), output, json);
+                    }
+                    Err(e) => exit_err(&e.to_string()),
+                }
+            }
+        },
+
         DbCommand::Work { cmd } => match cmd {
             DbWorkCommand::List {
-                selection, year_min, year_max, venue, tag, author, sort, limit, json,
+                selection, collection, year_min, year_max, venue, tag, author, sort, limit, json,
             } => {
                 let rag = open_db_store().await;
-            let paper_ids = match selection.as_deref() {
-                Some(sel) => match papers_core::selection::load_selection(sel) {
+            let paper_ids = match (collection.as_deref(), selection.as_deref()) {
+                (Some(c), _) => Some(collection_paper_ids(c).await),
+                (None, Some(sel)) => match papers_core::selection::load_selection(sel) {
                     Ok(s) => Some(s.entries.iter().flat_map(|e| {
                         e.doi.iter().chain(e.openalex_id.iter()).chain(e.zotero_key.iter()).cloned()
                     }).collect()),
                     Err(e) => exit_err(&e.to_string()),
                 },
-                None => None,
+                (None, None) => None,
             };
                 let params = papers_db::ListPapersParams {
                     paper_ids, filter_year_min: year_min, filter_year_max: year_max,
@@ -2192,6 +3145,45 @@ async fn handle_db_command(cmd: DbCommand) {
                 }
             }
 
+            DbWorkCommand::Status { paper_id, status, rating, chunk, json } => {
+                use papers_core::reading_status::{ReadingStatus, ReadingStatusError, StatusUpdate, load_statuses, set_status};
+                let status = status
+                    .map(|s| s.parse::<ReadingStatus>())
+                    .transpose()
+                    .unwrap_or_else(|e: ReadingStatusError| exit_err(&e.to_string()));
+                let mut statuses = load_statuses().unwrap_or_else(|e| exit_err(&e.to_string()));
+                match paper_id {
+                    Some(paper_id) => {
+                        let record = if status.is_some() || rating.is_some() || chunk.is_some() {
+                            let update = StatusUpdate { status, rating, last_chunk_id: chunk };
+                            set_status(&paper_id, update).unwrap_or_else(|e| exit_err(&e.to_string()))
+                        } else {
+                            statuses.get(&paper_id).cloned().unwrap_or_default()
+                        };
+                        let paper_id = papers_core::reading_status::paper_key(&paper_id);
+                        if json {
+                            print_json(&serde_json::json!({ "paper_id": paper_id, "record": record }));
+                        } else {
+                            print!("{}", format::format_paper_status(&paper_id, &record));
+                        }
+                    }
+                    None => {
+                        if let Some(status) = status {
+                            statuses.papers.retain(|_, record| record.status == status);
+                        }
+                        if json {
+                            print_json(&statuses);
+                        } else if statuses.papers.is_empty() {
+                            println!("No reading status recorded");
+                        } else {
+                            for (paper_id, record) in &statuses.papers {
+                                print!("{}", format::format_paper_status(paper_id, record));
+                            }
+                        }
+                    }
+                }
+            }
+
             DbWorkCommand::Search { query, selection, year_min, year_max, venue, tag, limit, json } => {
                 let rag = open_db_store().await;
             let paper_ids = match selection.as_deref() {
@@ -2213,7 +3205,70 @@ async fn handle_db_command(cmd: DbCommand) {
                 }
             }
 
-            DbWorkCommand::Add { work: item_key, all, tag, force, json, force_extract, embed_only } => {
+            DbWorkCommand::Similar { paper_id, selection, limit, json } => {
+                let rag = open_db_store().await;
+                let paper_id = match papers_db::resolve_paper_id(&rag, &paper_id).await {
+                    Ok(r) => r,
+                    Err(e) => exit_err(&e.to_string()),
+                };
+                let paper_ids = match selection.as_deref() {
+                    Some(sel) => match papers_core::selection::load_selection(sel) {
+                        Ok(s) => Some(s.entries.iter().flat_map(|e| {
+                            e.doi.iter().chain(e.openalex_id.iter()).chain(e.zotero_key.iter()).cloned()
+                        }).collect()),
+                        Err(e) => exit_err(&e.to_string()),
+                    },
+                    None => None,
+                };
+                let params = papers_db::SimilarWorksParams { paper_id, paper_ids, limit };
+                match papers_db::query::similar_works(&rag, params).await {
+                    Ok(results) => { if json { print_json(&results); } else { format_db_work_similar(&results); } }
+                    Err(e) => exit_err(&e.to_string()),
+                }
+            }
+
+            DbWorkCommand::Cluster { k, selection, json } => {
+                let rag = open_db_store().await;
+                let paper_ids = match selection.as_deref() {
+                    Some(sel) => match papers_core::selection::load_selection(sel) {
+                        Ok(s) => Some(s.entries.iter().flat_map(|e| {
+                            e.doi.iter().chain(e.openalex_id.iter()).chain(e.zotero_key.iter()).cloned()
+                        }).collect()),
+                        Err(e) => exit_err(&e.to_string()),
+                    },
+                    None => None,
+                };
+                let params = papers_db::ClusterWorksParams { paper_ids, k };
+                match papers_db::query::cluster_works(&rag, params).await {
+                    Ok(result) => { if json { print_json(&result); } else { format_db_work_cluster(&result); } }
+                    Err(e) => exit_err(&e.to_string()),
+                }
+            }
+
+            DbWorkCommand::Timeline { milestones, selection, json } => {
+                let rag = open_db_store().await;
+                let paper_ids = match selection.as_deref() {
+                    Some(sel) => match papers_core::selection::load_selection(sel) {
+                        Ok(s) => Some(s.entries.iter().flat_map(|e| {
+                            e.doi.iter().chain(e.openalex_id.iter()).chain(e.zotero_key.iter()).cloned()
+                        }).collect()),
+                        Err(e) => exit_err(&e.to_string()),
+                    },
+                    None => None,
+                };
+                let params = papers_db::WorkTimelineParams { paper_ids, milestones_per_year: milestones };
+                match papers_db::query::work_timeline(&rag, params).await {
+                    Ok(mut timeline) => {
+                        if let Some(sel) = selection {
+                            timeline.scope = sel;
+                        }
+                        if json { print_json(&timeline); } else { print!("{}", format::format_timeline(&timeline)); }
+                    }
+                    Err(e) => exit_err(&e.to_string()),
+                }
+            }
+
+            DbWorkCommand::Add { work: item_key, all, tag, force, json, force_extract, embed_only, chunking } => {
                 let rag = open_db_store().await;
                 if all {
                     let keys = papers_db::list_cached_item_keys();
@@ -2239,23 +3294,29 @@ async fn handle_db_command(cmd: DbCommand) {
                             }
                         }
                     }
+                    let vault_zotero = vault_zotero().await;
                     for key in &keys {
                         let mut params = match papers_db::ingest_params_from_cache(key) {
                             Ok(p) => p,
                             Err(e) => { eprintln!("  [skip] {key}: {e}"); failed += 1; continue; }
                         };
                         params.force = force;
+                        params.chunking = chunking.unwrap_or_default();
                         if !force && papers_db::is_ingested(&rag, &params.paper_id).await {
                             if !json { println!("  [skip] {key}: already indexed"); }
                             continue;
                         }
                         if !json { print!("  [ingest] {key}... "); }
+                        let paper_id = params.paper_id.clone();
                         match papers_db::ingest_paper(&rag, params).await {
                             Ok(stats) => {
                                 total_chunks += stats.chunks_added;
                                 total_exhibits += stats.exhibits_added;
                                 ingested += 1;
                                 if !json { println!("{} chunks, {} exhibits", stats.chunks_added, stats.exhibits_added); }
+                                if let Some(z) = &vault_zotero {
+                                    refresh_vault_note(&rag, z.as_ref(), &paper_id, key).await;
+                                }
                             }
                             Err(e) => {
                                 failed += 1;
@@ -2315,6 +3376,7 @@ async fn handle_db_command(cmd: DbCommand) {
                     };
                     if let Some(tags) = tag { params.tags.extend(tags); }
                     params.force = force;
+                    params.chunking = chunking.unwrap_or_default();
                     if !force && papers_db::is_ingested(&rag, &params.paper_id).await {
                         if json {
                             print_json(&serde_json::json!({
@@ -2326,8 +3388,12 @@ async fn handle_db_command(cmd: DbCommand) {
                         }
                         return;
                     }
+                    let paper_id = params.paper_id.clone();
                     match papers_db::ingest_paper(&rag, params).await {
                         Ok(stats) => {
+                            if let Some(z) = vault_zotero().await {
+                                refresh_vault_note(&rag, z.as_ref(), &paper_id, &key).await;
+                            }
                             if json {
                                 print_json(&serde_json::json!({
                                     "chunks_added": stats.chunks_added,
@@ -2350,12 +3416,36 @@ async fn handle_db_command(cmd: DbCommand) {
                     Ok(r) => r,
                     Err(e) => exit_err(&e.to_string()),
                 };
-                match papers_db::query::remove_work(&rag, &paper_id).await {
-                    Ok(()) => {
+                match rag.delete_paper(&paper_id).await {
+                    Ok(stats) => {
                         if json {
-                            print_json(&serde_json::json!({ "removed": true, "paper_id": paper_id }));
+                            print_json(&stats);
                         } else {
-                            println!("Removed: {paper_id}");
+                            println!("Removed: {paper_id} ({} chunks, {} exhibits)",
+                                stats.chunks_removed, stats.exhibits_removed);
+                        }
+                    }
+                    Err(e) => exit_err(&e.to_string()),
+                }
+            }
+
+            DbWorkCommand::Reindex { paper_id, json } => {
+                let rag = open_db_store().await;
+                let paper_id = match papers_db::resolve_paper_id(&rag, &paper_id).await {
+                    Ok(r) => r,
+                    Err(e) => exit_err(&e.to_string()),
+                };
+                match rag.reindex_paper(&paper_id).await {
+                    Ok(stats) => {
+                        if json {
+                            print_json(&serde_json::json!({
+                                "paper_id": paper_id,
+                                "chunks_added": stats.chunks_added,
+                                "exhibits_added": stats.exhibits_added,
+                            }));
+                        } else {
+                            println!("Re-indexed {paper_id}: {} chunks, {} exhibits",
+                                stats.chunks_added, stats.exhibits_added);
                         }
                     }
                     Err(e) => exit_err(&e.to_string()),
@@ -2409,9 +3499,56 @@ async fn handle_db_command(cmd: DbCommand) {
             }
         },
 
+        DbCommand::Abstract { cmd } => match cmd {
+            DbAbstractCommand::Index { selection, json } => {
+                let rag = open_db_store().await;
+                let zotero = optional_zotero()
+                    .await
+                    .unwrap_or_else(|e| exit_err(&e.to_string()));
+                let entries = papers_core::abstracts::abstract_entries(zotero.as_ref(), selection.as_deref())
+                    .await
+                    .unwrap_or_else(|e| exit_err(&e.to_string()));
+                let batch = papers_core::abstracts::entry_abstracts(client, &entries).await;
+                match papers_db::index_abstracts(&rag, &batch.abstracts).await {
+                    Ok(indexed) => {
+                        if json {
+                            print_json(&serde_json::json!({ "indexed": indexed, "missing": batch.missing }));
+                        } else {
+                            println!("Indexed {indexed} abstracts");
+                            if !batch.missing.is_empty() {
+                                println!("{} entries without an OpenAlex abstract:", batch.missing.len());
+                                for m in &batch.missing { println!("  {m}"); }
+                            }
+                        }
+                    }
+                    Err(e) => exit_err(&e.to_string()),
+                }
+            }
+
+            DbAbstractCommand::Search { query, selection, year_min, year_max, limit, json } => {
+                let rag = open_db_store().await;
+                let paper_ids = match selection.as_deref() {
+                    Some(sel) => match papers_core::selection::load_selection(sel) {
+                        Ok(s) => Some(s.entries.iter().flat_map(|e| {
+                            e.doi.iter().chain(e.openalex_id.iter()).chain(e.zotero_key.iter()).cloned()
+                        }).collect()),
+                        Err(e) => exit_err(&e.to_string()),
+                    },
+                    None => None,
+                };
+                let params = papers_db::SearchAbstractsParams {
+                    query, paper_ids, filter_year_min: year_min, filter_year_max: year_max, limit,
+                };
+                match papers_db::query::search_abstracts(&rag, params).await {
+                    Ok(results) => { if json { print_json(&results); } else { format_db_abstract_search(&results); } }
+                    Err(e) => exit_err(&e.to_string()),
+                }
+            }
+        },
+
         DbCommand::Section { cmd } => match cmd {
             DbSectionCommand::Search {
-                query, selection, work, chapter_idx, year_min, year_max, venue, tag, limit, json,
+                query, selection, work, chapter_idx, chapter, year_min, year_max, venue, tag, limit, json,
             } => {
                 let rag = open_db_store().await;
             let paper_ids = match selection.as_deref() {
@@ -2432,6 +3569,16 @@ async fn handle_db_command(cmd: DbCommand) {
                     None => None,
                 },
             };
+                let chapter_idx = match paper_ids.as_deref() {
+                    Some([paper_id]) if selection.is_none() => {
+                        papers_db::resolve_outline_scope(&rag, paper_id, chapter_idx, chapter.as_deref(), None, None)
+                            .await
+                            .unwrap_or_else(|e| exit_err(&e.to_string()))
+                            .0
+                    }
+                    _ if chapter.is_some() => exit_err("--chapter requires --work"),
+                    _ => chapter_idx,
+                };
                 let params = papers_db::SearchSectionsParams {
                     query, paper_ids, chapter_idx, depth: None,
                     filter_year_min: year_min, filter_year_max: year_max,
@@ -2461,16 +3608,27 @@ async fn handle_db_command(cmd: DbCommand) {
                 }
             }
 
-            DbSectionCommand::Get { paper_id, chapter_idx, section_idx, json } => {
+            DbSectionCommand::Get { paper_id, chapter_idx, section_idx, section, exhibits, json } => {
                 let rag = open_db_store().await;
                 let paper_id = match papers_db::resolve_paper_id(&rag, &paper_id).await {
                     Ok(r) => r,
                     Err(e) => exit_err(&e.to_string()),
                 };
-                match papers_db::query::get_section(&rag, &paper_id, chapter_idx, section_idx).await {
-                    Ok(result) => { if json { print_json(&result); } else { format_db_section(&result); } }
-                    Err(e) => exit_err(&e.to_string()),
+                let scope = papers_db::resolve_outline_scope(&rag, &paper_id, chapter_idx, None, section_idx, section.as_deref())
+                    .await
+                    .unwrap_or_else(|e| exit_err(&e.to_string()));
+                let (Some(chapter_idx), Some(section_idx)) = scope else {
+                    exit_err("pass --section, or --chapter-idx and --section-idx");
+                };
+                let mut result = papers_db::query::get_section(&rag, &paper_id, chapter_idx, section_idx)
+                    .await
+                    .unwrap_or_else(|e| exit_err(&e.to_string()));
+                if exhibits
+                    && let Err(e) = papers_db::query::inline_exhibits(&rag, result.chunks.iter_mut()).await
+                {
+                    exit_err(&e.to_string());
                 }
+                if json { print_json(&result); } else { format_db_section(&result); }
             }
         },
 
@@ -2494,6 +3652,154 @@ async fn handle_db_command(cmd: DbCommand) {
             }
         },
 
+        DbCommand::Vault { cmd } => match cmd {
+            DbVaultCommand::Sync { work, dir, json } => {
+                let dir = dir
+                    .map(std::path::PathBuf::from)
+                    .or_else(papers_core::vault::configured_vault_dir)
+                    .unwrap_or_else(|| {
+                        exit_err(&papers_core::vault::VaultError::NotConfigured.to_string())
+                    });
+                let rag = open_db_store().await;
+                let zotero = optional_zotero().await.unwrap_or_else(|e| exit_err(&e.to_string()));
+                let paper_ids = match work {
+                    Some(work) => vec![
+                        papers_db::resolve_paper_id(&rag, &work)
+                            .await
+                            .unwrap_or_else(|e| exit_err(&e.to_string())),
+                    ],
+                    None => {
+                        let params = papers_db::ListPapersParams {
+                            paper_ids: None,
+                            filter_year_min: None,
+                            filter_year_max: None,
+                            filter_venue: None,
+                            filter_tags: None,
+                            filter_authors: None,
+                            sort_by: None,
+                            limit: u16::MAX,
+                        };
+                        papers_db::query::list_papers(&rag, params)
+                            .await
+                            .unwrap_or_else(|e| exit_err(&e.to_string()))
+                            .into_iter()
+                            .map(|p| p.paper_id)
+                            .collect()
+                    }
+                };
+                let mut written = Vec::new();
+                let mut failed = 0usize;
+                for paper_id in &paper_ids {
+                    match sync_vault_note(&rag, zotero.as_ref(), paper_id, None, &dir).await {
+                        Ok(path) => {
+                            if !json { println!("  [note] {}", path.display()); }
+                            written.push(serde_json::json!({ "paper_id": paper_id, "path": path }));
+                        }
+                        Err(e) => {
+                            failed += 1;
+                            eprintln!("  [fail] {paper_id}: {e}");
+                        }
+                    }
+                }
+                if json {
+                    print_json(&serde_json::json!({ "written": written, "failed": failed }));
+                } else {
+                    println!(
+                        "Synced {} notes to {} ({failed} failed)",
+                        written.len(),
+                        dir.display()
+                    );
+                }
+            }
+        },
+
+        DbCommand::Stats { json } => {
+            let rag = open_db_store().await;
+            match rag.stats().await {
+                Ok(stats) => {
+                    if json { print_json(&stats); } else { print!("{}", format::format_db_stats(&stats)); }
+                }
+                Err(e) => exit_err(&e.to_string()),
+            }
+        }
+
+        DbCommand::Migrate { json } => {
+            let rag = open_db_store().await;
+            let mut last_table = "";
+            let result = rag
+                .migrate(|p| {
+                    if json {
+                        return;
+                    }
+                    if p.table != last_table && !last_table.is_empty() {
+                        eprintln!();
+                    }
+                    last_table = p.table;
+                    eprint!("\r  {}: {}/{}", p.table, p.done, p.total);
+                })
+                .await;
+            if !json && !last_table.is_empty() {
+                eprintln!();
+            }
+            match result {
+                Ok((_, stats)) => {
+                    if json { print_json(&stats); } else { print!("{}", format::format_migrate_stats(&stats)); }
+                }
+                Err(e) => exit_err(&e.to_string()),
+            }
+        }
+
+        DbCommand::Watch { collection, interval, once, json } => {
+            let zotero = zotero_client().await.unwrap_or_else(|e| exit_err(&e.to_string()));
+            let rag = open_db_store().await;
+            let collection = if collection.is_empty() {
+                papers_core::config::PapersConfig::load().map(|c| c.watch_collections).unwrap_or_default()
+            } else {
+                collection
+            };
+            let mut collections = Vec::new();
+            for input in &collection {
+                match papers_core::zotero::resolve_collection_key(&zotero, input).await {
+                    Ok(key) => collections.push(key),
+                    Err(e) => exit_err(&e.to_string()),
+                }
+            }
+            let mut state = papers_core::sync::SyncState::load().unwrap_or_else(|e| exit_err(&e.to_string()));
+            if !json {
+                let scope = if collection.is_empty() { "the whole library".to_string() } else { collection.join(", ") };
+                println!("Watching {scope} (library version {})", state.library_version);
+            }
+            loop {
+                match papers_core::sync::plan_sync(&zotero, &state, &collections).await {
+                    Ok(plan) => {
+                        let report = papers_db::sync::apply_sync(&rag, &plan, &mut state, async |key: &str| {
+                            run_extraction_for_key(&zotero, key).await
+                        })
+                        .await;
+                        if let Err(e) = state.save() {
+                            eprintln!("  [sync] failed to save state: {e}");
+                        }
+                        if let Some(z) = vault_zotero().await {
+                            for key in &report.indexed {
+                                if let Some(paper_id) = state.indexed.get(key) {
+                                    refresh_vault_note(&rag, z.as_ref(), paper_id, key).await;
+                                }
+                            }
+                        }
+                        if json {
+                            println!("{}", serde_json::to_string(&report).unwrap_or_default());
+                        } else {
+                            print!("{}", format::format_sync_report(&report, state.library_version));
+                        }
+                    }
+                    Err(e) => eprintln!("  [sync] poll failed: {e}"),
+                }
+                if once {
+                    break;
+                }
+                tokio::time::sleep(std::time::Duration::from_secs(interval.max(1))).await;
+            }
+        }
     }
 }
 
@@ -2520,6 +3826,63 @@ fn format_db_work_search(results: &[papers_db::WorkSearchResult]) {
     }
 }
 
+fn format_db_abstract_search(results: &[papers_db::AbstractSearchResult]) {
+    if results.is_empty() { println!("No matching abstracts found."); return; }
+    for r in results {
+        let year = r.year.map(|y| y.to_string()).unwrap_or_else(|| "?".into());
+        let venue = r.venue.as_deref().unwrap_or("");
+        let ingested = if r.ingested { "full text indexed" } else { "abstract only" };
+        println!("[{:.3}] {} ({}, {})", r.score, r.title, year, venue);
+        println!("       {}  |  {}", ingested, r.paper_id);
+        println!("       {}", r.text.chars().take(100).collect::<String>());
+        println!();
+    }
+}
+
+fn format_db_work_similar(results: &[papers_db::SimilarWork]) {
+    if results.is_empty() { println!("No other indexed papers."); return; }
+    for r in results {
+        let year = r.year.map(|y| y.to_string()).unwrap_or_else(|| "?".into());
+        let venue = r.venue.as_deref().unwrap_or("");
+        println!("[{:.3}] {} ({}, {})", r.score, r.title, year, venue);
+        println!("       {} chunks  |  {}", r.chunk_count, r.paper_id);
+    }
+}
+
+/// Print a graph export (or its JSON envelope), or write it to `output`.
+fn write_graph_export(export: &papers_core::graph::GraphExport, output: Option<std::path::PathBuf>, json: bool) {
+    let content = if json {
+        serde_json::to_string_pretty(export).expect("JSON serialization failed") + "\n"
+    } else {
+        export.content.clone()
+    };
+    match output {
+        Some(path) => {
+            if let Err(e) = std::fs::write(&path, content) {
+                exit_err(&format!("failed to write {}: {e}", path.display()));
+            }
+            eprintln!("Wrote {} nodes and {} edges to {}", export.nodes, export.edges, path.display());
+            if !export.unresolved.is_empty() {
+                eprintln!("{} entries not found in OpenAlex", export.unresolved.len());
+            }
+        }
+        None => print!("{content}"),
+    }
+}
+
+fn format_db_work_cluster(result: &papers_db::WorkClustering) {
+    if result.clusters.is_empty() { println!("No indexed papers."); return; }
+    println!("{} clusters (silhouette {:.3})", result.k, result.silhouette);
+    for c in &result.clusters {
+        println!();
+        println!("[{}] {} ({} papers)", c.cluster, c.label, c.papers.len());
+        for p in &c.papers {
+            let year = p.year.map(|y| y.to_string()).unwrap_or_else(|| "?".into());
+            println!("    [{:.3}] {} ({})  |  {}", p.score, p.title, year, p.paper_id);
+        }
+    }
+}
+
 fn format_db_chunk_list(chunks: &[papers_db::ChunkListItem]) {
     if chunks.is_empty() { println!("No chunks found."); return; }
     for c in chunks {
@@ -2530,6 +3893,25 @@ fn format_db_chunk_list(chunks: &[papers_db::ChunkListItem]) {
     }
 }
 
+fn format_db_passage_matches(matches: &[papers_db::PassageMatch]) {
+    if matches.is_empty() { println!("No chunk holds this annotation."); return; }
+    for m in matches {
+        let page = match (m.page_start, m.page_end) {
+            (Some(a), Some(b)) if a != b => format!("pp. {}-{}", a + 1, b + 1),
+            (Some(a), _) => format!("p. {}", a + 1),
+            _ => "page unknown".to_string(),
+        };
+        let how = if m.score > 0.0 { format!("{:.0}% of text", m.score * 100.0) } else { "same page".to_string() };
+        println!("[{}]  Ch.{} {} / Sec.{} {}  ({page}; {how})",
+            m.chunk_id, m.chapter_idx, m.chapter_title, m.section_idx, m.section_title);
+        println!("    {}", m.text.chars().take(200).collect::<String>());
+        let prev = m.prev.as_ref().map(|p| p.chunk_id.as_str()).unwrap_or("(none)");
+        let next = m.next.as_ref().map(|n| n.chunk_id.as_str()).unwrap_or("(none)");
+        println!("    ← {}  /  {} →", prev, next);
+        println!();
+    }
+}
+
 fn format_db_section_search(results: &[papers_db::SectionSearchResult]) {
     if results.is_empty() { println!("No matching sections found."); return; }
     for r in results {
@@ -2570,13 +3952,153 @@ fn handle_config_command(cmd: ConfigCommand) {
                 Err(e) => exit_err(&e.to_string()),
             }
         }
+        ConfigCommand::Set {
+            cmd: ConfigSetCommand::Vault { dir },
+        } => {
+            let mut cfg = match papers_core::config::PapersConfig::load() {
+                Ok(c) => c,
+                Err(e) => exit_err(&format!("Failed to load config: {e}")),
+            };
+            cfg.vault_dir = Some(dir);
+            match cfg.save() {
+                Ok(()) => println!(
+                    "Config saved: {}",
+                    papers_core::config::PapersConfig::config_path().display()
+                ),
+                Err(e) => exit_err(&e.to_string()),
+            }
+        }
+        ConfigCommand::Set {
+            cmd: ConfigSetCommand::TextSources { order },
+        } => {
+            let mut cfg = match papers_core::config::PapersConfig::load() {
+                Ok(c) => c,
+                Err(e) => exit_err(&format!("Failed to load config: {e}")),
+            };
+            let sources: Result<Vec<papers_core::text::TextSource>, String> =
+                order.split(',').map(|s| s.parse()).collect();
+            cfg.text_sources = match sources {
+                Ok(sources) => sources,
+                Err(e) => exit_err(&e),
+            };
+            match cfg.save() {
+                Ok(()) => println!(
+                    "Config saved: {}",
+                    papers_core::config::PapersConfig::config_path().display()
+                ),
+                Err(e) => exit_err(&e.to_string()),
+            }
+        }
+        ConfigCommand::Set {
+            cmd: ConfigSetCommand::UnpaywallEmail { email },
+        } => {
+            let mut cfg = match papers_core::config::PapersConfig::load() {
+                Ok(c) => c,
+                Err(e) => exit_err(&format!("Failed to load config: {e}")),
+            };
+            cfg.unpaywall_email = Some(email);
+            match cfg.save() {
+                Ok(()) => println!(
+                    "Config saved: {}",
+                    papers_core::config::PapersConfig::config_path().display()
+                ),
+                Err(e) => exit_err(&e.to_string()),
+            }
+        }
+        ConfigCommand::Set {
+            cmd: ConfigSetCommand::LinkResolver { url },
+        } => {
+            let mut cfg = match papers_core::config::PapersConfig::load() {
+                Ok(c) => c,
+                Err(e) => exit_err(&format!("Failed to load config: {e}")),
+            };
+            if !url.contains("{doi}") {
+                exit_err("Link resolver URL must contain a {doi} placeholder");
+            }
+            cfg.link_resolver = Some(url);
+            match cfg.save() {
+                Ok(()) => println!(
+                    "Config saved: {}",
+                    papers_core::config::PapersConfig::config_path().display()
+                ),
+                Err(e) => exit_err(&e.to_string()),
+            }
+        }
+        ConfigCommand::Set {
+            cmd: ConfigSetCommand::AutoIndexAbstracts { enabled },
+        } => {
+            let mut cfg = match papers_core::config::PapersConfig::load() {
+                Ok(c) => c,
+                Err(e) => exit_err(&format!("Failed to load config: {e}")),
+            };
+            cfg.auto_index_abstracts = enabled;
+            match cfg.save() {
+                Ok(()) => println!(
+                    "Config saved: {}",
+                    papers_core::config::PapersConfig::config_path().display()
+                ),
+                Err(e) => exit_err(&e.to_string()),
+            }
+        }
+        ConfigCommand::Show => {
+            use papers_core::settings::{Settings, SETTINGS};
+            let settings = Settings::load().unwrap_or_default();
+            match &settings.path {
+                Some(path) => println!("Settings file: {}\n", path.display()),
+                None => println!("Settings file: none (create papers.toml to add one)\n"),
+            }
+            println!("{:<26} {:<30} {:<10} VALUE", "KEY", "VARIABLE", "SOURCE");
+            for s in SETTINGS {
+                let value = std::env::var(s.env).ok();
+                let source = match &value {
+                    None => "-",
+                    Some(v) if settings.get(s.key) == Some(v.as_str()) => "file",
+                    Some(_) => "env",
+                };
+                let shown = match value {
+                    Some(_) if s.secret => "(set)".to_string(),
+                    Some(v) => v,
+                    None => String::new(),
+                };
+                println!("{:<26} {:<30} {source:<10} {shown}", s.key, s.env);
+            }
+        }
+        ConfigCommand::ZoteroProfile { cmd } => {
+            use papers_core::zotero_profiles::{ProfileStore, ZoteroCredentials};
+            let mut store = match ProfileStore::load() {
+                Ok(s) => s,
+                Err(e) => exit_err(&format!("Failed to load Zotero profiles: {e}")),
+            };
+            match cmd {
+                ZoteroProfileCommand::Set { name, user_id, api_key } => {
+                    if let Err(e) = store.set(&name, ZoteroCredentials { user_id, api_key }) {
+                        exit_err(&e.to_string());
+                    }
+                }
+                ZoteroProfileCommand::List => {
+                    for (name, user_id) in store.list() {
+                        println!("{name}\t{user_id}");
+                    }
+                    return;
+                }
+                ZoteroProfileCommand::Remove { name } => {
+                    if !store.remove(&name) {
+                        exit_err(&format!("No Zotero profile named {name:?}"));
+                    }
+                }
+            }
+            match store.save() {
+                Ok(()) => println!("Zotero profiles saved: {}", ProfileStore::default_path().display()),
+                Err(e) => exit_err(&e.to_string()),
+            }
+        }
     }
 }
 
 async fn handle_mcp_command(cmd: McpCommand) {
     match cmd {
-        McpCommand::Start { stdio: _ } => {
-            if let Err(e) = papers_mcp::start_stdio().await {
+        McpCommand::Start { stdio: _, read_only } => {
+            if let Err(e) = papers_mcp::start_stdio(read_only).await {
                 exit_err(&format!("MCP server error: {e}"));
             }
         }
@@ -2591,24 +4113,91 @@ fn format_db_search(results: &[papers_db::SearchResult]) {
         return;
     }
     for r in results {
-        let c = &r.chunk;
-        println!(
-            "{:.2}  {}  |  {} › {}",
-            r.score, c.paper_id, c.chapter_title, c.section_title,
-        );
-        let preview: String = c.text.chars().take(200).collect();
-        println!("      {}", preview);
-        let prev = r
-            .prev
-            .as_ref()
-            .map(|p| p.chunk_id.as_str())
-            .unwrap_or("(none)");
-        let next = r
-            .next
-            .as_ref()
-            .map(|n| n.chunk_id.as_str())
-            .unwrap_or("(none)");
-        println!("      ← {}  /  {} →", prev, next);
+        let c = &r.chunk;
+        println!(
+            "{:.2}  {}  |  {} › {}",
+            r.score, c.paper_id, c.chapter_title, c.section_title,
+        );
+        let preview: String = c.text.chars().take(200).collect();
+        println!("      {}", preview);
+        let prev = r
+            .prev
+            .as_ref()
+            .map(|p| p.chunk_id.as_str())
+            .unwrap_or("(none)");
+        let next = r
+            .next
+            .as_ref()
+            .map(|n| n.chunk_id.as_str())
+            .unwrap_or("(none)");
+        println!("      ← {}  /  {} →", prev, next);
+        println!();
+    }
+}
+
+fn format_db_verify(result: &papers_db::ClaimVerification) {
+    println!(
+        "{} supporting, {} contradicting, {} neutral",
+        result.supporting, result.contradicting, result.neutral,
+    );
+    println!();
+    for p in &result.passages {
+        let stance = match p.stance {
+            papers_db::Stance::Supporting => "SUPPORTS",
+            papers_db::Stance::Contradicting => "CONTRADICTS",
+            papers_db::Stance::Neutral => "neutral",
+        };
+        let c = &p.result.chunk;
+        println!("{stance:<11}  {}  |  {} › {}", c.chunk_id, c.paper_title, c.section_title);
+        println!("      \"{}\"", p.evidence.chars().take(240).collect::<String>());
+        println!("      overlap {:.2}; {}", p.overlap, p.cues.join("; "));
+        println!();
+    }
+}
+
+fn format_db_ground(result: &papers_db::DraftGrounding) {
+    println!(
+        "{} paragraphs, {} without citations, {} blocks skipped",
+        result.paragraphs.len(), result.uncited, result.skipped,
+    );
+    if result.truncated {
+        println!("(only the first {} paragraphs were grounded)", papers_db::query::MAX_DRAFT_PARAGRAPHS);
+    }
+    println!();
+    for p in &result.paragraphs {
+        let mark = if p.cited { "cited" } else { "UNCITED" };
+        println!("line {:<5} {mark:<8} {}", p.line, p.text.chars().take(100).collect::<String>());
+        if p.candidates.is_empty() {
+            println!("      (no matching papers)");
+        }
+        for c in &p.candidates {
+            println!("      [{:.3}] {}  ({} passages)", c.score, c.paper_title, c.passages);
+            println!("             {}  |  {}", c.chunk_id, c.section_title);
+        }
+        println!();
+    }
+}
+
+fn format_db_equations(results: &[papers_db::EquationSearchResult]) {
+    if results.is_empty() { println!("No equations found."); return; }
+    for r in results {
+        println!("[{:.3}] {} / {} \u{2014} {}", r.score, r.chapter_title, r.section_title, r.paper_title);
+        println!("       {}", r.chunk_id);
+        for tex in &r.equations {
+            println!("       $$ {tex} $$");
+        }
+        println!("       {}", r.text.chars().take(160).collect::<String>());
+        println!();
+    }
+}
+
+fn format_db_citation_contexts(results: &[papers_db::CitationContext]) {
+    if results.is_empty() { println!("No citing sentences found."); return; }
+    for r in results {
+        let year = r.year.map(|y| y.to_string()).unwrap_or_else(|| "?".into());
+        println!("{} ({})  |  {}", r.paper_title, year, r.chunk_id);
+        println!("       {}", r.sentence);
+        println!("       {} \u{2192} {}", r.marker, r.ref_text.chars().take(120).collect::<String>());
         println!();
     }
 }
@@ -2665,6 +4254,16 @@ fn format_db_section(r: &papers_db::SectionResult) {
     println!();
     for chunk in &r.chunks {
         println!("{}", chunk.text);
+        for e in &chunk.exhibits {
+            println!();
+            println!("  {} [{}] {}", e.exhibit_id, e.exhibit_type, e.caption);
+            if let Some(desc) = &e.description {
+                println!("    {desc}");
+            }
+            if let Some(img) = &e.image_path {
+                println!("    Image: {img}");
+            }
+        }
         println!("---");
     }
 }
@@ -2723,17 +4322,21 @@ fn format_db_papers(papers: &[papers_db::PaperSummary]) {
         return;
     }
     println!(
-        "{:<6}  {:<12}  {:<6}  {}",
-        "YEAR", "VENUE", "CHUNKS", "TITLE"
+        "{:<6}  {:<12}  {:<6}  {:<5}  {}",
+        "YEAR", "VENUE", "CHUNKS", "READ", "TITLE"
     );
     for p in papers {
         println!(
-            "{:<6}  {:<12}  {:<6}  {}",
+            "{:<6}  {:<12}  {:<6}  {:<5}  {}",
             p.year.map(|y| y.to_string()).unwrap_or_else(|| "?".into()),
             p.venue.as_deref().unwrap_or(""),
             p.chunk_count,
+            p.reading.as_ref().map(|r| format!("{:.0}%", r.percent_complete)).unwrap_or_default(),
             p.title,
         );
+        if let Some(r) = &p.reading {
+            println!("{:<36}continue at {}", "", r.last_chunk_id);
+        }
     }
 }
 
@@ -2797,6 +4400,7 @@ async fn handle_selection_command(cmd: SelectionCommand, client: &OpenAlexClient
             let names = list_selection_names();
             let state = load_state();
             let active = state.active.as_deref();
+            let db_scope = state.db_scope.as_deref();
             let items: Vec<format::SelectionListItem> = names
                 .iter()
                 .map(|name| {
@@ -2805,6 +4409,7 @@ async fn handle_selection_command(cmd: SelectionCommand, client: &OpenAlexClient
                         name: name.clone(),
                         item_count: count,
                         is_active: Some(name.as_str()) == active,
+                        is_db_scope: Some(name.as_str()) == db_scope,
                     }
                 })
                 .collect();
@@ -2816,6 +4421,7 @@ async fn handle_selection_command(cmd: SelectionCommand, client: &OpenAlexClient
                             "name": i.name,
                             "item_count": i.item_count,
                             "is_active": i.is_active,
+                            "is_db_scope": i.is_db_scope,
                         })
                     })
                     .collect();
@@ -2919,11 +4525,17 @@ async fn handle_selection_command(cmd: SelectionCommand, client: &OpenAlexClient
             };
             let mut state = load_state();
             let was_active = state.active.as_deref() == Some(&sel_name);
+            let was_db_scope = state.db_scope.as_deref() == Some(&sel_name);
             if let Err(e) = delete_selection(&sel_name) {
                 exit_err(&e.to_string());
             }
-            if was_active {
-                state.active = None;
+            if was_active || was_db_scope {
+                if was_active {
+                    state.active = None;
+                }
+                if was_db_scope {
+                    state.db_scope = None;
+                }
                 let _ = save_state(&state);
             }
             if json {
@@ -2941,7 +4553,8 @@ async fn handle_selection_command(cmd: SelectionCommand, client: &OpenAlexClient
             let sel_name = resolve_sel_name(selection, &active_selection_name);
             // For selection add, Zotero is optional — treat all errors as "not available"
             let zotero = optional_zotero().await.unwrap_or(None);
-            let entry = match resolve_paper(&paper, client, zotero.as_ref()).await {
+            let crossref = papers_core::crossref::CrossrefClient::new();
+            let entry = match resolve_paper(&paper, client, zotero.as_ref(), Some(&crossref)).await {
                 Ok(e) => e,
                 Err(e) => exit_err(&e.to_string()),
             };
@@ -2969,6 +4582,7 @@ async fn handle_selection_command(cmd: SelectionCommand, client: &OpenAlexClient
                 if let Err(e) = save_selection(&sel) {
                     exit_err(&e.to_string());
                 }
+                auto_index_abstracts(client, std::slice::from_ref(&entry)).await;
             }
             if json {
                 print_json(&serde_json::json!({ "entry": entry, "added": !is_dup }));
@@ -2980,6 +4594,102 @@ async fn handle_selection_command(cmd: SelectionCommand, client: &OpenAlexClient
             }
         }
 
+        SelectionCommand::AddBulk { mut papers, selection, json } => {
+            let sel_name = resolve_sel_name(selection, &active_selection_name);
+            if papers.is_empty() {
+                let input = std::io::read_to_string(std::io::stdin())
+                    .unwrap_or_else(|e| exit_err(&format!("failed to read stdin: {e}")));
+                papers = input.lines().map(str::to_string).collect();
+            }
+            let zotero = optional_zotero().await.unwrap_or(None);
+            let crossref = papers_core::crossref::CrossrefClient::new();
+            let mut sel = load_selection(&sel_name).unwrap_or_else(|e| exit_err(&e.to_string()));
+            let before = sel.entries.len();
+            let summary = papers_core::selection::add_papers(&mut sel, &papers, client, zotero.as_ref(), Some(&crossref)).await;
+            if !summary.added.is_empty() {
+                if let Err(e) = save_selection(&sel) {
+                    exit_err(&e.to_string());
+                }
+                auto_index_abstracts(client, &sel.entries[before..]).await;
+            }
+            if json {
+                print_json(&summary);
+            } else {
+                print!("{}", format::format_selection_add_bulk(&summary));
+            }
+        }
+
+        SelectionCommand::AddQuery { search, filter, sort, work_filters, limit, selection, json } => {
+            let sel_name = resolve_sel_name(selection, &active_selection_name);
+            let mut sel = load_selection(&sel_name).unwrap_or_else(|e| exit_err(&e.to_string()));
+            let before = sel.entries.len();
+            let mut params = work_filter_params(filter, &work_filters);
+            params.search = search;
+            params.sort = sort;
+            let summary = match papers_core::api::selection_add_query(client, &mut sel, &params, limit).await {
+                Ok(s) => s,
+                Err(e) => exit_err(&e.to_string()),
+            };
+            if !summary.added.is_empty() {
+                if let Err(e) = save_selection(&sel) {
+                    exit_err(&e.to_string());
+                }
+                auto_index_abstracts(client, &sel.entries[before..]).await;
+            }
+            if json {
+                print_json(&summary);
+            } else {
+                print!("{}", format::format_selection_add_query(&summary));
+            }
+        }
+
+        SelectionCommand::AddReferences { work, search, filter, work_filters, selection, json } => {
+            let sel_name = resolve_sel_name(selection, &active_selection_name);
+            let mut sel = load_selection(&sel_name).unwrap_or_else(|e| exit_err(&e.to_string()));
+            let before = sel.entries.len();
+            let mut params = work_filter_params(filter, &work_filters);
+            params.search = search;
+            let result = match papers_core::api::selection_add_references(client, &mut sel, &work, &params).await {
+                Ok(r) => r,
+                Err(e) => exit_err(&e.to_string()),
+            };
+            if !result.summary.added.is_empty() {
+                if let Err(e) = save_selection(&sel) {
+                    exit_err(&e.to_string());
+                }
+                auto_index_abstracts(client, &sel.entries[before..]).await;
+            }
+            if json {
+                print_json(&result);
+            } else {
+                print!("{}", format::format_selection_add_references(&result));
+            }
+        }
+
+        SelectionCommand::AddCiters { work, min_citations, filter, sort, work_filters, limit, selection, json } => {
+            let sel_name = resolve_sel_name(selection, &active_selection_name);
+            let mut sel = load_selection(&sel_name).unwrap_or_else(|e| exit_err(&e.to_string()));
+            let before = sel.entries.len();
+            let mut params = work_filter_params(filter, &work_filters);
+            params.sort = Some(sort);
+            let result =
+                match papers_core::api::selection_add_citers(client, &mut sel, &work, &params, min_citations, limit).await {
+                    Ok(r) => r,
+                    Err(e) => exit_err(&e.to_string()),
+                };
+            if !result.summary.added.is_empty() {
+                if let Err(e) = save_selection(&sel) {
+                    exit_err(&e.to_string());
+                }
+                auto_index_abstracts(client, &sel.entries[before..]).await;
+            }
+            if json {
+                print_json(&result);
+            } else {
+                print!("{}", format::format_selection_add_citers(&result));
+            }
+        }
+
         SelectionCommand::Remove {
             paper,
             selection,
@@ -3027,6 +4737,54 @@ async fn handle_selection_command(cmd: SelectionCommand, client: &OpenAlexClient
             }
         }
 
+        SelectionCommand::Note {
+            paper,
+            note,
+            selection,
+            json,
+        } => {
+            let sel_name = resolve_sel_name(selection, &active_selection_name);
+            let entry = papers_core::selection::annotate_entry(&sel_name, &paper, note.as_deref())
+                .unwrap_or_else(|e| exit_err(&e.to_string()));
+            if json {
+                print_json(&serde_json::json!({ "selection": sel_name, "entry": entry }));
+            } else {
+                print!("{}", format::format_selection_note(&entry, &sel_name));
+            }
+        }
+
+        SelectionCommand::Tag {
+            paper,
+            add,
+            remove,
+            selection,
+            json,
+        } => {
+            if add.is_empty() && remove.is_empty() {
+                exit_err("pass --add and/or --remove");
+            }
+            let sel_name = resolve_sel_name(selection, &active_selection_name);
+            let entry = papers_core::selection::tag_entry(&sel_name, &paper, &add, &remove)
+                .unwrap_or_else(|e| exit_err(&e.to_string()));
+            if json {
+                print_json(&serde_json::json!({ "selection": sel_name, "entry": entry }));
+            } else {
+                print!("{}", format::format_selection_tags(&entry, &sel_name));
+            }
+        }
+
+        SelectionCommand::Scope { name, clear, json } => {
+            let scope = if clear { None } else { Some(resolve_sel_name(name, &active_selection_name)) };
+            if let Err(e) = papers_core::selection::set_db_scope(scope.clone()) {
+                exit_err(&e.to_string());
+            }
+            if json {
+                print_json(&serde_json::json!({ "db_scope": scope }));
+            } else {
+                print!("{}", format::format_selection_scope(scope.as_deref()));
+            }
+        }
+
         SelectionCommand::Status { selection, json } => {
             let sel_name = resolve_sel_name(selection, &active_selection_name);
             let sel = load_selection(&sel_name).unwrap_or_else(|e| exit_err(&e.to_string()));
@@ -3410,6 +5168,7 @@ async fn handle_selection_command(cmd: SelectionCommand, client: &OpenAlexClient
                 let sel_name = resolve_sel_name(selection, &active_selection_name);
                 let sel = load_selection(&sel_name).unwrap_or_else(|e| exit_err(&e.to_string()));
                 let store = open_db_store().await;
+                let vault_zotero = vault_zotero().await;
                 let mut added = 0usize;
                 let mut skipped = 0usize;
                 let mut errors = 0usize;
@@ -3431,8 +5190,15 @@ async fn handle_selection_command(cmd: SelectionCommand, client: &OpenAlexClient
 
                     match papers_db::ingest_params_from_cache(&key) {
                         Ok(params) => {
+                            let paper_id = params.paper_id.clone();
                             match papers_db::ingest_paper(&store, params).await {
-                                Ok(_) => { added += 1; }
+                                Ok(_) => {
+                                    added += 1;
+                                    if let Some(z) = &vault_zotero {
+                                        refresh_vault_note(&store, z.as_ref(), &paper_id, &key)
+                                            .await;
+                                    }
+                                }
                                 Err(e) => {
                                     eprintln!("  Error ingesting {key}: {e}");
                                     errors += 1;
@@ -3570,6 +5336,8 @@ async fn handle_selection_command(cmd: SelectionCommand, client: &OpenAlexClient
                             issn: None,
                             isbn: None,
                             work_type: None,
+                            note: None,
+                            tags: Vec::new(),
                         };
                         fill_from_zotero_item(&mut entry, item);
                         new_entries.push(entry);
@@ -3581,6 +5349,7 @@ async fn handle_selection_command(cmd: SelectionCommand, client: &OpenAlexClient
                 }
 
                 // Deduplicate
+                let before = sel.entries.len();
                 let mut added = 0usize;
                 let mut dupes = 0usize;
                 for entry in new_entries {
@@ -3602,6 +5371,7 @@ async fn handle_selection_command(cmd: SelectionCommand, client: &OpenAlexClient
                 if let Err(e) = save_selection(&sel) {
                     exit_err(&e.to_string());
                 }
+                auto_index_abstracts(client, &sel.entries[before..]).await;
 
                 if json {
                     print_json(&serde_json::json!({"added": added, "duplicates": dupes}));
@@ -3609,8 +5379,143 @@ async fn handle_selection_command(cmd: SelectionCommand, client: &OpenAlexClient
                     print!("{}", format::format_selection_collection_add(&sel_name, &coll_name, added, dupes));
                 }
             }
+            SelectionCollectionCommand::Sync { collection, selection, json } => {
+                let sel_name = resolve_sel_name(selection, &active_selection_name);
+                let zotero = match zotero_client().await {
+                    Ok(z) => z,
+                    Err(e) => exit_err(&format!("Zotero unavailable: {e}")),
+                };
+                let report = papers_core::selection_zotero::sync_selection_to_collection(
+                    client,
+                    &zotero,
+                    &sel_name,
+                    collection.as_deref(),
+                )
+                .await
+                .unwrap_or_else(|e| exit_err(&e.to_string()));
+                if json {
+                    print_json(&report);
+                } else {
+                    print!("{}", format::format_selection_collection_sync(&report));
+                }
+            }
+            SelectionCollectionCommand::Import { collection, name, json } => {
+                let zotero = match zotero_client().await {
+                    Ok(z) => z,
+                    Err(e) => exit_err(&format!("Zotero unavailable: {e}")),
+                };
+                let import = papers_core::selection_zotero::selection_from_collection(&zotero, &collection, name.as_deref())
+                    .await
+                    .unwrap_or_else(|e| exit_err(&e.to_string()));
+                if json {
+                    print_json(&import);
+                } else {
+                    print!("{}", format::format_selection_collection_import(&import));
+                }
+            }
         }
 
+        SelectionCommand::Screen { cmd } => match cmd {
+            SelectionScreenCommand::Decide { paper, decision, stage, reason, reviewer, adjudicate, selection, json } => {
+                use papers_core::screening::{ScreeningDecision, ScreeningStage, adjudicate_decision, record_decision};
+                let sel_name = resolve_sel_name(selection, &active_selection_name);
+                let decision: ScreeningDecision = decision.parse().unwrap_or_else(|e: papers_core::screening::ScreeningError| exit_err(&e.to_string()));
+                let stage: ScreeningStage = stage.parse().unwrap_or_else(|e: papers_core::screening::ScreeningError| exit_err(&e.to_string()));
+                let record_fn = if adjudicate { adjudicate_decision } else { record_decision };
+                let (entry, record) = record_fn(&sel_name, &paper, stage, decision, reason, reviewer)
+                    .unwrap_or_else(|e| exit_err(&e.to_string()));
+                if json {
+                    print_json(&serde_json::json!({ "selection": sel_name, "title": entry.title, "record": record }));
+                } else {
+                    let title = entry.title.as_deref().unwrap_or(&paper);
+                    print!("{}", format::format_selection_screen_decide(title, &record));
+                }
+            }
+
+            SelectionScreenCommand::Status { stage, pending, selection, json } => {
+                use papers_core::screening::{ScreeningStage, load_screening, screening_entries, screening_progress};
+                let sel_name = resolve_sel_name(selection, &active_selection_name);
+                let stage: ScreeningStage = stage.parse().unwrap_or_else(|e: papers_core::screening::ScreeningError| exit_err(&e.to_string()));
+                let sel = load_selection(&sel_name).unwrap_or_else(|e| exit_err(&e.to_string()));
+                let state = load_screening(&sel_name).unwrap_or_else(|e| exit_err(&e.to_string()));
+                let statuses = screening_entries(&sel, &state);
+                let progress = screening_progress(&statuses, stage);
+                if json {
+                    let entries: Vec<_> = statuses
+                        .iter()
+                        .filter(|s| s.is_eligible(stage) && !(pending && s.record(stage).is_some()))
+                        .collect();
+                    print_json(&serde_json::json!({ "selection": sel_name, "progress": progress, "entries": entries }));
+                } else {
+                    print!("{}", format::format_selection_screen_status(&sel_name, &progress, &statuses, pending));
+                }
+            }
+
+            SelectionScreenCommand::Export { stage, output, selection, json } => {
+                use papers_core::screening::{ScreeningStage, blinded_csv, blinded_entries, load_screening};
+                let sel_name = resolve_sel_name(selection, &active_selection_name);
+                let stage: ScreeningStage = stage.parse().unwrap_or_else(|e: papers_core::screening::ScreeningError| exit_err(&e.to_string()));
+                let sel = load_selection(&sel_name).unwrap_or_else(|e| exit_err(&e.to_string()));
+                let state = load_screening(&sel_name).unwrap_or_else(|e| exit_err(&e.to_string()));
+                let entries = blinded_entries(&sel, &state, stage);
+                let content = if json {
+                    serde_json::to_string_pretty(&entries).expect("JSON serialization failed") + "\n"
+                } else {
+                    blinded_csv(&entries)
+                };
+                match output {
+                    Some(path) => {
+                        if let Err(e) = std::fs::write(&path, content) {
+                            exit_err(&format!("failed to write {}: {e}", path.display()));
+                        }
+                        eprintln!("Wrote {} entr{} to {}", entries.len(),
+                            if entries.len() == 1 { "y" } else { "ies" }, path.display());
+                    }
+                    None => print!("{content}"),
+                }
+            }
+
+            SelectionScreenCommand::Import { file, reviewer, stage, selection, json } => {
+                use papers_core::screening::{ScreeningStage, import_decisions_csv};
+                let sel_name = resolve_sel_name(selection, &active_selection_name);
+                let stage: ScreeningStage = stage.parse().unwrap_or_else(|e: papers_core::screening::ScreeningError| exit_err(&e.to_string()));
+                let csv = std::fs::read_to_string(&file)
+                    .unwrap_or_else(|e| exit_err(&format!("failed to read {}: {e}", file.display())));
+                let report = import_decisions_csv(&sel_name, &csv, stage, reviewer.as_deref())
+                    .unwrap_or_else(|e| exit_err(&e.to_string()));
+                if json {
+                    print_json(&report);
+                } else {
+                    print!("{}", format::format_selection_screen_import(&sel_name, &report));
+                }
+            }
+
+            SelectionScreenCommand::Conflicts { stage, selection, json } => {
+                use papers_core::screening::{ScreeningStage, load_screening, screening_conflicts};
+                let sel_name = resolve_sel_name(selection, &active_selection_name);
+                let stage: ScreeningStage = stage.parse().unwrap_or_else(|e: papers_core::screening::ScreeningError| exit_err(&e.to_string()));
+                let sel = load_selection(&sel_name).unwrap_or_else(|e| exit_err(&e.to_string()));
+                let state = load_screening(&sel_name).unwrap_or_else(|e| exit_err(&e.to_string()));
+                let conflicts = screening_conflicts(&sel, &state, stage);
+                if json {
+                    print_json(&conflicts);
+                } else {
+                    print!("{}", format::format_selection_screen_conflicts(&conflicts));
+                }
+            }
+
+            SelectionScreenCommand::Prisma { selection, json } => {
+                let sel_name = resolve_sel_name(selection, &active_selection_name);
+                let flow = papers_core::screening::prisma_flow(&sel_name)
+                    .unwrap_or_else(|e| exit_err(&e.to_string()));
+                if json {
+                    print_json(&flow);
+                } else {
+                    print!("{}", format::format_selection_prisma(&flow));
+                }
+            }
+        },
+
         SelectionCommand::Merge { source, selection, json } => {
             let target_name = resolve_sel_name(selection, &active_selection_name);
             let source_name = match resolve_selection(&source) {
@@ -3623,6 +5528,7 @@ async fn handle_selection_command(cmd: SelectionCommand, client: &OpenAlexClient
             let source_sel = load_selection(&source_name).unwrap_or_else(|e| exit_err(&e.to_string()));
             let mut target_sel = load_selection(&target_name).unwrap_or_else(|e| exit_err(&e.to_string()));
 
+            let before = target_sel.entries.len();
             let mut added = 0usize;
             let mut dupes = 0usize;
             for entry in &source_sel.entries {
@@ -3647,6 +5553,7 @@ async fn handle_selection_command(cmd: SelectionCommand, client: &OpenAlexClient
             if let Err(e) = save_selection(&target_sel) {
                 exit_err(&e.to_string());
             }
+            auto_index_abstracts(client, &target_sel.entries[before..]).await;
 
             if json {
                 print_json(&serde_json::json!({"added": added, "duplicates": dupes, "source": source_name, "target": target_name}));
@@ -3655,6 +5562,160 @@ async fn handle_selection_command(cmd: SelectionCommand, client: &OpenAlexClient
             }
         }
 
+        SelectionCommand::Export { format, output, selection, json } => {
+            use papers_core::export::{ExportError, ExportFormat, export_selection};
+            let sel_name = resolve_sel_name(selection, &active_selection_name);
+            let format: ExportFormat = format.parse().unwrap_or_else(|e: ExportError| exit_err(&e.to_string()));
+            let zotero = optional_zotero().await.unwrap_or(None);
+            let export = export_selection(&sel_name, format, client, zotero.as_ref())
+                .await
+                .unwrap_or_else(|e| exit_err(&e.to_string()));
+            let content = if json {
+                serde_json::to_string_pretty(&export).expect("JSON serialization failed") + "\n"
+            } else {
+                match &export.content {
+                    serde_json::Value::String(text) => text.clone(),
+                    other => serde_json::to_string_pretty(other).expect("JSON serialization failed") + "\n",
+                }
+            };
+            match output {
+                Some(path) => {
+                    if let Err(e) = std::fs::write(&path, content) {
+                        exit_err(&format!("failed to write {}: {e}", path.display()));
+                    }
+                    eprintln!("Wrote {} entr{} to {} ({} from Zotero, {} from OpenAlex, {} from selection metadata)",
+                        export.count, if export.count == 1 { "y" } else { "ies" }, path.display(),
+                        export.zotero, export.openalex, export.selection_only);
+                }
+                None => print!("{content}"),
+            }
+        }
+
+        SelectionCommand::Cite { file, bib, output, append, selection, json } => {
+            let latex = match file {
+                Some(path) => std::fs::read_to_string(&path)
+                    .unwrap_or_else(|e| exit_err(&format!("failed to read {}: {e}", path.display()))),
+                None => std::io::read_to_string(std::io::stdin())
+                    .unwrap_or_else(|e| exit_err(&format!("failed to read stdin: {e}"))),
+            };
+            let bibtex = bib.as_ref().map(|path| {
+                std::fs::read_to_string(path)
+                    .unwrap_or_else(|e| exit_err(&format!("failed to read {}: {e}", path.display())))
+            });
+            let sel_name = resolve_sel_name(selection, &active_selection_name);
+            let zotero = optional_zotero().await.unwrap_or(None);
+            let fill = papers_core::latex_cite::fill_selection_citations(&latex, &sel_name, bibtex.as_deref(), client, zotero.as_ref())
+                .await
+                .unwrap_or_else(|e| exit_err(&e.to_string()));
+            if let Some(path) = &output
+                && let Err(e) = std::fs::write(path, &fill.latex)
+            {
+                exit_err(&format!("failed to write {}: {e}", path.display()));
+            }
+            if append && !fill.missing_bibtex.is_empty() {
+                let path = bib.as_ref().expect("--append requires --bib");
+                let mut text = bibtex.unwrap_or_default();
+                if !text.is_empty() && !text.ends_with("\n\n") {
+                    text.push_str(if text.ends_with('\n') { "\n" } else { "\n\n" });
+                }
+                text.push_str(&fill.missing_bibtex);
+                if let Err(e) = std::fs::write(path, text) {
+                    exit_err(&format!("failed to write {}: {e}", path.display()));
+                }
+                eprintln!("Appended {} entr{} to {}", fill.missing_keys.len(),
+                    if fill.missing_keys.len() == 1 { "y" } else { "ies" }, path.display());
+            }
+            if json {
+                print_json(&fill);
+            } else {
+                print!("{}", format::format_latex_cite(&fill, append));
+            }
+        }
+
+        SelectionCommand::Site { out, selection, json } => {
+            use papers_core::site::{SiteFigure, site_papers, write_site};
+            let sel_name = resolve_sel_name(selection, &active_selection_name);
+            let sel = load_selection(&sel_name).unwrap_or_else(|e| exit_err(&e.to_string()));
+            let zotero = optional_zotero().await.unwrap_or(None);
+            let mut papers = site_papers(&sel, client, zotero.as_ref()).await;
+            if let Ok(rag) = papers_db::DbStore::open(&papers_db::DbStore::default_path()).await {
+                for paper in &mut papers {
+                    for id in paper.db_ids() {
+                        let exhibits =
+                            papers_db::query::list_exhibits(&rag, &id).await.unwrap_or_default();
+                        if exhibits.is_empty() {
+                            continue;
+                        }
+                        paper.figures = exhibits
+                            .into_iter()
+                            .map(|e| SiteFigure {
+                                exhibit_type: e.exhibit_type,
+                                caption: e.caption,
+                                image_path: e.image_path.map(Into::into),
+                            })
+                            .collect();
+                        break;
+                    }
+                }
+            }
+            let summary =
+                write_site(&out, &sel.name, &papers).unwrap_or_else(|e| exit_err(&e.to_string()));
+            if json {
+                print_json(&summary);
+            } else {
+                println!(
+                    "Wrote {} paper page{} ({} figures, {} annotations) to {}",
+                    summary.papers,
+                    if summary.papers == 1 { "" } else { "s" },
+                    summary.figures,
+                    summary.annotations,
+                    summary.index.display()
+                );
+            }
+        }
+
+        SelectionCommand::Apc { selection, json } => {
+            let sel_name = resolve_sel_name(selection, &active_selection_name);
+            let sel = load_selection(&sel_name).unwrap_or_else(|e| exit_err(&e.to_string()));
+            let report = papers_core::api::selection_apc_report(client, &sel).await;
+            if json {
+                print_json(&report);
+            } else {
+                print!("{}", format::format_apc_report(&report));
+            }
+        }
+
+        SelectionCommand::Sdg { selection, json } => {
+            let sel_name = resolve_sel_name(selection, &active_selection_name);
+            let sel = load_selection(&sel_name).unwrap_or_else(|e| exit_err(&e.to_string()));
+            let report = papers_core::api::selection_sdg_report(client, &sel).await;
+            if json {
+                print_json(&report);
+            } else {
+                print!("{}", format::format_selection_sdg_report(&report));
+            }
+        }
+
+        SelectionCommand::Graph { format, output, selection, json } => {
+            use papers_core::graph::{GraphError, GraphFormat};
+            let format: GraphFormat = format.parse().unwrap_or_else(|e: GraphError| exit_err(&e.to_string()));
+            let sel_name = resolve_sel_name(selection, &active_selection_name);
+            let sel = load_selection(&sel_name).unwrap_or_else(|e| exit_err(&e.to_string()));
+            let export = papers_core::api::selection_citation_graph(client, &sel, format).await;
+            write_graph_export(&export, output, json);
+        }
+
+        SelectionCommand::Timeline { milestones, selection, json } => {
+            let sel_name = resolve_sel_name(selection, &active_selection_name);
+            let sel = load_selection(&sel_name).unwrap_or_else(|e| exit_err(&e.to_string()));
+            let timeline = papers_core::api::selection_timeline(client, &sel, milestones).await;
+            if json {
+                print_json(&timeline);
+            } else {
+                print!("{}", format::format_timeline(&timeline));
+            }
+        }
+
         SelectionCommand::Rename { new_name, selection, json } => {
             let old_name = resolve_sel_name(selection, &active_selection_name);
             if let Err(e) = validate_name(&new_name) {
@@ -3668,13 +5729,23 @@ async fn handle_selection_command(cmd: SelectionCommand, client: &OpenAlexClient
             if let Err(e) = save_selection(&sel) {
                 exit_err(&e.to_string());
             }
+            if let Err(e) = papers_core::screening::rename_screening(&old_name, &new_name) {
+                exit_err(&e.to_string());
+            }
             if let Err(e) = delete_selection(&old_name) {
                 exit_err(&e.to_string());
             }
-            // Update state if this was the active selection
+            // Update state if this was the active selection or the DB scope
             let mut state = load_state();
-            if state.active.as_deref() == Some(&old_name) {
+            let was_active = state.active.as_deref() == Some(&old_name);
+            let was_db_scope = state.db_scope.as_deref() == Some(&old_name);
+            if was_active {
                 state.active = Some(new_name.clone());
+            }
+            if was_db_scope {
+                state.db_scope = Some(new_name.clone());
+            }
+            if was_active || was_db_scope {
                 let _ = save_state(&state);
             }
 
@@ -0,0 +1,247 @@
+//! Paper abstracts for the abstracts-only semantic index, a lightweight tier
+//! below full-text ingestion: one OpenAlex abstract per selection entry or
+//! watched Zotero item, so papers without an indexed PDF are still found by
+//! meaning.
+//!
+//! This module only gathers the abstracts; embedding and storing them is up
+//! to the caller (`papers_db::ingest::index_abstracts`). With
+//! `auto_index_abstracts` set in `config.json`, the CLI and MCP server do so
+//! for every paper added to a selection.
+
+use std::collections::HashSet;
+
+use papers_openalex::{GetParams, OpenAlexClient, Work};
+use papers_zotero::{ItemListParams, ZoteroClient, ZoteroError};
+use serde::Serialize;
+
+use crate::selection::{SelectionEntry, SelectionError, fill_from_zotero_item, strip_doi_prefix};
+
+#[derive(Debug, thiserror::Error)]
+pub enum AbstractsError {
+    #[error(transparent)]
+    Selection(#[from] SelectionError),
+    #[error("Zotero error: {0}")]
+    Zotero(#[from] ZoteroError),
+}
+
+/// Fields fetched per work when collecting abstracts.
+pub const ABSTRACT_SELECT: &str =
+    "id,display_name,doi,publication_year,authorships,primary_location,abstract_inverted_index";
+
+/// Page size for listing the items of a watched collection.
+const COLLECTION_PAGE_SIZE: u32 = 100;
+
+/// One paper's abstract and the metadata shown with search hits.
+#[derive(Debug, Clone, Serialize)]
+pub struct PaperAbstract {
+    /// The ID the paper has (or would have) in the full-text index: its DOI,
+    /// else its Zotero item key, else its OpenAlex ID.
+    pub paper_id: String,
+    pub openalex_id: Option<String>,
+    pub doi: Option<String>,
+    pub title: String,
+    pub authors: Vec<String>,
+    pub year: Option<u16>,
+    pub venue: Option<String>,
+    pub text: String,
+}
+
+impl PaperAbstract {
+    /// The abstract of `work`, looked up for `entry`; `None` when OpenAlex has
+    /// no abstract for it.
+    pub fn from_work(entry: &SelectionEntry, work: &Work) -> Option<Self> {
+        let text = work.abstract_text.as_deref().map(str::trim).filter(|t| !t.is_empty())?;
+        let openalex_id = work.id.strip_prefix("https://openalex.org/").unwrap_or(&work.id);
+        let doi = entry
+            .doi
+            .as_deref()
+            .or(work.doi.as_deref())
+            .map(|d| strip_doi_prefix(d).to_string());
+        let paper_id = doi
+            .clone()
+            .or_else(|| entry.zotero_key.clone())
+            .unwrap_or_else(|| openalex_id.to_string());
+        Some(Self {
+            paper_id,
+            openalex_id: Some(openalex_id.to_string()),
+            doi,
+            title: work
+                .display_name
+                .clone()
+                .or_else(|| work.title.clone())
+                .or_else(|| entry.title.clone())
+                .unwrap_or_default(),
+            authors: work
+                .authorships
+                .iter()
+                .flatten()
+                .filter_map(|a| a.author.as_ref()?.display_name.clone())
+                .collect(),
+            year: work.publication_year.and_then(|y| u16::try_from(y).ok()),
+            venue: work
+                .primary_location
+                .as_ref()
+                .and_then(|l| l.source.as_ref())
+                .and_then(|s| s.display_name.clone()),
+            text: text.to_string(),
+        })
+    }
+}
+
+/// Abstracts found for a set of entries.
+#[derive(Debug, Default, Serialize)]
+pub struct AbstractBatch {
+    pub abstracts: Vec<PaperAbstract>,
+    /// Entries that could not be looked up in OpenAlex or have no abstract.
+    pub missing: Vec<String>,
+}
+
+/// Fetch the OpenAlex abstract of each entry (by OpenAlex ID, else DOI).
+/// Entries resolving to a paper already collected are skipped, so
+/// overlapping selections and collections yield one abstract per paper.
+pub async fn entry_abstracts(client: &OpenAlexClient, entries: &[SelectionEntry]) -> AbstractBatch {
+    let params = GetParams { select: Some(ABSTRACT_SELECT.to_string()) };
+    let mut batch = AbstractBatch::default();
+    let mut seen = HashSet::new();
+    for entry in entries {
+        let label = || {
+            entry.title.clone()
+                .or_else(|| entry.doi.clone())
+                .or_else(|| entry.zotero_key.clone())
+                .unwrap_or_default()
+        };
+        let Some(id) = entry.openalex_id.as_deref().or(entry.doi.as_deref()) else {
+            batch.missing.push(label());
+            continue;
+        };
+        let found = crate::api::work_get(client, id, &params)
+            .await
+            .ok()
+            .and_then(|work| PaperAbstract::from_work(entry, &work));
+        match found {
+            Some(abs) => {
+                if seen.insert(abs.paper_id.clone()) {
+                    batch.abstracts.push(abs);
+                }
+            }
+            None => batch.missing.push(label()),
+        }
+    }
+    batch
+}
+
+/// Whether papers added to a selection are embedded into the abstracts tier
+/// right away (`auto_index_abstracts` in `config.json`).
+pub fn auto_index_enabled() -> bool {
+    crate::config::PapersConfig::load().is_ok_and(|c| c.auto_index_abstracts)
+}
+
+/// Selection-style entries for the regular items in `collection_keys` (the
+/// collections `db watch` follows), with their Zotero keys set.
+pub async fn collection_entries(
+    zotero: &ZoteroClient,
+    collection_keys: &[String],
+) -> Result<Vec<SelectionEntry>, ZoteroError> {
+    let mut entries = Vec::new();
+    for key in collection_keys {
+        let mut start = 0;
+        loop {
+            let page = zotero
+                .list_collection_top_items(key, &ItemListParams {
+                    limit: Some(COLLECTION_PAGE_SIZE),
+                    start: Some(start),
+                    ..Default::default()
+                })
+                .await?;
+            let fetched = page.items.len();
+            for item in &page.items {
+                if matches!(item.data.item_type.as_str(), "attachment" | "note" | "annotation") {
+                    continue;
+                }
+                let mut entry = SelectionEntry {
+                    zotero_key: Some(item.key.clone()),
+                    openalex_id: None,
+                    doi: None,
+                    title: None,
+                    authors: None,
+                    year: None,
+                    issn: None,
+                    isbn: None,
+                    work_type: None,
+                    note: None,
+                    tags: Vec::new(),
+                };
+                fill_from_zotero_item(&mut entry, item);
+                entries.push(entry);
+            }
+            start += COLLECTION_PAGE_SIZE;
+            if fetched < COLLECTION_PAGE_SIZE as usize
+                || page.total_results.is_none_or(|total| u64::from(start) >= total)
+            {
+                break;
+            }
+        }
+    }
+    Ok(entries)
+}
+
+/// The entries the abstracts tier covers: those of `selection`, or, when it is
+/// `None`, of every selection plus the items of the collections `db watch`
+/// follows (`watch_collections` in `config.json`; needs `zotero`).
+pub async fn abstract_entries(
+    zotero: Option<&ZoteroClient>,
+    selection: Option<&str>,
+) -> Result<Vec<SelectionEntry>, AbstractsError> {
+    if let Some(name) = selection {
+        return Ok(crate::selection::load_selection(name)?.entries);
+    }
+    let mut entries = Vec::new();
+    for name in crate::selection::list_selection_names() {
+        entries.extend(crate::selection::load_selection(&name)?.entries);
+    }
+    let watched = crate::config::PapersConfig::load().map(|c| c.watch_collections).unwrap_or_default();
+    if let (Some(zotero), false) = (zotero, watched.is_empty()) {
+        let mut keys = Vec::new();
+        for input in &watched {
+            keys.push(crate::zotero::resolve_collection_key(zotero, input).await?);
+        }
+        entries.extend(collection_entries(zotero, &keys).await?);
+    }
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_work_prefers_doi_then_zotero_key() {
+        let work: Work = serde_json::from_value(serde_json::json!({
+            "id": "https://openalex.org/W42",
+            "display_name": "Sleep",
+            "doi": "https://doi.org/10.1/abc",
+            "publication_year": 2021,
+            "abstract_inverted_index": {"We": [0], "slept.": [1]},
+        }))
+        .unwrap();
+        let mut entry: SelectionEntry = serde_json::from_value(serde_json::json!({
+            "zotero_key": "ABCD1234", "openalex_id": "W42", "doi": null, "title": null,
+            "authors": null, "year": null, "issn": null, "isbn": null,
+        }))
+        .unwrap();
+        let abs = PaperAbstract::from_work(&entry, &work).unwrap();
+        assert_eq!(abs.paper_id, "10.1/abc");
+        assert_eq!(abs.openalex_id.as_deref(), Some("W42"));
+        assert_eq!(abs.text, "We slept.");
+        assert_eq!(abs.year, Some(2021));
+
+        let mut no_doi = work.clone();
+        no_doi.doi = None;
+        assert_eq!(PaperAbstract::from_work(&entry, &no_doi).unwrap().paper_id, "ABCD1234");
+        entry.zotero_key = None;
+        assert_eq!(PaperAbstract::from_work(&entry, &no_doi).unwrap().paper_id, "W42");
+
+        no_doi.abstract_text = None;
+        assert!(PaperAbstract::from_work(&entry, &no_doi).is_none());
+    }
+}
@@ -0,0 +1,229 @@
+//! Article processing charge (APC) aggregation over a set of works.
+//!
+//! OpenAlex records two APC figures per work: `apc_list` (the venue's list
+//! price) and `apc_paid` (its estimate of what was actually charged, only set
+//! for gold and hybrid OA). Estimated spend uses `apc_paid` when present and
+//! falls back to `apc_list` for gold/hybrid works, so closed, green, bronze,
+//! and diamond works count as zero.
+
+use std::collections::HashMap;
+
+use papers_openalex::Work;
+use serde::Serialize;
+
+/// Fields fetched per work for APC aggregation.
+pub const APC_SELECT: &str =
+    "id,display_name,publication_year,open_access,apc_list,apc_paid,primary_location";
+
+/// Number of sources listed in [`ApcReport::by_source`].
+const TOP_SOURCES: usize = 10;
+
+/// Works and estimated spend for one source or year.
+#[derive(Debug, Clone, Serialize)]
+pub struct ApcGroup {
+    /// Source ID or publication year.
+    pub key: String,
+    /// Source display name (`None` for years).
+    pub name: Option<String>,
+    pub works: usize,
+    /// Works with a non-zero estimated charge.
+    pub charged_works: usize,
+    pub estimated_usd: i64,
+}
+
+/// APC totals for a filtered set of works or a selection.
+#[derive(Debug, Clone, Serialize)]
+pub struct ApcReport {
+    /// Total works matching the filter in OpenAlex (`None` for selections).
+    pub matched: Option<i64>,
+    /// Works analysed.
+    pub works: usize,
+    /// True when fewer works were analysed than matched.
+    pub truncated: bool,
+    /// Works with an `apc_list` / `apc_paid` USD value.
+    pub with_apc_list: usize,
+    pub with_apc_paid: usize,
+    /// Sum of `apc_list.value_usd` / `apc_paid.value_usd` over analysed works.
+    pub apc_list_usd: i64,
+    pub apc_paid_usd: i64,
+    /// Works with a non-zero estimated charge.
+    pub charged_works: usize,
+    /// Estimated total spend in USD over analysed works.
+    pub estimated_spend_usd: i64,
+    /// Mean estimated charge per charged work.
+    pub mean_charge_usd: Option<f64>,
+    /// `estimated_spend_usd` scaled to `matched` when the result was truncated.
+    pub extrapolated_spend_usd: Option<i64>,
+    /// Sources with the highest estimated spend.
+    pub by_source: Vec<ApcGroup>,
+    /// Estimated spend per publication year, oldest first.
+    pub by_year: Vec<ApcGroup>,
+    /// Selection entries that could not be looked up in OpenAlex.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub unresolved: Vec<String>,
+}
+
+/// Estimated charge for one work in USD (see module docs).
+pub fn estimated_charge_usd(work: &Work) -> i64 {
+    if let Some(paid) = work.apc_paid.as_ref().and_then(|a| a.value_usd) {
+        return paid;
+    }
+    let oa_status = work.open_access.as_ref().and_then(|oa| oa.oa_status.as_deref());
+    match oa_status {
+        Some("gold" | "hybrid") => work.apc_list.as_ref().and_then(|a| a.value_usd).unwrap_or(0),
+        _ => 0,
+    }
+}
+
+fn add_to_group(group: &mut ApcGroup, charge: i64) {
+    group.works += 1;
+    group.estimated_usd += charge;
+    if charge > 0 {
+        group.charged_works += 1;
+    }
+}
+
+/// Aggregate APC figures over `works`. `matched` is the total number of works
+/// in scope, used to extrapolate when only a prefix was fetched.
+pub fn apc_report(works: &[Work], matched: Option<i64>) -> ApcReport {
+    let mut with_apc_list = 0;
+    let mut with_apc_paid = 0;
+    let mut apc_list_usd = 0;
+    let mut apc_paid_usd = 0;
+    let mut charged_works = 0;
+    let mut estimated_spend_usd = 0;
+    let mut sources: HashMap<String, ApcGroup> = HashMap::new();
+    let mut years: HashMap<i32, ApcGroup> = HashMap::new();
+
+    for work in works {
+        if let Some(v) = work.apc_list.as_ref().and_then(|a| a.value_usd) {
+            with_apc_list += 1;
+            apc_list_usd += v;
+        }
+        if let Some(v) = work.apc_paid.as_ref().and_then(|a| a.value_usd) {
+            with_apc_paid += 1;
+            apc_paid_usd += v;
+        }
+        let charge = estimated_charge_usd(work);
+        estimated_spend_usd += charge;
+        if charge > 0 {
+            charged_works += 1;
+        }
+
+        let source = work.primary_location.as_ref().and_then(|l| l.source.as_ref());
+        if let Some(id) = source.and_then(|s| s.id.clone()) {
+            let group = sources.entry(id.clone()).or_insert_with(|| ApcGroup {
+                key: id,
+                name: source.and_then(|s| s.display_name.clone()),
+                works: 0,
+                charged_works: 0,
+                estimated_usd: 0,
+            });
+            add_to_group(group, charge);
+        }
+        if let Some(year) = work.publication_year {
+            let group = years.entry(year).or_insert_with(|| ApcGroup {
+                key: year.to_string(),
+                name: None,
+                works: 0,
+                charged_works: 0,
+                estimated_usd: 0,
+            });
+            add_to_group(group, charge);
+        }
+    }
+
+    let mut by_source: Vec<ApcGroup> = sources.into_values().collect();
+    by_source.sort_by(|a, b| b.estimated_usd.cmp(&a.estimated_usd).then(b.works.cmp(&a.works)));
+    by_source.truncate(TOP_SOURCES);
+    let mut by_year: Vec<(i32, ApcGroup)> = years.into_iter().collect();
+    by_year.sort_by_key(|(y, _)| *y);
+
+    let truncated = matched.is_some_and(|m| m > works.len() as i64);
+    let extrapolated_spend_usd = match matched {
+        Some(m) if truncated && !works.is_empty() => {
+            Some((estimated_spend_usd as f64 * m as f64 / works.len() as f64).round() as i64)
+        }
+        _ => None,
+    };
+
+    ApcReport {
+        matched,
+        works: works.len(),
+        truncated,
+        with_apc_list,
+        with_apc_paid,
+        apc_list_usd,
+        apc_paid_usd,
+        charged_works,
+        estimated_spend_usd,
+        mean_charge_usd: (charged_works > 0)
+            .then(|| estimated_spend_usd as f64 / charged_works as f64),
+        extrapolated_spend_usd,
+        by_source,
+        by_year: by_year.into_iter().map(|(_, g)| g).collect(),
+        unresolved: Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn work(id: &str, year: i32, oa_status: &str, list: Option<i64>, paid: Option<i64>) -> Work {
+        let apc = |v: Option<i64>| {
+            v.map(|v| serde_json::json!({"value": v, "currency": "USD", "value_usd": v}))
+        };
+        serde_json::from_value(serde_json::json!({
+            "id": format!("https://openalex.org/{id}"),
+            "publication_year": year,
+            "open_access": {"is_oa": oa_status != "closed", "oa_status": oa_status},
+            "apc_list": apc(list),
+            "apc_paid": apc(paid),
+            "primary_location": {"source": {"id": "https://openalex.org/S1", "display_name": "J"}},
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn estimated_charge_prefers_paid_and_ignores_non_apc_oa() {
+        assert_eq!(estimated_charge_usd(&work("W1", 2020, "gold", Some(2000), Some(1800))), 1800);
+        assert_eq!(estimated_charge_usd(&work("W2", 2020, "hybrid", Some(3000), None)), 3000);
+        assert_eq!(estimated_charge_usd(&work("W3", 2020, "green", Some(3000), None)), 0);
+        assert_eq!(estimated_charge_usd(&work("W4", 2020, "closed", Some(3000), None)), 0);
+        assert_eq!(estimated_charge_usd(&work("W5", 2020, "diamond", None, None)), 0);
+    }
+
+    #[test]
+    fn report_totals_groups_and_extrapolation() {
+        let works = vec![
+            work("W1", 2021, "gold", Some(2000), Some(1800)),
+            work("W2", 2020, "hybrid", Some(3000), None),
+            work("W3", 2020, "closed", Some(3000), None),
+        ];
+        let report = apc_report(&works, Some(6));
+        assert_eq!(report.works, 3);
+        assert!(report.truncated);
+        assert_eq!(report.with_apc_list, 3);
+        assert_eq!(report.with_apc_paid, 1);
+        assert_eq!(report.apc_list_usd, 8000);
+        assert_eq!(report.apc_paid_usd, 1800);
+        assert_eq!(report.charged_works, 2);
+        assert_eq!(report.estimated_spend_usd, 4800);
+        assert_eq!(report.mean_charge_usd, Some(2400.0));
+        assert_eq!(report.extrapolated_spend_usd, Some(9600));
+        assert_eq!(report.by_source.len(), 1);
+        assert_eq!(report.by_source[0].works, 3);
+        let years: Vec<_> =
+            report.by_year.iter().map(|g| (g.key.as_str(), g.estimated_usd)).collect();
+        assert_eq!(years, vec![("2020", 3000), ("2021", 1800)]);
+    }
+
+    #[test]
+    fn complete_report_is_not_extrapolated() {
+        let report = apc_report(&[work("W1", 2021, "gold", Some(2000), None)], Some(1));
+        assert!(!report.truncated);
+        assert_eq!(report.extrapolated_spend_usd, None);
+        assert_eq!(apc_report(&[], None).mean_charge_usd, None);
+    }
+}
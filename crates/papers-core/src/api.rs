@@ -1,6 +1,6 @@
 use papers_openalex::{
-    Author, AutocompleteResponse, Domain, Field, FindWorksParams, FindWorksResponse, Funder,
-    GetParams, Institution, OpenAlexClient, OpenAlexError, Publisher, Source, Subfield,
+    Author, AutocompleteResponse, CacheStats, Domain, Field, FindWorksParams, FindWorksResponse,
+    Funder, GetParams, Institution, OpenAlexClient, OpenAlexError, Publisher, Source, Subfield,
     Topic, Work,
 };
 use papers_zotero::ZoteroClient;
@@ -17,6 +17,7 @@ use crate::summary::{
     PublisherSummary, SlimListResponse, SourceSummary, SubfieldSummary, TopicSummary, WorkSummary,
     summary_list_result,
 };
+use crate::util::short_id;
 
 // ── List ─────────────────────────────────────────────────────────────────
 
@@ -178,18 +179,48 @@ entity_get_fn!(subfield_get, Subfield, get_subfield, "subfields");
 pub struct WorkGetResponse {
     pub work: Work,
     pub in_zotero: bool,
+    /// 1.0 when matched by DOI; lower for a title-and-year match, which
+    /// agents may want to verify.
+    pub zotero_match_confidence: Option<f64>,
     pub zotero: Option<crate::text::ZoteroItemInfo>,
+    /// True when OpenAlex didn't know the DOI and `work` was built from
+    /// Crossref metadata (see [`crate::crossref`]).
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub from_crossref: bool,
+    /// TLDR, influential citations and recommendations; only filled by
+    /// [`work_enrich`], and `None` there if Semantic Scholar doesn't know the work.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub semantic_scholar: Option<crate::semantic_scholar::S2Enrichment>,
+    /// Why enrichment failed (e.g. rate limiting); the OpenAlex data is still returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub semantic_scholar_error: Option<String>,
 }
 
 /// Get a work by ID and check if it's in the Zotero library (if Zotero is configured).
+///
+/// When `id` is a DOI OpenAlex returns 404 for and `crossref` is given, the
+/// work is built from Crossref metadata instead.
 pub async fn work_get_response(
     client: &OpenAlexClient,
     zotero: Option<&ZoteroClient>,
+    crossref: Option<&crate::crossref::CrossrefClient>,
     id: &str,
     params: &GetParams,
 ) -> Result<WorkGetResponse, FilterError> {
     let t0 = std::time::Instant::now();
-    let work = work_get(client, id, params).await?;
+    let (work, from_crossref) = match work_get(client, id, params).await {
+        Ok(work) => (work, false),
+        Err(e) => {
+            let not_found = matches!(e, FilterError::Api(OpenAlexError::Api { status: 404, .. }));
+            match crossref {
+                Some(cr) if not_found && crate::selection::looks_like_doi(id) => match cr.get_work(id).await {
+                    Ok(record) => (crate::crossref::work_from_crossref(&record), true),
+                    Err(_) => return Err(e),
+                },
+                _ => return Err(e),
+            }
+        }
+    };
     eprintln!("[timing] openalex work_get: {:?}", t0.elapsed());
 
     let zotero_info = if let Some(z) = zotero {
@@ -203,11 +234,1529 @@ pub async fn work_get_response(
     eprintln!("[timing] total: {:?}", t0.elapsed());
     Ok(WorkGetResponse {
         in_zotero: zotero_info.is_some(),
+        zotero_match_confidence: zotero_info.as_ref().map(|z| z.confidence),
         work,
         zotero: zotero_info,
+        from_crossref,
+        semantic_scholar: None,
+        semantic_scholar_error: None,
+    })
+}
+
+/// [`work_get_response`] plus Semantic Scholar's TLDR, influential citation
+/// count and up to `recommendations` similar papers.
+///
+/// Semantic Scholar failures don't fail the call: the OpenAlex response is
+/// returned with `semantic_scholar_error` set instead.
+pub async fn work_enrich(
+    client: &OpenAlexClient,
+    zotero: Option<&ZoteroClient>,
+    crossref: Option<&crate::crossref::CrossrefClient>,
+    s2: &crate::semantic_scholar::SemanticScholarClient,
+    id: &str,
+    recommendations: u32,
+) -> Result<WorkGetResponse, FilterError> {
+    let mut response = work_get_response(client, zotero, crossref, id, &GetParams::default()).await?;
+    match crate::semantic_scholar::enrich_work(s2, &response.work, recommendations).await {
+        Ok(enrichment) => response.semantic_scholar = enrichment,
+        Err(e) => response.semantic_scholar_error = Some(e.to_string()),
+    }
+    Ok(response)
+}
+
+// ── Metadata quality ─────────────────────────────────────────────────────
+
+/// Fetch the full record for `id` and check it for common metadata problems.
+pub async fn work_quality(
+    client: &OpenAlexClient,
+    id: &str,
+) -> Result<crate::quality::WorkQualityReport, FilterError> {
+    let work = work_get(client, id, &GetParams::default()).await?;
+    Ok(crate::quality::check_work(&work))
+}
+
+// ── Published version ────────────────────────────────────────────────────
+
+/// Location DOIs looked up per request.
+const VERSION_MAX_LOCATION_DOIS: usize = 5;
+
+/// Title-search results scored per request.
+const VERSION_TITLE_CANDIDATES: u32 = 10;
+
+/// Find the published version of a preprint, or the preprint of a published
+/// work. See [`crate::versions`] for the evidence used.
+pub async fn work_published_version(
+    client: &OpenAlexClient,
+    id: &str,
+) -> Result<crate::versions::PublishedVersionReport, FilterError> {
+    use crate::versions::{self, VersionRole};
+
+    let params = GetParams { select: Some(versions::VERSION_SELECT.to_string()) };
+    let work = work_get(client, id, &params).await?;
+    let mut candidates = Vec::new();
+
+    for doi in versions::location_dois(&work).into_iter().take(VERSION_MAX_LOCATION_DOIS) {
+        if let Ok(other) = work_get(client, &doi, &params).await
+            && let Some(m) = versions::score_candidate(&work, &other, true)
+        {
+            candidates.push(m);
+        }
+    }
+
+    let title = work.display_name.as_deref().or(work.title.as_deref()).unwrap_or_default();
+    // Filter values can't contain `,` or `|`; keep words only.
+    let query: Vec<&str> = title.split(|c: char| !c.is_alphanumeric()).filter(|t| !t.is_empty()).collect();
+    if !query.is_empty() {
+        let type_filter = match versions::work_role(&work) {
+            VersionRole::Preprint => "type:!preprint",
+            VersionRole::Published => "type:preprint",
+        };
+        let list_params = papers_openalex::ListParams {
+            filter: Some(format!("title.search:{},{type_filter}", query.join(" "))),
+            per_page: Some(VERSION_TITLE_CANDIDATES),
+            select: Some(versions::VERSION_SELECT.to_string()),
+            ..Default::default()
+        };
+        let resp = client.list_works(&list_params).await?;
+        candidates.extend(
+            resp.results
+                .iter()
+                .filter_map(|other| versions::score_candidate(&work, other, false)),
+        );
+    }
+
+    Ok(versions::version_report(&work, candidates))
+}
+
+// ── Work stats ───────────────────────────────────────────────────────────
+
+/// Count the works matching `params` (filters, aliases, and search; paging,
+/// `select`, and `group_by` are ignored) per group of `by`, in one request.
+///
+/// Non-year dimensions keep the `top` largest groups; years are all kept.
+pub async fn work_stats(
+    client: &OpenAlexClient,
+    params: &WorkListParams,
+    by: crate::stats::StatsDimension,
+    top: usize,
+) -> Result<crate::stats::WorkStats, FilterError> {
+    let (alias_values, list_params) = params.into_aliases_and_list_params();
+    let filter =
+        resolve_filters(client, WORK_ALIASES, &alias_values, list_params.filter.as_deref()).await?;
+    let request = papers_openalex::ListParams {
+        filter: filter.clone(),
+        search: list_params.search,
+        group_by: Some(by.group_by_field().to_string()),
+        ..Default::default()
+    };
+    let resp = client.list_works(&request).await?;
+    Ok(crate::stats::WorkStats {
+        by,
+        group_by: by.group_by_field().to_string(),
+        filter,
+        total: resp.meta.count,
+        groups_count: resp.meta.groups_count,
+        series: crate::stats::build_series(by, resp.group_by, top),
     })
 }
 
+// ── APC report ───────────────────────────────────────────────────────────
+
+/// Maximum works fetched by `work_apc_report`.
+pub const APC_REPORT_MAX_WORKS: u32 = 10_000;
+
+/// Estimate APC spend over the works matching `params` (filters, aliases, and
+/// search; paging and `select` are ignored).
+///
+/// Pages through results with a cursor, 200 at a time, until `max_works`
+/// (clamped to [`APC_REPORT_MAX_WORKS`]) have been fetched. When more works
+/// match, the report is marked truncated and includes an extrapolated total.
+pub async fn work_apc_report(
+    client: &OpenAlexClient,
+    params: &WorkListParams,
+    max_works: u32,
+) -> Result<crate::apc::ApcReport, FilterError> {
+    let max_works = max_works.clamp(1, APC_REPORT_MAX_WORKS) as usize;
+    let (works, matched) =
+        fetch_matching_works(client, params, Some(crate::apc::APC_SELECT), max_works).await?;
+    Ok(crate::apc::apc_report(&works, Some(matched)))
+}
+
+/// Page through the works matching `params` (filters, aliases, search, and
+/// sort) with a cursor, 200 at a time, until `max_works` have been fetched.
+/// Returns the works and the total number matching.
+async fn fetch_matching_works(
+    client: &OpenAlexClient,
+    params: &WorkListParams,
+    select: Option<&str>,
+    max_works: usize,
+) -> Result<(Vec<Work>, i64), FilterError> {
+    let (alias_values, list_params) = params.into_aliases_and_list_params();
+    let filter =
+        resolve_filters(client, WORK_ALIASES, &alias_values, list_params.filter.as_deref()).await?;
+
+    let mut works = Vec::new();
+    let mut matched = 0;
+    let mut cursor = Some("*".to_string());
+    while let Some(c) = cursor.take() {
+        let page = papers_openalex::ListParams {
+            filter: filter.clone(),
+            search: list_params.search.clone(),
+            sort: list_params.sort.clone(),
+            per_page: Some((max_works - works.len()).min(200) as u32),
+            cursor: Some(c),
+            select: select.map(str::to_string),
+            ..Default::default()
+        };
+        let resp = client.list_works(&page).await?;
+        matched = resp.meta.count;
+        let done = resp.results.is_empty();
+        works.extend(resp.results);
+        if !done && works.len() < max_works {
+            cursor = resp.meta.next_cursor;
+        }
+    }
+    works.truncate(max_works);
+    Ok((works, matched))
+}
+
+// ── Bulk selection add ───────────────────────────────────────────────────
+
+/// Maximum works `selection_add_query` adds in one call.
+pub const SELECTION_ADD_QUERY_MAX: u32 = 500;
+
+/// Add the works matching `params` (as for `work_list`; paging and `select`
+/// are ignored) to `selection`, taking the first `limit` (clamped to
+/// [`SELECTION_ADD_QUERY_MAX`]) in the query's sort order.
+///
+/// Works already in the selection are skipped and listed as duplicates; see
+/// [`add_works`](crate::selection::add_works). The caller saves the selection.
+pub async fn selection_add_query(
+    client: &OpenAlexClient,
+    selection: &mut crate::selection::Selection,
+    params: &WorkListParams,
+    limit: u32,
+) -> Result<crate::selection::BulkAddSummary, FilterError> {
+    let limit = limit.clamp(1, SELECTION_ADD_QUERY_MAX) as usize;
+    let (works, matched) = fetch_matching_works(client, params, None, limit).await?;
+    let mut summary = crate::selection::add_works(selection, &works);
+    summary.matched = matched.max(0) as u64;
+    Ok(summary)
+}
+
+/// Outcome of [`selection_add_references`].
+#[derive(Debug, Serialize)]
+pub struct ReferenceAddSummary {
+    /// OpenAlex ID of the citing work.
+    pub root: String,
+    /// Number of works it cites; `matched` of them pass the filters.
+    pub references: usize,
+    #[serde(flatten)]
+    pub summary: crate::selection::BulkAddSummary,
+}
+
+/// Add the works cited by `id` (its `referenced_works`) to `selection`: one
+/// backward-snowballing step. Filters, aliases and `search` in `params` narrow
+/// the references (e.g. `year: ">2015"`, `topic`); paging, `sort` and `select`
+/// are ignored. References are fetched in batches of [`RELATED_FILTER_CHUNK`]
+/// (`ids.openalex:R1|R2|…` plus the filters).
+///
+/// Works already in the selection are skipped and listed as duplicates; see
+/// [`add_works`](crate::selection::add_works). The caller saves the selection.
+pub async fn selection_add_references(
+    client: &OpenAlexClient,
+    selection: &mut crate::selection::Selection,
+    id: &str,
+    params: &WorkListParams,
+) -> Result<ReferenceAddSummary, FilterError> {
+    let get = GetParams { select: Some("id,referenced_works".to_string()) };
+    let root = work_get(client, id, &get).await?;
+    let mut refs = short_ids(&root.referenced_works);
+    refs.sort();
+    refs.dedup();
+
+    let (alias_values, list_params) = params.into_aliases_and_list_params();
+    let filter =
+        resolve_filters(client, WORK_ALIASES, &alias_values, list_params.filter.as_deref()).await?;
+    let mut works = Vec::new();
+    for chunk in refs.chunks(RELATED_FILTER_CHUNK) {
+        let ids = format!("ids.openalex:{}", chunk.join("|"));
+        let chunk_params = papers_openalex::ListParams {
+            filter: Some(match &filter {
+                Some(filter) => format!("{filter},{ids}"),
+                None => ids,
+            }),
+            search: list_params.search.clone(),
+            per_page: Some(chunk.len() as u32),
+            ..Default::default()
+        };
+        works.extend(client.list_works(&chunk_params).await?.results);
+    }
+    Ok(ReferenceAddSummary {
+        root: short_id(&root.id).to_string(),
+        references: refs.len(),
+        summary: crate::selection::add_works(selection, &works),
+    })
+}
+
+/// Outcome of [`selection_add_citers`].
+#[derive(Debug, Serialize)]
+pub struct CiterAddSummary {
+    /// OpenAlex ID of the cited work.
+    pub root: String,
+    /// Its total citation count in OpenAlex.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cited_by_count: Option<i64>,
+    #[serde(flatten)]
+    pub summary: crate::selection::BulkAddSummary,
+}
+
+/// Add the works citing `id` (`cites:` filter) to `selection`: one forward
+/// snowballing step. `min_citations` keeps only citers cited at least that
+/// often; filters, aliases (e.g. `year: "2018-2024"`) and `search` in `params`
+/// narrow them further. The first `limit` (clamped to
+/// [`SELECTION_ADD_QUERY_MAX`]) are taken in `params.sort` order, most cited
+/// first by default; `matched` counts all citers passing the filters.
+///
+/// Works already in the selection are skipped and listed as duplicates; see
+/// [`add_works`](crate::selection::add_works). The caller saves the selection.
+pub async fn selection_add_citers(
+    client: &OpenAlexClient,
+    selection: &mut crate::selection::Selection,
+    id: &str,
+    params: &WorkListParams,
+    min_citations: Option<u32>,
+    limit: u32,
+) -> Result<CiterAddSummary, FilterError> {
+    let limit = limit.clamp(1, SELECTION_ADD_QUERY_MAX) as usize;
+    let get = GetParams { select: Some("id,cited_by_count".to_string()) };
+    let root = work_get(client, id, &get).await?;
+    let root_id = short_id(&root.id).to_string();
+
+    let mut conditions: Vec<String> = params.filter.iter().filter(|f| !f.is_empty()).cloned().collect();
+    conditions.push(format!("cites:{root_id}"));
+    // OpenAlex has no `>=`, so "at least n" is "more than n - 1".
+    if let Some(n) = min_citations.filter(|n| *n > 0) {
+        conditions.push(format!("cited_by_count:>{}", n - 1));
+    }
+    let params = WorkListParams {
+        filter: Some(conditions.join(",")),
+        sort: params.sort.clone().or_else(|| Some("cited_by_count:desc".to_string())),
+        ..params.clone()
+    };
+    let (works, matched) = fetch_matching_works(client, &params, None, limit).await?;
+    let mut summary = crate::selection::add_works(selection, &works);
+    summary.matched = matched.max(0) as u64;
+    Ok(CiterAddSummary { root: root_id, cited_by_count: root.cited_by_count, summary })
+}
+
+/// Estimate APC spend over a selection's entries, fetching each by OpenAlex ID
+/// (falling back to DOI). Entries that cannot be found are listed in
+/// `unresolved`.
+pub async fn selection_apc_report(
+    client: &OpenAlexClient,
+    selection: &crate::selection::Selection,
+) -> crate::apc::ApcReport {
+    let params = GetParams { select: Some(crate::apc::APC_SELECT.to_string()) };
+    let (works, unresolved) = fetch_selection_works(client, selection, &params).await;
+    let mut report = crate::apc::apc_report(&works, None);
+    report.unresolved = unresolved;
+    report
+}
+
+/// Fetch the OpenAlex record of each selection entry (by OpenAlex ID, else
+/// DOI). Returns the works found and a label for each entry that was not.
+async fn fetch_selection_works(
+    client: &OpenAlexClient,
+    selection: &crate::selection::Selection,
+    params: &GetParams,
+) -> (Vec<Work>, Vec<String>) {
+    let mut works = Vec::new();
+    let mut unresolved = Vec::new();
+    for entry in &selection.entries {
+        let label = || {
+            entry.title.clone()
+                .or_else(|| entry.doi.clone())
+                .or_else(|| entry.zotero_key.clone())
+                .unwrap_or_default()
+        };
+        let Some(id) = entry.openalex_id.as_deref().or(entry.doi.as_deref()) else {
+            unresolved.push(label());
+            continue;
+        };
+        match work_get(client, id, params).await {
+            Ok(work) => works.push(work),
+            Err(_) => unresolved.push(label()),
+        }
+    }
+    (works, unresolved)
+}
+
+// ── SDG report ───────────────────────────────────────────────────────────
+
+/// Fetch each selection entry's OpenAlex record (by OpenAlex ID, else DOI) and
+/// aggregate its Sustainable Development Goal tags.
+///
+/// Entries with neither identifier, or whose lookup fails, are listed in
+/// `unresolved` rather than failing the whole report.
+pub async fn selection_sdg_report(
+    client: &OpenAlexClient,
+    selection: &crate::selection::Selection,
+) -> crate::sdg::SdgReport {
+    let params = GetParams {
+        select: Some("id,display_name,sustainable_development_goals".to_string()),
+    };
+    let (works, unresolved) = fetch_selection_works(client, selection, &params).await;
+    crate::sdg::sdg_report(&selection.name, selection.entries.len(), &works, unresolved)
+}
+
+// ── Citation graph export ────────────────────────────────────────────────
+
+/// Fetch each selection entry's OpenAlex record (by OpenAlex ID, else DOI) and
+/// serialize the citation graph among them, built from `referenced_works`.
+///
+/// Entries with neither identifier, or whose lookup fails, are listed in
+/// `unresolved` and left out of the graph.
+pub async fn selection_citation_graph(
+    client: &OpenAlexClient,
+    selection: &crate::selection::Selection,
+    format: crate::graph::GraphFormat,
+) -> crate::graph::GraphExport {
+    let params = GetParams { select: Some(crate::graph::GRAPH_SELECT.to_string()) };
+    let (works, unresolved) = fetch_selection_works(client, selection, &params).await;
+    let graph = crate::graph::graph_from_works(&works);
+    crate::graph::GraphExport::new(&selection.name, format, &graph, unresolved)
+}
+
+// ── Timeline ─────────────────────────────────────────────────────────────
+
+/// Fetch each selection entry's OpenAlex record (by OpenAlex ID, else DOI) and
+/// bucket the selection by publication year, flagging the
+/// `milestones_per_year` most-cited papers of each year.
+///
+/// Entries whose lookup fails fall back to their stored title, authors and
+/// year; those without a stored year are listed in `unresolved`.
+pub async fn selection_timeline(
+    client: &OpenAlexClient,
+    selection: &crate::selection::Selection,
+    milestones_per_year: usize,
+) -> crate::timeline::Timeline {
+    use crate::timeline::TimelinePaper;
+
+    let params = GetParams { select: Some(crate::timeline::TIMELINE_SELECT.to_string()) };
+    let mut papers = Vec::new();
+    let mut unresolved = Vec::new();
+    for entry in &selection.entries {
+        let work = match entry.openalex_id.as_deref().or(entry.doi.as_deref()) {
+            Some(id) => work_get(client, id, &params).await.ok(),
+            None => None,
+        };
+        match work {
+            Some(work) => papers.push(TimelinePaper::from_work(&work)),
+            None if entry.year.is_some() => papers.push(TimelinePaper::from_entry(entry)),
+            None => unresolved.push(
+                entry.title.clone()
+                    .or_else(|| entry.doi.clone())
+                    .or_else(|| entry.zotero_key.clone())
+                    .unwrap_or_default(),
+            ),
+        }
+    }
+    crate::timeline::build_timeline(&selection.name, papers, milestones_per_year, unresolved)
+}
+
+// ── Digest ───────────────────────────────────────────────────────────────
+
+/// Build the digest of the last `days` days (clamped to
+/// `1..=DIGEST_MAX_DAYS`, see [`crate::digest`]): works matching each search
+/// query in `watches`, works citing the papers of `selections`, and items
+/// added to `zotero` when a client is given.
+pub async fn digest_generate(
+    client: &OpenAlexClient,
+    zotero: Option<&ZoteroClient>,
+    selections: &[crate::selection::Selection],
+    watches: &[String],
+    days: u32,
+) -> crate::digest::Digest {
+    use crate::digest::{
+        DIGEST_CITERS_MAX, DIGEST_MAX_DAYS, DIGEST_SELECT, DIGEST_WATCH_LIMIT, Digest, DigestWork,
+        WatchHits, group_citations, window_start,
+    };
+
+    let days = days.clamp(1, DIGEST_MAX_DAYS);
+    let since = window_start(days);
+    let published = format!("from_publication_date:{since}");
+    let mut warnings = Vec::new();
+
+    let mut watch_hits = Vec::new();
+    for query in watches.iter().map(|q| q.trim()).filter(|q| !q.is_empty()) {
+        let params = WorkListParams {
+            search: Some(query.to_string()),
+            filter: Some(published.clone()),
+            sort: Some("publication_date:desc".to_string()),
+            ..Default::default()
+        };
+        match fetch_matching_works(client, &params, Some(DIGEST_SELECT), DIGEST_WATCH_LIMIT).await {
+            Ok((works, matched)) => watch_hits.push(WatchHits {
+                query: query.to_string(),
+                matched,
+                works: works.iter().map(DigestWork::from_work).collect(),
+            }),
+            Err(e) => warnings.push(format!("watch \"{query}\": {e}")),
+        }
+    }
+
+    let mut tracked: Vec<(String, Option<String>)> = Vec::new();
+    for entry in selections.iter().flat_map(|s| &s.entries) {
+        let Some(id) = entry.openalex_id.as_deref().map(short_id) else { continue };
+        if !tracked.iter().any(|(t, _)| t == id) {
+            tracked.push((id.to_string(), entry.title.clone()));
+        }
+    }
+    let mut citers: Vec<Work> = Vec::new();
+    let mut seen = std::collections::HashSet::new();
+    for chunk in tracked.chunks(RELATED_FILTER_CHUNK) {
+        let ids: Vec<&str> = chunk.iter().map(|(id, _)| id.as_str()).collect();
+        let params = WorkListParams {
+            filter: Some(format!("cites:{},{published}", ids.join("|"))),
+            sort: Some("publication_date:desc".to_string()),
+            ..Default::default()
+        };
+        let max = DIGEST_CITERS_MAX.saturating_sub(citers.len());
+        if max == 0 {
+            break;
+        }
+        match fetch_matching_works(client, &params, Some(DIGEST_SELECT), max).await {
+            // A work citing papers from two chunks comes back twice.
+            Ok((works, _)) => citers.extend(works.into_iter().filter(|w| seen.insert(w.id.clone()))),
+            Err(e) => warnings.push(format!("citations: {e}")),
+        }
+    }
+    let cited = group_citations(&tracked, &citers);
+
+    let added = match zotero {
+        Some(zotero) => match recent_zotero_items(zotero, &since).await {
+            Ok(items) => items,
+            Err(e) => {
+                warnings.push(format!("Zotero: {e}"));
+                Vec::new()
+            }
+        },
+        None => {
+            warnings.push("Zotero: unavailable, newly added items skipped".to_string());
+            Vec::new()
+        }
+    };
+
+    let names = selections.iter().map(|s| s.name.clone()).collect();
+    Digest::new(since, days, watch_hits, names, cited, added, warnings)
+}
+
+// ── Review context ───────────────────────────────────────────────────────
+
+/// Related-work landscape for a submission, from its title or abstract
+/// (`text`), see [`crate::review`]. `references` are the submission's cited
+/// works as DOIs or OpenAlex IDs; without them every frequently cited work
+/// is a possible missed citation.
+pub async fn review_context(
+    client: &OpenAlexClient,
+    text: &str,
+    references: &[String],
+) -> crate::review::ReviewContext {
+    use crate::review::{
+        RECENT_YEARS, REVIEW_SEARCH_LIMIT, REVIEW_SECTION_LIMIT, REVIEW_SELECT, References, ReviewContext, fuse,
+        most_referenced, search_terms,
+    };
+
+    let mut warnings = Vec::new();
+    let find = FindWorksParams { query: text.to_string(), count: Some(REVIEW_SEARCH_LIMIT as u32), filter: None };
+    let found = match work_find(client, &find).await {
+        Ok(resp) => resp
+            .results
+            .into_iter()
+            .filter_map(|r| Some((serde_json::from_value::<Work>(r.work).ok()?, r.score)))
+            .collect(),
+        Err(e) => {
+            warnings.push(format!("semantic search (work_find): {e}"));
+            Vec::new()
+        }
+    };
+
+    let terms = search_terms(text);
+    let year: i32 = crate::text::iso_now()[..4].parse().unwrap_or(2026);
+    let recent_since = year - RECENT_YEARS + 1;
+    let mut searches = Vec::new();
+    for (label, filter) in [("search", None), ("recent search", Some(format!("from_publication_date:{recent_since}-01-01")))] {
+        if terms.is_empty() {
+            break;
+        }
+        let params = WorkListParams { search: Some(terms.clone()), filter, ..Default::default() };
+        match fetch_matching_works(client, &params, Some(REVIEW_SELECT), REVIEW_SEARCH_LIMIT).await {
+            Ok((works, _)) => searches.push(works),
+            Err(e) => warnings.push(format!("{label}: {e}")),
+        }
+    }
+
+    let own_title = text.trim().lines().next().unwrap_or("");
+    let ranked = fuse(found, searches, own_title);
+    let references = References::new(references);
+    let cited = most_referenced(&ranked, &references, REVIEW_SECTION_LIMIT);
+    let ids: Vec<String> = cited.iter().map(|(id, _)| id.clone()).collect();
+    let missed = match related_candidates(client, "ids.openalex", &ids, 200).await {
+        Ok(works) => works
+            .into_iter()
+            .filter_map(|work| {
+                let n = cited.iter().find(|(id, _)| id == short_id(&work.id))?.1;
+                Some((work, n))
+            })
+            .collect(),
+        Err(e) => {
+            warnings.push(format!("cited works: {e}"));
+            Vec::new()
+        }
+    };
+    ReviewContext::new(terms, recent_since, ranked, missed, &references, warnings)
+}
+
+/// Top-level items (no standalone notes or attachments) added on or after
+/// `since` (`YYYY-MM-DD`), newest first.
+async fn recent_zotero_items(
+    zotero: &ZoteroClient,
+    since: &str,
+) -> Result<Vec<crate::digest::ZoteroAddition>, papers_zotero::ZoteroError> {
+    use crate::digest::{DIGEST_ZOTERO_MAX, ZoteroAddition};
+
+    let mut added = Vec::new();
+    let mut start = 0;
+    loop {
+        let params = papers_zotero::ItemListParams {
+            sort: Some("dateAdded".to_string()),
+            direction: Some("desc".to_string()),
+            limit: Some(100),
+            start: Some(start),
+            ..Default::default()
+        };
+        let page = zotero.list_top_items(&params).await?;
+        let count = page.items.len() as u32;
+        for item in &page.items {
+            // `dateAdded` is ISO 8601, so it sorts against a bare date.
+            if item.data.date_added.as_deref().is_none_or(|d| d < since) {
+                return Ok(added);
+            }
+            if !matches!(item.data.item_type.as_str(), "note" | "attachment") {
+                added.push(ZoteroAddition::from_item(item));
+            }
+        }
+        start += count;
+        if count < 100 || added.len() >= DIGEST_ZOTERO_MAX {
+            added.truncate(DIGEST_ZOTERO_MAX);
+            return Ok(added);
+        }
+    }
+}
+
+// ── Citation graph ───────────────────────────────────────────────────────
+
+/// Fields fetched for each node in a citation traversal (no abstract, to keep
+/// multi-hop responses small).
+const CITATION_GRAPH_SELECT: &str =
+    "id,display_name,doi,publication_year,type,authorships,primary_location,open_access,cited_by_count,primary_topic";
+
+/// Maximum traversal depth accepted by `work_citations` / `work_references`.
+pub const CITATION_GRAPH_MAX_DEPTH: u32 = 3;
+
+/// Maximum unique works collected by `work_citations` / `work_references`.
+pub const CITATION_GRAPH_MAX_WORKS: usize = 1_000;
+
+/// Options for `work_citations` / `work_references`.
+#[derive(Debug, Clone)]
+pub struct CitationGraphParams {
+    /// Number of hops from the root work (1 = direct citations/references).
+    /// Clamped to `1..=CITATION_GRAPH_MAX_DEPTH`.
+    pub depth: u32,
+    /// Maximum works fetched per expanded work, most-cited first (max 200).
+    pub per_work: u32,
+    /// Stop expanding once this many unique works have been collected.
+    /// Clamped to `1..=CITATION_GRAPH_MAX_WORKS`.
+    pub max_works: usize,
+}
+
+impl Default for CitationGraphParams {
+    fn default() -> Self {
+        Self {
+            depth: 1,
+            per_work: 25,
+            max_works: 200,
+        }
+    }
+}
+
+/// Which edge of the citation graph to follow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CitationDirection {
+    /// Works that cite the given work (`cites:` filter).
+    Citations,
+    /// Works the given work references (`cited_by:` filter).
+    References,
+}
+
+impl CitationDirection {
+    fn filter_key(self) -> &'static str {
+        match self {
+            CitationDirection::Citations => "cites",
+            CitationDirection::References => "cited_by",
+        }
+    }
+}
+
+/// A work reached during a citation traversal.
+#[derive(Serialize)]
+pub struct CitationGraphNode {
+    /// Hops from the root work (1 = direct).
+    pub depth: u32,
+    /// Short OpenAlex ID of the work this one was reached from.
+    pub via: String,
+    #[serde(flatten)]
+    pub work: WorkSummary,
+}
+
+#[derive(Serialize)]
+pub struct CitationGraphResponse {
+    /// Short OpenAlex ID of the root work.
+    pub root: String,
+    pub direction: CitationDirection,
+    pub depth: u32,
+    /// Number of unique works returned (root excluded).
+    pub count: usize,
+    /// True if `per_work` or `max_works` cut the traversal short.
+    pub truncated: bool,
+    pub works: Vec<CitationGraphNode>,
+}
+
+/// Works citing `id`, expanded breadth-first up to `params.depth` hops.
+pub async fn work_citations(
+    client: &OpenAlexClient,
+    id: &str,
+    params: &CitationGraphParams,
+) -> Result<CitationGraphResponse, FilterError> {
+    citation_graph(client, id, CitationDirection::Citations, params).await
+}
+
+/// Works referenced by `id`, expanded breadth-first up to `params.depth` hops.
+pub async fn work_references(
+    client: &OpenAlexClient,
+    id: &str,
+    params: &CitationGraphParams,
+) -> Result<CitationGraphResponse, FilterError> {
+    citation_graph(client, id, CitationDirection::References, params).await
+}
+
+/// Resolve any work identifier (OpenAlex ID, DOI, title) to a short `W…` ID.
+async fn resolve_work_short_id(
+    client: &OpenAlexClient,
+    input: &str,
+) -> Result<String, FilterError> {
+    let resolved = resolve_get_id(client, input, "works").await?;
+    if is_openalex_id(&resolved, "works") {
+        return Ok(resolved);
+    }
+    let params = GetParams { select: Some("id".to_string()) };
+    let work = client.get_work(&resolved, &params).await?;
+    Ok(work
+        .id
+        .strip_prefix("https://openalex.org/")
+        .unwrap_or(&work.id)
+        .to_string())
+}
+
+async fn citation_graph(
+    client: &OpenAlexClient,
+    id: &str,
+    direction: CitationDirection,
+    params: &CitationGraphParams,
+) -> Result<CitationGraphResponse, FilterError> {
+    let depth = params.depth.clamp(1, CITATION_GRAPH_MAX_DEPTH);
+    let per_work = params.per_work.clamp(1, 200);
+    let max_works = params.max_works.clamp(1, CITATION_GRAPH_MAX_WORKS);
+    let root = resolve_work_short_id(client, id).await?;
+
+    let mut seen = std::collections::HashSet::from([root.clone()]);
+    let mut works = Vec::new();
+    let mut truncated = false;
+    let mut frontier = vec![root.clone()];
+
+    'levels: for level in 1..=depth {
+        let mut next = Vec::new();
+        for parent in &frontier {
+            let list_params = papers_openalex::ListParams {
+                filter: Some(format!("{}:{parent}", direction.filter_key())),
+                sort: Some("cited_by_count:desc".to_string()),
+                per_page: Some(per_work),
+                select: Some(CITATION_GRAPH_SELECT.to_string()),
+                ..Default::default()
+            };
+            let resp = client.list_works(&list_params).await?;
+            if resp.meta.count > resp.results.len() as i64 {
+                truncated = true;
+            }
+            for work in resp.results {
+                let short = work
+                    .id
+                    .strip_prefix("https://openalex.org/")
+                    .unwrap_or(&work.id)
+                    .to_string();
+                if !seen.insert(short.clone()) {
+                    continue;
+                }
+                if works.len() >= max_works {
+                    truncated = true;
+                    break 'levels;
+                }
+                next.push(short);
+                works.push(CitationGraphNode {
+                    depth: level,
+                    via: parent.clone(),
+                    work: WorkSummary::from(work),
+                });
+            }
+        }
+        frontier = next;
+        if frontier.is_empty() {
+            break;
+        }
+    }
+
+    Ok(CitationGraphResponse {
+        root,
+        direction,
+        depth,
+        count: works.len(),
+        truncated,
+        works,
+    })
+}
+
+// ── Related works ────────────────────────────────────────────────────────
+
+/// Fields fetched for each seed: what the three signals are computed from.
+const RELATED_SEED_SELECT: &str = "id,display_name,related_works,referenced_works,topics";
+
+/// Fields fetched for each candidate: the summary fields plus references and
+/// topics, so every candidate can be compared against every seed.
+const RELATED_CANDIDATE_SELECT: &str =
+    "id,display_name,doi,publication_year,type,authorships,primary_location,open_access,cited_by_count,primary_topic,referenced_works,topics";
+
+/// Values OR'd into one filter (OpenAlex accepts up to 100).
+const RELATED_FILTER_CHUNK: usize = 50;
+
+/// Score for each seed that lists a work in its OpenAlex `related_works`.
+pub const RELATED_WEIGHT_OPENALEX: f64 = 3.0;
+/// Score per reference a work shares with a seed.
+pub const RELATED_WEIGHT_REFERENCE: f64 = 1.0;
+/// Score per topic a work shares with a seed.
+pub const RELATED_WEIGHT_TOPIC: f64 = 0.5;
+
+/// Options for `work_related` / `selection_related`.
+#[derive(Debug, Clone)]
+pub struct RelatedWorksParams {
+    /// Maximum recommendations returned.
+    pub limit: usize,
+    /// Candidates fetched per seed for each of the shared-reference and
+    /// shared-topic searches, most-cited first (max 200).
+    pub per_seed: u32,
+}
+
+impl Default for RelatedWorksParams {
+    fn default() -> Self {
+        Self {
+            limit: 20,
+            per_seed: 25,
+        }
+    }
+}
+
+/// Why a work was recommended, relative to one seed.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum RelatedReason {
+    /// Listed in the seed's OpenAlex `related_works`.
+    OpenalexRelated { seed: String },
+    /// Cites some of the same works as the seed.
+    SharedReferences { seed: String, count: usize },
+    /// Classified under some of the seed's topics.
+    SharedTopics { seed: String, topics: Vec<String> },
+}
+
+/// A recommended work.
+#[derive(Serialize)]
+pub struct RelatedWork {
+    pub score: f64,
+    pub reasons: Vec<RelatedReason>,
+    #[serde(flatten)]
+    pub work: WorkSummary,
+}
+
+#[derive(Serialize)]
+pub struct RelatedWorksResponse {
+    /// Short OpenAlex IDs of the works the recommendations are based on.
+    pub seeds: Vec<String>,
+    /// Number of recommendations returned.
+    pub count: usize,
+    /// Highest score first.
+    pub works: Vec<RelatedWork>,
+    /// Selection entries that could not be found in OpenAlex.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub unresolved: Vec<String>,
+}
+
+/// Recommend works to read after `id`, ranked by OpenAlex `related_works`,
+/// shared references, and shared topics.
+pub async fn work_related(
+    client: &OpenAlexClient,
+    id: &str,
+    params: &RelatedWorksParams,
+) -> Result<RelatedWorksResponse, FilterError> {
+    let get = GetParams { select: Some(RELATED_SEED_SELECT.to_string()) };
+    let seed = work_get(client, id, &get).await?;
+    related_works(client, vec![seed], Vec::new(), params).await
+}
+
+/// Like [`work_related`], seeded with every entry of `selection`. Works already
+/// in the selection are never recommended.
+pub async fn selection_related(
+    client: &OpenAlexClient,
+    selection: &crate::selection::Selection,
+    params: &RelatedWorksParams,
+) -> Result<RelatedWorksResponse, FilterError> {
+    let get = GetParams { select: Some(RELATED_SEED_SELECT.to_string()) };
+    let (seeds, unresolved) = fetch_selection_works(client, selection, &get).await;
+    related_works(client, seeds, unresolved, params).await
+}
+
+/// Short IDs of `work`'s topics, with their display names.
+fn work_topics(work: &Work) -> Vec<(String, String)> {
+    work.topics
+        .iter()
+        .flatten()
+        .filter_map(|t| {
+            let id = short_id(t.id.as_deref()?).to_string();
+            let name = t.display_name.clone().unwrap_or_else(|| id.clone());
+            Some((id, name))
+        })
+        .collect()
+}
+
+fn short_ids(ids: &Option<Vec<String>>) -> Vec<String> {
+    ids.iter().flatten().map(|id| short_id(id).to_string()).collect()
+}
+
+/// Candidates most-cited first matching `key:v1|v2|…`, in chunks of
+/// [`RELATED_FILTER_CHUNK`] values, up to `per_page` per chunk.
+async fn related_candidates(
+    client: &OpenAlexClient,
+    key: &str,
+    values: &[String],
+    per_page: u32,
+) -> Result<Vec<Work>, FilterError> {
+    let mut works = Vec::new();
+    for chunk in values.chunks(RELATED_FILTER_CHUNK) {
+        let list_params = papers_openalex::ListParams {
+            filter: Some(format!("{key}:{}", chunk.join("|"))),
+            sort: Some("cited_by_count:desc".to_string()),
+            per_page: Some(per_page),
+            select: Some(RELATED_CANDIDATE_SELECT.to_string()),
+            ..Default::default()
+        };
+        works.extend(client.list_works(&list_params).await?.results);
+    }
+    Ok(works)
+}
+
+/// A seed's neighbours in each signal, as short IDs.
+struct SeedSignals<'a> {
+    id: &'a str,
+    related: std::collections::HashSet<String>,
+    refs: std::collections::HashSet<String>,
+    topics: std::collections::HashSet<String>,
+}
+
+/// Gather candidates from every signal for every seed, then score each
+/// candidate against each seed with the `RELATED_WEIGHT_*` weights.
+async fn related_works(
+    client: &OpenAlexClient,
+    seeds: Vec<Work>,
+    unresolved: Vec<String>,
+    params: &RelatedWorksParams,
+) -> Result<RelatedWorksResponse, FilterError> {
+    use std::collections::{BTreeSet, HashMap, HashSet};
+
+    let per_seed = params.per_seed.clamp(1, 200);
+    let seed_ids: Vec<String> =
+        seeds.iter().map(|s| short_id(&s.id).to_string()).collect();
+    let excluded: HashSet<&str> = seed_ids.iter().map(String::as_str).collect();
+
+    let mut candidates: HashMap<String, Work> = HashMap::new();
+    let add = |works: Vec<Work>, candidates: &mut HashMap<String, Work>| {
+        for work in works {
+            let id = short_id(&work.id).to_string();
+            if !excluded.contains(id.as_str()) {
+                candidates.entry(id).or_insert(work);
+            }
+        }
+    };
+    let mut openalex_related = Vec::new();
+    for seed in &seeds {
+        let refs = short_ids(&seed.referenced_works);
+        if !refs.is_empty() {
+            add(related_candidates(client, "cites", &refs, per_seed).await?, &mut candidates);
+        }
+        let topics: Vec<String> = work_topics(seed).into_iter().map(|(id, _)| id).collect();
+        if !topics.is_empty() {
+            add(related_candidates(client, "topics.id", &topics, per_seed).await?, &mut candidates);
+        }
+        openalex_related.extend(short_ids(&seed.related_works));
+    }
+    let missing: Vec<String> = openalex_related
+        .into_iter()
+        .filter(|id| !excluded.contains(id.as_str()) && !candidates.contains_key(id))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    add(related_candidates(client, "ids.openalex", &missing, 200).await?, &mut candidates);
+
+    let seed_signals: Vec<SeedSignals> = seeds
+        .iter()
+        .zip(&seed_ids)
+        .map(|(seed, id)| SeedSignals {
+            id,
+            related: short_ids(&seed.related_works).into_iter().collect(),
+            refs: short_ids(&seed.referenced_works).into_iter().collect(),
+            topics: work_topics(seed).into_iter().map(|(id, _)| id).collect(),
+        })
+        .collect();
+
+    let mut works: Vec<RelatedWork> = candidates
+        .into_iter()
+        .filter_map(|(id, work)| {
+            let refs = short_ids(&work.referenced_works);
+            let topics = work_topics(&work);
+            let mut score = 0.0;
+            let mut reasons = Vec::new();
+            for seed in &seed_signals {
+                let seed_id = || seed.id.to_string();
+                if seed.related.contains(&id) {
+                    score += RELATED_WEIGHT_OPENALEX;
+                    reasons.push(RelatedReason::OpenalexRelated { seed: seed_id() });
+                }
+                let count = refs.iter().filter(|r| seed.refs.contains(*r)).count();
+                if count > 0 {
+                    score += RELATED_WEIGHT_REFERENCE * count as f64;
+                    reasons.push(RelatedReason::SharedReferences { seed: seed_id(), count });
+                }
+                let shared: Vec<String> = topics
+                    .iter()
+                    .filter(|(t, _)| seed.topics.contains(t))
+                    .map(|(_, name)| name.clone())
+                    .collect();
+                if !shared.is_empty() {
+                    score += RELATED_WEIGHT_TOPIC * shared.len() as f64;
+                    reasons.push(RelatedReason::SharedTopics { seed: seed_id(), topics: shared });
+                }
+            }
+            (score > 0.0).then(|| RelatedWork { score, reasons, work: WorkSummary::from(work) })
+        })
+        .collect();
+    works.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then(b.work.cited_by_count.cmp(&a.work.cited_by_count))
+            .then_with(|| a.work.id.cmp(&b.work.id))
+    });
+    works.truncate(params.limit);
+
+    Ok(RelatedWorksResponse {
+        seeds: seed_ids,
+        count: works.len(),
+        works,
+        unresolved,
+    })
+}
+
+// ── Reference search ─────────────────────────────────────────────────────
+
+/// Fields fetched for each matching reference.
+const REFERENCE_SEARCH_SELECT: &str =
+    "id,display_name,doi,publication_year,type,authorships,primary_location,open_access,cited_by_count,primary_topic,abstract_inverted_index";
+
+/// Options for `work_search_references`.
+#[derive(Debug, Clone)]
+pub struct ReferenceSearchParams {
+    /// Maximum matches returned.
+    pub limit: usize,
+}
+
+impl Default for ReferenceSearchParams {
+    fn default() -> Self {
+        Self { limit: 20 }
+    }
+}
+
+/// A reference matching the query.
+#[derive(Serialize)]
+pub struct ReferenceMatch {
+    /// OpenAlex search relevance; comparable across the whole response.
+    pub relevance_score: Option<f64>,
+    #[serde(flatten)]
+    pub work: WorkSummary,
+}
+
+#[derive(Serialize)]
+pub struct ReferenceSearchResponse {
+    /// Short OpenAlex ID of the citing work.
+    pub root: String,
+    pub query: String,
+    /// Number of references searched.
+    pub references: usize,
+    /// Number of matches returned.
+    pub count: usize,
+    /// Most relevant first.
+    pub works: Vec<ReferenceMatch>,
+}
+
+/// Search the works cited by `id` for `query`: "which of the papers cited by X
+/// is about Y". References are searched in batches of [`RELATED_FILTER_CHUNK`]
+/// (`ids.openalex:R1|R2|…` plus `search`) and merged by relevance score.
+pub async fn work_search_references(
+    client: &OpenAlexClient,
+    id: &str,
+    query: &str,
+    params: &ReferenceSearchParams,
+) -> Result<ReferenceSearchResponse, FilterError> {
+    let get = GetParams { select: Some("id,referenced_works".to_string()) };
+    let root = work_get(client, id, &get).await?;
+    let mut refs = short_ids(&root.referenced_works);
+    refs.sort();
+    refs.dedup();
+
+    let mut matches: Vec<Work> = Vec::new();
+    for chunk in refs.chunks(RELATED_FILTER_CHUNK) {
+        let list_params = papers_openalex::ListParams {
+            filter: Some(format!("ids.openalex:{}", chunk.join("|"))),
+            search: Some(query.to_string()),
+            per_page: Some(chunk.len() as u32),
+            select: Some(REFERENCE_SEARCH_SELECT.to_string()),
+            ..Default::default()
+        };
+        matches.extend(client.list_works(&list_params).await?.results);
+    }
+    matches.sort_by(|a, b| {
+        let score = |w: &Work| w.relevance_score.unwrap_or(f64::NEG_INFINITY);
+        score(b)
+            .total_cmp(&score(a))
+            .then(b.cited_by_count.cmp(&a.cited_by_count))
+            .then_with(|| a.id.cmp(&b.id))
+    });
+    matches.truncate(params.limit);
+
+    let works: Vec<ReferenceMatch> = matches
+        .into_iter()
+        .map(|work| ReferenceMatch { relevance_score: work.relevance_score, work: WorkSummary::from(work) })
+        .collect();
+    Ok(ReferenceSearchResponse {
+        root: short_id(&root.id).to_string(),
+        query: query.to_string(),
+        references: refs.len(),
+        count: works.len(),
+        works,
+    })
+}
+
+// ── Author profile ───────────────────────────────────────────────────────
+
+/// Options for `author_profile`.
+#[derive(Debug, Clone)]
+pub struct AuthorProfileParams {
+    /// Number of most-cited works to include (max 50).
+    pub top_works: u32,
+    /// Number of most frequent co-authors to include.
+    pub top_coauthors: usize,
+    /// Number of topics to include in the distribution.
+    pub topics: usize,
+}
+
+impl Default for AuthorProfileParams {
+    fn default() -> Self {
+        Self {
+            top_works: 5,
+            top_coauthors: 10,
+            topics: 10,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct AuthorYearCount {
+    pub year: i32,
+    pub works_count: Option<i64>,
+    pub cited_by_count: Option<i64>,
+}
+
+#[derive(Serialize)]
+pub struct AuthorCoauthor {
+    /// Short OpenAlex author ID.
+    pub id: String,
+    pub display_name: String,
+    /// Number of works shared with the profiled author.
+    pub shared_works: i64,
+}
+
+#[derive(Serialize)]
+pub struct AuthorTopicShare {
+    pub display_name: String,
+    pub field: Option<String>,
+    /// Number of the author's works tagged with this topic.
+    pub count: i64,
+    /// `count` as a fraction of the author's `works_count`.
+    pub share: Option<f64>,
+}
+
+/// Scholar-style overview of an author, assembled from one author fetch and
+/// two work list queries.
+#[derive(Serialize)]
+pub struct AuthorProfile {
+    pub id: String,
+    pub display_name: Option<String>,
+    pub orcid: Option<String>,
+    pub last_known_institutions: Vec<String>,
+    pub works_count: Option<i64>,
+    pub cited_by_count: Option<i64>,
+    pub h_index: Option<i64>,
+    pub i10_index: Option<i64>,
+    pub two_yr_mean_citedness: Option<f64>,
+    /// Oldest year first.
+    pub counts_by_year: Vec<AuthorYearCount>,
+    pub top_works: Vec<WorkSummary>,
+    pub top_coauthors: Vec<AuthorCoauthor>,
+    pub topics: Vec<AuthorTopicShare>,
+}
+
+/// Fields fetched for an author's top works (no abstract).
+const AUTHOR_TOP_WORKS_SELECT: &str =
+    "id,display_name,doi,publication_year,type,authorships,primary_location,open_access,cited_by_count,primary_topic";
+
+/// Build a one-call researcher profile: citation metrics, yearly counts, most-cited
+/// works, most frequent co-authors and topic distribution.
+pub async fn author_profile(
+    client: &OpenAlexClient,
+    id: &str,
+    params: &AuthorProfileParams,
+) -> Result<AuthorProfile, FilterError> {
+    let author = author_get(client, id, &GetParams::default()).await?;
+    let short_id = author
+        .id
+        .strip_prefix("https://openalex.org/")
+        .unwrap_or(&author.id)
+        .to_string();
+    let filter = format!("author.id:{short_id}");
+
+    let works_params = papers_openalex::ListParams {
+        filter: Some(filter.clone()),
+        sort: Some("cited_by_count:desc".to_string()),
+        per_page: Some(params.top_works.clamp(1, 50)),
+        select: Some(AUTHOR_TOP_WORKS_SELECT.to_string()),
+        ..Default::default()
+    };
+    let coauthor_params = papers_openalex::ListParams {
+        filter: Some(filter),
+        group_by: Some("authorships.author.id".to_string()),
+        ..Default::default()
+    };
+    let (works, coauthors) = tokio::try_join!(
+        client.list_works(&works_params),
+        client.list_works(&coauthor_params),
+    )?;
+
+    let mut counts_by_year: Vec<AuthorYearCount> = author
+        .counts_by_year
+        .unwrap_or_default()
+        .into_iter()
+        .map(|c| AuthorYearCount {
+            year: c.year,
+            works_count: c.works_count,
+            cited_by_count: c.cited_by_count,
+        })
+        .collect();
+    counts_by_year.sort_by_key(|c| c.year);
+
+    let mut top_coauthors: Vec<AuthorCoauthor> = coauthors
+        .group_by
+        .into_iter()
+        .filter_map(|g| {
+            let id = g.key.strip_prefix("https://openalex.org/").unwrap_or(&g.key);
+            (id != short_id && !id.is_empty() && id != "unknown").then(|| AuthorCoauthor {
+                id: id.to_string(),
+                display_name: g.key_display_name,
+                shared_works: g.count,
+            })
+        })
+        .collect();
+    top_coauthors.sort_by_key(|c| std::cmp::Reverse(c.shared_works));
+    top_coauthors.truncate(params.top_coauthors);
+
+    let works_count = author.works_count;
+    let mut topics: Vec<AuthorTopicShare> = author
+        .topics
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|t| {
+            let count = t.count?;
+            Some(AuthorTopicShare {
+                display_name: t.display_name?,
+                field: t.field.and_then(|f| f.display_name),
+                count,
+                share: works_count
+                    .filter(|&n| n > 0)
+                    .map(|n| count as f64 / n as f64),
+            })
+        })
+        .collect();
+    topics.sort_by_key(|t| std::cmp::Reverse(t.count));
+    topics.truncate(params.topics);
+
+    let stats = author.summary_stats;
+    Ok(AuthorProfile {
+        id: short_id,
+        display_name: author.display_name,
+        orcid: author.orcid,
+        last_known_institutions: author
+            .last_known_institutions
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|i| i.display_name)
+            .collect(),
+        works_count,
+        cited_by_count: author.cited_by_count,
+        h_index: stats.as_ref().and_then(|s| s.h_index),
+        i10_index: stats.as_ref().and_then(|s| s.i10_index),
+        two_yr_mean_citedness: stats.as_ref().and_then(|s| s.two_yr_mean_citedness),
+        counts_by_year,
+        top_works: works.results.into_iter().map(WorkSummary::from).collect(),
+        top_coauthors,
+        topics,
+    })
+}
+
+// ── Author affiliation history ───────────────────────────────────────────
+
+/// A contiguous run of years an author was affiliated with one institution.
+#[derive(Clone, Serialize)]
+pub struct AffiliationSpan {
+    /// Short OpenAlex institution ID.
+    pub institution_id: Option<String>,
+    pub display_name: Option<String>,
+    pub country_code: Option<String>,
+    pub r#type: Option<String>,
+    pub start_year: i32,
+    pub end_year: i32,
+}
+
+#[derive(Serialize)]
+pub struct AffiliationHistory {
+    pub id: String,
+    pub display_name: Option<String>,
+    /// `"affiliations"` when taken from the author record, `"works"` when
+    /// rebuilt from the author's works because the record had none.
+    pub source: &'static str,
+    /// Ordered by start year, then end year.
+    pub spans: Vec<AffiliationSpan>,
+    /// Year asked about via the `year` argument, if any.
+    pub year: Option<i32>,
+    /// Spans covering `year` (empty when `year` is not set or nothing matches).
+    pub at_year: Vec<AffiliationSpan>,
+}
+
+/// Maximum works scanned when rebuilding history from works.
+const AFFILIATION_WORKS_LIMIT: u32 = 200;
+
+/// Split a set of years into inclusive `(start, end)` runs of consecutive years.
+fn year_runs(years: &[i32]) -> Vec<(i32, i32)> {
+    let mut years = years.to_vec();
+    years.sort_unstable();
+    years.dedup();
+    let mut runs: Vec<(i32, i32)> = Vec::new();
+    for y in years {
+        match runs.last_mut() {
+            Some((_, end)) if *end + 1 == y => *end = y,
+            _ => runs.push((y, y)),
+        }
+    }
+    runs
+}
+
+/// Institution spans for an author, e.g. to answer "where was X in 2019".
+///
+/// Built from the author's `affiliations` (institution + years). If that list
+/// is empty, it is rebuilt from the institutions on the author's own
+/// authorships across their works (up to 200, most recent first).
+pub async fn author_affiliation_history(
+    client: &OpenAlexClient,
+    id: &str,
+    year: Option<i32>,
+) -> Result<AffiliationHistory, FilterError> {
+    let author = author_get(client, id, &GetParams::default()).await?;
+    let short_id = author
+        .id
+        .strip_prefix("https://openalex.org/")
+        .unwrap_or(&author.id)
+        .to_string();
+
+    let mut by_institution: Vec<(papers_openalex::DehydratedInstitution, Vec<i32>)> = author
+        .affiliations
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|a| Some((a.institution?, a.years.unwrap_or_default())))
+        .collect();
+    let mut source = "affiliations";
+
+    if by_institution.is_empty() {
+        source = "works";
+        let params = papers_openalex::ListParams {
+            filter: Some(format!("author.id:{short_id}")),
+            sort: Some("publication_year:desc".to_string()),
+            per_page: Some(AFFILIATION_WORKS_LIMIT),
+            select: Some("id,publication_year,authorships".to_string()),
+            ..Default::default()
+        };
+        let works = client.list_works(&params).await?;
+        for work in works.results {
+            let Some(y) = work.publication_year else { continue };
+            let own = work.authorships.unwrap_or_default().into_iter().find(|a| {
+                a.author
+                    .as_ref()
+                    .and_then(|au| au.id.as_deref())
+                    .is_some_and(|aid| aid.ends_with(&short_id))
+            });
+            for inst in own.and_then(|a| a.institutions).unwrap_or_default() {
+                match by_institution.iter_mut().find(|(i, _)| i.id == inst.id) {
+                    Some((_, years)) => years.push(y),
+                    None => by_institution.push((inst, vec![y])),
+                }
+            }
+        }
+    }
+
+    let mut spans: Vec<AffiliationSpan> = Vec::new();
+    for (inst, years) in by_institution {
+        for (start_year, end_year) in year_runs(&years) {
+            spans.push(AffiliationSpan {
+                institution_id: inst
+                    .id
+                    .as_deref()
+                    .map(|i| i.strip_prefix("https://openalex.org/").unwrap_or(i).to_string()),
+                display_name: inst.display_name.clone(),
+                country_code: inst.country_code.clone(),
+                r#type: inst.r#type.clone(),
+                start_year,
+                end_year,
+            });
+        }
+    }
+    spans.sort_by_key(|s| (s.start_year, s.end_year));
+
+    let at_year = year
+        .map(|y| {
+            spans
+                .iter()
+                .filter(|s| s.start_year <= y && y <= s.end_year)
+                .cloned()
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(AffiliationHistory {
+        id: short_id,
+        display_name: author.display_name,
+        source,
+        spans,
+        year,
+        at_year,
+    })
+}
+
+// ── Author resolution ────────────────────────────────────────────────────
+
+/// Most candidates scored per request.
+pub const AUTHOR_RESOLVE_MAX_CANDIDATES: u32 = 25;
+
+/// Options for `author_resolve`.
+#[derive(Debug, Clone)]
+pub struct AuthorResolveParams {
+    pub hints: crate::disambiguation::ResolveHints,
+    /// Authors fetched by name search and scored (max 25).
+    pub candidates: u32,
+}
+
+impl Default for AuthorResolveParams {
+    fn default() -> Self {
+        Self {
+            hints: Default::default(),
+            candidates: 10,
+        }
+    }
+}
+
+/// Keep words only, since filter values can't contain `,` or `|`.
+fn filter_words(text: &str) -> String {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Resolve a free-text author name to ranked OpenAlex authors. See
+/// [`crate::disambiguation`] for scoring. A coauthor hint costs one works
+/// count query per candidate.
+pub async fn author_resolve(
+    client: &OpenAlexClient,
+    name: &str,
+    params: &AuthorResolveParams,
+) -> Result<crate::disambiguation::AuthorResolution, FilterError> {
+    use crate::disambiguation;
+
+    let list_params = papers_openalex::ListParams {
+        search: Some(name.to_string()),
+        per_page: Some(params.candidates.clamp(1, AUTHOR_RESOLVE_MAX_CANDIDATES)),
+        select: Some(disambiguation::AUTHOR_RESOLVE_SELECT.to_string()),
+        ..Default::default()
+    };
+    let authors = client.list_authors(&list_params).await?.results;
+    let max_works = authors.iter().filter_map(|a| a.works_count).max().unwrap_or(0);
+    let coauthor = params.hints.coauthor.as_deref().map(filter_words).filter(|c| !c.is_empty());
+
+    let mut candidates = Vec::with_capacity(authors.len());
+    for author in &authors {
+        let shared = match &coauthor {
+            Some(coauthor) => {
+                let works_params = papers_openalex::ListParams {
+                    filter: Some(format!(
+                        "author.id:{},raw_author_name.search:{coauthor}",
+                        author.id.strip_prefix("https://openalex.org/").unwrap_or(&author.id)
+                    )),
+                    per_page: Some(1),
+                    select: Some("id".to_string()),
+                    ..Default::default()
+                };
+                Some(client.list_works(&works_params).await?.meta.count)
+            }
+            None => None,
+        };
+        candidates.push(disambiguation::score_author(author, name, &params.hints, shared, max_works));
+    }
+
+    Ok(disambiguation::resolution(name, candidates))
+}
+
 // ── Autocomplete ─────────────────────────────────────────────────────────
 
 pub async fn work_autocomplete(
@@ -274,10 +1823,50 @@ pub async fn work_find(
     }
 }
 
+// ── Cache ────────────────────────────────────────────────────────────────
+
+/// Entry counts for the client's response cache, or `None` when it has none.
+pub fn cache_stats(client: &OpenAlexClient) -> Option<CacheStats> {
+    client.cache().map(|c| c.stats())
+}
+
+/// Remove cached responses, all or for one entity type (`"works"`, …).
+/// Returns the number removed; `0` when the client has no cache.
+pub fn cache_clear(client: &OpenAlexClient, entity: Option<&str>) -> std::io::Result<usize> {
+    match client.cache() {
+        Some(cache) => cache.clear(entity),
+        None => Ok(0),
+    }
+}
+
+/// Remove expired responses and enforce the cache size limit. Returns the
+/// number of entries removed; `0` when the client has no cache.
+pub fn cache_prune(client: &OpenAlexClient) -> usize {
+    match client.cache() {
+        Some(cache) => {
+            let before = cache.stats().entries;
+            cache.prune();
+            before.saturating_sub(cache.stats().entries)
+        }
+        None => 0,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    // ── year_runs ────────────────────────────────────────────────────────
+
+    #[test]
+    fn year_runs_splits_gaps() {
+        assert_eq!(
+            year_runs(&[2021, 2019, 2018, 2023, 2019]),
+            vec![(2018, 2019), (2021, 2021), (2023, 2023)]
+        );
+        assert!(year_runs(&[]).is_empty());
+    }
+
     // ── looks_like_identifier: OpenAlex IDs ──────────────────────────────
 
     #[test]
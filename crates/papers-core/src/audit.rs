@@ -0,0 +1,363 @@
+//! Access audit log for shared deployments.
+//!
+//! The HTTP server appends one [`AuditRecord`] per tool call to
+//! `audit/YYYY-MM-DD.jsonl` (UTC days) in the data directory; with tenants,
+//! each tenant's calls go to its own directory (see [`crate::tenant`]).
+//! Records name the user, MCP session and tool, plus the identifier arguments
+//! listed in [`AUDITED_ARGS`]; free text such as search queries, filters and
+//! note bodies is never written.
+//!
+//! Configured through the environment:
+//!
+//! - `PAPERS_AUDIT=off` disables the log.
+//! - `PAPERS_AUDIT_RETENTION_DAYS` (default 90, `0` keeps everything): day
+//!   files older than this are deleted when the server starts and once a day
+//!   after that.
+
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+/// Default for `PAPERS_AUDIT_RETENTION_DAYS`.
+pub const DEFAULT_RETENTION_DAYS: u32 = 90;
+
+/// Tool arguments recorded in [`AuditRecord::ids`]: the ones that identify a
+/// work, paper, selection or library item rather than describe a search.
+pub const AUDITED_ARGS: &[&str] = &[
+    "id",
+    "key",
+    "paper_id",
+    "paper",
+    "chapter_idx",
+    "section_idx",
+    "selection",
+    "name",
+    "collection",
+    "tag",
+];
+
+/// Longest identifier value kept; longer values are cut.
+const MAX_ID_LEN: usize = 200;
+
+#[derive(Debug, thiserror::Error)]
+pub enum AuditError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("invalid PAPERS_AUDIT_RETENTION_DAYS {0:?}: expected a number of days")]
+    Retention(String),
+}
+
+/// One tool call.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditRecord {
+    /// ISO 8601 UTC time the call finished.
+    pub ts: String,
+    /// Tenant name; absent in single-user mode.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    /// MCP session ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session: Option<String>,
+    pub tool: String,
+    /// Identifier arguments, see [`audited_ids`].
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub ids: BTreeMap<String, String>,
+    pub ok: bool,
+    pub duration_ms: u64,
+}
+
+impl AuditRecord {
+    /// A record of `tool` finishing now, without user or session.
+    pub fn now(
+        tool: impl Into<String>,
+        ids: BTreeMap<String, String>,
+        ok: bool,
+        duration: std::time::Duration,
+    ) -> Self {
+        Self {
+            ts: crate::text::iso_now(),
+            user: None,
+            session: None,
+            tool: tool.into(),
+            ids,
+            ok,
+            duration_ms: duration.as_millis() as u64,
+        }
+    }
+
+    /// The day file this record belongs in (`YYYY-MM-DD`).
+    fn day(&self) -> &str {
+        self.ts.get(..10).unwrap_or(&self.ts)
+    }
+}
+
+/// The [`AUDITED_ARGS`] present in `args` with string or number values.
+pub fn audited_ids(args: &serde_json::Map<String, serde_json::Value>) -> BTreeMap<String, String> {
+    AUDITED_ARGS
+        .iter()
+        .filter_map(|&name| {
+            let value = match args.get(name)? {
+                serde_json::Value::String(s) => s.chars().take(MAX_ID_LEN).collect(),
+                serde_json::Value::Number(n) => n.to_string(),
+                _ => return None,
+            };
+            Some((name.to_string(), value))
+        })
+        .collect()
+}
+
+/// Which records [`export`] writes. Days are inclusive `YYYY-MM-DD` bounds.
+#[derive(Debug, Clone, Default)]
+pub struct AuditFilter {
+    pub since: Option<String>,
+    pub until: Option<String>,
+    pub user: Option<String>,
+    pub tool: Option<String>,
+}
+
+impl AuditFilter {
+    fn includes_day(&self, day: &str) -> bool {
+        self.since.as_deref().is_none_or(|s| day >= s)
+            && self.until.as_deref().is_none_or(|u| day <= u)
+    }
+
+    fn matches(&self, record: &AuditRecord) -> bool {
+        self.includes_day(record.day())
+            && self.user.as_deref().is_none_or(|u| record.user.as_deref() == Some(u))
+            && self.tool.as_deref().is_none_or(|t| record.tool == t)
+    }
+}
+
+/// `audit/` in the [`crate::tenant::data_dir`].
+pub fn audit_dir() -> PathBuf {
+    audit_dir_in(&crate::tenant::data_dir())
+}
+
+/// `audit/` in `data_dir`, e.g. a tenant's [`crate::tenant::Tenant::data_dir`].
+pub fn audit_dir_in(data_dir: &Path) -> PathBuf {
+    data_dir.join("audit")
+}
+
+/// Appends records to day files in a directory and prunes expired ones.
+#[derive(Debug)]
+pub struct AuditLog {
+    dir: PathBuf,
+    retention_days: u32,
+    /// Day of the last write; a new day triggers a prune.
+    last_day: Mutex<Option<String>>,
+}
+
+impl AuditLog {
+    pub fn new(dir: PathBuf, retention_days: u32) -> Self {
+        Self { dir, retention_days, last_day: Mutex::new(None) }
+    }
+
+    /// Log to [`audit_dir`] as configured by `PAPERS_AUDIT` and
+    /// `PAPERS_AUDIT_RETENTION_DAYS`; `None` when disabled.
+    pub fn from_env() -> Result<Option<Self>, AuditError> {
+        let enabled = std::env::var("PAPERS_AUDIT")
+            .map(|v| !matches!(v.trim().to_ascii_lowercase().as_str(), "off" | "0" | "false"))
+            .unwrap_or(true);
+        if !enabled {
+            return Ok(None);
+        }
+        let retention_days = match std::env::var("PAPERS_AUDIT_RETENTION_DAYS") {
+            Ok(v) if !v.trim().is_empty() => {
+                v.trim().parse().map_err(|_| AuditError::Retention(v))?
+            }
+            _ => DEFAULT_RETENTION_DAYS,
+        };
+        Ok(Some(Self::new(audit_dir(), retention_days)))
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// A log with the same retention writing to `dir`, e.g. a tenant's.
+    pub fn relocated(&self, dir: PathBuf) -> Self {
+        Self::new(dir, self.retention_days)
+    }
+
+    /// Append `record` to its day file, pruning first if this is the first
+    /// write of a new day.
+    pub fn record(&self, record: &AuditRecord) -> Result<(), AuditError> {
+        let day = record.day().to_string();
+        let mut last_day = self.last_day.lock().unwrap_or_else(|e| e.into_inner());
+        if last_day.as_deref() != Some(day.as_str()) {
+            std::fs::create_dir_all(&self.dir)?;
+            self.prune()?;
+            *last_day = Some(day.clone());
+        }
+        let mut line = serde_json::to_string(record)?;
+        line.push('\n');
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.dir.join(format!("{day}.jsonl")))?;
+        file.write_all(line.as_bytes())?;
+        Ok(())
+    }
+
+    /// Delete day files older than the retention period. Returns how many
+    /// were removed.
+    pub fn prune(&self) -> Result<usize, AuditError> {
+        if self.retention_days == 0 {
+            return Ok(0);
+        }
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+        let oldest = now.saturating_sub(u64::from(self.retention_days - 1) * 86_400);
+        let cutoff = crate::text::iso_from_secs(oldest);
+        self.prune_before(&cutoff[..10])
+    }
+
+    fn prune_before(&self, cutoff_day: &str) -> Result<usize, AuditError> {
+        let mut removed = 0;
+        for (day, path) in day_files(&self.dir)? {
+            if day.as_str() < cutoff_day {
+                std::fs::remove_file(path)?;
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+}
+
+/// `(day, path)` for each `YYYY-MM-DD.jsonl` in `dir`, oldest first. Empty if
+/// `dir` doesn't exist.
+fn day_files(dir: &Path) -> Result<Vec<(String, PathBuf)>, AuditError> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+    let mut files: Vec<(String, PathBuf)> = entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let day = path.file_name()?.to_str()?.strip_suffix(".jsonl")?.to_string();
+            let is_day = day.len() == 10
+                && day.bytes().enumerate().all(|(i, b)| {
+                    if i == 4 || i == 7 { b == b'-' } else { b.is_ascii_digit() }
+                });
+            is_day.then_some((day, path))
+        })
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+/// Write the records in `dir` matching `filter` to `out` as JSONL, oldest
+/// first. Unreadable lines are skipped. Returns the number written.
+pub fn export(dir: &Path, filter: &AuditFilter, out: &mut dyn Write) -> Result<usize, AuditError> {
+    let mut written = 0;
+    for (day, path) in day_files(dir)? {
+        if !filter.includes_day(&day) {
+            continue;
+        }
+        let content = std::fs::read_to_string(&path)?;
+        for line in content.lines() {
+            let Ok(record) = serde_json::from_str::<AuditRecord>(line) else {
+                continue;
+            };
+            if filter.matches(&record) {
+                serde_json::to_writer(&mut *out, &record)?;
+                out.write_all(b"\n")?;
+                written += 1;
+            }
+        }
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn record(ts: &str, user: &str, tool: &str) -> AuditRecord {
+        AuditRecord {
+            ts: ts.to_string(),
+            user: Some(user.to_string()),
+            session: Some("s1".to_string()),
+            tool: tool.to_string(),
+            ids: BTreeMap::from([("id".to_string(), "W1".to_string())]),
+            ok: true,
+            duration_ms: 12,
+        }
+    }
+
+    #[test]
+    fn test_audited_ids_keeps_only_identifiers() {
+        let args = serde_json::json!({
+            "id": "W2741809807",
+            "chapter_idx": 2,
+            "query": "private search terms",
+            "filter": "author:Smith",
+            "key": ["not", "a", "scalar"],
+        });
+        let ids = audited_ids(args.as_object().unwrap());
+        assert_eq!(
+            ids,
+            BTreeMap::from([
+                ("chapter_idx".to_string(), "2".to_string()),
+                ("id".to_string(), "W2741809807".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_record_and_export_filters() {
+        let dir = TempDir::new().unwrap();
+        let log = AuditLog::new(dir.path().to_path_buf(), 0);
+        log.record(&record("2026-01-01T10:00:00Z", "alice", "work_get")).unwrap();
+        log.record(&record("2026-01-01T11:00:00Z", "bob", "work_get")).unwrap();
+        log.record(&record("2026-01-02T09:00:00Z", "alice", "db_chunk_search")).unwrap();
+        assert!(dir.path().join("2026-01-01.jsonl").exists());
+
+        let mut out = Vec::new();
+        let n = export(dir.path(), &AuditFilter::default(), &mut out).unwrap();
+        assert_eq!(n, 3);
+        let first: AuditRecord =
+            serde_json::from_str(String::from_utf8(out).unwrap().lines().next().unwrap()).unwrap();
+        assert_eq!(first, record("2026-01-01T10:00:00Z", "alice", "work_get"));
+
+        let filter = AuditFilter { user: Some("alice".into()), ..Default::default() };
+        assert_eq!(export(dir.path(), &filter, &mut Vec::new()).unwrap(), 2);
+        let filter = AuditFilter { since: Some("2026-01-02".into()), ..Default::default() };
+        assert_eq!(export(dir.path(), &filter, &mut Vec::new()).unwrap(), 1);
+        let filter = AuditFilter { tool: Some("work_get".into()), until: Some("2026-01-01".into()), ..Default::default() };
+        assert_eq!(export(dir.path(), &filter, &mut Vec::new()).unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_tenants_get_their_own_audit_dir() {
+        let tenant = TempDir::new().unwrap();
+        let dir = crate::tenant::scope(tenant.path(), async { audit_dir() }).await;
+        assert_eq!(dir, audit_dir_in(tenant.path()));
+        assert_ne!(audit_dir(), dir);
+
+        let log = AuditLog::new(PathBuf::from("/var/audit"), 30).relocated(dir.clone());
+        assert_eq!((log.dir(), log.retention_days), (dir.as_path(), 30));
+    }
+
+    #[test]
+    fn test_prune_removes_only_old_day_files() {
+        let dir = TempDir::new().unwrap();
+        for name in ["2020-01-01.jsonl", "2020-01-02.jsonl", "notes.jsonl"] {
+            std::fs::write(dir.path().join(name), "").unwrap();
+        }
+        let log = AuditLog::new(dir.path().to_path_buf(), 30);
+        assert_eq!(log.prune_before("2020-01-02").unwrap(), 1);
+        assert!(!dir.path().join("2020-01-01.jsonl").exists());
+        assert!(dir.path().join("2020-01-02.jsonl").exists());
+        assert!(dir.path().join("notes.jsonl").exists());
+        assert_eq!(log.prune().unwrap(), 1);
+        assert_eq!(AuditLog::new(dir.path().to_path_buf(), 0).prune().unwrap(), 0);
+    }
+}
@@ -0,0 +1,515 @@
+//! Formatted citations for a single work: APA (7th edition), MLA (9th
+//! edition), Chicago author-date (17th edition), and BibTeX.
+//!
+//! The reference-list entries follow the CSL styles of the same names for
+//! the common item types — journal articles, preprints, conference papers,
+//! chapters, books, theses, and reports — and are rendered as plain text:
+//! titles are kept as published (no case conversion) and italics are not
+//! marked. Metadata comes from the OpenAlex work via
+//! [`record_from_work`], the same record `selection export` uses.
+
+use papers_openalex::{GetParams, OpenAlexClient};
+use serde::Serialize;
+
+use crate::export::{
+    CitationRecord, PersonName, WORK_EXPORT_SELECT, generated_key, record_from_work, to_bibtex,
+};
+use crate::filter::FilterError;
+
+#[derive(thiserror::Error, Debug)]
+pub enum CiteError {
+    #[error(transparent)]
+    Filter(#[from] FilterError),
+    #[error("invalid citation style {0:?}: use apa, mla, chicago, or bibtex")]
+    InvalidStyle(String),
+}
+
+/// Citation style for [`format_citation`].
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum CitationStyle {
+    Apa,
+    Mla,
+    Chicago,
+    Bibtex,
+}
+
+impl CitationStyle {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CitationStyle::Apa => "apa",
+            CitationStyle::Mla => "mla",
+            CitationStyle::Chicago => "chicago",
+            CitationStyle::Bibtex => "bibtex",
+        }
+    }
+}
+
+impl std::str::FromStr for CitationStyle {
+    type Err = CiteError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().replace('_', "-").as_str() {
+            "apa" | "apa7" => Ok(CitationStyle::Apa),
+            "mla" | "mla9" => Ok(CitationStyle::Mla),
+            "chicago" | "chicago-author-date" => Ok(CitationStyle::Chicago),
+            "bibtex" | "bib" => Ok(CitationStyle::Bibtex),
+            _ => Err(CiteError::InvalidStyle(s.to_string())),
+        }
+    }
+}
+
+/// A work's citation in one style.
+#[derive(Serialize, Debug)]
+pub struct FormattedCitation {
+    /// Short OpenAlex ID of the cited work.
+    pub id: String,
+    pub style: CitationStyle,
+    /// Reference-list entry, or the BibTeX entry.
+    pub citation: String,
+    /// Parenthetical in-text citation, or `\cite{key}` for BibTeX.
+    pub in_text: String,
+    /// Generated citation key, e.g. `vaswani2017attention`.
+    pub key: String,
+}
+
+/// Fetch work `id` (OpenAlex ID, DOI, or title) and format it in `style`.
+pub async fn format_citation(
+    client: &OpenAlexClient,
+    id: &str,
+    style: CitationStyle,
+) -> Result<FormattedCitation, CiteError> {
+    let params = GetParams { select: Some(WORK_EXPORT_SELECT.to_string()) };
+    let work = crate::api::work_get(client, id, &params).await?;
+    let mut rec = record_from_work(&work);
+    rec.key = generated_key(&rec);
+    Ok(FormattedCitation {
+        id: work.id.rsplit('/').next().unwrap_or(&work.id).to_string(),
+        style,
+        citation: format_record(&rec, style),
+        in_text: in_text(&rec, style),
+        key: rec.key,
+    })
+}
+
+// ── Shared pieces ──────────────────────────────────────────────────────────
+
+/// How a record's source is cited.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    /// Article in a journal, magazine, newspaper, or preprint server.
+    Article,
+    /// Part of a larger work: conference paper or book chapter.
+    Part,
+    /// Stands on its own: book, thesis, report, or anything without a container.
+    Standalone,
+}
+
+fn kind(rec: &CitationRecord) -> Kind {
+    match rec.item_type.as_str() {
+        "conferencePaper" | "bookSection" => Kind::Part,
+        "book" | "thesis" | "report" => Kind::Standalone,
+        _ if rec.container_title.is_some() => Kind::Article,
+        _ => Kind::Standalone,
+    }
+}
+
+fn family(p: &PersonName) -> &str {
+    p.family.as_deref().or(p.literal.as_deref()).unwrap_or("")
+}
+
+/// "Family, Given", or the literal name.
+fn inverted(p: &PersonName) -> String {
+    match (&p.family, &p.given) {
+        (Some(f), Some(g)) => format!("{f}, {g}"),
+        _ => family(p).to_string(),
+    }
+}
+
+/// "Given Family", or the literal name.
+fn natural(p: &PersonName) -> String {
+    match (&p.family, &p.given) {
+        (Some(f), Some(g)) => format!("{g} {f}"),
+        _ => family(p).to_string(),
+    }
+}
+
+/// "Ada M." → "A. M."; "Jean-Paul" → "J.-P."
+fn initials(given: &str) -> String {
+    given
+        .split_whitespace()
+        .map(|part| {
+            part.split('-')
+                .filter_map(|s| s.chars().find(|c| c.is_alphabetic()))
+                .map(|c| format!("{}.", c.to_uppercase()))
+                .collect::<Vec<_>>()
+                .join("-")
+        })
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// `s` followed by a period unless it already ends in terminal punctuation.
+fn sentence(s: &str) -> String {
+    let s = s.trim();
+    if s.ends_with(['.', '?', '!']) { s.to_string() } else { format!("{s}.") }
+}
+
+/// `"Title."`, keeping a title's own `?` or `!` inside the quotes.
+fn quoted_title(title: &str) -> String {
+    format!("\"{}\"", sentence(title))
+}
+
+fn title(rec: &CitationRecord) -> &str {
+    rec.title.as_deref().unwrap_or("Untitled")
+}
+
+/// The first few words of the title, for in-text citations of anonymous works.
+fn short_title(rec: &CitationRecord) -> String {
+    let words: Vec<_> = title(rec).split_whitespace().collect();
+    let short = words[..words.len().min(4)].join(" ");
+    format!("\"{}\"", short.trim_end_matches([':', ',', '.']))
+}
+
+fn page_range(pages: &str) -> String {
+    pages.replace("--", "–").replace('-', "–")
+}
+
+fn link(rec: &CitationRecord) -> Option<String> {
+    rec.doi.as_ref().map(|d| format!("https://doi.org/{d}")).or_else(|| rec.url.clone())
+}
+
+/// `names` joined with commas and `conjunction` before the last one, with
+/// a serial comma when there are three or more.
+fn join_names(names: &[String], conjunction: &str) -> String {
+    match names {
+        [] => String::new(),
+        [only] => only.clone(),
+        [a, b] => format!("{a} {conjunction} {b}"),
+        [rest @ .., last] => format!("{}, {conjunction} {last}", rest.join(", ")),
+    }
+}
+
+// ── Reference-list entries ────────────────────────────────────────────────
+
+/// Render `rec` as a reference-list entry (or BibTeX entry) in `style`.
+pub fn format_record(rec: &CitationRecord, style: CitationStyle) -> String {
+    match style {
+        CitationStyle::Apa => apa(rec),
+        CitationStyle::Mla => mla(rec),
+        CitationStyle::Chicago => chicago(rec),
+        CitationStyle::Bibtex => to_bibtex(rec).trim_end().to_string(),
+    }
+}
+
+fn apa_name(p: &PersonName) -> String {
+    match (&p.family, &p.given) {
+        (Some(f), Some(g)) if !initials(g).is_empty() => format!("{f}, {}", initials(g)),
+        _ => family(p).to_string(),
+    }
+}
+
+/// APA lists up to 20 authors; beyond that the first 19, an ellipsis, and the last.
+fn apa_authors(authors: &[PersonName]) -> String {
+    let names: Vec<_> = authors.iter().map(apa_name).collect();
+    match names.len() {
+        0 => String::new(),
+        1 => names[0].clone(),
+        2..=20 => format!("{}, & {}", names[..names.len() - 1].join(", "), names[names.len() - 1]),
+        _ => format!("{}, . . . {}", names[..19].join(", "), names[names.len() - 1]),
+    }
+}
+
+fn apa(rec: &CitationRecord) -> String {
+    let year = rec.year().map_or("n.d.".to_string(), |y| y.to_string());
+    let mut parts = Vec::new();
+    if rec.authors.is_empty() {
+        parts.push(sentence(title(rec)));
+        parts.push(format!("({year})."));
+    } else {
+        parts.push(sentence(&apa_authors(&rec.authors)));
+        parts.push(format!("({year})."));
+        parts.push(sentence(title(rec)));
+    }
+    let container = rec.container_title.as_deref();
+    match kind(rec) {
+        Kind::Article => {
+            let mut source = container.unwrap_or_default().to_string();
+            match (&rec.volume, &rec.issue) {
+                (Some(v), Some(i)) => source.push_str(&format!(", {v}({i})")),
+                (Some(v), None) => source.push_str(&format!(", {v}")),
+                (None, Some(i)) => source.push_str(&format!(", ({i})")),
+                (None, None) => {}
+            }
+            if let Some(p) = &rec.pages {
+                source.push_str(&format!(", {}", page_range(p)));
+            }
+            parts.push(sentence(&source));
+        }
+        Kind::Part => {
+            if let Some(c) = container {
+                let pages = rec.pages.as_deref().map_or(String::new(), |p| format!(" (pp. {})", page_range(p)));
+                parts.push(format!("In {c}{pages}."));
+            }
+            parts.extend(rec.publisher.as_deref().map(sentence));
+        }
+        Kind::Standalone => parts.extend(rec.publisher.as_deref().map(sentence)),
+    }
+    parts.extend(link(rec));
+    parts.join(" ")
+}
+
+/// MLA names the first author inverted, a second author in natural order,
+/// and shortens three or more to "et al."
+fn mla_authors(authors: &[PersonName]) -> String {
+    match authors {
+        [] => String::new(),
+        [only] => inverted(only),
+        [a, b] => format!("{}, and {}", inverted(a), natural(b)),
+        [first, ..] => format!("{}, et al", inverted(first)),
+    }
+}
+
+fn mla(rec: &CitationRecord) -> String {
+    let mut parts = Vec::new();
+    if !rec.authors.is_empty() {
+        parts.push(sentence(&mla_authors(&rec.authors)));
+    }
+    let kind = kind(rec);
+    parts.push(match kind {
+        Kind::Standalone => sentence(title(rec)),
+        _ => quoted_title(title(rec)),
+    });
+    let mut elements: Vec<String> = Vec::new();
+    if kind != Kind::Standalone {
+        elements.extend(rec.container_title.clone());
+    }
+    elements.extend(rec.volume.as_ref().map(|v| format!("vol. {v}")));
+    elements.extend(rec.issue.as_ref().map(|i| format!("no. {i}")));
+    if kind != Kind::Article {
+        elements.extend(rec.publisher.clone());
+    }
+    elements.extend(rec.year().map(|y| y.to_string()));
+    if let Some(p) = &rec.pages {
+        let prefix = if p.contains(['-', '–']) { "pp." } else { "p." };
+        elements.push(format!("{prefix} {}", page_range(p)));
+    }
+    if !elements.is_empty() {
+        parts.push(sentence(&elements.join(", ")));
+    }
+    parts.extend(link(rec).map(|l| format!("{l}.")));
+    parts.join(" ")
+}
+
+/// Chicago lists up to ten authors; beyond that the first seven and "et al."
+fn chicago_authors(authors: &[PersonName]) -> String {
+    let Some((first, rest)) = authors.split_first() else {
+        return String::new();
+    };
+    if authors.len() > 10 {
+        let names: Vec<_> =
+            std::iter::once(inverted(first)).chain(rest[..6].iter().map(natural)).collect();
+        return format!("{}, et al", names.join(", "));
+    }
+    let names: Vec<_> = std::iter::once(inverted(first)).chain(rest.iter().map(natural)).collect();
+    match names.len() {
+        2 => format!("{}, and {}", names[0], names[1]),
+        _ => join_names(&names, "and"),
+    }
+}
+
+fn chicago(rec: &CitationRecord) -> String {
+    let year = rec.year().map_or("n.d.".to_string(), |y| y.to_string());
+    let kind = kind(rec);
+    let title = match kind {
+        Kind::Standalone => sentence(title(rec)),
+        _ => quoted_title(title(rec)),
+    };
+    let mut parts = Vec::new();
+    if rec.authors.is_empty() {
+        parts.push(title);
+        parts.push(sentence(&year));
+    } else {
+        parts.push(sentence(&chicago_authors(&rec.authors)));
+        parts.push(sentence(&year));
+        parts.push(title);
+    }
+    let container = rec.container_title.as_deref();
+    match kind {
+        Kind::Article => {
+            let mut source = container.unwrap_or_default().to_string();
+            if let Some(v) = &rec.volume {
+                source.push_str(&format!(" {v}"));
+            }
+            if let Some(i) = &rec.issue {
+                source.push_str(&format!(" ({i})"));
+            }
+            if let Some(p) = &rec.pages {
+                let sep = if rec.volume.is_some() || rec.issue.is_some() { ":" } else { "," };
+                source.push_str(&format!("{sep} {}", page_range(p)));
+            }
+            parts.push(sentence(&source));
+        }
+        Kind::Part => {
+            if let Some(c) = container {
+                let pages = rec.pages.as_deref().map_or(String::new(), |p| format!(", {}", page_range(p)));
+                parts.push(format!("In {c}{pages}."));
+            }
+            parts.extend(rec.publisher.as_deref().map(sentence));
+        }
+        Kind::Standalone => parts.extend(rec.publisher.as_deref().map(sentence)),
+    }
+    parts.extend(link(rec).map(|l| format!("{l}.")));
+    parts.join(" ")
+}
+
+// ── In-text citations ─────────────────────────────────────────────────────
+
+/// The parenthetical in-text citation for `rec` in `style`.
+pub fn in_text(rec: &CitationRecord, style: CitationStyle) -> String {
+    let names: Vec<String> = rec.authors.iter().map(|a| family(a).to_string()).collect();
+    let year = rec.year().map_or("n.d.".to_string(), |y| y.to_string());
+    match style {
+        CitationStyle::Apa => {
+            let who = match names.as_slice() {
+                [] => short_title(rec),
+                [a] => a.clone(),
+                [a, b] => format!("{a} & {b}"),
+                [a, ..] => format!("{a} et al."),
+            };
+            format!("({who}, {year})")
+        }
+        CitationStyle::Mla => {
+            let who = match names.as_slice() {
+                [] => short_title(rec),
+                [a] => a.clone(),
+                [a, b] => format!("{a} and {b}"),
+                [a, ..] => format!("{a} et al."),
+            };
+            format!("({who})")
+        }
+        CitationStyle::Chicago => {
+            let who = match names.as_slice() {
+                [] => short_title(rec),
+                [_, _, _, _, ..] => format!("{} et al.", names[0]),
+                _ => join_names(&names, "and"),
+            };
+            format!("({who} {year})")
+        }
+        CitationStyle::Bibtex => format!("\\cite{{{}}}", rec.key),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::RecordSource;
+
+    fn record(item_type: &str, authors: &[&str]) -> CitationRecord {
+        CitationRecord {
+            key: "key".to_string(),
+            source: RecordSource::OpenAlex,
+            item_type: item_type.to_string(),
+            title: None,
+            authors: authors.iter().map(|a| PersonName::from_display(a)).collect(),
+            date_parts: vec![2017],
+            container_title: None,
+            publisher: None,
+            volume: None,
+            issue: None,
+            pages: None,
+            doi: None,
+            url: None,
+            issn: None,
+            isbn: None,
+            language: None,
+        }
+    }
+
+    fn article() -> CitationRecord {
+        CitationRecord {
+            title: Some("Deep residual learning".to_string()),
+            container_title: Some("Journal of Vision".to_string()),
+            volume: Some("12".to_string()),
+            issue: Some("3".to_string()),
+            pages: Some("100-110".to_string()),
+            doi: Some("10.1/jov.12.3".to_string()),
+            ..record("journalArticle", &["Kaiming He", "Jean-Paul Sartre"])
+        }
+    }
+
+    #[test]
+    fn test_journal_article_styles() {
+        let rec = article();
+        assert_eq!(
+            format_record(&rec, CitationStyle::Apa),
+            "He, K., & Sartre, J.-P. (2017). Deep residual learning. Journal of Vision, 12(3), 100–110. https://doi.org/10.1/jov.12.3"
+        );
+        assert_eq!(
+            format_record(&rec, CitationStyle::Mla),
+            "He, Kaiming, and Jean-Paul Sartre. \"Deep residual learning.\" Journal of Vision, vol. 12, no. 3, 2017, pp. 100–110. https://doi.org/10.1/jov.12.3."
+        );
+        assert_eq!(
+            format_record(&rec, CitationStyle::Chicago),
+            "He, Kaiming, and Jean-Paul Sartre. 2017. \"Deep residual learning.\" Journal of Vision 12 (3): 100–110. https://doi.org/10.1/jov.12.3."
+        );
+        assert!(format_record(&rec, CitationStyle::Bibtex).starts_with("@article{key,"));
+        assert_eq!(in_text(&rec, CitationStyle::Apa), "(He & Sartre, 2017)");
+        assert_eq!(in_text(&rec, CitationStyle::Mla), "(He and Sartre)");
+        assert_eq!(in_text(&rec, CitationStyle::Chicago), "(He and Sartre 2017)");
+        assert_eq!(in_text(&rec, CitationStyle::Bibtex), "\\cite{key}");
+    }
+
+    #[test]
+    fn test_conference_paper_with_many_authors() {
+        let rec = CitationRecord {
+            title: Some("Attention is all you need?".to_string()),
+            container_title: Some("Advances in Neural Information Processing Systems".to_string()),
+            pages: Some("5998-6008".to_string()),
+            ..record("conferencePaper", &["Ashish Vaswani", "Noam Shazeer", "Niki Parmar", "Jakob Uszkoreit"])
+        };
+        assert_eq!(
+            format_record(&rec, CitationStyle::Apa),
+            "Vaswani, A., Shazeer, N., Parmar, N., & Uszkoreit, J. (2017). Attention is all you need? In Advances in Neural Information Processing Systems (pp. 5998–6008)."
+        );
+        assert_eq!(
+            format_record(&rec, CitationStyle::Mla),
+            "Vaswani, Ashish, et al. \"Attention is all you need?\" Advances in Neural Information Processing Systems, 2017, pp. 5998–6008."
+        );
+        assert_eq!(
+            format_record(&rec, CitationStyle::Chicago),
+            "Vaswani, Ashish, Noam Shazeer, Niki Parmar, and Jakob Uszkoreit. 2017. \"Attention is all you need?\" In Advances in Neural Information Processing Systems, 5998–6008."
+        );
+        assert_eq!(in_text(&rec, CitationStyle::Apa), "(Vaswani et al., 2017)");
+        assert_eq!(in_text(&rec, CitationStyle::Chicago), "(Vaswani et al. 2017)");
+    }
+
+    #[test]
+    fn test_book_without_author_or_year() {
+        let rec = CitationRecord {
+            title: Some("The Chicago Manual of Style".to_string()),
+            publisher: Some("University of Chicago Press".to_string()),
+            date_parts: Vec::new(),
+            ..record("book", &[])
+        };
+        assert_eq!(
+            format_record(&rec, CitationStyle::Apa),
+            "The Chicago Manual of Style. (n.d.). University of Chicago Press."
+        );
+        assert_eq!(format_record(&rec, CitationStyle::Mla), "The Chicago Manual of Style. University of Chicago Press.");
+        assert_eq!(
+            format_record(&rec, CitationStyle::Chicago),
+            "The Chicago Manual of Style. n.d. University of Chicago Press."
+        );
+        assert_eq!(in_text(&rec, CitationStyle::Apa), "(\"The Chicago Manual of\", n.d.)");
+    }
+
+    #[test]
+    fn test_style_parsing() {
+        assert_eq!("APA".parse::<CitationStyle>().unwrap(), CitationStyle::Apa);
+        assert_eq!("chicago_author_date".parse::<CitationStyle>().unwrap(), CitationStyle::Chicago);
+        assert_eq!("bib".parse::<CitationStyle>().unwrap(), CitationStyle::Bibtex);
+        assert!(matches!("harvard".parse::<CitationStyle>(), Err(CiteError::InvalidStyle(_))));
+    }
+}
@@ -16,19 +16,76 @@ pub enum ConfigError {
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub struct PapersConfig {
     pub embedding_model: String,
+    /// Directory for per-paper Markdown notes (see [`crate::vault`]).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vault_dir: Option<String>,
+    /// Where to deliver new-result notifications (see [`crate::notify`]).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub webhooks: Vec<crate::notify::Webhook>,
+    /// Email delivery of notifications (see [`crate::notify`]).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<crate::notify::EmailConfig>,
+    /// Periodic tasks for the MCP server (see [`crate::schedule`]).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub schedule: Vec<crate::schedule::ScheduleEntry>,
+    /// Zotero collections (keys or names) `db watch` follows; empty follows
+    /// the whole library (see [`crate::sync`]).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub watch_collections: Vec<String>,
+    /// Search queries the scheduled `digest` task watches (see [`crate::schedule`]).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub digest_watches: Vec<String>,
+    /// Selections whose new citers the scheduled `digest` task reports.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub digest_selections: Vec<String>,
+    /// Order of the `work_text` PDF sources; empty uses the default chain
+    /// (see [`crate::text::TextSourceConfig`]).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub text_sources: Vec<crate::text::TextSource>,
+    /// Contact email for Unpaywall lookups.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unpaywall_email: Option<String>,
+    /// Institutional link resolver URL with a `{doi}` placeholder.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub link_resolver: Option<String>,
+    /// Embed the abstract of every paper added to a selection into the
+    /// abstracts tier (see [`crate::abstracts`]).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub auto_index_abstracts: bool,
 }
 
 impl Default for PapersConfig {
     fn default() -> Self {
         Self {
             embedding_model: "embedding-gemma-300m".to_string(),
+            vault_dir: None,
+            webhooks: Vec::new(),
+            email: None,
+            schedule: Vec::new(),
+            watch_collections: Vec::new(),
+            digest_watches: Vec::new(),
+            digest_selections: Vec::new(),
+            text_sources: Vec::new(),
+            unpaywall_email: None,
+            link_resolver: None,
+            auto_index_abstracts: false,
         }
     }
 }
 
 impl PapersConfig {
-    /// Returns `<config_dir>/.papers/config.json`.
+    /// Returns `<config_dir>/.papers/config.json`, or the tenant's
+    /// `config.json` inside a [`crate::tenant::scope`].
     pub fn config_path() -> PathBuf {
+        match crate::tenant::current_data_dir() {
+            Some(dir) => dir.join("config.json"),
+            None => Self::shared_config_path(),
+        }
+    }
+
+    /// Returns `<config_dir>/.papers/config.json`, whatever the enclosing
+    /// [`crate::tenant::scope`].
+    pub fn shared_config_path() -> PathBuf {
         dirs::config_dir()
             .unwrap_or_else(|| PathBuf::from("."))
             .join(".papers")
@@ -72,6 +129,14 @@ mod tests {
     use super::*;
     use tempfile::TempDir;
 
+    #[tokio::test]
+    async fn test_tenants_have_their_own_config() {
+        let tenant = TempDir::new().unwrap();
+        let path = crate::tenant::scope(tenant.path(), async { PapersConfig::config_path() }).await;
+        assert_eq!(path, tenant.path().join("config.json"));
+        assert_eq!(PapersConfig::config_path(), PapersConfig::shared_config_path());
+    }
+
     #[test]
     fn test_config_default() {
         let cfg = PapersConfig::default();
@@ -84,12 +149,44 @@ mod tests {
         let path = dir.path().join("config.json");
         let cfg = PapersConfig {
             embedding_model: "embedding-gemma-300m".to_string(),
+            vault_dir: Some("/notes/papers".to_string()),
+            webhooks: vec![crate::notify::Webhook {
+                url: "https://hooks.slack.com/services/T/B/x".to_string(),
+                kind: crate::notify::WebhookKind::Slack,
+            }],
+            email: Some(crate::notify::EmailConfig {
+                smtp_url: "smtps://me@mail.example.com".to_string(),
+                from: "papers@example.com".to_string(),
+                to: vec!["me@example.com".to_string()],
+            }),
+            schedule: vec![crate::schedule::ScheduleEntry {
+                task: crate::schedule::ScheduledTask::CacheEvict,
+                every: "6h".to_string(),
+            }],
+            watch_collections: vec!["Reading Group".to_string()],
+            digest_watches: vec!["gaussian splatting".to_string()],
+            digest_selections: vec!["my-papers".to_string()],
+            text_sources: vec![crate::text::TextSource::Unpaywall, crate::text::TextSource::OaUrls],
+            unpaywall_email: Some("me@example.com".to_string()),
+            link_resolver: Some("https://resolver.example.edu/?doi={doi}".to_string()),
+            auto_index_abstracts: true,
         };
         let json = serde_json::to_vec_pretty(&cfg).unwrap();
         std::fs::write(&path, &json).unwrap();
         let bytes = std::fs::read(&path).unwrap();
         let loaded: PapersConfig = serde_json::from_slice(&bytes).unwrap();
         assert_eq!(loaded.embedding_model, cfg.embedding_model);
+        assert_eq!(loaded.vault_dir, cfg.vault_dir);
+        assert_eq!(loaded.webhooks, cfg.webhooks);
+        assert_eq!(loaded.email, cfg.email);
+        assert_eq!(loaded.schedule, cfg.schedule);
+        assert_eq!(loaded.watch_collections, cfg.watch_collections);
+        assert_eq!(loaded.digest_watches, cfg.digest_watches);
+        assert_eq!(loaded.digest_selections, cfg.digest_selections);
+        assert_eq!(loaded.text_sources, cfg.text_sources);
+        assert_eq!(loaded.unpaywall_email, cfg.unpaywall_email);
+        assert_eq!(loaded.link_resolver, cfg.link_resolver);
+        assert_eq!(loaded.auto_index_abstracts, cfg.auto_index_abstracts);
     }
 
     #[test]
@@ -0,0 +1,155 @@
+//! Crossref fallback for DOIs OpenAlex doesn't know yet.
+//!
+//! Crossref learns about a DOI when it is registered; OpenAlex can take weeks
+//! to pick it up, so very recent preprints often 404 there. Callers that
+//! resolve a DOI try OpenAlex first and fall back to Crossref, mapping the
+//! record onto the same shapes ([`Work`] here,
+//! [`SelectionEntry`](crate::SelectionEntry) in
+//! [`fill_from_crossref_work`](crate::selection::fill_from_crossref_work)) so
+//! nothing downstream needs a second code path.
+
+use papers_openalex::Work;
+use serde_json::json;
+
+pub use papers_crossref::{CrossrefClient, CrossrefError, CrossrefWork};
+
+/// Value of [`Work::indexed_in`] for works built from Crossref metadata.
+pub const CROSSREF_SOURCE: &str = "crossref";
+
+/// Whether `work` came from [`work_from_crossref`] rather than OpenAlex.
+pub fn is_crossref_work(work: &Work) -> bool {
+    !work.id.starts_with("https://openalex.org/")
+        && work.indexed_in.as_ref().is_some_and(|i| i.iter().any(|s| s == CROSSREF_SOURCE))
+}
+
+/// OpenAlex's simplified work type for a Crossref type.
+fn openalex_type(crossref_type: &str) -> &'static str {
+    match crossref_type {
+        "journal-article" | "proceedings-article" => "article",
+        "posted-content" => "preprint",
+        "book-chapter" | "book-section" | "book-part" => "book-chapter",
+        "book" | "monograph" | "edited-book" | "reference-book" => "book",
+        "dissertation" => "dissertation",
+        "dataset" => "dataset",
+        "report" => "report",
+        "standard" => "standard",
+        "peer-review" => "peer-review",
+        _ => "other",
+    }
+}
+
+/// Map a Crossref record onto an OpenAlex [`Work`].
+///
+/// There is no OpenAlex ID, so `id` is the DOI URL and `indexed_in` is
+/// `["crossref"]` (see [`is_crossref_work`]). Fields Crossref doesn't carry
+/// (topics, open-access status, referenced works, ...) are left empty.
+pub fn work_from_crossref(cr: &CrossrefWork) -> Work {
+    let doi_url = format!("https://doi.org/{}", cr.doi);
+    let last = cr.author.len().saturating_sub(1);
+    let authorships: Vec<_> = cr
+        .author
+        .iter()
+        .enumerate()
+        .map(|(i, a)| {
+            let position = match i {
+                0 => "first",
+                i if i == last => "last",
+                _ => "middle",
+            };
+            let name = a.display_name();
+            json!({
+                "author_position": position,
+                "author": {"id": null, "display_name": name, "orcid": a.orcid},
+                "raw_author_name": name,
+            })
+        })
+        .collect();
+    let source = cr.container_title.first().map(|title| {
+        json!({
+            "display_name": title,
+            "issn_l": cr.issn.first(),
+            "issn": (!cr.issn.is_empty()).then_some(&cr.issn),
+            "host_organization_name": cr.publisher,
+        })
+    });
+    let (first_page, last_page) = cr.pages();
+    let title = cr.title();
+    let work = json!({
+        "id": doi_url,
+        "doi": doi_url,
+        "title": title,
+        "display_name": title,
+        "publication_year": cr.year(),
+        "publication_date": cr.issued.as_ref().and_then(|d| d.iso_date()),
+        "language": cr.language,
+        "type": cr.r#type.as_deref().map(openalex_type),
+        "type_crossref": cr.r#type,
+        "indexed_in": [CROSSREF_SOURCE],
+        "ids": {"doi": doi_url},
+        "primary_location": {"landing_page_url": cr.url.as_ref().unwrap_or(&doi_url), "source": source},
+        "authorships": authorships,
+        "biblio": {"volume": cr.volume, "issue": cr.issue, "first_page": first_page, "last_page": last_page},
+        "cited_by_count": cr.is_referenced_by_count,
+        "abstract": cr.abstract_text(),
+    });
+    serde_json::from_value(work).expect("Crossref fields map onto Work")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn preprint() -> CrossrefWork {
+        serde_json::from_value(json!({
+            "DOI": "10.1101/2024.05.01.592000",
+            "title": ["A very recent preprint"],
+            "author": [
+                {"given": "Ada", "family": "Lovelace", "ORCID": "https://orcid.org/0000-0002-1825-0097"},
+                {"given": "Charles", "family": "Babbage"},
+                {"name": "The Analytical Engine Consortium"}
+            ],
+            "type": "posted-content",
+            "issued": {"date-parts": [[2024, 5, 3]]},
+            "container-title": ["bioRxiv"],
+            "page": "1-12",
+            "is-referenced-by-count": 2,
+            "abstract": "<jats:p>Engines compute.</jats:p>",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_work_from_crossref() {
+        let work = work_from_crossref(&preprint());
+        assert_eq!(work.id, "https://doi.org/10.1101/2024.05.01.592000");
+        assert_eq!(work.doi.as_deref(), Some("https://doi.org/10.1101/2024.05.01.592000"));
+        assert_eq!(work.display_name.as_deref(), Some("A very recent preprint"));
+        assert_eq!(work.publication_year, Some(2024));
+        assert_eq!(work.publication_date.as_deref(), Some("2024-05-03"));
+        assert_eq!(work.r#type.as_deref(), Some("preprint"));
+        assert_eq!(work.type_crossref.as_deref(), Some("posted-content"));
+        assert_eq!(work.cited_by_count, Some(2));
+        assert_eq!(work.abstract_text.as_deref(), Some("Engines compute."));
+        let authorships = work.authorships.as_ref().unwrap();
+        let positions: Vec<_> = authorships.iter().map(|a| a.author_position.as_deref().unwrap()).collect();
+        assert_eq!(positions, ["first", "middle", "last"]);
+        let author = authorships[0].author.as_ref().unwrap();
+        assert_eq!(author.display_name.as_deref(), Some("Ada Lovelace"));
+        let source = work.primary_location.as_ref().unwrap().source.as_ref().unwrap();
+        assert_eq!(source.display_name.as_deref(), Some("bioRxiv"));
+        assert_eq!(work.biblio.as_ref().unwrap().last_page.as_deref(), Some("12"));
+        assert!(is_crossref_work(&work));
+    }
+
+    #[test]
+    fn test_fill_from_crossref_work_keeps_existing_fields() {
+        let mut entry = crate::selection::empty_entry();
+        entry.title = Some("Zotero title".into());
+        crate::selection::fill_from_crossref_work(&mut entry, &preprint());
+        assert_eq!(entry.title.as_deref(), Some("Zotero title"));
+        assert_eq!(entry.doi.as_deref(), Some("10.1101/2024.05.01.592000"));
+        assert_eq!(entry.year, Some(2024));
+        assert_eq!(entry.authors.as_ref().unwrap().len(), 3);
+        assert_eq!(entry.work_type.as_deref(), Some("posted-content"));
+    }
+}
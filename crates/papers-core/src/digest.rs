@@ -0,0 +1,340 @@
+//! Daily digest: what is new since yesterday (or the last `days` days) in one
+//! report, meant as the single morning call an agent makes.
+//!
+//! A digest has three sections:
+//!
+//! - **Watches** — works published in the window that match standing search
+//!   queries. OpenAlex only exposes creation dates to premium keys, so "new"
+//!   means `from_publication_date`.
+//! - **New citations** — works published in the window that cite the papers
+//!   of the given selections (your own papers, or any set you track).
+//! - **Zotero** — top-level items added to the library in the window.
+//!
+//! Sections whose source fails are left empty and the failure is listed in
+//! `warnings`, so one unreachable backend doesn't lose the whole digest.
+
+use std::collections::HashMap;
+
+use papers_openalex::Work;
+use papers_zotero::Item;
+use serde::Serialize;
+
+use crate::util::short_id;
+
+/// Fields fetched for watch hits and citing works.
+pub const DIGEST_SELECT: &str =
+    "id,display_name,doi,publication_year,publication_date,authorships,primary_location,cited_by_count,referenced_works";
+
+/// Default window, in days.
+pub const DEFAULT_DIGEST_DAYS: u32 = 1;
+
+/// Maximum window, in days.
+pub const DIGEST_MAX_DAYS: u32 = 90;
+
+/// Maximum works listed per watch.
+pub const DIGEST_WATCH_LIMIT: usize = 25;
+
+/// Maximum citing works fetched across all tracked papers.
+pub const DIGEST_CITERS_MAX: usize = 200;
+
+/// Maximum Zotero items listed.
+pub const DIGEST_ZOTERO_MAX: usize = 200;
+
+/// A work in the digest.
+#[derive(Debug, Clone, Serialize)]
+pub struct DigestWork {
+    /// Short OpenAlex work ID.
+    pub id: String,
+    pub title: Option<String>,
+    /// First three authors.
+    pub authors: Vec<String>,
+    pub publication_date: Option<String>,
+    pub venue: Option<String>,
+    pub doi: Option<String>,
+}
+
+impl DigestWork {
+    pub fn from_work(work: &Work) -> Self {
+        Self {
+            id: short_id(&work.id).to_string(),
+            title: work.display_name.clone().or_else(|| work.title.clone()),
+            authors: work
+                .authorships
+                .iter()
+                .flatten()
+                .filter_map(|a| a.author.as_ref()?.display_name.clone())
+                .take(3)
+                .collect(),
+            publication_date: work
+                .publication_date
+                .clone()
+                .or_else(|| work.publication_year.map(|y| y.to_string())),
+            venue: work
+                .primary_location
+                .as_ref()
+                .and_then(|l| l.source.as_ref())
+                .and_then(|s| s.display_name.clone()),
+            doi: work.doi.as_deref().map(|d| crate::selection::strip_doi_prefix(d).to_string()),
+        }
+    }
+}
+
+/// New works matching one watch query.
+#[derive(Debug, Clone, Serialize)]
+pub struct WatchHits {
+    pub query: String,
+    /// Total matches in the window; `works` lists at most
+    /// [`DIGEST_WATCH_LIMIT`] of them, newest first.
+    pub matched: i64,
+    pub works: Vec<DigestWork>,
+}
+
+/// A tracked paper with works that newly cite it.
+#[derive(Debug, Clone, Serialize)]
+pub struct CitedPaper {
+    /// Short OpenAlex work ID.
+    pub id: String,
+    pub title: Option<String>,
+    pub citers: Vec<DigestWork>,
+}
+
+/// A top-level Zotero item added in the window.
+#[derive(Debug, Clone, Serialize)]
+pub struct ZoteroAddition {
+    pub key: String,
+    pub item_type: String,
+    pub title: Option<String>,
+    /// First creator's last name (or single-field name).
+    pub creator: Option<String>,
+    pub date_added: String,
+}
+
+impl ZoteroAddition {
+    pub fn from_item(item: &Item) -> Self {
+        let creator = item.data.creators.first().and_then(|c| c.last_name.clone().or_else(|| c.name.clone()));
+        Self {
+            key: item.key.clone(),
+            item_type: item.data.item_type.clone(),
+            title: item.data.title.clone(),
+            creator,
+            date_added: item.data.date_added.clone().unwrap_or_default(),
+        }
+    }
+}
+
+/// Body of `digest_generate`.
+#[derive(Debug, Clone, Serialize)]
+pub struct Digest {
+    /// When the digest was generated (ISO 8601 UTC).
+    pub generated_at: String,
+    /// First day of the window (`YYYY-MM-DD`).
+    pub since: String,
+    pub days: u32,
+    pub watches: Vec<WatchHits>,
+    /// Tracked papers with new citations, most newly cited first.
+    pub cited: Vec<CitedPaper>,
+    /// Selections whose papers were checked for new citations.
+    pub selections: Vec<String>,
+    pub zotero: Vec<ZoteroAddition>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+    /// The digest rendered as Markdown.
+    pub markdown: String,
+}
+
+impl Digest {
+    /// Assemble a digest and render its Markdown.
+    pub fn new(
+        since: String,
+        days: u32,
+        watches: Vec<WatchHits>,
+        selections: Vec<String>,
+        cited: Vec<CitedPaper>,
+        zotero: Vec<ZoteroAddition>,
+        warnings: Vec<String>,
+    ) -> Self {
+        let mut digest = Self {
+            generated_at: crate::text::iso_now(),
+            since,
+            days,
+            watches,
+            cited,
+            selections,
+            zotero,
+            warnings,
+            markdown: String::new(),
+        };
+        digest.markdown = render_markdown(&digest);
+        digest
+    }
+}
+
+/// First day of a `days`-day window ending today (UTC), as `YYYY-MM-DD`.
+pub fn window_start(days: u32) -> String {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let start = now.saturating_sub(u64::from(days.saturating_sub(1)) * 86_400);
+    crate::text::iso_from_secs(start)[..10].to_string()
+}
+
+/// Group `citers` under the tracked papers (`tracked`: short ID → title) they
+/// reference. Papers without citers are dropped; the rest are ordered by
+/// number of citers, then ID.
+pub fn group_citations(tracked: &[(String, Option<String>)], citers: &[Work]) -> Vec<CitedPaper> {
+    let index: HashMap<&str, usize> = tracked.iter().enumerate().map(|(i, (id, _))| (id.as_str(), i)).collect();
+    let mut by_paper: Vec<Vec<DigestWork>> = vec![Vec::new(); tracked.len()];
+    for work in citers {
+        for reference in work.referenced_works.iter().flatten() {
+            if let Some(&i) = index.get(short_id(reference)) {
+                by_paper[i].push(DigestWork::from_work(work));
+            }
+        }
+    }
+    let mut cited: Vec<CitedPaper> = tracked
+        .iter()
+        .zip(by_paper)
+        .filter(|(_, citers)| !citers.is_empty())
+        .map(|((id, title), citers)| CitedPaper { id: id.clone(), title: title.clone(), citers })
+        .collect();
+    cited.sort_by(|a, b| b.citers.len().cmp(&a.citers.len()).then_with(|| a.id.cmp(&b.id)));
+    cited
+}
+
+fn work_line(work: &DigestWork) -> String {
+    let mut line = format!("- {}", work.title.as_deref().unwrap_or(&work.id));
+    if !work.authors.is_empty() {
+        line.push_str(&format!(" — {}", work.authors.join(", ")));
+    }
+    if let Some(venue) = &work.venue {
+        line.push_str(&format!(", *{venue}*"));
+    }
+    if let Some(date) = &work.publication_date {
+        line.push_str(&format!(" ({date})"));
+    }
+    match &work.doi {
+        Some(doi) => line.push_str(&format!(" [doi:{doi}](https://doi.org/{doi})")),
+        None => line.push_str(&format!(" `{}`", work.id)),
+    }
+    line
+}
+
+fn render_markdown(digest: &Digest) -> String {
+    let mut md = format!("# Digest since {}\n", digest.since);
+
+    md.push_str("\n## Watches\n");
+    if digest.watches.is_empty() {
+        md.push_str("\nNo watches.\n");
+    }
+    for watch in &digest.watches {
+        md.push_str(&format!("\n### {} ({} new)\n\n", watch.query, watch.matched));
+        if watch.works.is_empty() {
+            md.push_str("Nothing new.\n");
+        }
+        for work in &watch.works {
+            md.push_str(&work_line(work));
+            md.push('\n');
+        }
+        if watch.matched > watch.works.len() as i64 {
+            md.push_str(&format!("- … and {} more\n", watch.matched - watch.works.len() as i64));
+        }
+    }
+
+    md.push_str("\n## New citations\n\n");
+    if digest.selections.is_empty() {
+        md.push_str("No selections tracked.\n");
+    } else if digest.cited.is_empty() {
+        md.push_str(&format!("No new citations of papers in {}.\n", digest.selections.join(", ")));
+    }
+    for paper in &digest.cited {
+        let n = paper.citers.len();
+        md.push_str(&format!(
+            "**{}** — {n} new citation{}\n",
+            paper.title.as_deref().unwrap_or(&paper.id),
+            if n == 1 { "" } else { "s" }
+        ));
+        for work in &paper.citers {
+            md.push_str(&work_line(work));
+            md.push('\n');
+        }
+        md.push('\n');
+    }
+
+    md.push_str("\n## Added to Zotero\n\n");
+    if digest.zotero.is_empty() {
+        md.push_str("Nothing added.\n");
+    }
+    for item in &digest.zotero {
+        md.push_str(&format!("- {}", item.title.as_deref().unwrap_or("(untitled)")));
+        if let Some(creator) = &item.creator {
+            md.push_str(&format!(" — {creator}"));
+        }
+        md.push_str(&format!(" ({}, `{}`)\n", item.item_type, item.key));
+    }
+
+    if !digest.warnings.is_empty() {
+        md.push_str("\n## Warnings\n\n");
+        for warning in &digest.warnings {
+            md.push_str(&format!("- {warning}\n"));
+        }
+    }
+    md
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn work(id: &str, title: &str, refs: &[&str]) -> Work {
+        serde_json::from_value(serde_json::json!({
+            "id": format!("https://openalex.org/{id}"),
+            "display_name": title,
+            "publication_date": "2026-10-14",
+            "referenced_works": refs.iter().map(|r| format!("https://openalex.org/{r}")).collect::<Vec<_>>(),
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn citations_are_grouped_by_tracked_paper() {
+        let tracked = vec![
+            ("W1".to_string(), Some("Mine".to_string())),
+            ("W2".to_string(), Some("Also mine".to_string())),
+            ("W3".to_string(), None),
+        ];
+        let citers = [work("W10", "Cites both", &["W1", "W2", "W99"]), work("W11", "Cites one", &["W2"])];
+        let cited = group_citations(&tracked, &citers);
+        let summary: Vec<_> = cited
+            .iter()
+            .map(|p| (p.id.as_str(), p.citers.iter().map(|c| c.id.as_str()).collect::<Vec<_>>()))
+            .collect();
+        assert_eq!(summary, [("W2", vec!["W10", "W11"]), ("W1", vec!["W10"])]);
+    }
+
+    #[test]
+    fn markdown_has_every_section() {
+        let hit = DigestWork::from_work(&work("W10", "Gaussian splats", &[]));
+        let digest = Digest::new(
+            "2026-10-14".to_string(),
+            1,
+            vec![WatchHits { query: "gaussian splatting".to_string(), matched: 3, works: vec![hit.clone()] }],
+            vec!["mine".to_string()],
+            vec![CitedPaper { id: "W1".to_string(), title: Some("Mine".to_string()), citers: vec![hit] }],
+            Vec::new(),
+            vec!["Zotero: unavailable".to_string()],
+        );
+        let md = &digest.markdown;
+        assert!(md.starts_with("# Digest since 2026-10-14\n"));
+        assert!(md.contains("### gaussian splatting (3 new)\n\n- Gaussian splats (2026-10-14) `W10`\n- … and 2 more\n"));
+        assert!(md.contains("**Mine** — 1 new citation\n"));
+        assert!(md.contains("## Added to Zotero\n\nNothing added.\n"));
+        assert!(md.contains("## Warnings\n\n- Zotero: unavailable\n"));
+    }
+
+    #[test]
+    fn window_start_counts_today() {
+        assert_eq!(window_start(1), crate::text::iso_now()[..10]);
+        assert!(window_start(7) < window_start(1));
+    }
+}
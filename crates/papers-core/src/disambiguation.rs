@@ -0,0 +1,366 @@
+//! Author disambiguation: ranking namesakes returned by an author search.
+//!
+//! Autocomplete returns the most-cited author whose name matches, which is
+//! often the wrong person for common names. Here every candidate from a name
+//! search is scored on how well its name matches the query, plus whichever
+//! hints were given: an institution it is (or was) affiliated with, a topic it
+//! works on, and a coauthor it has published with. Confidence is the weighted
+//! mean of those signals, so it is comparable only within one query.
+
+use papers_openalex::{Author, DehydratedInstitution};
+use serde::Serialize;
+
+use crate::util::short_id;
+
+/// Fields fetched for each candidate.
+pub const AUTHOR_RESOLVE_SELECT: &str = "id,orcid,display_name,display_name_alternatives,\
+works_count,cited_by_count,affiliations,last_known_institutions,topics";
+
+/// Weight of each hint relative to the name match.
+const HINT_WEIGHT: f64 = 1.0;
+
+/// Weight of the prominence tie-breaker (log works count).
+const PROMINENCE_WEIGHT: f64 = 0.25;
+
+/// Signal value for a match on a past rather than current affiliation, or on
+/// a topic's field/subfield rather than the topic itself.
+const PARTIAL_MATCH: f64 = 0.6;
+
+/// Topics listed per candidate in the output.
+const CANDIDATE_TOPICS: usize = 3;
+
+/// Words skipped when building institution acronyms.
+const ACRONYM_STOPWORDS: &[&str] = &["of", "the", "and", "for", "de", "la", "du", "at", "in"];
+
+/// Optional hints narrowing down which namesake is meant.
+#[derive(Debug, Clone, Default)]
+pub struct ResolveHints {
+    /// Institution name or acronym, e.g. `"MIT"` or `"University of Toronto"`.
+    pub affiliation: Option<String>,
+    /// Topic, subfield or field name, e.g. `"machine learning"`.
+    pub topic: Option<String>,
+    /// Name of someone the author has published with.
+    pub coauthor: Option<String>,
+}
+
+/// Why a candidate scored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthorEvidence {
+    /// Display name (or an alternative) matches every query token.
+    NameMatch,
+    /// A last known institution matches the affiliation hint.
+    CurrentAffiliation,
+    /// A historical affiliation matches the affiliation hint.
+    PastAffiliation,
+    /// One of the author's topics matches the topic hint.
+    TopicMatch,
+    /// The field or subfield of one of the author's topics matches.
+    FieldMatch,
+    /// At least one shared work with the hinted coauthor.
+    Coauthor,
+}
+
+/// A scored candidate author.
+#[derive(Debug, Clone, Serialize)]
+pub struct AuthorCandidate {
+    /// Short OpenAlex author ID.
+    pub id: String,
+    pub display_name: Option<String>,
+    pub orcid: Option<String>,
+    pub last_known_institutions: Vec<String>,
+    pub works_count: Option<i64>,
+    pub cited_by_count: Option<i64>,
+    /// Top topics by score.
+    pub topics: Vec<String>,
+    /// Confidence (0–1) that this is the author meant.
+    pub confidence: f64,
+    pub name_similarity: f64,
+    /// Works shared with the hinted coauthor, when a coauthor was given.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coauthor_works: Option<i64>,
+    pub evidence: Vec<AuthorEvidence>,
+}
+
+/// Result of `author_resolve`.
+#[derive(Debug, Clone, Serialize)]
+pub struct AuthorResolution {
+    pub query: String,
+    /// Candidates, most likely first.
+    pub candidates: Vec<AuthorCandidate>,
+}
+
+fn tokens(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .map(|t| t.to_lowercase())
+        .collect()
+}
+
+/// True when `a` and `b` are the same name token, or one is the initial of
+/// the other.
+fn token_matches(a: &str, b: &str) -> bool {
+    a == b
+        || (a.chars().count() == 1 && b.starts_with(a))
+        || (b.chars().count() == 1 && a.starts_with(b))
+}
+
+/// Fraction of query tokens found in `name` (initials count), halved when the
+/// last query token, usually the family name, has no exact match.
+pub fn name_similarity(query: &str, name: &str) -> f64 {
+    let (query, name) = (tokens(query), tokens(name));
+    let Some(family) = query.last() else { return 0.0 };
+    if name.is_empty() {
+        return 0.0;
+    }
+    let matched = query.iter().filter(|q| name.iter().any(|n| token_matches(q, n))).count();
+    let similarity = matched as f64 / query.len() as f64;
+    if name.contains(family) { similarity } else { similarity / 2.0 }
+}
+
+/// True when every hint token appears in `name`, or the hint is the acronym
+/// of `name` (`"MIT"` for "Massachusetts Institute of Technology").
+pub fn name_contains_hint(name: &str, hint: &str) -> bool {
+    let (name, hint) = (tokens(name), tokens(hint));
+    if hint.is_empty() {
+        return false;
+    }
+    if hint.iter().all(|h| name.contains(h)) {
+        return true;
+    }
+    let acronym: String = name
+        .iter()
+        .filter(|t| !ACRONYM_STOPWORDS.contains(&t.as_str()))
+        .filter_map(|t| t.chars().next())
+        .collect();
+    hint.len() == 1 && acronym.len() > 1 && acronym == hint[0]
+}
+
+fn institution_matches(institution: &DehydratedInstitution, hint: &str) -> bool {
+    institution.display_name.as_deref().is_some_and(|n| name_contains_hint(n, hint))
+}
+
+fn affiliation_signal(author: &Author, hint: &str) -> Option<AuthorEvidence> {
+    if author.last_known_institutions.iter().flatten().any(|i| institution_matches(i, hint)) {
+        return Some(AuthorEvidence::CurrentAffiliation);
+    }
+    author
+        .affiliations
+        .iter()
+        .flatten()
+        .filter_map(|a| a.institution.as_ref())
+        .any(|i| institution_matches(i, hint))
+        .then_some(AuthorEvidence::PastAffiliation)
+}
+
+fn topic_signal(author: &Author, hint: &str) -> Option<AuthorEvidence> {
+    let topics = author.topics.as_deref().unwrap_or_default();
+    if topics.iter().any(|t| t.display_name.as_deref().is_some_and(|n| name_contains_hint(n, hint))) {
+        return Some(AuthorEvidence::TopicMatch);
+    }
+    topics
+        .iter()
+        .flat_map(|t| [t.subfield.as_ref(), t.field.as_ref()])
+        .flatten()
+        .any(|level| level.display_name.as_deref().is_some_and(|n| name_contains_hint(n, hint)))
+        .then_some(AuthorEvidence::FieldMatch)
+}
+
+/// Score `author` against `query` and `hints`. `coauthor_works` is the number
+/// of works shared with the hinted coauthor (ignored without that hint) and
+/// `max_works` the largest works count among all candidates.
+pub fn score_author(
+    author: &Author,
+    query: &str,
+    hints: &ResolveHints,
+    coauthor_works: Option<i64>,
+    max_works: i64,
+) -> AuthorCandidate {
+    let name_sim = author
+        .display_name
+        .iter()
+        .chain(author.display_name_alternatives.iter().flatten())
+        .map(|n| name_similarity(query, n))
+        .fold(0.0, f64::max);
+
+    let mut evidence = Vec::new();
+    if name_sim >= 1.0 {
+        evidence.push(AuthorEvidence::NameMatch);
+    }
+    let mut score = name_sim;
+    let mut weight = 1.0;
+
+    if let Some(hint) = &hints.affiliation {
+        weight += HINT_WEIGHT;
+        match affiliation_signal(author, hint) {
+            Some(AuthorEvidence::CurrentAffiliation) => {
+                score += HINT_WEIGHT;
+                evidence.push(AuthorEvidence::CurrentAffiliation);
+            }
+            Some(e) => {
+                score += HINT_WEIGHT * PARTIAL_MATCH;
+                evidence.push(e);
+            }
+            None => {}
+        }
+    }
+    if let Some(hint) = &hints.topic {
+        weight += HINT_WEIGHT;
+        match topic_signal(author, hint) {
+            Some(AuthorEvidence::TopicMatch) => {
+                score += HINT_WEIGHT;
+                evidence.push(AuthorEvidence::TopicMatch);
+            }
+            Some(e) => {
+                score += HINT_WEIGHT * PARTIAL_MATCH;
+                evidence.push(e);
+            }
+            None => {}
+        }
+    }
+    let coauthor_works = hints.coauthor.as_ref().map(|_| coauthor_works.unwrap_or(0));
+    if let Some(shared) = coauthor_works {
+        weight += HINT_WEIGHT;
+        if shared > 0 {
+            score += HINT_WEIGHT;
+            evidence.push(AuthorEvidence::Coauthor);
+        }
+    }
+
+    let works = author.works_count.unwrap_or(0).max(0);
+    if max_works > 0 {
+        score += PROMINENCE_WEIGHT * (1.0 + works as f64).ln() / (1.0 + max_works as f64).ln();
+    }
+    weight += PROMINENCE_WEIGHT;
+
+    let mut topics: Vec<_> = author.topics.iter().flatten().collect();
+    topics.sort_by(|a, b| b.score.unwrap_or(0.0).total_cmp(&a.score.unwrap_or(0.0)));
+
+    AuthorCandidate {
+        id: short_id(&author.id).to_string(),
+        display_name: author.display_name.clone(),
+        orcid: author.orcid.clone(),
+        last_known_institutions: author
+            .last_known_institutions
+            .iter()
+            .flatten()
+            .filter_map(|i| i.display_name.clone())
+            .collect(),
+        works_count: author.works_count,
+        cited_by_count: author.cited_by_count,
+        topics: topics
+            .into_iter()
+            .filter_map(|t| t.display_name.clone())
+            .take(CANDIDATE_TOPICS)
+            .collect(),
+        confidence: (score / weight * 100.0).round() / 100.0,
+        name_similarity: (name_sim * 100.0).round() / 100.0,
+        coauthor_works,
+        evidence,
+    }
+}
+
+/// Rank scored candidates by confidence, then works count.
+pub fn resolution(query: &str, mut candidates: Vec<AuthorCandidate>) -> AuthorResolution {
+    candidates.sort_by(|a, b| {
+        b.confidence
+            .total_cmp(&a.confidence)
+            .then(b.works_count.cmp(&a.works_count))
+            .then_with(|| a.id.cmp(&b.id))
+    });
+    AuthorResolution { query: query.to_string(), candidates }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn author(id: &str, name: &str, works: i64, current: &[&str], past: &[&str], topics: &[(&str, &str)]) -> Author {
+        let inst = |n: &&str| serde_json::json!({"display_name": n});
+        serde_json::from_value(serde_json::json!({
+            "id": format!("https://openalex.org/{id}"),
+            "display_name": name,
+            "works_count": works,
+            "last_known_institutions": current.iter().map(inst).collect::<Vec<_>>(),
+            "affiliations": past.iter().map(|n| serde_json::json!({"institution": inst(n), "years": [2010]})).collect::<Vec<_>>(),
+            "topics": topics.iter().map(|(t, f)| serde_json::json!({
+                "display_name": t, "score": 0.9, "field": {"display_name": f}
+            })).collect::<Vec<_>>(),
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn name_similarity_counts_initials() {
+        assert_eq!(name_similarity("Yann LeCun", "Yann LeCun"), 1.0);
+        assert_eq!(name_similarity("Y. LeCun", "Yann André LeCun"), 1.0);
+        assert_eq!(name_similarity("Yann LeCun", "Yann Lecun"), 1.0);
+        assert_eq!(name_similarity("John Smith", "Jane Smith"), 0.5);
+        assert_eq!(name_similarity("John Smith", "John Smithson"), 0.25);
+        assert_eq!(name_similarity("", "John Smith"), 0.0);
+    }
+
+    #[test]
+    fn hints_match_words_and_acronyms() {
+        assert!(name_contains_hint("Massachusetts Institute of Technology", "MIT"));
+        assert!(name_contains_hint("University of Toronto", "toronto"));
+        assert!(!name_contains_hint("University of Toronto", "Toronto Metropolitan"));
+        assert!(!name_contains_hint("Stanford University", "S"));
+        assert!(name_contains_hint("Artificial Intelligence", "artificial intelligence"));
+    }
+
+    #[test]
+    fn hints_separate_namesakes() {
+        let hints = ResolveHints {
+            affiliation: Some("MIT".into()),
+            topic: Some("machine learning".into()),
+            coauthor: None,
+        };
+        let famous = author(
+            "A1", "Wei Wang", 900, &["Tsinghua University"], &[],
+            &[("Materials Chemistry", "Chemistry")],
+        );
+        let meant = author(
+            "A2", "Wei Wang", 40, &["Massachusetts Institute of Technology"], &[],
+            &[("Machine Learning Theory", "Computer Science")],
+        );
+        let former = author(
+            "A3", "W. Wang", 40, &["Stanford University"], &["Massachusetts Institute of Technology"],
+            &[("Graph Neural Networks", "Computer Science")],
+        );
+        let scored = [&famous, &meant, &former].map(|a| score_author(a, "Wei Wang", &hints, None, 900));
+        let ranked = resolution("Wei Wang", scored.to_vec());
+        let ids: Vec<_> = ranked.candidates.iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(ids, ["A2", "A3", "A1"]);
+        let best = &ranked.candidates[0];
+        assert_eq!(
+            best.evidence,
+            vec![AuthorEvidence::NameMatch, AuthorEvidence::CurrentAffiliation, AuthorEvidence::TopicMatch]
+        );
+        assert!(best.confidence > 0.9);
+        assert_eq!(ranked.candidates[1].evidence, vec![AuthorEvidence::NameMatch, AuthorEvidence::PastAffiliation]);
+        assert!(ranked.candidates[2].confidence < 0.5);
+    }
+
+    #[test]
+    fn coauthor_and_prominence() {
+        let hints = ResolveHints { coauthor: Some("Geoffrey Hinton".into()), ..Default::default() };
+        let a = author("A1", "Yann LeCun", 500, &[], &[], &[]);
+        let b = author("A2", "Yann LeCun", 5, &[], &[], &[]);
+        let with = score_author(&b, "Yann LeCun", &hints, Some(3), 500);
+        let without = score_author(&a, "Yann LeCun", &hints, None, 500);
+        assert_eq!(with.coauthor_works, Some(3));
+        assert_eq!(without.coauthor_works, Some(0));
+        assert!(with.confidence > without.confidence);
+
+        // With no hints the more prolific namesake wins.
+        let none = ResolveHints::default();
+        let ranked = resolution(
+            "Yann LeCun",
+            vec![score_author(&b, "Yann LeCun", &none, None, 500), score_author(&a, "Yann LeCun", &none, None, 500)],
+        );
+        assert_eq!(ranked.candidates[0].id, "A1");
+        assert_eq!(ranked.candidates[0].confidence, 1.0);
+        assert!(ranked.candidates[0].coauthor_works.is_none());
+    }
+}
@@ -0,0 +1,748 @@
+//! Selection export to BibTeX and CSL-JSON.
+//!
+//! Each entry is turned into a [`CitationRecord`] from the best available
+//! source: the Zotero item when the entry has a Zotero key and a client is
+//! configured, else the OpenAlex work (by OpenAlex ID or DOI), else the
+//! metadata stored on the selection entry itself.
+
+use std::collections::HashSet;
+
+use papers_openalex::{GetParams, OpenAlexClient, Work};
+use papers_zotero::{Item, ZoteroClient};
+use serde::Serialize;
+
+use crate::selection::{
+    Selection, SelectionEntry, SelectionError, load_selection, openalex_type_to_zotero,
+    strip_doi_prefix,
+};
+
+// ── Error ──────────────────────────────────────────────────────────────────
+
+#[derive(thiserror::Error, Debug)]
+pub enum ExportError {
+    #[error(transparent)]
+    Selection(#[from] SelectionError),
+    #[error("invalid export format {0:?}: use bibtex or csl-json")]
+    InvalidFormat(String),
+}
+
+/// Output format for [`export_selection`].
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum ExportFormat {
+    Bibtex,
+    CslJson,
+}
+
+impl ExportFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExportFormat::Bibtex => "bibtex",
+            ExportFormat::CslJson => "csl-json",
+        }
+    }
+}
+
+impl std::str::FromStr for ExportFormat {
+    type Err = ExportError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().replace('_', "-").as_str() {
+            "bibtex" | "bib" => Ok(ExportFormat::Bibtex),
+            "csl-json" | "csljson" | "csl" | "json" => Ok(ExportFormat::CslJson),
+            _ => Err(ExportError::InvalidFormat(s.to_string())),
+        }
+    }
+}
+
+// ── Data model ─────────────────────────────────────────────────────────────
+
+/// Where a [`CitationRecord`]'s metadata came from.
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordSource {
+    Zotero,
+    OpenAlex,
+    Selection,
+}
+
+/// A person (author) as family/given name parts, or a single literal name
+/// for organizations.
+#[derive(Serialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct PersonName {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub family: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub given: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub literal: Option<String>,
+}
+
+impl PersonName {
+    /// Split a display name on its last space: "Ada M. Lovelace" → given
+    /// "Ada M.", family "Lovelace". Names without a space become literals.
+    pub fn from_display(name: &str) -> Self {
+        let name = name.trim();
+        match name.rsplit_once(' ') {
+            Some((given, family)) => PersonName {
+                family: Some(family.to_string()),
+                given: Some(given.trim().to_string()),
+                literal: None,
+            },
+            None => PersonName { literal: Some(name.to_string()), ..Default::default() },
+        }
+    }
+}
+
+/// Source-independent bibliographic record for one selection entry.
+#[derive(Serialize, Clone, Debug)]
+pub struct CitationRecord {
+    /// Citation key, unique within an export.
+    pub key: String,
+    pub source: RecordSource,
+    /// Zotero item type (`journalArticle`, `book`, ...), used for both formats.
+    pub item_type: String,
+    pub title: Option<String>,
+    pub authors: Vec<PersonName>,
+    /// `[year, month, day]` prefix, as far as known.
+    pub date_parts: Vec<u32>,
+    /// Journal, proceedings, or book title the work appeared in.
+    pub container_title: Option<String>,
+    pub publisher: Option<String>,
+    pub volume: Option<String>,
+    pub issue: Option<String>,
+    pub pages: Option<String>,
+    /// Bare DOI (no `https://doi.org/` prefix).
+    pub doi: Option<String>,
+    pub url: Option<String>,
+    pub issn: Option<String>,
+    pub isbn: Option<String>,
+    pub language: Option<String>,
+}
+
+impl CitationRecord {
+    fn empty(source: RecordSource, item_type: &str) -> Self {
+        CitationRecord {
+            key: String::new(),
+            source,
+            item_type: item_type.to_string(),
+            title: None,
+            authors: Vec::new(),
+            date_parts: Vec::new(),
+            container_title: None,
+            publisher: None,
+            volume: None,
+            issue: None,
+            pages: None,
+            doi: None,
+            url: None,
+            issn: None,
+            isbn: None,
+            language: None,
+        }
+    }
+
+    pub(crate) fn year(&self) -> Option<u32> {
+        self.date_parts.first().copied()
+    }
+}
+
+fn non_empty(s: &Option<String>) -> Option<String> {
+    s.as_deref().map(str::trim).filter(|s| !s.is_empty()).map(str::to_string)
+}
+
+/// Parse the leading `YYYY[-MM[-DD]]` of a date string into date parts.
+/// Falls back to the first four-digit year anywhere in the string.
+fn parse_date_parts(date: &str) -> Vec<u32> {
+    let parts: Vec<u32> = date
+        .split(['-', '/'])
+        .map_while(|p| p.trim().parse().ok())
+        .take(3)
+        .collect();
+    if parts.first().is_some_and(|y| (1000..3000).contains(y)) {
+        return parts.into_iter().filter(|&p| p > 0).collect();
+    }
+    date.as_bytes()
+        .windows(4)
+        .filter(|w| w.iter().all(u8::is_ascii_digit))
+        .find_map(|w| std::str::from_utf8(w).ok()?.parse::<u32>().ok())
+        .filter(|y| (1000..3000).contains(y))
+        .into_iter()
+        .collect()
+}
+
+/// Build a record from a Zotero item.
+pub fn record_from_zotero(item: &Item) -> CitationRecord {
+    let data = &item.data;
+    let mut rec = CitationRecord::empty(RecordSource::Zotero, &data.item_type);
+    rec.key = citation_key_from_zotero(item).unwrap_or_default();
+    rec.title = non_empty(&data.title);
+    rec.authors = data
+        .creators
+        .iter()
+        .filter(|c| c.creator_type == "author")
+        .map(|c| match (&c.last_name, &c.name) {
+            (Some(family), _) => PersonName {
+                family: Some(family.clone()),
+                given: non_empty(&c.first_name),
+                literal: None,
+            },
+            (None, Some(name)) => PersonName { literal: Some(name.clone()), ..Default::default() },
+            (None, None) => PersonName::default(),
+        })
+        .filter(|p| p != &PersonName::default())
+        .collect();
+    let date = item.meta.parsed_date.as_deref().or(data.date.as_deref()).unwrap_or("");
+    rec.date_parts = parse_date_parts(date);
+    rec.container_title = non_empty(&data.publication_title).or_else(|| {
+        ["proceedingsTitle", "bookTitle", "websiteTitle", "repository"]
+            .iter()
+            .find_map(|f| data.extra_fields.get(*f)?.as_str().filter(|s| !s.is_empty()))
+            .map(str::to_string)
+    });
+    rec.publisher = non_empty(&data.publisher).or_else(|| {
+        ["university", "institution"]
+            .iter()
+            .find_map(|f| data.extra_fields.get(*f)?.as_str().filter(|s| !s.is_empty()))
+            .map(str::to_string)
+    });
+    rec.volume = non_empty(&data.volume);
+    rec.issue = non_empty(&data.issue);
+    rec.pages = non_empty(&data.pages);
+    rec.doi = non_empty(&data.doi).map(|d| strip_doi_prefix(&d).to_string());
+    rec.url = non_empty(&data.url);
+    rec.issn = non_empty(&data.issn);
+    rec.isbn = non_empty(&data.isbn);
+    rec.language = non_empty(&data.language);
+    rec
+}
+
+/// Zotero's own citation key field, or a Better BibTeX `Citation Key:` line in
+/// `extra`.
+fn citation_key_from_zotero(item: &Item) -> Option<String> {
+    non_empty(&item.data.citation_key).or_else(|| {
+        item.data.extra.as_deref()?.lines().find_map(|line| {
+            let key = line.trim().strip_prefix("Citation Key:")?.trim();
+            (!key.is_empty()).then(|| key.to_string())
+        })
+    })
+}
+
+/// Build a record from an OpenAlex work.
+pub fn record_from_work(work: &Work) -> CitationRecord {
+    let oa_type = work.type_crossref.as_deref().or(work.r#type.as_deref()).unwrap_or("");
+    let mut rec = CitationRecord::empty(RecordSource::OpenAlex, openalex_type_to_zotero(oa_type));
+    rec.title = non_empty(&work.display_name).or_else(|| non_empty(&work.title));
+    rec.authors = work
+        .authorships
+        .iter()
+        .flatten()
+        .filter_map(|a| {
+            let author = a.author.as_ref()?;
+            let name = author.display_name.clone().or_else(|| a.raw_author_name.clone())?;
+            Some(PersonName::from_display(&name))
+        })
+        .collect();
+    rec.date_parts = match &work.publication_date {
+        Some(date) => parse_date_parts(date),
+        None => work.publication_year.map(|y| y as u32).into_iter().collect(),
+    };
+    let source = work.primary_location.as_ref().and_then(|l| l.source.as_ref());
+    rec.container_title = source.and_then(|s| non_empty(&s.display_name));
+    rec.publisher = source.and_then(|s| non_empty(&s.host_organization_name));
+    rec.issn = source
+        .and_then(|s| s.issn_l.clone().or_else(|| s.issn.as_ref()?.first().cloned()));
+    if let Some(biblio) = &work.biblio {
+        rec.volume = non_empty(&biblio.volume);
+        rec.issue = non_empty(&biblio.issue);
+        rec.pages = match (non_empty(&biblio.first_page), non_empty(&biblio.last_page)) {
+            (Some(first), Some(last)) if first != last => Some(format!("{first}-{last}")),
+            (Some(first), _) => Some(first),
+            (None, _) => None,
+        };
+    }
+    rec.doi = work.doi.as_deref().map(|d| strip_doi_prefix(d).to_string());
+    rec.url = work.primary_location.as_ref().and_then(|l| l.landing_page_url.clone());
+    rec.language = non_empty(&work.language);
+    rec
+}
+
+/// Build a record from the metadata stored on a selection entry.
+pub fn record_from_entry(entry: &SelectionEntry) -> CitationRecord {
+    let item_type =
+        entry.work_type.as_deref().map(openalex_type_to_zotero).unwrap_or("document");
+    let mut rec = CitationRecord::empty(RecordSource::Selection, item_type);
+    rec.title = non_empty(&entry.title);
+    rec.authors = entry.authors.iter().flatten().map(|a| PersonName::from_display(a)).collect();
+    rec.date_parts = entry.year.into_iter().collect();
+    rec.doi = entry.doi.as_deref().map(|d| strip_doi_prefix(d).to_string());
+    rec.issn = entry.issn.as_ref().and_then(|v| v.first().cloned());
+    rec.isbn = entry.isbn.as_ref().and_then(|v| v.first().cloned());
+    rec
+}
+
+// ── Citation keys ──────────────────────────────────────────────────────────
+
+const KEY_STOPWORDS: &[&str] = &["a", "an", "the", "on", "of", "in", "for", "and", "to", "with"];
+
+/// Fold common Latin accents to ASCII and keep only alphanumerics.
+pub(crate) fn key_part(s: &str) -> String {
+    s.chars()
+        .map(|c| match c {
+            'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'À' | 'Á' | 'Â' | 'Ã' | 'Ä' | 'Å' => 'a',
+            'ç' | 'č' | 'ć' | 'Ç' | 'Č' | 'Ć' => 'c',
+            'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ě' | 'È' | 'É' | 'Ê' | 'Ë' => 'e',
+            'ì' | 'í' | 'î' | 'ï' | 'Ì' | 'Í' | 'Î' | 'Ï' => 'i',
+            'ñ' | 'ń' | 'Ñ' => 'n',
+            'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'Ò' | 'Ó' | 'Ô' | 'Õ' | 'Ö' | 'Ø' => 'o',
+            'š' | 'ś' | 'ß' | 'Š' | 'Ś' => 's',
+            'ù' | 'ú' | 'û' | 'ü' | 'Ù' | 'Ú' | 'Û' | 'Ü' => 'u',
+            'ý' | 'ÿ' | 'Ý' => 'y',
+            'ž' | 'ź' | 'ż' | 'Ž' | 'Ź' | 'Ż' => 'z',
+            'ł' | 'Ł' => 'l',
+            c => c,
+        })
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect::<String>()
+        .to_lowercase()
+}
+
+/// `familyYEARword`, e.g. `vaswani2017attention`.
+pub(crate) fn generated_key(rec: &CitationRecord) -> String {
+    let name = rec
+        .authors
+        .first()
+        .and_then(|a| a.family.as_deref().or(a.literal.as_deref()))
+        .map(|n| key_part(n.split_whitespace().last().unwrap_or(n)))
+        .unwrap_or_default();
+    let year = rec.year().map(|y| y.to_string()).unwrap_or_default();
+    let word = rec
+        .title
+        .as_deref()
+        .unwrap_or("")
+        .split_whitespace()
+        .map(key_part)
+        .find(|w| !w.is_empty() && !KEY_STOPWORDS.contains(&w.as_str()))
+        .unwrap_or_default();
+    let key = format!("{name}{year}{word}");
+    if key.is_empty() { "ref".to_string() } else { key }
+}
+
+/// Fill in missing keys and make all keys unique by appending `b`, `c`, ...
+fn assign_keys(records: &mut [CitationRecord]) {
+    let mut seen = HashSet::new();
+    for rec in records {
+        if rec.key.is_empty() {
+            rec.key = generated_key(rec);
+        }
+        let base = rec.key.clone();
+        let mut suffix = b'b';
+        while !seen.insert(rec.key.clone()) {
+            rec.key = format!("{base}{}", suffix as char);
+            suffix = suffix.saturating_add(1);
+        }
+    }
+}
+
+// ── Fetching ───────────────────────────────────────────────────────────────
+
+/// Fields needed for a citation record.
+pub(crate) const WORK_EXPORT_SELECT: &str = "id,doi,title,display_name,publication_year,\
+    publication_date,type,type_crossref,authorships,primary_location,biblio,language";
+
+/// Build citation records for every entry in `selection`, preferring Zotero
+/// data, then OpenAlex, then the entry's own metadata. Lookup failures fall
+/// through to the next source rather than aborting the export.
+pub async fn selection_records(
+    selection: &Selection,
+    client: &OpenAlexClient,
+    zotero: Option<&ZoteroClient>,
+) -> Vec<CitationRecord> {
+    let params = GetParams { select: Some(WORK_EXPORT_SELECT.to_string()) };
+    let mut records = Vec::with_capacity(selection.entries.len());
+    for entry in &selection.entries {
+        if let (Some(z), Some(key)) = (zotero, entry.zotero_key.as_deref())
+            && let Ok(item) = z.get_item(key).await
+        {
+            records.push(record_from_zotero(&item));
+            continue;
+        }
+        if let Some(id) = entry.openalex_id.as_deref().or(entry.doi.as_deref())
+            && let Ok(work) = crate::api::work_get(client, id, &params).await
+        {
+            records.push(record_from_work(&work));
+            continue;
+        }
+        records.push(record_from_entry(entry));
+    }
+    assign_keys(&mut records);
+    records
+}
+
+// ── BibTeX ─────────────────────────────────────────────────────────────────
+
+fn bibtex_type(item_type: &str) -> &'static str {
+    match item_type {
+        "journalArticle" | "magazineArticle" | "newspaperArticle" => "article",
+        "book" => "book",
+        "bookSection" => "incollection",
+        "conferencePaper" => "inproceedings",
+        "thesis" => "phdthesis",
+        "report" => "techreport",
+        _ => "misc",
+    }
+}
+
+fn bibtex_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '\n' | '\r' => out.push(' '),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn bibtex_name(p: &PersonName) -> String {
+    match (&p.family, &p.given, &p.literal) {
+        (Some(family), Some(given), _) => {
+            format!("{}, {}", bibtex_escape(family), bibtex_escape(given))
+        }
+        (Some(family), None, _) => bibtex_escape(family),
+        (None, _, Some(literal)) => format!("{{{}}}", bibtex_escape(literal)),
+        _ => String::new(),
+    }
+}
+
+/// Render one record as a BibTeX entry.
+pub fn to_bibtex(rec: &CitationRecord) -> String {
+    let kind = bibtex_type(&rec.item_type);
+    let mut fields: Vec<(&str, String)> = Vec::new();
+    if let Some(title) = &rec.title {
+        fields.push(("title", bibtex_escape(title)));
+    }
+    if !rec.authors.is_empty() {
+        let names: Vec<_> = rec.authors.iter().map(bibtex_name).collect();
+        fields.push(("author", names.join(" and ")));
+    }
+    if let Some(year) = rec.year() {
+        fields.push(("year", year.to_string()));
+    }
+    if let Some(container) = &rec.container_title {
+        let name = match kind {
+            "article" => "journal",
+            "inproceedings" | "incollection" => "booktitle",
+            _ => "howpublished",
+        };
+        fields.push((name, bibtex_escape(container)));
+    }
+    if let Some(publisher) = &rec.publisher {
+        let name = match kind {
+            "phdthesis" => "school",
+            "techreport" => "institution",
+            _ => "publisher",
+        };
+        fields.push((name, bibtex_escape(publisher)));
+    }
+    if let Some(v) = &rec.volume {
+        fields.push(("volume", bibtex_escape(v)));
+    }
+    if let Some(v) = &rec.issue {
+        fields.push(("number", bibtex_escape(v)));
+    }
+    if let Some(v) = &rec.pages {
+        fields.push(("pages", bibtex_escape(&v.replace('–', "-").replace('-', "--"))));
+    }
+    if let Some(v) = &rec.doi {
+        fields.push(("doi", v.clone()));
+    }
+    if let Some(v) = &rec.url {
+        fields.push(("url", v.clone()));
+    }
+    if let Some(v) = &rec.issn {
+        fields.push(("issn", v.clone()));
+    }
+    if let Some(v) = &rec.isbn {
+        fields.push(("isbn", v.clone()));
+    }
+    if let Some(v) = &rec.language {
+        fields.push(("language", bibtex_escape(v)));
+    }
+
+    let mut out = format!("@{kind}{{{},\n", rec.key);
+    for (name, value) in fields {
+        out.push_str(&format!("  {name} = {{{value}}},\n"));
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// Render records as a BibTeX file (entries separated by blank lines).
+pub fn records_to_bibtex(records: &[CitationRecord]) -> String {
+    records.iter().map(to_bibtex).collect::<Vec<_>>().join("\n")
+}
+
+// ── CSL-JSON ───────────────────────────────────────────────────────────────
+
+fn csl_type(item_type: &str) -> &'static str {
+    match item_type {
+        "journalArticle" => "article-journal",
+        "magazineArticle" => "article-magazine",
+        "newspaperArticle" => "article-newspaper",
+        "preprint" => "article",
+        "book" => "book",
+        "bookSection" => "chapter",
+        "conferencePaper" => "paper-conference",
+        "thesis" => "thesis",
+        "report" => "report",
+        "webpage" => "webpage",
+        "dataset" => "dataset",
+        "letter" => "personal_communication",
+        "standard" => "standard",
+        _ => "document",
+    }
+}
+
+/// Render one record as a CSL-JSON item.
+pub fn to_csl_json(rec: &CitationRecord) -> serde_json::Value {
+    let mut item = serde_json::Map::new();
+    item.insert("id".into(), rec.key.clone().into());
+    item.insert("type".into(), csl_type(&rec.item_type).into());
+    let mut insert = |name: &str, value: &Option<String>| {
+        if let Some(v) = value {
+            item.insert(name.into(), v.clone().into());
+        }
+    };
+    insert("title", &rec.title);
+    insert("container-title", &rec.container_title);
+    insert("publisher", &rec.publisher);
+    insert("volume", &rec.volume);
+    insert("issue", &rec.issue);
+    insert("page", &rec.pages);
+    insert("DOI", &rec.doi);
+    insert("URL", &rec.url);
+    insert("ISSN", &rec.issn);
+    insert("ISBN", &rec.isbn);
+    insert("language", &rec.language);
+    if !rec.authors.is_empty() {
+        item.insert("author".into(), serde_json::json!(rec.authors));
+    }
+    if !rec.date_parts.is_empty() {
+        item.insert("issued".into(), serde_json::json!({ "date-parts": [rec.date_parts] }));
+    }
+    serde_json::Value::Object(item)
+}
+
+/// Render records as a CSL-JSON array.
+pub fn records_to_csl_json(records: &[CitationRecord]) -> serde_json::Value {
+    serde_json::Value::Array(records.iter().map(to_csl_json).collect())
+}
+
+// ── Selection export ───────────────────────────────────────────────────────
+
+/// Export selection `name` as a BibTeX file.
+pub async fn export_selection_bibtex(
+    name: &str,
+    client: &OpenAlexClient,
+    zotero: Option<&ZoteroClient>,
+) -> Result<String, ExportError> {
+    let sel = load_selection(name)?;
+    Ok(records_to_bibtex(&selection_records(&sel, client, zotero).await))
+}
+
+/// Export selection `name` as a CSL-JSON array.
+pub async fn export_selection_csl_json(
+    name: &str,
+    client: &OpenAlexClient,
+    zotero: Option<&ZoteroClient>,
+) -> Result<serde_json::Value, ExportError> {
+    let sel = load_selection(name)?;
+    Ok(records_to_csl_json(&selection_records(&sel, client, zotero).await))
+}
+
+/// Result of [`export_selection`].
+#[derive(Serialize, Debug)]
+pub struct SelectionExport {
+    pub selection: String,
+    pub format: ExportFormat,
+    pub count: usize,
+    /// Number of records built from Zotero, OpenAlex, and selection metadata.
+    pub zotero: usize,
+    pub openalex: usize,
+    pub selection_only: usize,
+    /// BibTeX text, or the CSL-JSON array.
+    pub content: serde_json::Value,
+}
+
+/// Export selection `name` in `format`, with per-source record counts.
+pub async fn export_selection(
+    name: &str,
+    format: ExportFormat,
+    client: &OpenAlexClient,
+    zotero: Option<&ZoteroClient>,
+) -> Result<SelectionExport, ExportError> {
+    let sel = load_selection(name)?;
+    let records = selection_records(&sel, client, zotero).await;
+    let count_source = |s| records.iter().filter(|r| r.source == s).count();
+    let content = match format {
+        ExportFormat::Bibtex => records_to_bibtex(&records).into(),
+        ExportFormat::CslJson => records_to_csl_json(&records),
+    };
+    Ok(SelectionExport {
+        selection: sel.name.clone(),
+        format,
+        count: records.len(),
+        zotero: count_source(RecordSource::Zotero),
+        openalex: count_source(RecordSource::OpenAlex),
+        selection_only: count_source(RecordSource::Selection),
+        content,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn work() -> Work {
+        serde_json::from_value(serde_json::json!({
+            "id": "https://openalex.org/W1",
+            "doi": "https://doi.org/10.1234/ABC",
+            "display_name": "Attention Is All You Need",
+            "publication_date": "2017-06-12",
+            "publication_year": 2017,
+            "type": "article",
+            "type_crossref": "proceedings-article",
+            "authorships": [
+                {"author": {"display_name": "Ashish Vaswani"}},
+                {"author": {"display_name": "Noam Shazeer"}}
+            ],
+            "primary_location": {"source": {"display_name": "NeurIPS", "host_organization_name": "Curran"}},
+            "biblio": {"volume": "30", "first_page": "5998", "last_page": "6008"}
+        }))
+        .unwrap()
+    }
+
+    fn zotero_item(extra: Option<&str>) -> Item {
+        serde_json::from_value(serde_json::json!({
+            "key": "ABCD1234",
+            "version": 1,
+            "library": {"type": "user", "id": 1, "name": "u", "links": {}},
+            "meta": {"parsedDate": "2020-03"},
+            "data": {
+                "key": "ABCD1234",
+                "version": 1,
+                "itemType": "journalArticle",
+                "title": "Müller & Co: 50% of #1",
+                "creators": [
+                    {"creatorType": "author", "firstName": "Jörg", "lastName": "Müller"},
+                    {"creatorType": "author", "name": "CERN Collaboration"},
+                    {"creatorType": "editor", "firstName": "E", "lastName": "Editor"}
+                ],
+                "tags": [],
+                "collections": [],
+                "publicationTitle": "Journal of Things",
+                "volume": "4",
+                "pages": "10-20",
+                "DOI": "10.5555/xyz",
+                "extra": extra
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn format_parses_aliases() {
+        assert_eq!("BibTeX".parse::<ExportFormat>().unwrap(), ExportFormat::Bibtex);
+        assert_eq!("csl_json".parse::<ExportFormat>().unwrap(), ExportFormat::CslJson);
+        assert!("ris".parse::<ExportFormat>().is_err());
+    }
+
+    #[test]
+    fn date_parts_from_iso_and_free_text() {
+        assert_eq!(parse_date_parts("2017-06-12"), vec![2017, 6, 12]);
+        assert_eq!(parse_date_parts("2020-03"), vec![2020, 3]);
+        assert_eq!(parse_date_parts("March 2020"), vec![2020]);
+        assert!(parse_date_parts("n.d.").is_empty());
+    }
+
+    #[test]
+    fn work_record_to_bibtex() {
+        let mut records = vec![record_from_work(&work())];
+        assign_keys(&mut records);
+        let bib = to_bibtex(&records[0]);
+        assert!(bib.starts_with("@inproceedings{vaswani2017attention,\n"), "{bib}");
+        assert!(bib.contains("  author = {Vaswani, Ashish and Shazeer, Noam},\n"));
+        assert!(bib.contains("  booktitle = {NeurIPS},\n"));
+        assert!(bib.contains("  pages = {5998--6008},\n"));
+        assert!(bib.contains("  doi = {10.1234/ABC},\n"));
+        assert!(bib.ends_with("}\n"));
+    }
+
+    #[test]
+    fn zotero_record_escapes_and_keeps_literal_authors() {
+        let mut records = vec![record_from_zotero(&zotero_item(None))];
+        assign_keys(&mut records);
+        let rec = &records[0];
+        assert_eq!(rec.key, "muller2020muller");
+        assert_eq!(rec.authors.len(), 2);
+        let bib = to_bibtex(rec);
+        assert!(bib.contains("title = {Müller \\& Co: 50\\% of \\#1}"));
+        assert!(bib.contains("author = {Müller, Jörg and {CERN Collaboration}}"));
+        assert!(bib.contains("journal = {Journal of Things}"));
+    }
+
+    #[test]
+    fn zotero_citation_key_from_extra_is_kept() {
+        let rec = record_from_zotero(&zotero_item(Some("tex.note: x\nCitation Key: mueller20")));
+        assert_eq!(rec.key, "mueller20");
+    }
+
+    #[test]
+    fn duplicate_keys_get_suffixes() {
+        let mut records: Vec<_> = (0..3).map(|_| record_from_work(&work())).collect();
+        assign_keys(&mut records);
+        let keys: Vec<_> = records.iter().map(|r| r.key.as_str()).collect();
+        assert_eq!(
+            keys,
+            vec!["vaswani2017attention", "vaswani2017attentionb", "vaswani2017attentionc"]
+        );
+    }
+
+    #[test]
+    fn csl_json_item() {
+        let mut records = vec![record_from_zotero(&zotero_item(None))];
+        assign_keys(&mut records);
+        let csl = to_csl_json(&records[0]);
+        assert_eq!(csl["type"], "article-journal");
+        assert_eq!(csl["container-title"], "Journal of Things");
+        assert_eq!(csl["DOI"], "10.5555/xyz");
+        assert_eq!(csl["issued"]["date-parts"], serde_json::json!([[2020, 3]]));
+        assert_eq!(csl["author"][0], serde_json::json!({"family": "Müller", "given": "Jörg"}));
+        assert_eq!(csl["author"][1], serde_json::json!({"literal": "CERN Collaboration"}));
+    }
+
+    #[test]
+    fn entry_fallback_record() {
+        let entry: SelectionEntry = serde_json::from_value(serde_json::json!({
+            "zotero_key": null,
+            "openalex_id": null,
+            "doi": "10.1/x",
+            "title": "On Things",
+            "authors": ["Grace Hopper"],
+            "year": 1952,
+            "issn": null,
+            "isbn": ["978-0"],
+            "work_type": "book"
+        }))
+        .unwrap();
+        let rec = record_from_entry(&entry);
+        assert_eq!(rec.item_type, "book");
+        assert_eq!(generated_key(&rec), "hopper1952things");
+        assert_eq!(rec.isbn.as_deref(), Some("978-0"));
+    }
+}
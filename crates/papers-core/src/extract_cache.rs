@@ -1,7 +1,8 @@
 //! Thin filesystem layer for the papers-extract cache.
 //!
 //! Cache location: `<cache-dir>/papers/extracts/{cache_id}/`
-//! Override with env var `PAPERS_EXTRACT_CACHE_DIR`.
+//! Override with env var `PAPERS_EXTRACT_CACHE_DIR`. Inside a
+//! [`crate::tenant::scope`] the tenant's `cache/extracts/` is used instead.
 //!
 //! Each cache entry contains:
 //! - `meta.json`        — paper metadata ([`super::text::ExtractionMeta`])
@@ -18,8 +19,12 @@ use crate::text::ExtractionMeta;
 
 /// Return the base directory for extract caches.
 ///
-/// Uses `PAPERS_EXTRACT_CACHE_DIR` if set, otherwise `<cache_dir>/papers/extracts/`.
+/// The tenant's `cache/extracts/` inside a [`crate::tenant::scope`], else
+/// `PAPERS_EXTRACT_CACHE_DIR` if set, otherwise `<cache_dir>/papers/extracts/`.
 pub fn extract_cache_root() -> Option<PathBuf> {
+    if let Some(dir) = crate::tenant::current_data_dir() {
+        return Some(dir.join("cache").join("extracts"));
+    }
     if let Ok(base) = std::env::var("PAPERS_EXTRACT_CACHE_DIR") {
         return Some(PathBuf::from(base));
     }
@@ -227,4 +232,18 @@ mod tests {
         assert!(read_cached_extraction_json("NOPE").is_none());
         assert!(read_cached_meta("NOPE").is_none());
     }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_tenant_scope_has_its_own_caches() {
+        let _guard = setup_temp_cache();
+        let tenant = tempfile::tempdir().unwrap();
+        let (extracts, datalab) = crate::tenant::scope(tenant.path(), async {
+            (extract_cache_root(), crate::text::datalab_cache_root())
+        })
+        .await;
+        assert_eq!(extracts, Some(tenant.path().join("cache").join("extracts")));
+        assert_eq!(datalab, Some(tenant.path().join("cache").join("datalab")));
+        assert_ne!(extract_cache_root(), extracts);
+    }
 }
@@ -1,5 +1,9 @@
 use papers_openalex::{ListParams, OpenAlexClient, OpenAlexError};
 
+use crate::geo;
+use crate::language;
+use crate::sdg;
+
 // ── Alias specification types ────────────────────────────────────────────
 
 /// Alias definition mapping a user-facing name to its OpenAlex filter key.
@@ -17,6 +21,16 @@ pub(crate) enum AliasKind {
     Direct,
     /// Boolean flag — when present and true, emits `filter_key:true`.
     Boolean,
+    /// Exclusion flag — when present and true, emits `filter_key:false`.
+    Exclude,
+    /// ISO 3166-1 alpha-2 code or country name, validated against [`geo::COUNTRIES`].
+    Country,
+    /// Continent name, normalized to the snake_case form OpenAlex expects.
+    Continent,
+    /// ISO 639-1 code or language name, validated against [`language::LANGUAGES`].
+    Language,
+    /// SDG number, name, or ID URI, validated against [`sdg::SDGS`].
+    Sdg,
 }
 
 // ── Error type ───────────────────────────────────────────────────────────
@@ -40,6 +54,14 @@ pub enum FilterError {
         /// Each entry is (display_name, cited_by_count).
         suggestions: Vec<(String, u64)>,
     },
+    #[error("Invalid {alias} \"{value}\". {hint}Valid values: {}", .valid.join(", "))]
+    InvalidValue {
+        alias: &'static str,
+        value: String,
+        /// Optional sentence pointing at the likely intended value.
+        hint: String,
+        valid: Vec<String>,
+    },
     #[error(transparent)]
     Api(#[from] OpenAlexError),
 }
@@ -65,16 +87,20 @@ pub(crate) const WORK_ALIASES: &[AliasSpec] = &[
     AliasSpec { name: "institution", filter_key: "authorships.institutions.lineage",          entity_type: "institutions",  kind: AliasKind::Entity },
     AliasSpec { name: "year",        filter_key: "publication_year",                          entity_type: "",              kind: AliasKind::Direct },
     AliasSpec { name: "citations",   filter_key: "cited_by_count",                            entity_type: "",              kind: AliasKind::Direct },
-    AliasSpec { name: "country",     filter_key: "authorships.institutions.country_code",     entity_type: "",              kind: AliasKind::Direct },
-    AliasSpec { name: "continent",   filter_key: "authorships.institutions.continent",        entity_type: "",              kind: AliasKind::Direct },
+    AliasSpec { name: "country",     filter_key: "authorships.institutions.country_code",     entity_type: "",              kind: AliasKind::Country },
+    AliasSpec { name: "continent",   filter_key: "authorships.institutions.continent",        entity_type: "",              kind: AliasKind::Continent },
     AliasSpec { name: "type",        filter_key: "type",                                      entity_type: "",              kind: AliasKind::Direct },
     AliasSpec { name: "open",        filter_key: "is_oa",                                     entity_type: "",              kind: AliasKind::Boolean },
+    AliasSpec { name: "language",    filter_key: "language",                                  entity_type: "",              kind: AliasKind::Language },
+    AliasSpec { name: "exclude_retracted", filter_key: "is_retracted",                        entity_type: "",              kind: AliasKind::Exclude },
+    AliasSpec { name: "exclude_paratext",  filter_key: "is_paratext",                         entity_type: "",              kind: AliasKind::Exclude },
+    AliasSpec { name: "sdg",         filter_key: "sustainable_development_goals.id",          entity_type: "",              kind: AliasKind::Sdg },
 ];
 
 pub(crate) const AUTHOR_ALIASES: &[AliasSpec] = &[
     AliasSpec { name: "institution", filter_key: "last_known_institutions.id",                entity_type: "institutions",  kind: AliasKind::Entity },
-    AliasSpec { name: "country",     filter_key: "last_known_institutions.country_code",      entity_type: "",              kind: AliasKind::Direct },
-    AliasSpec { name: "continent",   filter_key: "last_known_institutions.continent",         entity_type: "",              kind: AliasKind::Direct },
+    AliasSpec { name: "country",     filter_key: "last_known_institutions.country_code",      entity_type: "",              kind: AliasKind::Country },
+    AliasSpec { name: "continent",   filter_key: "last_known_institutions.continent",         entity_type: "",              kind: AliasKind::Continent },
     AliasSpec { name: "citations",   filter_key: "cited_by_count",                            entity_type: "",              kind: AliasKind::Direct },
     AliasSpec { name: "works",       filter_key: "works_count",                               entity_type: "",              kind: AliasKind::Direct },
     AliasSpec { name: "h_index",     filter_key: "summary_stats.h_index",                     entity_type: "",              kind: AliasKind::Direct },
@@ -82,8 +108,8 @@ pub(crate) const AUTHOR_ALIASES: &[AliasSpec] = &[
 
 pub(crate) const SOURCE_ALIASES: &[AliasSpec] = &[
     AliasSpec { name: "publisher",   filter_key: "host_organization_lineage",                 entity_type: "publishers",    kind: AliasKind::Entity },
-    AliasSpec { name: "country",     filter_key: "country_code",                              entity_type: "",              kind: AliasKind::Direct },
-    AliasSpec { name: "continent",   filter_key: "continent",                                 entity_type: "",              kind: AliasKind::Direct },
+    AliasSpec { name: "country",     filter_key: "country_code",                              entity_type: "",              kind: AliasKind::Country },
+    AliasSpec { name: "continent",   filter_key: "continent",                                 entity_type: "",              kind: AliasKind::Continent },
     AliasSpec { name: "type",        filter_key: "type",                                      entity_type: "",              kind: AliasKind::Direct },
     AliasSpec { name: "open",        filter_key: "is_oa",                                     entity_type: "",              kind: AliasKind::Boolean },
     AliasSpec { name: "citations",   filter_key: "cited_by_count",                            entity_type: "",              kind: AliasKind::Direct },
@@ -91,8 +117,8 @@ pub(crate) const SOURCE_ALIASES: &[AliasSpec] = &[
 ];
 
 pub(crate) const INSTITUTION_ALIASES: &[AliasSpec] = &[
-    AliasSpec { name: "country",     filter_key: "country_code",                              entity_type: "",              kind: AliasKind::Direct },
-    AliasSpec { name: "continent",   filter_key: "continent",                                 entity_type: "",              kind: AliasKind::Direct },
+    AliasSpec { name: "country",     filter_key: "country_code",                              entity_type: "",              kind: AliasKind::Country },
+    AliasSpec { name: "continent",   filter_key: "continent",                                 entity_type: "",              kind: AliasKind::Continent },
     AliasSpec { name: "type",        filter_key: "type",                                      entity_type: "",              kind: AliasKind::Direct },
     AliasSpec { name: "citations",   filter_key: "cited_by_count",                            entity_type: "",              kind: AliasKind::Direct },
     AliasSpec { name: "works",       filter_key: "works_count",                               entity_type: "",              kind: AliasKind::Direct },
@@ -107,15 +133,15 @@ pub(crate) const TOPIC_ALIASES: &[AliasSpec] = &[
 ];
 
 pub(crate) const PUBLISHER_ALIASES: &[AliasSpec] = &[
-    AliasSpec { name: "country",     filter_key: "country_codes",                             entity_type: "",              kind: AliasKind::Direct },
-    AliasSpec { name: "continent",   filter_key: "continent",                                 entity_type: "",              kind: AliasKind::Direct },
+    AliasSpec { name: "country",     filter_key: "country_codes",                             entity_type: "",              kind: AliasKind::Country },
+    AliasSpec { name: "continent",   filter_key: "continent",                                 entity_type: "",              kind: AliasKind::Continent },
     AliasSpec { name: "citations",   filter_key: "cited_by_count",                            entity_type: "",              kind: AliasKind::Direct },
     AliasSpec { name: "works",       filter_key: "works_count",                               entity_type: "",              kind: AliasKind::Direct },
 ];
 
 pub(crate) const FUNDER_ALIASES: &[AliasSpec] = &[
-    AliasSpec { name: "country",     filter_key: "country_code",                              entity_type: "",              kind: AliasKind::Direct },
-    AliasSpec { name: "continent",   filter_key: "continent",                                 entity_type: "",              kind: AliasKind::Direct },
+    AliasSpec { name: "country",     filter_key: "country_code",                              entity_type: "",              kind: AliasKind::Country },
+    AliasSpec { name: "continent",   filter_key: "continent",                                 entity_type: "",              kind: AliasKind::Continent },
     AliasSpec { name: "citations",   filter_key: "cited_by_count",                            entity_type: "",              kind: AliasKind::Direct },
     AliasSpec { name: "works",       filter_key: "works_count",                               entity_type: "",              kind: AliasKind::Direct },
 ];
@@ -177,6 +203,11 @@ pub(crate) async fn resolve_filters(
                     resolve_alias_value(client, val, spec.entity_type).await?
                 }
                 AliasKind::Boolean => "true".to_string(),
+                AliasKind::Exclude => "false".to_string(),
+                AliasKind::Country => resolve_country_value(val)?,
+                AliasKind::Continent => resolve_continent_value(val)?,
+                AliasKind::Language => resolve_language_value(val)?,
+                AliasKind::Sdg => resolve_sdg_value(val)?,
             };
             conditions.push(format!("{}:{}", spec.filter_key, resolved_value));
         }
@@ -233,6 +264,10 @@ pub struct WorkListParams {
     pub continent: Option<String>,
     pub r#type: Option<String>,
     pub open: Option<bool>,
+    pub language: Option<String>,
+    pub exclude_retracted: Option<bool>,
+    pub exclude_paratext: Option<bool>,
+    pub sdg: Option<String>,
 }
 
 impl WorkListParams {
@@ -252,6 +287,10 @@ impl WorkListParams {
             self.continent.clone(),
             self.r#type.clone(),
             if self.open == Some(true) { Some("true".to_string()) } else { None },
+            self.language.clone(),
+            if self.exclude_retracted == Some(true) { Some("false".to_string()) } else { None },
+            if self.exclude_paratext == Some(true) { Some("false".to_string()) } else { None },
+            self.sdg.clone(),
         ];
         let list_params = ListParams {
             filter: self.filter.clone(),
@@ -290,6 +329,10 @@ pub async fn resolve_work_filters(
         aliases.continent.clone(),
         aliases.r#type.clone(),
         if aliases.open == Some(true) { Some("true".to_string()) } else { None },
+        aliases.language.clone(),
+        if aliases.exclude_retracted == Some(true) { Some("false".to_string()) } else { None },
+        if aliases.exclude_paratext == Some(true) { Some("false".to_string()) } else { None },
+        aliases.sdg.clone(),
     ];
     resolve_filters(client, WORK_ALIASES, &alias_values, raw_filter).await
 }
@@ -311,6 +354,10 @@ pub struct WorkFilterAliases {
     pub continent: Option<String>,
     pub r#type: Option<String>,
     pub open: Option<bool>,
+    pub language: Option<String>,
+    pub exclude_retracted: Option<bool>,
+    pub exclude_paratext: Option<bool>,
+    pub sdg: Option<String>,
 }
 
 // ── Macro for entity list params ─────────────────────────────────────────
@@ -673,6 +720,115 @@ async fn resolve_alias_value(
     Ok(resolved.join("|"))
 }
 
+/// Map each `|`-separated segment through `resolve`, keeping a leading `!`.
+fn map_segments(
+    value: &str,
+    mut resolve: impl FnMut(&str) -> Result<String, FilterError>,
+) -> Result<String, FilterError> {
+    let mut resolved = Vec::new();
+    for segment in value.split('|') {
+        let segment = segment.trim();
+        if segment.is_empty() {
+            continue;
+        }
+        match segment.strip_prefix('!') {
+            Some(rest) => resolved.push(format!("!{}", resolve(rest.trim())?)),
+            None => resolved.push(resolve(segment)?),
+        }
+    }
+    Ok(resolved.join("|"))
+}
+
+/// Normalize country values to uppercase ISO 3166-1 alpha-2 codes.
+fn resolve_country_value(value: &str) -> Result<String, FilterError> {
+    map_segments(value, |segment| {
+        if let Some(country) = geo::find_country(segment) {
+            return Ok(country.code.to_string());
+        }
+        let hint = if let Some(c) = geo::Continent::parse(segment) {
+            format!("\"{segment}\" is a continent; use continent:{} instead. ", c.as_str())
+        } else {
+            let close = geo::countries_matching(segment);
+            if close.is_empty() {
+                "Use an ISO 3166-1 alpha-2 code or an English country name. ".to_string()
+            } else {
+                let names: Vec<_> =
+                    close.iter().map(|c| format!("{} ({})", c.code, c.name)).collect();
+                format!("Did you mean {}? ", names.join(", "))
+            }
+        };
+        Err(FilterError::InvalidValue {
+            alias: "country",
+            value: segment.to_string(),
+            hint,
+            valid: geo::COUNTRIES.iter().map(|c| c.code.to_string()).collect(),
+        })
+    })
+}
+
+/// Normalize continent values to the names OpenAlex uses (`north_america`).
+fn resolve_continent_value(value: &str) -> Result<String, FilterError> {
+    map_segments(value, |segment| {
+        if let Some(c) = geo::Continent::parse(segment) {
+            return Ok(c.as_str().to_string());
+        }
+        let hint = match geo::find_country(segment) {
+            Some(country) => format!(
+                "\"{segment}\" is a country in {}; use country:{} instead. ",
+                country.continent.as_str(),
+                country.code
+            ),
+            None => String::new(),
+        };
+        Err(FilterError::InvalidValue {
+            alias: "continent",
+            value: segment.to_string(),
+            hint,
+            valid: geo::Continent::ALL.iter().map(|c| c.as_str().to_string()).collect(),
+        })
+    })
+}
+
+/// Normalize language values to lowercase ISO 639-1 codes (`german` → `de`).
+fn resolve_language_value(value: &str) -> Result<String, FilterError> {
+    map_segments(value, |segment| {
+        if let Some(lang) = language::find_language(segment) {
+            return Ok(lang.code.to_string());
+        }
+        Err(FilterError::InvalidValue {
+            alias: "language",
+            value: segment.to_string(),
+            hint: "Use an ISO 639-1 code or an English language name. ".to_string(),
+            valid: language::LANGUAGES.iter().map(|l| l.code.to_string()).collect(),
+        })
+    })
+}
+
+/// Normalize SDG values to OpenAlex ID URIs (`13` → `https://metadata.un.org/sdg/13`).
+fn resolve_sdg_value(value: &str) -> Result<String, FilterError> {
+    map_segments(value, |segment| {
+        if let Some(goal) = sdg::find_sdg(segment) {
+            return Ok(goal.id());
+        }
+        let close = sdg::sdgs_matching(segment);
+        if let [goal] = close.as_slice() {
+            return Ok(goal.id());
+        }
+        let hint = if close.is_empty() {
+            "Use a goal number (1-17) or name. ".to_string()
+        } else {
+            let names: Vec<_> = close.iter().map(|g| format!("{} ({})", g.number, g.name)).collect();
+            format!("Did you mean {}? ", names.join(", "))
+        };
+        Err(FilterError::InvalidValue {
+            alias: "sdg",
+            value: segment.to_string(),
+            hint,
+            valid: sdg::SDGS.iter().map(|g| format!("{} ({})", g.number, g.name)).collect(),
+        })
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1056,6 +1212,166 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_continent_normalized() {
+        let client = OpenAlexClient::new();
+        let aliases = WorkFilterAliases {
+            continent: Some("North America|!south-america".to_string()),
+            ..Default::default()
+        };
+        let result = resolve_work_filters(&client, &aliases, None).await.unwrap();
+        assert_eq!(
+            result.as_deref(),
+            Some("authorships.institutions.continent:north_america|!south_america")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_continent_invalid_lists_valid_values() {
+        let client = OpenAlexClient::new();
+        let aliases = WorkFilterAliases {
+            continent: Some("france".to_string()),
+            ..Default::default()
+        };
+        let err = resolve_work_filters(&client, &aliases, None).await.unwrap_err();
+        let msg = err.to_string();
+        assert!(matches!(err, FilterError::InvalidValue { alias: "continent", .. }));
+        assert!(msg.contains("use country:FR"), "{msg}");
+        assert!(msg.contains("africa, antarctica, asia, europe, north_america, oceania, south_america"));
+    }
+
+    #[tokio::test]
+    async fn test_country_names_and_lowercase() {
+        let client = OpenAlexClient::new();
+        let aliases = WorkFilterAliases {
+            country: Some("us|Germany|uk".to_string()),
+            ..Default::default()
+        };
+        let result = resolve_work_filters(&client, &aliases, None).await.unwrap();
+        assert_eq!(
+            result.as_deref(),
+            Some("authorships.institutions.country_code:US|DE|GB")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_language_names_and_codes() {
+        let client = OpenAlexClient::new();
+        let aliases = WorkFilterAliases {
+            language: Some("German|EN|!français".to_string()),
+            ..Default::default()
+        };
+        let result = resolve_work_filters(&client, &aliases, None).await.unwrap();
+        assert_eq!(result.as_deref(), Some("language:de|en|!fr"));
+    }
+
+    #[tokio::test]
+    async fn test_exclude_retracted_and_paratext() {
+        let client = OpenAlexClient::new();
+        let aliases = WorkFilterAliases {
+            exclude_retracted: Some(true),
+            exclude_paratext: Some(true),
+            ..Default::default()
+        };
+        let result = resolve_work_filters(&client, &aliases, None).await.unwrap();
+        assert_eq!(result.as_deref(), Some("is_retracted:false,is_paratext:false"));
+    }
+
+    #[tokio::test]
+    async fn test_exclude_false_is_noop() {
+        let client = OpenAlexClient::new();
+        let aliases = WorkFilterAliases {
+            exclude_retracted: Some(false),
+            ..Default::default()
+        };
+        let result = resolve_work_filters(&client, &aliases, None).await.unwrap();
+        assert_eq!(result, None);
+    }
+
+    #[tokio::test]
+    async fn test_exclude_retracted_conflicts_with_raw_filter() {
+        let client = OpenAlexClient::new();
+        let aliases = WorkFilterAliases {
+            exclude_retracted: Some(true),
+            ..Default::default()
+        };
+        let err = resolve_work_filters(&client, &aliases, Some("is_retracted:true"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, FilterError::Conflict { alias: "exclude_retracted", .. }));
+    }
+
+    #[tokio::test]
+    async fn test_language_invalid() {
+        let client = OpenAlexClient::new();
+        let aliases = WorkFilterAliases {
+            language: Some("elvish".to_string()),
+            ..Default::default()
+        };
+        let err = resolve_work_filters(&client, &aliases, None).await.unwrap_err();
+        match err {
+            FilterError::InvalidValue { alias, valid, .. } => {
+                assert_eq!(alias, "language");
+                assert!(valid.contains(&"de".to_string()));
+            }
+            other => panic!("expected InvalidValue, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sdg_numbers_and_names() {
+        let client = OpenAlexClient::new();
+        let aliases = WorkFilterAliases {
+            sdg: Some("3|SDG 13|!hunger".to_string()),
+            ..Default::default()
+        };
+        let result = resolve_work_filters(&client, &aliases, None).await.unwrap();
+        assert_eq!(
+            result.as_deref(),
+            Some(
+                "sustainable_development_goals.id:https://metadata.un.org/sdg/3\
+                 |https://metadata.un.org/sdg/13|!https://metadata.un.org/sdg/2"
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sdg_ambiguous_name() {
+        let client = OpenAlexClient::new();
+        let aliases = WorkFilterAliases {
+            sdg: Some("water".to_string()),
+            ..Default::default()
+        };
+        let err = resolve_work_filters(&client, &aliases, None).await.unwrap_err();
+        let msg = err.to_string();
+        assert!(msg.contains("Did you mean 6 (Clean water and sanitation), 14 (Life below water)?"));
+        assert!(matches!(err, FilterError::InvalidValue { alias: "sdg", .. }));
+    }
+
+    #[tokio::test]
+    async fn test_country_invalid() {
+        let client = OpenAlexClient::new();
+        let aliases = WorkFilterAliases {
+            country: Some("europe".to_string()),
+            ..Default::default()
+        };
+        let err = resolve_work_filters(&client, &aliases, None).await.unwrap_err();
+        assert!(err.to_string().contains("use continent:europe"));
+
+        let aliases = WorkFilterAliases {
+            country: Some("ZZ".to_string()),
+            ..Default::default()
+        };
+        let err = resolve_work_filters(&client, &aliases, None).await.unwrap_err();
+        match err {
+            FilterError::InvalidValue { alias, valid, .. } => {
+                assert_eq!(alias, "country");
+                assert!(valid.contains(&"US".to_string()));
+            }
+            other => panic!("expected InvalidValue, got {other:?}"),
+        }
+    }
+
     #[tokio::test]
     async fn test_type_direct() {
         let client = OpenAlexClient::new();
@@ -0,0 +1,296 @@
+//! Local country and continent lookup tables.
+//!
+//! OpenAlex accepts `country_code` filters as ISO 3166-1 alpha-2 codes and
+//! `continent` filters as snake_case continent names. Anything else (a
+//! lowercase code, "north america", "Germany") silently matches nothing, so
+//! shorthand filters are normalized and validated against these tables before
+//! being sent.
+
+/// A continent as OpenAlex names it in `continent` filters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Continent {
+    Africa,
+    Antarctica,
+    Asia,
+    Europe,
+    NorthAmerica,
+    Oceania,
+    SouthAmerica,
+}
+
+impl Continent {
+    pub const ALL: [Continent; 7] = [
+        Continent::Africa,
+        Continent::Antarctica,
+        Continent::Asia,
+        Continent::Europe,
+        Continent::NorthAmerica,
+        Continent::Oceania,
+        Continent::SouthAmerica,
+    ];
+
+    /// The filter value OpenAlex expects, e.g. `north_america`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Continent::Africa => "africa",
+            Continent::Antarctica => "antarctica",
+            Continent::Asia => "asia",
+            Continent::Europe => "europe",
+            Continent::NorthAmerica => "north_america",
+            Continent::Oceania => "oceania",
+            Continent::SouthAmerica => "south_america",
+        }
+    }
+
+    /// Wikidata ID OpenAlex uses for the continent entity.
+    pub fn wikidata_id(self) -> &'static str {
+        match self {
+            Continent::Africa => "Q15",
+            Continent::Antarctica => "Q51",
+            Continent::Asia => "Q48",
+            Continent::Europe => "Q46",
+            Continent::NorthAmerica => "Q49",
+            Continent::Oceania => "Q55643",
+            Continent::SouthAmerica => "Q18",
+        }
+    }
+
+    /// Parse a continent name leniently: case, spaces, hyphens and
+    /// underscores are ignored, and Wikidata IDs are accepted.
+    pub fn parse(value: &str) -> Option<Continent> {
+        let key = normalize_name(value);
+        let wikidata = value.trim().rsplit('/').next().unwrap_or("");
+        Continent::ALL
+            .into_iter()
+            .find(|c| {
+                normalize_name(c.as_str()) == key || c.wikidata_id().eq_ignore_ascii_case(wikidata)
+            })
+            .or(match key.as_str() {
+                "australia" | "australasia" => Some(Continent::Oceania),
+                "centralamerica" | "caribbean" => Some(Continent::NorthAmerica),
+                "latinamerica" => Some(Continent::SouthAmerica),
+                _ => None,
+            })
+    }
+}
+
+/// One row of the ISO 3166-1 table.
+#[derive(Debug, Clone, Copy)]
+pub struct Country {
+    /// ISO 3166-1 alpha-2 code, uppercase.
+    pub code: &'static str,
+    pub name: &'static str,
+    pub continent: Continent,
+}
+
+/// Look up a country by alpha-2 code (any case) or English short name.
+///
+/// A few common alternatives (`UK`, `USA`, `United States of America`) are
+/// accepted as well.
+pub fn find_country(value: &str) -> Option<&'static Country> {
+    let trimmed = value.trim();
+    if trimmed.len() == 2 {
+        let code = trimmed.to_ascii_uppercase();
+        let code = if code == "UK" { "GB".to_string() } else { code };
+        return COUNTRIES.iter().find(|c| c.code == code);
+    }
+    let key = normalize_name(trimmed);
+    let code = match key.as_str() {
+        "usa" | "unitedstatesofamerica" | "america" => Some("US"),
+        "greatbritain" | "england" | "scotland" | "wales" => Some("GB"),
+        "southkorea" => Some("KR"),
+        "northkorea" => Some("KP"),
+        "russia" => Some("RU"),
+        "iran" => Some("IR"),
+        "vietnam" => Some("VN"),
+        "czechia" => Some("CZ"),
+        "turkiye" => Some("TR"),
+        _ => None,
+    };
+    match code {
+        Some(code) => COUNTRIES.iter().find(|c| c.code == code),
+        None => COUNTRIES.iter().find(|c| normalize_name(c.name) == key),
+    }
+}
+
+/// The continent a country belongs to, by alpha-2 code or name.
+pub fn country_continent(value: &str) -> Option<Continent> {
+    find_country(value).map(|c| c.continent)
+}
+
+/// Countries whose code or name starts with `prefix`, for error hints.
+pub fn countries_matching(prefix: &str) -> Vec<&'static Country> {
+    let key = normalize_name(prefix);
+    if key.is_empty() {
+        return Vec::new();
+    }
+    COUNTRIES
+        .iter()
+        .filter(|c| {
+            normalize_name(c.name).starts_with(&key) || c.code.eq_ignore_ascii_case(&key)
+        })
+        .collect()
+}
+
+fn normalize_name(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+use Continent::{
+    Africa as AF, Antarctica as AN, Asia as AS, Europe as EU, NorthAmerica as NA,
+    Oceania as OC, SouthAmerica as SA,
+};
+
+macro_rules! countries {
+    ($($code:literal $name:literal $cont:ident),* $(,)?) => {
+        /// ISO 3166-1 alpha-2 codes with the continent GeoNames assigns them.
+        pub static COUNTRIES: &[Country] = &[
+            $(Country { code: $code, name: $name, continent: $cont }),*
+        ];
+    };
+}
+
+countries![
+    "AD" "Andorra" EU, "AE" "United Arab Emirates" AS, "AF" "Afghanistan" AS,
+    "AG" "Antigua and Barbuda" NA, "AI" "Anguilla" NA, "AL" "Albania" EU,
+    "AM" "Armenia" AS, "AO" "Angola" AF, "AQ" "Antarctica" AN,
+    "AR" "Argentina" SA, "AS" "American Samoa" OC, "AT" "Austria" EU,
+    "AU" "Australia" OC, "AW" "Aruba" NA, "AX" "Aland Islands" EU,
+    "AZ" "Azerbaijan" AS, "BA" "Bosnia and Herzegovina" EU, "BB" "Barbados" NA,
+    "BD" "Bangladesh" AS, "BE" "Belgium" EU, "BF" "Burkina Faso" AF,
+    "BG" "Bulgaria" EU, "BH" "Bahrain" AS, "BI" "Burundi" AF,
+    "BJ" "Benin" AF, "BL" "Saint Barthelemy" NA, "BM" "Bermuda" NA,
+    "BN" "Brunei" AS, "BO" "Bolivia" SA, "BQ" "Caribbean Netherlands" NA,
+    "BR" "Brazil" SA, "BS" "Bahamas" NA, "BT" "Bhutan" AS,
+    "BV" "Bouvet Island" AN, "BW" "Botswana" AF, "BY" "Belarus" EU,
+    "BZ" "Belize" NA, "CA" "Canada" NA, "CC" "Cocos (Keeling) Islands" AS,
+    "CD" "Democratic Republic of the Congo" AF, "CF" "Central African Republic" AF,
+    "CG" "Republic of the Congo" AF, "CH" "Switzerland" EU, "CI" "Cote d'Ivoire" AF,
+    "CK" "Cook Islands" OC, "CL" "Chile" SA, "CM" "Cameroon" AF,
+    "CN" "China" AS, "CO" "Colombia" SA, "CR" "Costa Rica" NA,
+    "CU" "Cuba" NA, "CV" "Cabo Verde" AF, "CW" "Curacao" NA,
+    "CX" "Christmas Island" OC, "CY" "Cyprus" EU, "CZ" "Czech Republic" EU,
+    "DE" "Germany" EU, "DJ" "Djibouti" AF, "DK" "Denmark" EU,
+    "DM" "Dominica" NA, "DO" "Dominican Republic" NA, "DZ" "Algeria" AF,
+    "EC" "Ecuador" SA, "EE" "Estonia" EU, "EG" "Egypt" AF,
+    "EH" "Western Sahara" AF, "ER" "Eritrea" AF, "ES" "Spain" EU,
+    "ET" "Ethiopia" AF, "FI" "Finland" EU, "FJ" "Fiji" OC,
+    "FK" "Falkland Islands" SA, "FM" "Micronesia" OC, "FO" "Faroe Islands" EU,
+    "FR" "France" EU, "GA" "Gabon" AF, "GB" "United Kingdom" EU,
+    "GD" "Grenada" NA, "GE" "Georgia" AS, "GF" "French Guiana" SA,
+    "GG" "Guernsey" EU, "GH" "Ghana" AF, "GI" "Gibraltar" EU,
+    "GL" "Greenland" NA, "GM" "Gambia" AF, "GN" "Guinea" AF,
+    "GP" "Guadeloupe" NA, "GQ" "Equatorial Guinea" AF, "GR" "Greece" EU,
+    "GS" "South Georgia and the South Sandwich Islands" AN, "GT" "Guatemala" NA,
+    "GU" "Guam" OC, "GW" "Guinea-Bissau" AF, "GY" "Guyana" SA,
+    "HK" "Hong Kong" AS, "HM" "Heard Island and McDonald Islands" AN, "HN" "Honduras" NA,
+    "HR" "Croatia" EU, "HT" "Haiti" NA, "HU" "Hungary" EU,
+    "ID" "Indonesia" AS, "IE" "Ireland" EU, "IL" "Israel" AS,
+    "IM" "Isle of Man" EU, "IN" "India" AS, "IO" "British Indian Ocean Territory" AS,
+    "IQ" "Iraq" AS, "IR" "Islamic Republic of Iran" AS, "IS" "Iceland" EU,
+    "IT" "Italy" EU, "JE" "Jersey" EU, "JM" "Jamaica" NA,
+    "JO" "Jordan" AS, "JP" "Japan" AS, "KE" "Kenya" AF,
+    "KG" "Kyrgyzstan" AS, "KH" "Cambodia" AS, "KI" "Kiribati" OC,
+    "KM" "Comoros" AF, "KN" "Saint Kitts and Nevis" NA, "KP" "Democratic People's Republic of Korea" AS,
+    "KR" "Republic of Korea" AS, "KW" "Kuwait" AS, "KY" "Cayman Islands" NA,
+    "KZ" "Kazakhstan" AS, "LA" "Laos" AS, "LB" "Lebanon" AS,
+    "LC" "Saint Lucia" NA, "LI" "Liechtenstein" EU, "LK" "Sri Lanka" AS,
+    "LR" "Liberia" AF, "LS" "Lesotho" AF, "LT" "Lithuania" EU,
+    "LU" "Luxembourg" EU, "LV" "Latvia" EU, "LY" "Libya" AF,
+    "MA" "Morocco" AF, "MC" "Monaco" EU, "MD" "Moldova" EU,
+    "ME" "Montenegro" EU, "MF" "Saint Martin" NA, "MG" "Madagascar" AF,
+    "MH" "Marshall Islands" OC, "MK" "North Macedonia" EU, "ML" "Mali" AF,
+    "MM" "Myanmar" AS, "MN" "Mongolia" AS, "MO" "Macao" AS,
+    "MP" "Northern Mariana Islands" OC, "MQ" "Martinique" NA, "MR" "Mauritania" AF,
+    "MS" "Montserrat" NA, "MT" "Malta" EU, "MU" "Mauritius" AF,
+    "MV" "Maldives" AS, "MW" "Malawi" AF, "MX" "Mexico" NA,
+    "MY" "Malaysia" AS, "MZ" "Mozambique" AF, "NA" "Namibia" AF,
+    "NC" "New Caledonia" OC, "NE" "Niger" AF, "NF" "Norfolk Island" OC,
+    "NG" "Nigeria" AF, "NI" "Nicaragua" NA, "NL" "Netherlands" EU,
+    "NO" "Norway" EU, "NP" "Nepal" AS, "NR" "Nauru" OC,
+    "NU" "Niue" OC, "NZ" "New Zealand" OC, "OM" "Oman" AS,
+    "PA" "Panama" NA, "PE" "Peru" SA, "PF" "French Polynesia" OC,
+    "PG" "Papua New Guinea" OC, "PH" "Philippines" AS, "PK" "Pakistan" AS,
+    "PL" "Poland" EU, "PM" "Saint Pierre and Miquelon" NA, "PN" "Pitcairn" OC,
+    "PR" "Puerto Rico" NA, "PS" "Palestine" AS, "PT" "Portugal" EU,
+    "PW" "Palau" OC, "PY" "Paraguay" SA, "QA" "Qatar" AS,
+    "RE" "Reunion" AF, "RO" "Romania" EU, "RS" "Serbia" EU,
+    "RU" "Russian Federation" EU, "RW" "Rwanda" AF, "SA" "Saudi Arabia" AS,
+    "SB" "Solomon Islands" OC, "SC" "Seychelles" AF, "SD" "Sudan" AF,
+    "SE" "Sweden" EU, "SG" "Singapore" AS, "SH" "Saint Helena" AF,
+    "SI" "Slovenia" EU, "SJ" "Svalbard and Jan Mayen" EU, "SK" "Slovakia" EU,
+    "SL" "Sierra Leone" AF, "SM" "San Marino" EU, "SN" "Senegal" AF,
+    "SO" "Somalia" AF, "SR" "Suriname" SA, "SS" "South Sudan" AF,
+    "ST" "Sao Tome and Principe" AF, "SV" "El Salvador" NA, "SX" "Sint Maarten" NA,
+    "SY" "Syria" AS, "SZ" "Eswatini" AF, "TC" "Turks and Caicos Islands" NA,
+    "TD" "Chad" AF, "TF" "French Southern Territories" AN, "TG" "Togo" AF,
+    "TH" "Thailand" AS, "TJ" "Tajikistan" AS, "TK" "Tokelau" OC,
+    "TL" "Timor-Leste" OC, "TM" "Turkmenistan" AS, "TN" "Tunisia" AF,
+    "TO" "Tonga" OC, "TR" "Turkey" AS, "TT" "Trinidad and Tobago" NA,
+    "TV" "Tuvalu" OC, "TW" "Taiwan" AS, "TZ" "Tanzania" AF,
+    "UA" "Ukraine" EU, "UG" "Uganda" AF, "UM" "United States Minor Outlying Islands" OC,
+    "US" "United States" NA, "UY" "Uruguay" SA, "UZ" "Uzbekistan" AS,
+    "VA" "Holy See" EU, "VC" "Saint Vincent and the Grenadines" NA, "VE" "Venezuela" SA,
+    "VG" "British Virgin Islands" NA, "VI" "U.S. Virgin Islands" NA, "VN" "Viet Nam" AS,
+    "VU" "Vanuatu" OC, "WF" "Wallis and Futuna" OC, "WS" "Samoa" OC,
+    "XK" "Kosovo" EU, "YE" "Yemen" AS, "YT" "Mayotte" AF,
+    "ZA" "South Africa" AF, "ZM" "Zambia" AF, "ZW" "Zimbabwe" AF,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn table_codes_are_unique_and_sorted() {
+        for pair in COUNTRIES.windows(2) {
+            assert!(pair[0].code < pair[1].code, "{} >= {}", pair[0].code, pair[1].code);
+        }
+        assert!(COUNTRIES.iter().all(|c| c.code.len() == 2
+            && c.code.chars().all(|ch| ch.is_ascii_uppercase())));
+    }
+
+    #[test]
+    fn continent_parse_variants() {
+        assert_eq!(Continent::parse("north america"), Some(Continent::NorthAmerica));
+        assert_eq!(Continent::parse("North-America"), Some(Continent::NorthAmerica));
+        assert_eq!(Continent::parse("north_america"), Some(Continent::NorthAmerica));
+        assert_eq!(Continent::parse("EUROPE"), Some(Continent::Europe));
+        assert_eq!(Continent::parse("Q46"), Some(Continent::Europe));
+        assert_eq!(
+            Continent::parse("https://www.wikidata.org/wiki/Q18"),
+            Some(Continent::SouthAmerica)
+        );
+        assert_eq!(Continent::parse("australia"), Some(Continent::Oceania));
+        assert_eq!(Continent::parse("atlantis"), None);
+    }
+
+    #[test]
+    fn find_country_by_code_and_name() {
+        assert_eq!(find_country("us").unwrap().code, "US");
+        assert_eq!(find_country("UK").unwrap().code, "GB");
+        assert_eq!(find_country("Germany").unwrap().code, "DE");
+        assert_eq!(find_country("south korea").unwrap().code, "KR");
+        assert_eq!(find_country("united states of america").unwrap().code, "US");
+        assert!(find_country("ZZ").is_none());
+        assert!(find_country("Narnia").is_none());
+    }
+
+    #[test]
+    fn country_continent_lookup() {
+        assert_eq!(country_continent("MX"), Some(Continent::NorthAmerica));
+        assert_eq!(country_continent("BR"), Some(Continent::SouthAmerica));
+        assert_eq!(country_continent("nz"), Some(Continent::Oceania));
+        assert_eq!(country_continent("France"), Some(Continent::Europe));
+    }
+
+    #[test]
+    fn countries_matching_prefix() {
+        let codes: Vec<_> = countries_matching("united").iter().map(|c| c.code).collect();
+        assert_eq!(codes, vec!["AE", "GB", "UM", "US"]);
+    }
+}
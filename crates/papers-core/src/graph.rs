@@ -0,0 +1,281 @@
+//! Citation graphs among a set of papers, serialized as GraphML (Gephi,
+//! yEd, Cytoscape) or DOT (Graphviz).
+//!
+//! Nodes are the papers in scope; an edge runs from a citing paper to a cited
+//! paper when both are in scope. Citations to papers outside the scope are
+//! dropped, as are self-citations of a paper to itself.
+
+use std::collections::{BTreeSet, HashSet};
+
+use papers_openalex::Work;
+use serde::Serialize;
+
+use crate::selection::strip_doi_prefix;
+use crate::util::short_id;
+
+/// Fields fetched per work when building a selection citation graph.
+pub const GRAPH_SELECT: &str = "id,display_name,doi,publication_year,cited_by_count,referenced_works";
+
+#[derive(thiserror::Error, Debug)]
+pub enum GraphError {
+    #[error("invalid graph format {0:?}: use graphml or dot")]
+    InvalidFormat(String),
+}
+
+/// Serialization format for a [`CitationGraph`].
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum GraphFormat {
+    GraphMl,
+    Dot,
+}
+
+impl GraphFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GraphFormat::GraphMl => "graphml",
+            GraphFormat::Dot => "dot",
+        }
+    }
+}
+
+impl std::str::FromStr for GraphFormat {
+    type Err = GraphError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "graphml" | "xml" => Ok(GraphFormat::GraphMl),
+            "dot" | "gv" | "graphviz" => Ok(GraphFormat::Dot),
+            _ => Err(GraphError::InvalidFormat(s.to_string())),
+        }
+    }
+}
+
+/// A paper in a citation graph.
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct GraphNode {
+    /// OpenAlex work ID or indexed paper ID.
+    pub id: String,
+    pub label: String,
+    pub year: Option<u32>,
+    /// Bare DOI (no `https://doi.org/` prefix).
+    pub doi: Option<String>,
+    /// Citation count from the node's source (`None` when unknown).
+    pub cited_by_count: Option<i64>,
+}
+
+/// A citation from `source` to `target`.
+#[derive(Serialize, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GraphEdge {
+    pub source: String,
+    pub target: String,
+}
+
+/// Citation graph among a set of papers.
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct CitationGraph {
+    /// Sorted by id.
+    pub nodes: Vec<GraphNode>,
+    /// Sorted by source, then target; no duplicates.
+    pub edges: Vec<GraphEdge>,
+}
+
+impl CitationGraph {
+    /// Build a graph from `nodes` and candidate `edges`, keeping only edges
+    /// between two distinct nodes.
+    pub fn new(mut nodes: Vec<GraphNode>, edges: impl IntoIterator<Item = GraphEdge>) -> Self {
+        nodes.sort_by(|a, b| a.id.cmp(&b.id));
+        nodes.dedup_by(|a, b| a.id == b.id);
+        let ids: HashSet<&str> = nodes.iter().map(|n| n.id.as_str()).collect();
+        let edges: BTreeSet<GraphEdge> = edges
+            .into_iter()
+            .filter(|e| e.source != e.target)
+            .filter(|e| ids.contains(e.source.as_str()) && ids.contains(e.target.as_str()))
+            .collect();
+        CitationGraph { nodes, edges: edges.into_iter().collect() }
+    }
+
+    /// Serialize in `format`.
+    pub fn render(&self, format: GraphFormat) -> String {
+        match format {
+            GraphFormat::GraphMl => to_graphml(self),
+            GraphFormat::Dot => to_dot(self),
+        }
+    }
+}
+
+/// Graph over `works`, with an edge for each `referenced_works` entry that is
+/// itself one of `works`.
+pub fn graph_from_works(works: &[Work]) -> CitationGraph {
+    let nodes = works
+        .iter()
+        .map(|w| GraphNode {
+            id: short_id(&w.id).to_string(),
+            label: w.display_name.clone().or_else(|| w.title.clone()).unwrap_or_default(),
+            year: w.publication_year.and_then(|y| u32::try_from(y).ok()),
+            doi: w.doi.as_deref().map(|d| strip_doi_prefix(d).to_string()),
+            cited_by_count: w.cited_by_count,
+        })
+        .collect();
+    let edges = works.iter().flat_map(|w| {
+        let source = short_id(&w.id).to_string();
+        w.referenced_works.iter().flatten().map(move |r| GraphEdge {
+            source: source.clone(),
+            target: short_id(r).to_string(),
+        })
+    });
+    CitationGraph::new(nodes, edges)
+}
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// GraphML document with `label`, `year`, `doi` and `cited_by_count` node
+/// attributes.
+pub fn to_graphml(graph: &CitationGraph) -> String {
+    let mut out = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n\
+         \x20 <key id=\"label\" for=\"node\" attr.name=\"label\" attr.type=\"string\"/>\n\
+         \x20 <key id=\"year\" for=\"node\" attr.name=\"year\" attr.type=\"int\"/>\n\
+         \x20 <key id=\"doi\" for=\"node\" attr.name=\"doi\" attr.type=\"string\"/>\n\
+         \x20 <key id=\"cited_by_count\" for=\"node\" attr.name=\"cited_by_count\" attr.type=\"long\"/>\n\
+         \x20 <graph id=\"citations\" edgedefault=\"directed\">\n",
+    );
+    for node in &graph.nodes {
+        out.push_str(&format!("    <node id=\"{}\">\n", xml_escape(&node.id)));
+        out.push_str(&format!("      <data key=\"label\">{}</data>\n", xml_escape(&node.label)));
+        if let Some(year) = node.year {
+            out.push_str(&format!("      <data key=\"year\">{year}</data>\n"));
+        }
+        if let Some(doi) = &node.doi {
+            out.push_str(&format!("      <data key=\"doi\">{}</data>\n", xml_escape(doi)));
+        }
+        if let Some(count) = node.cited_by_count {
+            out.push_str(&format!("      <data key=\"cited_by_count\">{count}</data>\n"));
+        }
+        out.push_str("    </node>\n");
+    }
+    for edge in &graph.edges {
+        out.push_str(&format!(
+            "    <edge source=\"{}\" target=\"{}\"/>\n",
+            xml_escape(&edge.source),
+            xml_escape(&edge.target)
+        ));
+    }
+    out.push_str("  </graph>\n</graphml>\n");
+    out
+}
+
+fn dot_quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', " "))
+}
+
+/// DOT digraph; nodes are labelled `title (year)`.
+pub fn to_dot(graph: &CitationGraph) -> String {
+    let mut out = String::from("digraph citations {\n  rankdir=LR;\n  node [shape=box];\n");
+    for node in &graph.nodes {
+        let label = match node.year {
+            Some(year) => format!("{} ({year})", node.label),
+            None => node.label.clone(),
+        };
+        out.push_str(&format!("  {} [label={}];\n", dot_quote(&node.id), dot_quote(&label)));
+    }
+    for edge in &graph.edges {
+        out.push_str(&format!("  {} -> {};\n", dot_quote(&edge.source), dot_quote(&edge.target)));
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// A serialized citation graph with its size.
+#[derive(Serialize, Debug)]
+pub struct GraphExport {
+    /// Selection name or description of the DB scope.
+    pub scope: String,
+    pub format: GraphFormat,
+    pub nodes: usize,
+    pub edges: usize,
+    /// Selection entries that could not be looked up in OpenAlex.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub unresolved: Vec<String>,
+    /// GraphML or DOT text.
+    pub content: String,
+}
+
+impl GraphExport {
+    pub fn new(scope: &str, format: GraphFormat, graph: &CitationGraph, unresolved: Vec<String>) -> Self {
+        GraphExport {
+            scope: scope.to_string(),
+            format,
+            nodes: graph.nodes.len(),
+            edges: graph.edges.len(),
+            unresolved,
+            content: graph.render(format),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn work(id: &str, title: &str, refs: &[&str]) -> Work {
+        serde_json::from_value(serde_json::json!({
+            "id": format!("https://openalex.org/{id}"),
+            "display_name": title,
+            "doi": format!("https://doi.org/10.1234/{id}"),
+            "publication_year": 2020,
+            "cited_by_count": 3,
+            "referenced_works": refs.iter().map(|r| format!("https://openalex.org/{r}")).collect::<Vec<_>>(),
+        }))
+        .unwrap()
+    }
+
+    fn sample() -> CitationGraph {
+        graph_from_works(&[
+            work("W2", "Follow-up <fast> & \"simple\"", &["W1", "W9", "W2", "W1"]),
+            work("W1", "Origin", &[]),
+        ])
+    }
+
+    #[test]
+    fn keeps_only_edges_within_scope() {
+        let graph = sample();
+        let ids: Vec<_> = graph.nodes.iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(ids, ["W1", "W2"]);
+        assert_eq!(graph.edges, vec![GraphEdge { source: "W2".into(), target: "W1".into() }]);
+        assert_eq!(graph.nodes[0].doi.as_deref(), Some("10.1234/W1"));
+    }
+
+    #[test]
+    fn graphml_escapes_and_lists_edges() {
+        let xml = to_graphml(&sample());
+        assert!(xml.contains("<graph id=\"citations\" edgedefault=\"directed\">"));
+        assert!(xml.contains("<data key=\"label\">Follow-up &lt;fast&gt; &amp; &quot;simple&quot;</data>"));
+        assert!(xml.contains("<data key=\"year\">2020</data>"));
+        assert!(xml.contains("<edge source=\"W2\" target=\"W1\"/>"));
+        assert!(xml.ends_with("</graphml>\n"));
+    }
+
+    #[test]
+    fn dot_quotes_labels() {
+        let dot = to_dot(&sample());
+        assert!(dot.starts_with("digraph citations {"));
+        assert!(dot.contains("\"W1\" [label=\"Origin (2020)\"];"));
+        assert!(dot.contains("[label=\"Follow-up <fast> & \\\"simple\\\" (2020)\"];"));
+        assert!(dot.contains("\"W2\" -> \"W1\";"));
+    }
+
+    #[test]
+    fn parses_format_names() {
+        assert_eq!("GraphML".parse::<GraphFormat>().unwrap(), GraphFormat::GraphMl);
+        assert_eq!("gv".parse::<GraphFormat>().unwrap(), GraphFormat::Dot);
+        assert!("gexf".parse::<GraphFormat>().is_err());
+    }
+}
@@ -0,0 +1,337 @@
+//! Paper identifiers in free text.
+//!
+//! [`extract_identifiers`] scans arbitrary pasted text (an email, a reference
+//! list, a chat message) for DOIs, arXiv IDs, OpenAlex work IDs and, where an
+//! entry carries none of those, a probable title. Each candidate gets a
+//! confidence and the line it came from, and an `input` that
+//! [`resolve_paper`](crate::selection::resolve_paper) accepts, so the
+//! candidates worth keeping can be handed straight to
+//! [`add_papers`](crate::selection::add_papers).
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::references::{find_dois, parse_reference, split_entries};
+
+/// Titles scoring below this are not reported.
+pub const MIN_TITLE_CONFIDENCE: f64 = 0.4;
+
+/// Longest `context` reported, in characters.
+const CONTEXT_CHARS: usize = 200;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IdentifierKind {
+    Doi,
+    Arxiv,
+    Openalex,
+    Title,
+}
+
+/// One identifier found in the text.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct IdentifierCandidate {
+    pub kind: IdentifierKind,
+    /// Lowercase bare DOI, versionless arXiv ID, short OpenAlex ID (`W…`), or
+    /// title text.
+    pub value: String,
+    /// What to pass to `selection_add` / `selection_add_bulk`: `value`, or the
+    /// arXiv DOI (`10.48550/arXiv.…`) for an arXiv ID.
+    pub input: String,
+    /// 0–1: about 1 for prefixed identifiers (`doi:`, `arXiv:`, URLs), lower
+    /// for bare IDs, and at most 0.9 for titles.
+    pub confidence: f64,
+    /// The line or reference entry the identifier was found in.
+    pub context: String,
+}
+
+/// Scan `text` for paper identifiers: DOIs, arXiv and OpenAlex IDs in order
+/// of appearance, then probable titles. A paper mentioned more than once is
+/// reported once, with its highest confidence.
+///
+/// Titles are only guessed for entries with no DOI, arXiv or OpenAlex ID,
+/// using the reference parser; they score higher with a year, name-like
+/// authors, a list label, quotes, and Title Case. Entries are the items of a
+/// numbered list when the text has one, else its lines.
+pub fn extract_identifiers(text: &str) -> Vec<IdentifierCandidate> {
+    let mut found = Vec::new();
+    for line in text.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        found.extend(line_identifiers(line));
+    }
+    let mut entries = split_entries(text);
+    if entries.iter().all(|(number, _)| number.is_none()) {
+        entries = text.lines().map(str::trim).filter(|l| !l.is_empty()).map(|l| (None, l.to_string())).collect();
+    }
+    for (number, entry) in entries {
+        if line_identifiers(&entry).is_empty()
+            && let Some(candidate) = title_candidate(number, &entry)
+        {
+            found.push(candidate);
+        }
+    }
+
+    let mut out: Vec<IdentifierCandidate> = Vec::new();
+    let mut index: HashMap<(IdentifierKind, String), usize> = HashMap::new();
+    for candidate in found {
+        let key = (candidate.kind, candidate.value.to_lowercase());
+        match index.get(&key) {
+            Some(&i) => {
+                if candidate.confidence > out[i].confidence {
+                    out[i].confidence = candidate.confidence;
+                }
+            }
+            None => {
+                index.insert(key, out.len());
+                out.push(candidate);
+            }
+        }
+    }
+    out
+}
+
+fn context(text: &str) -> String {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    match text.char_indices().nth(CONTEXT_CHARS) {
+        Some((end, _)) => format!("{}…", &text[..end]),
+        None => text,
+    }
+}
+
+fn candidate(kind: IdentifierKind, value: String, input: String, confidence: f64, line: &str) -> IdentifierCandidate {
+    IdentifierCandidate { kind, value, input, confidence, context: context(line) }
+}
+
+/// DOIs, arXiv IDs and OpenAlex IDs in one line, in order of appearance.
+fn line_identifiers(line: &str) -> Vec<IdentifierCandidate> {
+    let lower = line.to_lowercase();
+    let mut out = Vec::new();
+
+    for (start, doi) in find_dois(line) {
+        let before = lower[..start].trim_end_matches([' ', ':']);
+        let prefixed = before.ends_with("doi") || before.ends_with("doi.org/");
+        let confidence = if prefixed { 0.99 } else { 0.95 };
+        out.push((start, candidate(IdentifierKind::Doi, doi.clone(), doi, confidence, line)));
+    }
+
+    let mut arxiv_seen = Vec::new();
+    for prefix in ["arxiv:", "arxiv.org/abs/", "arxiv.org/pdf/"] {
+        let mut from = 0;
+        while let Some(pos) = lower[from..].find(prefix) {
+            let start = from + pos + prefix.len();
+            from = start;
+            if let Some(id) = arxiv_id(line[start..].trim_start()) {
+                arxiv_seen.push(id.clone());
+                out.push((start, arxiv_candidate(id, 0.97, line)));
+            }
+        }
+    }
+
+    for (start, token) in tokens(line) {
+        if let Some(id) = new_style_arxiv_id(token)
+            && !arxiv_seen.contains(&id)
+        {
+            out.push((start, arxiv_candidate(id, 0.6, line)));
+            continue;
+        }
+        let (id, confidence) = match token.to_lowercase().find("openalex.org/") {
+            Some(i) => (token[i + "openalex.org/".len()..].trim_start_matches("works/"), 0.97),
+            None => (token, 0.7),
+        };
+        let digits = id.strip_prefix(['W', 'w']).unwrap_or("");
+        if digits.len() >= 5 && digits.chars().all(|c| c.is_ascii_digit()) {
+            let id = format!("W{digits}");
+            out.push((start, candidate(IdentifierKind::Openalex, id.clone(), id, confidence, line)));
+        }
+    }
+    out.sort_by_key(|(start, _)| *start);
+    out.into_iter().map(|(_, c)| c).collect()
+}
+
+/// Whitespace-separated words of `line` without surrounding punctuation,
+/// with their byte offsets.
+fn tokens(line: &str) -> impl Iterator<Item = (usize, &str)> {
+    line.split_whitespace()
+        .map(|t| t.trim_matches(['(', ')', '[', ']', '<', '>', ',', ';', '.', '"', '\'']))
+        .filter(|t| !t.is_empty())
+        .map(move |t| (t.as_ptr() as usize - line.as_ptr() as usize, t))
+}
+
+fn arxiv_candidate(id: String, confidence: f64, line: &str) -> IdentifierCandidate {
+    let input = format!("10.48550/arXiv.{id}");
+    candidate(IdentifierKind::Arxiv, id, input, confidence, line)
+}
+
+/// The arXiv ID at the start of `text`, without its version: new style
+/// (`2301.12345`) or old style (`hep-th/9901001`).
+fn arxiv_id(text: &str) -> Option<String> {
+    let token: String = text
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '/' | '-'))
+        .collect();
+    let token = token.trim_end_matches('.');
+    if let Some(id) = new_style_arxiv_id(token) {
+        return Some(id);
+    }
+    let (archive, number) = token.split_once('/')?;
+    let number = strip_version(number);
+    let archive_ok = !archive.is_empty()
+        && archive.chars().all(|c| c.is_ascii_alphabetic() || matches!(c, '-' | '.'));
+    (archive_ok && number.len() == 7 && number.chars().all(|c| c.is_ascii_digit()))
+        .then(|| format!("{}/{number}", archive.to_lowercase()))
+}
+
+/// `YYMM.NNNNN` (with an optional `vN`) as a versionless arXiv ID.
+fn new_style_arxiv_id(token: &str) -> Option<String> {
+    let token = strip_version(token);
+    let (yymm, number) = token.split_once('.')?;
+    let month: u32 = yymm.get(2..)?.parse().ok()?;
+    let digits_ok = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+    (yymm.len() == 4
+        && digits_ok(yymm)
+        && (1..=12).contains(&month)
+        && (4..=5).contains(&number.len())
+        && digits_ok(number))
+    .then(|| token.to_string())
+}
+
+fn strip_version(id: &str) -> &str {
+    match id.rfind('v') {
+        Some(i) if i > 0 && id.len() > i + 1 && id[i + 1..].chars().all(|c| c.is_ascii_digit()) => &id[..i],
+        _ => id,
+    }
+}
+
+/// Lowercase name particles (`van der Waals`).
+const NAME_PARTICLES: &[&str] = &["van", "von", "der", "den", "de", "da", "di", "du", "la", "le"];
+
+/// True when every author looks like a personal name: up to five words of
+/// letters (and `.`, `-`, `'`, `,`), each capitalized unless a particle.
+fn looks_like_names(authors: &[String]) -> bool {
+    let is_name = |name: &str| {
+        let words: Vec<&str> = name.split_whitespace().collect();
+        (1..=5).contains(&words.len())
+            && name.chars().filter(|c| c.is_alphabetic()).count() >= 2
+            && words.iter().all(|w| {
+                w.chars().all(|c| c.is_alphabetic() || matches!(c, '.' | '-' | '\'' | ','))
+                    && (w.chars().next().is_some_and(char::is_uppercase) || NAME_PARTICLES.contains(w))
+            })
+    };
+    !authors.is_empty() && authors.iter().all(|a| is_name(a))
+}
+
+/// True when most words of `title` longer than three letters are capitalized.
+fn is_title_case(title: &str) -> bool {
+    let words: Vec<&str> = title.split_whitespace().filter(|w| w.chars().count() > 3).collect();
+    let capitalized = words.iter().filter(|w| w.chars().next().is_some_and(char::is_uppercase)).count();
+    !words.is_empty() && capitalized * 4 >= words.len() * 3
+}
+
+/// A probable title in a reference entry or line without identifiers.
+fn title_candidate(number: Option<u32>, entry: &str) -> Option<IdentifierCandidate> {
+    let parsed = parse_reference(number, entry);
+    let quoted = entry.contains(['"', '\u{201c}']);
+    let (title, mut confidence) = match parsed.title.clone() {
+        Some(title) => {
+            let mut c: f64 = 0.2;
+            if parsed.year.is_some() {
+                c += 0.25;
+            }
+            if looks_like_names(&parsed.authors) {
+                c += 0.25;
+            }
+            if quoted {
+                c += 0.1;
+            }
+            (title, c)
+        }
+        // A bare line with no sentence break: the line itself may be a title.
+        None if parsed.year.is_none() && !entry.trim_end_matches('.').contains(". ") => {
+            (entry.trim().trim_end_matches('.').to_string(), 0.35)
+        }
+        None => return None,
+    };
+    let words = title.split_whitespace().count();
+    if !(3..=40).contains(&words) {
+        return None;
+    }
+    if number.is_some() {
+        confidence += 0.1;
+    }
+    if is_title_case(&title) {
+        confidence += 0.1;
+    }
+    let confidence = (confidence.min(0.9) * 100.0).round() / 100.0;
+    (confidence >= MIN_TITLE_CONFIDENCE)
+        .then(|| candidate(IdentifierKind::Title, title.clone(), title, confidence, entry))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(found: &[IdentifierCandidate]) -> Vec<(IdentifierKind, &str)> {
+        found.iter().map(|c| (c.kind, c.value.as_str())).collect()
+    }
+
+    #[test]
+    fn test_extract_identifiers_from_email() {
+        let text = "Hi! Two papers worth a look:\n\
+                    https://doi.org/10.1145/3592433 (the VBD paper), and arXiv:1706.03762v7.\n\
+                    Also see https://openalex.org/W2741809807 and 2103.00020 if you have time.\n\
+                    DOI: 10.1038/NATURE14539.";
+        let found = extract_identifiers(text);
+        assert_eq!(
+            kinds(&found),
+            vec![
+                (IdentifierKind::Doi, "10.1145/3592433"),
+                (IdentifierKind::Arxiv, "1706.03762"),
+                (IdentifierKind::Openalex, "W2741809807"),
+                (IdentifierKind::Arxiv, "2103.00020"),
+                (IdentifierKind::Doi, "10.1038/nature14539"),
+            ]
+        );
+        assert_eq!(found[0].confidence, 0.99);
+        assert_eq!(found[1].input, "10.48550/arXiv.1706.03762");
+        assert_eq!(found[1].confidence, 0.97);
+        assert_eq!(found[3].confidence, 0.6);
+        assert!(found[2].context.starts_with("Also see"));
+    }
+
+    #[test]
+    fn test_extract_identifiers_from_reference_list() {
+        let text = "[1] A. Vaswani, N. Shazeer, and N. Parmar. Attention is all you need. In NeurIPS, 2017.\n\
+                    [2] Chris Giles, Elie Diaz, and Cem Yuksel. Augmented vertex block descent. ACM TOG, 2025. doi:10.1145/3731195\n\
+                    [3] Smith, J. (2020). A study of things. Journal of Stuff.\n\
+                    [4] Old preprint, arXiv hep-th/9901001 and arXiv:hep-th/9901001v2";
+        let found = extract_identifiers(text);
+        assert_eq!(
+            kinds(&found),
+            vec![
+                (IdentifierKind::Doi, "10.1145/3731195"),
+                (IdentifierKind::Arxiv, "hep-th/9901001"),
+                (IdentifierKind::Title, "Attention is all you need"),
+                (IdentifierKind::Title, "A study of things"),
+            ]
+        );
+        let attention = &found[2];
+        assert_eq!(attention.confidence, 0.8);
+        assert!(attention.context.starts_with("A. Vaswani"));
+    }
+
+    #[test]
+    fn test_bare_title_lines_need_title_case() {
+        let found = extract_identifiers(
+            "Hi! Two papers worth a look:\nNeural Radiance Fields for View Synthesis\nthanks again for the help today\n",
+        );
+        assert_eq!(kinds(&found), vec![(IdentifierKind::Title, "Neural Radiance Fields for View Synthesis")]);
+        assert_eq!(found[0].confidence, 0.45);
+    }
+
+    #[test]
+    fn test_version_numbers_are_not_arxiv_ids() {
+        assert!(extract_identifiers("Upgraded to 2024.1312 and W12").is_empty());
+        assert!(new_style_arxiv_id("2413.12345").is_none());
+        assert_eq!(new_style_arxiv_id("2301.12345v3").as_deref(), Some("2301.12345"));
+    }
+}
@@ -0,0 +1,125 @@
+//! ISO 639-1 language lookup for the `language` work filter.
+//!
+//! OpenAlex stores a work's language as a lowercase ISO 639-1 code (`en`,
+//! `de`). Names such as "german" or uppercase codes match nothing, so the
+//! shorthand filter maps them to codes before sending.
+
+/// One row of the ISO 639-1 table.
+#[derive(Debug, Clone, Copy)]
+pub struct Language {
+    /// Two-letter ISO 639-1 code, lowercase.
+    pub code: &'static str,
+    /// English name.
+    pub name: &'static str,
+}
+
+/// Look up a language by ISO 639-1 code (any case), English name, or a few
+/// common native names and ISO 639-2 codes (`deutsch`, `deu`, `fra`).
+pub fn find_language(value: &str) -> Option<&'static Language> {
+    let key = value.trim().to_lowercase();
+    if key.len() == 2 {
+        return LANGUAGES.iter().find(|l| l.code == key);
+    }
+    let code = match key.as_str() {
+        "eng" => Some("en"),
+        "deutsch" | "deu" | "ger" => Some("de"),
+        "français" | "francais" | "fra" | "fre" => Some("fr"),
+        "español" | "espanol" | "castilian" | "spa" => Some("es"),
+        "português" | "portugues" | "por" => Some("pt"),
+        "italiano" | "ita" => Some("it"),
+        "nederlands" | "flemish" | "nld" | "dut" => Some("nl"),
+        "русский" | "rus" => Some("ru"),
+        "中文" | "mandarin" | "zho" | "chi" => Some("zh"),
+        "日本語" | "jpn" => Some("ja"),
+        "한국어" | "kor" => Some("ko"),
+        "farsi" | "fas" | "per" => Some("fa"),
+        _ => None,
+    };
+    match code {
+        Some(code) => LANGUAGES.iter().find(|l| l.code == code),
+        None => LANGUAGES.iter().find(|l| {
+            l.name
+                .split(", ")
+                .any(|n| n.eq_ignore_ascii_case(&key))
+        }),
+    }
+}
+
+macro_rules! languages {
+    ($($code:literal $name:literal),* $(,)?) => {
+        /// All ISO 639-1 codes. Alternative names are separated by `", "`.
+        pub static LANGUAGES: &[Language] = &[
+            $(Language { code: $code, name: $name }),*
+        ];
+    };
+}
+
+languages![
+    "aa" "Afar", "ab" "Abkhazian", "ae" "Avestan", "af" "Afrikaans", "ak" "Akan",
+    "am" "Amharic", "an" "Aragonese", "ar" "Arabic", "as" "Assamese", "av" "Avaric",
+    "ay" "Aymara", "az" "Azerbaijani", "ba" "Bashkir", "be" "Belarusian",
+    "bg" "Bulgarian", "bi" "Bislama", "bm" "Bambara", "bn" "Bengali, Bangla",
+    "bo" "Tibetan", "br" "Breton", "bs" "Bosnian", "ca" "Catalan, Valencian",
+    "ce" "Chechen", "ch" "Chamorro", "co" "Corsican", "cr" "Cree", "cs" "Czech",
+    "cu" "Church Slavic", "cv" "Chuvash", "cy" "Welsh", "da" "Danish", "de" "German",
+    "dv" "Divehi, Maldivian", "dz" "Dzongkha", "ee" "Ewe", "el" "Greek",
+    "en" "English", "eo" "Esperanto", "es" "Spanish", "et" "Estonian", "eu" "Basque",
+    "fa" "Persian", "ff" "Fulah", "fi" "Finnish", "fj" "Fijian", "fo" "Faroese",
+    "fr" "French", "fy" "Western Frisian", "ga" "Irish", "gd" "Scottish Gaelic, Gaelic",
+    "gl" "Galician", "gn" "Guarani", "gu" "Gujarati", "gv" "Manx", "ha" "Hausa",
+    "he" "Hebrew", "hi" "Hindi", "ho" "Hiri Motu", "hr" "Croatian",
+    "ht" "Haitian Creole, Haitian", "hu" "Hungarian", "hy" "Armenian", "hz" "Herero",
+    "ia" "Interlingua", "id" "Indonesian", "ie" "Interlingue", "ig" "Igbo",
+    "ii" "Sichuan Yi", "ik" "Inupiaq", "io" "Ido", "is" "Icelandic", "it" "Italian",
+    "iu" "Inuktitut", "ja" "Japanese", "jv" "Javanese", "ka" "Georgian", "kg" "Kongo",
+    "ki" "Kikuyu", "kj" "Kuanyama", "kk" "Kazakh", "kl" "Kalaallisut, Greenlandic",
+    "km" "Khmer", "kn" "Kannada", "ko" "Korean", "kr" "Kanuri", "ks" "Kashmiri",
+    "ku" "Kurdish", "kv" "Komi", "kw" "Cornish", "ky" "Kyrgyz, Kirghiz", "la" "Latin",
+    "lb" "Luxembourgish", "lg" "Ganda", "li" "Limburgish", "ln" "Lingala", "lo" "Lao",
+    "lt" "Lithuanian", "lu" "Luba-Katanga", "lv" "Latvian", "mg" "Malagasy",
+    "mh" "Marshallese", "mi" "Maori", "mk" "Macedonian", "ml" "Malayalam",
+    "mn" "Mongolian", "mr" "Marathi", "ms" "Malay", "mt" "Maltese", "my" "Burmese",
+    "na" "Nauru", "nb" "Norwegian Bokmal, Bokmal", "nd" "North Ndebele", "ne" "Nepali",
+    "ng" "Ndonga", "nl" "Dutch", "nn" "Norwegian Nynorsk, Nynorsk", "no" "Norwegian",
+    "nr" "South Ndebele", "nv" "Navajo", "ny" "Chichewa, Nyanja", "oc" "Occitan",
+    "oj" "Ojibwa", "om" "Oromo", "or" "Oriya, Odia", "os" "Ossetian", "pa" "Punjabi",
+    "pi" "Pali", "pl" "Polish", "ps" "Pashto", "pt" "Portuguese", "qu" "Quechua",
+    "rm" "Romansh", "rn" "Rundi", "ro" "Romanian, Moldavian", "ru" "Russian",
+    "rw" "Kinyarwanda", "sa" "Sanskrit", "sc" "Sardinian", "sd" "Sindhi",
+    "se" "Northern Sami", "sg" "Sango", "si" "Sinhala", "sk" "Slovak", "sl" "Slovenian",
+    "sm" "Samoan", "sn" "Shona", "so" "Somali", "sq" "Albanian", "sr" "Serbian",
+    "ss" "Swati", "st" "Southern Sotho", "su" "Sundanese", "sv" "Swedish",
+    "sw" "Swahili", "ta" "Tamil", "te" "Telugu", "tg" "Tajik", "th" "Thai",
+    "ti" "Tigrinya", "tk" "Turkmen", "tl" "Tagalog, Filipino", "tn" "Tswana",
+    "to" "Tonga", "tr" "Turkish", "ts" "Tsonga", "tt" "Tatar", "tw" "Twi",
+    "ty" "Tahitian", "ug" "Uyghur, Uighur", "uk" "Ukrainian", "ur" "Urdu", "uz" "Uzbek",
+    "ve" "Venda", "vi" "Vietnamese", "vo" "Volapuk", "wa" "Walloon", "wo" "Wolof",
+    "xh" "Xhosa", "yi" "Yiddish", "yo" "Yoruba", "za" "Zhuang", "zh" "Chinese",
+    "zu" "Zulu",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn table_codes_are_unique_and_sorted() {
+        assert_eq!(LANGUAGES.len(), 183);
+        for pair in LANGUAGES.windows(2) {
+            assert!(pair[0].code < pair[1].code, "{} >= {}", pair[0].code, pair[1].code);
+        }
+    }
+
+    #[test]
+    fn find_by_code_name_and_alias() {
+        assert_eq!(find_language("DE").unwrap().code, "de");
+        assert_eq!(find_language("german").unwrap().code, "de");
+        assert_eq!(find_language("Deutsch").unwrap().code, "de");
+        assert_eq!(find_language("deu").unwrap().code, "de");
+        assert_eq!(find_language("Bangla").unwrap().code, "bn");
+        assert_eq!(find_language("filipino").unwrap().code, "tl");
+        assert_eq!(find_language("中文").unwrap().code, "zh");
+        assert!(find_language("xx").is_none());
+        assert!(find_language("klingon").is_none());
+    }
+}
@@ -0,0 +1,762 @@
+//! Filling `\cite` keys in a LaTeX fragment from a selection.
+//!
+//! Three kinds of citation are found in the fragment:
+//!
+//! - placeholders: `?` or `?hint` in a `\cite`-family command, e.g.
+//!   `\citep{?}` or `\cite{?vaswani attention}`;
+//! - keys already cited, e.g. `\cite{vaswani2017attention}`;
+//! - author-year mentions in prose, e.g. `Vaswani et al. (2017)` or
+//!   `(Vaswani and Shazeer, 2017)`.
+//!
+//! Each is matched against the entries of an existing BibTeX file, when one is
+//! given, and then the selection's records. A placeholder is matched on its
+//! hint, or on the sentence before it when bare: by DOI, then first author and
+//! year, then title words. Mentions are matched on first author and year.
+//! Resolved placeholders are replaced by their key; selection records cited
+//! but missing from the BibTeX file are returned as entries to add.
+
+use std::collections::HashSet;
+
+use papers_openalex::OpenAlexClient;
+use papers_zotero::ZoteroClient;
+use serde::Serialize;
+
+use crate::export::{CitationRecord, key_part, selection_records, to_bibtex};
+use crate::selection::{SelectionError, load_selection};
+
+/// Title words shorter than this are not matched.
+const MIN_TITLE_WORD: usize = 3;
+
+/// At least this share of a title's words must appear in a hint.
+const MIN_TITLE_OVERLAP: f64 = 0.5;
+
+/// A bare placeholder is matched on at most this many words before it.
+const CONTEXT_WORDS: usize = 40;
+
+const TITLE_STOPWORDS: &[&str] = &["the", "and", "for", "with", "from", "via", "using", "towards", "into", "its", "are"];
+
+/// Capitalized words that start a sentence before a year rather than name an author.
+const NOT_AUTHORS: &[&str] = &[
+    "In", "By", "Since", "Until", "From", "After", "Before", "During", "Of", "The", "As", "At", "On", "See",
+    "Also", "Early", "Late", "Mid", "Circa",
+];
+
+// ── Result types ───────────────────────────────────────────────────────────
+
+/// How a citation appears in the fragment.
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CiteKind {
+    Placeholder,
+    Key,
+    Mention,
+}
+
+/// Where a resolved key comes from.
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum KeySource {
+    Bibtex,
+    Selection,
+}
+
+/// One citation in the fragment and what it resolved to.
+#[derive(Serialize, Clone, Debug)]
+pub struct CiteMatch {
+    pub kind: CiteKind,
+    /// 1-based line in the fragment.
+    pub line: usize,
+    /// The placeholder, key, or mention as written.
+    pub text: String,
+    /// Resolved cite key; `None` when nothing or several entries matched.
+    pub key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<KeySource>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Keys of equally good matches when the citation is ambiguous.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub candidates: Vec<String>,
+}
+
+/// Result of [`fill_citations`].
+#[derive(Serialize, Debug)]
+pub struct CiteFill {
+    /// The fragment with resolved placeholders replaced by their keys.
+    pub latex: String,
+    pub citations: Vec<CiteMatch>,
+    pub resolved: usize,
+    pub unresolved: usize,
+    /// Selection keys cited (or resolved) that the BibTeX file lacks.
+    pub missing_keys: Vec<String>,
+    /// BibTeX entries for `missing_keys`, to append to the `.bib` file.
+    pub missing_bibtex: String,
+}
+
+// ── Candidates ─────────────────────────────────────────────────────────────
+
+/// A work that a citation can resolve to.
+struct Candidate {
+    key: String,
+    source: KeySource,
+    title: Option<String>,
+    /// Normalized first-author family name.
+    first_author: Option<String>,
+    year: Option<u32>,
+    doi: Option<String>,
+    title_words: HashSet<String>,
+    /// Index into the selection records, for selection candidates.
+    record: Option<usize>,
+}
+
+fn title_words(title: &str) -> HashSet<String> {
+    title
+        .split(|c: char| !c.is_alphanumeric())
+        .map(key_part)
+        .filter(|w| w.len() >= MIN_TITLE_WORD && !TITLE_STOPWORDS.contains(&w.as_str()))
+        .collect()
+}
+
+/// Normalized last word of a family name.
+fn family_key(name: &str) -> Option<String> {
+    let last = name.split_whitespace().last()?;
+    Some(key_part(last)).filter(|k| !k.is_empty())
+}
+
+fn title_key(title: &Option<String>) -> Option<String> {
+    title.as_deref().map(key_part).filter(|k| !k.is_empty())
+}
+
+fn bib_candidate(entry: BibEntry) -> Candidate {
+    Candidate {
+        title_words: entry.title.as_deref().map(title_words).unwrap_or_default(),
+        first_author: entry.authors.first().and_then(|a| family_key(a)),
+        key: entry.key,
+        source: KeySource::Bibtex,
+        title: entry.title,
+        year: entry.year,
+        doi: entry.doi.map(|d| d.to_lowercase()),
+        record: None,
+    }
+}
+
+fn record_candidate(index: usize, rec: &CitationRecord) -> Candidate {
+    Candidate {
+        key: rec.key.clone(),
+        source: KeySource::Selection,
+        title: rec.title.clone(),
+        first_author: rec
+            .authors
+            .first()
+            .and_then(|a| a.family.as_deref().or(a.literal.as_deref()))
+            .and_then(family_key),
+        year: rec.date_parts.first().copied(),
+        doi: rec.doi.as_ref().map(|d| d.to_lowercase()),
+        title_words: rec.title.as_deref().map(title_words).unwrap_or_default(),
+        record: Some(index),
+    }
+}
+
+/// BibTeX entries first, then selection records that are not already in the
+/// BibTeX file (same DOI or title). Selection keys that clash with a BibTeX
+/// key get a `b`, `c`, ... suffix.
+fn candidates(records: &[CitationRecord], bibtex: Option<&str>) -> Vec<Candidate> {
+    let mut out: Vec<Candidate> = bibtex.map(parse_bibtex).unwrap_or_default().into_iter().map(bib_candidate).collect();
+    let dois: HashSet<String> = out.iter().filter_map(|c| c.doi.clone()).collect();
+    let titles: HashSet<String> = out.iter().filter_map(|c| title_key(&c.title)).collect();
+    let mut keys: HashSet<String> = out.iter().map(|c| c.key.clone()).collect();
+    for (i, rec) in records.iter().enumerate() {
+        let duplicate = rec.doi.as_ref().is_some_and(|d| dois.contains(&d.to_lowercase()))
+            || title_key(&rec.title).is_some_and(|t| titles.contains(&t));
+        if duplicate {
+            continue;
+        }
+        let mut candidate = record_candidate(i, rec);
+        let mut suffix = b'b';
+        while !keys.insert(candidate.key.clone()) {
+            candidate.key = format!("{}{}", rec.key, suffix as char);
+            suffix = suffix.saturating_add(1);
+        }
+        out.push(candidate);
+    }
+    out
+}
+
+// ── Matching ───────────────────────────────────────────────────────────────
+
+/// `2017` or `2017a`.
+fn year_of(token: &str) -> Option<u32> {
+    let token = token.trim_matches(|c: char| !c.is_alphanumeric());
+    let digits = token.strip_suffix(|c: char| c.is_ascii_lowercase()).unwrap_or(token);
+    if digits.len() != 4 || !(digits.starts_with("19") || digits.starts_with("20")) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+/// Indices of the best-matching candidates for a free-text hint: all that
+/// share its DOI, else the highest-scoring ones. A candidate scores when its
+/// first author and year both appear in the hint, or when enough of its title
+/// words do.
+fn match_hint(hint: &str, candidates: &[Candidate]) -> Vec<usize> {
+    let lower = hint.to_lowercase();
+    let dois: Vec<&str> = lower
+        .split(|c: char| c.is_whitespace() || c == ',' || c == ';')
+        .map(|t| t.trim_end_matches('.'))
+        .filter_map(|t| t.find("10.").map(|i| &t[i..]))
+        .filter(|t| t.contains('/'))
+        .collect();
+    let by_doi: Vec<usize> = (0..candidates.len())
+        .filter(|&i| candidates[i].doi.as_deref().is_some_and(|d| dois.contains(&d)))
+        .collect();
+    if !by_doi.is_empty() {
+        return by_doi;
+    }
+
+    let words: HashSet<String> = hint.split(|c: char| !c.is_alphanumeric()).map(key_part).collect();
+    let hint_title_words = title_words(hint);
+    let years: Vec<u32> = hint.split_whitespace().filter_map(year_of).collect();
+    let scores: Vec<f64> = candidates
+        .iter()
+        .map(|c| {
+            let author = c.first_author.as_ref().is_some_and(|a| words.contains(a));
+            let year = c.year.is_some_and(|y| years.contains(&y));
+            let shared = c.title_words.intersection(&hint_title_words).count();
+            let overlap = if shared >= 2 { shared as f64 / c.title_words.len() as f64 } else { 0.0 };
+            if author && year {
+                2.0 + overlap
+            } else if overlap >= MIN_TITLE_OVERLAP {
+                overlap + if author { 0.5 } else { 0.0 }
+            } else {
+                0.0
+            }
+        })
+        .collect();
+    let best = scores.iter().copied().fold(0.0, f64::max);
+    if best == 0.0 {
+        return Vec::new();
+    }
+    (0..candidates.len()).filter(|&i| (scores[i] - best).abs() < 1e-9).collect()
+}
+
+/// Indices of candidates whose first author is `family` and year is `year`.
+fn match_author_year(family: &str, year: u32, candidates: &[Candidate]) -> Vec<usize> {
+    let family = family_key(family);
+    (0..candidates.len())
+        .filter(|&i| candidates[i].first_author == family && candidates[i].year == Some(year))
+        .collect()
+}
+
+// ── Scanning the fragment ──────────────────────────────────────────────────
+
+/// A `\cite`-family command and the byte range of its key list.
+struct CiteCommand {
+    start: usize,
+    keys: std::ops::Range<usize>,
+}
+
+/// Every command whose name contains `cite` (`\cite`, `\citep`, `\parencite`,
+/// ...), skipping `*` and optional `[...]` arguments before the key list.
+fn cite_commands(text: &str) -> Vec<CiteCommand> {
+    let bytes = text.as_bytes();
+    let mut out = Vec::new();
+    let mut i = 0;
+    while let Some(offset) = text[i..].find('\\') {
+        let start = i + offset;
+        let mut pos = start + 1;
+        while pos < bytes.len() && bytes[pos].is_ascii_alphabetic() {
+            pos += 1;
+        }
+        i = pos.max(start + 1);
+        if !text[start + 1..pos].contains("cite") {
+            continue;
+        }
+        if bytes.get(pos) == Some(&b'*') {
+            pos += 1;
+        }
+        loop {
+            while bytes.get(pos).is_some_and(u8::is_ascii_whitespace) {
+                pos += 1;
+            }
+            if bytes.get(pos) != Some(&b'[') {
+                break;
+            }
+            match text[pos..].find(']') {
+                Some(end) => pos += end + 1,
+                None => break,
+            }
+        }
+        if bytes.get(pos) != Some(&b'{') {
+            continue;
+        }
+        let Some(end) = text[pos..].find('}') else { break };
+        out.push(CiteCommand { start, keys: pos + 1..pos + end });
+        i = pos + end + 1;
+    }
+    out
+}
+
+/// Which bytes of `text` are in a `%` comment.
+fn comment_mask(text: &str) -> Vec<bool> {
+    let mut mask = vec![false; text.len()];
+    let mut line_start = 0;
+    for line in text.split_inclusive('\n') {
+        let comment = line.char_indices().find(|&(i, c)| c == '%' && !line[..i].ends_with('\\'));
+        if let Some((i, _)) = comment {
+            mask[line_start + i..line_start + line.trim_end_matches('\n').len()].fill(true);
+        }
+        line_start += line.len();
+    }
+    mask
+}
+
+/// `text` with the `blank` bytes and cite commands replaced by spaces, byte
+/// offsets kept.
+fn prose_mask(text: &str, mut blank: Vec<bool>, commands: &[CiteCommand]) -> String {
+    for c in commands {
+        blank[c.start..=c.keys.end].fill(true);
+    }
+    text.char_indices()
+        .map(|(i, c)| if blank[i] { " ".repeat(c.len_utf8()) } else { c.to_string() })
+        .collect()
+}
+
+fn line_of(text: &str, offset: usize) -> usize {
+    text[..offset].matches('\n').count() + 1
+}
+
+/// Up to [`CONTEXT_WORDS`] words of the sentence ending at `end` in `prose`.
+fn sentence_before(prose: &str, end: usize) -> String {
+    let before = &prose[..end];
+    let start = [". ", ".\n", "\n\n"].iter().filter_map(|b| before.rfind(b).map(|i| i + b.len())).max().unwrap_or(0);
+    let words: Vec<&str> = before[start..].split_whitespace().collect();
+    words[words.len().saturating_sub(CONTEXT_WORDS)..].join(" ")
+}
+
+/// `word` without a possessive `'s`.
+fn strip_possessive(word: &str) -> &str {
+    word.strip_suffix("'s").or_else(|| word.strip_suffix("’s")).unwrap_or(word)
+}
+
+fn is_name(word: &str) -> bool {
+    let word = strip_possessive(word);
+    let mut chars = word.chars();
+    chars.next().is_some_and(char::is_uppercase)
+        && chars.all(|c| c.is_alphabetic() || c == '-' || c == '\'')
+        && !NOT_AUTHORS.contains(&word)
+}
+
+/// The author group ending `s`: `Name`, `Name et al.`, `Name and Other`, or
+/// `Name & Other`. Returns the byte offset where the group starts and the
+/// first author's name.
+fn author_group(s: &str) -> Option<(usize, &str)> {
+    let s = s.trim_end_matches([' ', ',']);
+    let words: Vec<(usize, &str)> = s
+        .split(' ')
+        .scan(0, |pos, w| {
+            let start = *pos;
+            *pos += w.len() + 1;
+            Some((start, w))
+        })
+        .filter(|(_, w)| !w.is_empty())
+        .collect();
+    let mut n = words.len();
+    if n >= 2 && words[n - 1].1.starts_with("al.") && words[n - 2].1 == "et" {
+        n -= 2;
+    }
+    if n == 0 || !is_name(words[n - 1].1.trim_end_matches(',')) {
+        return None;
+    }
+    let mut first = n - 1;
+    if n >= 3 && matches!(words[n - 2].1, "and" | "&") && is_name(words[n - 3].1) {
+        first = n - 3;
+    }
+    let (start, name) = words[first];
+    Some((start, strip_possessive(name.trim_end_matches(','))))
+}
+
+/// An author-year mention: byte range in the fragment, first author, year.
+struct Mention<'a> {
+    range: std::ops::Range<usize>,
+    author: &'a str,
+    year: u32,
+}
+
+/// Author-year mentions in `prose`: `Name (2017)` after the names, or
+/// `Name, 2017` segments inside parentheses.
+fn mentions(prose: &str) -> Vec<Mention<'_>> {
+    let mut out = Vec::new();
+    let mut line_start = 0;
+    for line in prose.split_inclusive('\n') {
+        let mut from = 0;
+        while let Some(open) = line[from..].find('(').map(|i| from + i) {
+            let Some(close) = line[open..].find(')').map(|i| open + i) else { break };
+            from = close + 1;
+            let inner = &line[open + 1..close];
+            if let Some(year) = year_of(inner).filter(|_| !inner.trim().contains(' ')) {
+                if let Some((start, author)) = author_group(&line[..open]) {
+                    out.push(Mention { range: line_start + start..line_start + close + 1, author, year });
+                }
+                continue;
+            }
+            let mut seg_start = open + 1;
+            for seg in inner.split(';') {
+                let seg_range = seg_start..seg_start + seg.len();
+                seg_start += seg.len() + 1;
+                let trimmed = seg.trim_end();
+                let Some((names, year)) = trimmed.rsplit_once([' ', ',']) else { continue };
+                let Some(year) = year_of(year) else { continue };
+                if let Some((start, author)) = author_group(names.trim_start_matches(' ')) {
+                    let lead = names.len() - names.trim_start_matches(' ').len();
+                    let range_start = line_start + seg_range.start + lead + start;
+                    out.push(Mention { range: range_start..line_start + seg_range.start + trimmed.len(), author, year });
+                }
+            }
+        }
+        line_start += line.len();
+    }
+    out
+}
+
+// ── Filling ────────────────────────────────────────────────────────────────
+
+/// Resolve the citations in `latex` against `bibtex` (an existing `.bib`
+/// file's text) and the selection `records`, as described in the module docs.
+/// Without a BibTeX file every resolved key is reported missing, so
+/// `missing_bibtex` is the bibliography for the fragment.
+pub fn fill_citations(latex: &str, records: &[CitationRecord], bibtex: Option<&str>) -> CiteFill {
+    let candidates = candidates(records, bibtex);
+    let comments = comment_mask(latex);
+    let mut commands = cite_commands(latex);
+    commands.retain(|c| !comments[c.start]);
+    let prose = prose_mask(latex, comments, &commands);
+    let mut citations = Vec::new();
+    let mut cited: Vec<usize> = Vec::new();
+    let mut resolve = |kind, line, text: &str, matched: Vec<usize>| {
+        let mut m = CiteMatch {
+            kind,
+            line,
+            text: text.to_string(),
+            key: None,
+            source: None,
+            title: None,
+            candidates: Vec::new(),
+        };
+        match matched.as_slice() {
+            [i] => {
+                let c = &candidates[*i];
+                m.key = Some(c.key.clone());
+                m.source = Some(c.source);
+                m.title = c.title.clone();
+                if !cited.contains(i) {
+                    cited.push(*i);
+                }
+            }
+            _ => m.candidates = matched.iter().map(|&i| candidates[i].key.clone()).collect(),
+        }
+        let key = m.key.clone();
+        citations.push(m);
+        key
+    };
+
+    let mut filled = String::with_capacity(latex.len());
+    let mut copied = 0;
+    for command in &commands {
+        let line = line_of(latex, command.start);
+        let keys: Vec<String> = latex[command.keys.clone()]
+            .split(',')
+            .map(|piece| {
+                let key = piece.trim();
+                if let Some(hint) = key.strip_prefix('?') {
+                    let hint = if hint.trim().is_empty() { sentence_before(&prose, command.start) } else { hint.to_string() };
+                    match resolve(CiteKind::Placeholder, line, key, match_hint(&hint, &candidates)) {
+                        Some(resolved) => piece.replace(key, &resolved),
+                        None => piece.to_string(),
+                    }
+                } else {
+                    if !key.is_empty() {
+                        let matched = candidates.iter().position(|c| c.key == key).into_iter().collect();
+                        resolve(CiteKind::Key, line, key, matched);
+                    }
+                    piece.to_string()
+                }
+            })
+            .collect();
+        filled.push_str(&latex[copied..command.keys.start]);
+        filled.push_str(&keys.join(","));
+        copied = command.keys.end;
+    }
+    filled.push_str(&latex[copied..]);
+
+    for mention in mentions(&prose) {
+        let matched = match_author_year(mention.author, mention.year, &candidates);
+        resolve(CiteKind::Mention, line_of(latex, mention.range.start), &latex[mention.range], matched);
+    }
+    citations.sort_by_key(|c| c.line);
+
+    let missing: Vec<CitationRecord> = cited
+        .iter()
+        .filter_map(|&i| {
+            let c = &candidates[i];
+            let mut rec = records[c.record?].clone();
+            rec.key = c.key.clone();
+            Some(rec)
+        })
+        .collect();
+    let resolved = citations.iter().filter(|c| c.key.is_some()).count();
+    CiteFill {
+        latex: filled,
+        unresolved: citations.len() - resolved,
+        resolved,
+        missing_keys: missing.iter().map(|r| r.key.clone()).collect(),
+        missing_bibtex: missing.iter().map(to_bibtex).collect::<Vec<_>>().join("\n"),
+        citations,
+    }
+}
+
+/// [`fill_citations`] against the records of selection `name` (built as for
+/// [`crate::export::export_selection`]).
+pub async fn fill_selection_citations(
+    latex: &str,
+    name: &str,
+    bibtex: Option<&str>,
+    client: &OpenAlexClient,
+    zotero: Option<&ZoteroClient>,
+) -> Result<CiteFill, SelectionError> {
+    let selection = load_selection(name)?;
+    let records = selection_records(&selection, client, zotero).await;
+    Ok(fill_citations(latex, &records, bibtex))
+}
+
+// ── BibTeX parsing ─────────────────────────────────────────────────────────
+
+/// The fields of a `.bib` entry used for matching.
+#[derive(Debug, Default, PartialEq)]
+struct BibEntry {
+    key: String,
+    title: Option<String>,
+    /// Author names as written, in order.
+    authors: Vec<String>,
+    year: Option<u32>,
+    doi: Option<String>,
+}
+
+/// The text inside the `{...}` or `(...)` group `s` starts with, and the byte
+/// length of the whole group.
+fn group(s: &str) -> Option<(&str, usize)> {
+    let close = if s.starts_with('(') { ')' } else { '}' };
+    let mut depth = 0usize;
+    for (i, c) in s.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' if depth > 0 => {
+                depth -= 1;
+                if depth == 0 && close == '}' {
+                    return Some((&s[1..i], i + 1));
+                }
+            }
+            ')' if close == ')' && depth == 0 => return Some((&s[1..i], i + 1)),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// A field value without braces, accent commands, or repeated whitespace.
+fn clean_value(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                if chars.peek().is_some_and(|n| "\"'^`~=.".contains(*n)) {
+                    chars.next();
+                }
+            }
+            '{' | '}' => {}
+            c => out.push(c),
+        }
+    }
+    out.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// `name = value` pairs of an entry body after the key.
+fn bib_fields(mut s: &str) -> Vec<(String, String)> {
+    let mut out = Vec::new();
+    while let Some(eq) = s.find('=') {
+        let name = s[..eq].trim().trim_start_matches(',').trim().to_lowercase();
+        let rest = s[eq + 1..].trim_start();
+        let (value, len) = if rest.starts_with('{') {
+            match group(rest) {
+                Some(g) => g,
+                None => break,
+            }
+        } else if let Some(quoted) = rest.strip_prefix('"') {
+            // A quote inside braces, as in `{\"u}`, does not end the value.
+            let mut depth = 0usize;
+            let end = quoted.char_indices().find(|&(_, c)| {
+                match c {
+                    '{' => depth += 1,
+                    '}' => depth = depth.saturating_sub(1),
+                    _ => {}
+                }
+                c == '"' && depth == 0
+            });
+            match end {
+                Some((end, _)) => (&quoted[..end], end + 2),
+                None => break,
+            }
+        } else {
+            let end = rest.find(',').unwrap_or(rest.len());
+            (rest[..end].trim(), end)
+        };
+        out.push((name, clean_value(value)));
+        s = &rest[len..];
+    }
+    out
+}
+
+/// Entries of a `.bib` file; `@comment`, `@string`, and `@preamble` are skipped.
+fn parse_bibtex(text: &str) -> Vec<BibEntry> {
+    let mut out = Vec::new();
+    let mut rest = text;
+    while let Some(at) = rest.find('@') {
+        rest = &rest[at + 1..];
+        let Some(open) = rest.find(['{', '(']) else { break };
+        let kind = rest[..open].trim().to_lowercase();
+        let Some((body, len)) = group(&rest[open..]) else { break };
+        rest = &rest[open + len..];
+        if matches!(kind.as_str(), "comment" | "string" | "preamble") {
+            continue;
+        }
+        let Some((key, fields)) = body.split_once(',') else { continue };
+        let mut entry = BibEntry { key: key.trim().to_string(), ..Default::default() };
+        for (name, value) in bib_fields(fields) {
+            match name.as_str() {
+                "title" => entry.title = Some(value),
+                "author" => {
+                    entry.authors = value
+                        .split(" and ")
+                        .map(|a| a.split_once(',').map_or(a, |(family, _)| family).trim().to_string())
+                        .filter(|a| !a.is_empty())
+                        .collect();
+                }
+                "year" => entry.year = year_of(&value),
+                "date" if entry.year.is_none() => entry.year = value.get(..4).and_then(year_of),
+                "doi" => entry.doi = Some(crate::selection::strip_doi_prefix(&value).to_string()),
+                _ => {}
+            }
+        }
+        out.push(entry);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::record_from_entry;
+    use crate::selection::SelectionEntry;
+
+    const BIB: &str = r#"@comment{jabref-meta: x}
+@inproceedings{mildenhall2020nerf,
+  title = {{NeRF}: Representing Scenes as Neural Radiance Fields for View Synthesis},
+  author = {Mildenhall, Ben and Srinivasan, Pratul P.},
+  year = 2020,
+  doi = {10.1007/978-3-030-58452-8_24},
+}
+@article(muller2022instant,
+  title = "Instant Neural Graphics Primitives",
+  author = "M{\"u}ller, Thomas and Evans, Alex",
+  date = {2022-07},
+)
+"#;
+
+    fn record(key: &str, title: &str, author: &str, year: u32, doi: Option<&str>) -> CitationRecord {
+        let entry: SelectionEntry = serde_json::from_value(serde_json::json!({
+            "zotero_key": null,
+            "openalex_id": null,
+            "doi": doi,
+            "title": title,
+            "authors": [author],
+            "year": year,
+            "issn": null,
+            "isbn": null,
+            "work_type": "article"
+        }))
+        .unwrap();
+        let mut rec = record_from_entry(&entry);
+        rec.key = key.to_string();
+        rec
+    }
+
+    fn records() -> Vec<CitationRecord> {
+        vec![
+            record("vaswani2017attention", "Attention Is All You Need", "Ashish Vaswani", 2017, None),
+            record("kerbl2023gaussian", "3D Gaussian Splatting for Real-Time Radiance Field Rendering", "Bernhard Kerbl", 2023, None),
+            // Already in the .bib file under another key.
+            record("mildenhall2020representing", "NeRF", "Ben Mildenhall", 2020, Some("10.1007/978-3-030-58452-8_24")),
+        ]
+    }
+
+    #[test]
+    fn bibtex_entries_are_parsed() {
+        let entries = parse_bibtex(BIB);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].key, "mildenhall2020nerf");
+        assert_eq!(entries[0].title.as_deref(), Some("NeRF: Representing Scenes as Neural Radiance Fields for View Synthesis"));
+        assert_eq!(entries[0].authors, ["Mildenhall", "Srinivasan"]);
+        assert_eq!(entries[0].year, Some(2020));
+        assert_eq!(entries[1].authors, ["Muller", "Evans"]);
+        assert_eq!(entries[1].year, Some(2022));
+    }
+
+    #[test]
+    fn placeholders_keys_and_mentions_are_resolved() {
+        let latex = "Scenes can be rendered in real time with Gaussian splatting for radiance fields \\citep{?}.\n\
+            Transformers \\cite{?vaswani 2017} build on attention.\n\
+            % \\cite{?}\n\
+            Instant NGP \\cite{muller2022instant, nobody2000} trains fast, unlike Mildenhall et al. (2020).\n\
+            Attention helps (see Vaswani, 2017; Kerbl et al., 2023).\n";
+        let fill = fill_citations(latex, &records(), Some(BIB));
+        assert!(fill.latex.contains("\\citep{kerbl2023gaussian}"), "{}", fill.latex);
+        assert!(fill.latex.contains("\\cite{vaswani2017attention}"));
+        assert!(fill.latex.contains("% \\cite{?}"));
+
+        let got: Vec<_> = fill.citations.iter().map(|c| (c.kind, c.line, c.key.as_deref())).collect();
+        assert_eq!(
+            got,
+            [
+                (CiteKind::Placeholder, 1, Some("kerbl2023gaussian")),
+                (CiteKind::Placeholder, 2, Some("vaswani2017attention")),
+                (CiteKind::Key, 4, Some("muller2022instant")),
+                (CiteKind::Key, 4, None),
+                (CiteKind::Mention, 4, Some("mildenhall2020nerf")),
+                (CiteKind::Mention, 5, Some("vaswani2017attention")),
+                (CiteKind::Mention, 5, Some("kerbl2023gaussian")),
+            ]
+        );
+        assert_eq!(fill.citations[4].text, "Mildenhall et al. (2020)");
+        assert_eq!(fill.citations[6].text, "Kerbl et al., 2023");
+        assert_eq!((fill.resolved, fill.unresolved), (6, 1));
+        assert_eq!(fill.missing_keys, ["kerbl2023gaussian", "vaswani2017attention"]);
+        assert!(fill.missing_bibtex.starts_with("@article{kerbl2023gaussian,\n"));
+    }
+
+    #[test]
+    fn ambiguous_placeholders_list_candidates() {
+        let mut recs = records();
+        recs.push(record("vaswani2017other", "Other Work", "Ashish Vaswani", 2017, None));
+        let fill = fill_citations("\\cite{?Vaswani 2017}", &recs, None);
+        assert_eq!(fill.latex, "\\cite{?Vaswani 2017}");
+        assert_eq!(fill.citations[0].key, None);
+        assert_eq!(fill.citations[0].candidates, ["vaswani2017attention", "vaswani2017other"]);
+        assert!(fill.missing_keys.is_empty());
+    }
+
+    #[test]
+    fn sentence_openers_are_not_mentions() {
+        let prose = "In (2020) results improved. By 2021 (2022) nothing.";
+        assert!(mentions(prose).is_empty());
+    }
+}
@@ -1,11 +1,51 @@
+pub mod abstracts;
 pub mod api;
+pub mod apc;
+pub mod audit;
+pub mod cite;
 pub mod config;
+pub mod crossref;
+pub mod digest;
+pub mod disambiguation;
+pub mod export;
 pub mod extract_cache;
 pub mod filter;
+pub mod geo;
+pub mod graph;
+pub mod identifiers;
+pub mod language;
+pub mod latex_cite;
+pub mod library;
+pub mod metadata_diff;
+pub mod notify;
+pub mod quality;
+pub mod raw;
+pub mod reading_status;
+pub mod references;
+pub mod review;
+pub mod sampled;
+pub mod schedule;
+pub mod screening;
+pub mod sdg;
 pub mod selection;
+pub mod selection_zotero;
+pub mod semantic_scholar;
+pub mod settings;
+pub mod site;
+pub mod smtp;
+pub mod stats;
 pub mod summary;
+pub mod sync;
+pub mod tenant;
 pub mod text;
+pub mod text_quality;
+pub mod timeline;
+pub mod util;
+pub mod vault;
+pub mod versions;
 pub mod zotero;
+pub mod zotero_fulltext;
+pub mod zotero_profiles;
 
 pub use api::WorkGetResponse;
 pub use selection::{
@@ -25,7 +65,8 @@ pub use text::ZoteroItemInfo;
 pub use papers_openalex::{
     Author, Domain, Field, Funder, HierarchyEntity, HierarchyIds, Institution, Publisher, Source,
     Subfield, Topic, Work,
-    DiskCache,
+    CacheLookups, CachePolicy, CacheStats, DiskCache, EntityCacheStats,
+    Snapshot, SnapshotManifest,
     OpenAlexClient, OpenAlexError, Result,
     ListParams, GetParams, FindWorksParams,
     ListMeta, ListResponse,
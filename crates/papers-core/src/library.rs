@@ -0,0 +1,168 @@
+//! Merged search across OpenAlex and the Zotero library.
+//!
+//! Both sources are queried with the same text. A Zotero item and an OpenAlex
+//! work are treated as the same paper when their DOIs agree or, lacking a DOI
+//! on either side, when their titles are near-identical and their years are at
+//! most one apart. Each hit records where the paper already lives: in Zotero,
+//! in the local RAG index (filled in by the caller, which owns the DB), and in
+//! which selections.
+
+use papers_openalex::{ListParams, OpenAlexClient, OpenAlexError, Work};
+use papers_zotero::{Item, ItemListParams, ZoteroClient, ZoteroError};
+use serde::Serialize;
+
+use crate::selection::{
+    Selection, SelectionEntry, entry_matches_doi, entry_matches_key, entry_matches_openalex,
+    fill_from_oa_work, fill_from_zotero_item, list_selection_names, load_selection,
+};
+use crate::versions::title_similarity;
+
+/// Results per source when no limit is given.
+pub const DEFAULT_LIBRARY_SEARCH_LIMIT: u32 = 10;
+
+/// Titles at or above this similarity match when neither side has a DOI to compare.
+pub const MERGE_TITLE_SIMILARITY: f64 = 0.9;
+
+#[derive(Debug, thiserror::Error)]
+pub enum LibrarySearchError {
+    #[error("OpenAlex search failed: {0}")]
+    OpenAlex(#[from] OpenAlexError),
+    #[error("Zotero search failed: {0}")]
+    Zotero(#[from] ZoteroError),
+}
+
+/// One deduplicated search result.
+#[derive(Debug, Clone, Serialize)]
+pub struct LibraryHit {
+    #[serde(flatten)]
+    pub entry: SelectionEntry,
+    pub in_zotero: bool,
+    pub in_rag_index: bool,
+    pub in_selection: bool,
+    /// Names of the selections containing the paper.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub selections: Vec<String>,
+}
+
+impl LibraryHit {
+    fn new(entry: SelectionEntry) -> Self {
+        Self {
+            in_zotero: entry.zotero_key.is_some(),
+            entry,
+            in_rag_index: false,
+            in_selection: false,
+            selections: Vec::new(),
+        }
+    }
+
+    /// IDs the paper may be indexed under in the local DB (DOI, then Zotero key).
+    pub fn db_ids(&self) -> Vec<String> {
+        self.entry.doi.iter().chain(self.entry.zotero_key.iter()).cloned().collect()
+    }
+
+    fn same_paper(&self, other: &SelectionEntry) -> bool {
+        let this = &self.entry;
+        if let (Some(doi), Some(_)) = (&this.doi, &other.doi) {
+            return entry_matches_doi(other, doi);
+        }
+        let years_close = match (this.year, other.year) {
+            (Some(a), Some(b)) => a.abs_diff(b) <= 1,
+            _ => true,
+        };
+        match (&this.title, &other.title) {
+            (Some(a), Some(b)) => years_close && title_similarity(a, b) >= MERGE_TITLE_SIMILARITY,
+            _ => false,
+        }
+    }
+}
+
+fn empty_entry() -> SelectionEntry {
+    SelectionEntry {
+        zotero_key: None,
+        openalex_id: None,
+        doi: None,
+        title: None,
+        authors: None,
+        year: None,
+        issn: None,
+        isbn: None,
+        work_type: None,
+        note: None,
+        tags: Vec::new(),
+    }
+}
+
+/// Merge Zotero items and OpenAlex works into one list: Zotero hits first, in
+/// Zotero's order, then OpenAlex works that matched none of them.
+pub fn merge_hits(items: &[Item], works: &[Work]) -> Vec<LibraryHit> {
+    let mut hits: Vec<LibraryHit> = Vec::with_capacity(items.len() + works.len());
+    for item in items {
+        let mut entry = empty_entry();
+        entry.zotero_key = Some(item.key.clone());
+        fill_from_zotero_item(&mut entry, item);
+        if !hits.iter().any(|h| h.entry.zotero_key == entry.zotero_key) {
+            hits.push(LibraryHit::new(entry));
+        }
+    }
+    for work in works {
+        let mut entry = empty_entry();
+        fill_from_oa_work(&mut entry, work);
+        match hits.iter_mut().find(|h| h.same_paper(&entry)) {
+            Some(hit) => fill_from_oa_work(&mut hit.entry, work),
+            None => hits.push(LibraryHit::new(entry)),
+        }
+    }
+    hits
+}
+
+/// Set `in_selection` and `selections` from the given selections.
+pub fn mark_selections(hits: &mut [LibraryHit], selections: &[Selection]) {
+    for hit in hits {
+        let e = &hit.entry;
+        hit.selections = selections
+            .iter()
+            .filter(|sel| {
+                sel.entries.iter().any(|s| {
+                    e.zotero_key.as_deref().is_some_and(|k| entry_matches_key(s, k))
+                        || e.openalex_id.as_deref().is_some_and(|id| entry_matches_openalex(s, id))
+                        || e.doi.as_deref().is_some_and(|d| entry_matches_doi(s, d))
+                })
+            })
+            .map(|sel| sel.name.clone())
+            .collect();
+        hit.in_selection = !hit.selections.is_empty();
+    }
+}
+
+/// Search OpenAlex and, when a client is given, Zotero for `query`, then merge
+/// and mark selection membership. `in_rag_index` is left `false`.
+pub async fn library_search(
+    client: &OpenAlexClient,
+    zotero: Option<&ZoteroClient>,
+    query: &str,
+    limit: Option<u32>,
+) -> Result<Vec<LibraryHit>, LibrarySearchError> {
+    let limit = limit.unwrap_or(DEFAULT_LIBRARY_SEARCH_LIMIT);
+    let oa_params = ListParams {
+        search: Some(query.to_string()),
+        per_page: Some(limit),
+        ..Default::default()
+    };
+    let z_params = ItemListParams {
+        q: Some(query.to_string()),
+        limit: Some(limit),
+        ..Default::default()
+    };
+    let zotero_search = async {
+        match zotero {
+            Some(z) => z.list_top_items(&z_params).await.map(|r| r.items),
+            None => Ok(Vec::new()),
+        }
+    };
+    let (works, items) = tokio::join!(client.list_works(&oa_params), zotero_search);
+    let mut hits = merge_hits(&items?, &works?.results);
+    let selections: Vec<Selection> =
+        list_selection_names().iter().filter_map(|n| load_selection(n).ok()).collect();
+    mark_selections(&mut hits, &selections);
+    Ok(hits)
+}
@@ -0,0 +1,455 @@
+//! Compare a Zotero item's bibliographic fields with the OpenAlex record.
+//!
+//! Libraries drift: items are added from publisher pages before a DOI exists,
+//! preprints get published, author lists are truncated on import. This module
+//! lines up title, year, DOI, venue, and authors field by field, and builds a
+//! Zotero PATCH payload that brings the item in line with OpenAlex.
+
+use papers_openalex::{GetParams, OpenAlexClient, Work};
+use papers_zotero::{Item, ZoteroClient, ZoteroError};
+use serde::Serialize;
+
+use crate::text::{ZoteroMatchMethod, zotero_item_year, zotero_match_confidence};
+use crate::zotero::{
+    ZoteroWriteError, looks_like_zotero_key, zotero_container_field, zotero_creator,
+    zotero_has_doi_field,
+};
+
+/// A compared field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiffField {
+    Title,
+    Year,
+    Doi,
+    Venue,
+    Authors,
+}
+
+/// How the two records compare on one field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiffStatus {
+    /// Equal after normalising case, punctuation, and DOI prefixes.
+    Same,
+    Differs,
+    MissingInZotero,
+    /// Reported but never patched: the Zotero value is kept.
+    MissingInOpenalex,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FieldDiff {
+    pub field: DiffField,
+    pub status: DiffStatus,
+    pub zotero: Option<String>,
+    pub openalex: Option<String>,
+}
+
+/// Field-by-field comparison of one Zotero item with its OpenAlex work.
+#[derive(Debug, Clone, Serialize)]
+pub struct MetadataDiff {
+    pub work_id: String,
+    pub zotero_key: String,
+    pub zotero_version: u64,
+    pub item_type: String,
+    /// How the item matches the work; `None` when a Zotero key was given and
+    /// the records no longer look like the same publication.
+    pub matched_by: Option<ZoteroMatchMethod>,
+    pub fields: Vec<FieldDiff>,
+    /// Fields whose status is not `same`.
+    pub discrepancies: usize,
+    /// Zotero item fields to PATCH to adopt the OpenAlex values, or `None`
+    /// when there is nothing to fix.
+    pub patch: Option<serde_json::Value>,
+    /// Whether `patch` was written to the library.
+    pub applied: bool,
+}
+
+/// Lowercase alphanumeric words, so case and punctuation never count as drift.
+fn normalize_text(s: &str) -> String {
+    s.split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .map(|t| t.to_lowercase())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn normalize_doi(doi: &str) -> String {
+    crate::selection::strip_doi_prefix(doi.trim()).to_lowercase()
+}
+
+/// Non-empty, trimmed.
+fn present(s: Option<&str>) -> Option<&str> {
+    s.map(str::trim).filter(|s| !s.is_empty())
+}
+
+fn compare(
+    field: DiffField,
+    zotero: Option<String>,
+    openalex: Option<String>,
+    same: impl Fn(&str, &str) -> bool,
+) -> FieldDiff {
+    let status = match (&zotero, &openalex) {
+        (Some(z), Some(o)) if same(z, o) => DiffStatus::Same,
+        (Some(_), Some(_)) => DiffStatus::Differs,
+        (None, Some(_)) => DiffStatus::MissingInZotero,
+        (Some(_), None) => DiffStatus::MissingInOpenalex,
+        (None, None) => DiffStatus::Same,
+    };
+    FieldDiff { field, status, zotero, openalex }
+}
+
+/// The item's DOI: its `DOI` field, or a `DOI: …` line in `extra` for item
+/// types without one.
+fn zotero_doi(item: &Item) -> Option<String> {
+    if let Some(doi) = present(item.data.doi.as_deref()) {
+        return Some(doi.to_string());
+    }
+    item.data.extra.as_deref()?.lines().find_map(|line| {
+        let (name, value) = line.split_once(':')?;
+        name.trim().eq_ignore_ascii_case("doi").then(|| value.trim().to_string())
+    })
+}
+
+fn zotero_venue(item: &Item) -> Option<String> {
+    let field = zotero_container_field(&item.data.item_type)?;
+    let value = if field == "publicationTitle" {
+        item.data.publication_title.as_deref()
+    } else {
+        item.data.extra_fields.get(field).and_then(|v| v.as_str())
+    };
+    present(value).map(str::to_string)
+}
+
+/// Author family names, in order. Zotero editors and other non-author
+/// creators are ignored unless the item has no authors at all.
+fn zotero_family_names(item: &Item) -> Vec<String> {
+    let creators = &item.data.creators;
+    let has_authors = creators.iter().any(|c| c.creator_type == "author");
+    creators
+        .iter()
+        .filter(|c| !has_authors || c.creator_type == "author")
+        .filter_map(|c| {
+            present(c.last_name.as_deref())
+                .or_else(|| present(c.name.as_deref())?.split_whitespace().last())
+                .map(str::to_string)
+        })
+        .collect()
+}
+
+fn openalex_author_names(work: &Work) -> Vec<&str> {
+    work.authorships
+        .as_deref()
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|a| {
+            a.author
+                .as_ref()
+                .and_then(|au| au.display_name.as_deref())
+                .or(a.raw_author_name.as_deref())
+        })
+        .collect()
+}
+
+fn family_name(name: &str) -> &str {
+    match name.split_once(',') {
+        Some((last, _)) => last.trim(),
+        None => name.split_whitespace().last().unwrap_or(name),
+    }
+}
+
+fn join_names(names: Vec<String>) -> Option<String> {
+    (!names.is_empty()).then(|| names.join(", "))
+}
+
+/// Compare `item` with `work` on title, year, DOI, venue, and authors.
+///
+/// Venue is skipped for item types without a container field (books,
+/// reports, theses). Authors are compared by family name, in order.
+pub fn diff_item(work: &Work, item: &Item) -> Vec<FieldDiff> {
+    let text_eq = |a: &str, b: &str| normalize_text(a) == normalize_text(b);
+    let mut fields = vec![
+        compare(
+            DiffField::Title,
+            present(item.data.title.as_deref()).map(str::to_string),
+            present(work.display_name.as_deref().or(work.title.as_deref())).map(str::to_string),
+            text_eq,
+        ),
+        compare(
+            DiffField::Year,
+            zotero_item_year(item).map(|y| y.to_string()),
+            work.publication_year.map(|y| y.to_string()),
+            |a, b| a == b,
+        ),
+        compare(
+            DiffField::Doi,
+            zotero_doi(item),
+            present(work.doi.as_deref()).map(|d| crate::selection::strip_doi_prefix(d).to_string()),
+            |a, b| normalize_doi(a) == normalize_doi(b),
+        ),
+    ];
+    if zotero_container_field(&item.data.item_type).is_some() {
+        let venue = work
+            .primary_location
+            .as_ref()
+            .and_then(|l| l.source.as_ref())
+            .and_then(|s| present(s.display_name.as_deref()))
+            .map(str::to_string);
+        fields.push(compare(DiffField::Venue, zotero_venue(item), venue, text_eq));
+    }
+    let oa_families: Vec<String> = openalex_author_names(work)
+        .into_iter()
+        .map(|n| family_name(n).to_string())
+        .collect();
+    fields.push(compare(
+        DiffField::Authors,
+        join_names(zotero_family_names(item)),
+        join_names(oa_families),
+        text_eq,
+    ));
+    fields
+}
+
+/// Zotero fields that adopt the OpenAlex values for every field that
+/// differs or is missing in Zotero. `None` when nothing needs fixing.
+///
+/// Authors are replaced wholesale from OpenAlex; the item's editors and
+/// other non-author creators are kept. A DOI goes in `extra` for item types
+/// without a `DOI` field.
+pub fn patch_payload(work: &Work, item: &Item, fields: &[FieldDiff]) -> Option<serde_json::Value> {
+    let mut patch = serde_json::Map::new();
+    let item_type = item.data.item_type.as_str();
+    for diff in fields {
+        if !matches!(diff.status, DiffStatus::Differs | DiffStatus::MissingInZotero) {
+            continue;
+        }
+        let Some(value) = diff.openalex.clone() else { continue };
+        match diff.field {
+            DiffField::Title => {
+                patch.insert("title".into(), value.into());
+            }
+            DiffField::Year => {
+                let date = work.publication_date.clone().unwrap_or(value);
+                patch.insert("date".into(), date.into());
+            }
+            DiffField::Doi if zotero_has_doi_field(item_type) => {
+                patch.insert("DOI".into(), value.into());
+            }
+            DiffField::Doi => {
+                let mut lines: Vec<String> = item
+                    .data
+                    .extra
+                    .as_deref()
+                    .unwrap_or_default()
+                    .lines()
+                    .filter(|l| {
+                        !l.split_once(':')
+                            .is_some_and(|(name, _)| name.trim().eq_ignore_ascii_case("doi"))
+                    })
+                    .map(str::to_string)
+                    .collect();
+                lines.push(format!("DOI: {value}"));
+                patch.insert("extra".into(), lines.join("\n").into());
+            }
+            DiffField::Venue => {
+                if let Some(field) = zotero_container_field(item_type) {
+                    patch.insert(field.into(), value.into());
+                }
+            }
+            DiffField::Authors => {
+                let mut creators: Vec<serde_json::Value> = openalex_author_names(work)
+                    .into_iter()
+                    .map(zotero_creator)
+                    .collect();
+                creators.extend(
+                    item.data
+                        .creators
+                        .iter()
+                        .filter(|c| c.creator_type != "author")
+                        .filter_map(|c| serde_json::to_value(c).ok())
+                        .map(|mut v| {
+                            if let Some(obj) = v.as_object_mut() {
+                                obj.retain(|_, f| !f.is_null());
+                            }
+                            v
+                        }),
+                );
+                patch.insert("creators".into(), creators.into());
+            }
+        }
+    }
+    (!patch.is_empty()).then(|| patch.into())
+}
+
+/// Compare a library item with its OpenAlex record, optionally applying the
+/// fix.
+///
+/// `id` is either a Zotero item key (the work is then looked up by the
+/// item's DOI, or by title) or anything [`crate::api::work_get`] accepts (the
+/// item is then found with [`crate::text::find_work_in_zotero`]). With
+/// `apply`, the PATCH payload is written against the fetched item version;
+/// a concurrent edit is reported as [`ZoteroWriteError::VersionConflict`].
+pub async fn work_metadata_diff(
+    openalex: &OpenAlexClient,
+    zotero: &ZoteroClient,
+    id: &str,
+    apply: bool,
+) -> Result<MetadataDiff, ZoteroWriteError> {
+    let params = GetParams::default();
+    let (work, item) = if looks_like_zotero_key(id) {
+        let item = zotero.get_item(id).await?;
+        let lookup = zotero_doi(&item)
+            .map(|d| normalize_doi(&d))
+            .or_else(|| present(item.data.title.as_deref()).map(str::to_string))
+            .ok_or_else(|| {
+                ZoteroWriteError::Invalid(format!("item {id} has neither a DOI nor a title"))
+            })?;
+        (crate::api::work_get(openalex, &lookup, &params).await?, item)
+    } else {
+        let work = crate::api::work_get(openalex, id, &params).await?;
+        let info = crate::text::find_work_in_zotero(zotero, &work)
+            .await?
+            .ok_or_else(|| {
+                ZoteroWriteError::Invalid(format!("{} is not in the Zotero library", work.id))
+            })?;
+        (work, zotero.get_item(&info.key).await?)
+    };
+
+    let fields = diff_item(&work, &item);
+    let patch = patch_payload(&work, &item, &fields);
+    let mut applied = false;
+    if apply && let Some(patch) = &patch {
+        match zotero.for_writes().patch_item(&item.key, item.version, patch.clone()).await {
+            Err(ZoteroError::Api { status: 412, .. }) => {
+                return Err(ZoteroWriteError::VersionConflict {
+                    key: item.key.clone(),
+                    version: item.version,
+                });
+            }
+            r => r?,
+        }
+        applied = true;
+    }
+
+    Ok(MetadataDiff {
+        work_id: work.id.clone(),
+        zotero_key: item.key.clone(),
+        zotero_version: item.version,
+        item_type: item.data.item_type.clone(),
+        matched_by: zotero_match_confidence(&work, &item).map(|(m, _)| m),
+        discrepancies: fields.iter().filter(|f| f.status != DiffStatus::Same).count(),
+        fields,
+        patch,
+        applied,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn work() -> Work {
+        serde_json::from_value(serde_json::json!({
+            "id": "https://openalex.org/W1",
+            "display_name": "Attention Is All You Need",
+            "doi": "https://doi.org/10.5555/ATTN",
+            "publication_year": 2017,
+            "publication_date": "2017-06-12",
+            "primary_location": {"source": {"id": "https://openalex.org/S1", "display_name": "NeurIPS"}},
+            "authorships": [
+                {"author": {"id": "https://openalex.org/A1", "display_name": "Ashish Vaswani"}},
+                {"author": {"id": "https://openalex.org/A2", "display_name": "Noam Shazeer"}}
+            ]
+        }))
+        .unwrap()
+    }
+
+    fn item(data: serde_json::Value) -> Item {
+        let mut data = data;
+        let obj = data.as_object_mut().unwrap();
+        obj.insert("key".into(), "ABCD1234".into());
+        obj.insert("version".into(), 7.into());
+        serde_json::from_value(serde_json::json!({
+            "key": "ABCD1234",
+            "version": 7,
+            "library": {"type": "user", "id": 1, "name": "me", "links": {}},
+            "links": {},
+            "meta": {},
+            "data": data,
+        }))
+        .unwrap()
+    }
+
+    fn status(fields: &[FieldDiff], field: DiffField) -> Option<DiffStatus> {
+        fields.iter().find(|f| f.field == field).map(|f| f.status)
+    }
+
+    #[test]
+    fn test_diff_ignores_case_punctuation_and_doi_prefix() {
+        let it = item(serde_json::json!({
+            "itemType": "conferencePaper",
+            "title": "Attention is all you need.",
+            "date": "2017",
+            "DOI": "10.5555/attn",
+            "proceedingsTitle": "neurips",
+            "creators": [
+                {"creatorType": "author", "firstName": "Ashish", "lastName": "Vaswani"},
+                {"creatorType": "author", "firstName": "Noam", "lastName": "Shazeer"},
+                {"creatorType": "editor", "firstName": "Ed", "lastName": "Itor"}
+            ]
+        }));
+        let fields = diff_item(&work(), &it);
+        assert!(fields.iter().all(|f| f.status == DiffStatus::Same), "{fields:?}");
+        assert!(patch_payload(&work(), &it, &fields).is_none());
+    }
+
+    #[test]
+    fn test_diff_reports_drift_and_builds_patch() {
+        let it = item(serde_json::json!({
+            "itemType": "report",
+            "title": "Attention is all you need (preprint)",
+            "date": "2016",
+            "extra": "Citation Key: vaswani\nDOI: 10.1/old",
+            "creators": [
+                {"creatorType": "author", "firstName": "A.", "lastName": "Vaswani"},
+                {"creatorType": "editor", "name": "Some Editor"}
+            ]
+        }));
+        let fields = diff_item(&work(), &it);
+        assert_eq!(status(&fields, DiffField::Title), Some(DiffStatus::Differs));
+        assert_eq!(status(&fields, DiffField::Year), Some(DiffStatus::Differs));
+        assert_eq!(status(&fields, DiffField::Doi), Some(DiffStatus::Differs));
+        assert_eq!(status(&fields, DiffField::Venue), None);
+        assert_eq!(status(&fields, DiffField::Authors), Some(DiffStatus::Differs));
+
+        let patch = patch_payload(&work(), &it, &fields).unwrap();
+        assert_eq!(patch["title"], "Attention Is All You Need");
+        assert_eq!(patch["date"], "2017-06-12");
+        assert_eq!(patch["extra"], "Citation Key: vaswani\nDOI: 10.5555/ATTN");
+        let creators = patch["creators"].as_array().unwrap();
+        assert_eq!(creators.len(), 3);
+        assert_eq!(creators[1]["lastName"], "Shazeer");
+        assert_eq!(creators[2], serde_json::json!({"creatorType": "editor", "name": "Some Editor"}));
+    }
+
+    #[test]
+    fn test_missing_in_openalex_is_not_patched() {
+        let mut w = work();
+        w.doi = None;
+        let it = item(serde_json::json!({
+            "itemType": "journalArticle",
+            "title": "Attention Is All You Need",
+            "date": "2017",
+            "DOI": "10.5555/attn",
+            "creators": []
+        }));
+        let fields = diff_item(&w, &it);
+        assert_eq!(status(&fields, DiffField::Doi), Some(DiffStatus::MissingInOpenalex));
+        assert_eq!(status(&fields, DiffField::Venue), Some(DiffStatus::MissingInZotero));
+        let patch = patch_payload(&w, &it, &fields).unwrap();
+        assert!(patch.get("DOI").is_none());
+        assert_eq!(patch["publicationTitle"], "NeurIPS");
+        assert_eq!(patch["creators"].as_array().unwrap().len(), 2);
+    }
+}
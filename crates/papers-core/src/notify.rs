@@ -0,0 +1,305 @@
+//! Webhook and email delivery for new-result notifications.
+//!
+//! Channels are configured under `webhooks` and `email` in `config.json` (a
+//! tenant's own, inside a [`crate::tenant::scope`]):
+//!
+//! ```json
+//! { "webhooks": [
+//!     { "url": "https://example.com/hook" },
+//!     { "url": "https://hooks.slack.com/services/…", "kind": "slack" }
+//!   ],
+//!   "email": {
+//!     "smtp_url": "smtps://papers%40example.com@mail.example.com",
+//!     "from": "Papers <papers@example.com>",
+//!     "to": ["me@example.com"]
+//! } }
+//! ```
+//!
+//! Generic webhooks receive the [`Notification`] as JSON; Slack webhooks receive
+//! a `{"text": ...}` message with one linked line per work. Emails list one
+//! work per line, sent through [`crate::smtp`].
+//!
+//! Nothing is sent unless asked for: [`notify_digest`] announces the new works
+//! of a digest (`digest_notify`, `papers digest --notify`, or the scheduled
+//! `digest` task). Works announced once are recorded in [`notified_path`] and
+//! not announced again.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::PapersConfig;
+use crate::digest::{DIGEST_MAX_DAYS, Digest, DigestWork, window_start};
+use crate::smtp::{Email, SmtpServer};
+
+/// How long one webhook may take to answer.
+pub const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Days a work stays in [`notified_path`]. Twice the longest digest window,
+/// so a work can't come back while it is still being reported.
+pub const NOTIFIED_RETENTION_DAYS: u32 = 2 * DIGEST_MAX_DAYS;
+
+#[derive(Debug, thiserror::Error)]
+pub enum NotifyError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+/// Payload format of a webhook.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookKind {
+    /// POST the [`Notification`] as JSON.
+    #[default]
+    Generic,
+    /// POST a Slack-compatible `{"text": ...}` message.
+    Slack,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Webhook {
+    pub url: String,
+    #[serde(default)]
+    pub kind: WebhookKind,
+}
+
+/// Email delivery settings.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EmailConfig {
+    /// `smtps://user@host[:port]` or `smtp://host[:port]`, see [`crate::smtp`].
+    pub smtp_url: String,
+    /// Sender, `addr@example.com` or `Name <addr@example.com>`.
+    pub from: String,
+    pub to: Vec<String>,
+}
+
+/// Where notifications go.
+#[derive(Debug, Clone, Default)]
+pub struct Channels {
+    pub webhooks: Vec<Webhook>,
+    pub email: Option<EmailConfig>,
+}
+
+impl Channels {
+    pub fn is_empty(&self) -> bool {
+        self.webhooks.is_empty() && self.email.is_none()
+    }
+}
+
+/// A batch of new results to announce.
+#[derive(Debug, Clone, Serialize)]
+pub struct Notification {
+    /// What produced the results, e.g. a saved query name.
+    pub title: String,
+    pub works: Vec<NotifiedWork>,
+}
+
+impl Notification {
+    /// The new works of `digest`: watch hits, then works newly citing tracked
+    /// papers, each listed once. Zotero additions are left out, since the user
+    /// added them.
+    pub fn from_digest(digest: &Digest) -> Self {
+        let mut seen = std::collections::HashSet::new();
+        let works = digest
+            .watches
+            .iter()
+            .flat_map(|w| &w.works)
+            .chain(digest.cited.iter().flat_map(|c| &c.citers))
+            .filter(|w| seen.insert(w.id.clone()))
+            .map(NotifiedWork::from_digest_work)
+            .collect();
+        Self { title: format!("Digest since {}", digest.since), works }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NotifiedWork {
+    pub id: String,
+    pub title: Option<String>,
+    pub year: Option<i32>,
+    /// Landing page: the DOI URL when known, else the OpenAlex URL.
+    pub url: Option<String>,
+}
+
+impl NotifiedWork {
+    pub fn from_digest_work(work: &DigestWork) -> Self {
+        let url = match &work.doi {
+            Some(doi) => format!("https://doi.org/{doi}"),
+            None => format!("https://openalex.org/{}", work.id),
+        };
+        Self {
+            id: work.id.clone(),
+            title: work.title.clone(),
+            year: work.publication_date.as_deref().and_then(|d| d.get(..4)?.parse().ok()),
+            url: Some(url),
+        }
+    }
+}
+
+/// Outcome of delivering to one webhook, or by email.
+#[derive(Debug, Clone, Serialize)]
+pub struct Delivery {
+    /// The webhook URL, or `mailto:` and the email recipients.
+    pub url: String,
+    pub ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Channels from `config.json`; empty when none are configured or the config
+/// can't be read.
+pub fn configured_channels() -> Channels {
+    match PapersConfig::load() {
+        Ok(config) => Channels { webhooks: config.webhooks, email: config.email },
+        Err(_) => Channels::default(),
+    }
+}
+
+/// Slack message text: the title, then one `<url|title> (year)` line per work.
+pub fn slack_text(notification: &Notification) -> String {
+    let mut text = format!(
+        "*{}* — {} new work{}",
+        notification.title,
+        notification.works.len(),
+        if notification.works.len() == 1 { "" } else { "s" }
+    );
+    for work in &notification.works {
+        let title = work.title.as_deref().unwrap_or(&work.id);
+        let title = title.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;");
+        text.push_str("\n• ");
+        match &work.url {
+            Some(url) => text.push_str(&format!("<{url}|{title}>")),
+            None => text.push_str(&title),
+        }
+        if let Some(year) = work.year {
+            text.push_str(&format!(" ({year})"));
+        }
+    }
+    text
+}
+
+/// Email subject and body: one `title (year)` line per work, with its URL
+/// on the next line.
+pub fn email_text(notification: &Notification) -> (String, String) {
+    let count = notification.works.len();
+    let subject = format!("{} — {count} new work{}", notification.title, if count == 1 { "" } else { "s" });
+    let mut body = String::new();
+    for work in &notification.works {
+        body.push_str(&format!("- {}", work.title.as_deref().unwrap_or(&work.id)));
+        if let Some(year) = work.year {
+            body.push_str(&format!(" ({year})"));
+        }
+        body.push('\n');
+        if let Some(url) = &work.url {
+            body.push_str(&format!("  {url}\n"));
+        }
+    }
+    (subject, body)
+}
+
+/// Email `notification` as configured in `email`.
+pub async fn send_email(notification: &Notification, email: &EmailConfig) -> Delivery {
+    let url = format!("mailto:{}", email.to.join(","));
+    let (subject, body) = email_text(notification);
+    let message = Email { from: email.from.clone(), to: email.to.clone(), subject, body };
+    let result = match SmtpServer::parse(&email.smtp_url) {
+        Ok(server) => crate::smtp::send_email(&server, &message).await,
+        Err(e) => Err(e),
+    };
+    let error = result.err().map(|e| e.to_string());
+    Delivery { url, ok: error.is_none(), error }
+}
+
+/// POST `notification` to every webhook. Failures are reported per webhook
+/// rather than aborting the remaining deliveries.
+pub async fn send(notification: &Notification, webhooks: &[Webhook]) -> Vec<Delivery> {
+    let http = reqwest::Client::builder().timeout(WEBHOOK_TIMEOUT).build().unwrap_or_default();
+    let mut deliveries = Vec::with_capacity(webhooks.len());
+    for hook in webhooks {
+        let request = match hook.kind {
+            WebhookKind::Generic => http.post(&hook.url).json(notification),
+            WebhookKind::Slack => {
+                http.post(&hook.url).json(&serde_json::json!({ "text": slack_text(notification) }))
+            }
+        };
+        let error = match request.send().await {
+            Ok(resp) if resp.status().is_success() => None,
+            Ok(resp) => Some(format!("HTTP {}", resp.status())),
+            Err(e) => Some(e.to_string()),
+        };
+        deliveries.push(Delivery { url: hook.url.clone(), ok: error.is_none(), error });
+    }
+    deliveries
+}
+
+/// Outcome of [`notify_digest`].
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct NotifyReport {
+    /// IDs of the works announced by this call.
+    pub notified: Vec<String>,
+    /// Works left out because an earlier call announced them.
+    pub already_notified: usize,
+    pub deliveries: Vec<Delivery>,
+}
+
+/// Works already announced, by ID, with the day they were.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Notified {
+    #[serde(default)]
+    works: BTreeMap<String, String>,
+}
+
+/// `notified.json` in the [`crate::tenant::data_dir`].
+pub fn notified_path() -> PathBuf {
+    crate::tenant::data_dir().join("notified.json")
+}
+
+fn load_notified() -> Result<Notified, NotifyError> {
+    let path = notified_path();
+    if !path.exists() {
+        return Ok(Notified::default());
+    }
+    Ok(serde_json::from_slice(&std::fs::read(path)?)?)
+}
+
+fn save_notified(notified: &Notified) -> Result<(), NotifyError> {
+    let path = notified_path();
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(path, serde_json::to_vec_pretty(notified)?)?;
+    Ok(())
+}
+
+/// Announce the new works of `digest` on every channel, leaving out works
+/// announced before. Works count as announced once any delivery succeeds, so
+/// a run where every channel fails is retried next time. Nothing is sent
+/// without channels or new works.
+pub async fn notify_digest(digest: &Digest, channels: &Channels) -> Result<NotifyReport, NotifyError> {
+    let mut notification = Notification::from_digest(digest);
+    let mut notified = load_notified()?;
+    let found = notification.works.len();
+    notification.works.retain(|w| !notified.works.contains_key(&w.id));
+    let mut report = NotifyReport { already_notified: found - notification.works.len(), ..Default::default() };
+    if channels.is_empty() || notification.works.is_empty() {
+        return Ok(report);
+    }
+
+    report.deliveries = send(&notification, &channels.webhooks).await;
+    if let Some(email) = &channels.email {
+        report.deliveries.push(send_email(&notification, email).await);
+    }
+    if report.deliveries.iter().any(|d| d.ok) {
+        let today = crate::text::iso_now()[..10].to_string();
+        let cutoff = window_start(NOTIFIED_RETENTION_DAYS);
+        notified.works.retain(|_, day| *day >= cutoff);
+        report.notified = notification.works.iter().map(|w| w.id.clone()).collect();
+        notified.works.extend(report.notified.iter().map(|id| (id.clone(), today.clone())));
+        save_notified(&notified)?;
+    }
+    Ok(report)
+}
@@ -0,0 +1,321 @@
+use papers_openalex::Work;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// How much a quality issue matters for downstream use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QualitySeverity {
+    /// Likely to produce wrong results in analyses or exports.
+    Warning,
+    /// Worth knowing, but the record is usable as-is.
+    Info,
+}
+
+/// The kind of problem detected on a work record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QualityCheck {
+    MissingAbstract,
+    NoOaLocation,
+    AmbiguousAuthorship,
+    DoiCasing,
+    MissingReferences,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct QualityIssue {
+    pub check: QualityCheck,
+    pub severity: QualitySeverity,
+    pub message: String,
+    pub suggestion: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WorkQualityReport {
+    pub id: String,
+    pub title: Option<String>,
+    pub doi: Option<String>,
+    /// True when no `warning`-level issues were found.
+    pub ok: bool,
+    pub issues: Vec<QualityIssue>,
+}
+
+/// Run all metadata checks against a full `Work` record.
+///
+/// The record should be fetched without `select`, otherwise missing fields are
+/// reported as problems.
+pub fn check_work(work: &Work) -> WorkQualityReport {
+    let mut issues = Vec::new();
+    check_abstract(work, &mut issues);
+    check_oa_location(work, &mut issues);
+    check_authorships(work, &mut issues);
+    check_doi_casing(work, &mut issues);
+    check_references(work, &mut issues);
+
+    WorkQualityReport {
+        id: work.id.clone(),
+        title: work.display_name.clone().or_else(|| work.title.clone()),
+        doi: work.doi.clone(),
+        ok: !issues.iter().any(|i| i.severity == QualitySeverity::Warning),
+        issues,
+    }
+}
+
+fn issue(
+    check: QualityCheck,
+    severity: QualitySeverity,
+    message: impl Into<String>,
+    suggestion: impl Into<String>,
+) -> QualityIssue {
+    QualityIssue {
+        check,
+        severity,
+        message: message.into(),
+        suggestion: suggestion.into(),
+    }
+}
+
+fn check_abstract(work: &Work, issues: &mut Vec<QualityIssue>) {
+    let missing = work
+        .abstract_text
+        .as_deref()
+        .is_none_or(|a| a.trim().is_empty());
+    if missing {
+        issues.push(issue(
+            QualityCheck::MissingAbstract,
+            QualitySeverity::Warning,
+            "No abstract is available from OpenAlex.",
+            "Extract the abstract from the full text (work_text) or copy it from the publisher landing page.",
+        ));
+    }
+}
+
+fn check_oa_location(work: &Work, issues: &mut Vec<QualityIssue>) {
+    let is_oa = work.open_access.as_ref().and_then(|oa| oa.is_oa) == Some(true);
+    let has_location = work.best_oa_location.is_some()
+        || work.open_access.as_ref().is_some_and(|oa| oa.oa_url.is_some())
+        || work
+            .locations
+            .as_ref()
+            .is_some_and(|ls| ls.iter().any(|l| l.is_oa == Some(true)));
+    if !is_oa && !has_location {
+        issues.push(issue(
+            QualityCheck::NoOaLocation,
+            QualitySeverity::Info,
+            "No open-access location is known for this work.",
+            "Check preprint servers or author pages, or attach a PDF in Zotero before running full-text analyses.",
+        ));
+    } else if is_oa && !has_location {
+        issues.push(issue(
+            QualityCheck::NoOaLocation,
+            QualitySeverity::Warning,
+            "Marked open access but no open-access location is listed.",
+            "Verify the oa_url manually; the OA flag may be stale.",
+        ));
+    }
+}
+
+fn check_authorships(work: &Work, issues: &mut Vec<QualityIssue>) {
+    let authorships = work.authorships.as_deref().unwrap_or_default();
+    if authorships.is_empty() {
+        issues.push(issue(
+            QualityCheck::AmbiguousAuthorship,
+            QualitySeverity::Warning,
+            "The record has no authorships.",
+            "Confirm the author list against the publisher page before citing or exporting.",
+        ));
+        return;
+    }
+
+    let mut unresolved = Vec::new();
+    let mut seen: HashMap<&str, usize> = HashMap::new();
+    for (i, a) in authorships.iter().enumerate() {
+        let name = a
+            .author
+            .as_ref()
+            .and_then(|au| au.display_name.as_deref())
+            .or(a.raw_author_name.as_deref())
+            .unwrap_or("(unnamed)");
+        match a.author.as_ref().and_then(|au| au.id.as_deref()) {
+            Some(id) => *seen.entry(id).or_default() += 1,
+            None => unresolved.push(format!("#{} {name}", i + 1)),
+        }
+    }
+
+    if !unresolved.is_empty() {
+        issues.push(issue(
+            QualityCheck::AmbiguousAuthorship,
+            QualitySeverity::Warning,
+            format!(
+                "{} authorship(s) are not linked to an OpenAlex author: {}.",
+                unresolved.len(),
+                unresolved.join(", ")
+            ),
+            "Disambiguate these authors by ORCID or affiliation before using author-level metrics.",
+        ));
+    }
+
+    let mut duplicated: Vec<&str> = seen
+        .into_iter()
+        .filter(|(_, n)| *n > 1)
+        .map(|(id, _)| id.strip_prefix("https://openalex.org/").unwrap_or(id))
+        .collect();
+    if !duplicated.is_empty() {
+        duplicated.sort_unstable();
+        issues.push(issue(
+            QualityCheck::AmbiguousAuthorship,
+            QualitySeverity::Warning,
+            format!(
+                "The same OpenAlex author appears in several authorships: {}.",
+                duplicated.join(", ")
+            ),
+            "Two distinct people were likely merged into one author profile; check the raw author names.",
+        ));
+    }
+}
+
+fn check_doi_casing(work: &Work, issues: &mut Vec<QualityIssue>) {
+    let Some(doi) = work.doi.as_deref() else {
+        return;
+    };
+    let ids_doi = work.ids.as_ref().and_then(|ids| ids.doi.as_deref());
+    if let Some(other) = ids_doi
+        && other != doi
+        && other.eq_ignore_ascii_case(doi)
+    {
+        issues.push(issue(
+            QualityCheck::DoiCasing,
+            QualitySeverity::Info,
+            format!("DOI casing differs between fields: `{doi}` vs `{other}`."),
+            "DOIs are case-insensitive; compare them lowercased when matching against Zotero or exports.",
+        ));
+    } else if doi != doi.to_lowercase() {
+        issues.push(issue(
+            QualityCheck::DoiCasing,
+            QualitySeverity::Info,
+            format!("DOI `{doi}` is not lowercase."),
+            "DOIs are case-insensitive; lowercase it before deduplicating or matching.",
+        ));
+    }
+}
+
+fn check_references(work: &Work, issues: &mut Vec<QualityIssue>) {
+    let count = work
+        .referenced_works_count
+        .or_else(|| work.referenced_works.as_ref().map(|r| r.len() as i64))
+        .unwrap_or(0);
+    if count == 0 {
+        issues.push(issue(
+            QualityCheck::MissingReferences,
+            QualitySeverity::Warning,
+            "No references are recorded for this work.",
+            "Citation-graph tools (work_references) will return nothing; parse references from the full text instead.",
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn work(value: serde_json::Value) -> Work {
+        let mut base = serde_json::json!({
+            "id": "https://openalex.org/W1",
+            "doi": "https://doi.org/10.1234/abc",
+            "display_name": "A Paper",
+            "abstract_inverted_index": {"Hello": [0], "world": [1]},
+            "open_access": {"is_oa": true, "oa_status": "gold", "oa_url": "https://x.org/a.pdf", "any_repository_has_fulltext": false},
+            "best_oa_location": {"is_oa": true, "landing_page_url": null, "pdf_url": "https://x.org/a.pdf", "source": null, "license": null, "version": null},
+            "authorships": [
+                {"author_position": "first", "author": {"id": "https://openalex.org/A1", "display_name": "Alice", "orcid": null}},
+                {"author_position": "last", "author": {"id": "https://openalex.org/A2", "display_name": "Bob", "orcid": null}}
+            ],
+            "referenced_works_count": 12
+        });
+        for (k, v) in value.as_object().unwrap() {
+            base[k] = v.clone();
+        }
+        serde_json::from_value(base).unwrap()
+    }
+
+    fn checks(report: &WorkQualityReport) -> Vec<QualityCheck> {
+        report.issues.iter().map(|i| i.check).collect()
+    }
+
+    #[test]
+    fn clean_record_has_no_issues() {
+        let report = check_work(&work(serde_json::json!({})));
+        assert!(report.ok);
+        assert!(report.issues.is_empty());
+    }
+
+    #[test]
+    fn missing_abstract_and_references() {
+        let report = check_work(&work(serde_json::json!({
+            "abstract_inverted_index": null,
+            "referenced_works_count": 0
+        })));
+        assert!(!report.ok);
+        assert_eq!(
+            checks(&report),
+            vec![QualityCheck::MissingAbstract, QualityCheck::MissingReferences]
+        );
+    }
+
+    #[test]
+    fn closed_access_is_info_only() {
+        let report = check_work(&work(serde_json::json!({
+            "open_access": {"is_oa": false, "oa_status": "closed", "oa_url": null, "any_repository_has_fulltext": false},
+            "best_oa_location": null
+        })));
+        assert!(report.ok);
+        assert_eq!(report.issues.len(), 1);
+        assert_eq!(report.issues[0].check, QualityCheck::NoOaLocation);
+        assert_eq!(report.issues[0].severity, QualitySeverity::Info);
+    }
+
+    #[test]
+    fn oa_flag_without_location_is_warning() {
+        let report = check_work(&work(serde_json::json!({
+            "open_access": {"is_oa": true, "oa_status": "bronze", "oa_url": null, "any_repository_has_fulltext": false},
+            "best_oa_location": null
+        })));
+        assert!(!report.ok);
+        assert_eq!(checks(&report), vec![QualityCheck::NoOaLocation]);
+    }
+
+    #[test]
+    fn unresolved_and_duplicate_authors() {
+        let report = check_work(&work(serde_json::json!({
+            "authorships": [
+                {"author": {"id": "https://openalex.org/A1", "display_name": "Alice", "orcid": null}},
+                {"author": {"id": null, "display_name": "Bob", "orcid": null}},
+                {"author": {"id": "https://openalex.org/A1", "display_name": "Alice", "orcid": null}}
+            ]
+        })));
+        assert_eq!(
+            checks(&report),
+            vec![QualityCheck::AmbiguousAuthorship, QualityCheck::AmbiguousAuthorship]
+        );
+        assert!(report.issues[0].message.contains("#2 Bob"));
+        assert!(report.issues[1].message.contains("A1"));
+    }
+
+    #[test]
+    fn doi_casing_mismatch_between_fields() {
+        let report = check_work(&work(serde_json::json!({
+            "ids": {"openalex": null, "doi": "https://doi.org/10.1234/ABC", "mag": null, "pmid": null, "pmcid": null}
+        })));
+        assert_eq!(checks(&report), vec![QualityCheck::DoiCasing]);
+        assert!(report.ok);
+    }
+
+    #[test]
+    fn uppercase_doi_flagged() {
+        let report = check_work(&work(serde_json::json!({
+            "doi": "https://doi.org/10.1234/ABC"
+        })));
+        assert_eq!(checks(&report), vec![QualityCheck::DoiCasing]);
+    }
+}
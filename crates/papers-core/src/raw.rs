@@ -0,0 +1,276 @@
+//! Raw OpenAlex and Zotero passthroughs for parameters the typed tools don't
+//! cover yet.
+//!
+//! OpenAlex requests are limited to read-only entity endpoints on the
+//! configured base URL and go through the client's API key and cache. Zotero
+//! requests are limited to known library and schema endpoints and are
+//! read-only unless writes are explicitly enabled.
+
+use papers_openalex::{OpenAlexClient, OpenAlexError};
+use papers_zotero::{RawResponse, ZoteroClient, ZoteroError};
+
+/// First path segments accepted by [`openalex_raw`].
+pub const RAW_ALLOWED_ROOTS: &[&str] = &[
+    "works",
+    "authors",
+    "sources",
+    "institutions",
+    "topics",
+    "keywords",
+    "publishers",
+    "funders",
+    "domains",
+    "fields",
+    "subfields",
+    "concepts",
+    "autocomplete",
+    "find",
+];
+
+/// Query keys the client sets itself.
+const RAW_RESERVED_KEYS: &[&str] = &["api_key"];
+
+#[derive(Debug, thiserror::Error)]
+pub enum RawError {
+    #[error("Path \"{path}\" is not allowed: {reason}. Allowed roots: {}", RAW_ALLOWED_ROOTS.join(", "))]
+    Path { path: String, reason: &'static str },
+    #[error("Query parameter \"{0}\" is set by the client and can't be passed")]
+    ReservedKey(String),
+    #[error(transparent)]
+    Api(#[from] OpenAlexError),
+}
+
+/// Validate `path` and return it with a single leading `/` (`works/W1` → `/works/W1`).
+pub fn normalize_raw_path(path: &str) -> Result<String, RawError> {
+    let reject = |reason| Err(RawError::Path { path: path.to_string(), reason });
+    let trimmed = path.trim().trim_start_matches('/');
+    if trimmed.is_empty() {
+        return reject("empty path");
+    }
+    if trimmed.contains(['?', '#']) {
+        return reject("pass query parameters separately, not in the path");
+    }
+    let lower = trimmed.to_lowercase();
+    if lower.contains("..") || lower.contains("%2e") || trimmed.contains('\\') {
+        return reject("path traversal");
+    }
+    if trimmed.chars().any(char::is_whitespace) {
+        return reject("whitespace in path");
+    }
+    let root = trimmed.split('/').next().unwrap_or_default();
+    if !RAW_ALLOWED_ROOTS.contains(&root) {
+        return reject("unknown endpoint");
+    }
+    Ok(format!("/{}", trimmed.trim_end_matches('/')))
+}
+
+/// `GET` an allow-listed OpenAlex endpoint and return the response JSON as-is.
+///
+/// `path` is relative to the API root (`works`, `works/W2741809807`,
+/// `autocomplete/authors`); `query` holds parameters exactly as OpenAlex names
+/// them (`filter`, `per-page`, `group_by`, …).
+pub async fn openalex_raw(
+    client: &OpenAlexClient,
+    path: &str,
+    query: &[(String, String)],
+) -> Result<serde_json::Value, RawError> {
+    let path = normalize_raw_path(path)?;
+    if let Some((key, _)) = query.iter().find(|(k, _)| RAW_RESERVED_KEYS.contains(&k.as_str())) {
+        return Err(RawError::ReservedKey(key.clone()));
+    }
+    let pairs: Vec<(&str, String)> = query.iter().map(|(k, v)| (k.as_str(), v.clone())).collect();
+    Ok(client.get_raw(&path, pairs).await?)
+}
+
+// ── Zotero ────────────────────────────────────────────────────────────────
+
+/// First path segments served under the library prefix (`/users/<id>` or `/groups/<id>`).
+pub const ZOTERO_RAW_LIBRARY_ROOTS: &[&str] = &[
+    "items",
+    "collections",
+    "searches",
+    "tags",
+    "fulltext",
+    "deleted",
+    "settings",
+    "publications",
+];
+
+/// Library-independent schema endpoints, served from the API root.
+pub const ZOTERO_RAW_SCHEMA_ROOTS: &[&str] = &[
+    "itemTypes",
+    "itemFields",
+    "itemTypeFields",
+    "itemTypeCreatorTypes",
+    "creatorFields",
+    "creatorTypes",
+    "schema",
+];
+
+/// Environment variable that enables non-GET methods in [`zotero_raw`] callers.
+pub const ZOTERO_RAW_WRITES_ENV: &str = "PAPERS_ZOTERO_RAW_WRITES";
+
+/// Query keys that would override the client's credentials.
+const ZOTERO_RAW_RESERVED_KEYS: &[&str] = &["key"];
+
+#[derive(Debug, thiserror::Error)]
+pub enum ZoteroRawError {
+    #[error(
+        "Path \"{path}\" is not allowed: {reason}. Allowed roots: {}, {}, groups",
+        ZOTERO_RAW_LIBRARY_ROOTS.join(", "),
+        ZOTERO_RAW_SCHEMA_ROOTS.join(", ")
+    )]
+    Path { path: String, reason: &'static str },
+    #[error("Unsupported method \"{0}\" (use GET, POST, PUT, PATCH, or DELETE)")]
+    Method(String),
+    #[error("{0} requests are disabled; set {ZOTERO_RAW_WRITES_ENV}=1 to allow writes")]
+    WritesDisabled(String),
+    #[error("Query parameter \"{0}\" is set by the client and can't be passed")]
+    ReservedKey(String),
+    #[error(transparent)]
+    Api(#[from] ZoteroError),
+}
+
+/// A raw Zotero request. `path` is relative to the library (`items/top`,
+/// `collections/AB12CDEF/items`) or names a schema endpoint (`itemTypeFields`).
+#[derive(Debug, Clone, Default)]
+pub struct ZoteroRawRequest {
+    /// HTTP method; `GET` when `None`.
+    pub method: Option<String>,
+    pub path: String,
+    pub query: Vec<(String, String)>,
+    /// JSON body for writes.
+    pub body: Option<serde_json::Value>,
+    /// Sent as `If-Unmodified-Since-Version`.
+    pub version: Option<u64>,
+}
+
+/// Whether [`ZOTERO_RAW_WRITES_ENV`] is set to `1` or `true`.
+pub fn zotero_raw_writes_enabled() -> bool {
+    std::env::var(ZOTERO_RAW_WRITES_ENV)
+        .is_ok_and(|v| matches!(v.trim().to_lowercase().as_str(), "1" | "true"))
+}
+
+/// Validate a Zotero `path` and return it absolute from the API root for `client`'s
+/// library (`items/top` → `/users/<id>/items/top`, `itemTypes` → `/itemTypes`).
+pub fn normalize_zotero_raw_path(
+    client: &ZoteroClient,
+    path: &str,
+    write: bool,
+) -> Result<String, ZoteroRawError> {
+    let reject = |reason| Err(ZoteroRawError::Path { path: path.to_string(), reason });
+    let trimmed = path.trim().trim_start_matches('/').trim_end_matches('/');
+    if trimmed.is_empty() {
+        return reject("empty path");
+    }
+    if trimmed.contains(['?', '#']) {
+        return reject("pass query parameters separately, not in the path");
+    }
+    let lower = trimmed.to_lowercase();
+    if lower.contains("..") || lower.contains("%2e") || trimmed.contains('\\') {
+        return reject("path traversal");
+    }
+    if trimmed.chars().any(char::is_whitespace) {
+        return reject("whitespace in path");
+    }
+    let root = trimmed.split('/').next().unwrap_or_default();
+    if ZOTERO_RAW_LIBRARY_ROOTS.contains(&root) {
+        // `items/new` is the item template endpoint, which lives at the API root.
+        if trimmed == "items/new" {
+            return Ok(format!("/{trimmed}"));
+        }
+        return Ok(format!("{}/{trimmed}", client.library_prefix()));
+    }
+    if write {
+        return reject("only library endpoints accept writes");
+    }
+    if ZOTERO_RAW_SCHEMA_ROOTS.contains(&root) {
+        return Ok(format!("/{trimmed}"));
+    }
+    if trimmed == "groups" {
+        return Ok(format!("{}/groups", client.user_prefix()));
+    }
+    reject("unknown endpoint")
+}
+
+/// Send a validated raw request to the Zotero API.
+///
+/// Only `GET` is allowed unless `allow_writes` is true; writes are sent through
+/// [`ZoteroClient::for_writes`] since the local API is read-only.
+pub async fn zotero_raw(
+    client: &ZoteroClient,
+    req: &ZoteroRawRequest,
+    allow_writes: bool,
+) -> Result<RawResponse, ZoteroRawError> {
+    let method_name = req.method.as_deref().unwrap_or("GET").trim().to_uppercase();
+    let method = match method_name.as_str() {
+        "GET" => reqwest::Method::GET,
+        "POST" => reqwest::Method::POST,
+        "PUT" => reqwest::Method::PUT,
+        "PATCH" => reqwest::Method::PATCH,
+        "DELETE" => reqwest::Method::DELETE,
+        _ => return Err(ZoteroRawError::Method(method_name)),
+    };
+    let write = method != reqwest::Method::GET;
+    if write && !allow_writes {
+        return Err(ZoteroRawError::WritesDisabled(method_name));
+    }
+    let path = normalize_zotero_raw_path(client, &req.path, write)?;
+    if let Some((key, _)) =
+        req.query.iter().find(|(k, _)| ZOTERO_RAW_RESERVED_KEYS.contains(&k.as_str()))
+    {
+        return Err(ZoteroRawError::ReservedKey(key.clone()));
+    }
+    let pairs: Vec<(&str, String)> =
+        req.query.iter().map(|(k, v)| (k.as_str(), v.clone())).collect();
+    let client = if write { client.for_writes() } else { client.clone() };
+    Ok(client.raw_request(method, &path, pairs, req.body.as_ref(), req.version).await?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_allowed_paths() {
+        assert_eq!(normalize_raw_path("works").unwrap(), "/works");
+        assert_eq!(normalize_raw_path("/works/W1/").unwrap(), "/works/W1");
+        assert_eq!(normalize_raw_path("autocomplete/authors").unwrap(), "/autocomplete/authors");
+        assert_eq!(
+            normalize_raw_path("works/https://doi.org/10.1234/x").unwrap(),
+            "/works/https://doi.org/10.1234/x"
+        );
+    }
+
+    #[test]
+    fn rejects_unlisted_and_unsafe_paths() {
+        for path in [
+            "",
+            "/",
+            "users",
+            "https://example.com/works",
+            "works/../admin",
+            "works/%2E%2E/x",
+            "works?filter=x",
+            "works/W 1",
+        ] {
+            assert!(normalize_raw_path(path).is_err(), "{path} should be rejected");
+        }
+    }
+
+    #[test]
+    fn zotero_paths_are_scoped_to_the_library() {
+        let user = ZoteroClient::new("42", "k");
+        let group = user.clone().with_library(papers_zotero::LibraryScope::Group(7));
+        let norm = |client: &ZoteroClient, path: &str| normalize_zotero_raw_path(client, path, false);
+        assert_eq!(norm(&user, "items/top").unwrap(), "/users/42/items/top");
+        assert_eq!(norm(&group, "/collections/").unwrap(), "/groups/7/collections");
+        assert_eq!(norm(&group, "itemTypeFields").unwrap(), "/itemTypeFields");
+        assert_eq!(norm(&group, "items/new").unwrap(), "/items/new");
+        assert_eq!(norm(&group, "groups").unwrap(), "/users/42/groups");
+        for path in ["keys/current", "users/1/items", "items/../keys", "items?q=x", ""] {
+            assert!(norm(&user, path).is_err(), "{path} should be rejected");
+        }
+        assert!(normalize_zotero_raw_path(&user, "itemTypes", true).is_err());
+    }
+}
@@ -0,0 +1,212 @@
+//! Per-paper reading status: whether a paper is unread, being read, or read,
+//! an optional 1–5 rating, and the chunk where reading left off.
+//!
+//! All papers share one file, [`status_path`], keyed by [`paper_key`].
+//! Papers are identified as in the DB: by DOI, or by Zotero item key when they
+//! have none. OpenAlex IDs work too, for papers known only from OpenAlex.
+//! A paper with no record is unread.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::selection::{Selection, SelectionEntry, strip_doi_prefix};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ReadingStatusError {
+    #[error("invalid reading status {0:?}: use unread, reading or read")]
+    InvalidStatus(String),
+    #[error("invalid rating {0}: use 1 to 5, or 0 to clear")]
+    InvalidRating(u8),
+    #[error("paper ID must not be empty")]
+    EmptyId,
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReadingStatus {
+    #[default]
+    Unread,
+    Reading,
+    Read,
+}
+
+impl ReadingStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReadingStatus::Unread => "unread",
+            ReadingStatus::Reading => "reading",
+            ReadingStatus::Read => "read",
+        }
+    }
+}
+
+impl std::str::FromStr for ReadingStatus {
+    type Err = ReadingStatusError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "unread" | "new" => Ok(ReadingStatus::Unread),
+            "reading" | "started" => Ok(ReadingStatus::Reading),
+            "read" | "done" => Ok(ReadingStatus::Read),
+            _ => Err(ReadingStatusError::InvalidStatus(s.to_string())),
+        }
+    }
+}
+
+/// What is recorded for one paper.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PaperStatus {
+    pub status: ReadingStatus,
+    /// 1 (poor) to 5 (excellent).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rating: Option<u8>,
+    /// DB chunk ID of the chunk read most recently.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_chunk_id: Option<String>,
+    /// ISO 8601 UTC time of the last change; empty for papers with no record.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub updated_at: String,
+}
+
+/// Changes to a paper's record; `None` fields are left as they are.
+#[derive(Debug, Clone, Default)]
+pub struct StatusUpdate {
+    pub status: Option<ReadingStatus>,
+    /// 1 to 5; 0 clears the rating.
+    pub rating: Option<u8>,
+    pub last_chunk_id: Option<String>,
+}
+
+/// The whole store: [`PaperStatus`] by [`paper_key`].
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ReadingStatuses {
+    #[serde(default)]
+    pub papers: BTreeMap<String, PaperStatus>,
+}
+
+impl ReadingStatuses {
+    /// The record for `paper_id`, if any.
+    pub fn get(&self, paper_id: &str) -> Option<&PaperStatus> {
+        self.papers.get(&paper_key(paper_id))
+    }
+
+    /// The record for a selection entry, looked up by DOI, then Zotero key,
+    /// then OpenAlex ID.
+    pub fn for_entry(&self, entry: &SelectionEntry) -> Option<&PaperStatus> {
+        [entry.doi.as_deref(), entry.zotero_key.as_deref(), entry.openalex_id.as_deref()]
+            .into_iter()
+            .flatten()
+            .filter(|id| !id.trim().is_empty())
+            .find_map(|id| self.get(id))
+    }
+
+    /// Recorded statuses of the entries of `sel`, by 1-based entry index as in
+    /// `selection status`.
+    pub fn for_selection(&self, sel: &Selection) -> BTreeMap<usize, PaperStatus> {
+        sel.entries
+            .iter()
+            .enumerate()
+            .filter_map(|(i, entry)| self.for_entry(entry).map(|s| (i + 1, s.clone())))
+            .collect()
+    }
+}
+
+/// Key a paper ID is stored under: DOIs without their `https://doi.org/` or
+/// `doi:` prefix and lowercased, OpenAlex IDs without their URL prefix, and
+/// anything else (Zotero keys) trimmed.
+pub fn paper_key(paper_id: &str) -> String {
+    let id = paper_id.trim();
+    let doi = strip_doi_prefix(id);
+    if doi.starts_with("10.") {
+        return doi.to_lowercase();
+    }
+    id.strip_prefix("https://openalex.org/").unwrap_or(id).to_string()
+}
+
+/// `reading_status.json` in the [`crate::tenant::data_dir`].
+pub fn status_path() -> PathBuf {
+    crate::tenant::data_dir().join("reading_status.json")
+}
+
+/// Every recorded status; empty if nothing was recorded yet.
+pub fn load_statuses() -> Result<ReadingStatuses, ReadingStatusError> {
+    let path = status_path();
+    if !path.exists() {
+        return Ok(ReadingStatuses::default());
+    }
+    Ok(serde_json::from_slice(&std::fs::read(path)?)?)
+}
+
+fn save_statuses(statuses: &ReadingStatuses) -> Result<(), ReadingStatusError> {
+    let path = status_path();
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(path, serde_json::to_vec_pretty(statuses)?)?;
+    Ok(())
+}
+
+/// The status of `paper_id`: its record, or unread with nothing else set.
+pub fn get_status(paper_id: &str) -> Result<PaperStatus, ReadingStatusError> {
+    Ok(load_statuses()?.get(paper_id).cloned().unwrap_or_default())
+}
+
+/// Apply `update` to the record of `paper_id`, creating it if needed, and
+/// return the new record.
+pub fn set_status(paper_id: &str, update: StatusUpdate) -> Result<PaperStatus, ReadingStatusError> {
+    if paper_id.trim().is_empty() {
+        return Err(ReadingStatusError::EmptyId);
+    }
+    if let Some(rating) = update.rating.filter(|r| *r > 5) {
+        return Err(ReadingStatusError::InvalidRating(rating));
+    }
+    let mut statuses = load_statuses()?;
+    let record = statuses.papers.entry(paper_key(paper_id)).or_default();
+    if let Some(status) = update.status {
+        record.status = status;
+    }
+    if let Some(rating) = update.rating {
+        record.rating = (rating > 0).then_some(rating);
+    }
+    if let Some(chunk_id) = update.last_chunk_id {
+        record.last_chunk_id = Some(chunk_id);
+    }
+    record.updated_at = crate::text::iso_now();
+    let record = record.clone();
+    save_statuses(&statuses)?;
+    Ok(record)
+}
+
+/// Record that `chunk_id` of `paper_id` was read: the chunk becomes the last
+/// one read, and an unread paper becomes `reading`. Read papers stay read.
+pub fn record_chunk_read(paper_id: &str, chunk_id: &str) -> Result<PaperStatus, ReadingStatusError> {
+    let current = get_status(paper_id)?.status;
+    let status = (current == ReadingStatus::Unread).then_some(ReadingStatus::Reading);
+    set_status(paper_id, StatusUpdate { status, rating: None, last_chunk_id: Some(chunk_id.to_string()) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paper_key() {
+        assert_eq!(paper_key(" https://doi.org/10.1145/ABC "), "10.1145/abc");
+        assert_eq!(paper_key("doi:10.1/X"), "10.1/x");
+        assert_eq!(paper_key("https://openalex.org/W123"), "W123");
+        assert_eq!(paper_key("ABCD1234"), "ABCD1234");
+    }
+
+    #[test]
+    fn test_parse_status() {
+        assert_eq!("Read".parse::<ReadingStatus>().unwrap(), ReadingStatus::Read);
+        assert_eq!("reading".parse::<ReadingStatus>().unwrap(), ReadingStatus::Reading);
+        assert!("skimmed".parse::<ReadingStatus>().is_err());
+    }
+}
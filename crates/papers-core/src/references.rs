@@ -0,0 +1,632 @@
+//! Reference-list extraction from a paper's full text.
+//!
+//! The references section is located by its heading (`References`,
+//! `Bibliography`, ...) in extracted text, or by its section header block in
+//! DataLab Marker JSON, and split into entries: numbered lists (`[12]`, `12.`,
+//! `12)`) start an entry at each label; otherwise each line is an entry, with
+//! lines not ending in a period folded into the next. Each entry is parsed
+//! heuristically into authors, title, year and DOI, then optionally resolved
+//! to an OpenAlex work by DOI or, failing that, by title search.
+
+use papers_openalex::{GetParams, ListParams, OpenAlexClient, Work};
+use papers_zotero::ZoteroClient;
+use serde::Serialize;
+
+use crate::selection::{
+    Selection, entry_matches_doi, entry_matches_openalex, list_selection_names, load_selection,
+};
+use crate::text::WorkTextError;
+use crate::util::{short_id, split_sentences};
+use crate::versions::title_similarity;
+
+/// Fields fetched when resolving a reference.
+pub const REFERENCE_SELECT: &str = "id,display_name,doi,publication_year";
+
+/// Minimum title similarity for a title-search result to count as the cited work.
+pub const REFERENCE_TITLE_SIMILARITY: f64 = 0.8;
+
+const REFERENCES_TITLES: &[&str] = &[
+    "references",
+    "bibliography",
+    "literature cited",
+    "works cited",
+    "cited literature",
+    "reference list",
+    "references and notes",
+];
+
+/// One entry of a paper's reference list.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ParsedReference {
+    /// Label of a numbered list (`[12]` → 12).
+    pub number: Option<u32>,
+    /// Entry text with wrapped lines joined and the label removed.
+    pub raw: String,
+    pub authors: Vec<String>,
+    pub title: Option<String>,
+    pub year: Option<u32>,
+    /// Lowercase bare DOI.
+    pub doi: Option<String>,
+}
+
+/// A parsed reference with its OpenAlex match and library membership.
+#[derive(Debug, Clone, Serialize)]
+pub struct ExtractedReference {
+    #[serde(flatten)]
+    pub parsed: ParsedReference,
+    /// Short OpenAlex ID of the matched work.
+    pub openalex_id: Option<String>,
+    /// Title of the matched work, to check title-search matches by eye.
+    pub openalex_title: Option<String>,
+    /// `"doi"` or `"title"`; `None` when unresolved.
+    pub resolved_by: Option<String>,
+    /// Set by callers that own the DB.
+    pub in_rag_index: bool,
+    pub in_selection: bool,
+    /// Names of the selections containing the cited work.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub selections: Vec<String>,
+}
+
+impl ExtractedReference {
+    fn new(parsed: ParsedReference) -> Self {
+        Self {
+            parsed,
+            openalex_id: None,
+            openalex_title: None,
+            resolved_by: None,
+            in_rag_index: false,
+            in_selection: false,
+            selections: Vec::new(),
+        }
+    }
+
+    /// IDs the cited work may be indexed under in the local DB.
+    pub fn db_ids(&self) -> Vec<String> {
+        self.parsed.doi.iter().cloned().collect()
+    }
+}
+
+/// Reference list of one work.
+#[derive(Debug, Clone, Serialize)]
+pub struct ExtractedReferences {
+    pub work_id: String,
+    pub title: Option<String>,
+    pub count: usize,
+    /// References matched to an OpenAlex work.
+    pub resolved: usize,
+    pub references: Vec<ExtractedReference>,
+}
+
+// ── Locating the section ─────────────────────────────────────────────────
+
+fn is_references_heading(line: &str) -> bool {
+    let title = line.trim().trim_start_matches('#').trim();
+    let title = title
+        .trim_start_matches(|c: char| c.is_ascii_digit() || c == '.' || c.is_whitespace())
+        .trim_end_matches(':')
+        .trim()
+        .trim_matches('*')
+        .to_lowercase();
+    REFERENCES_TITLES.contains(&title.as_str())
+}
+
+fn ends_references(line: &str) -> bool {
+    let trimmed = line.trim();
+    let title = trimmed.trim_start_matches('#').trim().to_lowercase();
+    trimmed.starts_with('#')
+        || title.starts_with("appendix")
+        || title.starts_with("supplementary")
+        || title.starts_with("supporting information")
+}
+
+/// Text of the references section of extracted text: the lines after the
+/// last references heading, up to the next Markdown heading or appendix.
+pub fn references_section(text: &str) -> Option<String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let start = lines.iter().rposition(|l| is_references_heading(l))?;
+    let body: Vec<&str> =
+        lines[start + 1..].iter().take_while(|l| !ends_references(l)).copied().collect();
+    Some(body.join("\n"))
+}
+
+fn strip_html(html: &str) -> String {
+    let mut out = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => out.push(c),
+            _ => {}
+        }
+    }
+    out.replace("&amp;", "&").replace("&lt;", "<").replace("&gt;", ">").trim().to_string()
+}
+
+/// Text of the references section of a DataLab Marker JSON document, one
+/// block or list item per line.
+pub fn marker_references_text(root: &serde_json::Value) -> String {
+    let blocks = root
+        .get("children")
+        .and_then(|c| c.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|page| page.get("children").and_then(|c| c.as_array()))
+        .flatten();
+    let mut in_refs = false;
+    let mut lines = Vec::new();
+    for block in blocks {
+        let html = block.get("html").and_then(|v| v.as_str()).unwrap_or("");
+        match block.get("block_type").and_then(|v| v.as_str()).unwrap_or("") {
+            "SectionHeader" => in_refs = is_references_heading(&strip_html(html)),
+            "Text" | "ListGroup" if in_refs => {
+                lines.extend(html.split("</li>").map(strip_html).filter(|l| !l.is_empty()));
+            }
+            _ => {}
+        }
+    }
+    lines.join("\n")
+}
+
+// ── Splitting and parsing entries ────────────────────────────────────────
+
+/// Split a leading list label (`[12]`, `12.`, `12)`) off a line.
+fn split_label(line: &str) -> Option<(u32, &str)> {
+    let (digits, rest) = match line.strip_prefix('[') {
+        Some(inner) => {
+            let (digits, rest) = inner.split_once(']')?;
+            (digits, rest)
+        }
+        None => {
+            let end = line.find(|c: char| !c.is_ascii_digit())?;
+            let rest = line[end..].strip_prefix(['.', ')'])?;
+            if !rest.starts_with(char::is_whitespace) {
+                return None;
+            }
+            (&line[..end], rest)
+        }
+    };
+    if digits.is_empty() || digits.len() > 4 {
+        return None;
+    }
+    Some((digits.trim().parse().ok()?, rest.trim()))
+}
+
+/// Split a references section into `(label, text)` entries.
+pub fn split_entries(text: &str) -> Vec<(Option<u32>, String)> {
+    let lines: Vec<&str> = text
+        .lines()
+        .map(|l| l.trim().trim_start_matches(['-', '*']).trim())
+        .collect();
+    let numbered = lines.iter().filter(|l| split_label(l).is_some()).count() >= 2;
+
+    let mut entries: Vec<(Option<u32>, String)> = Vec::new();
+    let mut open = false;
+    for line in lines {
+        if line.is_empty() {
+            if !numbered {
+                open = false;
+            }
+            continue;
+        }
+        let label = if numbered { split_label(line) } else { None };
+        match label {
+            Some((n, rest)) => {
+                entries.push((Some(n), rest.to_string()));
+                open = true;
+            }
+            None if open => {
+                let entry = &mut entries.last_mut().expect("open entry").1;
+                entry.push(' ');
+                entry.push_str(line);
+            }
+            None if numbered => continue,
+            None => {
+                entries.push((None, line.to_string()));
+                open = true;
+            }
+        }
+        if !numbered && line.ends_with('.') {
+            open = false;
+        }
+    }
+    entries.retain(|(_, text)| !text.is_empty());
+    entries
+}
+
+/// Every DOI in `text` with its byte offset, lowercased, without trailing
+/// punctuation.
+pub(crate) fn find_dois(text: &str) -> Vec<(usize, String)> {
+    let mut dois = Vec::new();
+    let mut from = 0;
+    while let Some(pos) = text[from..].find("10.") {
+        let start = from + pos;
+        from = start + 3;
+        if text[..start].ends_with(|c: char| c.is_ascii_alphanumeric()) {
+            continue;
+        }
+        let candidate: String =
+            text[start..].chars().take_while(|c| !c.is_whitespace()).collect();
+        let Some((prefix, suffix)) = candidate.split_once('/') else { continue };
+        let registrant = &prefix[3..];
+        if (4..=9).contains(&registrant.len())
+            && registrant.chars().all(|c| c.is_ascii_digit())
+            && !suffix.is_empty()
+        {
+            let doi = candidate.trim_end_matches(['.', ',', ';', ')', ']']);
+            dois.push((start, doi.to_lowercase()));
+            from = start + candidate.len();
+        }
+    }
+    dois
+}
+
+/// First DOI in `text`, lowercased, without trailing punctuation.
+fn find_doi(text: &str) -> Option<String> {
+    find_dois(text).into_iter().next().map(|(_, doi)| doi)
+}
+
+/// First plausible publication year in `text` (1800–2099), with its byte
+/// offset. A letter suffix (`2020a`) is allowed.
+fn find_year(text: &str) -> Option<(usize, u32)> {
+    let bytes = text.as_bytes();
+    (0..bytes.len().saturating_sub(3)).find_map(|i| {
+        let digits = &bytes[i..i + 4];
+        let before_ok = i == 0 || !bytes[i - 1].is_ascii_alphanumeric();
+        let after = bytes.get(i + 4);
+        let after_ok = after.is_none_or(|b| !b.is_ascii_digit())
+            && (after.is_none_or(|b| !b.is_ascii_alphabetic())
+                || bytes.get(i + 5).is_none_or(|b| !b.is_ascii_alphanumeric()));
+        if !(before_ok && after_ok && digits.iter().all(u8::is_ascii_digit)) {
+            return None;
+        }
+        let year: u32 = std::str::from_utf8(digits).ok()?.parse().ok()?;
+        (1800..2100).contains(&year).then_some((i, year))
+    })
+}
+
+/// Sentences of a reference, keeping initials together. Unlike in running
+/// text, `et al.` ends one here: it closes the author list.
+fn sentences(text: &str) -> Vec<&str> {
+    const ET_AL: &str = "et al.";
+    let authors_end = text
+        .find(ET_AL)
+        .map(|i| i + ET_AL.len())
+        .filter(|&end| text[end..].chars().next().is_none_or(char::is_whitespace));
+    match authors_end {
+        Some(end) => [split_sentences(&text[..end]), split_sentences(&text[end..])].concat(),
+        None => split_sentences(text),
+    }
+}
+
+fn is_initials(part: &str) -> bool {
+    let tokens: Vec<&str> =
+        part.split(|c: char| c == '.' || c == '-' || c.is_whitespace()).filter(|t| !t.is_empty()).collect();
+    !tokens.is_empty()
+        && tokens.iter().all(|t| t.chars().count() <= 2 && t.chars().all(char::is_uppercase))
+}
+
+/// Split an author list into names. `Surname, J.` pairs are kept together.
+fn split_authors(text: &str) -> Vec<String> {
+    let normalized = text
+        .replace("et al.", "")
+        .replace("et al", "")
+        .replace(" & ", ", ")
+        .replace(", and ", ", ")
+        .replace(" and ", ", ")
+        .replace(';', ",");
+    let parts: Vec<&str> = normalized
+        .split(',')
+        .map(|p| p.trim().trim_start_matches("and ").trim())
+        .filter(|p| !p.is_empty())
+        .collect();
+
+    let mut names: Vec<String> = Vec::new();
+    for part in parts {
+        if is_initials(part)
+            && let Some(last) = names.last_mut()
+            && !last.contains(',')
+        {
+            last.push_str(", ");
+            last.push_str(part);
+            continue;
+        }
+        names.push(part.to_string());
+    }
+    names
+        .into_iter()
+        .map(|n| {
+            // Keep the period of a trailing initial ("Pauly, M.") but not a
+            // sentence end ("B. Witkin.").
+            let last = n.rsplit([' ', ',']).next().unwrap_or("");
+            if is_initials(last) { n } else { n.trim_end_matches('.').to_string() }
+        })
+        .filter(|n| !n.is_empty() && n != "others")
+        .collect()
+}
+
+fn clean_title(title: &str) -> Option<String> {
+    let title = title
+        .trim()
+        .trim_matches(['"', '\u{201c}', '\u{201d}', '\''])
+        .trim_end_matches(['.', ','])
+        .trim_matches(['"', '\u{201c}', '\u{201d}'])
+        .trim();
+    (!title.is_empty()).then(|| title.to_string())
+}
+
+/// Parse one reference entry into authors, title, year and DOI.
+///
+/// Handles author–year styles (`Smith, J. (2020). Title. Venue.`), quoted
+/// titles (`A. Smith, "Title," in Proc.`), and author–title–venue styles
+/// (`A. Smith and B. Lee. Title. Venue, 2020.`).
+pub fn parse_reference(number: Option<u32>, raw: &str) -> ParsedReference {
+    let raw = raw.split_whitespace().collect::<Vec<_>>().join(" ");
+    let doi = find_doi(&raw);
+    let without_doi = match &doi {
+        Some(d) => raw.to_lowercase().find(d.as_str()).map_or(raw.clone(), |i| raw[..i].to_string()),
+        None => raw.clone(),
+    };
+    let year = find_year(&without_doi);
+
+    let quoted = ['"', '\u{201c}']
+        .iter()
+        .find_map(|q| without_doi.find(*q).map(|i| (i, q.len_utf8())));
+    let (authors, title) = if let Some((open, len)) = quoted {
+        let rest = &without_doi[open + len..];
+        let close = rest.find(['"', '\u{201d}']).unwrap_or(rest.len());
+        (&without_doi[..open], clean_title(&rest[..close]))
+    } else if let Some((pos, _)) = year.filter(|(pos, _)| without_doi[..*pos].ends_with('(')) {
+        let after = &without_doi[pos..];
+        let after = after.find(')').map_or("", |i| &after[i + 1..]);
+        let after = after.trim_start_matches(['.', ',', ':', ' ']);
+        (&without_doi[..pos - 1], sentences(after).first().and_then(|s| clean_title(s)))
+    } else {
+        let parts = sentences(&without_doi);
+        let authors = parts.first().copied().unwrap_or("");
+        (authors, parts.get(1).and_then(|s| clean_title(s)))
+    };
+
+    ParsedReference {
+        number,
+        authors: split_authors(authors.trim().trim_end_matches(',')),
+        title,
+        year: year.map(|(_, y)| y),
+        doi,
+        raw,
+    }
+}
+
+/// Split and parse a references section.
+pub fn parse_references(text: &str) -> Vec<ParsedReference> {
+    split_entries(text).into_iter().map(|(n, raw)| parse_reference(n, &raw)).collect()
+}
+
+// ── Resolution ───────────────────────────────────────────────────────────
+
+/// Look up `reference` in OpenAlex: by DOI, else by searching its title and
+/// keeping the result with the most similar title (at least
+/// [`REFERENCE_TITLE_SIMILARITY`]) and a year within one of the parsed year.
+pub async fn resolve_reference(
+    client: &OpenAlexClient,
+    reference: &ParsedReference,
+) -> Option<(Work, &'static str)> {
+    if let Some(doi) = &reference.doi {
+        let params = GetParams { select: Some(REFERENCE_SELECT.to_string()) };
+        if let Ok(work) = crate::api::work_get(client, doi, &params).await {
+            return Some((work, "doi"));
+        }
+    }
+    let title = reference.title.as_deref()?;
+    if title.split_whitespace().count() < 3 {
+        return None;
+    }
+    let params = ListParams {
+        search: Some(title.to_string()),
+        per_page: Some(5),
+        select: Some(REFERENCE_SELECT.to_string()),
+        ..Default::default()
+    };
+    let works = client.list_works(&params).await.ok()?.results;
+    works
+        .into_iter()
+        .filter(|w| match (reference.year, w.publication_year) {
+            (Some(a), Some(b)) => (a as i64 - b as i64).abs() <= 1,
+            _ => true,
+        })
+        .filter_map(|w| {
+            let sim = title_similarity(title, w.display_name.as_deref()?);
+            (sim >= REFERENCE_TITLE_SIMILARITY).then_some((sim, w))
+        })
+        .max_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|(_, w)| (w, "title"))
+}
+
+/// Resolve each reference in OpenAlex when `resolve` is set, and mark which
+/// selections contain it.
+pub async fn resolve_references(
+    client: &OpenAlexClient,
+    parsed: Vec<ParsedReference>,
+    resolve: bool,
+) -> Vec<ExtractedReference> {
+    let mut refs: Vec<ExtractedReference> = parsed.into_iter().map(ExtractedReference::new).collect();
+    if resolve {
+        for reference in &mut refs {
+            if let Some((work, by)) = resolve_reference(client, &reference.parsed).await {
+                reference.openalex_id = Some(short_id(&work.id).to_string());
+                reference.openalex_title = work.display_name.clone();
+                reference.resolved_by = Some(by.to_string());
+                if reference.parsed.doi.is_none() {
+                    reference.parsed.doi =
+                        work.doi.as_deref().map(|d| crate::selection::strip_doi_prefix(d).to_lowercase());
+                }
+            }
+        }
+    }
+    let selections: Vec<Selection> =
+        list_selection_names().iter().filter_map(|n| load_selection(n).ok()).collect();
+    mark_selections(&mut refs, &selections);
+    refs
+}
+
+/// Set `in_selection` and `selections` from the given selections.
+pub fn mark_selections(refs: &mut [ExtractedReference], selections: &[Selection]) {
+    for reference in refs {
+        let doi = reference.parsed.doi.as_deref();
+        let openalex_id = reference.openalex_id.as_deref();
+        reference.selections = selections
+            .iter()
+            .filter(|sel| {
+                sel.entries.iter().any(|e| {
+                    doi.is_some_and(|d| entry_matches_doi(e, d))
+                        || openalex_id.is_some_and(|id| entry_matches_openalex(e, id))
+                })
+            })
+            .map(|sel| sel.name.clone())
+            .collect();
+        reference.in_selection = !reference.selections.is_empty();
+    }
+}
+
+/// Fetch a work's full text (see [`crate::text::work_text`]), extract its
+/// reference list, and optionally resolve each entry in OpenAlex.
+/// `in_rag_index` is left `false`.
+pub async fn work_references_extracted(
+    client: &OpenAlexClient,
+    zotero: Option<&ZoteroClient>,
+    work_id: &str,
+    resolve: bool,
+) -> Result<ExtractedReferences, WorkTextError> {
+    let text = crate::text::work_text(client, zotero, work_id).await?;
+    let parsed = references_section(&text.text).map(|s| parse_references(&s)).unwrap_or_default();
+    let references = resolve_references(client, parsed, resolve).await;
+    Ok(ExtractedReferences {
+        work_id: short_id(&text.work_id).to_string(),
+        title: text.title,
+        count: references.len(),
+        resolved: references.iter().filter(|r| r.openalex_id.is_some()).count(),
+        references,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_last_references_heading_and_stops_at_appendix() {
+        let text = "Contents\nReferences\n\n# Introduction\nBody.\n\n## References\n\
+                    [1] A. Smith. One. 2020.\n[2] B. Lee. Two. 2021.\n\n# Appendix A\nMore.";
+        assert_eq!(
+            references_section(text).unwrap().trim(),
+            "[1] A. Smith. One. 2020.\n[2] B. Lee. Two. 2021."
+        );
+        assert_eq!(references_section("Introduction\nNo refs."), None);
+    }
+
+    #[test]
+    fn splits_numbered_and_unnumbered_entries() {
+        let numbered = "[1] A. Smith. Fast\nsolvers. 2020.\n[2] B. Lee. Other. 2019.";
+        let entries = split_entries(numbered);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0], (Some(1), "A. Smith. Fast solvers. 2020.".to_string()));
+
+        let plain = "Smith, J. (2020). A long title that\nwraps. Journal.\nLee, B. (2019). Other. Venue.";
+        let entries = split_entries(plain);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].1, "Smith, J. (2020). A long title that wraps. Journal.");
+        assert_eq!(entries[1].0, None);
+    }
+
+    #[test]
+    fn parses_author_title_venue_style() {
+        let r = parse_reference(
+            Some(3),
+            "A. Baraff and B. Witkin. Large steps in cloth simulation. In Proc. SIGGRAPH, 1998. \
+             doi:10.1145/280814.280821.",
+        );
+        assert_eq!(r.authors, vec!["A. Baraff", "B. Witkin"]);
+        assert_eq!(r.title.as_deref(), Some("Large steps in cloth simulation"));
+        assert_eq!(r.year, Some(1998));
+        assert_eq!(r.doi.as_deref(), Some("10.1145/280814.280821"));
+    }
+
+    #[test]
+    fn parses_author_year_style() {
+        let r = parse_reference(
+            None,
+            "Bouaziz, S., Martin, S., & Pauly, M. (2014a). Projective dynamics: fusing constraint \
+             projections for fast simulation. ACM TOG, 33(4).",
+        );
+        assert_eq!(r.authors, vec!["Bouaziz, S.", "Martin, S.", "Pauly, M."]);
+        assert_eq!(
+            r.title.as_deref(),
+            Some("Projective dynamics: fusing constraint projections for fast simulation")
+        );
+        assert_eq!(r.year, Some(2014));
+        assert_eq!(r.doi, None);
+    }
+
+    #[test]
+    fn parses_quoted_titles() {
+        let r = parse_reference(
+            Some(7),
+            "J. Doe, K. Roe et al., \u{201c}Neural fields in visual computing,\u{201d} in Proc. EG, 2022, pp. 1-10.",
+        );
+        assert_eq!(r.authors, vec!["J. Doe", "K. Roe"]);
+        assert_eq!(r.title.as_deref(), Some("Neural fields in visual computing"));
+        assert_eq!(r.year, Some(2022));
+    }
+
+    #[test]
+    fn et_al_ends_the_author_list() {
+        let r = parse_reference(
+            Some(3),
+            "Doe J, Roe K, et al. Neural fields in visual computing. Comput. Graph. Forum 41, 2022.",
+        );
+        assert_eq!(r.authors, vec!["Doe J", "Roe K"]);
+        assert_eq!(r.title.as_deref(), Some("Neural fields in visual computing"));
+    }
+
+    #[test]
+    fn reads_marker_references_blocks() {
+        let root = serde_json::json!({"children": [{"children": [
+            {"block_type": "SectionHeader", "html": "<h2>Results</h2>"},
+            {"block_type": "Text", "html": "<p>Not a reference.</p>"},
+            {"block_type": "SectionHeader", "html": "<h2>References</h2>"},
+            {"block_type": "ListGroup", "html": "<ul><li>[1] A. One.</li><li>[2] B. Two &amp; C.</li></ul>"},
+        ]}]});
+        assert_eq!(marker_references_text(&root), "[1] A. One.\n[2] B. Two & C.");
+    }
+
+    #[test]
+    fn marks_selections_by_doi_or_openalex_id() {
+        let entry = |doi: Option<&str>, oa: Option<&str>| crate::selection::SelectionEntry {
+            zotero_key: None,
+            openalex_id: oa.map(str::to_string),
+            doi: doi.map(str::to_string),
+            title: None,
+            authors: None,
+            year: None,
+            issn: None,
+            isbn: None,
+            work_type: None,
+            note: None,
+            tags: Vec::new(),
+        };
+        let selections = vec![
+            Selection { name: "a".into(), entries: vec![entry(Some("10.1234/X"), None)] },
+            Selection { name: "b".into(), entries: vec![entry(None, Some("W9"))] },
+        ];
+        let mut refs = vec![
+            ExtractedReference::new(parse_reference(None, "A. B. Title here. doi:10.1234/x")),
+            ExtractedReference::new(parse_reference(None, "C. D. Other title.")),
+        ];
+        refs[1].openalex_id = Some("W9".into());
+        mark_selections(&mut refs, &selections);
+        assert_eq!(refs[0].selections, vec!["a"]);
+        assert_eq!(refs[1].selections, vec!["b"]);
+        assert!(refs[0].in_selection && refs[1].in_selection);
+    }
+}
@@ -0,0 +1,368 @@
+//! Related-work landscape for a reviewer assessing a submission's novelty.
+//!
+//! [`crate::api::review_context`] searches OpenAlex three ways for the
+//! submission's title or abstract: semantic `work_find` (needs an API key),
+//! full-text search, and full-text search limited to the last
+//! [`RECENT_YEARS`] years. The hits are merged into one candidate list,
+//! ranked by reciprocal rank fusion across the three searches, and reported
+//! as:
+//!
+//! - **Clusters** — candidates grouped by OpenAlex primary topic.
+//! - **Key prior works** — the most-cited candidates published before the
+//!   recent window.
+//! - **Recent closest matches** — candidates from the recent window, most
+//!   relevant first.
+//! - **Possible missed citations** — works cited by several candidates that
+//!   are not in the submission's reference list (when given).
+//!
+//! Searches that fail are skipped and listed in `warnings`.
+
+use std::collections::{HashMap, HashSet};
+
+use papers_openalex::Work;
+use serde::Serialize;
+
+use crate::summary::WorkSummary;
+use crate::util::short_id;
+
+/// Fields fetched for candidates.
+pub const REVIEW_SELECT: &str =
+    "id,display_name,doi,publication_year,type,authorships,primary_location,open_access,cited_by_count,primary_topic,referenced_works";
+
+/// Candidates fetched from each search.
+pub const REVIEW_SEARCH_LIMIT: usize = 50;
+
+/// Years counted as recent, including the current one.
+pub const RECENT_YEARS: i32 = 3;
+
+/// Works listed per section.
+pub const REVIEW_SECTION_LIMIT: usize = 10;
+
+/// Works listed per cluster.
+pub const REVIEW_CLUSTER_WORKS: usize = 5;
+
+/// A work must be cited by at least this many candidates to be a possible
+/// missed citation.
+pub const MIN_RELATED_CITATIONS: usize = 2;
+
+/// Words kept for the full-text search query.
+const SEARCH_TERMS: usize = 10;
+
+/// Reciprocal rank fusion constant.
+const RRF_K: f64 = 60.0;
+
+const STOPWORDS: &[&str] = &[
+    "a", "an", "the", "of", "for", "and", "or", "in", "on", "to", "with", "by", "from", "via", "we", "our", "is", "are",
+    "this", "that", "using", "based", "towards", "toward", "into", "at", "as", "its",
+];
+
+/// A candidate and its rank in the merged list.
+#[derive(Debug, Clone)]
+pub struct RankedWork {
+    pub work: Work,
+    /// Reciprocal rank fusion score over the searches that found the work.
+    pub relevance: f64,
+    /// `work_find` similarity, when the semantic search found the work.
+    pub similarity: Option<f64>,
+}
+
+/// A candidate in a section of the landscape.
+#[derive(Serialize)]
+pub struct ReviewWork {
+    pub relevance: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub similarity: Option<f64>,
+    /// Whether the submission cites the work; absent without a reference list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub in_references: Option<bool>,
+    #[serde(flatten)]
+    pub work: WorkSummary,
+}
+
+/// Candidates sharing a primary topic.
+#[derive(Debug, Clone, Serialize)]
+pub struct TopicCluster {
+    pub topic: String,
+    pub count: usize,
+    pub first_year: Option<i32>,
+    pub last_year: Option<i32>,
+    /// Most relevant titles, at most [`REVIEW_CLUSTER_WORKS`].
+    pub works: Vec<String>,
+}
+
+/// A work the candidates cite that the submission may have missed.
+#[derive(Serialize)]
+pub struct MissedCitation {
+    /// Number of candidates citing it.
+    pub cited_by_related: usize,
+    #[serde(flatten)]
+    pub work: WorkSummary,
+}
+
+/// Body of `review_context`.
+#[derive(Serialize)]
+pub struct ReviewContext {
+    /// Full-text search query derived from the submission.
+    pub search_terms: String,
+    /// First year of the recent window.
+    pub recent_since: i32,
+    /// Number of distinct candidates found.
+    pub candidates: usize,
+    /// Largest first.
+    pub clusters: Vec<TopicCluster>,
+    /// Most cited first.
+    pub key_prior_works: Vec<ReviewWork>,
+    /// Most relevant first.
+    pub recent_closest: Vec<ReviewWork>,
+    /// Most cited by candidates first.
+    pub possible_missed_citations: Vec<MissedCitation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+fn title_key(work: &Work) -> String {
+    work.display_name.as_deref().unwrap_or("").chars().filter(|c| c.is_alphanumeric()).collect::<String>().to_lowercase()
+}
+
+/// The full-text search query for `text`: its first line, or first sentence
+/// when it is a single paragraph, without stopwords and cut to
+/// [`SEARCH_TERMS`] words. OpenAlex matches every search word, so a whole
+/// abstract would find nothing.
+pub fn search_terms(text: &str) -> String {
+    let text = text.trim();
+    let first = match text.lines().next() {
+        Some(line) if line.len() < text.len() => line,
+        _ => text.split_once(". ").map_or(text, |(sentence, _)| sentence),
+    };
+    first
+        .split(|c: char| !c.is_alphanumeric() && c != '-')
+        .filter(|w| !w.is_empty() && !STOPWORDS.contains(&w.to_lowercase().as_str()))
+        .take(SEARCH_TERMS)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Merge the semantic hits (`found`, with similarity scores) and each
+/// full-text ranking in `searches` into one list, most relevant first. Works
+/// titled like the submission itself (`own_title`) are dropped.
+pub fn fuse(found: Vec<(Work, f64)>, searches: Vec<Vec<Work>>, own_title: &str) -> Vec<RankedWork> {
+    let own: String = own_title.chars().filter(|c| c.is_alphanumeric()).collect::<String>().to_lowercase();
+    let mut ranked: Vec<RankedWork> = Vec::new();
+    let mut index: HashMap<String, usize> = HashMap::new();
+    let mut add = |rank: usize, work: Work, similarity: Option<f64>| {
+        if !own.is_empty() && title_key(&work) == own {
+            return;
+        }
+        let score = 1.0 / (RRF_K + rank as f64 + 1.0);
+        match index.get(short_id(&work.id)) {
+            Some(&i) => {
+                ranked[i].relevance += score;
+                ranked[i].similarity = ranked[i].similarity.or(similarity);
+            }
+            None => {
+                index.insert(short_id(&work.id).to_string(), ranked.len());
+                ranked.push(RankedWork { work, relevance: score, similarity });
+            }
+        }
+    };
+    for (rank, (work, score)) in found.into_iter().enumerate() {
+        add(rank, work, Some(score));
+    }
+    for search in searches {
+        for (rank, work) in search.into_iter().enumerate() {
+            add(rank, work, None);
+        }
+    }
+    ranked.sort_by(|a, b| b.relevance.total_cmp(&a.relevance).then_with(|| a.work.id.cmp(&b.work.id)));
+    ranked
+}
+
+/// Candidates grouped by primary topic, largest group first. Works without
+/// a primary topic are left out.
+pub fn clusters(ranked: &[RankedWork]) -> Vec<TopicCluster> {
+    let mut groups: Vec<TopicCluster> = Vec::new();
+    for r in ranked {
+        let Some(topic) = r.work.primary_topic.as_ref().and_then(|t| t.display_name.clone()) else { continue };
+        let i = match groups.iter().position(|g| g.topic == topic) {
+            Some(i) => i,
+            None => {
+                groups.push(TopicCluster { topic, count: 0, first_year: None, last_year: None, works: Vec::new() });
+                groups.len() - 1
+            }
+        };
+        let group = &mut groups[i];
+        group.count += 1;
+        if let Some(year) = r.work.publication_year {
+            group.first_year = Some(group.first_year.map_or(year, |y| y.min(year)));
+            group.last_year = Some(group.last_year.map_or(year, |y| y.max(year)));
+        }
+        if group.works.len() < REVIEW_CLUSTER_WORKS {
+            group.works.push(r.work.display_name.clone().unwrap_or_else(|| short_id(&r.work.id).to_string()));
+        }
+    }
+    groups.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.topic.cmp(&b.topic)));
+    groups
+}
+
+/// The submission's reference list, as short OpenAlex IDs and bare DOIs.
+#[derive(Debug, Default)]
+pub struct References {
+    ids: HashSet<String>,
+    dois: HashSet<String>,
+    given: bool,
+}
+
+impl References {
+    /// Parse DOIs and OpenAlex IDs (short or URL form); anything else is ignored.
+    pub fn new(references: &[String]) -> Self {
+        let mut refs = Self { given: !references.is_empty(), ..Default::default() };
+        for r in references {
+            let r = r.trim();
+            let id = short_id(r);
+            if id.len() > 1 && id.starts_with(['W', 'w']) && id[1..].chars().all(|c| c.is_ascii_digit()) {
+                refs.ids.insert(id.to_uppercase());
+            } else {
+                refs.dois.insert(crate::selection::strip_doi_prefix(r).to_lowercase());
+            }
+        }
+        refs
+    }
+
+    /// Whether the list contains `work`; `None` when no list was given.
+    pub fn contains(&self, work: &Work) -> Option<bool> {
+        if !self.given {
+            return None;
+        }
+        let doi = work.doi.as_deref().map(|d| crate::selection::strip_doi_prefix(d).to_lowercase());
+        Some(self.ids.contains(short_id(&work.id)) || doi.is_some_and(|d| self.dois.contains(&d)))
+    }
+
+    fn contains_id(&self, id: &str) -> bool {
+        self.ids.contains(id)
+    }
+}
+
+/// Short IDs of works cited by at least [`MIN_RELATED_CITATIONS`]
+/// candidates and not in `references`, with their citation counts, most
+/// cited first, at most `limit`.
+pub fn most_referenced(ranked: &[RankedWork], references: &References, limit: usize) -> Vec<(String, usize)> {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for r in ranked {
+        let refs: HashSet<&str> = r.work.referenced_works.iter().flatten().map(|id| short_id(id)).collect();
+        for id in refs {
+            *counts.entry(id).or_default() += 1;
+        }
+    }
+    let mut cited: Vec<(String, usize)> = counts
+        .into_iter()
+        .filter(|&(id, n)| n >= MIN_RELATED_CITATIONS && !references.contains_id(id))
+        .map(|(id, n)| (id.to_string(), n))
+        .collect();
+    cited.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    cited.truncate(limit);
+    cited
+}
+
+impl ReviewContext {
+    /// Split `ranked` into the report's sections. `missed` are the works
+    /// [`most_referenced`] named, with their counts.
+    pub fn new(
+        search_terms: String,
+        recent_since: i32,
+        ranked: Vec<RankedWork>,
+        missed: Vec<(Work, usize)>,
+        references: &References,
+        warnings: Vec<String>,
+    ) -> Self {
+        let review_work = |r: &RankedWork| ReviewWork {
+            relevance: r.relevance,
+            similarity: r.similarity,
+            in_references: references.contains(&r.work),
+            work: WorkSummary::from(r.work.clone()),
+        };
+        let is_recent = |r: &&RankedWork| r.work.publication_year.is_some_and(|y| y >= recent_since);
+        let mut prior: Vec<&RankedWork> =
+            ranked.iter().filter(|r| r.work.publication_year.is_some_and(|y| y < recent_since)).collect();
+        prior.sort_by_key(|r| std::cmp::Reverse(r.work.cited_by_count.unwrap_or(0)));
+        let mut possible_missed_citations: Vec<MissedCitation> = missed
+            .into_iter()
+            .filter(|(work, _)| references.contains(work) != Some(true))
+            .map(|(work, cited_by_related)| MissedCitation { cited_by_related, work: WorkSummary::from(work) })
+            .collect();
+        possible_missed_citations.sort_by_key(|m| std::cmp::Reverse(m.cited_by_related));
+        Self {
+            search_terms,
+            recent_since,
+            candidates: ranked.len(),
+            clusters: clusters(&ranked),
+            key_prior_works: prior.into_iter().take(REVIEW_SECTION_LIMIT).map(review_work).collect(),
+            recent_closest: ranked.iter().filter(is_recent).take(REVIEW_SECTION_LIMIT).map(review_work).collect(),
+            possible_missed_citations,
+            warnings,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn work(id: &str, title: &str, year: i32, cited: i64, topic: &str, refs: &[&str]) -> Work {
+        serde_json::from_value(serde_json::json!({
+            "id": format!("https://openalex.org/{id}"),
+            "display_name": title,
+            "publication_year": year,
+            "cited_by_count": cited,
+            "primary_topic": {"id": "https://openalex.org/T1", "display_name": topic},
+            "referenced_works": refs.iter().map(|r| format!("https://openalex.org/{r}")).collect::<Vec<_>>(),
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn search_terms_use_the_title_line() {
+        assert_eq!(
+            search_terms("Fast Gaussian Splatting for Large Scenes\n\nWe present a method..."),
+            "Fast Gaussian Splatting Large Scenes"
+        );
+        assert_eq!(search_terms("We study NeRF. It is slow."), "study NeRF");
+    }
+
+    #[test]
+    fn searches_are_fused_and_the_submission_dropped() {
+        let a = work("W1", "Alpha", 2018, 500, "Rendering", &[]);
+        let b = work("W2", "Beta", 2025, 5, "Rendering", &[]);
+        let own = work("W3", "My Submission", 2026, 0, "Rendering", &[]);
+        let ranked = fuse(vec![(b.clone(), 0.9), (own.clone(), 0.95)], vec![vec![a.clone(), b.clone()], vec![own]], "My submission");
+        let ids: Vec<_> = ranked.iter().map(|r| short_id(&r.work.id)).collect();
+        assert_eq!(ids, ["W2", "W1"]);
+        assert_eq!(ranked[0].similarity, Some(0.9));
+        assert_eq!(ranked[1].similarity, None);
+    }
+
+    #[test]
+    fn landscape_sections() {
+        let ranked = fuse(
+            Vec::new(),
+            vec![vec![
+                work("W1", "Recent close", 2025, 3, "Rendering", &["W9", "W8"]),
+                work("W2", "Classic", 2015, 900, "Rendering", &["W9", "W8"]),
+                work("W3", "Older, less cited", 2016, 40, "Geometry", &["W9"]),
+            ]],
+            "",
+        );
+        let references = References::new(&["https://openalex.org/W8".to_string(), "10.1/x".to_string()]);
+        assert_eq!(most_referenced(&ranked, &references, 10), [("W9".to_string(), 3)]);
+
+        let missed = vec![(work("W9", "Foundational", 2010, 5000, "Rendering", &[]), 3)];
+        let context = ReviewContext::new("terms".to_string(), 2024, ranked, missed, &references, Vec::new());
+        assert_eq!(context.candidates, 3);
+        let titles = |works: &[ReviewWork]| works.iter().map(|w| w.work.title.clone().unwrap()).collect::<Vec<_>>();
+        assert_eq!(titles(&context.key_prior_works), ["Classic", "Older, less cited"]);
+        assert_eq!(titles(&context.recent_closest), ["Recent close"]);
+        assert_eq!(context.recent_closest[0].in_references, Some(false));
+        assert_eq!(context.possible_missed_citations[0].cited_by_related, 3);
+        assert_eq!(context.clusters[0].topic, "Rendering");
+        assert_eq!((context.clusters[0].count, context.clusters[0].first_year, context.clusters[0].last_year), (2, Some(2015), Some(2025)));
+    }
+}
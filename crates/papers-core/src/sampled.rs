@@ -0,0 +1,190 @@
+//! Answers obtained through MCP sampling, kept per work so the client's model
+//! is never asked the same question twice about the same paper.
+//!
+//! Each work has one file, `{PAPERS_DATA_DIR or platform data dir}/sampled/<work>.json`
+//! (inside a [`crate::tenant::scope`], the tenant's `sampled/`), mapping an
+//! artifact kind — [`PDF_URL`], [`SUMMARY`], or [`figure_kind`] — to the
+//! answer. These live with the data rather than the response cache because
+//! they cost a model call to regenerate and `cache clear` should not drop them.
+//!
+//! A `null` value records that the model had no usable answer. Those are
+//! asked again after [`MISS_RETRY_DAYS`], since a paper may have become
+//! available in the meantime.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+/// A direct PDF URL that downloaded as a PDF.
+pub const PDF_URL: &str = "pdf_url";
+/// A generated summary of the paper.
+pub const SUMMARY: &str = "summary";
+/// Days before a recorded miss is asked again.
+pub const MISS_RETRY_DAYS: u64 = 30;
+
+/// Kind under which the description of figure `figure` (e.g. `3`, `S1`) is kept.
+pub fn figure_kind(figure: &str) -> String {
+    format!("figure:{figure}")
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SampledError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+/// One sampled answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SampledArtifact {
+    /// The answer, or `None` when the model had none.
+    pub value: Option<String>,
+    /// Model that produced it, as reported by the client.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// ISO 8601 UTC time of the model call.
+    pub sampled_at: String,
+}
+
+impl SampledArtifact {
+    /// Whether this is a miss old enough to ask again.
+    pub fn is_stale_miss(&self) -> bool {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        let cutoff = crate::text::iso_from_secs(now.saturating_sub(MISS_RETRY_DAYS * 86_400));
+        self.value.is_none() && self.sampled_at < cutoff
+    }
+}
+
+/// Everything sampled for one work.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkArtifacts {
+    pub work_id: String,
+    #[serde(default)]
+    pub artifacts: BTreeMap<String, SampledArtifact>,
+}
+
+/// `sampled/` in the [`crate::tenant::data_dir`].
+pub fn sampled_dir() -> PathBuf {
+    crate::tenant::data_dir().join("sampled")
+}
+
+/// Per-work files of sampled answers, see the module docs.
+#[derive(Debug, Clone)]
+pub struct SampledStore {
+    dir: PathBuf,
+}
+
+impl SampledStore {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    /// Store at [`sampled_dir`].
+    pub fn open_default() -> Self {
+        Self::new(sampled_dir())
+    }
+
+    /// `work_id` as a file name: OpenAlex IDs stay as they are, DOIs and URLs
+    /// have their separators replaced.
+    fn path(&self, work_id: &str) -> PathBuf {
+        let id = work_id.trim().trim_start_matches("https://openalex.org/");
+        let name: String =
+            id.chars().map(|c| if c.is_ascii_alphanumeric() || "-_.".contains(c) { c } else { '_' }).collect();
+        self.dir.join(format!("{name}.json"))
+    }
+
+    /// Everything recorded for `work_id`; empty if nothing was.
+    pub fn load(&self, work_id: &str) -> Result<WorkArtifacts, SampledError> {
+        let path = self.path(work_id);
+        if !path.exists() {
+            return Ok(WorkArtifacts { work_id: work_id.to_string(), ..Default::default() });
+        }
+        Ok(serde_json::from_slice(&std::fs::read(path)?)?)
+    }
+
+    /// The recorded answer of `kind` for `work_id`. Unreadable files count as
+    /// nothing recorded.
+    pub fn get(&self, work_id: &str, kind: &str) -> Option<SampledArtifact> {
+        self.load(work_id).ok()?.artifacts.remove(kind)
+    }
+
+    /// Record an answer (`None` for a miss), replacing any earlier one of the same kind.
+    pub fn put(
+        &self,
+        work_id: &str,
+        kind: &str,
+        value: Option<&str>,
+        model: Option<&str>,
+    ) -> Result<(), SampledError> {
+        let mut entry = self.load(work_id).unwrap_or_default();
+        entry.work_id = work_id.to_string();
+        entry.artifacts.insert(
+            kind.to_string(),
+            SampledArtifact {
+                value: value.map(str::to_string),
+                model: model.map(str::to_string),
+                sampled_at: crate::text::iso_now(),
+            },
+        );
+        std::fs::create_dir_all(&self.dir)?;
+        std::fs::write(self.path(work_id), serde_json::to_vec_pretty(&entry)?)?;
+        Ok(())
+    }
+
+    /// Forget one recorded answer; returns whether it existed.
+    pub fn remove(&self, work_id: &str, kind: &str) -> Result<bool, SampledError> {
+        let mut entry = self.load(work_id)?;
+        if entry.artifacts.remove(kind).is_none() {
+            return Ok(false);
+        }
+        let path = self.path(work_id);
+        if entry.artifacts.is_empty() {
+            std::fs::remove_file(path)?;
+        } else {
+            std::fs::write(path, serde_json::to_vec_pretty(&entry)?)?;
+        }
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_put_get_remove() {
+        let dir = TempDir::new().unwrap();
+        let store = SampledStore::new(dir.path().to_path_buf());
+        assert_eq!(store.get("W1", PDF_URL), None);
+
+        store.put("W1", PDF_URL, Some("https://example.org/a.pdf"), Some("model-a")).unwrap();
+        store.put("W1", &figure_kind("2"), Some("A bar chart of FPS per scene."), None).unwrap();
+        store.put("10.1/x y", SUMMARY, None, None).unwrap();
+
+        let url = store.get("https://openalex.org/W1", PDF_URL).unwrap();
+        assert_eq!(url.value.as_deref(), Some("https://example.org/a.pdf"));
+        assert_eq!(url.model.as_deref(), Some("model-a"));
+        assert_eq!(store.load("W1").unwrap().artifacts.len(), 2);
+        assert!(dir.path().join("10.1_x_y.json").exists());
+
+        assert!(store.remove("W1", PDF_URL).unwrap());
+        assert!(!store.remove("W1", PDF_URL).unwrap());
+        assert!(store.remove("W1", &figure_kind("2")).unwrap());
+        assert!(!dir.path().join("W1.json").exists());
+    }
+
+    #[test]
+    fn test_misses_are_retried_after_a_while() {
+        let miss = |sampled_at: &str| SampledArtifact { value: None, model: None, sampled_at: sampled_at.to_string() };
+        assert!(miss("2001-01-01T00:00:00Z").is_stale_miss());
+        assert!(!miss(&crate::text::iso_now()).is_stale_miss());
+        let hit = SampledArtifact { value: Some("x".into()), ..miss("2001-01-01T00:00:00Z") };
+        assert!(!hit.is_stale_miss());
+    }
+}
@@ -0,0 +1,102 @@
+//! Periodic maintenance tasks for long-running servers.
+//!
+//! Entries are configured under `schedule` in `config.json`:
+//!
+//! ```json
+//! { "schedule": [
+//!     { "task": "cache_evict", "every": "6h" },
+//!     { "task": "library_sync", "every": "@daily" },
+//!     { "task": "zotero_sync", "every": "30m" },
+//!     { "task": "digest", "every": "@daily" }
+//! ],
+//!   "digest_watches": ["gaussian splatting"],
+//!   "digest_selections": ["my-papers"] }
+//! ```
+//!
+//! `every` is either a duration (`90s`, `30m`, `6h`, `1d`) or one of the cron
+//! shorthands `@hourly`, `@daily` and `@weekly`. The MCP server runs each entry
+//! on its own timer for as long as it stays up.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::PapersConfig;
+use crate::digest::DIGEST_MAX_DAYS;
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum ScheduleError {
+    #[error("invalid interval {0:?}: expected e.g. \"30m\", \"6h\", \"1d\" or \"@daily\"")]
+    Interval(String),
+}
+
+/// Work a schedule entry can run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScheduledTask {
+    /// Drop expired OpenAlex responses and trim the cache to its size limit.
+    CacheEvict,
+    /// Index cached Zotero extractions that aren't in the RAG database yet.
+    LibrarySync,
+    /// Run one poll of the Zotero watcher (see [`crate::sync`]), like a
+    /// single `db watch --once`, except that items are only indexed when
+    /// their extraction is cached or backed up in Zotero.
+    ZoteroSync,
+    /// Build the digest of `digest_watches` and `digest_selections` over the
+    /// last interval (see [`digest_days`]) and announce its new works to the
+    /// configured webhooks and email (see [`crate::notify`]).
+    #[serde(alias = "watch")]
+    Digest,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScheduleEntry {
+    pub task: ScheduledTask,
+    pub every: String,
+}
+
+impl ScheduleEntry {
+    pub fn interval(&self) -> Result<Duration, ScheduleError> {
+        parse_interval(&self.every)
+    }
+}
+
+/// Schedule entries from `config.json`; empty when none are configured or the
+/// config can't be read.
+pub fn configured_schedule() -> Vec<ScheduleEntry> {
+    PapersConfig::load().map(|c| c.schedule).unwrap_or_default()
+}
+
+/// Digest window for a task run every `period`: whole days covering it,
+/// within `1..=DIGEST_MAX_DAYS`.
+pub fn digest_days(period: Duration) -> u32 {
+    let days = period.as_secs().div_ceil(86_400);
+    days.clamp(1, u64::from(DIGEST_MAX_DAYS)) as u32
+}
+
+/// Parse `"<n>s|m|h|d"` or `@hourly` / `@daily` / `@weekly`. Zero is rejected.
+pub fn parse_interval(spec: &str) -> Result<Duration, ScheduleError> {
+    let err = || ScheduleError::Interval(spec.to_string());
+    let spec = spec.trim();
+    let secs = match spec {
+        "@hourly" => 3600,
+        "@daily" => 86_400,
+        "@weekly" => 7 * 86_400,
+        _ => {
+            let split = spec.find(|c: char| !c.is_ascii_digit()).ok_or_else(err)?;
+            let n: u64 = spec[..split].parse().map_err(|_| err())?;
+            let unit = match spec[split..].trim() {
+                "s" => 1,
+                "m" => 60,
+                "h" => 3600,
+                "d" => 86_400,
+                _ => return Err(err()),
+            };
+            n.checked_mul(unit).ok_or_else(err)?
+        }
+    };
+    if secs == 0 {
+        return Err(err());
+    }
+    Ok(Duration::from_secs(secs))
+}
@@ -0,0 +1,666 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::selection::{
+    Selection, SelectionEntry, SelectionError, find_entry_index, load_selection,
+    selections_dir, strip_doi_prefix,
+};
+
+// ── Error ──────────────────────────────────────────────────────────────────
+
+#[derive(thiserror::Error, Debug)]
+pub enum ScreeningError {
+    #[error(transparent)]
+    Selection(#[from] SelectionError),
+    #[error("invalid screening stage {0:?}: use title_abstract or full_text")]
+    InvalidStage(String),
+    #[error("invalid screening decision {0:?}: use include, exclude or maybe")]
+    InvalidDecision(String),
+    #[error("{0:?} has not been included at title/abstract screening")]
+    NotEligible(String),
+    #[error("entry has no identifier (zotero key, OpenAlex ID, DOI or title)")]
+    NoIdentifier,
+    #[error("invalid CSV: {0}")]
+    Csv(String),
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("json error: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+// ── Data model ─────────────────────────────────────────────────────────────
+
+/// PRISMA screening stage. Full-text assessment is only open to entries
+/// included at title/abstract screening.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum ScreeningStage {
+    TitleAbstract,
+    FullText,
+}
+
+impl ScreeningStage {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ScreeningStage::TitleAbstract => "title_abstract",
+            ScreeningStage::FullText => "full_text",
+        }
+    }
+}
+
+impl std::str::FromStr for ScreeningStage {
+    type Err = ScreeningError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().replace('-', "_").as_str() {
+            "title_abstract" | "ta" | "abstract" => Ok(ScreeningStage::TitleAbstract),
+            "full_text" | "ft" | "fulltext" => Ok(ScreeningStage::FullText),
+            _ => Err(ScreeningError::InvalidStage(s.to_string())),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum ScreeningDecision {
+    Include,
+    Exclude,
+    Maybe,
+}
+
+impl ScreeningDecision {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ScreeningDecision::Include => "include",
+            ScreeningDecision::Exclude => "exclude",
+            ScreeningDecision::Maybe => "maybe",
+        }
+    }
+}
+
+impl std::str::FromStr for ScreeningDecision {
+    type Err = ScreeningError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "include" | "in" | "yes" => Ok(ScreeningDecision::Include),
+            "exclude" | "out" | "no" => Ok(ScreeningDecision::Exclude),
+            "maybe" | "unsure" => Ok(ScreeningDecision::Maybe),
+            _ => Err(ScreeningError::InvalidDecision(s.to_string())),
+        }
+    }
+}
+
+/// One screening decision. Records are append-only; the latest record for a
+/// stage is the effective decision.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ScreeningRecord {
+    pub stage: ScreeningStage,
+    pub decision: ScreeningDecision,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reviewer: Option<String>,
+    /// ISO 8601 UTC timestamp.
+    pub decided_at: String,
+    /// Set on decisions that settle a disagreement between reviewers.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub adjudicated: bool,
+}
+
+/// Screening state for one selection, stored next to the selection file at
+/// `selections/screening/<name>.json`.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct ScreeningState {
+    pub selection: String,
+    /// Decision history keyed by [`entry_screening_key`].
+    #[serde(default)]
+    pub records: BTreeMap<String, Vec<ScreeningRecord>>,
+}
+
+impl ScreeningState {
+    /// Effective (latest) decision for an entry at a stage.
+    pub fn latest(&self, entry: &SelectionEntry, stage: ScreeningStage) -> Option<&ScreeningRecord> {
+        let key = entry_screening_key(entry)?;
+        self.records
+            .get(&key)?
+            .iter()
+            .rev()
+            .find(|r| r.stage == stage)
+    }
+
+    /// Latest decision of each reviewer for an entry at a stage, unless an
+    /// adjudicated decision has superseded them. Records without a reviewer are
+    /// ignored.
+    pub fn reviewer_decisions(
+        &self,
+        entry: &SelectionEntry,
+        stage: ScreeningStage,
+    ) -> BTreeMap<String, ScreeningDecision> {
+        let mut out = BTreeMap::new();
+        let Some(records) = entry_screening_key(entry).and_then(|k| self.records.get(&k)) else {
+            return out;
+        };
+        for r in records.iter().rev().filter(|r| r.stage == stage) {
+            if r.adjudicated {
+                break;
+            }
+            if let Some(reviewer) = &r.reviewer {
+                out.entry(reviewer.clone()).or_insert(r.decision);
+            }
+        }
+        out
+    }
+}
+
+/// Per-entry screening status, as returned by [`screening_entries`].
+#[derive(Serialize, Debug, Clone)]
+pub struct ScreeningEntryStatus {
+    /// 1-based position in the selection.
+    pub index: usize,
+    pub entry: SelectionEntry,
+    pub title_abstract: Option<ScreeningRecord>,
+    pub full_text: Option<ScreeningRecord>,
+}
+
+impl ScreeningEntryStatus {
+    /// Whether the entry is open for screening at `stage`.
+    pub fn is_eligible(&self, stage: ScreeningStage) -> bool {
+        match stage {
+            ScreeningStage::TitleAbstract => true,
+            ScreeningStage::FullText => self
+                .title_abstract
+                .as_ref()
+                .is_some_and(|r| r.decision == ScreeningDecision::Include),
+        }
+    }
+
+    /// Effective decision at `stage`.
+    pub fn record(&self, stage: ScreeningStage) -> Option<&ScreeningRecord> {
+        match stage {
+            ScreeningStage::TitleAbstract => self.title_abstract.as_ref(),
+            ScreeningStage::FullText => self.full_text.as_ref(),
+        }
+    }
+}
+
+/// Decision counts for one stage. `eligible` is every entry in the selection
+/// for title/abstract, and the title/abstract inclusions for full text.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct ScreeningProgress {
+    pub stage: ScreeningStage,
+    pub eligible: usize,
+    pub included: usize,
+    pub excluded: usize,
+    pub maybe: usize,
+    pub unscreened: usize,
+}
+
+/// Counts for a PRISMA 2020 flow diagram.
+#[derive(Serialize, Debug, Clone)]
+pub struct PrismaFlow {
+    pub selection: String,
+    /// Records identified (every entry in the selection).
+    pub identified: usize,
+    pub title_abstract: ScreeningProgress,
+    pub full_text: ScreeningProgress,
+    /// Full-text exclusions grouped by reason ("(no reason)" when unset).
+    pub full_text_exclusion_reasons: BTreeMap<String, usize>,
+    /// Studies included in the review.
+    pub included: usize,
+}
+
+// ── Storage ────────────────────────────────────────────────────────────────
+
+fn screening_path(name: &str) -> Option<PathBuf> {
+    selections_dir().map(|mut p| {
+        p.push("screening");
+        p.push(format!("{name}.json"));
+        p
+    })
+}
+
+/// Load the screening state for a selection. Returns an empty state if the
+/// selection has not been screened yet.
+pub fn load_screening(name: &str) -> Result<ScreeningState, ScreeningError> {
+    let path = screening_path(name).ok_or(SelectionError::NoDataDir)?;
+    if !path.exists() {
+        return Ok(ScreeningState { selection: name.to_string(), ..Default::default() });
+    }
+    let s = std::fs::read_to_string(&path)?;
+    Ok(serde_json::from_str(&s)?)
+}
+
+pub fn save_screening(state: &ScreeningState) -> Result<(), ScreeningError> {
+    let path = screening_path(&state.selection).ok_or(SelectionError::NoDataDir)?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let json = serde_json::to_string_pretty(state)?;
+    let tmp = path.with_extension("tmp");
+    std::fs::write(&tmp, json.as_bytes())?;
+    std::fs::rename(&tmp, &path)?;
+    Ok(())
+}
+
+/// Remove a selection's screening state, if any.
+pub fn delete_screening(name: &str) -> Result<(), ScreeningError> {
+    let path = screening_path(name).ok_or(SelectionError::NoDataDir)?;
+    if path.exists() {
+        std::fs::remove_file(&path)?;
+    }
+    Ok(())
+}
+
+/// Move screening state along with a renamed selection.
+pub fn rename_screening(old_name: &str, new_name: &str) -> Result<(), ScreeningError> {
+    let mut state = load_screening(old_name)?;
+    if state.records.is_empty() {
+        return Ok(());
+    }
+    state.selection = new_name.to_string();
+    save_screening(&state)?;
+    delete_screening(old_name)
+}
+
+// ── Entry lookup ───────────────────────────────────────────────────────────
+
+/// Stable key used to attach screening records to a selection entry.
+/// Prefers the OpenAlex ID, then Zotero key, then DOI, then lowercased title.
+pub fn entry_screening_key(entry: &SelectionEntry) -> Option<String> {
+    entry
+        .openalex_id
+        .clone()
+        .or_else(|| entry.zotero_key.clone())
+        .or_else(|| entry.doi.as_deref().map(|d| strip_doi_prefix(d).to_lowercase()))
+        .or_else(|| entry.title.as_deref().map(|t| t.trim().to_lowercase()))
+}
+
+/// Find an entry by 1-based index, Zotero key, DOI, OpenAlex ID or title substring.
+pub fn find_entry<'a>(sel: &'a Selection, input: &str) -> Result<&'a SelectionEntry, ScreeningError> {
+    Ok(&sel.entries[find_entry_index(sel, input)?])
+}
+
+// ── Decisions ──────────────────────────────────────────────────────────────
+
+/// Record a screening decision for one entry of a selection.
+///
+/// Full-text decisions are rejected unless the entry's latest title/abstract
+/// decision is `include`.
+pub fn record_decision(
+    sel_name: &str,
+    paper: &str,
+    stage: ScreeningStage,
+    decision: ScreeningDecision,
+    reason: Option<String>,
+    reviewer: Option<String>,
+) -> Result<(SelectionEntry, ScreeningRecord), ScreeningError> {
+    record_decision_inner(sel_name, paper, stage, decision, reason, reviewer, false)
+}
+
+/// Record a decision that settles a reviewer conflict. Earlier reviewer
+/// decisions at the stage no longer count towards [`screening_conflicts`].
+pub fn adjudicate_decision(
+    sel_name: &str,
+    paper: &str,
+    stage: ScreeningStage,
+    decision: ScreeningDecision,
+    reason: Option<String>,
+    reviewer: Option<String>,
+) -> Result<(SelectionEntry, ScreeningRecord), ScreeningError> {
+    record_decision_inner(sel_name, paper, stage, decision, reason, reviewer, true)
+}
+
+fn record_decision_inner(
+    sel_name: &str,
+    paper: &str,
+    stage: ScreeningStage,
+    decision: ScreeningDecision,
+    reason: Option<String>,
+    reviewer: Option<String>,
+    adjudicated: bool,
+) -> Result<(SelectionEntry, ScreeningRecord), ScreeningError> {
+    let sel = load_selection(sel_name)?;
+    let entry = find_entry(&sel, paper)?.clone();
+    let mut state = load_screening(sel_name)?;
+    let record = push_record(&mut state, &entry, stage, decision, reason, reviewer, adjudicated)?;
+    save_screening(&state)?;
+    Ok((entry, record))
+}
+
+fn push_record(
+    state: &mut ScreeningState,
+    entry: &SelectionEntry,
+    stage: ScreeningStage,
+    decision: ScreeningDecision,
+    reason: Option<String>,
+    reviewer: Option<String>,
+    adjudicated: bool,
+) -> Result<ScreeningRecord, ScreeningError> {
+    let key = entry_screening_key(entry).ok_or(ScreeningError::NoIdentifier)?;
+    if stage == ScreeningStage::FullText {
+        let ta = state.latest(entry, ScreeningStage::TitleAbstract);
+        if ta.map(|r| r.decision) != Some(ScreeningDecision::Include) {
+            let label = entry.title.clone().unwrap_or(key);
+            return Err(ScreeningError::NotEligible(label));
+        }
+    }
+    let record = ScreeningRecord {
+        stage,
+        decision,
+        reason: reason.filter(|r| !r.trim().is_empty()),
+        reviewer: reviewer.filter(|r| !r.trim().is_empty()),
+        decided_at: crate::text::iso_now(),
+        adjudicated,
+    };
+    state.records.entry(key).or_default().push(record.clone());
+    Ok(record)
+}
+
+// ── Two-reviewer workflow ──────────────────────────────────────────────────
+
+/// Entry as shown to a blinded reviewer: no authors, venue or prior decisions.
+#[derive(Serialize, Debug, Clone)]
+pub struct BlindedEntry {
+    /// Screening key; must be preserved when the decisions are imported.
+    pub key: String,
+    pub title: Option<String>,
+    pub year: Option<u32>,
+}
+
+/// Reviewers disagree on an entry's latest decision at a stage.
+#[derive(Serialize, Debug, Clone)]
+pub struct ScreeningConflict {
+    pub key: String,
+    pub title: Option<String>,
+    pub stage: ScreeningStage,
+    pub decisions: BTreeMap<String, ScreeningDecision>,
+}
+
+/// Outcome of [`import_decisions_csv`].
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct ScreeningImportReport {
+    pub imported: usize,
+    /// Rows with an empty decision column.
+    pub blank: usize,
+    /// Keys not present in the selection.
+    pub unknown_keys: Vec<String>,
+    /// Keys rejected because the entry is not open for the stage.
+    pub ineligible_keys: Vec<String>,
+    /// Reviewer disagreements after the import, for the imported stage.
+    pub conflicts: Vec<ScreeningConflict>,
+}
+
+/// Entries open for screening at `stage`, stripped of identifying metadata.
+pub fn blinded_entries(sel: &Selection, state: &ScreeningState, stage: ScreeningStage) -> Vec<BlindedEntry> {
+    screening_entries(sel, state)
+        .into_iter()
+        .filter(|s| s.is_eligible(stage))
+        .filter_map(|s| {
+            Some(BlindedEntry {
+                key: entry_screening_key(&s.entry)?,
+                title: s.entry.title,
+                year: s.entry.year,
+            })
+        })
+        .collect()
+}
+
+/// Render blinded entries as CSV with empty `decision` and `reason` columns
+/// for the reviewer to fill in.
+pub fn blinded_csv(entries: &[BlindedEntry]) -> String {
+    let mut out = String::from("key,title,year,decision,reason\n");
+    for e in entries {
+        let row = [
+            e.key.clone(),
+            e.title.clone().unwrap_or_default(),
+            e.year.map(|y| y.to_string()).unwrap_or_default(),
+            String::new(),
+            String::new(),
+        ];
+        let fields: Vec<String> = row.iter().map(|f| csv_escape(f)).collect();
+        out.push_str(&fields.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+/// Import one reviewer's decisions from CSV (as produced by [`blinded_csv`]).
+///
+/// Requires `key` and `decision` columns; `reason`, `reviewer` and `stage`
+/// columns are optional and override the corresponding arguments per row.
+/// Every row must name a reviewer so that conflicts can be detected.
+pub fn import_decisions_csv(
+    sel_name: &str,
+    csv: &str,
+    stage: ScreeningStage,
+    reviewer: Option<&str>,
+) -> Result<ScreeningImportReport, ScreeningError> {
+    let sel = load_selection(sel_name)?;
+    let mut state = load_screening(sel_name)?;
+    let rows = parse_csv(csv)?;
+    let mut rows = rows.into_iter();
+    let header: Vec<String> = rows
+        .next()
+        .ok_or_else(|| ScreeningError::Csv("empty file".into()))?
+        .into_iter()
+        .map(|h| h.trim().to_lowercase())
+        .collect();
+    let col = |name: &str| header.iter().position(|h| h == name);
+    let key_col = col("key").ok_or_else(|| ScreeningError::Csv("missing \"key\" column".into()))?;
+    let decision_col = col("decision").ok_or_else(|| ScreeningError::Csv("missing \"decision\" column".into()))?;
+    let (reason_col, reviewer_col, stage_col) = (col("reason"), col("reviewer"), col("stage"));
+
+    let by_key: BTreeMap<String, &SelectionEntry> = sel
+        .entries
+        .iter()
+        .filter_map(|e| Some((entry_screening_key(e)?, e)))
+        .collect();
+
+    let mut report = ScreeningImportReport::default();
+    for (i, row) in rows.enumerate() {
+        let cell = |c: Option<usize>| c.and_then(|c| row.get(c)).map(|v| v.trim()).filter(|v| !v.is_empty());
+        let Some(key) = cell(Some(key_col)) else { continue };
+        let Some(decision) = cell(Some(decision_col)) else {
+            report.blank += 1;
+            continue;
+        };
+        let line = i + 2;
+        let decision: ScreeningDecision = decision
+            .parse()
+            .map_err(|e| ScreeningError::Csv(format!("line {line}: {e}")))?;
+        let row_stage = match cell(stage_col) {
+            Some(s) => s.parse().map_err(|e| ScreeningError::Csv(format!("line {line}: {e}")))?,
+            None => stage,
+        };
+        let row_reviewer = cell(reviewer_col)
+            .or(reviewer)
+            .ok_or_else(|| ScreeningError::Csv(format!("line {line}: no reviewer given")))?;
+        let Some(entry) = by_key.get(key) else {
+            report.unknown_keys.push(key.to_string());
+            continue;
+        };
+        match push_record(
+            &mut state,
+            entry,
+            row_stage,
+            decision,
+            cell(reason_col).map(str::to_string),
+            Some(row_reviewer.to_string()),
+            false,
+        ) {
+            Ok(_) => report.imported += 1,
+            Err(ScreeningError::NotEligible(_)) => report.ineligible_keys.push(key.to_string()),
+            Err(e) => return Err(e),
+        }
+    }
+    save_screening(&state)?;
+    report.conflicts = screening_conflicts(&sel, &state, stage);
+    Ok(report)
+}
+
+/// Entries whose reviewers disagree at `stage` and have not been adjudicated.
+pub fn screening_conflicts(sel: &Selection, state: &ScreeningState, stage: ScreeningStage) -> Vec<ScreeningConflict> {
+    sel.entries
+        .iter()
+        .filter_map(|e| {
+            let decisions = state.reviewer_decisions(e, stage);
+            let mut values = decisions.values();
+            let first = values.next()?;
+            if values.all(|d| d == first) {
+                return None;
+            }
+            Some(ScreeningConflict {
+                key: entry_screening_key(e)?,
+                title: e.title.clone(),
+                stage,
+                decisions,
+            })
+        })
+        .collect()
+}
+
+fn csv_escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Minimal RFC 4180 parser: quoted fields, escaped quotes, CRLF or LF rows.
+fn parse_csv(input: &str) -> Result<Vec<Vec<String>>, ScreeningError> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = input.trim_start_matches('\u{feff}').chars().peekable();
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    field.push('"');
+                    chars.next();
+                }
+                '"' => in_quotes = false,
+                _ => field.push(c),
+            }
+            continue;
+        }
+        match c {
+            '"' if field.is_empty() => in_quotes = true,
+            ',' => row.push(std::mem::take(&mut field)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            _ => field.push(c),
+        }
+    }
+    if in_quotes {
+        return Err(ScreeningError::Csv("unterminated quoted field".into()));
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    rows.retain(|r| !(r.len() == 1 && r[0].trim().is_empty()));
+    Ok(rows)
+}
+
+// ── Progress & PRISMA ──────────────────────────────────────────────────────
+
+/// Effective title/abstract and full-text decisions for every entry.
+pub fn screening_entries(sel: &Selection, state: &ScreeningState) -> Vec<ScreeningEntryStatus> {
+    sel.entries
+        .iter()
+        .enumerate()
+        .map(|(i, e)| ScreeningEntryStatus {
+            index: i + 1,
+            entry: e.clone(),
+            title_abstract: state.latest(e, ScreeningStage::TitleAbstract).cloned(),
+            full_text: state.latest(e, ScreeningStage::FullText).cloned(),
+        })
+        .collect()
+}
+
+/// Decision counts for a stage.
+pub fn screening_progress(statuses: &[ScreeningEntryStatus], stage: ScreeningStage) -> ScreeningProgress {
+    let mut p = ScreeningProgress {
+        stage,
+        eligible: 0,
+        included: 0,
+        excluded: 0,
+        maybe: 0,
+        unscreened: 0,
+    };
+    for s in statuses.iter().filter(|s| s.is_eligible(stage)) {
+        let record = s.record(stage);
+        p.eligible += 1;
+        match record.map(|r| r.decision) {
+            Some(ScreeningDecision::Include) => p.included += 1,
+            Some(ScreeningDecision::Exclude) => p.excluded += 1,
+            Some(ScreeningDecision::Maybe) => p.maybe += 1,
+            None => p.unscreened += 1,
+        }
+    }
+    p
+}
+
+/// Build PRISMA flow counts for a selection.
+pub fn prisma_flow(sel_name: &str) -> Result<PrismaFlow, ScreeningError> {
+    let sel = load_selection(sel_name)?;
+    let state = load_screening(sel_name)?;
+    let statuses = screening_entries(&sel, &state);
+
+    let mut reasons: BTreeMap<String, usize> = BTreeMap::new();
+    for r in statuses
+        .iter()
+        .filter(|s| s.is_eligible(ScreeningStage::FullText))
+        .filter_map(|s| s.full_text.as_ref())
+        .filter(|r| r.decision == ScreeningDecision::Exclude)
+    {
+        let reason = r.reason.clone().unwrap_or_else(|| "(no reason)".to_string());
+        *reasons.entry(reason).or_default() += 1;
+    }
+
+    let title_abstract = screening_progress(&statuses, ScreeningStage::TitleAbstract);
+    let full_text = screening_progress(&statuses, ScreeningStage::FullText);
+    Ok(PrismaFlow {
+        selection: sel.name,
+        identified: statuses.len(),
+        included: full_text.included,
+        title_abstract,
+        full_text,
+        full_text_exclusion_reasons: reasons,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_csv_quoted_fields() {
+        let rows = parse_csv("key,title\r\nW1,\"A, B \"\"C\"\"\"\nW2,\"multi\nline\"\n").unwrap();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[1], vec!["W1", "A, B \"C\""]);
+        assert_eq!(rows[2], vec!["W2", "multi\nline"]);
+    }
+
+    #[test]
+    fn test_parse_csv_unterminated_quote() {
+        assert!(parse_csv("key\n\"W1\n").is_err());
+    }
+
+    #[test]
+    fn test_csv_escape_round_trip() {
+        let title = "Deep \"nets\", revisited";
+        let line = format!("W1,{}\n", csv_escape(title));
+        assert_eq!(parse_csv(&line).unwrap()[0][1], title);
+        assert_eq!(csv_escape("plain"), "plain");
+    }
+}
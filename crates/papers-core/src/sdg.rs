@@ -0,0 +1,237 @@
+//! UN Sustainable Development Goals: lookup for the `sdg` work filter and
+//! per-selection aggregation.
+//!
+//! OpenAlex tags works with SDGs using `https://metadata.un.org/sdg/N` IDs.
+//! Users think in goal numbers ("SDG 3") or names ("climate action"), so the
+//! shorthand filter maps those to IDs before sending.
+
+use papers_openalex::Work;
+use serde::Serialize;
+
+/// Prefix of the SDG IDs OpenAlex uses in `sustainable_development_goals.id`.
+pub const SDG_ID_PREFIX: &str = "https://metadata.un.org/sdg/";
+
+/// One of the 17 Sustainable Development Goals.
+#[derive(Debug, Clone, Copy)]
+pub struct Sdg {
+    /// Goal number, 1–17.
+    pub number: u8,
+    /// Short name as OpenAlex displays it.
+    pub name: &'static str,
+}
+
+impl Sdg {
+    /// OpenAlex ID URI (e.g. `"https://metadata.un.org/sdg/3"`).
+    pub fn id(&self) -> String {
+        format!("{SDG_ID_PREFIX}{}", self.number)
+    }
+}
+
+/// All 17 goals in order.
+pub static SDGS: &[Sdg] = &[
+    Sdg { number: 1, name: "No poverty" },
+    Sdg { number: 2, name: "Zero hunger" },
+    Sdg { number: 3, name: "Good health and well-being" },
+    Sdg { number: 4, name: "Quality education" },
+    Sdg { number: 5, name: "Gender equality" },
+    Sdg { number: 6, name: "Clean water and sanitation" },
+    Sdg { number: 7, name: "Affordable and clean energy" },
+    Sdg { number: 8, name: "Decent work and economic growth" },
+    Sdg { number: 9, name: "Industry, innovation and infrastructure" },
+    Sdg { number: 10, name: "Reduced inequalities" },
+    Sdg { number: 11, name: "Sustainable cities and communities" },
+    Sdg { number: 12, name: "Responsible consumption and production" },
+    Sdg { number: 13, name: "Climate action" },
+    Sdg { number: 14, name: "Life below water" },
+    Sdg { number: 15, name: "Life on land" },
+    Sdg { number: 16, name: "Peace, justice and strong institutions" },
+    Sdg { number: 17, name: "Partnerships for the goals" },
+];
+
+/// Look up a goal by number (`3`, `sdg3`, `SDG 3`), full ID URI, or name.
+pub fn find_sdg(value: &str) -> Option<&'static Sdg> {
+    let key = value.trim().to_lowercase();
+    let key = key.strip_prefix(SDG_ID_PREFIX).unwrap_or(&key);
+    let digits = key.strip_prefix("sdg").unwrap_or(key).trim_start_matches([' ', '-', '_']);
+    if let Ok(n) = digits.parse::<u8>() {
+        return SDGS.iter().find(|s| s.number == n);
+    }
+    let key = normalize_name(key);
+    SDGS.iter().find(|s| normalize_name(s.name) == key)
+}
+
+/// Goals whose name contains `value` (for "did you mean" hints and partial
+/// names such as "climate" or "hunger").
+pub fn sdgs_matching(value: &str) -> Vec<&'static Sdg> {
+    let key = normalize_name(value);
+    if key.is_empty() {
+        return Vec::new();
+    }
+    SDGS.iter().filter(|s| normalize_name(s.name).contains(&key)).collect()
+}
+
+/// The goal number for an OpenAlex SDG ID URI.
+pub fn sdg_number(id: &str) -> Option<u8> {
+    id.strip_prefix(SDG_ID_PREFIX)?.parse().ok()
+}
+
+fn normalize_name(s: &str) -> String {
+    s.to_lowercase()
+        .replace('&', "and")
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .collect()
+}
+
+// ── Selection report ─────────────────────────────────────────────────────
+
+/// Works in a selection tagged with one goal.
+#[derive(Debug, Clone, Serialize)]
+pub struct SdgReportGoal {
+    pub number: u8,
+    pub id: String,
+    pub name: &'static str,
+    /// Number of works tagged with this goal.
+    pub works: usize,
+    /// Share of resolved works tagged with this goal (0.0–1.0).
+    pub share: f64,
+    /// Mean OpenAlex relevance score across the tagged works.
+    pub mean_score: f64,
+    /// OpenAlex IDs of the tagged works, highest score first.
+    pub work_ids: Vec<String>,
+}
+
+/// SDG coverage of a selection, most-tagged goal first.
+#[derive(Debug, Clone, Serialize)]
+pub struct SdgReport {
+    pub selection: String,
+    /// Entries in the selection.
+    pub total: usize,
+    /// Entries whose OpenAlex record was fetched.
+    pub resolved: usize,
+    /// Resolved works with at least one SDG tag.
+    pub tagged: usize,
+    /// Entries that could not be looked up in OpenAlex (title or identifier).
+    pub unresolved: Vec<String>,
+    pub goals: Vec<SdgReportGoal>,
+}
+
+/// Aggregate the SDG tags of `works` (the resolved entries of a selection).
+pub fn sdg_report(
+    selection: &str,
+    total: usize,
+    works: &[Work],
+    unresolved: Vec<String>,
+) -> SdgReport {
+    let mut tagged_works: Vec<Vec<(String, f64)>> = vec![Vec::new(); SDGS.len()];
+    let mut tagged = 0;
+    for work in works {
+        let tags = work.sustainable_development_goals.as_deref().unwrap_or_default();
+        let mut any = false;
+        for tag in tags {
+            let Some(n) = tag.id.as_deref().and_then(sdg_number) else {
+                continue;
+            };
+            if !(1..=SDGS.len() as u8).contains(&n) {
+                continue;
+            }
+            tagged_works[n as usize - 1].push((work.id.clone(), tag.score.unwrap_or(0.0)));
+            any = true;
+        }
+        if any {
+            tagged += 1;
+        }
+    }
+
+    let mut goals: Vec<SdgReportGoal> = SDGS
+        .iter()
+        .zip(tagged_works)
+        .filter(|(_, w)| !w.is_empty())
+        .map(|(sdg, mut w)| {
+            w.sort_by(|a, b| b.1.total_cmp(&a.1));
+            let mean_score = w.iter().map(|(_, s)| s).sum::<f64>() / w.len() as f64;
+            SdgReportGoal {
+                number: sdg.number,
+                id: sdg.id(),
+                name: sdg.name,
+                works: w.len(),
+                share: w.len() as f64 / works.len() as f64,
+                mean_score,
+                work_ids: w.into_iter().map(|(id, _)| id).collect(),
+            }
+        })
+        .collect();
+    goals.sort_by(|a, b| b.works.cmp(&a.works).then(a.number.cmp(&b.number)));
+
+    SdgReport {
+        selection: selection.to_string(),
+        total,
+        resolved: works.len(),
+        tagged,
+        unresolved,
+        goals,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn work(id: &str, sdgs: &[(u8, f64)]) -> Work {
+        let tags: Vec<_> = sdgs
+            .iter()
+            .map(|(n, score)| {
+                serde_json::json!({
+                    "id": format!("{SDG_ID_PREFIX}{n}"),
+                    "display_name": SDGS[*n as usize - 1].name,
+                    "score": score,
+                })
+            })
+            .collect();
+        serde_json::from_value(serde_json::json!({
+            "id": format!("https://openalex.org/{id}"),
+            "sustainable_development_goals": tags,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn find_by_number_id_and_name() {
+        assert_eq!(find_sdg("3").unwrap().number, 3);
+        assert_eq!(find_sdg("SDG 13").unwrap().number, 13);
+        assert_eq!(find_sdg("sdg-7").unwrap().number, 7);
+        assert_eq!(find_sdg("https://metadata.un.org/sdg/14").unwrap().number, 14);
+        assert_eq!(find_sdg("climate action").unwrap().number, 13);
+        assert_eq!(find_sdg("Peace justice & strong institutions").unwrap().number, 16);
+        assert!(find_sdg("18").is_none());
+        assert!(find_sdg("climate").is_none());
+    }
+
+    #[test]
+    fn partial_names_match() {
+        let numbers: Vec<_> = sdgs_matching("water").iter().map(|s| s.number).collect();
+        assert_eq!(numbers, vec![6, 14]);
+        assert_eq!(sdgs_matching("hunger")[0].number, 2);
+        assert!(sdgs_matching("").is_empty());
+    }
+
+    #[test]
+    fn report_counts_and_orders_goals() {
+        let works = vec![
+            work("W1", &[(3, 0.9), (13, 0.5)]),
+            work("W2", &[(13, 0.8)]),
+            work("W3", &[]),
+        ];
+        let report = sdg_report("s", 4, &works, vec!["Missing".to_string()]);
+        assert_eq!(report.total, 4);
+        assert_eq!(report.resolved, 3);
+        assert_eq!(report.tagged, 2);
+        assert_eq!(report.goals.len(), 2);
+        let climate = &report.goals[0];
+        assert_eq!(climate.number, 13);
+        assert_eq!(climate.works, 2);
+        assert!((climate.mean_score - 0.65).abs() < 1e-9);
+        assert_eq!(climate.work_ids, vec!["https://openalex.org/W2", "https://openalex.org/W1"]);
+        assert_eq!(report.goals[1].number, 3);
+    }
+}
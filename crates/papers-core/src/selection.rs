@@ -47,6 +47,12 @@ pub struct SelectionEntry {
     /// Used by `selection sync` to create Zotero items with the correct itemType.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub work_type: Option<String>,
+    /// Why the paper is in the selection, e.g. "baseline for section 4".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    /// User tags for filtering and grouping entries (compared case-insensitively).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -58,13 +64,21 @@ pub struct Selection {
 #[derive(Serialize, Deserialize, Default)]
 pub struct SelectionState {
     pub active: Option<String>,
+    /// Selection the MCP DB tools are scoped to when a call names no
+    /// selection or paper.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub db_scope: Option<String>,
 }
 
 // ── Storage paths ──────────────────────────────────────────────────────────
 
 /// Returns the selections directory, allowing `PAPERS_DATA_DIR` env var override
 /// (used by tests). Falls back to `dirs::data_dir()/papers/selections`.
+/// Inside a [`crate::tenant::scope`] this is the tenant's `selections/` instead.
 pub fn selections_dir() -> Option<PathBuf> {
+    if let Some(dir) = crate::tenant::current_data_dir() {
+        return Some(dir.join("selections"));
+    }
     if let Ok(override_dir) = std::env::var("PAPERS_DATA_DIR") {
         let mut p = PathBuf::from(override_dir);
         p.push("papers");
@@ -178,8 +192,29 @@ pub fn save_state(s: &SelectionState) -> Result<(), SelectionError> {
     Ok(())
 }
 
+/// Selection used when none is active (see [`crate::settings`]).
+pub const DEFAULT_SELECTION_ENV: &str = "PAPERS_SELECTION";
+
+/// The active selection, else the `PAPERS_SELECTION` default if it exists.
 pub fn active_selection_name() -> Option<String> {
-    load_state().active
+    load_state().active.or_else(|| {
+        std::env::var(DEFAULT_SELECTION_ENV)
+            .ok()
+            .map(|name| name.trim().to_string())
+            .filter(|name| !name.is_empty() && load_selection(name).is_ok())
+    })
+}
+
+/// The default DB scope, unless its selection has since been deleted.
+pub fn db_scope_name() -> Option<String> {
+    load_state().db_scope.filter(|name| load_selection(name).is_ok())
+}
+
+/// Set (or with `None`, clear) the default DB scope.
+pub fn set_db_scope(name: Option<String>) -> Result<(), SelectionError> {
+    let mut state = load_state();
+    state.db_scope = name;
+    save_state(&state)
 }
 
 // ── CRUD ───────────────────────────────────────────────────────────────────
@@ -203,12 +238,14 @@ pub fn save_selection(sel: &Selection) -> Result<(), SelectionError> {
     Ok(())
 }
 
+/// Delete a selection file along with its screening state, if any.
 pub fn delete_selection(name: &str) -> Result<(), SelectionError> {
     let path = selection_path(name).ok_or(SelectionError::NoDataDir)?;
     if !path.exists() {
         return Err(SelectionError::NotFound(name.to_string()));
     }
     std::fs::remove_file(&path)?;
+    let _ = crate::screening::delete_screening(name);
     Ok(())
 }
 
@@ -240,6 +277,16 @@ pub fn entry_matches_doi(entry: &SelectionEntry, doi: &str) -> bool {
         .unwrap_or(false)
 }
 
+/// Whether `sel` already has an entry for the same paper as `entry` (by
+/// Zotero key, OpenAlex ID, or DOI).
+pub fn contains_entry(sel: &Selection, entry: &SelectionEntry) -> bool {
+    sel.entries.iter().any(|e| {
+        entry.zotero_key.as_deref().is_some_and(|k| entry_matches_key(e, k))
+            || entry.openalex_id.as_deref().is_some_and(|id| entry_matches_openalex(e, id))
+            || entry.doi.as_deref().is_some_and(|d| entry_matches_doi(e, d))
+    })
+}
+
 /// Match a removal input against an entry (by key, OA ID, DOI, or title substring).
 pub fn entry_matches_remove_input(entry: &SelectionEntry, input: &str) -> bool {
     if crate::zotero::looks_like_zotero_key(input) && entry_matches_key(entry, input) {
@@ -262,6 +309,115 @@ pub fn entry_matches_remove_input(entry: &SelectionEntry, input: &str) -> bool {
     false
 }
 
+/// Position of the entry matching `input`: a 1-based index, Zotero key, DOI,
+/// OpenAlex ID or title substring.
+pub fn find_entry_index(sel: &Selection, input: &str) -> Result<usize, SelectionError> {
+    if let Ok(idx) = input.parse::<usize>() {
+        return idx
+            .checked_sub(1)
+            .filter(|&i| i < sel.entries.len())
+            .ok_or(SelectionError::ItemNotFound);
+    }
+    sel.entries
+        .iter()
+        .position(|e| entry_matches_remove_input(e, input))
+        .ok_or(SelectionError::ItemNotFound)
+}
+
+// ── Notes & tags ───────────────────────────────────────────────────────────
+
+/// An entry with its 1-based position in the selection.
+#[derive(Serialize, Debug)]
+pub struct IndexedEntry<'a> {
+    pub index: usize,
+    #[serde(flatten)]
+    pub entry: &'a SelectionEntry,
+}
+
+/// Entries sharing one tag. `tag` is `None` for the untagged group.
+#[derive(Serialize, Debug)]
+pub struct TagGroup<'a> {
+    pub tag: Option<String>,
+    pub entries: Vec<IndexedEntry<'a>>,
+}
+
+pub fn entry_has_tag(entry: &SelectionEntry, tag: &str) -> bool {
+    let tag = tag.trim();
+    entry.tags.iter().any(|t| t.eq_ignore_ascii_case(tag))
+}
+
+/// Set the note on the entry of selection `name` matching `input` (see
+/// [`find_entry_index`]), or clear it when `note` is `None` or blank.
+pub fn annotate_entry(name: &str, input: &str, note: Option<&str>) -> Result<SelectionEntry, SelectionError> {
+    let mut sel = load_selection(name)?;
+    let idx = find_entry_index(&sel, input)?;
+    let entry = &mut sel.entries[idx];
+    entry.note = note.map(str::trim).filter(|n| !n.is_empty()).map(str::to_string);
+    let updated = entry.clone();
+    save_selection(&sel)?;
+    Ok(updated)
+}
+
+/// Add and remove tags on the entry of selection `name` matching `input`.
+/// Tags are trimmed and compared case-insensitively; an existing tag keeps
+/// its original spelling. Removals apply after additions.
+pub fn tag_entry(
+    name: &str,
+    input: &str,
+    add: &[String],
+    remove: &[String],
+) -> Result<SelectionEntry, SelectionError> {
+    let mut sel = load_selection(name)?;
+    let idx = find_entry_index(&sel, input)?;
+    let entry = &mut sel.entries[idx];
+    for tag in add.iter().map(|t| t.trim()).filter(|t| !t.is_empty()) {
+        if !entry_has_tag(entry, tag) {
+            entry.tags.push(tag.to_string());
+        }
+    }
+    entry.tags.retain(|t| !remove.iter().any(|r| r.trim().eq_ignore_ascii_case(t)));
+    let updated = entry.clone();
+    save_selection(&sel)?;
+    Ok(updated)
+}
+
+/// Entries carrying `tag`, with their positions.
+pub fn entries_with_tag<'a>(sel: &'a Selection, tag: &str) -> Vec<IndexedEntry<'a>> {
+    sel.entries
+        .iter()
+        .enumerate()
+        .filter(|(_, e)| entry_has_tag(e, tag))
+        .map(|(i, entry)| IndexedEntry { index: i + 1, entry })
+        .collect()
+}
+
+/// Entries grouped by tag, groups sorted by tag (case-insensitively) with
+/// untagged entries last. An entry with several tags appears in each group.
+pub fn group_by_tag(sel: &Selection) -> Vec<TagGroup<'_>> {
+    let mut groups: Vec<TagGroup<'_>> = Vec::new();
+    let mut untagged = Vec::new();
+    for (i, entry) in sel.entries.iter().enumerate() {
+        if entry.tags.is_empty() {
+            untagged.push(IndexedEntry { index: i + 1, entry });
+        }
+        for tag in &entry.tags {
+            let item = IndexedEntry { index: i + 1, entry };
+            match groups
+                .iter_mut()
+                .find(|g| g.tag.as_deref().is_some_and(|t| t.eq_ignore_ascii_case(tag)))
+            {
+                Some(group) => group.entries.push(item),
+                None => groups.push(TagGroup { tag: Some(tag.clone()), entries: vec![item] }),
+            }
+        }
+    }
+    groups.sort_by_key(|g| g.tag.as_deref().map(str::to_lowercase));
+    if !untagged.is_empty() {
+        groups.push(TagGroup { tag: None, entries: untagged });
+    }
+    groups
+}
+
 // ── Input type detection ───────────────────────────────────────────────────
 
 pub fn looks_like_doi(input: &str) -> bool {
@@ -295,10 +451,12 @@ fn normalize_doi(doi: &str) -> String {
 
 /// Resolve a paper input string to a SelectionEntry.
 /// Tries Zotero first (if available), then OpenAlex. Merges metadata from both.
+/// A DOI that OpenAlex doesn't know is looked up in Crossref, if given.
 pub async fn resolve_paper(
     input: &str,
     client: &papers_openalex::OpenAlexClient,
     zotero: Option<&papers_zotero::ZoteroClient>,
+    crossref: Option<&papers_crossref::CrossrefClient>,
 ) -> Result<SelectionEntry, SelectionError> {
     let input = input.trim();
     let mut entry = SelectionEntry {
@@ -311,6 +469,8 @@ pub async fn resolve_paper(
         issn: None,
         isbn: None,
         work_type: None,
+        note: None,
+        tags: Vec::new(),
     };
 
     let is_zotero_key = crate::zotero::looks_like_zotero_key(input);
@@ -382,6 +542,13 @@ pub async fn resolve_paper(
                 }
             }
         }
+    } else if let (true, Some(cr)) = (is_doi, crossref) {
+        // Step 3b: OpenAlex lags behind DOI registration (recent preprints
+        // especially), so ask Crossref. Step 2 already searched Zotero by
+        // this DOI, so there is nothing to retry there.
+        if let Ok(work) = cr.get_work(strip_doi_prefix(input)).await {
+            fill_from_crossref_work(&mut entry, &work);
+        }
     }
 
     // Step 5: Fail if nothing at all was resolved
@@ -396,6 +563,129 @@ pub async fn resolve_paper(
     Ok(entry)
 }
 
+/// Candidates offered when a free-text add is ambiguous.
+pub const DEFAULT_CANDIDATE_LIMIT: usize = 5;
+
+/// Plausible matches for a free-text paper query, best first.
+///
+/// Zotero keys, DOIs and OpenAlex IDs are unambiguous and yield no candidates;
+/// use [`resolve_paper`] for those. Otherwise the Zotero library (if configured)
+/// and OpenAlex are both searched, and an OpenAlex hit sharing a DOI with a
+/// Zotero hit is merged into it rather than listed twice.
+pub async fn paper_candidates(
+    input: &str,
+    client: &papers_openalex::OpenAlexClient,
+    zotero: Option<&papers_zotero::ZoteroClient>,
+    limit: usize,
+) -> Vec<SelectionEntry> {
+    let input = input.trim();
+    if crate::zotero::looks_like_zotero_key(input)
+        || looks_like_doi(input)
+        || looks_like_openalex_work_id(input)
+    {
+        return Vec::new();
+    }
+
+    let mut candidates: Vec<SelectionEntry> = Vec::new();
+    if let Some(z) = zotero {
+        let params = ItemListParams::builder().q(input).limit(limit as u32).build();
+        if let Ok(resp) = z.list_top_items(&params).await {
+            for item in resp.items {
+                let mut entry = empty_entry();
+                entry.zotero_key = Some(item.key.clone());
+                fill_from_zotero_item(&mut entry, &item);
+                candidates.push(entry);
+            }
+        }
+    }
+
+    let params = ListParams {
+        search: Some(input.to_string()),
+        per_page: Some(limit as u32),
+        ..Default::default()
+    };
+    if let Ok(resp) = client.list_works(&params).await {
+        for work in resp.results {
+            let bare = work.doi.as_deref().map(strip_doi_prefix);
+            let existing = bare.and_then(|d| candidates.iter_mut().find(|c| entry_matches_doi(c, d)));
+            match existing {
+                Some(entry) => fill_from_oa_work(entry, &work),
+                None => {
+                    let mut entry = empty_entry();
+                    fill_from_oa_work(&mut entry, &work);
+                    candidates.push(entry);
+                }
+            }
+        }
+    }
+    candidates.truncate(limit);
+    candidates
+}
+
+/// One-line description of a candidate, e.g.
+/// `Attention Is All You Need — Ashish Vaswani et al. (2017)`.
+pub fn candidate_label(entry: &SelectionEntry) -> String {
+    let mut label = entry
+        .title
+        .clone()
+        .or_else(|| entry.doi.clone())
+        .or_else(|| entry.openalex_id.clone())
+        .or_else(|| entry.zotero_key.clone())
+        .unwrap_or_default();
+    if let Some(first) = entry.authors.as_ref().and_then(|a| a.first()) {
+        let more = entry.authors.as_ref().is_some_and(|a| a.len() > 1);
+        label.push_str(&format!(" — {first}{}", if more { " et al." } else { "" }));
+    }
+    if let Some(year) = entry.year {
+        label.push_str(&format!(" ({year})"));
+    }
+    if entry.zotero_key.is_some() {
+        label.push_str(" [in Zotero]");
+    }
+    label
+}
+
+/// Complete a candidate picked from [`paper_candidates`] into a full entry.
+///
+/// The candidate is re-resolved by its DOI or OpenAlex ID so it gets the same
+/// cross-referencing as a direct add; if that fails it is returned as is.
+pub async fn resolve_candidate(
+    candidate: SelectionEntry,
+    client: &papers_openalex::OpenAlexClient,
+    zotero: Option<&papers_zotero::ZoteroClient>,
+) -> SelectionEntry {
+    let id = candidate.doi.as_deref().or(candidate.openalex_id.as_deref());
+    let resolved = match id {
+        Some(id) => resolve_paper(id, client, zotero, None).await.ok(),
+        None => None,
+    };
+    match resolved {
+        Some(mut entry) => {
+            if entry.zotero_key.is_none() {
+                entry.zotero_key = candidate.zotero_key;
+            }
+            entry
+        }
+        None => candidate,
+    }
+}
+
+pub(crate) fn empty_entry() -> SelectionEntry {
+    SelectionEntry {
+        zotero_key: None,
+        openalex_id: None,
+        doi: None,
+        title: None,
+        authors: None,
+        year: None,
+        issn: None,
+        isbn: None,
+        work_type: None,
+        note: None,
+        tags: Vec::new(),
+    }
+}
+
 async fn resolve_via_openalex(
     input: &str,
     client: &papers_openalex::OpenAlexClient,
@@ -533,6 +823,115 @@ pub fn fill_from_oa_work(entry: &mut SelectionEntry, work: &papers_openalex::Wor
     }
 }
 
+/// Outcome of adding many works to a selection at once.
+#[derive(Serialize, Debug)]
+pub struct BulkAddSummary {
+    pub selection: String,
+    /// Works matching the query; more than `fetched` when the limit cut it short.
+    pub matched: u64,
+    pub fetched: usize,
+    /// Labels of the entries added, in query order.
+    pub added: Vec<String>,
+    /// Labels of fetched works that were already in the selection.
+    pub duplicates: Vec<String>,
+    /// Inputs that could not be resolved to a paper ([`add_papers`] only).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub unresolved: Vec<String>,
+}
+
+/// Append an entry built from each OpenAlex work to `sel`, skipping works
+/// already present (see [`contains_entry`]), including repeats within `works`.
+/// Entries carry OpenAlex metadata only; no Zotero lookup is made.
+pub fn add_works(sel: &mut Selection, works: &[papers_openalex::Work]) -> BulkAddSummary {
+    let mut added = Vec::new();
+    let mut duplicates = Vec::new();
+    for work in works {
+        let mut entry = empty_entry();
+        fill_from_oa_work(&mut entry, work);
+        let label = candidate_label(&entry);
+        if contains_entry(sel, &entry) {
+            duplicates.push(label);
+        } else {
+            sel.entries.push(entry);
+            added.push(label);
+        }
+    }
+    BulkAddSummary {
+        selection: sel.name.clone(),
+        matched: works.len() as u64,
+        fetched: works.len(),
+        added,
+        duplicates,
+        unresolved: Vec::new(),
+    }
+}
+
+/// Resolve each input as [`resolve_paper`] does (DOI, OpenAlex ID, Zotero
+/// key, or title) and append it to `sel`, skipping papers already present,
+/// including repeats within `inputs`. Inputs that resolve to nothing are
+/// listed in `unresolved`; `matched` counts the (non-blank) inputs and
+/// `fetched` those resolved. The caller saves the selection.
+pub async fn add_papers(
+    sel: &mut Selection,
+    inputs: &[String],
+    client: &papers_openalex::OpenAlexClient,
+    zotero: Option<&papers_zotero::ZoteroClient>,
+    crossref: Option<&papers_crossref::CrossrefClient>,
+) -> BulkAddSummary {
+    let inputs: Vec<&str> = inputs.iter().map(|i| i.trim()).filter(|i| !i.is_empty()).collect();
+    let mut summary = BulkAddSummary {
+        selection: sel.name.clone(),
+        matched: inputs.len() as u64,
+        fetched: 0,
+        added: Vec::new(),
+        duplicates: Vec::new(),
+        unresolved: Vec::new(),
+    };
+    for input in inputs {
+        let Ok(entry) = resolve_paper(input, client, zotero, crossref).await else {
+            summary.unresolved.push(input.to_string());
+            continue;
+        };
+        summary.fetched += 1;
+        let label = candidate_label(&entry);
+        if contains_entry(sel, &entry) {
+            summary.duplicates.push(label);
+        } else {
+            sel.entries.push(entry);
+            summary.added.push(label);
+        }
+    }
+    summary
+}
+
+/// Fill the fields of `entry` that are still empty from a Crossref record.
+pub fn fill_from_crossref_work(entry: &mut SelectionEntry, cr: &papers_crossref::CrossrefWork) {
+    if entry.doi.is_none() {
+        entry.doi = Some(cr.doi.clone());
+    }
+    if entry.title.is_none() {
+        entry.title = cr.title();
+    }
+    if entry.authors.is_none() {
+        let authors: Vec<String> = cr.author.iter().filter_map(|a| a.display_name()).collect();
+        if !authors.is_empty() {
+            entry.authors = Some(authors);
+        }
+    }
+    if entry.year.is_none() {
+        entry.year = cr.year().and_then(|y| u32::try_from(y).ok());
+    }
+    if entry.issn.is_none() && !cr.issn.is_empty() {
+        entry.issn = Some(cr.issn.clone());
+    }
+    if entry.isbn.is_none() && !cr.isbn.is_empty() {
+        entry.isbn = Some(cr.isbn.clone());
+    }
+    if entry.work_type.is_none() {
+        entry.work_type = cr.r#type.clone();
+    }
+}
+
 /// Map an OpenAlex/Crossref work type to a Zotero itemType string.
 ///
 /// Checks `type_crossref` first (more granular), then falls back to `type`.
@@ -0,0 +1,275 @@
+//! Moving papers between selections and Zotero collections.
+//!
+//! [`sync_selection_to_collection`] files every entry of a selection into a
+//! collection, creating the collection and any Zotero items that are missing
+//! (from OpenAlex metadata, via [`crate::zotero::create_item_from_work`]).
+//! [`selection_from_collection`] goes the other way and adds a collection's
+//! items to a selection. Both only add: nothing is removed on either side.
+
+use std::collections::HashSet;
+
+use papers_openalex::OpenAlexClient;
+use papers_zotero::{CollectionListParams, ItemListParams, ZoteroClient, ZoteroError};
+use serde::Serialize;
+
+use crate::selection::{
+    BulkAddSummary, Selection, SelectionError, candidate_label, contains_entry, empty_entry, fill_from_zotero_item,
+    load_selection, save_selection, validate_name,
+};
+use crate::zotero::{ZoteroWriteError, create_item_from_work, looks_like_zotero_key, resolve_collection_key};
+
+#[derive(Debug, thiserror::Error)]
+pub enum SelectionZoteroError {
+    #[error(transparent)]
+    Selection(#[from] SelectionError),
+    #[error("Zotero error: {0}")]
+    Zotero(#[from] ZoteroError),
+    #[error("could not create collection {name:?}: {message}")]
+    CreateCollection { name: String, message: String },
+}
+
+/// Page size for listing collections and collection items.
+const PAGE_SIZE: u32 = 100;
+
+/// An entry that could not be put into the collection.
+#[derive(Debug, Clone, Serialize)]
+pub struct EntryFailure {
+    pub entry: String,
+    pub error: String,
+}
+
+/// Outcome of [`sync_selection_to_collection`]. Entries are given as labels
+/// (see [`candidate_label`]).
+#[derive(Debug, Clone, Serialize)]
+pub struct CollectionSyncReport {
+    pub selection: String,
+    pub collection_key: String,
+    pub collection_name: String,
+    pub created_collection: bool,
+    /// Entries for which a Zotero item was created, already in the collection.
+    pub created_items: Vec<String>,
+    /// Entries without a Zotero key that turned out to be in the library already.
+    pub linked: Vec<String>,
+    /// Existing items added to the collection.
+    pub added: Vec<String>,
+    /// Items that were in the collection already.
+    pub already_in_collection: usize,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub failed: Vec<EntryFailure>,
+}
+
+/// Outcome of [`selection_from_collection`].
+#[derive(Debug, Serialize)]
+pub struct CollectionImport {
+    pub collection_key: String,
+    pub collection_name: String,
+    pub created_selection: bool,
+    #[serde(flatten)]
+    pub summary: BulkAddSummary,
+}
+
+/// The collection with key `input`, else the one named `input`
+/// (case-insensitive, exact), else a new top-level collection of that name.
+/// Returns its key, its name, and whether it was created.
+async fn find_or_create_collection(
+    zotero: &ZoteroClient,
+    input: &str,
+) -> Result<(String, String, bool), SelectionZoteroError> {
+    if looks_like_zotero_key(input) {
+        let collection = zotero.get_collection(input).await?;
+        return Ok((collection.key, collection.data.name, false));
+    }
+    let mut start = 0;
+    loop {
+        let page = zotero
+            .list_collections(&CollectionListParams { limit: Some(PAGE_SIZE), start: Some(start), ..Default::default() })
+            .await?;
+        let fetched = page.items.len();
+        if let Some(c) = page.items.into_iter().find(|c| c.data.name.eq_ignore_ascii_case(input)) {
+            return Ok((c.key, c.data.name, false));
+        }
+        start += PAGE_SIZE;
+        if fetched < PAGE_SIZE as usize || page.total_results.is_none_or(|total| u64::from(start) >= total) {
+            break;
+        }
+    }
+    let resp = zotero.for_writes().create_collections(vec![serde_json::json!({ "name": input })]).await?;
+    match resp.successful_keys().into_iter().next() {
+        Some(key) => Ok((key, input.to_string(), true)),
+        None => Err(SelectionZoteroError::CreateCollection {
+            name: input.to_string(),
+            message: resp.failed.values().next().map_or_else(|| "no key returned".to_string(), |f| f.message.clone()),
+        }),
+    }
+}
+
+/// Put every entry of selection `name` into a Zotero collection: `collection`
+/// (a key, or a name matched exactly), or one named after the selection.
+/// A missing collection is created. Entries without a Zotero key get an item
+/// created from OpenAlex, unless the library already has the work, in which
+/// case they are linked to it. New Zotero keys are saved to the selection.
+pub async fn sync_selection_to_collection(
+    openalex: &OpenAlexClient,
+    zotero: &ZoteroClient,
+    name: &str,
+    collection: Option<&str>,
+) -> Result<CollectionSyncReport, SelectionZoteroError> {
+    let mut sel = load_selection(name)?;
+    let (collection_key, collection_name, created_collection) =
+        find_or_create_collection(zotero, collection.unwrap_or(name)).await?;
+    let mut report = CollectionSyncReport {
+        selection: sel.name.clone(),
+        collection_key: collection_key.clone(),
+        collection_name,
+        created_collection,
+        created_items: Vec::new(),
+        linked: Vec::new(),
+        added: Vec::new(),
+        already_in_collection: 0,
+        failed: Vec::new(),
+    };
+
+    let mut created_keys = HashSet::new();
+    for entry in &mut sel.entries {
+        if entry.zotero_key.is_some() {
+            continue;
+        }
+        let label = candidate_label(entry);
+        let Some(id) = entry.openalex_id.clone().or_else(|| entry.doi.clone()) else {
+            report.failed.push(EntryFailure { entry: label, error: "no DOI or OpenAlex ID to create an item from".into() });
+            continue;
+        };
+        match create_item_from_work(openalex, zotero, &id, std::slice::from_ref(&collection_key), &[], false).await {
+            Ok(item) => {
+                created_keys.insert(item.key.clone());
+                entry.zotero_key = Some(item.key);
+                report.created_items.push(label);
+            }
+            Err(ZoteroWriteError::AlreadyExists { key }) => {
+                entry.zotero_key = Some(key);
+                report.linked.push(label);
+            }
+            Err(e) => report.failed.push(EntryFailure { entry: label, error: e.to_string() }),
+        }
+    }
+    if !report.created_items.is_empty() || !report.linked.is_empty() {
+        save_selection(&sel)?;
+    }
+
+    let writer = zotero.for_writes();
+    for entry in &sel.entries {
+        let Some(key) = entry.zotero_key.as_deref().filter(|k| !created_keys.contains(*k)) else { continue };
+        let label = candidate_label(entry);
+        let item = match writer.get_item(key).await {
+            Ok(item) => item,
+            Err(e) => {
+                report.failed.push(EntryFailure { entry: label, error: e.to_string() });
+                continue;
+            }
+        };
+        if item.data.collections.contains(&collection_key) {
+            report.already_in_collection += 1;
+            continue;
+        }
+        let mut collections = item.data.collections.clone();
+        collections.push(collection_key.clone());
+        match writer.patch_item(key, item.version, serde_json::json!({ "collections": collections })).await {
+            Ok(()) => report.added.push(label),
+            Err(e) => report.failed.push(EntryFailure { entry: label, error: e.to_string() }),
+        }
+    }
+    Ok(report)
+}
+
+/// Selection name derived from a collection name: characters other than
+/// alphanumerics, `-` and `_` become `-`.
+pub fn selection_name_for(collection_name: &str) -> String {
+    let mut name = String::new();
+    for c in collection_name.trim().chars() {
+        if c.is_alphanumeric() || c == '_' || c == '-' {
+            name.push(c);
+        } else if !name.ends_with('-') {
+            name.push('-');
+        }
+    }
+    name.trim_matches('-').to_string()
+}
+
+/// Add the top-level items of `collection` (key or name, see
+/// [`resolve_collection_key`]) to selection `name`, or to one named after the
+/// collection (see [`selection_name_for`]). The selection is created if
+/// missing; items already in it are skipped. Standalone notes and attachments
+/// are ignored. Subcollections are not included.
+pub async fn selection_from_collection(
+    zotero: &ZoteroClient,
+    collection: &str,
+    name: Option<&str>,
+) -> Result<CollectionImport, SelectionZoteroError> {
+    let collection_key = resolve_collection_key(zotero, collection).await?;
+    let collection_name = zotero.get_collection(&collection_key).await?.data.name;
+    let sel_name = name.map_or_else(|| selection_name_for(&collection_name), str::to_string);
+    validate_name(&sel_name)?;
+    let (mut sel, created_selection) = match load_selection(&sel_name) {
+        Ok(sel) => (sel, false),
+        Err(SelectionError::NotFound(_)) => (Selection { name: sel_name.clone(), entries: Vec::new() }, true),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut summary = BulkAddSummary {
+        selection: sel_name,
+        matched: 0,
+        fetched: 0,
+        added: Vec::new(),
+        duplicates: Vec::new(),
+        unresolved: Vec::new(),
+    };
+    let mut start = 0;
+    loop {
+        let page = zotero
+            .list_collection_top_items(
+                &collection_key,
+                &ItemListParams { limit: Some(PAGE_SIZE), start: Some(start), ..Default::default() },
+            )
+            .await?;
+        let fetched = page.items.len();
+        summary.matched += fetched as u64;
+        for item in &page.items {
+            if matches!(item.data.item_type.as_str(), "attachment" | "note" | "annotation") {
+                continue;
+            }
+            summary.fetched += 1;
+            let mut entry = empty_entry();
+            entry.zotero_key = Some(item.key.clone());
+            fill_from_zotero_item(&mut entry, item);
+            entry.doi = entry.doi.filter(|d| !d.is_empty());
+            let label = candidate_label(&entry);
+            if contains_entry(&sel, &entry) {
+                summary.duplicates.push(label);
+            } else {
+                sel.entries.push(entry);
+                summary.added.push(label);
+            }
+        }
+        start += PAGE_SIZE;
+        if fetched < PAGE_SIZE as usize || page.total_results.is_none_or(|total| u64::from(start) >= total) {
+            break;
+        }
+    }
+    if created_selection || !summary.added.is_empty() {
+        save_selection(&sel)?;
+    }
+    Ok(CollectionImport { collection_key, collection_name, created_selection, summary })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_selection_name_for() {
+        assert_eq!(selection_name_for("GPU Papers"), "GPU-Papers");
+        assert_eq!(selection_name_for(" Thesis / Ch. 2 "), "Thesis-Ch-2");
+        assert_eq!(selection_name_for("neural_fields-2024"), "neural_fields-2024");
+        assert_eq!(selection_name_for("???"), "");
+    }
+}
@@ -0,0 +1,127 @@
+//! Semantic Scholar enrichment for OpenAlex works.
+//!
+//! Semantic Scholar has signals OpenAlex lacks that help triage a paper: a
+//! one-sentence TLDR, how many citing papers build substantially on it
+//! ("influential" citations), and embedding-based recommendations. Works are
+//! matched by DOI, falling back to PubMed, PubMed Central and MAG IDs.
+
+use papers_openalex::Work;
+use serde::Serialize;
+
+pub use papers_semanticscholar::{S2Paper, SemanticScholarClient, SemanticScholarError};
+
+/// Recommendations fetched by default.
+pub const DEFAULT_RECOMMENDATIONS: u32 = 5;
+
+/// What Semantic Scholar adds to a work.
+#[derive(Debug, Clone, Serialize)]
+pub struct S2Enrichment {
+    pub paper_id: String,
+    /// Paper page on semanticscholar.org.
+    pub url: Option<String>,
+    /// One-sentence machine-generated summary, when Semantic Scholar has one.
+    pub tldr: Option<String>,
+    pub citation_count: Option<u64>,
+    /// Citing papers Semantic Scholar judges to build substantially on this one.
+    pub influential_citation_count: Option<u64>,
+    /// Similar papers by embedding, most similar first.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub recommendations: Vec<S2Recommendation>,
+}
+
+/// A paper Semantic Scholar recommends alongside the enriched work.
+#[derive(Debug, Clone, Serialize)]
+pub struct S2Recommendation {
+    pub paper_id: String,
+    pub title: Option<String>,
+    pub year: Option<u16>,
+    /// Bare DOI, usable with `work_get`.
+    pub doi: Option<String>,
+    pub citation_count: Option<u64>,
+    pub url: Option<String>,
+}
+
+impl From<S2Paper> for S2Recommendation {
+    fn from(paper: S2Paper) -> Self {
+        Self {
+            doi: paper.external_ids.and_then(|ids| ids.doi),
+            paper_id: paper.paper_id,
+            title: paper.title,
+            year: paper.year,
+            citation_count: paper.citation_count,
+            url: paper.url,
+        }
+    }
+}
+
+/// The Semantic Scholar ID for `work`: `DOI:`, `PMID:`, `PMCID:` or `MAG:`
+/// followed by the bare identifier, in that order of preference.
+pub fn s2_id_for_work(work: &Work) -> Option<String> {
+    let ids = work.ids.as_ref();
+    let tail = |url: &str| url.rsplit('/').next().unwrap_or(url).to_string();
+    if let Some(doi) = work.doi.as_deref().or(ids.and_then(|i| i.doi.as_deref())) {
+        let doi = doi.trim_start_matches("https://doi.org/").trim_start_matches("http://doi.org/");
+        return Some(format!("DOI:{doi}"));
+    }
+    let ids = ids?;
+    if let Some(pmid) = &ids.pmid {
+        return Some(format!("PMID:{}", tail(pmid)));
+    }
+    if let Some(pmcid) = &ids.pmcid {
+        return Some(format!("PMCID:{}", tail(pmcid).trim_start_matches("PMC")));
+    }
+    ids.mag.as_ref().map(|mag| format!("MAG:{mag}"))
+}
+
+/// Look `work` up in Semantic Scholar and fetch up to `recommendations`
+/// similar papers (0 skips the second request).
+///
+/// Returns `Ok(None)` when the work has no identifier Semantic Scholar
+/// understands or Semantic Scholar doesn't know it.
+pub async fn enrich_work(
+    client: &SemanticScholarClient,
+    work: &Work,
+    recommendations: u32,
+) -> Result<Option<S2Enrichment>, SemanticScholarError> {
+    let Some(id) = s2_id_for_work(work) else { return Ok(None) };
+    let paper = match client.get_paper(&id).await {
+        Ok(paper) => paper,
+        Err(SemanticScholarError::NotFound(_)) => return Ok(None),
+        Err(e) => return Err(e),
+    };
+    let recommended = if recommendations > 0 {
+        client.recommendations(&paper.paper_id, recommendations).await?
+    } else {
+        Vec::new()
+    };
+    Ok(Some(S2Enrichment {
+        paper_id: paper.paper_id,
+        url: paper.url,
+        tldr: paper.tldr.map(|t| t.text),
+        citation_count: paper.citation_count,
+        influential_citation_count: paper.influential_citation_count,
+        recommendations: recommended.into_iter().map(S2Recommendation::from).collect(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn work(value: serde_json::Value) -> Work {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_s2_id_for_work() {
+        let doi = work(serde_json::json!({"id": "https://openalex.org/W1", "doi": "https://doi.org/10.1/ABC"}));
+        assert_eq!(s2_id_for_work(&doi).as_deref(), Some("DOI:10.1/ABC"));
+        let pmc = work(serde_json::json!({
+            "id": "https://openalex.org/W2",
+            "ids": {"pmcid": "https://www.ncbi.nlm.nih.gov/pmc/articles/123456", "mag": "42"}
+        }));
+        assert_eq!(s2_id_for_work(&pmc).as_deref(), Some("PMCID:123456"));
+        let none = work(serde_json::json!({"id": "https://openalex.org/W3"}));
+        assert_eq!(s2_id_for_work(&none), None);
+    }
+}
@@ -0,0 +1,268 @@
+//! `papers.toml`: server settings in one file instead of a pile of
+//! environment variables.
+//!
+//! The file is the first of `$PAPERS_CONFIG`, `./papers.toml` and
+//! `<config_dir>/papers/config.toml` (`$XDG_CONFIG_HOME/papers/config.toml` on
+//! Linux) that exists. Every setting stands for an environment variable (see
+//! [`SETTINGS`]), and the CLI exports the file's values at startup for
+//! variables that are not already set, so the environment always wins:
+//!
+//! ```toml
+//! [keys]
+//! openalex = "..."
+//! zotero_user_id = "1234567"
+//! zotero_api_key = "..."
+//!
+//! [cache]
+//! ttl = "1d"
+//! entity_ttl = { works = "7d" }
+//!
+//! [tools]
+//! disabled = ["zotero_work_create", "zotero_work_update"]
+//! ```
+//!
+//! Unknown keys are an error, so typos don't go unnoticed.
+
+use std::io;
+use std::path::PathBuf;
+
+/// Overrides where the settings file is looked for.
+pub const CONFIG_ENV: &str = "PAPERS_CONFIG";
+
+/// A setting and the environment variable it stands for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Setting {
+    /// Dotted key, e.g. `cache.ttl` for `ttl` under `[cache]`.
+    pub key: &'static str,
+    pub env: &'static str,
+    pub about: &'static str,
+    /// Masked by `papers config show`.
+    pub secret: bool,
+}
+
+const fn setting(key: &'static str, env: &'static str, about: &'static str) -> Setting {
+    Setting { key, env, about, secret: false }
+}
+
+const fn secret(key: &'static str, env: &'static str, about: &'static str) -> Setting {
+    Setting { key, env, about, secret: true }
+}
+
+/// Keys under this table map to `PAPERS_CACHE_TTL_<KEY>`, e.g. `works`.
+const ENTITY_TTL_TABLE: &str = "cache.entity_ttl";
+
+/// Every setting. A key may stand for several variables.
+pub const SETTINGS: &[Setting] = &[
+    secret("keys.openalex", "OPENALEX_KEY", "OpenAlex API key, for higher rate limits"),
+    secret("keys.openalex", "OPENALEX_API_KEY", "OpenAlex API key, for the content API"),
+    secret("keys.zotero_user_id", "ZOTERO_USER_ID", "Zotero user ID"),
+    secret("keys.zotero_api_key", "ZOTERO_API_KEY", "Zotero API key"),
+    secret("keys.datalab", "DATALAB_API_KEY", "DataLab API key"),
+    secret("keys.semantic_scholar", "SEMANTIC_SCHOLAR_API_KEY", "Semantic Scholar API key"),
+    setting("contact.crossref_mailto", "CROSSREF_MAILTO", "email for Crossref's polite pool"),
+    setting("contact.unpaywall_email", "UNPAYWALL_EMAIL", "email sent with Unpaywall lookups"),
+    setting("zotero.profile", "PAPERS_ZOTERO_PROFILE", "default Zotero profile"),
+    setting("cache.ttl", "PAPERS_CACHE_TTL", "OpenAlex cache TTL, e.g. \"1d\""),
+    setting("cache.max_mb", "PAPERS_CACHE_MAX_MB", "OpenAlex cache size limit (0 for unlimited)"),
+    setting("paths.data_dir", "PAPERS_DATA_DIR", "root of selections, RAG store, sampled answers and tenants"),
+    setting("paths.db", "PAPERS_DB_PATH", "RAG store directory"),
+    setting("paths.vault", "PAPERS_VAULT_DIR", "per-paper Markdown notes directory"),
+    setting("selection.default", "PAPERS_SELECTION", "selection used when none is active"),
+    setting("embedding.model", "PAPERS_EMBEDDING_MODEL", "embedding model for the RAG store"),
+    setting("embedding.rerank_model", "PAPERS_RERANK_MODEL", "cross-encoder used by --rerank"),
+    setting("datalab.mode", "PAPERS_DATALAB_MODE", "DataLab quality: fast, balanced or accurate"),
+    setting("concurrency.openalex", "PAPERS_CONCURRENCY_OPENALEX", "OpenAlex requests in flight"),
+    setting("concurrency.zotero", "PAPERS_CONCURRENCY_ZOTERO", "Zotero requests in flight"),
+    setting("concurrency.datalab", "PAPERS_CONCURRENCY_DATALAB", "DataLab conversions in flight"),
+    setting("server.host", "PAPERS_HOST", "address `papers serve` binds"),
+    setting("server.port", "PAPERS_PORT", "port `papers serve` listens on"),
+    setting("server.metrics", "PAPERS_METRICS", "collect usage metrics"),
+    secret("server.tenants", "PAPERS_TENANTS", "\"name:token\" users of a shared server"),
+    setting("audit.enabled", "PAPERS_AUDIT", "record tool calls in the audit log"),
+    setting("audit.retention_days", "PAPERS_AUDIT_RETENTION_DAYS", "days of audit log kept (0 keeps all)"),
+    setting("tools.enabled", "PAPERS_TOOLS_ENABLED", "MCP tools to offer; empty offers all"),
+    setting("tools.disabled", "PAPERS_TOOLS_DISABLED", "MCP tools to hide"),
+    setting("tools.read_only", "PAPERS_READ_ONLY", "hide MCP tools that write to Zotero, the DB, selections, or disk"),
+];
+
+#[derive(Debug, thiserror::Error)]
+pub enum SettingsError {
+    #[error("IO error: {0}")]
+    Io(#[from] io::Error),
+    #[error("invalid TOML: {0}")]
+    Toml(String),
+    #[error("unknown setting {0:?}")]
+    Unknown(String),
+    #[error("setting {0:?}: expected a string, number, boolean or list of strings")]
+    Value(String),
+}
+
+/// Values read from a settings file, by dotted key.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Settings {
+    /// The file they came from; `None` when there is none.
+    pub path: Option<PathBuf>,
+    values: Vec<(String, String)>,
+}
+
+impl Settings {
+    /// The settings file to read, see the module docs.
+    pub fn path() -> Option<PathBuf> {
+        if let Some(path) = std::env::var_os(CONFIG_ENV).filter(|p| !p.is_empty()) {
+            return Some(PathBuf::from(path));
+        }
+        let user = dirs::config_dir().map(|d| d.join("papers").join("config.toml"));
+        std::iter::once(PathBuf::from("papers.toml")).chain(user).find(|p| p.is_file())
+    }
+
+    /// Loads [`Self::path`]. Returns empty settings when there is no file.
+    pub fn load() -> Result<Self, SettingsError> {
+        let Some(path) = Self::path() else {
+            return Ok(Self::default());
+        };
+        let mut settings = Self::parse(&std::fs::read_to_string(&path)?)?;
+        settings.path = Some(path);
+        Ok(settings)
+    }
+
+    pub fn parse(text: &str) -> Result<Self, SettingsError> {
+        let doc = toml_edit::Document::parse(text).map_err(|e| SettingsError::Toml(e.to_string()))?;
+        let mut values = Vec::new();
+        flatten_table(doc.as_table().iter(), "", &mut values)?;
+        for (key, _) in &values {
+            if env_var(key).is_none() {
+                return Err(SettingsError::Unknown(key.clone()));
+            }
+        }
+        Ok(Self { path: None, values })
+    }
+
+    /// The value of dotted `key`, as exported.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.values.iter().find(|(k, _)| k == key).map(|(_, v)| v.as_str())
+    }
+
+    /// `(variable, value)` for every setting in the file.
+    pub fn env_vars(&self) -> Vec<(String, String)> {
+        let mut vars = Vec::new();
+        for (key, value) in &self.values {
+            if let Some(name) = key.strip_prefix(ENTITY_TTL_TABLE).and_then(|k| k.strip_prefix('.')) {
+                vars.push((format!("PAPERS_CACHE_TTL_{}", name.to_ascii_uppercase()), value.clone()));
+            }
+            for s in SETTINGS.iter().filter(|s| s.key == key) {
+                vars.push((s.env.to_string(), value.clone()));
+            }
+        }
+        vars
+    }
+
+    /// [`Self::env_vars`] that `is_set` says are not already set.
+    pub fn unset_env_vars(&self, is_set: impl Fn(&str) -> bool) -> Vec<(String, String)> {
+        self.env_vars().into_iter().filter(|(name, _)| !is_set(name)).collect()
+    }
+}
+
+/// The variable dotted `key` stands for (the first, for keys with several).
+fn env_var(key: &str) -> Option<String> {
+    if let Some(name) = key.strip_prefix(ENTITY_TTL_TABLE).and_then(|k| k.strip_prefix('.')) {
+        return Some(format!("PAPERS_CACHE_TTL_{}", name.to_ascii_uppercase()));
+    }
+    SETTINGS.iter().find(|s| s.key == key).map(|s| s.env.to_string())
+}
+
+fn join_key(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() { key.to_string() } else { format!("{prefix}.{key}") }
+}
+
+fn flatten_table<'a>(
+    entries: impl Iterator<Item = (&'a str, &'a toml_edit::Item)>,
+    prefix: &str,
+    out: &mut Vec<(String, String)>,
+) -> Result<(), SettingsError> {
+    for (key, item) in entries {
+        let key = join_key(prefix, key);
+        match item {
+            toml_edit::Item::Table(table) => flatten_table(table.iter(), &key, out)?,
+            toml_edit::Item::Value(value) => flatten_value(value, &key, out)?,
+            toml_edit::Item::None => {}
+            toml_edit::Item::ArrayOfTables(_) => return Err(SettingsError::Value(key)),
+        }
+    }
+    Ok(())
+}
+
+fn flatten_value(value: &toml_edit::Value, key: &str, out: &mut Vec<(String, String)>) -> Result<(), SettingsError> {
+    use toml_edit::Value;
+
+    if let Value::InlineTable(table) = value {
+        for (k, v) in table.iter() {
+            flatten_value(v, &join_key(key, k), out)?;
+        }
+        return Ok(());
+    }
+    let scalar = |value: &Value| match value {
+        Value::String(s) => Some(s.value().clone()),
+        Value::Integer(i) => Some(i.value().to_string()),
+        Value::Float(f) => Some(f.value().to_string()),
+        Value::Boolean(b) => Some(b.value().to_string()),
+        _ => None,
+    };
+    let text = match value {
+        Value::Array(items) => items.iter().map(scalar).collect::<Option<Vec<_>>>().map(|items| items.join(",")),
+        other => scalar(other),
+    };
+    out.push((key.to_string(), text.ok_or_else(|| SettingsError::Value(key.to_string()))?));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = r#"
+[keys]
+openalex = "oa-key"
+
+[cache]
+ttl = "1d"
+max_mb = 500
+entity_ttl = { works = "7d" }
+
+[server]
+metrics = true
+tenants = ["alice:s3cret", "bob:hunter2"]
+
+[tools]
+disabled = ["zotero_work_create"]
+"#;
+
+    #[test]
+    fn test_settings_map_to_env_vars() {
+        let settings = Settings::parse(EXAMPLE).unwrap();
+        assert_eq!(settings.get("cache.max_mb"), Some("500"));
+        let vars = settings.env_vars();
+        let get = |name: &str| vars.iter().find(|(n, _)| n == name).map(|(_, v)| v.as_str());
+        assert_eq!(get("OPENALEX_KEY"), Some("oa-key"));
+        assert_eq!(get("OPENALEX_API_KEY"), Some("oa-key"));
+        assert_eq!(get("PAPERS_CACHE_TTL"), Some("1d"));
+        assert_eq!(get("PAPERS_CACHE_TTL_WORKS"), Some("7d"));
+        assert_eq!(get("PAPERS_METRICS"), Some("true"));
+        assert_eq!(get("PAPERS_TENANTS"), Some("alice:s3cret,bob:hunter2"));
+        assert_eq!(get("PAPERS_TOOLS_DISABLED"), Some("zotero_work_create"));
+    }
+
+    #[test]
+    fn test_environment_wins() {
+        let settings = Settings::parse("[cache]\nttl = \"1d\"\nmax_mb = 5\n").unwrap();
+        let vars = settings.unset_env_vars(|name| name == "PAPERS_CACHE_TTL");
+        assert_eq!(vars, [("PAPERS_CACHE_MAX_MB".to_string(), "5".to_string())]);
+    }
+
+    #[test]
+    fn test_unknown_or_malformed_settings_are_errors() {
+        assert!(matches!(Settings::parse("[cache]\nttll = \"1d\"\n"), Err(SettingsError::Unknown(k)) if k == "cache.ttll"));
+        assert!(matches!(Settings::parse("[tools]\ndisabled = [1, [2]]\n"), Err(SettingsError::Value(_))));
+        assert!(matches!(Settings::parse("[cache\n"), Err(SettingsError::Toml(_))));
+    }
+
+}
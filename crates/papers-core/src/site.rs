@@ -0,0 +1,444 @@
+//! Static HTML site for a selection, for sharing a curated reading list.
+//!
+//! [`site_papers`] gathers each entry's metadata the same way
+//! [`export`](crate::export) does (Zotero item, else OpenAlex work, else the
+//! selection entry) plus its abstract, tags, and Zotero annotations. Callers
+//! with access to the local DB attach [`SiteFigure`]s, then [`write_site`]
+//! renders `index.html` (with client-side text, year, and tag filters) and one
+//! page per paper under `papers/`, copying figure images into `figures/`.
+//! Pages are self-contained: no external CSS, JS, or fonts.
+
+use std::collections::{BTreeSet, HashSet};
+use std::path::{Path, PathBuf};
+
+use papers_openalex::{GetParams, OpenAlexClient};
+use papers_zotero::ZoteroClient;
+use serde::Serialize;
+
+use crate::export::{
+    CitationRecord, PersonName, record_from_entry, record_from_work, record_from_zotero,
+};
+use crate::selection::{Selection, SelectionError};
+use crate::vault::AnnotationExcerpt;
+
+const WORK_SITE_SELECT: &str = "id,doi,title,display_name,publication_year,publication_date,\
+    type,type_crossref,authorships,primary_location,biblio,language,abstract_inverted_index,\
+    topics";
+
+#[derive(Debug, thiserror::Error)]
+pub enum SiteError {
+    #[error(transparent)]
+    Selection(#[from] SelectionError),
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// A figure, table, or other exhibit shown on a paper's page.
+#[derive(Debug, Clone, Serialize)]
+pub struct SiteFigure {
+    pub exhibit_type: String,
+    pub caption: String,
+    /// Local image file; copied into the site's `figures/` directory.
+    pub image_path: Option<PathBuf>,
+}
+
+/// One paper of the site.
+#[derive(Debug, Clone, Serialize)]
+pub struct SitePaper {
+    pub record: CitationRecord,
+    pub zotero_key: Option<String>,
+    pub abstract_text: Option<String>,
+    /// Zotero tags, or OpenAlex topics when the paper isn't in Zotero.
+    pub tags: Vec<String>,
+    pub annotations: Vec<AnnotationExcerpt>,
+    pub figures: Vec<SiteFigure>,
+}
+
+impl SitePaper {
+    /// IDs the paper may be indexed under in the local DB (DOI, then Zotero key).
+    pub fn db_ids(&self) -> Vec<String> {
+        self.record.doi.iter().chain(self.zotero_key.iter()).cloned().collect()
+    }
+}
+
+/// Result of [`write_site`].
+#[derive(Debug, Serialize)]
+pub struct SiteSummary {
+    pub index: PathBuf,
+    pub papers: usize,
+    pub figures: usize,
+    pub annotations: usize,
+}
+
+/// Gather metadata, abstracts, tags, and annotations for every entry of `selection`.
+pub async fn site_papers(
+    selection: &Selection,
+    client: &OpenAlexClient,
+    zotero: Option<&ZoteroClient>,
+) -> Vec<SitePaper> {
+    let params = GetParams { select: Some(WORK_SITE_SELECT.to_string()) };
+    let mut papers = Vec::with_capacity(selection.entries.len());
+    for entry in &selection.entries {
+        if let (Some(z), Some(key)) = (zotero, entry.zotero_key.as_deref())
+            && let Ok(item) = z.get_item(key).await
+        {
+            let annotations = crate::zotero::work_annotations(z, key).await.unwrap_or_default();
+            papers.push(SitePaper {
+                record: record_from_zotero(&item),
+                zotero_key: Some(key.to_string()),
+                abstract_text: item.data.abstract_note.clone(),
+                tags: item.data.tags.iter().map(|t| t.tag.clone()).collect(),
+                annotations: annotations
+                    .iter()
+                    .filter_map(AnnotationExcerpt::from_zotero)
+                    .collect(),
+                figures: Vec::new(),
+            });
+            continue;
+        }
+        if let Some(id) = entry.openalex_id.as_deref().or(entry.doi.as_deref())
+            && let Ok(work) = crate::api::work_get(client, id, &params).await
+        {
+            let topics = work.topics.iter().flatten().filter_map(|t| t.display_name.clone());
+            papers.push(SitePaper {
+                record: record_from_work(&work),
+                zotero_key: entry.zotero_key.clone(),
+                abstract_text: work.abstract_text.clone(),
+                tags: topics.collect(),
+                annotations: Vec::new(),
+                figures: Vec::new(),
+            });
+            continue;
+        }
+        papers.push(SitePaper {
+            record: record_from_entry(entry),
+            zotero_key: entry.zotero_key.clone(),
+            abstract_text: None,
+            tags: Vec::new(),
+            annotations: Vec::new(),
+            figures: Vec::new(),
+        });
+    }
+    papers
+}
+
+/// Render the site for `papers` into `out_dir`, titled `title`.
+pub fn write_site(
+    out_dir: &Path,
+    title: &str,
+    papers: &[SitePaper],
+) -> Result<SiteSummary, SiteError> {
+    std::fs::create_dir_all(out_dir.join("papers"))?;
+    std::fs::create_dir_all(out_dir.join("figures"))?;
+
+    let mut used = HashSet::new();
+    let slugs: Vec<String> = papers
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            let base = slugify(p.record.title.as_deref().unwrap_or_default());
+            let base = if base.is_empty() { format!("paper-{}", i + 1) } else { base };
+            let mut slug = base.clone();
+            let mut n = 2;
+            while !used.insert(slug.clone()) {
+                slug = format!("{base}-{n}");
+                n += 1;
+            }
+            slug
+        })
+        .collect();
+
+    let mut figures = 0;
+    for (paper, slug) in papers.iter().zip(&slugs) {
+        let mut images = Vec::new();
+        for (i, fig) in paper.figures.iter().enumerate() {
+            let copied = match &fig.image_path {
+                Some(src) if src.is_file() => {
+                    let ext = src.extension().and_then(|e| e.to_str()).unwrap_or("png");
+                    let name = format!("{slug}-{}.{ext}", i + 1);
+                    std::fs::copy(src, out_dir.join("figures").join(&name))?;
+                    figures += 1;
+                    Some(name)
+                }
+                _ => None,
+            };
+            images.push(copied);
+        }
+        let html = paper_page(title, paper, &images);
+        std::fs::write(out_dir.join("papers").join(format!("{slug}.html")), html)?;
+    }
+
+    let index = out_dir.join("index.html");
+    std::fs::write(&index, index_page(title, papers, &slugs))?;
+    Ok(SiteSummary {
+        index,
+        papers: papers.len(),
+        figures,
+        annotations: papers.iter().map(|p| p.annotations.len()).sum(),
+    })
+}
+
+// ── Rendering ─────────────────────────────────────────────────────────────
+
+const STYLE: &str = "body{font-family:system-ui,sans-serif;max-width:52rem;margin:2rem auto;\
+    padding:0 1rem;line-height:1.5;color:#222}a{color:#1a5fb4}.meta{color:#555}\
+    .filters{display:flex;gap:.5rem;flex-wrap:wrap;margin:1rem 0}\
+    .filters input,.filters select{padding:.3rem}ul.papers{list-style:none;padding:0}\
+    ul.papers li{margin:.8rem 0}.tag{display:inline-block;background:#eee;border-radius:.3rem;\
+    padding:0 .4rem;margin-right:.3rem;font-size:.85em}figure{margin:1.5rem 0}\
+    figure img{max-width:100%}figcaption{font-size:.9em;color:#444}\
+    blockquote{border-left:3px solid #ccc;margin:1rem 0;padding-left:1rem;color:#333}";
+
+const FILTER_SCRIPT: &str = r#"const q=document.getElementById('q'),
+y=document.getElementById('year'),t=document.getElementById('tag'),
+items=[...document.querySelectorAll('ul.papers li')];
+function apply(){const s=q.value.toLowerCase();let n=0;for(const li of items){
+const ok=(!s||li.dataset.text.includes(s))&&(!y.value||li.dataset.year===y.value)
+&&(!t.value||li.dataset.tags.split('|').includes(t.value));li.hidden=!ok;if(ok)n++;}
+document.getElementById('count').textContent=n+' of '+items.length+' papers';}
+[q,y,t].forEach(e=>e.addEventListener('input',apply));apply();"#;
+
+fn page(title: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n\
+         <title>{}</title>\n<style>{STYLE}</style>\n</head>\n<body>\n{body}</body>\n</html>\n",
+        escape(title)
+    )
+}
+
+fn index_page(title: &str, papers: &[SitePaper], slugs: &[String]) -> String {
+    let years: BTreeSet<u32> =
+        papers.iter().filter_map(|p| p.record.date_parts.first().copied()).collect();
+    let tags: BTreeSet<&str> =
+        papers.iter().flat_map(|p| p.tags.iter().map(String::as_str)).collect();
+
+    let mut body = format!("<h1>{}</h1>\n<div class=\"filters\">\n", escape(title));
+    body.push_str(
+        "<input id=\"q\" type=\"search\" placeholder=\"Filter by title, author, venue…\">\n",
+    );
+    body.push_str("<select id=\"year\"><option value=\"\">All years</option>");
+    for year in years.iter().rev() {
+        body.push_str(&format!("<option>{year}</option>"));
+    }
+    body.push_str("</select>\n<select id=\"tag\"><option value=\"\">All tags</option>");
+    for tag in &tags {
+        body.push_str(&format!("<option>{}</option>", escape(tag)));
+    }
+    body.push_str("</select>\n<span id=\"count\" class=\"meta\"></span>\n</div>\n");
+    body.push_str("<ul class=\"papers\">\n");
+
+    for (paper, slug) in papers.iter().zip(slugs) {
+        let rec = &paper.record;
+        let title = rec.title.as_deref().unwrap_or("Untitled");
+        let authors = author_list(&rec.authors);
+        let venue = rec.container_title.as_deref().unwrap_or_default();
+        let year = rec.date_parts.first().map(u32::to_string).unwrap_or_default();
+        let text = format!("{title} {authors} {venue} {}", paper.tags.join(" ")).to_lowercase();
+        body.push_str(&format!(
+            "<li data-year=\"{year}\" data-tags=\"{}\" data-text=\"{}\">\n\
+             <a href=\"papers/{slug}.html\">{}</a><br>\n<span class=\"meta\">{}</span>\n",
+            escape(&paper.tags.join("|")),
+            escape(&text),
+            escape(title),
+            escape(&byline(&authors, venue, &year)),
+        ));
+        if !paper.tags.is_empty() {
+            body.push_str("<div>");
+            for tag in &paper.tags {
+                body.push_str(&format!("<span class=\"tag\">{}</span>", escape(tag)));
+            }
+            body.push_str("</div>\n");
+        }
+        body.push_str("</li>\n");
+    }
+    body.push_str(&format!("</ul>\n<script>{FILTER_SCRIPT}</script>\n"));
+    page(title, &body)
+}
+
+fn paper_page(site_title: &str, paper: &SitePaper, images: &[Option<String>]) -> String {
+    let rec = &paper.record;
+    let title = rec.title.as_deref().unwrap_or("Untitled");
+    let year = rec.date_parts.first().map(u32::to_string).unwrap_or_default();
+    let venue = rec.container_title.as_deref().unwrap_or_default();
+    let mut body = format!(
+        "<p><a href=\"../index.html\">← {}</a></p>\n<h1>{}</h1>\n<p class=\"meta\">{}</p>\n",
+        escape(site_title),
+        escape(title),
+        escape(&byline(&author_list(&rec.authors), venue, &year)),
+    );
+    if let Some(doi) = &rec.doi {
+        let url = format!("https://doi.org/{doi}");
+        body.push_str(&format!("<p><a href=\"{}\">{}</a></p>\n", escape(&url), escape(&url)));
+    } else if let Some(url) = &rec.url {
+        body.push_str(&format!("<p><a href=\"{}\">{}</a></p>\n", escape(url), escape(url)));
+    }
+
+    if let Some(abstract_text) = paper.abstract_text.as_deref().filter(|a| !a.trim().is_empty()) {
+        body.push_str(&format!("<h2>Abstract</h2>\n<p>{}</p>\n", escape(abstract_text.trim())));
+    }
+
+    let shown: Vec<(&SiteFigure, &str)> = paper
+        .figures
+        .iter()
+        .zip(images)
+        .filter_map(|(fig, img)| img.as_deref().map(|img| (fig, img)))
+        .collect();
+    if !shown.is_empty() {
+        body.push_str("<h2>Figures</h2>\n");
+        for (fig, img) in shown {
+            body.push_str(&format!(
+                "<figure><img src=\"../figures/{}\" alt=\"{}\" loading=\"lazy\">\
+                 <figcaption>{}</figcaption></figure>\n",
+                escape(img),
+                escape(&fig.exhibit_type),
+                escape(&fig.caption),
+            ));
+        }
+    }
+
+    if !paper.annotations.is_empty() {
+        body.push_str("<h2>Annotations</h2>\n");
+        for ann in &paper.annotations {
+            body.push_str("<blockquote>");
+            if let Some(text) = &ann.text {
+                body.push_str(&format!("<p>{}</p>", escape(text)));
+            }
+            if let Some(comment) = &ann.comment {
+                body.push_str(&format!("<p><em>{}</em></p>", escape(comment)));
+            }
+            if let Some(pg) = &ann.page {
+                body.push_str(&format!("<p class=\"meta\">p. {}</p>", escape(pg)));
+            }
+            body.push_str("</blockquote>\n");
+        }
+    }
+    page(title, &body)
+}
+
+fn author_list(authors: &[PersonName]) -> String {
+    let names: Vec<String> = authors
+        .iter()
+        .map(|a| match (&a.given, &a.family, &a.literal) {
+            (_, _, Some(literal)) => literal.clone(),
+            (Some(given), Some(family), _) => format!("{given} {family}"),
+            (None, Some(family), _) => family.clone(),
+            _ => String::new(),
+        })
+        .filter(|n| !n.is_empty())
+        .collect();
+    names.join(", ")
+}
+
+fn byline(authors: &str, venue: &str, year: &str) -> String {
+    [authors, venue, year].into_iter().filter(|s| !s.is_empty()).collect::<Vec<_>>().join(" · ")
+}
+
+/// Lowercase ASCII slug of at most 60 characters.
+fn slugify(s: &str) -> String {
+    let mut slug = String::new();
+    for c in s.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+        if slug.len() >= 60 {
+            break;
+        }
+    }
+    slug.trim_end_matches('-').to_string()
+}
+
+fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::selection::SelectionEntry;
+
+    fn paper(title: &str, year: u32, tags: &[&str]) -> SitePaper {
+        let entry = SelectionEntry {
+            zotero_key: None,
+            openalex_id: None,
+            doi: Some("10.1/x".into()),
+            title: Some(title.into()),
+            authors: Some(vec!["Ada Lovelace".into()]),
+            year: Some(year),
+            issn: None,
+            isbn: None,
+            work_type: None,
+            note: None,
+            tags: Vec::new(),
+        };
+        SitePaper {
+            record: record_from_entry(&entry),
+            zotero_key: None,
+            abstract_text: Some("A <b>bold</b> claim.".into()),
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            annotations: vec![AnnotationExcerpt {
+                text: Some("highlight".into()),
+                comment: None,
+                page: Some("3".into()),
+            }],
+            figures: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn writes_index_and_paper_pages_with_unique_slugs() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let img = dir.path().join("src.png");
+        std::fs::write(&img, b"png").unwrap();
+        let mut first = paper("Neural Fields", 2022, &["rendering"]);
+        first.figures = vec![
+            SiteFigure {
+                exhibit_type: "figure".into(),
+                caption: "Overview".into(),
+                image_path: Some(img),
+            },
+            SiteFigure {
+                exhibit_type: "table".into(),
+                caption: "Missing".into(),
+                image_path: None,
+            },
+        ];
+        let papers = vec![first, paper("Neural Fields", 2023, &["geometry", "rendering"])];
+        let out = dir.path().join("site");
+
+        let summary = write_site(&out, "Reading group", &papers).unwrap();
+        assert_eq!((summary.papers, summary.figures, summary.annotations), (2, 1, 2));
+
+        let index = std::fs::read_to_string(summary.index).unwrap();
+        assert!(index.contains("href=\"papers/neural-fields.html\""));
+        assert!(index.contains("href=\"papers/neural-fields-2.html\""));
+        assert!(index.contains("data-tags=\"geometry|rendering\""));
+        assert!(index.contains("<option>2023</option><option>2022</option>"));
+
+        let page = std::fs::read_to_string(out.join("papers/neural-fields.html")).unwrap();
+        assert!(page.contains("A &lt;b&gt;bold&lt;/b&gt; claim."));
+        assert!(page.contains("src=\"../figures/neural-fields-1.png\""));
+        assert!(!page.contains("Missing"));
+        assert!(page.contains("https://doi.org/10.1/x"));
+        assert!(out.join("figures/neural-fields-1.png").exists());
+    }
+
+    #[test]
+    fn slugify_keeps_ascii_words() {
+        assert_eq!(slugify("3D Gaussian Splatting: Real-Time!"), "3d-gaussian-splatting-real-time");
+        assert_eq!(slugify("…"), "");
+    }
+}
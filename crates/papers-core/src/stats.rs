@@ -0,0 +1,235 @@
+//! Work counts broken down along one dimension, via OpenAlex `group_by`.
+//!
+//! A single grouped request answers questions such as "publications per year
+//! on topic X" or "top institutions publishing on Y". Groups come back as a
+//! tidy `{key, label, count}` series: years ascending (with empty years filled
+//! in so the series charts cleanly), everything else by count descending.
+
+use std::str::FromStr;
+
+use papers_openalex::GroupByResult;
+use serde::Serialize;
+
+/// Groups kept for non-year dimensions when no limit is given.
+pub const DEFAULT_STATS_TOP: usize = 25;
+
+#[derive(thiserror::Error, Debug)]
+pub enum StatsError {
+    #[error("invalid stats dimension {0:?}: use one of {names}", names = StatsDimension::NAMES.join(", "))]
+    InvalidDimension(String),
+}
+
+/// What to group works by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StatsDimension {
+    Year,
+    Type,
+    Topic,
+    Subfield,
+    Field,
+    Domain,
+    Institution,
+    Country,
+    Author,
+    Source,
+    Publisher,
+    Funder,
+    OaStatus,
+    Language,
+    Sdg,
+}
+
+impl StatsDimension {
+    /// Accepted names, for error messages and help text.
+    pub const NAMES: &'static [&'static str] = &[
+        "year", "type", "topic", "subfield", "field", "domain", "institution", "country",
+        "author", "source", "publisher", "funder", "oa_status", "language", "sdg",
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Year => "year",
+            Self::Type => "type",
+            Self::Topic => "topic",
+            Self::Subfield => "subfield",
+            Self::Field => "field",
+            Self::Domain => "domain",
+            Self::Institution => "institution",
+            Self::Country => "country",
+            Self::Author => "author",
+            Self::Source => "source",
+            Self::Publisher => "publisher",
+            Self::Funder => "funder",
+            Self::OaStatus => "oa_status",
+            Self::Language => "language",
+            Self::Sdg => "sdg",
+        }
+    }
+
+    /// OpenAlex `group_by` field for this dimension.
+    pub fn group_by_field(self) -> &'static str {
+        match self {
+            Self::Year => "publication_year",
+            Self::Type => "type",
+            Self::Topic => "primary_topic.id",
+            Self::Subfield => "primary_topic.subfield.id",
+            Self::Field => "primary_topic.field.id",
+            Self::Domain => "primary_topic.domain.id",
+            Self::Institution => "authorships.institutions.lineage",
+            Self::Country => "authorships.countries",
+            Self::Author => "authorships.author.id",
+            Self::Source => "primary_location.source.id",
+            Self::Publisher => "primary_location.source.publisher_lineage",
+            Self::Funder => "grants.funder",
+            Self::OaStatus => "open_access.oa_status",
+            Self::Language => "language",
+            Self::Sdg => "sustainable_development_goals.id",
+        }
+    }
+}
+
+impl FromStr for StatsDimension {
+    type Err = StatsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().replace(['-', ' '], "_").as_str() {
+            "year" | "years" | "publication_year" => Ok(Self::Year),
+            "type" | "types" | "work_type" => Ok(Self::Type),
+            "topic" | "topics" => Ok(Self::Topic),
+            "subfield" | "subfields" => Ok(Self::Subfield),
+            "field" | "fields" => Ok(Self::Field),
+            "domain" | "domains" => Ok(Self::Domain),
+            "institution" | "institutions" => Ok(Self::Institution),
+            "country" | "countries" => Ok(Self::Country),
+            "author" | "authors" => Ok(Self::Author),
+            "source" | "sources" | "venue" | "journal" => Ok(Self::Source),
+            "publisher" | "publishers" => Ok(Self::Publisher),
+            "funder" | "funders" => Ok(Self::Funder),
+            "oa_status" | "oa" | "open_access" => Ok(Self::OaStatus),
+            "language" | "languages" => Ok(Self::Language),
+            "sdg" | "sdgs" => Ok(Self::Sdg),
+            _ => Err(StatsError::InvalidDimension(s.to_string())),
+        }
+    }
+}
+
+/// One group: a short key (year, OpenAlex ID, code), its display label, and
+/// the number of matching works.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StatsPoint {
+    pub key: String,
+    pub label: String,
+    pub count: i64,
+}
+
+/// Works matching a filter, counted per group.
+#[derive(Debug, Clone, Serialize)]
+pub struct WorkStats {
+    pub by: StatsDimension,
+    /// The OpenAlex `group_by` field used.
+    pub group_by: String,
+    /// Resolved filter expression, if any.
+    pub filter: Option<String>,
+    /// Works matching the filter (a work can fall into several groups).
+    pub total: i64,
+    /// Distinct groups reported by OpenAlex, before `top` was applied.
+    pub groups_count: Option<i64>,
+    pub series: Vec<StatsPoint>,
+}
+
+/// Shorten an OpenAlex group key: `https://openalex.org/T10320` → `T10320`,
+/// `https://openalex.org/countries/US` → `US`.
+fn short_key(key: &str) -> &str {
+    match key.strip_prefix("https://openalex.org/") {
+        Some(rest) => rest.rsplit('/').next().unwrap_or(rest),
+        None => key,
+    }
+}
+
+/// Turn raw `group_by` results into a series.
+///
+/// Years are sorted ascending with missing years between the first and last
+/// filled in as zero, and are never truncated. Other dimensions keep the
+/// `top` largest groups.
+pub fn build_series(by: StatsDimension, groups: Vec<GroupByResult>, top: usize) -> Vec<StatsPoint> {
+    let mut series: Vec<StatsPoint> = groups
+        .into_iter()
+        .map(|g| StatsPoint {
+            key: short_key(&g.key).to_string(),
+            label: g.key_display_name,
+            count: g.count,
+        })
+        .collect();
+
+    if by != StatsDimension::Year {
+        series.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.key.cmp(&b.key)));
+        series.truncate(top);
+        return series;
+    }
+
+    let mut years: Vec<(i32, StatsPoint)> = series
+        .into_iter()
+        .filter_map(|p| Some((p.key.parse().ok()?, p)))
+        .collect();
+    years.sort_by_key(|(y, _)| *y);
+    let mut filled = Vec::with_capacity(years.len());
+    let mut next: Option<i32> = None;
+    for (year, point) in years {
+        if let Some(mut gap) = next {
+            while gap < year {
+                filled.push(StatsPoint { key: gap.to_string(), label: gap.to_string(), count: 0 });
+                gap += 1;
+            }
+        }
+        filled.push(point);
+        next = Some(year + 1);
+    }
+    filled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn group(key: &str, label: &str, count: i64) -> GroupByResult {
+        GroupByResult { key: key.into(), key_display_name: label.into(), count }
+    }
+
+    #[test]
+    fn test_dimension_parsing() {
+        assert_eq!("Years".parse::<StatsDimension>().unwrap(), StatsDimension::Year);
+        assert_eq!("oa-status".parse::<StatsDimension>().unwrap(), StatsDimension::OaStatus);
+        assert_eq!(
+            "institutions".parse::<StatsDimension>().unwrap().group_by_field(),
+            "authorships.institutions.lineage"
+        );
+        let err = "colour".parse::<StatsDimension>().unwrap_err().to_string();
+        assert!(err.contains("colour") && err.contains("oa_status"));
+    }
+
+    #[test]
+    fn test_year_series_sorted_and_gap_filled() {
+        let groups = vec![group("2022", "2022", 5), group("2019", "2019", 2), group("2020", "2020", 3)];
+        let series = build_series(StatsDimension::Year, groups, 1);
+        let shape: Vec<_> = series.iter().map(|p| (p.key.as_str(), p.count)).collect();
+        assert_eq!(shape, vec![("2019", 2), ("2020", 3), ("2021", 0), ("2022", 5)]);
+    }
+
+    #[test]
+    fn test_other_series_short_keys_and_top() {
+        let groups = vec![
+            group("https://openalex.org/I1", "MIT", 10),
+            group("https://openalex.org/I2", "ETH Zurich", 30),
+            group("https://openalex.org/countries/US", "United States", 20),
+        ];
+        let series = build_series(StatsDimension::Institution, groups, 2);
+        assert_eq!(
+            series,
+            vec![
+                StatsPoint { key: "I2".into(), label: "ETH Zurich".into(), count: 30 },
+                StatsPoint { key: "US".into(), label: "United States".into(), count: 20 },
+            ]
+        );
+    }
+}
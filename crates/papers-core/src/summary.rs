@@ -36,6 +36,10 @@ pub struct WorkSummary {
     pub cited_by_count: Option<i64>,
     pub primary_topic: Option<String>,
     pub abstract_text: Option<String>,
+    /// Venue list-price APC in USD (from `apc_list.value_usd`).
+    pub apc_list_usd: Option<i64>,
+    /// Estimated APC actually paid in USD (from `apc_paid.value_usd`).
+    pub apc_paid_usd: Option<i64>,
 }
 
 impl From<Work> for WorkSummary {
@@ -60,6 +64,9 @@ impl From<Work> for WorkSummary {
             .primary_topic
             .and_then(|t| t.display_name);
 
+        let apc_list_usd = w.apc_list.and_then(|a| a.value_usd);
+        let apc_paid_usd = w.apc_paid.and_then(|a| a.value_usd);
+
         WorkSummary {
             id: w.id,
             title: w.display_name,
@@ -73,6 +80,8 @@ impl From<Work> for WorkSummary {
             cited_by_count: w.cited_by_count,
             primary_topic,
             abstract_text: w.abstract_text,
+            apc_list_usd,
+            apc_paid_usd,
         }
     }
 }
@@ -137,6 +146,8 @@ pub struct SourceSummary {
     pub cited_by_count: Option<i64>,
     pub h_index: Option<i64>,
     pub host_organization_name: Option<String>,
+    /// APC list price in USD.
+    pub apc_usd: Option<i64>,
 }
 
 impl From<Source> for SourceSummary {
@@ -154,6 +165,7 @@ impl From<Source> for SourceSummary {
             cited_by_count: s.cited_by_count,
             h_index,
             host_organization_name: s.host_organization_name,
+            apc_usd: s.apc_usd,
         }
     }
 }
@@ -0,0 +1,177 @@
+//! Incremental Zotero change detection for `papers db watch`.
+//!
+//! Each poll asks Zotero for items modified since the last seen library
+//! version (`?since=`) and for objects deleted since then (`/deleted`). Parent
+//! items that gained a PDF attachment (and, with watched collections, items
+//! added to one of them) are planned for extraction and indexing; deleted items
+//! are planned for removal from the DB. Applying the plan is up to the caller
+//! (`papers_db::sync`), which then checkpoints [`SyncState`].
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::PathBuf;
+
+use papers_zotero::{DeletedParams, Item, ItemListParams, ZoteroClient, ZoteroError};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, thiserror::Error)]
+pub enum SyncError {
+    #[error("Zotero error: {0}")]
+    Zotero(#[from] ZoteroError),
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("invalid sync state: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+/// Page size for listing changed items.
+const SYNC_PAGE_SIZE: u32 = 100;
+
+/// Watcher checkpoint, persisted between polls.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncState {
+    /// Library version of the last applied poll; 0 before the first one.
+    pub library_version: u64,
+    /// Parent item key → DB paper id, for items the watcher indexed.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub indexed: BTreeMap<String, String>,
+    /// Item keys whose extraction or ingest failed; retried on the next poll.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pending: Vec<String>,
+}
+
+impl SyncState {
+    /// `{PAPERS_DATA_DIR or platform data dir}/sync.json`, or the tenant's.
+    pub fn path() -> PathBuf {
+        crate::tenant::data_dir().join("sync.json")
+    }
+
+    /// Loads the saved state; a fresh state if the watcher never ran.
+    pub fn load() -> Result<Self, SyncError> {
+        let path = Self::path();
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        Ok(serde_json::from_slice(&std::fs::read(path)?)?)
+    }
+
+    pub fn save(&self) -> Result<(), SyncError> {
+        let path = Self::path();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_vec_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+/// What changed in Zotero since the last poll.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct SyncPlan {
+    /// Parent item keys to extract and index, retries first.
+    pub ingest: Vec<String>,
+    /// Deleted item keys that may be indexed.
+    pub remove: Vec<String>,
+    /// Library version to checkpoint once the plan has been applied.
+    pub library_version: u64,
+}
+
+pub(crate) fn is_pdf_attachment(item: &Item) -> bool {
+    item.data.item_type == "attachment" && item.data.content_type.as_deref() == Some("application/pdf")
+}
+
+fn is_regular_item(item: &Item) -> bool {
+    !matches!(item.data.item_type.as_str(), "attachment" | "note" | "annotation")
+}
+
+/// Plan the next poll from `state`. `collections` are collection keys to
+/// watch; empty watches the whole library.
+///
+/// The first poll (`library_version == 0`) only records the current library
+/// version, so starting the watcher doesn't queue the whole existing library.
+pub async fn plan_sync(
+    client: &ZoteroClient,
+    state: &SyncState,
+    collections: &[String],
+) -> Result<SyncPlan, SyncError> {
+    let since = state.library_version;
+    if since == 0 {
+        let probe = client
+            .list_items(&ItemListParams { limit: Some(1), ..Default::default() })
+            .await?;
+        return Ok(SyncPlan {
+            ingest: state.pending.clone(),
+            remove: Vec::new(),
+            library_version: probe.last_modified_version.unwrap_or(0),
+        });
+    }
+
+    let mut changed: HashMap<String, Item> = HashMap::new();
+    let mut library_version = since;
+    let mut start = 0;
+    loop {
+        let page = client
+            .list_items(&ItemListParams {
+                since: Some(since),
+                limit: Some(SYNC_PAGE_SIZE),
+                start: Some(start),
+                ..Default::default()
+            })
+            .await?;
+        library_version = library_version.max(page.last_modified_version.unwrap_or(0));
+        let fetched = page.items.len();
+        changed.extend(page.items.into_iter().map(|item| (item.key.clone(), item)));
+        start += SYNC_PAGE_SIZE;
+        if fetched < SYNC_PAGE_SIZE as usize || page.total_results.is_none_or(|total| u64::from(start) >= total) {
+            break;
+        }
+    }
+
+    // Parents of new PDFs are known to have one; with watched collections, a
+    // changed parent may also have just been filed into one of them.
+    let mut with_pdf: BTreeSet<String> = BTreeSet::new();
+    let mut candidates: BTreeSet<String> = BTreeSet::new();
+    for item in changed.values() {
+        if is_pdf_attachment(item) {
+            if let Some(parent) = &item.data.parent_item {
+                with_pdf.insert(parent.clone());
+                candidates.insert(parent.clone());
+            }
+        } else if !collections.is_empty() && is_regular_item(item) {
+            candidates.insert(item.key.clone());
+        }
+    }
+
+    let mut ingest = state.pending.clone();
+    for key in candidates {
+        if state.indexed.contains_key(&key) || ingest.contains(&key) {
+            continue;
+        }
+        if !collections.is_empty() {
+            let item_collections = match changed.get(&key) {
+                Some(item) => item.data.collections.clone(),
+                None => client.get_item(&key).await?.data.collections,
+            };
+            if !item_collections.iter().any(|c| collections.contains(c)) {
+                continue;
+            }
+        }
+        if !with_pdf.contains(&key) {
+            let children = client
+                .list_item_children(&key, &ItemListParams { item_type: Some("attachment".into()), ..Default::default() })
+                .await?;
+            if !children.items.iter().any(is_pdf_attachment) {
+                continue;
+            }
+        }
+        ingest.push(key);
+    }
+
+    let deleted = client.get_deleted(&DeletedParams { since }).await?;
+    library_version = library_version.max(deleted.last_modified_version.unwrap_or(0));
+    let mut remove = deleted.data.items;
+    remove.sort();
+    remove.dedup();
+    ingest.retain(|key| !remove.contains(key));
+
+    Ok(SyncPlan { ingest, remove, library_version })
+}
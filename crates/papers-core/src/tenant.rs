@@ -0,0 +1,154 @@
+//! Per-user data namespaces for shared (HTTP) deployments.
+//!
+//! Tenants are declared in `PAPERS_TENANTS` as comma-separated `name:token`
+//! pairs, e.g. `alice:s3cret,bob:hunter2`. Each tenant gets its own directory
+//! under `{PAPERS_DATA_DIR or platform data dir}/tenants/<name>` holding its
+//! RAG store (`rag/`), selections (`selections/`) and OpenAlex response cache
+//! (`cache/`).
+//!
+//! Selection storage is process-global, so code acting for a tenant runs
+//! inside [`scope`]; [`crate::selection::selections_dir`] then resolves into
+//! the tenant's directory instead of the shared one. So does `config.json`
+//! ([`crate::config::PapersConfig::config_path`]): each tenant has its own
+//! webhooks, schedule and other settings. Zotero profiles
+//! ([`crate::zotero_profiles`]) are the operator's and stay shared.
+
+use std::future::Future;
+use std::path::{Path, PathBuf};
+
+use subtle::ConstantTimeEq;
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum TenantError {
+    #[error("invalid tenant entry {0:?}: expected \"name:token\"")]
+    Entry(String),
+    #[error("invalid tenant name {0:?}: use letters, digits, '-' or '_'")]
+    Name(String),
+    #[error("duplicate tenant {0:?}")]
+    Duplicate(String),
+}
+
+/// A user of a shared server, identified by a bearer token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tenant {
+    pub name: String,
+    pub token: String,
+}
+
+impl Tenant {
+    /// Root of this tenant's data, see the module docs for the layout.
+    pub fn data_dir(&self) -> PathBuf {
+        tenants_root().join(&self.name)
+    }
+}
+
+/// Tenants from `PAPERS_TENANTS`; empty when unset, meaning single-user mode.
+pub fn configured_tenants() -> Result<Vec<Tenant>, TenantError> {
+    parse_tenants(&std::env::var("PAPERS_TENANTS").unwrap_or_default())
+}
+
+/// Parse `name:token` pairs separated by commas. Blank entries are skipped.
+pub fn parse_tenants(spec: &str) -> Result<Vec<Tenant>, TenantError> {
+    let mut tenants: Vec<Tenant> = Vec::new();
+    for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+        let (name, token) = entry
+            .split_once(':')
+            .map(|(n, t)| (n.trim(), t.trim()))
+            .filter(|(n, t)| !n.is_empty() && !t.is_empty())
+            .ok_or_else(|| TenantError::Entry(entry.to_string()))?;
+        if !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+            return Err(TenantError::Name(name.to_string()));
+        }
+        if tenants.iter().any(|t| t.name == name) {
+            return Err(TenantError::Duplicate(name.to_string()));
+        }
+        tenants.push(Tenant { name: name.to_string(), token: token.to_string() });
+    }
+    Ok(tenants)
+}
+
+/// The tenant whose token is `token`, if any. Tokens are compared in
+/// constant time, and every tenant is checked, so timing doesn't reveal how
+/// much of a token matched.
+pub fn authenticate<'a>(tenants: &'a [Tenant], token: &str) -> Option<&'a Tenant> {
+    let mut found = None;
+    for tenant in tenants {
+        if bool::from(tenant.token.as_bytes().ct_eq(token.as_bytes())) {
+            found = Some(tenant);
+        }
+    }
+    found
+}
+
+/// `{PAPERS_DATA_DIR or platform data dir}/tenants`.
+pub fn tenants_root() -> PathBuf {
+    shared_data_dir().join("tenants")
+}
+
+/// `PAPERS_DATA_DIR`, else `papers/` in the platform data dir, else
+/// `.papers`, whatever the enclosing [`scope`].
+pub fn shared_data_dir() -> PathBuf {
+    std::env::var("PAPERS_DATA_DIR")
+        .ok()
+        .map(PathBuf::from)
+        .or_else(|| dirs::data_dir().map(|d| d.join("papers")))
+        .unwrap_or_else(|| PathBuf::from(".papers"))
+}
+
+tokio::task_local! {
+    static DATA_DIR: PathBuf;
+}
+
+/// Run `fut` with selection storage redirected into `data_dir`. Work spawned
+/// onto other tasks from inside `fut` does not inherit the scope.
+pub async fn scope<F: Future>(data_dir: &Path, fut: F) -> F::Output {
+    DATA_DIR.scope(data_dir.to_path_buf(), fut).await
+}
+
+/// The data directory of the enclosing [`scope`], if any.
+pub fn current_data_dir() -> Option<PathBuf> {
+    DATA_DIR.try_with(|d| d.clone()).ok()
+}
+
+/// Where per-user data lives: the [`current_data_dir`] inside a [`scope`],
+/// else the [`shared_data_dir`].
+pub fn data_dir() -> PathBuf {
+    current_data_dir().unwrap_or_else(shared_data_dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tenants() {
+        let tenants = parse_tenants(" alice:s3cret, bob:a:b ,").unwrap();
+        assert_eq!(tenants.len(), 2);
+        assert_eq!(tenants[0], Tenant { name: "alice".into(), token: "s3cret".into() });
+        assert_eq!(tenants[1].token, "a:b");
+        assert!(parse_tenants("").unwrap().is_empty());
+        assert_eq!(parse_tenants("alice"), Err(TenantError::Entry("alice".into())));
+        assert_eq!(parse_tenants("alice:"), Err(TenantError::Entry("alice:".into())));
+        assert_eq!(parse_tenants("../x:t"), Err(TenantError::Name("../x".into())));
+        assert_eq!(parse_tenants("a:1,a:2"), Err(TenantError::Duplicate("a".into())));
+    }
+
+    #[test]
+    fn test_authenticate() {
+        let tenants = parse_tenants("alice:t1,bob:t2").unwrap();
+        assert_eq!(authenticate(&tenants, "t2").map(|t| t.name.as_str()), Some("bob"));
+        assert!(authenticate(&tenants, "t3").is_none());
+        assert!(authenticate(&tenants, "t").is_none());
+        assert!(authenticate(&tenants, "t22").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_scope_sets_current_data_dir() {
+        assert!(current_data_dir().is_none());
+        let dir = PathBuf::from("/tmp/tenants/alice");
+        let seen = scope(&dir, async { (current_data_dir(), data_dir()) }).await;
+        assert_eq!(seen, (Some(dir.clone()), dir));
+        assert!(current_data_dir().is_none());
+        assert_eq!(data_dir(), shared_data_dir());
+    }
+}
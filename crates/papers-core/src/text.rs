@@ -1,8 +1,13 @@
 use base64::Engine as _;
+use futures::future::{BoxFuture, FutureExt, Shared};
+use futures::stream::{FuturesUnordered, StreamExt};
 use papers_openalex::{GetParams, OpenAlexClient, Work};
 use papers_zotero::{ItemListParams, ZoteroClient};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
+use std::sync::Arc;
+
+use crate::util::short_id;
 
 /// Where the PDF was obtained from.
 #[derive(Debug, Clone, Serialize)]
@@ -12,7 +17,163 @@ pub enum PdfSource {
     ZoteroRemote { item_key: String },
     DirectUrl { url: String },
     OpenAlexContent,
+    Unpaywall {
+        url: String,
+        /// `"publisher"` or `"repository"`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        host_type: Option<String>,
+        /// `"publishedVersion"`, `"acceptedVersion"` or `"submittedVersion"`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        version: Option<String>,
+    },
+    LinkResolver { url: String },
+    /// JATS XML full text from the PMC open-access subset.
+    Pmc { pmcid: String },
     LocalExtract,
+    /// A PDF kept from an earlier download, see [`save_downloaded_pdf`].
+    CachedPdf { path: String },
+}
+
+/// A place [`work_text`] looks for a work's PDF. The order is configurable
+/// with `text_sources` in `config.json`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TextSource {
+    /// An attachment file in the local Zotero data directory.
+    ZoteroLocal,
+    /// An attachment file downloaded through the Zotero API.
+    ZoteroRemote,
+    /// JATS XML from the PMC open-access subset, for works with a PMCID.
+    Pmc,
+    /// PDF URLs on OpenAlex locations, for whitelisted repository domains.
+    OaUrls,
+    /// The OpenAlex Content API (requires `OPENALEX_API_KEY`).
+    OpenalexContent,
+    /// Open-access PDF locations from Unpaywall (requires a contact email).
+    Unpaywall,
+    /// An institutional OpenURL link resolver (requires a URL template).
+    LinkResolver,
+}
+
+impl TextSource {
+    /// The chain used when `text_sources` is not configured.
+    pub const DEFAULT_ORDER: &'static [TextSource] = &[
+        TextSource::ZoteroLocal,
+        TextSource::ZoteroRemote,
+        TextSource::Pmc,
+        TextSource::OaUrls,
+        TextSource::OpenalexContent,
+        TextSource::Unpaywall,
+        TextSource::LinkResolver,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::ZoteroLocal => "zotero_local",
+            Self::ZoteroRemote => "zotero_remote",
+            Self::Pmc => "pmc",
+            Self::OaUrls => "oa_urls",
+            Self::OpenalexContent => "openalex_content",
+            Self::Unpaywall => "unpaywall",
+            Self::LinkResolver => "link_resolver",
+        }
+    }
+}
+
+impl std::str::FromStr for TextSource {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::DEFAULT_ORDER
+            .iter()
+            .copied()
+            .find(|source| source.as_str() == s.trim().replace('-', "_"))
+            .ok_or_else(|| {
+                let names: Vec<_> = Self::DEFAULT_ORDER.iter().map(|t| t.as_str()).collect();
+                format!("unknown text source {s:?}: use one of {}", names.join(", "))
+            })
+    }
+}
+
+/// The PDF source chain and the settings individual sources need.
+#[derive(Debug, Clone, Default)]
+pub struct TextSourceConfig {
+    /// Sources to try, in order; empty means [`TextSource::DEFAULT_ORDER`].
+    pub order: Vec<TextSource>,
+    /// Contact email sent to Unpaywall, which rejects anonymous requests.
+    pub unpaywall_email: Option<String>,
+    /// Link resolver URL with a `{doi}` placeholder.
+    pub link_resolver: Option<String>,
+}
+
+impl TextSourceConfig {
+    /// Read the chain from `config.json`, with `UNPAYWALL_EMAIL` taking
+    /// precedence over `unpaywall_email`. Falls back to the defaults when
+    /// the config can't be read.
+    pub fn load() -> Self {
+        let config = crate::config::PapersConfig::load().unwrap_or_default();
+        let env_email = std::env::var("UNPAYWALL_EMAIL").ok().filter(|e| !e.is_empty());
+        Self {
+            order: config.text_sources,
+            unpaywall_email: env_email.or(config.unpaywall_email),
+            link_resolver: config.link_resolver,
+        }
+    }
+
+    pub fn order(&self) -> &[TextSource] {
+        if self.order.is_empty() { TextSource::DEFAULT_ORDER } else { &self.order }
+    }
+}
+
+/// What happened when a source was tried.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AttemptOutcome {
+    Found,
+    /// Not applicable to this work or not configured; nothing was requested.
+    Skipped,
+    /// Tried, but yielded no file.
+    Failed,
+    /// Still running when another source found a file.
+    Cancelled,
+}
+
+impl AttemptOutcome {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Found => "found",
+            Self::Skipped => "skipped",
+            Self::Failed => "failed",
+            Self::Cancelled => "cancelled",
+        }
+    }
+}
+
+/// One step of the source chain, in the order tried.
+#[derive(Debug, Clone, Serialize)]
+pub struct SourceAttempt {
+    pub source: TextSource,
+    pub outcome: AttemptOutcome,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+/// `"; tried zotero_local (skipped: …), oa_urls (failed: …)"`, or empty.
+fn attempts_summary(attempts: &[SourceAttempt]) -> String {
+    if attempts.is_empty() {
+        return String::new();
+    }
+    let steps: Vec<String> = attempts
+        .iter()
+        .map(|a| {
+            let outcome = a.outcome.as_str();
+            match &a.reason {
+                Some(reason) => format!("{} ({outcome}: {reason})", a.source.as_str()),
+                None => format!("{} ({outcome})", a.source.as_str()),
+            }
+        })
+        .collect();
+    format!("; tried {}", steps.join(", "))
 }
 
 /// Result of extracting text from a work's PDF.
@@ -23,6 +184,156 @@ pub struct WorkTextResult {
     pub work_id: String,
     pub title: Option<String>,
     pub doi: Option<String>,
+    /// Sources tried before (and including) the one that yielded the file.
+    pub attempts: Vec<SourceAttempt>,
+    /// Quality of the local PDF extraction, and whether it was escalated.
+    /// `None` when the text came from a cache, an EPUB/HTML attachment, or PMC.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extraction: Option<ExtractionReport>,
+}
+
+/// How the text of a PDF was produced by [`do_extract_with_report`].
+#[derive(Debug, Clone, Serialize)]
+pub struct ExtractionReport {
+    /// Score of the local pdf-extract text.
+    pub quality: crate::text_quality::TextQuality,
+    /// Set (to the [`Extractor::name`], e.g. [`DATALAB_ESCALATION`]) when the
+    /// local text scored poorly and was replaced by a layout-aware conversion.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub escalated_to: Option<String>,
+    /// Why poor local text was served anyway.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
+}
+
+/// [`ExtractionReport::escalated_to`] for a DataLab "balanced" conversion.
+pub const DATALAB_ESCALATION: &str = "datalab_balanced";
+
+/// Default window size, in characters, for [`WorkTextResult::page`].
+pub const DEFAULT_TEXT_PAGE_CHARS: usize = 40_000;
+
+/// A heading detected in extracted text, at a character offset.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TextSection {
+    pub title: String,
+    pub offset: usize,
+}
+
+/// One window of a work's text, for reading long papers incrementally.
+///
+/// Offsets count characters (not bytes). `sections` covers the whole text, so
+/// callers can jump straight to a section by passing its `offset`.
+#[derive(Debug, Clone, Serialize)]
+pub struct WorkTextPage {
+    pub text: String,
+    pub source: PdfSource,
+    pub work_id: String,
+    pub title: Option<String>,
+    pub doi: Option<String>,
+    pub offset: usize,
+    pub total_chars: usize,
+    /// Offset of the next window, or `None` when this window reaches the end.
+    pub next_offset: Option<usize>,
+    pub sections: Vec<TextSection>,
+    pub attempts: Vec<SourceAttempt>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extraction: Option<ExtractionReport>,
+}
+
+impl WorkTextResult {
+    /// Cut the window of at most `max_chars` characters starting at `offset`.
+    /// Unless the window reaches the end of the text, it is shortened to end at
+    /// a line break in its second half, when there is one.
+    pub fn page(self, offset: usize, max_chars: usize) -> WorkTextPage {
+        let total_chars = self.text.chars().count();
+        let offset = offset.min(total_chars);
+        let mut end = offset.saturating_add(max_chars.max(1)).min(total_chars);
+        let byte_at = |chars: usize| {
+            self.text.char_indices().nth(chars).map_or(self.text.len(), |(i, _)| i)
+        };
+        let (start_byte, mut end_byte) = (byte_at(offset), byte_at(end));
+        if end < total_chars {
+            let window = &self.text[start_byte..end_byte];
+            let half = window.char_indices().nth((end - offset) / 2).map_or(0, |(i, _)| i);
+            if let Some(nl) = window.rfind('\n').filter(|&nl| nl >= half) {
+                end -= window[nl + 1..].chars().count();
+                end_byte = start_byte + nl + 1;
+            }
+        }
+        WorkTextPage {
+            text: self.text[start_byte..end_byte].to_string(),
+            sections: text_sections(&self.text),
+            next_offset: (end < total_chars).then_some(end),
+            offset,
+            total_chars,
+            source: self.source,
+            work_id: self.work_id,
+            title: self.title,
+            doi: self.doi,
+            attempts: self.attempts,
+            extraction: self.extraction,
+        }
+    }
+}
+
+/// Section names recognized as headings even without numbering or Markdown.
+const SECTION_NAMES: &[&str] = &[
+    "abstract",
+    "introduction",
+    "background",
+    "related work",
+    "method",
+    "methods",
+    "materials and methods",
+    "results",
+    "discussion",
+    "conclusion",
+    "conclusions",
+    "acknowledgments",
+    "acknowledgements",
+    "references",
+    "bibliography",
+    "appendix",
+];
+
+/// Detect headings in extracted text: Markdown headings (`## Results`),
+/// numbered headings (`3.1 Sampling Strategy`), and common bare section names
+/// (`References`). Offsets are character offsets of the heading line.
+pub fn text_sections(text: &str) -> Vec<TextSection> {
+    let mut sections = Vec::new();
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        if let Some(title) = heading_title(line.trim()) {
+            sections.push(TextSection { title, offset });
+        }
+        offset += line.chars().count();
+    }
+    sections
+}
+
+fn heading_title(line: &str) -> Option<String> {
+    if line.is_empty() || line.chars().count() > 80 {
+        return None;
+    }
+    if let Some(rest) = line.strip_prefix('#') {
+        let title = rest.trim_start_matches('#').trim();
+        return (!title.is_empty()).then(|| title.to_string());
+    }
+    let (number, rest) = match line.split_once(char::is_whitespace) {
+        Some((n, rest)) if n.chars().all(|c| c.is_ascii_digit() || c == '.') => {
+            (Some(n), rest.trim())
+        }
+        _ => (None, line),
+    };
+    let name = rest.trim_end_matches(':').to_lowercase();
+    if SECTION_NAMES.contains(&name.as_str()) {
+        return Some(line.trim_end_matches(':').to_string());
+    }
+    let numbered = number.is_some_and(|n| n.starts_with(|c: char| c.is_ascii_digit()));
+    let looks_like_title = rest.starts_with(|c: char| c.is_uppercase())
+        && rest.split_whitespace().count() <= 8
+        && !rest.ends_with(['.', ',', ';', ')']);
+    (numbered && looks_like_title).then(|| line.to_string())
 }
 
 /// Errors from the work_text pipeline.
@@ -43,15 +354,23 @@ pub enum WorkTextError {
     #[error("PDF extraction error: {0}")]
     PdfExtract(String),
 
-    #[error("No PDF found for work {work_id}{}", title.as_ref().map(|t| format!(" ({})", t)).unwrap_or_default())]
+    #[error(
+        "No PDF found for work {work_id}{}{}",
+        title.as_ref().map(|t| format!(" ({})", t)).unwrap_or_default(),
+        attempts_summary(attempts)
+    )]
     NoPdfFound {
         work_id: String,
         title: Option<String>,
         doi: Option<String>,
+        attempts: Vec<SourceAttempt>,
     },
 
     #[error("Invalid Zotero item key: {0}")]
     InvalidZoteroKey(String),
+
+    #[error("PMC error: {0}")]
+    Pmc(String),
 }
 
 /// Whitelisted domains for direct PDF download.
@@ -67,28 +386,569 @@ const DIRECT_PDF_DOMAINS: &[&str] = &[
     "plos.org",
 ];
 
-/// Extract text from PDF bytes using pdf-extract.
-pub fn extract_text_bytes(pdf_bytes: &[u8]) -> Result<String, WorkTextError> {
-    extract_text(pdf_bytes)
+/// Extract text from PDF, EPUB, HTML, or JATS XML bytes. The format is
+/// sniffed from the content: zip archives are read as EPUB (or zipped HTML
+/// snapshots), `<article>` XML as JATS, other markup as HTML, and anything
+/// else goes to pdf-extract. All but PDFs come back as Markdown-style text
+/// with `#` headings.
+pub fn extract_text_bytes(bytes: &[u8]) -> Result<String, WorkTextError> {
+    extract_text(bytes)
 }
 
-fn extract_text(pdf_bytes: &[u8]) -> Result<String, WorkTextError> {
-    pdf_extract::extract_text_from_mem(pdf_bytes)
+fn extract_text(bytes: &[u8]) -> Result<String, WorkTextError> {
+    if bytes.starts_with(b"PK\x03\x04") {
+        return extract_epub_text(bytes);
+    }
+    if looks_like_jats(bytes) {
+        return Ok(jats_to_text(&String::from_utf8_lossy(bytes)));
+    }
+    if looks_like_html(bytes) {
+        return Ok(html_to_text(&String::from_utf8_lossy(bytes)));
+    }
+    pdf_extract::extract_text_from_mem(bytes)
         .map_err(|e| WorkTextError::PdfExtract(e.to_string()))
 }
 
+// ── EPUB / HTML ─────────────────────────────────────────────────────────────
+
+/// Content types of Zotero attachments whose text can be extracted, in order
+/// of preference.
+pub const TEXT_ATTACHMENT_TYPES: &[&str] =
+    &["application/pdf", "application/epub+zip", "text/html"];
+
+fn looks_like_html(bytes: &[u8]) -> bool {
+    let head = String::from_utf8_lossy(&bytes[..bytes.len().min(1024)]).to_ascii_lowercase();
+    if head.contains("%pdf-") {
+        return false;
+    }
+    let head = head.trim_start_matches('\u{feff}').trim_start();
+    head.starts_with("<!doctype html") || head.starts_with("<?xml") || head.contains("<html")
+}
+
+/// Extract the text of an EPUB in reading (spine) order. Zip archives without
+/// an EPUB container, such as zipped HTML snapshots, yield all of their HTML
+/// files in name order.
+pub fn extract_epub_text(bytes: &[u8]) -> Result<String, WorkTextError> {
+    let err = |e: &dyn std::fmt::Display| WorkTextError::PdfExtract(format!("EPUB: {e}"));
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(bytes)).map_err(|e| err(&e))?;
+    let documents: Vec<String> = match zip_entry(&mut archive, "META-INF/container.xml")
+        .and_then(|c| markup_tags(&c, "rootfile").find_map(|t| markup_attr(t, "full-path")))
+    {
+        Some(opf_path) => {
+            let opf = zip_entry(&mut archive, &opf_path)
+                .ok_or_else(|| err(&format!("missing {opf_path}")))?;
+            let base = opf_path.rsplit_once('/').map_or("", |(dir, _)| dir);
+            epub_spine(&opf)
+                .into_iter()
+                .map(|href| if base.is_empty() { href } else { format!("{base}/{href}") })
+                .collect()
+        }
+        None => {
+            let mut names: Vec<String> = archive
+                .file_names()
+                .filter(|n| n.ends_with(".html") || n.ends_with(".htm") || n.ends_with(".xhtml"))
+                .map(String::from)
+                .collect();
+            names.sort();
+            names
+        }
+    };
+
+    let mut parts = Vec::new();
+    for name in &documents {
+        if let Some(html) = zip_entry(&mut archive, name) {
+            let text = html_to_text(&html);
+            if !text.is_empty() {
+                parts.push(text);
+            }
+        }
+    }
+    if parts.is_empty() {
+        return Err(err(&"no readable content documents"));
+    }
+    Ok(parts.join("\n\n"))
+}
+
+fn zip_entry(archive: &mut zip::ZipArchive<std::io::Cursor<&[u8]>>, name: &str) -> Option<String> {
+    use std::io::Read as _;
+    let mut file = archive.by_name(name).ok()?;
+    let mut buf = Vec::new();
+    file.read_to_end(&mut buf).ok()?;
+    Some(String::from_utf8_lossy(&buf).into_owned())
+}
+
+/// Content-document hrefs of an OPF package, in spine order.
+fn epub_spine(opf: &str) -> Vec<String> {
+    let manifest: Vec<(String, String, String)> = markup_tags(opf, "item")
+        .filter_map(|t| {
+            Some((
+                markup_attr(t, "id")?,
+                markup_attr(t, "href")?,
+                markup_attr(t, "media-type").unwrap_or_default(),
+            ))
+        })
+        .collect();
+    markup_tags(opf, "itemref")
+        .filter_map(|t| markup_attr(t, "idref"))
+        .filter_map(|idref| manifest.iter().find(|(id, _, _)| *id == idref))
+        .filter(|(_, _, media)| media.contains("html"))
+        .map(|(_, href, _)| percent_decode(href))
+        .collect()
+}
+
+/// Bodies of all `<name ...>` start tags (namespace prefixes allowed).
+fn markup_tags<'a>(xml: &'a str, name: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+    xml.split('<').skip(1).filter_map(move |s| {
+        let body = s.split('>').next()?;
+        let tag = body.split(|c: char| c.is_whitespace() || c == '/').next()?;
+        let local = tag.rsplit(':').next()?;
+        local.eq_ignore_ascii_case(name).then_some(body)
+    })
+}
+
+/// Value of attribute `key` in a start tag body.
+fn markup_attr(tag: &str, key: &str) -> Option<String> {
+    let mut rest = tag;
+    while let Some(pos) = rest.find(key) {
+        let before = rest[..pos].chars().next_back();
+        let after = rest[pos + key.len()..].trim_start();
+        rest = &rest[pos + key.len()..];
+        if !before.is_some_and(char::is_whitespace) {
+            continue;
+        }
+        let Some(value) = after.strip_prefix('=') else { continue };
+        let value = value.trim_start();
+        let quote = value.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+        let end = value[1..].find(quote)?;
+        return Some(decode_entities(&value[1..1 + end]));
+    }
+    None
+}
+
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%'
+            && let Some(hex) = s.get(i + 1..i + 3)
+            && let Ok(b) = u8::from_str_radix(hex, 16)
+        {
+            out.push(b);
+            i += 3;
+        } else {
+            out.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+fn decode_entities(s: &str) -> String {
+    if !s.contains('&') {
+        return s.to_string();
+    }
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        let decoded = rest[1..].find(';').filter(|&end| end <= 10).and_then(|end| {
+            let name = &rest[1..1 + end];
+            let c = match name {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some(' '),
+                "ndash" => Some('–'),
+                "mdash" => Some('—'),
+                "hellip" => Some('…'),
+                "lsquo" => Some('‘'),
+                "rsquo" => Some('’'),
+                "ldquo" => Some('“'),
+                "rdquo" => Some('”'),
+                _ => name
+                    .strip_prefix("#x")
+                    .or_else(|| name.strip_prefix("#X"))
+                    .and_then(|h| u32::from_str_radix(h, 16).ok())
+                    .or_else(|| name.strip_prefix('#').and_then(|d| d.parse().ok()))
+                    .and_then(char::from_u32),
+            };
+            c.map(|c| (c, end + 2))
+        });
+        match decoded {
+            Some((c, len)) => {
+                out.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Convert HTML or XHTML to plain text: headings become `#` lines, list items
+/// `- ` lines, and other block elements paragraph breaks. Scripts, styles, the
+/// document head, and navigation are dropped.
+pub fn html_to_text(html: &str) -> String {
+    const SKIP: &[&str] = &["head", "script", "style", "nav", "noscript", "svg", "template"];
+    const BLOCKS: &[&str] = &[
+        "p", "div", "section", "article", "main", "header", "footer", "aside", "blockquote",
+        "ul", "ol", "dl", "dt", "dd", "table", "tr", "figure", "figcaption", "pre", "hr",
+        "body", "caption",
+    ];
+
+    let mut w = TextWriter::default();
+    let mut skip_depth = 0usize;
+    let mut skip_tag = "";
+    let mut rest = html.trim_start_matches('\u{feff}');
+    while let Some(lt) = rest.find('<') {
+        if skip_depth == 0 {
+            w.text(&decode_entities(&rest[..lt]));
+        }
+        rest = &rest[lt..];
+        if let Some(after) = rest.strip_prefix("<!--") {
+            rest = after.find("-->").map_or("", |end| &after[end + 3..]);
+            continue;
+        }
+        let Some(gt) = rest.find('>') else { break };
+        let tag = &rest[1..gt];
+        rest = &rest[gt + 1..];
+        let closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        let name = name.rsplit(':').next().unwrap_or_default();
+
+        if let Some(&skip) = SKIP.iter().find(|s| **s == name) {
+            if skip_depth > 0 && skip == skip_tag {
+                skip_depth = if closing { skip_depth - 1 } else { skip_depth + 1 };
+            } else if skip_depth == 0 && !closing && !tag.ends_with('/') {
+                skip_depth = 1;
+                skip_tag = skip;
+            }
+            continue;
+        }
+        if skip_depth > 0 {
+            continue;
+        }
+        match name {
+            "br" => w.block(1),
+            "li" => {
+                w.block(1);
+                if !closing {
+                    w.prefix("- ");
+                }
+            }
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                w.block(2);
+                if !closing {
+                    let level = usize::from(name.as_bytes()[1] - b'0');
+                    w.prefix(&format!("{} ", "#".repeat(level)));
+                }
+            }
+            "td" | "th" if closing => w.text(" "),
+            _ if BLOCKS.contains(&name) => w.block(2),
+            _ => {}
+        }
+    }
+    if skip_depth == 0 {
+        w.text(&decode_entities(rest));
+    }
+    w.out.trim_end().to_string()
+}
+
+/// Whitespace-collapsing text builder for [`html_to_text`].
+#[derive(Default)]
+struct TextWriter {
+    out: String,
+    /// Newlines to emit before the next text.
+    pending_break: usize,
+    pending_space: bool,
+    pending_prefix: Option<String>,
+}
+
+impl TextWriter {
+    fn block(&mut self, newlines: usize) {
+        self.pending_break = self.pending_break.max(newlines);
+        self.pending_prefix = None;
+    }
+
+    fn prefix(&mut self, prefix: &str) {
+        self.pending_prefix = Some(prefix.to_string());
+    }
+
+    fn text(&mut self, s: &str) {
+        for c in s.chars() {
+            if c.is_whitespace() {
+                self.pending_space = true;
+                continue;
+            }
+            if self.pending_break > 0 || self.pending_prefix.is_some() {
+                if !self.out.is_empty() {
+                    let have = self.out.len() - self.out.trim_end_matches('\n').len();
+                    let want = self.pending_break.max(1);
+                    self.out.truncate(self.out.trim_end_matches(' ').len());
+                    for _ in have..want {
+                        self.out.push('\n');
+                    }
+                }
+                if let Some(prefix) = self.pending_prefix.take() {
+                    self.out.push_str(&prefix);
+                }
+                self.pending_break = 0;
+            } else if self.pending_space && !self.out.is_empty() && !self.out.ends_with('\n') {
+                self.out.push(' ');
+            }
+            self.pending_space = false;
+            self.out.push(c);
+        }
+    }
+}
+
+// ── PMC / JATS ──────────────────────────────────────────────────────────────
+
+/// Europe PMC REST API base, which serves JATS XML for the PMC open-access
+/// subset.
+const EUROPE_PMC_API: &str = "https://www.ebi.ac.uk/europepmc/webservices/rest";
+
+fn looks_like_jats(bytes: &[u8]) -> bool {
+    let head = String::from_utf8_lossy(&bytes[..bytes.len().min(1024)]).to_ascii_lowercase();
+    !head.contains("<html")
+        && ["<!doctype article", "<pmc-articleset", "<article ", "<article>"]
+            .iter()
+            .any(|marker| head.contains(marker))
+}
+
+/// `PMC1234`, `pmc1234` and `1234` all become `PMC1234`.
+fn normalize_pmcid(id: &str) -> Option<String> {
+    let id = id.trim();
+    let digits = match id.get(..3) {
+        Some(prefix) if prefix.eq_ignore_ascii_case("pmc") => &id[3..],
+        _ => id,
+    };
+    (!digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()))
+        .then(|| format!("PMC{digits}"))
+}
+
+/// The PMCID of a work, from the `ids.pmcid` URL OpenAlex records.
+pub fn pmcid_for_work(work: &Work) -> Option<String> {
+    let pmcid = work.ids.as_ref()?.pmcid.as_deref()?;
+    normalize_pmcid(pmcid.trim_end_matches('/').rsplit('/').next().unwrap_or(pmcid))
+}
+
+/// The PMCID in a Zotero item's `extra` field (a `PMCID: PMC1234` line, as
+/// written by Zotero's PubMed translator).
+pub fn zotero_item_pmcid(item: &papers_zotero::Item) -> Option<String> {
+    item.data.extra.as_deref()?.lines().find_map(|line| {
+        let (key, value) = line.split_once(':')?;
+        if key.trim().eq_ignore_ascii_case("pmcid") { normalize_pmcid(value) } else { None }
+    })
+}
+
+/// Download the JATS XML of `pmcid` from Europe PMC (at `api`). Articles
+/// outside the open-access subset are not served, and some open-access
+/// records carry only front matter; both count as misses.
+async fn fetch_pmc_jats(
+    http: &reqwest::Client,
+    api: &str,
+    pmcid: &str,
+) -> Result<Vec<u8>, SourceMiss> {
+    let url = format!("{}/{pmcid}/fullTextXML", api.trim_end_matches('/'));
+    let resp = http
+        .get(&url)
+        .header("User-Agent", DOWNLOAD_USER_AGENT)
+        .send()
+        .await
+        .map_err(|e| SourceMiss::Failed(e.to_string()))?;
+    if resp.status() == reqwest::StatusCode::NOT_FOUND {
+        return Err(SourceMiss::Failed(format!("{pmcid} is not in the PMC open-access subset")));
+    }
+    if !resp.status().is_success() {
+        return Err(SourceMiss::Failed(format!("HTTP {}", resp.status().as_u16())));
+    }
+    let bytes = resp.bytes().await.map_err(|e| SourceMiss::Failed(e.to_string()))?;
+    if !looks_like_jats(&bytes) || !String::from_utf8_lossy(&bytes).contains("<body") {
+        return Err(SourceMiss::Failed(format!("{pmcid} has no full-text body in PMC")));
+    }
+    Ok(bytes.to_vec())
+}
+
+/// The full text of a PMC open-access article as Markdown-style text (see
+/// [`jats_to_text`]), for callers that have a PMCID but no work, such as
+/// extraction of Zotero items without a PDF.
+pub async fn pmc_text(pmcid: &str) -> Result<String, WorkTextError> {
+    let pmcid = normalize_pmcid(pmcid)
+        .ok_or_else(|| WorkTextError::Pmc(format!("invalid PMCID {pmcid:?}")))?;
+    match fetch_pmc_jats(&reqwest::Client::new(), EUROPE_PMC_API, &pmcid).await {
+        Ok(bytes) => Ok(jats_to_text(&String::from_utf8_lossy(&bytes))),
+        Err(SourceMiss::Skipped(reason) | SourceMiss::Failed(reason)) => {
+            Err(WorkTextError::Pmc(reason))
+        }
+    }
+}
+
+/// Convert JATS article XML, as served by PMC, to the Markdown-style text
+/// [`html_to_text`] produces. The article title becomes a `#` heading; the
+/// abstract, body sections, acknowledgements, appendices and reference list
+/// become `##` headings, one level deeper per nested `<sec>`. Journal and
+/// author metadata, MathML and graphics are dropped. Figure and table
+/// captions are kept as paragraphs, list items and references as `- ` lines.
+pub fn jats_to_text(xml: &str) -> String {
+    const SKIP: &[&str] = &[
+        "graphic", "inline-graphic", "math", "object-id", "processing-meta",
+        "supplementary-material",
+    ];
+    const BLOCKS: &[&str] = &[
+        "p", "fig", "table-wrap", "table", "tr", "list", "disp-formula", "disp-quote",
+        "def-list", "def-item", "boxed-text", "statement", "fn", "table-wrap-foot", "front",
+        "body", "back", "sub-article",
+    ];
+    // Elements whose `<title>` is a heading, and the heading used without one.
+    const SECTIONS: &[(&str, Option<&str>)] = &[
+        ("sec", None),
+        ("app", None),
+        ("abstract", Some("Abstract")),
+        ("ack", Some("Acknowledgements")),
+        ("ref-list", Some("References")),
+        ("glossary", Some("Glossary")),
+    ];
+    let is_section = |name: &str| SECTIONS.iter().any(|(s, _)| *s == name);
+    let heading = |depth: usize| format!("{} ", "#".repeat((depth + 1).min(6)));
+
+    let mut w = TextWriter::default();
+    let mut stack: Vec<String> = Vec::new();
+    let mut skip_depth = 0usize;
+    let mut skip_tag = String::new();
+    let mut rest = xml.trim_start_matches('\u{feff}');
+    while let Some(lt) = rest.find('<') {
+        if skip_depth == 0 {
+            w.text(&decode_entities(&rest[..lt]));
+        }
+        rest = &rest[lt..];
+        if let Some(after) = rest.strip_prefix("<!--") {
+            rest = after.find("-->").map_or("", |end| &after[end + 3..]);
+            continue;
+        }
+        let Some(gt) = rest.find('>') else { break };
+        let tag = &rest[1..gt];
+        rest = &rest[gt + 1..];
+        if tag.starts_with(['?', '!']) {
+            continue;
+        }
+        let closing = tag.starts_with('/');
+        let self_closing = tag.ends_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        let name = name.rsplit(':').next().unwrap_or_default().to_string();
+
+        if skip_depth > 0 {
+            if name == skip_tag && !self_closing {
+                skip_depth = if closing { skip_depth - 1 } else { skip_depth + 1 };
+            }
+            continue;
+        }
+        let parent = stack.last().map(String::as_str).unwrap_or_default();
+        // Only the title and abstract are kept from the front matter, and
+        // section numbers are dropped from headings.
+        let skip = SKIP.contains(&name.as_str())
+            || (parent == "front" && name != "article-meta")
+            || (parent == "article-meta" && !matches!(name.as_str(), "title-group" | "abstract"))
+            || (parent == "title-group" && name != "article-title")
+            || (name == "abstract" && tag.contains("abstract-type"))
+            || (name == "label" && is_section(parent));
+        if skip && !closing {
+            if !self_closing {
+                skip_depth = 1;
+                skip_tag = name;
+            }
+            continue;
+        }
+
+        if closing {
+            if let Some(open) = stack.iter().rposition(|n| *n == name) {
+                stack.truncate(open);
+            }
+        } else if !self_closing {
+            stack.push(name.clone());
+        }
+        // The element this tag is directly inside of.
+        let enclosing = if closing || self_closing { stack.last() } else { stack.iter().nth_back(1) }
+            .map(String::as_str)
+            .unwrap_or_default();
+        let depth = stack.iter().filter(|n| is_section(n)).count();
+        match name.as_str() {
+            "break" => w.block(1),
+            "list-item" | "ref" => {
+                w.block(1);
+                if !closing {
+                    w.prefix("- ");
+                }
+            }
+            "article-title" if enclosing == "title-group" => {
+                w.block(2);
+                if !closing {
+                    w.prefix("# ");
+                }
+            }
+            "title" if closing && is_section(enclosing) => w.block(2),
+            "title" if is_section(enclosing) => {
+                w.block(2);
+                w.prefix(&heading(depth));
+            }
+            _ if is_section(&name) => {
+                w.block(2);
+                let untitled = SECTIONS.iter().find(|(s, _)| *s == name).and_then(|(_, d)| *d);
+                if let (false, Some(default)) = (closing || jats_has_title(rest), untitled) {
+                    w.prefix(&heading(depth));
+                    w.text(default);
+                    w.block(2);
+                }
+            }
+            "label" | "td" | "th" if closing => w.text(" "),
+            // Keep list items on their `- ` line.
+            "p" if enclosing == "list-item" => {}
+            _ if BLOCKS.contains(&name.as_str()) => w.block(2),
+            // Structured citations have no punctuation between their fields.
+            _ if closing && stack.iter().any(|n| n == "element-citation") => w.text(" "),
+            _ => {}
+        }
+    }
+    if skip_depth == 0 {
+        w.text(&decode_entities(rest));
+    }
+    w.out.trim_end().to_string()
+}
+
+/// Whether a section's content (`rest`, just after its start tag) opens with
+/// a `<title>`, possibly after a `<label>`.
+fn jats_has_title(rest: &str) -> bool {
+    let mut rest = rest.trim_start();
+    if rest.starts_with("<label") {
+        rest = rest.find("</label>").map_or("", |end| rest[end + 8..].trim_start());
+    }
+    rest.starts_with("<title>") || rest.starts_with("<title ")
+}
+
 /// Strip the `https://doi.org/` prefix from a DOI URL, returning the bare DOI.
 fn bare_doi(doi: &str) -> &str {
     doi.strip_prefix("https://doi.org/").unwrap_or(doi)
 }
 
 /// Extract the short OpenAlex ID (e.g. `W12345`) from a full URL.
-fn short_openalex_id(full_id: &str) -> &str {
-    full_id
-        .strip_prefix("https://openalex.org/")
-        .unwrap_or(full_id)
-}
-
 /// Check if a URL's host matches one of the whitelisted domains.
 fn is_whitelisted_url(url: &str) -> bool {
     DIRECT_PDF_DOMAINS
@@ -104,11 +964,43 @@ fn zotero_data_dir() -> Option<PathBuf> {
     dirs::home_dir().map(|h| h.join("Zotero"))
 }
 
-fn datalab_cache_dir(short_id: &str) -> Option<PathBuf> {
+/// Base directory of the DataLab cache: the tenant's `cache/datalab` inside a
+/// [`crate::tenant::scope`], else `PAPERS_DATALAB_CACHE_DIR`, else
+/// `{cache_dir}/papers/datalab`.
+pub fn datalab_cache_root() -> Option<PathBuf> {
+    if let Some(dir) = crate::tenant::current_data_dir() {
+        return Some(dir.join("cache").join("datalab"));
+    }
     if let Ok(base) = std::env::var("PAPERS_DATALAB_CACHE_DIR") {
-        return Some(PathBuf::from(base).join(short_id));
+        return Some(PathBuf::from(base));
     }
-    dirs::cache_dir().map(|d| d.join("papers").join("datalab").join(short_id))
+    dirs::cache_dir().map(|d| d.join("papers").join("datalab"))
+}
+
+fn datalab_cache_dir(short_id: &str) -> Option<PathBuf> {
+    datalab_cache_root().map(|base| base.join(short_id))
+}
+
+/// Where in-flight DataLab jobs are recorded (`.jobs` in the DataLab cache),
+/// so an escalation interrupted by a crash resumes instead of resubmitting.
+pub fn datalab_job_store() -> Option<papers_datalab::JobStore> {
+    datalab_cache_dir(".jobs").map(papers_datalab::JobStore::new)
+}
+
+pub use papers_datalab::JobProgress;
+
+/// Callback receiving the progress of DataLab conversions.
+pub type DatalabProgressHook = Arc<dyn Fn(&JobProgress) + Send + Sync>;
+
+tokio::task_local! {
+    static DATALAB_PROGRESS: DatalabProgressHook;
+}
+
+/// Run `fut` with DataLab conversions started inside it (e.g. a `work_text`
+/// escalation) reporting each poll to `hook`. Work spawned onto other tasks
+/// from inside `fut` does not inherit the hook.
+pub async fn with_datalab_progress<F: std::future::Future>(hook: DatalabProgressHook, fut: F) -> F::Output {
+    DATALAB_PROGRESS.scope(hook, fut).await
 }
 
 /// Upload the local DataLab cache for `item_key` to Zotero as
@@ -164,13 +1056,8 @@ pub fn datalab_cached_markdown(cache_id: &str) -> Option<String> {
 /// Scans the DataLab cache base directory and returns the name of every
 /// subdirectory that contains a `{key}.md` file.
 pub fn datalab_cached_item_keys() -> Vec<String> {
-    let base = if let Ok(base_str) = std::env::var("PAPERS_DATALAB_CACHE_DIR") {
-        PathBuf::from(base_str)
-    } else {
-        match dirs::cache_dir() {
-            Some(d) => d.join("papers").join("datalab"),
-            None => return vec![],
-        }
+    let Some(base) = datalab_cache_root() else {
+        return vec![];
     };
     if !base.is_dir() {
         return vec![];
@@ -208,7 +1095,8 @@ pub fn datalab_cache_dir_path(cache_id: &str) -> Option<std::path::PathBuf> {
 
 /// Return the directory where the open-access PDF for `doi` would be cached.
 ///
-/// Uses `PAPERS_DATALAB_CACHE_DIR` override when set, otherwise
+/// Inside a [`crate::tenant::scope`] this is the tenant's `cache/doi/{sanitized_doi}/`.
+/// Otherwise uses `PAPERS_DATALAB_CACHE_DIR` override when set, else
 /// `{cache_dir}/papers/doi/{sanitized_doi}/`.
 /// Slashes in the DOI are replaced with `_` to form a valid directory name.
 pub fn doi_pdf_cache_dir(doi: &str) -> Option<PathBuf> {
@@ -219,6 +1107,9 @@ pub fn doi_pdf_cache_dir(doi: &str) -> Option<PathBuf> {
         .or_else(|| doi.strip_prefix("doi:"))
         .unwrap_or(doi);
     let safe = bare.replace('/', "_");
+    if let Some(dir) = crate::tenant::current_data_dir() {
+        return Some(dir.join("cache").join("doi").join(safe));
+    }
     if let Ok(base) = std::env::var("PAPERS_DATALAB_CACHE_DIR") {
         return Some(PathBuf::from(base).join("doi").join(safe));
     }
@@ -267,6 +1158,59 @@ pub async fn try_download_open_access_pdf(
     try_openalex_content(http, work).await
 }
 
+/// Keep a downloaded PDF as `{cache_id}.pdf` in the DataLab cache, with a
+/// `meta.json` recording where it came from (unless an extraction already
+/// wrote one), so later [`work_text`] calls and RAG extraction read it
+/// instead of downloading it again. Anything that is not a PDF is ignored.
+/// Returns the path written.
+pub fn save_downloaded_pdf(
+    cache_id: &str,
+    bytes: &[u8],
+    source: &PdfSource,
+    title: Option<&str>,
+    doi: Option<&str>,
+) -> Option<PathBuf> {
+    if !bytes.starts_with(b"%PDF") {
+        return None;
+    }
+    let dir = datalab_cache_dir(cache_id)?;
+    std::fs::create_dir_all(&dir).ok()?;
+    let path = dir.join(format!("{cache_id}.pdf"));
+    std::fs::write(&path, bytes).ok()?;
+    let meta_path = dir.join("meta.json");
+    if !meta_path.exists() {
+        let url = match source {
+            PdfSource::DirectUrl { url } | PdfSource::Unpaywall { url, .. } | PdfSource::LinkResolver { url } => {
+                Some(url.clone())
+            }
+            _ => None,
+        };
+        let meta = ExtractionMeta {
+            item_key: cache_id.to_string(),
+            zotero_user_id: None,
+            title: title.map(String::from),
+            authors: None,
+            item_type: None,
+            date: None,
+            doi: doi.map(String::from),
+            url,
+            publication_title: None,
+            extracted_at: None,
+            processing_mode: None,
+            pdf_source: serde_json::to_value(source).ok(),
+        };
+        if let Ok(json) = serde_json::to_vec_pretty(&meta) {
+            let _ = std::fs::write(meta_path, json);
+        }
+    }
+    Some(path)
+}
+
+/// The PDF kept for `cache_id` by [`save_downloaded_pdf`], if any.
+pub fn cached_pdf_path(cache_id: &str) -> Option<PathBuf> {
+    datalab_cache_dir(cache_id).map(|d| d.join(format!("{cache_id}.pdf"))).filter(|p| p.is_file())
+}
+
 /// Metadata written alongside each DataLab extraction cache entry as `meta.json`.
 ///
 /// All fields except `item_key` are `Option` so that the struct can be read
@@ -295,11 +1239,16 @@ pub fn read_extraction_meta(cache_id: &str) -> Option<ExtractionMeta> {
 }
 
 /// Return an ISO 8601 UTC timestamp for the current moment (no external deps).
-fn iso_now() -> String {
+pub(crate) fn iso_now() -> String {
     let secs = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
         .map(|d| d.as_secs())
         .unwrap_or(0);
+    iso_from_secs(secs)
+}
+
+/// ISO 8601 UTC timestamp for `secs` since the unix epoch.
+pub(crate) fn iso_from_secs(secs: u64) -> String {
     // Civil date from unix epoch seconds using Hinnant's algorithm.
     let days = (secs / 86400) as i64;
     let z = days + 719_468;
@@ -405,7 +1354,20 @@ fn collect_pdf_urls(work: &Work) -> Vec<String> {
     urls
 }
 
-/// Brief Zotero library info for a work matched by DOI.
+/// Minimum confidence for a Zotero item matched by title and year alone.
+pub const ZOTERO_TITLE_MATCH_MIN: f64 = 0.8;
+
+/// How a Zotero item was matched to a work.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ZoteroMatchMethod {
+    /// The item's DOI equals the work's.
+    Doi,
+    /// Near-identical title and year; used when either side lacks a DOI.
+    Title,
+}
+
+/// Brief Zotero library info for a work found in the library.
 #[derive(Debug, Clone, Serialize)]
 pub struct ZoteroItemInfo {
     pub key: String,
@@ -414,20 +1376,57 @@ pub struct ZoteroItemInfo {
     pub has_pdf: bool,
     pub date_added: Option<String>,
     pub uri: String,
+    pub matched_by: ZoteroMatchMethod,
+    /// 1.0 for a DOI match, otherwise title similarity discounted by any
+    /// year difference. Surfaced as `zotero_match_confidence`.
+    #[serde(skip)]
+    pub confidence: f64,
+}
+
+/// Publication year of a Zotero item, from its parsed or raw date.
+pub(crate) fn zotero_item_year(item: &papers_zotero::Item) -> Option<i32> {
+    let date = item.meta.parsed_date.as_deref().or(item.data.date.as_deref())?;
+    date.get(..4)?.parse().ok()
 }
 
-/// Check if a work exists in the Zotero library, matched by DOI.
+/// How confidently `item` is the same publication as `work`.
 ///
-/// Returns `Ok(Some(...))` with brief metadata if found, `Ok(None)` if the
-/// work has no DOI or is not in the library, or an error on API failure.
+/// When both carry a DOI the DOIs decide: equal is a certain match and
+/// different rules the item out, however similar the titles. Otherwise the
+/// title similarity is scaled by 0.9 when a year is missing or off by one
+/// and by 0.5 when the years differ more; below
+/// [`ZOTERO_TITLE_MATCH_MIN`] there is no match.
+pub fn zotero_match_confidence(
+    work: &Work,
+    item: &papers_zotero::Item,
+) -> Option<(ZoteroMatchMethod, f64)> {
+    let item_doi = item.data.doi.as_deref().map(bare_doi).filter(|d| !d.is_empty());
+    if let (Some(work_doi), Some(item_doi)) = (work.doi.as_deref().map(bare_doi), item_doi) {
+        return work_doi
+            .eq_ignore_ascii_case(item_doi)
+            .then_some((ZoteroMatchMethod::Doi, 1.0));
+    }
+    let work_title = work.display_name.as_deref().or(work.title.as_deref())?;
+    let similarity = crate::versions::title_similarity(work_title, item.data.title.as_deref()?);
+    let year_factor = match (work.publication_year, zotero_item_year(item)) {
+        (Some(a), Some(b)) if a == b => 1.0,
+        (Some(a), Some(b)) if (a - b).abs() > 1 => 0.5,
+        _ => 0.9,
+    };
+    let confidence = similarity * year_factor;
+    (confidence >= ZOTERO_TITLE_MATCH_MIN).then_some((ZoteroMatchMethod::Title, confidence))
+}
+
+/// Check if a work exists in the Zotero library.
+///
+/// Items found by a title search are matched with [`zotero_match_confidence`]:
+/// a DOI match wins outright, otherwise the most confident title match is
+/// taken. Returns `Ok(Some(...))` with brief metadata if found, `Ok(None)` if
+/// the work has no title or is not in the library, or an error on API failure.
 pub async fn find_work_in_zotero(
     zotero: &ZoteroClient,
     work: &papers_openalex::Work,
 ) -> Result<Option<ZoteroItemInfo>, papers_zotero::ZoteroError> {
-    let doi = match &work.doi {
-        Some(d) => bare_doi(d),
-        None => return Ok(None),
-    };
     let title = work.display_name.as_deref().or(work.title.as_deref());
 
     // Search by title using the default q mode (title/creator/year only — fast).
@@ -444,52 +1443,64 @@ pub async fn find_work_in_zotero(
         return Ok(None);
     };
 
-    for item in &items {
-        let item_doi = match &item.data.doi {
-            Some(d) => d,
-            None => continue,
-        };
-        if !item_doi.eq_ignore_ascii_case(doi) {
-            continue;
-        }
-
-        let t_children = std::time::Instant::now();
-        let children = zotero
-            .list_item_children(&item.key, &ItemListParams::default())
-            .await?;
-        eprintln!("[timing] zotero list_item_children: {:?}", t_children.elapsed());
-        let has_pdf = children.items.iter().any(|child| {
-            child.data.content_type.as_deref() == Some("application/pdf")
-                && matches!(
-                    child.data.link_mode.as_deref(),
-                    Some("imported_file" | "imported_url")
-                )
+    let best = items
+        .iter()
+        .filter_map(|item| zotero_match_confidence(work, item).map(|m| (item, m)))
+        .max_by(|(_, (ma, a)), (_, (mb, b))| {
+            (*ma == ZoteroMatchMethod::Doi)
+                .cmp(&(*mb == ZoteroMatchMethod::Doi))
+                .then(a.total_cmp(b))
         });
+    let Some((item, (matched_by, confidence))) = best else {
+        return Ok(None);
+    };
 
-        let tags: Vec<String> = item.data.tags.iter().map(|t| t.tag.clone()).collect();
-        let uri = format!("zotero://select/library/items/{}", item.key);
-        return Ok(Some(ZoteroItemInfo {
-            key: item.key.clone(),
-            item_type: item.data.item_type.clone(),
-            tags,
-            has_pdf,
-            date_added: item.data.date_added.clone(),
-            uri,
-        }));
-    }
+    let t_children = std::time::Instant::now();
+    let children = zotero
+        .list_item_children(&item.key, &ItemListParams::default())
+        .await?;
+    eprintln!("[timing] zotero list_item_children: {:?}", t_children.elapsed());
+    let has_pdf = children.items.iter().any(|child| {
+        child.data.content_type.as_deref() == Some("application/pdf")
+            && matches!(
+                child.data.link_mode.as_deref(),
+                Some("imported_file" | "imported_url")
+            )
+    });
+
+    let tags: Vec<String> = item.data.tags.iter().map(|t| t.tag.clone()).collect();
+    let uri = format!("zotero://select/library/items/{}", item.key);
+    Ok(Some(ZoteroItemInfo {
+        key: item.key.clone(),
+        item_type: item.data.item_type.clone(),
+        tags,
+        has_pdf,
+        date_added: item.data.date_added.clone(),
+        uri,
+        matched_by,
+        confidence,
+    }))
+}
 
-    Ok(None)
+/// Position of the attachment's content type in [`TEXT_ATTACHMENT_TYPES`].
+fn text_attachment_rank(item: &papers_zotero::Item) -> Option<usize> {
+    let content_type = item.data.content_type.as_deref()?;
+    TEXT_ATTACHMENT_TYPES.iter().position(|t| *t == content_type)
 }
 
-/// Try to find and download a PDF from Zotero (local storage first, then remote API).
-///
-/// Returns `(pdf_bytes, source, zotero_item_key)` where `zotero_item_key` is the
-/// parent bibliographic item key (e.g. `U9PRIZJ7`), suitable for use as a cache ID.
-pub async fn try_zotero(
+/// A Zotero item whose DOI matches the work, with its text attachments (PDF,
+/// then EPUB, then HTML snapshot) that have a stored file.
+struct ZoteroAttachments {
+    item_key: String,
+    attachments: Vec<papers_zotero::Item>,
+}
+
+/// Find the library item for `doi` and list its stored text attachments.
+async fn find_zotero_attachments(
     zotero: &ZoteroClient,
     doi: &str,
     title: Option<&str>,
-) -> Result<Option<(Vec<u8>, PdfSource, String)>, WorkTextError> {
+) -> Result<Option<ZoteroAttachments>, WorkTextError> {
     // Zotero API's `q` parameter only searches title, creator, year, and full-text
     // content — it does NOT search metadata fields like DOI (per Zotero docs:
     // "Searching of other fields will be possible in the future").
@@ -500,6 +1511,7 @@ pub async fn try_zotero(
     }
     candidate_queries.push(doi.to_string());
 
+    let mut first_match = None;
     for query in &candidate_queries {
         let params = ItemListParams::builder()
             .q(query.as_str())
@@ -507,127 +1519,171 @@ pub async fn try_zotero(
             .build();
 
         let results = zotero.list_top_items(&params).await?;
-        if results.items.is_empty() {
-            continue;
-        }
-
         for item in &results.items {
             // Check that this item's DOI actually matches
-            let item_doi = match &item.data.doi {
-                Some(d) => d,
-                None => continue,
-            };
-            if !item_doi.eq_ignore_ascii_case(doi) {
+            if !item.data.doi.as_deref().is_some_and(|d| d.eq_ignore_ascii_case(doi)) {
                 continue;
             }
-
-        // Get children to find PDF attachment
-        let children = zotero
-            .list_item_children(&item.key, &ItemListParams::default())
-            .await?;
-
-        for child in &children.items {
-            let is_pdf = child
-                .data
-                .content_type
-                .as_deref()
-                == Some("application/pdf");
-            let has_local_file = matches!(
-                child.data.link_mode.as_deref(),
-                Some("imported_file" | "imported_url")
-            );
-
-            if !is_pdf || !has_local_file {
-                continue;
+            let mut attachments: Vec<papers_zotero::Item> = zotero
+                .list_item_children(&item.key, &ItemListParams::default())
+                .await?
+                .items
+                .into_iter()
+                .filter(|child| {
+                    text_attachment_rank(child).is_some()
+                        && matches!(
+                            child.data.link_mode.as_deref(),
+                            Some("imported_file" | "imported_url")
+                        )
+                })
+                .collect();
+            attachments.sort_by_key(|child| text_attachment_rank(child).unwrap_or(usize::MAX));
+            let found = ZoteroAttachments { item_key: item.key.clone(), attachments };
+            if !found.attachments.is_empty() {
+                return Ok(Some(found));
             }
+            first_match.get_or_insert(found);
+        }
+    }
+    Ok(first_match)
+}
 
-            // Try local file first
-            if let Some(filename) = &child.data.filename {
-                if let Some(data_dir) = zotero_data_dir() {
-                    let local_path = data_dir
-                        .join("storage")
-                        .join(&child.key)
-                        .join(filename);
-                    if local_path.exists() {
-                        let bytes = tokio::fs::read(&local_path)
-                            .await
-                            .map_err(|e| WorkTextError::PdfExtract(format!("Failed to read local file: {e}")))?;
-                        return Ok(Some((
-                            bytes,
-                            PdfSource::ZoteroLocal {
-                                path: local_path.to_string_lossy().into_owned(),
-                            },
-                            item.key.clone(),
-                        )));
-                    }
-                }
-            }
+/// Read the first attachment present in local Zotero storage.
+async fn read_local_attachment(
+    found: &ZoteroAttachments,
+) -> Result<(Vec<u8>, PdfSource), SourceMiss> {
+    let data_dir = zotero_data_dir()
+        .ok_or_else(|| SourceMiss::Skipped("no local Zotero data directory".into()))?;
+    for child in &found.attachments {
+        let Some(filename) = &child.data.filename else { continue };
+        let local_path = data_dir.join("storage").join(&child.key).join(filename);
+        if local_path.exists() {
+            let bytes = tokio::fs::read(&local_path)
+                .await
+                .map_err(|e| SourceMiss::Failed(format!("failed to read local file: {e}")))?;
+            let path = local_path.to_string_lossy().into_owned();
+            return Ok((bytes, PdfSource::ZoteroLocal { path }));
+        }
+    }
+    Err(SourceMiss::Failed(format!(
+        "no attachment file under {}",
+        data_dir.join("storage").display()
+    )))
+}
 
-            // Try remote download
-            match zotero.download_item_file(&child.key).await {
-                Ok(bytes) if !bytes.is_empty() => {
-                    return Ok(Some((
-                        bytes,
-                        PdfSource::ZoteroRemote {
-                            item_key: child.key.clone(),
-                        },
-                        item.key.clone(),
-                    )));
-                }
-                _ => continue,
+/// Download the first attachment the Zotero API serves.
+async fn download_remote_attachment(
+    zotero: &ZoteroClient,
+    found: &ZoteroAttachments,
+) -> Result<(Vec<u8>, PdfSource), SourceMiss> {
+    let mut last_error = String::from("attachment file is empty");
+    for child in &found.attachments {
+        match zotero.download_item_file(&child.key).await {
+            Ok(bytes) if !bytes.is_empty() => {
+                return Ok((bytes, PdfSource::ZoteroRemote { item_key: child.key.clone() }));
             }
-        }
+            Ok(_) => {}
+            Err(e) => last_error = e.to_string(),
         }
     }
+    Err(SourceMiss::Failed(last_error))
+}
 
-    Ok(None)
+/// Try to find and download a PDF from Zotero (local storage first, then remote API).
+/// Items without a PDF fall back to an EPUB or HTML snapshot attachment.
+///
+/// Returns `(pdf_bytes, source, zotero_item_key)` where `zotero_item_key` is the
+/// parent bibliographic item key (e.g. `U9PRIZJ7`), suitable for use as a cache ID.
+pub async fn try_zotero(
+    zotero: &ZoteroClient,
+    doi: &str,
+    title: Option<&str>,
+) -> Result<Option<(Vec<u8>, PdfSource, String)>, WorkTextError> {
+    let Some(found) = find_zotero_attachments(zotero, doi, title).await? else {
+        return Ok(None);
+    };
+    let fetched = match read_local_attachment(&found).await {
+        Ok(fetched) => Ok(fetched),
+        Err(_) => download_remote_attachment(zotero, &found).await,
+    };
+    Ok(fetched.ok().map(|(bytes, source)| (bytes, source, found.item_key)))
 }
 
-/// Try downloading a PDF from direct URLs (whitelisted domains only).
-async fn try_direct_urls(
+/// Why a source yielded no file.
+enum SourceMiss {
+    Skipped(String),
+    Failed(String),
+}
+
+/// User-Agent sent when downloading PDFs and landing pages.
+const DOWNLOAD_USER_AGENT: &str =
+    "papers-mcp/0.1 (https://github.com/mmgeorge/papers; mailto:papers@example.com)";
+
+/// GET `url` and return the body if it is a non-empty PDF.
+async fn download_pdf(http: &reqwest::Client, url: &str) -> Result<Vec<u8>, String> {
+    let resp = http
+        .get(url)
+        .header("User-Agent", DOWNLOAD_USER_AGENT)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    if !resp.status().is_success() {
+        return Err(format!("HTTP {}", resp.status().as_u16()));
+    }
+    let is_pdf = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|ct| ct.contains("application/pdf"));
+    if !is_pdf {
+        return Err("response is not a PDF".into());
+    }
+    let bytes = resp.bytes().await.map_err(|e| e.to_string())?;
+    if bytes.is_empty() {
+        return Err("empty response".into());
+    }
+    Ok(bytes.to_vec())
+}
+
+/// Try each URL in turn, reporting the last failure when none works.
+async fn download_first_pdf(
     http: &reqwest::Client,
     urls: &[String],
-) -> Result<Option<(Vec<u8>, PdfSource)>, WorkTextError> {
+) -> Result<(Vec<u8>, String), String> {
+    let mut last_error = String::from("no URLs");
     for url in urls {
-        if !is_whitelisted_url(url) {
-            continue;
+        match download_pdf(http, url).await {
+            Ok(bytes) => return Ok((bytes, url.clone())),
+            Err(e) => last_error = format!("{url}: {e}"),
         }
+    }
+    Err(last_error)
+}
 
-        let resp = http
-            .get(url)
-            .header(
-                "User-Agent",
-                "papers-mcp/0.1 (https://github.com/mmgeorge/papers; mailto:papers@example.com)",
-            )
-            .send()
-            .await;
-
-        let resp = match resp {
-            Ok(r) if r.status().is_success() => r,
-            _ => continue,
-        };
-
-        // Verify content type
-        let is_pdf = resp
-            .headers()
-            .get("content-type")
-            .and_then(|v| v.to_str().ok())
-            .is_some_and(|ct| ct.contains("application/pdf"));
-
-        if !is_pdf {
-            continue;
-        }
+/// Try downloading a PDF from direct URLs (whitelisted domains only).
+async fn try_direct_urls(
+    http: &reqwest::Client,
+    urls: &[String],
+) -> Result<Option<(Vec<u8>, PdfSource)>, WorkTextError> {
+    Ok(fetch_direct_urls(http, urls)
+        .await
+        .ok()
+        .map(|(bytes, url)| (bytes, PdfSource::DirectUrl { url })))
+}
 
-        let bytes = resp.bytes().await?.to_vec();
-        if !bytes.is_empty() {
-            return Ok(Some((
-                bytes,
-                PdfSource::DirectUrl { url: url.clone() },
-            )));
-        }
+async fn fetch_direct_urls(
+    http: &reqwest::Client,
+    urls: &[String],
+) -> Result<(Vec<u8>, String), SourceMiss> {
+    let allowed: Vec<String> = urls.iter().filter(|u| is_whitelisted_url(u)).cloned().collect();
+    if allowed.is_empty() {
+        return Err(SourceMiss::Skipped(if urls.is_empty() {
+            "no PDF URLs in OpenAlex".into()
+        } else {
+            format!("{} PDF URLs, none on a whitelisted domain", urls.len())
+        }));
     }
-
-    Ok(None)
+    download_first_pdf(http, &allowed).await.map_err(SourceMiss::Failed)
 }
 
 /// Try downloading from the OpenAlex Content API.
@@ -635,40 +1691,270 @@ async fn try_openalex_content(
     http: &reqwest::Client,
     work: &Work,
 ) -> Result<Option<(Vec<u8>, PdfSource)>, WorkTextError> {
+    Ok(fetch_openalex_content(http, work)
+        .await
+        .ok()
+        .map(|bytes| (bytes, PdfSource::OpenAlexContent)))
+}
+
+async fn fetch_openalex_content(http: &reqwest::Client, work: &Work) -> Result<Vec<u8>, SourceMiss> {
     let has_pdf = work
         .has_content
         .as_ref()
         .and_then(|hc| hc.pdf)
         .unwrap_or(false);
-
     if !has_pdf {
-        return Ok(None);
+        return Err(SourceMiss::Skipped("OpenAlex has no PDF for this work".into()));
     }
 
     let api_key = match std::env::var("OPENALEX_API_KEY") {
         Ok(key) if !key.is_empty() => key,
-        _ => return Ok(None),
+        _ => return Err(SourceMiss::Skipped("OPENALEX_API_KEY not set".into())),
     };
 
-    let short_id = short_openalex_id(&work.id);
+    let short_id = short_id(&work.id);
     let url = format!(
         "https://content.openalex.org/works/{}.pdf?api_key={}",
         short_id, api_key
     );
 
-    let resp = http.get(&url).send().await;
+    let resp = http.get(&url).send().await.map_err(|e| SourceMiss::Failed(e.to_string()))?;
+    if !resp.status().is_success() {
+        return Err(SourceMiss::Failed(format!("HTTP {}", resp.status().as_u16())));
+    }
+    let bytes = resp.bytes().await.map_err(|e| SourceMiss::Failed(e.to_string()))?;
+    if bytes.is_empty() {
+        return Err(SourceMiss::Failed("empty response".into()));
+    }
+    Ok(bytes.to_vec())
+}
+
+/// Unpaywall REST API base.
+const UNPAYWALL_API: &str = "https://api.unpaywall.org/v2";
+
+/// One open-access copy listed by Unpaywall.
+#[derive(Debug, Deserialize)]
+struct UnpaywallLocation {
+    url_for_pdf: Option<String>,
+    url_for_landing_page: Option<String>,
+    host_type: Option<String>,
+    version: Option<String>,
+}
+
+/// The parts of an Unpaywall `/v2/{doi}` record used to find a PDF.
+#[derive(Debug, Deserialize)]
+struct UnpaywallRecord {
+    best_oa_location: Option<UnpaywallLocation>,
+    #[serde(default)]
+    oa_locations: Vec<UnpaywallLocation>,
+}
 
-    let resp = match resp {
-        Ok(r) if r.status().is_success() => r,
-        _ => return Ok(None),
+/// Look `doi` up in Unpaywall (at `api`) and download an open-access copy,
+/// best location first.
+///
+/// Direct PDF links are tried first. Hybrid-OA publisher locations often list
+/// only a landing page, so landing pages are tried next, following the
+/// `citation_pdf_url` meta tag publishers expose for indexers.
+async fn fetch_unpaywall(
+    http: &reqwest::Client,
+    api: &str,
+    doi: &str,
+    email: &str,
+) -> Result<(Vec<u8>, PdfSource), SourceMiss> {
+    let url = format!("{api}/{doi}");
+    let resp = http
+        .get(&url)
+        .query(&[("email", email)])
+        .send()
+        .await
+        .map_err(|e| SourceMiss::Failed(e.to_string()))?;
+    if !resp.status().is_success() {
+        return Err(SourceMiss::Failed(format!("Unpaywall HTTP {}", resp.status().as_u16())));
+    }
+    let record: UnpaywallRecord =
+        resp.json().await.map_err(|e| SourceMiss::Failed(e.to_string()))?;
+    let locations: Vec<&UnpaywallLocation> =
+        record.best_oa_location.iter().chain(&record.oa_locations).collect();
+    let found = |loc: &UnpaywallLocation, url: &str| PdfSource::Unpaywall {
+        url: url.to_string(),
+        host_type: loc.host_type.clone(),
+        version: loc.version.clone(),
     };
 
-    let bytes = resp.bytes().await?.to_vec();
-    if !bytes.is_empty() {
-        return Ok(Some((bytes, PdfSource::OpenAlexContent)));
+    let mut tried: Vec<String> = Vec::new();
+    let mut last_error = None;
+    for loc in &locations {
+        let Some(pdf) = loc.url_for_pdf.as_deref() else { continue };
+        if tried.iter().any(|u| u == pdf) {
+            continue;
+        }
+        tried.push(pdf.to_string());
+        match download_pdf(http, pdf).await {
+            Ok(bytes) => return Ok((bytes, found(loc, pdf))),
+            Err(e) => last_error = Some(format!("{pdf}: {e}")),
+        }
+    }
+    let mut pages_tried: Vec<&str> = Vec::new();
+    for loc in &locations {
+        let Some(page) = loc.url_for_landing_page.as_deref() else { continue };
+        if pages_tried.contains(&page) {
+            continue;
+        }
+        pages_tried.push(page);
+        let pdf = match landing_page_pdf_url(http, page).await {
+            Ok(pdf) if !tried.contains(&pdf) => pdf,
+            Ok(_) => continue,
+            Err(e) => {
+                last_error = Some(format!("{page}: {e}"));
+                continue;
+            }
+        };
+        match download_pdf(http, &pdf).await {
+            Ok(bytes) => return Ok((bytes, found(loc, &pdf))),
+            Err(e) => last_error = Some(format!("{pdf}: {e}")),
+        }
+        tried.push(pdf);
     }
+    Err(SourceMiss::Failed(
+        last_error.unwrap_or_else(|| "Unpaywall lists no open-access copy".into()),
+    ))
+}
 
-    Ok(None)
+/// Fetch an article landing page and resolve its `citation_pdf_url` against
+/// the final (post-redirect) page URL.
+async fn landing_page_pdf_url(http: &reqwest::Client, page: &str) -> Result<String, String> {
+    let resp = http
+        .get(page)
+        .header("User-Agent", DOWNLOAD_USER_AGENT)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    if !resp.status().is_success() {
+        return Err(format!("HTTP {}", resp.status().as_u16()));
+    }
+    let base = resp.url().clone();
+    let html = resp.text().await.map_err(|e| e.to_string())?;
+    let href = citation_pdf_url(&html).ok_or("landing page has no citation_pdf_url")?;
+    base.join(&href).map(String::from).map_err(|e| e.to_string())
+}
+
+/// The `content` of the first `<meta name="citation_pdf_url">` tag in `html`.
+fn citation_pdf_url(html: &str) -> Option<String> {
+    let lower = html.to_ascii_lowercase();
+    let mut from = 0;
+    while let Some(i) = lower[from..].find("<meta") {
+        let start = from + i;
+        let end = start + lower[start..].find('>')?;
+        let tag = &html[start..end];
+        if html_attr(tag, "name").is_some_and(|n| n.eq_ignore_ascii_case("citation_pdf_url")) {
+            return html_attr(tag, "content")
+                .filter(|c| !c.trim().is_empty())
+                .map(|c| c.trim().replace("&amp;", "&"));
+        }
+        from = end;
+    }
+    None
+}
+
+/// Value of attribute `name` (lowercase) in an HTML start tag, quoted or not.
+fn html_attr<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let lower = tag.to_ascii_lowercase();
+    let mut from = 0;
+    while let Some(i) = lower[from..].find(name) {
+        let at = from + i;
+        from = at + name.len();
+        let rest = lower[from..].trim_start();
+        if !lower[..at].ends_with(|c: char| c.is_ascii_whitespace()) || !rest.starts_with('=') {
+            continue;
+        }
+        // `rest` is a suffix of `lower`, which has the same byte offsets as `tag`.
+        let value = tag[tag.len() - rest.len() + 1..].trim_start();
+        return match value.chars().next()? {
+            quote @ ('"' | '\'') => value[1..].split(quote).next(),
+            _ => value.split(|c: char| c.is_ascii_whitespace()).next(),
+        };
+    }
+    None
+}
+
+/// A file one source produced, before extraction.
+struct Fetched {
+    bytes: Vec<u8>,
+    source: PdfSource,
+    /// Parent item key, for files that came from Zotero.
+    zotero_item: Option<String>,
+}
+
+/// The Zotero lookup, shared by both Zotero sources so it runs once.
+type ZoteroLookup<'a> = Shared<BoxFuture<'a, Result<Arc<Option<ZoteroAttachments>>, String>>>;
+
+/// Everything the sources of one [`work_text_with_sources`] call share.
+struct SourceContext<'a> {
+    http: reqwest::Client,
+    work: &'a Work,
+    doi: Option<&'a str>,
+    zotero: Option<&'a ZoteroClient>,
+    zotero_lookup: Option<ZoteroLookup<'a>>,
+    config: &'a TextSourceConfig,
+}
+
+/// Fetch a work's file from one source.
+async fn fetch_from(cx: &SourceContext<'_>, source: TextSource) -> Result<Fetched, SourceMiss> {
+    let from_web = |(bytes, source)| Fetched { bytes, source, zotero_item: None };
+    match source {
+        TextSource::ZoteroLocal | TextSource::ZoteroRemote => {
+            let (Some(z), Some(lookup)) = (cx.zotero, &cx.zotero_lookup) else {
+                return Err(SourceMiss::Skipped(if cx.zotero.is_none() {
+                    "Zotero not configured".into()
+                } else {
+                    "work has no DOI".into()
+                }));
+            };
+            let found = lookup.clone().await.map_err(SourceMiss::Failed)?;
+            let Some(found) = found.as_ref() else {
+                return Err(SourceMiss::Failed("not in the Zotero library".into()));
+            };
+            if found.attachments.is_empty() {
+                return Err(SourceMiss::Failed("no stored PDF, EPUB or HTML attachment".into()));
+            }
+            let (bytes, pdf) = if source == TextSource::ZoteroLocal {
+                read_local_attachment(found).await?
+            } else {
+                download_remote_attachment(z, found).await?
+            };
+            Ok(Fetched { bytes, source: pdf, zotero_item: Some(found.item_key.clone()) })
+        }
+        TextSource::Pmc => match pmcid_for_work(cx.work) {
+            None => Err(SourceMiss::Skipped("work has no PMCID".into())),
+            Some(pmcid) => fetch_pmc_jats(&cx.http, EUROPE_PMC_API, &pmcid)
+                .await
+                .map(|bytes| from_web((bytes, PdfSource::Pmc { pmcid }))),
+        },
+        TextSource::OaUrls => fetch_direct_urls(&cx.http, &collect_pdf_urls(cx.work))
+            .await
+            .map(|(bytes, url)| from_web((bytes, PdfSource::DirectUrl { url }))),
+        TextSource::OpenalexContent => fetch_openalex_content(&cx.http, cx.work)
+            .await
+            .map(|bytes| from_web((bytes, PdfSource::OpenAlexContent))),
+        TextSource::Unpaywall => match (&cx.config.unpaywall_email, cx.doi) {
+            (None, _) => Err(SourceMiss::Skipped("no Unpaywall email configured".into())),
+            (_, None) => Err(SourceMiss::Skipped("work has no DOI".into())),
+            (Some(email), Some(doi)) => {
+                fetch_unpaywall(&cx.http, UNPAYWALL_API, doi, email).await.map(from_web)
+            }
+        },
+        TextSource::LinkResolver => match (&cx.config.link_resolver, cx.doi) {
+            (None, _) => Err(SourceMiss::Skipped("no link resolver configured".into())),
+            (_, None) => Err(SourceMiss::Skipped("work has no DOI".into())),
+            (Some(template), Some(doi)) => {
+                let url = template.replace("{doi}", doi);
+                match download_pdf(&cx.http, &url).await {
+                    Ok(bytes) => Ok(from_web((bytes, PdfSource::LinkResolver { url }))),
+                    Err(e) => Err(SourceMiss::Failed(e)),
+                }
+            }
+        },
+    }
 }
 
 /// Returns true if `key` is a valid Zotero item key (8 ASCII uppercase letters or digits).
@@ -801,7 +2087,9 @@ async fn upload_papers_zip(
 /// Extract text from PDF bytes.
 ///
 /// Checks the extract cache and legacy DataLab cache first. Falls back to
-/// local pdfium text extraction if no cache is available.
+/// local pdfium text extraction if no cache is available, escalating to the
+/// [`default_extractor`] when that text looks like junk (see
+/// [`extract_local_with`]).
 ///
 /// `zotero_id` is used as the on-disk cache ID. When `zotero` is `Some`,
 /// results are backed up to/restored from a `papers_extract_{key}.zip`
@@ -812,16 +2100,29 @@ pub async fn do_extract(
     zotero: Option<&ZoteroClient>,
     source: &mut PdfSource,
 ) -> Result<String, WorkTextError> {
+    do_extract_with_report(pdf_bytes, zotero_id, zotero, source)
+        .await
+        .map(|(text, _)| text)
+}
+
+/// [`do_extract`], also returning the [`ExtractionReport`] when the text was
+/// extracted locally from a PDF.
+pub async fn do_extract_with_report(
+    pdf_bytes: Vec<u8>,
+    zotero_id: &str,
+    zotero: Option<&ZoteroClient>,
+    source: &mut PdfSource,
+) -> Result<(String, Option<ExtractionReport>), WorkTextError> {
     // --- check new extract cache ---
     if let Some(md) = crate::extract_cache::read_cached_markdown(zotero_id) {
         *source = PdfSource::LocalExtract;
-        return Ok(md);
+        return Ok((md, None));
     }
 
     // --- check legacy DataLab cache ---
     if let Some(md) = datalab_cached_markdown(zotero_id) {
         *source = PdfSource::LocalExtract;
-        return Ok(md);
+        return Ok((md, None));
     }
 
     // --- Zotero cache check (papers_extract_*.zip) ---
@@ -834,7 +2135,7 @@ pub async fn do_extract(
                             let md_path = dir.join(format!("{zotero_id}.md"));
                             if let Ok(text) = std::fs::read_to_string(&md_path) {
                                 *source = PdfSource::LocalExtract;
-                                return Ok(text);
+                                return Ok((text, None));
                             }
                         }
                     }
@@ -848,16 +2149,633 @@ pub async fn do_extract(
     }
 
     // --- fall back to local pdfium extraction ---
-    extract_text(&pdf_bytes)
+    let extractor = default_extractor();
+    extract_local_with(pdf_bytes, zotero_id, zotero, source, extractor.as_deref()).await
+}
+
+/// Extract text locally and, for PDFs, score it with
+/// [`score_pages`](crate::text_quality::score_pages).
+///
+/// Poor text is replaced by a DataLab "balanced" conversion when `datalab` is
+/// set; the conversion is cached under `cache_id` like any DataLab extraction
+/// (and backed up to Zotero when `cache_id` is an item key), so the next call
+/// is a cache hit. Without DataLab, or when the conversion fails, the local
+/// text is returned with a warning in the report.
+pub async fn extract_local_checked(
+    bytes: Vec<u8>,
+    cache_id: &str,
+    zotero: Option<&ZoteroClient>,
+    source: &PdfSource,
+    datalab: Option<&papers_datalab::DatalabClient>,
+) -> Result<(String, Option<ExtractionReport>), WorkTextError> {
+    let extractor = datalab.map(|d| d as &dyn Extractor);
+    extract_local_with(bytes, cache_id, zotero, source, extractor).await
+}
+
+/// [`extract_local_checked`] escalating poor text to any [`Extractor`].
+pub async fn extract_local_with(
+    bytes: Vec<u8>,
+    cache_id: &str,
+    zotero: Option<&ZoteroClient>,
+    source: &PdfSource,
+    extractor: Option<&dyn Extractor>,
+) -> Result<(String, Option<ExtractionReport>), WorkTextError> {
+    if bytes.starts_with(b"PK\x03\x04") || looks_like_jats(&bytes) || looks_like_html(&bytes) {
+        return extract_text(&bytes).map(|text| (text, None));
+    }
+    let pages = pdf_extract::extract_text_from_mem_by_pages(&bytes)
+        .map_err(|e| WorkTextError::PdfExtract(e.to_string()))?;
+    let quality = crate::text_quality::score_pages(&pages);
+    let text = pages.concat();
+    if !quality.is_poor() {
+        return Ok((text, Some(ExtractionReport { quality, escalated_to: None, warning: None })));
+    }
+
+    let poor = format!(
+        "local text looks poor (score {:.2}, {} of {} pages empty)",
+        quality.score, quality.empty_pages, quality.pages
+    );
+    let Some(extractor) = extractor else {
+        let warning =
+            format!("{poor}; set DATALAB_API_KEY, MARKER_URL or GROBID_URL to escalate to a layout-aware extractor");
+        return Ok((text, Some(ExtractionReport { quality, escalated_to: None, warning: Some(warning) })));
+    };
+    match escalate(extractor, bytes, cache_id, zotero, source).await {
+        Ok(markdown) => {
+            let report = ExtractionReport {
+                quality,
+                escalated_to: Some(extractor.name().to_string()),
+                warning: None,
+            };
+            Ok((markdown, Some(report)))
+        }
+        Err(e) => {
+            let warning = format!("{poor}; escalation to {} failed: {e}", extractor.name());
+            Ok((text, Some(ExtractionReport { quality, escalated_to: None, warning: Some(warning) })))
+        }
+    }
+}
+
+/// Convert a PDF with `extractor` and cache the result (markdown, Marker JSON
+/// and images, with `meta.json`) under `cache_id` in the DataLab cache, where
+/// the RAG ingester reads it.
+async fn escalate(
+    extractor: &dyn Extractor,
+    bytes: Vec<u8>,
+    cache_id: &str,
+    zotero: Option<&ZoteroClient>,
+    source: &PdfSource,
+) -> Result<String, String> {
+    let extraction = extractor.convert(bytes, cache_id).await?;
+    if extraction.markdown.trim().is_empty() {
+        return Err("no markdown returned".into());
+    }
+
+    if let Some(dir) = datalab_cache_dir(cache_id)
+        && std::fs::create_dir_all(&dir).is_ok()
+        && std::fs::write(dir.join(format!("{cache_id}.md")), &extraction.markdown).is_ok()
+    {
+        if let Some(json) = &extraction.json
+            && let Ok(bytes) = serde_json::to_vec(json)
+        {
+            let _ = std::fs::write(dir.join(format!("{cache_id}.json")), bytes);
+        }
+        if !extraction.images.is_empty() && std::fs::create_dir_all(dir.join("images")).is_ok() {
+            for (name, bytes) in &extraction.images {
+                if let Some(name) = std::path::Path::new(name).file_name() {
+                    let _ = std::fs::write(dir.join("images").join(name), bytes);
+                }
+            }
+        }
+        write_extraction_meta(&dir, cache_id, zotero, Some(extractor.processing_mode()), Some(source)).await;
+        if let Some(zc) = zotero
+            && is_valid_zotero_key(cache_id)
+            && let Err(e) = upload_papers_zip(zc, cache_id, &dir, cache_id).await
+            && !is_zotero_write_denied(&e)
+        {
+            eprintln!("[{}] backing up {cache_id} to Zotero failed: {e}", extractor.name());
+        }
+        extractor.cached(cache_id);
+    }
+    Ok(extraction.markdown)
+}
+
+// ── Extraction backends ─────────────────────────────────────────────────────
+
+/// DataLab quality for conversions: `fast`, `balanced` (default) or `accurate`.
+pub const DATALAB_MODE_ENV: &str = "PAPERS_DATALAB_MODE";
+
+/// The [`DATALAB_MODE_ENV`] mode and its name; unknown values are `balanced`.
+fn datalab_mode() -> (papers_datalab::ProcessingMode, &'static str) {
+    use papers_datalab::ProcessingMode;
+    match std::env::var(DATALAB_MODE_ENV).unwrap_or_default().trim().to_ascii_lowercase().as_str() {
+        "fast" => (ProcessingMode::Fast, "fast"),
+        "accurate" => (ProcessingMode::Accurate, "accurate"),
+        _ => (ProcessingMode::Balanced, "balanced"),
+    }
+}
+
+/// A layout-aware PDF converter that poor local text is escalated to.
+pub trait Extractor: Send + Sync {
+    /// Recorded in [`ExtractionReport::escalated_to`].
+    fn name(&self) -> &str;
+
+    /// Recorded as `processing_mode` in `meta.json`.
+    fn processing_mode(&self) -> &str {
+        self.name()
+    }
+
+    /// Convert `pdf`, the document cached as `cache_id`.
+    fn convert<'a>(&'a self, pdf: Vec<u8>, cache_id: &'a str) -> BoxFuture<'a, Result<Extraction, String>>;
+
+    /// Called once the conversion of `cache_id` has been cached.
+    fn cached(&self, _cache_id: &str) {}
+}
+
+/// The output of an [`Extractor`].
+#[derive(Debug, Clone, Default)]
+pub struct Extraction {
+    pub markdown: String,
+    /// Marker-style JSON: a `children` list of pages whose `children` are
+    /// blocks with a `block_type` and `html`. Written to `{cache_id}.json`
+    /// for the RAG ingester.
+    pub json: Option<serde_json::Value>,
+    /// Images referenced by the JSON, as `(file name, bytes)`.
+    pub images: Vec<(String, Vec<u8>)>,
+}
+
+/// The DataLab "balanced" conversion ([`DATALAB_ESCALATION`]).
+///
+/// The job is tracked in [`datalab_job_store`] until the markdown is cached,
+/// so a retry after a crash polls the job already submitted. Progress goes to
+/// the hook installed by [`with_datalab_progress`], if any.
+impl Extractor for papers_datalab::DatalabClient {
+    fn name(&self) -> &str {
+        DATALAB_ESCALATION
+    }
+
+    fn processing_mode(&self) -> &str {
+        datalab_mode().1
+    }
+
+    fn convert<'a>(&'a self, pdf: Vec<u8>, cache_id: &'a str) -> BoxFuture<'a, Result<Extraction, String>> {
+        async move {
+            let req = papers_datalab::MarkerRequest {
+                file: Some(pdf),
+                filename: Some(format!("{cache_id}.pdf")),
+                output_format: vec![papers_datalab::OutputFormat::Markdown],
+                mode: datalab_mode().0,
+                ..Default::default()
+            };
+            let hook = DATALAB_PROGRESS.try_with(Arc::clone).ok();
+            let resp = match datalab_job_store() {
+                Some(jobs) => {
+                    self.convert_document_tracked(req, &jobs, cache_id, |p| {
+                        if let Some(hook) = &hook {
+                            hook(p);
+                        }
+                    })
+                    .await
+                }
+                None => self.convert_document(req).await,
+            }
+            .map_err(|e| e.to_string())?;
+            Ok(Extraction { markdown: resp.markdown.unwrap_or_default(), ..Default::default() })
+        }
+        .boxed()
+    }
+
+    fn cached(&self, cache_id: &str) {
+        if let Some(jobs) = datalab_job_store() {
+            jobs.remove(cache_id);
+        }
+    }
+}
+
+/// The backend poor local text is escalated to: a self-hosted Marker or
+/// GROBID server when `MARKER_URL` or `GROBID_URL` is set (see
+/// [`LocalExtractor::from_env`]), else DataLab when `DATALAB_API_KEY` is.
+pub fn default_extractor() -> Option<Box<dyn Extractor>> {
+    if let Some(local) = LocalExtractor::from_env() {
+        return Some(Box::new(local));
+    }
+    let datalab = papers_datalab::DatalabClient::from_env().ok()?;
+    Some(Box::new(datalab))
+}
+
+/// Kinds of self-hosted extraction server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LocalBackend {
+    /// `marker_server` from marker-pdf: `POST /marker/upload`.
+    Marker,
+    /// GROBID: `POST /api/processFulltextDocument`, returning TEI XML.
+    Grobid,
+}
+
+/// How long a self-hosted server may take to convert one PDF.
+const LOCAL_EXTRACT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(600);
+
+/// A self-hosted Marker or GROBID server, a free alternative to DataLab.
+/// Both produce Marker-style JSON; GROBID's TEI is converted on our side.
+#[derive(Debug, Clone)]
+pub struct LocalExtractor {
+    backend: LocalBackend,
+    base_url: String,
+    http: reqwest::Client,
+}
+
+impl LocalExtractor {
+    pub fn new(backend: LocalBackend, base_url: impl Into<String>) -> Self {
+        let http = reqwest::Client::builder()
+            .timeout(LOCAL_EXTRACT_TIMEOUT)
+            .build()
+            .unwrap_or_default();
+        Self { backend, base_url: base_url.into().trim_end_matches('/').to_string(), http }
+    }
+
+    /// A server from `MARKER_URL`, else `GROBID_URL`.
+    pub fn from_env() -> Option<Self> {
+        let var = |name| std::env::var(name).ok().filter(|url| !url.trim().is_empty());
+        var("MARKER_URL")
+            .map(|url| Self::new(LocalBackend::Marker, url))
+            .or_else(|| var("GROBID_URL").map(|url| Self::new(LocalBackend::Grobid, url)))
+    }
+
+    pub fn backend(&self) -> LocalBackend {
+        self.backend
+    }
+
+    async fn convert_marker(&self, pdf: Vec<u8>, cache_id: &str) -> Result<Extraction, String> {
+        let file = reqwest::multipart::Part::bytes(pdf)
+            .file_name(format!("{cache_id}.pdf"))
+            .mime_str("application/pdf")
+            .map_err(|e| e.to_string())?;
+        let form = reqwest::multipart::Form::new().part("file", file).text("output_format", "json");
+        let resp = self
+            .http
+            .post(format!("{}/marker/upload", self.base_url))
+            .multipart(form)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        let status = resp.status();
+        if !status.is_success() {
+            return Err(format!("Marker server returned {status}"));
+        }
+        let body: serde_json::Value = resp.json().await.map_err(|e| e.to_string())?;
+        if body.get("success").and_then(|s| s.as_bool()) == Some(false) {
+            let error = body.get("error").and_then(|e| e.as_str()).unwrap_or("conversion failed");
+            return Err(format!("Marker server: {error}"));
+        }
+        // `output` is the rendered JSON document, serialized as a string.
+        let json = match body.get("output") {
+            Some(serde_json::Value::String(s)) => serde_json::from_str(s).map_err(|e| e.to_string())?,
+            Some(doc @ serde_json::Value::Object(_)) => doc.clone(),
+            _ => return Err("Marker server returned no output".into()),
+        };
+        let images = body
+            .get("images")
+            .and_then(|i| i.as_object())
+            .into_iter()
+            .flatten()
+            .filter_map(|(name, data)| {
+                let bytes = base64::engine::general_purpose::STANDARD.decode(data.as_str()?).ok()?;
+                Some((name.clone(), bytes))
+            })
+            .collect();
+        Ok(Extraction { markdown: marker_json_to_markdown(&json), json: Some(json), images })
+    }
+
+    async fn convert_grobid(&self, pdf: Vec<u8>, cache_id: &str) -> Result<Extraction, String> {
+        let input = reqwest::multipart::Part::bytes(pdf)
+            .file_name(format!("{cache_id}.pdf"))
+            .mime_str("application/pdf")
+            .map_err(|e| e.to_string())?;
+        let form = reqwest::multipart::Form::new()
+            .part("input", input)
+            .text("includeRawCitations", "1");
+        let resp = self
+            .http
+            .post(format!("{}/api/processFulltextDocument", self.base_url))
+            .multipart(form)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        let status = resp.status();
+        if !status.is_success() {
+            return Err(format!("GROBID returned {status}"));
+        }
+        let tei = resp.text().await.map_err(|e| e.to_string())?;
+        let json = tei_to_marker_json(&tei);
+        Ok(Extraction { markdown: marker_json_to_markdown(&json), json: Some(json), images: Vec::new() })
+    }
+}
+
+impl Extractor for LocalExtractor {
+    fn name(&self) -> &str {
+        match self.backend {
+            LocalBackend::Marker => "marker_local",
+            LocalBackend::Grobid => "grobid",
+        }
+    }
+
+    fn convert<'a>(&'a self, pdf: Vec<u8>, cache_id: &'a str) -> BoxFuture<'a, Result<Extraction, String>> {
+        match self.backend {
+            LocalBackend::Marker => self.convert_marker(pdf, cache_id).boxed(),
+            LocalBackend::Grobid => self.convert_grobid(pdf, cache_id).boxed(),
+        }
+    }
+}
+
+/// Markdown for a Marker-style JSON document: each block's HTML as text, with
+/// page headers and footers dropped.
+pub fn marker_json_to_markdown(root: &serde_json::Value) -> String {
+    let blocks = root
+        .get("children")
+        .and_then(|c| c.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|page| page.get("children").and_then(|c| c.as_array()))
+        .flatten();
+    let mut paragraphs = Vec::new();
+    for block in blocks {
+        let block_type = block.get("block_type").and_then(|v| v.as_str()).unwrap_or("");
+        if matches!(block_type, "PageHeader" | "PageFooter" | "TableOfContents") {
+            continue;
+        }
+        let text = html_to_text(block.get("html").and_then(|v| v.as_str()).unwrap_or(""));
+        if !text.is_empty() {
+            paragraphs.push(text);
+        }
+    }
+    paragraphs.join("\n\n")
+}
+
+fn html_escape(s: &str) -> String {
+    s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+/// Convert GROBID's TEI XML to a one-page Marker-style JSON document: the
+/// title as an `<h1>` header, headings as `<h2>`–`<h4>` by their section
+/// number, paragraphs, formulas, figures and tables with their captions, and
+/// the bibliography as a "References" list.
+pub fn tei_to_marker_json(tei: &str) -> serde_json::Value {
+    let mut blocks = MarkerBlocks::default();
+    let header = tei_element(tei, "teiHeader").unwrap_or_default();
+    let title = tei_element(header, "titleStmt").and_then(|t| tei_element(t, "title")).map(tei_text);
+    if let Some(title) = title.filter(|t| !t.is_empty()) {
+        blocks.push("SectionHeader", format!("<h1>{}</h1>", html_escape(&title)));
+    }
+    if let Some(abstract_xml) = tei_element(header, "abstract") {
+        let paragraphs: Vec<String> =
+            tei_elements(abstract_xml, "p").into_iter().map(|(_, p)| tei_text(p)).filter(|p| !p.is_empty()).collect();
+        if !paragraphs.is_empty() {
+            blocks.push("SectionHeader", "<h2>Abstract</h2>".into());
+            for p in paragraphs {
+                blocks.push("Text", format!("<p>{}</p>", html_escape(&p)));
+            }
+        }
+    }
+    if let Some(text) = tei_element(tei, "text") {
+        tei_blocks(text, &mut blocks);
+    }
+    serde_json::json!({
+        "block_type": "Document",
+        "children": [{
+            "id": "/page/0/Page/0",
+            "block_type": "Page",
+            "html": "",
+            "children": blocks.0,
+        }],
+    })
+}
+
+/// Blocks of the single page [`tei_to_marker_json`] produces.
+#[derive(Default)]
+struct MarkerBlocks(Vec<serde_json::Value>);
+
+impl MarkerBlocks {
+    fn push(&mut self, block_type: &str, html: String) {
+        let id = format!("/page/0/{block_type}/{}", self.0.len());
+        self.0.push(serde_json::json!({"id": id, "block_type": block_type, "html": html}));
+    }
+}
+
+fn tei_blocks(xml: &str, blocks: &mut MarkerBlocks) {
+    for (name, tag, inner) in tei_children(xml) {
+        match name {
+            "div" if markup_attr(tag, "type").as_deref() == Some("references") => {
+                let entries: Vec<String> = tei_elements(inner, "biblStruct")
+                    .into_iter()
+                    .map(|(_, bibl)| tei_bibl_text(bibl))
+                    .filter(|e| !e.is_empty())
+                    .map(|e| format!("<li>{}</li>", html_escape(&e)))
+                    .collect();
+                if !entries.is_empty() {
+                    blocks.push("SectionHeader", "<h2>References</h2>".into());
+                    blocks.push("ListGroup", format!("<ul>{}</ul>", entries.concat()));
+                }
+            }
+            "front" | "body" | "back" | "div" => tei_blocks(inner, blocks),
+            "head" => {
+                let number = markup_attr(tag, "n").filter(|n| !n.is_empty());
+                // "2" is a section, "2.1" a subsection, "2.1.3" deeper still.
+                let level = number.as_deref().map_or(2, |n| 2 + n.trim_end_matches('.').matches('.').count()).min(4);
+                let text = tei_text(inner);
+                let text = match number {
+                    Some(n) => format!("{n} {text}"),
+                    None => text,
+                };
+                blocks.push("SectionHeader", format!("<h{level}>{}</h{level}>", html_escape(&text)));
+            }
+            "p" => {
+                let text = tei_text(inner);
+                if !text.is_empty() {
+                    blocks.push("Text", format!("<p>{}</p>", html_escape(&text)));
+                }
+            }
+            "formula" => {
+                let text = tei_text(inner);
+                if !text.is_empty() {
+                    blocks.push("Equation", format!("<p><math display=\"block\">{}</math></p>", html_escape(&text)));
+                }
+            }
+            "list" => {
+                let items: String = tei_elements(inner, "item")
+                    .into_iter()
+                    .map(|(_, item)| format!("<li>{}</li>", html_escape(&tei_text(item))))
+                    .collect();
+                if !items.is_empty() {
+                    blocks.push("ListGroup", format!("<ul>{items}</ul>"));
+                }
+            }
+            "figure" => {
+                let is_table = markup_attr(tag, "type").as_deref() == Some("table");
+                let html = if is_table {
+                    let rows: String = tei_elements(inner, "row")
+                        .into_iter()
+                        .map(|(_, row)| {
+                            let cells: String = tei_elements(row, "cell")
+                                .into_iter()
+                                .map(|(_, cell)| format!("<td>{}</td>", html_escape(&tei_text(cell))))
+                                .collect();
+                            format!("<tr>{cells}</tr>")
+                        })
+                        .collect();
+                    format!("<table>{rows}</table>")
+                } else {
+                    String::new()
+                };
+                blocks.push(if is_table { "Table" } else { "Figure" }, html);
+                let caption: Vec<String> = ["head", "figDesc"]
+                    .iter()
+                    .filter_map(|name| tei_element(inner, name).map(tei_text))
+                    .filter(|t| !t.is_empty())
+                    .collect();
+                if !caption.is_empty() {
+                    blocks.push("Caption", format!("<p>{}</p>", html_escape(&caption.join(" "))));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// One bibliography entry as a citation line: GROBID's raw citation when it
+/// kept one, else `Authors. Title. Venue, volume, pages, year. DOI`.
+fn tei_bibl_text(bibl: &str) -> String {
+    let raw = tei_elements(bibl, "note")
+        .into_iter()
+        .find(|(tag, _)| markup_attr(tag, "type").as_deref() == Some("raw_reference"));
+    if let Some((_, raw)) = raw {
+        return tei_text(raw);
+    }
+    let analytic = tei_element(bibl, "analytic");
+    let monogr = tei_element(bibl, "monogr").unwrap_or_default();
+    let authors: Vec<String> = tei_elements(analytic.unwrap_or(monogr), "author")
+        .into_iter()
+        .filter_map(|(_, author)| tei_element(author, "persName"))
+        .map(|name| {
+            let parts: Vec<String> = tei_children(name).into_iter().map(|(_, _, part)| tei_text(part)).collect();
+            parts.join(" ")
+        })
+        .filter(|a| !a.is_empty())
+        .collect();
+    let title = analytic.and_then(|a| tei_element(a, "title")).map(tei_text);
+    let imprint = tei_element(monogr, "imprint").unwrap_or_default();
+    let mut venue: Vec<String> = tei_element(monogr, "title").map(tei_text).into_iter().collect();
+    for (tag, scope) in tei_elements(imprint, "biblScope") {
+        let text = match (markup_attr(tag, "from"), markup_attr(tag, "to")) {
+            (Some(from), Some(to)) => format!("{from}–{to}"),
+            _ => tei_text(scope),
+        };
+        venue.push(text);
+    }
+    let year = tei_elements(imprint, "date")
+        .into_iter()
+        .find_map(|(tag, date)| markup_attr(tag, "when").or_else(|| Some(tei_text(date))))
+        .map(|d| d.chars().take(4).collect::<String>());
+    venue.extend(year);
+    venue.retain(|v| !v.is_empty());
+    let doi = tei_elements(bibl, "idno")
+        .into_iter()
+        .find(|(tag, _)| markup_attr(tag, "type").is_some_and(|t| t.eq_ignore_ascii_case("doi")))
+        .map(|(_, doi)| format!("https://doi.org/{}", tei_text(doi)));
+
+    let mut parts = Vec::new();
+    if !authors.is_empty() {
+        parts.push(authors.join(", "));
+    }
+    parts.extend(title.filter(|t| !t.is_empty()));
+    if !venue.is_empty() {
+        parts.push(venue.join(", "));
+    }
+    parts.extend(doi);
+    if parts.is_empty() { String::new() } else { format!("{}.", parts.join(". ")) }
+}
+
+/// The whitespace-collapsed text content of an XML fragment.
+fn tei_text(xml: &str) -> String {
+    html_to_text(xml).split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Top-level elements of `xml` as `(local name, start tag body, content)`.
+fn tei_children(xml: &str) -> Vec<(&str, &str, &str)> {
+    let mut out = Vec::new();
+    let mut depth = 0usize;
+    let mut open: Option<(&str, &str, usize)> = None;
+    let mut pos = 0;
+    while let Some(lt) = xml[pos..].find('<') {
+        let start = pos + lt;
+        if xml[start..].starts_with("<!--") {
+            pos = xml[start..].find("-->").map_or(xml.len(), |end| start + end + 3);
+            continue;
+        }
+        let Some(gt) = xml[start..].find('>') else { break };
+        let end = start + gt + 1;
+        let tag = &xml[start + 1..end - 1];
+        pos = end;
+        if tag.starts_with(['?', '!']) {
+            continue;
+        }
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default();
+        let name = name.rsplit(':').next().unwrap_or_default();
+        if tag.starts_with('/') {
+            depth = depth.saturating_sub(1);
+            if depth == 0
+                && let Some((name, tag, content_start)) = open.take()
+            {
+                out.push((name, tag, &xml[content_start..start]));
+            }
+        } else if tag.ends_with('/') {
+            if depth == 0 {
+                out.push((name, tag, ""));
+            }
+        } else {
+            if depth == 0 {
+                open = Some((name, tag, end));
+            }
+            depth += 1;
+        }
+    }
+    out
+}
+
+/// Every `name` element in `xml` (not looking inside matches) as
+/// `(start tag body, content)`, in document order.
+fn tei_elements<'a>(xml: &'a str, name: &str) -> Vec<(&'a str, &'a str)> {
+    let mut out = Vec::new();
+    for (child, tag, inner) in tei_children(xml) {
+        if child == name {
+            out.push((tag, inner));
+        } else {
+            out.extend(tei_elements(inner, name));
+        }
+    }
+    out
+}
+
+/// The content of the first `name` element in `xml`.
+fn tei_element<'a>(xml: &'a str, name: &str) -> Option<&'a str> {
+    tei_elements(xml, name).into_iter().next().map(|(_, inner)| inner)
 }
 
 /// Download and extract the full text of a scholarly work.
 ///
-/// Tries multiple sources in priority order:
+/// Tries the PDF sources configured in `config.json` (see
+/// [`TextSourceConfig::load`]), by default in this order:
 /// 1. Local Zotero storage (filesystem)
 /// 2. Remote Zotero API (if credentials available)
-/// 3. Direct PDF URLs from OpenAlex locations (whitelisted domains)
-/// 4. OpenAlex Content API (requires `OPENALEX_API_KEY`)
+/// 3. PMC open-access JATS XML (for works with a PMCID)
+/// 4. Direct PDF URLs from OpenAlex locations (whitelisted domains)
+/// 5. OpenAlex Content API (requires `OPENALEX_API_KEY`)
+/// 6. Unpaywall (requires a contact email)
+/// 7. A link resolver (requires a URL template)
 ///
 /// If a cached extraction exists (from `papers-extract` or legacy DataLab cache),
 /// it is returned directly. Otherwise falls back to local pdfium text extraction.
@@ -866,60 +2784,131 @@ pub async fn work_text(
     zotero: Option<&ZoteroClient>,
     work_id: &str,
 ) -> Result<WorkTextResult, WorkTextError> {
-    // 1. Fetch work metadata from OpenAlex
+    work_text_with_sources(openalex, zotero, work_id, &TextSourceConfig::load()).await
+}
+
+/// [`work_text`] with an explicit source chain. Every configured source is
+/// recorded in `attempts` (in configured order), on success and in
+/// [`WorkTextError::NoPdfFound`].
+///
+/// All sources are probed concurrently and the first to produce a file wins;
+/// the others are cancelled (dropped mid-request) and reported as such. The
+/// configured order therefore selects which sources run, not which one wins.
+pub async fn work_text_with_sources(
+    openalex: &OpenAlexClient,
+    zotero: Option<&ZoteroClient>,
+    work_id: &str,
+    sources: &TextSourceConfig,
+) -> Result<WorkTextResult, WorkTextError> {
     let work = crate::api::work_get(openalex, work_id, &GetParams::default()).await?;
 
     let title = work.title.clone().or_else(|| work.display_name.clone());
     let doi_raw = work.doi.as_deref();
     let doi = doi_raw.map(bare_doi);
-    let short_id = short_openalex_id(&work.id);
-
-    let http = reqwest::Client::new();
-
-    // 2. Try Zotero (local then remote)
-    if let (Some(zotero), Some(doi)) = (zotero, doi) {
-        if let Some((bytes, mut source, zotero_key)) = try_zotero(zotero, doi, title.as_deref()).await? {
-            let text = do_extract(bytes, &zotero_key, Some(zotero), &mut source).await?;
-            return Ok(WorkTextResult {
-                text,
-                source,
-                work_id: work.id.clone(),
-                title,
-                doi: doi_raw.map(String::from),
-            });
-        }
-    }
-
-    // 3. Try direct PDF URLs from OpenAlex locations
-    let pdf_urls = collect_pdf_urls(&work);
-    if let Some((bytes, mut source)) = try_direct_urls(&http, &pdf_urls).await? {
-        let text = do_extract(bytes, short_id, None, &mut source).await?;
+    let short_id = short_id(&work.id);
+
+    // A PDF kept from an earlier download skips the source chain.
+    if let Some(path) = cached_pdf_path(short_id)
+        && let Ok(bytes) = std::fs::read(&path)
+    {
+        let mut pdf_source = PdfSource::CachedPdf { path: path.display().to_string() };
+        let (text, extraction) = do_extract_with_report(bytes, short_id, None, &mut pdf_source).await?;
         return Ok(WorkTextResult {
             text,
-            source,
+            source: pdf_source,
             work_id: work.id.clone(),
             title,
             doi: doi_raw.map(String::from),
+            attempts: Vec::new(),
+            extraction,
         });
     }
 
-    // 4. Try OpenAlex Content API
-    if let Some((bytes, mut source)) = try_openalex_content(&http, &work).await? {
-        let text = do_extract(bytes, short_id, None, &mut source).await?;
-        return Ok(WorkTextResult {
-            text,
-            source,
+    let zotero_lookup = zotero.zip(doi).map(|(z, doi)| {
+        let title = title.clone();
+        async move {
+            find_zotero_attachments(z, doi, title.as_deref())
+                .await
+                .map(Arc::new)
+                .map_err(|e| e.to_string())
+        }
+        .boxed()
+        .shared()
+    });
+    let cx = SourceContext {
+        http: reqwest::Client::new(),
+        work: &work,
+        doi,
+        zotero,
+        zotero_lookup,
+        config: sources,
+    };
+
+    let order = sources.order();
+    let mut pending: FuturesUnordered<_> = order
+        .iter()
+        .enumerate()
+        .map(|(i, &source)| {
+            let cx = &cx;
+            async move { (i, fetch_from(cx, source).await) }
+        })
+        .collect();
+    let mut outcomes: Vec<Option<SourceAttempt>> = vec![None; order.len()];
+    let mut winner = None;
+    while let Some((i, fetched)) = pending.next().await {
+        let source = order[i];
+        let (outcome, reason) = match fetched {
+            Ok(fetched) => {
+                outcomes[i] = Some(SourceAttempt { source, outcome: AttemptOutcome::Found, reason: None });
+                winner = Some(fetched);
+                break;
+            }
+            Err(SourceMiss::Skipped(reason)) => (AttemptOutcome::Skipped, reason),
+            Err(SourceMiss::Failed(reason)) => (AttemptOutcome::Failed, reason),
+        };
+        outcomes[i] = Some(SourceAttempt { source, outcome, reason: Some(reason) });
+    }
+    // Dropping the rest cancels their in-flight requests.
+    drop(pending);
+
+    let attempts: Vec<SourceAttempt> = order
+        .iter()
+        .zip(outcomes)
+        .map(|(&source, attempt)| {
+            attempt.unwrap_or(SourceAttempt {
+                source,
+                outcome: AttemptOutcome::Cancelled,
+                reason: Some("another source found a file first".into()),
+            })
+        })
+        .collect();
+
+    let Some(Fetched { bytes, source: mut pdf_source, zotero_item }) = winner else {
+        return Err(WorkTextError::NoPdfFound {
             work_id: work.id.clone(),
             title,
             doi: doi_raw.map(String::from),
+            attempts,
         });
+    };
+    // Zotero files are cached under the item key and backed up to it. Local
+    // Zotero files are already on disk; downloads are kept.
+    if !matches!(pdf_source, PdfSource::ZoteroLocal { .. }) {
+        let cache_id = zotero_item.as_deref().unwrap_or(short_id);
+        save_downloaded_pdf(cache_id, &bytes, &pdf_source, title.as_deref(), doi_raw);
     }
-
-    // 5. No PDF found
-    Err(WorkTextError::NoPdfFound {
+    let (text, extraction) = match &zotero_item {
+        Some(key) => do_extract_with_report(bytes, key, zotero, &mut pdf_source).await?,
+        None => do_extract_with_report(bytes, short_id, None, &mut pdf_source).await?,
+    };
+    Ok(WorkTextResult {
+        text,
+        source: pdf_source,
         work_id: work.id.clone(),
         title,
         doi: doi_raw.map(String::from),
+        attempts,
+        extraction,
     })
 }
 
@@ -945,6 +2934,8 @@ pub async fn poll_zotero_for_work(
                 work_id: work_id.to_string(),
                 title: title.map(String::from),
                 doi: Some(doi.to_string()),
+                attempts: Vec::new(),
+                extraction: None,
             });
         }
         tokio::time::sleep(std::time::Duration::from_secs(2)).await;
@@ -954,6 +2945,7 @@ pub async fn poll_zotero_for_work(
         work_id: work_id.to_string(),
         title: title.map(String::from),
         doi: Some(doi.to_string()),
+        attempts: Vec::new(),
     })
 }
 
@@ -961,6 +2953,234 @@ pub async fn poll_zotero_for_work(
 mod tests {
     use super::*;
 
+    fn zotero_item(title: &str, doi: Option<&str>, date: &str) -> papers_zotero::Item {
+        serde_json::from_value(serde_json::json!({
+            "key": "ABCD1234",
+            "version": 1,
+            "library": {"type": "user", "id": 1, "name": "me", "links": {}},
+            "meta": {"parsedDate": date},
+            "data": {
+                "key": "ABCD1234", "version": 1, "itemType": "book",
+                "title": title, "DOI": doi, "creators": [], "tags": [],
+                "collections": [], "relations": {},
+            },
+        }))
+        .unwrap()
+    }
+
+    fn oa_work(title: &str, doi: Option<&str>, year: i32) -> Work {
+        serde_json::from_value(serde_json::json!({
+            "id": "https://openalex.org/W1",
+            "display_name": title,
+            "doi": doi.map(|d| format!("https://doi.org/{d}")),
+            "publication_year": year,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_zotero_match_confidence_prefers_doi_then_title_and_year() {
+        let work = oa_work("Physically Based Rendering", Some("10.1016/pbr"), 2016);
+        let same_doi = zotero_item("PBRT 3rd edition", Some("10.1016/PBR"), "2016");
+        assert_eq!(zotero_match_confidence(&work, &same_doi), Some((ZoteroMatchMethod::Doi, 1.0)));
+
+        // A conflicting DOI rules the item out however close the title.
+        let other_doi = zotero_item("Physically Based Rendering", Some("10.1016/other"), "2016");
+        assert_eq!(zotero_match_confidence(&work, &other_doi), None);
+
+        let no_doi = zotero_item("Physically based rendering", None, "2016-11-25");
+        assert_eq!(zotero_match_confidence(&work, &no_doi), Some((ZoteroMatchMethod::Title, 1.0)));
+        let off_by_one = zotero_item("Physically Based Rendering", None, "2017");
+        assert_eq!(zotero_match_confidence(&work, &off_by_one), Some((ZoteroMatchMethod::Title, 0.9)));
+        let other_edition = zotero_item("Physically Based Rendering", None, "2004");
+        assert_eq!(zotero_match_confidence(&work, &other_edition), None);
+        let different = zotero_item("Real-Time Rendering", None, "2016");
+        assert_eq!(zotero_match_confidence(&work, &different), None);
+    }
+
+    fn result(text: &str) -> WorkTextResult {
+        WorkTextResult {
+            text: text.to_string(),
+            source: PdfSource::LocalExtract,
+            work_id: "W1".into(),
+            title: None,
+            doi: None,
+            attempts: Vec::new(),
+            extraction: None,
+        }
+    }
+
+    #[test]
+    fn test_text_sections() {
+        let text = "Title\nAbstract\nWe study é.\n1 Introduction\nText.\n\
+                    2.1 Sampling Strategy\n3 items were used.\n## Results\nReferences\n";
+        let sections = text_sections(text);
+        let titles: Vec<_> = sections.iter().map(|s| s.title.as_str()).collect();
+        assert_eq!(
+            titles,
+            ["Abstract", "1 Introduction", "2.1 Sampling Strategy", "Results", "References"]
+        );
+        assert_eq!(sections[1].offset, "Title\nAbstract\nWe study é.\n".chars().count());
+    }
+
+    #[test]
+    fn test_page_windows_cover_text_and_break_at_lines() {
+        let text = "ééé line one\nline two\nline three\n";
+        let first = result(text).page(0, 20);
+        assert_eq!(first.text, "ééé line one\n");
+        assert_eq!(first.total_chars, text.chars().count());
+        assert_eq!(first.next_offset, Some(13));
+
+        let mut all = first.text;
+        let mut next = first.next_offset;
+        while let Some(offset) = next {
+            let page = result(text).page(offset, 20);
+            all.push_str(&page.text);
+            next = page.next_offset;
+        }
+        assert_eq!(all, text);
+
+        let past_end = result(text).page(1000, 20);
+        assert_eq!((past_end.text.as_str(), past_end.next_offset), ("", None));
+    }
+
+    fn zip_bytes(files: &[(&str, &str)]) -> Vec<u8> {
+        use std::io::Write as _;
+        let mut zip = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        for (name, content) in files {
+            zip.start_file(*name, zip::write::SimpleFileOptions::default()).unwrap();
+            zip.write_all(content.as_bytes()).unwrap();
+        }
+        zip.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn test_html_to_text() {
+        let html = r#"<!DOCTYPE html><html><head><title>T</title><style>p{}</style></head>
+            <body><nav><a href="/">Home</a></nav><h1>Deep   Learning</h1>
+            <p>First&nbsp;para with <em>emphasis</em> &amp; a<br>break.</p>
+            <ul><li>one</li><li>two &#8211; &#x2014;</li></ul>
+            <!-- <p>hidden</p> --><script>var x = "<p>";</script><h2 id="m">Methods</h2>
+            <table><tr><td>a</td><td>b</td></tr></table></body></html>"#;
+        assert_eq!(
+            html_to_text(html),
+            "# Deep Learning\n\nFirst para with emphasis & a\nbreak.\n\n- one\n- two – —\n\n\
+             ## Methods\n\na b"
+        );
+    }
+
+    #[test]
+    fn test_extract_epub_text_follows_spine() {
+        let container = r#"<?xml version="1.0"?><container><rootfiles>
+            <rootfile full-path="OEBPS/content.opf" media-type="application/oebps-package+xml"/>
+            </rootfiles></container>"#;
+        let opf = r#"<package><manifest>
+            <item id="c2" href="Text/ch%202.xhtml" media-type="application/xhtml+xml"/>
+            <item id="c1" href="Text/ch1.xhtml" media-type="application/xhtml+xml"/>
+            <item id="css" href="style.css" media-type="text/css"/>
+            </manifest><spine><itemref idref="c1"/><itemref idref="css"/><itemref idref="c2"/>
+            </spine></package>"#;
+        let bytes = zip_bytes(&[
+            ("mimetype", "application/epub+zip"),
+            ("META-INF/container.xml", container),
+            ("OEBPS/content.opf", opf),
+            ("OEBPS/Text/ch 2.xhtml", "<html><body><h1>Two</h1><p>Second.</p></body></html>"),
+            ("OEBPS/Text/ch1.xhtml", "<html><body><h1>One</h1><p>First.</p></body></html>"),
+        ]);
+        assert_eq!(
+            extract_text_bytes(&bytes).unwrap(),
+            "# One\n\nFirst.\n\n# Two\n\nSecond."
+        );
+    }
+
+    #[test]
+    fn test_extract_zipped_html_snapshot() {
+        let bytes = zip_bytes(&[("b.html", "<p>Later</p>"), ("a.html", "<p>Earlier</p>")]);
+        assert_eq!(extract_text_bytes(&bytes).unwrap(), "Earlier\n\nLater");
+        let html = b"\xef\xbb\xbf<!doctype html><p>Snapshot</p>";
+        assert_eq!(extract_text_bytes(html).unwrap(), "Snapshot");
+    }
+
+    const JATS: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE article PUBLIC "-//NLM//DTD JATS (Z39.96) Journal Archiving and Interchange DTD v1.2 20190208//EN" "JATS-archivearticle1.dtd">
+<article xmlns:mml="http://www.w3.org/1998/Math/MathML" article-type="research-article">
+<front><journal-meta><journal-title-group><journal-title>PLoS Biol</journal-title></journal-title-group></journal-meta>
+<article-meta><article-id pub-id-type="pmcid">PMC123</article-id>
+<title-group><article-title>Gut <italic>microbes</italic> &amp; sleep</article-title></title-group>
+<contrib-group><contrib><name><surname>Smith</surname><given-names>J</given-names></name></contrib></contrib-group>
+<abstract><p>We studied sleep.</p></abstract>
+<abstract abstract-type="graphical"><p>Graphical.</p></abstract>
+</article-meta></front>
+<body><sec id="s1"><label>1</label><title>Introduction</title><p>Sleep matters<xref ref-type="bibr" rid="r1">[1]</xref>.</p>
+<sec><title>Background</title><p>Mice with <inline-formula><mml:math><mml:mi>x</mml:mi></mml:math></inline-formula> slept.</p></sec></sec>
+<sec><title>Results</title><fig id="f1"><label>Figure 1</label><caption><title>Sleep time.</title><p>Hours per day.</p></caption><graphic xlink:href="f1.jpg"/></fig>
+<list list-type="bullet"><list-item><p>one</p></list-item><list-item><p>two</p></list-item></list></sec></body>
+<back><ack><p>Thanks.</p></ack><ref-list><ref id="r1"><element-citation><person-group><name><surname>Doe</surname><given-names>A</given-names></name></person-group><article-title>Sleep</article-title><source>Nature</source><year>2020</year></element-citation></ref></ref-list></back>
+</article>"#;
+
+    #[test]
+    fn test_jats_to_text() {
+        assert_eq!(
+            jats_to_text(JATS),
+            "# Gut microbes & sleep\n\n## Abstract\n\nWe studied sleep.\n\n\
+             ## Introduction\n\nSleep matters[1].\n\n### Background\n\nMice with slept.\n\n\
+             ## Results\n\nFigure 1 Sleep time.\n\nHours per day.\n\n- one\n- two\n\n\
+             ## Acknowledgements\n\nThanks.\n\n## References\n\n- Doe A Sleep Nature 2020"
+        );
+        // Sniffed as JATS, not HTML, despite the XML declaration.
+        assert_eq!(extract_text_bytes(JATS.as_bytes()).unwrap(), jats_to_text(JATS));
+    }
+
+    #[test]
+    fn test_pmcid_parsing() {
+        let mut work = oa_work("T", None, 2020);
+        assert_eq!(pmcid_for_work(&work), None);
+        work.ids = serde_json::from_value(serde_json::json!({
+            "pmcid": "https://www.ncbi.nlm.nih.gov/pmc/articles/5815332"
+        }))
+        .unwrap();
+        assert_eq!(pmcid_for_work(&work).as_deref(), Some("PMC5815332"));
+
+        let mut item = zotero_item("T", None, "2020");
+        item.data.extra = Some("PMID: 29123\nPMCID: pmc5815332 ".into());
+        assert_eq!(zotero_item_pmcid(&item).as_deref(), Some("PMC5815332"));
+        item.data.extra = Some("PMCID: none".into());
+        assert_eq!(zotero_item_pmcid(&item), None);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_pmc_jats() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/PMC123/fullTextXML"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(JATS))
+            .mount(&mock)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/PMC456/fullTextXML"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                "<article><front><article-meta/></front></article>",
+            ))
+            .mount(&mock)
+            .await;
+
+        let http = reqwest::Client::new();
+        let Ok(bytes) = fetch_pmc_jats(&http, &mock.uri(), "PMC123").await else {
+            panic!("expected JATS XML");
+        };
+        assert_eq!(bytes, JATS.as_bytes());
+        let reason = |miss| match miss {
+            SourceMiss::Failed(reason) | SourceMiss::Skipped(reason) => reason,
+        };
+        let miss = fetch_pmc_jats(&http, &mock.uri(), "PMC456").await.err().unwrap();
+        assert_eq!(reason(miss), "PMC456 has no full-text body in PMC");
+        let miss = fetch_pmc_jats(&http, &mock.uri(), "PMC789").await.err().unwrap();
+        assert_eq!(reason(miss), "PMC789 is not in the PMC open-access subset");
+    }
+
     #[test]
     fn test_bare_doi() {
         assert_eq!(bare_doi("https://doi.org/10.1234/test"), "10.1234/test");
@@ -968,12 +3188,12 @@ mod tests {
     }
 
     #[test]
-    fn test_short_openalex_id() {
+    fn test_short_id() {
         assert_eq!(
-            short_openalex_id("https://openalex.org/W2741809807"),
+            short_id("https://openalex.org/W2741809807"),
             "W2741809807"
         );
-        assert_eq!(short_openalex_id("W2741809807"), "W2741809807");
+        assert_eq!(short_id("W2741809807"), "W2741809807");
     }
 
     #[test]
@@ -1011,4 +3231,195 @@ mod tests {
         assert_eq!(urls[0], "https://arxiv.org/pdf/1234");
         assert_eq!(urls[1], "https://europepmc.org/pdf/5678");
     }
+
+    #[test]
+    fn test_text_source_parsing_and_default_order() {
+        assert_eq!("oa-urls".parse::<TextSource>(), Ok(TextSource::OaUrls));
+        assert_eq!(" unpaywall".parse::<TextSource>(), Ok(TextSource::Unpaywall));
+        let err = "scihub".parse::<TextSource>().unwrap_err();
+        assert!(err.contains("scihub") && err.contains("link_resolver"));
+
+        let config = TextSourceConfig::default();
+        assert_eq!(config.order(), TextSource::DEFAULT_ORDER);
+        let config = TextSourceConfig { order: vec![TextSource::Unpaywall], ..Default::default() };
+        assert_eq!(config.order(), &[TextSource::Unpaywall]);
+    }
+
+    #[test]
+    fn test_citation_pdf_url() {
+        let html = r#"<html><head>
+            <META content="Fast Rendering" name="citation_title">
+            <meta name="citation_pdf_url" content="/doi/pdf/10.1145/1?download=true&amp;x=1" />
+        </head></html>"#;
+        assert_eq!(citation_pdf_url(html).as_deref(), Some("/doi/pdf/10.1145/1?download=true&x=1"));
+        let html = "<meta property=og:title content=x><meta name='citation_pdf_url' content='https://a.org/p.pdf'>";
+        assert_eq!(citation_pdf_url(html).as_deref(), Some("https://a.org/p.pdf"));
+        assert_eq!(citation_pdf_url("<meta name=\"citation_title\" content=\"x\">"), None);
+    }
+
+    #[tokio::test]
+    async fn test_unpaywall_follows_landing_page_when_pdf_links_fail() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock = MockServer::start().await;
+        let record = serde_json::json!({
+            // Hybrid OA at the publisher: a landing page but no direct PDF link.
+            "best_oa_location": {
+                "url_for_pdf": null,
+                "url_for_landing_page": format!("{}/article/1", mock.uri()),
+                "host_type": "publisher",
+                "version": "publishedVersion",
+            },
+            "oa_locations": [{
+                // A stale repository link.
+                "url_for_pdf": format!("{}/repo/gone.pdf", mock.uri()),
+                "url_for_landing_page": null,
+                "host_type": "repository",
+                "version": "acceptedVersion",
+            }],
+        });
+        Mock::given(method("GET"))
+            .and(path("/10.1234/hybrid"))
+            .and(query_param("email", "me@example.org"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(record))
+            .mount(&mock)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repo/gone.pdf"))
+            .respond_with(ResponseTemplate::new(404))
+            .expect(1)
+            .mount(&mock)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/article/1"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"<html><head><meta name="citation_pdf_url" content="/article/1.pdf"></head></html>"#,
+            ))
+            .mount(&mock)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/article/1.pdf"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "application/pdf")
+                    .set_body_bytes(b"%PDF-1.7".to_vec()),
+            )
+            .mount(&mock)
+            .await;
+
+        let http = reqwest::Client::new();
+        let Ok((bytes, source)) =
+            fetch_unpaywall(&http, &mock.uri(), "10.1234/hybrid", "me@example.org").await
+        else {
+            panic!("expected a PDF from the landing page");
+        };
+        assert_eq!(bytes, b"%PDF-1.7");
+        let PdfSource::Unpaywall { url, host_type, version } = source else {
+            panic!("expected an Unpaywall source, got {source:?}");
+        };
+        assert_eq!(url, format!("{}/article/1.pdf", mock.uri()));
+        assert_eq!(host_type.as_deref(), Some("publisher"));
+        assert_eq!(version.as_deref(), Some("publishedVersion"));
+    }
+
+    #[tokio::test]
+    async fn test_unpaywall_reports_last_failure() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/10.1234/closed"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "best_oa_location": null,
+                "oa_locations": [],
+            })))
+            .mount(&mock)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/10.1234/nopage"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "best_oa_location": {"url_for_landing_page": format!("{}/landing", mock.uri())},
+            })))
+            .mount(&mock)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/landing"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("<html></html>"))
+            .mount(&mock)
+            .await;
+
+        let http = reqwest::Client::new();
+        let reason = |miss| match miss {
+            SourceMiss::Failed(reason) | SourceMiss::Skipped(reason) => reason,
+        };
+        let miss = fetch_unpaywall(&http, &mock.uri(), "10.1234/closed", "me@example.org").await.err().unwrap();
+        assert_eq!(reason(miss), "Unpaywall lists no open-access copy");
+        let miss = fetch_unpaywall(&http, &mock.uri(), "10.1234/nopage", "me@example.org").await.err().unwrap();
+        assert!(reason(miss).ends_with("/landing: landing page has no citation_pdf_url"));
+    }
+
+    #[test]
+    fn test_tei_to_marker_json() {
+        let tei = r##"<?xml version="1.0" encoding="UTF-8"?>
+<TEI xmlns="http://www.tei-c.org/ns/1.0">
+  <teiHeader>
+    <fileDesc><titleStmt><title level="a" type="main">Sleep &amp; Memory</title></titleStmt></fileDesc>
+    <profileDesc><abstract><div><p>We slept.</p></div></abstract></profileDesc>
+  </teiHeader>
+  <text>
+    <body>
+      <div><head n="1">Introduction</head><p>Sleep <ref type="bibr" target="#b0">[1]</ref> matters.</p></div>
+      <div><head n="1.1">Setup</head><formula>x = 1</formula>
+        <figure type="table"><head>Table 1</head><figDesc>Results.</figDesc>
+          <table><row><cell>a</cell><cell>b</cell></row></table></figure>
+      </div>
+    </body>
+    <back>
+      <div type="references"><listBibl>
+        <biblStruct xml:id="b0">
+          <analytic><title level="a">A study</title>
+            <author><persName><forename>Ada</forename><surname>Lovelace</surname></persName></author></analytic>
+          <monogr><title level="j">Nature</title>
+            <imprint><biblScope unit="volume">12</biblScope><biblScope unit="page" from="1" to="9"/>
+              <date type="published" when="2020-05-01"/></imprint></monogr>
+          <idno type="DOI">10.1/abc</idno>
+        </biblStruct>
+      </listBibl></div>
+    </back>
+  </text>
+</TEI>"##;
+        let json = tei_to_marker_json(tei);
+        let blocks: Vec<(String, String)> = json["children"][0]["children"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|b| (b["block_type"].as_str().unwrap().to_string(), b["html"].as_str().unwrap().to_string()))
+            .collect();
+        let expected = [
+            ("SectionHeader", "<h1>Sleep &amp; Memory</h1>"),
+            ("SectionHeader", "<h2>Abstract</h2>"),
+            ("Text", "<p>We slept.</p>"),
+            ("SectionHeader", "<h2>1 Introduction</h2>"),
+            ("Text", "<p>Sleep [1] matters.</p>"),
+            ("SectionHeader", "<h3>1.1 Setup</h3>"),
+            ("Equation", "<p><math display=\"block\">x = 1</math></p>"),
+            ("Table", "<table><tr><td>a</td><td>b</td></tr></table>"),
+            ("Caption", "<p>Table 1 Results.</p>"),
+            ("SectionHeader", "<h2>References</h2>"),
+            (
+                "ListGroup",
+                "<ul><li>Ada Lovelace. A study. Nature, 12, 1–9, 2020. https://doi.org/10.1/abc.</li></ul>",
+            ),
+        ];
+        let expected: Vec<(String, String)> =
+            expected.iter().map(|(t, h)| (t.to_string(), h.to_string())).collect();
+        assert_eq!(blocks, expected);
+
+        let markdown = marker_json_to_markdown(&json);
+        assert!(markdown.starts_with("# Sleep & Memory\n\n## Abstract\n\nWe slept."));
+        assert!(markdown.contains("### 1.1 Setup"));
+        assert_eq!(crate::references::marker_references_text(&json).lines().count(), 1);
+    }
 }
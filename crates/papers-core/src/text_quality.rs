@@ -0,0 +1,122 @@
+//! Sanity scoring for locally extracted PDF text.
+//!
+//! pdf-extract silently returns junk for scanned PDFs (no text layer), PDFs
+//! with broken font encodings (`\u{FFFD}` or private-use glyphs everywhere),
+//! and pages it can't parse (empty strings). [`score_pages`] measures how much
+//! readable text each page yielded so callers can tell real text from junk.
+
+use serde::Serialize;
+
+/// Characters per page a text-bearing page of a paper comfortably exceeds.
+/// Pages at or above it count as fully covered.
+const FULL_PAGE_CHARS: f64 = 1500.0;
+
+/// Pages with fewer non-whitespace characters than this count as empty.
+const EMPTY_PAGE_CHARS: usize = 50;
+
+/// Scores below this are treated as junk.
+pub const MIN_TEXT_SCORE: f64 = 0.35;
+
+/// A garbage ratio above this is junk however much text there is.
+pub const MAX_GARBAGE_RATIO: f64 = 0.1;
+
+/// How readable the text extracted from a PDF looks.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TextQuality {
+    pub pages: usize,
+    /// Pages with (almost) no text, typically scanned images.
+    pub empty_pages: usize,
+    /// Mean non-whitespace characters per page.
+    pub chars_per_page: f64,
+    /// Share of non-whitespace characters that are replacement, control, or
+    /// private-use code points.
+    pub garbage_ratio: f64,
+    /// 0 (junk) to 1 (clean): page coverage discounted by the garbage ratio.
+    pub score: f64,
+}
+
+impl TextQuality {
+    /// Whether the text is too sparse or garbled to serve as the paper's text.
+    pub fn is_poor(&self) -> bool {
+        self.score < MIN_TEXT_SCORE || self.garbage_ratio > MAX_GARBAGE_RATIO
+    }
+}
+
+fn is_garbage(c: char) -> bool {
+    c == '\u{FFFD}'
+        || (c.is_control() && !c.is_whitespace())
+        || ('\u{E000}'..='\u{F8FF}').contains(&c)
+}
+
+/// Score the text of each page of a PDF.
+pub fn score_pages<S: AsRef<str>>(pages: &[S]) -> TextQuality {
+    let mut empty_pages = 0;
+    let mut chars = 0usize;
+    let mut garbage = 0usize;
+    let mut coverage = 0.0;
+    for page in pages {
+        let page_chars = page.as_ref().chars().filter(|c| !c.is_whitespace());
+        let (mut count, mut bad) = (0usize, 0usize);
+        for c in page_chars {
+            count += 1;
+            bad += usize::from(is_garbage(c));
+        }
+        if count - bad < EMPTY_PAGE_CHARS {
+            empty_pages += 1;
+        }
+        coverage += ((count - bad) as f64 / FULL_PAGE_CHARS).min(1.0);
+        chars += count;
+        garbage += bad;
+    }
+
+    let n = pages.len();
+    let garbage_ratio = if chars == 0 { 0.0 } else { garbage as f64 / chars as f64 };
+    let (chars_per_page, coverage) = if n == 0 {
+        (0.0, 0.0)
+    } else {
+        (chars as f64 / n as f64, coverage / n as f64)
+    };
+    TextQuality {
+        pages: n,
+        empty_pages,
+        chars_per_page,
+        garbage_ratio,
+        score: coverage * (1.0 - garbage_ratio),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(len: usize) -> String {
+        "word ".repeat(len / 4)
+    }
+
+    #[test]
+    fn test_full_pages_score_clean() {
+        let quality = score_pages(&[page(3000), page(2000)]);
+        assert_eq!(quality.empty_pages, 0);
+        assert!((quality.score - 1.0).abs() < 1e-9);
+        assert!(!quality.is_poor());
+    }
+
+    #[test]
+    fn test_scanned_pages_score_poor() {
+        // Only the first page has a text layer.
+        let quality = score_pages(&[page(2000), String::new(), "  \n".into(), page(20)]);
+        assert_eq!(quality.pages, 4);
+        assert_eq!(quality.empty_pages, 3);
+        assert!(quality.score < MIN_TEXT_SCORE);
+        assert!(quality.is_poor());
+        assert!(score_pages::<&str>(&[]).is_poor());
+    }
+
+    #[test]
+    fn test_garbled_encoding_is_poor() {
+        let garbled = "\u{FFFD}\u{E001}ab ".repeat(1000);
+        let quality = score_pages(&[garbled]);
+        assert!((quality.garbage_ratio - 0.5).abs() < 1e-9);
+        assert!(quality.is_poor());
+    }
+}
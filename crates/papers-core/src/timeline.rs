@@ -0,0 +1,232 @@
+//! Year-bucketed timelines of a paper set, for clients that render the
+//! development of a research area.
+//!
+//! Papers are grouped by publication year, oldest first. Within a year the
+//! most-cited papers (by `cited_by_count`) are flagged as milestones; papers
+//! without a count, or with a count of zero, are never milestones.
+
+use std::collections::BTreeMap;
+
+use papers_openalex::Work;
+use serde::Serialize;
+
+use crate::selection::SelectionEntry;
+
+/// Fields fetched per work when building a selection timeline.
+pub const TIMELINE_SELECT: &str =
+    "id,display_name,doi,publication_year,authorships,primary_location,cited_by_count";
+
+/// Default number of milestones flagged per year.
+pub const DEFAULT_MILESTONES_PER_YEAR: usize = 1;
+
+/// One paper on a timeline.
+#[derive(Debug, Clone, Serialize)]
+pub struct TimelinePaper {
+    /// OpenAlex work ID, selection key, or indexed paper ID.
+    pub id: String,
+    pub title: Option<String>,
+    pub authors: Vec<String>,
+    pub year: Option<u32>,
+    pub venue: Option<String>,
+    pub doi: Option<String>,
+    /// Citation count from the paper's source (`None` when unknown).
+    pub cited_by_count: Option<i64>,
+    /// True when this is one of the year's most-cited papers.
+    pub milestone: bool,
+}
+
+impl TimelinePaper {
+    /// Build a timeline paper from an OpenAlex work.
+    pub fn from_work(work: &Work) -> Self {
+        let id = work.id.strip_prefix("https://openalex.org/").unwrap_or(&work.id);
+        Self {
+            id: id.to_string(),
+            title: work.display_name.clone().or_else(|| work.title.clone()),
+            authors: work
+                .authorships
+                .iter()
+                .flatten()
+                .filter_map(|a| a.author.as_ref()?.display_name.clone())
+                .collect(),
+            year: work.publication_year.and_then(|y| u32::try_from(y).ok()),
+            venue: work
+                .primary_location
+                .as_ref()
+                .and_then(|l| l.source.as_ref())
+                .and_then(|s| s.display_name.clone()),
+            doi: work.doi.as_deref().map(|d| crate::selection::strip_doi_prefix(d).to_string()),
+            cited_by_count: work.cited_by_count,
+            milestone: false,
+        }
+    }
+
+    /// Build a timeline paper from a selection entry's stored metadata, for
+    /// entries that could not be looked up.
+    pub fn from_entry(entry: &SelectionEntry) -> Self {
+        Self {
+            id: entry
+                .openalex_id
+                .clone()
+                .or_else(|| entry.doi.clone())
+                .or_else(|| entry.zotero_key.clone())
+                .unwrap_or_default(),
+            title: entry.title.clone(),
+            authors: entry.authors.clone().unwrap_or_default(),
+            year: entry.year,
+            venue: None,
+            doi: entry.doi.clone(),
+            cited_by_count: None,
+            milestone: false,
+        }
+    }
+}
+
+/// Papers published in one year.
+#[derive(Debug, Clone, Serialize)]
+pub struct TimelineYear {
+    pub year: u32,
+    pub count: usize,
+    /// Most-cited first, then by title.
+    pub papers: Vec<TimelinePaper>,
+}
+
+/// A paper set bucketed by publication year.
+#[derive(Debug, Clone, Serialize)]
+pub struct Timeline {
+    /// Selection name or description of the DB scope.
+    pub scope: String,
+    /// Papers placed on the timeline, including `undated`.
+    pub total: usize,
+    /// First and last year with papers.
+    pub first_year: Option<u32>,
+    pub last_year: Option<u32>,
+    /// Oldest first; years without papers are omitted.
+    pub years: Vec<TimelineYear>,
+    /// Papers with no publication year.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub undated: Vec<TimelinePaper>,
+    /// Selection entries with no lookup result and no stored year.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub unresolved: Vec<String>,
+}
+
+fn by_citations_then_title(a: &TimelinePaper, b: &TimelinePaper) -> std::cmp::Ordering {
+    b.cited_by_count
+        .unwrap_or(-1)
+        .cmp(&a.cited_by_count.unwrap_or(-1))
+        .then_with(|| a.title.cmp(&b.title))
+}
+
+/// Bucket `papers` by year and flag up to `milestones_per_year` of the
+/// most-cited papers in each year as milestones (0 disables milestones).
+pub fn build_timeline(
+    scope: &str,
+    papers: Vec<TimelinePaper>,
+    milestones_per_year: usize,
+    unresolved: Vec<String>,
+) -> Timeline {
+    let total = papers.len();
+    let mut buckets: BTreeMap<u32, Vec<TimelinePaper>> = BTreeMap::new();
+    let mut undated = Vec::new();
+    for paper in papers {
+        match paper.year {
+            Some(year) => buckets.entry(year).or_default().push(paper),
+            None => undated.push(paper),
+        }
+    }
+
+    let years: Vec<TimelineYear> = buckets
+        .into_iter()
+        .map(|(year, mut papers)| {
+            papers.sort_by(by_citations_then_title);
+            for paper in papers
+                .iter_mut()
+                .take(milestones_per_year)
+                .filter(|p| p.cited_by_count.is_some_and(|c| c > 0))
+            {
+                paper.milestone = true;
+            }
+            TimelineYear { year, count: papers.len(), papers }
+        })
+        .collect();
+    undated.sort_by(by_citations_then_title);
+
+    Timeline {
+        scope: scope.to_string(),
+        total,
+        first_year: years.first().map(|y| y.year),
+        last_year: years.last().map(|y| y.year),
+        years,
+        undated,
+        unresolved,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paper(id: &str, year: Option<u32>, cited: Option<i64>) -> TimelinePaper {
+        TimelinePaper {
+            id: id.to_string(),
+            title: Some(id.to_string()),
+            authors: Vec::new(),
+            year,
+            venue: None,
+            doi: None,
+            cited_by_count: cited,
+            milestone: false,
+        }
+    }
+
+    #[test]
+    fn buckets_by_year_and_flags_milestones() {
+        let papers = vec![
+            paper("a", Some(2021), Some(5)),
+            paper("b", Some(2019), Some(100)),
+            paper("c", Some(2021), Some(40)),
+            paper("d", Some(2019), Some(0)),
+            paper("e", None, Some(9)),
+        ];
+        let t = build_timeline("area", papers, 1, Vec::new());
+        assert_eq!(t.total, 5);
+        assert_eq!((t.first_year, t.last_year), (Some(2019), Some(2021)));
+        let years: Vec<_> = t.years.iter().map(|y| (y.year, y.count)).collect();
+        assert_eq!(years, vec![(2019, 2), (2021, 2)]);
+        let ids: Vec<_> = t.years[1].papers.iter().map(|p| (p.id.as_str(), p.milestone)).collect();
+        assert_eq!(ids, vec![("c", true), ("a", false)]);
+        assert!(t.years[0].papers[0].milestone);
+        assert_eq!(t.undated.len(), 1);
+        assert!(!t.undated[0].milestone);
+    }
+
+    #[test]
+    fn uncited_papers_are_never_milestones() {
+        let papers = vec![paper("a", Some(2020), Some(0)), paper("b", Some(2020), None)];
+        let t = build_timeline("area", papers, 2, Vec::new());
+        assert!(t.years[0].papers.iter().all(|p| !p.milestone));
+        let t = build_timeline("area", vec![paper("a", Some(2020), Some(3))], 0, Vec::new());
+        assert!(!t.years[0].papers[0].milestone);
+    }
+
+    #[test]
+    fn from_work_extracts_key_metadata() {
+        let work: Work = serde_json::from_value(serde_json::json!({
+            "id": "https://openalex.org/W1",
+            "display_name": "Paper",
+            "doi": "https://doi.org/10.1/x",
+            "publication_year": 2020,
+            "cited_by_count": 7,
+            "authorships": [{"author": {"display_name": "Ada"}}],
+            "primary_location": {"source": {"display_name": "Journal"}},
+        }))
+        .unwrap();
+        let p = TimelinePaper::from_work(&work);
+        assert_eq!(p.id, "W1");
+        assert_eq!(p.doi.as_deref(), Some("10.1/x"));
+        assert_eq!(p.year, Some(2020));
+        assert_eq!(p.authors, vec!["Ada"]);
+        assert_eq!(p.venue.as_deref(), Some("Journal"));
+        assert_eq!(p.cited_by_count, Some(7));
+    }
+}
@@ -0,0 +1,78 @@
+//! Small text helpers shared by the parsing and reporting modules.
+
+/// Words that end in a period without ending the sentence.
+const ABBREVIATIONS: &[&str] = &[
+    "al", "fig", "figs", "eq", "eqs", "sec", "e.g", "i.e", "vs", "cf", "ref", "refs", "no", "vol", "pp", "ed", "eds",
+    "proc",
+];
+
+/// `W123` from `https://openalex.org/W123`; other IDs come back unchanged.
+pub fn short_id(id: &str) -> &str {
+    id.strip_prefix("https://openalex.org/").unwrap_or(id)
+}
+
+/// Whether the word ending at a `.` is an abbreviation or an initial rather
+/// than the end of a sentence (`et al.`, `Fig.`, `e.g.`, `vol.`, `J.`, `JM.`).
+pub fn is_abbreviation(word: &str) -> bool {
+    let word = word.trim_start_matches(|c: char| !c.is_alphanumeric());
+    let letters: Vec<char> = word.chars().filter(|c| c.is_alphabetic()).collect();
+    word.chars().count() == 1
+        || (!letters.is_empty() && letters.len() <= 2 && letters.iter().all(|c| c.is_uppercase()))
+        || ABBREVIATIONS.contains(&word.to_lowercase().as_str())
+}
+
+/// Split `text` after each sentence end: `.`, `?` or `!` followed by
+/// whitespace or the end of the text, except a period after an
+/// [`is_abbreviation`]. Sentences come back trimmed; empty ones are dropped.
+pub fn split_sentences(text: &str) -> Vec<&str> {
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    for (i, c) in text.char_indices() {
+        if !matches!(c, '.' | '?' | '!') || text[i + 1..].starts_with(|n: char| !n.is_whitespace()) {
+            continue;
+        }
+        let word = text[start..i].rsplit(char::is_whitespace).next().unwrap_or("");
+        if c == '.' && is_abbreviation(word) {
+            continue;
+        }
+        let sentence = text[start..=i].trim();
+        if !sentence.is_empty() {
+            sentences.push(sentence);
+        }
+        start = i + 1;
+    }
+    let rest = text[start..].trim();
+    if !rest.is_empty() {
+        sentences.push(rest);
+    }
+    sentences
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_id_strips_the_openalex_prefix() {
+        assert_eq!(short_id("https://openalex.org/W123"), "W123");
+        assert_eq!(short_id("W123"), "W123");
+    }
+
+    #[test]
+    fn abbreviations_and_initials() {
+        for word in ["al", "Fig", "(e.g", "vol", "J", "JM", "x"] {
+            assert!(is_abbreviation(word), "{word}");
+        }
+        for word in ["higher", "2020", "USA", "Results"] {
+            assert!(!is_abbreviation(word), "{word}");
+        }
+    }
+
+    #[test]
+    fn split_sentences_skips_abbreviations() {
+        assert_eq!(
+            split_sentences("See Fig. 3 and J. Doe et al. for 1.5x gains. Why? Done!  "),
+            ["See Fig. 3 and J. Doe et al. for 1.5x gains.", "Why?", "Done!"]
+        );
+    }
+}
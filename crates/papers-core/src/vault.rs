@@ -0,0 +1,336 @@
+//! Per-paper Markdown notes for Obsidian-style vaults.
+//!
+//! Each indexed paper gets one note: YAML frontmatter with its metadata, then a
+//! generated block (summary, annotation excerpts, links) between
+//! [`BEGIN_MARKER`] and [`END_MARKER`]. Re-writing a note replaces the
+//! frontmatter and the generated block and keeps everything after the end
+//! marker, so notes can be re-synced on every re-ingest without losing edits.
+//! Existing notes are found by their `paper_id` frontmatter key, so renaming a
+//! note file (or a title change upstream) doesn't create duplicates.
+
+use std::path::{Path, PathBuf};
+
+use papers_zotero::Item;
+
+/// Environment variable that overrides the configured vault directory.
+pub const VAULT_DIR_ENV: &str = "PAPERS_VAULT_DIR";
+
+/// Start of the generated part of a note.
+pub const BEGIN_MARKER: &str = "<!-- papers:begin -->";
+
+/// End of the generated part of a note; anything after it belongs to the user.
+pub const END_MARKER: &str = "<!-- papers:end -->";
+
+#[derive(Debug, thiserror::Error)]
+pub enum VaultError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error(
+        "No vault directory configured. Set {VAULT_DIR_ENV}, run `papers config set vault <dir>`, \
+         or pass --dir"
+    )]
+    NotConfigured,
+}
+
+/// A highlighted passage or comment from a PDF annotation.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize)]
+pub struct AnnotationExcerpt {
+    pub text: Option<String>,
+    pub comment: Option<String>,
+    pub page: Option<String>,
+}
+
+impl AnnotationExcerpt {
+    /// Build an excerpt from a Zotero annotation item. Returns `None` when the
+    /// annotation has neither text nor a comment (e.g. image annotations).
+    pub fn from_zotero(item: &Item) -> Option<Self> {
+        let field = |name: &str| {
+            item.data
+                .extra_fields
+                .get(name)
+                .and_then(|v| v.as_str())
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(String::from)
+        };
+        let excerpt = Self {
+            text: field("annotationText"),
+            comment: field("annotationComment"),
+            page: field("annotationPageLabel"),
+        };
+        (excerpt.text.is_some() || excerpt.comment.is_some()).then_some(excerpt)
+    }
+}
+
+/// Everything rendered into a paper's note.
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct VaultNote {
+    pub paper_id: String,
+    pub item_key: Option<String>,
+    pub title: String,
+    pub authors: Vec<String>,
+    pub year: Option<u16>,
+    pub venue: Option<String>,
+    pub doi: Option<String>,
+    pub tags: Vec<String>,
+    pub summary: Option<String>,
+    pub annotations: Vec<AnnotationExcerpt>,
+    /// `(chapter_idx, title)` pairs, linked as `papers://` resources.
+    pub chapters: Vec<(u16, String)>,
+}
+
+/// The vault directory from [`VAULT_DIR_ENV`] or the `vault_dir` config key.
+pub fn configured_vault_dir() -> Option<PathBuf> {
+    if let Ok(dir) = std::env::var(VAULT_DIR_ENV)
+        && !dir.trim().is_empty()
+    {
+        return Some(PathBuf::from(dir));
+    }
+    crate::config::PapersConfig::load().ok()?.vault_dir.map(PathBuf::from)
+}
+
+/// File name for a new note: the title with characters that are invalid in
+/// file names or special in Obsidian links removed, falling back to the paper ID.
+pub fn note_file_name(note: &VaultNote) -> String {
+    let clean = |s: &str| {
+        s.chars()
+            .map(|c| if r#"\/:*?"<>|#^[]"#.contains(c) || c.is_control() { ' ' } else { c })
+            .collect::<String>()
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    let mut name = clean(&note.title);
+    if name.is_empty() {
+        name = clean(&note.paper_id);
+    }
+    if name.chars().count() > 120 {
+        name = name.chars().take(120).collect::<String>().trim_end().to_string();
+    }
+    format!("{name}.md")
+}
+
+/// Render the YAML frontmatter (including the `---` fences).
+pub fn render_frontmatter(note: &VaultNote) -> String {
+    // JSON strings are valid YAML double-quoted scalars, which sidesteps YAML's
+    // many special characters in titles and venues.
+    let quote = |s: &str| serde_json::to_string(s).unwrap_or_default();
+    let mut out = String::from("---\n");
+    out.push_str(&format!("paper_id: {}\n", quote(&note.paper_id)));
+    out.push_str(&format!("title: {}\n", quote(&note.title)));
+    if !note.authors.is_empty() {
+        out.push_str("authors:\n");
+        for author in &note.authors {
+            out.push_str(&format!("  - {}\n", quote(author)));
+        }
+    }
+    if let Some(year) = note.year {
+        out.push_str(&format!("year: {year}\n"));
+    }
+    if let Some(venue) = &note.venue {
+        out.push_str(&format!("venue: {}\n", quote(venue)));
+    }
+    if let Some(doi) = &note.doi {
+        out.push_str(&format!("doi: {}\n", quote(doi)));
+    }
+    if let Some(key) = &note.item_key {
+        out.push_str(&format!("zotero_key: {}\n", quote(key)));
+    }
+    if !note.tags.is_empty() {
+        out.push_str("tags:\n");
+        for tag in &note.tags {
+            // Obsidian tags can't contain spaces.
+            out.push_str(&format!("  - {}\n", quote(&tag.replace(' ', "-"))));
+        }
+    }
+    out.push_str("---\n");
+    out
+}
+
+/// Render the generated block (including the begin/end markers).
+pub fn render_body(note: &VaultNote) -> String {
+    let mut out = format!("{BEGIN_MARKER}\n# {}\n\n", note.title);
+    let byline: Vec<String> = [
+        (!note.authors.is_empty()).then(|| note.authors.join(", ")),
+        note.venue.clone(),
+        note.year.map(|y| y.to_string()),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    if !byline.is_empty() {
+        out.push_str(&format!("{}\n\n", byline.join(" · ")));
+    }
+
+    out.push_str("## Summary\n\n");
+    match note.summary.as_deref().map(str::trim).filter(|s| !s.is_empty()) {
+        Some(summary) => out.push_str(&format!("{summary}\n\n")),
+        None => out.push_str("_No abstract available._\n\n"),
+    }
+
+    if !note.annotations.is_empty() {
+        out.push_str("## Annotations\n\n");
+        for ann in &note.annotations {
+            if let Some(text) = &ann.text {
+                for line in text.lines() {
+                    out.push_str(&format!("> {line}\n"));
+                }
+                if let Some(page) = &ann.page {
+                    out.push_str(&format!("> — p. {page}\n"));
+                }
+            } else if let Some(page) = &ann.page {
+                out.push_str(&format!("p. {page}: "));
+            }
+            if let Some(comment) = &ann.comment {
+                if ann.text.is_some() {
+                    out.push('\n');
+                }
+                out.push_str(comment);
+                out.push('\n');
+            }
+            out.push('\n');
+        }
+    }
+
+    out.push_str("## Links\n\n");
+    if let Some(doi) = &note.doi {
+        out.push_str(&format!("- DOI: [{doi}](https://doi.org/{doi})\n"));
+    }
+    if let Some(key) = &note.item_key {
+        out.push_str(&format!("- Zotero: [open](zotero://select/library/items/{key})\n"));
+    }
+    for (idx, chapter) in &note.chapters {
+        let uri = format!("papers://paper/{}/chapter/{idx}", note.paper_id);
+        out.push_str(&format!("- Chapter {idx}: [{chapter}]({uri})\n"));
+    }
+    out.push_str(&format!("{END_MARKER}\n"));
+    out
+}
+
+/// Render a complete note for a file that doesn't exist yet.
+pub fn render_note(note: &VaultNote) -> String {
+    format!("{}{}\n## Notes\n\n", render_frontmatter(note), render_body(note))
+}
+
+/// Merge freshly rendered frontmatter and body into `existing` note text,
+/// keeping whatever follows the end marker. Without markers, the existing
+/// body (after any frontmatter) is kept below the generated block.
+pub fn merge_note(existing: &str, note: &VaultNote) -> String {
+    let user = match existing.find(END_MARKER) {
+        Some(pos) => {
+            existing[pos + END_MARKER.len()..].trim_start_matches(['\r', '\n']).to_string()
+        }
+        None => split_frontmatter(existing).1.trim_start().to_string(),
+    };
+    format!("{}{}\n{user}", render_frontmatter(note), render_body(note))
+}
+
+/// Find the note for `paper_id` among the top-level `.md` files of `dir`.
+pub fn find_note(dir: &Path, paper_id: &str) -> Result<Option<PathBuf>, VaultError> {
+    if !dir.exists() {
+        return Ok(None);
+    }
+    let wanted = format!("paper_id: {}", serde_json::to_string(paper_id).unwrap_or_default());
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().is_none_or(|e| e != "md") {
+            continue;
+        }
+        let Ok(text) = std::fs::read_to_string(&path) else { continue };
+        if split_frontmatter(&text).0.lines().any(|l| l.trim_end() == wanted) {
+            return Ok(Some(path));
+        }
+    }
+    Ok(None)
+}
+
+/// Create or update the note for `note.paper_id` in `dir`, returning its path.
+pub fn write_note(dir: &Path, note: &VaultNote) -> Result<PathBuf, VaultError> {
+    std::fs::create_dir_all(dir)?;
+    if let Some(path) = find_note(dir, &note.paper_id)? {
+        let existing = std::fs::read_to_string(&path)?;
+        std::fs::write(&path, merge_note(&existing, note))?;
+        return Ok(path);
+    }
+    let mut path = dir.join(note_file_name(note));
+    if path.exists() {
+        // Same title as a note for another paper (or a user's own note).
+        let stem = note_file_name(note).trim_end_matches(".md").to_string();
+        let suffix = note.item_key.as_deref().unwrap_or(&note.paper_id).replace('/', "_");
+        path = dir.join(format!("{stem} ({suffix}).md"));
+    }
+    std::fs::write(&path, render_note(note))?;
+    Ok(path)
+}
+
+/// Split note text into (frontmatter without fences, rest).
+fn split_frontmatter(text: &str) -> (&str, &str) {
+    if let Some(rest) = text.strip_prefix("---\n").or_else(|| text.strip_prefix("---\r\n"))
+        && let Some(end) = rest.find("\n---")
+    {
+        let after = &rest[end + 4..];
+        return (&rest[..end], after.trim_start_matches(['\r', '\n']));
+    }
+    ("", text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn note() -> VaultNote {
+        VaultNote {
+            paper_id: "10.1145/3592433".into(),
+            item_key: Some("ABC12345".into()),
+            title: "3D Gaussian Splatting: Real-Time Radiance Fields".into(),
+            authors: vec!["Bernhard Kerbl".into(), "Georgios Kopanas".into()],
+            year: Some(2023),
+            venue: Some("ACM TOG".into()),
+            doi: Some("10.1145/3592433".into()),
+            tags: vec!["neural rendering".into()],
+            summary: Some("We present a method.".into()),
+            annotations: vec![AnnotationExcerpt {
+                text: Some("anisotropic 3D Gaussians".into()),
+                comment: Some("key idea".into()),
+                page: Some("2".into()),
+            }],
+            chapters: vec![(1, "Introduction".into())],
+        }
+    }
+
+    #[test]
+    fn renders_frontmatter_and_sections() {
+        let text = render_note(&note());
+        assert!(text.starts_with("---\npaper_id: \"10.1145/3592433\"\n"));
+        assert!(text.contains("  - \"neural-rendering\"\n"));
+        assert!(text.contains("## Summary\n\nWe present a method.\n"));
+        assert!(text.contains("> anisotropic 3D Gaussians\n> — p. 2\n\nkey idea\n"));
+        assert!(text.contains("(papers://paper/10.1145/3592433/chapter/1)"));
+        assert!(text.ends_with(&format!("{END_MARKER}\n\n## Notes\n\n")));
+        assert_eq!(note_file_name(&note()), "3D Gaussian Splatting Real-Time Radiance Fields.md");
+    }
+
+    #[test]
+    fn merge_keeps_user_notes_after_end_marker() {
+        let original = render_note(&note()) + "My thoughts.\n";
+        let mut updated = note();
+        updated.summary = Some("Updated abstract.".into());
+        let merged = merge_note(&original, &updated);
+        assert!(merged.contains("Updated abstract."));
+        assert!(!merged.contains("We present a method."));
+        assert!(merged.ends_with(&format!("{END_MARKER}\n\n## Notes\n\nMy thoughts.\n")));
+        assert_eq!(merge_note(&merged, &updated), merged);
+    }
+
+    #[test]
+    fn write_note_updates_existing_file_found_by_paper_id() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = write_note(dir.path(), &note()).unwrap();
+        let renamed = dir.path().join("Gaussians.md");
+        std::fs::rename(&path, &renamed).unwrap();
+        let mut updated = note();
+        updated.title = "Renamed upstream".into();
+        assert_eq!(write_note(dir.path(), &updated).unwrap(), renamed);
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+}
@@ -0,0 +1,447 @@
+//! Preprint ↔ published version linkage.
+//!
+//! OpenAlex sometimes merges a preprint into its journal article (the preprint
+//! becomes a `submittedVersion` location of the published work) and sometimes
+//! keeps them as separate works. Evidence for a link comes from three places:
+//! locations of the same record, DOIs found in location landing pages, and a
+//! title search scored by title similarity, author overlap, and year order.
+
+use std::collections::HashSet;
+
+use papers_openalex::{Location, Work};
+use serde::Serialize;
+
+use crate::util::short_id;
+
+/// Fields fetched for the queried work and every candidate.
+pub const VERSION_SELECT: &str =
+    "id,doi,display_name,publication_year,type,authorships,primary_location,locations";
+
+/// Title-search candidates below this similarity are discarded.
+pub const MIN_TITLE_SIMILARITY: f64 = 0.75;
+
+/// Confidence reported when the other version is a location of the same record.
+const SAME_RECORD_CONFIDENCE: f64 = 0.9;
+
+/// DOI prefixes registered by preprint servers.
+const PREPRINT_DOI_PREFIXES: &[&str] = &[
+    "10.48550/", // arXiv
+    "10.1101/",  // bioRxiv / medRxiv (also Cold Spring Harbor journals, see below)
+    "10.21203/", // Research Square
+    "10.20944/", // Preprints.org
+    "10.31219/", // OSF Preprints
+    "10.31234/", // PsyArXiv
+    "10.31235/", // SocArXiv
+    "10.2139/",  // SSRN
+    "10.26434/", // ChemRxiv
+    "10.36227/", // TechRxiv
+];
+
+/// Which side of the preprint/published pair a work or location is on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VersionRole {
+    Preprint,
+    Published,
+}
+
+impl VersionRole {
+    pub fn opposite(self) -> Self {
+        match self {
+            Self::Preprint => Self::Published,
+            Self::Published => Self::Preprint,
+        }
+    }
+}
+
+/// Why a candidate was linked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VersionEvidence {
+    /// The candidate's DOI appears in a location of the queried work.
+    LocationDoi,
+    /// Found by title search with near-identical title.
+    TitleMatch,
+    /// At least half of the shorter author list is shared.
+    AuthorOverlap,
+}
+
+impl VersionEvidence {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::LocationDoi => "location_doi",
+            Self::TitleMatch => "title_match",
+            Self::AuthorOverlap => "author_overlap",
+        }
+    }
+}
+
+/// Compact description of a work.
+#[derive(Debug, Clone, Serialize)]
+pub struct VersionRef {
+    pub id: String,
+    pub doi: Option<String>,
+    pub title: Option<String>,
+    pub publication_year: Option<i32>,
+    pub r#type: Option<String>,
+    /// Primary location source name.
+    pub source: Option<String>,
+}
+
+impl From<&Work> for VersionRef {
+    fn from(work: &Work) -> Self {
+        Self {
+            id: short_id(&work.id).to_string(),
+            doi: work.doi.clone(),
+            title: work.display_name.clone().or_else(|| work.title.clone()),
+            publication_year: work.publication_year,
+            r#type: work.r#type.clone(),
+            source: work
+                .primary_location
+                .as_ref()
+                .and_then(|l| l.source.as_ref())
+                .and_then(|s| s.display_name.clone()),
+        }
+    }
+}
+
+/// A location of the queried record that holds the other version.
+#[derive(Debug, Clone, Serialize)]
+pub struct VersionLocation {
+    pub source: Option<String>,
+    pub version: Option<String>,
+    pub landing_page_url: Option<String>,
+    pub doi: Option<String>,
+}
+
+/// A candidate counterpart with its confidence (0–1).
+#[derive(Debug, Clone, Serialize)]
+pub struct VersionMatch {
+    #[serde(flatten)]
+    pub work: VersionRef,
+    pub confidence: f64,
+    pub title_similarity: f64,
+    pub author_overlap: f64,
+    pub evidence: Vec<VersionEvidence>,
+}
+
+/// Result of `work_published_version`.
+#[derive(Debug, Clone, Serialize)]
+pub struct PublishedVersionReport {
+    pub work: VersionRef,
+    pub role: VersionRole,
+    /// Preprint side of the best link (the work itself when it is the preprint).
+    pub preprint_id: Option<String>,
+    /// Published side of the best link (the work itself when it is published).
+    pub published_id: Option<String>,
+    /// Confidence of the best link; `None` when no counterpart was found.
+    pub confidence: Option<f64>,
+    /// Locations of the same record holding the other version.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub merged_locations: Vec<VersionLocation>,
+    /// Separate works that may be the other version, best first.
+    pub candidates: Vec<VersionMatch>,
+}
+
+/// Lowercase bare DOI (`10.x/y`) from a DOI URL, `doi:` string, or landing page URL.
+pub fn bare_doi(value: &str) -> Option<String> {
+    let lower = value.trim().to_lowercase();
+    let start = lower.find("10.")?;
+    let doi = &lower[start..];
+    let (prefix, suffix) = doi.split_once('/')?;
+    if prefix.len() < 7 || suffix.is_empty() || !prefix[3..].chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    Some(doi.trim_end_matches(['/', '.']).to_string())
+}
+
+/// True when `doi` was registered by a preprint server.
+pub fn is_preprint_doi(doi: &str) -> bool {
+    let Some(doi) = bare_doi(doi) else {
+        return false;
+    };
+    PREPRINT_DOI_PREFIXES.iter().any(|p| doi.starts_with(p))
+        // 10.1101 is shared with Cold Spring Harbor journals (10.1101/gr.…);
+        // bioRxiv / medRxiv suffixes are numeric or date-based.
+        && (!doi.starts_with("10.1101/")
+            || doi["10.1101/".len()..].starts_with(|c: char| c.is_ascii_digit()))
+}
+
+/// Classify a work as preprint or published.
+pub fn work_role(work: &Work) -> VersionRole {
+    let is_preprint = work.r#type.as_deref() == Some("preprint")
+        || work.doi.as_deref().is_some_and(is_preprint_doi);
+    if is_preprint { VersionRole::Preprint } else { VersionRole::Published }
+}
+
+/// Classify one location, or `None` when it gives no signal.
+fn location_role(location: &Location) -> Option<VersionRole> {
+    let source_type = location.source.as_ref().and_then(|s| s.r#type.as_deref());
+    let url_doi = location.landing_page_url.as_deref().and_then(bare_doi);
+    if url_doi.as_deref().is_some_and(is_preprint_doi)
+        || location.version.as_deref() == Some("submittedVersion")
+    {
+        return Some(VersionRole::Preprint);
+    }
+    match (location.version.as_deref(), source_type) {
+        (Some("publishedVersion"), Some("journal" | "conference" | "book series")) => {
+            Some(VersionRole::Published)
+        }
+        _ => None,
+    }
+}
+
+/// Locations of `work` holding the version opposite to `role`.
+pub fn merged_locations(work: &Work, role: VersionRole) -> Vec<VersionLocation> {
+    let want = role.opposite();
+    let mut seen = HashSet::new();
+    work.locations
+        .iter()
+        .flatten()
+        .filter(|l| location_role(l) == Some(want))
+        .filter(|l| seen.insert(l.landing_page_url.clone()))
+        .map(|l| VersionLocation {
+            source: l.source.as_ref().and_then(|s| s.display_name.clone()),
+            version: l.version.clone(),
+            landing_page_url: l.landing_page_url.clone(),
+            doi: l.landing_page_url.as_deref().and_then(bare_doi),
+        })
+        .collect()
+}
+
+/// DOIs in `work`'s location landing pages other than its own DOI.
+pub fn location_dois(work: &Work) -> Vec<String> {
+    let own = work.doi.as_deref().and_then(bare_doi);
+    let mut dois: Vec<String> = Vec::new();
+    for location in work.locations.iter().flatten() {
+        if let Some(doi) = location.landing_page_url.as_deref().and_then(bare_doi)
+            && Some(&doi) != own.as_ref()
+            && !dois.contains(&doi)
+        {
+            dois.push(doi);
+        }
+    }
+    dois
+}
+
+fn title_tokens(title: &str) -> HashSet<String> {
+    title
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .map(|t| t.to_lowercase())
+        .collect()
+}
+
+/// Jaccard similarity of lowercase title words.
+pub fn title_similarity(a: &str, b: &str) -> f64 {
+    let (a, b) = (title_tokens(a), title_tokens(b));
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    a.intersection(&b).count() as f64 / a.union(&b).count() as f64
+}
+
+fn family_names(work: &Work) -> HashSet<String> {
+    work.authorships
+        .iter()
+        .flatten()
+        .filter_map(|a| a.author.as_ref()?.display_name.as_deref())
+        .filter_map(|name| name.split_whitespace().last())
+        .map(|n| n.to_lowercase())
+        .collect()
+}
+
+/// Shared family names as a fraction of the shorter author list.
+pub fn author_overlap(a: &Work, b: &Work) -> f64 {
+    let (a, b) = (family_names(a), family_names(b));
+    let shorter = a.len().min(b.len());
+    if shorter == 0 {
+        return 0.0;
+    }
+    a.intersection(&b).count() as f64 / shorter as f64
+}
+
+/// True when the preprint does not post-date the published version by more
+/// than a year, and the gap is at most five years.
+fn years_consistent(preprint: Option<i32>, published: Option<i32>) -> bool {
+    match (preprint, published) {
+        (Some(pre), Some(publ)) => (-1..=5).contains(&(publ - pre)),
+        _ => true,
+    }
+}
+
+/// Score `candidate` as the counterpart of `work`. Returns `None` when the
+/// candidate is the same work, on the same side, or (without a location DOI
+/// link) not similar enough by title.
+pub fn score_candidate(work: &Work, candidate: &Work, location_doi: bool) -> Option<VersionMatch> {
+    if short_id(&work.id) == short_id(&candidate.id) {
+        return None;
+    }
+    let role = work_role(work);
+    if work_role(candidate) == role {
+        return None;
+    }
+    let title = |w: &Work| w.display_name.clone().or_else(|| w.title.clone()).unwrap_or_default();
+    let title_sim = title_similarity(&title(work), &title(candidate));
+    if !location_doi && title_sim < MIN_TITLE_SIMILARITY {
+        return None;
+    }
+    let authors = author_overlap(work, candidate);
+    let (pre, publ) = match role {
+        VersionRole::Preprint => (work, candidate),
+        VersionRole::Published => (candidate, work),
+    };
+    let years_ok = years_consistent(pre.publication_year, publ.publication_year);
+
+    let mut confidence =
+        0.55 * title_sim + 0.3 * authors + if years_ok { 0.15 } else { 0.0 };
+    let mut evidence = Vec::new();
+    if location_doi {
+        confidence = 0.5 + 0.5 * confidence;
+        evidence.push(VersionEvidence::LocationDoi);
+    }
+    if title_sim >= MIN_TITLE_SIMILARITY {
+        evidence.push(VersionEvidence::TitleMatch);
+    }
+    if authors >= 0.5 {
+        evidence.push(VersionEvidence::AuthorOverlap);
+    }
+    Some(VersionMatch {
+        work: VersionRef::from(candidate),
+        confidence: (confidence * 100.0).round() / 100.0,
+        title_similarity: (title_sim * 100.0).round() / 100.0,
+        author_overlap: (authors * 100.0).round() / 100.0,
+        evidence,
+    })
+}
+
+/// Combine the queried work, its merged locations, and scored candidates into
+/// a report. Candidates found more than once keep their best score and the
+/// union of their evidence, sorted by confidence.
+pub fn version_report(work: &Work, mut candidates: Vec<VersionMatch>) -> PublishedVersionReport {
+    let role = work_role(work);
+    let merged = merged_locations(work, role);
+    let this = VersionRef::from(work);
+
+    candidates.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+    let mut merged_candidates: Vec<VersionMatch> = Vec::new();
+    for candidate in candidates {
+        match merged_candidates.iter_mut().find(|c| c.work.id == candidate.work.id) {
+            Some(best) => {
+                for e in candidate.evidence {
+                    if !best.evidence.contains(&e) {
+                        best.evidence.push(e);
+                    }
+                }
+                best.evidence.sort();
+            }
+            None => merged_candidates.push(candidate),
+        }
+    }
+    let candidates = merged_candidates;
+
+    let best = candidates.first().map(|c| (c.work.id.clone(), c.confidence));
+    let (other, confidence) = match best {
+        Some((id, conf)) if merged.is_empty() || conf >= SAME_RECORD_CONFIDENCE => {
+            (Some(id), Some(conf))
+        }
+        _ if !merged.is_empty() => (Some(this.id.clone()), Some(SAME_RECORD_CONFIDENCE)),
+        _ => (None, None),
+    };
+    let (preprint_id, published_id) = match role {
+        VersionRole::Preprint => (Some(this.id.clone()), other),
+        VersionRole::Published => (other, Some(this.id.clone())),
+    };
+    PublishedVersionReport {
+        work: this,
+        role,
+        preprint_id,
+        published_id,
+        confidence,
+        merged_locations: merged,
+        candidates,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn work(id: &str, doi: &str, title: &str, year: i32, ty: &str, authors: &[&str]) -> Work {
+        let authorships: Vec<_> = authors
+            .iter()
+            .map(|a| serde_json::json!({"author": {"display_name": a}}))
+            .collect();
+        serde_json::from_value(serde_json::json!({
+            "id": format!("https://openalex.org/{id}"),
+            "doi": format!("https://doi.org/{doi}"),
+            "display_name": title,
+            "publication_year": year,
+            "type": ty,
+            "authorships": authorships,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn preprint_dois_and_roles() {
+        assert!(is_preprint_doi("https://doi.org/10.48550/arXiv.1706.03762"));
+        assert!(is_preprint_doi("10.1101/2020.03.01.123456"));
+        assert!(!is_preprint_doi("10.1101/gr.123456.110"));
+        assert!(!is_preprint_doi("https://doi.org/10.1038/nature14539"));
+        let pre = work("W1", "10.48550/arxiv.1", "T", 2020, "article", &[]);
+        assert_eq!(work_role(&pre), VersionRole::Preprint);
+        let publ = work("W2", "10.1038/x", "T", 2021, "article", &[]);
+        assert_eq!(work_role(&publ), VersionRole::Published);
+    }
+
+    #[test]
+    fn bare_doi_from_urls() {
+        assert_eq!(bare_doi("https://doi.org/10.1038/ABC").as_deref(), Some("10.1038/abc"));
+        assert_eq!(bare_doi("https://arxiv.org/abs/1706.03762"), None);
+        assert_eq!(bare_doi("doi:10.1/x"), None);
+    }
+
+    #[test]
+    fn title_match_scores_and_links() {
+        let pre = work(
+            "W1", "10.48550/arxiv.1", "Attention Is All You Need", 2017, "preprint",
+            &["Ashish Vaswani", "Noam Shazeer"],
+        );
+        let publ = work(
+            "W2", "10.5555/nips", "Attention is all you need", 2017, "article",
+            &["Ashish Vaswani", "Noam Shazeer", "Niki Parmar"],
+        );
+        let other = work("W3", "10.1/y", "Something else entirely", 2018, "article", &[]);
+        let m = score_candidate(&pre, &publ, false).unwrap();
+        assert_eq!(m.confidence, 1.0);
+        assert_eq!(m.evidence, vec![VersionEvidence::TitleMatch, VersionEvidence::AuthorOverlap]);
+        assert!(score_candidate(&pre, &other, false).is_none());
+        // Same side is never a counterpart
+        assert!(score_candidate(&publ, &other, true).is_none());
+
+        let report = version_report(&pre, vec![m]);
+        assert_eq!(report.role, VersionRole::Preprint);
+        assert_eq!(report.preprint_id.as_deref(), Some("W1"));
+        assert_eq!(report.published_id.as_deref(), Some("W2"));
+        assert_eq!(report.confidence, Some(1.0));
+    }
+
+    #[test]
+    fn merged_record_links_to_itself() {
+        let mut publ = work("W2", "10.1038/x", "T", 2021, "article", &[]);
+        publ.locations = serde_json::from_value(serde_json::json!([
+            {"landing_page_url": "https://doi.org/10.1038/x", "version": "publishedVersion",
+             "source": {"display_name": "Nature", "type": "journal"}},
+            {"landing_page_url": "https://arxiv.org/abs/2001.1", "version": "submittedVersion",
+             "source": {"display_name": "arXiv", "type": "repository"}},
+        ]))
+        .unwrap();
+        let report = version_report(&publ, Vec::new());
+        assert_eq!(report.merged_locations.len(), 1);
+        assert_eq!(report.merged_locations[0].source.as_deref(), Some("arXiv"));
+        assert_eq!(report.preprint_id.as_deref(), Some("W2"));
+        assert_eq!(report.published_id.as_deref(), Some("W2"));
+        assert_eq!(report.confidence, Some(SAME_RECORD_CONFIDENCE));
+    }
+}
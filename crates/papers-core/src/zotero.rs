@@ -1,4 +1,8 @@
-use papers_zotero::{CollectionListParams, ItemListParams, ZoteroClient, ZoteroError};
+use crate::filter::FilterError;
+use papers_openalex::{OpenAlexClient, Work};
+use papers_zotero::{
+    CollectionListParams, Item, ItemListParams, LibraryScope, ZoteroClient, ZoteroError,
+};
 
 /// Returns `true` if `input` looks like a Zotero key.
 ///
@@ -28,6 +32,44 @@ pub async fn resolve_item_key(client: &ZoteroClient, input: &str) -> Result<Stri
         })
 }
 
+/// List up to `limit` top-level items matching a title/search string.
+///
+/// Used to offer a choice when [`resolve_item_key`] would otherwise silently
+/// take the first match. Returns an empty list when `input` is already a key.
+pub async fn item_key_candidates(
+    client: &ZoteroClient,
+    input: &str,
+    limit: u32,
+) -> Result<Vec<Item>, ZoteroError> {
+    if looks_like_zotero_key(input) {
+        return Ok(Vec::new());
+    }
+    let params = ItemListParams::builder().q(input).limit(limit).build();
+    Ok(client.list_top_items(&params).await?.items)
+}
+
+/// One-line description of an item for presenting it as a candidate, e.g.
+/// `Attention Is All You Need — Vaswani et al. (2017)`.
+pub fn item_label(item: &Item) -> String {
+    let title = item.data.title.as_deref().filter(|t| !t.is_empty()).unwrap_or(&item.key);
+    let year = item
+        .meta
+        .parsed_date
+        .as_deref()
+        .or(item.data.date.as_deref())
+        .and_then(|d| d.get(..4))
+        .filter(|y| y.chars().all(|c| c.is_ascii_digit()));
+    let mut label = title.to_string();
+    if let Some(creators) = item.meta.creator_summary.as_deref().filter(|c| !c.is_empty()) {
+        label.push_str(" — ");
+        label.push_str(creators);
+    }
+    if let Some(year) = year {
+        label.push_str(&format!(" ({year})"));
+    }
+    label
+}
+
 /// Resolve a collection key or name to a Zotero collection key.
 ///
 /// If `input` looks like a Zotero key, it is returned directly. Otherwise all
@@ -77,6 +119,566 @@ pub async fn resolve_search_key(
         })
 }
 
+/// Resolve a library reference to a [`LibraryScope`].
+///
+/// Accepts `"user"`/`"my library"` (or an empty string) for the personal
+/// library, a numeric group ID, or a group name. Names are matched
+/// case-insensitively against the user's groups, preferring an exact match
+/// over the first name containing `input`.
+pub async fn resolve_library(
+    client: &ZoteroClient,
+    input: &str,
+) -> Result<LibraryScope, ZoteroError> {
+    let input = input.trim();
+    let input_lower = input.to_lowercase();
+    if input.is_empty() || matches!(input_lower.as_str(), "user" | "my library") {
+        return Ok(LibraryScope::User);
+    }
+    if let Ok(id) = input.parse::<u64>() {
+        return Ok(LibraryScope::Group(id));
+    }
+    let groups = client.list_groups().await?.items;
+    groups
+        .iter()
+        .find(|g| g.data.name.to_lowercase() == input_lower)
+        .or_else(|| groups.iter().find(|g| g.data.name.to_lowercase().contains(&input_lower)))
+        .map(|g| LibraryScope::Group(g.id))
+        .ok_or_else(|| {
+            let names: Vec<&str> = groups.iter().map(|g| g.data.name.as_str()).collect();
+            ZoteroError::Api {
+                status: 404,
+                message: format!(
+                    "No group library found matching: {input} (available: {})",
+                    if names.is_empty() { "none".to_string() } else { names.join(", ") }
+                ),
+            }
+        })
+}
+
+/// Return `client` scoped to `library` (see [`resolve_library`]), or the
+/// client unchanged when `library` is `None`.
+pub async fn scoped_client(
+    client: ZoteroClient,
+    library: Option<&str>,
+) -> Result<ZoteroClient, ZoteroError> {
+    match library {
+        Some(input) => {
+            let scope = resolve_library(&client, input).await?;
+            Ok(client.with_library(scope))
+        }
+        None => Ok(client),
+    }
+}
+
+/// All annotations on an item's PDF, EPUB, and HTML snapshot attachments.
+/// Attachments whose annotations can't be listed are skipped.
+pub async fn work_annotations(
+    client: &ZoteroClient,
+    item_key: &str,
+) -> Result<Vec<Item>, ZoteroError> {
+    let att_params = ItemListParams { item_type: Some("attachment".into()), ..Default::default() };
+    let ann_params = ItemListParams { item_type: Some("annotation".into()), ..Default::default() };
+    let attachments = client.list_item_children(item_key, &att_params).await?;
+    let mut annotations = Vec::new();
+    for att in &attachments.items {
+        let annotatable = matches!(
+            att.data.content_type.as_deref(),
+            Some("application/pdf") | Some("application/epub+zip") | Some("text/html")
+        );
+        if annotatable && let Ok(r) = client.list_item_children(&att.key, &ann_params).await {
+            annotations.extend(r.items);
+        }
+    }
+    Ok(annotations)
+}
+
+/// A Zotero annotation traced back to the top-level item it annotates.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct AnnotationTarget {
+    pub annotation_key: String,
+    pub annotation_type: Option<String>,
+    /// Key of the top-level item owning the annotated attachment.
+    pub item_key: String,
+    pub text: Option<String>,
+    pub comment: Option<String>,
+    pub page_label: Option<String>,
+    /// 0-based PDF page index from the annotation's position.
+    pub page_index: Option<u16>,
+}
+
+/// The 0-based PDF page an annotation sits on, from the `pageIndex` of its
+/// `annotationPosition` JSON. `None` for EPUB and snapshot annotations.
+pub fn annotation_page_index(item: &Item) -> Option<u16> {
+    let position = item.data.extra_fields.get("annotationPosition")?.as_str()?;
+    let position: serde_json::Value = serde_json::from_str(position).ok()?;
+    position.get("pageIndex")?.as_u64()?.try_into().ok()
+}
+
+/// Fetch an annotation and follow its attachment up to the annotated item.
+pub async fn resolve_annotation(
+    client: &ZoteroClient,
+    annotation_key: &str,
+) -> Result<AnnotationTarget, ZoteroError> {
+    let not_found = |message: String| ZoteroError::Api { status: 404, message };
+    let annotation = client.get_item(annotation_key).await?;
+    if annotation.data.item_type != "annotation" {
+        return Err(not_found(format!(
+            "{annotation_key} is a {}, not an annotation",
+            annotation.data.item_type
+        )));
+    }
+    let attachment_key = annotation
+        .data
+        .parent_item
+        .clone()
+        .ok_or_else(|| not_found(format!("annotation {annotation_key} has no attachment")))?;
+    let attachment = client.get_item(&attachment_key).await?;
+    let item_key = attachment.data.parent_item.clone().ok_or_else(|| {
+        not_found(format!("attachment {attachment_key} is not attached to an item"))
+    })?;
+    let field = |name: &str| {
+        annotation.data.extra_fields
+            .get(name)
+            .and_then(|v| v.as_str())
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(String::from)
+    };
+    Ok(AnnotationTarget {
+        annotation_key: annotation.key.clone(),
+        annotation_type: field("annotationType"),
+        item_key,
+        text: field("annotationText"),
+        comment: field("annotationComment"),
+        page_label: field("annotationPageLabel"),
+        page_index: annotation_page_index(&annotation),
+    })
+}
+
+// ── Export ────────────────────────────────────────────────────────────────
+
+/// Export formats Zotero's translators accept as `format=` on item lists.
+/// `bib` returns a formatted bibliography in the CSL `style` given with the request.
+pub const ZOTERO_EXPORT_FORMATS: &[&str] = &[
+    "bibtex",
+    "biblatex",
+    "csljson",
+    "ris",
+    "bib",
+    "mods",
+    "refer",
+    "tei",
+    "csv",
+    "rdf_zotero",
+    "rdf_dc",
+    "rdf_bibliontology",
+    "bookends",
+    "coins",
+    "wikipedia",
+];
+
+/// Page size used when listing or exporting a whole library or collection.
+const EXPORT_PAGE_SIZE: u32 = 100;
+
+/// IDs under which the top-level items of a collection (key or name, see
+/// [`resolve_collection_key`]) may be indexed in the DB: each item's DOI as
+/// Zotero stores it, and its item key. Subcollections are not included.
+pub async fn collection_paper_ids(client: &ZoteroClient, collection: &str) -> Result<Vec<String>, ZoteroError> {
+    let key = resolve_collection_key(client, collection).await?;
+    let mut ids = Vec::new();
+    let mut start = 0;
+    loop {
+        let page = client
+            .list_collection_top_items(
+                &key,
+                &ItemListParams { limit: Some(EXPORT_PAGE_SIZE), start: Some(start), ..Default::default() },
+            )
+            .await?;
+        let fetched = page.items.len();
+        for item in page.items {
+            if let Some(doi) = item.data.doi.as_deref().map(str::trim).filter(|d| !d.is_empty()) {
+                ids.push(doi.to_string());
+            }
+            ids.push(item.key);
+        }
+        start += EXPORT_PAGE_SIZE;
+        if fetched < EXPORT_PAGE_SIZE as usize || page.total_results.is_none_or(|total| u64::from(start) >= total) {
+            break;
+        }
+    }
+    Ok(ids)
+}
+
+/// Export top-level items (of `collection_key`, or the whole library) in one
+/// of [`ZOTERO_EXPORT_FORMATS`] using Zotero's own translators.
+///
+/// When `params.limit` is set only that page is exported; otherwise every
+/// matching item is fetched in pages of 100. `csljson` pages are merged into a
+/// single `{"items": [...]}` document; text formats are concatenated.
+pub async fn export_top_items(
+    client: &ZoteroClient,
+    collection_key: Option<&str>,
+    params: &ItemListParams,
+    format: &str,
+) -> Result<String, ZoteroError> {
+    let format = format.trim().to_lowercase();
+    if !ZOTERO_EXPORT_FORMATS.contains(&format.as_str()) {
+        return Err(ZoteroError::Api {
+            status: 400,
+            message: format!(
+                "Unsupported export format: {format} (supported: {})",
+                ZOTERO_EXPORT_FORMATS.join(", ")
+            ),
+        });
+    }
+    let fetch = |params: ItemListParams| {
+        let format = format.clone();
+        async move {
+            match collection_key {
+                Some(key) => client.export_collection_top_items(key, &params, &format).await,
+                None => client.export_top_items(&params, &format).await,
+            }
+        }
+    };
+
+    let mut pages = Vec::new();
+    if params.limit.is_some() {
+        pages.push(fetch(params.clone()).await?.text);
+    } else {
+        let mut start = params.start.unwrap_or(0);
+        loop {
+            let page = fetch(ItemListParams {
+                limit: Some(EXPORT_PAGE_SIZE),
+                start: Some(start),
+                ..params.clone()
+            })
+            .await?;
+            pages.push(page.text);
+            start += EXPORT_PAGE_SIZE;
+            if page.total_results.is_none_or(|total| u64::from(start) >= total) {
+                break;
+            }
+        }
+    }
+
+    if format == "csljson" {
+        let mut items = Vec::new();
+        for page in &pages {
+            let doc: serde_json::Value = serde_json::from_str(page)?;
+            if let Some(page_items) = doc.get("items").and_then(|v| v.as_array()) {
+                items.extend(page_items.iter().cloned());
+            }
+        }
+        return Ok(serde_json::to_string_pretty(&serde_json::json!({ "items": items }))?);
+    }
+    let pages: Vec<&str> = pages.iter().map(|p| p.trim_end()).filter(|p| !p.is_empty()).collect();
+    Ok(pages.join("\n\n"))
+}
+
+// ── Item writes ───────────────────────────────────────────────────────────
+
+/// Errors from creating or updating items in the Zotero library.
+#[derive(Debug, thiserror::Error)]
+pub enum ZoteroWriteError {
+    #[error(transparent)]
+    OpenAlex(#[from] FilterError),
+
+    #[error(transparent)]
+    Zotero(#[from] ZoteroError),
+
+    #[error("work is already in the Zotero library as {key} (pass allow_duplicate to add it anyway)")]
+    AlreadyExists { key: String },
+
+    #[error("item {key} changed since version {version}; fetch it again and retry")]
+    VersionConflict { key: String, version: u64 },
+
+    #[error("{0}")]
+    Invalid(String),
+}
+
+/// Fields to change on an existing item. `None` / empty leaves a field as-is.
+#[derive(Debug, Clone, Default)]
+pub struct ZoteroItemUpdate {
+    pub title: Option<String>,
+    pub abstract_note: Option<String>,
+    pub doi: Option<String>,
+    pub date: Option<String>,
+    pub url: Option<String>,
+    pub extra: Option<String>,
+    pub add_tags: Vec<String>,
+    pub remove_tags: Vec<String>,
+    /// Collection keys or names to add the item to.
+    pub add_collections: Vec<String>,
+}
+
+impl ZoteroItemUpdate {
+    pub fn is_empty(&self) -> bool {
+        self.title.is_none()
+            && self.abstract_note.is_none()
+            && self.doi.is_none()
+            && self.date.is_none()
+            && self.url.is_none()
+            && self.extra.is_none()
+            && self.add_tags.is_empty()
+            && self.remove_tags.is_empty()
+            && self.add_collections.is_empty()
+    }
+}
+
+/// Split an author name into a Zotero creator (two-field when possible).
+pub(crate) fn zotero_creator(name: &str) -> serde_json::Value {
+    let name = name.trim();
+    if let Some((last, first)) = name.split_once(',') {
+        return serde_json::json!({
+            "creatorType": "author",
+            "firstName": first.trim(),
+            "lastName": last.trim(),
+        });
+    }
+    match name.rsplit_once(' ') {
+        Some((first, last)) => serde_json::json!({
+            "creatorType": "author",
+            "firstName": first.trim(),
+            "lastName": last,
+        }),
+        None => serde_json::json!({"creatorType": "author", "name": name}),
+    }
+}
+
+/// Field holding the venue name for a Zotero item type, if it has one.
+pub(crate) fn zotero_container_field(item_type: &str) -> Option<&'static str> {
+    match item_type {
+        "journalArticle" => Some("publicationTitle"),
+        "conferencePaper" => Some("proceedingsTitle"),
+        "bookSection" => Some("bookTitle"),
+        "preprint" => Some("repository"),
+        _ => None,
+    }
+}
+
+/// Whether a Zotero item type has its own `DOI` field (others keep it in `extra`).
+pub(crate) fn zotero_has_doi_field(item_type: &str) -> bool {
+    matches!(item_type, "journalArticle" | "conferencePaper" | "preprint" | "dataset")
+}
+
+/// Build a Zotero item JSON object (creators, DOI, abstract, venue, biblio) from
+/// an OpenAlex work.
+///
+/// Only fields valid for the mapped item type are set. Types without a `DOI`
+/// field get a `DOI: …` line in `extra`, which Zotero recognises.
+pub fn work_to_zotero_item(work: &Work) -> serde_json::Value {
+    let oa_type = work.type_crossref.as_deref().or(work.r#type.as_deref());
+    let item_type = crate::selection::openalex_type_to_zotero(oa_type.unwrap_or_default());
+    let mut item = serde_json::json!({ "itemType": item_type });
+    let obj = item.as_object_mut().expect("json object");
+
+    if let Some(title) = work.display_name.as_deref().or(work.title.as_deref()) {
+        obj.insert("title".into(), title.into());
+    }
+    let creators: Vec<serde_json::Value> = work
+        .authorships
+        .as_deref()
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|a| {
+            a.author
+                .as_ref()
+                .and_then(|au| au.display_name.as_deref())
+                .or(a.raw_author_name.as_deref())
+        })
+        .map(zotero_creator)
+        .collect();
+    obj.insert("creators".into(), creators.into());
+    if let Some(abs) = &work.abstract_text {
+        obj.insert("abstractNote".into(), abs.as_str().into());
+    }
+    if let Some(date) = work
+        .publication_date
+        .clone()
+        .or_else(|| work.publication_year.map(|y| y.to_string()))
+    {
+        obj.insert("date".into(), date.into());
+    }
+    if let Some(lang) = &work.language {
+        obj.insert("language".into(), lang.as_str().into());
+    }
+    let location = work.primary_location.as_ref();
+    if let Some(url) = location.and_then(|l| l.landing_page_url.as_deref()) {
+        obj.insert("url".into(), url.into());
+    }
+
+    if let Some(field) = zotero_container_field(item_type)
+        && let Some(name) = location
+            .and_then(|l| l.source.as_ref())
+            .and_then(|s| s.display_name.as_deref())
+    {
+        obj.insert(field.into(), name.into());
+    }
+
+    if let Some(b) = &work.biblio {
+        if matches!(item_type, "journalArticle" | "conferencePaper" | "bookSection" | "book")
+            && let Some(v) = &b.volume
+        {
+            obj.insert("volume".into(), v.as_str().into());
+        }
+        if item_type == "journalArticle"
+            && let Some(i) = &b.issue
+        {
+            obj.insert("issue".into(), i.as_str().into());
+        }
+        if matches!(item_type, "journalArticle" | "conferencePaper" | "bookSection") {
+            let pages = match (&b.first_page, &b.last_page) {
+                (Some(f), Some(l)) if f != l => Some(format!("{f}-{l}")),
+                (Some(f), _) => Some(f.clone()),
+                _ => None,
+            };
+            if let Some(p) = pages {
+                obj.insert("pages".into(), p.into());
+            }
+        }
+    }
+
+    let short_id = work.id.strip_prefix("https://openalex.org/").unwrap_or(&work.id);
+    let mut extra = vec![format!("OpenAlex: {short_id}")];
+    if let Some(doi) = &work.doi {
+        let doi = doi.strip_prefix("https://doi.org/").unwrap_or(doi);
+        if zotero_has_doi_field(item_type) {
+            obj.insert("DOI".into(), doi.into());
+        } else {
+            extra.push(format!("DOI: {doi}"));
+        }
+    }
+    obj.insert("extra".into(), extra.join("\n").into());
+    item
+}
+
+async fn resolve_collection_keys(
+    zotero: &ZoteroClient,
+    inputs: &[String],
+) -> Result<Vec<String>, ZoteroError> {
+    let mut keys = Vec::with_capacity(inputs.len());
+    for input in inputs {
+        keys.push(resolve_collection_key(zotero, input).await?);
+    }
+    Ok(keys)
+}
+
+/// Fetch an OpenAlex work and add it to the Zotero library.
+///
+/// Fails with [`ZoteroWriteError::AlreadyExists`] if the work is already in the
+/// library (same DOI, or a near-identical title and year when a DOI is missing), unless `allow_duplicate` is set. `collections`
+/// accepts keys or names. Writes always go to the web API.
+pub async fn create_item_from_work(
+    openalex: &OpenAlexClient,
+    zotero: &ZoteroClient,
+    id: &str,
+    collections: &[String],
+    tags: &[String],
+    allow_duplicate: bool,
+) -> Result<Item, ZoteroWriteError> {
+    let work = crate::api::work_get(openalex, id, &papers_openalex::GetParams::default()).await?;
+    if !allow_duplicate
+        && let Some(existing) = crate::text::find_work_in_zotero(zotero, &work).await?
+    {
+        return Err(ZoteroWriteError::AlreadyExists { key: existing.key });
+    }
+
+    let mut item = work_to_zotero_item(&work);
+    let collection_keys = resolve_collection_keys(zotero, collections).await?;
+    if !collection_keys.is_empty() {
+        item["collections"] = collection_keys.into();
+    }
+    if !tags.is_empty() {
+        item["tags"] = tags
+            .iter()
+            .map(|t| serde_json::json!({ "tag": t }))
+            .collect::<Vec<_>>()
+            .into();
+    }
+    Ok(zotero.for_writes().create_item(item).await?)
+}
+
+/// Apply `update` to an existing item and return the saved item.
+///
+/// If `version` is given it must match the item's current version, otherwise
+/// [`ZoteroWriteError::VersionConflict`] is returned without writing. If it is
+/// omitted the current version is fetched first. A concurrent edit between the
+/// fetch and the write is reported the same way (HTTP 412).
+pub async fn update_item_fields(
+    zotero: &ZoteroClient,
+    key: &str,
+    update: &ZoteroItemUpdate,
+    version: Option<u64>,
+) -> Result<Item, ZoteroWriteError> {
+    if !looks_like_zotero_key(key) {
+        return Err(ZoteroWriteError::Invalid(format!(
+            "expected an 8-character Zotero item key, got {key:?}"
+        )));
+    }
+    if update.is_empty() {
+        return Err(ZoteroWriteError::Invalid("no fields to update".into()));
+    }
+    let writer = zotero.for_writes();
+    let current = writer.get_item(key).await?;
+    if let Some(v) = version
+        && v != current.version
+    {
+        return Err(ZoteroWriteError::VersionConflict { key: key.to_string(), version: v });
+    }
+
+    let mut patch = serde_json::Map::new();
+    let fields = [
+        ("title", &update.title),
+        ("abstractNote", &update.abstract_note),
+        ("DOI", &update.doi),
+        ("date", &update.date),
+        ("url", &update.url),
+        ("extra", &update.extra),
+    ];
+    for (name, value) in fields {
+        if let Some(v) = value {
+            patch.insert(name.into(), v.as_str().into());
+        }
+    }
+    if !update.add_tags.is_empty() || !update.remove_tags.is_empty() {
+        let mut tags: Vec<String> = current
+            .data
+            .tags
+            .iter()
+            .map(|t| t.tag.clone())
+            .filter(|t| !update.remove_tags.contains(t))
+            .collect();
+        for t in &update.add_tags {
+            if !tags.contains(t) {
+                tags.push(t.clone());
+            }
+        }
+        let tags: Vec<serde_json::Value> =
+            tags.into_iter().map(|t| serde_json::json!({ "tag": t })).collect();
+        patch.insert("tags".into(), tags.into());
+    }
+    if !update.add_collections.is_empty() {
+        let mut collections = current.data.collections.clone();
+        for k in resolve_collection_keys(zotero, &update.add_collections).await? {
+            if !collections.contains(&k) {
+                collections.push(k);
+            }
+        }
+        patch.insert("collections".into(), collections.into());
+    }
+
+    match writer.patch_item(key, current.version, patch.into()).await {
+        Err(ZoteroError::Api { status: 412, .. }) => {
+            return Err(ZoteroWriteError::VersionConflict {
+                key: key.to_string(),
+                version: current.version,
+            });
+        }
+        r => r?,
+    }
+    Ok(writer.get_item(key).await?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -103,4 +705,56 @@ mod tests {
         assert!(!looks_like_zotero_key("Test Paper")); // space
         assert!(!looks_like_zotero_key("GPU Papers")); // collection name
     }
+
+    fn work(value: serde_json::Value) -> Work {
+        let mut base = serde_json::json!({
+            "id": "https://openalex.org/W1",
+            "doi": "https://doi.org/10.1234/abc",
+            "display_name": "A Paper",
+            "publication_date": "2020-05-01",
+            "type": "article",
+            "type_crossref": "journal-article",
+            "abstract_inverted_index": {"Hello": [0], "world": [1]},
+            "authorships": [
+                {"author": {"id": "https://openalex.org/A1", "display_name": "Ada M. Lovelace", "orcid": null}},
+                {"author": {"id": "https://openalex.org/A2", "display_name": "Plato", "orcid": null}}
+            ],
+            "primary_location": {"is_oa": false, "landing_page_url": "https://example.com/a", "pdf_url": null, "source": {"id": "https://openalex.org/S1", "display_name": "Nature"}, "license": null, "version": null},
+            "biblio": {"volume": "7", "issue": "2", "first_page": "10", "last_page": "20"}
+        });
+        for (k, v) in value.as_object().unwrap() {
+            base[k] = v.clone();
+        }
+        serde_json::from_value(base).unwrap()
+    }
+
+    #[test]
+    fn test_work_to_zotero_item_journal_article() {
+        let item = work_to_zotero_item(&work(serde_json::json!({})));
+        assert_eq!(item["itemType"], "journalArticle");
+        assert_eq!(item["title"], "A Paper");
+        assert_eq!(item["DOI"], "10.1234/abc");
+        assert_eq!(item["abstractNote"], "Hello world");
+        assert_eq!(item["date"], "2020-05-01");
+        assert_eq!(item["publicationTitle"], "Nature");
+        assert_eq!(item["pages"], "10-20");
+        assert_eq!(item["issue"], "2");
+        assert_eq!(item["extra"], "OpenAlex: W1");
+        assert_eq!(item["creators"][0]["firstName"], "Ada M.");
+        assert_eq!(item["creators"][0]["lastName"], "Lovelace");
+        assert_eq!(item["creators"][1]["name"], "Plato");
+    }
+
+    #[test]
+    fn test_work_to_zotero_item_book_chapter_puts_doi_in_extra() {
+        let item = work_to_zotero_item(&work(serde_json::json!({
+            "type": "book-chapter",
+            "type_crossref": "book-chapter"
+        })));
+        assert_eq!(item["itemType"], "bookSection");
+        assert_eq!(item["bookTitle"], "Nature");
+        assert!(item.get("DOI").is_none());
+        assert!(item.get("issue").is_none());
+        assert_eq!(item["extra"], "OpenAlex: W1\nDOI: 10.1234/abc");
+    }
 }
@@ -0,0 +1,374 @@
+//! Local full-text index over a Zotero library's PDF attachments.
+//!
+//! Zotero's `q` search only matches PDF text with `qmode=everything`, and the
+//! web API applies it inconsistently. This keeps a copy of every indexed PDF's
+//! text (`GET /items/<key>/fulltext`) in
+//! `{PAPERS_DATA_DIR or platform data dir}/zotero_fulltext/<library>.json`
+//! (inside a [`crate::tenant::scope`], the tenant's `zotero_fulltext/`) and
+//! searches it locally.
+//!
+//! [`FulltextIndex::sync`] is incremental: `GET /fulltext?since=` lists only
+//! attachments whose text changed after the checkpointed library version, and
+//! `/deleted` drops attachments removed from the library. The first sync
+//! fetches every PDF's text, one request each.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+
+use papers_zotero::{DeletedParams, FulltextParams, Item, ItemListParams, ZoteroClient, ZoteroError};
+use serde::{Deserialize, Serialize};
+
+use crate::sync::is_pdf_attachment;
+
+#[derive(Debug, thiserror::Error)]
+pub enum FulltextIndexError {
+    #[error("Zotero error: {0}")]
+    Zotero(#[from] ZoteroError),
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("invalid full-text index: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("empty search query")]
+    EmptyQuery,
+}
+
+/// Item keys per `itemKey=` lookup (the API's maximum).
+const KEY_BATCH: usize = 50;
+/// Characters of context on each side of a match in a snippet.
+const SNIPPET_CONTEXT: usize = 80;
+/// Snippets returned per hit.
+const MAX_SNIPPETS: usize = 3;
+
+/// One PDF attachment's indexed text.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexedAttachment {
+    /// Full-text version reported by `/fulltext`.
+    pub version: u64,
+    /// Key of the regular item the PDF is attached to; `None` for standalone PDFs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_key: Option<String>,
+    /// Title of the parent item, or of the attachment when standalone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    pub content: String,
+}
+
+/// The persisted index for one library.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FulltextIndex {
+    /// Library path prefix, e.g. `/users/12345` or `/groups/678`.
+    pub library: String,
+    /// Library version of the last completed sync; 0 before the first one.
+    pub library_version: u64,
+    /// Attachment key → indexed text.
+    #[serde(default)]
+    pub attachments: BTreeMap<String, IndexedAttachment>,
+}
+
+/// What a [`FulltextIndex::sync`] changed.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct FulltextSyncReport {
+    /// Attachments whose text was fetched.
+    pub updated: usize,
+    /// Attachments dropped because they were deleted or are no longer PDFs.
+    pub removed: usize,
+    /// Attachments in the index after the sync.
+    pub indexed: usize,
+    /// Library version checkpointed by the sync.
+    pub library_version: u64,
+}
+
+/// An attachment whose text matched every query term.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FulltextHit {
+    /// The item to cite or open: the parent item, or the attachment itself when standalone.
+    pub item_key: String,
+    pub attachment_key: String,
+    pub title: Option<String>,
+    /// Total occurrences of the query terms.
+    pub matches: usize,
+    /// Text around the first few matches.
+    pub snippets: Vec<String>,
+}
+
+/// Response of [`search_library`].
+#[derive(Debug, Clone, Serialize)]
+pub struct FulltextSearchResult {
+    pub query: String,
+    /// Present when the index was synced before searching.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync: Option<FulltextSyncReport>,
+    /// Attachments searched.
+    pub indexed: usize,
+    pub results: Vec<FulltextHit>,
+}
+
+/// `{PAPERS_DATA_DIR or platform data dir}/zotero_fulltext`, or the tenant's `zotero_fulltext/`.
+pub fn index_dir() -> PathBuf {
+    crate::tenant::data_dir().join("zotero_fulltext")
+}
+
+/// Items for `keys`, fetched [`KEY_BATCH`] at a time.
+async fn items_by_key(client: &ZoteroClient, keys: &[String]) -> Result<HashMap<String, Item>, ZoteroError> {
+    let mut out = HashMap::new();
+    for batch in keys.chunks(KEY_BATCH) {
+        let page = client
+            .list_items(&ItemListParams {
+                item_key: Some(batch.join(",")),
+                limit: Some(KEY_BATCH as u32),
+                ..Default::default()
+            })
+            .await?;
+        out.extend(page.items.into_iter().map(|item| (item.key.clone(), item)));
+    }
+    Ok(out)
+}
+
+impl FulltextIndex {
+    /// Where the index of `library` (a [`ZoteroClient::library_prefix`]) is kept.
+    pub fn path(library: &str) -> PathBuf {
+        let name: String = library
+            .trim_matches('/')
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        index_dir().join(format!("{name}.json"))
+    }
+
+    /// The saved index of `library`; empty if it was never synced.
+    pub fn load(library: &str) -> Result<Self, FulltextIndexError> {
+        let path = Self::path(library);
+        if !path.exists() {
+            return Ok(Self { library: library.to_string(), ..Default::default() });
+        }
+        Ok(serde_json::from_slice(&std::fs::read(path)?)?)
+    }
+
+    pub fn save(&self) -> Result<(), FulltextIndexError> {
+        let path = Self::path(&self.library);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_vec(self)?)?;
+        Ok(())
+    }
+
+    /// Bring the index up to date with `client`'s library. Does not save; on
+    /// error the checkpoint is left unchanged so the next sync retries.
+    pub async fn sync(&mut self, client: &ZoteroClient) -> Result<FulltextSyncReport, FulltextIndexError> {
+        let since = self.library_version;
+        let params = if since == 0 { FulltextParams::default() } else { FulltextParams::builder().since(since).build() };
+        let versions = client.list_fulltext_versions(&params).await?;
+        let mut library_version = since.max(versions.last_modified_version.unwrap_or(0));
+
+        let mut removed = 0;
+        if since > 0 {
+            let deleted = client.get_deleted(&DeletedParams { since }).await?;
+            library_version = library_version.max(deleted.last_modified_version.unwrap_or(0));
+            for key in &deleted.data.items {
+                removed += usize::from(self.attachments.remove(key).is_some());
+            }
+        }
+
+        let mut changed: Vec<String> = versions
+            .data
+            .iter()
+            .filter(|(key, version)| self.attachments.get(*key).is_none_or(|a| a.version < **version))
+            .map(|(key, _)| key.clone())
+            .collect();
+        changed.sort();
+
+        let attachments = items_by_key(client, &changed).await?;
+        let mut parent_keys: Vec<String> = attachments
+            .values()
+            .filter(|item| is_pdf_attachment(item))
+            .filter_map(|item| item.data.parent_item.clone())
+            .collect();
+        parent_keys.sort();
+        parent_keys.dedup();
+        let parents = items_by_key(client, &parent_keys).await?;
+
+        let mut updated = 0;
+        for key in changed {
+            let Some(item) = attachments.get(&key).filter(|item| is_pdf_attachment(item)) else {
+                removed += usize::from(self.attachments.remove(&key).is_some());
+                continue;
+            };
+            let content = match client.get_item_fulltext(&key).await {
+                Ok(resp) => resp.data.content,
+                Err(ZoteroError::Api { status: 404, .. }) => continue,
+                Err(e) => return Err(e.into()),
+            };
+            let parent_key = item.data.parent_item.clone();
+            let title = match &parent_key {
+                Some(parent) => parents.get(parent).and_then(|p| p.data.title.clone()),
+                None => item.data.title.clone(),
+            };
+            self.attachments.insert(
+                key.clone(),
+                IndexedAttachment { version: versions.data[&key], parent_key, title, content },
+            );
+            updated += 1;
+        }
+
+        self.library_version = library_version;
+        Ok(FulltextSyncReport { updated, removed, indexed: self.attachments.len(), library_version })
+    }
+
+    /// Attachments containing every whitespace-separated term of `query`
+    /// (ASCII case-insensitive), most matches first.
+    pub fn search(&self, query: &str, limit: usize) -> Vec<FulltextHit> {
+        let terms: Vec<String> = query.split_whitespace().map(str::to_ascii_lowercase).collect();
+        if terms.is_empty() {
+            return Vec::new();
+        }
+        let mut hits: Vec<FulltextHit> = self
+            .attachments
+            .iter()
+            .filter_map(|(key, att)| {
+                // ASCII lowercasing keeps byte offsets valid in `content`.
+                let haystack = att.content.to_ascii_lowercase();
+                let mut positions = Vec::new();
+                for term in &terms {
+                    let found: Vec<usize> = haystack.match_indices(term.as_str()).map(|(i, _)| i).collect();
+                    if found.is_empty() {
+                        return None;
+                    }
+                    positions.extend(found.into_iter().map(|i| (i, term.len())));
+                }
+                let matches = positions.len();
+                positions.sort();
+                let mut snippets = Vec::new();
+                let mut covered = 0;
+                for (start, len) in positions {
+                    if snippets.len() == MAX_SNIPPETS {
+                        break;
+                    }
+                    if start < covered {
+                        continue;
+                    }
+                    let (snippet, end) = snippet(&att.content, start, start + len);
+                    snippets.push(snippet);
+                    covered = end;
+                }
+                Some(FulltextHit {
+                    item_key: att.parent_key.clone().unwrap_or_else(|| key.clone()),
+                    attachment_key: key.clone(),
+                    title: att.title.clone(),
+                    matches,
+                    snippets,
+                })
+            })
+            .collect();
+        hits.sort_by(|a, b| b.matches.cmp(&a.matches).then_with(|| a.attachment_key.cmp(&b.attachment_key)));
+        hits.truncate(limit);
+        hits
+    }
+}
+
+/// Whitespace-collapsed text around `content[start..end]`, with `…` where it
+/// was cut, and the byte offset where the context ends.
+fn snippet(content: &str, start: usize, end: usize) -> (String, usize) {
+    let mut from = start.saturating_sub(SNIPPET_CONTEXT);
+    while !content.is_char_boundary(from) {
+        from -= 1;
+    }
+    let mut to = (end + SNIPPET_CONTEXT).min(content.len());
+    while !content.is_char_boundary(to) {
+        to += 1;
+    }
+    let body = content[from..to].split_whitespace().collect::<Vec<_>>().join(" ");
+    let lead = if from > 0 { "…" } else { "" };
+    let tail = if to < content.len() { "…" } else { "" };
+    (format!("{lead}{body}{tail}"), to)
+}
+
+/// Search the local full-text index of `client`'s library, syncing it first
+/// when `sync` is set or the library was never indexed.
+pub async fn search_library(
+    client: &ZoteroClient,
+    query: &str,
+    limit: usize,
+    sync: bool,
+) -> Result<FulltextSearchResult, FulltextIndexError> {
+    if query.trim().is_empty() {
+        return Err(FulltextIndexError::EmptyQuery);
+    }
+    let mut index = FulltextIndex::load(&client.library_prefix())?;
+    let report = if sync || index.library_version == 0 {
+        let report = index.sync(client).await?;
+        index.save()?;
+        Some(report)
+    } else {
+        None
+    };
+    Ok(FulltextSearchResult {
+        query: query.to_string(),
+        sync: report,
+        indexed: index.attachments.len(),
+        results: index.search(query, limit),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn index(docs: &[(&str, Option<&str>, &str)]) -> FulltextIndex {
+        FulltextIndex {
+            library: "/users/1".into(),
+            library_version: 10,
+            attachments: docs
+                .iter()
+                .map(|(key, parent, content)| {
+                    let att = IndexedAttachment {
+                        version: 1,
+                        parent_key: parent.map(str::to_string),
+                        title: None,
+                        content: content.to_string(),
+                    };
+                    (key.to_string(), att)
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_search_requires_every_term_and_ranks_by_matches() {
+        let idx = index(&[
+            ("ATT00001", Some("PARENT01"), "Gaussian splatting renders radiance fields. Splatting is fast."),
+            ("ATT00002", None, "Neural radiance fields."),
+            ("ATT00003", Some("PARENT03"), "Splatting of radiance fields, splatting again, SPLATTING."),
+        ]);
+        let hits = idx.search("splatting Radiance", 10);
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].item_key, "PARENT03");
+        assert_eq!(hits[0].matches, 4);
+        assert_eq!(hits[1].item_key, "PARENT01");
+
+        let standalone = idx.search("neural", 10);
+        assert_eq!(standalone[0].item_key, "ATT00002");
+        assert_eq!(standalone[0].attachment_key, "ATT00002");
+        assert!(idx.search("   ", 10).is_empty());
+        assert_eq!(idx.search("fields", 1).len(), 1);
+    }
+
+    #[test]
+    fn test_snippets_are_trimmed_and_do_not_overlap() {
+        let filler = "lorem ipsum ".repeat(20);
+        let text = format!("{filler}the   target\nword here{filler}target again{filler}");
+        let hits = index(&[("ATT00001", None, &text)]).search("target", 10);
+        let snippets = &hits[0].snippets;
+        assert_eq!(snippets.len(), 2);
+        assert!(snippets[0].starts_with('…') && snippets[0].ends_with('…'));
+        assert!(snippets[0].contains("the target word here"));
+        assert!(snippets[1].contains("target again"));
+    }
+
+    #[test]
+    fn test_snippet_respects_char_boundaries() {
+        let text = format!("{}match{}", "é".repeat(100), "ü".repeat(100));
+        let (s, _) = snippet(&text, 200, 205);
+        assert!(s.contains("match"));
+    }
+}
@@ -0,0 +1,209 @@
+//! Named Zotero credentials, so one server can act for several libraries.
+//!
+//! Profiles live in `<config_dir>/.papers/zotero_profiles.json`, a map from
+//! profile name to `{"user_id", "api_key"}`. They are kept out of
+//! `config.json` so API keys never end up in a shared config, and the file is
+//! written owner-readable only on Unix. Unlike `config.json`, the file is the
+//! same for every tenant: the operator manages it, and a tenant may only use
+//! the profile named after it.
+//!
+//! Credentials for a call are picked in this order:
+//!
+//! 1. an explicit user ID and API key,
+//! 2. a named profile,
+//! 3. the profile named by `PAPERS_ZOTERO_PROFILE`,
+//! 4. `ZOTERO_USER_ID` and `ZOTERO_API_KEY`, preferring the local Zotero API.
+//!
+//! Profile credentials always use the web API: the local API serves whatever
+//! library the desktop app on this machine has open, whichever user asks.
+
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use papers_zotero::{ZoteroClient, ZoteroError};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ProfileError {
+    #[error("IO error: {0}")]
+    Io(#[from] io::Error),
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("invalid Zotero profile name {0:?}: use letters, digits, '-' or '_'")]
+    Name(String),
+    #[error("unknown Zotero profile {0:?}; add it with `papers config zotero-profile set`")]
+    Unknown(String),
+    #[error("zotero_user_id and zotero_api_key must be given together")]
+    Incomplete,
+}
+
+/// A Zotero user ID and API key.
+#[derive(Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct ZoteroCredentials {
+    pub user_id: String,
+    pub api_key: String,
+}
+
+impl std::fmt::Debug for ZoteroCredentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ZoteroCredentials").field("user_id", &self.user_id).field("api_key", &"***").finish()
+    }
+}
+
+impl ZoteroCredentials {
+    /// A web API client for these credentials.
+    pub fn client(&self) -> ZoteroClient {
+        ZoteroClient::new(self.user_id.clone(), self.api_key.clone())
+    }
+}
+
+/// The profiles file, see the module docs.
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub struct ProfileStore {
+    profiles: BTreeMap<String, ZoteroCredentials>,
+}
+
+impl ProfileStore {
+    /// Returns `<config_dir>/.papers/zotero_profiles.json`.
+    pub fn default_path() -> PathBuf {
+        crate::config::PapersConfig::shared_config_path().with_file_name("zotero_profiles.json")
+    }
+
+    /// Loads from [`Self::default_path`]. Returns an empty store if the file is missing.
+    pub fn load() -> Result<Self, ProfileError> {
+        Self::load_from(&Self::default_path())
+    }
+
+    pub fn load_from(path: &Path) -> Result<Self, ProfileError> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        Ok(serde_json::from_slice(&std::fs::read(path)?)?)
+    }
+
+    /// Writes to [`Self::default_path`], creating parent directories as needed.
+    pub fn save(&self) -> Result<(), ProfileError> {
+        self.save_to(&Self::default_path())
+    }
+
+    pub fn save_to(&self, path: &Path) -> Result<(), ProfileError> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        let mut file = options.open(path)?;
+        io::Write::write_all(&mut file, &serde_json::to_vec_pretty(self)?)?;
+        Ok(())
+    }
+
+    pub fn get(&self, name: &str) -> Option<&ZoteroCredentials> {
+        self.profiles.get(name)
+    }
+
+    /// Add or replace profile `name`.
+    pub fn set(&mut self, name: &str, credentials: ZoteroCredentials) -> Result<(), ProfileError> {
+        if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+            return Err(ProfileError::Name(name.to_string()));
+        }
+        self.profiles.insert(name.to_string(), credentials);
+        Ok(())
+    }
+
+    /// Remove profile `name`; returns whether it existed.
+    pub fn remove(&mut self, name: &str) -> bool {
+        self.profiles.remove(name).is_some()
+    }
+
+    /// Profile names with their user IDs, sorted by name.
+    pub fn list(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.profiles.iter().map(|(name, c)| (name.as_str(), c.user_id.as_str()))
+    }
+}
+
+/// The profile named by `PAPERS_ZOTERO_PROFILE`, if set.
+pub fn default_profile() -> Option<String> {
+    std::env::var("PAPERS_ZOTERO_PROFILE").ok().filter(|p| !p.trim().is_empty())
+}
+
+/// Credentials for an explicit `user_id`/`api_key` pair, else for `profile`
+/// (looked up in `store`). `Ok(None)` when neither is given.
+pub fn resolve(
+    store: &ProfileStore,
+    profile: Option<&str>,
+    user_id: Option<&str>,
+    api_key: Option<&str>,
+) -> Result<Option<ZoteroCredentials>, ProfileError> {
+    match (user_id, api_key) {
+        (Some(user_id), Some(api_key)) => {
+            return Ok(Some(ZoteroCredentials { user_id: user_id.to_string(), api_key: api_key.to_string() }));
+        }
+        (None, None) => {}
+        _ => return Err(ProfileError::Incomplete),
+    }
+    match profile {
+        Some(name) => store.get(name).cloned().map(Some).ok_or_else(|| ProfileError::Unknown(name.to_string())),
+        None => Ok(None),
+    }
+}
+
+/// Connect with `profile`, else [`default_profile`], else the environment
+/// (see the module docs).
+pub async fn connect(profile: Option<&str>) -> Result<ZoteroClient, ZoteroError> {
+    let Some(name) = profile.map(str::to_string).or_else(default_profile) else {
+        return ZoteroClient::from_env_prefer_local().await;
+    };
+    let as_zotero_error = |e: ProfileError| ZoteroError::Api { status: 0, message: e.to_string() };
+    let store = ProfileStore::load().map_err(as_zotero_error)?;
+    store.get(&name).map(ZoteroCredentials::client).ok_or_else(|| as_zotero_error(ProfileError::Unknown(name)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn creds(user_id: &str) -> ZoteroCredentials {
+        ZoteroCredentials { user_id: user_id.to_string(), api_key: format!("key-{user_id}") }
+    }
+
+    #[test]
+    fn test_profile_store_roundtrip() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("zotero_profiles.json");
+        let mut store = ProfileStore::default();
+        store.set("alice", creds("111")).unwrap();
+        store.set("bob", creds("222")).unwrap();
+        assert!(matches!(store.set("bad name", creds("3")), Err(ProfileError::Name(_))));
+        store.save_to(&path).unwrap();
+
+        let mut loaded = ProfileStore::load_from(&path).unwrap();
+        assert_eq!(loaded.get("alice"), Some(&creds("111")));
+        assert!(loaded.remove("bob"));
+        assert_eq!(loaded.list().collect::<Vec<_>>(), [("alice", "111")]);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            assert_eq!(std::fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+        }
+    }
+
+    #[test]
+    fn test_resolve_prefers_explicit_credentials() {
+        let mut store = ProfileStore::default();
+        store.set("alice", creds("111")).unwrap();
+        assert_eq!(resolve(&store, Some("alice"), Some("9"), Some("k")).unwrap().unwrap().user_id, "9");
+        assert_eq!(resolve(&store, Some("alice"), None, None).unwrap(), Some(creds("111")));
+        assert_eq!(resolve(&store, None, None, None).unwrap(), None);
+        assert!(matches!(resolve(&store, None, Some("9"), None), Err(ProfileError::Incomplete)));
+        assert!(matches!(resolve(&store, Some("carol"), None, None), Err(ProfileError::Unknown(_))));
+    }
+
+    #[test]
+    fn test_credentials_debug_hides_api_key() {
+        assert!(!format!("{:?}", creds("111")).contains("key-111"));
+    }
+}
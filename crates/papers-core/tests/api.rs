@@ -284,6 +284,102 @@ async fn test_work_get_returns_full() {
     assert!(json.contains("counts_by_year"));
 }
 
+#[tokio::test]
+async fn test_work_get_response_falls_back_to_crossref() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/works/doi:10.1101/2024.05.01.592000"))
+        .respond_with(ResponseTemplate::new(404).set_body_string("Not found"))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/crossref/works/10.1101/2024.05.01.592000"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "status": "ok",
+            "message": {
+                "DOI": "10.1101/2024.05.01.592000",
+                "title": ["A Very Recent Preprint"],
+                "type": "posted-content",
+                "issued": {"date-parts": [[2024, 5, 3]]},
+            }
+        })))
+        .mount(&mock)
+        .await;
+
+    let client = make_client(&mock);
+    let crossref = papers_core::crossref::CrossrefClient::new().with_base_url(format!("{}/crossref", mock.uri()));
+    let doi = "10.1101/2024.05.01.592000";
+    let response = api::work_get_response(&client, None, Some(&crossref), doi, &GetParams::default())
+        .await
+        .unwrap();
+    assert!(response.from_crossref);
+    assert_eq!(response.work.display_name.as_deref(), Some("A Very Recent Preprint"));
+    assert_eq!(response.work.r#type.as_deref(), Some("preprint"));
+    assert_eq!(serde_json::to_value(&response).unwrap()["from_crossref"], true);
+
+    let err = api::work_get_response(&client, None, None, doi, &GetParams::default()).await.unwrap_err();
+    assert!(err.to_string().contains("404"));
+}
+
+#[tokio::test]
+async fn test_work_enrich_adds_semantic_scholar_fields() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/works/W1"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(work_json()))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/s2/graph/v1/paper/DOI:10.1234/test"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "paperId": "abc123",
+            "citationCount": 50,
+            "influentialCitationCount": 7,
+            "tldr": {"model": "tldr@v2.0.0", "text": "A great paper about things."}
+        })))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/s2/recommendations/v1/papers/forpaper/abc123"))
+        .and(query_param("limit", "3"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "recommendedPapers": [{"paperId": "def456", "title": "A Related Paper", "externalIds": {"DOI": "10.1234/related"}}]
+        })))
+        .mount(&mock)
+        .await;
+
+    let client = make_client(&mock);
+    let s2 = papers_core::semantic_scholar::SemanticScholarClient::new().with_base_url(format!("{}/s2", mock.uri()));
+    let response = api::work_enrich(&client, None, None, &s2, "W1", 3).await.unwrap();
+    let enrichment = response.semantic_scholar.unwrap();
+    assert_eq!(enrichment.tldr.as_deref(), Some("A great paper about things."));
+    assert_eq!(enrichment.influential_citation_count, Some(7));
+    assert_eq!(enrichment.recommendations[0].doi.as_deref(), Some("10.1234/related"));
+    assert!(response.semantic_scholar_error.is_none());
+}
+
+#[tokio::test]
+async fn test_work_enrich_keeps_openalex_data_when_semantic_scholar_fails() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/works/W1"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(work_json()))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/s2/graph/v1/paper/DOI:10.1234/test"))
+        .respond_with(ResponseTemplate::new(429).set_body_string("Too Many Requests"))
+        .mount(&mock)
+        .await;
+
+    let client = make_client(&mock);
+    let s2 = papers_core::semantic_scholar::SemanticScholarClient::new().with_base_url(format!("{}/s2", mock.uri()));
+    let response = api::work_enrich(&client, None, None, &s2, "W1", 3).await.unwrap();
+    assert_eq!(response.work.display_name.as_deref(), Some("A Great Paper"));
+    assert!(response.semantic_scholar.is_none());
+    assert!(response.semantic_scholar_error.unwrap().contains("429"));
+}
+
 #[tokio::test]
 async fn test_author_list_applies_summary() {
     let mock = MockServer::start().await;
@@ -959,67 +1055,1207 @@ async fn test_work_get_single_candidate_auto_selected() {
     assert!(result.is_ok());
 }
 
-// ── work_text tests ──────────────────────────────────────────────────────
+// ── Metadata quality ─────────────────────────────────────────────────────
 
 #[tokio::test]
-async fn test_work_text_no_pdf_found() {
+async fn test_work_quality_fetches_full_record() {
     let mock = MockServer::start().await;
-    // Work has no pdf_url in any location and no has_content.pdf
     Mock::given(method("GET"))
         .and(path("/works/W1"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(work_json()))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let client = make_client(&mock);
+    let report = api::work_quality(&client, "W1").await.unwrap();
+    assert_eq!(report.id, "https://openalex.org/W1");
+    // work_json() has an abstract, OA, a resolved author and one reference
+    assert!(report.ok);
+    assert!(report.issues.is_empty());
+}
+
+// ── Published version ────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_work_published_version_links_preprint_to_journal() {
+    let mock = MockServer::start().await;
+    let authors = r#"[{"author": {"display_name": "Alice Smith"}}, {"author": {"display_name": "Bob Jones"}}]"#;
+    let preprint = format!(
+        r#"{{"id": "https://openalex.org/W1", "doi": "https://doi.org/10.48550/arxiv.2001.00001",
+            "display_name": "Deep Nets for Everything", "publication_year": 2020, "type": "preprint",
+            "authorships": {authors},
+            "locations": [
+                {{"landing_page_url": "https://arxiv.org/abs/2001.00001", "version": "submittedVersion",
+                  "source": {{"display_name": "arXiv", "type": "repository"}}}},
+                {{"landing_page_url": "https://doi.org/10.1038/s1", "version": "publishedVersion",
+                  "source": {{"display_name": "Nature", "type": "journal"}}}}
+            ]}}"#
+    );
+    let published = format!(
+        r#"{{"id": "https://openalex.org/W2", "doi": "https://doi.org/10.1038/s1",
+            "display_name": "Deep nets for everything", "publication_year": 2021, "type": "article",
+            "authorships": {authors},
+            "primary_location": {{"source": {{"display_name": "Nature", "type": "journal"}}}}}}"#
+    );
+    let unrelated = r#"{"id": "https://openalex.org/W3", "display_name": "Shallow nets for nothing",
+        "publication_year": 2019, "type": "article"}"#;
+    Mock::given(method("GET"))
+        .and(path("/works/W1"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(preprint))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/works/doi:10.1038/s1"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(published.clone()))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param(
+            "filter",
+            "title.search:Deep Nets for Everything,type:!preprint",
+        ))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(list_response(&format!("{published}, {unrelated}"))),
+        )
+        .mount(&mock)
+        .await;
+
+    let client = make_client(&mock);
+    let report = api::work_published_version(&client, "W1").await.unwrap();
+    assert_eq!(report.role, papers_core::versions::VersionRole::Preprint);
+    assert_eq!(report.preprint_id.as_deref(), Some("W1"));
+    assert_eq!(report.published_id.as_deref(), Some("W2"));
+    assert_eq!(report.confidence, Some(1.0));
+    // W2 is found by location DOI and title search but listed once; W3 is dropped
+    assert_eq!(report.candidates.len(), 1);
+    let json = serde_json::to_value(&report.candidates[0]).unwrap();
+    assert_eq!(json["id"], "W2");
+    assert_eq!(
+        json["evidence"],
+        serde_json::json!(["location_doi", "title_match", "author_overlap"])
+    );
+    assert_eq!(report.merged_locations.len(), 1);
+}
+
+// ── Work stats ───────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_work_stats_groups_in_one_request() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("filter", "type:article"))
+        .and(query_param("search", "neural rendering"))
+        .and(query_param("group_by", "publication_year"))
         .respond_with(ResponseTemplate::new(200).set_body_string(
-            r#"{
-                "id": "https://openalex.org/W1",
-                "display_name": "A Paper Without PDF",
-                "doi": null,
-                "primary_location": null,
-                "locations": [],
-                "best_oa_location": null,
-                "has_content": null
-            }"#,
+            r#"{"meta": {"count": 9, "db_response_time_ms": 1, "page": 1, "per_page": 25, "next_cursor": null, "groups_count": 2},
+                "results": [],
+                "group_by": [
+                    {"key": "2023", "key_display_name": "2023", "count": 6},
+                    {"key": "2021", "key_display_name": "2021", "count": 3}
+                ]}"#,
         ))
+        .expect(1)
         .mount(&mock)
         .await;
 
     let client = make_client(&mock);
-    let result = text::work_text(&client, None, "W1").await;
-    assert!(result.is_err());
-    let err = result.unwrap_err();
-    match err {
-        text::WorkTextError::NoPdfFound { ref work_id, ref title, .. } => {
-            assert_eq!(work_id, "https://openalex.org/W1");
-            assert_eq!(title.as_deref(), Some("A Paper Without PDF"));
-        }
-        other => panic!("Expected NoPdfFound, got: {other}"),
+    let params = WorkListParams {
+        search: Some("neural rendering".into()),
+        r#type: Some("article".into()),
+        per_page: Some(5),
+        ..Default::default()
+    };
+    let stats = api::work_stats(&client, &params, papers_core::stats::StatsDimension::Year, 10).await.unwrap();
+    assert_eq!(stats.total, 9);
+    assert_eq!(stats.group_by, "publication_year");
+    assert_eq!(stats.filter.as_deref(), Some("type:article"));
+    let series: Vec<_> = stats.series.iter().map(|p| (p.key.as_str(), p.count)).collect();
+    assert_eq!(series, vec![("2021", 3), ("2022", 0), ("2023", 6)]);
+}
+
+// ── APC report ───────────────────────────────────────────────────────────
+
+fn apc_work_json(id: &str, oa_status: &str, list_usd: i64) -> String {
+    format!(
+        r#"{{"id": "https://openalex.org/{id}", "publication_year": 2023,
+            "open_access": {{"is_oa": true, "oa_status": "{oa_status}"}},
+            "apc_list": {{"value": {list_usd}, "currency": "USD", "value_usd": {list_usd}}},
+            "primary_location": {{"source": {{"id": "https://openalex.org/S1", "display_name": "J"}}}}}}"#
+    )
+}
+
+#[tokio::test]
+async fn test_work_apc_report_pages_with_cursor() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("cursor", "*"))
+        .and(query_param("filter", "publication_year:2023"))
+        .and(query_param("select", papers_core::apc::APC_SELECT))
+        .respond_with(ResponseTemplate::new(200).set_body_string(format!(
+            r#"{{"meta": {{"count": 3, "db_response_time_ms": 1, "page": null, "per_page": 2, "next_cursor": "next", "groups_count": null}},
+                "results": [{}, {}], "group_by": []}}"#,
+            apc_work_json("W1", "gold", 2000),
+            apc_work_json("W2", "green", 2000),
+        )))
+        .expect(1)
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("cursor", "next"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(format!(
+            r#"{{"meta": {{"count": 3, "db_response_time_ms": 1, "page": null, "per_page": 2, "next_cursor": null, "groups_count": null}},
+                "results": [{}], "group_by": []}}"#,
+            apc_work_json("W3", "hybrid", 3000),
+        )))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let client = make_client(&mock);
+    let params = WorkListParams { year: Some("2023".into()), ..Default::default() };
+    let report = api::work_apc_report(&client, &params, 1000).await.unwrap();
+    assert_eq!(report.matched, Some(3));
+    assert_eq!(report.works, 3);
+    assert!(!report.truncated);
+    assert_eq!(report.apc_list_usd, 7000);
+    assert_eq!(report.estimated_spend_usd, 5000);
+    assert_eq!(report.charged_works, 2);
+}
+
+#[tokio::test]
+async fn test_work_apc_report_stops_at_max_works() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("per-page", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(format!(
+            r#"{{"meta": {{"count": 4, "db_response_time_ms": 1, "page": null, "per_page": 1, "next_cursor": "next", "groups_count": null}},
+                "results": [{}], "group_by": []}}"#,
+            apc_work_json("W1", "gold", 2000),
+        )))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let client = make_client(&mock);
+    let report = api::work_apc_report(&client, &WorkListParams::default(), 1).await.unwrap();
+    assert!(report.truncated);
+    assert_eq!(report.works, 1);
+    assert_eq!(report.extrapolated_spend_usd, Some(8000));
+}
+
+// ── SDG report ───────────────────────────────────────────────────────────
+
+fn selection_entry(openalex_id: Option<&str>, title: &str) -> papers_core::SelectionEntry {
+    papers_core::SelectionEntry {
+        zotero_key: None,
+        openalex_id: openalex_id.map(str::to_string),
+        doi: None,
+        title: Some(title.to_string()),
+        authors: None,
+        year: None,
+        issn: None,
+        isbn: None,
+        work_type: None,
+        note: None,
+        tags: Vec::new(),
     }
-    assert!(err.to_string().contains("A Paper Without PDF"));
 }
 
 #[tokio::test]
-async fn test_work_text_no_pdf_found_with_non_whitelisted_url() {
+async fn test_selection_sdg_report_aggregates_tags() {
     let mock = MockServer::start().await;
-    // Work has a pdf_url but on a non-whitelisted domain
+    Mock::given(method("GET"))
+        .and(path("/works/W1"))
+        .and(query_param("select", "id,display_name,sustainable_development_goals"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            r#"{"id": "https://openalex.org/W1", "sustainable_development_goals": [
+                {"id": "https://metadata.un.org/sdg/13", "display_name": "Climate action", "score": 0.8}
+            ]}"#,
+        ))
+        .mount(&mock)
+        .await;
     Mock::given(method("GET"))
         .and(path("/works/W2"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&mock)
+        .await;
+
+    let client = make_client(&mock);
+    let selection = papers_core::Selection {
+        name: "climate".to_string(),
+        entries: vec![
+            selection_entry(Some("W1"), "Tagged"),
+            selection_entry(Some("W2"), "Gone"),
+            selection_entry(None, "No ids"),
+        ],
+    };
+    let report = api::selection_sdg_report(&client, &selection).await;
+    assert_eq!(report.total, 3);
+    assert_eq!(report.resolved, 1);
+    assert_eq!(report.tagged, 1);
+    assert_eq!(report.unresolved, vec!["Gone", "No ids"]);
+    assert_eq!(report.goals.len(), 1);
+    assert_eq!(report.goals[0].number, 13);
+    assert_eq!(report.goals[0].work_ids, vec!["https://openalex.org/W1"]);
+}
+
+// ── Citation graph export ────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_selection_citation_graph_links_referenced_works() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/works/W1"))
+        .and(query_param("select", papers_core::graph::GRAPH_SELECT))
         .respond_with(ResponseTemplate::new(200).set_body_string(
-            r#"{
-                "id": "https://openalex.org/W2",
-                "display_name": "Publisher Paper",
-                "doi": null,
-                "primary_location": {"pdf_url": "https://publisher.com/paper.pdf"},
-                "locations": [],
-                "best_oa_location": null,
-                "has_content": null
-            }"#,
+            r#"{"id": "https://openalex.org/W1", "display_name": "Origin", "publication_year": 2010,
+                "referenced_works": []}"#,
+        ))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/works/W2"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            r#"{"id": "https://openalex.org/W2", "display_name": "Follow-up", "publication_year": 2015,
+                "referenced_works": ["https://openalex.org/W1", "https://openalex.org/W7"]}"#,
         ))
         .mount(&mock)
         .await;
 
     let client = make_client(&mock);
-    let result = text::work_text(&client, None, "W2").await;
-    assert!(result.is_err());
-    match result.unwrap_err() {
-        text::WorkTextError::NoPdfFound { .. } => {}
-        other => panic!("Expected NoPdfFound, got: {other}"),
-    }
+    let selection = papers_core::Selection {
+        name: "graph".to_string(),
+        entries: vec![
+            selection_entry(Some("W1"), "Origin"),
+            selection_entry(Some("W2"), "Follow-up"),
+            selection_entry(None, "No ids"),
+        ],
+    };
+    let export =
+        api::selection_citation_graph(&client, &selection, papers_core::graph::GraphFormat::Dot).await;
+    assert_eq!(export.scope, "graph");
+    assert_eq!((export.nodes, export.edges), (2, 1));
+    assert_eq!(export.unresolved, vec!["No ids"]);
+    assert!(export.content.contains("\"W2\" -> \"W1\";"));
+}
+
+// ── Timeline ─────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_selection_timeline_buckets_years_with_fallback() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/works/W1"))
+        .and(query_param("select", papers_core::timeline::TIMELINE_SELECT))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            r#"{"id": "https://openalex.org/W1", "display_name": "Seminal",
+                "publication_year": 2015, "cited_by_count": 900}"#,
+        ))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/works/W2"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&mock)
+        .await;
+
+    let client = make_client(&mock);
+    let mut stored = selection_entry(Some("W2"), "Stored");
+    stored.year = Some(2020);
+    let selection = papers_core::Selection {
+        name: "area".to_string(),
+        entries: vec![
+            selection_entry(Some("W1"), "Seminal"),
+            stored,
+            selection_entry(None, "No ids"),
+        ],
+    };
+    let timeline = api::selection_timeline(&client, &selection, 1).await;
+    assert_eq!(timeline.scope, "area");
+    assert_eq!(timeline.total, 2);
+    let years: Vec<_> = timeline.years.iter().map(|y| y.year).collect();
+    assert_eq!(years, vec![2015, 2020]);
+    assert!(timeline.years[0].papers[0].milestone);
+    assert_eq!(timeline.years[1].papers[0].title.as_deref(), Some("Stored"));
+    assert!(!timeline.years[1].papers[0].milestone);
+    assert_eq!(timeline.unresolved, vec!["No ids"]);
+}
+
+// ── Abstracts ────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_entry_abstracts_dedupes_and_reports_missing() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/works/W1"))
+        .and(query_param("select", papers_core::abstracts::ABSTRACT_SELECT))
+        .respond_with(ResponseTemplate::new(200).set_body_string(work_json()))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/works/W2"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            r#"{"id": "https://openalex.org/W2", "display_name": "No abstract"}"#,
+        ))
+        .mount(&mock)
+        .await;
+
+    let client = make_client(&mock);
+    let entries = vec![
+        selection_entry(Some("W1"), "A Great Paper"),
+        selection_entry(Some("W1"), "Same paper, other selection"),
+        selection_entry(Some("W2"), "No abstract"),
+        selection_entry(None, "No ids"),
+    ];
+    let batch = papers_core::abstracts::entry_abstracts(&client, &entries).await;
+    assert_eq!(batch.abstracts.len(), 1);
+    let abs = &batch.abstracts[0];
+    assert_eq!(abs.paper_id, "10.1234/test");
+    assert_eq!(abs.text, "Hello world");
+    assert_eq!(abs.authors, vec!["Alice"]);
+    assert_eq!(abs.venue.as_deref(), Some("Nature"));
+    assert_eq!(batch.missing, vec!["No abstract", "No ids"]);
+}
+
+// ── Digest ───────────────────────────────────────────────────────────────
+
+fn zotero_item(key: &str, fields: &str, date_added: &str) -> String {
+    format!(
+        r#"{{"key": "{key}", "version": 1,
+            "library": {{"type": "user", "id": 1, "name": "testuser", "links": {{}}}},
+            "data": {{"key": "{key}", "version": 1, {fields}, "dateAdded": "{date_added}"}}}}"#
+    )
+}
+
+#[tokio::test]
+async fn test_digest_generate_collects_watches_citations_and_zotero_additions() {
+    let mock = MockServer::start().await;
+    let since = papers_core::digest::window_start(1);
+    let published = format!("from_publication_date:{since}");
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("search", "gaussian splatting"))
+        .and(query_param("filter", published.as_str()))
+        .respond_with(ResponseTemplate::new(200).set_body_string(list_response(
+            r#"{"id": "https://openalex.org/W10", "display_name": "Splats Everywhere", "publication_date": "2026-10-15"}"#,
+        )))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("filter", format!("cites:W1|W2,{published}").as_str()))
+        .respond_with(ResponseTemplate::new(200).set_body_string(list_response(
+            r#"{"id": "https://openalex.org/W20", "display_name": "Building On It",
+                "referenced_works": ["https://openalex.org/W2", "https://openalex.org/W99"]}"#,
+        )))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/users/testuser/items/top"))
+        .and(query_param("sort", "dateAdded"))
+        .respond_with(ResponseTemplate::new(200).insert_header("Total-Results", "3").set_body_string(format!(
+            "[{},{},{}]",
+            zotero_item("NEW1", r#""itemType": "journalArticle", "title": "Fresh",
+                "creators": [{"creatorType": "author", "lastName": "Lovelace"}]"#, &format!("{since}T08:00:00Z")),
+            zotero_item("NOTE", r#""itemType": "note""#, &format!("{since}T07:00:00Z")),
+            zotero_item("OLD1", r#""itemType": "book", "title": "Old""#, "2001-01-01T00:00:00Z"),
+        )))
+        .mount(&mock)
+        .await;
+
+    let client = make_client(&mock);
+    let zotero = papers_zotero::ZoteroClient::new("testuser", "test-api-key").with_base_url(mock.uri());
+    let selection = papers_core::Selection {
+        name: "mine".to_string(),
+        entries: vec![
+            selection_entry(Some("W1"), "First"),
+            selection_entry(Some("https://openalex.org/W2"), "Second"),
+            selection_entry(None, "Unpublished"),
+        ],
+    };
+    let watches = vec!["gaussian splatting".to_string(), " ".to_string()];
+    let digest = api::digest_generate(&client, Some(&zotero), &[selection], &watches, 0).await;
+
+    assert_eq!((digest.since.as_str(), digest.days), (since.as_str(), 1));
+    assert!(digest.warnings.is_empty(), "{:?}", digest.warnings);
+    assert_eq!(digest.watches.len(), 1);
+    assert_eq!(digest.watches[0].works[0].id, "W10");
+    assert_eq!(digest.cited.len(), 1);
+    assert_eq!((digest.cited[0].id.as_str(), digest.cited[0].citers[0].id.as_str()), ("W2", "W20"));
+    let added: Vec<_> = digest.zotero.iter().map(|z| (z.key.as_str(), z.creator.as_deref())).collect();
+    assert_eq!(added, [("NEW1", Some("Lovelace"))]);
+    assert!(digest.markdown.contains("**Second** — 1 new citation"));
+}
+
+#[tokio::test]
+async fn test_digest_generate_without_zotero_warns() {
+    let mock = MockServer::start().await;
+    let digest = api::digest_generate(&make_client(&mock), None, &[], &[], 1).await;
+    assert!(digest.watches.is_empty() && digest.cited.is_empty() && digest.zotero.is_empty());
+    assert_eq!(digest.warnings.len(), 1);
+    assert!(digest.warnings[0].starts_with("Zotero:"));
+}
+
+// ── Citation graph ───────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_work_citations_depth_one_uses_cites_filter() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("filter", "cites:W1"))
+        .and(query_param("sort", "cited_by_count:desc"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(multi_result_list_response(&[
+            ("https://openalex.org/W2", "Citer A", 50),
+            ("https://openalex.org/W3", "Citer B", 10),
+        ])))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let client = make_client(&mock);
+    let resp = api::work_citations(&client, "W1", &api::CitationGraphParams::default())
+        .await
+        .unwrap();
+    assert_eq!(resp.root, "W1");
+    assert_eq!(resp.count, 2);
+    assert!(!resp.truncated);
+    assert!(resp.works.iter().all(|n| n.depth == 1 && n.via == "W1"));
+}
+
+#[tokio::test]
+async fn test_work_references_depth_two_deduplicates() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("filter", "cited_by:W1"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(multi_result_list_response(&[
+            ("https://openalex.org/W2", "Ref A", 50),
+            ("https://openalex.org/W3", "Ref B", 10),
+        ])))
+        .mount(&mock)
+        .await;
+    // W2 references W3 (already seen) and W1 (the root) plus a new work W4
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("filter", "cited_by:W2"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(multi_result_list_response(&[
+            ("https://openalex.org/W3", "Ref B", 10),
+            ("https://openalex.org/W1", "Root", 5),
+            ("https://openalex.org/W4", "Ref C", 3),
+        ])))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("filter", "cited_by:W3"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(multi_result_list_response(&[
+            ("https://openalex.org/W4", "Ref C", 3),
+        ])))
+        .mount(&mock)
+        .await;
+
+    let client = make_client(&mock);
+    let params = api::CitationGraphParams { depth: 2, ..Default::default() };
+    let resp = api::work_references(&client, "W1", &params).await.unwrap();
+    let ids: Vec<&str> = resp.works.iter().map(|n| n.work.id.as_str()).collect();
+    assert_eq!(
+        ids,
+        vec!["https://openalex.org/W2", "https://openalex.org/W3", "https://openalex.org/W4"]
+    );
+    let w4 = &resp.works[2];
+    assert_eq!((w4.depth, w4.via.as_str()), (2, "W2"));
+}
+
+#[tokio::test]
+async fn test_work_citations_max_works_truncates() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("filter", "cites:W1"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(multi_result_list_response(&[
+            ("https://openalex.org/W2", "Citer A", 50),
+            ("https://openalex.org/W3", "Citer B", 10),
+            ("https://openalex.org/W4", "Citer C", 5),
+        ])))
+        .mount(&mock)
+        .await;
+
+    let client = make_client(&mock);
+    let params = api::CitationGraphParams { max_works: 2, ..Default::default() };
+    let resp = api::work_citations(&client, "W1", &params).await.unwrap();
+    assert_eq!(resp.count, 2);
+    assert!(resp.truncated);
+
+    let params = api::CitationGraphParams { max_works: 0, ..Default::default() };
+    let resp = api::work_citations(&client, "W1", &params).await.unwrap();
+    assert_eq!(resp.count, 1);
+    assert!(resp.truncated);
+}
+
+#[tokio::test]
+async fn test_work_citations_resolves_doi_root() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/works/doi:10.1234/test"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(work_json()))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("filter", "cites:W1"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(multi_result_list_response(&[])))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let client = make_client(&mock);
+    let resp = api::work_citations(&client, "10.1234/test", &api::CitationGraphParams::default())
+        .await
+        .unwrap();
+    assert_eq!(resp.root, "W1");
+    assert_eq!(resp.count, 0);
+}
+
+// ── Related works ────────────────────────────────────────────────────────
+
+fn related_list_response(results: &[&str]) -> String {
+    format!(
+        r#"{{"meta": {{"count": {}, "db_response_time_ms": 5, "page": 1, "per_page": 25, "next_cursor": null, "groups_count": null}}, "results": [{}], "group_by": []}}"#,
+        results.len(),
+        results.join(", ")
+    )
+}
+
+#[tokio::test]
+async fn test_work_related_ranks_by_combined_signals() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/works/W1"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            r#"{"id": "https://openalex.org/W1", "display_name": "Seed",
+                "related_works": ["https://openalex.org/W5"],
+                "referenced_works": ["https://openalex.org/W2", "https://openalex.org/W3"],
+                "topics": [{"id": "https://openalex.org/T1", "display_name": "Machine Learning"}]}"#,
+        ))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("filter", "cites:W2|W3"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(related_list_response(&[
+            r#"{"id": "https://openalex.org/W1", "referenced_works": ["https://openalex.org/W2"]}"#,
+            r#"{"id": "https://openalex.org/W4", "display_name": "Coupled", "cited_by_count": 9,
+                "referenced_works": ["https://openalex.org/W2", "https://openalex.org/W3"]}"#,
+        ])))
+        .expect(1)
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("filter", "topics.id:T1"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(related_list_response(&[
+            r#"{"id": "https://openalex.org/W6", "display_name": "Same topic",
+                "topics": [{"id": "https://openalex.org/T1", "display_name": "Machine Learning"}]}"#,
+        ])))
+        .expect(1)
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("filter", "ids.openalex:W5"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(related_list_response(&[
+            r#"{"id": "https://openalex.org/W5", "display_name": "Related",
+                "referenced_works": ["https://openalex.org/W3"]}"#,
+        ])))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let client = make_client(&mock);
+    let resp = api::work_related(&client, "W1", &api::RelatedWorksParams::default())
+        .await
+        .unwrap();
+    assert_eq!(resp.seeds, vec!["W1"]);
+    let ids: Vec<&str> = resp.works.iter().map(|w| w.work.id.as_str()).collect();
+    assert_eq!(
+        ids,
+        vec!["https://openalex.org/W5", "https://openalex.org/W4", "https://openalex.org/W6"]
+    );
+    assert_eq!(resp.works[0].score, 4.0);
+    assert_eq!(
+        resp.works[0].reasons,
+        vec![
+            api::RelatedReason::OpenalexRelated { seed: "W1".into() },
+            api::RelatedReason::SharedReferences { seed: "W1".into(), count: 1 },
+        ]
+    );
+    assert_eq!(
+        resp.works[2].reasons,
+        vec![api::RelatedReason::SharedTopics {
+            seed: "W1".into(),
+            topics: vec!["Machine Learning".into()],
+        }]
+    );
+}
+
+#[tokio::test]
+async fn test_selection_related_excludes_seeds_and_reports_unresolved() {
+    let mock = MockServer::start().await;
+    for (id, related) in [("W1", "W2"), ("W2", "W3")] {
+        Mock::given(method("GET"))
+            .and(path(format!("/works/{id}")))
+            .respond_with(ResponseTemplate::new(200).set_body_string(format!(
+                r#"{{"id": "https://openalex.org/{id}", "related_works": ["https://openalex.org/{related}"]}}"#
+            )))
+            .mount(&mock)
+            .await;
+    }
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("filter", "ids.openalex:W3"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(related_list_response(&[
+            r#"{"id": "https://openalex.org/W3", "display_name": "Next"}"#,
+        ])))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let client = make_client(&mock);
+    let selection = papers_core::Selection {
+        name: "reading".to_string(),
+        entries: vec![
+            selection_entry(Some("W1"), "First"),
+            selection_entry(Some("W2"), "Second"),
+            selection_entry(None, "No ids"),
+        ],
+    };
+    let resp = api::selection_related(&client, &selection, &api::RelatedWorksParams::default())
+        .await
+        .unwrap();
+    assert_eq!(resp.seeds, vec!["W1", "W2"]);
+    assert_eq!(resp.count, 1);
+    assert_eq!(resp.works[0].work.id, "https://openalex.org/W3");
+    assert_eq!(resp.unresolved, vec!["No ids"]);
+}
+
+// ── Reference search ─────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_work_search_references_merges_batches_by_relevance() {
+    let mock = MockServer::start().await;
+    let refs: Vec<String> = (100..152).map(|n| format!("W{n}")).collect();
+    let urls: Vec<String> = refs.iter().map(|r| format!(r#""https://openalex.org/{r}""#)).collect();
+    Mock::given(method("GET"))
+        .and(path("/works/W1"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(format!(
+            r#"{{"id": "https://openalex.org/W1", "referenced_works": [{}]}}"#,
+            urls.join(", ")
+        )))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("filter", format!("ids.openalex:{}", refs[..50].join("|"))))
+        .and(query_param("search", "contact"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(related_list_response(&[
+            r#"{"id": "https://openalex.org/W120", "display_name": "Contact friction", "relevance_score": 40.5}"#,
+            r#"{"id": "https://openalex.org/W130", "display_name": "Contact mentioned", "relevance_score": 3.0}"#,
+        ])))
+        .expect(1)
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("filter", "ids.openalex:W150|W151"))
+        .and(query_param("search", "contact"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(related_list_response(&[
+            r#"{"id": "https://openalex.org/W151", "display_name": "Contact solvers", "relevance_score": 12.0}"#,
+        ])))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let client = make_client(&mock);
+    let params = api::ReferenceSearchParams { limit: 2 };
+    let resp = api::work_search_references(&client, "W1", "contact", &params).await.unwrap();
+    assert_eq!(resp.root, "W1");
+    assert_eq!(resp.references, 52);
+    assert_eq!(resp.count, 2);
+    let ids: Vec<&str> = resp.works.iter().map(|w| w.work.id.as_str()).collect();
+    assert_eq!(ids, vec!["https://openalex.org/W120", "https://openalex.org/W151"]);
+    assert_eq!(resp.works[1].relevance_score, Some(12.0));
+}
+
+#[tokio::test]
+async fn test_work_search_references_without_references() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/works/W1"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"id": "https://openalex.org/W1"}"#))
+        .mount(&mock)
+        .await;
+
+    let client = make_client(&mock);
+    let resp = api::work_search_references(&client, "W1", "contact", &api::ReferenceSearchParams::default())
+        .await
+        .unwrap();
+    assert_eq!(resp.references, 0);
+    assert!(resp.works.is_empty());
+}
+
+// ── Citation formatting ──────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_format_citation_apa() {
+    use papers_core::cite::{CitationStyle, format_citation};
+
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/works/W1"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            r#"{"id": "https://openalex.org/W1", "display_name": "Splatting at scale", "publication_year": 2023,
+                "doi": "https://doi.org/10.1145/3592433", "type": "article",
+                "authorships": [{"author": {"display_name": "Bernhard Kerbl"}}, {"author": {"display_name": "Georgios Kopanas"}}],
+                "primary_location": {"source": {"display_name": "ACM Transactions on Graphics"}},
+                "biblio": {"volume": "42", "issue": "4", "first_page": "1", "last_page": "14"}}"#,
+        ))
+        .mount(&mock)
+        .await;
+
+    let client = make_client(&mock);
+    let cited = format_citation(&client, "W1", CitationStyle::Apa).await.unwrap();
+    assert_eq!(
+        cited.citation,
+        "Kerbl, B., & Kopanas, G. (2023). Splatting at scale. ACM Transactions on Graphics, 42(4), 1–14. https://doi.org/10.1145/3592433"
+    );
+    assert_eq!(cited.in_text, "(Kerbl & Kopanas, 2023)");
+    assert_eq!(cited.key, "kerbl2023splatting");
+    assert_eq!(cited.id, "W1");
+}
+
+// ── Review context ───────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_review_context_without_semantic_search() {
+    use wiremock::matchers::{query_param_contains, query_param_is_missing};
+
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/find/works"))
+        .respond_with(ResponseTemplate::new(403).set_body_string("API key required"))
+        .mount(&mock)
+        .await;
+    let old = r#"{"id": "https://openalex.org/W1", "display_name": "Classic splatting", "publication_year": 2010, "cited_by_count": 800,
+        "primary_topic": {"id": "https://openalex.org/T1", "display_name": "Rendering"}, "referenced_works": ["https://openalex.org/W9"]}"#;
+    let recent = r#"{"id": "https://openalex.org/W2", "display_name": "Recent splatting", "publication_year": 2099, "cited_by_count": 1,
+        "primary_topic": {"id": "https://openalex.org/T1", "display_name": "Rendering"}, "referenced_works": ["https://openalex.org/W9"]}"#;
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("search", "Gaussian Splatting Large Scenes"))
+        .and(query_param_is_missing("filter"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(related_list_response(&[old, recent])))
+        .expect(1)
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param_contains("filter", "from_publication_date:"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(related_list_response(&[recent])))
+        .expect(1)
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("filter", "ids.openalex:W9"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(related_list_response(&[
+            r#"{"id": "https://openalex.org/W9", "display_name": "Foundational", "publication_year": 1995}"#,
+        ])))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let client = make_client(&mock);
+    let context = api::review_context(&client, "Gaussian Splatting for Large Scenes\nWe present...", &[]).await;
+    assert_eq!(context.search_terms, "Gaussian Splatting Large Scenes");
+    assert_eq!(context.candidates, 2);
+    assert_eq!(context.warnings.len(), 1);
+    assert!(context.warnings[0].starts_with("semantic search"), "{:?}", context.warnings);
+    assert_eq!(context.key_prior_works[0].work.title.as_deref(), Some("Classic splatting"));
+    assert_eq!(context.recent_closest[0].work.title.as_deref(), Some("Recent splatting"));
+    assert_eq!(context.possible_missed_citations[0].cited_by_related, 2);
+    assert_eq!(context.possible_missed_citations[0].work.title.as_deref(), Some("Foundational"));
+    assert_eq!(context.clusters[0].count, 2);
+}
+
+// ── Author profile ───────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_author_profile_composes_calls() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/authors/A1"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(author_json()))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("filter", "author.id:A1"))
+        .and(query_param("sort", "cited_by_count:desc"))
+        .and(query_param("per-page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(list_response(work_json())))
+        .expect(1)
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("filter", "author.id:A1"))
+        .and(query_param("group_by", "authorships.author.id"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            r#"{"meta": {"count": 100, "db_response_time_ms": 5, "page": 1, "per_page": 25, "next_cursor": null, "groups_count": 3}, "results": [], "group_by": [
+                {"key": "https://openalex.org/A1", "key_display_name": "Alice Smith", "count": 100},
+                {"key": "https://openalex.org/A3", "key_display_name": "Carol", "count": 4},
+                {"key": "https://openalex.org/A2", "key_display_name": "Bob", "count": 12}
+            ]}"#,
+        ))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let client = make_client(&mock);
+    let params = api::AuthorProfileParams { top_works: 2, top_coauthors: 1, topics: 2 };
+    let profile = api::author_profile(&client, "A1", &params).await.unwrap();
+    assert_eq!(profile.id, "A1");
+    assert_eq!(profile.h_index, Some(30));
+    assert_eq!(profile.i10_index, Some(50));
+    assert_eq!(profile.top_works.len(), 1);
+    // Self is excluded and co-authors are sorted by shared works
+    assert_eq!(profile.top_coauthors.len(), 1);
+    assert_eq!(profile.top_coauthors[0].id, "A2");
+    assert_eq!(profile.top_coauthors[0].shared_works, 12);
+    let topics: Vec<_> = profile.topics.iter().map(|t| t.display_name.as_str()).collect();
+    assert_eq!(topics, vec!["Machine Learning", "Deep Learning"]);
+    assert_eq!(profile.topics[0].share, Some(0.2));
+}
+
+#[tokio::test]
+async fn test_author_affiliation_history_spans_and_year() {
+    let mock = MockServer::start().await;
+    let author = r#"{
+        "id": "https://openalex.org/A1",
+        "display_name": "Alice Smith",
+        "affiliations": [
+            {"institution": {"id": "https://openalex.org/I1", "display_name": "MIT", "ror": null, "country_code": "US", "type": "education"}, "years": [2016, 2017, 2018, 2022]},
+            {"institution": {"id": "https://openalex.org/I2", "display_name": "ETH Zurich", "ror": null, "country_code": "CH", "type": "education"}, "years": [2021, 2020, 2019]}
+        ]
+    }"#;
+    Mock::given(method("GET"))
+        .and(path("/authors/A1"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(author))
+        .mount(&mock)
+        .await;
+
+    let client = make_client(&mock);
+    let history = api::author_affiliation_history(&client, "A1", Some(2019)).await.unwrap();
+    assert_eq!(history.source, "affiliations");
+    let spans: Vec<_> = history
+        .spans
+        .iter()
+        .map(|s| (s.display_name.as_deref().unwrap(), s.start_year, s.end_year))
+        .collect();
+    assert_eq!(
+        spans,
+        vec![("MIT", 2016, 2018), ("ETH Zurich", 2019, 2021), ("MIT", 2022, 2022)]
+    );
+    assert_eq!(history.at_year.len(), 1);
+    assert_eq!(history.at_year[0].institution_id.as_deref(), Some("I2"));
+}
+
+#[tokio::test]
+async fn test_author_affiliation_history_falls_back_to_works() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/authors/A1"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            r#"{"id": "https://openalex.org/A1", "display_name": "Alice Smith", "affiliations": []}"#,
+        ))
+        .mount(&mock)
+        .await;
+    let works = r#"
+        {"id": "https://openalex.org/W1", "publication_year": 2020, "authorships": [
+            {"author": {"id": "https://openalex.org/A9", "display_name": "Other"}, "institutions": [{"id": "https://openalex.org/I9", "display_name": "Elsewhere"}]},
+            {"author": {"id": "https://openalex.org/A1", "display_name": "Alice Smith"}, "institutions": [{"id": "https://openalex.org/I1", "display_name": "MIT"}]}
+        ]},
+        {"id": "https://openalex.org/W2", "publication_year": 2019, "authorships": [
+            {"author": {"id": "https://openalex.org/A1", "display_name": "Alice Smith"}, "institutions": [{"id": "https://openalex.org/I1", "display_name": "MIT"}]}
+        ]}"#;
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("filter", "author.id:A1"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(list_response(works)))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let client = make_client(&mock);
+    let history = api::author_affiliation_history(&client, "A1", None).await.unwrap();
+    assert_eq!(history.source, "works");
+    assert_eq!(history.spans.len(), 1);
+    assert_eq!(history.spans[0].display_name.as_deref(), Some("MIT"));
+    assert_eq!((history.spans[0].start_year, history.spans[0].end_year), (2019, 2020));
+    assert!(history.at_year.is_empty());
+}
+
+#[tokio::test]
+async fn test_author_resolve_ranks_namesakes_by_hints() {
+    let mock = MockServer::start().await;
+    let authors = [
+        r#"{"id": "https://openalex.org/A1", "display_name": "Wei Wang", "works_count": 900,
+            "last_known_institutions": [{"display_name": "Tsinghua University"}],
+            "topics": [{"display_name": "Materials Chemistry", "score": 0.9}]}"#,
+        r#"{"id": "https://openalex.org/A2", "display_name": "Wei Wang", "works_count": 40,
+            "last_known_institutions": [{"display_name": "Massachusetts Institute of Technology"}],
+            "topics": [{"display_name": "Machine Learning Theory", "score": 0.9}]}"#,
+    ];
+    Mock::given(method("GET"))
+        .and(path("/authors"))
+        .and(query_param("search", "Wei Wang"))
+        .and(query_param("per-page", "5"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(related_list_response(&authors)))
+        .expect(1)
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("filter", "author.id:A1,raw_author_name.search:Jane Doe"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(related_list_response(&[])))
+        .expect(1)
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("filter", "author.id:A2,raw_author_name.search:Jane Doe"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(related_list_response(&[r#"{"id": "https://openalex.org/W1"}"#])),
+        )
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let client = make_client(&mock);
+    let params = api::AuthorResolveParams {
+        hints: papers_core::disambiguation::ResolveHints {
+            affiliation: Some("MIT".into()),
+            topic: None,
+            coauthor: Some("Jane Doe,".into()),
+        },
+        candidates: 5,
+    };
+    let resolution = api::author_resolve(&client, "Wei Wang", &params).await.unwrap();
+    assert_eq!(resolution.query, "Wei Wang");
+    let ids: Vec<_> = resolution.candidates.iter().map(|c| c.id.as_str()).collect();
+    assert_eq!(ids, ["A2", "A1"]);
+    assert_eq!(resolution.candidates[0].coauthor_works, Some(1));
+    assert_eq!(resolution.candidates[1].coauthor_works, Some(0));
+    assert!(resolution.candidates[0].confidence > resolution.candidates[1].confidence);
+}
+
+// ── work_text tests ──────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_work_text_no_pdf_found() {
+    let mock = MockServer::start().await;
+    // Work has no pdf_url in any location and no has_content.pdf
+    Mock::given(method("GET"))
+        .and(path("/works/W1"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            r#"{
+                "id": "https://openalex.org/W1",
+                "display_name": "A Paper Without PDF",
+                "doi": null,
+                "primary_location": null,
+                "locations": [],
+                "best_oa_location": null,
+                "has_content": null
+            }"#,
+        ))
+        .mount(&mock)
+        .await;
+
+    let client = make_client(&mock);
+    let result = text::work_text(&client, None, "W1").await;
+    assert!(result.is_err());
+    let err = result.unwrap_err();
+    match err {
+        text::WorkTextError::NoPdfFound { ref work_id, ref title, .. } => {
+            assert_eq!(work_id, "https://openalex.org/W1");
+            assert_eq!(title.as_deref(), Some("A Paper Without PDF"));
+        }
+        other => panic!("Expected NoPdfFound, got: {other}"),
+    }
+    assert!(err.to_string().contains("A Paper Without PDF"));
+}
+
+#[tokio::test]
+async fn test_work_text_no_pdf_found_with_non_whitelisted_url() {
+    let mock = MockServer::start().await;
+    // Work has a pdf_url but on a non-whitelisted domain
+    Mock::given(method("GET"))
+        .and(path("/works/W2"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            r#"{
+                "id": "https://openalex.org/W2",
+                "display_name": "Publisher Paper",
+                "doi": null,
+                "primary_location": {"pdf_url": "https://publisher.com/paper.pdf"},
+                "locations": [],
+                "best_oa_location": null,
+                "has_content": null
+            }"#,
+        ))
+        .mount(&mock)
+        .await;
+
+    let client = make_client(&mock);
+    let result = text::work_text(&client, None, "W2").await;
+    assert!(result.is_err());
+    match result.unwrap_err() {
+        text::WorkTextError::NoPdfFound { .. } => {}
+        other => panic!("Expected NoPdfFound, got: {other}"),
+    }
+}
+
+#[tokio::test]
+async fn test_work_text_with_sources_reports_attempts_in_order() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/works/W3"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            r#"{
+                "id": "https://openalex.org/W3",
+                "display_name": "Paywalled Paper",
+                "doi": "https://doi.org/10.1234/paywalled",
+                "primary_location": {"pdf_url": "https://publisher.com/paper.pdf"},
+                "locations": [],
+                "best_oa_location": null,
+                "has_content": null
+            }"#,
+        ))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/resolver/10.1234/paywalled"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&mock)
+        .await;
+
+    let client = make_client(&mock);
+    let sources = text::TextSourceConfig {
+        order: vec![
+            text::TextSource::LinkResolver,
+            text::TextSource::OaUrls,
+            text::TextSource::Unpaywall,
+            text::TextSource::ZoteroLocal,
+        ],
+        unpaywall_email: None,
+        link_resolver: Some(format!("{}/resolver/{{doi}}", mock.uri())),
+    };
+    let err = text::work_text_with_sources(&client, None, "W3", &sources)
+        .await
+        .unwrap_err();
+    let text::WorkTextError::NoPdfFound { ref attempts, .. } = err else {
+        panic!("Expected NoPdfFound, got: {err}");
+    };
+    let steps: Vec<_> = attempts.iter().map(|a| (a.source, a.outcome)).collect();
+    assert_eq!(
+        steps,
+        vec![
+            (text::TextSource::LinkResolver, text::AttemptOutcome::Failed),
+            (text::TextSource::OaUrls, text::AttemptOutcome::Skipped),
+            (text::TextSource::Unpaywall, text::AttemptOutcome::Skipped),
+            (text::TextSource::ZoteroLocal, text::AttemptOutcome::Skipped),
+        ]
+    );
+    assert_eq!(attempts[0].reason.as_deref(), Some("HTTP 404"));
+    assert_eq!(attempts[3].reason.as_deref(), Some("Zotero not configured"));
+    let msg = err.to_string();
+    assert!(msg.contains("link_resolver (failed: HTTP 404)"), "{msg}");
+    assert!(msg.contains("none on a whitelisted domain"), "{msg}");
+}
+
+#[tokio::test]
+async fn test_work_text_with_sources_probes_concurrently() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/works/W4"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            r#"{
+                "id": "https://openalex.org/W4",
+                "display_name": "Slow Sources Paper",
+                "doi": "https://doi.org/10.1234/slow",
+                "primary_location": null,
+                "locations": [],
+                "best_oa_location": null,
+                "has_content": null
+            }"#,
+        ))
+        .mount(&mock)
+        .await;
+    let delay = std::time::Duration::from_millis(800);
+    Mock::given(method("GET"))
+        .and(path("/users/testuser/items/top"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Total-Results", "0")
+                .set_body_string("[]")
+                .set_delay(delay),
+        )
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/resolver/10.1234/slow"))
+        .respond_with(ResponseTemplate::new(404).set_delay(delay))
+        .mount(&mock)
+        .await;
+
+    let client = make_client(&mock);
+    let zotero = papers_zotero::ZoteroClient::new("testuser", "test-api-key").with_base_url(mock.uri());
+    let sources = text::TextSourceConfig {
+        order: vec![
+            text::TextSource::ZoteroLocal,
+            text::TextSource::ZoteroRemote,
+            text::TextSource::LinkResolver,
+        ],
+        unpaywall_email: None,
+        link_resolver: Some(format!("{}/resolver/{{doi}}", mock.uri())),
+    };
+    let started = std::time::Instant::now();
+    let err = text::work_text_with_sources(&client, Some(&zotero), "W4", &sources)
+        .await
+        .unwrap_err();
+    // The Zotero searches (title, then DOI) and the resolver overlap.
+    assert!(started.elapsed() < delay * 3, "took {:?}", started.elapsed());
+    let text::WorkTextError::NoPdfFound { attempts, .. } = err else {
+        panic!("Expected NoPdfFound, got: {err}");
+    };
+    let steps: Vec<_> = attempts
+        .iter()
+        .map(|a| (a.source, a.outcome, a.reason.as_deref().unwrap_or_default()))
+        .collect();
+    assert_eq!(
+        steps,
+        vec![
+            (text::TextSource::ZoteroLocal, text::AttemptOutcome::Failed, "not in the Zotero library"),
+            (text::TextSource::ZoteroRemote, text::AttemptOutcome::Failed, "not in the Zotero library"),
+            (text::TextSource::LinkResolver, text::AttemptOutcome::Failed, "HTTP 404"),
+        ]
+    );
 }
@@ -0,0 +1,118 @@
+use papers_core::OpenAlexClient;
+use papers_core::export::*;
+use papers_core::selection::*;
+use serial_test::serial;
+use tempfile::TempDir;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+// ── Test helpers ───────────────────────────────────────────────────────────
+
+/// Sets `PAPERS_DATA_DIR` to an isolated temp dir for the duration of the
+/// returned `TempDir`. The caller must keep the `TempDir` alive.
+fn isolated_dir() -> TempDir {
+    let dir = TempDir::new().expect("tempdir");
+    // SAFETY: see selection.rs tests.
+    unsafe { std::env::set_var("PAPERS_DATA_DIR", dir.path()) };
+    dir
+}
+
+fn entry(oa_id: Option<&str>, title: &str) -> SelectionEntry {
+    SelectionEntry {
+        zotero_key: None,
+        openalex_id: oa_id.map(str::to_string),
+        doi: None,
+        title: Some(title.to_string()),
+        authors: Some(vec!["Grace Hopper".to_string()]),
+        year: Some(1952),
+        issn: None,
+        isbn: None,
+        work_type: Some("report".to_string()),
+        note: None,
+        tags: Vec::new(),
+    }
+}
+
+async fn mock_openalex() -> MockServer {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/works/W1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "https://openalex.org/W1",
+            "doi": "https://doi.org/10.1234/test",
+            "display_name": "A Great Paper",
+            "publication_year": 2020,
+            "type": "article",
+            "authorships": [{"author": {"display_name": "Alice Smith"}}],
+            "primary_location": {"source": {"display_name": "Nature"}},
+        })))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/works/W404"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&mock)
+        .await;
+    mock
+}
+
+fn save(entries: Vec<SelectionEntry>) {
+    save_selection(&Selection { name: "thesis".to_string(), entries }).unwrap();
+}
+
+// ── Export ─────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+#[serial]
+async fn test_export_bibtex_falls_back_to_entry_metadata() {
+    let _dir = isolated_dir();
+    save(vec![
+        entry(Some("W1"), "ignored"),
+        entry(Some("W404"), "The Compiler"),
+        entry(None, "Notes"),
+    ]);
+    let mock = mock_openalex().await;
+    let client = OpenAlexClient::new().with_base_url(mock.uri());
+
+    let bib = export_selection_bibtex("thesis", &client, None).await.unwrap();
+    assert!(bib.contains("@article{smith2020great,\n"), "{bib}");
+    assert!(bib.contains("  journal = {Nature},\n"));
+    assert!(bib.contains("  doi = {10.1234/test},\n"));
+    assert!(bib.contains("@techreport{hopper1952compiler,\n"));
+    assert!(bib.contains("@techreport{hopper1952notes,\n"));
+}
+
+#[tokio::test]
+#[serial]
+async fn test_export_csl_json_with_source_counts() {
+    let _dir = isolated_dir();
+    save(vec![entry(Some("W1"), "ignored"), entry(None, "Notes")]);
+    let mock = mock_openalex().await;
+    let client = OpenAlexClient::new().with_base_url(mock.uri());
+
+    let export = export_selection("thesis", ExportFormat::CslJson, &client, None).await.unwrap();
+    assert_eq!(export.count, 2);
+    assert_eq!(export.openalex, 1);
+    assert_eq!(export.selection_only, 1);
+    assert_eq!(export.zotero, 0);
+    let items = export.content.as_array().unwrap();
+    assert_eq!(items[0]["id"], "smith2020great");
+    assert_eq!(items[0]["type"], "article-journal");
+    assert_eq!(items[0]["author"][0], serde_json::json!({"family": "Smith", "given": "Alice"}));
+    assert_eq!(items[1]["type"], "report");
+    assert_eq!(items[1]["issued"]["date-parts"], serde_json::json!([[1952]]));
+
+    let csl = export_selection_csl_json("thesis", &client, None).await.unwrap();
+    assert_eq!(&csl, &export.content);
+}
+
+#[tokio::test]
+#[serial]
+async fn test_export_missing_selection_errors() {
+    let _dir = isolated_dir();
+    let client = OpenAlexClient::new();
+    assert!(matches!(
+        export_selection_bibtex("nope", &client, None).await,
+        Err(ExportError::Selection(_))
+    ));
+}
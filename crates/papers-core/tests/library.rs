@@ -0,0 +1,120 @@
+use papers_core::OpenAlexClient;
+use papers_core::library::*;
+use papers_core::selection::{Selection, SelectionEntry};
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn zotero_item(key: &str, title: &str, doi: Option<&str>, date: &str) -> papers_zotero::Item {
+    serde_json::from_value(serde_json::json!({
+        "key": key,
+        "version": 1,
+        "library": { "type": "user", "id": 1, "name": "test", "links": {} },
+        "links": {},
+        "meta": {},
+        "data": {
+            "key": key,
+            "version": 1,
+            "itemType": "journalArticle",
+            "title": title,
+            "DOI": doi,
+            "date": date,
+            "creators": [{ "creatorType": "author", "firstName": "Ada", "lastName": "Lovelace" }],
+            "tags": [],
+            "collections": []
+        }
+    }))
+    .unwrap()
+}
+
+fn work(id: &str, title: &str, doi: Option<&str>, year: i32) -> serde_json::Value {
+    serde_json::json!({
+        "id": format!("https://openalex.org/{id}"),
+        "doi": doi.map(|d| format!("https://doi.org/{d}")),
+        "display_name": title,
+        "publication_year": year,
+    })
+}
+
+#[test]
+fn test_merge_hits_dedupes_by_doi_and_title() {
+    let items = [
+        zotero_item("ZKEY0001", "Neural Radiance Fields", Some("10.1/NERF"), "2020"),
+        zotero_item("ZKEY0002", "3D Gaussian Splatting for Real-Time Rendering", None, "2023"),
+    ];
+    let works: Vec<papers_openalex::Work> = serde_json::from_value(serde_json::json!([
+        work("W1", "NeRF: Representing Scenes", Some("10.1/nerf"), 2020),
+        work("W2", "3D Gaussian splatting for real-time rendering", None, 2023),
+        work("W3", "3D Gaussian Splatting for Real-Time Rendering", None, 2015),
+        work("W4", "Instant Neural Graphics Primitives", Some("10.1/ngp"), 2022),
+    ]))
+    .unwrap();
+
+    let hits = merge_hits(&items, &works);
+    let ids: Vec<_> = hits
+        .iter()
+        .map(|h| (h.entry.zotero_key.as_deref(), h.entry.openalex_id.as_deref(), h.in_zotero))
+        .collect();
+    assert_eq!(
+        ids,
+        [
+            (Some("ZKEY0001"), Some("W1"), true),
+            (Some("ZKEY0002"), Some("W2"), true),
+            (None, Some("W3"), false),
+            (None, Some("W4"), false),
+        ]
+    );
+    // Zotero metadata wins over OpenAlex for merged hits.
+    assert_eq!(hits[0].entry.title.as_deref(), Some("Neural Radiance Fields"));
+    assert_eq!(hits[0].db_ids(), ["10.1/NERF", "ZKEY0001"]);
+}
+
+#[test]
+fn test_mark_selections_matches_any_identifier() {
+    let works: Vec<papers_openalex::Work> = serde_json::from_value(serde_json::json!([
+        work("W1", "One", Some("10.1/one"), 2020),
+        work("W2", "Two", None, 2021),
+    ]))
+    .unwrap();
+    let mut hits = merge_hits(&[], &works);
+    let entry = SelectionEntry {
+        zotero_key: None,
+        openalex_id: None,
+        doi: Some("https://doi.org/10.1/ONE".to_string()),
+        title: None,
+        authors: None,
+        year: None,
+        issn: None,
+        isbn: None,
+        work_type: None,
+        note: None,
+        tags: Vec::new(),
+    };
+    let selections = [Selection { name: "reading".to_string(), entries: vec![entry] }];
+    mark_selections(&mut hits, &selections);
+    assert!(hits[0].in_selection);
+    assert_eq!(hits[0].selections, ["reading"]);
+    assert!(!hits[1].in_selection);
+}
+
+#[tokio::test]
+async fn test_library_search_without_zotero_returns_openalex_hits() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("search", "radiance fields"))
+        .and(query_param("per-page", "5"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "meta": { "count": 1, "db_response_time_ms": 1, "page": 1, "per_page": 5 },
+            "results": [work("W1", "Neural Radiance Fields", Some("10.1/nerf"), 2020)],
+        })))
+        .mount(&mock)
+        .await;
+    let client = OpenAlexClient::new().with_base_url(mock.uri());
+
+    let hits = library_search(&client, None, "radiance fields", Some(5)).await.unwrap();
+    assert_eq!(hits.len(), 1);
+    assert!(!hits[0].in_zotero && !hits[0].in_rag_index);
+    let json = serde_json::to_value(&hits[0]).unwrap();
+    assert_eq!(json["openalex_id"], "W1");
+    assert_eq!(json["doi"], "10.1/nerf");
+}
@@ -0,0 +1,90 @@
+//! Tests for the per-paper reading status store.
+
+use papers_core::reading_status::{
+    ReadingStatus, ReadingStatusError, StatusUpdate, get_status, load_statuses, record_chunk_read, set_status,
+};
+use papers_core::selection::{Selection, SelectionEntry};
+use serial_test::serial;
+use tempfile::TempDir;
+
+/// Sets `PAPERS_DATA_DIR` to an isolated temp dir for the duration of the
+/// returned `TempDir`. The caller must keep the `TempDir` alive.
+fn isolated_dir() -> TempDir {
+    let dir = TempDir::new().expect("tempdir");
+    // SAFETY: serial test; no other threads read the environment.
+    unsafe { std::env::set_var("PAPERS_DATA_DIR", dir.path()) };
+    dir
+}
+
+fn entry(zotero_key: Option<&str>, doi: Option<&str>) -> SelectionEntry {
+    serde_json::from_value(serde_json::json!({
+        "zotero_key": zotero_key,
+        "openalex_id": null,
+        "doi": doi,
+        "title": "A Paper",
+        "authors": null,
+        "year": null,
+        "issn": null,
+        "isbn": null,
+    }))
+    .unwrap()
+}
+
+#[test]
+#[serial]
+fn unknown_papers_are_unread() {
+    let _dir = isolated_dir();
+    let status = get_status("10.1145/3592433").unwrap();
+    assert_eq!(status.status, ReadingStatus::Unread);
+    assert_eq!(status.rating, None);
+    assert!(load_statuses().unwrap().papers.is_empty());
+}
+
+#[test]
+#[serial]
+fn set_updates_only_given_fields() {
+    let _dir = isolated_dir();
+    set_status("https://doi.org/10.1145/ABC", StatusUpdate { status: Some(ReadingStatus::Read), rating: Some(4), last_chunk_id: None })
+        .unwrap();
+    let status = set_status("10.1145/abc", StatusUpdate { last_chunk_id: Some("10.1145/abc/ch2/s0/p1".into()), ..Default::default() })
+        .unwrap();
+    assert_eq!(status.status, ReadingStatus::Read);
+    assert_eq!(status.rating, Some(4));
+    assert_eq!(status.last_chunk_id.as_deref(), Some("10.1145/abc/ch2/s0/p1"));
+
+    let status = set_status("10.1145/abc", StatusUpdate { rating: Some(0), ..Default::default() }).unwrap();
+    assert_eq!(status.rating, None);
+    assert!(matches!(
+        set_status("10.1145/abc", StatusUpdate { rating: Some(6), ..Default::default() }),
+        Err(ReadingStatusError::InvalidRating(6))
+    ));
+    assert!(matches!(set_status(" ", StatusUpdate::default()), Err(ReadingStatusError::EmptyId)));
+}
+
+#[test]
+#[serial]
+fn chunk_reads_start_reading_but_keep_read() {
+    let _dir = isolated_dir();
+    let status = record_chunk_read("ABCD1234", "ABCD1234/ch1/s0/p0").unwrap();
+    assert_eq!(status.status, ReadingStatus::Reading);
+
+    set_status("ABCD1234", StatusUpdate { status: Some(ReadingStatus::Read), ..Default::default() }).unwrap();
+    let status = record_chunk_read("ABCD1234", "ABCD1234/ch1/s0/p0").unwrap();
+    assert_eq!(status.status, ReadingStatus::Read);
+}
+
+#[test]
+#[serial]
+fn selection_entries_match_by_doi_or_zotero_key() {
+    let _dir = isolated_dir();
+    set_status("10.1/read", StatusUpdate { status: Some(ReadingStatus::Read), ..Default::default() }).unwrap();
+    set_status("ZKEY0001", StatusUpdate { status: Some(ReadingStatus::Reading), ..Default::default() }).unwrap();
+    let sel = Selection {
+        name: "s".into(),
+        entries: vec![entry(None, Some("10.1/READ")), entry(Some("OTHER001"), None), entry(Some("ZKEY0001"), Some(""))],
+    };
+    let statuses = load_statuses().unwrap().for_selection(&sel);
+    assert_eq!(statuses.len(), 2);
+    assert_eq!(statuses[&1].status, ReadingStatus::Read);
+    assert_eq!(statuses[&3].status, ReadingStatus::Reading);
+}
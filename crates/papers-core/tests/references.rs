@@ -0,0 +1,80 @@
+use papers_core::OpenAlexClient;
+use papers_core::references::*;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn work(id: &str, title: &str, doi: &str, year: i32) -> serde_json::Value {
+    serde_json::json!({
+        "id": format!("https://openalex.org/{id}"),
+        "doi": format!("https://doi.org/{doi}"),
+        "display_name": title,
+        "publication_year": year,
+    })
+}
+
+#[tokio::test]
+async fn test_resolve_references_by_doi_then_title() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/works/doi:10.1145/280814.280821"))
+        .and(query_param("select", REFERENCE_SELECT))
+        .respond_with(ResponseTemplate::new(200).set_body_json(work(
+            "W1",
+            "Large steps in cloth simulation",
+            "10.1145/280814.280821",
+            1998,
+        )))
+        .expect(1)
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("search", "Projective dynamics: fusing constraint projections for fast simulation"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "meta": { "count": 2, "db_response_time_ms": 1, "page": 1, "per_page": 5 },
+            "results": [
+                work("W3", "Fast simulation of mass-spring systems", "10.1/ms", 2013),
+                work("W2", "Projective Dynamics: Fusing Constraint Projections for Fast Simulation", "10.1145/2601097.2601116", 2014),
+            ],
+        })))
+        .expect(1)
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("search", "An unrelated workshop note"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "meta": { "count": 1, "db_response_time_ms": 1, "page": 1, "per_page": 5 },
+            "results": [work("W4", "Something else entirely", "10.1/else", 2001)],
+        })))
+        .mount(&mock)
+        .await;
+    let client = OpenAlexClient::new().with_base_url(mock.uri());
+
+    let parsed = parse_references(
+        "[1] D. Baraff and A. Witkin. Large steps in cloth simulation. 1998. doi:10.1145/280814.280821.\n\
+         [2] S. Bouaziz et al. Projective dynamics: fusing constraint projections for fast simulation. 2014.\n\
+         [3] J. Doe. An unrelated workshop note. 2001.",
+    );
+    let refs = resolve_references(&client, parsed, true).await;
+    assert_eq!(refs.len(), 3);
+    assert_eq!(refs[0].openalex_id.as_deref(), Some("W1"));
+    assert_eq!(refs[0].resolved_by.as_deref(), Some("doi"));
+    assert_eq!(refs[1].openalex_id.as_deref(), Some("W2"));
+    assert_eq!(refs[1].resolved_by.as_deref(), Some("title"));
+    assert_eq!(refs[1].parsed.doi.as_deref(), Some("10.1145/2601097.2601116"));
+    assert_eq!(refs[2].openalex_id, None);
+    assert!(refs.iter().all(|r| !r.in_rag_index));
+}
+
+#[tokio::test]
+async fn test_resolve_references_skips_lookups_when_disabled() {
+    let mock = MockServer::start().await;
+    let client = OpenAlexClient::new().with_base_url(mock.uri());
+    let parsed = parse_references("[1] A. Smith. A title with words. 2020. doi:10.1234/abc.\n[2] B. Lee. Other. 2019.");
+    let refs = resolve_references(&client, parsed, false).await;
+    assert_eq!(refs.len(), 2);
+    assert!(refs.iter().all(|r| r.openalex_id.is_none()));
+    assert_eq!(refs[0].parsed.doi.as_deref(), Some("10.1234/abc"));
+    assert!(mock.received_requests().await.unwrap().is_empty());
+}
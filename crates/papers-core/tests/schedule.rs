@@ -0,0 +1,50 @@
+use std::time::Duration;
+
+use papers_core::schedule::*;
+
+#[test]
+fn test_parse_interval_units_and_shorthands() {
+    assert_eq!(parse_interval("90s"), Ok(Duration::from_secs(90)));
+    assert_eq!(parse_interval("30m"), Ok(Duration::from_secs(1800)));
+    assert_eq!(parse_interval("6h"), Ok(Duration::from_secs(6 * 3600)));
+    assert_eq!(parse_interval(" 2 d "), Ok(Duration::from_secs(2 * 86_400)));
+    assert_eq!(parse_interval("@hourly"), Ok(Duration::from_secs(3600)));
+    assert_eq!(parse_interval("@weekly"), Ok(Duration::from_secs(7 * 86_400)));
+}
+
+#[test]
+fn test_parse_interval_rejects_bad_specs() {
+    for spec in ["", "6", "h", "0m", "5w", "-1h", "@monthly", "99999999999999999999d"] {
+        assert_eq!(parse_interval(spec), Err(ScheduleError::Interval(spec.to_string())), "{spec}");
+    }
+}
+
+#[test]
+fn test_schedule_entry_deserializes() {
+    let entries: Vec<ScheduleEntry> = serde_json::from_str(
+        r#"[{"task": "cache_evict", "every": "6h"}, {"task": "library_sync", "every": "@daily"},
+            {"task": "zotero_sync", "every": "30m"}, {"task": "digest", "every": "@daily"}]"#,
+    )
+    .unwrap();
+    assert_eq!(entries[0].task, ScheduledTask::CacheEvict);
+    assert_eq!(entries[1].task, ScheduledTask::LibrarySync);
+    assert_eq!(entries[1].interval(), Ok(Duration::from_secs(86_400)));
+    assert_eq!(entries[2].task, ScheduledTask::ZoteroSync);
+    assert_eq!(entries[3].task, ScheduledTask::Digest);
+}
+
+#[test]
+fn test_watch_runs_the_digest() {
+    let entry: ScheduleEntry = serde_json::from_str(r#"{"task": "watch", "every": "1h"}"#).unwrap();
+    assert_eq!(entry.task, ScheduledTask::Digest);
+    assert!(serde_json::from_str::<ScheduleEntry>(r#"{"task": "reindex", "every": "1h"}"#).is_err());
+}
+
+#[test]
+fn test_digest_days_cover_the_interval() {
+    assert_eq!(digest_days(Duration::from_secs(1800)), 1);
+    assert_eq!(digest_days(Duration::from_secs(86_400)), 1);
+    assert_eq!(digest_days(Duration::from_secs(86_401)), 2);
+    assert_eq!(digest_days(Duration::from_secs(7 * 86_400)), 7);
+    assert_eq!(digest_days(Duration::from_secs(365 * 86_400)), 90);
+}
@@ -0,0 +1,263 @@
+use papers_core::screening::*;
+use papers_core::selection::*;
+use serial_test::serial;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+// ── Test helpers ───────────────────────────────────────────────────────────
+
+/// Sets `PAPERS_DATA_DIR` to an isolated temp dir for the duration of the
+/// returned `TempDir`. The caller must keep the `TempDir` alive.
+fn isolated_dir() -> (TempDir, PathBuf) {
+    let dir = TempDir::new().expect("tempdir");
+    let path = dir.path().to_path_buf();
+    // SAFETY: see selection.rs tests.
+    unsafe { std::env::set_var("PAPERS_DATA_DIR", &path) };
+    (dir, path)
+}
+
+fn entry(oa_id: &str, title: &str) -> SelectionEntry {
+    SelectionEntry {
+        zotero_key: None,
+        openalex_id: Some(oa_id.to_string()),
+        doi: None,
+        title: Some(title.to_string()),
+        authors: None,
+        year: Some(2020),
+        issn: None,
+        isbn: None,
+        work_type: None,
+        note: None,
+        tags: Vec::new(),
+    }
+}
+
+fn make_selection(name: &str, n: usize) {
+    let entries = (1..=n).map(|i| entry(&format!("W{i}"), &format!("Paper {i}"))).collect();
+    save_selection(&Selection { name: name.to_string(), entries }).unwrap();
+}
+
+// ── Parsing ────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_parse_stage_and_decision() {
+    assert_eq!("title_abstract".parse::<ScreeningStage>().unwrap(), ScreeningStage::TitleAbstract);
+    assert_eq!("full-text".parse::<ScreeningStage>().unwrap(), ScreeningStage::FullText);
+    assert!("abstracts".parse::<ScreeningStage>().is_err());
+    assert_eq!("Include".parse::<ScreeningDecision>().unwrap(), ScreeningDecision::Include);
+    assert_eq!("maybe".parse::<ScreeningDecision>().unwrap(), ScreeningDecision::Maybe);
+    assert!("perhaps".parse::<ScreeningDecision>().is_err());
+}
+
+// ── Decisions ──────────────────────────────────────────────────────────────
+
+#[test]
+#[serial]
+fn test_record_decision_persists_and_latest_wins() {
+    let (_dir, _) = isolated_dir();
+    make_selection("review", 2);
+
+    record_decision("review", "W1", ScreeningStage::TitleAbstract, ScreeningDecision::Maybe, None, Some("AB".into())).unwrap();
+    let (e, rec) = record_decision("review", "Paper 1", ScreeningStage::TitleAbstract, ScreeningDecision::Include, None, Some("AB".into())).unwrap();
+    assert_eq!(e.openalex_id.as_deref(), Some("W1"));
+    assert_eq!(rec.decision, ScreeningDecision::Include);
+    assert!(!rec.decided_at.is_empty());
+
+    let state = load_screening("review").unwrap();
+    assert_eq!(state.records["W1"].len(), 2);
+    let latest = state.latest(&entry("W1", "Paper 1"), ScreeningStage::TitleAbstract).unwrap();
+    assert_eq!(latest.decision, ScreeningDecision::Include);
+    assert_eq!(latest.reviewer.as_deref(), Some("AB"));
+}
+
+#[test]
+#[serial]
+fn test_record_decision_by_index() {
+    let (_dir, _) = isolated_dir();
+    make_selection("review", 3);
+    let (e, _) = record_decision("review", "3", ScreeningStage::TitleAbstract, ScreeningDecision::Exclude, Some("wrong population".into()), None).unwrap();
+    assert_eq!(e.openalex_id.as_deref(), Some("W3"));
+    assert!(record_decision("review", "4", ScreeningStage::TitleAbstract, ScreeningDecision::Exclude, None, None).is_err());
+}
+
+#[test]
+#[serial]
+fn test_full_text_requires_title_abstract_include() {
+    let (_dir, _) = isolated_dir();
+    make_selection("review", 1);
+    let err = record_decision("review", "W1", ScreeningStage::FullText, ScreeningDecision::Include, None, None).unwrap_err();
+    assert!(matches!(err, ScreeningError::NotEligible(_)));
+
+    record_decision("review", "W1", ScreeningStage::TitleAbstract, ScreeningDecision::Include, None, None).unwrap();
+    record_decision("review", "W1", ScreeningStage::FullText, ScreeningDecision::Include, None, None).unwrap();
+}
+
+#[test]
+#[serial]
+fn test_unknown_entry_errors() {
+    let (_dir, _) = isolated_dir();
+    make_selection("review", 1);
+    let err = record_decision("review", "W999", ScreeningStage::TitleAbstract, ScreeningDecision::Include, None, None).unwrap_err();
+    assert!(matches!(err, ScreeningError::Selection(SelectionError::ItemNotFound)));
+}
+
+// ── Progress & PRISMA ──────────────────────────────────────────────────────
+
+#[test]
+#[serial]
+fn test_progress_and_prisma_flow() {
+    let (_dir, _) = isolated_dir();
+    make_selection("review", 6);
+    let ta = ScreeningStage::TitleAbstract;
+    let ft = ScreeningStage::FullText;
+    use ScreeningDecision::*;
+    record_decision("review", "W1", ta, Include, None, None).unwrap();
+    record_decision("review", "W2", ta, Include, None, None).unwrap();
+    record_decision("review", "W3", ta, Include, None, None).unwrap();
+    record_decision("review", "W4", ta, Exclude, Some("off topic".into()), None).unwrap();
+    record_decision("review", "W5", ta, Maybe, None, None).unwrap();
+    record_decision("review", "W1", ft, Include, None, None).unwrap();
+    record_decision("review", "W2", ft, Exclude, Some("no outcome data".into()), None).unwrap();
+
+    let flow = prisma_flow("review").unwrap();
+    assert_eq!(flow.identified, 6);
+    assert_eq!(flow.title_abstract.eligible, 6);
+    assert_eq!(flow.title_abstract.included, 3);
+    assert_eq!(flow.title_abstract.excluded, 1);
+    assert_eq!(flow.title_abstract.maybe, 1);
+    assert_eq!(flow.title_abstract.unscreened, 1);
+    assert_eq!(flow.full_text.eligible, 3);
+    assert_eq!(flow.full_text.included, 1);
+    assert_eq!(flow.full_text.excluded, 1);
+    assert_eq!(flow.full_text.unscreened, 1);
+    assert_eq!(flow.full_text_exclusion_reasons.get("no outcome data"), Some(&1));
+    assert_eq!(flow.included, 1);
+}
+
+#[test]
+#[serial]
+fn test_revoked_title_abstract_include_drops_full_text() {
+    let (_dir, _) = isolated_dir();
+    make_selection("review", 1);
+    record_decision("review", "W1", ScreeningStage::TitleAbstract, ScreeningDecision::Include, None, None).unwrap();
+    record_decision("review", "W1", ScreeningStage::FullText, ScreeningDecision::Include, None, None).unwrap();
+    record_decision("review", "W1", ScreeningStage::TitleAbstract, ScreeningDecision::Exclude, None, None).unwrap();
+    let flow = prisma_flow("review").unwrap();
+    assert_eq!(flow.full_text.eligible, 0);
+    assert_eq!(flow.included, 0);
+}
+
+// ── Lifecycle ──────────────────────────────────────────────────────────────
+
+#[test]
+#[serial]
+fn test_screening_not_listed_as_selection() {
+    let (_dir, _) = isolated_dir();
+    make_selection("review", 1);
+    record_decision("review", "W1", ScreeningStage::TitleAbstract, ScreeningDecision::Include, None, None).unwrap();
+    assert_eq!(list_selection_names(), vec!["review".to_string()]);
+}
+
+#[test]
+#[serial]
+fn test_delete_and_rename_carry_screening() {
+    let (_dir, _) = isolated_dir();
+    make_selection("review", 1);
+    record_decision("review", "W1", ScreeningStage::TitleAbstract, ScreeningDecision::Include, None, None).unwrap();
+
+    rename_screening("review", "review2").unwrap();
+    assert!(load_screening("review").unwrap().records.is_empty());
+    let state = load_screening("review2").unwrap();
+    assert_eq!(state.selection, "review2");
+    assert_eq!(state.records.len(), 1);
+
+    make_selection("review2", 1);
+    delete_selection("review2").unwrap();
+    assert!(load_screening("review2").unwrap().records.is_empty());
+}
+
+// ── Blinded export & import ────────────────────────────────────────────────
+
+#[test]
+#[serial]
+fn test_blinded_export_omits_authors_and_decisions() {
+    let (_dir, _) = isolated_dir();
+    let mut e = entry("W1", "Paper, \"quoted\"");
+    e.authors = Some(vec!["Jane Doe".into()]);
+    save_selection(&Selection { name: "review".into(), entries: vec![e, entry("W2", "Paper 2")] }).unwrap();
+    record_decision("review", "W2", ScreeningStage::TitleAbstract, ScreeningDecision::Exclude, None, Some("AB".into())).unwrap();
+
+    let sel = load_selection("review").unwrap();
+    let state = load_screening("review").unwrap();
+    let entries = blinded_entries(&sel, &state, ScreeningStage::TitleAbstract);
+    assert_eq!(entries.len(), 2);
+    let csv = blinded_csv(&entries);
+    assert!(csv.starts_with("key,title,year,decision,reason\n"));
+    assert!(csv.contains("W1,\"Paper, \"\"quoted\"\"\",2020,,\n"));
+    assert!(csv.contains("W2,Paper 2,2020,,\n"));
+    assert!(!csv.contains("Jane Doe"));
+    assert!(!csv.contains("exclude"));
+
+    // Full-text export only lists title/abstract inclusions
+    assert!(blinded_entries(&sel, &state, ScreeningStage::FullText).is_empty());
+}
+
+#[test]
+#[serial]
+fn test_import_two_reviewers_detects_conflicts() {
+    let (_dir, _) = isolated_dir();
+    make_selection("review", 3);
+
+    let a = "key,title,year,decision,reason\nW1,Paper 1,2020,include,\nW2,Paper 2,2020,exclude,off topic\nW3,Paper 3,2020,,\n";
+    let report = import_decisions_csv("review", a, ScreeningStage::TitleAbstract, Some("AB")).unwrap();
+    assert_eq!(report.imported, 2);
+    assert_eq!(report.blank, 1);
+    assert!(report.conflicts.is_empty());
+
+    let b = "key,decision,reviewer\nW1,include,CD\nW2,include,CD\nW9,include,CD\n";
+    let report = import_decisions_csv("review", b, ScreeningStage::TitleAbstract, None).unwrap();
+    assert_eq!(report.imported, 2);
+    assert_eq!(report.unknown_keys, vec!["W9".to_string()]);
+    assert_eq!(report.conflicts.len(), 1);
+    let c = &report.conflicts[0];
+    assert_eq!(c.key, "W2");
+    assert_eq!(c.decisions["AB"], ScreeningDecision::Exclude);
+    assert_eq!(c.decisions["CD"], ScreeningDecision::Include);
+
+    // Adjudication clears the conflict and sets the effective decision
+    adjudicate_decision("review", "W2", ScreeningStage::TitleAbstract, ScreeningDecision::Include, None, Some("AB".into())).unwrap();
+    let sel = load_selection("review").unwrap();
+    let state = load_screening("review").unwrap();
+    assert!(screening_conflicts(&sel, &state, ScreeningStage::TitleAbstract).is_empty());
+    assert_eq!(
+        state.latest(&sel.entries[1], ScreeningStage::TitleAbstract).unwrap().decision,
+        ScreeningDecision::Include
+    );
+}
+
+#[test]
+#[serial]
+fn test_import_rejects_bad_csv() {
+    let (_dir, _) = isolated_dir();
+    make_selection("review", 1);
+    let err = import_decisions_csv("review", "id,decision\nW1,include\n", ScreeningStage::TitleAbstract, Some("AB")).unwrap_err();
+    assert!(matches!(err, ScreeningError::Csv(_)));
+    let err = import_decisions_csv("review", "key,decision\nW1,perhaps\n", ScreeningStage::TitleAbstract, Some("AB")).unwrap_err();
+    assert!(matches!(err, ScreeningError::Csv(_)));
+    let err = import_decisions_csv("review", "key,decision\nW1,include\n", ScreeningStage::TitleAbstract, None).unwrap_err();
+    assert!(matches!(err, ScreeningError::Csv(_)));
+    // Nothing was saved on error
+    assert!(load_screening("review").unwrap().records.is_empty());
+}
+
+#[test]
+#[serial]
+fn test_import_full_text_marks_ineligible() {
+    let (_dir, _) = isolated_dir();
+    make_selection("review", 2);
+    record_decision("review", "W1", ScreeningStage::TitleAbstract, ScreeningDecision::Include, None, None).unwrap();
+    let csv = "key,decision\nW1,include\nW2,include\n";
+    let report = import_decisions_csv("review", csv, ScreeningStage::FullText, Some("AB")).unwrap();
+    assert_eq!(report.imported, 1);
+    assert_eq!(report.ineligible_keys, vec!["W2".to_string()]);
+}
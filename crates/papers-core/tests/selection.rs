@@ -93,12 +93,30 @@ fn list_active_marked() {
     for name in &["a", "b", "c"] {
         save_selection(&Selection { name: name.to_string(), entries: vec![] }).unwrap();
     }
-    save_state(&SelectionState { active: Some("b".into()) }).unwrap();
+    save_state(&SelectionState { active: Some("b".into()), ..Default::default() }).unwrap();
     assert_eq!(active_selection_name().as_deref(), Some("b"));
     let state = load_state();
     assert_eq!(state.active.as_deref(), Some("b"));
 }
 
+#[tokio::test]
+#[serial]
+async fn tenant_scope_isolates_selections() {
+    let (_dir, path) = isolated_dir();
+    save_selection(&Selection { name: "shared".into(), entries: vec![] }).unwrap();
+    let alice = path.join("tenants").join("alice");
+    papers_core::tenant::scope(&alice, async {
+        assert!(list_selection_names().is_empty());
+        save_selection(&Selection { name: "mine".into(), entries: vec![] }).unwrap();
+        save_state(&SelectionState { active: Some("mine".into()), ..Default::default() }).unwrap();
+        assert_eq!(list_selection_names(), vec!["mine"]);
+    })
+    .await;
+    assert!(alice.join("selections").join("mine.json").exists());
+    assert_eq!(list_selection_names(), vec!["shared"]);
+    assert!(active_selection_name().is_none());
+}
+
 #[test]
 #[serial]
 fn list_counts_match_entries() {
@@ -113,6 +131,8 @@ fn list_counts_match_entries() {
         issn: None,
         isbn: None,
         work_type: None,
+        note: None,
+        tags: Vec::new(),
     };
     save_selection(&Selection { name: "zero".into(), entries: vec![] }).unwrap();
     save_selection(&Selection {
@@ -175,7 +195,7 @@ fn get_by_index_activates() {
 fn get_no_arg_uses_active() {
     let (_dir, _) = isolated_dir();
     save_selection(&Selection { name: "bar".into(), entries: vec![] }).unwrap();
-    save_state(&SelectionState { active: Some("bar".into()) }).unwrap();
+    save_state(&SelectionState { active: Some("bar".into()), ..Default::default() }).unwrap();
     let active = active_selection_name().unwrap();
     let sel = load_selection(&active).unwrap();
     assert_eq!(sel.name, "bar");
@@ -213,6 +233,8 @@ fn get_lists_all_entries() {
             issn: None,
             isbn: None,
         work_type: None,
+        note: None,
+        tags: Vec::new(),
         })
         .collect();
     save_selection(&Selection { name: "mysel".into(), entries: entries.clone() }).unwrap();
@@ -237,7 +259,7 @@ fn create_makes_file() {
 fn create_activates() {
     let (_dir, _) = isolated_dir();
     save_selection(&Selection { name: "mysel".into(), entries: vec![] }).unwrap();
-    save_state(&SelectionState { active: Some("mysel".into()) }).unwrap();
+    save_state(&SelectionState { active: Some("mysel".into()), ..Default::default() }).unwrap();
     assert_eq!(active_selection_name().as_deref(), Some("mysel"));
 }
 
@@ -265,10 +287,10 @@ fn create_duplicate_errors() {
 fn create_replaces_previous_active() {
     let (_dir, _) = isolated_dir();
     save_selection(&Selection { name: "a".into(), entries: vec![] }).unwrap();
-    save_state(&SelectionState { active: Some("a".into()) }).unwrap();
+    save_state(&SelectionState { active: Some("a".into()), ..Default::default() }).unwrap();
 
     save_selection(&Selection { name: "b".into(), entries: vec![] }).unwrap();
-    save_state(&SelectionState { active: Some("b".into()) }).unwrap();
+    save_state(&SelectionState { active: Some("b".into()), ..Default::default() }).unwrap();
 
     assert_eq!(active_selection_name().as_deref(), Some("b"));
     // "a" still exists
@@ -293,7 +315,7 @@ fn delete_removes_file() {
 fn delete_active_clears_state() {
     let (_dir, _) = isolated_dir();
     save_selection(&Selection { name: "active_one".into(), entries: vec![] }).unwrap();
-    save_state(&SelectionState { active: Some("active_one".into()) }).unwrap();
+    save_state(&SelectionState { active: Some("active_one".into()), ..Default::default() }).unwrap();
     delete_selection("active_one").unwrap();
     // caller (CLI/MCP) is responsible for clearing state; test the mechanism:
     let mut state = load_state();
@@ -310,11 +332,31 @@ fn delete_non_active_preserves_state() {
     let (_dir, _) = isolated_dir();
     save_selection(&Selection { name: "keep_active".into(), entries: vec![] }).unwrap();
     save_selection(&Selection { name: "other".into(), entries: vec![] }).unwrap();
-    save_state(&SelectionState { active: Some("keep_active".into()) }).unwrap();
+    save_state(&SelectionState { active: Some("keep_active".into()), ..Default::default() }).unwrap();
     delete_selection("other").unwrap();
     assert_eq!(active_selection_name().as_deref(), Some("keep_active"));
 }
 
+#[test]
+#[serial]
+fn db_scope_round_trips_and_ignores_deleted_selection() {
+    let (_dir, _) = isolated_dir();
+    assert!(db_scope_name().is_none());
+    save_selection(&Selection { name: "scoped".into(), entries: vec![] }).unwrap();
+    save_state(&SelectionState { active: Some("scoped".into()), ..Default::default() }).unwrap();
+
+    set_db_scope(Some("scoped".into())).unwrap();
+    assert_eq!(db_scope_name().as_deref(), Some("scoped"));
+    // Setting the scope leaves the active selection alone.
+    assert_eq!(active_selection_name().as_deref(), Some("scoped"));
+
+    delete_selection("scoped").unwrap();
+    assert!(db_scope_name().is_none());
+
+    set_db_scope(None).unwrap();
+    assert!(load_state().db_scope.is_none());
+}
+
 #[test]
 #[serial]
 fn delete_by_index() {
@@ -356,7 +398,7 @@ async fn add_by_openalex_id_not_in_zotero() {
     let client = make_oa_client(&mock);
     save_selection(&Selection { name: "mysel".into(), entries: vec![] }).unwrap();
 
-    let entry = resolve_paper("W2741809807", &client, None).await.unwrap();
+    let entry = resolve_paper("W2741809807", &client, None, None).await.unwrap();
     assert_eq!(entry.openalex_id.as_deref(), Some("W2741809807"));
     assert_eq!(entry.doi.as_deref(), Some("10.1234/test"));
     assert!(entry.title.is_some());
@@ -379,11 +421,53 @@ async fn add_by_doi_not_in_zotero() {
         .await;
 
     let client = make_oa_client(&mock);
-    let entry = resolve_paper("10.1234/test", &client, None).await.unwrap();
+    let entry = resolve_paper("10.1234/test", &client, None, None).await.unwrap();
     assert_eq!(entry.doi.as_deref(), Some("10.1234/test"));
     assert!(entry.openalex_id.is_some());
 }
 
+#[tokio::test]
+#[serial]
+async fn add_by_doi_unknown_to_openalex_falls_back_to_crossref() {
+    let (_dir, _) = isolated_dir();
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/works/doi:10.1101/2024.05.01.592000"))
+        .respond_with(ResponseTemplate::new(404).set_body_string("Not found"))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/crossref/works/10.1101/2024.05.01.592000"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "status": "ok",
+            "message": {
+                "DOI": "10.1101/2024.05.01.592000",
+                "title": ["A Very Recent Preprint"],
+                "author": [{"given": "Ada", "family": "Lovelace"}],
+                "type": "posted-content",
+                "issued": {"date-parts": [[2024, 5, 3]]},
+            }
+        })))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let client = make_oa_client(&mock);
+    let crossref = papers_core::crossref::CrossrefClient::new().with_base_url(format!("{}/crossref", mock.uri()));
+    let entry = resolve_paper("https://doi.org/10.1101/2024.05.01.592000", &client, None, Some(&crossref))
+        .await
+        .unwrap();
+    assert_eq!(entry.doi.as_deref(), Some("10.1101/2024.05.01.592000"));
+    assert_eq!(entry.title.as_deref(), Some("A Very Recent Preprint"));
+    assert_eq!(entry.authors, Some(vec!["Ada Lovelace".to_string()]));
+    assert_eq!(entry.year, Some(2024));
+    assert_eq!(entry.work_type.as_deref(), Some("posted-content"));
+    assert!(entry.openalex_id.is_none());
+
+    let err = resolve_paper("10.1101/2024.05.01.592000", &client, None, None).await.unwrap_err();
+    assert!(matches!(err, SelectionError::CannotResolve(_)));
+}
+
 #[tokio::test]
 #[serial]
 async fn add_title_zotero_miss_openalex_hit() {
@@ -399,7 +483,7 @@ async fn add_title_zotero_miss_openalex_hit() {
         .await;
 
     let client = make_oa_client(&mock);
-    let entry = resolve_paper("My Unique Title", &client, None).await.unwrap();
+    let entry = resolve_paper("My Unique Title", &client, None, None).await.unwrap();
     assert_eq!(entry.openalex_id.as_deref(), Some("W999"));
     assert_eq!(entry.title.as_deref(), Some("My Unique Title"));
     assert!(entry.zotero_key.is_none());
@@ -418,10 +502,59 @@ async fn add_title_zotero_miss_openalex_miss() {
         .await;
 
     let client = make_oa_client(&mock);
-    let err = resolve_paper("xyzzy completely unknown title xyz", &client, None).await.unwrap_err();
+    let err = resolve_paper("xyzzy completely unknown title xyz", &client, None, None).await.unwrap_err();
     assert!(matches!(err, SelectionError::CannotResolve(_)));
 }
 
+#[tokio::test]
+async fn candidates_list_title_matches_and_skip_ids() {
+    let mock = MockServer::start().await;
+    let first = work_json("W1", Some("10.1234/a"), "Neural Fields", &["Ann Lee", "Bo Chen"], 2021);
+    let second = work_json("W2", None, "Neural Fields Revisited", &["Cy Park"], 2023);
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("search", "neural fields"))
+        .and(query_param("per-page", "5"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(format!(
+            r#"{{"meta":{{"count":2,"db_response_time_ms":5,"page":1,"per_page":5}},"results":[{first},{second}]}}"#
+        )))
+        .expect(1)
+        .mount(&mock)
+        .await;
+    let client = make_oa_client(&mock);
+
+    let candidates = paper_candidates("neural fields", &client, None, DEFAULT_CANDIDATE_LIMIT).await;
+    let ids: Vec<_> = candidates.iter().map(|c| c.openalex_id.as_deref().unwrap()).collect();
+    assert_eq!(ids, vec!["W1", "W2"]);
+    assert_eq!(candidate_label(&candidates[0]), "Neural Fields — Ann Lee et al. (2021)");
+    assert_eq!(candidate_label(&candidates[1]), "Neural Fields Revisited — Cy Park (2023)");
+
+    assert!(paper_candidates("10.1234/a", &client, None, 5).await.is_empty());
+    assert!(paper_candidates("W2741809807", &client, None, 5).await.is_empty());
+}
+
+#[tokio::test]
+async fn resolve_candidate_rebuilds_entry_from_doi() {
+    let mock = MockServer::start().await;
+    let work = work_json("W7", Some("10.1234/seven"), "Seven", &["Dee"], 2019);
+    Mock::given(method("GET"))
+        .and(path("/works/doi:10.1234/seven"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(&work))
+        .mount(&mock)
+        .await;
+    let client = make_oa_client(&mock);
+
+    let mut candidate = titled("Seven");
+    candidate.doi = Some("10.1234/seven".into());
+    let entry = resolve_candidate(candidate, &client, None).await;
+    assert_eq!(entry.openalex_id.as_deref(), Some("W7"));
+    assert!(entry.issn.is_some());
+
+    let untraceable = titled("No identifiers");
+    let entry = resolve_candidate(untraceable, &client, None).await;
+    assert_eq!(entry.title.as_deref(), Some("No identifiers"));
+}
+
 #[tokio::test]
 #[serial]
 async fn add_populates_all_fields() {
@@ -436,7 +569,7 @@ async fn add_populates_all_fields() {
         .await;
 
     let client = make_oa_client(&mock);
-    let entry = resolve_paper("W100", &client, None).await.unwrap();
+    let entry = resolve_paper("W100", &client, None, None).await.unwrap();
     assert_eq!(entry.openalex_id.as_deref(), Some("W100"));
     assert_eq!(entry.doi.as_deref(), Some("10.100/foo"));
     assert_eq!(entry.title.as_deref(), Some("Full Fields Paper"));
@@ -458,7 +591,7 @@ async fn add_issn_from_journal() {
         .await;
 
     let client = make_oa_client(&mock);
-    let entry = resolve_paper("W200", &client, None).await.unwrap();
+    let entry = resolve_paper("W200", &client, None, None).await.unwrap();
     // The mock work has ISSN 0028-0836 in primary_location.source.issn
     assert!(entry.issn.is_some());
     let issns = entry.issn.unwrap();
@@ -482,13 +615,13 @@ async fn add_duplicate_by_doi_noop() {
     let client = make_oa_client(&mock);
     save_selection(&Selection { name: "s".into(), entries: vec![] }).unwrap();
 
-    let entry1 = resolve_paper("10.300/dup", &client, None).await.unwrap();
+    let entry1 = resolve_paper("10.300/dup", &client, None, None).await.unwrap();
     let mut sel = load_selection("s").unwrap();
     sel.entries.push(entry1.clone());
     save_selection(&sel).unwrap();
 
     // Second add — same DOI
-    let entry2 = resolve_paper("10.300/dup", &client, None).await.unwrap();
+    let entry2 = resolve_paper("10.300/dup", &client, None, None).await.unwrap();
     let mut sel = load_selection("s").unwrap();
     let is_dup = sel.entries.iter().any(|e| {
         entry2.doi.as_deref().map(|d| entry_matches_doi(e, d)).unwrap_or(false)
@@ -518,12 +651,12 @@ async fn add_duplicate_by_openalex_id_noop() {
     let client = make_oa_client(&mock);
     save_selection(&Selection { name: "t".into(), entries: vec![] }).unwrap();
 
-    let entry1 = resolve_paper("W400", &client, None).await.unwrap();
+    let entry1 = resolve_paper("W400", &client, None, None).await.unwrap();
     let mut sel = load_selection("t").unwrap();
     sel.entries.push(entry1.clone());
     save_selection(&sel).unwrap();
 
-    let entry2 = resolve_paper("W400", &client, None).await.unwrap();
+    let entry2 = resolve_paper("W400", &client, None, None).await.unwrap();
     let mut sel = load_selection("t").unwrap();
     let is_dup = sel.entries.iter().any(|e| {
         entry2.openalex_id.as_deref().map(|id| entry_matches_openalex(e, id)).unwrap_or(false)
@@ -536,6 +669,40 @@ async fn add_duplicate_by_openalex_id_noop() {
     assert_eq!(load_selection("t").unwrap().entries.len(), 1);
 }
 
+#[tokio::test]
+#[serial]
+async fn add_papers_bulk_skips_duplicates_and_reports_unresolved() {
+    let (_dir, _) = isolated_dir();
+    let mock = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/works/doi:10.500/a"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(work_json("W500", Some("10.500/a"), "Paper A", &["Auth"], 2020)))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/works/W501"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(work_json("W501", None, "Paper B", &["Auth"], 2021)))
+        .mount(&mock)
+        .await;
+
+    let client = make_oa_client(&mock);
+    let mut sel = Selection { name: "bulk".into(), entries: vec![] };
+    let inputs: Vec<String> = ["10.500/a", "W501", "https://doi.org/10.500/a", "10.404/missing", " "]
+        .map(String::from)
+        .to_vec();
+    let summary = add_papers(&mut sel, &inputs, &client, None, None).await;
+
+    assert_eq!(sel.entries.len(), 2);
+    assert_eq!(summary.selection, "bulk");
+    assert_eq!(summary.matched, 4);
+    assert_eq!(summary.fetched, 3);
+    assert_eq!(summary.added.len(), 2);
+    assert!(summary.added[0].starts_with("Paper A"));
+    assert_eq!(summary.duplicates.len(), 1);
+    assert_eq!(summary.unresolved, vec!["10.404/missing".to_string()]);
+}
+
 // ── validate_name ──────────────────────────────────────────────────────────
 
 #[test]
@@ -648,6 +815,8 @@ fn entry_matches_doi_normalized() {
         issn: None,
         isbn: None,
         work_type: None,
+        note: None,
+        tags: Vec::new(),
     };
     assert!(entry_matches_doi(&entry, "10.1234/foo"));
     assert!(entry_matches_doi(&entry, "https://doi.org/10.1234/foo"));
@@ -670,6 +839,8 @@ fn entry_matches_remove_title_case_insensitive() {
         issn: None,
         isbn: None,
         work_type: None,
+        note: None,
+        tags: Vec::new(),
     };
     assert!(entry_matches_remove_input(&entry, "attention"));
     assert!(entry_matches_remove_input(&entry, "ATTENTION IS ALL"));
@@ -688,6 +859,8 @@ fn entry_matches_remove_oa_full_url() {
         issn: None,
         isbn: None,
         work_type: None,
+        note: None,
+        tags: Vec::new(),
     };
     assert!(entry_matches_remove_input(&entry, "https://openalex.org/W99999"));
     assert!(entry_matches_remove_input(&entry, "W99999"));
@@ -709,6 +882,8 @@ fn save_load_roundtrip_all_fields() {
         issn: Some(vec!["0028-0836".into()]),
         isbn: Some(vec!["978-3-16-148410-0".into()]),
         work_type: None,
+        note: None,
+        tags: Vec::new(),
     };
     let sel = Selection { name: "roundtrip".into(), entries: vec![entry] };
     save_selection(&sel).unwrap();
@@ -741,7 +916,7 @@ async fn add_doi_https_prefix_resolves() {
         .await;
 
     let client = make_oa_client(&mock);
-    let entry = resolve_paper("https://doi.org/10.500/test", &client, None).await.unwrap();
+    let entry = resolve_paper("https://doi.org/10.500/test", &client, None, None).await.unwrap();
     assert_eq!(entry.doi.as_deref(), Some("10.500/test"));
     assert_eq!(entry.openalex_id.as_deref(), Some("W500"));
 }
@@ -760,7 +935,7 @@ async fn add_doi_colon_prefix_resolves() {
         .await;
 
     let client = make_oa_client(&mock);
-    let entry = resolve_paper("doi:10.501/test", &client, None).await.unwrap();
+    let entry = resolve_paper("doi:10.501/test", &client, None, None).await.unwrap();
     assert_eq!(entry.doi.as_deref(), Some("10.501/test"));
     assert_eq!(entry.openalex_id.as_deref(), Some("W501"));
 }
@@ -779,7 +954,7 @@ async fn add_oa_full_url_resolves() {
         .await;
 
     let client = make_oa_client(&mock);
-    let entry = resolve_paper("https://openalex.org/W600", &client, None).await.unwrap();
+    let entry = resolve_paper("https://openalex.org/W600", &client, None, None).await.unwrap();
     assert_eq!(entry.openalex_id.as_deref(), Some("W600"));
     assert_eq!(entry.title.as_deref(), Some("Full URL Paper"));
 }
@@ -800,6 +975,8 @@ fn remove_by_zotero_key() {
         issn: None,
         isbn: None,
         work_type: None,
+        note: None,
+        tags: Vec::new(),
     };
     let mut sel = Selection { name: "r".into(), entries: vec![entry] };
     save_selection(&sel).unwrap();
@@ -824,6 +1001,8 @@ fn remove_by_doi() {
         issn: None,
         isbn: None,
         work_type: None,
+        note: None,
+        tags: Vec::new(),
     };
     let mut sel = Selection { name: "s".into(), entries: vec![entry] };
     save_selection(&sel).unwrap();
@@ -848,6 +1027,8 @@ fn remove_by_openalex_id() {
         issn: None,
         isbn: None,
         work_type: None,
+        note: None,
+        tags: Vec::new(),
     };
     let mut sel = Selection { name: "u".into(), entries: vec![entry] };
     save_selection(&sel).unwrap();
@@ -872,6 +1053,8 @@ fn remove_nonexistent_item() {
         issn: None,
         isbn: None,
         work_type: None,
+        note: None,
+        tags: Vec::new(),
     };
     let sel = Selection { name: "v".into(), entries: vec![entry] };
     save_selection(&sel).unwrap();
@@ -898,11 +1081,13 @@ fn remove_from_explicit_selection() {
         issn: None,
         isbn: None,
         work_type: None,
+        note: None,
+        tags: Vec::new(),
     };
     // Create two selections; only "other" has the entry
     save_selection(&Selection { name: "active".into(), entries: vec![] }).unwrap();
     save_selection(&Selection { name: "other".into(), entries: vec![entry] }).unwrap();
-    save_state(&SelectionState { active: Some("active".into()) }).unwrap();
+    save_state(&SelectionState { active: Some("active".into()), ..Default::default() }).unwrap();
 
     // Remove from "other" explicitly
     let name = resolve_selection("other").unwrap();
@@ -929,6 +1114,8 @@ fn remove_by_title_substring_partial() {
         issn: None,
         isbn: None,
         work_type: None,
+        note: None,
+        tags: Vec::new(),
     };
     let entry2 = SelectionEntry {
         zotero_key: None,
@@ -940,6 +1127,8 @@ fn remove_by_title_substring_partial() {
         issn: None,
         isbn: None,
         work_type: None,
+        note: None,
+        tags: Vec::new(),
     };
     let mut sel = Selection { name: "multi".into(), entries: vec![entry1, entry2] };
     save_selection(&sel).unwrap();
@@ -985,6 +1174,8 @@ fn fill_from_oa_work_captures_type() {
         issn: None,
         isbn: None,
         work_type: None,
+        note: None,
+        tags: Vec::new(),
     };
     fill_from_oa_work(&mut entry, &work);
     assert_eq!(entry.work_type.as_deref(), Some("preprint"));
@@ -1004,6 +1195,8 @@ fn fill_from_oa_work_prefers_crossref_type() {
         issn: None,
         isbn: None,
         work_type: None,
+        note: None,
+        tags: Vec::new(),
     };
     fill_from_oa_work(&mut entry, &work);
     assert_eq!(entry.work_type.as_deref(), Some("proceedings-article"));
@@ -1022,6 +1215,8 @@ fn fill_from_oa_work_does_not_overwrite_existing_work_type() {
         issn: None,
         isbn: None,
         work_type: Some("preprint".into()), // already set
+        note: None,
+        tags: Vec::new(),
     };
     fill_from_oa_work(&mut entry, &work);
     // Should not overwrite existing value
@@ -1081,6 +1276,8 @@ fn roundtrip_with_work_type() {
         issn: None,
         isbn: None,
         work_type: Some("proceedings-article".into()),
+        note: None,
+        tags: Vec::new(),
     };
     let sel = Selection { name: "type-test".into(), entries: vec![entry] };
     save_selection(&sel).unwrap();
@@ -1106,6 +1303,8 @@ fn roundtrip_work_type_none_omitted_from_json() {
         issn: None,
         isbn: None,
         work_type: None,
+        note: None,
+        tags: Vec::new(),
     };
     let sel = Selection { name: "no-type".into(), entries: vec![entry] };
     save_selection(&sel).unwrap();
@@ -1134,16 +1333,18 @@ fn rename_changes_file_and_updates_state() {
         issn: None,
         isbn: None,
         work_type: None,
+        note: None,
+        tags: Vec::new(),
     };
     save_selection(&Selection { name: "old-name".into(), entries: vec![entry] }).unwrap();
-    save_state(&SelectionState { active: Some("old-name".into()) }).unwrap();
+    save_state(&SelectionState { active: Some("old-name".into()), ..Default::default() }).unwrap();
 
     // Perform rename: save under new name, copy entries, delete old, update state
     let old_sel = load_selection("old-name").unwrap();
     let new_sel = Selection { name: "new-name".into(), entries: old_sel.entries };
     save_selection(&new_sel).unwrap();
     delete_selection("old-name").unwrap();
-    save_state(&SelectionState { active: Some("new-name".into()) }).unwrap();
+    save_state(&SelectionState { active: Some("new-name".into()), ..Default::default() }).unwrap();
 
     let old_file = data_path.join("papers/selections/old-name.json");
     let new_file = data_path.join("papers/selections/new-name.json");
@@ -1192,6 +1393,8 @@ fn merge_adds_new_entries() {
         issn: None,
         isbn: None,
         work_type: None,
+        note: None,
+        tags: Vec::new(),
     };
     let e2 = SelectionEntry {
         zotero_key: None,
@@ -1203,6 +1406,8 @@ fn merge_adds_new_entries() {
         issn: None,
         isbn: None,
         work_type: None,
+        note: None,
+        tags: Vec::new(),
     };
 
     let mut target = Selection { name: "target".into(), entries: vec![e1.clone()] };
@@ -1240,6 +1445,8 @@ fn merge_deduplicates_by_openalex_id() {
         issn: None,
         isbn: None,
         work_type: None,
+        note: None,
+        tags: Vec::new(),
     };
     let mut target = Selection { name: "t".into(), entries: vec![e.clone()] };
     let source = Selection { name: "s".into(), entries: vec![e.clone()] };
@@ -1273,6 +1480,8 @@ fn merge_deduplicates_by_doi() {
         issn: None,
         isbn: None,
         work_type: None,
+        note: None,
+        tags: Vec::new(),
     };
     let mut target = Selection { name: "td".into(), entries: vec![e.clone()] };
     let source = Selection { name: "sd".into(), entries: vec![e.clone()] };
@@ -1306,6 +1515,8 @@ fn merge_preserves_source_selection() {
         issn: None,
         isbn: None,
         work_type: None,
+        note: None,
+        tags: Vec::new(),
     };
     let mut target = Selection { name: "main".into(), entries: vec![] };
     let source = Selection { name: "side".into(), entries: vec![e.clone()] };
@@ -1330,3 +1541,266 @@ fn merge_nonexistent_source_errors() {
     let err = load_selection("nonexistent-source").unwrap_err();
     assert!(matches!(err, SelectionError::NotFound(_)));
 }
+
+// ── notes & tags ──────────────────────────────────────────────────────────
+
+fn titled(title: &str) -> SelectionEntry {
+    SelectionEntry {
+        zotero_key: None,
+        openalex_id: None,
+        doi: None,
+        title: Some(title.into()),
+        authors: None,
+        year: None,
+        issn: None,
+        isbn: None,
+        work_type: None,
+        note: None,
+        tags: Vec::new(),
+    }
+}
+
+#[test]
+#[serial]
+fn annotate_sets_and_clears_note() {
+    let (_dir, path) = isolated_dir();
+    let entries = vec![titled("Neural Radiance Fields"), titled("Gaussian Splatting")];
+    save_selection(&Selection { name: "reading".into(), entries }).unwrap();
+
+    let entry = annotate_entry("reading", "splatting", Some("  baseline for section 4 ")).unwrap();
+    assert_eq!(entry.note.as_deref(), Some("baseline for section 4"));
+    assert_eq!(load_selection("reading").unwrap().entries[1].note.as_deref(), Some("baseline for section 4"));
+    let raw = std::fs::read_to_string(path.join("papers/selections/reading.json")).unwrap();
+    assert!(raw.contains("\"note\""));
+
+    annotate_entry("reading", "2", None).unwrap();
+    let sel = load_selection("reading").unwrap();
+    assert!(sel.entries[1].note.is_none());
+    assert!(matches!(annotate_entry("reading", "3", Some("x")), Err(SelectionError::ItemNotFound)));
+}
+
+#[test]
+#[serial]
+fn tag_adds_removes_and_ignores_case() {
+    let (_dir, _) = isolated_dir();
+    save_selection(&Selection { name: "reading".into(), entries: vec![titled("Paper One")] }).unwrap();
+
+    let entry = tag_entry("reading", "1", &["Baseline".into(), "baseline".into(), " ".into(), "nerf".into()], &[])
+        .unwrap();
+    assert_eq!(entry.tags, vec!["Baseline", "nerf"]);
+    let entry = tag_entry("reading", "Paper One", &["survey".into()], &["BASELINE".into()]).unwrap();
+    assert_eq!(entry.tags, vec!["nerf", "survey"]);
+    assert_eq!(load_selection("reading").unwrap().entries[0].tags, vec!["nerf", "survey"]);
+}
+
+#[test]
+fn old_entries_without_note_or_tags_still_load() {
+    let entry: SelectionEntry = serde_json::from_str(r#"{"title": "Old", "zotero_key": null, "openalex_id": null, "doi": null, "authors": null, "year": null, "issn": null, "isbn": null}"#).unwrap();
+    assert!(entry.note.is_none());
+    assert!(entry.tags.is_empty());
+    let json = serde_json::to_string(&entry).unwrap();
+    assert!(!json.contains("note") && !json.contains("tags"));
+}
+
+#[test]
+fn filter_and_group_by_tag() {
+    let mut a = titled("A");
+    a.tags = vec!["baseline".into(), "NeRF".into()];
+    let mut b = titled("B");
+    b.tags = vec!["nerf".into()];
+    let c = titled("C");
+    let sel = Selection { name: "s".into(), entries: vec![a, b, c] };
+
+    let nerf: Vec<_> = entries_with_tag(&sel, "NERF").iter().map(|e| e.index).collect();
+    assert_eq!(nerf, vec![1, 2]);
+
+    let groups = group_by_tag(&sel);
+    let shape: Vec<_> = groups
+        .iter()
+        .map(|g| (g.tag.clone(), g.entries.iter().map(|e| e.index).collect::<Vec<_>>()))
+        .collect();
+    assert_eq!(
+        shape,
+        vec![
+            (Some("baseline".to_string()), vec![1]),
+            (Some("NeRF".to_string()), vec![1, 2]),
+            (None, vec![3]),
+        ]
+    );
+    let json = serde_json::to_value(&groups[2]).unwrap();
+    assert_eq!(json["entries"][0]["index"], 3);
+    assert_eq!(json["entries"][0]["title"], "C");
+}
+
+// ── bulk add from a query ──────────────────────────────────────────────────
+
+fn page_response(results: &[String], count: i64, next_cursor: Option<&str>) -> String {
+    let cursor = next_cursor.map_or("null".into(), |c| format!("\"{c}\""));
+    format!(
+        r#"{{"meta":{{"count":{count},"db_response_time_ms":5,"page":null,"per_page":200,"next_cursor":{cursor},"groups_count":null}},"results":[{}],"group_by":[]}}"#,
+        results.join(",")
+    )
+}
+
+#[tokio::test]
+#[serial]
+async fn add_query_pages_with_cursor_and_skips_duplicates() {
+    let mock = MockServer::start().await;
+    let client = make_oa_client(&mock);
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("cursor", "*"))
+        .and(query_param("filter", "publication_year:2024"))
+        .and(query_param("sort", "cited_by_count:desc"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(page_response(
+            &[
+                work_json("W1", Some("10.1/one"), "Paper One", &["Ada"], 2024),
+                work_json("W2", Some("10.1/two"), "Paper Two", &["Bob"], 2024),
+            ],
+            40,
+            Some("next"),
+        )))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("cursor", "next"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(page_response(
+            &[
+                // Same DOI as a work on the first page under another OpenAlex ID.
+                work_json("W9", Some("10.1/ONE"), "Paper One (duplicate record)", &["Ada"], 2024),
+                work_json("W3", None, "Paper Three", &["Cy"], 2024),
+            ],
+            40,
+            Some("after"),
+        )))
+        .mount(&mock)
+        .await;
+
+    let mut existing = titled("Paper Two");
+    existing.openalex_id = Some("W2".into());
+    let mut sel = Selection { name: "review".into(), entries: vec![existing] };
+    let params = papers_core::WorkListParams {
+        filter: Some("publication_year:2024".into()),
+        sort: Some("cited_by_count:desc".into()),
+        ..Default::default()
+    };
+    let summary = papers_core::api::selection_add_query(&client, &mut sel, &params, 4).await.unwrap();
+
+    assert_eq!(summary.selection, "review");
+    assert_eq!((summary.matched, summary.fetched), (40, 4));
+    assert_eq!(summary.added.len(), 2);
+    assert!(summary.added[0].starts_with("Paper One"));
+    assert!(summary.added[1].starts_with("Paper Three"));
+    assert_eq!(summary.duplicates.len(), 2);
+    let ids: Vec<_> = sel.entries.iter().map(|e| e.openalex_id.as_deref().unwrap()).collect();
+    assert_eq!(ids, ["W2", "W1", "W3"]);
+    assert_eq!(sel.entries[1].doi.as_deref(), Some("10.1/one"));
+    assert_eq!(sel.entries[1].year, Some(2024));
+}
+
+#[tokio::test]
+#[serial]
+async fn add_references_filters_cited_works_and_skips_duplicates() {
+    let mock = MockServer::start().await;
+    let client = make_oa_client(&mock);
+    Mock::given(method("GET"))
+        .and(path("/works/W100"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "https://openalex.org/W100",
+            "referenced_works": [
+                "https://openalex.org/W3",
+                "https://openalex.org/W1",
+                "https://openalex.org/W2",
+                "https://openalex.org/W1",
+            ],
+        })))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("filter", "publication_year:2020,ids.openalex:W1|W2|W3"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(page_response(
+            &[
+                work_json("W1", Some("10.1/one"), "Paper One", &["Ada"], 2020),
+                work_json("W2", None, "Paper Two", &["Bob"], 2020),
+            ],
+            2,
+            None,
+        )))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mut existing = titled("Paper Two");
+    existing.openalex_id = Some("W2".into());
+    let mut sel = Selection { name: "review".into(), entries: vec![existing] };
+    let params = papers_core::WorkListParams { filter: Some("publication_year:2020".into()), ..Default::default() };
+    let result =
+        papers_core::api::selection_add_references(&client, &mut sel, "W100", &params).await.unwrap();
+
+    assert_eq!(result.root, "W100");
+    assert_eq!(result.references, 3);
+    assert_eq!((result.summary.matched, result.summary.fetched), (2, 2));
+    assert_eq!(result.summary.added.len(), 1);
+    assert!(result.summary.added[0].starts_with("Paper One"));
+    assert_eq!(result.summary.duplicates.len(), 1);
+    let ids: Vec<_> = sel.entries.iter().map(|e| e.openalex_id.as_deref().unwrap()).collect();
+    assert_eq!(ids, ["W2", "W1"]);
+}
+
+#[tokio::test]
+#[serial]
+async fn add_citers_caps_by_limit_and_filters_by_citations() {
+    let mock = MockServer::start().await;
+    let client = make_oa_client(&mock);
+    Mock::given(method("GET"))
+        .and(path("/works/W100"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "https://openalex.org/W100",
+            "cited_by_count": 812,
+        })))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("filter", "type:article,cites:W100,cited_by_count:>9"))
+        .and(query_param("sort", "cited_by_count:desc"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(page_response(
+            &[
+                work_json("W1", Some("10.1/one"), "Paper One", &["Ada"], 2021),
+                work_json("W2", None, "Paper Two", &["Bob"], 2022),
+            ],
+            37,
+            Some("next"),
+        )))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mut existing = titled("Paper One");
+    existing.doi = Some("10.1/one".into());
+    let mut sel = Selection { name: "review".into(), entries: vec![existing] };
+    let params = papers_core::WorkListParams { filter: Some("type:article".into()), ..Default::default() };
+    let result =
+        papers_core::api::selection_add_citers(&client, &mut sel, "W100", &params, Some(10), 2).await.unwrap();
+
+    assert_eq!(result.root, "W100");
+    assert_eq!(result.cited_by_count, Some(812));
+    assert_eq!((result.summary.matched, result.summary.fetched), (37, 2));
+    assert_eq!(result.summary.duplicates.len(), 1);
+    assert_eq!(result.summary.added.len(), 1);
+    assert_eq!(sel.entries[1].openalex_id.as_deref(), Some("W2"));
+}
+
+#[test]
+fn contains_entry_matches_key_openalex_id_or_doi() {
+    let mut existing = titled("Paper");
+    existing.doi = Some("10.1/ABC".into());
+    let sel = Selection { name: "s".into(), entries: vec![existing] };
+    let mut entry = titled("Other title");
+    entry.doi = Some("https://doi.org/10.1/abc".into());
+    assert!(contains_entry(&sel, &entry));
+    entry.doi = Some("10.1/abd".into());
+    assert!(!contains_entry(&sel, &entry));
+}
@@ -0,0 +1,238 @@
+//! Tests for moving papers between selections and Zotero collections.
+
+use papers_core::selection::{Selection, load_selection, save_selection};
+use papers_core::selection_zotero::{selection_from_collection, sync_selection_to_collection};
+use papers_openalex::OpenAlexClient;
+use papers_zotero::ZoteroClient;
+use serial_test::serial;
+use tempfile::TempDir;
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+// ── Test helpers ──────────────────────────────────────────────────────────────
+
+/// Sets `PAPERS_DATA_DIR` to an isolated temp dir for the duration of the
+/// returned `TempDir`. The caller must keep the `TempDir` alive.
+fn isolated_dir() -> TempDir {
+    let dir = TempDir::new().expect("tempdir");
+    // SAFETY: serial test; no other threads read the environment.
+    unsafe { std::env::set_var("PAPERS_DATA_DIR", dir.path()) };
+    dir
+}
+
+fn make_zotero_client(mock: &MockServer) -> ZoteroClient {
+    ZoteroClient::new("test", "test-key").with_base_url(mock.uri())
+}
+
+fn array_response(items: &[String]) -> ResponseTemplate {
+    ResponseTemplate::new(200)
+        .insert_header("Total-Results", items.len().to_string().as_str())
+        .insert_header("Last-Modified-Version", "100")
+        .set_body_string(format!("[{}]", items.join(",")))
+}
+
+fn item(key: &str, item_type: &str, title: &str, doi: &str, collections: &[&str]) -> String {
+    serde_json::json!({
+        "key": key,
+        "version": 7,
+        "library": {"type": "user", "id": 1, "name": "test", "links": {}},
+        "links": {},
+        "meta": {},
+        "data": {
+            "key": key,
+            "version": 7,
+            "itemType": item_type,
+            "title": title,
+            "DOI": doi,
+            "creators": [{"creatorType": "author", "firstName": "Ada", "lastName": "Lovelace"}],
+            "date": "2021-05-01",
+            "tags": [],
+            "collections": collections,
+        },
+    })
+    .to_string()
+}
+
+fn collection(key: &str, name: &str) -> String {
+    serde_json::json!({
+        "key": key,
+        "version": 1,
+        "library": {"type": "user", "id": 1, "name": "test", "links": {}},
+        "links": {},
+        "meta": {"numCollections": 0, "numItems": 2},
+        "data": {"key": key, "version": 1, "name": name, "parentCollection": false, "relations": {}},
+    })
+    .to_string()
+}
+
+fn entry(zotero_key: Option<&str>, openalex_id: Option<&str>, title: &str) -> papers_core::selection::SelectionEntry {
+    serde_json::from_value(serde_json::json!({
+        "zotero_key": zotero_key,
+        "openalex_id": openalex_id,
+        "doi": null,
+        "title": title,
+        "authors": null,
+        "year": null,
+        "issn": null,
+        "isbn": null,
+    }))
+    .unwrap()
+}
+
+// ── sync_selection_to_collection ──────────────────────────────────────────────
+
+#[tokio::test]
+#[serial]
+async fn sync_creates_missing_items_and_files_existing_ones() {
+    let _dir = isolated_dir();
+    let mock = MockServer::start().await;
+    save_selection(&Selection {
+        name: "thesis".into(),
+        entries: vec![
+            entry(Some("INCOLL01"), None, "Already filed"),
+            entry(Some("NOTCOLL1"), None, "Not filed yet"),
+            entry(None, Some("W1"), "A New Paper"),
+            entry(None, None, "Orphan"),
+        ],
+    })
+    .unwrap();
+
+    // "thesis" matches the existing "Thesis" collection.
+    Mock::given(method("GET"))
+        .and(path("/users/test/collections"))
+        .respond_with(array_response(&[collection("COLTHES1", "Thesis")]))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/works/W1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "https://openalex.org/W1",
+            "doi": "https://doi.org/10.1234/new",
+            "display_name": "A New Paper",
+            "publication_year": 2024,
+            "type": "article",
+            "type_crossref": "journal-article",
+        })))
+        .mount(&mock)
+        .await;
+    // Duplicate check finds nothing.
+    Mock::given(method("GET"))
+        .and(path("/users/test/items/top"))
+        .respond_with(array_response(&[]))
+        .mount(&mock)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/users/test/items"))
+        .and(body_partial_json(serde_json::json!([{"title": "A New Paper", "collections": ["COLTHES1"]}])))
+        .respond_with(ResponseTemplate::new(200).set_body_string(format!(
+            r#"{{"successful": {{"0": {}}}, "unchanged": {{}}, "failed": {{}}}}"#,
+            item("NEWITEM1", "journalArticle", "A New Paper", "10.1234/new", &["COLTHES1"])
+        )))
+        .expect(1)
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/users/test/items/INCOLL01"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(item("INCOLL01", "journalArticle", "Already filed", "", &["COLTHES1"])))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/users/test/items/NOTCOLL1"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(item("NOTCOLL1", "journalArticle", "Not filed yet", "", &["OTHER001"])))
+        .mount(&mock)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path("/users/test/items/NOTCOLL1"))
+        .and(body_partial_json(serde_json::json!({"collections": ["OTHER001", "COLTHES1"]})))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let openalex = OpenAlexClient::new().with_base_url(mock.uri());
+    let report = sync_selection_to_collection(&openalex, &make_zotero_client(&mock), "thesis", None).await.unwrap();
+    assert_eq!(report.collection_key, "COLTHES1");
+    assert_eq!(report.collection_name, "Thesis");
+    assert!(!report.created_collection);
+    assert_eq!(report.created_items, ["A New Paper"]);
+    assert_eq!(report.added, ["Not filed yet [in Zotero]"]);
+    assert_eq!(report.already_in_collection, 1);
+    assert_eq!(report.failed.len(), 1);
+    assert_eq!(report.failed[0].entry, "Orphan");
+
+    let saved = load_selection("thesis").unwrap();
+    assert_eq!(saved.entries[2].zotero_key.as_deref(), Some("NEWITEM1"));
+}
+
+#[tokio::test]
+#[serial]
+async fn sync_creates_a_missing_collection() {
+    let _dir = isolated_dir();
+    let mock = MockServer::start().await;
+    save_selection(&Selection { name: "empty".into(), entries: Vec::new() }).unwrap();
+    Mock::given(method("GET"))
+        .and(path("/users/test/collections"))
+        .respond_with(array_response(&[collection("COLOTHER", "Other")]))
+        .mount(&mock)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/users/test/collections"))
+        .and(body_partial_json(serde_json::json!([{"name": "Reading Group"}])))
+        .respond_with(ResponseTemplate::new(200).set_body_string(format!(
+            r#"{{"successful": {{"0": {}}}, "unchanged": {{}}, "failed": {{}}}}"#,
+            collection("COLNEW01", "Reading Group")
+        )))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let openalex = OpenAlexClient::new().with_base_url(mock.uri());
+    let report = sync_selection_to_collection(&openalex, &make_zotero_client(&mock), "empty", Some("Reading Group"))
+        .await
+        .unwrap();
+    assert!(report.created_collection);
+    assert_eq!(report.collection_key, "COLNEW01");
+}
+
+// ── selection_from_collection ─────────────────────────────────────────────────
+
+#[tokio::test]
+#[serial]
+async fn import_creates_selection_and_skips_known_items() {
+    let _dir = isolated_dir();
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/test/collections/COL12345"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(collection("COL12345", "GPU Papers")))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/users/test/collections/COL12345/items/top"))
+        .respond_with(array_response(&[
+            item("ITEMAAAA", "journalArticle", "Ray Tracing on GPUs", "10.1/rt", &["COL12345"]),
+            item("ITEMBBBB", "conferencePaper", "Mesh Shaders", "", &["COL12345"]),
+            item("NOTE0001", "note", "", "", &["COL12345"]),
+        ]))
+        .mount(&mock)
+        .await;
+
+    let zotero = make_zotero_client(&mock);
+    let import = selection_from_collection(&zotero, "COL12345", None).await.unwrap();
+    assert!(import.created_selection);
+    assert_eq!(import.collection_name, "GPU Papers");
+    assert_eq!(import.summary.selection, "GPU-Papers");
+    assert_eq!((import.summary.matched, import.summary.fetched), (3, 2));
+    assert_eq!(import.summary.added.len(), 2);
+
+    let sel = load_selection("GPU-Papers").unwrap();
+    assert_eq!(sel.entries[0].zotero_key.as_deref(), Some("ITEMAAAA"));
+    assert_eq!(sel.entries[0].doi.as_deref(), Some("10.1/rt"));
+    assert_eq!(sel.entries[0].year, Some(2021));
+    assert_eq!(sel.entries[1].doi, None);
+
+    // Importing again adds nothing.
+    let again = selection_from_collection(&zotero, "COL12345", None).await.unwrap();
+    assert!(!again.created_selection);
+    assert!(again.summary.added.is_empty());
+    assert_eq!(again.summary.duplicates.len(), 2);
+}
@@ -0,0 +1,63 @@
+use papers_core::OpenAlexClient;
+use papers_core::selection::*;
+use papers_core::site::*;
+use tempfile::TempDir;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn entry(oa_id: Option<&str>, title: &str) -> SelectionEntry {
+    SelectionEntry {
+        zotero_key: None,
+        openalex_id: oa_id.map(str::to_string),
+        doi: None,
+        title: Some(title.to_string()),
+        authors: Some(vec!["Grace Hopper".to_string()]),
+        year: Some(1952),
+        issn: None,
+        isbn: None,
+        work_type: None,
+        note: None,
+        tags: Vec::new(),
+    }
+}
+
+#[tokio::test]
+async fn test_site_papers_use_openalex_abstract_and_topics() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/works/W1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "https://openalex.org/W1",
+            "doi": "https://doi.org/10.1234/test",
+            "display_name": "A Great Paper",
+            "publication_year": 2020,
+            "type": "article",
+            "authorships": [{"author": {"display_name": "Alice Smith"}}],
+            "abstract_inverted_index": {"Fields": [0], "are": [1], "neural.": [2]},
+            "topics": [{"id": "https://openalex.org/T1", "display_name": "Rendering"}],
+        })))
+        .mount(&mock)
+        .await;
+    let client = OpenAlexClient::new().with_base_url(mock.uri());
+    let sel = Selection {
+        name: "reading group".to_string(),
+        entries: vec![entry(Some("W1"), "ignored"), entry(None, "Notes")],
+    };
+
+    let papers = site_papers(&sel, &client, None).await;
+    assert_eq!(papers.len(), 2);
+    assert_eq!(papers[0].record.title.as_deref(), Some("A Great Paper"));
+    assert_eq!(papers[0].abstract_text.as_deref(), Some("Fields are neural."));
+    assert_eq!(papers[0].tags, vec!["Rendering"]);
+    assert_eq!(papers[0].db_ids(), vec!["10.1234/test"]);
+    assert_eq!(papers[1].record.title.as_deref(), Some("Notes"));
+    assert!(papers[1].abstract_text.is_none());
+
+    let dir = TempDir::new().unwrap();
+    let summary = write_site(dir.path(), &sel.name, &papers).unwrap();
+    assert_eq!(summary.papers, 2);
+    let index = std::fs::read_to_string(dir.path().join("index.html")).unwrap();
+    assert!(index.contains("<title>reading group</title>"));
+    assert!(dir.path().join("papers/a-great-paper.html").exists());
+    assert!(dir.path().join("papers/notes.html").exists());
+}
@@ -0,0 +1,306 @@
+//! Tests for Zotero change detection used by `db watch` and the local full-text index.
+
+use papers_core::sync::{SyncState, plan_sync};
+use papers_core::zotero_fulltext::{FulltextIndex, search_library};
+use papers_zotero::ZoteroClient;
+use serial_test::serial;
+use tempfile::TempDir;
+use wiremock::matchers::{method, path, query_param, query_param_is_missing};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+// ── Test helpers ──────────────────────────────────────────────────────────────
+
+fn make_zotero_client(mock: &MockServer) -> ZoteroClient {
+    ZoteroClient::new("testuser", "test-api-key").with_base_url(mock.uri())
+}
+
+fn items_response(items: &[String], version: u64) -> ResponseTemplate {
+    ResponseTemplate::new(200)
+        .insert_header("Total-Results", items.len().to_string().as_str())
+        .insert_header("Last-Modified-Version", version.to_string().as_str())
+        .set_body_string(format!("[{}]", items.join(",")))
+}
+
+fn deleted_response(items: &[&str], version: u64) -> ResponseTemplate {
+    ResponseTemplate::new(200)
+        .insert_header("Last-Modified-Version", version.to_string().as_str())
+        .set_body_json(serde_json::json!({
+            "collections": [], "searches": [], "items": items, "tags": [], "settings": [],
+        }))
+}
+
+fn item(key: &str, item_type: &str, parent: Option<&str>, content_type: Option<&str>, collections: &[&str]) -> String {
+    let mut data = serde_json::json!({
+        "key": key,
+        "version": 1,
+        "itemType": item_type,
+        "title": format!("Item {key}"),
+        "creators": [],
+        "tags": [],
+        "collections": collections,
+        "dateAdded": "2024-01-01T00:00:00Z",
+        "dateModified": "2024-01-01T00:00:00Z",
+    });
+    if let Some(parent) = parent {
+        data["parentItem"] = parent.into();
+        data["linkMode"] = "imported_file".into();
+    }
+    if let Some(content_type) = content_type {
+        data["contentType"] = content_type.into();
+    }
+    serde_json::json!({
+        "key": key,
+        "version": 1,
+        "library": {"type": "user", "id": 1, "name": "testuser", "links": {}},
+        "links": {},
+        "meta": {},
+        "data": data,
+    })
+    .to_string()
+}
+
+fn state(version: u64) -> SyncState {
+    SyncState { library_version: version, ..Default::default() }
+}
+
+async fn mount_changes(mock: &MockServer, items: &[String], deleted: &[&str], version: u64) {
+    Mock::given(method("GET"))
+        .and(path("/users/testuser/items"))
+        .and(query_param("since", "100"))
+        .respond_with(items_response(items, version))
+        .mount(mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/users/testuser/deleted"))
+        .and(query_param("since", "100"))
+        .respond_with(deleted_response(deleted, version))
+        .mount(mock)
+        .await;
+}
+
+// ── plan_sync ─────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn first_poll_only_checkpoints() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/testuser/items"))
+        .respond_with(items_response(&[item("AAAA1111", "journalArticle", None, None, &[])], 120))
+        .mount(&mock)
+        .await;
+
+    let plan = plan_sync(&make_zotero_client(&mock), &state(0), &[]).await.unwrap();
+    assert!(plan.ingest.is_empty());
+    assert!(plan.remove.is_empty());
+    assert_eq!(plan.library_version, 120);
+}
+
+#[tokio::test]
+async fn new_pdfs_are_ingested_and_deletions_removed() {
+    let mock = MockServer::start().await;
+    let changed = [
+        item("PDF00001", "attachment", Some("PARENT01"), Some("application/pdf"), &[]),
+        item("EPUB0001", "attachment", Some("PARENT02"), Some("application/epub+zip"), &[]),
+        item("PARENT03", "journalArticle", None, None, &[]),
+        item("PDF00004", "attachment", Some("INDEXED1"), Some("application/pdf"), &[]),
+    ];
+    mount_changes(&mock, &changed, &["GONE0001", "INDEXED1"], 130).await;
+
+    let mut prev = state(100);
+    prev.pending = vec!["RETRY001".to_string()];
+    prev.indexed.insert("INDEXED1".to_string(), "10.1/indexed".to_string());
+
+    let plan = plan_sync(&make_zotero_client(&mock), &prev, &[]).await.unwrap();
+    assert_eq!(plan.ingest, ["RETRY001", "PARENT01"]);
+    assert_eq!(plan.remove, ["GONE0001", "INDEXED1"]);
+    assert_eq!(plan.library_version, 130);
+}
+
+#[tokio::test]
+async fn watched_collections_filter_candidates() {
+    let mock = MockServer::start().await;
+    let changed = [
+        // Filed into the watched collection; its PDF predates the last poll.
+        item("FILED001", "journalArticle", None, None, &["WATCHED1"]),
+        // Changed, but elsewhere.
+        item("OTHER001", "journalArticle", None, None, &["ELSEWHR1"]),
+        // New PDF whose parent didn't change and isn't watched.
+        item("PDF00002", "attachment", Some("UNWATCH1"), Some("application/pdf"), &[]),
+        // Watched, but without a PDF.
+        item("NOPDF001", "book", None, None, &["WATCHED1"]),
+    ];
+    mount_changes(&mock, &changed, &[], 140).await;
+    Mock::given(method("GET"))
+        .and(path("/users/testuser/items/FILED001/children"))
+        .respond_with(items_response(&[item("PDF00001", "attachment", Some("FILED001"), Some("application/pdf"), &[])], 140))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/users/testuser/items/NOPDF001/children"))
+        .respond_with(items_response(&[], 140))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/users/testuser/items/UNWATCH1"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(item("UNWATCH1", "journalArticle", None, None, &["ELSEWHR1"])),
+        )
+        .mount(&mock)
+        .await;
+
+    let plan = plan_sync(&make_zotero_client(&mock), &state(100), &["WATCHED1".to_string()])
+        .await
+        .unwrap();
+    assert_eq!(plan.ingest, ["FILED001"]);
+    assert!(plan.remove.is_empty());
+    assert_eq!(plan.library_version, 140);
+}
+
+// ── SyncState ─────────────────────────────────────────────────────────────────
+
+#[test]
+#[serial]
+fn state_round_trips_and_defaults_when_missing() {
+    let dir = TempDir::new().unwrap();
+    // SAFETY: serial test; no other threads read the environment.
+    unsafe { std::env::set_var("PAPERS_DATA_DIR", dir.path()) };
+
+    assert_eq!(SyncState::load().unwrap(), SyncState::default());
+    let mut saved = state(42);
+    saved.indexed.insert("PARENT01".to_string(), "10.1/x".to_string());
+    saved.pending.push("RETRY001".to_string());
+    saved.save().unwrap();
+    assert_eq!(SyncState::load().unwrap(), saved);
+    assert!(dir.path().join("sync.json").exists());
+
+    unsafe { std::env::remove_var("PAPERS_DATA_DIR") };
+}
+
+#[tokio::test]
+#[serial]
+async fn tenants_keep_their_own_state() {
+    let (shared, tenant) = (TempDir::new().unwrap(), TempDir::new().unwrap());
+    // SAFETY: serial test; no other threads read the environment.
+    unsafe { std::env::set_var("PAPERS_DATA_DIR", shared.path()) };
+
+    papers_core::tenant::scope(tenant.path(), async { state(7).save().unwrap() }).await;
+    assert!(tenant.path().join("sync.json").exists());
+    assert_eq!(SyncState::load().unwrap(), SyncState::default());
+
+    unsafe { std::env::remove_var("PAPERS_DATA_DIR") };
+}
+
+// ── Full-text index ───────────────────────────────────────────────────────────
+
+fn fulltext_response(content: &str) -> ResponseTemplate {
+    ResponseTemplate::new(200)
+        .insert_header("Last-Modified-Version", "1")
+        .set_body_json(serde_json::json!({"content": content, "indexedPages": 1, "totalPages": 1}))
+}
+
+#[tokio::test]
+#[serial]
+async fn fulltext_index_syncs_incrementally() {
+    let dir = TempDir::new().unwrap();
+    // SAFETY: serial test; no other threads read the environment.
+    unsafe { std::env::set_var("PAPERS_DATA_DIR", dir.path()) };
+    let mock = MockServer::start().await;
+
+    // First sync: every attachment with text.
+    Mock::given(method("GET"))
+        .and(path("/users/testuser/fulltext"))
+        .and(query_param_is_missing("since"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Last-Modified-Version", "200")
+                .set_body_json(serde_json::json!({"PDF00001": 150, "EPUB0001": 160, "PDF00002": 170})),
+        )
+        .expect(1)
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/users/testuser/items"))
+        .and(query_param("itemKey", "EPUB0001,PDF00001,PDF00002"))
+        .respond_with(items_response(
+            &[
+                item("PDF00001", "attachment", Some("PARENT01"), Some("application/pdf"), &[]),
+                item("EPUB0001", "attachment", Some("PARENT02"), Some("application/epub+zip"), &[]),
+                item("PDF00002", "attachment", None, Some("application/pdf"), &[]),
+            ],
+            200,
+        ))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/users/testuser/items"))
+        .and(query_param("itemKey", "PARENT01"))
+        .respond_with(items_response(&[item("PARENT01", "journalArticle", None, None, &[])], 200))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/users/testuser/items/PDF00001/fulltext"))
+        .respond_with(fulltext_response("We train a sparse voxel grid of radiance values."))
+        .expect(1)
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/users/testuser/items/PDF00002/fulltext"))
+        .respond_with(fulltext_response("Radiance caching for real-time global illumination."))
+        .up_to_n_times(1)
+        .mount(&mock)
+        .await;
+
+    // Second sync: PDF00002 re-indexed, PDF00001 deleted.
+    Mock::given(method("GET"))
+        .and(path("/users/testuser/fulltext"))
+        .and(query_param("since", "200"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Last-Modified-Version", "210")
+                .set_body_json(serde_json::json!({"PDF00002": 205})),
+        )
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/users/testuser/deleted"))
+        .and(query_param("since", "200"))
+        .respond_with(deleted_response(&["PDF00001"], 210))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/users/testuser/items"))
+        .and(query_param("itemKey", "PDF00002"))
+        .respond_with(items_response(&[item("PDF00002", "attachment", None, Some("application/pdf"), &[])], 210))
+        .mount(&mock)
+        .await;
+
+    let client = make_zotero_client(&mock);
+    let first = search_library(&client, "radiance", 10, false).await.unwrap();
+    let report = first.sync.unwrap();
+    assert_eq!((report.updated, report.indexed, report.library_version), (2, 2, 200));
+    assert_eq!(first.results.len(), 2);
+    let voxel = first.results.iter().find(|hit| hit.attachment_key == "PDF00001").unwrap();
+    assert_eq!(voxel.item_key, "PARENT01");
+    assert_eq!(voxel.title.as_deref(), Some("Item PARENT01"));
+    assert!(voxel.snippets[0].contains("radiance values"));
+
+    // Searching again reads the saved index without syncing.
+    let cached = search_library(&client, "voxel grid", 10, false).await.unwrap();
+    assert!(cached.sync.is_none());
+    assert_eq!(cached.results[0].item_key, "PARENT01");
+
+    Mock::given(method("GET"))
+        .and(path("/users/testuser/items/PDF00002/fulltext"))
+        .respond_with(fulltext_response("Radiance caching, revised."))
+        .mount(&mock)
+        .await;
+    let second = search_library(&client, "radiance", 10, true).await.unwrap();
+    let report = second.sync.unwrap();
+    assert_eq!((report.updated, report.removed, report.indexed, report.library_version), (1, 1, 1, 210));
+    assert_eq!(second.results.len(), 1);
+    assert!(second.results[0].snippets[0].contains("revised"));
+    assert_eq!(FulltextIndex::load("/users/testuser").unwrap().library_version, 210);
+
+    unsafe { std::env::remove_var("PAPERS_DATA_DIR") };
+}
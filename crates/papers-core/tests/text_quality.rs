@@ -0,0 +1,252 @@
+/// Integration tests for scoring local PDF text and escalating poor text to
+/// DataLab or a self-hosted Marker / GROBID server in `extract_local_checked`,
+/// and for keeping downloaded PDFs in the DataLab cache.
+///
+/// The extraction servers are always mocked with wiremock. The DataLab cache is redirected to
+/// a temp dir with `PAPERS_DATALAB_CACHE_DIR`, which is why these tests live
+/// in their own binary.
+use papers_core::text::{self, DATALAB_ESCALATION, LocalBackend, LocalExtractor, PdfSource};
+use papers_datalab::{DatalabClient, JobRecord};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// A one-page PDF whose only text is `text`: a stand-in for a scanned paper
+/// whose text layer is nearly empty.
+fn tiny_pdf(text: &str) -> Vec<u8> {
+    let content = format!("BT /F1 12 Tf 72 720 Td ({text}) Tj ET");
+    let objects = [
+        "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+        "<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_string(),
+        "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] \
+         /Resources << /Font << /F1 5 0 R >> >> /Contents 4 0 R >>"
+            .to_string(),
+        format!("<< /Length {} >>\nstream\n{content}\nendstream", content.len()),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>".to_string(),
+    ];
+    let mut pdf = b"%PDF-1.4\n".to_vec();
+    let mut offsets = Vec::new();
+    for (i, obj) in objects.iter().enumerate() {
+        offsets.push(pdf.len());
+        pdf.extend(format!("{} 0 obj\n{obj}\nendobj\n", i + 1).bytes());
+    }
+    let xref = pdf.len();
+    pdf.extend(format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).bytes());
+    for offset in offsets {
+        pdf.extend(format!("{offset:010} 00000 n \n").bytes());
+    }
+    pdf.extend(
+        format!("trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref}\n%%EOF\n", objects.len() + 1)
+            .bytes(),
+    );
+    pdf
+}
+
+/// The temp DataLab cache shared by every test in this binary (tests run in
+/// parallel, so each one redirecting the cache on its own would race).
+fn use_temp_cache() -> &'static std::path::Path {
+    static CACHE: std::sync::OnceLock<tempfile::TempDir> = std::sync::OnceLock::new();
+    CACHE
+        .get_or_init(|| {
+            let dir = tempfile::tempdir().unwrap();
+            // SAFETY: set once, before any test in this binary reads it.
+            unsafe { std::env::set_var("PAPERS_DATALAB_CACHE_DIR", dir.path()) };
+            dir
+        })
+        .path()
+}
+
+#[tokio::test]
+async fn test_poor_text_without_datalab_is_flagged() {
+    let source = PdfSource::DirectUrl { url: "https://arxiv.org/pdf/1".into() };
+    let (text, report) =
+        text::extract_local_checked(tiny_pdf("Scanned"), "W1", None, &source, None).await.unwrap();
+    assert!(text.contains("Scanned"));
+    let report = report.expect("PDFs are scored");
+    assert_eq!(report.quality.pages, 1);
+    assert_eq!(report.quality.empty_pages, 1);
+    assert!(report.quality.is_poor());
+    assert_eq!(report.escalated_to, None);
+    assert!(report.warning.unwrap().contains("DATALAB_API_KEY"));
+}
+
+#[tokio::test]
+async fn test_poor_text_escalates_to_datalab_balanced() {
+    let cache = use_temp_cache();
+    let mock = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/v1/marker"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "request_id": "req1",
+            "request_check_url": format!("{}/api/v1/marker/req1", mock.uri()),
+        })))
+        .expect(1)
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/marker/req1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "status": "complete",
+            "markdown": "# A Scanned Paper\n\nThe full text, recovered by OCR.",
+        })))
+        .mount(&mock)
+        .await;
+    let datalab = DatalabClient::new("mock-key").with_base_url(mock.uri());
+
+    let source = PdfSource::DirectUrl { url: "https://arxiv.org/pdf/2".into() };
+    let (text, report) =
+        text::extract_local_checked(tiny_pdf("Scanned"), "W2", None, &source, Some(&datalab))
+            .await
+            .unwrap();
+    assert!(text.starts_with("# A Scanned Paper"));
+    let report = report.unwrap();
+    assert_eq!(report.escalated_to.as_deref(), Some(DATALAB_ESCALATION));
+    assert_eq!(report.warning, None);
+
+    // Cached, so the next do_extract is a cache hit.
+    assert!(cache.join("W2").join("W2.md").exists());
+    assert!(text::datalab_job_store().unwrap().load("W2").is_none(), "job record removed once cached");
+    let meta = text::read_extraction_meta("W2").unwrap();
+    assert_eq!(meta.processing_mode.as_deref(), Some("balanced"));
+    assert_eq!(text::datalab_cached_markdown("W2").as_deref(), Some(text.as_str()));
+}
+
+#[tokio::test]
+async fn test_interrupted_escalation_resumes_recorded_job() {
+    let cache = use_temp_cache();
+    // A job submitted by a process that died before the result came back.
+    let jobs = text::datalab_job_store().unwrap();
+    jobs.save(&JobRecord::new("W3", "req3")).unwrap();
+
+    let mock = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/v1/marker"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/marker/req3"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "status": "complete",
+            "markdown": "# Resumed\n\nRecovered without resubmitting.",
+        })))
+        .expect(1)
+        .mount(&mock)
+        .await;
+    let datalab = DatalabClient::new("mock-key").with_base_url(mock.uri());
+
+    let polls = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let seen = polls.clone();
+    let hook: text::DatalabProgressHook = std::sync::Arc::new(move |p| seen.lock().unwrap().push((p.resumed, p.polls)));
+    let source = PdfSource::DirectUrl { url: "https://arxiv.org/pdf/3".into() };
+    let (text, report) = text::with_datalab_progress(
+        hook,
+        text::extract_local_checked(tiny_pdf("Scanned"), "W3", None, &source, Some(&datalab)),
+    )
+    .await
+    .unwrap();
+    assert!(text.starts_with("# Resumed"));
+    assert_eq!(report.unwrap().escalated_to.as_deref(), Some(DATALAB_ESCALATION));
+    assert_eq!(*polls.lock().unwrap(), vec![(true, 1)]);
+    assert!(cache.join("W3").join("W3.md").exists());
+    assert!(jobs.load("W3").is_none());
+}
+
+#[tokio::test]
+async fn test_poor_text_escalates_to_local_grobid() {
+    let cache = use_temp_cache();
+    let mock = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/processFulltextDocument"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            r#"<TEI><teiHeader><fileDesc><titleStmt><title>A Scanned Paper</title></titleStmt></fileDesc></teiHeader>
+            <text><body><div><head n="1">Methods</head><p>Recovered by GROBID.</p></div></body></text></TEI>"#,
+        ))
+        .expect(1)
+        .mount(&mock)
+        .await;
+    let grobid = LocalExtractor::new(LocalBackend::Grobid, mock.uri());
+
+    let source = PdfSource::DirectUrl { url: "https://arxiv.org/pdf/4".into() };
+    let (text, report) =
+        text::extract_local_with(tiny_pdf("Scanned"), "W4", None, &source, Some(&grobid)).await.unwrap();
+    assert_eq!(text, "# A Scanned Paper\n\n## 1 Methods\n\nRecovered by GROBID.");
+    assert_eq!(report.unwrap().escalated_to.as_deref(), Some("grobid"));
+
+    // Marker-style JSON lands where the RAG ingester reads it.
+    let json: serde_json::Value =
+        serde_json::from_slice(&std::fs::read(cache.join("W4").join("W4.json")).unwrap()).unwrap();
+    assert_eq!(json["children"][0]["children"][1]["html"], "<h2>1 Methods</h2>");
+    assert_eq!(text::read_extraction_meta("W4").unwrap().processing_mode.as_deref(), Some("grobid"));
+    assert_eq!(text::datalab_cached_markdown("W4").as_deref(), Some(text.as_str()));
+}
+
+#[tokio::test]
+async fn test_failed_local_marker_keeps_local_text() {
+    use_temp_cache();
+    let mock = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/marker/upload"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": false,
+            "error": "out of memory",
+        })))
+        .mount(&mock)
+        .await;
+    let marker = LocalExtractor::new(LocalBackend::Marker, mock.uri());
+
+    let source = PdfSource::DirectUrl { url: "https://arxiv.org/pdf/5".into() };
+    let (text, report) =
+        text::extract_local_with(tiny_pdf("Scanned"), "W5", None, &source, Some(&marker)).await.unwrap();
+    assert!(text.contains("Scanned"));
+    let report = report.unwrap();
+    assert_eq!(report.escalated_to, None);
+    assert!(report.warning.unwrap().ends_with("escalation to marker_local failed: Marker server: out of memory"));
+}
+
+#[tokio::test]
+async fn test_downloaded_pdf_is_kept_for_later_calls() {
+    let cache = use_temp_cache();
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/works/W60606"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            r#"{"id": "https://openalex.org/W60606", "display_name": "Kept Paper", "doi": "https://doi.org/10.1234/kept"}"#,
+        ))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/resolver/10.1234/kept"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "application/pdf")
+                .set_body_bytes(tiny_pdf("Kept for later")),
+        )
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let client = papers_core::OpenAlexClient::new().with_base_url(mock.uri());
+    let sources = text::TextSourceConfig {
+        order: vec![text::TextSource::LinkResolver],
+        unpaywall_email: None,
+        link_resolver: Some(format!("{}/resolver/{{doi}}", mock.uri())),
+    };
+    let first = text::work_text_with_sources(&client, None, "W60606", &sources).await.unwrap();
+    assert!(matches!(first.source, PdfSource::LinkResolver { .. }));
+    assert!(cache.join("W60606").join("W60606.pdf").exists());
+    let meta = text::read_extraction_meta("W60606").unwrap();
+    assert_eq!(meta.doi.as_deref(), Some("https://doi.org/10.1234/kept"));
+    assert_eq!(meta.pdf_source.unwrap()["type"], "link_resolver");
+
+    // The resolver is not asked again.
+    let second = text::work_text_with_sources(&client, None, "W60606", &sources).await.unwrap();
+    assert!(matches!(second.source, PdfSource::CachedPdf { .. }));
+    assert!(second.attempts.is_empty());
+    assert_eq!(second.text, first.text);
+
+    // Only PDFs are kept.
+    assert!(text::save_downloaded_pdf("W60607", b"<html></html>", &first.source, None, None).is_none());
+    assert_eq!(text::cached_pdf_path("W60607"), None);
+}
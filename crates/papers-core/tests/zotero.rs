@@ -1,6 +1,12 @@
-use papers_core::zotero::{resolve_collection_key, resolve_item_key, resolve_search_key};
-use papers_zotero::ZoteroClient;
-use wiremock::matchers::{method, path, query_param};
+use papers_core::OpenAlexClient;
+use papers_core::metadata_diff::{DiffField, DiffStatus, work_metadata_diff};
+use papers_core::zotero::{
+    ZoteroItemUpdate, ZoteroWriteError, create_item_from_work, export_top_items, item_key_candidates,
+    collection_paper_ids, item_label, resolve_annotation, resolve_collection_key, resolve_item_key, resolve_library, resolve_search_key, scoped_client,
+    update_item_fields,
+};
+use papers_zotero::{ItemListParams, LibraryScope, ZoteroClient};
+use wiremock::matchers::{body_partial_json, header, method, path, query_param};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 fn make_client(mock: &MockServer) -> ZoteroClient {
@@ -118,6 +124,31 @@ async fn test_resolve_item_key_not_found() {
     assert!(msg.contains("nonexistent paper title xyz"));
 }
 
+#[tokio::test]
+async fn test_item_key_candidates_lists_matches_with_labels() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/test/items/top"))
+        .and(query_param("q", "Attention"))
+        .and(query_param("limit", "5"))
+        .respond_with(array_response(item_list_json()))
+        .mount(&mock)
+        .await;
+
+    let client = make_client(&mock);
+    let items = item_key_candidates(&client, "Attention", 5).await.unwrap();
+    assert_eq!(items.len(), 1);
+    assert_eq!(item_label(&items[0]), "Attention Is All You Need");
+
+    let mut item = items[0].clone();
+    item.meta.creator_summary = Some("Vaswani et al.".into());
+    item.meta.parsed_date = Some("2017-06-12".into());
+    assert_eq!(item_label(&item), "Attention Is All You Need — Vaswani et al. (2017)");
+
+    assert!(item_key_candidates(&client, "ABC12345", 5).await.unwrap().is_empty());
+    assert_eq!(mock.received_requests().await.unwrap().len(), 1);
+}
+
 // ── resolve_collection_key ────────────────────────────────────────────
 
 #[tokio::test]
@@ -197,6 +228,37 @@ async fn test_resolve_collection_key_not_found() {
     assert!(msg.contains("nonexistent collection"));
 }
 
+// ── collection_paper_ids ──────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_collection_paper_ids_by_name() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/test/collections"))
+        .respond_with(array_response(collection_list_json()))
+        .mount(&mock)
+        .await;
+    let with_doi = item_list_json().replace(
+        r#""title": "Attention Is All You Need","#,
+        r#""title": "Attention Is All You Need", "DOI": " 10.5555/attention ","#,
+    );
+    let second = item_list_json().replace("ABC12345", "XYZ98765");
+    let both = format!("[{},{}]", &with_doi[1..with_doi.len() - 1], &second[1..second.len() - 1]);
+    Mock::given(method("GET"))
+        .and(path("/users/test/collections/COL12345/items/top"))
+        .and(query_param("start", "0"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Total-Results", "2")
+                .set_body_string(both),
+        )
+        .mount(&mock)
+        .await;
+
+    let ids = collection_paper_ids(&make_client(&mock), "gpu").await.unwrap();
+    assert_eq!(ids, ["10.5555/attention", "ABC12345", "XYZ98765"]);
+}
+
 // ── resolve_search_key ────────────────────────────────────────────────
 
 #[tokio::test]
@@ -256,3 +318,410 @@ async fn test_resolve_search_key_not_found() {
     let msg = result.unwrap_err().to_string();
     assert!(msg.contains("nonexistent search"));
 }
+
+// ── resolve_library ──────────────────────────────────────────────────
+
+fn group_list_json() -> &'static str {
+    r#"[
+        {"id": 111, "version": 1, "data": {"id": 111, "version": 1, "name": "Graphics Lab Archive"}},
+        {"id": 222, "version": 1, "data": {"id": 222, "version": 1, "name": "Graphics Lab"}}
+    ]"#
+}
+
+#[tokio::test]
+async fn test_resolve_library_user_and_numeric_id() {
+    let mock = MockServer::start().await;
+    let client = make_client(&mock);
+    assert_eq!(resolve_library(&client, "user").await.unwrap(), LibraryScope::User);
+    assert_eq!(resolve_library(&client, "My Library").await.unwrap(), LibraryScope::User);
+    assert_eq!(resolve_library(&client, "4711").await.unwrap(), LibraryScope::Group(4711));
+    assert!(mock.received_requests().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_resolve_library_by_group_name() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/test/groups"))
+        .respond_with(array_response(group_list_json()))
+        .mount(&mock)
+        .await;
+
+    let client = make_client(&mock);
+    // Exact match wins over an earlier substring match.
+    assert_eq!(resolve_library(&client, "graphics lab").await.unwrap(), LibraryScope::Group(222));
+    assert_eq!(resolve_library(&client, "archive").await.unwrap(), LibraryScope::Group(111));
+    let msg = resolve_library(&client, "biology").await.unwrap_err().to_string();
+    assert!(msg.contains("Graphics Lab Archive, Graphics Lab"), "{msg}");
+}
+
+#[tokio::test]
+async fn test_scoped_client_targets_group_library() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/groups/222/collections"))
+        .respond_with(array_response(collection_list_json()))
+        .mount(&mock)
+        .await;
+
+    let client = scoped_client(make_client(&mock), Some("222")).await.unwrap();
+    let result = resolve_collection_key(&client, "GPU Papers").await.unwrap();
+    assert_eq!(result, "COL12345");
+}
+
+// ── export_top_items ─────────────────────────────────────────────────
+
+fn export_page(body: &str, total: u64) -> ResponseTemplate {
+    ResponseTemplate::new(200)
+        .insert_header("Total-Results", total.to_string().as_str())
+        .set_body_string(body)
+}
+
+#[tokio::test]
+async fn test_export_top_items_pages_through_bibtex() {
+    let mock = MockServer::start().await;
+    for (start, body) in [("0", "@article{a,\n}\n"), ("100", "@article{b,\n}\n")] {
+        Mock::given(method("GET"))
+            .and(path("/users/test/items/top"))
+            .and(query_param("format", "bibtex"))
+            .and(query_param("limit", "100"))
+            .and(query_param("start", start))
+            .respond_with(export_page(body, 150))
+            .expect(1)
+            .mount(&mock)
+            .await;
+    }
+
+    let text = export_top_items(&make_client(&mock), None, &ItemListParams::default(), "BibTeX")
+        .await
+        .unwrap();
+    assert_eq!(text, "@article{a,\n}\n\n@article{b,\n}");
+}
+
+#[tokio::test]
+async fn test_export_collection_csljson_merges_pages() {
+    let mock = MockServer::start().await;
+    for (start, id) in [("0", "a"), ("100", "b")] {
+        Mock::given(method("GET"))
+            .and(path("/users/test/collections/COL12345/items/top"))
+            .and(query_param("format", "csljson"))
+            .and(query_param("start", start))
+            .respond_with(export_page(&format!(r#"{{"items":[{{"id":"{id}"}}]}}"#), 101))
+            .mount(&mock)
+            .await;
+    }
+
+    let client = make_client(&mock);
+    let text = export_top_items(&client, Some("COL12345"), &ItemListParams::default(), "csljson")
+        .await
+        .unwrap();
+    let doc: serde_json::Value = serde_json::from_str(&text).unwrap();
+    let items = doc["items"].as_array().unwrap();
+    let ids: Vec<&str> = items.iter().map(|i| i["id"].as_str().unwrap()).collect();
+    assert_eq!(ids, ["a", "b"]);
+}
+
+#[tokio::test]
+async fn test_export_top_items_rejects_unknown_format() {
+    let mock = MockServer::start().await;
+    let err = export_top_items(&make_client(&mock), None, &ItemListParams::default(), "docx")
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("Unsupported export format: docx"), "{err}");
+    assert!(mock.received_requests().await.unwrap().is_empty());
+}
+
+// ── Item writes ───────────────────────────────────────────────────────────
+
+fn item_json(version: u64, tags: &str) -> String {
+    format!(
+        r#"{{
+        "key": "ABC12345",
+        "version": {version},
+        "library": {{"type": "user", "id": 1, "name": "test", "links": {{}}}},
+        "links": {{}},
+        "meta": {{}},
+        "data": {{
+            "key": "ABC12345",
+            "version": {version},
+            "itemType": "journalArticle",
+            "title": "Attention Is All You Need",
+            "tags": [{tags}],
+            "collections": []
+        }}
+    }}"#
+    )
+}
+
+fn openalex_work_json() -> &'static str {
+    r#"{
+        "id": "https://openalex.org/W1",
+        "doi": "https://doi.org/10.1234/new",
+        "display_name": "A New Paper",
+        "publication_year": 2024,
+        "type": "article",
+        "type_crossref": "journal-article",
+        "abstract_inverted_index": {"An": [0], "abstract": [1]},
+        "authorships": [{"author": {"id": "https://openalex.org/A1", "display_name": "Alice Smith", "orcid": null}}]
+    }"#
+}
+
+#[tokio::test]
+async fn test_create_item_from_work_posts_mapped_item() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/works/W1"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(openalex_work_json()))
+        .mount(&mock)
+        .await;
+    // Duplicate check: title search returns nothing
+    Mock::given(method("GET"))
+        .and(path("/users/test/items/top"))
+        .respond_with(array_response("[]").insert_header("Total-Results", "0"))
+        .mount(&mock)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/users/test/items"))
+        .and(body_partial_json(serde_json::json!([{
+            "itemType": "journalArticle",
+            "title": "A New Paper",
+            "DOI": "10.1234/new",
+            "abstractNote": "An abstract",
+            "creators": [{"creatorType": "author", "firstName": "Alice", "lastName": "Smith"}],
+            "tags": [{"tag": "to-read"}]
+        }])))
+        .respond_with(ResponseTemplate::new(200).set_body_string(format!(
+            r#"{{"successful": {{"0": {}}}, "unchanged": {{}}, "failed": {{}}}}"#,
+            item_json(1, "")
+        )))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let openalex = OpenAlexClient::new().with_base_url(mock.uri());
+    let item = create_item_from_work(
+        &openalex,
+        &make_client(&mock),
+        "W1",
+        &[],
+        &["to-read".to_string()],
+        false,
+    )
+    .await
+    .unwrap();
+    assert_eq!(item.key, "ABC12345");
+}
+
+#[tokio::test]
+async fn test_create_item_from_work_rejects_duplicate_doi() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/works/W1"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(openalex_work_json()))
+        .mount(&mock)
+        .await;
+    let existing = r#"[{
+        "key": "DUP12345", "version": 1,
+        "library": {"type": "user", "id": 1, "name": "test", "links": {}},
+        "links": {}, "meta": {},
+        "data": {"key": "DUP12345", "version": 1, "itemType": "journalArticle", "title": "A New Paper", "DOI": "10.1234/NEW"}
+    }]"#;
+    Mock::given(method("GET"))
+        .and(path("/users/test/items/top"))
+        .respond_with(array_response(existing))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/users/test/items/DUP12345/children"))
+        .respond_with(array_response("[]"))
+        .mount(&mock)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/users/test/items"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&mock)
+        .await;
+
+    let openalex = OpenAlexClient::new().with_base_url(mock.uri());
+    let err = create_item_from_work(&openalex, &make_client(&mock), "W1", &[], &[], false)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ZoteroWriteError::AlreadyExists { key } if key == "DUP12345"));
+}
+
+#[tokio::test]
+async fn test_update_item_fields_merges_tags_with_current_version() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/test/items/ABC12345"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(item_json(7, r#"{"tag": "old"}, {"tag": "keep"}"#)))
+        .mount(&mock)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path("/users/test/items/ABC12345"))
+        .and(header("If-Unmodified-Since-Version", "7"))
+        .and(body_partial_json(serde_json::json!({
+            "abstractNote": "New abstract",
+            "tags": [{"tag": "keep"}, {"tag": "new"}]
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let update = ZoteroItemUpdate {
+        abstract_note: Some("New abstract".into()),
+        add_tags: vec!["new".into()],
+        remove_tags: vec!["old".into()],
+        ..Default::default()
+    };
+    let item = update_item_fields(&make_client(&mock), "ABC12345", &update, None)
+        .await
+        .unwrap();
+    assert_eq!(item.key, "ABC12345");
+}
+
+#[tokio::test]
+async fn test_update_item_fields_stale_version() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/test/items/ABC12345"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(item_json(7, "")))
+        .mount(&mock)
+        .await;
+    Mock::given(method("PATCH"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(0)
+        .mount(&mock)
+        .await;
+
+    let update = ZoteroItemUpdate { title: Some("New".into()), ..Default::default() };
+    let err = update_item_fields(&make_client(&mock), "ABC12345", &update, Some(5))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ZoteroWriteError::VersionConflict { version: 5, .. }));
+}
+
+#[tokio::test]
+async fn test_update_item_fields_concurrent_edit_maps_412() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/test/items/ABC12345"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(item_json(7, "")))
+        .mount(&mock)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path("/users/test/items/ABC12345"))
+        .respond_with(ResponseTemplate::new(412).set_body_string("Precondition Failed"))
+        .mount(&mock)
+        .await;
+
+    let update = ZoteroItemUpdate { title: Some("New".into()), ..Default::default() };
+    let err = update_item_fields(&make_client(&mock), "ABC12345", &update, None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ZoteroWriteError::VersionConflict { version: 7, .. }));
+}
+
+// ── Metadata diff ─────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_work_metadata_diff_by_key_patches_drifted_fields() {
+    let mock = MockServer::start().await;
+    let drifted = r#"{
+        "key": "ABC12345", "version": 7,
+        "library": {"type": "user", "id": 1, "name": "test", "links": {}},
+        "links": {}, "meta": {},
+        "data": {
+            "key": "ABC12345", "version": 7, "itemType": "journalArticle",
+            "title": "A new paper (draft)", "date": "2023", "DOI": "10.1234/NEW",
+            "creators": [{"creatorType": "author", "firstName": "A.", "lastName": "Smith"}]
+        }
+    }"#;
+    Mock::given(method("GET"))
+        .and(path("/users/test/items/ABC12345"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(drifted))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/works/doi:10.1234/new"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(openalex_work_json()))
+        .mount(&mock)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path("/users/test/items/ABC12345"))
+        .and(header("If-Unmodified-Since-Version", "7"))
+        .and(body_partial_json(serde_json::json!({"title": "A New Paper", "date": "2024"})))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let openalex = OpenAlexClient::new().with_base_url(mock.uri());
+    let diff = work_metadata_diff(&openalex, &make_client(&mock), "ABC12345", true)
+        .await
+        .unwrap();
+    assert_eq!(diff.work_id, "https://openalex.org/W1");
+    assert_eq!(diff.discrepancies, 2);
+    assert!(diff.applied);
+    let patch = diff.patch.unwrap();
+    assert!(patch.get("DOI").is_none() && patch.get("creators").is_none());
+    let doi = diff.fields.iter().find(|f| f.field == DiffField::Doi).unwrap();
+    assert_eq!(doi.status, DiffStatus::Same);
+}
+
+// ── resolve_annotation ────────────────────────────────────────────────
+
+fn child_item_json(key: &str, item_type: &str, parent: &str, extra: &str) -> String {
+    format!(
+        r#"{{
+        "key": "{key}",
+        "version": 1,
+        "library": {{"type": "user", "id": 1, "name": "test", "links": {{}}}},
+        "links": {{}},
+        "meta": {{}},
+        "data": {{
+            "key": "{key}",
+            "version": 1,
+            "itemType": "{item_type}",
+            "parentItem": "{parent}",
+            "tags": [],
+            "relations": {{}}{extra}
+        }}
+    }}"#
+    )
+}
+
+#[tokio::test]
+async fn test_resolve_annotation_follows_attachment_to_item() {
+    let mock = MockServer::start().await;
+    let annotation = child_item_json(
+        "ANN12345",
+        "annotation",
+        "ATT12345",
+        r#", "annotationType": "highlight", "annotationText": " A highlighted passage ",
+            "annotationPageLabel": "7", "annotationPosition": "{\"pageIndex\":6,\"rects\":[[1,2,3,4]]}""#,
+    );
+    Mock::given(method("GET"))
+        .and(path("/users/test/items/ANN12345"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(annotation))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/users/test/items/ATT12345"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(child_item_json("ATT12345", "attachment", "ABC12345", "")))
+        .mount(&mock)
+        .await;
+
+    let target = resolve_annotation(&make_client(&mock), "ANN12345").await.unwrap();
+    assert_eq!(target.item_key, "ABC12345");
+    assert_eq!(target.annotation_type.as_deref(), Some("highlight"));
+    assert_eq!(target.text.as_deref(), Some("A highlighted passage"));
+    assert_eq!(target.page_label.as_deref(), Some("7"));
+    assert_eq!(target.page_index, Some(6));
+    assert!(target.comment.is_none());
+
+    let err = resolve_annotation(&make_client(&mock), "ATT12345").await.unwrap_err();
+    assert!(err.to_string().contains("not an annotation"), "{err}");
+}
@@ -0,0 +1,179 @@
+use crate::error::{CrossrefError, Result};
+use crate::types::{CrossrefWork, Envelope};
+
+const DEFAULT_BASE_URL: &str = "https://api.crossref.org";
+
+/// Async client for the Crossref REST API.
+///
+/// No API key is needed. When a contact email is configured (see
+/// [`CrossrefClient::new`]) it is sent as a `mailto` query parameter and in
+/// the `User-Agent`, which puts requests in Crossref's polite pool.
+///
+/// ```no_run
+/// # async fn example() -> papers_crossref::Result<()> {
+/// use papers_crossref::CrossrefClient;
+///
+/// let client = CrossrefClient::new().with_mailto("you@example.org");
+/// let work = client.get_work("https://doi.org/10.1038/nature12373").await?;
+/// println!("{}", work.doi);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct CrossrefClient {
+    http: reqwest::Client,
+    base_url: String,
+    mailto: Option<String>,
+}
+
+impl Default for CrossrefClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CrossrefClient {
+    /// Create a new client, reading the contact email from the
+    /// `CROSSREF_MAILTO` environment variable.
+    pub fn new() -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            mailto: std::env::var("CROSSREF_MAILTO").ok().filter(|m| !m.trim().is_empty()),
+        }
+    }
+
+    /// Identify requests with `email` to use the polite pool.
+    pub fn with_mailto(mut self, email: impl Into<String>) -> Self {
+        self.mailto = Some(email.into());
+        self
+    }
+
+    /// Override the base URL. Useful for testing with a mock server.
+    pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = url.into();
+        self
+    }
+
+    /// GET /works/{doi} — metadata for one DOI.
+    ///
+    /// Accepts a bare DOI or one prefixed with `https://doi.org/` or `doi:`.
+    /// Returns [`CrossrefError::NotFound`] if Crossref has no record of it.
+    pub async fn get_work(&self, doi: &str) -> Result<CrossrefWork> {
+        let doi = bare_doi(doi);
+        let url = format!("{}/works/{}", self.base_url, encode_doi(doi));
+        let mut req = self.http.get(&url);
+        if let Some(mailto) = &self.mailto {
+            req = req
+                .query(&[("mailto", mailto)])
+                .header(reqwest::header::USER_AGENT, format!("papers (mailto:{mailto})"));
+        }
+        let resp = req.send().await?;
+        let status = resp.status();
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Err(CrossrefError::NotFound(doi.to_string()));
+        }
+        if !status.is_success() {
+            let message = resp.text().await.unwrap_or_default();
+            return Err(CrossrefError::Api { status: status.as_u16(), message });
+        }
+        let text = resp.text().await?;
+        let envelope: Envelope<CrossrefWork> = serde_json::from_str(&text)?;
+        Ok(envelope.message)
+    }
+}
+
+fn bare_doi(doi: &str) -> &str {
+    let doi = doi.trim();
+    doi.strip_prefix("https://doi.org/")
+        .or_else(|| doi.strip_prefix("http://doi.org/"))
+        .or_else(|| doi.strip_prefix("doi:"))
+        .unwrap_or(doi)
+}
+
+/// Percent-encode a DOI for use in a URL path. DOIs may contain almost any
+/// printable character; `/` is left alone since Crossref expects it unescaped.
+fn encode_doi(doi: &str) -> String {
+    let mut out = String::with_capacity(doi.len());
+    for b in doi.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' => {
+                out.push(b as char)
+            }
+            _ => out.push_str(&format!("%{b:02X}")),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn work_json() -> serde_json::Value {
+        serde_json::json!({
+            "status": "ok",
+            "message-type": "work",
+            "message": {
+                "DOI": "10.1101/2024.05.01.592000",
+                "title": ["A very recent preprint"],
+                "author": [{"given": "Ada", "family": "Lovelace", "sequence": "first"}],
+                "type": "posted-content",
+                "issued": {"date-parts": [[2024, 5, 3]]},
+                "publisher": "Cold Spring Harbor Laboratory",
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_get_work() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/works/10.1101/2024.05.01.592000"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(work_json()))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = CrossrefClient::new().with_base_url(server.uri());
+        let work = client.get_work("https://doi.org/10.1101/2024.05.01.592000").await.unwrap();
+        assert_eq!(work.doi, "10.1101/2024.05.01.592000");
+        assert_eq!(work.title().as_deref(), Some("A very recent preprint"));
+        assert_eq!(work.author[0].display_name().as_deref(), Some("Ada Lovelace"));
+        assert_eq!(work.r#type.as_deref(), Some("posted-content"));
+    }
+
+    #[tokio::test]
+    async fn test_get_work_sends_mailto() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/works/10.1101/2024.05.01.592000"))
+            .and(query_param("mailto", "me@example.org"))
+            .and(header("user-agent", "papers (mailto:me@example.org)"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(work_json()))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = CrossrefClient::new().with_base_url(server.uri()).with_mailto("me@example.org");
+        client.get_work("10.1101/2024.05.01.592000").await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_get_work_not_found() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(404).set_body_string("Resource not found."))
+            .mount(&server)
+            .await;
+        let client = CrossrefClient::new().with_base_url(server.uri());
+        let err = client.get_work("10.9999/missing").await.unwrap_err();
+        assert!(matches!(err, CrossrefError::NotFound(doi) if doi == "10.9999/missing"));
+    }
+
+    #[test]
+    fn test_encode_doi() {
+        assert_eq!(encode_doi("10.1002/(SICI)1097-4571"), "10.1002/%28SICI%291097-4571");
+        assert_eq!(encode_doi("10.1000/a#b"), "10.1000/a%23b");
+    }
+}
@@ -0,0 +1,18 @@
+/// Errors returned by [`CrossrefClient`](crate::CrossrefClient) methods.
+#[derive(thiserror::Error, Debug)]
+pub enum CrossrefError {
+    #[error("HTTP error: {0}")]
+    Http(#[from] reqwest::Error),
+
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("API error ({status}): {message}")]
+    Api { status: u16, message: String },
+
+    #[error("DOI {0} not found in Crossref")]
+    NotFound(String),
+}
+
+/// A [`Result`](std::result::Result) alias with [`CrossrefError`] as the error type.
+pub type Result<T> = std::result::Result<T, CrossrefError>;
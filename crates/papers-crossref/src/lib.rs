@@ -0,0 +1,32 @@
+//! Async Rust client for the [Crossref REST API](https://api.crossref.org).
+//!
+//! Crossref is the registration agency for most scholarly DOIs, so it knows
+//! about a work as soon as the DOI is minted — often days or weeks before
+//! OpenAlex picks it up.
+//!
+//! # Quick start
+//!
+//! ```no_run
+//! # async fn example() -> papers_crossref::Result<()> {
+//! use papers_crossref::CrossrefClient;
+//!
+//! let client = CrossrefClient::new();
+//! let work = client.get_work("10.1038/nature12373").await?;
+//! println!("{:?} ({:?})", work.title(), work.year());
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Polite pool
+//!
+//! Crossref needs no API key. Set the `CROSSREF_MAILTO` environment variable
+//! (or call [`CrossrefClient::with_mailto`]) to identify yourself and get
+//! routed to Crossref's more reliable "polite" pool.
+
+pub mod client;
+pub mod error;
+pub mod types;
+
+pub use client::CrossrefClient;
+pub use error::{CrossrefError, Result};
+pub use types::{CrossrefAuthor, CrossrefWork, DateParts};
@@ -0,0 +1,210 @@
+use serde::{Deserialize, Serialize};
+
+/// A work record from `GET /works/{doi}`.
+///
+/// Crossref returns most text fields as arrays (`title`, `container-title`,
+/// `ISSN`); they default to empty when absent. Only the fields `papers` maps
+/// onto its own metadata are modeled.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct CrossrefWork {
+    /// The DOI, without a `https://doi.org/` prefix (e.g. `"10.1038/nature12373"`).
+    #[serde(rename = "DOI")]
+    pub doi: String,
+
+    #[serde(default)]
+    pub title: Vec<String>,
+
+    #[serde(default)]
+    pub subtitle: Vec<String>,
+
+    #[serde(default)]
+    pub author: Vec<CrossrefAuthor>,
+
+    /// Journal, proceedings, or book title.
+    #[serde(default)]
+    pub container_title: Vec<String>,
+
+    pub publisher: Option<String>,
+
+    /// Crossref type: `"journal-article"`, `"posted-content"`,
+    /// `"proceedings-article"`, `"book-chapter"`, etc.
+    pub r#type: Option<String>,
+
+    /// Earliest of the print and online publication dates.
+    pub issued: Option<DateParts>,
+
+    #[serde(rename = "ISSN", default)]
+    pub issn: Vec<String>,
+
+    #[serde(rename = "ISBN", default)]
+    pub isbn: Vec<String>,
+
+    pub volume: Option<String>,
+
+    pub issue: Option<String>,
+
+    /// Page range, e.g. `"123-130"`.
+    pub page: Option<String>,
+
+    /// Abstract as JATS XML (see [`CrossrefWork::abstract_text`]).
+    #[serde(rename = "abstract")]
+    pub abstract_jats: Option<String>,
+
+    /// Landing page URL.
+    #[serde(rename = "URL")]
+    pub url: Option<String>,
+
+    /// ISO 639-1 language code, when the depositor gave one.
+    pub language: Option<String>,
+
+    /// Citations Crossref counts from other Crossref DOIs.
+    pub is_referenced_by_count: Option<i64>,
+}
+
+/// One author of a [`CrossrefWork`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrossrefAuthor {
+    pub given: Option<String>,
+    pub family: Option<String>,
+    /// Set instead of `given`/`family` for organizational authors.
+    pub name: Option<String>,
+    /// ORCID URL (e.g. `"https://orcid.org/0000-0002-1825-0097"`).
+    #[serde(rename = "ORCID")]
+    pub orcid: Option<String>,
+    /// `"first"` or `"additional"`.
+    pub sequence: Option<String>,
+}
+
+impl CrossrefAuthor {
+    /// `"Given Family"`, or the organization name.
+    pub fn display_name(&self) -> Option<String> {
+        let parts: Vec<&str> =
+            [self.given.as_deref(), self.family.as_deref()].into_iter().flatten().collect();
+        let name = parts.join(" ").trim().to_string();
+        if name.is_empty() {
+            self.name.clone().filter(|n| !n.is_empty())
+        } else {
+            Some(name)
+        }
+    }
+}
+
+/// A Crossref partial date: `{"date-parts": [[2024, 3, 15]]}`. Month and day
+/// may be missing, and Crossref sometimes sends `[[null]]`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DateParts {
+    #[serde(rename = "date-parts", default)]
+    pub date_parts: Vec<Vec<Option<i32>>>,
+}
+
+impl DateParts {
+    fn parts(&self) -> &[Option<i32>] {
+        self.date_parts.first().map(Vec::as_slice).unwrap_or_default()
+    }
+
+    pub fn year(&self) -> Option<i32> {
+        self.parts().first().copied().flatten()
+    }
+
+    /// ISO 8601 date, only when year, month and day are all known.
+    pub fn iso_date(&self) -> Option<String> {
+        match self.parts() {
+            [Some(y), Some(m), Some(d), ..] => Some(format!("{y:04}-{m:02}-{d:02}")),
+            _ => None,
+        }
+    }
+}
+
+impl CrossrefWork {
+    /// The main title, with the subtitle appended after a colon.
+    pub fn title(&self) -> Option<String> {
+        let title = self.title.first().map(|t| t.trim()).filter(|t| !t.is_empty())?;
+        match self.subtitle.first().map(|s| s.trim()).filter(|s| !s.is_empty()) {
+            Some(sub) => Some(format!("{title}: {sub}")),
+            None => Some(title.to_string()),
+        }
+    }
+
+    pub fn year(&self) -> Option<i32> {
+        self.issued.as_ref().and_then(DateParts::year)
+    }
+
+    /// The abstract as plain text, with JATS tags stripped and whitespace
+    /// collapsed.
+    pub fn abstract_text(&self) -> Option<String> {
+        let jats = self.abstract_jats.as_deref()?;
+        let mut text = String::with_capacity(jats.len());
+        let mut in_tag = false;
+        for c in jats.chars() {
+            match c {
+                '<' => {
+                    in_tag = true;
+                    text.push(' ');
+                }
+                '>' => in_tag = false,
+                _ if !in_tag => text.push(c),
+                _ => {}
+            }
+        }
+        let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        // JATS abstracts usually open with a bare "Abstract" heading.
+        let text = text.strip_prefix("Abstract ").unwrap_or(&text).to_string();
+        (!text.is_empty()).then_some(text)
+    }
+
+    /// First and last page, split from [`page`](Self::page).
+    pub fn pages(&self) -> (Option<&str>, Option<&str>) {
+        match self.page.as_deref() {
+            Some(page) => match page.split_once('-') {
+                Some((first, last)) => (Some(first.trim()), Some(last.trim())),
+                None => (Some(page.trim()), None),
+            },
+            None => (None, None),
+        }
+    }
+}
+
+/// Crossref's response envelope: `{"status": "ok", "message": {...}}`.
+#[derive(Deserialize)]
+pub(crate) struct Envelope<T> {
+    pub message: T,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_title_year_and_abstract() {
+        let work: CrossrefWork = serde_json::from_value(serde_json::json!({
+            "DOI": "10.1101/2024.01.01.123456",
+            "title": ["Fast protein folding"],
+            "subtitle": ["a preprint"],
+            "issued": {"date-parts": [[2024, 1, 3]]},
+            "abstract": "<jats:title>Abstract</jats:title><jats:p>We fold   proteins.</jats:p>",
+            "page": "10-19",
+        }))
+        .unwrap();
+        assert_eq!(work.title().as_deref(), Some("Fast protein folding: a preprint"));
+        assert_eq!(work.year(), Some(2024));
+        assert_eq!(work.issued.as_ref().unwrap().iso_date().as_deref(), Some("2024-01-03"));
+        assert_eq!(work.abstract_text().as_deref(), Some("We fold proteins."));
+        assert_eq!(work.pages(), (Some("10"), Some("19")));
+    }
+
+    #[test]
+    fn test_partial_dates_and_author_names() {
+        let date: DateParts = serde_json::from_value(serde_json::json!({"date-parts": [[2023, 5]]})).unwrap();
+        assert_eq!(date.year(), Some(2023));
+        assert_eq!(date.iso_date(), None);
+        let date: DateParts = serde_json::from_value(serde_json::json!({"date-parts": [[null]]})).unwrap();
+        assert_eq!(date.year(), None);
+
+        let author: CrossrefAuthor =
+            serde_json::from_value(serde_json::json!({"given": "Ada", "family": "Lovelace"})).unwrap();
+        assert_eq!(author.display_name().as_deref(), Some("Ada Lovelace"));
+        let org: CrossrefAuthor = serde_json::from_value(serde_json::json!({"name": "CERN"})).unwrap();
+        assert_eq!(org.display_name().as_deref(), Some("CERN"));
+    }
+}
@@ -1,6 +1,7 @@
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use crate::error::{DatalabError, Result};
+use crate::jobs::{JobProgress, JobRecord, JobStore, unix_now};
 use crate::types::{MarkerPollResponse, MarkerRequest, MarkerStatus, MarkerSubmitResponse, StepTypesResponse};
 
 const DEFAULT_BASE_URL: &str = "https://www.datalab.to";
@@ -89,6 +90,95 @@ impl DatalabClient {
         }
     }
 
+    /// Like [`convert_document`](Self::convert_document), but records the job
+    /// in `jobs` under `key` and reports each poll to `on_progress`.
+    ///
+    /// If `jobs` already holds a resumable record for `key` (left by a call
+    /// whose process died), that job is polled instead of submitting `req`
+    /// again; when DataLab no longer knows the job, it is resubmitted. The
+    /// record is kept after completion, so a result that was fetched but never
+    /// stored can still be fetched: remove it with [`JobStore::remove`] once the
+    /// result is safely stored. Failing to write a record does not fail the
+    /// conversion.
+    pub async fn convert_document_tracked(
+        &self,
+        req: MarkerRequest,
+        jobs: &JobStore,
+        key: &str,
+        mut on_progress: impl FnMut(&JobProgress),
+    ) -> Result<MarkerPollResponse> {
+        if let Some(result) = self.resume_job(jobs, key, &mut on_progress).await? {
+            return Ok(result);
+        }
+        let submit = self.submit_marker(req).await?;
+        let record = JobRecord::new(key, &submit.request_id);
+        let _ = jobs.save(&record);
+        self.poll_tracked(jobs, record, false, &mut on_progress).await
+    }
+
+    /// Poll the job recorded in `jobs` for `key` until it finishes.
+    ///
+    /// Returns `Ok(None)` when there is nothing to resume: no record, a failed
+    /// job, or a job DataLab no longer knows (its record is then removed).
+    /// Unlike a fresh submission, the first poll is made right away.
+    pub async fn resume_job(
+        &self,
+        jobs: &JobStore,
+        key: &str,
+        mut on_progress: impl FnMut(&JobProgress),
+    ) -> Result<Option<MarkerPollResponse>> {
+        let Some(record) = jobs.load(key).filter(JobRecord::is_resumable) else {
+            return Ok(None);
+        };
+        match self.poll_tracked(jobs, record, true, &mut on_progress).await {
+            Ok(result) => Ok(Some(result)),
+            Err(DatalabError::Api { .. }) => {
+                jobs.remove(key);
+                Ok(None)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn poll_tracked(
+        &self,
+        jobs: &JobStore,
+        mut record: JobRecord,
+        resumed: bool,
+        on_progress: &mut impl FnMut(&JobProgress),
+    ) -> Result<MarkerPollResponse> {
+        let started = Instant::now();
+        let mut polls = 0;
+        loop {
+            if !(resumed && polls == 0) {
+                tokio::time::sleep(Duration::from_secs(2)).await;
+            }
+            let poll = self.get_marker_result(&record.request_id).await?;
+            polls += 1;
+            record.status = poll.status.clone();
+            record.updated_at = unix_now();
+            record.error = poll.error.clone();
+            let _ = jobs.save(&record);
+            on_progress(&JobProgress {
+                key: record.key.clone(),
+                request_id: record.request_id.clone(),
+                resumed,
+                polls,
+                elapsed: started.elapsed(),
+                status: poll.status.clone(),
+            });
+            match poll.status {
+                MarkerStatus::Complete => return Ok(poll),
+                MarkerStatus::Failed => {
+                    return Err(DatalabError::Processing(
+                        poll.error.unwrap_or_else(|| "unknown processing error".to_string()),
+                    ));
+                }
+                MarkerStatus::Processing => continue,
+            }
+        }
+    }
+
     /// POST /api/v1/marker — submit a conversion job.
     ///
     /// Returns immediately with a `request_id`. Use [`get_marker_result`](Self::get_marker_result)
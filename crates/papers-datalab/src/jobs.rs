@@ -0,0 +1,127 @@
+//! Persistent tracking of submitted conversion jobs.
+//!
+//! A [`JobStore`] keeps one small JSON record per document (keyed by a
+//! caller-chosen ID) holding the DataLab `request_id` and the last status
+//! seen. [`DatalabClient::convert_document_tracked`](crate::DatalabClient::convert_document_tracked)
+//! writes these records as it polls, so when the process dies mid-conversion
+//! the next call for the same key resumes polling the existing job instead of
+//! uploading (and paying for) the document again.
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::types::MarkerStatus;
+
+/// A submitted conversion job, as stored on disk.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct JobRecord {
+    /// The caller's ID for the document (e.g. a Zotero item key).
+    pub key: String,
+    /// DataLab's ID for the job, used to poll for its result.
+    pub request_id: String,
+    /// Last status seen when polling.
+    pub status: MarkerStatus,
+    /// Unix time (seconds) the job was submitted.
+    pub submitted_at: u64,
+    /// Unix time (seconds) of the last poll.
+    pub updated_at: u64,
+    /// DataLab's error message for a failed job.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl JobRecord {
+    /// A freshly submitted job.
+    pub fn new(key: &str, request_id: &str) -> Self {
+        let now = unix_now();
+        Self {
+            key: key.to_string(),
+            request_id: request_id.to_string(),
+            status: MarkerStatus::Processing,
+            submitted_at: now,
+            updated_at: now,
+            error: None,
+        }
+    }
+
+    /// Whether polling this job can still yield a result: it is processing,
+    /// or complete but not yet removed by the caller.
+    pub fn is_resumable(&self) -> bool {
+        self.status != MarkerStatus::Failed
+    }
+}
+
+/// Progress of a tracked conversion, reported after every poll.
+#[derive(Debug, Clone)]
+pub struct JobProgress {
+    pub key: String,
+    pub request_id: String,
+    /// `true` when polling a job submitted by an earlier call.
+    pub resumed: bool,
+    /// Polls made by this call so far.
+    pub polls: u32,
+    /// Time since this call started (submitting or resuming).
+    pub elapsed: Duration,
+    pub status: MarkerStatus,
+}
+
+/// A directory of [`JobRecord`]s, one `{key}.json` file per document.
+#[derive(Debug, Clone)]
+pub struct JobStore {
+    dir: PathBuf,
+}
+
+impl JobStore {
+    /// A store in `dir`, created on first save.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        let name: String = key
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.') { c } else { '_' })
+            .collect();
+        self.dir.join(format!("{name}.json"))
+    }
+
+    /// The record for `key`, if one exists and parses.
+    pub fn load(&self, key: &str) -> Option<JobRecord> {
+        let bytes = std::fs::read(self.path(key)).ok()?;
+        serde_json::from_slice(&bytes).ok()
+    }
+
+    /// Write `record`, replacing any earlier record for its key.
+    pub fn save(&self, record: &JobRecord) -> std::io::Result<()> {
+        std::fs::create_dir_all(&self.dir)?;
+        let json = serde_json::to_vec_pretty(record).map_err(std::io::Error::other)?;
+        std::fs::write(self.path(&record.key), json)
+    }
+
+    /// Forget the job for `key`. Missing records are not an error.
+    pub fn remove(&self, key: &str) {
+        let _ = std::fs::remove_file(self.path(key));
+    }
+
+    /// Every stored record, oldest submission first.
+    pub fn list(&self) -> Vec<JobRecord> {
+        let Ok(entries) = std::fs::read_dir(&self.dir) else {
+            return Vec::new();
+        };
+        let mut records: Vec<JobRecord> = entries
+            .flatten()
+            .filter(|e| e.path().extension().is_some_and(|ext| ext == "json"))
+            .filter_map(|e| serde_json::from_slice(&std::fs::read(e.path()).ok()?).ok())
+            .collect();
+        records.sort_by_key(|r| r.submitted_at);
+        records
+    }
+}
+
+pub(crate) fn unix_now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
+}
@@ -5,6 +5,9 @@
 //! submit a job with [`DatalabClient::submit_marker`] and poll for the result
 //! with [`DatalabClient::get_marker_result`], or use the convenience method
 //! [`DatalabClient::convert_document`] which handles polling automatically.
+//! [`DatalabClient::convert_document_tracked`] also records the job in a
+//! [`JobStore`], so a conversion interrupted by a crash resumes by polling
+//! the existing job rather than resubmitting the document.
 //!
 //! # Quick start
 //!
@@ -35,10 +38,12 @@
 
 pub mod client;
 pub mod error;
+pub mod jobs;
 pub mod types;
 
 pub use client::DatalabClient;
 pub use error::{DatalabError, Result};
+pub use jobs::{JobProgress, JobRecord, JobStore};
 pub use types::{
     MarkerPollResponse, MarkerRequest, MarkerStatus, MarkerSubmitResponse, OutputFormat,
     ProcessingMode, StepType, StepTypesResponse,
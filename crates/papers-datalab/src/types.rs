@@ -21,7 +21,7 @@ pub enum ProcessingMode {
     Accurate,
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum MarkerStatus {
     Processing,
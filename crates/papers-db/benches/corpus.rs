@@ -0,0 +1,120 @@
+//! End-to-end benchmarks over a synthetic corpus (`papers_db::fixtures`):
+//! search with neighbor fetching, outlines, paper listing, and ingest into a
+//! populated store. The corpus is deterministic, so numbers are comparable
+//! across commits.
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use tempfile::TempDir;
+
+use papers_db::fixtures::{self, CorpusSpec};
+use papers_db::query::{get_paper_outline, list_papers};
+use papers_db::{DbStore, IngestParams, ListPapersParams, SearchMode, SearchParams};
+
+struct Corpus {
+    _dir: TempDir,
+    store: DbStore,
+    papers: Vec<IngestParams>,
+}
+
+fn corpus(rt: &tokio::runtime::Runtime, papers: usize) -> Corpus {
+    let dir = TempDir::new().unwrap();
+    let spec = CorpusSpec { papers, ..Default::default() };
+    let (store, papers) = rt.block_on(async {
+        let store = DbStore::open_for_test(dir.path().join("rag").to_str().unwrap()).await.unwrap();
+        let papers = fixtures::load_corpus(&store, &dir.path().join("cache"), &spec).await.unwrap();
+        (store, papers)
+    });
+    Corpus { _dir: dir, store, papers }
+}
+
+fn search_params(limit: u16) -> SearchParams {
+    SearchParams {
+        query: String::new(),
+        paper_ids: None,
+        chapter_idx: None,
+        section_idx: None,
+        filter_year_min: None,
+        filter_year_max: None,
+        filter_venue: None,
+        filter_tags: None,
+        filter_depth: None,
+        mode: SearchMode::Semantic,
+        rerank: false,
+        limit,
+    }
+}
+
+fn list_params(filter_tags: Option<Vec<String>>) -> ListPapersParams {
+    ListPapersParams {
+        paper_ids: None,
+        filter_year_min: None,
+        filter_year_max: None,
+        filter_venue: None,
+        filter_tags,
+        filter_authors: None,
+        sort_by: None,
+        limit: 50,
+    }
+}
+
+fn bench_search_neighbors(c: &mut Criterion) {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let mut group = c.benchmark_group("corpus_search");
+    group.sample_size(20);
+
+    let query = fixtures::unit_vector(1);
+    for &papers in &[100, 1000] {
+        let corpus = corpus(&rt, papers);
+        for &limit in &[5u16, 20] {
+            group.bench_with_input(BenchmarkId::new(format!("papers{papers}"), limit), &limit, |b, &limit| {
+                b.to_async(&rt)
+                    .iter(|| papers_db::search_with_embedding(&corpus.store, search_params(limit), &query));
+            });
+        }
+    }
+    group.finish();
+}
+
+fn bench_outline_and_list(c: &mut Criterion) {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let corpus = corpus(&rt, 1000);
+    let mut group = c.benchmark_group("corpus_browse");
+    group.sample_size(20);
+
+    let paper_id = corpus.papers[corpus.papers.len() / 2].paper_id.clone();
+    group.bench_function("outline", |b| {
+        b.to_async(&rt).iter(|| get_paper_outline(&corpus.store, &paper_id));
+    });
+    group.bench_function("list_papers", |b| {
+        b.to_async(&rt).iter(|| list_papers(&corpus.store, list_params(None)));
+    });
+    group.bench_function("list_papers_by_tag", |b| {
+        b.to_async(&rt)
+            .iter(|| list_papers(&corpus.store, list_params(Some(vec!["rendering".to_string()]))));
+    });
+    group.finish();
+}
+
+fn bench_ingest(c: &mut Criterion) {
+    // Keep the embedding cache out of the platform cache dir. Set before the
+    // runtime starts its worker threads, so nothing reads the env concurrently.
+    let embed_cache = TempDir::new().unwrap();
+    unsafe {
+        std::env::set_var("PAPERS_EMBED_CACHE_DIR", embed_cache.path());
+    }
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let corpus = corpus(&rt, 1000);
+    let mut group = c.benchmark_group("corpus_ingest");
+    group.sample_size(10);
+
+    // Re-ingesting replaces the paper's rows and rebuilds the indexes, the
+    // same work as adding a new paper to a 1k-paper library.
+    let paper = corpus.papers[0].clone();
+    group.bench_function("paper", |b| {
+        b.to_async(&rt).iter(|| papers_db::ingest_paper(&corpus.store, paper.clone()));
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_search_neighbors, bench_outline_and_list, bench_ingest);
+criterion_main!(benches);
@@ -7,7 +7,7 @@ use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
 use rand::Rng;
 use std::sync::Arc;
 
-use papers_db::schema::{EMBED_DIM, chunks_schema, figures_schema};
+use papers_db::schema::{EMBED_DIM, chunks_schema, exhibits_schema};
 
 fn build_string_list_array(lists: &[Vec<String>]) -> arrow_array::ListArray {
     let mut builder = ListBuilder::new(StringBuilder::new());
@@ -57,7 +57,7 @@ async fn insert_synthetic_chunks(store: &papers_db::DbStore, n: usize) {
     let mut years: Vec<Option<u16>> = Vec::with_capacity(n);
     let mut venues: Vec<Option<&str>> = Vec::with_capacity(n);
     let mut tags_list = Vec::with_capacity(n);
-    let mut figure_ids_list = Vec::with_capacity(n);
+    let mut exhibit_ids_list = Vec::with_capacity(n);
 
     for i in 0..n {
         let ch = (i / 40) as u16;
@@ -82,7 +82,7 @@ async fn insert_synthetic_chunks(store: &papers_db::DbStore, n: usize) {
         years.push(Some(2024));
         venues.push(Some("SIGGRAPH"));
         tags_list.push(vec!["bench".to_string()]);
-        figure_ids_list.push(vec![]);
+        exhibit_ids_list.push(vec![]);
     }
 
     let vectors = build_vector_array(&embeddings);
@@ -116,7 +116,9 @@ async fn insert_synthetic_chunks(store: &papers_db::DbStore, n: usize) {
             Arc::new(UInt16Array::from(years)),
             Arc::new(StringArray::from(venues)),
             Arc::new(build_string_list_array(&tags_list)),
-            Arc::new(build_string_list_array(&figure_ids_list)),
+            Arc::new(build_string_list_array(&exhibit_ids_list)),
+            Arc::new(StringArray::from(vec!["block"; n])),
+            Arc::new(StringArray::from(vec![None::<&str>; n])),
         ],
     )
     .unwrap();
@@ -126,17 +128,17 @@ async fn insert_synthetic_chunks(store: &papers_db::DbStore, n: usize) {
     table.add(Box::new(reader)).execute().await.unwrap();
 }
 
-/// Insert `n` synthetic figures into the store.
-async fn insert_synthetic_figures(store: &papers_db::DbStore, n: usize) {
+/// Insert `n` synthetic exhibits into the store.
+async fn insert_synthetic_exhibits(store: &papers_db::DbStore, n: usize) {
     let mut rng = rand::thread_rng();
-    let schema = figures_schema();
+    let schema = exhibits_schema();
 
     let paper_id = "bench-paper";
 
-    let mut figure_ids = Vec::with_capacity(n);
+    let mut exhibit_ids = Vec::with_capacity(n);
     let mut paper_ids = Vec::with_capacity(n);
     let mut embeddings = Vec::with_capacity(n);
-    let mut figure_types = Vec::with_capacity(n);
+    let mut exhibit_types = Vec::with_capacity(n);
     let mut captions = Vec::with_capacity(n);
     let mut descriptions = Vec::with_capacity(n);
     let mut image_paths: Vec<Option<&str>> = Vec::with_capacity(n);
@@ -149,12 +151,14 @@ async fn insert_synthetic_figures(store: &papers_db::DbStore, n: usize) {
     let mut years: Vec<Option<u16>> = Vec::with_capacity(n);
     let mut venues: Vec<Option<&str>> = Vec::with_capacity(n);
     let mut tags_list: Vec<Vec<String>> = Vec::with_capacity(n);
+    let mut first_ref_chunk_ids: Vec<Option<String>> = Vec::with_capacity(n);
+    let mut ref_counts = Vec::with_capacity(n);
 
     for i in 0..n {
-        figure_ids.push(format!("{paper_id}/fig{i}"));
+        exhibit_ids.push(format!("{paper_id}/fig{i}"));
         paper_ids.push(paper_id.to_string());
         embeddings.push(random_vector(&mut rng));
-        figure_types.push(if i % 3 == 0 { "table" } else { "figure" }.to_string());
+        exhibit_types.push(if i % 3 == 0 { "table" } else { "figure" }.to_string());
         captions.push(format!("Fig. {i}. Synthetic figure caption."));
         descriptions.push(format!("Description of figure {i}."));
         image_paths.push(None);
@@ -167,12 +171,14 @@ async fn insert_synthetic_figures(store: &papers_db::DbStore, n: usize) {
         years.push(Some(2024));
         venues.push(None);
         tags_list.push(vec![]);
+        first_ref_chunk_ids.push(Some(format!("{paper_id}/ch{}/s0/p0", i / 10)));
+        ref_counts.push(1u16);
     }
 
     let vectors = build_vector_array(&embeddings);
-    let fig_id_refs: Vec<&str> = figure_ids.iter().map(|s| s.as_str()).collect();
+    let exhibit_id_refs: Vec<&str> = exhibit_ids.iter().map(|s| s.as_str()).collect();
     let paper_id_refs: Vec<&str> = paper_ids.iter().map(|s| s.as_str()).collect();
-    let ft_refs: Vec<&str> = figure_types.iter().map(|s| s.as_str()).collect();
+    let et_refs: Vec<&str> = exhibit_types.iter().map(|s| s.as_str()).collect();
     let cap_refs: Vec<&str> = captions.iter().map(|s| s.as_str()).collect();
     let desc_refs: Vec<&str> = descriptions.iter().map(|s| s.as_str()).collect();
     let title_refs: Vec<&str> = titles.iter().map(|s| s.as_str()).collect();
@@ -180,10 +186,10 @@ async fn insert_synthetic_figures(store: &papers_db::DbStore, n: usize) {
     let batch = RecordBatch::try_new(
         schema.clone(),
         vec![
-            Arc::new(StringArray::from(fig_id_refs)),
+            Arc::new(StringArray::from(exhibit_id_refs)),
             Arc::new(StringArray::from(paper_id_refs)),
             Arc::new(vectors),
-            Arc::new(StringArray::from(ft_refs)),
+            Arc::new(StringArray::from(et_refs)),
             Arc::new(StringArray::from(cap_refs)),
             Arc::new(StringArray::from(desc_refs)),
             Arc::new(StringArray::from(image_paths)),
@@ -196,12 +202,15 @@ async fn insert_synthetic_figures(store: &papers_db::DbStore, n: usize) {
             Arc::new(UInt16Array::from(years)),
             Arc::new(StringArray::from(venues)),
             Arc::new(build_string_list_array(&tags_list)),
+            Arc::new(StringArray::from(first_ref_chunk_ids)),
+            Arc::new(UInt16Array::from(ref_counts)),
+            Arc::new(StringArray::from(vec![None::<&str>; n])),
         ],
     )
     .unwrap();
 
     let reader = RecordBatchIterator::new(vec![Ok(batch)], schema);
-    let table = store.figures_table().await.unwrap();
+    let table = store.exhibits_table().await.unwrap();
     table.add(Box::new(reader)).execute().await.unwrap();
 }
 
@@ -236,6 +245,8 @@ fn bench_search(c: &mut Criterion) {
                     filter_venue: None,
                     filter_tags: None,
                     filter_depth: None,
+                    mode: papers_db::SearchMode::Semantic,
+                    rerank: false,
                     limit: 5,
                 };
                 papers_db::search_with_embedding(&store, params, &query_vec)
@@ -245,10 +256,10 @@ fn bench_search(c: &mut Criterion) {
     group.finish();
 }
 
-fn bench_search_figures(c: &mut Criterion) {
+fn bench_search_exhibits(c: &mut Criterion) {
     let rt = tokio::runtime::Runtime::new().unwrap();
 
-    let mut group = c.benchmark_group("search_figures");
+    let mut group = c.benchmark_group("search_exhibits");
     group.sample_size(20);
 
     for &n in &[100, 500, 1000] {
@@ -257,27 +268,27 @@ fn bench_search_figures(c: &mut Criterion) {
 
         let store = rt.block_on(async {
             let s = papers_db::DbStore::open_for_test(&db_path).await.unwrap();
-            insert_synthetic_figures(&s, n).await;
+            insert_synthetic_exhibits(&s, n).await;
             s
         });
 
         let mut rng = rand::thread_rng();
         let query_vec = random_vector(&mut rng);
 
-        group.bench_with_input(BenchmarkId::new("figures", n), &n, |b, _| {
+        group.bench_with_input(BenchmarkId::new("exhibits", n), &n, |b, _| {
             b.to_async(&rt).iter(|| {
-                let params = papers_db::SearchFiguresParams {
+                let params = papers_db::SearchExhibitsParams {
                     query: String::new(),
                     paper_ids: None,
-                    filter_figure_type: None,
+                    filter_exhibit_type: None,
                     limit: 5,
                 };
-                papers_db::search_figures_with_embedding(&store, params, &query_vec)
+                papers_db::search_exhibits_with_embedding(&store, params, &query_vec)
             });
         });
     }
     group.finish();
 }
 
-criterion_group!(benches, bench_search, bench_search_figures);
+criterion_group!(benches, bench_search, bench_search_exhibits);
 criterion_main!(benches);
@@ -0,0 +1,311 @@
+//! Reference-list parsing and inline citation linking for the citations table.
+//!
+//! References come from the paper's bibliography as plain text, one entry
+//! (or wrapped line) per line. Numeric markers (`[12]`, `[3, 5–7]`) resolve
+//! by entry number; author–year markers (`(Smith et al. 2020)`,
+//! `Smith and Jones (2020)`) by first-author surname and year.
+
+use std::collections::HashSet;
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::ingest::ChunkRecord;
+use crate::is_abbreviation;
+
+/// Widest `[a–b]` range expanded; wider ones are almost certainly not citations.
+const MAX_RANGE: u32 = 50;
+
+static ENTRY_LABEL_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\s*(?:\[(\d{1,4})\]|(\d{1,4})\.\s)\s*").unwrap());
+static DOI_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)\b10\.\d{4,9}/[^\s]+").unwrap());
+static YEAR_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\b(?:19|20)\d{2}\b").unwrap());
+static NUMERIC_MARKER_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\[(\d{1,4}(?:\s*[-–,]\s*\d{1,4})*)\]").unwrap());
+static AUTHOR_YEAR_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"\b(\p{Lu}[\p{L}'’-]+)(?:\s+et\s+al\.?|\s+(?:and|&)\s+\p{Lu}[\p{L}'’-]+)?(?:,?\s+((?:19|20)\d{2})[a-z]?\b|\s+\(((?:19|20)\d{2})[a-z]?\))",
+    )
+    .unwrap()
+});
+
+/// One entry of a paper's reference list.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Reference {
+    /// Entry number for numbered lists (`[12]` or `12.`).
+    pub(crate) number: Option<u32>,
+    /// Entry text without its number.
+    pub(crate) text: String,
+    /// Lowercased DOI, if the entry has one.
+    pub(crate) doi: Option<String>,
+    /// First author's surname, lowercased.
+    pub(crate) surname: Option<String>,
+    pub(crate) year: Option<u16>,
+}
+
+/// An inline citation matched to a reference entry.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct CitationRecord {
+    pub(crate) citation_id: String,
+    pub(crate) chunk_id: String,
+    /// The marker as written, e.g. `[3, 5-7]` or `Smith et al. (2020)`.
+    pub(crate) marker: String,
+    /// The sentence containing the marker.
+    pub(crate) sentence: String,
+    /// `"12"` for numbered references, `"smith 2020"` for author–year ones.
+    pub(crate) ref_label: String,
+    pub(crate) ref_text: String,
+    pub(crate) cited_doi: Option<String>,
+}
+
+/// Split a references section into entries.
+///
+/// Numbered lists start a new entry at each label and fold other lines into
+/// the previous one. Unnumbered lists take each line as an entry, folding a
+/// line into the previous one when that didn't end with a period (a wrapped
+/// entry).
+pub(crate) fn parse_references(text: &str) -> Vec<Reference> {
+    let lines: Vec<&str> = text.lines().map(str::trim).filter(|l| !l.is_empty()).collect();
+    let numbered = lines.iter().any(|l| ENTRY_LABEL_RE.is_match(l));
+
+    let mut entries: Vec<(Option<u32>, String)> = Vec::new();
+    for line in lines {
+        let label = ENTRY_LABEL_RE.captures(line).and_then(|caps| {
+            let n = caps.get(1).or_else(|| caps.get(2))?.as_str().parse::<u32>().ok()?;
+            Some((n, caps.get(0)?.end()))
+        });
+        match (label, entries.last_mut()) {
+            (Some((n, end)), _) if numbered => entries.push((Some(n), line[end..].to_string())),
+            (None, Some((_, prev))) if numbered || !prev.ends_with('.') => {
+                prev.push(' ');
+                prev.push_str(line);
+            }
+            _ => entries.push((None, line.to_string())),
+        }
+    }
+
+    entries
+        .into_iter()
+        .map(|(number, text)| Reference {
+            number,
+            doi: DOI_RE.find(&text).map(|m| {
+                m.as_str().trim_end_matches(['.', ',', ';', ')', ']']).to_lowercase()
+            }),
+            surname: first_surname(&text),
+            year: YEAR_RE.find(&text).and_then(|m| m.as_str().parse().ok()),
+            text,
+        })
+        .collect()
+}
+
+/// The first word of an entry that looks like a surname rather than an
+/// initial (`Smith, J.` and `J. Smith` both give `smith`).
+fn first_surname(entry: &str) -> Option<String> {
+    entry
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .take(4)
+        .find(|w| w.chars().filter(|c| c.is_alphabetic()).count() >= 2 && !w.ends_with('.'))
+        .map(|w| w.trim_matches(|c: char| !c.is_alphabetic()).to_lowercase())
+}
+
+/// Lowercase alphanumeric words joined by single spaces, for matching titles
+/// against reference text regardless of punctuation and case.
+pub(crate) fn normalize_key(text: &str) -> String {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Find citation markers in `chunks` that resolve to an entry of `references`.
+///
+/// Table chunks are skipped (they repeat the table's cells), and a sentence
+/// repeated by chunk overlap is only linked once per reference.
+pub(crate) fn link_citations(chunks: &[ChunkRecord], references: &[Reference]) -> Vec<CitationRecord> {
+    let mut records: Vec<CitationRecord> = Vec::new();
+    if references.is_empty() {
+        return records;
+    }
+    let numbered = references.iter().any(|r| r.number.is_some());
+    let mut seen: HashSet<(String, String)> = HashSet::new();
+
+    for chunk in chunks.iter().filter(|c| c.block_type != "table") {
+        let mut found: Vec<(usize, usize, &Reference, String)> = Vec::new();
+        if numbered {
+            for caps in NUMERIC_MARKER_RE.captures_iter(&chunk.text) {
+                let m = caps.get(0).unwrap();
+                for n in expand_numbers(&caps[1]) {
+                    if let Some(r) = references.iter().find(|r| r.number == Some(n)) {
+                        found.push((m.start(), m.end(), r, n.to_string()));
+                    }
+                }
+            }
+        } else {
+            for caps in AUTHOR_YEAR_RE.captures_iter(&chunk.text) {
+                let m = caps.get(0).unwrap();
+                let surname = caps[1].to_lowercase();
+                let Some(year) = caps.get(2).or_else(|| caps.get(3)).map(|y| y.as_str()) else {
+                    continue;
+                };
+                if let Some(r) = references.iter().find(|r| {
+                    r.surname.as_deref() == Some(surname.as_str()) && r.year == year.parse().ok()
+                }) {
+                    found.push((m.start(), m.end(), r, format!("{surname} {year}")));
+                }
+            }
+        }
+
+        for (start, end, reference, ref_label) in found {
+            let sentence = sentence_around(&chunk.text, start, end);
+            if !seen.insert((sentence.clone(), ref_label.clone())) {
+                continue;
+            }
+            records.push(CitationRecord {
+                citation_id: format!("{}/c{}", chunk.chunk_id, records.len() + 1),
+                chunk_id: chunk.chunk_id.clone(),
+                marker: chunk.text[start..end].to_string(),
+                sentence,
+                ref_label,
+                ref_text: reference.text.clone(),
+                cited_doi: reference.doi.clone(),
+            });
+        }
+    }
+    records
+}
+
+/// `"3, 5-7"` → `[3, 5, 6, 7]`. Descending or over-wide ranges keep only
+/// their endpoints.
+fn expand_numbers(list: &str) -> Vec<u32> {
+    let mut numbers = Vec::new();
+    for part in list.split(',') {
+        let bounds: Vec<u32> = part
+            .split(['-', '–'])
+            .filter_map(|n| n.trim().parse().ok())
+            .collect();
+        match bounds[..] {
+            [a, b] if a <= b && b - a <= MAX_RANGE => numbers.extend(a..=b),
+            _ => numbers.extend(bounds),
+        }
+    }
+    numbers.dedup();
+    numbers
+}
+
+/// The sentence of `text` containing the byte range `start..end`.
+fn sentence_around(text: &str, start: usize, end: usize) -> String {
+    let is_boundary = |i: usize| {
+        let Some(c) = text[..i].chars().next_back() else { return false };
+        if c == '\n' {
+            return true;
+        }
+        if !matches!(c, '.' | '?' | '!') || !text[i..].starts_with(char::is_whitespace) {
+            return false;
+        }
+        let before = &text[..i - c.len_utf8()];
+        let word = before.rsplit(char::is_whitespace).next().unwrap_or("");
+        c != '.' || !is_abbreviation(word)
+    };
+
+    let from = (1..=start)
+        .rev()
+        .filter(|&i| text.is_char_boundary(i))
+        .find(|&i| is_boundary(i))
+        .unwrap_or(0);
+    let to = (end..=text.len())
+        .filter(|&i| text.is_char_boundary(i))
+        .find(|&i| i > end && is_boundary(i))
+        .unwrap_or(text.len());
+    text[from..to].trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunk(id: &str, text: &str) -> ChunkRecord {
+        ChunkRecord {
+            chunk_id: id.to_string(),
+            chapter_title: "Intro".to_string(),
+            chapter_idx: 1,
+            section_title: String::new(),
+            section_idx: 0,
+            chunk_idx: 0,
+            block_type: "text".to_string(),
+            text: text.to_string(),
+            page_start: None,
+            page_end: None,
+            exhibit_ids: vec![],
+            equation_tex: vec![],
+        }
+    }
+
+    #[test]
+    fn parse_numbered_references() {
+        let refs = parse_references(
+            "[1] J. Smith and K. Lee. Fast solvers.\nIn Proc. SIGGRAPH, 2020. doi:10.1145/123.456.\n\
+             [2] Doe, A. Slow solvers. 2019.\n",
+        );
+        assert_eq!(refs.len(), 2);
+        assert_eq!(refs[0].number, Some(1));
+        assert_eq!(refs[0].text, "J. Smith and K. Lee. Fast solvers. In Proc. SIGGRAPH, 2020. doi:10.1145/123.456.");
+        assert_eq!(refs[0].doi.as_deref(), Some("10.1145/123.456"));
+        assert_eq!(refs[0].surname.as_deref(), Some("smith"));
+        assert_eq!(refs[0].year, Some(2020));
+        assert_eq!(refs[1].surname.as_deref(), Some("doe"));
+    }
+
+    #[test]
+    fn parse_author_year_references_folds_wrapped_lines() {
+        let refs = parse_references(
+            "Smith, J., Lee, K., 2020. Fast solvers for\nelastic bodies. ACM TOG.\nDoe, A., 2019. Slow solvers.",
+        );
+        assert_eq!(refs.len(), 2);
+        assert_eq!(refs[0].number, None);
+        assert!(refs[0].text.ends_with("elastic bodies. ACM TOG."));
+        assert_eq!((refs[1].surname.as_deref(), refs[1].year), (Some("doe"), Some(2019)));
+    }
+
+    #[test]
+    fn link_numeric_citations_with_ranges() {
+        let refs = parse_references("[1] Smith. A. 2020.\n[2] Lee. B. 2019.\n[3] Doe. C. 2018.");
+        let chunks = [chunk(
+            "p/ch1/s0/p0",
+            "Prior work is extensive. Solvers such as Smith et al. [1-3] are fast. See also [9].",
+        )];
+        let citations = link_citations(&chunks, &refs);
+        let labels: Vec<&str> = citations.iter().map(|c| c.ref_label.as_str()).collect();
+        assert_eq!(labels, ["1", "2", "3"]);
+        assert_eq!(citations[0].sentence, "Solvers such as Smith et al. [1-3] are fast.");
+        assert_eq!(citations[0].marker, "[1-3]");
+        assert_eq!(citations[2].citation_id, "p/ch1/s0/p0/c3");
+    }
+
+    #[test]
+    fn link_author_year_citations_and_dedupe_overlap() {
+        let refs = parse_references("Smith, J., 2020. Fast solvers.\nLee, K., 2019. Slow solvers.");
+        let text = "We build on Smith et al. (2020). Others disagree (Lee and Park, 2019; Smith 2021).";
+        let chunks = [chunk("p/ch1/s0/p0", text), chunk("p/ch1/s0/p1", text)];
+        let citations = link_citations(&chunks, &refs);
+        let labels: Vec<&str> = citations.iter().map(|c| c.ref_label.as_str()).collect();
+        assert_eq!(labels, ["smith 2020", "lee 2019"]);
+        assert_eq!(citations[0].sentence, "We build on Smith et al. (2020).");
+        assert_eq!(citations[0].marker, "Smith et al. (2020)");
+        assert_eq!(citations[1].marker, "Lee and Park, 2019");
+        assert!(citations.iter().all(|c| c.chunk_id == "p/ch1/s0/p0"));
+    }
+
+    #[test]
+    fn expand_numbers_handles_lists_and_bad_ranges() {
+        assert_eq!(expand_numbers("3, 5-7"), [3, 5, 6, 7]);
+        assert_eq!(expand_numbers("9–2"), [9, 2]);
+        assert_eq!(expand_numbers("1-500"), [1, 500]);
+    }
+
+    #[test]
+    fn normalize_key_ignores_case_and_punctuation() {
+        assert_eq!(normalize_key("Fast Solvers: A  Survey."), "fast solvers a survey");
+    }
+}
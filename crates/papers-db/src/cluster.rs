@@ -0,0 +1,250 @@
+//! Spherical k-means over unit vectors and title-term labelling, used by
+//! `query::cluster_works` to group papers by their chunk centroids.
+
+use std::collections::{HashMap, HashSet};
+
+const MAX_ITERATIONS: usize = 50;
+/// Upper bound on the cluster counts tried when `k` is chosen automatically.
+const AUTO_MAX_K: usize = 12;
+
+const TITLE_STOPWORDS: &[&str] = &[
+    "and", "for", "the", "with", "from", "into", "onto", "over", "under", "via", "using", "towards",
+    "toward", "its", "their", "our", "are", "is", "can", "not", "new", "based", "approach",
+    "method", "methods", "study", "analysis", "paper", "case", "use",
+];
+
+/// Result of one k-means run.
+#[derive(Debug)]
+pub(crate) struct Clustering {
+    /// Cluster index of each input vector.
+    pub(crate) assignments: Vec<usize>,
+    /// Unit centroid of each cluster.
+    pub(crate) centroids: Vec<Vec<f32>>,
+    /// Mean silhouette coefficient on cosine distance; 0 for a single cluster.
+    pub(crate) silhouette: f32,
+}
+
+pub(crate) fn dot(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+fn normalize(v: &mut [f32]) {
+    let norm = v.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        v.iter_mut().for_each(|x| *x /= norm);
+    }
+}
+
+fn nearest(vector: &[f32], centroids: &[Vec<f32>]) -> usize {
+    let mut best = 0;
+    let mut best_sim = f32::NEG_INFINITY;
+    for (i, c) in centroids.iter().enumerate() {
+        let sim = dot(vector, c);
+        if sim > best_sim {
+            best = i;
+            best_sim = sim;
+        }
+    }
+    best
+}
+
+/// Cluster unit `vectors` into `k` groups by cosine similarity.
+///
+/// Seeds are picked farthest-first starting from the first vector, so the
+/// result is deterministic for a given input order. `k` is clamped to
+/// `1..=vectors.len()`.
+pub(crate) fn kmeans(vectors: &[Vec<f32>], k: usize) -> Clustering {
+    if vectors.is_empty() {
+        return Clustering { assignments: Vec::new(), centroids: Vec::new(), silhouette: 0.0 };
+    }
+    let k = k.clamp(1, vectors.len());
+
+    let mut centroids = vec![vectors[0].clone()];
+    while centroids.len() < k {
+        let farthest = vectors
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (i, dot(v, &centroids[nearest(v, &centroids)])))
+            .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+            .map_or(0, |(i, _)| i);
+        centroids.push(vectors[farthest].clone());
+    }
+
+    let mut assignments = vec![usize::MAX; vectors.len()];
+    for _ in 0..MAX_ITERATIONS {
+        let next: Vec<usize> = vectors.iter().map(|v| nearest(v, &centroids)).collect();
+        if next == assignments {
+            break;
+        }
+        assignments = next;
+        for (c, centroid) in centroids.iter_mut().enumerate() {
+            let mut sum = vec![0.0f32; centroid.len()];
+            let mut members = 0;
+            for (v, _) in vectors.iter().zip(&assignments).filter(|(_, a)| **a == c) {
+                sum.iter_mut().zip(v).for_each(|(s, x)| *s += x);
+                members += 1;
+            }
+            // An emptied cluster keeps its previous centroid.
+            if members > 0 {
+                normalize(&mut sum);
+                *centroid = sum;
+            }
+        }
+    }
+
+    let silhouette = silhouette(vectors, &assignments, k);
+    Clustering { assignments, centroids, silhouette }
+}
+
+/// Mean silhouette coefficient of `assignments`, using `1 - cosine` as the
+/// distance. Points alone in their cluster score 0.
+pub(crate) fn silhouette(vectors: &[Vec<f32>], assignments: &[usize], k: usize) -> f32 {
+    if k < 2 || vectors.len() < 2 {
+        return 0.0;
+    }
+    let mut total = 0.0f32;
+    for (i, v) in vectors.iter().enumerate() {
+        let mut sums = vec![0.0f32; k];
+        let mut counts = vec![0usize; k];
+        for (j, w) in vectors.iter().enumerate() {
+            if i != j {
+                sums[assignments[j]] += 1.0 - dot(v, w);
+                counts[assignments[j]] += 1;
+            }
+        }
+        let own = assignments[i];
+        if counts[own] == 0 {
+            continue;
+        }
+        let a = sums[own] / counts[own] as f32;
+        let b = (0..k)
+            .filter(|&c| c != own && counts[c] > 0)
+            .map(|c| sums[c] / counts[c] as f32)
+            .fold(f32::INFINITY, f32::min);
+        if b.is_finite() && a.max(b) > 0.0 {
+            total += (b - a) / a.max(b);
+        }
+    }
+    total / vectors.len() as f32
+}
+
+/// Cluster with the `k` in `2..=min(AUTO_MAX_K, n / 2)` that has the highest
+/// silhouette; a single cluster when there are fewer than four vectors.
+pub(crate) fn kmeans_auto(vectors: &[Vec<f32>]) -> Clustering {
+    let max_k = AUTO_MAX_K.min(vectors.len() / 2);
+    if max_k < 2 {
+        return kmeans(vectors, 1);
+    }
+    let mut best = kmeans(vectors, 2);
+    for k in 3..=max_k {
+        let candidate = kmeans(vectors, k);
+        if candidate.silhouette > best.silhouette {
+            best = candidate;
+        }
+    }
+    best
+}
+
+fn title_terms(title: &str) -> HashSet<String> {
+    title
+        .split(|c: char| !c.is_alphanumeric() && c != '-')
+        .map(|w| w.trim_matches('-').to_lowercase())
+        .filter(|w| w.chars().count() >= 3 && !w.chars().all(|c| c.is_ascii_digit()))
+        .filter(|w| !TITLE_STOPWORDS.contains(&w.as_str()))
+        .collect()
+}
+
+/// The `n` terms that best distinguish `member_titles` from `all_titles`:
+/// ranked by the number of member titles containing the term times its
+/// inverse document frequency across all titles. Terms in only one member
+/// title are skipped unless the cluster has a single paper.
+pub(crate) fn label_terms(member_titles: &[&str], all_titles: &[&str], n: usize) -> Vec<String> {
+    let mut corpus_df: HashMap<String, usize> = HashMap::new();
+    for title in all_titles {
+        for term in title_terms(title) {
+            *corpus_df.entry(term).or_default() += 1;
+        }
+    }
+    let mut member_df: HashMap<String, usize> = HashMap::new();
+    for title in member_titles {
+        for term in title_terms(title) {
+            *member_df.entry(term).or_default() += 1;
+        }
+    }
+
+    let min_df = if member_titles.len() > 1 { 2 } else { 1 };
+    let total = all_titles.len().max(1) as f32;
+    let mut scored: Vec<(String, f32)> = member_df
+        .into_iter()
+        .filter(|(_, df)| *df >= min_df)
+        .map(|(term, df)| {
+            let corpus = corpus_df.get(&term).copied().unwrap_or(df).max(1) as f32;
+            let score = df as f32 * (1.0 + (total / corpus).ln());
+            (term, score)
+        })
+        .collect();
+    scored.sort_by(|a, b| {
+        b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal).then_with(|| a.0.cmp(&b.0))
+    });
+    scored.into_iter().take(n).map(|(term, _)| term).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unit(v: &[f32]) -> Vec<f32> {
+        let mut v = v.to_vec();
+        normalize(&mut v);
+        v
+    }
+
+    fn two_groups() -> Vec<Vec<f32>> {
+        vec![
+            unit(&[1.0, 0.1, 0.0]),
+            unit(&[0.0, 0.1, 1.0]),
+            unit(&[1.0, 0.0, 0.1]),
+            unit(&[0.1, 0.0, 1.0]),
+            unit(&[0.9, 0.1, 0.1]),
+            unit(&[0.1, 0.1, 0.9]),
+        ]
+    }
+
+    #[test]
+    fn kmeans_separates_obvious_groups() {
+        let c = kmeans(&two_groups(), 2);
+        assert_eq!(c.assignments, [0, 1, 0, 1, 0, 1]);
+        assert_eq!(c.centroids.len(), 2);
+        assert!(c.silhouette > 0.8, "silhouette {}", c.silhouette);
+    }
+
+    #[test]
+    fn kmeans_clamps_k_and_handles_empty_input() {
+        assert_eq!(kmeans(&two_groups()[..2], 5).centroids.len(), 2);
+        assert_eq!(kmeans(&two_groups(), 0).assignments, [0; 6]);
+        assert!(kmeans(&[], 3).assignments.is_empty());
+    }
+
+    #[test]
+    fn kmeans_auto_picks_k_by_silhouette() {
+        let mut vectors = two_groups();
+        vectors.push(unit(&[0.0, 1.0, 0.1]));
+        vectors.push(unit(&[0.1, 1.0, 0.0]));
+        let c = kmeans_auto(&vectors);
+        assert_eq!(c.centroids.len(), 3);
+        assert_eq!(kmeans_auto(&vectors[..3]).centroids.len(), 1);
+    }
+
+    #[test]
+    fn label_terms_prefer_shared_distinctive_words() {
+        let all = [
+            "Neural Radiance Fields for View Synthesis",
+            "Fast Radiance Fields with Hash Encoding",
+            "Path Tracing for Global Illumination",
+            "Bidirectional Path Tracing",
+        ];
+        let terms = label_terms(&all[..2], &all, 3);
+        assert_eq!(terms, ["fields", "radiance"]);
+        assert_eq!(label_terms(&all[3..], &all, 1), ["bidirectional"]);
+    }
+}
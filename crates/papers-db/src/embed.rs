@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use fastembed::{EmbeddingModel, InitOptions, TextEmbedding};
+use fastembed::{EmbeddingModel, InitOptions, RerankInitOptions, RerankerModel, TextEmbedding, TextRerank};
 
 use crate::error::DbError;
 #[cfg(any(test, feature = "bench"))]
@@ -70,6 +70,9 @@ fn init_ort_runtime() -> Result<(), DbError> {
 
 pub struct Embedder {
     model: Option<TextEmbedding>,
+    /// Length of the zero vectors returned without a model (tests and benches).
+    #[cfg(any(test, feature = "bench"))]
+    fake_dim: usize,
 }
 
 impl std::fmt::Debug for Embedder {
@@ -104,13 +107,24 @@ impl Embedder {
         }
 
         let model = TextEmbedding::try_new(opts).map_err(|e| DbError::Embed(e.to_string()))?;
-        Ok(Self { model: Some(model) })
+        Ok(Self {
+            model: Some(model),
+            #[cfg(any(test, feature = "bench"))]
+            fake_dim: EMBED_DIM as usize,
+        })
     }
 
     /// Test-only: create an embedder that returns zero vectors without loading any model.
     #[cfg(any(test, feature = "bench"))]
     pub fn fake() -> Self {
-        Self { model: None }
+        Self::fake_with_dim(EMBED_DIM as usize)
+    }
+
+    /// Test-only: like [`Embedder::fake`], with vectors of length `dim`, to
+    /// stand in for a model of another dimension.
+    #[cfg(any(test, feature = "bench"))]
+    pub fn fake_with_dim(dim: usize) -> Self {
+        Self { model: None, fake_dim: dim }
     }
 
     /// Embed documents at ingest time.
@@ -124,7 +138,7 @@ impl Embedder {
                 #[cfg(any(test, feature = "bench"))]
                 return Ok(texts
                     .iter()
-                    .map(|_| vec![0.0f32; EMBED_DIM as usize])
+                    .map(|_| vec![0.0f32; self.fake_dim])
                     .collect());
                 #[cfg(not(any(test, feature = "bench")))]
                 unreachable!("Embedder has no model; Embedder::fake() is test-only");
@@ -142,7 +156,7 @@ impl Embedder {
             Some(m) => m,
             None => {
                 #[cfg(any(test, feature = "bench"))]
-                return Ok(vec![0.0f32; EMBED_DIM as usize]);
+                return Ok(vec![0.0f32; self.fake_dim]);
                 #[cfg(not(any(test, feature = "bench")))]
                 unreachable!("Embedder has no model; Embedder::fake() is test-only");
             }
@@ -156,3 +170,117 @@ impl Embedder {
             .ok_or_else(|| DbError::Embed("empty embedding result".into()))
     }
 }
+
+/// Env var selecting the cross-encoder used by `rerank` searches.
+pub const RERANK_MODEL_ENV: &str = "PAPERS_RERANK_MODEL";
+
+/// Cross-encoder models accepted in `PAPERS_RERANK_MODEL`; the first is the default.
+const RERANK_MODELS: &[(&str, RerankerModel)] = &[
+    ("bge-reranker-base", RerankerModel::BGERerankerBase),
+    ("bge-reranker-v2-m3", RerankerModel::BGERerankerV2M3),
+    ("jina-reranker-v1-turbo-en", RerankerModel::JINARerankerV1TurboEn),
+    ("jina-reranker-v2-base-multilingual", RerankerModel::JINARerankerV2BaseMultiligual),
+];
+
+/// Name of the configured reranker model (`PAPERS_RERANK_MODEL` or the default).
+pub fn rerank_model_name() -> String {
+    std::env::var(RERANK_MODEL_ENV)
+        .ok()
+        .filter(|v| !v.trim().is_empty())
+        .unwrap_or_else(|| RERANK_MODELS[0].0.to_string())
+}
+
+fn rerank_model(name: &str) -> Result<RerankerModel, DbError> {
+    RERANK_MODELS
+        .iter()
+        .find(|(n, _)| n.eq_ignore_ascii_case(name.trim()))
+        .map(|(_, m)| m.clone())
+        .ok_or_else(|| {
+            let valid: Vec<&str> = RERANK_MODELS.iter().map(|(n, _)| *n).collect();
+            DbError::Embed(format!(
+                "unknown {RERANK_MODEL_ENV} '{name}' (expected one of: {})",
+                valid.join(", ")
+            ))
+        })
+}
+
+/// Cross-encoder that scores (query, passage) pairs jointly.
+pub struct Reranker {
+    model: Option<TextRerank>,
+}
+
+impl std::fmt::Debug for Reranker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Reranker").finish()
+    }
+}
+
+impl Reranker {
+    /// Blocking constructor — call from spawn_blocking.
+    /// Downloads model weights on first run from the HF Hub cache.
+    pub fn new(name: &str) -> Result<Self, DbError> {
+        let model_kind = rerank_model(name)?;
+
+        #[cfg(target_os = "windows")]
+        init_ort_runtime()?;
+
+        let cache_dir = dirs::cache_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("papers")
+            .join("fastembed");
+
+        let opts = RerankInitOptions::new(model_kind).with_cache_dir(cache_dir);
+        #[cfg(target_os = "windows")]
+        let opts = opts.with_execution_providers(vec![ort::ep::cuda::CUDA::default().build()]);
+        #[cfg(target_os = "macos")]
+        let opts = opts.with_execution_providers(vec![ort::ep::coreml::CoreML::default().build()]);
+
+        let model = TextRerank::try_new(opts).map_err(|e| DbError::Embed(e.to_string()))?;
+        Ok(Self { model: Some(model) })
+    }
+
+    /// Test-only: create a reranker that scores by query-term overlap without loading a model.
+    #[cfg(any(test, feature = "bench"))]
+    pub fn fake() -> Self {
+        Self { model: None }
+    }
+
+    /// Score each document against `query`. Scores are returned in input order;
+    /// higher means more relevant.
+    pub fn rerank(&mut self, query: &str, documents: &[String]) -> Result<Vec<f32>, DbError> {
+        if documents.is_empty() {
+            return Ok(vec![]);
+        }
+        let model = match &mut self.model {
+            Some(m) => m,
+            None => {
+                #[cfg(any(test, feature = "bench"))]
+                return Ok(documents.iter().map(|d| term_overlap(query, d)).collect());
+                #[cfg(not(any(test, feature = "bench")))]
+                unreachable!("Reranker has no model; Reranker::fake() is test-only");
+            }
+        };
+        let refs: Vec<&str> = documents.iter().map(|s| s.as_str()).collect();
+        let results = model
+            .rerank(query, refs, false, None)
+            .map_err(|e| DbError::Embed(e.to_string()))?;
+        let mut scores = vec![f32::MIN; documents.len()];
+        for r in results {
+            if let Some(slot) = scores.get_mut(r.index) {
+                *slot = r.score;
+            }
+        }
+        Ok(scores)
+    }
+}
+
+/// Fraction of query terms that occur in `doc` (case-insensitive).
+#[cfg(any(test, feature = "bench"))]
+fn term_overlap(query: &str, doc: &str) -> f32 {
+    let doc = doc.to_lowercase();
+    let terms: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+    if terms.is_empty() {
+        return 0.0;
+    }
+    terms.iter().filter(|t| doc.contains(t.as_str())).count() as f32 / terms.len() as f32
+}
@@ -18,4 +18,6 @@ pub enum DbError {
     Cache(String),
     #[error("Ingest error: {0}")]
     Ingest(String),
+    #[error("Migration error: {0}")]
+    Migrate(String),
 }
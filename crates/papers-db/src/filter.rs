@@ -51,6 +51,11 @@ impl FilterBuilder {
         self
     }
 
+    pub fn not_null(mut self, col: &str) -> Self {
+        self.clauses.push(format!("{col} IS NOT NULL"));
+        self
+    }
+
     /// Filter: tags list contains any of the given tags.
     pub fn tags_any(mut self, tags: &[String]) -> Self {
         if tags.is_empty() {
@@ -0,0 +1,356 @@
+//! Synthetic paper corpus for tests, benchmarks, and `papers demo`.
+//!
+//! Papers are DataLab Marker JSON plus `meta.json`, laid out like the DataLab
+//! cache (`<root>/<item_key>/<item_key>.json`), so they go through the same
+//! parser as real extractions. Output is a pure function of [`CorpusSpec`], so
+//! benchmark runs on different commits measure the same corpus.
+
+use std::path::{Path, PathBuf};
+
+use arrow_array::{RecordBatch, RecordBatchIterator};
+use arrow_schema::ArrowError;
+use serde_json::{Value, json};
+
+use crate::error::DbError;
+use crate::ingest::{IngestParams, build_chunks_batch, build_exhibits_batch, parse_paper_blocks};
+use crate::schema::{EMBED_DIM, chunks_schema, exhibits_schema};
+use crate::store::DbStore;
+use crate::types::ChunkingConfig;
+
+const WORDS: &[&str] = &[
+    "adaptive", "algorithm", "analysis", "approximation", "baseline", "bounded", "cache",
+    "coherent", "convergence", "dataset", "deferred", "density", "differentiable", "error",
+    "estimator", "evaluation", "field", "filter", "geometry", "gradient", "graph", "hierarchy",
+    "illumination", "importance", "kernel", "latency", "layer", "lighting", "mesh", "model",
+    "network", "neural", "noise", "occlusion", "optimization", "parallel", "path", "probability",
+    "radiance", "ray", "reconstruction", "rendering", "sampling", "scene", "shading", "signal",
+    "simulation", "sparse", "spectral", "stochastic", "surface", "temporal", "texture",
+    "throughput", "tracing", "training", "variance", "vertex", "visibility", "volume",
+];
+
+const CHAPTER_TITLES: &[&str] = &[
+    "Introduction", "Related Work", "Background", "Method", "Implementation", "Experiments",
+    "Results", "Discussion", "Limitations", "Conclusion",
+];
+
+const GIVEN_INITIALS: &[&str] = &["A.", "B.", "C.", "D.", "E.", "J.", "K.", "L.", "M.", "S."];
+
+const FAMILY_NAMES: &[&str] = &[
+    "Chen", "Garcia", "Kim", "Müller", "Nakamura", "Novak", "Okafor", "Rossi", "Silva", "Smith",
+    "Wang", "Zhang",
+];
+
+const VENUES: &[&str] = &["SIGGRAPH", "SIGGRAPH Asia", "EGSR", "HPG", "CVPR", "NeurIPS"];
+
+const TAGS: &[&str] = &["rendering", "GPU", "geometry", "learning", "simulation", "sampling"];
+
+/// Shape of the generated corpus.
+#[derive(Debug, Clone)]
+pub struct CorpusSpec {
+    pub papers: usize,
+    /// Top-level (h2) chapters per paper.
+    pub chapters: usize,
+    /// Subsections (h3) per chapter, after the chapter's own lead paragraphs.
+    pub sections: usize,
+    /// Paragraphs per section.
+    pub paragraphs: usize,
+    /// Figures and tables per paper (alternating), one per odd-numbered
+    /// chapter, wrapping around when there are more than chapters.
+    pub figures: usize,
+    /// Display equations per chapter, after the chapter's lead paragraphs.
+    pub equations: usize,
+    pub seed: u64,
+}
+
+impl Default for CorpusSpec {
+    fn default() -> Self {
+        Self {
+            papers: 1000,
+            chapters: 6,
+            sections: 2,
+            paragraphs: 3,
+            figures: 3,
+            equations: 1,
+            seed: 0x5eed,
+        }
+    }
+}
+
+/// xorshift64*. Fixtures can't use `rand`, which is only a dev-dependency.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Self(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut x = self.0;
+        x ^= x >> 12;
+        x ^= x << 25;
+        x ^= x >> 27;
+        self.0 = x;
+        x.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// Uniform in `[-1, 1)`.
+    fn signed_unit(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 23) as f32 - 1.0
+    }
+
+    fn pick<'a>(&mut self, items: &[&'a str]) -> &'a str {
+        items[self.below(items.len())]
+    }
+}
+
+/// Item key of the `idx`-th synthetic paper (`SYN00042`).
+pub fn item_key(idx: usize) -> String {
+    format!("SYN{idx:05}")
+}
+
+/// A random unit vector, e.g. a stand-in query embedding.
+pub fn unit_vector(seed: u64) -> Vec<f32> {
+    let mut rng = Rng::new(seed);
+    let mut v: Vec<f32> = (0..EMBED_DIM).map(|_| rng.signed_unit()).collect();
+    let norm = v.iter().map(|x| x * x).sum::<f32>().sqrt().max(f32::EPSILON);
+    v.iter_mut().for_each(|x| *x /= norm);
+    v
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    chars
+        .next()
+        .map(|c| c.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+fn words(rng: &mut Rng, n: usize) -> Vec<&'static str> {
+    (0..n).map(|_| rng.pick(WORDS)).collect()
+}
+
+fn sentence(rng: &mut Rng) -> String {
+    let n = 8 + rng.below(12);
+    format!("{}.", capitalize(&words(rng, n).join(" ")))
+}
+
+fn paragraph(rng: &mut Rng) -> String {
+    let n = 3 + rng.below(4);
+    (0..n).map(|_| sentence(rng)).collect::<Vec<_>>().join(" ")
+}
+
+fn heading(words: &[&str]) -> String {
+    words.iter().map(|w| capitalize(w)).collect::<Vec<_>>().join(" ")
+}
+
+/// A display equation in the LaTeX Marker puts inside `<math>`.
+fn equation(rng: &mut Rng) -> String {
+    const LHS: &[&str] = &["L(x)", "E[f]", "\\hat{I}", "\\sigma^2", "w_i", "\\nabla \\theta"];
+    const TERMS: &[&str] = &[
+        "\\sum_{i=1}^{N} w_i f(x_i)",
+        "\\frac{1}{N} \\sum_{k} p(x_k)",
+        "\\int_{\\Omega} L(\\omega) \\cos\\theta \\, d\\omega",
+        "\\lambda \\lVert x \\rVert^2",
+        "\\exp(-\\alpha t)",
+        "\\mathbb{E}[(X - \\mu)^2]",
+    ];
+    let lhs = rng.pick(LHS);
+    let a = rng.pick(TERMS);
+    let b = rng.pick(TERMS);
+    format!("{lhs} = {a} + {b}")
+}
+
+/// Marker JSON and ingest parameters for the `idx`-th paper of `spec`, with
+/// its cache directory under `root`. Nothing is written.
+pub fn synthetic_paper(spec: &CorpusSpec, idx: usize, root: &Path) -> (IngestParams, Value) {
+    let mut rng = Rng::new(spec.seed ^ (idx as u64).wrapping_add(1).wrapping_mul(0xD1B5_4A32_D192_ED03));
+    let key = item_key(idx);
+
+    let title_len = 3 + rng.below(5);
+    let title = heading(&words(&mut rng, title_len));
+    let mut authors: Vec<String> = Vec::new();
+    for _ in 0..1 + rng.below(5) {
+        let name = format!("{} {}", rng.pick(GIVEN_INITIALS), rng.pick(FAMILY_NAMES));
+        if !authors.contains(&name) {
+            authors.push(name);
+        }
+    }
+    let mut tags: Vec<String> = Vec::new();
+    for _ in 0..1 + rng.below(2) {
+        let tag = rng.pick(TAGS).to_string();
+        if !tags.contains(&tag) {
+            tags.push(tag);
+        }
+    }
+
+    let mut blocks = vec![json!({
+        "block_type": "SectionHeader",
+        "id": "sh_title",
+        "page": 0,
+        "html": format!("<h1>{title}</h1>"),
+        "section_hierarchy": {},
+    })];
+    let mut page = 0u16;
+    let mut figures = 0usize;
+    let mut equations = 0usize;
+    let mut push = |blocks: &mut Vec<Value>, block_type: &str, id: String, html: String, hierarchy: Value| {
+        // Roughly four blocks to a page.
+        if blocks.len().is_multiple_of(4) {
+            page += 1;
+        }
+        blocks.push(json!({
+            "block_type": block_type,
+            "id": id,
+            "page": page,
+            "html": html,
+            "section_hierarchy": hierarchy,
+        }));
+    };
+
+    for ch in 0..spec.chapters {
+        let ch_id = format!("sh_ch{ch}");
+        let ch_title = match CHAPTER_TITLES.get(ch) {
+            Some(t) => t.to_string(),
+            None => heading(&words(&mut rng, 2)),
+        };
+        let ch_hierarchy = json!({"1": "sh_title", "2": ch_id});
+        push(&mut blocks, "SectionHeader", ch_id.clone(), format!("<h2>{ch_title}</h2>"), json!({"1": "sh_title"}));
+        for p in 0..spec.paragraphs {
+            let html = format!("<p>{}</p>", paragraph(&mut rng));
+            push(&mut blocks, "Text", format!("t_ch{ch}_p{p}"), html, ch_hierarchy.clone());
+        }
+        for _ in 0..spec.equations {
+            equations += 1;
+            let html = format!("<p><math display=\"block\">{}</math></p>", equation(&mut rng));
+            push(&mut blocks, "Equation", format!("eq{equations}"), html, ch_hierarchy.clone());
+        }
+        // Figures and tables go in odd-numbered chapters, referenced from the text.
+        let chapter_figures = (0..spec.figures).filter(|&f| (2 * f + 1) % spec.chapters == ch).count();
+        for _ in 0..chapter_figures {
+            figures += 1;
+            let kind = if figures % 2 == 1 { "Figure" } else { "Table" };
+            let caption = sentence(&mut rng);
+            let html = format!("<p>As shown in {kind} {figures}, {}</p>", sentence(&mut rng).to_lowercase());
+            push(&mut blocks, "Text", format!("t_ch{ch}_ref"), html, ch_hierarchy.clone());
+            let html = format!("<img src=\"x{figures}.png\" alt=\"{kind} {figures}: {caption}\"/>");
+            push(&mut blocks, kind, format!("x{figures}"), html, ch_hierarchy.clone());
+            let html = format!("<p>{kind} {figures}. {caption}</p>");
+            push(&mut blocks, "Caption", format!("cap{figures}"), html, ch_hierarchy.clone());
+        }
+        for s in 0..spec.sections {
+            let sec_id = format!("sh_ch{ch}_s{s}");
+            let sec_title = format!("{}.{} {}", ch + 1, s + 1, heading(&words(&mut rng, 2)));
+            let sec_hierarchy = json!({"1": "sh_title", "2": ch_id, "3": sec_id});
+            push(&mut blocks, "SectionHeader", sec_id.clone(), format!("<h3>{sec_title}</h3>"), sec_hierarchy.clone());
+            for p in 0..spec.paragraphs {
+                let html = format!("<p>{}</p>", paragraph(&mut rng));
+                push(&mut blocks, "Text", format!("t_ch{ch}_s{s}_p{p}"), html, sec_hierarchy.clone());
+            }
+        }
+    }
+
+    let params = IngestParams {
+        item_key: key.clone(),
+        paper_id: key.clone(),
+        title,
+        authors,
+        year: Some(2000 + rng.below(25) as u16),
+        venue: Some(rng.pick(VENUES).to_string()),
+        tags,
+        cache_dir: root.join(&key),
+        force: false,
+        chunking: ChunkingConfig::default(),
+    };
+    let marker = json!({"children": [{"block_type": "Page", "children": blocks}]});
+    (params, marker)
+}
+
+/// Write every paper of `spec` under `root` as `<key>/<key>.json` plus
+/// `<key>/meta.json`, returning their ingest parameters in order.
+pub fn write_corpus(root: &Path, spec: &CorpusSpec) -> Result<Vec<IngestParams>, DbError> {
+    let mut out = Vec::with_capacity(spec.papers);
+    for idx in 0..spec.papers {
+        let (params, marker) = synthetic_paper(spec, idx, root);
+        std::fs::create_dir_all(&params.cache_dir)?;
+        std::fs::write(
+            params.cache_dir.join(format!("{}.json", params.item_key)),
+            serde_json::to_vec(&marker)?,
+        )?;
+        let meta = papers_core::text::ExtractionMeta {
+            item_key: params.item_key.clone(),
+            zotero_user_id: None,
+            title: Some(params.title.clone()),
+            authors: Some(params.authors.clone()),
+            item_type: Some("conferencePaper".to_string()),
+            date: params.year.map(|y| y.to_string()),
+            doi: None,
+            url: None,
+            publication_title: params.venue.clone(),
+            extracted_at: None,
+            processing_mode: Some("synthetic".to_string()),
+            pdf_source: None,
+        };
+        std::fs::write(params.cache_dir.join("meta.json"), serde_json::to_vec_pretty(&meta)?)?;
+        out.push(params);
+    }
+    Ok(out)
+}
+
+/// Default scratch directory for `papers demo`:
+/// `{PAPERS_DATA_DIR or platform data dir}/demo`.
+pub fn demo_dir() -> PathBuf {
+    std::env::var("PAPERS_DATA_DIR")
+        .ok()
+        .map(PathBuf::from)
+        .or_else(|| dirs::data_dir().map(|d| d.join("papers")))
+        .unwrap_or_else(|| PathBuf::from(".papers"))
+        .join("demo")
+}
+
+/// Write the corpus and bulk-load it into `store`: one insert per table and a
+/// single index build, with seeded random unit vectors in place of
+/// embeddings. Much faster than `ingest_paper` per paper, which rebuilds the
+/// indexes after every insert, so use it to set up stores to query.
+pub async fn load_corpus(
+    store: &DbStore,
+    root: &Path,
+    spec: &CorpusSpec,
+) -> Result<Vec<IngestParams>, DbError> {
+    let papers = write_corpus(root, spec)?;
+    let mut chunk_batches: Vec<RecordBatch> = Vec::new();
+    let mut exhibit_batches: Vec<RecordBatch> = Vec::new();
+    let mut seed = spec.seed;
+    let mut vectors = |n: usize| -> Vec<Vec<f32>> {
+        (0..n)
+            .map(|_| {
+                seed = seed.wrapping_add(1);
+                unit_vector(seed)
+            })
+            .collect()
+    };
+    for params in &papers {
+        let (chunks, exhibits) = parse_paper_blocks(params)?;
+        if !chunks.is_empty() {
+            chunk_batches.push(build_chunks_batch(params, &chunks, &vectors(chunks.len()))?);
+        }
+        if !exhibits.is_empty() {
+            exhibit_batches.push(build_exhibits_batch(params, &exhibits, &vectors(exhibits.len()))?);
+        }
+    }
+
+    if !chunk_batches.is_empty() {
+        let reader = RecordBatchIterator::new(chunk_batches.into_iter().map(Ok::<_, ArrowError>), chunks_schema());
+        store.chunks_table().await?.add(Box::new(reader)).execute().await?;
+    }
+    if !exhibit_batches.is_empty() {
+        let reader =
+            RecordBatchIterator::new(exhibit_batches.into_iter().map(Ok::<_, ArrowError>), exhibits_schema());
+        store.exhibits_table().await?.add(Box::new(reader)).execute().await?;
+    }
+    store.ensure_indexes().await;
+    Ok(papers)
+}
@@ -0,0 +1,174 @@
+//! Splitting a Markdown draft into the paragraphs `query::ground_draft`
+//! finds sources for.
+//!
+//! Paragraphs are runs of non-blank lines. Headings, fenced code, tables,
+//! HTML comments and image lines are not prose and are skipped, as are
+//! paragraphs too short to make a claim. Existing citations — `[@key]`,
+//! `\cite{..}`, `[12]`, `(Author, 2020)`, `Author et al. (2020)` — are
+//! detected so uncited paragraphs can be told apart, and stripped from the
+//! query text together with link targets and emphasis markers.
+
+/// Paragraphs with fewer words are skipped.
+pub(crate) const MIN_PARAGRAPH_WORDS: usize = 8;
+
+/// A prose paragraph of the draft.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct DraftParagraph {
+    /// 1-based line the paragraph starts on.
+    pub(crate) line: usize,
+    pub(crate) text: String,
+    pub(crate) cited: bool,
+    /// `text` without citations and Markdown markup, used as the query.
+    pub(crate) query: String,
+}
+
+/// The prose paragraphs of `markdown`, and how many blocks were skipped.
+pub(crate) fn paragraphs(markdown: &str) -> (Vec<DraftParagraph>, usize) {
+    let mut blocks: Vec<(usize, Vec<&str>)> = Vec::new();
+    let mut current: Option<(usize, Vec<&str>)> = None;
+    let mut in_fence = false;
+    let mut skipped = 0;
+    for (i, line) in markdown.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            blocks.extend(current.take());
+            if !in_fence {
+                skipped += 1;
+            }
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            continue;
+        }
+        if trimmed.is_empty() {
+            blocks.extend(current.take());
+            continue;
+        }
+        if trimmed.starts_with('#') {
+            blocks.extend(current.take());
+            skipped += 1;
+            continue;
+        }
+        current.get_or_insert_with(|| (i + 1, Vec::new())).1.push(trimmed);
+    }
+    blocks.extend(current);
+
+    let mut out = Vec::new();
+    for (line, lines) in blocks {
+        let is_markup = lines.iter().all(|l| l.starts_with('|'))
+            || lines.iter().all(|l| l.starts_with("!["))
+            || (lines[0].starts_with("<!--") && lines[lines.len() - 1].ends_with("-->"));
+        let text = lines.join(" ");
+        let query = strip_markup(&text);
+        if is_markup || query.split_whitespace().count() < MIN_PARAGRAPH_WORDS {
+            skipped += 1;
+            continue;
+        }
+        out.push(DraftParagraph { line, cited: has_citation(&text), text, query });
+    }
+    (out, skipped)
+}
+
+fn is_year(s: &str) -> bool {
+    let digits: String = s.chars().take_while(char::is_ascii_digit).collect();
+    digits.len() == 4 && (digits.starts_with("19") || digits.starts_with("20"))
+}
+
+/// Whether `text` already cites something.
+pub(crate) fn has_citation(text: &str) -> bool {
+    if text.contains("[@") || text.contains("\\cite") || text.contains("et al.") {
+        return true;
+    }
+    // `[12]`, `[3, 4]`, `[5-7]`
+    let numeric = text.split('[').skip(1).any(|rest| {
+        rest.split_once(']').is_some_and(|(inner, _)| {
+            !inner.is_empty() && inner.chars().all(|c| c.is_ascii_digit() || ", -–".contains(c))
+        })
+    });
+    // `(Author, 2020)`, `(Author 2020a; Other 2021)`
+    let author_year = text.split('(').skip(1).any(|rest| {
+        rest.split_once(')').is_some_and(|(inner, _)| {
+            inner.chars().next().is_some_and(char::is_uppercase)
+                && inner.split(|c: char| c.is_whitespace() || c == ',' || c == ';').any(is_year)
+        })
+    });
+    numeric || author_year
+}
+
+/// `text` without citations, link targets, inline code and emphasis markers.
+pub(crate) fn strip_markup(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        // Drop bracketed citations and `\cite{..}` wholesale.
+        if rest.starts_with("[@") || rest.starts_with("\\cite") {
+            let close = if rest.starts_with('[') { ']' } else { '}' };
+            rest = rest.find(close).map_or("", |end| &rest[end + 1..]);
+            continue;
+        }
+        // `[text](url)` keeps `text`.
+        if c == ']' && rest[1..].starts_with('(') {
+            rest = rest.find(')').map_or("", |end| &rest[end + 1..]);
+            continue;
+        }
+        if !matches!(c, '*' | '_' | '`' | '[' | ']' | '>') {
+            out.push(c);
+        }
+        rest = &rest[c.len_utf8()..];
+    }
+    out.split_whitespace().collect::<Vec<_>>().join(" ").replace(" .", ".").replace(" ,", ",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DRAFT: &str = "# Introduction
+
+Neural radiance fields represent scenes as continuous volumetric functions learned from posed images.
+
+Gaussian splatting replaces ray marching with rasterized anisotropic Gaussians [@kerbl2023].
+
+```python
+print(\"not prose\")
+```
+
+| Method | FPS |
+|--------|-----|
+
+Short one.
+";
+
+    #[test]
+    fn prose_paragraphs_are_kept_with_their_lines() {
+        let (paras, skipped) = paragraphs(DRAFT);
+        assert_eq!(paras.iter().map(|p| p.line).collect::<Vec<_>>(), [3, 5]);
+        assert_eq!(paras.iter().map(|p| p.cited).collect::<Vec<_>>(), [false, true]);
+        assert_eq!(paras[1].query, "Gaussian splatting replaces ray marching with rasterized anisotropic Gaussians.");
+        // Heading, code block, table and the short paragraph.
+        assert_eq!(skipped, 4);
+    }
+
+    #[test]
+    fn citation_styles_are_detected() {
+        for cited in [
+            "as shown before [12].",
+            "as shown before [3, 5-7].",
+            "as shown by (Mildenhall, 2020).",
+            "as shown by (Kerbl et al. 2023; Wu 2024a).",
+            "Mildenhall et al. (2020) showed this.",
+            "as shown before \\cite{nerf}.",
+        ] {
+            assert!(has_citation(cited), "{cited}");
+        }
+        for uncited in ["see [the appendix](#a).", "this holds (see below).", "in 2020 (roughly)."] {
+            assert!(!has_citation(uncited), "{uncited}");
+        }
+    }
+
+    #[test]
+    fn markup_is_stripped_from_queries() {
+        assert_eq!(strip_markup("**Fast** [rendering](https://x.y) with `CUDA` \\cite{a} works"), "Fast rendering with CUDA works");
+    }
+}
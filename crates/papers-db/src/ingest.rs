@@ -9,13 +9,17 @@ use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
 
+use crate::citations::{CitationRecord, link_citations, normalize_key, parse_references};
 use crate::config::*;
 use crate::error::DbError;
-use crate::schema::{EMBED_DIM, chunks_schema, exhibits_schema};
+use crate::schema::{EMBED_DIM, abstracts_schema, chunks_schema, citations_schema, exhibits_schema};
+use crate::split_sentences;
 use crate::store::DbStore;
-use crate::types::IngestStats;
+use crate::types::{ChunkStrategy, ChunkingConfig, IngestStats};
 use lancedb::index::Index;
+use papers_core::abstracts::PaperAbstract;
 
+#[derive(Clone)]
 pub struct IngestParams {
     pub item_key: String,
     pub paper_id: String,
@@ -27,6 +31,8 @@ pub struct IngestParams {
     pub cache_dir: PathBuf,
     /// When `true`, bypass the embedding cache and re-embed from scratch.
     pub force: bool,
+    /// How body text is cut into chunks; recorded with the paper's chunks.
+    pub chunking: ChunkingConfig,
 }
 
 pub(crate) struct ChunkRecord {
@@ -41,6 +47,8 @@ pub(crate) struct ChunkRecord {
     pub(crate) page_start: Option<u16>,
     pub(crate) page_end: Option<u16>,
     pub(crate) exhibit_ids: Vec<String>,
+    /// LaTeX of the display equations in this chunk, in reading order.
+    pub(crate) equation_tex: Vec<String>,
 }
 
 pub(crate) struct ExhibitRecord {
@@ -50,6 +58,8 @@ pub(crate) struct ExhibitRecord {
     pub(crate) description: Option<String>,
     pub(crate) image_path: Option<String>,
     pub(crate) content: Option<String>,
+    /// Normalized cell text of a table, one `Header: value; …` line per row.
+    pub(crate) table_text: Option<String>,
     pub(crate) page: Option<u16>,
     pub(crate) chapter_idx: u16,
     pub(crate) section_idx: u16,
@@ -67,25 +77,31 @@ fn estimate_tokens(text: &str) -> usize {
 // ── ChunkBuffer ───────────────────────────────────────────────────────────────
 
 struct ChunkBuffer {
+    config: ChunkingConfig,
     paragraphs: Vec<String>,
     token_count: usize,
     page_start: Option<u16>,
     page_end: Option<u16>,
+    /// LaTeX of display equations pushed since the last flush.
+    equations: Vec<String>,
 }
 
 struct FlushedChunk {
     text: String,
     page_start: Option<u16>,
     page_end: Option<u16>,
+    equations: Vec<String>,
 }
 
 impl ChunkBuffer {
-    fn new() -> Self {
+    fn new(config: ChunkingConfig) -> Self {
         Self {
+            config,
             paragraphs: Vec::new(),
             token_count: 0,
             page_start: None,
             page_end: None,
+            equations: Vec::new(),
         }
     }
 
@@ -101,7 +117,36 @@ impl ChunkBuffer {
     }
 
     fn would_overflow(&self, text: &str) -> bool {
-        self.token_count + estimate_tokens(text) > TARGET_CHUNK_TOKENS
+        self.token_count + estimate_tokens(text) > self.config.target_tokens
+    }
+
+    /// With [`ChunkStrategy::SentenceWindow`], cut a block longer than
+    /// `max_tokens` into runs of whole sentences of at most `target_tokens`;
+    /// pushing them one by one then flushes between runs, carrying the overlap.
+    /// Anything else (including a single over-long sentence) comes back whole.
+    fn split_block(&self, text: &str) -> Vec<String> {
+        if self.config.strategy != ChunkStrategy::SentenceWindow
+            || estimate_tokens(text) <= self.config.max_tokens
+        {
+            return vec![text.to_string()];
+        }
+        let mut windows = Vec::new();
+        let mut current: Vec<&str> = Vec::new();
+        let mut tokens = 0;
+        for sentence in split_sentences(text) {
+            let sentence_tokens = estimate_tokens(sentence);
+            if !current.is_empty() && tokens + sentence_tokens > self.config.target_tokens {
+                windows.push(current.join(" "));
+                current.clear();
+                tokens = 0;
+            }
+            current.push(sentence);
+            tokens += sentence_tokens;
+        }
+        if !current.is_empty() {
+            windows.push(current.join(" "));
+        }
+        windows
     }
 
     fn is_empty(&self) -> bool {
@@ -117,6 +162,7 @@ impl ChunkBuffer {
             text,
             page_start: self.page_start,
             page_end: self.page_end,
+            equations: std::mem::take(&mut self.equations),
         };
         self.paragraphs.clear();
         self.token_count = 0;
@@ -125,9 +171,9 @@ impl ChunkBuffer {
         Some(chunk)
     }
 
-    /// Extract the last N sentences from text to use as overlap in the next buffer.
-    fn overlap_tail(text: &str) -> String {
-        if OVERLAP_SENTENCES == 0 {
+    /// Extract the last `sentences` sentences from text to use as overlap in the next buffer.
+    fn overlap_tail(text: &str, sentences: usize) -> String {
+        if sentences == 0 {
             return String::new();
         }
         // Simple sentence boundary: period/question/exclamation followed by space or end
@@ -144,14 +190,18 @@ impl ChunkBuffer {
             // Not enough sentences to extract overlap
             return String::new();
         }
-        // Take the last OVERLAP_SENTENCES sentence boundaries
-        let start_boundary = if boundaries.len() >= OVERLAP_SENTENCES + 1 {
-            boundaries[boundaries.len() - OVERLAP_SENTENCES - 1]
+        // Take the last `sentences` sentence boundaries
+        let start_boundary = if boundaries.len() >= sentences + 1 {
+            boundaries[boundaries.len() - sentences - 1]
         } else {
             0
         };
         text[start_boundary..].trim().to_string()
     }
+
+    fn overlap(&self, text: &str) -> String {
+        Self::overlap_tail(text, self.config.overlap_sentences)
+    }
 }
 
 // ── HTML processing ───────────────────────────────────────────────────────────
@@ -202,6 +252,38 @@ fn strip_html_preserve_math(html: &str) -> String {
     strip_html(&buf)
 }
 
+/// LaTeX content of each `<math>` element in `html`.
+fn extract_math_tex(html: &str) -> Vec<String> {
+    let mut tex = Vec::new();
+    let mut remaining = html;
+    while let Some(start) = remaining.find("<math") {
+        remaining = &remaining[start..];
+        let Some(tag_end) = remaining.find('>') else { break };
+        remaining = &remaining[tag_end + 1..];
+        let Some(close) = remaining.find("</math>") else { break };
+        let content = strip_html(&remaining[..close]);
+        if !content.is_empty() {
+            tex.push(content);
+        }
+        remaining = &remaining[close + 7..];
+    }
+    tex
+}
+
+/// `latex` without surrounding `$$…$$`, `$…$`, `\[…\]` or `\(…\)` delimiters.
+fn strip_math_delimiters(latex: &str) -> &str {
+    let latex = latex.trim();
+    [("$$", "$$"), ("\\[", "\\]"), ("\\(", "\\)"), ("$", "$")]
+        .iter()
+        .find_map(|(open, close)| {
+            latex
+                .strip_prefix(open)
+                .and_then(|rest| rest.strip_suffix(close))
+                .filter(|inner| !inner.is_empty())
+        })
+        .map_or(latex, str::trim)
+}
+
 /// Parsed cell from an HTML table row.
 struct ParsedCell {
     text: String,
@@ -463,6 +545,132 @@ fn html_table_to_markdown(html: &str) -> Option<String> {
     Some(lines.join("\n"))
 }
 
+/// Render a table as searchable text: one line per body row, each cell
+/// prefixed with its column header (`Method: Ours; PSNR: 32.1`). Rows without
+/// headers are joined with `; `. Returns `None` if no cell has text.
+fn normalize_table_text(header: &[String], rows: &[Vec<String>]) -> Option<String> {
+    let clean = |s: &str| s.split_whitespace().collect::<Vec<_>>().join(" ");
+    let mut lines: Vec<String> = Vec::new();
+    let header_line = header.iter().map(|h| clean(h)).filter(|h| !h.is_empty()).collect::<Vec<_>>();
+    if !header_line.is_empty() {
+        lines.push(header_line.join("; "));
+    }
+    for row in rows {
+        let cells: Vec<String> = row
+            .iter()
+            .enumerate()
+            .filter_map(|(col, cell)| {
+                let cell = clean(cell);
+                if cell.is_empty() {
+                    return None;
+                }
+                match header.get(col).map(|h| clean(h)) {
+                    Some(h) if !h.is_empty() && h != cell => Some(format!("{h}: {cell}")),
+                    _ => Some(cell),
+                }
+            })
+            .collect();
+        if !cells.is_empty() {
+            lines.push(cells.join("; "));
+        }
+    }
+    if lines.is_empty() { None } else { Some(lines.join("\n")) }
+}
+
+/// Normalized text of a DataLab `<table>` (see `normalize_table_text`).
+fn html_table_to_text(html: &str) -> Option<String> {
+    if !html.to_ascii_lowercase().contains("<table") {
+        return None;
+    }
+    let html = html
+        .replace("<br/>", " ")
+        .replace("<br>", " ")
+        .replace("<br />", " ")
+        .replace("<BR/>", " ")
+        .replace("<BR>", " ")
+        .replace("<BR />", " ");
+    let (grid, thead_row_count) = build_table_grid(&html);
+    let header = flatten_header(&grid, thead_row_count);
+    let rows: Vec<Vec<String>> = grid
+        .iter()
+        .skip(thead_row_count)
+        .map(|row| row.iter().map(|c| c.clone().unwrap_or_default()).collect())
+        .collect();
+    normalize_table_text(&header, &rows)
+}
+
+/// Normalized text of a pipe-delimited markdown table; the row above the
+/// `---` separator is the header. Lines outside the table are ignored.
+fn markdown_table_to_text(markdown: &str) -> Option<String> {
+    let is_separator = |cells: &[String]| {
+        !cells.is_empty()
+            && cells.iter().all(|c| !c.is_empty() && c.chars().all(|ch| matches!(ch, '-' | ':' | ' ')))
+    };
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    let mut header: Vec<String> = Vec::new();
+    for line in markdown.lines() {
+        let line = line.trim();
+        if !line.starts_with('|') {
+            continue;
+        }
+        let cells: Vec<String> = line
+            .trim_matches('|')
+            .split('|')
+            .map(|c| c.trim().to_string())
+            .collect();
+        if is_separator(&cells) {
+            if header.is_empty() && rows.len() == 1 {
+                header = rows.pop().unwrap_or_default();
+            }
+            continue;
+        }
+        rows.push(cells);
+    }
+    normalize_table_text(&header, &rows)
+}
+
+/// Append one `table` chunk per table exhibit with cell text, after the last
+/// chunk of the table's section, so chunk search matches table contents.
+/// Runs after exhibit linking: table chunks point at their own table and don't
+/// count as references to it.
+fn append_table_chunks(
+    paper_id: &str,
+    chunk_records: &mut Vec<ChunkRecord>,
+    exhibit_records: &[ExhibitRecord],
+) {
+    for er in exhibit_records {
+        let Some(table_text) = &er.table_text else { continue };
+        let mut in_section = chunk_records
+            .iter()
+            .filter(|c| c.chapter_idx == er.chapter_idx && c.section_idx == er.section_idx);
+        let chunk_idx = in_section.clone().map(|c| c.chunk_idx + 1).max().unwrap_or(0);
+        let (chapter_title, section_title) = in_section
+            .next_back()
+            .or_else(|| chunk_records.iter().rfind(|c| c.chapter_idx == er.chapter_idx))
+            .map(|c| (c.chapter_title.clone(), c.section_title.clone()))
+            .unwrap_or_default();
+        let text = if er.caption.is_empty() {
+            table_text.clone()
+        } else {
+            format!("{}\n\n{table_text}", er.caption)
+        };
+        chunk_records.push(ChunkRecord {
+            chunk_id: format!("{paper_id}/ch{}/s{}/p{chunk_idx}", er.chapter_idx, er.section_idx),
+            chapter_title,
+            chapter_idx: er.chapter_idx,
+            section_title,
+            section_idx: er.section_idx,
+            chunk_idx,
+            block_type: "table".to_string(),
+            text,
+            page_start: er.page,
+            page_end: er.page,
+            exhibit_ids: vec![er.exhibit_id.clone()],
+            equation_tex: vec![],
+        });
+    }
+}
+
 /// Extract `src` attribute value from an `<img>` tag.
 fn extract_img_src(html: &str) -> Option<String> {
     extract_attr(html, "src")
@@ -505,10 +713,7 @@ fn extract_cache_root() -> Option<PathBuf> {
 
 /// Return the DataLab cache root directory (legacy pipeline).
 fn datalab_cache_root() -> Option<PathBuf> {
-    if let Ok(p) = std::env::var("PAPERS_DATALAB_CACHE_DIR") {
-        return Some(PathBuf::from(p));
-    }
-    dirs::cache_dir().map(|d| d.join("papers").join("datalab"))
+    papers_core::text::datalab_cache_root()
 }
 
 /// Locate the cache directory for an item_key, preferring extract cache over datalab.
@@ -530,6 +735,17 @@ fn resolve_cache_dir(item_key: &str) -> Option<PathBuf> {
     None
 }
 
+/// Item key of the cached extraction that ingests as `paper_id`: the key
+/// itself, or the cached item whose metadata gives it that DOI.
+pub(crate) fn cached_item_key_for(paper_id: &str) -> Option<String> {
+    if resolve_cache_dir(paper_id).is_some() {
+        return Some(paper_id.to_string());
+    }
+    list_cached_item_keys()
+        .into_iter()
+        .find(|key| ingest_params_from_cache(key).is_ok_and(|p| p.paper_id == paper_id))
+}
+
 /// Build IngestParams from a cached item_key using meta.json.
 ///
 /// Prefers the new extract cache (`<cache>/papers/extracts/{key}/`) over the
@@ -579,6 +795,7 @@ pub fn ingest_params_from_cache(item_key: &str) -> Result<IngestParams, DbError>
         tags: vec![],
         cache_dir,
         force: false,
+        chunking: ChunkingConfig::default(),
     })
 }
 
@@ -596,7 +813,13 @@ pub fn embed_cache_base() -> PathBuf {
         .join("papers")
 }
 
+/// `PAPERS_EMBEDDING_MODEL`, else the model in the user's config.
 fn default_embed_model() -> String {
+    if let Ok(model) = std::env::var("PAPERS_EMBEDDING_MODEL")
+        && !model.trim().is_empty()
+    {
+        return model.trim().to_string();
+    }
     papers_core::config::PapersConfig::load()
         .map(|c| c.embedding_model)
         .unwrap_or_else(|_| "embedding-gemma-300m".to_string())
@@ -618,7 +841,7 @@ fn unix_timestamp_str() -> String {
 
 /// Parse the DataLab Marker JSON for a paper into raw `ChunkRecord` and
 /// `ExhibitRecord` lists.
-fn parse_paper_blocks(
+pub(crate) fn parse_paper_blocks(
     params: &IngestParams,
 ) -> Result<(Vec<ChunkRecord>, Vec<ExhibitRecord>), DbError> {
     let json_path = params.cache_dir.join(format!("{}.json", params.item_key));
@@ -677,7 +900,7 @@ fn parse_paper_blocks(
     let mut chunk_records: Vec<ChunkRecord> = Vec::new();
     let mut exhibit_records: Vec<ExhibitRecord> = Vec::new();
 
-    let mut buffer = ChunkBuffer::new();
+    let mut buffer = ChunkBuffer::new(params.chunking);
     let mut in_references = false;
 
     // Algorithm accumulation state
@@ -738,6 +961,7 @@ fn parse_paper_blocks(
             description: None,
             image_path: None,
             content: Some(content),
+            table_text: None,
             page,
             chapter_idx,
             section_idx,
@@ -771,6 +995,7 @@ fn parse_paper_blocks(
             page_start: flushed.page_start,
             page_end: flushed.page_end,
             exhibit_ids: vec![],
+            equation_tex: flushed.equations,
         });
         *chunk_idx += 1;
     };
@@ -791,10 +1016,11 @@ fn parse_paper_blocks(
                 let prev = chunk_records.last().unwrap();
                 if prev.chapter_idx == chapter_idx && prev.section_idx == section_idx {
                     let prev_tokens = estimate_tokens(&prev.text);
-                    if prev_tokens + f_tokens <= MAX_CHUNK_TOKENS {
+                    if prev_tokens + f_tokens <= params.chunking.max_tokens {
                         let prev = chunk_records.last_mut().unwrap();
                         prev.text.push_str("\n\n");
                         prev.text.push_str(&f.text);
+                        prev.equation_tex.extend(f.equations);
                         if let Some(p) = f.page_end {
                             prev.page_end = Some(p);
                         }
@@ -985,27 +1211,32 @@ fn parse_paper_blocks(
                 }
 
                 // Buffer-based accumulation
-                if !buffer.is_empty() && buffer.would_overflow(&text) {
-                    // Token-limit flush with overlap
-                    if let Some(flushed) = buffer.flush() {
-                        let overlap = ChunkBuffer::overlap_tail(&flushed.text);
-                        emit_chunk(
-                            flushed,
-                            &mut chunk_idx,
-                            &current_chapter_title,
-                            chapter_idx,
-                            &current_section_title,
-                            section_idx,
-                            &mut chunk_records,
-                            params,
-                        );
-                        // Carry overlap into new buffer
-                        if !overlap.is_empty() {
-                            buffer.push(overlap, page_num);
+                for piece in buffer.split_block(&text) {
+                    if !buffer.is_empty() && buffer.would_overflow(&piece) {
+                        // Token-limit flush with overlap
+                        if let Some(flushed) = buffer.flush() {
+                            let overlap = buffer.overlap(&flushed.text);
+                            emit_chunk(
+                                flushed,
+                                &mut chunk_idx,
+                                &current_chapter_title,
+                                chapter_idx,
+                                &current_section_title,
+                                section_idx,
+                                &mut chunk_records,
+                                params,
+                            );
+                            // Carry overlap into new buffer
+                            if !overlap.is_empty() {
+                                buffer.push(overlap, page_num);
+                            }
                         }
                     }
+                    buffer.push(piece, page_num);
+                }
+                if block_type == "Equation" {
+                    buffer.equations.extend(extract_math_tex(html));
                 }
-                buffer.push(text, page_num);
             }
 
             "Figure" | "Table" | "Picture" => {
@@ -1052,10 +1283,10 @@ fn parse_paper_blocks(
                     .unwrap_or_else(|| alt_text.clone());
                 let description = if alt_text.is_empty() { None } else { Some(alt_text) };
 
-                let content = if block_type == "Table" {
-                    html_table_to_markdown(html)
+                let (content, table_text) = if block_type == "Table" {
+                    (html_table_to_markdown(html), html_table_to_text(html))
                 } else {
-                    None
+                    (None, None)
                 };
 
                 let image_path = src.map(|s| {
@@ -1076,6 +1307,7 @@ fn parse_paper_blocks(
                     description,
                     image_path,
                     content,
+                    table_text,
                     page: page_num,
                     chapter_idx,
                     section_idx,
@@ -1198,6 +1430,7 @@ fn parse_paper_blocks(
             }
         }
     }
+    append_table_chunks(&params.paper_id, &mut chunk_records, &exhibit_records);
 
     eprintln!(
         "  [{}] extracted {} chunks, {} exhibits ({} section headers)",
@@ -1298,25 +1531,57 @@ pub async fn cache_paper_embeddings(
 
 /// Build embedding text with title + section context prepended.
 fn embedding_text(params: &IngestParams, c: &ChunkRecord) -> String {
+    chunk_embedding_text(&params.title, &c.chapter_title, &c.section_title, &c.text)
+}
+
+/// Text embedded for a chunk: paper title and chapter/section headings, then the chunk.
+pub(crate) fn chunk_embedding_text(title: &str, chapter_title: &str, section_title: &str, text: &str) -> String {
     let mut s = String::new();
-    if !params.title.is_empty() {
-        s.push_str(&params.title);
+    if !title.is_empty() {
+        s.push_str(title);
         s.push_str(" — ");
     }
-    if !c.chapter_title.is_empty() {
-        s.push_str(&c.chapter_title);
-        if !c.section_title.is_empty() {
+    if !chapter_title.is_empty() {
+        s.push_str(chapter_title);
+        if !section_title.is_empty() {
             s.push_str(" — ");
-            s.push_str(&c.section_title);
+            s.push_str(section_title);
         }
     }
     if !s.is_empty() {
         s.push_str("\n\n");
     }
-    s.push_str(&c.text);
+    s.push_str(text);
     s
 }
 
+/// Text embedded for an exhibit: caption and description, plus the body of
+/// algorithms and the cell text of tables.
+pub(crate) fn exhibit_embedding_text(
+    exhibit_type: &str,
+    caption: &str,
+    description: Option<&str>,
+    content: Option<&str>,
+    table_text: Option<&str>,
+) -> String {
+    let mut parts = Vec::new();
+    if !caption.is_empty() {
+        parts.push(caption);
+    }
+    if let Some(desc) = description {
+        parts.push(desc);
+    }
+    if let Some(content) = content
+        && exhibit_type == "algorithm"
+    {
+        parts.push(content);
+    }
+    if let Some(table_text) = table_text {
+        parts.push(table_text);
+    }
+    parts.join("\n")
+}
+
 // ── Reflow-based chunking ─────────────────────────────────────────────────────
 
 use papers_extract::types::{ReflowDocument, ReflowNode};
@@ -1345,7 +1610,7 @@ impl<'a> ChunkingState<'a> {
             chunk_idx: 0,
             chapter_title: String::new(),
             section_title: String::new(),
-            buffer: ChunkBuffer::new(),
+            buffer: ChunkBuffer::new(params.chunking),
             chunk_records: Vec::new(),
             exhibit_records: Vec::new(),
             exhibit_seq: 0,
@@ -1371,6 +1636,7 @@ impl<'a> ChunkingState<'a> {
             page_start: flushed.page_start,
             page_end: flushed.page_end,
             exhibit_ids: vec![],
+            equation_tex: flushed.equations,
         });
         self.chunk_idx += 1;
     }
@@ -1385,7 +1651,7 @@ impl<'a> ChunkingState<'a> {
     /// Flush mid-section due to token overflow, carrying overlap into the new buffer.
     fn flush_mid_section(&mut self) {
         if let Some(flushed) = self.buffer.flush() {
-            let overlap = ChunkBuffer::overlap_tail(&flushed.text);
+            let overlap = self.buffer.overlap(&flushed.text);
             self.emit_chunk(flushed);
             if !overlap.is_empty() {
                 self.buffer.push(overlap, None);
@@ -1393,18 +1659,31 @@ impl<'a> ChunkingState<'a> {
         }
     }
 
+    /// Push a paragraph, flushing mid-section first if it would overflow the
+    /// buffer. Under the sentence-window strategy an over-long paragraph goes
+    /// in window by window.
+    fn push_paragraph(&mut self, text: &str) {
+        for piece in self.buffer.split_block(text) {
+            if !self.buffer.is_empty() && self.buffer.would_overflow(&piece) {
+                self.flush_mid_section();
+            }
+            self.buffer.push(piece, None);
+        }
+    }
+
     /// Smart merge: if chunk < MIN_CHUNK_TOKENS and previous chunk is same section,
-    /// merge if combined <= MAX_CHUNK_TOKENS. Otherwise emit standalone.
+    /// merge if combined <= `max_tokens`. Otherwise emit standalone.
     fn smart_merge_or_emit(&mut self, flushed: FlushedChunk) {
         let f_tokens = estimate_tokens(&flushed.text);
         if f_tokens < MIN_CHUNK_TOKENS && !self.chunk_records.is_empty() {
             let prev = self.chunk_records.last().unwrap();
             if prev.chapter_idx == self.chapter_idx && prev.section_idx == self.section_idx {
                 let prev_tokens = estimate_tokens(&prev.text);
-                if prev_tokens + f_tokens <= MAX_CHUNK_TOKENS {
+                if prev_tokens + f_tokens <= self.params.chunking.max_tokens {
                     let prev = self.chunk_records.last_mut().unwrap();
                     prev.text.push_str("\n\n");
                     prev.text.push_str(&flushed.text);
+                    prev.equation_tex.extend(flushed.equations);
                     if let Some(p) = flushed.page_end {
                         prev.page_end = Some(p);
                     }
@@ -1553,7 +1832,7 @@ fn process_reflow_node(node: &ReflowNode, state: &mut ChunkingState) {
                 _ => {
                     // Subsection (depth 3+): inject as bold paragraph separator
                     if !state.buffer.is_empty()
-                        && state.buffer.token_count > TARGET_CHUNK_TOKENS / 2
+                        && state.buffer.token_count > state.params.chunking.target_tokens / 2
                     {
                         state.flush_mid_section();
                     }
@@ -1571,10 +1850,7 @@ fn process_reflow_node(node: &ReflowNode, state: &mut ChunkingState) {
             if text.is_empty() {
                 return;
             }
-            if !state.buffer.is_empty() && state.buffer.would_overflow(text) {
-                state.flush_mid_section();
-            }
-            state.buffer.push(text.to_string(), None);
+            state.push_paragraph(text);
 
             // Append associated footnotes inline
             for footnote in footnotes {
@@ -1595,11 +1871,14 @@ fn process_reflow_node(node: &ReflowNode, state: &mut ChunkingState) {
             };
 
             if !state.buffer.is_empty()
-                && state.buffer.token_count + estimate_tokens(&formula_text) > MAX_CHUNK_TOKENS
+                && state.buffer.token_count + estimate_tokens(&formula_text) > state.params.chunking.max_tokens
             {
                 state.flush_mid_section();
             }
             state.buffer.push(formula_text, None);
+            if let Some(latex) = content {
+                state.buffer.equations.push(strip_math_delimiters(latex).to_string());
+            }
         }
 
         ReflowNode::Figure { path, caption } => {
@@ -1617,6 +1896,7 @@ fn process_reflow_node(node: &ReflowNode, state: &mut ChunkingState) {
                 description: None,
                 image_path,
                 content: None,
+                table_text: None,
                 page: None,
                 chapter_idx: state.chapter_idx,
                 section_idx: state.section_idx,
@@ -1640,6 +1920,7 @@ fn process_reflow_node(node: &ReflowNode, state: &mut ChunkingState) {
                 description: None,
                 image_path,
                 content: None,
+                table_text: None,
                 page: None,
                 chapter_idx: state.chapter_idx,
                 section_idx: state.section_idx,
@@ -1658,6 +1939,7 @@ fn process_reflow_node(node: &ReflowNode, state: &mut ChunkingState) {
                 description: None,
                 image_path: None,
                 content: Some(content.clone()),
+                table_text: markdown_table_to_text(content),
                 page: None,
                 chapter_idx: state.chapter_idx,
                 section_idx: state.section_idx,
@@ -1679,6 +1961,7 @@ fn process_reflow_node(node: &ReflowNode, state: &mut ChunkingState) {
                 description: None,
                 image_path: None,
                 content: Some(content.clone()),
+                table_text: None,
                 page: None,
                 chapter_idx: state.chapter_idx,
                 section_idx: state.section_idx,
@@ -1727,10 +2010,7 @@ fn process_reflow_node(node: &ReflowNode, state: &mut ChunkingState) {
             if text.is_empty() {
                 return;
             }
-            if !state.buffer.is_empty() && state.buffer.would_overflow(text) {
-                state.flush_mid_section();
-            }
-            state.buffer.push(text.to_string(), None);
+            state.push_paragraph(text);
         }
 
         ReflowNode::Footnote { marker, content } => {
@@ -1757,6 +2037,57 @@ fn process_reflow_node(node: &ReflowNode, state: &mut ChunkingState) {
     }
 }
 
+/// Build a ReflowDocument from Markdown-style text, as produced for EPUB and
+/// HTML attachments by `papers_core::text::extract_text_bytes`.
+///
+/// Blocks are separated by blank lines. `#` headings nest the blocks that
+/// follow them; the shallowest heading level present becomes depth 1
+/// (chapters), the next depth 2 (sections), and so on.
+pub fn reflow_from_markdown(markdown: &str) -> ReflowDocument {
+    fn heading_level(block: &str) -> Option<u32> {
+        let hashes = block.chars().take_while(|c| *c == '#').count();
+        ((1..=6).contains(&hashes) && block[hashes..].starts_with(' ') && !block.contains('\n'))
+            .then_some(hashes as u32)
+    }
+    fn close(stack: &mut Vec<(u32, String, Vec<ReflowNode>)>, root: &mut Vec<ReflowNode>) {
+        if let Some((depth, text, children)) = stack.pop() {
+            let node = ReflowNode::Heading { depth, text, section: None, children };
+            match stack.last_mut() {
+                Some((_, _, parent)) => parent.push(node),
+                None => root.push(node),
+            }
+        }
+    }
+
+    let blocks: Vec<&str> =
+        markdown.split("\n\n").map(str::trim).filter(|b| !b.is_empty()).collect();
+    let min_level = blocks.iter().filter_map(|b| heading_level(b)).min().unwrap_or(1);
+    let mut root = Vec::new();
+    let mut stack: Vec<(u32, String, Vec<ReflowNode>)> = Vec::new();
+    for block in blocks {
+        match heading_level(block) {
+            Some(level) => {
+                let depth = level - min_level + 1;
+                while stack.last().is_some_and(|(d, _, _)| *d >= depth) {
+                    close(&mut stack, &mut root);
+                }
+                stack.push((depth, block[level as usize..].trim().to_string(), Vec::new()));
+            }
+            None => {
+                let node = ReflowNode::Text { content: block.to_string(), footnotes: vec![] };
+                match stack.last_mut() {
+                    Some((_, _, children)) => children.push(node),
+                    None => root.push(node),
+                }
+            }
+        }
+    }
+    while !stack.is_empty() {
+        close(&mut stack, &mut root);
+    }
+    ReflowDocument { title: None, toc: vec![], children: root }
+}
+
 /// Parse a ReflowDocument into chunks and exhibits for database ingestion.
 pub(crate) fn parse_reflow_document(
     params: &IngestParams,
@@ -1775,6 +2106,7 @@ pub(crate) fn parse_reflow_document(
 
     // Post-process: link exhibit references
     state.link_exhibits();
+    append_table_chunks(&params.paper_id, &mut state.chunk_records, &state.exhibit_records);
 
     eprintln!(
         "  [{}] chunked {} chunks, {} exhibits",
@@ -1786,13 +2118,113 @@ pub(crate) fn parse_reflow_document(
     Ok((state.chunk_records, state.exhibit_records))
 }
 
+// ── References ────────────────────────────────────────────────────────────────
+
+fn is_references_title(title: &str) -> bool {
+    let lower = title.to_lowercase();
+    REFERENCES_TITLES.iter().any(|t| lower.contains(t))
+}
+
+/// Text of the references section of a reflow document, one paragraph or
+/// list item per line: `References` nodes plus the content under a
+/// references heading.
+pub(crate) fn reflow_references_text(doc: &ReflowDocument) -> String {
+    fn walk(node: &ReflowNode, in_refs: &mut bool, lines: &mut Vec<String>) {
+        match node {
+            ReflowNode::Heading { text, section, children, .. } => {
+                *in_refs = is_references_title(&format_heading_title(section.as_deref(), text));
+                for child in children {
+                    walk(child, in_refs, lines);
+                }
+            }
+            ReflowNode::References { content } => lines.push(content.clone()),
+            ReflowNode::Text { content, .. } | ReflowNode::FormattedText { content } if *in_refs => {
+                lines.push(content.clone());
+            }
+            ReflowNode::List { items, .. } if *in_refs => lines.extend(items.iter().cloned()),
+            _ => {}
+        }
+    }
+    let mut in_refs = false;
+    let mut lines = Vec::new();
+    for node in &doc.children {
+        walk(node, &mut in_refs, &mut lines);
+    }
+    lines.join("\n")
+}
+
+/// Text of the references section of a DataLab Marker JSON, one block or list
+/// item per line: the `Text` and `ListGroup` blocks after a references heading.
+pub(crate) fn marker_references_text(params: &IngestParams) -> Result<String, DbError> {
+    let json_path = params.cache_dir.join(format!("{}.json", params.item_key));
+    let root: Value = serde_json::from_slice(&std::fs::read(&json_path)?)?;
+    let blocks = root
+        .get("children")
+        .and_then(|c| c.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|page| page.get("children").and_then(|c| c.as_array()))
+        .flatten();
+
+    let mut in_refs = false;
+    let mut lines = Vec::new();
+    for block in blocks {
+        let html = block.get("html").and_then(|v| v.as_str()).unwrap_or("");
+        match block.get("block_type").and_then(|v| v.as_str()).unwrap_or("") {
+            "SectionHeader" => in_refs = is_references_title(&strip_html(html)),
+            "Text" | "ListGroup" if in_refs => {
+                lines.extend(html.split("</li>").map(strip_html).filter(|l| !l.is_empty()));
+            }
+            _ => {}
+        }
+    }
+    Ok(lines.join("\n"))
+}
+
+/// Citation rows for a paper's chunks, linked against its reference list.
+pub(crate) fn build_citations_batch(
+    params: &IngestParams,
+    records: &[CitationRecord],
+) -> Result<RecordBatch, DbError> {
+    let n = records.len();
+    let citation_ids: Vec<&str> = records.iter().map(|r| r.citation_id.as_str()).collect();
+    let paper_ids: Vec<&str> = vec![params.paper_id.as_str(); n];
+    let chunk_ids: Vec<&str> = records.iter().map(|r| r.chunk_id.as_str()).collect();
+    let titles: Vec<&str> = vec![params.title.as_str(); n];
+    let years: Vec<Option<u16>> = vec![params.year; n];
+    let markers: Vec<&str> = records.iter().map(|r| r.marker.as_str()).collect();
+    let sentences: Vec<&str> = records.iter().map(|r| r.sentence.as_str()).collect();
+    let ref_labels: Vec<&str> = records.iter().map(|r| r.ref_label.as_str()).collect();
+    let ref_texts: Vec<&str> = records.iter().map(|r| r.ref_text.as_str()).collect();
+    let ref_keys: Vec<String> = records.iter().map(|r| normalize_key(&r.ref_text)).collect();
+    let cited_dois: Vec<Option<&str>> = records.iter().map(|r| r.cited_doi.as_deref()).collect();
+
+    RecordBatch::try_new(
+        citations_schema(),
+        vec![
+            Arc::new(StringArray::from(citation_ids)),
+            Arc::new(StringArray::from(paper_ids)),
+            Arc::new(StringArray::from(chunk_ids)),
+            Arc::new(StringArray::from(titles)),
+            Arc::new(UInt16Array::from(years)),
+            Arc::new(StringArray::from(markers)),
+            Arc::new(StringArray::from(sentences)),
+            Arc::new(StringArray::from(ref_labels)),
+            Arc::new(StringArray::from(ref_texts)),
+            Arc::new(StringArray::from(ref_keys)),
+            Arc::new(StringArray::from(cited_dois)),
+        ],
+    )
+    .map_err(|e: ArrowError| DbError::Arrow(e.to_string()))
+}
+
 /// Ingest a paper into LanceDB.
 ///
 /// Tries the reflow pipeline first (`reflow.json` in cache_dir). Falls back to
 /// the legacy DataLab Marker pipeline (`{item_key}.json`) if no reflow is found.
 pub async fn ingest_paper(store: &DbStore, params: IngestParams) -> Result<IngestStats, DbError> {
     let t_total = std::time::Instant::now();
-    let (chunk_records, exhibit_records) = {
+    let (chunk_records, exhibit_records, references_text) = {
         let reflow_path = params.cache_dir.join("reflow.json");
         if reflow_path.exists() {
             let json_bytes = std::fs::read(&reflow_path).map_err(|e| {
@@ -1801,11 +2233,14 @@ pub async fn ingest_paper(store: &DbStore, params: IngestParams) -> Result<Inges
             let doc: ReflowDocument = serde_json::from_slice(&json_bytes).map_err(|e| {
                 DbError::Ingest(format!("failed to parse reflow.json: {e}"))
             })?;
-            parse_reflow_document(&params, &doc)?
+            let (chunks, exhibits) = parse_reflow_document(&params, &doc)?;
+            (chunks, exhibits, reflow_references_text(&doc))
         } else {
-            parse_paper_blocks(&params)?
+            let (chunks, exhibits) = parse_paper_blocks(&params)?;
+            (chunks, exhibits, marker_references_text(&params)?)
         }
     };
+    let citation_records = link_citations(&chunk_records, &parse_references(&references_text));
 
     let chunks_added = chunk_records.len();
     let exhibits_added = exhibit_records.len();
@@ -1819,6 +2254,9 @@ pub async fn ingest_paper(store: &DbStore, params: IngestParams) -> Result<Inges
     if let Ok(exhibits_table) = store.exhibits_table().await {
         let _ = exhibits_table.delete(&delete_filter).await;
     }
+    if let Ok(citations_table) = store.citations_table().await {
+        let _ = citations_table.delete(&delete_filter).await;
+    }
 
     // ── Embed chunk texts (with cache) ─────────────────────────────────────
     let model = default_embed_model();
@@ -1832,8 +2270,16 @@ pub async fn ingest_paper(store: &DbStore, params: IngestParams) -> Result<Inges
                 .load_manifest(&model, &params.item_key)
                 .map_err(cache_err)?
                 .map(|manifest| {
+                    // Chunks cut under another strategy or config are stale
+                    // even when the count happens to match.
+                    let same_texts = manifest
+                        .chunks
+                        .iter()
+                        .map(|c| &c.text)
+                        .eq(chunk_records.iter().map(|c| &c.text));
                     embed_cache
                         .load_embeddings(&model, &params.item_key, &manifest)
+                        .map(|embs| (embs, same_texts))
                         .map_err(cache_err)
                 })
                 .transpose()?
@@ -1842,7 +2288,7 @@ pub async fn ingest_paper(store: &DbStore, params: IngestParams) -> Result<Inges
         };
 
         match cached {
-            Some(embs) if embs.len() == chunk_records.len() => {
+            Some((embs, true)) if embs.len() == chunk_records.len() => {
                 eprintln!(
                     "  [{}] embed cache hit ({} chunks)",
                     params.item_key,
@@ -1850,12 +2296,13 @@ pub async fn ingest_paper(store: &DbStore, params: IngestParams) -> Result<Inges
                 );
                 embs
             }
-            Some(embs) => {
+            Some((embs, same_texts)) => {
                 eprintln!(
-                    "  [{}] embed cache stale ({} cached vs {} chunks), re-embedding...",
+                    "  [{}] embed cache stale ({} cached vs {} chunks{}), re-embedding...",
                     params.item_key,
                     embs.len(),
-                    chunk_records.len()
+                    chunk_records.len(),
+                    if same_texts { "" } else { ", texts changed" }
                 );
                 let t = std::time::Instant::now();
                 let texts: Vec<String> = chunk_records
@@ -1956,20 +2403,13 @@ pub async fn ingest_paper(store: &DbStore, params: IngestParams) -> Result<Inges
     let exhibit_texts: Vec<String> = exhibit_records
         .iter()
         .map(|f| {
-            let mut parts = Vec::new();
-            if !f.caption.is_empty() {
-                parts.push(f.caption.clone());
-            }
-            if let Some(desc) = &f.description {
-                parts.push(desc.clone());
-            }
-            if let Some(content) = &f.content {
-                if f.exhibit_type == "algorithm" {
-                    // Include algorithm content for embedding
-                    parts.push(content.clone());
-                }
-            }
-            parts.join("\n")
+            exhibit_embedding_text(
+                &f.exhibit_type,
+                &f.caption,
+                f.description.as_deref(),
+                f.content.as_deref(),
+                f.table_text.as_deref(),
+            )
         })
         .collect();
     let exhibit_embeddings = if exhibit_texts.is_empty() {
@@ -2006,6 +2446,9 @@ pub async fn ingest_paper(store: &DbStore, params: IngestParams) -> Result<Inges
             let _ = e;
             eprintln!("  [{}] chunks index rebuild skipped", params.item_key);
         }
+        if let Err(e) = crate::store::create_fts_index(&table).await {
+            eprintln!("  [{}] chunks full-text index rebuild failed: {e}", params.item_key);
+        }
         eprintln!("  [{}] chunks inserted ({:.1}s)", params.item_key, t.elapsed().as_secs_f64());
     }
 
@@ -2047,6 +2490,18 @@ pub async fn ingest_paper(store: &DbStore, params: IngestParams) -> Result<Inges
         eprintln!("  [{}] exhibits inserted ({:.1}s)", params.item_key, t.elapsed().as_secs_f64());
     }
 
+    // ── Insert citations ───────────────────────────────────────────────────
+    if !citation_records.is_empty() {
+        eprintln!(
+            "  [{}] inserting {} citations...",
+            params.item_key,
+            citation_records.len()
+        );
+        let batch = build_citations_batch(&params, &citation_records)?;
+        let reader = RecordBatchIterator::new(vec![Ok(batch)], citations_schema());
+        store.citations_table().await?.add(Box::new(reader)).execute().await?;
+    }
+
     eprintln!("  [{}] done (total {:.1}s)", params.item_key, t_total.elapsed().as_secs_f64());
     Ok(IngestStats {
         chunks_added,
@@ -2065,7 +2520,8 @@ fn build_string_list_array(lists: &[Vec<String>]) -> ListArray {
     builder.finish()
 }
 
-fn build_vector_array(embeddings: &[Vec<f32>]) -> FixedSizeListArray {
+pub(crate) fn build_vector_array(embeddings: &[Vec<f32>]) -> FixedSizeListArray {
+    let dim = embeddings.first().map_or(EMBED_DIM, |v| v.len() as i32);
     let flat: Vec<f32> = embeddings.iter().flat_map(|v| v.iter().copied()).collect();
     let flat_array = Arc::new(Float32Array::from(flat));
     let field = Arc::new(arrow_schema::Field::new(
@@ -2073,10 +2529,10 @@ fn build_vector_array(embeddings: &[Vec<f32>]) -> FixedSizeListArray {
         arrow_schema::DataType::Float32,
         true,
     ));
-    FixedSizeListArray::new(field, EMBED_DIM, flat_array, None)
+    FixedSizeListArray::new(field, dim, flat_array, None)
 }
 
-fn build_chunks_batch(
+pub(crate) fn build_chunks_batch(
     params: &IngestParams,
     records: &[ChunkRecord],
     embeddings: &[Vec<f32>],
@@ -2103,6 +2559,9 @@ fn build_chunks_batch(
     let venues: Vec<Option<&str>> = vec![params.venue.as_deref(); n];
     let tags_list: Vec<Vec<String>> = vec![params.tags.clone(); n];
     let exhibit_ids_list: Vec<Vec<String>> = records.iter().map(|r| r.exhibit_ids.clone()).collect();
+    let chunking = params.chunking.label();
+    let chunkings: Vec<&str> = vec![chunking.as_str(); n];
+    let equation_texs: Vec<Option<String>> = records.iter().map(|r| join_equations(&r.equation_tex)).collect();
 
     let batch = RecordBatch::try_new(
         schema,
@@ -2126,6 +2585,8 @@ fn build_chunks_batch(
             Arc::new(StringArray::from(venues)),
             Arc::new(build_string_list_array(&tags_list)),
             Arc::new(build_string_list_array(&exhibit_ids_list)),
+            Arc::new(StringArray::from(chunkings)),
+            Arc::new(StringArray::from(equation_texs)),
         ],
     )
     .map_err(|e: ArrowError| DbError::Arrow(e.to_string()))?;
@@ -2133,7 +2594,7 @@ fn build_chunks_batch(
     Ok(batch)
 }
 
-fn build_exhibits_batch(
+pub(crate) fn build_exhibits_batch(
     params: &IngestParams,
     records: &[ExhibitRecord],
     embeddings: &[Vec<f32>],
@@ -2168,6 +2629,7 @@ fn build_exhibits_batch(
         .map(|r| r.first_ref_chunk_id.as_deref())
         .collect();
     let ref_counts: Vec<u16> = records.iter().map(|r| r.ref_count).collect();
+    let table_texts: Vec<Option<&str>> = records.iter().map(|r| r.table_text.as_deref()).collect();
 
     let batch = RecordBatch::try_new(
         schema,
@@ -2190,6 +2652,7 @@ fn build_exhibits_batch(
             Arc::new(build_string_list_array(&tags_list)),
             Arc::new(StringArray::from(first_ref_chunk_ids)),
             Arc::new(UInt16Array::from(ref_counts)),
+            Arc::new(StringArray::from(table_texts)),
         ],
     )
     .map_err(|e: ArrowError| DbError::Arrow(e.to_string()))?;
@@ -2197,6 +2660,83 @@ fn build_exhibits_batch(
     Ok(batch)
 }
 
+/// Equations are stored in one column, separated by blank lines (display LaTeX
+/// doesn't contain them); `None` for chunks without equations.
+pub(crate) fn join_equations(equations: &[String]) -> Option<String> {
+    if equations.is_empty() { None } else { Some(equations.join("\n\n")) }
+}
+
+/// Inverse of `join_equations`.
+pub(crate) fn split_equations(joined: Option<String>) -> Vec<String> {
+    joined
+        .map(|s| s.split("\n\n").map(str::to_string).collect())
+        .unwrap_or_default()
+}
+
+// ── Abstracts-only tier ───────────────────────────────────────────────────────
+
+/// Text embedded for an abstract: the paper title, then the abstract.
+pub(crate) fn abstract_embedding_text(title: &str, text: &str) -> String {
+    if title.is_empty() { text.to_string() } else { format!("{title}\n\n{text}") }
+}
+
+pub(crate) fn build_abstracts_batch(
+    abstracts: &[PaperAbstract],
+    embeddings: &[Vec<f32>],
+) -> Result<RecordBatch, DbError> {
+    let paper_ids: Vec<&str> = abstracts.iter().map(|a| a.paper_id.as_str()).collect();
+    let openalex_ids: Vec<Option<&str>> = abstracts.iter().map(|a| a.openalex_id.as_deref()).collect();
+    let dois: Vec<Option<&str>> = abstracts.iter().map(|a| a.doi.as_deref()).collect();
+    let titles: Vec<&str> = abstracts.iter().map(|a| a.title.as_str()).collect();
+    let authors_list: Vec<Vec<String>> = abstracts.iter().map(|a| a.authors.clone()).collect();
+    let years: Vec<Option<u16>> = abstracts.iter().map(|a| a.year).collect();
+    let venues: Vec<Option<&str>> = abstracts.iter().map(|a| a.venue.as_deref()).collect();
+    let texts: Vec<&str> = abstracts.iter().map(|a| a.text.as_str()).collect();
+
+    RecordBatch::try_new(
+        abstracts_schema(),
+        vec![
+            Arc::new(StringArray::from(paper_ids)),
+            Arc::new(build_vector_array(embeddings)),
+            Arc::new(StringArray::from(openalex_ids)),
+            Arc::new(StringArray::from(dois)),
+            Arc::new(StringArray::from(titles)),
+            Arc::new(build_string_list_array(&authors_list)),
+            Arc::new(UInt16Array::from(years)),
+            Arc::new(StringArray::from(venues)),
+            Arc::new(StringArray::from(texts)),
+        ],
+    )
+    .map_err(|e: ArrowError| DbError::Arrow(e.to_string()))
+}
+
+/// Embed `abstracts` (one vector per paper) into the abstracts-only tier,
+/// replacing any abstract already stored for the same paper. Returns the
+/// number stored.
+pub async fn index_abstracts(store: &DbStore, abstracts: &[PaperAbstract]) -> Result<usize, DbError> {
+    if abstracts.is_empty() {
+        return Ok(0);
+    }
+    let texts: Vec<String> = abstracts
+        .iter()
+        .map(|a| abstract_embedding_text(&a.title, &a.text))
+        .collect();
+    let embeddings = store.embed_documents(texts).await?;
+    let batch = build_abstracts_batch(abstracts, &embeddings)?;
+
+    let table = store.abstracts_table().await?;
+    let ids: Vec<String> = abstracts.iter().map(|a| a.paper_id.clone()).collect();
+    if let Some(filter) = crate::filter::FilterBuilder::new().paper_ids(&ids).build() {
+        table.delete(&filter).await?;
+    }
+    let schema = batch.schema();
+    table
+        .add(Box::new(RecordBatchIterator::new(vec![Ok(batch)], schema)))
+        .execute()
+        .await?;
+    Ok(abstracts.len())
+}
+
 /// Check if a paper is already indexed in the RAG database.
 pub async fn is_ingested(store: &DbStore, paper_id: &str) -> bool {
     use futures::TryStreamExt;
@@ -2373,7 +2913,7 @@ mod tests {
 
     #[test]
     fn chunk_buffer_push_updates_tokens_and_pages() {
-        let mut buf = ChunkBuffer::new();
+        let mut buf = ChunkBuffer::new(ChunkingConfig::default());
         buf.push("hello world".to_string(), Some(3));
         assert!(buf.token_count > 0);
         assert_eq!(buf.page_start, Some(3));
@@ -2386,7 +2926,7 @@ mod tests {
 
     #[test]
     fn chunk_buffer_flush_joins_and_resets() {
-        let mut buf = ChunkBuffer::new();
+        let mut buf = ChunkBuffer::new(ChunkingConfig::default());
         buf.push("paragraph one".to_string(), Some(1));
         buf.push("paragraph two".to_string(), Some(2));
         let flushed = buf.flush().unwrap();
@@ -2398,14 +2938,14 @@ mod tests {
 
     #[test]
     fn chunk_buffer_flush_empty_returns_none() {
-        let mut buf = ChunkBuffer::new();
+        let mut buf = ChunkBuffer::new(ChunkingConfig::default());
         assert!(buf.flush().is_none());
     }
 
     #[test]
     fn chunk_buffer_overlap_tail_extracts_sentences() {
         let text = "First sentence. Second sentence. Third sentence.";
-        let overlap = ChunkBuffer::overlap_tail(text);
+        let overlap = ChunkBuffer::overlap_tail(text, OVERLAP_SENTENCES);
         assert!(overlap.contains("Second sentence."));
         assert!(overlap.contains("Third sentence."));
     }
@@ -2413,7 +2953,7 @@ mod tests {
     #[test]
     fn chunk_buffer_overlap_tail_single_sentence() {
         let text = "Only one sentence.";
-        let overlap = ChunkBuffer::overlap_tail(text);
+        let overlap = ChunkBuffer::overlap_tail(text, OVERLAP_SENTENCES);
         assert!(overlap.is_empty());
     }
 
@@ -2597,6 +3137,7 @@ mod tests {
             tags: vec![],
             cache_dir: std::path::PathBuf::from("."),
             force: false,
+            chunking: ChunkingConfig::default(),
         }
     }
 
@@ -2650,6 +3191,24 @@ mod tests {
         assert_eq!(chunks[2].chapter_title, "2 Methods");
     }
 
+    #[test]
+    fn reflow_from_markdown_nests_headings() {
+        let md = "Preface text.\n\n## One\n\nFirst.\n\n### Details\n\n- a\n- b\n\n\
+                  ## Two\n\nSecond.";
+        let doc = reflow_from_markdown(md);
+        assert_eq!(doc.children.len(), 3);
+
+        let (chunks, _exhibits) = parse_reflow_document(&test_params(), &doc).unwrap();
+        let layout: Vec<_> = chunks
+            .iter()
+            .map(|c| (c.chapter_idx, c.section_idx, c.section_title.as_str()))
+            .collect();
+        assert_eq!(layout, [(0, 0, ""), (1, 0, ""), (1, 1, "Details"), (2, 0, "")]);
+        assert_eq!(chunks[1].chapter_title, "One");
+        assert_eq!(chunks[3].chapter_title, "Two");
+        assert!(chunks[2].text.contains("- a\n- b"));
+    }
+
     #[test]
     fn reflow_formula_absorption() {
         let doc = ReflowDocument {
@@ -3116,6 +3675,47 @@ mod tests {
         assert_eq!(chunks[1].chapter_idx, 1);
     }
 
+    #[test]
+    fn split_sentences_keeps_terminators() {
+        assert_eq!(
+            split_sentences("One. Two? Three! e.g.x four"),
+            ["One.", "Two?", "Three!", "e.g.x four"]
+        );
+    }
+
+    #[test]
+    fn reflow_sentence_window_splits_long_paragraph_with_overlap() {
+        let para = (0..80)
+            .map(|i| format!("Sentence {i} has a handful of extra words in it."))
+            .collect::<Vec<_>>()
+            .join(" "); // ~1040 tokens in one block
+        let doc = ReflowDocument {
+            title: None,
+            toc: vec![],
+            children: vec![ReflowNode::Heading {
+                depth: 1,
+                text: "1 Chapter".into(), section: None,
+                children: vec![ReflowNode::Text { content: para, footnotes: vec![] }],
+            }],
+        };
+
+        let mut params = test_params();
+        let (block, _) = parse_reflow_document(&params, &doc).unwrap();
+        assert_eq!(block.len(), 1, "block strategy keeps the paragraph whole");
+
+        params.chunking.strategy = ChunkStrategy::SentenceWindow;
+        let (windows, _) = parse_reflow_document(&params, &doc).unwrap();
+        assert!(windows.len() >= 3, "expected several windows, got {}", windows.len());
+        for (i, chunk) in windows.iter().enumerate() {
+            assert_eq!(chunk.chunk_id, format!("{}/ch1/s0/p{i}", params.paper_id));
+            assert!(estimate_tokens(&chunk.text) <= params.chunking.max_tokens);
+        }
+        // Each window starts with the last two sentences of the previous one.
+        let tail = ChunkBuffer::overlap_tail(&windows[0].text, 2);
+        assert!(windows[1].text.starts_with(&tail), "{tail:?} not carried over");
+        assert!(windows.last().unwrap().text.ends_with("Sentence 79 has a handful of extra words in it."));
+    }
+
     #[test]
     fn reflow_small_chunk_merge_same_section() {
         // Two short paragraphs in the same section — should be merged
@@ -3595,4 +4195,91 @@ mod tests {
         let ids: std::collections::HashSet<_> = chunks.iter().map(|c| &c.chunk_id).collect();
         assert_eq!(ids.len(), chunks.len(), "all chunk IDs should be unique");
     }
+
+    #[test]
+    fn table_text_labels_cells_with_headers() {
+        let html = "<table><thead><tr><th>Method</th><th>PSNR</th></tr></thead>\
+                    <tbody><tr><td>Ours</td><td>32.1</td></tr><tr><td>NeRF</td><td></td></tr></tbody></table>";
+        assert_eq!(
+            html_table_to_text(html).unwrap(),
+            "Method; PSNR\nMethod: Ours; PSNR: 32.1\nMethod: NeRF"
+        );
+        assert_eq!(html_table_to_text("<img src=\"t.png\"/>"), None);
+
+        let markdown = "Table 2: Timings\n| Scene | Time |\n| --- | :---: |\n| Lego | 5 ms |";
+        assert_eq!(markdown_table_to_text(markdown).unwrap(), "Scene; Time\nScene: Lego; Time: 5 ms");
+        assert_eq!(markdown_table_to_text("| a | b |\n| c | d |").unwrap(), "a; b\nc; d");
+    }
+
+    #[test]
+    fn reflow_tables_get_a_searchable_chunk() {
+        let doc = ReflowDocument {
+            title: None,
+            toc: vec![],
+            children: vec![ReflowNode::Heading {
+                depth: 1,
+                text: "Results".to_string(),
+                section: None,
+                children: vec![
+                    ReflowNode::Text {
+                        content: "Table 1 lists the timings.".to_string(),
+                        footnotes: vec![],
+                    },
+                    ReflowNode::Table {
+                        content: "| Scene | Time |\n|---|---|\n| Lego | 5 ms |".to_string(),
+                        caption: Some("Table 1: Timings".to_string()),
+                    },
+                ],
+            }],
+        };
+
+        let (chunks, exhibits) = parse_reflow_document(&test_params(), &doc).unwrap();
+        assert_eq!(exhibits[0].table_text.as_deref(), Some("Scene; Time\nScene: Lego; Time: 5 ms"));
+        let table = chunks.iter().find(|c| c.block_type == "table").unwrap();
+        assert_eq!(table.text, "Table 1: Timings\n\nScene; Time\nScene: Lego; Time: 5 ms");
+        assert_eq!(table.exhibit_ids, [exhibits[0].exhibit_id.clone()]);
+        assert_eq!(table.section_title, chunks[0].section_title);
+        assert_eq!(table.chunk_idx, chunks[0].chunk_idx + 1);
+        // The table's own chunk isn't a reference to it.
+        assert_eq!(exhibits[0].first_ref_chunk_id.as_deref(), Some(chunks[0].chunk_id.as_str()));
+        assert_eq!(exhibits[0].ref_count, 1);
+    }
+
+    #[test]
+    fn equation_tex_is_extracted_without_delimiters() {
+        assert_eq!(
+            extract_math_tex("<p><math display=\"block\">x_{i+1} = x_i - h \\nabla f</math></p>"),
+            ["x_{i+1} = x_i - h \\nabla f"]
+        );
+        assert!(extract_math_tex("<p>No math here.</p>").is_empty());
+        assert_eq!(strip_math_delimiters("$$E = mc^2$$"), "E = mc^2");
+        assert_eq!(strip_math_delimiters("\\[ a + b \\]"), "a + b");
+        assert_eq!(strip_math_delimiters("a + b"), "a + b");
+    }
+
+    #[test]
+    fn reflow_formulas_are_recorded_on_their_chunk() {
+        let doc = ReflowDocument {
+            title: None,
+            toc: vec![],
+            children: vec![ReflowNode::Heading {
+                depth: 1,
+                text: "Method".to_string(),
+                section: None,
+                children: vec![
+                    ReflowNode::Text { content: "The update rule is".into(), footnotes: vec![] },
+                    ReflowNode::Formula { content: Some("$$x' = x + v h$$".into()), path: None },
+                    ReflowNode::Formula { content: None, path: Some("images/eq2.png".into()) },
+                    ReflowNode::Text { content: "where h is the step.".into(), footnotes: vec![] },
+                ],
+            }],
+        };
+
+        let (chunks, _) = parse_reflow_document(&test_params(), &doc).unwrap();
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].equation_tex, ["x' = x + v h"]);
+        assert_eq!(join_equations(&chunks[0].equation_tex).as_deref(), Some("x' = x + v h"));
+        assert_eq!(split_equations(Some("a\n\nb".into())), ["a", "b"]);
+        assert!(join_equations(&[]).is_none());
+    }
 }
@@ -1,22 +1,29 @@
 pub mod config;
 pub mod embed_cache;
 pub mod error;
+pub mod fixtures;
 pub mod ingest;
 pub mod query;
 pub mod schema;
 pub mod store;
+pub mod sync;
 pub mod types;
 
+mod citations;
+mod cluster;
 mod embed;
 mod filter;
+mod ground;
+mod verify;
 
 pub use embed_cache::EmbedCache;
+pub use papers_core::util::{is_abbreviation, split_sentences};
 pub use error::DbError;
 pub use ingest::{
-    cache_paper_embeddings, embed_cache_base, IngestParams, ingest_paper, ingest_params_from_cache,
-    is_ingested, list_cached_item_keys,
+    cache_paper_embeddings, embed_cache_base, IngestParams, index_abstracts, ingest_paper,
+    ingest_params_from_cache, is_ingested, list_cached_item_keys, reflow_from_markdown,
 };
-pub use query::resolve_paper_id;
+pub use query::{resolve_outline_scope, resolve_paper_id};
 #[cfg(any(test, feature = "bench"))]
 pub use query::{search_exhibits_with_embedding, search_with_embedding};
 pub use store::DbStore;
@@ -1,22 +1,31 @@
 use arrow_array::{
-    Array, Float32Array, LargeStringArray, ListArray, RecordBatch, StringArray, UInt16Array,
+    Array, FixedSizeListArray, Float32Array, LargeStringArray, ListArray, RecordBatch, StringArray,
+    UInt16Array, UInt32Array, UInt64Array,
 };
 use arrow_schema::DataType;
 use futures::TryStreamExt;
+use lancedb::index::scalar::FullTextSearchQuery;
 use lancedb::query::{ExecutableQuery, QueryBase, Select};
 use std::collections::HashMap;
 
+use crate::citations::normalize_key;
+use crate::cluster::{self, dot};
 use crate::error::DbError;
 use crate::filter::{validate_scope, FilterBuilder};
+use crate::ground;
+use crate::ingest::split_equations;
 use crate::store::DbStore;
+use crate::verify;
 use crate::types::{
-    ChapterListItem, ChapterResult, ChapterSearchResult, ChapterSection, ChunkListItem, ChunkResult,
-    ChunkSummary, ChunkWithPosition, ExhibitResult, ExhibitSearchResult, ListChaptersParams,
-    ListChunksParams, ListPapersParams, ListSectionsParams, ListTagsParams, OutlineChapter,
-    OutlineSection, PaperOutline, PaperSummary, PositionContext, ReferencedExhibit,
-    SearchChaptersParams, SearchChunkResult, SearchExhibitsParams, SearchParams, SearchResult,
-    SearchSectionsParams, SearchWorksParams, SectionListItem, SectionResult, SectionSearchResult,
-    TagSummary, WorkMetadata, WorkSearchResult,
+    AbstractSearchResult, ChapterListItem, ChapterResult, ChapterSearchResult, ChapterSection, ChunkListItem, ChunkResult,
+    ChunkSummary, ChunkWithPosition, CitationContext, CitationContextsParams, ClusterMember, ClusterWorksParams, EquationSearchResult, ExhibitResult, ExhibitSearchResult, ListChaptersParams,
+    ListChunksParams, ListPapersParams, LocatePassageParams, ListSectionsParams, ListTagsParams, OutlineChapter, PaperSort,
+    OutlineSection, PaperOutline, PaperSummary, PassageMatch, PositionContext, ReadingState, ReferencedExhibit,
+    SearchChaptersParams, SearchChunkResult, SearchEquationsParams, SearchExhibitsParams, SearchMode, SearchParams,
+    SearchAbstractsParams, SearchResult, SearchSectionsParams, SearchWorksParams, SectionListItem, SectionResult,
+    SectionSearchResult, SimilarWork, SimilarWorksParams, TagSummary, WorkCluster, WorkClustering, WorkMetadata,
+    WorkSearchResult, WorkTimelineParams, ClaimVerification, Stance, VerifiedPassage, VerifyParams,
+    CitationCandidate, DraftGrounding, GroundDraftParams, GroundedParagraph,
 };
 
 // ── Arrow extraction helpers ────────────────────────────────────────────────
@@ -31,7 +40,7 @@ fn missing_col(name: &str) -> DbError {
     DbError::Arrow(format!("missing column '{name}'"))
 }
 
-fn col_str(batch: &RecordBatch, name: &str, row: usize) -> Result<String, DbError> {
+pub(crate) fn col_str(batch: &RecordBatch, name: &str, row: usize) -> Result<String, DbError> {
     let col = batch.column_by_name(name).ok_or_else(|| missing_col(name))?;
     if let Some(arr) = col.as_any().downcast_ref::<StringArray>() {
         Ok(if arr.is_null(row) { String::new() } else { arr.value(row).to_string() })
@@ -42,7 +51,7 @@ fn col_str(batch: &RecordBatch, name: &str, row: usize) -> Result<String, DbErro
     }
 }
 
-fn col_str_opt(batch: &RecordBatch, name: &str, row: usize) -> Result<Option<String>, DbError> {
+pub(crate) fn col_str_opt(batch: &RecordBatch, name: &str, row: usize) -> Result<Option<String>, DbError> {
     let col = batch.column_by_name(name).ok_or_else(|| missing_col(name))?;
     if *col.data_type() == DataType::Null {
         return Ok(None);
@@ -70,6 +79,20 @@ fn col_u16_opt(batch: &RecordBatch, name: &str, row: usize) -> Result<Option<u16
     Ok(if arr.is_null(row) { None } else { Some(arr.value(row)) })
 }
 
+fn col_u32(batch: &RecordBatch, name: &str, row: usize) -> Result<u32, DbError> {
+    let col = batch.column_by_name(name).ok_or_else(|| missing_col(name))?;
+    let arr = col.as_any().downcast_ref::<UInt32Array>()
+        .ok_or_else(|| arrow_err(name, "UInt32", col.data_type()))?;
+    Ok(arr.value(row))
+}
+
+fn col_u64(batch: &RecordBatch, name: &str, row: usize) -> Result<u64, DbError> {
+    let col = batch.column_by_name(name).ok_or_else(|| missing_col(name))?;
+    let arr = col.as_any().downcast_ref::<UInt64Array>()
+        .ok_or_else(|| arrow_err(name, "UInt64", col.data_type()))?;
+    Ok(arr.value(row))
+}
+
 fn col_str_list(batch: &RecordBatch, name: &str, row: usize) -> Result<Vec<String>, DbError> {
     let col = batch.column_by_name(name).ok_or_else(|| missing_col(name))?;
     if col.is_null(row) {
@@ -93,6 +116,16 @@ fn col_f32(batch: &RecordBatch, name: &str, row: usize) -> Result<f32, DbError>
     Ok(arr.value(row))
 }
 
+fn col_vector(batch: &RecordBatch, name: &str, row: usize) -> Result<Vec<f32>, DbError> {
+    let col = batch.column_by_name(name).ok_or_else(|| missing_col(name))?;
+    let arr = col.as_any().downcast_ref::<FixedSizeListArray>()
+        .ok_or_else(|| arrow_err(name, "FixedSizeList<Float32>", col.data_type()))?;
+    let values = arr.value(row);
+    let values = values.as_any().downcast_ref::<Float32Array>()
+        .ok_or_else(|| arrow_err(name, "FixedSizeList<Float32>", col.data_type()))?;
+    Ok(values.values().to_vec())
+}
+
 fn total_rows(batches: &[RecordBatch]) -> usize {
     batches.iter().map(|b| b.num_rows()).sum()
 }
@@ -116,6 +149,7 @@ fn chunk_from_row(batch: &RecordBatch, row: usize) -> Result<ChunkData, DbError>
         depth: col_str(batch, "depth", row)?,
         block_type: col_str(batch, "block_type", row)?,
         exhibit_ids: col_str_list(batch, "exhibit_ids", row)?,
+        equation_tex: split_equations(col_str_opt(batch, "equation_tex", row)?),
     })
 }
 
@@ -135,6 +169,7 @@ struct ChunkData {
     depth: String,
     block_type: String,
     exhibit_ids: Vec<String>,
+    equation_tex: Vec<String>,
 }
 
 // ── Shared async helpers ────────────────────────────────────────────────────
@@ -494,8 +529,10 @@ async fn build_chunk_with_position(
         depth: data.depth,
         block_type: data.block_type,
         exhibit_ids: data.exhibit_ids,
+        equation_tex: data.equation_tex,
         referenced_exhibits,
         position: pos,
+        exhibits: Vec::new(),
     })
 }
 
@@ -565,13 +602,153 @@ pub async fn resolve_paper_id(store: &DbStore, input: &str) -> Result<String, Db
     }
 }
 
-/// Semantic search across indexed paper chunks.
+/// Lowercase alphanumeric words of a heading, so "3.2 Local System Solver"
+/// and "3.2. local system-solver" compare equal.
+fn heading_tokens(s: &str) -> Vec<String> {
+    s.split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .map(|t| t.to_lowercase())
+        .collect()
+}
+
+/// How well `query` names `title`, from 0 to 1: exact, a prefix either way
+/// (so "3.2" finds "3.2 Local System Solver"), contained ("local system
+/// solver"), or else overlap of the non-numeric words.
+fn heading_score(query: &[String], title: &str) -> f64 {
+    let title = heading_tokens(title);
+    if query.is_empty() || title.is_empty() {
+        0.0
+    } else if query == title.as_slice() {
+        1.0
+    } else if title.starts_with(query) || query.starts_with(&title) {
+        0.9
+    } else if title.windows(query.len()).any(|w| w == query) {
+        0.8
+    } else {
+        // Section numbers only count when they line up, as above.
+        let words = |tokens: &[String]| -> Vec<String> {
+            tokens.iter().filter(|t| !t.chars().all(|c| c.is_ascii_digit())).cloned().collect()
+        };
+        let (query, title) = (words(query), words(&title));
+        let shared = query.iter().filter(|t| title.contains(t)).count();
+        if shared == 0 {
+            return 0.0;
+        }
+        shared as f64 / (query.len() + title.len() - shared) as f64
+    }
+}
+
+/// Minimum [`heading_score`] for a title to count as a match.
+const HEADING_MATCH_THRESHOLD: f64 = 0.5;
+
+/// The best match for `query` among `(key, title)` candidates, earliest on ties.
+fn best_heading<T: Copy>(candidates: &[(T, &str)], query: &str) -> Option<T> {
+    let query = heading_tokens(query);
+    let mut best: Option<(T, f64)> = None;
+    for &(key, title) in candidates {
+        let score = heading_score(&query, title);
+        if score >= HEADING_MATCH_THRESHOLD && best.is_none_or(|(_, b)| score > b) {
+            best = Some((key, score));
+        }
+    }
+    best.map(|(key, _)| key)
+}
+
+/// Find the chapter of `outline` titled `chapter`.
+pub fn match_chapter(outline: &PaperOutline, chapter: &str) -> Result<u16, DbError> {
+    let candidates: Vec<(u16, &str)> = outline
+        .chapters
+        .iter()
+        .map(|c| (c.chapter_idx, c.chapter_title.as_str()))
+        .collect();
+    best_heading(&candidates, chapter).ok_or_else(|| {
+        let available: Vec<String> =
+            candidates.iter().map(|(idx, title)| format!("  {idx}: {title}")).collect();
+        DbError::NotFound(format!(
+            "no chapter matching '{chapter}' in {}. Chapters:\n{}",
+            outline.paper_id,
+            available.join("\n")
+        ))
+    })
+}
+
+/// Find the section of `outline` titled `section`, within `chapter_idx` if given.
+/// Returns `(chapter_idx, section_idx)`.
+pub fn match_section(
+    outline: &PaperOutline,
+    chapter_idx: Option<u16>,
+    section: &str,
+) -> Result<(u16, u16), DbError> {
+    let candidates: Vec<((u16, u16), &str)> = outline
+        .chapters
+        .iter()
+        .filter(|c| chapter_idx.is_none_or(|idx| c.chapter_idx == idx))
+        .flat_map(|c| {
+            c.sections
+                .iter()
+                .map(|s| ((c.chapter_idx, s.section_idx), s.section_title.as_str()))
+        })
+        .collect();
+    best_heading(&candidates, section).ok_or_else(|| {
+        let available: Vec<String> = candidates
+            .iter()
+            .map(|((ch, sec), title)| format!("  {ch}.{sec}: {title}"))
+            .collect();
+        DbError::NotFound(format!(
+            "no section matching '{section}' in {}. Sections:\n{}",
+            outline.paper_id,
+            available.join("\n")
+        ))
+    })
+}
+
+/// Resolve chapter/section scope given as indices or titles. Indices win over
+/// titles; titles are fuzzy-matched against the paper's outline, which is only
+/// fetched when one is given. A section title also fixes the chapter.
+pub async fn resolve_outline_scope(
+    store: &DbStore,
+    paper_id: &str,
+    chapter_idx: Option<u16>,
+    chapter: Option<&str>,
+    section_idx: Option<u16>,
+    section: Option<&str>,
+) -> Result<(Option<u16>, Option<u16>), DbError> {
+    let chapter = chapter.filter(|_| chapter_idx.is_none());
+    let section = section.filter(|_| section_idx.is_none());
+    if chapter.is_none() && section.is_none() {
+        return Ok((chapter_idx, section_idx));
+    }
+    let outline = get_paper_outline(store, paper_id).await?;
+    let chapter_idx = match chapter {
+        Some(title) => Some(match_chapter(&outline, title)?),
+        None => chapter_idx,
+    };
+    match section {
+        Some(title) => {
+            let (ch, sec) = match_section(&outline, chapter_idx, title)?;
+            Ok((Some(ch), Some(sec)))
+        }
+        None => Ok((chapter_idx, section_idx)),
+    }
+}
+
+/// Search across indexed paper chunks, ranked according to `params.mode`.
+///
+/// With `params.rerank`, a larger candidate pool is retrieved first and
+/// re-scored by the cross-encoder; only the top `limit` are returned.
 pub async fn search(
     store: &DbStore,
     params: SearchParams,
 ) -> Result<Vec<SearchResult>, DbError> {
-    let embedding = store.embed_query(&params.query).await?;
-    search_with_embedding(store, params, &embedding).await
+    let limit = params.limit as usize;
+    let hits = if params.rerank {
+        let pool = (limit * RERANK_CANDIDATE_FACTOR).max(RERANK_MIN_CANDIDATES);
+        let candidates = ranked_candidates(store, &params, pool).await?;
+        rerank_candidates(store, &params.query, candidates, limit).await?
+    } else {
+        ranked_candidates(store, &params, limit).await?
+    };
+    build_search_results(store, hits).await
 }
 
 /// Search with a pre-computed embedding vector (used by benchmarks to bypass the embedder).
@@ -581,23 +758,250 @@ pub async fn search_with_embedding(
     params: SearchParams,
     embedding: &[f32],
 ) -> Result<Vec<SearchResult>, DbError> {
-    search_with_embedding_inner(store, params, embedding).await
+    let filter = chunk_search_filter(&params)?;
+    let table = store.chunks_table().await?;
+    let hits = vector_candidates(&table, embedding, filter.as_deref(), params.limit as usize).await?;
+    build_search_results(store, hits).await
 }
 
-#[cfg(not(any(test, feature = "bench")))]
-async fn search_with_embedding(
+/// BM25 search over the full-text index on chunk text.
+///
+/// Matches exact terminology (method names, acronyms) that embeddings tend to
+/// blur. Requires the FTS index created at ingest time.
+pub async fn keyword_search(
     store: &DbStore,
     params: SearchParams,
-    embedding: &[f32],
 ) -> Result<Vec<SearchResult>, DbError> {
-    search_with_embedding_inner(store, params, embedding).await
+    search(store, SearchParams { mode: SearchMode::Keyword, ..params }).await
 }
 
-async fn search_with_embedding_inner(
+/// Rank chunks by both embedding similarity and BM25, fused with reciprocal
+/// rank fusion.
+///
+/// Each ranking contributes `1 / (RRF_K + rank)` per chunk, so a chunk that
+/// appears near the top of either list surfaces even when the other list
+/// misses it entirely.
+pub async fn hybrid_search(
     store: &DbStore,
     params: SearchParams,
-    embedding: &[f32],
 ) -> Result<Vec<SearchResult>, DbError> {
+    search(store, SearchParams { mode: SearchMode::Hybrid, ..params }).await
+}
+
+/// Retrieve the chunks most similar to `params.statement` and label each as
+/// supporting, contradicting or neutral to it with a lexical heuristic
+/// (term overlap plus negation, direction and contrast cues).
+pub async fn verify_claim(
+    store: &DbStore,
+    params: VerifyParams,
+) -> Result<ClaimVerification, DbError> {
+    let results = search(
+        store,
+        SearchParams {
+            query: params.statement.clone(),
+            paper_ids: params.paper_ids,
+            chapter_idx: None,
+            section_idx: None,
+            filter_year_min: None,
+            filter_year_max: None,
+            filter_venue: None,
+            filter_tags: None,
+            filter_depth: None,
+            mode: params.mode,
+            rerank: params.rerank,
+            limit: params.limit,
+        },
+    )
+    .await?;
+    let passages: Vec<VerifiedPassage> = results
+        .into_iter()
+        .map(|result| {
+            let judgement = verify::judge(&params.statement, &result.chunk.text);
+            VerifiedPassage {
+                stance: judgement.stance,
+                overlap: judgement.overlap,
+                evidence: judgement.evidence,
+                cues: judgement.cues,
+                result,
+            }
+        })
+        .collect();
+    let count = |stance: Stance| passages.iter().filter(|p| p.stance == stance).count();
+    Ok(ClaimVerification {
+        statement: params.statement,
+        supporting: count(Stance::Supporting),
+        contradicting: count(Stance::Contradicting),
+        neutral: count(Stance::Neutral),
+        passages,
+    })
+}
+
+/// Most paragraphs of a draft grounded per call; the rest are counted in
+/// [`DraftGrounding::truncated`].
+pub const MAX_DRAFT_PARAGRAPHS: usize = 100;
+
+/// Passages retrieved per candidate paper, so papers with several matching
+/// passages can surface.
+const GROUND_PASSAGES_PER_CANDIDATE: u16 = 3;
+
+/// Split a Markdown draft into prose paragraphs and, for each, retrieve the
+/// passages most similar to it and group them by paper into candidate
+/// citations. Paragraphs that already cite something are flagged `cited`.
+pub async fn ground_draft(
+    store: &DbStore,
+    params: GroundDraftParams,
+) -> Result<DraftGrounding, DbError> {
+    let (mut drafted, skipped) = ground::paragraphs(&params.markdown);
+    let truncated = drafted.len().saturating_sub(MAX_DRAFT_PARAGRAPHS);
+    drafted.truncate(MAX_DRAFT_PARAGRAPHS);
+    let mut paragraphs = Vec::with_capacity(drafted.len());
+    for paragraph in drafted {
+        let results = search(
+            store,
+            SearchParams {
+                query: paragraph.query,
+                paper_ids: params.paper_ids.clone(),
+                chapter_idx: None,
+                section_idx: None,
+                filter_year_min: None,
+                filter_year_max: None,
+                filter_venue: None,
+                filter_tags: None,
+                filter_depth: None,
+                mode: params.mode,
+                rerank: params.rerank,
+                limit: params.limit.saturating_mul(GROUND_PASSAGES_PER_CANDIDATE),
+            },
+        )
+        .await?;
+        // Results are best first, so a paper's first passage is its best.
+        let mut candidates: Vec<CitationCandidate> = Vec::new();
+        for result in results {
+            let chunk = result.chunk;
+            if let Some(existing) = candidates.iter_mut().find(|c| c.paper_id == chunk.paper_id) {
+                existing.passages += 1;
+                continue;
+            }
+            candidates.push(CitationCandidate {
+                text_preview: truncate_at_sentence(&chunk.text, PREVIEW_MIN_CHARS),
+                paper_id: chunk.paper_id,
+                paper_title: chunk.paper_title,
+                score: result.score,
+                passages: 1,
+                chunk_id: chunk.chunk_id,
+                section_title: chunk.section_title,
+            });
+        }
+        candidates.truncate(params.limit as usize);
+        paragraphs.push(GroundedParagraph { line: paragraph.line, text: paragraph.text, cited: paragraph.cited, candidates });
+    }
+    let uncited = paragraphs.iter().filter(|p| !p.cited && !p.candidates.is_empty()).count();
+    Ok(DraftGrounding { paragraphs, uncited, skipped, truncated })
+}
+
+/// Retrieve the top `limit` chunks for `params.mode`, with their mode-specific scores.
+async fn ranked_candidates(
+    store: &DbStore,
+    params: &SearchParams,
+    limit: usize,
+) -> Result<Vec<(ChunkData, f32)>, DbError> {
+    let filter = chunk_search_filter(params)?;
+    let filter = filter.as_deref();
+    let table = store.chunks_table().await?;
+    match params.mode {
+        SearchMode::Semantic => {
+            let embedding = store.embed_query(&params.query).await?;
+            vector_candidates(&table, &embedding, filter, limit).await
+        }
+        SearchMode::Keyword => keyword_candidates(&table, &params.query, filter, limit).await,
+        SearchMode::Hybrid => {
+            let candidates = (limit * HYBRID_CANDIDATE_FACTOR).max(HYBRID_MIN_CANDIDATES);
+            let embedding = store.embed_query(&params.query).await?;
+            let (semantic, keyword) = futures::try_join!(
+                vector_candidates(&table, &embedding, filter, candidates),
+                keyword_candidates(&table, &params.query, filter, candidates),
+            )?;
+
+            let rankings = [
+                semantic.iter().map(|(d, _)| d.chunk_id.clone()).collect::<Vec<_>>(),
+                keyword.iter().map(|(d, _)| d.chunk_id.clone()).collect::<Vec<_>>(),
+            ];
+            let mut by_id: HashMap<String, ChunkData> = semantic
+                .into_iter()
+                .chain(keyword)
+                .map(|(d, _)| (d.chunk_id.clone(), d))
+                .collect();
+            Ok(reciprocal_rank_fusion(&rankings)
+                .into_iter()
+                .take(limit)
+                .filter_map(|(id, score)| by_id.remove(&id).map(|d| (d, score)))
+                .collect())
+        }
+    }
+}
+
+/// Re-score candidates with the cross-encoder and keep the best `limit`.
+///
+/// The cross-encoder sees the chunk together with its paper and section titles,
+/// mirroring the prefix used when embedding.
+async fn rerank_candidates(
+    store: &DbStore,
+    query: &str,
+    candidates: Vec<(ChunkData, f32)>,
+    limit: usize,
+) -> Result<Vec<(ChunkData, f32)>, DbError> {
+    let passages = candidates
+        .iter()
+        .map(|(d, _)| format!("{} — {}\n\n{}", d.title, d.section_title, d.text))
+        .collect();
+    let scores = store.rerank(query, passages).await?;
+    let mut reranked: Vec<(ChunkData, f32)> = candidates
+        .into_iter()
+        .zip(scores)
+        .map(|((d, _), score)| (d, score))
+        .collect();
+    reranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+    reranked.truncate(limit);
+    Ok(reranked)
+}
+
+/// Candidate pool for reranking is this many times `limit`, at least `RERANK_MIN_CANDIDATES`.
+const RERANK_CANDIDATE_FACTOR: usize = 5;
+const RERANK_MIN_CANDIDATES: usize = 30;
+
+/// Smoothing constant from the original RRF paper (Cormack et al., 2009).
+const RRF_K: f32 = 60.0;
+/// Each ranking fetches this many times `limit` candidates before fusion.
+const HYBRID_CANDIDATE_FACTOR: usize = 4;
+const HYBRID_MIN_CANDIDATES: usize = 20;
+
+/// Fuse several best-first rankings of chunk IDs. Returns IDs with their
+/// summed `1 / (RRF_K + rank)` scores, best first; ties keep first-seen order.
+fn reciprocal_rank_fusion(rankings: &[Vec<String>]) -> Vec<(String, f32)> {
+    let mut order: Vec<String> = Vec::new();
+    let mut scores: HashMap<&str, f32> = HashMap::new();
+    for ranking in rankings {
+        for (rank, id) in ranking.iter().enumerate() {
+            let entry = scores.entry(id.as_str()).or_insert_with(|| {
+                order.push(id.clone());
+                0.0
+            });
+            *entry += 1.0 / (RRF_K + rank as f32 + 1.0);
+        }
+    }
+    let mut fused: Vec<(String, f32)> = order
+        .into_iter()
+        .map(|id| {
+            let score = scores[id.as_str()];
+            (id, score)
+        })
+        .collect();
+    fused.sort_by(|a, b| b.1.total_cmp(&a.1));
+    fused
+}
+
+/// Validate scope and build the LanceDB filter shared by all search modes.
+fn chunk_search_filter(params: &SearchParams) -> Result<Option<String>, DbError> {
     validate_scope(
         params.chapter_idx,
         params.section_idx,
@@ -606,7 +1010,6 @@ async fn search_with_embedding_inner(
             .and_then(|ids| ids.first())
             .map(|s| s.as_str()),
     )?;
-    let table = store.chunks_table().await?;
 
     let mut fb = FilterBuilder::new();
     if let Some(ids) = params.paper_ids.as_deref() {
@@ -622,16 +1025,24 @@ async fn search_with_embedding_inner(
     if let Some(venue) = &params.filter_venue {
         fb = fb.eq_str("venue", venue);
     }
-    if let Some(depth) = &params.filter_depth {
-        fb = fb.eq_str("depth", depth);
+    if let Some(depth) = params.filter_depth {
+        fb = fb.eq_str("depth", depth.as_str());
     }
     if let Some(tags) = params.filter_tags.as_deref() {
         fb = fb.tags_any(tags);
     }
+    Ok(fb.build())
+}
 
+async fn vector_candidates(
+    table: &lancedb::Table,
+    embedding: &[f32],
+    filter: Option<&str>,
+    limit: usize,
+) -> Result<Vec<(ChunkData, f32)>, DbError> {
     let mut query_builder = table.query().nearest_to(embedding)?;
-    query_builder = query_builder.limit(params.limit as usize);
-    if let Some(filter) = fb.build() {
+    query_builder = query_builder.limit(limit);
+    if let Some(filter) = filter {
         query_builder = query_builder.only_if(filter);
     }
 
@@ -640,22 +1051,58 @@ async fn search_with_embedding_inner(
         .await?
         .try_collect::<Vec<_>>()
         .await
-        .map_err(|e| DbError::LanceDb(e))?;
+        .map_err(DbError::LanceDb)?;
+    scored_chunks(&batches, "_distance")
+}
+
+async fn keyword_candidates(
+    table: &lancedb::Table,
+    query: &str,
+    filter: Option<&str>,
+    limit: usize,
+) -> Result<Vec<(ChunkData, f32)>, DbError> {
+    let mut query_builder = table
+        .query()
+        .full_text_search(FullTextSearchQuery::new(query.to_string()))
+        .limit(limit);
+    if let Some(filter) = filter {
+        query_builder = query_builder.only_if(filter);
+    }
+
+    let batches = query_builder
+        .execute()
+        .await?
+        .try_collect::<Vec<_>>()
+        .await
+        .map_err(DbError::LanceDb)?;
+    scored_chunks(&batches, "_score")
+}
 
-    // Collect all chunk data and scores first
+/// Read chunk rows plus the given score column (0.0 if absent).
+fn scored_chunks(
+    batches: &[RecordBatch],
+    score_col: &str,
+) -> Result<Vec<(ChunkData, f32)>, DbError> {
     let mut chunk_data_list: Vec<(ChunkData, f32)> = Vec::new();
-    for batch in &batches {
-        let has_distance = batch.column_by_name("_distance").is_some();
+    for batch in batches {
+        let has_score = batch.column_by_name(score_col).is_some();
         for row in 0..batch.num_rows() {
-            let score = if has_distance {
-                col_f32(batch, "_distance", row)?
+            let score = if has_score {
+                col_f32(batch, score_col, row)?
             } else {
                 0.0
             };
             chunk_data_list.push((chunk_from_row(batch, row)?, score));
         }
     }
+    Ok(chunk_data_list)
+}
 
+/// Attach prev/next neighbor previews to ranked chunks.
+async fn build_search_results(
+    store: &DbStore,
+    chunk_data_list: Vec<(ChunkData, f32)>,
+) -> Result<Vec<SearchResult>, DbError> {
     // Collect all neighbor keys across all results in one pass
     let mut neighbor_keys: Vec<NeighborKey> = Vec::new();
     for (data, _) in &chunk_data_list {
@@ -700,6 +1147,7 @@ async fn search_with_embedding_inner(
             section_title: data.section_title,
             chunk_idx: data.chunk_idx,
             exhibit_ids: data.exhibit_ids,
+            equation_tex: data.equation_tex,
         };
         results.push(SearchResult {
             chunk,
@@ -711,6 +1159,34 @@ async fn search_with_embedding_inner(
     Ok(results)
 }
 
+/// Find where equations are defined: semantic search over the chunks that
+/// hold display equations, returning each chunk's LaTeX with its prose.
+pub async fn search_equations(
+    store: &DbStore,
+    params: SearchEquationsParams,
+) -> Result<Vec<EquationSearchResult>, DbError> {
+    let mut fb = FilterBuilder::new().not_null("equation_tex");
+    if let Some(ids) = params.paper_ids.as_deref() {
+        fb = fb.paper_ids(ids);
+    }
+    let embedding = store.embed_query(&params.query).await?;
+    let table = store.chunks_table().await?;
+    let hits = vector_candidates(&table, &embedding, fb.build().as_deref(), params.limit as usize).await?;
+    Ok(hits
+        .into_iter()
+        .map(|(data, score)| EquationSearchResult {
+            chunk_id: data.chunk_id,
+            paper_id: data.paper_id,
+            paper_title: data.title,
+            chapter_title: data.chapter_title,
+            section_title: data.section_title,
+            equations: data.equation_tex,
+            text: data.text,
+            score,
+        })
+        .collect())
+}
+
 /// Search for exhibits (figures, tables, algorithms) by description.
 pub async fn search_exhibits(
     store: &DbStore,
@@ -741,8 +1217,8 @@ async fn search_exhibits_with_embedding_inner(
     if let Some(ids) = params.paper_ids.as_deref() {
         fb = fb.paper_ids(ids);
     }
-    if let Some(ft) = &params.filter_exhibit_type {
-        fb = fb.eq_str("exhibit_type", ft);
+    if let Some(ft) = params.filter_exhibit_type {
+        fb = fb.eq_str("exhibit_type", ft.as_str());
     }
 
     let mut query_builder = table.query().nearest_to(embedding)?;
@@ -948,6 +1424,59 @@ pub async fn get_chapter(
     })
 }
 
+/// Inline the full record (caption, description, image path, content) of
+/// every exhibit referenced in `chunks` into the first chunk that references
+/// it, so callers don't need a `get_exhibit` per exhibit.
+pub async fn inline_exhibits<'a>(
+    store: &DbStore,
+    chunks: impl IntoIterator<Item = &'a mut ChunkWithPosition>,
+) -> Result<(), DbError> {
+    let mut chunks: Vec<&mut ChunkWithPosition> = chunks.into_iter().collect();
+    let mut ids: Vec<String> = Vec::new();
+    for chunk in &chunks {
+        for eid in &chunk.exhibit_ids {
+            if !ids.contains(eid) {
+                ids.push(eid.clone());
+            }
+        }
+    }
+    if ids.is_empty() {
+        return Ok(());
+    }
+
+    let id_list = ids
+        .iter()
+        .map(|id| format!("'{}'", id.replace('\'', "''")))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let batches = store
+        .exhibits_table()
+        .await?
+        .query()
+        .only_if(format!("exhibit_id IN ({id_list})"))
+        .execute()
+        .await?
+        .try_collect::<Vec<_>>()
+        .await
+        .map_err(DbError::LanceDb)?;
+    let mut found: HashMap<String, ExhibitResult> = HashMap::new();
+    for batch in &batches {
+        for row in 0..batch.num_rows() {
+            let exhibit = exhibit_from_row(batch, row)?;
+            found.insert(exhibit.exhibit_id.clone(), exhibit);
+        }
+    }
+
+    for chunk in &mut chunks {
+        for eid in chunk.exhibit_ids.clone() {
+            if let Some(exhibit) = found.remove(&eid) {
+                chunk.exhibits.push(exhibit);
+            }
+        }
+    }
+    Ok(())
+}
+
 /// Retrieve an exhibit by ID.
 pub async fn get_exhibit(store: &DbStore, exhibit_id: &str) -> Result<ExhibitResult, DbError> {
     let table = store.exhibits_table().await?;
@@ -968,19 +1497,52 @@ pub async fn get_exhibit(store: &DbStore, exhibit_id: &str) -> Result<ExhibitRes
             "exhibit not found: {exhibit_id}"
         )));
     }
-    let batch = &batches[0];
+    exhibit_from_row(&batches[0], 0)
+}
+
+/// List all exhibits of a paper in document order (`fig1`, `fig2`, …).
+/// Returns an empty list for unknown papers.
+pub async fn list_exhibits(
+    store: &DbStore,
+    paper_id: &str,
+) -> Result<Vec<ExhibitResult>, DbError> {
+    let table = store.exhibits_table().await?;
+    let escaped = paper_id.replace('\'', "''");
+    let batches = table
+        .query()
+        .only_if(format!("paper_id = '{escaped}'"))
+        .execute()
+        .await?
+        .try_collect::<Vec<_>>()
+        .await
+        .map_err(DbError::LanceDb)?;
+
+    let mut exhibits = Vec::new();
+    for batch in &batches {
+        for row in 0..batch.num_rows() {
+            exhibits.push(exhibit_from_row(batch, row)?);
+        }
+    }
+    let seq = |e: &ExhibitResult| {
+        e.exhibit_id.rsplit_once("/fig").and_then(|(_, n)| n.parse::<u32>().ok()).unwrap_or(0)
+    };
+    exhibits.sort_by_key(seq);
+    Ok(exhibits)
+}
+
+fn exhibit_from_row(batch: &RecordBatch, row: usize) -> Result<ExhibitResult, DbError> {
     Ok(ExhibitResult {
-        exhibit_id: col_str(batch, "exhibit_id", 0)?,
-        paper_id: col_str(batch, "paper_id", 0)?,
-        exhibit_type: col_str(batch, "exhibit_type", 0)?,
-        caption: col_str(batch, "caption", 0)?,
-        description: col_str_opt(batch, "description", 0)?,
-        image_path: col_str_opt(batch, "image_path", 0)?,
-        content: col_str_opt(batch, "content", 0)?,
-        page: col_u16_opt(batch, "page", 0)?,
+        exhibit_id: col_str(batch, "exhibit_id", row)?,
+        paper_id: col_str(batch, "paper_id", row)?,
+        exhibit_type: col_str(batch, "exhibit_type", row)?,
+        caption: col_str(batch, "caption", row)?,
+        description: col_str_opt(batch, "description", row)?,
+        image_path: col_str_opt(batch, "image_path", row)?,
+        content: col_str_opt(batch, "content", row)?,
+        page: col_u16_opt(batch, "page", row)?,
         referenced_by: vec![],
-        first_ref_chunk_id: col_str_opt(batch, "first_ref_chunk_id", 0)?,
-        ref_count: col_u16(batch, "ref_count", 0)?,
+        first_ref_chunk_id: col_str_opt(batch, "first_ref_chunk_id", row)?,
+        ref_count: col_u16(batch, "ref_count", row)?,
     })
 }
 
@@ -1136,7 +1698,7 @@ pub async fn list_papers(
     let mut query = table
         .query()
         .select(Select::columns(&[
-            "paper_id", "title", "authors", "year", "venue", "tags",
+            "paper_id", "title", "authors", "year", "venue", "tags", "chunking",
         ]));
     if let Some(filter) = fb.build() {
         query = query.only_if(filter);
@@ -1164,6 +1726,8 @@ pub async fn list_papers(
                     tags: col_str_list(batch, "tags", row)?,
                     chunk_count: 0,
                     exhibit_count: 0,
+                    chunking: col_str(batch, "chunking", row)?,
+                    reading: None,
                 });
             }
             paper_map.get_mut(&pid).unwrap().chunk_count += 1;
@@ -1199,12 +1763,17 @@ pub async fn list_papers(
         }
     }
 
+    let mut reading = reading_states(store, params.paper_ids.as_deref()).await?;
+    for (pid, paper) in paper_map.iter_mut() {
+        paper.reading = reading.remove(pid);
+    }
+
     let mut papers: Vec<PaperSummary> = paper_map.into_values().collect();
 
     // Sort
-    match params.sort_by.as_deref().unwrap_or("year") {
-        "title" => papers.sort_by(|a, b| a.title.cmp(&b.title)),
-        _ => papers.sort_by(|a, b| b.year.cmp(&a.year)),
+    match params.sort_by.unwrap_or_default() {
+        PaperSort::Title => papers.sort_by(|a, b| a.title.cmp(&b.title)),
+        PaperSort::Year => papers.sort_by(|a, b| b.year.cmp(&a.year)),
     }
 
     papers.truncate(params.limit as usize);
@@ -1401,15 +1970,595 @@ pub async fn search_works(
     Ok(results)
 }
 
+/// Semantic search over the abstracts-only tier: one hit per paper, whether or
+/// not its full text is indexed. Each hit says whether it is (`ingested`).
+pub async fn search_abstracts(
+    store: &DbStore,
+    params: SearchAbstractsParams,
+) -> Result<Vec<AbstractSearchResult>, DbError> {
+    let embedding = store.embed_query(&params.query).await?;
+    let table = store.abstracts_table().await?;
+
+    let mut fb = FilterBuilder::new();
+    if let Some(ids) = params.paper_ids.as_deref() {
+        fb = fb.paper_ids(ids);
+    }
+    fb = fb.year_range(params.filter_year_min, params.filter_year_max);
+
+    let mut query_builder = table.query().nearest_to(embedding)?.limit(params.limit as usize);
+    if let Some(filter) = fb.build() {
+        query_builder = query_builder.only_if(filter);
+    }
+    let batches = query_builder
+        .execute()
+        .await?
+        .try_collect::<Vec<_>>()
+        .await
+        .map_err(DbError::LanceDb)?;
+
+    let mut results = Vec::new();
+    for batch in &batches {
+        let has_distance = batch.column_by_name("_distance").is_some();
+        for row in 0..batch.num_rows() {
+            results.push(AbstractSearchResult {
+                paper_id: col_str(batch, "paper_id", row)?,
+                openalex_id: col_str_opt(batch, "openalex_id", row)?,
+                doi: col_str_opt(batch, "doi", row)?,
+                title: col_str(batch, "title", row)?,
+                authors: col_str_list(batch, "authors", row)?,
+                year: col_u16_opt(batch, "year", row)?,
+                venue: col_str_opt(batch, "venue", row)?,
+                score: if has_distance { col_f32(batch, "_distance", row)? } else { 0.0 },
+                text: col_str(batch, "text", row)?,
+                ingested: false,
+            });
+        }
+    }
+    results.sort_by(|a, b| a.score.partial_cmp(&b.score).unwrap_or(std::cmp::Ordering::Equal));
+    results.truncate(params.limit as usize);
+
+    let ids: Vec<String> = results.iter().map(|r| r.paper_id.clone()).collect();
+    if let Some(filter) = FilterBuilder::new().paper_ids(&ids).build() {
+        let batches = store
+            .chunks_table()
+            .await?
+            .query()
+            .only_if(filter)
+            .select(Select::columns(&["paper_id"]))
+            .execute()
+            .await?
+            .try_collect::<Vec<_>>()
+            .await
+            .map_err(DbError::LanceDb)?;
+        let mut ingested = std::collections::HashSet::new();
+        for batch in &batches {
+            for row in 0..batch.num_rows() {
+                ingested.insert(col_str(batch, "paper_id", row)?);
+            }
+        }
+        for r in &mut results {
+            r.ingested = ingested.contains(&r.paper_id);
+        }
+    }
+    Ok(results)
+}
+
+/// Mean of `vectors`, scaled to unit length; all zeros if they cancel out.
+fn unit_centroid(vectors: &[Vec<f32>]) -> Vec<f32> {
+    let dim = vectors.first().map_or(0, Vec::len);
+    let mut centroid = vec![0.0f32; dim];
+    for v in vectors {
+        for (c, x) in centroid.iter_mut().zip(v) {
+            *c += x;
+        }
+    }
+    let norm = centroid.iter().map(|c| c * c).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        centroid.iter_mut().for_each(|c| *c /= norm);
+    }
+    centroid
+}
+
+/// A paper's metadata and the unit centroid of its chunk vectors.
+struct PaperCentroid {
+    paper_id: String,
+    title: String,
+    authors: Vec<String>,
+    year: Option<u16>,
+    venue: Option<String>,
+    chunk_count: usize,
+    centroid: Vec<f32>,
+}
+
+/// Centroids of every indexed paper, or of `paper_ids` only, sorted by id.
+async fn paper_centroids(
+    store: &DbStore,
+    paper_ids: Option<&[String]>,
+) -> Result<Vec<PaperCentroid>, DbError> {
+    let table = store.chunks_table().await?;
+    let mut query = table.query().select(Select::columns(&[
+        "paper_id", "title", "authors", "year", "venue", "vector",
+    ]));
+    if let Some(ids) = paper_ids {
+        if let Some(filter) = FilterBuilder::new().paper_ids(ids).build() {
+            query = query.only_if(filter);
+        }
+    }
+    let batches = query
+        .execute()
+        .await?
+        .try_collect::<Vec<_>>()
+        .await
+        .map_err(DbError::LanceDb)?;
+
+    let mut papers: HashMap<String, (PaperCentroid, Vec<Vec<f32>>)> = HashMap::new();
+    for batch in &batches {
+        for row in 0..batch.num_rows() {
+            let pid = col_str(batch, "paper_id", row)?;
+            let vector = col_vector(batch, "vector", row)?;
+            if let Some((_, vectors)) = papers.get_mut(&pid) {
+                vectors.push(vector);
+                continue;
+            }
+            let paper = PaperCentroid {
+                paper_id: pid.clone(),
+                title: col_str(batch, "title", row)?,
+                authors: col_str_list(batch, "authors", row)?,
+                year: col_u16_opt(batch, "year", row)?,
+                venue: col_str_opt(batch, "venue", row)?,
+                chunk_count: 0,
+                centroid: Vec::new(),
+            };
+            papers.insert(pid, (paper, vec![vector]));
+        }
+    }
+
+    let mut centroids: Vec<PaperCentroid> = papers
+        .into_values()
+        .map(|(mut paper, vectors)| {
+            paper.centroid = unit_centroid(&vectors);
+            paper.chunk_count = vectors.len();
+            paper
+        })
+        .collect();
+    centroids.sort_by(|a, b| a.paper_id.cmp(&b.paper_id));
+    Ok(centroids)
+}
+
+/// Rank the other indexed papers by similarity to `paper_id`: the cosine
+/// similarity of each paper's centroid (mean chunk vector) to the target's.
+/// Reads every in-scope chunk vector, which is fine at personal-library scale.
+pub async fn similar_works(
+    store: &DbStore,
+    params: SimilarWorksParams,
+) -> Result<Vec<SimilarWork>, DbError> {
+    let target = paper_centroids(store, Some(std::slice::from_ref(&params.paper_id)))
+        .await?
+        .pop()
+        .ok_or_else(|| DbError::NotFound(format!("paper not found: {}", params.paper_id)))?
+        .centroid;
+
+    let mut results: Vec<SimilarWork> = paper_centroids(store, params.paper_ids.as_deref())
+        .await?
+        .into_iter()
+        .filter(|p| p.paper_id != params.paper_id)
+        .map(|p| SimilarWork {
+            score: dot(&target, &p.centroid),
+            paper_id: p.paper_id,
+            title: p.title,
+            authors: p.authors,
+            year: p.year,
+            venue: p.venue,
+            chunk_count: p.chunk_count,
+        })
+        .collect();
+    results.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.paper_id.cmp(&b.paper_id))
+    });
+    results.truncate(params.limit as usize);
+    Ok(results)
+}
+
+/// Group indexed papers into themes: k-means on paper centroids (cosine),
+/// with each cluster labelled by the title terms that set it apart.
+pub async fn cluster_works(
+    store: &DbStore,
+    params: ClusterWorksParams,
+) -> Result<WorkClustering, DbError> {
+    if params.k == Some(0) {
+        return Err(DbError::Scope("k must be at least 1".into()));
+    }
+    let papers = paper_centroids(store, params.paper_ids.as_deref()).await?;
+    let vectors: Vec<Vec<f32>> = papers.iter().map(|p| p.centroid.clone()).collect();
+    let clustering = match params.k {
+        Some(k) => cluster::kmeans(&vectors, k as usize),
+        None => cluster::kmeans_auto(&vectors),
+    };
+    let all_titles: Vec<&str> = papers.iter().map(|p| p.title.as_str()).collect();
+
+    let mut clusters: Vec<WorkCluster> = Vec::new();
+    for (c, centroid) in clustering.centroids.iter().enumerate() {
+        let mut members: Vec<ClusterMember> = papers
+            .iter()
+            .zip(&clustering.assignments)
+            .filter(|(_, a)| **a == c)
+            .map(|(p, _)| ClusterMember {
+                paper_id: p.paper_id.clone(),
+                title: p.title.clone(),
+                year: p.year,
+                score: dot(&p.centroid, centroid),
+            })
+            .collect();
+        if members.is_empty() {
+            continue;
+        }
+        members.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.paper_id.cmp(&b.paper_id))
+        });
+        let member_titles: Vec<&str> = members.iter().map(|m| m.title.as_str()).collect();
+        let terms = cluster::label_terms(&member_titles, &all_titles, 5);
+        let label = if terms.is_empty() { members[0].title.clone() } else { terms.join(", ") };
+        clusters.push(WorkCluster { cluster: 0, label, terms, papers: members });
+    }
+    clusters.sort_by(|a, b| {
+        b.papers
+            .len()
+            .cmp(&a.papers.len())
+            .then_with(|| a.papers[0].paper_id.cmp(&b.papers[0].paper_id))
+    });
+    for (i, cluster) in clusters.iter_mut().enumerate() {
+        cluster.cluster = i;
+    }
+
+    Ok(WorkClustering { k: clusters.len(), silhouette: clustering.silhouette, clusters })
+}
+
+/// Bucket indexed papers by publication year for timeline views.
+///
+/// `cited_by_count` is the number of other indexed papers whose reference
+/// lists cite the paper by DOI, so milestones are the papers most cited
+/// within the corpus. The returned `scope` is `"index"`; callers scoping to a
+/// selection replace it with the selection name.
+pub async fn work_timeline(
+    store: &DbStore,
+    params: WorkTimelineParams,
+) -> Result<papers_core::timeline::Timeline, DbError> {
+    let papers = list_papers(
+        store,
+        ListPapersParams {
+            paper_ids: params.paper_ids,
+            filter_year_min: None,
+            filter_year_max: None,
+            filter_venue: None,
+            filter_tags: None,
+            filter_authors: None,
+            sort_by: None,
+            limit: u16::MAX,
+        },
+    )
+    .await?;
+
+    let mut citers: HashMap<String, std::collections::HashSet<String>> = HashMap::new();
+    for (paper_id, doi) in doi_citations(store).await? {
+        citers.entry(doi).or_default().insert(paper_id);
+    }
+
+    let papers = papers
+        .into_iter()
+        .map(|p| {
+            let doi = papers_core::selection::looks_like_doi(&p.paper_id)
+                .then(|| papers_core::selection::strip_doi_prefix(&p.paper_id).to_string());
+            let cited_by_count = doi.as_ref().map(|doi| {
+                citers.get(&doi.to_lowercase()).map_or(0, |ids| {
+                    ids.iter().filter(|id| **id != p.paper_id).count() as i64
+                })
+            });
+            papers_core::timeline::TimelinePaper {
+                id: p.paper_id,
+                title: Some(p.title),
+                authors: p.authors,
+                year: p.year.map(u32::from),
+                venue: p.venue,
+                doi,
+                cited_by_count,
+                milestone: false,
+            }
+        })
+        .collect();
+    Ok(papers_core::timeline::build_timeline(
+        "index",
+        papers,
+        params.milestones_per_year as usize,
+        Vec::new(),
+    ))
+}
+
 /// Delete all chunks and exhibits for a paper from the index.
 pub async fn remove_work(store: &DbStore, paper_id: &str) -> Result<(), DbError> {
-    let paper_id_esc = paper_id.replace('\'', "''");
-    let filter = format!("paper_id = '{paper_id_esc}'");
-    let chunks_table = store.chunks_table().await?;
-    chunks_table.delete(&filter).await.map_err(DbError::LanceDb)?;
-    let exhibits_table = store.exhibits_table().await?;
-    exhibits_table.delete(&filter).await.map_err(DbError::LanceDb)?;
-    Ok(())
+    store.delete_paper(paper_id).await.map(|_| ())
+}
+
+// ── Citations ────────────────────────────────────────────────────────────────
+
+/// `(citing paper_id, cited DOI)` for every linked citation whose reference
+/// entry carries a DOI. DOIs are lowercase, as stored at ingest.
+async fn doi_citations(store: &DbStore) -> Result<Vec<(String, String)>, DbError> {
+    let batches = store
+        .citations_table()
+        .await?
+        .query()
+        .only_if("cited_doi IS NOT NULL")
+        .select(Select::columns(&["paper_id", "cited_doi"]))
+        .execute()
+        .await?
+        .try_collect::<Vec<_>>()
+        .await
+        .map_err(DbError::LanceDb)?;
+    let mut rows = Vec::new();
+    for batch in &batches {
+        for row in 0..batch.num_rows() {
+            if let Some(doi) = col_str_opt(batch, "cited_doi", row)? {
+                rows.push((col_str(batch, "paper_id", row)?, doi));
+            }
+        }
+    }
+    Ok(rows)
+}
+
+/// Every sentence in the indexed papers that cites `params.doi`.
+///
+/// Reference entries are matched by DOI, or — since many reference lists
+/// omit DOIs — by title: `params.title`, else the cited paper's own title if
+/// it is indexed. Titles under three words are too ambiguous to match on.
+pub async fn citation_contexts(
+    store: &DbStore,
+    params: CitationContextsParams,
+) -> Result<Vec<CitationContext>, DbError> {
+    let bare = papers_core::selection::strip_doi_prefix(params.doi.trim());
+    let doi = bare.to_lowercase();
+
+    let title = match params.title {
+        Some(title) => Some(title),
+        None => {
+            let ids = [bare.to_string(), doi.clone()];
+            let batches = store
+                .chunks_table()
+                .await?
+                .query()
+                .only_if(FilterBuilder::new().paper_ids(&ids).build().unwrap_or_default())
+                .select(Select::columns(&["title"]))
+                .limit(1)
+                .execute()
+                .await?
+                .try_collect::<Vec<_>>()
+                .await
+                .map_err(DbError::LanceDb)?;
+            match batches.iter().find(|b| b.num_rows() > 0) {
+                Some(batch) => Some(col_str(batch, "title", 0)?),
+                None => None,
+            }
+        }
+    };
+    let title_key = title
+        .as_deref()
+        .map(normalize_key)
+        .filter(|key| key.split(' ').count() >= 3);
+
+    let mut matches = vec![format!("cited_doi = '{}'", doi.replace('\'', "''"))];
+    if let Some(key) = &title_key {
+        matches.push(format!("ref_key LIKE '%{key}%'"));
+    }
+    let mut filter = format!("({})", matches.join(" OR "));
+    if let Some(ids) = params.paper_ids.as_deref()
+        && let Some(scope) = FilterBuilder::new().paper_ids(ids).build()
+    {
+        filter = format!("{filter} AND {scope}");
+    }
+
+    let batches = store
+        .citations_table()
+        .await?
+        .query()
+        .only_if(filter)
+        .execute()
+        .await?
+        .try_collect::<Vec<_>>()
+        .await
+        .map_err(DbError::LanceDb)?;
+
+    let mut results = Vec::new();
+    for batch in &batches {
+        for row in 0..batch.num_rows() {
+            let cited_doi = col_str_opt(batch, "cited_doi", row)?;
+            results.push(CitationContext {
+                paper_id: col_str(batch, "paper_id", row)?,
+                paper_title: col_str(batch, "title", row)?,
+                year: col_u16_opt(batch, "year", row)?,
+                chunk_id: col_str(batch, "chunk_id", row)?,
+                sentence: col_str(batch, "sentence", row)?,
+                marker: col_str(batch, "marker", row)?,
+                ref_text: col_str(batch, "ref_text", row)?,
+                matched_by: if cited_doi.as_deref() == Some(doi.as_str()) { "doi" } else { "title" }
+                    .to_string(),
+            });
+        }
+    }
+    // Newest citing papers first; rows within a paper stay in reading order.
+    results.sort_by(|a, b| b.year.cmp(&a.year).then_with(|| a.paper_id.cmp(&b.paper_id)));
+    results.truncate(params.limit as usize);
+    Ok(results)
+}
+
+/// Citation graph among indexed papers: an edge from each paper to every
+/// other indexed paper (by DOI id) that one of its reference entries cites.
+pub async fn citation_graph(
+    store: &DbStore,
+    paper_ids: Option<Vec<String>>,
+) -> Result<papers_core::graph::CitationGraph, DbError> {
+    use papers_core::graph::{CitationGraph, GraphEdge, GraphNode};
+
+    let papers = list_papers(
+        store,
+        ListPapersParams {
+            paper_ids,
+            filter_year_min: None,
+            filter_year_max: None,
+            filter_venue: None,
+            filter_tags: None,
+            filter_authors: None,
+            sort_by: None,
+            limit: u16::MAX,
+        },
+    )
+    .await?;
+    let by_doi: HashMap<String, String> = papers
+        .iter()
+        .filter(|p| papers_core::selection::looks_like_doi(&p.paper_id))
+        .map(|p| {
+            let doi = papers_core::selection::strip_doi_prefix(&p.paper_id).to_lowercase();
+            (doi, p.paper_id.clone())
+        })
+        .collect();
+
+    let edges: Vec<GraphEdge> = doi_citations(store)
+        .await?
+        .into_iter()
+        .filter_map(|(source, doi)| Some(GraphEdge { source, target: by_doi.get(&doi)?.clone() }))
+        .collect();
+
+    let nodes = papers
+        .into_iter()
+        .map(|p| GraphNode {
+            doi: papers_core::selection::looks_like_doi(&p.paper_id)
+                .then(|| papers_core::selection::strip_doi_prefix(&p.paper_id).to_string()),
+            id: p.paper_id,
+            label: p.title,
+            year: p.year.map(u32::from),
+            cited_by_count: None,
+        })
+        .collect();
+    Ok(CitationGraph::new(nodes, edges))
+}
+
+// ── Reading position ─────────────────────────────────────────────────────────
+
+fn reading_state_from_row(batch: &RecordBatch, row: usize) -> Result<ReadingState, DbError> {
+    let furthest = col_u32(batch, "furthest_chunk", row)?;
+    let total = col_u32(batch, "total_chunks", row)?.max(1);
+    Ok(ReadingState {
+        paper_id: col_str(batch, "paper_id", row)?,
+        last_chunk_id: col_str(batch, "last_chunk_id", row)?,
+        chapter_idx: col_u16(batch, "chapter_idx", row)?,
+        section_idx: col_u16(batch, "section_idx", row)?,
+        chunk_idx: col_u16(batch, "chunk_idx", row)?,
+        percent_complete: (furthest as f32 / total as f32 * 100.0).min(100.0),
+        updated_at: col_u64(batch, "updated_at", row)?,
+    })
+}
+
+/// Reading positions keyed by paper ID, for all papers or just `paper_ids`.
+pub async fn reading_states(
+    store: &DbStore,
+    paper_ids: Option<&[String]>,
+) -> Result<HashMap<String, ReadingState>, DbError> {
+    let mut query = store.reading_table().await?.query();
+    if let Some(filter) = paper_ids.and_then(|ids| FilterBuilder::new().paper_ids(ids).build()) {
+        query = query.only_if(filter);
+    }
+    let batches = query
+        .execute()
+        .await?
+        .try_collect::<Vec<_>>()
+        .await
+        .map_err(DbError::LanceDb)?;
+    let mut states = HashMap::new();
+    for batch in &batches {
+        for row in 0..batch.num_rows() {
+            let state = reading_state_from_row(batch, row)?;
+            states.insert(state.paper_id.clone(), state);
+        }
+    }
+    Ok(states)
+}
+
+/// Record `chunk_id` as read, making it the paper's place to continue from.
+///
+/// Percent complete counts the paper's chunks in reading order up to the
+/// furthest chunk ever marked, so jumping back to reread doesn't lower it.
+pub async fn mark_read(store: &DbStore, chunk_id: &str) -> Result<ReadingState, DbError> {
+    let chunk = get_chunk(store, chunk_id).await?.chunk;
+    let filter = format!("paper_id = '{}'", chunk.paper_id.replace('\'', "''"));
+    let batches = store
+        .chunks_table()
+        .await?
+        .query()
+        .only_if(&filter)
+        .select(Select::columns(&["chapter_idx", "section_idx", "chunk_idx"]))
+        .execute()
+        .await?
+        .try_collect::<Vec<_>>()
+        .await
+        .map_err(DbError::LanceDb)?;
+    let mut order = Vec::new();
+    for batch in &batches {
+        for row in 0..batch.num_rows() {
+            order.push((
+                col_u16(batch, "chapter_idx", row)?,
+                col_u16(batch, "section_idx", row)?,
+                col_u16(batch, "chunk_idx", row)?,
+            ));
+        }
+    }
+    order.sort_unstable();
+    let key = (chunk.chapter_idx, chunk.section_idx, chunk.chunk_idx);
+    let position = order.partition_point(|k| *k < key) as u32 + 1;
+    let total = order.len() as u32;
+
+    let table = store.reading_table().await?;
+    let previous = table
+        .query()
+        .only_if(&filter)
+        .select(Select::columns(&["furthest_chunk"]))
+        .execute()
+        .await?
+        .try_collect::<Vec<_>>()
+        .await
+        .map_err(DbError::LanceDb)?;
+    let mut furthest = position;
+    if let Some(batch) = previous.iter().find(|b| b.num_rows() > 0) {
+        // A reindex may have left the paper with fewer chunks.
+        furthest = furthest.max(col_u32(batch, "furthest_chunk", 0)?.min(total));
+    }
+    let updated_at = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    let batch = RecordBatch::try_new(
+        crate::schema::reading_schema(),
+        vec![
+            std::sync::Arc::new(StringArray::from(vec![chunk.paper_id.as_str()])),
+            std::sync::Arc::new(StringArray::from(vec![chunk.chunk_id.as_str()])),
+            std::sync::Arc::new(UInt16Array::from(vec![chunk.chapter_idx])),
+            std::sync::Arc::new(UInt16Array::from(vec![chunk.section_idx])),
+            std::sync::Arc::new(UInt16Array::from(vec![chunk.chunk_idx])),
+            std::sync::Arc::new(UInt32Array::from(vec![furthest])),
+            std::sync::Arc::new(UInt32Array::from(vec![total])),
+            std::sync::Arc::new(UInt64Array::from(vec![updated_at])),
+        ],
+    )
+    .map_err(|e| DbError::Arrow(e.to_string()))?;
+    let reader =
+        arrow_array::RecordBatchIterator::new(vec![Ok(batch.clone())], crate::schema::reading_schema());
+    table.delete(&filter).await?;
+    table.add(Box::new(reader)).execute().await?;
+    reading_state_from_row(&batch, 0)
 }
 
 // ── Chunk list ───────────────────────────────────────────────────────────────
@@ -1486,6 +2635,114 @@ pub async fn list_chunks(
     Ok(results)
 }
 
+// ── Passage location ─────────────────────────────────────────────────────────
+
+/// Minimum [`passage_score`] for a chunk to count as holding the passage.
+const PASSAGE_MATCH_THRESHOLD: f64 = 0.3;
+
+/// Share of the passage's adjacent word pairs (or its single word) that also
+/// appear in `chunk`. Word pairs keep a highlight that straddles two chunks
+/// scoring on both, while common words alone don't match.
+fn passage_score(passage: &[String], chunk: &str) -> f64 {
+    let chunk = heading_tokens(chunk);
+    match passage {
+        [] => 0.0,
+        [word] => if chunk.contains(word) { 1.0 } else { 0.0 },
+        _ => {
+            let pairs: std::collections::HashSet<(&str, &str)> =
+                chunk.windows(2).map(|w| (w[0].as_str(), w[1].as_str())).collect();
+            let shared = passage
+                .windows(2)
+                .filter(|w| pairs.contains(&(w[0].as_str(), w[1].as_str())))
+                .count();
+            shared as f64 / (passage.len() - 1) as f64
+        }
+    }
+}
+
+/// Find the chunks of a paper that hold a highlighted passage, best first.
+///
+/// Chunks are matched on the passage's words, ignoring case, punctuation and
+/// the line breaks PDF selections pick up; on equal scores a chunk spanning
+/// `page_index` wins. When the text matches nothing (or is empty), the chunks
+/// on that page are returned in reading order instead.
+pub async fn locate_passage(
+    store: &DbStore,
+    params: LocatePassageParams,
+) -> Result<Vec<PassageMatch>, DbError> {
+    let table = store.chunks_table().await?;
+    let Some(filter) = FilterBuilder::new().paper_ids(&[params.paper_id.clone()]).build() else {
+        return Ok(Vec::new());
+    };
+    let batches = table
+        .query()
+        .only_if(filter)
+        .select(Select::columns(&[
+            "chunk_id", "chapter_idx", "chapter_title", "section_idx", "section_title",
+            "chunk_idx", "page_start", "page_end", "text",
+        ]))
+        .execute()
+        .await?
+        .try_collect::<Vec<_>>()
+        .await
+        .map_err(DbError::LanceDb)?;
+    if total_rows(&batches) == 0 {
+        return Err(DbError::NotFound(format!("paper not indexed: {}", params.paper_id)));
+    }
+
+    let passage = heading_tokens(&params.text);
+    let mut matches = Vec::new();
+    for batch in &batches {
+        for row in 0..batch.num_rows() {
+            let text = col_str(batch, "text", row)?;
+            let page_start = col_u16_opt(batch, "page_start", row)?;
+            let page_end = col_u16_opt(batch, "page_end", row)?;
+            let on_page = match (params.page_index, page_start) {
+                (Some(page), Some(start)) => (start..=page_end.unwrap_or(start)).contains(&page),
+                _ => false,
+            };
+            matches.push(PassageMatch {
+                chunk_id: col_str(batch, "chunk_id", row)?,
+                chapter_idx: col_u16(batch, "chapter_idx", row)?,
+                chapter_title: col_str(batch, "chapter_title", row)?,
+                section_idx: col_u16(batch, "section_idx", row)?,
+                section_title: col_str(batch, "section_title", row)?,
+                chunk_idx: col_u16(batch, "chunk_idx", row)?,
+                page_start,
+                page_end,
+                score: passage_score(&passage, &text) as f32,
+                on_page,
+                text,
+                prev: None,
+                next: None,
+            });
+        }
+    }
+    matches.sort_by_key(|m| (m.chapter_idx, m.section_idx, m.chunk_idx));
+
+    let threshold = PASSAGE_MATCH_THRESHOLD as f32;
+    if matches.iter().any(|m| m.score >= threshold) {
+        matches.retain(|m| m.score >= threshold);
+        // Stable sort, so equal candidates stay in reading order.
+        matches.sort_by(|a, b| b.score.total_cmp(&a.score).then(b.on_page.cmp(&a.on_page)));
+    } else {
+        matches.retain(|m| m.on_page);
+        for m in &mut matches {
+            m.score = 0.0;
+        }
+    }
+    matches.truncate(params.limit as usize);
+
+    for m in &mut matches {
+        let (prev, next) =
+            fetch_neighbors(&table, &params.paper_id, m.chapter_idx, m.section_idx, m.chunk_idx)
+                .await?;
+        m.prev = prev;
+        m.next = next;
+    }
+    Ok(matches)
+}
+
 // ── Section-level queries ────────────────────────────────────────────────────
 
 /// Semantic search returning one result per matching section.
@@ -1897,4 +3154,33 @@ mod tests {
         // Under max, no period, returns as-is (no truncation needed since == min)
         assert_eq!(result, text);
     }
+
+    // ── reciprocal_rank_fusion ────────────────────────────────────────────
+
+    fn ids(v: &[&str]) -> Vec<String> {
+        v.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn rrf_rewards_agreement_across_rankings() {
+        let fused = reciprocal_rank_fusion(&[ids(&["a", "b", "c"]), ids(&["d", "b", "e"])]);
+        let order: Vec<&str> = fused.iter().map(|(id, _)| id.as_str()).collect();
+        // b is 2nd in both lists; a and d are 1st in one list only.
+        assert_eq!(order, vec!["b", "a", "d", "c", "e"]);
+        assert!((fused[0].1 - 2.0 / 62.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn rrf_ties_keep_first_seen_order() {
+        let fused = reciprocal_rank_fusion(&[ids(&["x"]), ids(&["y"])]);
+        let order: Vec<&str> = fused.iter().map(|(id, _)| id.as_str()).collect();
+        assert_eq!(order, vec!["x", "y"]);
+    }
+
+    #[test]
+    fn search_mode_parse() {
+        assert_eq!("Hybrid".parse::<SearchMode>().unwrap(), SearchMode::Hybrid);
+        assert_eq!("keyword".parse::<SearchMode>().unwrap(), SearchMode::Keyword);
+        assert!("bm25".parse::<SearchMode>().unwrap_err().contains("semantic, keyword, or hybrid"));
+    }
 }
@@ -20,16 +20,39 @@ fn string_list_field_nullable(name: &str) -> Field {
 }
 
 fn vector_field() -> Field {
+    vector_field_with_dim(EMBED_DIM)
+}
+
+fn vector_field_with_dim(dim: i32) -> Field {
     Field::new(
         "vector",
         DataType::FixedSizeList(
             Arc::new(Field::new("item", DataType::Float32, true)),
-            EMBED_DIM,
+            dim,
         ),
         false,
     )
 }
 
+/// Length of the `vector` column in `schema`, if it has one.
+pub fn vector_dim(schema: &Schema) -> Option<i32> {
+    match schema.field_with_name("vector").ok()?.data_type() {
+        DataType::FixedSizeList(_, dim) => Some(*dim),
+        _ => None,
+    }
+}
+
+/// `schema` with its `vector` column resized to `dim`; other columns and the
+/// metadata (including the schema version) are kept.
+pub fn with_vector_dim(schema: &Schema, dim: i32) -> Arc<Schema> {
+    let fields: Vec<Field> = schema
+        .fields()
+        .iter()
+        .map(|f| if f.name() == "vector" { vector_field_with_dim(dim) } else { f.as_ref().clone() })
+        .collect();
+    Arc::new(Schema::new_with_metadata(fields, schema.metadata().clone()))
+}
+
 pub fn chunks_schema() -> Arc<Schema> {
     Arc::new(Schema::new(vec![
         Field::new("chunk_id", DataType::Utf8, false),
@@ -51,6 +74,8 @@ pub fn chunks_schema() -> Arc<Schema> {
         Field::new("venue", DataType::Utf8, true),
         string_list_field("tags"),
         string_list_field("exhibit_ids"),
+        Field::new("chunking", DataType::Utf8, false),
+        Field::new("equation_tex", DataType::Utf8, true),
     ]))
 }
 
@@ -74,5 +99,53 @@ pub fn exhibits_schema() -> Arc<Schema> {
         string_list_field_nullable("tags"),
         Field::new("first_ref_chunk_id", DataType::Utf8, true),
         Field::new("ref_count", DataType::UInt16, false),
+        Field::new("table_text", DataType::Utf8, true),
+    ]))
+}
+
+/// Inline citations linked to reference entries; no vector column.
+pub fn citations_schema() -> Arc<Schema> {
+    Arc::new(Schema::new(vec![
+        Field::new("citation_id", DataType::Utf8, false),
+        Field::new("paper_id", DataType::Utf8, false),
+        Field::new("chunk_id", DataType::Utf8, false),
+        Field::new("title", DataType::Utf8, false),
+        Field::new("year", DataType::UInt16, true),
+        Field::new("marker", DataType::Utf8, false),
+        Field::new("sentence", DataType::Utf8, false),
+        Field::new("ref_label", DataType::Utf8, false),
+        Field::new("ref_text", DataType::Utf8, false),
+        Field::new("ref_key", DataType::Utf8, false),
+        Field::new("cited_doi", DataType::Utf8, true),
+    ]))
+}
+
+/// One OpenAlex abstract per paper, for the abstracts-only tier searched by
+/// `search_abstracts`. Papers need not have chunks.
+pub fn abstracts_schema() -> Arc<Schema> {
+    Arc::new(Schema::new(vec![
+        Field::new("paper_id", DataType::Utf8, false),
+        vector_field(),
+        Field::new("openalex_id", DataType::Utf8, true),
+        Field::new("doi", DataType::Utf8, true),
+        Field::new("title", DataType::Utf8, false),
+        string_list_field("authors"),
+        Field::new("year", DataType::UInt16, true),
+        Field::new("venue", DataType::Utf8, true),
+        Field::new("text", DataType::Utf8, false),
+    ]))
+}
+
+/// Reading position per paper, one row each; no vector column.
+pub fn reading_schema() -> Arc<Schema> {
+    Arc::new(Schema::new(vec![
+        Field::new("paper_id", DataType::Utf8, false),
+        Field::new("last_chunk_id", DataType::Utf8, false),
+        Field::new("chapter_idx", DataType::UInt16, false),
+        Field::new("section_idx", DataType::UInt16, false),
+        Field::new("chunk_idx", DataType::UInt16, false),
+        Field::new("furthest_chunk", DataType::UInt32, false),
+        Field::new("total_chunks", DataType::UInt32, false),
+        Field::new("updated_at", DataType::UInt64, false),
     ]))
 }
@@ -1,22 +1,35 @@
 use arrow_array::RecordBatchIterator;
 use arrow_schema::Schema;
+use futures::TryStreamExt;
 use lancedb::index::Index;
+use lancedb::index::scalar::FtsIndexBuilder;
+use lancedb::query::{ExecutableQuery, QueryBase, Select};
 use lancedb::{Connection, Table};
+use std::collections::HashSet;
+use std::path::Path;
 use std::sync::{Arc, Mutex};
 use tokio::sync::OnceCell;
 
-use crate::embed::Embedder;
+use crate::embed::{Embedder, Reranker};
 use crate::error::DbError;
-use crate::schema::{chunks_schema, exhibits_schema};
+use crate::schema::{
+    EMBED_DIM, abstracts_schema, chunks_schema, citations_schema, exhibits_schema, reading_schema,
+    vector_dim, with_vector_dim,
+};
+use crate::types::{DbStats, DeleteStats, IngestStats, ListPapersParams, MigrateProgress, MigrateStats};
+
+/// Rows embedded per batch by `DbStore::migrate`.
+const MIGRATE_BATCH: usize = 64;
 
 pub struct DbStore {
     pub(crate) db: Connection,
     pub(crate) embedder: OnceCell<Arc<Mutex<Embedder>>>,
+    pub(crate) reranker: OnceCell<Arc<Mutex<Reranker>>>,
 }
 
 impl DbStore {
     /// Open (or create) the RAG database at the given path.
-    /// Creates both tables with correct schemas if they don't exist yet.
+    /// Creates the tables with correct schemas if they don't exist yet.
     /// The embedding model is loaded lazily on first use.
     pub async fn open(path: &str) -> Result<Self, DbError> {
         let db = lancedb::connect(path).execute().await?;
@@ -26,10 +39,23 @@ impl DbStore {
         migrate_chunks_table(&chunks).await?;
         let exhibits = ensure_table(&db, "papers_exhibits", exhibits_schema()).await?;
         migrate_exhibits_table(&exhibits).await?;
+        ensure_table(&db, "papers_citations", citations_schema()).await?;
+        ensure_table(&db, "papers_reading", reading_schema()).await?;
+        ensure_table(&db, "papers_abstracts", abstracts_schema()).await?;
+
+        if let Some(dim) = vector_dim(&chunks.schema().await?)
+            && dim != EMBED_DIM
+        {
+            eprintln!(
+                "  warning: DB vectors are {dim}-d but {} is {EMBED_DIM}-d; run: papers db migrate",
+                crate::embed::MODEL_NAME
+            );
+        }
 
         let store = Self {
             db,
             embedder: OnceCell::new(),
+            reranker: OnceCell::new(),
         };
         Ok(store)
     }
@@ -55,6 +81,26 @@ impl DbStore {
             .cloned()
     }
 
+    /// Get or initialize the cross-encoder reranker (lazy loading; not part of `warm_up`).
+    async fn reranker(&self) -> Result<Arc<Mutex<Reranker>>, DbError> {
+        self.reranker
+            .get_or_try_init(|| async {
+                let name = crate::embed::rerank_model_name();
+                eprintln!(
+                    "    loading reranker {name} [{}] (downloads on first run)...",
+                    crate::embed::ep_name()
+                );
+                let t = std::time::Instant::now();
+                let reranker = tokio::task::spawn_blocking(move || Reranker::new(&name))
+                    .await
+                    .map_err(|e| DbError::Embed(format!("spawn_blocking join error: {e}")))??;
+                eprintln!("    reranker ready ({:.1}s)", t.elapsed().as_secs_f64());
+                Ok(Arc::new(Mutex::new(reranker)))
+            })
+            .await
+            .cloned()
+    }
+
     /// Default path: $PAPERS_DB_PATH or {PAPERS_DATA_DIR}/rag or platform data dir.
     pub fn default_path() -> String {
         if let Ok(p) = std::env::var("PAPERS_DB_PATH") {
@@ -77,11 +123,18 @@ impl DbStore {
         migrate_chunks_table(&chunks).await?;
         let exhibits = ensure_table(&db, "papers_exhibits", exhibits_schema()).await?;
         migrate_exhibits_table(&exhibits).await?;
+        ensure_table(&db, "papers_citations", citations_schema()).await?;
+        ensure_table(&db, "papers_reading", reading_schema()).await?;
+        ensure_table(&db, "papers_abstracts", abstracts_schema()).await?;
         let embedder = OnceCell::new();
         embedder
             .set(Arc::new(Mutex::new(Embedder::fake())))
             .unwrap();
-        Ok(Self { db, embedder })
+        let reranker = OnceCell::new();
+        reranker
+            .set(Arc::new(Mutex::new(Reranker::fake())))
+            .unwrap();
+        Ok(Self { db, embedder, reranker })
     }
 
     pub async fn chunks_table(&self) -> Result<Table, DbError> {
@@ -100,7 +153,31 @@ impl DbStore {
             .map_err(Into::into)
     }
 
-    /// Create vector indexes on both tables if they don't exist.
+    pub async fn citations_table(&self) -> Result<Table, DbError> {
+        self.db
+            .open_table("papers_citations")
+            .execute()
+            .await
+            .map_err(Into::into)
+    }
+
+    pub async fn reading_table(&self) -> Result<Table, DbError> {
+        self.db
+            .open_table("papers_reading")
+            .execute()
+            .await
+            .map_err(Into::into)
+    }
+
+    pub async fn abstracts_table(&self) -> Result<Table, DbError> {
+        self.db
+            .open_table("papers_abstracts")
+            .execute()
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Create vector indexes on the chunks and exhibits tables if they don't exist.
     /// Uses `Index::Auto` which selects IVF-PQ for vector columns.
     /// Logs and continues on failure (e.g. empty tables or < 256 rows).
     pub async fn ensure_indexes(&self) {
@@ -123,6 +200,232 @@ impl DbStore {
                 }
             }
         }
+
+        // Full-text index for keyword/hybrid search. Unlike the vector index it
+        // is only built when missing; ingest_paper rebuilds it after inserts.
+        if let Ok(table) = self.chunks_table().await
+            && !has_fts_index(&table).await
+            && let Err(e) = create_fts_index(&table).await
+        {
+            eprintln!("  full-text index skipped for papers_chunks: {e}");
+        }
+    }
+
+    /// Location the store was opened at.
+    pub fn path(&self) -> &str {
+        self.db.uri()
+    }
+
+    /// Delete every chunk, exhibit and citation of `paper_id`, and its reading
+    /// position. Deleting a paper that isn't indexed is not an error; the
+    /// counts are just zero.
+    pub async fn delete_paper(&self, paper_id: &str) -> Result<DeleteStats, DbError> {
+        let filter = format!("paper_id = '{}'", paper_id.replace('\'', "''"));
+        let chunks = self.chunks_table().await?;
+        let chunks_removed = chunks.count_rows(Some(filter.clone())).await?;
+        if chunks_removed > 0 {
+            chunks.delete(&filter).await?;
+        }
+        let exhibits = self.exhibits_table().await?;
+        let exhibits_removed = exhibits.count_rows(Some(filter.clone())).await?;
+        if exhibits_removed > 0 {
+            exhibits.delete(&filter).await?;
+        }
+        self.citations_table().await?.delete(&filter).await?;
+        self.reading_table().await?.delete(&filter).await?;
+        Ok(DeleteStats { paper_id: paper_id.to_string(), chunks_removed, exhibits_removed })
+    }
+
+    /// Rebuild a paper from its cached extraction: re-chunk, re-embed (the
+    /// embedding cache is bypassed) and replace its rows. Tags added at
+    /// ingest time are kept, as is the chunking it was ingested with.
+    pub async fn reindex_paper(&self, paper_id: &str) -> Result<IngestStats, DbError> {
+        let item_key = crate::ingest::cached_item_key_for(paper_id)
+            .ok_or_else(|| DbError::NotFound(format!("no cached extraction for {paper_id}")))?;
+        let mut params = crate::ingest::ingest_params_from_cache(&item_key)?;
+        let existing = crate::query::list_papers(
+            self,
+            ListPapersParams {
+                paper_ids: Some(vec![paper_id.to_string()]),
+                filter_year_min: None,
+                filter_year_max: None,
+                filter_venue: None,
+                filter_tags: None,
+                filter_authors: None,
+                sort_by: None,
+                limit: 1,
+            },
+        )
+        .await?;
+        if let Some(paper) = existing.into_iter().next() {
+            params.tags = paper.tags;
+            params.chunking = paper.chunking.parse().unwrap_or_default();
+        }
+        // The cached metadata may have gained a DOI since the paper was
+        // indexed under its item key; don't leave the old rows behind.
+        if params.paper_id != paper_id {
+            self.delete_paper(paper_id).await?;
+        }
+        params.force = true;
+        crate::ingest::ingest_paper(self, params).await
+    }
+
+    /// Paper, chunk, exhibit and abstract counts, size on disk and the
+    /// embedding model.
+    pub async fn stats(&self) -> Result<DbStats, DbError> {
+        let chunks_table = self.chunks_table().await?;
+        let chunks = chunks_table.count_rows(None).await?;
+        let exhibits = self.exhibits_table().await?.count_rows(None).await?;
+        let abstracts = self.abstracts_table().await?.count_rows(None).await?;
+        let batches = chunks_table
+            .query()
+            .select(Select::columns(&["paper_id"]))
+            .execute()
+            .await?
+            .try_collect::<Vec<_>>()
+            .await?;
+        let mut papers = HashSet::new();
+        for batch in &batches {
+            for row in 0..batch.num_rows() {
+                papers.insert(crate::query::col_str(batch, "paper_id", row)?);
+            }
+        }
+        let index_dim = vector_dim(&chunks_table.schema().await?).unwrap_or(EMBED_DIM);
+        Ok(DbStats {
+            path: self.path().to_string(),
+            papers: papers.len(),
+            chunks,
+            exhibits,
+            abstracts,
+            size_bytes: dir_size(Path::new(self.path())),
+            embed_model: crate::embed::MODEL_NAME.to_string(),
+            embed_dim: EMBED_DIM as usize,
+            index_dim: index_dim as usize,
+            needs_migration: index_dim != EMBED_DIM,
+        })
+    }
+
+    /// Re-embed every chunk, exhibit and abstract with the current embedding model, e.g.
+    /// after a model change altered the vector dimension.
+    ///
+    /// The migrated tables are written to a sibling `<path>.migrating` DB in
+    /// batches of [`MIGRATE_BATCH`], calling `on_progress` after each. Only
+    /// once all are complete is the old DB moved to `<path>.pre-migrate` and
+    /// the new one moved into place, so an interrupted migration leaves the
+    /// original untouched. Citations and reading positions have no vectors and
+    /// are copied as-is.
+    /// Returns the store reopened on the migrated DB.
+    pub async fn migrate(
+        self,
+        on_progress: impl FnMut(&MigrateProgress),
+    ) -> Result<(DbStore, MigrateStats), DbError> {
+        let embedder = self.embedder().await?;
+        self.migrate_with(embedder, on_progress).await
+    }
+
+    /// [`DbStore::migrate`] with an explicit embedder.
+    pub(crate) async fn migrate_with(
+        self,
+        embedder: Arc<Mutex<Embedder>>,
+        mut on_progress: impl FnMut(&MigrateProgress),
+    ) -> Result<(DbStore, MigrateStats), DbError> {
+        let path = self.path().to_string();
+        if !Path::new(&path).is_dir() {
+            return Err(DbError::Migrate(format!("{path} is not a local DB directory")));
+        }
+        let staging = format!("{path}.migrating");
+        let backup = format!("{path}.pre-migrate");
+        if Path::new(&backup).exists() {
+            return Err(DbError::Migrate(format!(
+                "{backup} is left from an earlier migration; remove it first"
+            )));
+        }
+        if Path::new(&staging).exists() {
+            std::fs::remove_dir_all(&staging)?;
+        }
+
+        let from_dim = vector_dim(&self.chunks_table().await?.schema().await?).unwrap_or(EMBED_DIM);
+        let target = lancedb::connect(&staging).execute().await?;
+        let (chunks, chunks_dim) = migrate_table(
+            &self.chunks_table().await?,
+            &target,
+            "chunks",
+            &embedder,
+            &mut on_progress,
+            |batch, row| {
+                Ok(crate::ingest::chunk_embedding_text(
+                    &crate::query::col_str(batch, "title", row)?,
+                    &crate::query::col_str(batch, "chapter_title", row)?,
+                    &crate::query::col_str(batch, "section_title", row)?,
+                    &crate::query::col_str(batch, "text", row)?,
+                ))
+            },
+        )
+        .await?;
+        let (exhibits, exhibits_dim) = migrate_table(
+            &self.exhibits_table().await?,
+            &target,
+            "exhibits",
+            &embedder,
+            &mut on_progress,
+            |batch, row| {
+                Ok(crate::ingest::exhibit_embedding_text(
+                    &crate::query::col_str(batch, "exhibit_type", row)?,
+                    &crate::query::col_str(batch, "caption", row)?,
+                    crate::query::col_str_opt(batch, "description", row)?.as_deref(),
+                    crate::query::col_str_opt(batch, "content", row)?.as_deref(),
+                    crate::query::col_str_opt(batch, "table_text", row)?.as_deref(),
+                ))
+            },
+        )
+        .await?;
+        let (abstracts, _) = migrate_table(
+            &self.abstracts_table().await?,
+            &target,
+            "abstracts",
+            &embedder,
+            &mut on_progress,
+            |batch, row| {
+                Ok(crate::ingest::abstract_embedding_text(
+                    &crate::query::col_str(batch, "title", row)?,
+                    &crate::query::col_str(batch, "text", row)?,
+                ))
+            },
+        )
+        .await?;
+        copy_table(&self.citations_table().await?, &target).await?;
+        copy_table(&self.reading_table().await?, &target).await?;
+        let to_dim = chunks_dim.or(exhibits_dim).unwrap_or(from_dim) as usize;
+        drop(target);
+
+        let migrated = Self {
+            db: lancedb::connect(&staging).execute().await?,
+            embedder: OnceCell::new(),
+            reranker: OnceCell::new(),
+        };
+        migrated.ensure_indexes().await;
+        drop(migrated);
+        drop(self);
+
+        std::fs::rename(&path, &backup)?;
+        if let Err(e) = std::fs::rename(&staging, &path) {
+            // Put the original back rather than leave no DB at `path`.
+            std::fs::rename(&backup, &path)?;
+            return Err(e.into());
+        }
+
+        let store = Self::open(&path).await?;
+        let _ = store.embedder.set(embedder);
+        let stats = MigrateStats {
+            path,
+            backup_path: backup,
+            chunks,
+            exhibits,
+            abstracts,
+            from_dim: from_dim as usize,
+            to_dim,
+        };
+        Ok((store, stats))
     }
 
     /// Eagerly initialize the embedding model so the first search call is fast.
@@ -146,6 +449,20 @@ impl DbStore {
         .map_err(|e| DbError::Embed(format!("spawn_blocking join error: {e}")))?
     }
 
+    /// Score `documents` against `query` with the cross-encoder, in input order.
+    pub async fn rerank(&self, query: &str, documents: Vec<String>) -> Result<Vec<f32>, DbError> {
+        let reranker = self.reranker().await?;
+        let query = query.to_string();
+        tokio::task::spawn_blocking(move || {
+            reranker
+                .lock()
+                .map_err(|e| DbError::Embed(format!("mutex poisoned: {e}")))?
+                .rerank(&query, &documents)
+        })
+        .await
+        .map_err(|e| DbError::Embed(format!("spawn_blocking join error: {e}")))?
+    }
+
     /// Embed document texts asynchronously.
     pub async fn embed_documents(&self, texts: Vec<String>) -> Result<Vec<Vec<f32>>, DbError> {
         let embedder = self.embedder().await?;
@@ -160,6 +477,115 @@ impl DbStore {
     }
 }
 
+/// Copy `source` into a table of the same name in `target`, replacing each
+/// row's vector with the embedding of `text(batch, row)`. Returns the row
+/// count and the new vector dimension (`None` for an empty table).
+async fn migrate_table(
+    source: &Table,
+    target: &Connection,
+    label: &'static str,
+    embedder: &Arc<Mutex<Embedder>>,
+    on_progress: &mut impl FnMut(&MigrateProgress),
+    text: impl Fn(&arrow_array::RecordBatch, usize) -> Result<String, DbError>,
+) -> Result<(usize, Option<i32>), DbError> {
+    let source_schema = source.schema().await?;
+    let batches = source.query().execute().await?.try_collect::<Vec<_>>().await?;
+    let total: usize = batches.iter().map(|b| b.num_rows()).sum();
+
+    let mut table: Option<Table> = None;
+    let mut dim = None;
+    let mut done = 0;
+    for batch in &batches {
+        let mut offset = 0;
+        while offset < batch.num_rows() {
+            let len = MIGRATE_BATCH.min(batch.num_rows() - offset);
+            let slice = batch.slice(offset, len);
+            let texts = (0..len).map(|row| text(&slice, row)).collect::<Result<Vec<_>, _>>()?;
+            let embedder = embedder.clone();
+            let embeddings = tokio::task::spawn_blocking(move || {
+                embedder
+                    .lock()
+                    .map_err(|e| DbError::Embed(format!("mutex poisoned: {e}")))?
+                    .embed_documents(&texts)
+            })
+            .await
+            .map_err(|e| DbError::Embed(format!("spawn_blocking join error: {e}")))??;
+            let vectors = crate::ingest::build_vector_array(&embeddings);
+            let schema = with_vector_dim(&source_schema, vectors.value_length());
+            dim = Some(vectors.value_length());
+            let vectors: arrow_array::ArrayRef = Arc::new(vectors);
+            let columns = schema
+                .fields()
+                .iter()
+                .map(|f| match f.name().as_str() {
+                    "vector" => Ok(vectors.clone()),
+                    name => slice.column_by_name(name).cloned().ok_or_else(|| {
+                        DbError::Arrow(format!("missing column '{name}'"))
+                    }),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let out = arrow_array::RecordBatch::try_new(schema.clone(), columns)
+                .map_err(|e| DbError::Arrow(e.to_string()))?;
+            let reader = RecordBatchIterator::new(vec![Ok(out)], schema);
+            match &table {
+                Some(t) => {
+                    t.add(Box::new(reader)).execute().await?;
+                }
+                None => {
+                    table = Some(target.create_table(source.name(), Box::new(reader)).execute().await?);
+                }
+            }
+            offset += len;
+            done += len;
+            on_progress(&MigrateProgress { table: label, done, total });
+        }
+    }
+    if table.is_none() {
+        // Empty source: keep an empty table with the source schema.
+        ensure_table(target, source.name(), source_schema).await?;
+    }
+    Ok((total, dim))
+}
+
+/// Copy `source` unchanged into a table of the same name in `target`.
+async fn copy_table(source: &Table, target: &Connection) -> Result<(), DbError> {
+    let schema = source.schema().await?;
+    let batches = source.query().execute().await?.try_collect::<Vec<_>>().await?;
+    let reader = RecordBatchIterator::new(batches.into_iter().map(Ok), schema);
+    target.create_table(source.name(), Box::new(reader)).execute().await?;
+    Ok(())
+}
+
+/// Total size of the files under `dir`; 0 if it can't be read.
+fn dir_size(dir: &Path) -> u64 {
+    let Ok(entries) = std::fs::read_dir(dir) else { return 0 };
+    entries
+        .flatten()
+        .map(|entry| match entry.file_type() {
+            Ok(t) if t.is_dir() => dir_size(&entry.path()),
+            Ok(_) => entry.metadata().map(|m| m.len()).unwrap_or(0),
+            Err(_) => 0,
+        })
+        .sum()
+}
+
+/// Create (or replace) the BM25 full-text index on chunk text.
+pub(crate) async fn create_fts_index(table: &Table) -> Result<(), DbError> {
+    table
+        .create_index(&["text"], Index::FTS(FtsIndexBuilder::default()))
+        .execute()
+        .await?;
+    Ok(())
+}
+
+async fn has_fts_index(table: &Table) -> bool {
+    table.list_indices().await.is_ok_and(|indices| {
+        indices
+            .iter()
+            .any(|idx| idx.columns.iter().any(|c| c == "text"))
+    })
+}
+
 /// Open a table if it exists, or create it with the given schema.
 async fn ensure_table(
     db: &Connection,
@@ -185,7 +611,7 @@ async fn ensure_table(
 const SCHEMA_VERSION_KEY: &str = "papers_schema_version";
 
 /// Current schema version for the chunks table.
-const CURRENT_CHUNKS_VERSION: u32 = 1;
+const CURRENT_CHUNKS_VERSION: u32 = 3;
 
 /// Versioned schema migrations for the chunks table.
 /// Each entry: (version, column_name, default_sql_expression).
@@ -193,6 +619,8 @@ const CURRENT_CHUNKS_VERSION: u32 = 1;
 /// on-disk version is below the entry's version number.
 const CHUNK_MIGRATIONS: &[(u32, &str, &str)] = &[
     (1, "block_type", "'text'"),
+    (2, "chunking", "'block'"),
+    (3, "equation_tex", "CAST(NULL AS string)"),
 ];
 
 /// Read the schema version stored in Arrow schema metadata, defaulting to 0.
@@ -262,11 +690,12 @@ async fn migrate_chunks_table(table: &Table) -> Result<(), DbError> {
 }
 
 /// Current schema version for the exhibits table.
-const CURRENT_EXHIBITS_VERSION: u32 = 1;
+const CURRENT_EXHIBITS_VERSION: u32 = 2;
 
 /// Versioned schema migrations for the exhibits table.
 const EXHIBIT_MIGRATIONS: &[(u32, &str, &str)] = &[
     (1, "content", "CAST(NULL AS string)"),
+    (2, "table_text", "CAST(NULL AS string)"),
 ];
 
 /// Apply pending schema migrations to the exhibits table.
@@ -319,12 +748,12 @@ mod tests {
     use arrow_schema::{DataType, Field};
     use serial_test::serial;
 
-    /// Build the v0 chunks schema (before block_type was added).
+    /// Build the v0 chunks schema (before any migrated column was added).
     fn chunks_schema_v0() -> Arc<Schema> {
         let fields: Vec<Field> = chunks_schema()
             .fields()
             .iter()
-            .filter(|f| f.name() != "block_type")
+            .filter(|f| !CHUNK_MIGRATIONS.iter().any(|&(_, col, _)| f.name() == col))
             .cloned()
             .map(|f| f.as_ref().clone())
             .collect();
@@ -544,12 +973,12 @@ mod tests {
 
     // ── Exhibits migration tests ──────────────────────────────────────────
 
-    /// Build the v0 exhibits schema (before content was added).
+    /// Build the v0 exhibits schema (before content and table_text were added).
     fn exhibits_schema_v0() -> Arc<Schema> {
         let fields: Vec<Field> = exhibits_schema()
             .fields()
             .iter()
-            .filter(|f| f.name() != "content")
+            .filter(|f| f.name() != "content" && f.name() != "table_text")
             .cloned()
             .map(|f| f.as_ref().clone())
             .collect();
@@ -584,6 +1013,10 @@ mod tests {
             schema.field_with_name("content").is_ok(),
             "content column should exist after migration"
         );
+        assert!(
+            schema.field_with_name("table_text").is_ok(),
+            "table_text column should exist after migration"
+        );
     }
 
     #[tokio::test]
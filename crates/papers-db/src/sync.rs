@@ -0,0 +1,83 @@
+//! Applies a Zotero sync plan (`papers_core::sync`) to the DB: extracts and
+//! indexes new items, removes deleted ones, and advances the checkpoint.
+
+use papers_core::sync::{SyncPlan, SyncState};
+use serde::Serialize;
+
+use crate::ingest::{ingest_paper, ingest_params_from_cache, is_ingested};
+use crate::query::remove_work;
+use crate::store::DbStore;
+
+/// Outcome of one applied poll.
+#[derive(Debug, Default, Serialize)]
+pub struct SyncReport {
+    /// Item keys indexed by this poll.
+    pub indexed: Vec<String>,
+    /// Item keys removed from the DB.
+    pub removed: Vec<String>,
+    /// Item keys that were already indexed (e.g. by `db work add`).
+    pub skipped: Vec<String>,
+    /// Item keys that failed, with the error; retried on the next poll.
+    pub failed: Vec<(String, String)>,
+}
+
+/// Apply `plan` to `store` and update `state` to match; the caller saves it.
+///
+/// `extract` fills the extraction cache for an item key (a no-op when it is
+/// already cached). Failures are recorded in the report and left in
+/// `state.pending` rather than aborting the poll.
+pub async fn apply_sync(
+    store: &DbStore,
+    plan: &SyncPlan,
+    state: &mut SyncState,
+    mut extract: impl AsyncFnMut(&str) -> Result<(), String>,
+) -> SyncReport {
+    let mut report = SyncReport::default();
+
+    for key in &plan.remove {
+        let tracked = state.indexed.remove(key);
+        // Papers indexed outside the watcher are found through their cached
+        // metadata, which outlives the Zotero item.
+        let paper_id = tracked
+            .or_else(|| ingest_params_from_cache(key).ok().map(|p| p.paper_id))
+            .unwrap_or_else(|| key.clone());
+        if !is_ingested(store, &paper_id).await {
+            continue;
+        }
+        match remove_work(store, &paper_id).await {
+            Ok(()) => report.removed.push(key.clone()),
+            Err(e) => eprintln!("  [sync] failed to remove {key}: {e}"),
+        }
+    }
+
+    for key in &plan.ingest {
+        if let Err(e) = extract(key).await {
+            report.failed.push((key.clone(), e));
+            continue;
+        }
+        let params = match ingest_params_from_cache(key) {
+            Ok(p) => p,
+            Err(e) => {
+                report.failed.push((key.clone(), e.to_string()));
+                continue;
+            }
+        };
+        let paper_id = params.paper_id.clone();
+        if is_ingested(store, &paper_id).await {
+            state.indexed.insert(key.clone(), paper_id);
+            report.skipped.push(key.clone());
+            continue;
+        }
+        match ingest_paper(store, params).await {
+            Ok(_) => {
+                state.indexed.insert(key.clone(), paper_id);
+                report.indexed.push(key.clone());
+            }
+            Err(e) => report.failed.push((key.clone(), e.to_string())),
+        }
+    }
+
+    state.pending = report.failed.iter().map(|(key, _)| key.clone()).collect();
+    state.library_version = plan.library_version;
+    report
+}
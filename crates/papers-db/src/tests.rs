@@ -7,12 +7,16 @@ use std::fs;
 use tempfile::TempDir;
 use serial_test::serial;
 
-use crate::ingest::{IngestParams, ingest_paper, ingest_params_from_cache, is_ingested, list_cached_item_keys};
+use crate::ingest::{IngestParams, index_abstracts, ingest_paper, ingest_params_from_cache, is_ingested, list_cached_item_keys};
 use crate::query::{
-    get_chapter, get_chunk, get_paper_outline, get_section, list_papers, list_tags,
+    get_chapter, get_chunk, get_paper_outline, get_section, inline_exhibits, list_papers, list_tags,
+    locate_passage, mark_read, reading_states, search_abstracts,
 };
 use crate::store::DbStore;
-use crate::types::{ListPapersParams, ListTagsParams};
+use crate::types::{
+    ChunkStrategy, ChunkingConfig, ListPapersParams, ListTagsParams, LocatePassageParams, PaperSort,
+    SearchAbstractsParams,
+};
 
 // ── Test isolation ────────────────────────────────────────────────────────────
 
@@ -173,6 +177,7 @@ fn make_test_cache(dir: &TempDir, item_key: &str) -> IngestParams {
         tags: vec!["rendering".to_string(), "GPU".to_string()],
         cache_dir,
         force: false,
+        chunking: ChunkingConfig::default(),
     }
 }
 
@@ -406,6 +411,93 @@ async fn test_list_papers_returns_ingested_paper() {
     assert_eq!(p.exhibit_count, 2);
 }
 
+fn paper_abstract(paper_id: &str, title: &str, year: u16) -> papers_core::abstracts::PaperAbstract {
+    papers_core::abstracts::PaperAbstract {
+        paper_id: paper_id.to_string(),
+        openalex_id: None,
+        doi: None,
+        title: title.to_string(),
+        authors: vec!["Ada".to_string()],
+        year: Some(year),
+        venue: None,
+        text: format!("Abstract of {title}."),
+    }
+}
+
+#[serial]
+#[tokio::test]
+async fn test_abstracts_tier_replaces_rows_and_flags_ingested() {
+    let _ecg = EmbedCacheGuard::new();
+    let cache_dir = TempDir::new().unwrap();
+    let db_dir = TempDir::new().unwrap();
+    let store = open_test_store(&db_dir).await;
+    ingest_paper(&store, make_test_cache(&cache_dir, "FULL")).await.unwrap();
+
+    let abstracts = vec![paper_abstract("FULL", "Full", 2020), paper_abstract("10.1/abs", "Only", 2010)];
+    assert_eq!(index_abstracts(&store, &abstracts).await.unwrap(), 2);
+    // Re-indexing a paper replaces its abstract.
+    index_abstracts(&store, &[paper_abstract("10.1/abs", "Only v2", 2010)]).await.unwrap();
+    assert_eq!(store.stats().await.unwrap().abstracts, 2);
+
+    let params = |year_min| SearchAbstractsParams {
+        query: "anything".to_string(),
+        paper_ids: None,
+        filter_year_min: year_min,
+        filter_year_max: None,
+        limit: 10,
+    };
+    let mut hits = search_abstracts(&store, params(None)).await.unwrap();
+    hits.sort_by(|a, b| a.paper_id.cmp(&b.paper_id));
+    let found: Vec<_> = hits.iter().map(|h| (h.paper_id.as_str(), h.title.as_str(), h.ingested)).collect();
+    assert_eq!(found, vec![("10.1/abs", "Only v2", false), ("FULL", "Full", true)]);
+
+    let hits = search_abstracts(&store, params(Some(2015))).await.unwrap();
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].paper_id, "FULL");
+}
+
+#[serial]
+#[tokio::test]
+async fn test_mark_read_tracks_last_and_furthest_chunk() {
+    let _ecg = EmbedCacheGuard::new();
+    let cache_dir = TempDir::new().unwrap();
+    let db_dir = TempDir::new().unwrap();
+    let store = open_test_store(&db_dir).await;
+    ingest_paper(&store, make_test_cache(&cache_dir, "READ")).await.unwrap();
+
+    let state = mark_read(&store, "READ/ch1/s1/p0").await.unwrap();
+    assert_eq!((state.chapter_idx, state.section_idx), (1, 1));
+    assert!((state.percent_complete - 200.0 / 3.0).abs() < 0.01);
+
+    // Going back to the first chunk moves the position but keeps the progress.
+    mark_read(&store, "READ/ch2/s0/p0").await.unwrap();
+    let state = mark_read(&store, "READ/ch1/s0/p0").await.unwrap();
+    assert_eq!(state.last_chunk_id, "READ/ch1/s0/p0");
+    assert_eq!(state.percent_complete, 100.0);
+
+    let papers = list_papers(
+        &store,
+        ListPapersParams {
+            paper_ids: None,
+            filter_year_min: None,
+            filter_year_max: None,
+            filter_venue: None,
+            filter_tags: None,
+            filter_authors: None,
+            sort_by: None,
+            limit: 50,
+        },
+    )
+    .await
+    .unwrap();
+    let reading = papers[0].reading.as_ref().expect("reading state");
+    assert_eq!(reading.last_chunk_id, "READ/ch1/s0/p0");
+
+    assert!(mark_read(&store, "READ/ch9/s0/p0").await.is_err());
+    store.delete_paper("READ").await.unwrap();
+    assert!(reading_states(&store, None).await.unwrap().is_empty());
+}
+
 #[serial]
 #[tokio::test]
 async fn test_list_papers_year_filter() {
@@ -612,6 +704,38 @@ async fn test_get_chunk_position_context() {
     assert_eq!(pos.total_chapters_in_paper, 2);
 }
 
+#[serial]
+#[tokio::test]
+async fn test_locate_passage_matches_text_then_page() {
+    let _ecg = EmbedCacheGuard::new();
+    let cache_dir = TempDir::new().unwrap();
+    let db_dir = TempDir::new().unwrap();
+    let store = open_test_store(&db_dir).await;
+    ingest_paper(&store, make_test_cache(&cache_dir, "LOCATE")).await.unwrap();
+    let params = |text: &str, page_index| LocatePassageParams {
+        paper_id: "LOCATE".into(),
+        text: text.into(),
+        page_index,
+        limit: 3,
+    };
+
+    // PDF selections carry stray case, punctuation and line breaks.
+    let found = locate_passage(&store, params("second INTRO\nparagraph", None)).await.unwrap();
+    assert_eq!(found[0].chunk_id, "LOCATE/ch1/s0/p0");
+    assert_eq!(found[0].score, 1.0);
+
+    // No text match: fall back to the chunks on the highlight's page.
+    let found = locate_passage(&store, params("", Some(1))).await.unwrap();
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].chunk_id, "LOCATE/ch1/s1/p0");
+    assert!(found[0].on_page);
+    assert_eq!(found[0].score, 0.0);
+
+    assert!(locate_passage(&store, LocatePassageParams { paper_id: "NOEXIST".into(), ..params("", None) })
+        .await
+        .is_err());
+}
+
 // ── get_section ───────────────────────────────────────────────────────────────
 
 #[serial]
@@ -765,6 +889,23 @@ async fn test_exhibit_ids_have_correct_format() {
     assert_eq!(tbl.caption, "Table 1: Results");
 }
 
+#[serial]
+#[tokio::test]
+async fn test_list_exhibits_in_document_order() {
+    let _ecg = EmbedCacheGuard::new();
+    use crate::query::list_exhibits;
+
+    let cache_dir = TempDir::new().unwrap();
+    let db_dir = TempDir::new().unwrap();
+    let store = open_test_store(&db_dir).await;
+    ingest_paper(&store, make_test_cache(&cache_dir, "FIGID")).await.unwrap();
+
+    let exhibits = list_exhibits(&store, "FIGID").await.unwrap();
+    let ids: Vec<&str> = exhibits.iter().map(|e| e.exhibit_id.as_str()).collect();
+    assert_eq!(ids, ["FIGID/fig1", "FIGID/fig2"]);
+    assert!(list_exhibits(&store, "NOPE").await.unwrap().is_empty());
+}
+
 // ── paper metadata roundtrip ──────────────────────────────────────────────────
 
 #[serial]
@@ -824,6 +965,7 @@ fn make_cache_from_json(dir: &TempDir, item_key: &str, json: &str) -> IngestPara
         tags: vec![],
         cache_dir,
         force: false,
+        chunking: ChunkingConfig::default(),
     }
 }
 
@@ -1412,6 +1554,8 @@ async fn test_search_result_has_paper_title() {
             filter_venue: None,
             filter_tags: None,
             filter_depth: None,
+            mode: crate::types::SearchMode::Semantic,
+            rerank: false,
             limit: 5,
         },
     )
@@ -1430,6 +1574,160 @@ async fn test_search_result_has_paper_title() {
     let _ = r.chunk.chunk_idx;
 }
 
+fn mode_search_params(query: &str, mode: crate::types::SearchMode) -> crate::types::SearchParams {
+    crate::types::SearchParams {
+        query: query.to_string(),
+        paper_ids: None,
+        chapter_idx: None,
+        section_idx: None,
+        filter_year_min: None,
+        filter_year_max: None,
+        filter_venue: None,
+        filter_tags: None,
+        filter_depth: None,
+        mode,
+        rerank: false,
+        limit: 5,
+    }
+}
+
+#[serial]
+#[tokio::test]
+async fn test_keyword_search_matches_exact_term() {
+    use crate::query::search;
+    use crate::types::SearchMode;
+
+    let _ecg = EmbedCacheGuard::new();
+    let cache_dir = TempDir::new().unwrap();
+    let db_dir = TempDir::new().unwrap();
+    let store = open_test_store(&db_dir).await;
+    ingest_paper(&store, make_test_cache(&cache_dir, "KW1")).await.unwrap();
+
+    let results = search(&store, mode_search_params("background", SearchMode::Keyword))
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 1, "only one chunk contains the term");
+    assert!(results[0].chunk.text.contains("Background text."));
+    assert!(results[0].score > 0.0, "BM25 score should be positive");
+}
+
+#[serial]
+#[tokio::test]
+async fn test_hybrid_search_ranks_keyword_hit_first() {
+    use crate::query::search;
+    use crate::types::SearchMode;
+
+    let _ecg = EmbedCacheGuard::new();
+    let cache_dir = TempDir::new().unwrap();
+    let db_dir = TempDir::new().unwrap();
+    let store = open_test_store(&db_dir).await;
+    ingest_paper(&store, make_test_cache(&cache_dir, "HY1")).await.unwrap();
+
+    // The fake embedder returns zero vectors, so the semantic ranking is
+    // arbitrary; the keyword hit must still win after fusion.
+    let results = search(&store, mode_search_params("background", SearchMode::Hybrid))
+        .await
+        .unwrap();
+    assert!(results.len() > 1, "semantic candidates should be fused in");
+    assert!(results[0].chunk.text.contains("Background text."));
+    assert!(results.windows(2).all(|w| w[0].score >= w[1].score));
+}
+
+#[serial]
+#[tokio::test]
+async fn test_rerank_reorders_candidates() {
+    use crate::query::search;
+    use crate::types::SearchMode;
+
+    let _ecg = EmbedCacheGuard::new();
+    let cache_dir = TempDir::new().unwrap();
+    let db_dir = TempDir::new().unwrap();
+    let store = open_test_store(&db_dir).await;
+    ingest_paper(&store, make_test_cache(&cache_dir, "RR1")).await.unwrap();
+
+    // Zero-vector embeddings give an arbitrary semantic order; the fake
+    // cross-encoder scores by query-term overlap, so the matching chunk wins.
+    let mut params = mode_search_params("background text", SearchMode::Semantic);
+    params.rerank = true;
+    params.limit = 2;
+    let results = search(&store, params).await.unwrap();
+    assert_eq!(results.len(), 2, "rerank truncates the pool to limit");
+    assert!(results[0].chunk.text.contains("Background text."));
+    assert_eq!(results[0].score, 1.0);
+    assert!(results[1].score < 1.0);
+}
+
+#[serial]
+#[tokio::test]
+async fn test_verify_claim_labels_retrieved_passages() {
+    use crate::query::verify_claim;
+    use crate::types::{SearchMode, Stance, VerifyParams};
+
+    let _ecg = EmbedCacheGuard::new();
+    let cache_dir = TempDir::new().unwrap();
+    let db_dir = TempDir::new().unwrap();
+    let store = open_test_store(&db_dir).await;
+    ingest_paper(&store, make_test_cache(&cache_dir, "VC1")).await.unwrap();
+
+    let params = |statement: &str| VerifyParams {
+        statement: statement.to_string(),
+        paper_ids: None,
+        mode: SearchMode::Keyword,
+        rerank: false,
+        limit: 5,
+    };
+    let background = |v: &crate::types::ClaimVerification| {
+        v.passages.iter().find(|p| p.evidence == "Background text.").cloned().unwrap()
+    };
+    let verified = verify_claim(&store, params("Background text")).await.unwrap();
+    assert_eq!(background(&verified).stance, Stance::Supporting);
+    assert_eq!(verified.supporting, 1);
+    assert_eq!(verified.supporting + verified.contradicting + verified.neutral, verified.passages.len());
+    // "Method description text." shares only one of the two claim terms.
+    assert!(verified.passages.iter().all(|p| p.stance != Stance::Contradicting));
+
+    let verified = verify_claim(&store, params("No background text")).await.unwrap();
+    let passage = background(&verified);
+    assert_eq!(passage.stance, Stance::Contradicting);
+    assert_eq!(passage.cues, ["negation differs from the claim"]);
+}
+
+#[serial]
+#[tokio::test]
+async fn test_ground_draft_maps_paragraphs_to_papers() {
+    use crate::query::ground_draft;
+    use crate::types::{GroundDraftParams, SearchMode};
+
+    let _ecg = EmbedCacheGuard::new();
+    let cache_dir = TempDir::new().unwrap();
+    let db_dir = TempDir::new().unwrap();
+    let store = open_test_store(&db_dir).await;
+    ingest_paper(&store, make_test_cache(&cache_dir, "GD1")).await.unwrap();
+    ingest_paper(&store, make_test_cache(&cache_dir, "GD2")).await.unwrap();
+
+    let draft = "# Related work\n\n\
+        Prior work gives the background text and a method description for this setting.\n\n\
+        Earlier systems reuse the same background text in their method description [@gd1].\n";
+    let params = |limit| GroundDraftParams {
+        markdown: draft.to_string(),
+        paper_ids: None,
+        mode: SearchMode::Keyword,
+        rerank: false,
+        limit,
+    };
+    let grounding = ground_draft(&store, params(2)).await.unwrap();
+    assert_eq!(grounding.skipped, 1);
+    assert_eq!(grounding.paragraphs.iter().map(|p| (p.line, p.cited)).collect::<Vec<_>>(), [(3, false), (5, true)]);
+    assert_eq!(grounding.uncited, 1);
+    let mut papers: Vec<_> = grounding.paragraphs[0].candidates.iter().map(|c| c.paper_id.as_str()).collect();
+    papers.sort();
+    assert_eq!(papers, ["GD1", "GD2"]);
+
+    let grounding = ground_draft(&store, params(1)).await.unwrap();
+    assert_eq!(grounding.paragraphs[0].candidates.len(), 1);
+    assert!(grounding.paragraphs[0].candidates[0].passages >= 1);
+}
+
 // ── sentence-aware preview in neighbors ──────────────────────────────────
 
 #[serial]
@@ -1469,6 +1767,7 @@ async fn test_neighbor_preview_sentence_truncation() {
         tags: vec![],
         cache_dir: item_dir,
         force: false,
+        chunking: ChunkingConfig::default(),
     };
     ingest_paper(&store, params).await.unwrap();
 
@@ -1513,6 +1812,7 @@ async fn test_equation_merged_with_surrounding_text() {
         tags: vec![],
         cache_dir: item_dir,
         force: false,
+        chunking: ChunkingConfig::default(),
     };
     ingest_paper(&store, params).await.unwrap();
 
@@ -1554,6 +1854,7 @@ async fn test_equation_merged_preserves_math() {
         tags: vec![],
         cache_dir: item_dir,
         force: false,
+        chunking: ChunkingConfig::default(),
     };
     ingest_paper(&store, params).await.unwrap();
 
@@ -1613,6 +1914,7 @@ async fn test_table_block_stores_content() {
         tags: vec![],
         cache_dir: item_dir,
         force: false,
+        chunking: ChunkingConfig::default(),
     };
     ingest_paper(&store, params).await.unwrap();
 
@@ -1682,6 +1984,7 @@ async fn test_search_exhibits_returns_content() {
         tags: vec![],
         cache_dir: item_dir,
         force: false,
+        chunking: ChunkingConfig::default(),
     };
     ingest_paper(&store, params).await.unwrap();
 
@@ -1703,6 +2006,154 @@ async fn test_search_exhibits_returns_content() {
     assert!(r.content.as_ref().unwrap().contains("NeoHookean"));
 }
 
+#[serial]
+#[tokio::test]
+async fn test_table_cells_are_searchable() {
+    use crate::query::{search, search_exhibits};
+    use crate::types::{ExhibitType, SearchExhibitsParams, SearchMode};
+    use futures::TryStreamExt;
+    use lancedb::query::ExecutableQuery;
+
+    let _ecg = EmbedCacheGuard::new();
+    let cache_dir = TempDir::new().unwrap();
+    let db_dir = TempDir::new().unwrap();
+    let store = open_test_store(&db_dir).await;
+
+    let json = serde_json::json!({
+        "children": [{
+            "block_type": "Page",
+            "children": [
+                {
+                    "block_type": "SectionHeader", "id": "h1",
+                    "html": "<h2>Results</h2>", "page": 1
+                },
+                {
+                    "block_type": "Text", "id": "t0",
+                    "html": "<p>Table 1 compares the solvers.</p>", "page": 1
+                },
+                {
+                    "block_type": "Caption", "id": "cap1",
+                    "html": "<p><b>Table 1.</b> Solver comparison.</p>", "page": 1
+                },
+                {
+                    "block_type": "Table", "id": "tbl1",
+                    "html": "<table><thead><tr><th>Solver</th><th>Time</th></tr></thead><tbody><tr><td>NeoHookean</td><td>12.5ms</td></tr></tbody></table>",
+                    "page": 1
+                }
+            ]
+        }]
+    });
+    let item_dir = cache_dir.path().join("TBLTEXT");
+    fs::create_dir_all(&item_dir).unwrap();
+    fs::write(item_dir.join("TBLTEXT.json"), serde_json::to_vec_pretty(&json).unwrap()).unwrap();
+    let params = crate::ingest::IngestParams {
+        item_key: "TBLTEXT".to_string(),
+        paper_id: "TBLTEXT".to_string(),
+        title: "Test".to_string(),
+        authors: vec![],
+        year: None,
+        venue: None,
+        tags: vec![],
+        cache_dir: item_dir,
+        force: false,
+        chunking: ChunkingConfig::default(),
+    };
+    ingest_paper(&store, params).await.unwrap();
+
+    let results = search(&store, mode_search_params("NeoHookean", SearchMode::Keyword))
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 1, "only the table chunk holds the cell text");
+    let chunk = &results[0].chunk;
+    assert_eq!(chunk.block_type, "table");
+    assert!(chunk.text.contains("Solver: NeoHookean; Time: 12.5ms"), "{}", chunk.text);
+    assert_eq!(chunk.exhibit_ids, ["TBLTEXT/fig1"]);
+
+    let exhibits = search_exhibits(
+        &store,
+        SearchExhibitsParams {
+            query: "NeoHookean timing".to_string(),
+            paper_ids: Some(vec!["TBLTEXT".to_string()]),
+            filter_exhibit_type: Some(ExhibitType::Table),
+            limit: 5,
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(exhibits[0].exhibit_id, "TBLTEXT/fig1");
+
+    let batches: Vec<arrow_array::RecordBatch> = store
+        .exhibits_table()
+        .await
+        .unwrap()
+        .query()
+        .execute()
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(
+        crate::query::col_str_opt(&batches[0], "table_text", 0).unwrap().as_deref(),
+        Some("Solver; Time\nSolver: NeoHookean; Time: 12.5ms")
+    );
+}
+
+#[serial]
+#[tokio::test]
+async fn test_equations_are_kept_as_latex_and_searchable() {
+    use crate::query::{get_chunk, search_equations};
+    use crate::types::SearchEquationsParams;
+
+    let _ecg = EmbedCacheGuard::new();
+    let cache_dir = TempDir::new().unwrap();
+    let db_dir = TempDir::new().unwrap();
+    let store = open_test_store(&db_dir).await;
+
+    let json = serde_json::json!({
+        "children": [{
+            "block_type": "Page",
+            "children": [
+                { "block_type": "SectionHeader", "id": "h1", "html": "<h2>Method</h2>", "page": 1 },
+                { "block_type": "Text", "id": "t0", "html": "<p>Positions are advanced with the update rule</p>", "page": 1 },
+                { "block_type": "Equation", "id": "eq1", "html": "<p><math display=\"block\">x_{n+1} = x_n + h v_n</math></p>", "page": 1 },
+                { "block_type": "SectionHeader", "id": "h2", "html": "<h2>Results</h2>", "page": 2 },
+                { "block_type": "Text", "id": "t1", "html": "<p>The solver converges quickly on all scenes.</p>", "page": 2 }
+            ]
+        }]
+    });
+    let item_dir = cache_dir.path().join("EQTEX");
+    fs::create_dir_all(&item_dir).unwrap();
+    fs::write(item_dir.join("EQTEX.json"), serde_json::to_vec_pretty(&json).unwrap()).unwrap();
+    let params = crate::ingest::IngestParams {
+        item_key: "EQTEX".to_string(),
+        paper_id: "EQTEX".to_string(),
+        title: "Test".to_string(),
+        authors: vec![],
+        year: None,
+        venue: None,
+        tags: vec![],
+        cache_dir: item_dir,
+        force: false,
+        chunking: ChunkingConfig::default(),
+    };
+    ingest_paper(&store, params).await.unwrap();
+
+    let results = search_equations(
+        &store,
+        SearchEquationsParams { query: "update rule".to_string(), paper_ids: None, limit: 5 },
+    )
+    .await
+    .unwrap();
+    assert_eq!(results.len(), 1, "only the Method chunk holds an equation");
+    assert_eq!(results[0].equations, ["x_{n+1} = x_n + h v_n"]);
+    assert_eq!(results[0].section_title, "Method");
+
+    let chunk = get_chunk(&store, &results[0].chunk_id).await.unwrap().chunk;
+    assert_eq!(chunk.equation_tex, results[0].equations);
+    assert!(chunk.text.contains("$x_{n+1} = x_n + h v_n$"), "{}", chunk.text);
+}
+
 // ── figure search result with score ──────────────────────────────────────
 
 #[serial]
@@ -2046,6 +2497,7 @@ fn make_params_from_json_str(dir: &TempDir, key: &str, json: &str) -> IngestPara
         tags: vec![],
         cache_dir,
         force: false,
+        chunking: ChunkingConfig::default(),
     }
 }
 
@@ -2697,6 +3149,48 @@ async fn test_crosslink_figure_ref() {
     );
 }
 
+#[serial]
+#[tokio::test]
+async fn inline_exhibits_attaches_records_to_first_referencing_chunk() {
+    let _ecg = EmbedCacheGuard::new();
+    let cache_dir = TempDir::new().unwrap();
+    let db_dir = TempDir::new().unwrap();
+    let store = open_test_store(&db_dir).await;
+
+    let blocks = vec![
+        make_block("SectionHeader", "h1", "<h2>Results</h2>", 0),
+        make_block("Text", "t0", "<p>As shown in Fig. 1, the method works.</p>", 0),
+        make_block("Figure", "fig1", "<img src=\"f.png\" alt=\"Figure 1: Results\"/>", 0),
+        make_block("Caption", "cap1", "<p>Figure 1: Performance results.</p>", 0),
+        make_block("SectionHeader", "h2", "<h3>Discussion</h3>", 0),
+        make_block("Text", "t1", "<p>Fig. 1 also shows the limits.</p>", 0),
+    ];
+    let json = make_json_from_blocks(blocks);
+    let params = make_params_from_json_str(&cache_dir, "INLINE", &json);
+    ingest_paper(&store, params).await.unwrap();
+
+    let mut chapter = get_chapter(&store, "INLINE", 1).await.unwrap();
+    assert!(chapter.sections.iter().flat_map(|s| &s.chunks).all(|c| c.exhibits.is_empty()));
+    inline_exhibits(&store, chapter.sections.iter_mut().flat_map(|s| s.chunks.iter_mut()))
+        .await
+        .unwrap();
+    let inlined: Vec<Vec<&str>> = chapter
+        .sections
+        .iter()
+        .flat_map(|s| &s.chunks)
+        .map(|c| c.exhibits.iter().map(|e| e.exhibit_id.as_str()).collect())
+        .collect();
+    assert_eq!(inlined.concat(), ["INLINE/fig1"], "inlined once: {inlined:?}");
+    assert_eq!(inlined[0], ["INLINE/fig1"]);
+
+    // Within just the second section, its own chunk is the first reference.
+    let last = chapter.sections.last().unwrap();
+    let mut section = get_section(&store, "INLINE", 1, last.section_idx).await.unwrap();
+    inline_exhibits(&store, section.chunks.iter_mut()).await.unwrap();
+    let exhibit = &section.chunks[0].exhibits[0];
+    assert_eq!(exhibit.caption, "Figure 1: Performance results.");
+}
+
 // ── Table cross-linking ─────────────────────────────────────────────────
 
 #[serial]
@@ -3122,3 +3616,650 @@ async fn selection_db_remove_tolerates_not_ingested() {
     let result = crate::query::remove_work(&store, "10.9999/NEVER_INGESTED").await;
     assert!(result.is_ok(), "remove_work on non-existent paper should succeed");
 }
+
+// ── Synthetic corpus fixtures ─────────────────────────────────────────────────
+
+#[test]
+fn synthetic_paper_is_deterministic() {
+    let spec = crate::fixtures::CorpusSpec::default();
+    let root = std::path::Path::new("/corpus");
+    let (a, json_a) = crate::fixtures::synthetic_paper(&spec, 7, root);
+    let (b, json_b) = crate::fixtures::synthetic_paper(&spec, 7, root);
+    assert_eq!(json_a, json_b);
+    assert_eq!((a.item_key.as_str(), a.title.as_str()), ("SYN00007", b.title.as_str()));
+    let (c, _) = crate::fixtures::synthetic_paper(&spec, 8, root);
+    assert_ne!(a.title, c.title);
+}
+
+#[test]
+fn synthetic_paper_follows_spec_counts() {
+    let spec = crate::fixtures::CorpusSpec {
+        chapters: 4,
+        figures: 5,
+        equations: 2,
+        ..Default::default()
+    };
+    let (_, marker) = crate::fixtures::synthetic_paper(&spec, 0, std::path::Path::new("/corpus"));
+    let blocks = marker["children"][0]["children"].as_array().unwrap();
+    let count = |t: &str| blocks.iter().filter(|b| b["block_type"] == t).count();
+    assert_eq!(count("Figure") + count("Table"), 5);
+    assert_eq!(count("Caption"), 5);
+    assert_eq!(count("Equation"), 8);
+    assert!(blocks.iter().filter(|b| b["block_type"] == "Equation").all(|b| {
+        b["html"].as_str().unwrap().contains("<math display=\"block\">")
+    }));
+}
+
+#[serial]
+#[tokio::test]
+async fn synthetic_corpus_loads_and_ingests() {
+    let _ecg = EmbedCacheGuard::new();
+    let cache_root = TempDir::new().unwrap();
+    let db_dir = TempDir::new().unwrap();
+    let store = open_test_store(&db_dir).await;
+    let spec = crate::fixtures::CorpusSpec {
+        papers: 3,
+        chapters: 3,
+        sections: 1,
+        paragraphs: 2,
+        figures: 1,
+        ..Default::default()
+    };
+
+    let papers = crate::fixtures::load_corpus(&store, cache_root.path(), &spec).await.unwrap();
+    assert_eq!(papers.len(), 3);
+    assert!(cache_root.path().join("SYN00002/meta.json").exists());
+
+    let listed = list_papers(
+        &store,
+        ListPapersParams {
+            paper_ids: None,
+            filter_year_min: None,
+            filter_year_max: None,
+            filter_venue: None,
+            filter_tags: None,
+            filter_authors: None,
+            sort_by: None,
+            limit: 50,
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(listed.len(), 3);
+
+    let outline = get_paper_outline(&store, "SYN00001").await.unwrap();
+    let titles: Vec<_> = outline.chapters.iter().map(|c| c.chapter_title.as_str()).collect();
+    assert_eq!(titles, ["Introduction", "Related Work", "Background"]);
+    assert!(outline.chapters.iter().all(|c| c.sections.len() == 2));
+
+    // The bulk loader and ingest_paper agree on what a paper contains.
+    let bulk_chunks = listed.iter().find(|p| p.paper_id == "SYN00001").unwrap().chunk_count;
+    let stats = ingest_paper(&store, papers[1].clone()).await.unwrap();
+    assert_eq!(stats.chunks_added, bulk_chunks);
+    assert_eq!(stats.exhibits_added, 1);
+}
+
+// ── Zotero sync ──────────────────────────────────────────────────────────────
+
+#[serial]
+#[tokio::test]
+async fn apply_sync_indexes_removes_and_keeps_failures_pending() {
+    use papers_core::sync::{SyncPlan, SyncState};
+
+    let _ecg = EmbedCacheGuard::new();
+    let cache_root = TempDir::new().unwrap();
+    let db_dir = TempDir::new().unwrap();
+    unsafe { std::env::set_var("PAPERS_DATALAB_CACHE_DIR", cache_root.path()) };
+    make_full_cache(cache_root.path(), "SYNCNEW1");
+    make_full_cache(cache_root.path(), "SYNCGONE");
+    let store = open_test_store(&db_dir).await;
+    ingest_paper(&store, ingest_params_from_cache("SYNCGONE").unwrap()).await.unwrap();
+
+    let plan = SyncPlan {
+        ingest: vec!["SYNCNEW1".to_string(), "SYNCFAIL".to_string()],
+        remove: vec!["SYNCGONE".to_string()],
+        library_version: 7,
+    };
+    let mut state = SyncState::default();
+    let mut extracted = Vec::new();
+    let report = crate::sync::apply_sync(&store, &plan, &mut state, async |key: &str| {
+        extracted.push(key.to_string());
+        if key == "SYNCFAIL" { Err("no PDF attachment".to_string()) } else { Ok(()) }
+    })
+    .await;
+
+    assert_eq!(extracted, ["SYNCNEW1", "SYNCFAIL"]);
+    assert_eq!(report.indexed, ["SYNCNEW1"]);
+    assert_eq!(report.removed, ["SYNCGONE"]);
+    assert_eq!(report.failed, [("SYNCFAIL".to_string(), "no PDF attachment".to_string())]);
+    assert!(is_ingested(&store, "10.9999/SYNCNEW1").await);
+    assert!(!is_ingested(&store, "10.9999/SYNCGONE").await);
+    assert_eq!(state.library_version, 7);
+    assert_eq!(state.pending, ["SYNCFAIL"]);
+    assert_eq!(state.indexed.get("SYNCNEW1").map(String::as_str), Some("10.9999/SYNCNEW1"));
+
+    unsafe { std::env::remove_var("PAPERS_DATALAB_CACHE_DIR") };
+}
+
+// ── Store management ─────────────────────────────────────────────────────────
+
+#[serial]
+#[tokio::test]
+async fn delete_paper_reports_removed_rows_and_stats_follow() {
+    let _ecg = EmbedCacheGuard::new();
+    let cache_root = TempDir::new().unwrap();
+    let db_dir = TempDir::new().unwrap();
+    unsafe { std::env::set_var("PAPERS_DATALAB_CACHE_DIR", cache_root.path()) };
+    make_full_cache(cache_root.path(), "MGMT0001");
+    make_full_cache(cache_root.path(), "MGMT0002");
+    let store = open_test_store(&db_dir).await;
+    let first = ingest_paper(&store, ingest_params_from_cache("MGMT0001").unwrap()).await.unwrap();
+    ingest_paper(&store, ingest_params_from_cache("MGMT0002").unwrap()).await.unwrap();
+
+    let stats = store.stats().await.unwrap();
+    assert_eq!(stats.papers, 2);
+    assert_eq!(stats.chunks, 2 * first.chunks_added);
+    assert!(stats.size_bytes > 0);
+    assert_eq!(stats.embed_dim, 768);
+
+    let removed = store.delete_paper("10.9999/MGMT0001").await.unwrap();
+    assert_eq!(removed.chunks_removed, first.chunks_added);
+    assert_eq!(removed.exhibits_removed, first.exhibits_added);
+    assert!(!is_ingested(&store, "10.9999/MGMT0001").await);
+    assert_eq!(store.stats().await.unwrap().papers, 1);
+
+    let again = store.delete_paper("10.9999/MGMT0001").await.unwrap();
+    assert_eq!((again.chunks_removed, again.exhibits_removed), (0, 0));
+
+    unsafe { std::env::remove_var("PAPERS_DATALAB_CACHE_DIR") };
+}
+
+#[serial]
+#[tokio::test]
+async fn chunking_strategy_is_recorded_per_paper() {
+    let _ecg = EmbedCacheGuard::new();
+    let cache_root = TempDir::new().unwrap();
+    let db_dir = TempDir::new().unwrap();
+    unsafe { std::env::set_var("PAPERS_DATALAB_CACHE_DIR", cache_root.path()) };
+    make_full_cache(cache_root.path(), "CHUNK001");
+    make_full_cache(cache_root.path(), "CHUNK002");
+    let store = open_test_store(&db_dir).await;
+    ingest_paper(&store, ingest_params_from_cache("CHUNK001").unwrap()).await.unwrap();
+    let mut params = ingest_params_from_cache("CHUNK002").unwrap();
+    params.chunking = ChunkingConfig {
+        strategy: "sentence-window".parse().unwrap(),
+        max_tokens: 300,
+        ..Default::default()
+    };
+    ingest_paper(&store, params).await.unwrap();
+
+    let papers = list_papers(
+        &store,
+        ListPapersParams {
+            paper_ids: None,
+            filter_year_min: None,
+            filter_year_max: None,
+            filter_venue: None,
+            filter_tags: None,
+            filter_authors: None,
+            sort_by: Some(PaperSort::Title),
+            limit: 10,
+        },
+    )
+    .await
+    .unwrap();
+    let labels: Vec<&str> = papers.iter().map(|p| p.chunking.as_str()).collect();
+    assert_eq!(
+        labels,
+        ["block(target=400,max=600,overlap=2)", "sentence-window(target=400,max=300,overlap=2)"]
+    );
+    let recorded: ChunkingConfig = labels[1].parse().unwrap();
+    assert_eq!((recorded.strategy, recorded.max_tokens), (ChunkStrategy::SentenceWindow, 300));
+    assert_eq!("block".parse::<ChunkingConfig>().unwrap(), ChunkingConfig::default());
+    assert!("paragraph".parse::<ChunkingConfig>().is_err());
+    assert!("block(max=big)".parse::<ChunkingConfig>().is_err());
+
+    // Reindexing keeps the recorded chunking.
+    store.reindex_paper("10.9999/CHUNK002").await.unwrap();
+    let papers = list_papers(
+        &store,
+        ListPapersParams {
+            paper_ids: Some(vec!["10.9999/CHUNK002".to_string()]),
+            filter_year_min: None,
+            filter_year_max: None,
+            filter_venue: None,
+            filter_tags: None,
+            filter_authors: None,
+            sort_by: None,
+            limit: 1,
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(papers[0].chunking, labels[1]);
+
+    unsafe { std::env::remove_var("PAPERS_DATALAB_CACHE_DIR") };
+}
+
+#[serial]
+#[tokio::test]
+async fn reindex_paper_rebuilds_from_cache_and_keeps_tags() {
+    let _ecg = EmbedCacheGuard::new();
+    let cache_root = TempDir::new().unwrap();
+    let db_dir = TempDir::new().unwrap();
+    unsafe { std::env::set_var("PAPERS_DATALAB_CACHE_DIR", cache_root.path()) };
+    make_full_cache(cache_root.path(), "MGMT0003");
+    let store = open_test_store(&db_dir).await;
+    let mut params = ingest_params_from_cache("MGMT0003").unwrap();
+    params.tags = vec!["baseline".to_string()];
+    let first = ingest_paper(&store, params).await.unwrap();
+
+    let stats = store.reindex_paper("10.9999/MGMT0003").await.unwrap();
+    assert_eq!(stats.chunks_added, first.chunks_added);
+    let papers = list_papers(
+        &store,
+        ListPapersParams {
+            paper_ids: None,
+            filter_year_min: None,
+            filter_year_max: None,
+            filter_venue: None,
+            filter_tags: None,
+            filter_authors: None,
+            sort_by: None,
+            limit: 10,
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(papers.len(), 1);
+    assert_eq!(papers[0].chunk_count, first.chunks_added);
+    assert_eq!(papers[0].tags, ["baseline"]);
+
+    assert!(matches!(
+        store.reindex_paper("10.9999/NOTCACHED").await,
+        Err(crate::error::DbError::NotFound(_))
+    ));
+
+    unsafe { std::env::remove_var("PAPERS_DATALAB_CACHE_DIR") };
+}
+
+#[serial]
+#[tokio::test]
+async fn migrate_re_embeds_into_new_dimension_and_keeps_backup() {
+    use crate::embed::Embedder;
+    use std::sync::{Arc, Mutex};
+    let fake = |dim| Arc::new(Mutex::new(Embedder::fake_with_dim(dim)));
+    let _ecg = EmbedCacheGuard::new();
+    let cache_root = TempDir::new().unwrap();
+    let db_dir = TempDir::new().unwrap();
+    unsafe { std::env::set_var("PAPERS_DATALAB_CACHE_DIR", cache_root.path()) };
+    make_full_cache(cache_root.path(), "MGMT0004");
+    let store = open_test_store(&db_dir).await;
+    let first = ingest_paper(&store, ingest_params_from_cache("MGMT0004").unwrap()).await.unwrap();
+    assert!(!store.stats().await.unwrap().needs_migration);
+
+    let mut progress = Vec::new();
+    let (store, migrated) = store
+        .migrate_with(fake(384), |p| progress.push((p.table, p.done, p.total)))
+        .await
+        .unwrap();
+    assert_eq!((migrated.chunks, migrated.exhibits), (first.chunks_added, first.exhibits_added));
+    assert_eq!((migrated.from_dim, migrated.to_dim), (768, 384));
+    assert!(std::path::Path::new(&migrated.backup_path).is_dir());
+    assert!(progress.contains(&("chunks", first.chunks_added, first.chunks_added)));
+    assert!(progress.iter().all(|&(_, done, total)| done <= total));
+
+    let stats = store.stats().await.unwrap();
+    assert_eq!((stats.chunks, stats.exhibits), (first.chunks_added, first.exhibits_added));
+    assert_eq!(stats.index_dim, 384);
+    assert!(stats.needs_migration);
+
+    // A leftover backup blocks another migration until it is removed.
+    let store = match store.migrate_with(fake(768), |_| {}).await {
+        Err(crate::error::DbError::Migrate(_)) => DbStore::open(&migrated.path).await.unwrap(),
+        other => panic!("expected a migration error, got {:?}", other.map(|(_, s)| s)),
+    };
+    std::fs::remove_dir_all(&migrated.backup_path).unwrap();
+    let (store, _) = store.migrate_with(fake(768), |_| {}).await.unwrap();
+    assert!(!store.stats().await.unwrap().needs_migration);
+
+    unsafe { std::env::remove_var("PAPERS_DATALAB_CACHE_DIR") };
+}
+
+// ── Outline addressing by title ──────────────────────────────────────────────
+
+fn outline_fixture() -> crate::types::PaperOutline {
+    use crate::types::{OutlineChapter, OutlineSection, PaperOutline};
+    let section = |section_idx, title: &str| OutlineSection {
+        section_idx,
+        section_title: title.to_string(),
+        chunk_count: 1,
+        summary: None,
+    };
+    let chapter = |chapter_idx, title: &str, sections| OutlineChapter {
+        chapter_idx,
+        chapter_title: title.to_string(),
+        sections,
+        exhibit_count: 0,
+    };
+    PaperOutline {
+        paper_id: "10.1/vbd".to_string(),
+        title: "Vertex Block Descent".to_string(),
+        authors: vec![],
+        year: None,
+        venue: None,
+        tags: vec![],
+        chapters: vec![
+            chapter(1, "1 Introduction", vec![section(0, "1 Introduction")]),
+            chapter(2, "3 Vertex Block Descent", vec![
+                section(1, "3.1 Global Optimization"),
+                section(2, "3.2 Local System Solver"),
+                section(3, "3.3 Damping"),
+            ]),
+            chapter(3, "5 Results", vec![section(1, "5.1 Large-Scale Tests"), section(2, "5.2 Damping Tests")]),
+        ],
+        total_chunks: 6,
+        total_exhibits: 0,
+    }
+}
+
+#[test]
+fn outline_titles_resolve_to_indices() {
+    use crate::query::{match_chapter, match_section};
+    let outline = outline_fixture();
+
+    assert_eq!(match_section(&outline, None, "3.2 Local System Solver").unwrap(), (2, 2));
+    assert_eq!(match_section(&outline, None, "local system solver").unwrap(), (2, 2));
+    assert_eq!(match_section(&outline, None, "3.2").unwrap(), (2, 2));
+    assert_eq!(match_section(&outline, None, "Local Solver").unwrap(), (2, 2));
+    // Chapter scope narrows an otherwise ambiguous title.
+    assert_eq!(match_section(&outline, None, "Damping").unwrap(), (2, 3));
+    assert_eq!(match_section(&outline, Some(3), "Damping").unwrap(), (3, 2));
+
+    assert_eq!(match_chapter(&outline, "Results").unwrap(), 3);
+    assert_eq!(match_chapter(&outline, "vertex block descent").unwrap(), 2);
+
+    let err = match_section(&outline, None, "Conclusion").unwrap_err().to_string();
+    assert!(err.contains("2.2: 3.2 Local System Solver"), "{err}");
+}
+
+// ── Similar works ─────────────────────────────────────────────────────────────
+
+/// Insert one chunk per vector for `paper_id`, bypassing extraction and the embedder.
+async fn insert_vectors(store: &DbStore, paper_id: &str, vectors: &[Vec<f32>]) {
+    use crate::ingest::{ChunkRecord, build_chunks_batch};
+    use crate::schema::chunks_schema;
+    use arrow_array::RecordBatchIterator;
+
+    let mut params = make_test_cache(&TempDir::new().unwrap(), paper_id);
+    params.title = format!("Paper {paper_id}");
+    let records: Vec<ChunkRecord> = (0..vectors.len())
+        .map(|i| ChunkRecord {
+            chunk_id: format!("{paper_id}/ch1/s1/p{i}"),
+            chapter_title: "Intro".to_string(),
+            chapter_idx: 1,
+            section_title: "Intro".to_string(),
+            section_idx: 1,
+            chunk_idx: i as u16,
+            block_type: "text".to_string(),
+            text: format!("chunk {i}"),
+            page_start: None,
+            page_end: None,
+            exhibit_ids: vec![],
+            equation_tex: vec![],
+        })
+        .collect();
+    let batch = build_chunks_batch(&params, &records, vectors).unwrap();
+    let reader = RecordBatchIterator::new(vec![Ok(batch)], chunks_schema());
+    store.chunks_table().await.unwrap().add(Box::new(reader)).execute().await.unwrap();
+}
+
+#[tokio::test]
+async fn similar_works_ranks_by_centroid_cosine() {
+    use crate::query::similar_works;
+    use crate::types::SimilarWorksParams;
+    let axis = |weights: &[(usize, f32)]| {
+        let mut v = vec![0.0f32; 768];
+        for &(i, w) in weights {
+            v[i] = w;
+        }
+        v
+    };
+    let db_dir = TempDir::new().unwrap();
+    let store = open_test_store(&db_dir).await;
+    insert_vectors(&store, "TARGET", &[axis(&[(0, 1.0)]), axis(&[(1, 1.0)])]).await;
+    insert_vectors(&store, "NEAR", &[axis(&[(0, 1.0), (1, 0.9)])]).await;
+    insert_vectors(&store, "MIDDLE", &[axis(&[(0, 1.0)]), axis(&[(2, 1.0)])]).await;
+    insert_vectors(&store, "FAR", &[axis(&[(3, 1.0)])]).await;
+
+    let params = |paper_ids: Option<Vec<String>>, limit| SimilarWorksParams {
+        paper_id: "TARGET".to_string(),
+        paper_ids,
+        limit,
+    };
+    let ranked = similar_works(&store, params(None, 10)).await.unwrap();
+    let ids: Vec<&str> = ranked.iter().map(|w| w.paper_id.as_str()).collect();
+    assert_eq!(ids, ["NEAR", "MIDDLE", "FAR"]);
+    assert!((ranked[1].score - 0.5).abs() < 1e-5, "{}", ranked[1].score);
+    assert_eq!(ranked[2].score, 0.0);
+    assert_eq!(ranked[1].chunk_count, 2);
+    assert_eq!(ranked[0].title, "Paper NEAR");
+
+    let scoped = similar_works(&store, params(Some(vec!["FAR".into(), "MIDDLE".into()]), 1))
+        .await
+        .unwrap();
+    assert_eq!(scoped.len(), 1);
+    assert_eq!(scoped[0].paper_id, "MIDDLE");
+
+    let missing = SimilarWorksParams { paper_id: "NOPE".to_string(), paper_ids: None, limit: 5 };
+    assert!(matches!(similar_works(&store, missing).await, Err(crate::error::DbError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn cluster_works_groups_papers_by_centroid() {
+    use crate::query::cluster_works;
+    use crate::types::ClusterWorksParams;
+    let axis = |weights: &[(usize, f32)]| {
+        let mut v = vec![0.0f32; 768];
+        for &(i, w) in weights {
+            v[i] = w;
+        }
+        v
+    };
+    let db_dir = TempDir::new().unwrap();
+    let store = open_test_store(&db_dir).await;
+    insert_vectors(&store, "A1", &[axis(&[(0, 1.0)])]).await;
+    insert_vectors(&store, "A2", &[axis(&[(0, 1.0), (1, 0.1)])]).await;
+    insert_vectors(&store, "A3", &[axis(&[(0, 1.0)]), axis(&[(0, 1.0), (2, 0.2)])]).await;
+    insert_vectors(&store, "B1", &[axis(&[(5, 1.0)])]).await;
+    insert_vectors(&store, "B2", &[axis(&[(5, 1.0), (6, 0.1)])]).await;
+
+    let auto = cluster_works(&store, ClusterWorksParams { paper_ids: None, k: None }).await.unwrap();
+    assert_eq!(auto.k, 2);
+    assert!(auto.silhouette > 0.8, "{}", auto.silhouette);
+    let members: Vec<Vec<&str>> = auto
+        .clusters
+        .iter()
+        .map(|c| {
+            let mut ids: Vec<&str> = c.papers.iter().map(|p| p.paper_id.as_str()).collect();
+            ids.sort();
+            ids
+        })
+        .collect();
+    assert_eq!(members, [vec!["A1", "A2", "A3"], vec!["B1", "B2"]]);
+    assert_eq!(auto.clusters[1].cluster, 1);
+    // Titles share no terms, so the label falls back to the most central title.
+    assert_eq!(auto.clusters[0].label, auto.clusters[0].papers[0].title);
+
+    let scoped = ClusterWorksParams { paper_ids: Some(vec!["A1".into(), "B1".into()]), k: Some(1) };
+    let single = cluster_works(&store, scoped).await.unwrap();
+    assert_eq!(single.k, 1);
+    assert_eq!(single.clusters[0].papers.len(), 2);
+
+    let zero = ClusterWorksParams { paper_ids: None, k: Some(0) };
+    assert!(matches!(cluster_works(&store, zero).await, Err(crate::error::DbError::Scope(_))));
+}
+
+// ── Citations ─────────────────────────────────────────────────────────────────
+
+/// Write a reflow.json with one body paragraph and a references section.
+fn make_reflow_cache(dir: &TempDir, paper_id: &str, title: &str, body: &str, references: &str) -> IngestParams {
+    use papers_extract::types::{ReflowDocument, ReflowNode};
+    let mut params = make_test_cache(dir, paper_id.replace('/', "_").as_str());
+    params.paper_id = paper_id.to_string();
+    params.title = title.to_string();
+    let doc = ReflowDocument {
+        title: None,
+        toc: vec![],
+        children: vec![
+            ReflowNode::Heading {
+                depth: 1,
+                text: "Introduction".into(),
+                section: None,
+                children: vec![ReflowNode::Text { content: body.into(), footnotes: vec![] }],
+            },
+            ReflowNode::References { content: references.into() },
+        ],
+    };
+    fs::write(params.cache_dir.join("reflow.json"), serde_json::to_vec(&doc).unwrap()).unwrap();
+    params
+}
+
+#[serial]
+#[tokio::test]
+async fn citation_contexts_match_by_doi_and_title() {
+    use crate::query::citation_contexts;
+    use crate::types::CitationContextsParams;
+    let _ecg = EmbedCacheGuard::new();
+    let cache_dir = TempDir::new().unwrap();
+    let db_dir = TempDir::new().unwrap();
+    let store = open_test_store(&db_dir).await;
+
+    let citing = make_reflow_cache(
+        &cache_dir,
+        "10.1/citing",
+        "A Citing Paper",
+        "Implicit integration is standard [1]. We extend projective dynamics [2, 3].",
+        "[1] A. Baraff and B. Witkin. Large steps in cloth simulation. 1998. doi:10.1145/280814.280821.\n\
+         [2] S. Bouaziz et al. Projective dynamics: fusing constraint projections\nfor fast simulation. 2014.\n\
+         [3] J. Doe. Unrelated work. 2001.",
+    );
+    ingest_paper(&store, citing).await.unwrap();
+    let cited = make_reflow_cache(
+        &cache_dir,
+        "10.1/pd",
+        "Projective Dynamics: Fusing Constraint Projections for Fast Simulation",
+        "We present projective dynamics.",
+        "",
+    );
+    ingest_paper(&store, cited).await.unwrap();
+
+    let params = |doi: &str| CitationContextsParams { doi: doi.to_string(), title: None, paper_ids: None, limit: 10 };
+    let by_doi = citation_contexts(&store, params("https://doi.org/10.1145/280814.280821")).await.unwrap();
+    assert_eq!(by_doi.len(), 1);
+    assert_eq!(by_doi[0].paper_id, "10.1/citing");
+    assert_eq!(by_doi[0].sentence, "Implicit integration is standard [1].");
+    assert_eq!(by_doi[0].matched_by, "doi");
+
+    // No DOI in the entry: matched through the cited paper's indexed title.
+    let by_title = citation_contexts(&store, params("10.1/pd")).await.unwrap();
+    assert_eq!(by_title.len(), 1);
+    assert_eq!(by_title[0].marker, "[2, 3]");
+    assert_eq!(by_title[0].matched_by, "title");
+
+    let scoped = CitationContextsParams { paper_ids: Some(vec!["10.1/pd".into()]), ..params("10.1/pd") };
+    assert!(citation_contexts(&store, scoped).await.unwrap().is_empty());
+
+    store.delete_paper("10.1/citing").await.unwrap();
+    assert!(citation_contexts(&store, params("10.1145/280814.280821")).await.unwrap().is_empty());
+}
+
+#[serial]
+#[tokio::test]
+async fn work_timeline_buckets_years_and_counts_local_citations() {
+    use crate::query::work_timeline;
+    use crate::types::WorkTimelineParams;
+    let _ecg = EmbedCacheGuard::new();
+    let cache_dir = TempDir::new().unwrap();
+    let db_dir = TempDir::new().unwrap();
+    let store = open_test_store(&db_dir).await;
+
+    let mut cited = make_reflow_cache(&cache_dir, "10.1234/pd", "Projective Dynamics", "We present it.", "");
+    cited.year = Some(2014);
+    ingest_paper(&store, cited).await.unwrap();
+    let mut other = make_reflow_cache(&cache_dir, "10.1234/other", "Other Work", "Unrelated.", "");
+    other.year = Some(2014);
+    ingest_paper(&store, other).await.unwrap();
+    let citing = make_reflow_cache(
+        &cache_dir,
+        "10.1234/citing",
+        "A Citing Paper",
+        "We extend projective dynamics [1].",
+        "[1] S. Bouaziz et al. Projective dynamics. 2014. doi:10.1234/PD.",
+    );
+    ingest_paper(&store, citing).await.unwrap();
+
+    let params = |paper_ids: Option<Vec<String>>| WorkTimelineParams { paper_ids, milestones_per_year: 1 };
+    let timeline = work_timeline(&store, params(None)).await.unwrap();
+    assert_eq!(timeline.total, 3);
+    let years: Vec<_> = timeline.years.iter().map(|y| (y.year, y.count)).collect();
+    assert_eq!(years, vec![(2014, 2), (2023, 1)]);
+    let first = &timeline.years[0].papers[0];
+    assert_eq!(first.id, "10.1234/pd");
+    assert_eq!(first.cited_by_count, Some(1));
+    assert!(first.milestone);
+    assert!(!timeline.years[0].papers[1].milestone);
+    assert!(!timeline.years[1].papers[0].milestone);
+
+    let scoped = work_timeline(&store, params(Some(vec!["10.1234/citing".into()]))).await.unwrap();
+    assert_eq!(scoped.total, 1);
+    assert_eq!(scoped.first_year, Some(2023));
+}
+
+#[serial]
+#[tokio::test]
+async fn citation_graph_links_indexed_papers_by_doi() {
+    use crate::query::citation_graph;
+    use papers_core::graph::GraphEdge;
+    let _ecg = EmbedCacheGuard::new();
+    let cache_dir = TempDir::new().unwrap();
+    let db_dir = TempDir::new().unwrap();
+    let store = open_test_store(&db_dir).await;
+
+    let cited = make_reflow_cache(&cache_dir, "10.1234/pd", "Projective Dynamics", "We present it.", "");
+    ingest_paper(&store, cited).await.unwrap();
+    let citing = make_reflow_cache(
+        &cache_dir,
+        "10.1234/citing",
+        "A Citing Paper",
+        "We extend projective dynamics [1] and unrelated work [2].",
+        "[1] S. Bouaziz et al. Projective dynamics. 2014. doi:10.1234/PD.\n\
+         [2] J. Doe. Elsewhere. 2001. doi:10.9999/other.",
+    );
+    ingest_paper(&store, citing).await.unwrap();
+
+    let graph = citation_graph(&store, None).await.unwrap();
+    assert_eq!(graph.nodes.len(), 2);
+    assert_eq!(
+        graph.edges,
+        vec![GraphEdge { source: "10.1234/citing".into(), target: "10.1234/pd".into() }]
+    );
+    assert_eq!(graph.nodes[1].doi.as_deref(), Some("10.1234/pd"));
+
+    let scoped = citation_graph(&store, Some(vec!["10.1234/citing".into()])).await.unwrap();
+    assert_eq!(scoped.nodes.len(), 1);
+    assert!(scoped.edges.is_empty());
+}
+
+#[test]
+fn filter_enums_parse_case_insensitively() {
+    use crate::types::{ChunkDepth, ExhibitType};
+    assert_eq!(" Paragraph ".parse::<ChunkDepth>().unwrap(), ChunkDepth::Paragraph);
+    assert_eq!("TABLE".parse::<ExhibitType>().unwrap(), ExhibitType::Table);
+    assert_eq!("Title".parse::<PaperSort>().unwrap(), PaperSort::Title);
+    assert_eq!(PaperSort::default(), PaperSort::Year);
+    let err = "tabel".parse::<ExhibitType>().unwrap_err();
+    assert!(err.contains("'tabel'") && err.contains("figure, table, or algorithm"), "{err}");
+    assert!("paragraphs".parse::<ChunkDepth>().is_err());
+    assert!("date".parse::<PaperSort>().is_err());
+}
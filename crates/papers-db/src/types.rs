@@ -40,8 +40,15 @@ pub struct ChunkWithPosition {
     pub depth: String,
     pub block_type: String,
     pub exhibit_ids: Vec<String>,
+    /// LaTeX of the display equations in this chunk.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub equation_tex: Vec<String>,
     pub referenced_exhibits: Vec<ReferencedExhibit>,
     pub position: PositionContext,
+    /// Full records of the exhibits first referenced by this chunk within the
+    /// returned section or chapter; only filled by `inline_exhibits`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exhibits: Vec<ExhibitResult>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -55,6 +62,8 @@ pub struct SearchChunkResult {
     pub section_title: String,
     pub chunk_idx: u16,
     pub exhibit_ids: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub equation_tex: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -62,6 +71,10 @@ pub struct SearchResult {
     pub chunk: SearchChunkResult,
     pub prev: Option<ChunkSummary>,
     pub next: Option<ChunkSummary>,
+    /// Meaning depends on [`SearchMode`]: vector distance for `semantic`
+    /// (lower is better), BM25 score for `keyword` and reciprocal rank fusion
+    /// score for `hybrid` (higher is better). With `rerank`, the cross-encoder
+    /// score (higher is better) regardless of mode.
     pub score: f32,
 }
 
@@ -167,6 +180,28 @@ pub struct PaperSummary {
     pub tags: Vec<String>,
     pub chunk_count: usize,
     pub exhibit_count: usize,
+    /// [`ChunkingConfig::label`] the paper was ingested with; `block` for
+    /// papers ingested before strategies were recorded.
+    pub chunking: String,
+    /// Where reading left off, once any chunk has been marked read.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reading: Option<ReadingState>,
+}
+
+/// Reading position in a paper, recorded by `mark_read`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadingState {
+    pub paper_id: String,
+    /// The chunk marked read most recently: where to continue.
+    pub last_chunk_id: String,
+    pub chapter_idx: u16,
+    pub section_idx: u16,
+    pub chunk_idx: u16,
+    /// Share of the paper's chunks up to the furthest one read, from 0 to 100.
+    /// Going back to reread a chunk doesn't lower it.
+    pub percent_complete: f32,
+    /// Unix time (seconds) of the last mark.
+    pub updated_at: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -175,6 +210,225 @@ pub struct TagSummary {
     pub paper_count: usize,
 }
 
+/// How `search` ranks chunks.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchMode {
+    /// Embedding similarity only.
+    #[default]
+    Semantic,
+    /// BM25 over the full-text index on chunk text.
+    Keyword,
+    /// Semantic and keyword rankings fused with reciprocal rank fusion.
+    Hybrid,
+}
+
+impl std::str::FromStr for SearchMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "semantic" => Ok(SearchMode::Semantic),
+            "keyword" => Ok(SearchMode::Keyword),
+            "hybrid" => Ok(SearchMode::Hybrid),
+            other => Err(format!(
+                "invalid search mode '{other}' (expected semantic, keyword, or hybrid)"
+            )),
+        }
+    }
+}
+
+/// Chunk granularity for the `filter_depth` search filter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChunkDepth {
+    Chapter,
+    Section,
+    Paragraph,
+}
+
+impl ChunkDepth {
+    /// Value stored in the `depth` column.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ChunkDepth::Chapter => "chapter",
+            ChunkDepth::Section => "section",
+            ChunkDepth::Paragraph => "paragraph",
+        }
+    }
+}
+
+impl std::str::FromStr for ChunkDepth {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "chapter" => Ok(ChunkDepth::Chapter),
+            "section" => Ok(ChunkDepth::Section),
+            "paragraph" => Ok(ChunkDepth::Paragraph),
+            other => Err(format!(
+                "invalid depth '{other}' (expected chapter, section, or paragraph)"
+            )),
+        }
+    }
+}
+
+/// Kind of exhibit, as stored in the `exhibit_type` column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExhibitType {
+    Figure,
+    Table,
+    Algorithm,
+}
+
+impl ExhibitType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ExhibitType::Figure => "figure",
+            ExhibitType::Table => "table",
+            ExhibitType::Algorithm => "algorithm",
+        }
+    }
+}
+
+impl std::str::FromStr for ExhibitType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "figure" => Ok(ExhibitType::Figure),
+            "table" => Ok(ExhibitType::Table),
+            "algorithm" => Ok(ExhibitType::Algorithm),
+            other => Err(format!(
+                "invalid exhibit type '{other}' (expected figure, table, or algorithm)"
+            )),
+        }
+    }
+}
+
+/// Sort order for `list_papers`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PaperSort {
+    /// Newest first.
+    #[default]
+    Year,
+    /// Alphabetical by title.
+    Title,
+}
+
+impl std::str::FromStr for PaperSort {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "year" => Ok(PaperSort::Year),
+            "title" => Ok(PaperSort::Title),
+            other => Err(format!("invalid sort '{other}' (expected year or title)")),
+        }
+    }
+}
+
+/// How `ingest_paper` cuts body text into chunks.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ChunkStrategy {
+    /// Whole blocks packed up to the target size; a block longer than the
+    /// maximum stays one oversized chunk.
+    #[default]
+    Block,
+    /// As `Block`, but a block longer than the maximum is cut at sentence
+    /// boundaries into windows that overlap by a few sentences.
+    SentenceWindow,
+}
+
+impl std::str::FromStr for ChunkStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "block" => Ok(ChunkStrategy::Block),
+            "sentence-window" | "sentence" => Ok(ChunkStrategy::SentenceWindow),
+            other => Err(format!(
+                "invalid chunking strategy '{other}' (expected block or sentence-window)"
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for ChunkStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ChunkStrategy::Block => "block",
+            ChunkStrategy::SentenceWindow => "sentence-window",
+        })
+    }
+}
+
+/// Chunking settings for one ingest; sizes are estimated tokens. Defaults to
+/// the constants in [`crate::config`]. Chunk IDs keep the
+/// `{paper_id}/ch{c}/s{s}/p{n}` form under every strategy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChunkingConfig {
+    pub strategy: ChunkStrategy,
+    /// The buffer flushes when the next paragraph would pass this size.
+    pub target_tokens: usize,
+    /// Upper bound for merges; with `SentenceWindow`, longer blocks are split.
+    pub max_tokens: usize,
+    /// Trailing sentences repeated at the start of the next chunk.
+    pub overlap_sentences: usize,
+}
+
+impl Default for ChunkingConfig {
+    fn default() -> Self {
+        Self {
+            strategy: ChunkStrategy::default(),
+            target_tokens: crate::config::TARGET_CHUNK_TOKENS,
+            max_tokens: crate::config::MAX_CHUNK_TOKENS,
+            overlap_sentences: crate::config::OVERLAP_SENTENCES,
+        }
+    }
+}
+
+impl ChunkingConfig {
+    /// Recorded in each chunk's `chunking` column, e.g.
+    /// `sentence-window(target=400,max=600,overlap=2)`.
+    pub fn label(&self) -> String {
+        format!(
+            "{}(target={},max={},overlap={})",
+            self.strategy, self.target_tokens, self.max_tokens, self.overlap_sentences
+        )
+    }
+}
+
+/// Parses a [`ChunkingConfig::label`]. Settings may be omitted, so
+/// `sentence-window` or `sentence-window(max=300)` work too; omitted ones
+/// take the defaults.
+impl std::str::FromStr for ChunkingConfig {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid chunking '{s}' (expected e.g. sentence-window(max=600,overlap=2))");
+        let (name, settings) = match s.trim().split_once('(') {
+            Some((name, rest)) => (name, rest.strip_suffix(')').ok_or_else(invalid)?),
+            None => (s.trim(), ""),
+        };
+        let mut config = ChunkingConfig { strategy: name.parse()?, ..Default::default() };
+        for setting in settings.split(',').filter(|setting| !setting.trim().is_empty()) {
+            let (key, value) = setting.split_once('=').ok_or_else(invalid)?;
+            let value: usize = value.trim().parse().map_err(|_| invalid())?;
+            match key.trim() {
+                "target" => config.target_tokens = value,
+                "max" => config.max_tokens = value,
+                "overlap" => config.overlap_sentences = value,
+                other => return Err(format!("unknown chunking setting '{other}' (expected target, max or overlap)")),
+            }
+        }
+        Ok(config)
+    }
+}
+
 /// Input parameters for search queries.
 pub struct SearchParams {
     pub query: String,
@@ -185,7 +439,10 @@ pub struct SearchParams {
     pub filter_year_max: Option<u16>,
     pub filter_venue: Option<String>,
     pub filter_tags: Option<Vec<String>>,
-    pub filter_depth: Option<String>,
+    pub filter_depth: Option<ChunkDepth>,
+    pub mode: SearchMode,
+    /// Re-score a larger candidate pool with the cross-encoder before truncating to `limit`.
+    pub rerank: bool,
     pub limit: u16,
 }
 
@@ -193,10 +450,61 @@ pub struct SearchParams {
 pub struct SearchExhibitsParams {
     pub query: String,
     pub paper_ids: Option<Vec<String>>,
-    pub filter_exhibit_type: Option<String>,
+    pub filter_exhibit_type: Option<ExhibitType>,
     pub limit: u16,
 }
 
+/// Input parameters for equation search.
+pub struct SearchEquationsParams {
+    pub query: String,
+    pub paper_ids: Option<Vec<String>>,
+    pub limit: u16,
+}
+
+/// A chunk holding display equations, matched by `search_equations`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EquationSearchResult {
+    pub chunk_id: String,
+    pub paper_id: String,
+    pub paper_title: String,
+    pub chapter_title: String,
+    pub section_title: String,
+    /// LaTeX of the chunk's display equations, in reading order.
+    pub equations: Vec<String>,
+    /// The surrounding prose, which is what the query is matched against.
+    pub text: String,
+    /// Vector distance (lower is better).
+    pub score: f32,
+}
+
+/// Input parameters for `citation_contexts`.
+pub struct CitationContextsParams {
+    /// DOI of the cited paper (a `https://doi.org/` prefix is ignored).
+    pub doi: String,
+    /// Also match reference entries without a DOI by this title. Defaults to
+    /// the cited paper's title when it is indexed itself.
+    pub title: Option<String>,
+    /// Only search citing papers in this set.
+    pub paper_ids: Option<Vec<String>>,
+    pub limit: u16,
+}
+
+/// A sentence in an indexed paper that cites the requested work.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CitationContext {
+    pub paper_id: String,
+    pub paper_title: String,
+    pub year: Option<u16>,
+    pub chunk_id: String,
+    pub sentence: String,
+    /// The citation marker as written, e.g. `[12]` or `Smith et al. (2020)`.
+    pub marker: String,
+    /// The reference-list entry the marker resolved to.
+    pub ref_text: String,
+    /// `"doi"` or `"title"`: how the entry was matched to the cited work.
+    pub matched_by: String,
+}
+
 /// Input parameters for list_papers.
 pub struct ListPapersParams {
     pub paper_ids: Option<Vec<String>>,
@@ -205,7 +513,7 @@ pub struct ListPapersParams {
     pub filter_venue: Option<String>,
     pub filter_tags: Option<Vec<String>>,
     pub filter_authors: Option<Vec<String>>,
-    pub sort_by: Option<String>,
+    pub sort_by: Option<PaperSort>,
     pub limit: u16,
 }
 
@@ -219,6 +527,57 @@ pub struct IngestStats {
     pub exhibits_added: usize,
 }
 
+/// Rows removed by `DbStore::delete_paper`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteStats {
+    pub paper_id: String,
+    pub chunks_removed: usize,
+    pub exhibits_removed: usize,
+}
+
+/// Store-wide counts and footprint from `DbStore::stats`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DbStats {
+    pub path: String,
+    pub papers: usize,
+    pub chunks: usize,
+    pub exhibits: usize,
+    /// Rows in the abstracts-only tier.
+    #[serde(default)]
+    pub abstracts: usize,
+    /// Bytes on disk under `path` (0 for remote stores).
+    pub size_bytes: u64,
+    pub embed_model: String,
+    pub embed_dim: usize,
+    /// Dimension of the stored vectors; differs from `embed_dim` after a model
+    /// change, until `DbStore::migrate` re-embeds the DB.
+    pub index_dim: usize,
+    pub needs_migration: bool,
+}
+
+/// Progress of `DbStore::migrate`, reported after each embedded batch.
+#[derive(Debug, Clone, Serialize)]
+pub struct MigrateProgress {
+    /// `"chunks"` or `"exhibits"`.
+    pub table: &'static str,
+    pub done: usize,
+    pub total: usize,
+}
+
+/// Outcome of `DbStore::migrate`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MigrateStats {
+    pub path: String,
+    /// Where the pre-migration DB was moved; delete it once satisfied.
+    pub backup_path: String,
+    pub chunks: usize,
+    pub exhibits: usize,
+    #[serde(default)]
+    pub abstracts: usize,
+    pub from_dim: usize,
+    pub to_dim: usize,
+}
+
 /// Input parameters for work-level semantic search.
 pub struct SearchWorksParams {
     pub query: String,
@@ -306,6 +665,103 @@ pub struct WorkSearchResult {
     pub top_chunk: String,
 }
 
+/// Input parameters for `search_abstracts`.
+pub struct SearchAbstractsParams {
+    pub query: String,
+    pub paper_ids: Option<Vec<String>>,
+    pub filter_year_min: Option<u16>,
+    pub filter_year_max: Option<u16>,
+    pub limit: u16,
+}
+
+/// One hit from the abstracts-only tier.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AbstractSearchResult {
+    pub paper_id: String,
+    pub openalex_id: Option<String>,
+    pub doi: Option<String>,
+    pub title: String,
+    pub authors: Vec<String>,
+    pub year: Option<u16>,
+    pub venue: Option<String>,
+    pub score: f32,
+    pub text: String,
+    /// Whether the paper's full text is also indexed (has chunks), so
+    /// chunk-level tools can be used on it.
+    pub ingested: bool,
+}
+
+/// Input parameters for `similar_works`.
+pub struct SimilarWorksParams {
+    pub paper_id: String,
+    /// Only rank these papers (e.g. a selection); `None` ranks the whole DB.
+    pub paper_ids: Option<Vec<String>>,
+    pub limit: u16,
+}
+
+/// An indexed paper ranked by `similar_works`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimilarWork {
+    pub paper_id: String,
+    pub title: String,
+    pub authors: Vec<String>,
+    pub year: Option<u16>,
+    pub venue: Option<String>,
+    /// Cosine similarity of the two papers' mean chunk vectors (higher is
+    /// more similar).
+    pub score: f32,
+    pub chunk_count: usize,
+}
+
+/// Input parameters for `cluster_works`.
+pub struct ClusterWorksParams {
+    /// Only cluster these papers (e.g. a selection); `None` clusters the whole DB.
+    pub paper_ids: Option<Vec<String>>,
+    /// Number of clusters; `None` picks the count with the best silhouette.
+    pub k: Option<u16>,
+}
+
+/// Papers grouped into themes by `cluster_works`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkClustering {
+    pub k: usize,
+    /// Mean silhouette coefficient in [-1, 1]; higher means better separated.
+    pub silhouette: f32,
+    /// Largest cluster first.
+    pub clusters: Vec<WorkCluster>,
+}
+
+/// One theme found by `cluster_works`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkCluster {
+    pub cluster: usize,
+    /// The top terms joined with ", ", or the most central title when no
+    /// term is shared by its papers.
+    pub label: String,
+    /// Title terms that distinguish this cluster from the rest of the corpus.
+    pub terms: Vec<String>,
+    /// Most central paper first.
+    pub papers: Vec<ClusterMember>,
+}
+
+/// A paper in a `WorkCluster`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClusterMember {
+    pub paper_id: String,
+    pub title: String,
+    pub year: Option<u16>,
+    /// Cosine similarity of the paper's centroid to the cluster's.
+    pub score: f32,
+}
+
+/// Input parameters for `work_timeline`.
+pub struct WorkTimelineParams {
+    /// Only place these papers (e.g. a selection); `None` uses the whole DB.
+    pub paper_ids: Option<Vec<String>>,
+    /// Most-cited papers flagged as milestones per year (0 disables).
+    pub milestones_per_year: u16,
+}
+
 /// One row in a chunk list.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChunkListItem {
@@ -320,6 +776,39 @@ pub struct ChunkListItem {
     pub text_preview: String,
 }
 
+/// Input parameters for finding the chunks that hold a highlighted passage,
+/// e.g. a Zotero annotation.
+pub struct LocatePassageParams {
+    pub paper_id: String,
+    /// The highlighted text; may be empty for annotations without text.
+    pub text: String,
+    /// 0-based PDF page index of the highlight, used to break ties and as a
+    /// fallback when the text matches nothing.
+    pub page_index: Option<u16>,
+    pub limit: u16,
+}
+
+/// A chunk holding all or part of a highlighted passage.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PassageMatch {
+    pub chunk_id: String,
+    pub chapter_idx: u16,
+    pub chapter_title: String,
+    pub section_idx: u16,
+    pub section_title: String,
+    pub chunk_idx: u16,
+    pub page_start: Option<u16>,
+    pub page_end: Option<u16>,
+    /// Share of the passage's word pairs found in the chunk, from 0 to 1.
+    /// Chunks found only by page score 0.
+    pub score: f32,
+    /// Whether the chunk spans the highlight's page.
+    pub on_page: bool,
+    pub text: String,
+    pub prev: Option<ChunkSummary>,
+    pub next: Option<ChunkSummary>,
+}
+
 /// One entry in a section-level search result list.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SectionSearchResult {
@@ -365,3 +854,104 @@ pub struct ChapterListItem {
     pub section_count: usize,
     pub chunk_count: usize,
 }
+
+/// Input parameters for `verify_claim`.
+pub struct VerifyParams {
+    /// The claim to check, e.g. a sentence from a draft.
+    pub statement: String,
+    /// Only search these papers (e.g. a selection); `None` searches the whole DB.
+    pub paper_ids: Option<Vec<String>>,
+    pub mode: SearchMode,
+    /// Pick passages with the cross-encoder (see [`SearchParams::rerank`]).
+    pub rerank: bool,
+    pub limit: u16,
+}
+
+/// How a passage relates to a claim, from a lexical heuristic (overlap of
+/// content terms plus negation, direction and contrast cues).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Stance {
+    Supporting,
+    Contradicting,
+    Neutral,
+}
+
+/// A retrieved passage labelled against the claim.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerifiedPassage {
+    pub stance: Stance,
+    /// Share of the claim's content terms found in `evidence` (0–1).
+    pub overlap: f32,
+    /// The sentence of the passage the stance is based on.
+    pub evidence: String,
+    /// Why the passage got its stance, e.g. "negation differs from the claim".
+    pub cues: Vec<String>,
+    #[serde(flatten)]
+    pub result: SearchResult,
+}
+
+/// Passages most similar to a claim, each labelled supporting, contradicting
+/// or neutral.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClaimVerification {
+    pub statement: String,
+    pub supporting: usize,
+    pub contradicting: usize,
+    pub neutral: usize,
+    /// In retrieval order.
+    pub passages: Vec<VerifiedPassage>,
+}
+
+/// Input parameters for `ground_draft`.
+pub struct GroundDraftParams {
+    /// The draft, as Markdown.
+    pub markdown: String,
+    /// Only cite these papers (e.g. a selection); `None` searches the whole DB.
+    pub paper_ids: Option<Vec<String>>,
+    pub mode: SearchMode,
+    /// Pick passages with the cross-encoder (see [`SearchParams::rerank`]).
+    pub rerank: bool,
+    /// Candidate papers per paragraph.
+    pub limit: u16,
+}
+
+/// A paper that could be cited for a paragraph.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CitationCandidate {
+    pub paper_id: String,
+    pub paper_title: String,
+    /// Score of the best matching passage (see [`SearchResult::score`]).
+    pub score: f32,
+    /// How many of the retrieved passages came from this paper.
+    pub passages: usize,
+    /// The best matching passage.
+    pub chunk_id: String,
+    pub section_title: String,
+    pub text_preview: String,
+}
+
+/// A draft paragraph with the papers that could back it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroundedParagraph {
+    /// 1-based line of the draft the paragraph starts on.
+    pub line: usize,
+    pub text: String,
+    /// Whether the paragraph already has a citation (`[@key]`, `[12]`,
+    /// `(Author, 2020)`, ...).
+    pub cited: bool,
+    /// Best first.
+    pub candidates: Vec<CitationCandidate>,
+}
+
+/// Paragraph → candidate citation mappings for a draft.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DraftGrounding {
+    pub paragraphs: Vec<GroundedParagraph>,
+    /// Paragraphs without a citation that have candidates.
+    pub uncited: usize,
+    /// Headings, code, tables and paragraphs too short to ground.
+    pub skipped: usize,
+    /// Paragraphs past [`MAX_DRAFT_PARAGRAPHS`](crate::query::MAX_DRAFT_PARAGRAPHS), not grounded.
+    pub truncated: usize,
+}
@@ -0,0 +1,225 @@
+//! Lexical entailment heuristic used by `query::verify_claim` to label
+//! retrieved passages as supporting, contradicting or neutral to a claim.
+//!
+//! The passage sentence sharing the most content terms with the claim is the
+//! evidence. Too little overlap is neutral. Otherwise the evidence contradicts
+//! the claim when its polarity differs — an odd number of negations on one
+//! side only, or opposite directional words ("increases" vs "reduces") — or
+//! it carries an explicit contrast cue ("in contrast", "failed to replicate");
+//! it supports the claim when the overlap is high. This is a screening aid:
+//! labels point at passages worth reading, not a verdict.
+
+use std::collections::HashSet;
+
+use crate::split_sentences;
+use crate::types::Stance;
+
+/// Below this share of claim terms found in the evidence, a passage is neutral.
+const MIN_OVERLAP: f32 = 0.34;
+/// At or above this share, a passage without polarity conflicts supports the claim.
+const SUPPORT_OVERLAP: f32 = 0.6;
+
+const STOPWORDS: &[&str] = &[
+    "the", "and", "for", "with", "from", "into", "onto", "that", "this", "these", "those", "are",
+    "was", "were", "been", "being", "has", "have", "had", "its", "their", "our", "than", "then",
+    "can", "could", "may", "might", "will", "would", "should", "does", "did", "also", "such",
+    "which", "while", "when", "where", "who", "whom", "all", "any", "both", "each", "other", "some",
+    "via", "using", "used", "use", "between", "over", "under", "about", "there", "here", "they",
+    "them", "very", "much", "many", "most", "only", "same", "show", "shows", "shown", "we", "is",
+    "it", "in", "on", "of", "to", "by", "as", "at", "or", "an", "be", "a",
+];
+
+const NEGATIONS: &[&str] = &[
+    "not", "no", "never", "none", "nothing", "neither", "nor", "cannot", "without", "lack",
+    "lacks", "lacking", "absent", "fail", "fails", "failed",
+];
+
+const UP: &[&str] = &[
+    "increase", "increases", "increased", "increasing", "higher", "more", "greater", "improve",
+    "improves", "improved", "improving", "outperform", "outperforms", "outperformed", "faster",
+    "larger", "better", "positive", "rise", "rises", "enhance", "enhances", "enhanced", "gain",
+    "gains", "boost", "boosts", "boosted",
+];
+
+const DOWN: &[&str] = &[
+    "decrease", "decreases", "decreased", "decreasing", "lower", "less", "fewer", "reduce",
+    "reduces", "reduced", "reducing", "degrade", "degrades", "degraded", "worsen", "worsens",
+    "worsened", "underperform", "underperforms", "underperformed", "slower", "smaller", "worse",
+    "negative", "decline", "declines", "declined", "drop", "drops", "dropped", "impair", "impairs",
+    "impaired", "loss",
+];
+
+const CONTRAST_CUES: &[&str] = &[
+    "contrary to",
+    "in contrast",
+    "contradict",
+    "refute",
+    "inconsistent with",
+    "did not replicate",
+    "failed to replicate",
+    "no evidence",
+    "no significant",
+    "not significant",
+];
+
+/// How a passage relates to a claim.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Judgement {
+    pub(crate) stance: Stance,
+    /// Share of the claim's content terms found in `evidence`.
+    pub(crate) overlap: f32,
+    /// The passage sentence the judgement is based on.
+    pub(crate) evidence: String,
+    /// Human-readable reasons for the stance.
+    pub(crate) cues: Vec<String>,
+}
+
+fn words(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric() && c != '-' && c != '\'' && c != '’')
+        .map(|w| w.trim_matches(|c| c == '-' || c == '\'' || c == '’').to_lowercase())
+        .filter(|w| !w.is_empty())
+        .collect()
+}
+
+/// Crude plural folding, so "splats" matches "splat".
+fn stem(word: &str) -> &str {
+    if word.len() > 4 && word.ends_with('s') && !word.ends_with("ss") {
+        &word[..word.len() - 1]
+    } else {
+        word
+    }
+}
+
+fn is_negation(word: &str) -> bool {
+    NEGATIONS.contains(&word) || word.ends_with("n't") || word.ends_with("n’t")
+}
+
+fn content_terms(words: &[String]) -> HashSet<String> {
+    words
+        .iter()
+        .map(String::as_str)
+        .filter(|w| !STOPWORDS.contains(w) && !is_negation(w))
+        .filter(|w| !UP.contains(w) && !DOWN.contains(w))
+        .filter(|w| w.chars().count() >= 3 || w.chars().all(|c| c.is_ascii_digit()))
+        .map(|w| stem(w).to_string())
+        .collect()
+}
+
+fn negated(words: &[String]) -> bool {
+    words.iter().filter(|w| is_negation(w)).count() % 2 == 1
+}
+
+/// +1 when upward words dominate, -1 when downward ones do, 0 otherwise.
+fn direction(words: &[String]) -> i32 {
+    let up = words.iter().filter(|w| UP.contains(&w.as_str())).count();
+    let down = words.iter().filter(|w| DOWN.contains(&w.as_str())).count();
+    (up as i32 - down as i32).signum()
+}
+
+/// Sentences of `text`; line breaks and semicolons also end one.
+fn sentences(text: &str) -> Vec<&str> {
+    text.split_inclusive(['\n', ';']).flat_map(split_sentences).collect()
+}
+
+/// Judge how `passage` relates to `claim`.
+pub(crate) fn judge(claim: &str, passage: &str) -> Judgement {
+    let claim_words = words(claim);
+    let claim_terms = content_terms(&claim_words);
+    let mut best: Option<(f32, &str, Vec<String>)> = None;
+    for sentence in sentences(passage) {
+        let sentence_words = words(sentence);
+        let terms = content_terms(&sentence_words);
+        let shared = claim_terms.intersection(&terms).count();
+        let overlap = if claim_terms.is_empty() { 0.0 } else { shared as f32 / claim_terms.len() as f32 };
+        if best.as_ref().is_none_or(|(b, _, _)| overlap > *b) {
+            best = Some((overlap, sentence, sentence_words));
+        }
+    }
+    let Some((overlap, evidence, evidence_words)) = best else {
+        return Judgement { stance: Stance::Neutral, overlap: 0.0, evidence: String::new(), cues: Vec::new() };
+    };
+
+    let mut cues = Vec::new();
+    let stance = if overlap < MIN_OVERLAP {
+        cues.push("few claim terms in the passage".to_string());
+        Stance::Neutral
+    } else {
+        if negated(&claim_words) != negated(&evidence_words) {
+            cues.push("negation differs from the claim".to_string());
+        }
+        let (claim_dir, evidence_dir) = (direction(&claim_words), direction(&evidence_words));
+        if claim_dir != 0 && evidence_dir != 0 && claim_dir != evidence_dir {
+            cues.push("opposite direction of effect".to_string());
+        }
+        let (claim_lower, evidence_lower) = (claim.to_lowercase(), evidence.to_lowercase());
+        for cue in CONTRAST_CUES {
+            if evidence_lower.contains(cue) && !claim_lower.contains(cue) {
+                cues.push(format!("contrast cue \"{cue}\""));
+            }
+        }
+        // Two polarity flips (e.g. "does not reduce" vs "increases") cancel out.
+        let flips = cues.iter().filter(|c| !c.starts_with("contrast")).count();
+        let contrast = cues.iter().any(|c| c.starts_with("contrast"));
+        if flips % 2 == 1 || contrast {
+            Stance::Contradicting
+        } else if overlap >= SUPPORT_OVERLAP {
+            cues.push("most claim terms in one sentence, same polarity".to_string());
+            Stance::Supporting
+        } else {
+            cues.push("partial overlap".to_string());
+            Stance::Neutral
+        }
+    };
+    Judgement { stance, overlap, evidence: evidence.to_string(), cues }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CLAIM: &str = "Gaussian splatting improves rendering speed over neural radiance fields.";
+
+    #[test]
+    fn matching_sentence_supports() {
+        let passage = "We review prior work. Gaussian splatting improves rendering speed by 100x over neural radiance fields.";
+        let j = judge(CLAIM, passage);
+        assert_eq!(j.stance, Stance::Supporting);
+        assert!(j.evidence.starts_with("Gaussian splatting improves"));
+        assert!(j.overlap > 0.9);
+    }
+
+    #[test]
+    fn negation_or_opposite_direction_contradicts() {
+        let negated = "Gaussian splatting does not improve rendering speed over neural radiance fields.";
+        assert_eq!(judge(CLAIM, negated).stance, Stance::Contradicting);
+        let opposite = "On our scenes, Gaussian splatting degrades rendering speed compared to neural radiance fields.";
+        let j = judge(CLAIM, opposite);
+        assert_eq!(j.stance, Stance::Contradicting);
+        assert_eq!(j.cues, ["opposite direction of effect"]);
+        // Negated opposite direction restates the claim.
+        let double = "Gaussian splatting does not degrade rendering speed relative to neural radiance fields.";
+        assert_eq!(judge(CLAIM, double).stance, Stance::Supporting);
+    }
+
+    #[test]
+    fn contrast_cue_contradicts() {
+        let passage = "In contrast to earlier reports, Gaussian splatting rendering speed matched neural radiance fields.";
+        assert_eq!(judge(CLAIM, passage).stance, Stance::Contradicting);
+    }
+
+    #[test]
+    fn unrelated_or_partial_passages_are_neutral() {
+        assert_eq!(judge(CLAIM, "Protein folding depends on temperature.").stance, Stance::Neutral);
+        let partial = "Neural radiance fields model view-dependent effects.";
+        assert_eq!(judge(CLAIM, partial).stance, Stance::Neutral);
+        assert_eq!(judge(CLAIM, "").stance, Stance::Neutral);
+    }
+
+    #[test]
+    fn sentences_skip_abbreviations() {
+        assert_eq!(
+            sentences("As in Fig. 2, speed is 1.5x higher (e.g. on GPUs). Next one!\nLast; done"),
+            ["As in Fig. 2, speed is 1.5x higher (e.g. on GPUs).", "Next one!", "Last;", "done"]
+        );
+    }
+}
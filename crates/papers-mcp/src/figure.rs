@@ -0,0 +1,145 @@
+//! Figure images for tool responses: load, downscale, and base64-encode so MCP
+//! clients that can't read local paths can still display them.
+
+use base64::Engine;
+use image::{DynamicImage, ImageFormat, imageops::FilterType};
+use std::io::Cursor;
+use std::path::Path;
+
+/// Longest side, in pixels, when the caller doesn't ask for a size.
+pub const DEFAULT_MAX_DIMENSION: u32 = 1024;
+
+/// Upper bound on the requested longest side.
+pub const MAX_DIMENSION_LIMIT: u32 = 2048;
+
+/// Encoded size limit. Larger PNGs are re-encoded as JPEG, then downscaled further.
+pub const MAX_IMAGE_BYTES: usize = 1_000_000;
+
+/// Below this longest side we stop shrinking and report the image as too large.
+const MIN_DIMENSION: u32 = 64;
+
+const JPEG_QUALITY: u8 = 85;
+
+/// An image ready to embed as MCP `ImageContent`.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct EncodedImage {
+    pub mime_type: &'static str,
+    pub width: u32,
+    pub height: u32,
+    /// Encoded size before base64.
+    pub bytes: usize,
+    /// Base64-encoded image data.
+    #[serde(skip)]
+    pub data: String,
+}
+
+/// Read the image at `path` and encode it with [`encode_image`].
+pub fn encode_image_file(path: &Path, max_dimension: u32) -> Result<EncodedImage, String> {
+    let raw = std::fs::read(path).map_err(|e| format!("Failed to read {}: {e}", path.display()))?;
+    encode_image(&raw, max_dimension)
+}
+
+/// Decode `raw`, downscale so the longest side is at most `max_dimension`
+/// (clamped to [`MAX_DIMENSION_LIMIT`]), and encode as PNG, falling back to JPEG
+/// and smaller sizes until the result fits in [`MAX_IMAGE_BYTES`].
+pub fn encode_image(raw: &[u8], max_dimension: u32) -> Result<EncodedImage, String> {
+    let img = image::load_from_memory(raw).map_err(|e| format!("Failed to decode image: {e}"))?;
+    let mut limit = max_dimension.clamp(MIN_DIMENSION, MAX_DIMENSION_LIMIT);
+    loop {
+        let scaled = if img.width().max(img.height()) > limit {
+            img.resize(limit, limit, FilterType::Triangle)
+        } else {
+            img.clone()
+        };
+        if let Some(encoded) = encode_within_limit(&scaled)? {
+            return Ok(encoded);
+        }
+        if limit <= MIN_DIMENSION {
+            return Err(format!("Image does not fit in {MAX_IMAGE_BYTES} bytes"));
+        }
+        limit = (limit / 2).max(MIN_DIMENSION);
+    }
+}
+
+/// PNG if it fits, else JPEG if that fits, else `None`.
+fn encode_within_limit(img: &DynamicImage) -> Result<Option<EncodedImage>, String> {
+    let png = encode_as(img, ImageFormat::Png)?;
+    if png.len() <= MAX_IMAGE_BYTES {
+        return Ok(Some(finish(img, png, "image/png")));
+    }
+    let mut jpeg = Vec::new();
+    let encoder =
+        image::codecs::jpeg::JpegEncoder::new_with_quality(&mut jpeg, JPEG_QUALITY);
+    DynamicImage::ImageRgb8(img.to_rgb8())
+        .write_with_encoder(encoder)
+        .map_err(|e| format!("Failed to encode JPEG: {e}"))?;
+    if jpeg.len() <= MAX_IMAGE_BYTES {
+        return Ok(Some(finish(img, jpeg, "image/jpeg")));
+    }
+    Ok(None)
+}
+
+fn encode_as(img: &DynamicImage, format: ImageFormat) -> Result<Vec<u8>, String> {
+    let mut out = Cursor::new(Vec::new());
+    img.write_to(&mut out, format).map_err(|e| format!("Failed to encode {format:?}: {e}"))?;
+    Ok(out.into_inner())
+}
+
+fn finish(img: &DynamicImage, bytes: Vec<u8>, mime_type: &'static str) -> EncodedImage {
+    EncodedImage {
+        mime_type,
+        width: img.width(),
+        height: img.height(),
+        bytes: bytes.len(),
+        data: base64::engine::general_purpose::STANDARD.encode(&bytes),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{Rgb, RgbImage};
+
+    fn png(width: u32, height: u32, pixel: impl Fn(u32, u32) -> Rgb<u8>) -> Vec<u8> {
+        let img = DynamicImage::ImageRgb8(RgbImage::from_fn(width, height, pixel));
+        encode_as(&img, ImageFormat::Png).unwrap()
+    }
+
+    #[test]
+    fn downscales_to_max_dimension_keeping_aspect() {
+        let raw = png(400, 200, |_, _| Rgb([10, 20, 30]));
+        let img = encode_image(&raw, 100).unwrap();
+        assert_eq!((img.width, img.height), (100, 50));
+        assert_eq!(img.mime_type, "image/png");
+        let decoded = base64::engine::general_purpose::STANDARD.decode(&img.data).unwrap();
+        assert_eq!(decoded.len(), img.bytes);
+    }
+
+    #[test]
+    fn small_images_are_not_upscaled() {
+        let raw = png(80, 60, |_, _| Rgb([0, 0, 0]));
+        let img = encode_image(&raw, DEFAULT_MAX_DIMENSION).unwrap();
+        assert_eq!((img.width, img.height), (80, 60));
+    }
+
+    #[test]
+    fn noisy_images_are_reencoded_under_the_limit() {
+        // Pseudo-random pixels compress poorly as PNG (~3 bytes/pixel).
+        let raw = png(1024, 1024, |x, y| {
+            let mut h = x.wrapping_mul(73_856_093) ^ y.wrapping_mul(19_349_663);
+            h ^= h >> 13;
+            h = h.wrapping_mul(0x5bd1_e995);
+            h ^= h >> 15;
+            Rgb([h as u8, (h >> 8) as u8, (h >> 16) as u8])
+        });
+        let img = encode_image(&raw, 1024).unwrap();
+        assert!(img.bytes <= MAX_IMAGE_BYTES);
+        // Either JPEG at full size or a smaller image.
+        assert!(img.mime_type == "image/jpeg" || img.width < 1024, "{img:?}");
+    }
+
+    #[test]
+    fn rejects_non_images() {
+        assert!(encode_image(b"not an image", 512).is_err());
+    }
+}
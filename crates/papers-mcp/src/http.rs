@@ -0,0 +1,330 @@
+//! Streamable HTTP transport for running the server as a long-lived service,
+//! e.g. in a container shared by a lab.
+//!
+//! MCP is served at `/mcp` and a liveness probe at `/healthz`. The bind
+//! address comes from `PAPERS_HOST` / `PAPERS_PORT`; everything else (Zotero
+//! credentials, cache and DB locations) uses the same environment variables as
+//! the stdio server. SIGINT and SIGTERM stop accepting requests, close open
+//! MCP sessions, and let in-flight requests finish.
+//!
+//! When `PAPERS_TENANTS` is set (see [`papers_core::tenant`]), `/mcp` requires
+//! `Authorization: Bearer <token>` and each tenant gets its own server, with
+//! its own RAG store, selections and OpenAlex cache, opened on first use.
+//!
+//! Tool calls are recorded in the access audit log ([`papers_core::audit`]).
+//! With `PAPERS_METRICS=on`, usage counters ([`crate::metrics`]) are also
+//! served as Prometheus text at `/metrics`.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use axum::Json;
+use axum::extract::{Request, State};
+use axum::http::{StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{any, get};
+use papers_core::audit::AuditLog;
+use papers_core::tenant::{self, Tenant};
+use rmcp::transport::streamable_http_server::session::local::LocalSessionManager;
+use rmcp::transport::streamable_http_server::{StreamableHttpServerConfig, StreamableHttpService};
+use serde::Serialize;
+
+use crate::limits::Limits;
+use crate::metrics::Metrics;
+use crate::server::PapersMcp;
+
+pub const DEFAULT_HOST: &str = "127.0.0.1";
+pub const DEFAULT_PORT: u16 = 8000;
+pub const MCP_PATH: &str = "/mcp";
+pub const HEALTH_PATH: &str = "/healthz";
+pub const METRICS_PATH: &str = "/metrics";
+
+/// Where `serve_http` listens.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServeConfig {
+    pub host: String,
+    pub port: u16,
+    /// Serve without the write tools (see [`PapersMcp::with_read_only`]).
+    pub read_only: bool,
+}
+
+impl Default for ServeConfig {
+    fn default() -> Self {
+        Self { host: DEFAULT_HOST.to_string(), port: DEFAULT_PORT, read_only: false }
+    }
+}
+
+impl ServeConfig {
+    /// Read `PAPERS_HOST`, `PAPERS_PORT` and `PAPERS_READ_ONLY`, falling back to the defaults.
+    pub fn from_env() -> Result<Self, String> {
+        let mut config = Self::from_vars(std::env::var("PAPERS_HOST").ok(), std::env::var("PAPERS_PORT").ok())?;
+        config.read_only = crate::server::read_only_from_env();
+        Ok(config)
+    }
+
+    /// Build from optional host and port strings; empty values count as unset.
+    pub fn from_vars(host: Option<String>, port: Option<String>) -> Result<Self, String> {
+        let mut config = Self::default();
+        if let Some(host) = host.filter(|h| !h.trim().is_empty()) {
+            config.host = host.trim().to_string();
+        }
+        if let Some(port) = port.filter(|p| !p.trim().is_empty()) {
+            config.port = port.trim().parse().map_err(|_| format!("invalid port: {port:?}"))?;
+        }
+        Ok(config)
+    }
+
+    pub fn addr(&self) -> String {
+        format!("{}:{}", self.host, self.port)
+    }
+}
+
+/// Body of `GET /healthz`.
+#[derive(Debug, Clone, Serialize)]
+pub struct Health {
+    pub status: &'static str,
+    pub version: &'static str,
+    /// Whether the RAG database opened; DB tools fail without it. Absent in
+    /// multi-tenant mode, where each tenant's database opens on first use.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub db: Option<bool>,
+    /// Number of configured tenants, in multi-tenant mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tenants: Option<usize>,
+}
+
+/// Routes for `server`: MCP at [`MCP_PATH`] and the health probe at [`HEALTH_PATH`].
+/// Every MCP session gets a clone of `server`, so clients share its caches and DB.
+pub fn router(server: PapersMcp, config: StreamableHttpServerConfig) -> axum::Router {
+    let health = Health {
+        status: "ok",
+        version: env!("CARGO_PKG_VERSION"),
+        db: Some(server.has_db()),
+        tenants: None,
+    };
+    let mcp = StreamableHttpService::new(
+        move || Ok(server.clone()),
+        Arc::new(LocalSessionManager::default()),
+        config,
+    );
+    axum::Router::new()
+        .nest_service(MCP_PATH, mcp)
+        .route(HEALTH_PATH, get(move || async move { Json(health) }))
+}
+
+/// Add the Prometheus scrape endpoint for `metrics` at [`METRICS_PATH`].
+pub fn with_metrics_route(router: axum::Router, metrics: Metrics) -> axum::Router {
+    router.route(
+        METRICS_PATH,
+        get(move || async move {
+            ([(header::CONTENT_TYPE, "text/plain; version=0.0.4")], metrics.render_prometheus())
+        }),
+    )
+}
+
+type BoxedServer = Pin<Box<dyn Future<Output = PapersMcp> + Send>>;
+
+/// Opens the server for a tenant the first time it connects.
+type OpenTenant = Arc<dyn Fn(Tenant) -> BoxedServer + Send + Sync>;
+
+type McpService = StreamableHttpService<PapersMcp, LocalSessionManager>;
+
+#[derive(Clone)]
+struct TenantState {
+    tenants: Arc<Vec<Tenant>>,
+    open: OpenTenant,
+    config: StreamableHttpServerConfig,
+    /// One MCP service per tenant, so sessions never cross tenants. Each is
+    /// opened once, without holding up other tenants while it opens.
+    services: Arc<std::sync::Mutex<HashMap<String, Arc<tokio::sync::OnceCell<McpService>>>>>,
+}
+
+impl TenantState {
+    async fn service(&self, tenant: &Tenant) -> McpService {
+        let cell = {
+            let mut services = self.services.lock().unwrap_or_else(|e| e.into_inner());
+            services.entry(tenant.name.clone()).or_default().clone()
+        };
+        cell.get_or_init(|| async {
+            let server = (self.open)(tenant.clone()).await;
+            StreamableHttpService::new(
+                move || Ok(server.clone()),
+                Arc::new(LocalSessionManager::default()),
+                self.config.clone(),
+            )
+        })
+        .await
+        .clone()
+    }
+}
+
+/// Multi-tenant routes: like [`router`], but `/mcp` authenticates the bearer
+/// token against `tenants` and dispatches to that tenant's server, created
+/// with `open` on first use.
+pub fn tenant_router<F, Fut>(
+    tenants: Vec<Tenant>,
+    config: StreamableHttpServerConfig,
+    open: F,
+) -> axum::Router
+where
+    F: Fn(Tenant) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = PapersMcp> + Send + 'static,
+{
+    let health = Health {
+        status: "ok",
+        version: env!("CARGO_PKG_VERSION"),
+        db: None,
+        tenants: Some(tenants.len()),
+    };
+    let state = TenantState {
+        tenants: Arc::new(tenants),
+        open: Arc::new(move |tenant| -> BoxedServer { Box::pin(open(tenant)) }),
+        config,
+        services: Arc::default(),
+    };
+    axum::Router::new()
+        .route(MCP_PATH, any(tenant_mcp))
+        .route(HEALTH_PATH, get(move || async move { Json(health) }))
+        .with_state(state)
+}
+
+async fn tenant_mcp(State(state): State<TenantState>, request: Request) -> Response {
+    let tenant = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .and_then(|token| tenant::authenticate(&state.tenants, token.trim()))
+        .cloned();
+    let Some(tenant) = tenant else {
+        return (
+            StatusCode::UNAUTHORIZED,
+            [(header::WWW_AUTHENTICATE, "Bearer")],
+            "missing or unknown bearer token",
+        )
+            .into_response();
+    };
+    state.service(&tenant).await.handle(request).await.into_response()
+}
+
+/// Open a tenant's server from its data directory, with `audit` relocated
+/// into that directory, and start its schedule.
+async fn open_tenant_server(
+    tenant: Tenant,
+    audit: Option<Arc<AuditLog>>,
+    limits: Limits,
+    metrics: Option<Metrics>,
+    read_only: bool,
+) -> PapersMcp {
+    let mut server = PapersMcp::for_tenant(&tenant).await.with_limits(limits);
+    if read_only {
+        server = server.with_read_only();
+    }
+    if let Some(audit) = audit {
+        let dir = papers_core::audit::audit_dir_in(&tenant.data_dir());
+        server = server.with_audit(Arc::new(audit.relocated(dir)));
+    }
+    if let Some(metrics) = metrics {
+        server = server.with_metrics(metrics);
+    }
+    // Dropping the handles detaches the timers; they run until exit.
+    let _ = server.start_scheduler().await;
+    server
+}
+
+/// Serve MCP over HTTP until SIGINT or SIGTERM. Runs the configured schedule
+/// (see [`crate::scheduler`]) for as long as the server is up, and records
+/// tool calls in the audit log unless `PAPERS_AUDIT=off`, and serves
+/// [`METRICS_PATH`] when `PAPERS_METRICS=on`.
+pub async fn serve_http(config: ServeConfig) -> Result<(), Box<dyn std::error::Error>> {
+    let tenants = tenant::configured_tenants()?;
+    let audit = AuditLog::from_env()?.map(Arc::new);
+    if let Some(audit) = &audit {
+        audit.prune()?;
+        if tenants.is_empty() {
+            eprintln!("audit log: {}", audit.dir().display());
+        } else {
+            eprintln!("audit log: per tenant, under {}", tenant::tenants_root().display());
+        }
+    }
+    let metrics = Metrics::from_env();
+    let http_config = StreamableHttpServerConfig::default();
+    let sessions = http_config.cancellation_token.clone();
+    let (app, _timers) = if tenants.is_empty() {
+        let mut server = PapersMcp::new().await;
+        if config.read_only {
+            server = server.with_read_only();
+        }
+        if let Some(audit) = audit {
+            server = server.with_audit(audit);
+        }
+        if let Some(metrics) = &metrics {
+            server = server.with_metrics(metrics.clone());
+        }
+        let timers = server.start_scheduler().await;
+        (router(server, http_config), timers)
+    } else {
+        eprintln!("multi-tenant mode: {} tenants", tenants.len());
+        // Tenants share the backends, so they share one set of limits and
+        // report into one set of metrics.
+        let limits = Limits::from_env();
+        let tenant_metrics = metrics.clone();
+        let read_only = config.read_only;
+        let open = move |tenant| {
+            open_tenant_server(tenant, audit.clone(), limits.clone(), tenant_metrics.clone(), read_only)
+        };
+        (tenant_router(tenants, http_config, open), Vec::new())
+    };
+    let app = match metrics {
+        Some(metrics) => with_metrics_route(app, metrics),
+        None => app,
+    };
+    let listener = tokio::net::TcpListener::bind(config.addr()).await?;
+    eprintln!("papers MCP server listening on http://{}{MCP_PATH}", listener.local_addr()?);
+    axum::serve(listener, app)
+        .with_graceful_shutdown(async move {
+            shutdown_signal().await;
+            eprintln!("shutting down");
+            sessions.cancel();
+        })
+        .await?;
+    Ok(())
+}
+
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        let _ = tokio::signal::ctrl_c().await;
+    };
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut sig) => {
+                sig.recv().await;
+            }
+            Err(_) => std::future::pending().await,
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+    tokio::select! {
+        _ = ctrl_c => {}
+        _ = terminate => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn config_defaults_and_overrides() {
+        assert_eq!(ServeConfig::from_vars(None, None).unwrap().addr(), "127.0.0.1:8000");
+        let config =
+            ServeConfig::from_vars(Some("0.0.0.0".into()), Some(" 9000 ".into())).unwrap();
+        assert_eq!(config.addr(), "0.0.0.0:9000");
+        assert_eq!(ServeConfig::from_vars(Some("".into()), Some("".into())).unwrap().port, 8000);
+        assert!(ServeConfig::from_vars(None, Some("http".into())).is_err());
+    }
+}
@@ -1,10 +1,25 @@
+pub mod figure;
+pub mod http;
+pub mod limits;
+pub mod metrics;
 pub mod params;
+pub mod resources;
+pub mod scheduler;
 pub mod server;
+pub mod zotero_slot;
 
-/// Start the MCP server on stdio. Blocks until the connection closes.
-pub async fn start_stdio() -> Result<(), Box<dyn std::error::Error>> {
+/// Start the MCP server on stdio, read-only if `read_only` (see
+/// [`server::PapersMcp::with_read_only`]). Blocks until the connection closes.
+pub async fn start_stdio(read_only: bool) -> Result<(), Box<dyn std::error::Error>> {
     use rmcp::ServiceExt;
-    let server = server::PapersMcp::new().await;
+    let mut server = server::PapersMcp::new().await;
+    if read_only {
+        server = server.with_read_only();
+    }
+    if let Some(metrics) = metrics::Metrics::from_env() {
+        server = server.with_metrics(metrics);
+    }
+    let _timers = server.start_scheduler().await;
     let service = server.serve(rmcp::transport::stdio()).await?;
     service.waiting().await?;
     Ok(())
@@ -0,0 +1,188 @@
+//! Per-backend concurrency limits shared by all tool calls.
+//!
+//! Each backend (OpenAlex, Zotero, DataLab) gets a semaphore. Work that talks
+//! to a backend holds one of its permits, so concurrent tool calls queue
+//! behind each other instead of flooding the backend, and fan-outs inside a
+//! tool (annotations per attachment, collections per item) run in parallel
+//! up to the same limit.
+//!
+//! Limits come from `PAPERS_CONCURRENCY_OPENALEX`, `PAPERS_CONCURRENCY_ZOTERO`
+//! and `PAPERS_CONCURRENCY_DATALAB`; see [`Limits::from_env`].
+
+use std::future::Future;
+use std::sync::Arc;
+
+use futures::stream::{self, StreamExt};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// A rate-limited service tools talk to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    OpenAlex,
+    Zotero,
+    Datalab,
+}
+
+impl Backend {
+    fn env_var(self) -> &'static str {
+        match self {
+            Backend::OpenAlex => "PAPERS_CONCURRENCY_OPENALEX",
+            Backend::Zotero => "PAPERS_CONCURRENCY_ZOTERO",
+            Backend::Datalab => "PAPERS_CONCURRENCY_DATALAB",
+        }
+    }
+}
+
+/// Requests allowed in flight per backend when the environment doesn't say.
+pub const DEFAULT_OPENALEX: usize = 8;
+pub const DEFAULT_ZOTERO: usize = 4;
+pub const DEFAULT_DATALAB: usize = 2;
+
+#[derive(Clone)]
+struct Lane {
+    permits: Arc<Semaphore>,
+    size: usize,
+}
+
+impl Lane {
+    fn new(size: usize) -> Self {
+        let size = size.max(1);
+        Self { permits: Arc::new(Semaphore::new(size)), size }
+    }
+}
+
+/// Semaphores for each [`Backend`]. Clones share the same permits.
+#[derive(Clone)]
+pub struct Limits {
+    openalex: Lane,
+    zotero: Lane,
+    datalab: Lane,
+}
+
+impl Limits {
+    /// Allow `openalex`, `zotero` and `datalab` requests in flight. Zero is
+    /// treated as one.
+    pub fn new(openalex: usize, zotero: usize, datalab: usize) -> Self {
+        Self { openalex: Lane::new(openalex), zotero: Lane::new(zotero), datalab: Lane::new(datalab) }
+    }
+
+    /// Limits from `PAPERS_CONCURRENCY_*`, falling back to the defaults for
+    /// unset or unparseable values.
+    pub fn from_env() -> Self {
+        Self::from_lookup(|key| std::env::var(key).ok())
+    }
+
+    fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Self {
+        let get = |backend: Backend, default: usize| {
+            lookup(backend.env_var()).and_then(|v| v.trim().parse().ok()).unwrap_or(default)
+        };
+        Self::new(
+            get(Backend::OpenAlex, DEFAULT_OPENALEX),
+            get(Backend::Zotero, DEFAULT_ZOTERO),
+            get(Backend::Datalab, DEFAULT_DATALAB),
+        )
+    }
+
+    fn lane(&self, backend: Backend) -> &Lane {
+        match backend {
+            Backend::OpenAlex => &self.openalex,
+            Backend::Zotero => &self.zotero,
+            Backend::Datalab => &self.datalab,
+        }
+    }
+
+    /// Requests allowed in flight for `backend`.
+    pub fn limit(&self, backend: Backend) -> usize {
+        self.lane(backend).size
+    }
+
+    /// Permits currently free for `backend`.
+    pub fn available(&self, backend: Backend) -> usize {
+        self.lane(backend).permits.available_permits()
+    }
+
+    /// Wait for a permit for `backend`; it is released when dropped.
+    ///
+    /// Don't acquire a second permit for the same backend while holding one:
+    /// with enough concurrent callers that deadlocks.
+    pub async fn acquire(&self, backend: Backend) -> OwnedSemaphorePermit {
+        // The semaphores are never closed.
+        self.lane(backend).permits.clone().acquire_owned().await.expect("semaphore closed")
+    }
+
+    /// Run `f` over `items` concurrently, each call holding a `backend`
+    /// permit. Results come back in the order of `items`.
+    pub async fn map<T, R, F, Fut>(&self, backend: Backend, items: impl IntoIterator<Item = T>, f: F) -> Vec<R>
+    where
+        F: Fn(T) -> Fut,
+        Fut: Future<Output = R>,
+    {
+        stream::iter(items)
+            .map(|item| {
+                let fut = f(item);
+                async move {
+                    let _permit = self.acquire(backend).await;
+                    fut.await
+                }
+            })
+            .buffered(self.limit(backend))
+            .collect()
+            .await
+    }
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self::new(DEFAULT_OPENALEX, DEFAULT_ZOTERO, DEFAULT_DATALAB)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    #[test]
+    fn limits_from_lookup() {
+        let env: HashMap<&str, &str> =
+            [("PAPERS_CONCURRENCY_ZOTERO", "2"), ("PAPERS_CONCURRENCY_DATALAB", "nope")].into();
+        let limits = Limits::from_lookup(|k| env.get(k).map(|v| v.to_string()));
+        assert_eq!(limits.limit(Backend::OpenAlex), DEFAULT_OPENALEX);
+        assert_eq!(limits.limit(Backend::Zotero), 2);
+        assert_eq!(limits.limit(Backend::Datalab), DEFAULT_DATALAB);
+        assert_eq!(Limits::new(0, 0, 0).limit(Backend::Zotero), 1);
+    }
+
+    #[tokio::test]
+    async fn map_keeps_order_and_respects_limit() {
+        let limits = Limits::new(8, 2, 1);
+        let (running, peak) = (AtomicUsize::new(0), AtomicUsize::new(0));
+        let out = limits
+            .map(Backend::Zotero, 0..6u64, |i| {
+                let (running, peak) = (&running, &peak);
+                async move {
+                    let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                    peak.fetch_max(now, Ordering::SeqCst);
+                    // Later items finish first, so order must come from `buffered`.
+                    tokio::time::sleep(Duration::from_millis(30 - 5 * i)).await;
+                    running.fetch_sub(1, Ordering::SeqCst);
+                    i * 10
+                }
+            })
+            .await;
+        assert_eq!(out, vec![0, 10, 20, 30, 40, 50]);
+        assert_eq!(peak.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn clones_share_permits() {
+        let limits = Limits::new(8, 2, 1);
+        let other = limits.clone();
+        let permit = limits.acquire(Backend::Datalab).await;
+        assert_eq!(other.available(Backend::Datalab), 0);
+        drop(permit);
+        assert_eq!(other.available(Backend::Datalab), 1);
+    }
+}
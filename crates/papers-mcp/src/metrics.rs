@@ -0,0 +1,299 @@
+//! Opt-in usage metrics: tool invocations, latencies, OpenAlex cache hit
+//! rates, and backend error rates.
+//!
+//! Enabled with `PAPERS_METRICS=on` (see [`Metrics::from_env`]). Counts are
+//! kept in memory since the server started and are read with the
+//! `server_stats` tool or, in HTTP mode, scraped as Prometheus text from
+//! [`METRICS_PATH`](crate::http::METRICS_PATH). Nothing about the arguments
+//! of a call is recorded; the audit log ([`papers_core::audit`]) does that.
+//!
+//! Errors are attributed to a backend by tool family (see [`backend_of`]),
+//! since a tool's error does not say which service failed.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use papers_core::{CacheLookups, DiskCache};
+use serde::Serialize;
+
+/// Upper bounds, in seconds, of the tool latency histogram buckets.
+pub const LATENCY_BUCKETS: &[f64] = &[0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0];
+
+/// The backend a tool's errors are attributed to: `zotero` for `zotero_*`
+/// tools, `db` for `db_*`, and `openalex` for everything else.
+pub fn backend_of(tool: &str) -> &'static str {
+    if tool.starts_with("zotero_") {
+        "zotero"
+    } else if tool.starts_with("db_") {
+        "db"
+    } else {
+        "openalex"
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct ToolCounts {
+    calls: u64,
+    errors: u64,
+    total: Duration,
+    max: Duration,
+    /// Calls per [`LATENCY_BUCKETS`] bucket (not cumulative), plus one for
+    /// calls slower than the last bound.
+    buckets: Vec<u64>,
+}
+
+struct Inner {
+    started: Instant,
+    tools: Mutex<BTreeMap<String, ToolCounts>>,
+    caches: Mutex<Vec<DiskCache>>,
+}
+
+/// In-memory counters shared by every clone, so the servers of all HTTP
+/// sessions and tenants report into one set.
+#[derive(Clone)]
+pub struct Metrics {
+    inner: Arc<Inner>,
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                started: Instant::now(),
+                tools: Mutex::default(),
+                caches: Mutex::default(),
+            }),
+        }
+    }
+
+    /// New counters when `PAPERS_METRICS` is `on`, `1` or `true`.
+    pub fn from_env() -> Option<Self> {
+        let enabled = std::env::var("PAPERS_METRICS")
+            .is_ok_and(|v| matches!(v.trim().to_ascii_lowercase().as_str(), "on" | "1" | "true"));
+        enabled.then(Self::new)
+    }
+
+    /// Count one call of `tool` that took `elapsed`.
+    pub fn record(&self, tool: &str, ok: bool, elapsed: Duration) {
+        let mut tools = self.inner.tools.lock().unwrap_or_else(|e| e.into_inner());
+        let counts = tools.entry(tool.to_string()).or_default();
+        counts.calls += 1;
+        if !ok {
+            counts.errors += 1;
+        }
+        counts.total += elapsed;
+        counts.max = counts.max.max(elapsed);
+        counts.buckets.resize(LATENCY_BUCKETS.len() + 1, 0);
+        let secs = elapsed.as_secs_f64();
+        let bucket = LATENCY_BUCKETS.iter().position(|le| secs <= *le).unwrap_or(LATENCY_BUCKETS.len());
+        counts.buckets[bucket] += 1;
+    }
+
+    /// Include the lookups of `cache` in the cache hit rate.
+    pub fn watch_cache(&self, cache: DiskCache) {
+        self.inner.caches.lock().unwrap_or_else(|e| e.into_inner()).push(cache);
+    }
+
+    fn cache_lookups(&self) -> Option<CacheLookups> {
+        let caches = self.inner.caches.lock().unwrap_or_else(|e| e.into_inner());
+        if caches.is_empty() {
+            return None;
+        }
+        Some(caches.iter().map(DiskCache::lookups).fold(CacheLookups::default(), |sum, l| CacheLookups {
+            hits: sum.hits + l.hits,
+            misses: sum.misses + l.misses,
+        }))
+    }
+
+    /// Everything counted so far, as returned by `server_stats`.
+    pub fn snapshot(&self) -> ServerStats {
+        let tools = self.inner.tools.lock().unwrap_or_else(|e| e.into_inner()).clone();
+        let mut backends: BTreeMap<&'static str, (u64, u64)> = BTreeMap::new();
+        for (name, counts) in &tools {
+            let backend = backends.entry(backend_of(name)).or_default();
+            backend.0 += counts.calls;
+            backend.1 += counts.errors;
+        }
+        ServerStats {
+            uptime_secs: self.inner.started.elapsed().as_secs(),
+            calls: tools.values().map(|c| c.calls).sum(),
+            errors: tools.values().map(|c| c.errors).sum(),
+            tools: tools
+                .into_iter()
+                .map(|(name, c)| ToolStats {
+                    mean_ms: c.total.as_millis() as u64 / c.calls.max(1),
+                    max_ms: c.max.as_millis() as u64,
+                    error_rate: rate(c.errors, c.calls),
+                    calls: c.calls,
+                    errors: c.errors,
+                    name,
+                })
+                .collect(),
+            backends: backends
+                .into_iter()
+                .map(|(name, (calls, errors))| BackendStats {
+                    name,
+                    calls,
+                    errors,
+                    error_rate: rate(errors, calls),
+                })
+                .collect(),
+            cache: self.cache_lookups().map(|l| CacheHitStats {
+                hits: l.hits,
+                misses: l.misses,
+                hit_rate: l.hit_rate(),
+            }),
+        }
+    }
+
+    /// The counters in the Prometheus text exposition format.
+    pub fn render_prometheus(&self) -> String {
+        let tools = self.inner.tools.lock().unwrap_or_else(|e| e.into_inner()).clone();
+        let stats = self.snapshot();
+        let mut out = String::new();
+        let header = |out: &mut String, name: &str, kind: &str, help: &str| {
+            let _ = writeln!(out, "# HELP {name} {help}\n# TYPE {name} {kind}");
+        };
+
+        header(&mut out, "papers_uptime_seconds", "gauge", "Seconds since the server started.");
+        let _ = writeln!(out, "papers_uptime_seconds {}", stats.uptime_secs);
+
+        header(&mut out, "papers_tool_calls_total", "counter", "Tool invocations.");
+        for (tool, c) in &tools {
+            let _ = writeln!(out, "papers_tool_calls_total{{tool=\"{tool}\"}} {}", c.calls);
+        }
+        header(&mut out, "papers_tool_errors_total", "counter", "Tool invocations that returned an error.");
+        for (tool, c) in &tools {
+            let _ = writeln!(out, "papers_tool_errors_total{{tool=\"{tool}\"}} {}", c.errors);
+        }
+
+        let name = "papers_tool_duration_seconds";
+        header(&mut out, name, "histogram", "Tool call latency.");
+        for (tool, c) in &tools {
+            let mut cumulative = 0;
+            for (le, count) in LATENCY_BUCKETS.iter().zip(&c.buckets) {
+                cumulative += count;
+                let _ = writeln!(out, "{name}_bucket{{tool=\"{tool}\",le=\"{le}\"}} {cumulative}");
+            }
+            let _ = writeln!(out, "{name}_bucket{{tool=\"{tool}\",le=\"+Inf\"}} {}", c.calls);
+            let _ = writeln!(out, "{name}_sum{{tool=\"{tool}\"}} {}", c.total.as_secs_f64());
+            let _ = writeln!(out, "{name}_count{{tool=\"{tool}\"}} {}", c.calls);
+        }
+
+        header(&mut out, "papers_backend_calls_total", "counter", "Tool invocations by backend.");
+        for b in &stats.backends {
+            let _ = writeln!(out, "papers_backend_calls_total{{backend=\"{}\"}} {}", b.name, b.calls);
+        }
+        header(&mut out, "papers_backend_errors_total", "counter", "Tool errors by backend.");
+        for b in &stats.backends {
+            let _ = writeln!(out, "papers_backend_errors_total{{backend=\"{}\"}} {}", b.name, b.errors);
+        }
+
+        if let Some(cache) = &stats.cache {
+            header(&mut out, "papers_cache_hits_total", "counter", "OpenAlex response cache hits.");
+            let _ = writeln!(out, "papers_cache_hits_total {}", cache.hits);
+            header(&mut out, "papers_cache_misses_total", "counter", "OpenAlex response cache misses.");
+            let _ = writeln!(out, "papers_cache_misses_total {}", cache.misses);
+        }
+        out
+    }
+}
+
+fn rate(errors: u64, calls: u64) -> f64 {
+    if calls == 0 { 0.0 } else { errors as f64 / calls as f64 }
+}
+
+/// Body of the `server_stats` tool.
+#[derive(Debug, Clone, Serialize)]
+pub struct ServerStats {
+    pub uptime_secs: u64,
+    pub calls: u64,
+    pub errors: u64,
+    /// Per tool, by name.
+    pub tools: Vec<ToolStats>,
+    /// Per backend (see [`backend_of`]), by name.
+    pub backends: Vec<BackendStats>,
+    /// OpenAlex response cache lookups; absent when caching is off.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache: Option<CacheHitStats>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ToolStats {
+    pub name: String,
+    pub calls: u64,
+    pub errors: u64,
+    pub error_rate: f64,
+    pub mean_ms: u64,
+    pub max_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BackendStats {
+    pub name: &'static str,
+    pub calls: u64,
+    pub errors: u64,
+    pub error_rate: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CacheHitStats {
+    pub hits: u64,
+    pub misses: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hit_rate: Option<f64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backend_of_tool_families() {
+        assert_eq!(backend_of("zotero_work_list"), "zotero");
+        assert_eq!(backend_of("db_chunk_search"), "db");
+        assert_eq!(backend_of("db_draft_ground"), "db");
+        assert_eq!(backend_of("work_list"), "openalex");
+    }
+
+    #[test]
+    fn snapshot_counts_calls_errors_and_latency() {
+        let metrics = Metrics::new();
+        metrics.record("work_list", true, Duration::from_millis(100));
+        metrics.record("work_list", false, Duration::from_millis(300));
+        metrics.clone().record("zotero_work_get", true, Duration::from_secs(90));
+        let stats = metrics.snapshot();
+        assert_eq!((stats.calls, stats.errors), (3, 1));
+        let work_list = stats.tools.iter().find(|t| t.name == "work_list").unwrap();
+        assert_eq!((work_list.calls, work_list.errors, work_list.mean_ms, work_list.max_ms), (2, 1, 200, 300));
+        assert_eq!(work_list.error_rate, 0.5);
+        let backends: Vec<_> = stats.backends.iter().map(|b| (b.name, b.calls, b.errors)).collect();
+        assert_eq!(backends, [("openalex", 2, 1), ("zotero", 1, 0)]);
+        assert!(stats.cache.is_none());
+    }
+
+    #[test]
+    fn prometheus_histogram_is_cumulative() {
+        let metrics = Metrics::new();
+        metrics.record("work_get", true, Duration::from_millis(40));
+        metrics.record("work_get", true, Duration::from_millis(700));
+        metrics.record("work_get", false, Duration::from_secs(120));
+        let text = metrics.render_prometheus();
+        assert!(text.contains("# TYPE papers_tool_calls_total counter\n"));
+        assert!(text.contains("papers_tool_calls_total{tool=\"work_get\"} 3\n"));
+        assert!(text.contains("papers_tool_errors_total{tool=\"work_get\"} 1\n"));
+        assert!(text.contains("papers_tool_duration_seconds_bucket{tool=\"work_get\",le=\"0.05\"} 1\n"));
+        assert!(text.contains("papers_tool_duration_seconds_bucket{tool=\"work_get\",le=\"1\"} 2\n"));
+        assert!(text.contains("papers_tool_duration_seconds_bucket{tool=\"work_get\",le=\"60\"} 2\n"));
+        assert!(text.contains("papers_tool_duration_seconds_bucket{tool=\"work_get\",le=\"+Inf\"} 3\n"));
+        assert!(text.contains("papers_backend_errors_total{backend=\"openalex\"} 1\n"));
+    }
+}
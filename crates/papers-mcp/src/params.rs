@@ -1,6 +1,154 @@
 use schemars::JsonSchema;
 use serde::Deserialize;
 
+/// Deserialize an optional enum parameter through its `FromStr` impl, so
+/// values match case-insensitively ("Desc", "TABLE") and a typo fails with
+/// the list of accepted values instead of falling back to a default.
+fn lax_optional_enum<'de, D, T>(d: D) -> Result<Option<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: std::str::FromStr<Err = String>,
+{
+    match Option::<String>::deserialize(d)? {
+        None => Ok(None),
+        Some(s) if s.trim().is_empty() => Ok(None),
+        Some(s) => s.parse().map(Some).map_err(serde::de::Error::custom),
+    }
+}
+
+/// Sort direction for Zotero list and search tools.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SortDirection {
+    Asc,
+    Desc,
+}
+
+impl SortDirection {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SortDirection::Asc => "asc",
+            SortDirection::Desc => "desc",
+        }
+    }
+}
+
+impl std::str::FromStr for SortDirection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "asc" => Ok(SortDirection::Asc),
+            "desc" => Ok(SortDirection::Desc),
+            other => Err(format!("invalid direction '{other}' (expected asc or desc)")),
+        }
+    }
+}
+
+impl From<SortDirection> for String {
+    fn from(direction: SortDirection) -> Self {
+        direction.as_str().to_string()
+    }
+}
+
+/// Which collections `zotero_collection_list` returns.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum CollectionScope {
+    #[default]
+    All,
+    Top,
+}
+
+impl std::str::FromStr for CollectionScope {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "all" => Ok(CollectionScope::All),
+            "top" => Ok(CollectionScope::Top),
+            other => Err(format!("invalid scope '{other}' (expected all or top)")),
+        }
+    }
+}
+
+/// Which tag index `zotero_tag_list` reads.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TagScope {
+    #[default]
+    All,
+    Top,
+    Trash,
+}
+
+impl std::str::FromStr for TagScope {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "all" => Ok(TagScope::All),
+            "top" => Ok(TagScope::Top),
+            "trash" => Ok(TagScope::Trash),
+            other => Err(format!("invalid scope '{other}' (expected all, top, or trash)")),
+        }
+    }
+}
+
+/// How `zotero_tag_search` matches the query against tag names.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum TagMatchMode {
+    #[default]
+    Contains,
+    StartsWith,
+}
+
+impl TagMatchMode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TagMatchMode::Contains => "contains",
+            TagMatchMode::StartsWith => "startsWith",
+        }
+    }
+}
+
+impl std::str::FromStr for TagMatchMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "contains" => Ok(TagMatchMode::Contains),
+            "startswith" | "starts_with" => Ok(TagMatchMode::StartsWith),
+            other => Err(format!("invalid qmode '{other}' (expected contains or startsWith)")),
+        }
+    }
+}
+
+/// DataLab Marker quality level for `work_text`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ExtractionQuality {
+    Fast,
+    Balanced,
+    Accurate,
+}
+
+impl std::str::FromStr for ExtractionQuality {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "fast" => Ok(ExtractionQuality::Fast),
+            "balanced" => Ok(ExtractionQuality::Balanced),
+            "accurate" => Ok(ExtractionQuality::Accurate),
+            other => Err(format!(
+                "invalid quality '{other}' (expected fast, balanced, or accurate)"
+            )),
+        }
+    }
+}
+
 /// Parameters for list endpoints that don't have filter aliases.
 /// Currently unused but kept for potential future use.
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
@@ -10,19 +158,25 @@ pub struct ListToolParams {
     /// Full-text search query.
     pub search: Option<String>,
     /// Sort field with optional `:desc` suffix.
+    #[schemars(extend("examples" = ["cited_by_count:desc"]))]
     pub sort: Option<String>,
     /// Results per page (1-200, default 25).
+    #[schemars(range(min = 1, max = 200))]
     pub per_page: Option<u32>,
     /// Page number for offset pagination (max page * per_page <= 10,000).
+    #[schemars(range(min = 1))]
     pub page: Option<u32>,
     /// Cursor for cursor-based pagination. Use `"*"` for the first page, then
     /// pass `meta.next_cursor` from the previous response.
+    #[schemars(extend("examples" = ["*"]))]
     pub cursor: Option<String>,
     /// Return a random sample of this many results.
+    #[schemars(range(min = 1))]
     pub sample: Option<u32>,
     /// Seed for reproducible random sampling. Only meaningful with `sample`.
     pub seed: Option<u32>,
     /// Comma-separated list of fields to include in the response.
+    #[schemars(extend("examples" = ["id,display_name"]))]
     pub select: Option<String>,
     /// Aggregate results by a field.
     pub group_by: Option<String>,
@@ -49,22 +203,30 @@ impl ListToolParams {
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct WorkListToolParams {
     /// Filter expression. Comma-separated AND conditions, pipe (`|`) for OR.
+    #[schemars(extend("examples" = ["publication_year:2024,is_oa:true"]))]
     pub filter: Option<String>,
     /// Sort field with optional `:desc` suffix. Example: `"cited_by_count:desc"`
+    #[schemars(extend("examples" = ["cited_by_count:desc"]))]
     pub sort: Option<String>,
     /// Results per page (1-200, default 25).
+    #[schemars(range(min = 1, max = 200))]
     pub per_page: Option<u32>,
     /// Page number for offset pagination (max page * per_page <= 10,000).
+    #[schemars(range(min = 1))]
     pub page: Option<u32>,
     /// Cursor for cursor-based pagination. Use `"*"` for the first page.
+    #[schemars(extend("examples" = ["*"]))]
     pub cursor: Option<String>,
     /// Return a random sample of this many results.
+    #[schemars(range(min = 1))]
     pub sample: Option<u32>,
     /// Seed for reproducible random sampling.
     pub seed: Option<u32>,
     /// Comma-separated list of fields to include in the response.
+    #[schemars(extend("examples" = ["id,display_name,publication_year,cited_by_count"]))]
     pub select: Option<String>,
     /// Aggregate results by a field.
+    #[schemars(extend("examples" = ["oa_status"]))]
     pub group_by: Option<String>,
     /// Filter by author name or OpenAlex author ID (e.g. "einstein", "Albert Einstein", or "A5108093963")
     pub author: Option<String>,
@@ -84,17 +246,29 @@ pub struct WorkListToolParams {
     /// Filter by institution name or ID. Uses lineage for broad matching (e.g. "mit" or "I136199984")
     pub institution: Option<String>,
     /// Filter by publication year (e.g. "2024", ">2008", "2008-2024")
+    #[schemars(extend("examples" = [">2020"]))]
     pub year: Option<String>,
     /// Filter by citation count (e.g. ">100", "10-50")
+    #[schemars(extend("examples" = [">100"]))]
     pub citations: Option<String>,
-    /// Filter by country code of author institutions (e.g. "US", "GB")
+    /// Filter by country code of author institutions (e.g. "US", "GB", or a name like "germany")
     pub country: Option<String>,
-    /// Filter by continent of author institutions (e.g. "europe", "asia")
+    /// Filter by continent of author institutions (e.g. "europe", "north america")
     pub continent: Option<String>,
     /// Filter by work type (e.g. "article", "preprint", "dataset")
     pub r#type: Option<String>,
     /// Filter for open access works only. Set to true to include only OA works.
     pub open: Option<bool>,
+    /// Filter by work language: ISO 639-1 code or name (e.g. "en", "de", "german", "de|fr")
+    pub language: Option<String>,
+    /// Set to true to drop retracted works (`is_retracted:false`).
+    pub exclude_retracted: Option<bool>,
+    /// Set to true to drop paratext such as covers, tables of contents, and front matter
+    /// (`is_paratext:false`).
+    pub exclude_paratext: Option<bool>,
+    /// UN Sustainable Development Goal by number or name (e.g. "3", "SDG 13", "climate action").
+    /// Pipe-separated for OR, `!` to negate.
+    pub sdg: Option<String>,
 }
 
 impl WorkListToolParams {
@@ -124,6 +298,10 @@ impl WorkListToolParams {
             continent: self.continent.clone(),
             r#type: self.r#type.clone(),
             open: self.open,
+            language: self.language.clone(),
+            exclude_retracted: self.exclude_retracted,
+            exclude_paratext: self.exclude_paratext,
+            sdg: self.sdg.clone(),
         }
     }
 }
@@ -132,30 +310,38 @@ impl WorkListToolParams {
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct AuthorListToolParams {
     /// Filter expression. Comma-separated AND conditions, pipe (`|`) for OR.
+    #[schemars(extend("examples" = ["works_count:>100"]))]
     pub filter: Option<String>,
     /// Sort field with optional `:desc` suffix.
+    #[schemars(extend("examples" = ["cited_by_count:desc"]))]
     pub sort: Option<String>,
     /// Results per page (1-200, default 25).
+    #[schemars(range(min = 1, max = 200))]
     pub per_page: Option<u32>,
     /// Page number for offset pagination.
+    #[schemars(range(min = 1))]
     pub page: Option<u32>,
     /// Cursor for cursor-based pagination.
+    #[schemars(extend("examples" = ["*"]))]
     pub cursor: Option<String>,
     /// Return a random sample of this many results.
+    #[schemars(range(min = 1))]
     pub sample: Option<u32>,
     /// Seed for reproducible random sampling.
     pub seed: Option<u32>,
     /// Comma-separated list of fields to include.
+    #[schemars(extend("examples" = ["id,display_name,works_count"]))]
     pub select: Option<String>,
     /// Aggregate results by a field.
     pub group_by: Option<String>,
     /// Filter by institution name or ID (e.g. "harvard", "mit", or "I136199984")
     pub institution: Option<String>,
-    /// Filter by country code of last known institution (e.g. "US", "GB")
+    /// Filter by country code of last known institution (e.g. "US", "GB", or a name like "germany")
     pub country: Option<String>,
-    /// Filter by continent of last known institution (e.g. "europe", "asia")
+    /// Filter by continent of last known institution (e.g. "europe", "north america")
     pub continent: Option<String>,
     /// Filter by citation count (e.g. ">1000", "100-500")
+    #[schemars(extend("examples" = [">100"]))]
     pub citations: Option<String>,
     /// Filter by works count (e.g. ">500", "100-200")
     pub works: Option<String>,
@@ -193,34 +379,42 @@ impl AuthorListToolParams {
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct SourceListToolParams {
     /// Filter expression.
+    #[schemars(extend("examples" = ["works_count:>100"]))]
     pub filter: Option<String>,
     /// Sort field with optional `:desc` suffix.
+    #[schemars(extend("examples" = ["cited_by_count:desc"]))]
     pub sort: Option<String>,
     /// Results per page (1-200, default 25).
+    #[schemars(range(min = 1, max = 200))]
     pub per_page: Option<u32>,
     /// Page number for offset pagination.
+    #[schemars(range(min = 1))]
     pub page: Option<u32>,
     /// Cursor for cursor-based pagination.
+    #[schemars(extend("examples" = ["*"]))]
     pub cursor: Option<String>,
     /// Return a random sample of this many results.
+    #[schemars(range(min = 1))]
     pub sample: Option<u32>,
     /// Seed for reproducible random sampling.
     pub seed: Option<u32>,
     /// Comma-separated list of fields to include.
+    #[schemars(extend("examples" = ["id,display_name,works_count"]))]
     pub select: Option<String>,
     /// Aggregate results by a field.
     pub group_by: Option<String>,
     /// Filter by publisher name or ID (e.g. "springer", "P4310319798")
     pub publisher: Option<String>,
-    /// Filter by country code (e.g. "US", "GB")
+    /// Filter by country code (e.g. "US", "GB", or a name like "germany")
     pub country: Option<String>,
-    /// Filter by continent (e.g. "europe")
+    /// Filter by continent (e.g. "europe", "north america")
     pub continent: Option<String>,
     /// Filter by source type (e.g. "journal", "repository", "conference")
     pub r#type: Option<String>,
     /// Filter for open access sources only.
     pub open: Option<bool>,
     /// Filter by citation count (e.g. ">10000")
+    #[schemars(extend("examples" = [">100"]))]
     pub citations: Option<String>,
     /// Filter by works count (e.g. ">100000")
     pub works: Option<String>,
@@ -254,30 +448,38 @@ impl SourceListToolParams {
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct InstitutionListToolParams {
     /// Filter expression.
+    #[schemars(extend("examples" = ["works_count:>100"]))]
     pub filter: Option<String>,
     /// Sort field with optional `:desc` suffix.
+    #[schemars(extend("examples" = ["cited_by_count:desc"]))]
     pub sort: Option<String>,
     /// Results per page (1-200, default 25).
+    #[schemars(range(min = 1, max = 200))]
     pub per_page: Option<u32>,
     /// Page number for offset pagination.
+    #[schemars(range(min = 1))]
     pub page: Option<u32>,
     /// Cursor for cursor-based pagination.
+    #[schemars(extend("examples" = ["*"]))]
     pub cursor: Option<String>,
     /// Return a random sample of this many results.
+    #[schemars(range(min = 1))]
     pub sample: Option<u32>,
     /// Seed for reproducible random sampling.
     pub seed: Option<u32>,
     /// Comma-separated list of fields to include.
+    #[schemars(extend("examples" = ["id,display_name,works_count"]))]
     pub select: Option<String>,
     /// Aggregate results by a field.
     pub group_by: Option<String>,
-    /// Filter by country code (e.g. "US", "GB")
+    /// Filter by country code (e.g. "US", "GB", or a name like "germany")
     pub country: Option<String>,
-    /// Filter by continent (e.g. "europe", "asia")
+    /// Filter by continent (e.g. "europe", "north america")
     pub continent: Option<String>,
     /// Filter by institution type (e.g. "education", "healthcare", "company")
     pub r#type: Option<String>,
     /// Filter by citation count (e.g. ">100000")
+    #[schemars(extend("examples" = [">100"]))]
     pub citations: Option<String>,
     /// Filter by works count (e.g. ">100000")
     pub works: Option<String>,
@@ -309,20 +511,27 @@ impl InstitutionListToolParams {
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct TopicListToolParams {
     /// Filter expression.
+    #[schemars(extend("examples" = ["works_count:>100"]))]
     pub filter: Option<String>,
     /// Sort field with optional `:desc` suffix.
+    #[schemars(extend("examples" = ["cited_by_count:desc"]))]
     pub sort: Option<String>,
     /// Results per page (1-200, default 25).
+    #[schemars(range(min = 1, max = 200))]
     pub per_page: Option<u32>,
     /// Page number for offset pagination.
+    #[schemars(range(min = 1))]
     pub page: Option<u32>,
     /// Cursor for cursor-based pagination.
+    #[schemars(extend("examples" = ["*"]))]
     pub cursor: Option<String>,
     /// Return a random sample of this many results.
+    #[schemars(range(min = 1))]
     pub sample: Option<u32>,
     /// Seed for reproducible random sampling.
     pub seed: Option<u32>,
     /// Comma-separated list of fields to include.
+    #[schemars(extend("examples" = ["id,display_name,works_count"]))]
     pub select: Option<String>,
     /// Aggregate results by a field.
     pub group_by: Option<String>,
@@ -333,6 +542,7 @@ pub struct TopicListToolParams {
     /// Filter by subfield name or ID (e.g. "artificial intelligence", "1702")
     pub subfield: Option<String>,
     /// Filter by citation count (e.g. ">1000")
+    #[schemars(extend("examples" = [">100"]))]
     pub citations: Option<String>,
     /// Filter by works count (e.g. ">1000")
     pub works: Option<String>,
@@ -364,28 +574,36 @@ impl TopicListToolParams {
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct PublisherListToolParams {
     /// Filter expression.
+    #[schemars(extend("examples" = ["works_count:>100"]))]
     pub filter: Option<String>,
     /// Sort field with optional `:desc` suffix.
+    #[schemars(extend("examples" = ["cited_by_count:desc"]))]
     pub sort: Option<String>,
     /// Results per page (1-200, default 25).
+    #[schemars(range(min = 1, max = 200))]
     pub per_page: Option<u32>,
     /// Page number for offset pagination.
+    #[schemars(range(min = 1))]
     pub page: Option<u32>,
     /// Cursor for cursor-based pagination.
+    #[schemars(extend("examples" = ["*"]))]
     pub cursor: Option<String>,
     /// Return a random sample of this many results.
+    #[schemars(range(min = 1))]
     pub sample: Option<u32>,
     /// Seed for reproducible random sampling.
     pub seed: Option<u32>,
     /// Comma-separated list of fields to include.
+    #[schemars(extend("examples" = ["id,display_name,works_count"]))]
     pub select: Option<String>,
     /// Aggregate results by a field.
     pub group_by: Option<String>,
-    /// Filter by country code (e.g. "US", "GB"). Note: uses `country_codes` (plural).
+    /// Filter by country code (e.g. "US", "GB", or a name like "germany"). Note: uses `country_codes` (plural).
     pub country: Option<String>,
-    /// Filter by continent (e.g. "europe")
+    /// Filter by continent (e.g. "europe", "north america")
     pub continent: Option<String>,
     /// Filter by citation count (e.g. ">10000")
+    #[schemars(extend("examples" = [">100"]))]
     pub citations: Option<String>,
     /// Filter by works count (e.g. ">1000000")
     pub works: Option<String>,
@@ -416,28 +634,36 @@ impl PublisherListToolParams {
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct FunderListToolParams {
     /// Filter expression.
+    #[schemars(extend("examples" = ["works_count:>100"]))]
     pub filter: Option<String>,
     /// Sort field with optional `:desc` suffix.
+    #[schemars(extend("examples" = ["cited_by_count:desc"]))]
     pub sort: Option<String>,
     /// Results per page (1-200, default 25).
+    #[schemars(range(min = 1, max = 200))]
     pub per_page: Option<u32>,
     /// Page number for offset pagination.
+    #[schemars(range(min = 1))]
     pub page: Option<u32>,
     /// Cursor for cursor-based pagination.
+    #[schemars(extend("examples" = ["*"]))]
     pub cursor: Option<String>,
     /// Return a random sample of this many results.
+    #[schemars(range(min = 1))]
     pub sample: Option<u32>,
     /// Seed for reproducible random sampling.
     pub seed: Option<u32>,
     /// Comma-separated list of fields to include.
+    #[schemars(extend("examples" = ["id,display_name,works_count"]))]
     pub select: Option<String>,
     /// Aggregate results by a field.
     pub group_by: Option<String>,
-    /// Filter by country code (e.g. "US", "GB")
+    /// Filter by country code (e.g. "US", "GB", or a name like "germany")
     pub country: Option<String>,
-    /// Filter by continent (e.g. "europe")
+    /// Filter by continent (e.g. "europe", "north america")
     pub continent: Option<String>,
     /// Filter by citation count (e.g. ">10000")
+    #[schemars(extend("examples" = [">100"]))]
     pub citations: Option<String>,
     /// Filter by works count (e.g. ">100000")
     pub works: Option<String>,
@@ -468,20 +694,27 @@ impl FunderListToolParams {
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct DomainListToolParams {
     /// Filter expression.
+    #[schemars(extend("examples" = ["works_count:>100"]))]
     pub filter: Option<String>,
     /// Sort field with optional `:desc` suffix.
+    #[schemars(extend("examples" = ["cited_by_count:desc"]))]
     pub sort: Option<String>,
     /// Results per page (1-200, default 25).
+    #[schemars(range(min = 1, max = 200))]
     pub per_page: Option<u32>,
     /// Page number for offset pagination.
+    #[schemars(range(min = 1))]
     pub page: Option<u32>,
     /// Cursor for cursor-based pagination.
+    #[schemars(extend("examples" = ["*"]))]
     pub cursor: Option<String>,
     /// Return a random sample of this many results.
+    #[schemars(range(min = 1))]
     pub sample: Option<u32>,
     /// Seed for reproducible random sampling.
     pub seed: Option<u32>,
     /// Comma-separated list of fields to include.
+    #[schemars(extend("examples" = ["id,display_name,works_count"]))]
     pub select: Option<String>,
     /// Aggregate results by a field.
     pub group_by: Option<String>,
@@ -511,20 +744,27 @@ impl DomainListToolParams {
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct FieldListToolParams {
     /// Filter expression.
+    #[schemars(extend("examples" = ["works_count:>100"]))]
     pub filter: Option<String>,
     /// Sort field with optional `:desc` suffix.
+    #[schemars(extend("examples" = ["cited_by_count:desc"]))]
     pub sort: Option<String>,
     /// Results per page (1-200, default 25).
+    #[schemars(range(min = 1, max = 200))]
     pub per_page: Option<u32>,
     /// Page number for offset pagination.
+    #[schemars(range(min = 1))]
     pub page: Option<u32>,
     /// Cursor for cursor-based pagination.
+    #[schemars(extend("examples" = ["*"]))]
     pub cursor: Option<String>,
     /// Return a random sample of this many results.
+    #[schemars(range(min = 1))]
     pub sample: Option<u32>,
     /// Seed for reproducible random sampling.
     pub seed: Option<u32>,
     /// Comma-separated list of fields to include.
+    #[schemars(extend("examples" = ["id,display_name,works_count"]))]
     pub select: Option<String>,
     /// Aggregate results by a field.
     pub group_by: Option<String>,
@@ -557,20 +797,27 @@ impl FieldListToolParams {
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct SubfieldListToolParams {
     /// Filter expression.
+    #[schemars(extend("examples" = ["works_count:>100"]))]
     pub filter: Option<String>,
     /// Sort field with optional `:desc` suffix.
+    #[schemars(extend("examples" = ["cited_by_count:desc"]))]
     pub sort: Option<String>,
     /// Results per page (1-200, default 25).
+    #[schemars(range(min = 1, max = 200))]
     pub per_page: Option<u32>,
     /// Page number for offset pagination.
+    #[schemars(range(min = 1))]
     pub page: Option<u32>,
     /// Cursor for cursor-based pagination.
+    #[schemars(extend("examples" = ["*"]))]
     pub cursor: Option<String>,
     /// Return a random sample of this many results.
+    #[schemars(range(min = 1))]
     pub sample: Option<u32>,
     /// Seed for reproducible random sampling.
     pub seed: Option<u32>,
     /// Comma-separated list of fields to include.
+    #[schemars(extend("examples" = ["id,display_name,works_count"]))]
     pub select: Option<String>,
     /// Aggregate results by a field.
     pub group_by: Option<String>,
@@ -608,20 +855,27 @@ pub struct WorkSearchToolParams {
     /// Full-text search query. Searches title, abstract, and fulltext.
     pub query: String,
     /// Filter expression. Comma-separated AND conditions, pipe (`|`) for OR.
+    #[schemars(extend("examples" = ["publication_year:2024,is_oa:true"]))]
     pub filter: Option<String>,
     /// Sort field with optional `:desc` suffix. Example: `"cited_by_count:desc"`
+    #[schemars(extend("examples" = ["cited_by_count:desc"]))]
     pub sort: Option<String>,
     /// Results per page (1-200, default 25).
+    #[schemars(range(min = 1, max = 200))]
     pub per_page: Option<u32>,
     /// Page number for offset pagination (max page * per_page <= 10,000).
+    #[schemars(range(min = 1))]
     pub page: Option<u32>,
     /// Cursor for cursor-based pagination. Use `"*"` for the first page.
+    #[schemars(extend("examples" = ["*"]))]
     pub cursor: Option<String>,
     /// Return a random sample of this many results.
+    #[schemars(range(min = 1))]
     pub sample: Option<u32>,
     /// Seed for reproducible random sampling.
     pub seed: Option<u32>,
     /// Comma-separated list of fields to include in the response.
+    #[schemars(extend("examples" = ["id,display_name,publication_year,cited_by_count"]))]
     pub select: Option<String>,
     /// Filter by author name or OpenAlex author ID (e.g. "einstein", "Albert Einstein", or "A5108093963")
     pub author: Option<String>,
@@ -640,17 +894,29 @@ pub struct WorkSearchToolParams {
     /// Filter by institution name or ID (e.g. "mit" or "I136199984")
     pub institution: Option<String>,
     /// Filter by publication year (e.g. "2024", ">2008", "2008-2024")
+    #[schemars(extend("examples" = [">2020"]))]
     pub year: Option<String>,
     /// Filter by citation count (e.g. ">100", "10-50")
+    #[schemars(extend("examples" = [">100"]))]
     pub citations: Option<String>,
-    /// Filter by country code of author institutions (e.g. "US", "GB")
+    /// Filter by country code of author institutions (e.g. "US", "GB", or a name like "germany")
     pub country: Option<String>,
-    /// Filter by continent of author institutions (e.g. "europe", "asia")
+    /// Filter by continent of author institutions (e.g. "europe", "north america")
     pub continent: Option<String>,
     /// Filter by work type (e.g. "article", "preprint", "dataset")
     pub r#type: Option<String>,
     /// Filter for open access works only.
     pub open: Option<bool>,
+    /// Filter by work language: ISO 639-1 code or name (e.g. "en", "de", "german", "de|fr")
+    pub language: Option<String>,
+    /// Set to true to drop retracted works (`is_retracted:false`).
+    pub exclude_retracted: Option<bool>,
+    /// Set to true to drop paratext such as covers, tables of contents, and front matter
+    /// (`is_paratext:false`).
+    pub exclude_paratext: Option<bool>,
+    /// UN Sustainable Development Goal by number or name (e.g. "3", "SDG 13", "climate action").
+    /// Pipe-separated for OR, `!` to negate.
+    pub sdg: Option<String>,
 }
 
 impl WorkSearchToolParams {
@@ -680,6 +946,10 @@ impl WorkSearchToolParams {
             continent: self.continent.clone(),
             r#type: self.r#type.clone(),
             open: self.open,
+            language: self.language.clone(),
+            exclude_retracted: self.exclude_retracted,
+            exclude_paratext: self.exclude_paratext,
+            sdg: self.sdg.clone(),
         }
     }
 }
@@ -690,28 +960,36 @@ pub struct AuthorSearchToolParams {
     /// Full-text search query.
     pub query: String,
     /// Filter expression. Comma-separated AND conditions, pipe (`|`) for OR.
+    #[schemars(extend("examples" = ["works_count:>100"]))]
     pub filter: Option<String>,
     /// Sort field with optional `:desc` suffix.
+    #[schemars(extend("examples" = ["cited_by_count:desc"]))]
     pub sort: Option<String>,
     /// Results per page (1-200, default 25).
+    #[schemars(range(min = 1, max = 200))]
     pub per_page: Option<u32>,
     /// Page number for offset pagination.
+    #[schemars(range(min = 1))]
     pub page: Option<u32>,
     /// Cursor for cursor-based pagination.
+    #[schemars(extend("examples" = ["*"]))]
     pub cursor: Option<String>,
     /// Return a random sample of this many results.
+    #[schemars(range(min = 1))]
     pub sample: Option<u32>,
     /// Seed for reproducible random sampling.
     pub seed: Option<u32>,
     /// Comma-separated list of fields to include.
+    #[schemars(extend("examples" = ["id,display_name,works_count"]))]
     pub select: Option<String>,
     /// Filter by institution name or ID (e.g. "harvard", "mit", or "I136199984")
     pub institution: Option<String>,
-    /// Filter by country code of last known institution (e.g. "US", "GB")
+    /// Filter by country code of last known institution (e.g. "US", "GB", or a name like "germany")
     pub country: Option<String>,
-    /// Filter by continent of last known institution (e.g. "europe", "asia")
+    /// Filter by continent of last known institution (e.g. "europe", "north america")
     pub continent: Option<String>,
     /// Filter by citation count (e.g. ">1000", "100-500")
+    #[schemars(extend("examples" = [">100"]))]
     pub citations: Option<String>,
     /// Filter by works count (e.g. ">500", "100-200")
     pub works: Option<String>,
@@ -748,32 +1026,40 @@ pub struct SourceSearchToolParams {
     /// Full-text search query.
     pub query: String,
     /// Filter expression.
+    #[schemars(extend("examples" = ["works_count:>100"]))]
     pub filter: Option<String>,
     /// Sort field with optional `:desc` suffix.
+    #[schemars(extend("examples" = ["cited_by_count:desc"]))]
     pub sort: Option<String>,
     /// Results per page (1-200, default 25).
+    #[schemars(range(min = 1, max = 200))]
     pub per_page: Option<u32>,
     /// Page number for offset pagination.
+    #[schemars(range(min = 1))]
     pub page: Option<u32>,
     /// Cursor for cursor-based pagination.
+    #[schemars(extend("examples" = ["*"]))]
     pub cursor: Option<String>,
     /// Return a random sample of this many results.
+    #[schemars(range(min = 1))]
     pub sample: Option<u32>,
     /// Seed for reproducible random sampling.
     pub seed: Option<u32>,
     /// Comma-separated list of fields to include.
+    #[schemars(extend("examples" = ["id,display_name,works_count"]))]
     pub select: Option<String>,
     /// Filter by publisher name or ID (e.g. "springer", "P4310319798")
     pub publisher: Option<String>,
-    /// Filter by country code (e.g. "US", "GB")
+    /// Filter by country code (e.g. "US", "GB", or a name like "germany")
     pub country: Option<String>,
-    /// Filter by continent (e.g. "europe")
+    /// Filter by continent (e.g. "europe", "north america")
     pub continent: Option<String>,
     /// Filter by source type (e.g. "journal", "repository", "conference")
     pub r#type: Option<String>,
     /// Filter for open access sources only.
     pub open: Option<bool>,
     /// Filter by citation count (e.g. ">10000")
+    #[schemars(extend("examples" = [">100"]))]
     pub citations: Option<String>,
     /// Filter by works count (e.g. ">100000")
     pub works: Option<String>,
@@ -809,28 +1095,36 @@ pub struct InstitutionSearchToolParams {
     /// Full-text search query.
     pub query: String,
     /// Filter expression.
+    #[schemars(extend("examples" = ["works_count:>100"]))]
     pub filter: Option<String>,
     /// Sort field with optional `:desc` suffix.
+    #[schemars(extend("examples" = ["cited_by_count:desc"]))]
     pub sort: Option<String>,
     /// Results per page (1-200, default 25).
+    #[schemars(range(min = 1, max = 200))]
     pub per_page: Option<u32>,
     /// Page number for offset pagination.
+    #[schemars(range(min = 1))]
     pub page: Option<u32>,
     /// Cursor for cursor-based pagination.
+    #[schemars(extend("examples" = ["*"]))]
     pub cursor: Option<String>,
     /// Return a random sample of this many results.
+    #[schemars(range(min = 1))]
     pub sample: Option<u32>,
     /// Seed for reproducible random sampling.
     pub seed: Option<u32>,
     /// Comma-separated list of fields to include.
+    #[schemars(extend("examples" = ["id,display_name,works_count"]))]
     pub select: Option<String>,
-    /// Filter by country code (e.g. "US", "GB")
+    /// Filter by country code (e.g. "US", "GB", or a name like "germany")
     pub country: Option<String>,
-    /// Filter by continent (e.g. "europe", "asia")
+    /// Filter by continent (e.g. "europe", "north america")
     pub continent: Option<String>,
     /// Filter by institution type (e.g. "education", "healthcare", "company")
     pub r#type: Option<String>,
     /// Filter by citation count (e.g. ">100000")
+    #[schemars(extend("examples" = [">100"]))]
     pub citations: Option<String>,
     /// Filter by works count (e.g. ">100000")
     pub works: Option<String>,
@@ -864,20 +1158,27 @@ pub struct TopicSearchToolParams {
     /// Full-text search query.
     pub query: String,
     /// Filter expression.
+    #[schemars(extend("examples" = ["works_count:>100"]))]
     pub filter: Option<String>,
     /// Sort field with optional `:desc` suffix.
+    #[schemars(extend("examples" = ["cited_by_count:desc"]))]
     pub sort: Option<String>,
     /// Results per page (1-200, default 25).
+    #[schemars(range(min = 1, max = 200))]
     pub per_page: Option<u32>,
     /// Page number for offset pagination.
+    #[schemars(range(min = 1))]
     pub page: Option<u32>,
     /// Cursor for cursor-based pagination.
+    #[schemars(extend("examples" = ["*"]))]
     pub cursor: Option<String>,
     /// Return a random sample of this many results.
+    #[schemars(range(min = 1))]
     pub sample: Option<u32>,
     /// Seed for reproducible random sampling.
     pub seed: Option<u32>,
     /// Comma-separated list of fields to include.
+    #[schemars(extend("examples" = ["id,display_name,works_count"]))]
     pub select: Option<String>,
     /// Filter by domain name or ID (e.g. "life sciences", "3")
     pub domain: Option<String>,
@@ -886,6 +1187,7 @@ pub struct TopicSearchToolParams {
     /// Filter by subfield name or ID (e.g. "artificial intelligence", "1702")
     pub subfield: Option<String>,
     /// Filter by citation count (e.g. ">1000")
+    #[schemars(extend("examples" = [">100"]))]
     pub citations: Option<String>,
     /// Filter by works count (e.g. ">1000")
     pub works: Option<String>,
@@ -919,26 +1221,34 @@ pub struct PublisherSearchToolParams {
     /// Full-text search query.
     pub query: String,
     /// Filter expression.
+    #[schemars(extend("examples" = ["works_count:>100"]))]
     pub filter: Option<String>,
     /// Sort field with optional `:desc` suffix.
+    #[schemars(extend("examples" = ["cited_by_count:desc"]))]
     pub sort: Option<String>,
     /// Results per page (1-200, default 25).
+    #[schemars(range(min = 1, max = 200))]
     pub per_page: Option<u32>,
     /// Page number for offset pagination.
+    #[schemars(range(min = 1))]
     pub page: Option<u32>,
     /// Cursor for cursor-based pagination.
+    #[schemars(extend("examples" = ["*"]))]
     pub cursor: Option<String>,
     /// Return a random sample of this many results.
+    #[schemars(range(min = 1))]
     pub sample: Option<u32>,
     /// Seed for reproducible random sampling.
     pub seed: Option<u32>,
     /// Comma-separated list of fields to include.
+    #[schemars(extend("examples" = ["id,display_name,works_count"]))]
     pub select: Option<String>,
-    /// Filter by country code (e.g. "US", "GB")
+    /// Filter by country code (e.g. "US", "GB", or a name like "germany")
     pub country: Option<String>,
-    /// Filter by continent (e.g. "europe")
+    /// Filter by continent (e.g. "europe", "north america")
     pub continent: Option<String>,
     /// Filter by citation count (e.g. ">10000")
+    #[schemars(extend("examples" = [">100"]))]
     pub citations: Option<String>,
     /// Filter by works count (e.g. ">1000000")
     pub works: Option<String>,
@@ -971,26 +1281,34 @@ pub struct FunderSearchToolParams {
     /// Full-text search query.
     pub query: String,
     /// Filter expression.
+    #[schemars(extend("examples" = ["works_count:>100"]))]
     pub filter: Option<String>,
     /// Sort field with optional `:desc` suffix.
+    #[schemars(extend("examples" = ["cited_by_count:desc"]))]
     pub sort: Option<String>,
     /// Results per page (1-200, default 25).
+    #[schemars(range(min = 1, max = 200))]
     pub per_page: Option<u32>,
     /// Page number for offset pagination.
+    #[schemars(range(min = 1))]
     pub page: Option<u32>,
     /// Cursor for cursor-based pagination.
+    #[schemars(extend("examples" = ["*"]))]
     pub cursor: Option<String>,
     /// Return a random sample of this many results.
+    #[schemars(range(min = 1))]
     pub sample: Option<u32>,
     /// Seed for reproducible random sampling.
     pub seed: Option<u32>,
     /// Comma-separated list of fields to include.
+    #[schemars(extend("examples" = ["id,display_name,works_count"]))]
     pub select: Option<String>,
-    /// Filter by country code (e.g. "US", "GB")
+    /// Filter by country code (e.g. "US", "GB", or a name like "germany")
     pub country: Option<String>,
-    /// Filter by continent (e.g. "europe")
+    /// Filter by continent (e.g. "europe", "north america")
     pub continent: Option<String>,
     /// Filter by citation count (e.g. ">10000")
+    #[schemars(extend("examples" = [">100"]))]
     pub citations: Option<String>,
     /// Filter by works count (e.g. ">100000")
     pub works: Option<String>,
@@ -1023,20 +1341,27 @@ pub struct DomainSearchToolParams {
     /// Full-text search query.
     pub query: String,
     /// Filter expression.
+    #[schemars(extend("examples" = ["works_count:>100"]))]
     pub filter: Option<String>,
     /// Sort field with optional `:desc` suffix.
+    #[schemars(extend("examples" = ["cited_by_count:desc"]))]
     pub sort: Option<String>,
     /// Results per page (1-200, default 25).
+    #[schemars(range(min = 1, max = 200))]
     pub per_page: Option<u32>,
     /// Page number for offset pagination.
+    #[schemars(range(min = 1))]
     pub page: Option<u32>,
     /// Cursor for cursor-based pagination.
+    #[schemars(extend("examples" = ["*"]))]
     pub cursor: Option<String>,
     /// Return a random sample of this many results.
+    #[schemars(range(min = 1))]
     pub sample: Option<u32>,
     /// Seed for reproducible random sampling.
     pub seed: Option<u32>,
     /// Comma-separated list of fields to include.
+    #[schemars(extend("examples" = ["id,display_name,works_count"]))]
     pub select: Option<String>,
     /// Filter by works count (e.g. ">100000000")
     pub works: Option<String>,
@@ -1066,20 +1391,27 @@ pub struct FieldSearchToolParams {
     /// Full-text search query.
     pub query: String,
     /// Filter expression.
+    #[schemars(extend("examples" = ["works_count:>100"]))]
     pub filter: Option<String>,
     /// Sort field with optional `:desc` suffix.
+    #[schemars(extend("examples" = ["cited_by_count:desc"]))]
     pub sort: Option<String>,
     /// Results per page (1-200, default 25).
+    #[schemars(range(min = 1, max = 200))]
     pub per_page: Option<u32>,
     /// Page number for offset pagination.
+    #[schemars(range(min = 1))]
     pub page: Option<u32>,
     /// Cursor for cursor-based pagination.
+    #[schemars(extend("examples" = ["*"]))]
     pub cursor: Option<String>,
     /// Return a random sample of this many results.
+    #[schemars(range(min = 1))]
     pub sample: Option<u32>,
     /// Seed for reproducible random sampling.
     pub seed: Option<u32>,
     /// Comma-separated list of fields to include.
+    #[schemars(extend("examples" = ["id,display_name,works_count"]))]
     pub select: Option<String>,
     /// Filter by domain name or ID (e.g. "life sciences", "3")
     pub domain: Option<String>,
@@ -1112,20 +1444,27 @@ pub struct SubfieldSearchToolParams {
     /// Full-text search query.
     pub query: String,
     /// Filter expression.
+    #[schemars(extend("examples" = ["works_count:>100"]))]
     pub filter: Option<String>,
     /// Sort field with optional `:desc` suffix.
+    #[schemars(extend("examples" = ["cited_by_count:desc"]))]
     pub sort: Option<String>,
     /// Results per page (1-200, default 25).
+    #[schemars(range(min = 1, max = 200))]
     pub per_page: Option<u32>,
     /// Page number for offset pagination.
+    #[schemars(range(min = 1))]
     pub page: Option<u32>,
     /// Cursor for cursor-based pagination.
+    #[schemars(extend("examples" = ["*"]))]
     pub cursor: Option<String>,
     /// Return a random sample of this many results.
+    #[schemars(range(min = 1))]
     pub sample: Option<u32>,
     /// Seed for reproducible random sampling.
     pub seed: Option<u32>,
     /// Comma-separated list of fields to include.
+    #[schemars(extend("examples" = ["id,display_name,works_count"]))]
     pub select: Option<String>,
     /// Filter by domain name or ID (e.g. "physical sciences", "3")
     pub domain: Option<String>,
@@ -1159,6 +1498,7 @@ impl SubfieldSearchToolParams {
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct WorkTextToolParams {
     /// Work identifier: OpenAlex ID (W...), DOI, PMID, or PMCID.
+    #[schemars(extend("examples" = ["W2741809807"]))]
     pub id: String,
     /// Use DataLab Marker API for extraction instead of local pdfium.
     /// Requires `DATALAB_API_KEY` env var. Quality levels:
@@ -1166,7 +1506,35 @@ pub struct WorkTextToolParams {
     /// - `"balanced"` — good quality/speed trade-off (DataLab default)
     /// - `"accurate"` — highest quality markdown with full layout reconstruction
     /// Omit to use local pdfium extraction.
-    pub advanced: Option<String>,
+    #[serde(default, deserialize_with = "lax_optional_enum")]
+    pub advanced: Option<ExtractionQuality>,
+    /// Character offset to start reading from (default 0). Use `next_offset` from the
+    /// previous response, or a section's `offset`, to continue.
+    pub offset: Option<usize>,
+    /// Maximum characters to return (default 40000).
+    #[schemars(range(min = 1))]
+    pub max_chars: Option<usize>,
+}
+
+/// Parameters for the `work_references_extracted` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct WorkReferencesExtractedToolParams {
+    /// Work identifier: OpenAlex ID (W...), DOI, PMID, or PMCID.
+    #[schemars(extend("examples" = ["W2741809807"]))]
+    pub id: String,
+    /// Look each reference up in OpenAlex by DOI or title (default true). Set false for a
+    /// fast parse-only result.
+    pub resolve: Option<bool>,
+}
+
+/// Parameters for the `work_extract_identifiers` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct WorkExtractIdentifiersToolParams {
+    /// Free text to scan: an email, a pasted reference list, notes.
+    pub text: String,
+    /// Drop candidates below this confidence (0–1, default 0).
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub min_confidence: Option<f64>,
 }
 
 /// Parameters for single-entity GET endpoints.
@@ -1174,8 +1542,10 @@ pub struct WorkTextToolParams {
 pub struct GetToolParams {
     /// Entity ID. Accepts OpenAlex IDs (e.g. `W2741809807`), DOIs, ORCIDs,
     /// ROR IDs, ISSNs, PMIDs, etc.
+    #[schemars(extend("examples" = ["W2741809807"]))]
     pub id: String,
     /// Comma-separated list of fields to include in the response.
+    #[schemars(extend("examples" = ["id,display_name"]))]
     pub select: Option<String>,
 }
 
@@ -1187,10 +1557,22 @@ impl GetToolParams {
     }
 }
 
+/// Parameters for the `work_enrich` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct WorkEnrichToolParams {
+    /// Work ID: OpenAlex ID, DOI, PMID, or PMCID.
+    #[schemars(extend("examples" = ["W2741809807", "10.48550/arXiv.1706.03762"]))]
+    pub id: String,
+    /// Number of Semantic Scholar recommendations to include (default 5; 0 to skip).
+    #[schemars(range(min = 0, max = 20))]
+    pub recommendations: Option<u32>,
+}
+
 /// Parameters for autocomplete endpoints.
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct AutocompleteToolParams {
     /// Search query for type-ahead matching.
+    #[schemars(extend("examples" = ["einst"]))]
     pub q: String,
 }
 
@@ -1201,8 +1583,10 @@ pub struct FindWorksToolParams {
     /// question. Maximum 10,000 characters.
     pub query: String,
     /// Number of results to return (1-100, default 25).
+    #[schemars(range(min = 1, max = 100))]
     pub count: Option<u32>,
     /// Filter expression to constrain results (same syntax as list endpoints).
+    #[schemars(extend("examples" = ["publication_year:2024,is_oa:true"]))]
     pub filter: Option<String>,
 }
 
@@ -1216,6 +1600,398 @@ impl FindWorksToolParams {
     }
 }
 
+/// Parameters for `author_profile`.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct AuthorProfileToolParams {
+    /// Author: OpenAlex ID (e.g. `A5023888391`), ORCID, or name.
+    #[schemars(extend("examples" = ["A5023888391"]))]
+    pub id: String,
+    /// Number of most-cited works to include (1-50, default 5).
+    #[schemars(range(min = 1, max = 50))]
+    pub top_works: Option<u32>,
+    /// Number of most frequent co-authors to include (default 10).
+    #[schemars(range(min = 1))]
+    pub top_coauthors: Option<usize>,
+    /// Number of topics to include in the distribution (default 10).
+    pub topics: Option<usize>,
+}
+
+impl AuthorProfileToolParams {
+    pub fn into_profile_params(&self) -> papers_core::api::AuthorProfileParams {
+        let defaults = papers_core::api::AuthorProfileParams::default();
+        papers_core::api::AuthorProfileParams {
+            top_works: self.top_works.unwrap_or(defaults.top_works),
+            top_coauthors: self.top_coauthors.unwrap_or(defaults.top_coauthors),
+            topics: self.topics.unwrap_or(defaults.topics),
+        }
+    }
+}
+
+/// Parameters for `author_affiliation_history`.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct AuthorAffiliationHistoryToolParams {
+    /// Author: OpenAlex ID (e.g. `A5023888391`), ORCID, or name.
+    #[schemars(extend("examples" = ["A5023888391"]))]
+    pub id: String,
+    /// Optional year; `at_year` lists the institutions the author was at that year.
+    pub year: Option<i32>,
+}
+
+/// Parameters for `author_resolve`.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct AuthorResolveToolParams {
+    /// Author name as written, e.g. "Wei Wang" or "Y. LeCun".
+    pub name: String,
+    /// Institution the author is or was at, by name or acronym (e.g. "MIT").
+    pub affiliation: Option<String>,
+    /// Topic, subfield, or field the author works on (e.g. "machine learning").
+    pub topic: Option<String>,
+    /// Name of someone the author has published with.
+    pub coauthor: Option<String>,
+    /// Namesakes fetched and scored (1-25, default 10).
+    #[schemars(range(min = 1, max = 25))]
+    pub candidates: Option<u32>,
+}
+
+impl AuthorResolveToolParams {
+    pub fn into_resolve_params(&self) -> papers_core::api::AuthorResolveParams {
+        papers_core::api::AuthorResolveParams {
+            hints: papers_core::disambiguation::ResolveHints {
+                affiliation: self.affiliation.clone(),
+                topic: self.topic.clone(),
+                coauthor: self.coauthor.clone(),
+            },
+            candidates: self.candidates.unwrap_or(papers_core::api::AuthorResolveParams::default().candidates),
+        }
+    }
+}
+
+/// Parameters for `work_quality`.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct WorkQualityToolParams {
+    /// Work: OpenAlex ID (e.g. `W2741809807`), DOI, or title.
+    #[schemars(extend("examples" = ["W2741809807"]))]
+    pub id: String,
+}
+
+/// Parameters for `work_published_version`.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct WorkPublishedVersionToolParams {
+    /// Preprint or published work: OpenAlex ID (e.g. `W2741809807`), DOI, or title.
+    #[schemars(extend("examples" = ["W2741809807"]))]
+    pub id: String,
+}
+
+/// Parameters for `work_citations` and `work_references`.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct WorkCitationGraphToolParams {
+    /// Root work: OpenAlex ID (e.g. `W2741809807`), DOI, or title.
+    #[schemars(extend("examples" = ["W2741809807"]))]
+    pub id: String,
+    /// Hops to expand from the root (1-3, default 1). depth=2 also follows the
+    /// citations/references of each first-hop work.
+    #[schemars(range(min = 1, max = 3))]
+    pub depth: Option<u32>,
+    /// Maximum works fetched per expanded work, most-cited first (1-200, default 25).
+    #[schemars(range(min = 1, max = 200))]
+    pub per_work: Option<u32>,
+    /// Stop once this many unique works have been collected (1-1000, default 200).
+    #[schemars(range(min = 1, max = 1000))]
+    pub max_works: Option<usize>,
+}
+
+impl WorkCitationGraphToolParams {
+    pub fn into_graph_params(&self) -> papers_core::api::CitationGraphParams {
+        let defaults = papers_core::api::CitationGraphParams::default();
+        papers_core::api::CitationGraphParams {
+            depth: self.depth.unwrap_or(defaults.depth),
+            per_work: self.per_work.unwrap_or(defaults.per_work),
+            max_works: self.max_works.unwrap_or(defaults.max_works),
+        }
+    }
+}
+
+/// Parameters for `work_related`.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct WorkRelatedToolParams {
+    /// Seed work: OpenAlex ID (e.g. `W2741809807`), DOI, or title. Omit to
+    /// seed with a selection instead.
+    pub id: Option<String>,
+    /// Seed selection name or 1-based index, used when `id` is omitted.
+    /// Defaults to the active selection.
+    #[schemars(extend("examples" = ["thesis-related-work"]))]
+    pub selection: Option<String>,
+    /// Maximum recommendations returned (default 20).
+    #[schemars(range(min = 1))]
+    pub limit: Option<usize>,
+    /// Candidates fetched per seed for the shared-reference and shared-topic
+    /// searches, most-cited first (1-200, default 25).
+    #[schemars(range(min = 1, max = 200))]
+    pub per_seed: Option<u32>,
+}
+
+impl WorkRelatedToolParams {
+    pub fn into_related_params(&self) -> papers_core::api::RelatedWorksParams {
+        let defaults = papers_core::api::RelatedWorksParams::default();
+        papers_core::api::RelatedWorksParams {
+            limit: self.limit.unwrap_or(defaults.limit),
+            per_seed: self.per_seed.unwrap_or(defaults.per_seed),
+        }
+    }
+}
+
+/// Parameters for `work_search_references`.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct WorkSearchReferencesToolParams {
+    /// Citing work: OpenAlex ID (e.g. `W2741809807`), DOI, or title.
+    #[schemars(extend("examples" = ["W2741809807"]))]
+    pub id: String,
+    /// Full-text search over the titles and abstracts of the works it cites.
+    pub query: String,
+    /// Maximum matches returned (default 20).
+    #[schemars(range(min = 1))]
+    pub limit: Option<usize>,
+}
+
+impl WorkSearchReferencesToolParams {
+    pub fn into_search_params(&self) -> papers_core::api::ReferenceSearchParams {
+        let defaults = papers_core::api::ReferenceSearchParams::default();
+        papers_core::api::ReferenceSearchParams { limit: self.limit.unwrap_or(defaults.limit) }
+    }
+}
+
+/// Parameters for `format_citation`.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct FormatCitationToolParams {
+    /// Work to cite: OpenAlex ID (e.g. `W2741809807`), DOI, or title.
+    #[schemars(extend("examples" = ["W2741809807"]))]
+    pub id: String,
+    /// Citation style: "apa" (default), "mla", "chicago" (author-date), or "bibtex".
+    #[schemars(extend("enum" = ["apa", "mla", "chicago", "bibtex", null]))]
+    pub style: Option<String>,
+}
+
+/// Parameters for `review_context`.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct ReviewContextToolParams {
+    /// Title or abstract of the submission under review. The title line (or
+    /// first sentence) is used as the search query.
+    pub text: String,
+    /// Works the submission cites, as DOIs or OpenAlex IDs. Used to flag
+    /// frequently cited prior work it does not reference.
+    pub references: Option<Vec<String>>,
+}
+
+/// Parameters for the `work_stats` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct WorkStatsToolParams {
+    /// Dimension to count works by: year, type, topic, subfield, field, domain, institution,
+    /// country, author, source, publisher, funder, oa_status, language, or sdg.
+    #[schemars(extend("enum" = ["year", "type", "topic", "subfield", "field", "domain", "institution", "country", "author", "source", "publisher", "funder", "oa_status", "language", "sdg"]))]
+    pub by: String,
+    /// Largest groups to return for non-year dimensions (default 25). Years are never truncated.
+    #[schemars(range(min = 1))]
+    pub top: Option<usize>,
+    /// Optional full-text search query to narrow the set of works.
+    pub search: Option<String>,
+    /// Filter expression. Comma-separated AND conditions, pipe (`|`) for OR.
+    #[schemars(extend("examples" = ["publication_year:2024,is_oa:true"]))]
+    pub filter: Option<String>,
+    /// Filter by author name or OpenAlex author ID (e.g. "einstein" or "A5108093963")
+    pub author: Option<String>,
+    /// Filter by topic name or OpenAlex topic ID (e.g. "deep learning", or "T10320")
+    pub topic: Option<String>,
+    /// Filter by domain name or ID (e.g. "physical sciences" or "3")
+    pub domain: Option<String>,
+    /// Filter by field name or ID (e.g. "computer science" or "17")
+    pub field: Option<String>,
+    /// Filter by subfield name or ID (e.g. "artificial intelligence" or "1702")
+    pub subfield: Option<String>,
+    /// Filter by publisher name or ID (e.g. "elsevier", "acm|ieee", or "P4310319798")
+    pub publisher: Option<String>,
+    /// Filter by source (journal/conference) name or ID (e.g. "plos one" or "S202381698")
+    pub source: Option<String>,
+    /// Filter by institution name or ID. Uses lineage for broad matching (e.g. "mit" or "I136199984")
+    pub institution: Option<String>,
+    /// Filter by publication year (e.g. "2024", ">2008", "2008-2024")
+    #[schemars(extend("examples" = [">2020"]))]
+    pub year: Option<String>,
+    /// Filter by citation count (e.g. ">100", "10-50")
+    #[schemars(extend("examples" = [">100"]))]
+    pub citations: Option<String>,
+    /// Filter by country code of author institutions (e.g. "US", "GB", or a name like "germany")
+    pub country: Option<String>,
+    /// Filter by continent of author institutions (e.g. "europe", "north america")
+    pub continent: Option<String>,
+    /// Filter by work type (e.g. "article", "preprint", "dataset")
+    pub r#type: Option<String>,
+    /// Filter for open access works only.
+    pub open: Option<bool>,
+    /// Filter by work language: ISO 639-1 code or name (e.g. "en", "de", "german", "de|fr")
+    pub language: Option<String>,
+    /// Set to true to drop retracted works (`is_retracted:false`).
+    pub exclude_retracted: Option<bool>,
+    /// Set to true to drop paratext (`is_paratext:false`).
+    pub exclude_paratext: Option<bool>,
+    /// UN Sustainable Development Goal by number or name (e.g. "3", "SDG 13", "climate action").
+    pub sdg: Option<String>,
+}
+
+impl WorkStatsToolParams {
+    pub fn into_work_list_params(&self) -> papers_core::WorkListParams {
+        papers_core::WorkListParams {
+            filter: self.filter.clone(),
+            search: self.search.clone(),
+            author: self.author.clone(),
+            topic: self.topic.clone(),
+            domain: self.domain.clone(),
+            field: self.field.clone(),
+            subfield: self.subfield.clone(),
+            publisher: self.publisher.clone(),
+            source: self.source.clone(),
+            institution: self.institution.clone(),
+            year: self.year.clone(),
+            citations: self.citations.clone(),
+            country: self.country.clone(),
+            continent: self.continent.clone(),
+            r#type: self.r#type.clone(),
+            open: self.open,
+            language: self.language.clone(),
+            exclude_retracted: self.exclude_retracted,
+            exclude_paratext: self.exclude_paratext,
+            sdg: self.sdg.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Parameters for the `work_apc_summary` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct WorkApcSummaryToolParams {
+    /// Optional full-text search query to narrow the set of works.
+    pub search: Option<String>,
+    /// Filter expression. Comma-separated AND conditions, pipe (`|`) for OR.
+    #[schemars(extend("examples" = ["publication_year:2024,is_oa:true"]))]
+    pub filter: Option<String>,
+    /// Maximum works to fetch and sum (default 1000, max 10000). When more works match,
+    /// an extrapolated total is included.
+    #[schemars(range(min = 1, max = 10000))]
+    pub max_works: Option<u32>,
+    /// Filter by author name or OpenAlex author ID (e.g. "einstein" or "A5108093963")
+    pub author: Option<String>,
+    /// Filter by topic name or OpenAlex topic ID (e.g. "deep learning", or "T10320")
+    pub topic: Option<String>,
+    /// Filter by domain name or ID (e.g. "physical sciences" or "3")
+    pub domain: Option<String>,
+    /// Filter by field name or ID (e.g. "computer science" or "17")
+    pub field: Option<String>,
+    /// Filter by subfield name or ID (e.g. "artificial intelligence" or "1702")
+    pub subfield: Option<String>,
+    /// Filter by publisher name or ID (e.g. "elsevier", "acm|ieee", or "P4310319798")
+    pub publisher: Option<String>,
+    /// Filter by source (journal/conference) name or ID (e.g. "plos one" or "S202381698")
+    pub source: Option<String>,
+    /// Filter by institution name or ID. Uses lineage for broad matching (e.g. "mit" or "I136199984")
+    pub institution: Option<String>,
+    /// Filter by publication year (e.g. "2024", ">2008", "2008-2024")
+    #[schemars(extend("examples" = [">2020"]))]
+    pub year: Option<String>,
+    /// Filter by citation count (e.g. ">100", "10-50")
+    #[schemars(extend("examples" = [">100"]))]
+    pub citations: Option<String>,
+    /// Filter by country code of author institutions (e.g. "US", "GB", or a name like "germany")
+    pub country: Option<String>,
+    /// Filter by continent of author institutions (e.g. "europe", "north america")
+    pub continent: Option<String>,
+    /// Filter by work type (e.g. "article", "preprint", "dataset")
+    pub r#type: Option<String>,
+    /// Filter for open access works only.
+    pub open: Option<bool>,
+    /// Filter by work language: ISO 639-1 code or name (e.g. "en", "de", "german", "de|fr")
+    pub language: Option<String>,
+    /// Set to true to drop retracted works (`is_retracted:false`).
+    pub exclude_retracted: Option<bool>,
+    /// Set to true to drop paratext (`is_paratext:false`).
+    pub exclude_paratext: Option<bool>,
+    /// UN Sustainable Development Goal by number or name (e.g. "3", "SDG 13", "climate action").
+    pub sdg: Option<String>,
+}
+
+impl WorkApcSummaryToolParams {
+    pub fn into_work_list_params(&self) -> papers_core::WorkListParams {
+        papers_core::WorkListParams {
+            filter: self.filter.clone(),
+            search: self.search.clone(),
+            author: self.author.clone(),
+            topic: self.topic.clone(),
+            domain: self.domain.clone(),
+            field: self.field.clone(),
+            subfield: self.subfield.clone(),
+            publisher: self.publisher.clone(),
+            source: self.source.clone(),
+            institution: self.institution.clone(),
+            year: self.year.clone(),
+            citations: self.citations.clone(),
+            country: self.country.clone(),
+            continent: self.continent.clone(),
+            r#type: self.r#type.clone(),
+            open: self.open,
+            language: self.language.clone(),
+            exclude_retracted: self.exclude_retracted,
+            exclude_paratext: self.exclude_paratext,
+            sdg: self.sdg.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+// ── Cache params ──────────────────────────────────────────────────────────
+
+/// Parameters for `cache_clear`.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct CacheClearToolParams {
+    /// Only clear this entity type: works, authors, sources, institutions, topics, keywords,
+    /// publishers, funders, domains, fields, subfields, or other. Omit to clear everything.
+    #[schemars(extend("enum" = ["works", "authors", "sources", "institutions", "topics", "keywords", "publishers", "funders", "domains", "fields", "subfields", "other", null]))]
+    pub entity: Option<String>,
+}
+
+// ── Raw passthrough params ────────────────────────────────────────────────
+
+/// Parameters for `openalex_raw`.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct OpenAlexRawToolParams {
+    /// Endpoint path relative to the API root, e.g. `works`, `works/W2741809807`,
+    /// `autocomplete/authors`, `topics`. Only entity, autocomplete, and find endpoints are allowed.
+    pub path: String,
+    /// Query parameters exactly as OpenAlex names them, e.g.
+    /// `{"filter": "publication_year:2024", "group_by": "oa_status", "per-page": 5}`.
+    pub query: Option<std::collections::BTreeMap<String, serde_json::Value>>,
+}
+
+impl OpenAlexRawToolParams {
+    /// Query pairs with non-string values rendered as JSON (`5` → `"5"`).
+    pub fn query_pairs(&self) -> Vec<(String, String)> {
+        raw_query_pairs(&self.query)
+    }
+}
+
+fn raw_query_pairs(
+    query: &Option<std::collections::BTreeMap<String, serde_json::Value>>,
+) -> Vec<(String, String)> {
+    query
+        .iter()
+        .flatten()
+        .map(|(k, v)| {
+            let v = match v {
+                serde_json::Value::String(s) => s.clone(),
+                other => other.to_string(),
+            };
+            (k.clone(), v)
+        })
+        .collect()
+}
+
 // ── Zotero tool params ────────────────────────────────────────────────────
 
 /// Deserialize `Option<u32>` accepting both JSON integers and quoted strings.
@@ -1270,24 +2046,111 @@ pub struct ZoteroWorkListToolParams {
     #[serde(default, deserialize_with = "lax_optional_u64")]
     pub since: Option<u64>,
     /// Sort field: `dateAdded`, `dateModified`, `title`, `creator`, `date`, etc.
+    #[schemars(extend("examples" = ["dateModified"]))]
     pub sort: Option<String>,
     /// Sort direction: `"asc"` or `"desc"`.
-    pub direction: Option<String>,
+    #[serde(default, deserialize_with = "lax_optional_enum")]
+    pub direction: Option<SortDirection>,
     /// Results per page (1–100, default 25).
     #[serde(default, deserialize_with = "lax_optional_u32")]
+    #[schemars(range(min = 1, max = 100))]
     pub limit: Option<u32>,
     /// Pagination offset (0-based).
     #[serde(default, deserialize_with = "lax_optional_u32")]
     pub start: Option<u32>,
+    /// Export with Zotero's own translators instead of returning item JSON:
+    /// `bibtex`, `biblatex`, `csljson`, `ris`, `bib` (formatted bibliography), etc.
+    /// Exports every matching item unless `limit` is set.
+    #[schemars(extend("examples" = ["bibtex"]))]
+    pub output_format: Option<String>,
+    /// CSL style for `output_format: "bib"` (e.g. `"apa"`, `"ieee"`).
+    pub style: Option<String>,
+    /// Library to use: a group ID, a group name (case-insensitive), or `"user"`.
+    /// Defaults to your personal library. See `zotero_group_list` for groups.
+    #[schemars(extend("examples" = ["user"]))]
+    pub library: Option<String>,
+}
+
+/// Parameters for the `zotero_work_create` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct ZoteroWorkCreateToolParams {
+    /// OpenAlex work to add: OpenAlex ID (e.g. `W2741809807`), DOI, or title.
+    #[schemars(extend("examples" = ["W2741809807"]))]
+    pub id: String,
+    /// Collection keys or names to file the new item under.
+    pub collections: Option<Vec<String>>,
+    /// Tags to attach to the new item.
+    pub tags: Option<Vec<String>>,
+    /// Create the item even if one with the same DOI is already in the library (default false).
+    pub allow_duplicate: Option<bool>,
+}
+
+/// Parameters for the `zotero_work_update` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct ZoteroWorkUpdateToolParams {
+    /// Item key (e.g. `LF4MJWZK`). Title searches are not accepted for writes.
+    #[schemars(extend("examples" = ["AB12CDEF"]))]
+    pub key: String,
+    /// Expected current item version (from `zotero_work_get`). The update is rejected
+    /// if the item changed since. Omit to update whatever version is current.
+    #[serde(default, deserialize_with = "lax_optional_u64")]
+    pub version: Option<u64>,
+    /// New title.
+    pub title: Option<String>,
+    /// New abstract.
+    pub abstract_note: Option<String>,
+    /// New DOI (bare, e.g. `10.1234/abc`).
+    pub doi: Option<String>,
+    /// New date (e.g. `2024-05-01` or `2024`).
+    pub date: Option<String>,
+    /// New URL.
+    pub url: Option<String>,
+    /// Replacement for the `extra` field.
+    pub extra: Option<String>,
+    /// Tags to add.
+    pub add_tags: Option<Vec<String>>,
+    /// Tags to remove.
+    pub remove_tags: Option<Vec<String>>,
+    /// Collection keys or names to add the item to.
+    pub add_collections: Option<Vec<String>>,
+}
+
+impl ZoteroWorkUpdateToolParams {
+    pub fn to_update(&self) -> papers_core::zotero::ZoteroItemUpdate {
+        papers_core::zotero::ZoteroItemUpdate {
+            title: self.title.clone(),
+            abstract_note: self.abstract_note.clone(),
+            doi: self.doi.clone(),
+            date: self.date.clone(),
+            url: self.url.clone(),
+            extra: self.extra.clone(),
+            add_tags: self.add_tags.clone().unwrap_or_default(),
+            remove_tags: self.remove_tags.clone().unwrap_or_default(),
+            add_collections: self.add_collections.clone().unwrap_or_default(),
+        }
+    }
+}
+
+/// Parameters for the `work_metadata_diff` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct WorkMetadataDiffToolParams {
+    /// Zotero item key (e.g. `LF4MJWZK`), or an OpenAlex work (ID, DOI, or title) to look up
+    /// in the library.
+    #[schemars(extend("examples" = ["W2741809807"]))]
+    pub id: String,
+    /// Write the generated PATCH to the Zotero item (default false: report only).
+    pub apply: Option<bool>,
 }
 
 /// Parameters for work/collection child-list tools (notes, attachments).
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct ZoteroWorkChildrenToolParams {
     /// Item or collection key (e.g. `LF4MJWZK`) or a title/name search string.
+    #[schemars(extend("examples" = ["AB12CDEF"]))]
     pub key: String,
     /// Results per page (1–100).
     #[serde(default, deserialize_with = "lax_optional_u32")]
+    #[schemars(range(min = 1, max = 100))]
     pub limit: Option<u32>,
     /// Pagination offset (0-based).
     #[serde(default, deserialize_with = "lax_optional_u32")]
@@ -1298,11 +2161,13 @@ pub struct ZoteroWorkChildrenToolParams {
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct ZoteroWorkTagsToolParams {
     /// Item key (e.g. `LF4MJWZK`) or a title/creator search string.
+    #[schemars(extend("examples" = ["AB12CDEF"]))]
     pub key: String,
     /// Filter tags by name (substring match).
     pub search: Option<String>,
     /// Results per page (1–100).
     #[serde(default, deserialize_with = "lax_optional_u32")]
+    #[schemars(range(min = 1, max = 100))]
     pub limit: Option<u32>,
     /// Pagination offset (0-based).
     #[serde(default, deserialize_with = "lax_optional_u32")]
@@ -1313,11 +2178,14 @@ pub struct ZoteroWorkTagsToolParams {
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct ZoteroAttachmentListToolParams {
     /// Sort field: `dateAdded`, `dateModified`, `title`, `accessDate`.
+    #[schemars(extend("examples" = ["dateModified"]))]
     pub sort: Option<String>,
     /// Sort direction: `"asc"` or `"desc"`.
-    pub direction: Option<String>,
+    #[serde(default, deserialize_with = "lax_optional_enum")]
+    pub direction: Option<SortDirection>,
     /// Results per page (1–100, default 25).
     #[serde(default, deserialize_with = "lax_optional_u32")]
+    #[schemars(range(min = 1, max = 100))]
     pub limit: Option<u32>,
     /// Pagination offset (0-based).
     #[serde(default, deserialize_with = "lax_optional_u32")]
@@ -1329,6 +2197,7 @@ pub struct ZoteroAttachmentListToolParams {
 pub struct ZoteroAnnotationListToolParams {
     /// Results per page (1–100, default 25).
     #[serde(default, deserialize_with = "lax_optional_u32")]
+    #[schemars(range(min = 1, max = 100))]
     pub limit: Option<u32>,
     /// Pagination offset (0-based).
     #[serde(default, deserialize_with = "lax_optional_u32")]
@@ -1340,6 +2209,7 @@ pub struct ZoteroAnnotationListToolParams {
 pub struct ZoteroNoteListToolParams {
     /// Results per page (1–100, default 25).
     #[serde(default, deserialize_with = "lax_optional_u32")]
+    #[schemars(range(min = 1, max = 100))]
     pub limit: Option<u32>,
     /// Pagination offset (0-based).
     #[serde(default, deserialize_with = "lax_optional_u32")]
@@ -1350,23 +2220,32 @@ pub struct ZoteroNoteListToolParams {
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct ZoteroCollectionListToolParams {
     /// Sort field: `"title"`, `"dateAdded"`, or `"dateModified"`.
+    #[schemars(extend("examples" = ["title"]))]
     pub sort: Option<String>,
     /// Sort direction: `"asc"` or `"desc"`.
-    pub direction: Option<String>,
+    #[serde(default, deserialize_with = "lax_optional_enum")]
+    pub direction: Option<SortDirection>,
     /// Results per page (1–100, default 25).
     #[serde(default, deserialize_with = "lax_optional_u32")]
+    #[schemars(range(min = 1, max = 100))]
     pub limit: Option<u32>,
     /// Pagination offset (0-based).
     #[serde(default, deserialize_with = "lax_optional_u32")]
     pub start: Option<u32>,
     /// Scope: `"all"` (default) lists all collections; `"top"` lists only root-level.
-    pub scope: Option<String>,
+    #[serde(default, deserialize_with = "lax_optional_enum")]
+    pub scope: Option<CollectionScope>,
+    /// Library to use: a group ID, a group name (case-insensitive), or `"user"`.
+    /// Defaults to your personal library. See `zotero_group_list` for groups.
+    #[schemars(extend("examples" = ["user"]))]
+    pub library: Option<String>,
 }
 
 /// Parameters for the `zotero_collection_works` tool.
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct ZoteroCollectionWorksToolParams {
     /// Collection key (e.g. `AB12CDEF`) or a name search string.
+    #[schemars(extend("examples" = ["AB12CDEF"]))]
     pub key: String,
     /// Text search (title, creator, year).
     pub search: Option<String>,
@@ -1378,81 +2257,119 @@ pub struct ZoteroCollectionWorksToolParams {
     /// Narrow to a specific bibliographic type (e.g. `"journalArticle"`).
     pub item_type: Option<String>,
     /// Sort field.
+    #[schemars(extend("examples" = ["dateModified"]))]
     pub sort: Option<String>,
     /// Sort direction: `"asc"` or `"desc"`.
-    pub direction: Option<String>,
+    #[serde(default, deserialize_with = "lax_optional_enum")]
+    pub direction: Option<SortDirection>,
     /// Results per page (1–100, default 25).
     #[serde(default, deserialize_with = "lax_optional_u32")]
+    #[schemars(range(min = 1, max = 100))]
     pub limit: Option<u32>,
     /// Pagination offset (0-based).
     #[serde(default, deserialize_with = "lax_optional_u32")]
     pub start: Option<u32>,
+    /// Export with Zotero's own translators instead of returning item JSON:
+    /// `bibtex`, `biblatex`, `csljson`, `ris`, `bib` (formatted bibliography), etc.
+    /// Exports every matching item unless `limit` is set.
+    #[schemars(extend("examples" = ["bibtex"]))]
+    pub output_format: Option<String>,
+    /// CSL style for `output_format: "bib"` (e.g. `"apa"`, `"ieee"`).
+    pub style: Option<String>,
+    /// Library to use: a group ID, a group name (case-insensitive), or `"user"`.
+    /// Defaults to your personal library. See `zotero_group_list` for groups.
+    #[schemars(extend("examples" = ["user"]))]
+    pub library: Option<String>,
 }
 
 /// Parameters for the `zotero_collection_notes` tool.
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct ZoteroCollectionNotesToolParams {
     /// Collection key (e.g. `AB12CDEF`) or a name search string.
+    #[schemars(extend("examples" = ["AB12CDEF"]))]
     pub key: String,
     /// Text search within note content.
     pub search: Option<String>,
     /// Results per page (1–100, default 25).
     #[serde(default, deserialize_with = "lax_optional_u32")]
+    #[schemars(range(min = 1, max = 100))]
     pub limit: Option<u32>,
     /// Pagination offset (0-based).
     #[serde(default, deserialize_with = "lax_optional_u32")]
     pub start: Option<u32>,
+    /// Library to use: a group ID, a group name (case-insensitive), or `"user"`.
+    /// Defaults to your personal library. See `zotero_group_list` for groups.
+    #[schemars(extend("examples" = ["user"]))]
+    pub library: Option<String>,
 }
 
 /// Parameters for the `zotero_collection_subcollections` tool.
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct ZoteroCollectionSubcollectionsToolParams {
     /// Collection key (e.g. `AB12CDEF`) or a name search string.
+    #[schemars(extend("examples" = ["AB12CDEF"]))]
     pub key: String,
     /// Sort field: `"title"`, `"dateAdded"`, or `"dateModified"`.
+    #[schemars(extend("examples" = ["title"]))]
     pub sort: Option<String>,
     /// Sort direction: `"asc"` or `"desc"`.
-    pub direction: Option<String>,
+    #[serde(default, deserialize_with = "lax_optional_enum")]
+    pub direction: Option<SortDirection>,
     /// Results per page (1–100, default 25).
     #[serde(default, deserialize_with = "lax_optional_u32")]
+    #[schemars(range(min = 1, max = 100))]
     pub limit: Option<u32>,
     /// Pagination offset (0-based).
     #[serde(default, deserialize_with = "lax_optional_u32")]
     pub start: Option<u32>,
+    /// Library to use: a group ID, a group name (case-insensitive), or `"user"`.
+    /// Defaults to your personal library. See `zotero_group_list` for groups.
+    #[schemars(extend("examples" = ["user"]))]
+    pub library: Option<String>,
 }
 
 /// Parameters for the `zotero_collection_tags` tool.
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct ZoteroCollectionTagsToolParams {
     /// Collection key (e.g. `AB12CDEF`) or a name search string.
+    #[schemars(extend("examples" = ["AB12CDEF"]))]
     pub key: String,
     /// Filter tags by name (substring match).
     pub search: Option<String>,
     /// Results per page (1–100, default 25).
     #[serde(default, deserialize_with = "lax_optional_u32")]
+    #[schemars(range(min = 1, max = 100))]
     pub limit: Option<u32>,
     /// Pagination offset (0-based).
     #[serde(default, deserialize_with = "lax_optional_u32")]
     pub start: Option<u32>,
     /// When true, return only tags on top-level items in the collection.
     pub top: Option<bool>,
+    /// Library to use: a group ID, a group name (case-insensitive), or `"user"`.
+    /// Defaults to your personal library. See `zotero_group_list` for groups.
+    #[schemars(extend("examples" = ["user"]))]
+    pub library: Option<String>,
 }
 
 /// Parameters for the `zotero_tag_list` tool.
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct ZoteroTagListToolParams {
     /// Sort field.
+    #[schemars(extend("examples" = ["title"]))]
     pub sort: Option<String>,
     /// Sort direction: `"asc"` or `"desc"`.
-    pub direction: Option<String>,
+    #[serde(default, deserialize_with = "lax_optional_enum")]
+    pub direction: Option<SortDirection>,
     /// Results per page (1–100, default 25).
     #[serde(default, deserialize_with = "lax_optional_u32")]
+    #[schemars(range(min = 1, max = 100))]
     pub limit: Option<u32>,
     /// Pagination offset (0-based).
     #[serde(default, deserialize_with = "lax_optional_u32")]
     pub start: Option<u32>,
     /// Scope: `"all"` (default) = global index, `"top"` = top-level items only, `"trash"` = trashed items.
-    pub scope: Option<String>,
+    #[serde(default, deserialize_with = "lax_optional_enum")]
+    pub scope: Option<TagScope>,
 }
 
 /// Parameters for the `zotero_work_search` tool.
@@ -1468,28 +2385,45 @@ pub struct ZoteroWorkSearchToolParams {
     /// Narrow to a specific bibliographic type (e.g. `"journalArticle"`, `"book"`).
     pub item_type: Option<String>,
     /// Sort field: `dateAdded`, `dateModified`, `title`, `creator`, `date`, etc.
+    #[schemars(extend("examples" = ["dateModified"]))]
     pub sort: Option<String>,
     /// Sort direction: `"asc"` or `"desc"`.
-    pub direction: Option<String>,
+    #[serde(default, deserialize_with = "lax_optional_enum")]
+    pub direction: Option<SortDirection>,
     /// Results per page (1–100, default 25).
     #[serde(default, deserialize_with = "lax_optional_u32")]
+    #[schemars(range(min = 1, max = 100))]
     pub limit: Option<u32>,
     /// Pagination offset (0-based).
     #[serde(default, deserialize_with = "lax_optional_u32")]
     pub start: Option<u32>,
 }
 
+/// Parameters for the `library_search` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct LibrarySearchToolParams {
+    /// Search query, sent to both OpenAlex and Zotero.
+    pub query: String,
+    /// Results per source before merging (default 10).
+    #[serde(default, deserialize_with = "lax_optional_u32")]
+    #[schemars(range(min = 1))]
+    pub limit: Option<u32>,
+}
+
 /// Parameters for the `zotero_attachment_search` tool.
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct ZoteroAttachmentSearchToolParams {
     /// Search query (filename or title).
     pub query: String,
     /// Sort field: `dateAdded`, `dateModified`, `title`, `accessDate`.
+    #[schemars(extend("examples" = ["dateModified"]))]
     pub sort: Option<String>,
     /// Sort direction: `"asc"` or `"desc"`.
-    pub direction: Option<String>,
+    #[serde(default, deserialize_with = "lax_optional_enum")]
+    pub direction: Option<SortDirection>,
     /// Results per page (1–100, default 25).
     #[serde(default, deserialize_with = "lax_optional_u32")]
+    #[schemars(range(min = 1, max = 100))]
     pub limit: Option<u32>,
     /// Pagination offset (0-based).
     #[serde(default, deserialize_with = "lax_optional_u32")]
@@ -1503,36 +2437,145 @@ pub struct ZoteroNoteSearchToolParams {
     pub query: String,
     /// Results per page (1–100, default 25).
     #[serde(default, deserialize_with = "lax_optional_u32")]
+    #[schemars(range(min = 1, max = 100))]
     pub limit: Option<u32>,
     /// Pagination offset (0-based).
     #[serde(default, deserialize_with = "lax_optional_u32")]
     pub start: Option<u32>,
 }
 
+/// Parameters for the `zotero_fulltext_search` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct ZoteroFulltextSearchToolParams {
+    /// Words that must all appear in the PDF text (case-insensitive).
+    #[schemars(extend("examples" = ["sparse voxel octree"]))]
+    pub query: String,
+    /// Maximum number of results (default 20).
+    #[serde(default, deserialize_with = "lax_optional_u32")]
+    #[schemars(range(min = 1, max = 100))]
+    pub limit: Option<u32>,
+    /// Fetch text added or changed since the last sync before searching (default false; the
+    /// first search in a library always syncs).
+    pub sync: Option<bool>,
+    /// Library to search: a group ID, a group name (case-insensitive), or `"user"`.
+    /// Defaults to your personal library.
+    pub library: Option<String>,
+}
+
 /// Parameters for the `zotero_tag_search` tool.
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct ZoteroTagSearchToolParams {
-    /// Tag name query (substring match).
+    /// Tag name query.
     pub query: String,
+    /// Match mode: `"contains"` (default) or `"startsWith"`.
+    #[serde(default, deserialize_with = "lax_optional_enum")]
+    pub qmode: Option<TagMatchMode>,
     /// Sort field.
+    #[schemars(extend("examples" = ["title"]))]
     pub sort: Option<String>,
     /// Sort direction: `"asc"` or `"desc"`.
-    pub direction: Option<String>,
+    #[serde(default, deserialize_with = "lax_optional_enum")]
+    pub direction: Option<SortDirection>,
     /// Results per page (1–100, default 25).
     #[serde(default, deserialize_with = "lax_optional_u32")]
+    #[schemars(range(min = 1, max = 100))]
     pub limit: Option<u32>,
     /// Pagination offset (0-based).
     #[serde(default, deserialize_with = "lax_optional_u32")]
     pub start: Option<u32>,
 }
 
-/// Parameters for single-key Zotero endpoints.
+/// Parameters for the `zotero_item_type_list` tool.
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
-pub struct ZoteroKeyToolParams {
+pub struct ZoteroItemTypeListToolParams {
+    /// Locale for the `localized` names, e.g. `"en-US"` (default) or `"de-DE"`.
+    pub locale: Option<String>,
+}
+
+/// Parameters for the `zotero_item_type_fields` and `zotero_item_type_creator_types` tools.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct ZoteroItemTypeToolParams {
+    /// Item type, e.g. `"journalArticle"`, `"book"`, `"conferencePaper"`.
+    /// See `zotero_item_type_list` for all types.
+    pub item_type: String,
+    /// Locale for the `localized` names, e.g. `"en-US"` (default) or `"de-DE"`.
+    pub locale: Option<String>,
+}
+
+/// Parameters for `zotero_raw`.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct ZoteroRawToolParams {
+    /// HTTP method, default `"GET"`. `POST`, `PUT`, `PATCH`, and `DELETE` are refused unless the
+    /// server was started with `PAPERS_ZOTERO_RAW_WRITES=1`.
+    #[schemars(extend("enum" = ["GET", "POST", "PUT", "PATCH", "DELETE", null]))]
+    pub method: Option<String>,
+    /// Path relative to the library, e.g. `items/top`, `collections/AB12CDEF/items`,
+    /// `items/ABC12345/children`; or a schema endpoint such as `itemTypes`, `itemTypeFields`,
+    /// `itemTypeCreatorTypes`, `creatorFields`, `items/new`; or `groups`.
+    pub path: String,
+    /// Query parameters exactly as Zotero names them, e.g.
+    /// `{"itemType": "book"}` or `{"format": "bibtex", "limit": 5}`.
+    pub query: Option<std::collections::BTreeMap<String, serde_json::Value>>,
+    /// JSON body for writes.
+    pub body: Option<serde_json::Value>,
+    /// Object or library version, sent as `If-Unmodified-Since-Version` (writes).
+    #[serde(default, deserialize_with = "lax_optional_u64")]
+    pub version: Option<u64>,
+    /// Library to use: a group ID, a group name (case-insensitive), or `"user"`.
+    /// Defaults to your personal library. See `zotero_group_list` for groups.
+    #[schemars(extend("examples" = ["user"]))]
+    pub library: Option<String>,
+}
+
+impl ZoteroRawToolParams {
+    /// Query pairs with non-string values rendered as JSON (`5` → `"5"`).
+    pub fn query_pairs(&self) -> Vec<(String, String)> {
+        raw_query_pairs(&self.query)
+    }
+}
+
+/// Parameters for single-key Zotero endpoints that can target a group library.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct ZoteroLibraryKeyToolParams {
+    /// Zotero key (e.g. `LF4MJWZK`) or a title/name search string.
+    /// If the value is not an 8-character uppercase key, the library is
+    /// searched by title/creator/year (items) or name (collections) and
+    /// the first match is used.
+    #[schemars(extend("examples" = ["AB12CDEF"]))]
+    pub key: String,
+    /// Library to use: a group ID, a group name (case-insensitive), or `"user"`.
+    /// Defaults to your personal library. See `zotero_group_list` for groups.
+    #[schemars(extend("examples" = ["user"]))]
+    pub library: Option<String>,
+}
+
+/// Parameters for the `zotero_collection_attachments` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct ZoteroCollectionAttachmentsToolParams {
+    /// Collection key (e.g. `AB12CDEF`) or a name search string.
+    #[schemars(extend("examples" = ["AB12CDEF"]))]
+    pub key: String,
+    /// Results per page (1–100).
+    #[serde(default, deserialize_with = "lax_optional_u32")]
+    #[schemars(range(min = 1, max = 100))]
+    pub limit: Option<u32>,
+    /// Pagination offset (0-based).
+    #[serde(default, deserialize_with = "lax_optional_u32")]
+    pub start: Option<u32>,
+    /// Library to use: a group ID, a group name (case-insensitive), or `"user"`.
+    /// Defaults to your personal library. See `zotero_group_list` for groups.
+    #[schemars(extend("examples" = ["user"]))]
+    pub library: Option<String>,
+}
+
+/// Parameters for single-key Zotero endpoints.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct ZoteroKeyToolParams {
     /// Zotero key (e.g. `LF4MJWZK`) or a title/name search string.
     /// If the value is not an 8-character uppercase key, the library is
     /// searched by title/creator/year (items) or name (collections) and
     /// the first match is used.
+    #[schemars(extend("examples" = ["AB12CDEF"]))]
     pub key: String,
 }
 
@@ -1650,7 +2693,10 @@ mod tests {
             "continent": "north america",
             "type": "article",
             "open": true,
-            "year": "2024"
+            "year": "2024",
+            "language": "german",
+            "exclude_retracted": true,
+            "sdg": "13"
         })).unwrap();
         let params = tool_params.into_work_list_params();
         assert_eq!(params.institution.as_deref(), Some("mit"));
@@ -1659,6 +2705,56 @@ mod tests {
         assert_eq!(params.r#type.as_deref(), Some("article"));
         assert_eq!(params.open, Some(true));
         assert_eq!(params.year.as_deref(), Some("2024"));
+        assert_eq!(params.language.as_deref(), Some("german"));
+        assert_eq!(params.exclude_retracted, Some(true));
+        assert_eq!(params.exclude_paratext, None);
+        assert_eq!(params.sdg.as_deref(), Some("13"));
+    }
+
+    #[test]
+    fn test_selection_add_query_params_conversion() {
+        let tool_params: SelectionAddQueryToolParams = serde_json::from_value(serde_json::json!({
+            "selection": "review",
+            "limit": 100,
+            "search": "neural fields",
+            "sort": "cited_by_count:desc",
+            "topic": "neural rendering",
+            "year": ">2021"
+        })).unwrap();
+        assert_eq!(tool_params.limit, Some(100));
+        let params = tool_params.into_work_list_params();
+        assert_eq!(params.search.as_deref(), Some("neural fields"));
+        assert_eq!(params.sort.as_deref(), Some("cited_by_count:desc"));
+        assert_eq!(params.topic.as_deref(), Some("neural rendering"));
+        assert_eq!(params.year.as_deref(), Some(">2021"));
+        assert_eq!((params.per_page, params.cursor), (None, None));
+    }
+
+    #[test]
+    fn test_selection_add_references_params_conversion() {
+        let tool_params: SelectionAddReferencesToolParams = serde_json::from_value(serde_json::json!({
+            "id": "W2741809807",
+            "year": ">2015",
+            "type": "article"
+        })).unwrap();
+        assert_eq!(tool_params.id, "W2741809807");
+        let params = tool_params.into_work_list_params();
+        assert_eq!(params.year.as_deref(), Some(">2015"));
+        assert_eq!(params.r#type.as_deref(), Some("article"));
+        assert_eq!((params.filter, params.sort), (None, None));
+    }
+
+    #[test]
+    fn test_selection_add_citers_params_conversion() {
+        let tool_params: SelectionAddCitersToolParams = serde_json::from_value(serde_json::json!({
+            "id": "W2741809807",
+            "min_citations": 10,
+            "year": "2018-2024"
+        })).unwrap();
+        assert_eq!((tool_params.min_citations, tool_params.limit), (Some(10), None));
+        let params = tool_params.into_work_list_params();
+        assert_eq!(params.year.as_deref(), Some("2018-2024"));
+        assert_eq!(params.sort, None);
     }
 
     #[test]
@@ -1738,6 +2834,11 @@ pub struct SelectionListToolParams {}
 pub struct SelectionGetToolParams {
     /// Selection name or 1-based index. Omit to use the active selection.
     pub name: Option<String>,
+    /// Only return entries carrying this tag (case-insensitive).
+    pub tag: Option<String>,
+    /// Group entries by tag instead of returning a flat list. Untagged entries
+    /// form a final group with a null tag.
+    pub group_by_tag: Option<bool>,
 }
 
 /// Parameters for `selection_create`.
@@ -1760,18 +2861,455 @@ pub struct SelectionAddToolParams {
     /// Paper identifier: Zotero key, DOI, OpenAlex Work ID (e.g. W2741809807), or title.
     pub paper: String,
     /// Target selection name or 1-based index. Defaults to the active selection.
+    #[schemars(extend("examples" = ["thesis-related-work"]))]
     pub selection: Option<String>,
 }
 
+/// Parameters for `selection_add_bulk`.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct SelectionAddBulkToolParams {
+    /// Paper identifiers, each as for `selection_add`: Zotero key, DOI, OpenAlex Work ID, or
+    /// title (e.g. the `input` of each `work_extract_identifiers` candidate).
+    #[schemars(extend("examples" = [["10.1145/3592433", "W2741809807", "Neural Radiance Fields"]]))]
+    pub papers: Vec<String>,
+    /// Target selection name or 1-based index. Defaults to the active selection.
+    #[schemars(extend("examples" = ["thesis-related-work"]))]
+    pub selection: Option<String>,
+}
+
+/// Parameters for `selection_add_query`.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct SelectionAddQueryToolParams {
+    /// Target selection name or 1-based index. Defaults to the active selection.
+    #[schemars(extend("examples" = ["thesis-related-work"]))]
+    pub selection: Option<String>,
+    /// Maximum works to add (default 50, max 500), taken in `sort` order.
+    #[schemars(range(min = 1, max = 500))]
+    pub limit: Option<u32>,
+    /// Optional full-text search query.
+    pub search: Option<String>,
+    /// Filter expression. Comma-separated AND conditions, pipe (`|`) for OR.
+    #[schemars(extend("examples" = ["publication_year:2024,is_oa:true"]))]
+    pub filter: Option<String>,
+    /// Sort field with optional `:desc` suffix; decides which works fall within `limit`.
+    #[schemars(extend("examples" = ["cited_by_count:desc"]))]
+    pub sort: Option<String>,
+    /// Filter by author name or OpenAlex author ID (e.g. "einstein" or "A5108093963")
+    pub author: Option<String>,
+    /// Filter by topic name or OpenAlex topic ID (e.g. "deep learning", or "T10320")
+    pub topic: Option<String>,
+    /// Filter by domain name or ID (e.g. "physical sciences" or "3")
+    pub domain: Option<String>,
+    /// Filter by field name or ID (e.g. "computer science" or "17")
+    pub field: Option<String>,
+    /// Filter by subfield name or ID (e.g. "artificial intelligence" or "1702")
+    pub subfield: Option<String>,
+    /// Filter by publisher name or ID (e.g. "acm", "acm|ieee", or "P4310319798")
+    pub publisher: Option<String>,
+    /// Filter by source (journal/conference) name or ID (e.g. "siggraph" or "S131921510")
+    pub source: Option<String>,
+    /// Filter by institution name or ID. Uses lineage for broad matching (e.g. "mit" or "I136199984")
+    pub institution: Option<String>,
+    /// Filter by publication year (e.g. "2024", ">2008", "2008-2024")
+    #[schemars(extend("examples" = [">2020"]))]
+    pub year: Option<String>,
+    /// Filter by citation count (e.g. ">100", "10-50")
+    #[schemars(extend("examples" = [">100"]))]
+    pub citations: Option<String>,
+    /// Filter by country code of author institutions (e.g. "US", "GB", or a name like "germany")
+    pub country: Option<String>,
+    /// Filter by continent of author institutions (e.g. "europe", "north america")
+    pub continent: Option<String>,
+    /// Filter by work type (e.g. "article", "preprint", "dataset")
+    pub r#type: Option<String>,
+    /// Filter for open access works only.
+    pub open: Option<bool>,
+    /// Filter by work language: ISO 639-1 code or name (e.g. "en", "de", "german", "de|fr")
+    pub language: Option<String>,
+    /// Set to true to drop retracted works (`is_retracted:false`).
+    pub exclude_retracted: Option<bool>,
+    /// Set to true to drop paratext (`is_paratext:false`).
+    pub exclude_paratext: Option<bool>,
+    /// UN Sustainable Development Goal by number or name (e.g. "3", "SDG 13", "climate action").
+    pub sdg: Option<String>,
+}
+
+impl SelectionAddQueryToolParams {
+    pub fn into_work_list_params(&self) -> papers_core::WorkListParams {
+        papers_core::WorkListParams {
+            filter: self.filter.clone(),
+            search: self.search.clone(),
+            sort: self.sort.clone(),
+            author: self.author.clone(),
+            topic: self.topic.clone(),
+            domain: self.domain.clone(),
+            field: self.field.clone(),
+            subfield: self.subfield.clone(),
+            publisher: self.publisher.clone(),
+            source: self.source.clone(),
+            institution: self.institution.clone(),
+            year: self.year.clone(),
+            citations: self.citations.clone(),
+            country: self.country.clone(),
+            continent: self.continent.clone(),
+            r#type: self.r#type.clone(),
+            open: self.open,
+            language: self.language.clone(),
+            exclude_retracted: self.exclude_retracted,
+            exclude_paratext: self.exclude_paratext,
+            sdg: self.sdg.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Parameters for `selection_add_references`.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct SelectionAddReferencesToolParams {
+    /// Citing work: OpenAlex ID (e.g. `W2741809807`), DOI, or title.
+    #[schemars(extend("examples" = ["W2741809807"]))]
+    pub id: String,
+    /// Target selection name or 1-based index. Defaults to the active selection.
+    #[schemars(extend("examples" = ["thesis-related-work"]))]
+    pub selection: Option<String>,
+    /// Filter expression applied to the cited works. Comma-separated AND conditions, pipe (`|`) for OR.
+    #[schemars(extend("examples" = ["type:article"]))]
+    pub filter: Option<String>,
+    /// Full-text search over the titles and abstracts of the cited works.
+    pub search: Option<String>,
+    /// Keep cited works from these publication years (e.g. "2024", ">2008", "2008-2024")
+    #[schemars(extend("examples" = [">2015"]))]
+    pub year: Option<String>,
+    /// Keep cited works on this topic: name or OpenAlex topic ID (e.g. "deep learning", or "T10320")
+    pub topic: Option<String>,
+    /// Keep cited works of this type (e.g. "article", "preprint", "dataset")
+    pub r#type: Option<String>,
+}
+
+impl SelectionAddReferencesToolParams {
+    pub fn into_work_list_params(&self) -> papers_core::WorkListParams {
+        papers_core::WorkListParams {
+            filter: self.filter.clone(),
+            search: self.search.clone(),
+            year: self.year.clone(),
+            topic: self.topic.clone(),
+            r#type: self.r#type.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Parameters for `selection_add_citers`.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct SelectionAddCitersToolParams {
+    /// Cited work: OpenAlex ID (e.g. `W2741809807`), DOI, or title.
+    #[schemars(extend("examples" = ["W2741809807"]))]
+    pub id: String,
+    /// Target selection name or 1-based index. Defaults to the active selection.
+    #[schemars(extend("examples" = ["thesis-related-work"]))]
+    pub selection: Option<String>,
+    /// Keep only citing works cited at least this many times themselves.
+    pub min_citations: Option<u32>,
+    /// Keep citing works from these publication years (e.g. "2024", ">2018", "2018-2024")
+    #[schemars(extend("examples" = ["2018-2024"]))]
+    pub year: Option<String>,
+    /// Filter expression applied to the citing works. Comma-separated AND conditions, pipe (`|`) for OR.
+    #[schemars(extend("examples" = ["type:article"]))]
+    pub filter: Option<String>,
+    /// Sort field with optional `:desc` suffix; decides which citers fall within `limit`
+    /// (default `cited_by_count:desc`).
+    pub sort: Option<String>,
+    /// Maximum works to add (default 50, max 500).
+    #[schemars(range(min = 1, max = 500))]
+    pub limit: Option<u32>,
+}
+
+impl SelectionAddCitersToolParams {
+    pub fn into_work_list_params(&self) -> papers_core::WorkListParams {
+        papers_core::WorkListParams {
+            filter: self.filter.clone(),
+            sort: self.sort.clone(),
+            year: self.year.clone(),
+            ..Default::default()
+        }
+    }
+}
+
 /// Parameters for `selection_remove`.
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct SelectionRemoveToolParams {
     /// Paper identifier: Zotero key, DOI, OpenAlex ID, or title substring.
     pub paper: String,
     /// Target selection name or 1-based index. Defaults to the active selection.
+    #[schemars(extend("examples" = ["thesis-related-work"]))]
+    pub selection: Option<String>,
+}
+
+/// Parameters for `selection_annotate`.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct SelectionAnnotateToolParams {
+    /// Entry to annotate: 1-based index, Zotero key, DOI, OpenAlex ID, or title substring.
+    pub paper: String,
+    /// Note explaining why the paper is in the list (e.g. "baseline for section 4").
+    /// Omit or pass an empty string to clear the note.
+    pub note: Option<String>,
+    /// Target selection name or 1-based index. Defaults to the active selection.
+    #[schemars(extend("examples" = ["thesis-related-work"]))]
+    pub selection: Option<String>,
+}
+
+/// Parameters for `selection_tag`.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct SelectionTagToolParams {
+    /// Entry to tag: 1-based index, Zotero key, DOI, OpenAlex ID, or title substring.
+    pub paper: String,
+    /// Tags to add. Tags already on the entry (ignoring case) are skipped.
+    pub add: Option<Vec<String>>,
+    /// Tags to remove (case-insensitive).
+    pub remove: Option<Vec<String>>,
+    /// Target selection name or 1-based index. Defaults to the active selection.
+    #[schemars(extend("examples" = ["thesis-related-work"]))]
     pub selection: Option<String>,
 }
 
+/// Parameters for `selection_scope`.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct SelectionScopeToolParams {
+    /// Selection name or 1-based index to scope the DB tools to. Defaults to
+    /// the active selection.
+    #[schemars(extend("examples" = ["thesis-related-work"]))]
+    pub selection: Option<String>,
+    /// Clear the default scope instead, so DB tools search everything again.
+    pub clear: Option<bool>,
+}
+
+/// Parameters for `selection_sync_to_zotero`.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct SelectionSyncToZoteroToolParams {
+    /// Selection name or 1-based index. Defaults to the active selection.
+    #[schemars(extend("examples" = ["thesis-related-work"]))]
+    pub selection: Option<String>,
+    /// Zotero collection key, or a collection name matched exactly (case-insensitive). Created
+    /// when no collection has that name. Defaults to the selection's name.
+    #[schemars(extend("examples" = ["Thesis related work"]))]
+    pub collection: Option<String>,
+}
+
+/// Parameters for `selection_from_collection`.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct SelectionFromCollectionToolParams {
+    /// Zotero collection key or name (case-insensitive substring).
+    #[schemars(extend("examples" = ["GPU Papers"]))]
+    pub collection: String,
+    /// Selection to add the items to, created if missing. Defaults to the collection's name with
+    /// spaces and punctuation replaced by `-`.
+    pub name: Option<String>,
+}
+
+/// Parameters for `selection_screen`.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct SelectionScreenToolParams {
+    /// Entry to screen: 1-based index, Zotero key, DOI, OpenAlex ID, or title substring.
+    pub paper: String,
+    /// Decision: "include", "exclude", or "maybe".
+    #[schemars(extend("enum" = ["include", "exclude", "maybe"]))]
+    pub decision: String,
+    /// Screening stage: "title_abstract" (default) or "full_text".
+    /// Full-text decisions require a title/abstract inclusion.
+    #[schemars(extend("enum" = ["title_abstract", "full_text", null]))]
+    pub stage: Option<String>,
+    /// Reason for the decision (shown in the PRISMA full-text exclusion breakdown).
+    pub reason: Option<String>,
+    /// Reviewer name or initials.
+    pub reviewer: Option<String>,
+    /// Settle a reviewer conflict: earlier reviewer decisions at this stage stop counting as conflicts.
+    pub adjudicate: Option<bool>,
+    /// Target selection name or 1-based index. Defaults to the active selection.
+    #[schemars(extend("examples" = ["thesis-related-work"]))]
+    pub selection: Option<String>,
+}
+
+/// Parameters for `selection_screening_export`.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct SelectionScreeningExportToolParams {
+    /// Stage to export: "title_abstract" (default) or "full_text".
+    #[schemars(extend("enum" = ["title_abstract", "full_text", null]))]
+    pub stage: Option<String>,
+    /// Target selection name or 1-based index. Defaults to the active selection.
+    #[schemars(extend("examples" = ["thesis-related-work"]))]
+    pub selection: Option<String>,
+}
+
+/// Parameters for `selection_screening_import`.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct SelectionScreeningImportToolParams {
+    /// CSV text with a header row. Requires `key` and `decision` columns;
+    /// optional `reason`, `reviewer`, and `stage` columns.
+    pub csv: String,
+    /// Reviewer for rows without a `reviewer` column.
+    pub reviewer: Option<String>,
+    /// Stage for rows without a `stage` column: "title_abstract" (default) or "full_text".
+    #[schemars(extend("enum" = ["title_abstract", "full_text", null]))]
+    pub stage: Option<String>,
+    /// Target selection name or 1-based index. Defaults to the active selection.
+    #[schemars(extend("examples" = ["thesis-related-work"]))]
+    pub selection: Option<String>,
+}
+
+/// Parameters for `selection_screening_conflicts`.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct SelectionScreeningConflictsToolParams {
+    /// Stage to check: "title_abstract" (default) or "full_text".
+    #[schemars(extend("enum" = ["title_abstract", "full_text", null]))]
+    pub stage: Option<String>,
+    /// Target selection name or 1-based index. Defaults to the active selection.
+    #[schemars(extend("examples" = ["thesis-related-work"]))]
+    pub selection: Option<String>,
+}
+
+/// Parameters for `selection_screening_status`.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct SelectionScreeningStatusToolParams {
+    /// Stage to report on: "title_abstract" (default) or "full_text".
+    #[schemars(extend("enum" = ["title_abstract", "full_text", null]))]
+    pub stage: Option<String>,
+    /// Only list entries still awaiting a decision at this stage.
+    pub pending_only: Option<bool>,
+    /// Target selection name or 1-based index. Defaults to the active selection.
+    #[schemars(extend("examples" = ["thesis-related-work"]))]
+    pub selection: Option<String>,
+}
+
+/// Parameters for `selection_prisma`.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct SelectionPrismaToolParams {
+    /// Target selection name or 1-based index. Defaults to the active selection.
+    #[schemars(extend("examples" = ["thesis-related-work"]))]
+    pub selection: Option<String>,
+}
+
+/// Parameters for `selection_export`.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct SelectionExportToolParams {
+    /// Target selection name or 1-based index. Defaults to the active selection.
+    #[schemars(extend("examples" = ["thesis-related-work"]))]
+    pub selection: Option<String>,
+    /// Output format: "bibtex" (default) or "csl-json".
+    #[schemars(extend("enum" = ["bibtex", "csl-json", null]))]
+    pub format: Option<String>,
+}
+
+/// Parameters for `selection_cite`.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct SelectionCiteToolParams {
+    /// LaTeX fragment with `\cite{?}` / `\cite{?hint}` placeholders, existing `\cite{key}`s, or
+    /// author-year mentions such as "Vaswani et al. (2017)".
+    pub latex: String,
+    /// Target selection name or 1-based index. Defaults to the active selection.
+    #[schemars(extend("examples" = ["thesis-related-work"]))]
+    pub selection: Option<String>,
+    /// Text of the manuscript's existing .bib file. Its keys are preferred, and only entries it
+    /// lacks are returned as missing.
+    pub bibtex: Option<String>,
+}
+
+/// Parameters for `selection_site`.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct SelectionSiteToolParams {
+    /// Target selection name or 1-based index. Defaults to the active selection.
+    #[schemars(extend("examples" = ["thesis-related-work"]))]
+    pub selection: Option<String>,
+    /// Directory to write the site into; created if missing. Existing pages are overwritten.
+    pub out_dir: String,
+}
+
+/// Parameters for `selection_apc_report`.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct SelectionApcReportToolParams {
+    /// Target selection name or 1-based index. Defaults to the active selection.
+    #[schemars(extend("examples" = ["thesis-related-work"]))]
+    pub selection: Option<String>,
+}
+
+/// Parameters for `selection_sdg_report`.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct SelectionSdgReportToolParams {
+    /// Target selection name or 1-based index. Defaults to the active selection.
+    #[schemars(extend("examples" = ["thesis-related-work"]))]
+    pub selection: Option<String>,
+}
+
+/// Parameters for `selection_timeline`.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct SelectionTimelineToolParams {
+    /// Target selection name or 1-based index. Defaults to the active selection.
+    #[schemars(extend("examples" = ["thesis-related-work"]))]
+    pub selection: Option<String>,
+    /// Most-cited works flagged as milestones per year (default 1, 0 disables).
+    pub milestones: Option<usize>,
+}
+
+/// Parameters for `paper_status_set`.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct PaperStatusSetToolParams {
+    /// Paper: DOI, Zotero item key, or OpenAlex ID (as in the DB, a DOI when the paper has one).
+    #[schemars(extend("examples" = ["10.48550/arXiv.1706.03762"]))]
+    pub paper_id: String,
+    /// Reading status: unread, reading, or read. Omit to keep the current one.
+    pub status: Option<String>,
+    /// Rating from 1 (poor) to 5 (excellent); 0 clears it. Omit to keep the current one.
+    pub rating: Option<u8>,
+    /// DB chunk ID where reading left off. Omit to keep the current one.
+    pub last_chunk_id: Option<String>,
+}
+
+/// Parameters for `paper_status_get`.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct PaperStatusGetToolParams {
+    /// Paper: DOI, Zotero item key, or OpenAlex ID. Omit to list every paper with a recorded status.
+    pub paper_id: Option<String>,
+    /// When listing, only papers with this status: unread, reading, or read.
+    pub status: Option<String>,
+}
+
+/// Parameters for `digest_generate`.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct DigestGenerateToolParams {
+    /// Selections (names or 1-based indices) whose papers are checked for new citations,
+    /// typically the selection holding your own papers.
+    #[schemars(extend("examples" = [["my-papers"]]))]
+    pub selections: Option<Vec<String>>,
+    /// Standing search queries; works published in the window that match each are listed.
+    #[schemars(extend("examples" = [["gaussian splatting", "neural radiance fields"]]))]
+    pub watches: Option<Vec<String>>,
+    /// Window in days, ending today (default 1, max 90).
+    pub days: Option<u32>,
+}
+
+/// Parameters for `digest_notify`.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct DigestNotifyToolParams {
+    /// Selections (names or 1-based indices) whose papers are checked for new citations.
+    #[schemars(extend("examples" = [["my-papers"]]))]
+    pub selections: Option<Vec<String>>,
+    /// Standing search queries; works published in the window that match each are announced.
+    #[schemars(extend("examples" = [["gaussian splatting"]]))]
+    pub watches: Option<Vec<String>>,
+    /// Window in days, ending today (default 1, max 90).
+    pub days: Option<u32>,
+}
+
+/// Parameters for `selection_citation_graph`.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct SelectionCitationGraphToolParams {
+    /// Target selection name or 1-based index. Defaults to the active selection.
+    #[schemars(extend("examples" = ["thesis-related-work"]))]
+    pub selection: Option<String>,
+    /// Output format: "graphml" (default) or "dot".
+    #[schemars(extend("enum" = ["graphml", "dot", null]))]
+    pub format: Option<String>,
+}
+
 // ── DB tool params ──────────────────────────────────────────────────────────
 
 /// Parameters for the `db_chunk_search` tool.
@@ -1779,14 +3317,25 @@ pub struct SelectionRemoveToolParams {
 pub struct DbChunkSearchParams {
     /// Natural language search query.
     pub query: String,
-    /// Scope to papers in this named selection.
+    /// Scope to papers in this named selection. Defaults to the DB scope set
+    /// by `selection_scope`, if any.
+    #[schemars(extend("examples" = ["thesis-related-work"]))]
     pub selection: Option<String>,
+    /// Scope to the papers of a Zotero collection, by key or name (case-insensitive substring),
+    /// instead of a selection. Subcollections are not included.
+    #[schemars(extend("examples" = ["GPU Papers"]))]
+    pub collection: Option<String>,
     /// Scope to a single paper by DOI, item key, or title search.
     pub work: Option<String>,
     /// Scope to a chapter (requires work).
     pub chapter_idx: Option<u16>,
+    /// Scope to a chapter by title instead, e.g. "Results" (requires work).
+    pub chapter: Option<String>,
     /// Scope to a section (requires work and chapter_idx).
     pub section_idx: Option<u16>,
+    /// Scope to a section by title instead, e.g. "3.2 Local System Solver" or
+    /// "local solver" (requires work; sets the chapter too).
+    pub section: Option<String>,
     /// Minimum publication year filter.
     pub filter_year_min: Option<u16>,
     /// Maximum publication year filter.
@@ -1796,8 +3345,19 @@ pub struct DbChunkSearchParams {
     /// Filter by tags (any match).
     pub filter_tags: Option<Vec<String>>,
     /// Granularity filter: "chapter", "section", or "paragraph".
-    pub filter_depth: Option<String>,
+    #[serde(default, deserialize_with = "lax_optional_enum")]
+    #[schemars(with = "Option<String>", extend("enum" = ["chapter", "section", "paragraph", null]))]
+    pub filter_depth: Option<papers_db::ChunkDepth>,
+    /// Ranking mode: "semantic" (default, embedding similarity), "keyword" (BM25 —
+    /// best for exact method names and acronyms), or "hybrid" (both, fused).
+    #[schemars(extend("enum" = ["semantic", "keyword", "hybrid", null]))]
+    pub mode: Option<String>,
+    /// Rerank a larger candidate pool with a local cross-encoder before returning the top results.
+    /// Slower (loads a second model on first use) but usually surfaces better passages for
+    /// question-style queries. Model is set by PAPERS_RERANK_MODEL (default bge-reranker-base).
+    pub rerank: Option<bool>,
     /// Maximum number of results (default 5).
+    #[schemars(range(min = 1))]
     pub limit: Option<u16>,
 }
 
@@ -1806,41 +3366,118 @@ pub struct DbChunkSearchParams {
 pub struct DbExhibitSearchParams {
     /// Natural language description of the figure, table, algorithm, or diagram to find.
     pub query: String,
-    /// Scope to papers in this named selection.
+    /// Scope to papers in this named selection. Defaults to the DB scope set
+    /// by `selection_scope`, if any.
+    #[schemars(extend("examples" = ["thesis-related-work"]))]
     pub selection: Option<String>,
     /// Scope to a single paper by DOI, item key, or title search.
     pub work: Option<String>,
     /// Filter by exhibit type: "figure", "table", or "algorithm".
-    pub filter_exhibit_type: Option<String>,
+    #[serde(default, deserialize_with = "lax_optional_enum")]
+    #[schemars(with = "Option<String>", extend("enum" = ["figure", "table", "algorithm", null]))]
+    pub filter_exhibit_type: Option<papers_db::ExhibitType>,
     /// Maximum number of results (default 5).
+    #[schemars(range(min = 1))]
+    pub limit: Option<u16>,
+}
+
+/// Parameters for the `db_equation_search` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct DbEquationSearchParams {
+    /// What the equation defines or does (e.g. "update rule", "loss function").
+    pub query: String,
+    /// Scope to papers in this named selection. Defaults to the DB scope set
+    /// by `selection_scope`, if any.
+    #[schemars(extend("examples" = ["thesis-related-work"]))]
+    pub selection: Option<String>,
+    /// Scope to a single paper by DOI, item key, or title search.
+    pub work: Option<String>,
+    /// Maximum number of results (default 5).
+    #[schemars(range(min = 1))]
+    pub limit: Option<u16>,
+}
+
+/// Parameters for the `db_citation_contexts` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct DbCitationContextsParams {
+    /// DOI of the cited paper (need not be indexed).
+    pub doi: String,
+    /// Title of the cited paper, to also match reference entries that lack a
+    /// DOI. Defaults to its title in the DB when it is indexed.
+    pub title: Option<String>,
+    /// Only search citing papers in this named selection. Defaults to the DB
+    /// scope set by `selection_scope`, if any.
+    #[schemars(extend("examples" = ["thesis-related-work"]))]
+    pub selection: Option<String>,
+    /// Maximum number of results (default 50).
+    #[schemars(range(min = 1))]
     pub limit: Option<u16>,
 }
 
-/// Parameters for the `db_chunk_get` tool.
+/// Parameters for the `db_citation_graph` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct DbCitationGraphParams {
+    /// Only include papers in this named selection. Defaults to the DB scope
+    /// set by `selection_scope`, if any.
+    #[schemars(extend("examples" = ["thesis-related-work"]))]
+    pub selection: Option<String>,
+    /// Output format: "graphml" (default) or "dot".
+    #[schemars(extend("enum" = ["graphml", "dot", null]))]
+    pub format: Option<String>,
+}
+
+/// Parameters for the `db_chunk_get` and `db_chunk_mark_read` tools.
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct DbChunkGetParams {
     /// Chunk ID (e.g. "10.1145/abc/ch1/s2/p3" or "YFACFA8C/ch1/s0/p0").
     pub chunk_id: String,
 }
 
+/// Parameters for the `db_chunk_locate` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct DbChunkLocateParams {
+    /// Zotero annotation key (from zotero_annotation_list or zotero_work_annotations).
+    #[schemars(extend("examples" = ["QXNY8AX8"]))]
+    pub annotation_key: String,
+    /// Maximum number of chunks to return (default 3).
+    #[schemars(range(min = 1, max = 20))]
+    pub limit: Option<u16>,
+}
+
 /// Parameters for the `db_section_get` tool.
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct DbSectionGetParams {
     /// Paper: DOI, item key, or title search.
+    #[schemars(extend("examples" = ["10.48550/arXiv.1706.03762"]))]
     pub paper_id: String,
     /// Chapter index (1-based).
-    pub chapter_idx: u16,
+    pub chapter_idx: Option<u16>,
     /// Section index (1-based).
-    pub section_idx: u16,
+    pub section_idx: Option<u16>,
+    /// Section title instead of indices, e.g. "3.2 Local System Solver" or
+    /// "local solver"; matched against the paper's outline.
+    pub section: Option<String>,
+    /// Inline each referenced exhibit's caption, description and image path
+    /// into the first chunk that references it.
+    #[serde(default)]
+    pub include_exhibits: bool,
 }
 
 /// Parameters for the `db_chapter_get` tool.
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct DbChapterGetParams {
     /// Paper: DOI, item key, or title search.
+    #[schemars(extend("examples" = ["10.48550/arXiv.1706.03762"]))]
     pub paper_id: String,
     /// Chapter index (1-based).
-    pub chapter_idx: u16,
+    pub chapter_idx: Option<u16>,
+    /// Chapter title instead of the index, e.g. "Results"; matched against the
+    /// paper's outline.
+    pub chapter: Option<String>,
+    /// Inline each referenced exhibit's caption, description and image path
+    /// into the first chunk that references it.
+    #[serde(default)]
+    pub include_exhibits: bool,
 }
 
 /// Parameters for the `db_exhibit_get` tool.
@@ -1848,20 +3485,34 @@ pub struct DbChapterGetParams {
 pub struct DbExhibitGetParams {
     /// Exhibit ID (e.g. "YFACFA8C/fig3").
     pub exhibit_id: String,
+    /// Also return the figure image as base64 image content.
+    #[serde(default)]
+    pub include_image: bool,
+    /// Longest side of the returned image in pixels (default 1024, max 2048).
+    #[serde(default, deserialize_with = "lax_optional_u32")]
+    #[schemars(range(min = 1, max = 2048))]
+    pub max_dimension: Option<u32>,
 }
 
 /// Parameters for the `db_work_outline` tool.
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct DbWorkOutlineParams {
     /// Paper: DOI, item key, or title search.
+    #[schemars(extend("examples" = ["10.48550/arXiv.1706.03762"]))]
     pub paper_id: String,
 }
 
 /// Parameters for the `db_work_list` tool.
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct DbWorkListParams {
-    /// Scope to papers in this named selection.
+    /// Scope to papers in this named selection. Defaults to the DB scope set
+    /// by `selection_scope`, if any.
+    #[schemars(extend("examples" = ["thesis-related-work"]))]
     pub selection: Option<String>,
+    /// Scope to the papers of a Zotero collection, by key or name (case-insensitive substring),
+    /// instead of a selection. Subcollections are not included.
+    #[schemars(extend("examples" = ["GPU Papers"]))]
+    pub collection: Option<String>,
     /// Minimum publication year.
     pub filter_year_min: Option<u16>,
     /// Maximum publication year.
@@ -1873,15 +3524,20 @@ pub struct DbWorkListParams {
     /// Filter by author name (substring match, any author).
     pub filter_authors: Option<Vec<String>>,
     /// Sort field: "year" (default) or "title".
-    pub sort_by: Option<String>,
+    #[serde(default, deserialize_with = "lax_optional_enum")]
+    #[schemars(with = "Option<String>", extend("enum" = ["year", "title", null]))]
+    pub sort_by: Option<papers_db::PaperSort>,
     /// Maximum number of results (default 50).
+    #[schemars(range(min = 1))]
     pub limit: Option<u16>,
 }
 
 /// Parameters for the `db_tag_list` tool.
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct DbTagListParams {
-    /// Scope to papers in this named selection.
+    /// Scope to papers in this named selection. Defaults to the DB scope set
+    /// by `selection_scope`, if any.
+    #[schemars(extend("examples" = ["thesis-related-work"]))]
     pub selection: Option<String>,
 }
 
@@ -1889,6 +3545,15 @@ pub struct DbTagListParams {
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct DbWorkGetParams {
     /// Paper: DOI, item key, or title search.
+    #[schemars(extend("examples" = ["10.48550/arXiv.1706.03762"]))]
+    pub paper_id: String,
+}
+
+/// Parameters for the `db_work_remove` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct DbWorkRemoveParams {
+    /// Paper: DOI, item key, or title search.
+    #[schemars(extend("examples" = ["10.48550/arXiv.1706.03762"]))]
     pub paper_id: String,
 }
 
@@ -1897,7 +3562,9 @@ pub struct DbWorkGetParams {
 pub struct DbWorkSearchParams {
     /// Natural language search query.
     pub query: String,
-    /// Scope to papers in this named selection.
+    /// Scope to papers in this named selection. Defaults to the DB scope set
+    /// by `selection_scope`, if any.
+    #[schemars(extend("examples" = ["thesis-related-work"]))]
     pub selection: Option<String>,
     /// Minimum publication year filter.
     pub filter_year_min: Option<u16>,
@@ -1908,9 +3575,116 @@ pub struct DbWorkSearchParams {
     /// Filter by tags (any match).
     pub filter_tags: Option<Vec<String>>,
     /// Maximum number of results (default 5).
+    #[schemars(range(min = 1))]
     pub limit: Option<u16>,
 }
 
+/// Parameters for the `db_abstract_index` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct DbAbstractIndexParams {
+    /// Only index this named selection's entries. Omit to index every
+    /// selection plus the items of the Zotero collections `db watch` follows.
+    #[schemars(extend("examples" = ["thesis-related-work"]))]
+    pub selection: Option<String>,
+}
+
+/// Parameters for the `db_abstract_search` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct DbAbstractSearchParams {
+    /// Natural language search query.
+    pub query: String,
+    /// Scope to papers in this named selection. Defaults to the DB scope set
+    /// by `selection_scope`, if any.
+    #[schemars(extend("examples" = ["thesis-related-work"]))]
+    pub selection: Option<String>,
+    /// Minimum publication year filter.
+    pub filter_year_min: Option<u16>,
+    /// Maximum publication year filter.
+    pub filter_year_max: Option<u16>,
+    /// Maximum number of results (default 10).
+    #[schemars(range(min = 1))]
+    pub limit: Option<u16>,
+}
+
+/// Parameters for the `db_claim_verify` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct DbClaimVerifyParams {
+    /// The claim to check, e.g. a sentence from a draft.
+    #[schemars(extend("examples" = ["Gaussian splatting renders faster than NeRF at equal quality."]))]
+    pub statement: String,
+    /// Scope to papers in this named selection. Defaults to the DB scope set
+    /// by `selection_scope`, if any.
+    #[schemars(extend("examples" = ["thesis-related-work"]))]
+    pub selection: Option<String>,
+    /// Scope to a single paper by DOI, item key, or title search.
+    pub work: Option<String>,
+    /// Retrieval mode: "semantic" (default), "keyword", or "hybrid".
+    #[schemars(extend("enum" = ["semantic", "keyword", "hybrid", null]))]
+    pub mode: Option<String>,
+    /// Pick passages with the local cross-encoder (model-assisted retrieval; slower on first use).
+    pub rerank: Option<bool>,
+    /// Maximum number of passages (default 8).
+    #[schemars(range(min = 1))]
+    pub limit: Option<u16>,
+}
+
+/// Parameters for the `db_draft_ground` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct DbDraftGroundParams {
+    /// The draft as Markdown. Paragraphs are separated by blank lines; headings, code
+    /// blocks and tables are skipped.
+    pub markdown_text: String,
+    /// Only suggest papers in this named selection. Defaults to the DB scope set
+    /// by `selection_scope`, if any.
+    #[schemars(extend("examples" = ["thesis-related-work"]))]
+    pub selection: Option<String>,
+    /// Retrieval mode: "semantic" (default), "keyword", or "hybrid".
+    #[schemars(extend("enum" = ["semantic", "keyword", "hybrid", null]))]
+    pub mode: Option<String>,
+    /// Pick passages with the local cross-encoder (slower on first use).
+    pub rerank: Option<bool>,
+    /// Candidate papers per paragraph (default 3).
+    #[schemars(range(min = 1))]
+    pub limit: Option<u16>,
+}
+
+/// Parameters for the `db_work_similar` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct DbWorkSimilarParams {
+    /// Paper to compare against: DOI, item key, or title search.
+    #[schemars(extend("examples" = ["10.48550/arXiv.1706.03762"]))]
+    pub paper_id: String,
+    /// Only rank papers in this named selection. Defaults to the DB scope set
+    /// by `selection_scope`, if any.
+    #[schemars(extend("examples" = ["thesis-related-work"]))]
+    pub selection: Option<String>,
+    /// Maximum number of results (default 10).
+    #[schemars(range(min = 1))]
+    pub limit: Option<u16>,
+}
+
+/// Parameters for the `db_work_cluster` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct DbWorkClusterParams {
+    /// Only cluster papers in this named selection. Defaults to the DB scope set
+    /// by `selection_scope`, if any.
+    #[schemars(extend("examples" = ["thesis-related-work"]))]
+    pub selection: Option<String>,
+    /// Number of clusters. Omit to pick the count automatically.
+    pub k: Option<u16>,
+}
+
+/// Parameters for the `db_work_timeline` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct DbWorkTimelineParams {
+    /// Only place papers in this named selection. Defaults to the DB scope set
+    /// by `selection_scope`, if any.
+    #[schemars(extend("examples" = ["thesis-related-work"]))]
+    pub selection: Option<String>,
+    /// Most-cited papers flagged as milestones per year (default 1, 0 disables).
+    pub milestones: Option<u16>,
+}
+
 /// Parameters for the `db_chunk_list` tool.
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct DbChunkListParams {
@@ -1921,6 +3695,7 @@ pub struct DbChunkListParams {
     /// Scope to a section (1-based within chapter; requires chapter_idx).
     pub section_idx: Option<u16>,
     /// Maximum number of results (default 50).
+    #[schemars(range(min = 1))]
     pub limit: Option<u16>,
 }
 
@@ -1929,12 +3704,16 @@ pub struct DbChunkListParams {
 pub struct DbSectionSearchParams {
     /// Natural language search query.
     pub query: String,
-    /// Scope to papers in this named selection.
+    /// Scope to papers in this named selection. Defaults to the DB scope set
+    /// by `selection_scope`, if any.
+    #[schemars(extend("examples" = ["thesis-related-work"]))]
     pub selection: Option<String>,
     /// Scope to a single paper by DOI, item key, or title search.
     pub work: Option<String>,
     /// Scope to a chapter (requires work).
     pub chapter_idx: Option<u16>,
+    /// Scope to a chapter by title instead, e.g. "Results" (requires work).
+    pub chapter: Option<String>,
     /// Minimum publication year filter.
     pub filter_year_min: Option<u16>,
     /// Maximum publication year filter.
@@ -1944,6 +3723,7 @@ pub struct DbSectionSearchParams {
     /// Filter by tags (any match).
     pub filter_tags: Option<Vec<String>>,
     /// Maximum number of results (default 5).
+    #[schemars(range(min = 1))]
     pub limit: Option<u16>,
 }
 
@@ -1959,7 +3739,9 @@ pub struct DbSectionListParams {
 pub struct DbChapterSearchParams {
     /// Natural language search query.
     pub query: String,
-    /// Scope to papers in this named selection.
+    /// Scope to papers in this named selection. Defaults to the DB scope set
+    /// by `selection_scope`, if any.
+    #[schemars(extend("examples" = ["thesis-related-work"]))]
     pub selection: Option<String>,
     /// Scope to a single paper by DOI, item key, or title search.
     pub work: Option<String>,
@@ -1972,6 +3754,7 @@ pub struct DbChapterSearchParams {
     /// Filter by tags (any match).
     pub filter_tags: Option<Vec<String>>,
     /// Maximum number of results (default 5).
+    #[schemars(range(min = 1))]
     pub limit: Option<u16>,
 }
 
@@ -0,0 +1,96 @@
+//! MCP resources for the local DB: each indexed paper is exposed as
+//! `papers://paper/{paper_id}` (its outline, as JSON) and each of its chapters
+//! as `papers://paper/{paper_id}/chapter/{chapter_idx}` (the chapter text, as
+//! Markdown).
+//!
+//! Paper IDs are DOIs or Zotero keys and are used verbatim, so a DOI's `/`
+//! stays in the URI; the chapter suffix is matched from the end.
+
+use papers_db::ChapterResult;
+
+/// Scheme and authority shared by all paper resource URIs.
+pub const PAPER_URI_PREFIX: &str = "papers://paper/";
+
+/// URI template for chapter resources, as advertised in `resources/templates/list`.
+pub const CHAPTER_URI_TEMPLATE: &str = "papers://paper/{paper_id}/chapter/{chapter_idx}";
+
+/// Papers per `resources/list` page.
+pub const RESOURCE_PAGE_SIZE: usize = 25;
+
+/// A parsed paper resource URI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PaperResource {
+    Paper { paper_id: String },
+    Chapter { paper_id: String, chapter_idx: u16 },
+}
+
+/// `papers://paper/{paper_id}`
+pub fn paper_uri(paper_id: &str) -> String {
+    format!("{PAPER_URI_PREFIX}{paper_id}")
+}
+
+/// `papers://paper/{paper_id}/chapter/{chapter_idx}`
+pub fn chapter_uri(paper_id: &str, chapter_idx: u16) -> String {
+    format!("{PAPER_URI_PREFIX}{paper_id}/chapter/{chapter_idx}")
+}
+
+/// Parse a paper or chapter resource URI. Returns `None` for anything else.
+pub fn parse_resource_uri(uri: &str) -> Option<PaperResource> {
+    let rest = uri.strip_prefix(PAPER_URI_PREFIX)?.trim_end_matches('/');
+    if let Some((paper_id, idx)) = rest.rsplit_once("/chapter/")
+        && let Ok(chapter_idx) = idx.parse::<u16>()
+        && !paper_id.is_empty()
+    {
+        return Some(PaperResource::Chapter { paper_id: paper_id.to_string(), chapter_idx });
+    }
+    (!rest.is_empty()).then(|| PaperResource::Paper { paper_id: rest.to_string() })
+}
+
+/// Render a chapter as Markdown: the chapter title, then each section's title
+/// followed by its chunks in reading order.
+pub fn chapter_markdown(chapter: &ChapterResult) -> String {
+    let mut out = format!("# {}\n", chapter.chapter_title);
+    for section in &chapter.sections {
+        if !section.section_title.is_empty() && section.section_title != chapter.chapter_title {
+            out.push_str(&format!("\n## {}\n", section.section_title));
+        }
+        for chunk in &section.chunks {
+            out.push('\n');
+            out.push_str(chunk.text.trim());
+            out.push('\n');
+        }
+    }
+    if !chapter.exhibit_ids.is_empty() {
+        out.push_str(&format!("\nExhibits: {}\n", chapter.exhibit_ids.join(", ")));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chapter_uris_round_trip_with_doi_paper_ids() {
+        let uri = chapter_uri("10.1145/3592433", 2);
+        assert_eq!(uri, "papers://paper/10.1145/3592433/chapter/2");
+        assert_eq!(
+            parse_resource_uri(&uri),
+            Some(PaperResource::Chapter { paper_id: "10.1145/3592433".into(), chapter_idx: 2 })
+        );
+        assert_eq!(
+            parse_resource_uri(&paper_uri("10.1145/3592433")),
+            Some(PaperResource::Paper { paper_id: "10.1145/3592433".into() })
+        );
+    }
+
+    #[test]
+    fn rejects_foreign_and_empty_uris() {
+        assert_eq!(parse_resource_uri("file:///tmp/x"), None);
+        assert_eq!(parse_resource_uri("papers://paper/"), None);
+        assert_eq!(
+            parse_resource_uri("papers://paper/ABCD1234/chapter/x"),
+            Some(PaperResource::Paper { paper_id: "ABCD1234/chapter/x".into() })
+        );
+    }
+}
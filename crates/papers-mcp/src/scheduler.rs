@@ -0,0 +1,222 @@
+//! Runs the `schedule` entries from `config.json` while the server is up.
+//!
+//! Each entry gets its own timer. The first run happens one interval after
+//! startup, so short-lived sessions don't pay for maintenance on launch.
+//! A tenant's timers run inside its [`papers_core::tenant::scope`], so they
+//! only see the tenant's caches and selections.
+//!
+//! `zotero_sync` polls Zotero like `papers db watch`, but the server has no
+//! extraction pipeline: new items are indexed when their extraction is cached
+//! or can be restored from its `papers_extract_*.zip` backup in Zotero, and
+//! stay pending (retried every poll) until `papers db watch` extracts them.
+//!
+//! `digest` covers the days since its previous run and announces only works
+//! not announced before, so each new work reaches the configured webhooks and
+//! email once.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use papers_core::OpenAlexClient;
+use papers_core::schedule::{ScheduleEntry, ScheduledTask};
+use tokio::task::JoinHandle;
+
+/// Spawn a timer per valid entry, scoped to `data_dir` when it is a tenant's.
+/// `zotero_profile` is the profile `zotero_sync` and `digest` connect with (see
+/// [`papers_core::zotero_profiles`]). Entries with a bad interval are reported
+/// on stderr and skipped.
+pub fn spawn(
+    entries: Vec<ScheduleEntry>,
+    client: OpenAlexClient,
+    db: Option<Arc<papers_db::DbStore>>,
+    data_dir: Option<PathBuf>,
+    zotero_profile: Option<String>,
+) -> Vec<JoinHandle<()>> {
+    let mut handles = Vec::new();
+    for entry in entries {
+        let period = match entry.interval() {
+            Ok(p) => p,
+            Err(e) => {
+                eprintln!("schedule: skipping {:?}: {e}", entry.task);
+                continue;
+            }
+        };
+        let client = client.clone();
+        let db = db.clone();
+        let data_dir = data_dir.clone();
+        let zotero_profile = zotero_profile.clone();
+        handles.push(tokio::spawn(async move {
+            let start = tokio::time::Instant::now() + period;
+            let mut timer = tokio::time::interval_at(start, period);
+            timer.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                timer.tick().await;
+                let run = run_task(entry.task, period, &client, db.as_deref(), zotero_profile.as_deref());
+                let result = match &data_dir {
+                    Some(dir) => papers_core::tenant::scope(dir, run).await,
+                    None => run.await,
+                };
+                match result {
+                    Ok(summary) => eprintln!("schedule: {:?}: {summary}", entry.task),
+                    Err(e) => eprintln!("schedule: {:?} failed: {e}", entry.task),
+                }
+            }
+        }));
+    }
+    handles
+}
+
+/// Run one task now, returning a one-line summary. `period` is the task's
+/// interval, which sets the `digest` window.
+pub async fn run_task(
+    task: ScheduledTask,
+    period: Duration,
+    client: &OpenAlexClient,
+    db: Option<&papers_db::DbStore>,
+    zotero_profile: Option<&str>,
+) -> Result<String, String> {
+    match task {
+        ScheduledTask::CacheEvict => {
+            let removed = papers_core::api::cache_prune(client);
+            Ok(format!("removed {removed} cached responses"))
+        }
+        ScheduledTask::LibrarySync => {
+            let db = db.ok_or("DB store unavailable")?;
+            let (mut ingested, mut failed) = (0usize, 0usize);
+            for key in papers_db::list_cached_item_keys() {
+                let params = match papers_db::ingest_params_from_cache(&key) {
+                    Ok(p) => p,
+                    Err(_) => {
+                        failed += 1;
+                        continue;
+                    }
+                };
+                if papers_db::is_ingested(db, &params.paper_id).await {
+                    continue;
+                }
+                match papers_db::ingest_paper(db, params).await {
+                    Ok(_) => ingested += 1,
+                    Err(e) => {
+                        eprintln!("schedule: library_sync: {key}: {e}");
+                        failed += 1;
+                    }
+                }
+            }
+            Ok(format!("indexed {ingested} papers ({failed} failed)"))
+        }
+        ScheduledTask::ZoteroSync => {
+            let db = db.ok_or("DB store unavailable")?;
+            let zotero = papers_core::zotero_profiles::connect(zotero_profile).await.map_err(|e| e.to_string())?;
+            let watched = papers_core::config::PapersConfig::load().map(|c| c.watch_collections).unwrap_or_default();
+            let mut collections = Vec::new();
+            for input in &watched {
+                let key = papers_core::zotero::resolve_collection_key(&zotero, input).await;
+                collections.push(key.map_err(|e| e.to_string())?);
+            }
+            let mut state = papers_core::sync::SyncState::load().map_err(|e| e.to_string())?;
+            let plan = papers_core::sync::plan_sync(&zotero, &state, &collections).await.map_err(|e| e.to_string())?;
+            let report = papers_db::sync::apply_sync(db, &plan, &mut state, async |key: &str| {
+                restore_extraction(&zotero, key).await
+            })
+            .await;
+            state.save().map_err(|e| e.to_string())?;
+            Ok(format!(
+                "indexed {} papers, removed {} ({} pending, library version {})",
+                report.indexed.len(),
+                report.removed.len(),
+                report.failed.len(),
+                state.library_version
+            ))
+        }
+        ScheduledTask::Digest => {
+            let channels = papers_core::notify::configured_channels();
+            if channels.is_empty() {
+                return Err("no webhooks or email configured in config.json".to_string());
+            }
+            let config = papers_core::config::PapersConfig::load().map_err(|e| e.to_string())?;
+            let mut selections = Vec::new();
+            for input in &config.digest_selections {
+                let name = papers_core::selection::resolve_selection(input).map_err(|e| e.to_string())?;
+                selections.push(papers_core::selection::load_selection(&name).map_err(|e| e.to_string())?);
+            }
+            let zotero = papers_core::zotero_profiles::connect(zotero_profile).await.ok();
+            let days = papers_core::schedule::digest_days(period);
+            let digest = papers_core::api::digest_generate(
+                client,
+                zotero.as_ref(),
+                &selections,
+                &config.digest_watches,
+                days,
+            )
+            .await;
+            let report = papers_core::notify::notify_digest(&digest, &channels).await.map_err(|e| e.to_string())?;
+            let failed = report.deliveries.iter().filter(|d| !d.ok).count();
+            Ok(format!(
+                "announced {} new works over {days} days ({} already announced, {failed} deliveries failed)",
+                report.notified.len(),
+                report.already_notified
+            ))
+        }
+    }
+}
+
+/// Make the extraction of `key` available to ingest: already cached, or
+/// restored from its `papers_extract_*.zip` backup in Zotero.
+async fn restore_extraction(zotero: &papers_zotero::ZoteroClient, key: &str) -> Result<(), String> {
+    if papers_core::extract_cache::extract_cached(key) || papers_core::text::datalab_cached_markdown(key).is_some() {
+        return Ok(());
+    }
+    let backup = papers_core::text::find_papers_zip_key(zotero, key).await.map_err(|e| e.to_string())?;
+    match backup {
+        Some(att_key) => papers_core::text::download_extraction_from_zotero(zotero, &att_key, key)
+            .await
+            .map_err(|e| e.to_string()),
+        None => Err("not extracted yet; run `papers db watch` to extract it".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: Duration = Duration::from_secs(3600);
+
+    #[tokio::test]
+    async fn cache_evict_without_cache_is_a_no_op() {
+        let summary = run_task(ScheduledTask::CacheEvict, HOUR, &OpenAlexClient::new(), None, None).await;
+        assert_eq!(summary.as_deref(), Ok("removed 0 cached responses"));
+    }
+
+    #[tokio::test]
+    async fn library_sync_needs_the_db() {
+        let result = run_task(ScheduledTask::LibrarySync, HOUR, &OpenAlexClient::new(), None, None).await;
+        assert_eq!(result, Err("DB store unavailable".to_string()));
+    }
+
+    #[tokio::test]
+    async fn zotero_sync_needs_the_db() {
+        let result = run_task(ScheduledTask::ZoteroSync, HOUR, &OpenAlexClient::new(), None, None).await;
+        assert_eq!(result, Err("DB store unavailable".to_string()));
+    }
+
+    #[tokio::test]
+    async fn digest_needs_a_channel() {
+        let dir = std::env::temp_dir().join(format!("papers-scheduler-digest-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let run = run_task(ScheduledTask::Digest, HOUR, &OpenAlexClient::new(), None, None);
+        let result = papers_core::tenant::scope(&dir, run).await;
+        assert_eq!(result, Err("no webhooks or email configured in config.json".to_string()));
+    }
+
+    #[tokio::test]
+    async fn invalid_intervals_are_skipped() {
+        let entries = vec![
+            ScheduleEntry { task: ScheduledTask::CacheEvict, every: "soon".to_string() },
+            ScheduleEntry { task: ScheduledTask::CacheEvict, every: "1h".to_string() },
+        ];
+        let handles = spawn(entries, OpenAlexClient::new(), None, None, None);
+        assert_eq!(handles.len(), 1);
+        handles.iter().for_each(JoinHandle::abort);
+    }
+}
@@ -1,85 +1,308 @@
-use papers_core::{filter::FilterError, zotero as zotero_resolve, DiskCache, OpenAlexClient};
+use papers_core::{
+    filter::FilterError, zotero as zotero_resolve, CachePolicy, DiskCache, OpenAlexClient,
+};
+use papers_core::audit::{AuditLog, AuditRecord};
+use papers_core::crossref::CrossrefClient;
+use papers_core::semantic_scholar::SemanticScholarClient;
+use papers_core::tenant::Tenant;
 use papers_zotero::ZoteroClient;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Duration;
-use rmcp::handler::server::tool::ToolRouter;
+use rmcp::handler::server::tool::{ToolCallContext, ToolRouter};
 use rmcp::handler::server::wrapper::Parameters;
-use rmcp::model::{ServerCapabilities, ServerInfo};
-use rmcp::service::RoleServer;
-use rmcp::{Peer, ServerHandler, tool, tool_handler, tool_router};
+use rmcp::model::{
+    AnnotateAble, CallToolRequestParams, CallToolResult, Content, ListToolsResult, ListResourceTemplatesResult, ListResourcesResult,
+    PaginatedRequestParams, RawResource, RawResourceTemplate, ReadResourceRequestParams,
+    ReadResourceResult, ResourceContents, ServerCapabilities, ServerInfo, Tool,
+};
+use rmcp::service::{RequestContext, RoleServer};
+use rmcp::{ErrorData as McpError, Peer, ServerHandler, tool, tool_router};
 use serde::Serialize;
 
+use crate::limits::{Backend, Limits};
+use crate::metrics::Metrics;
+use crate::resources::{self, PaperResource};
+use crate::zotero_slot::ZoteroSlot;
 use crate::params::{
-    AutocompleteToolParams, AuthorListToolParams, AuthorSearchToolParams,
+    AutocompleteToolParams, AuthorAffiliationHistoryToolParams, AuthorListToolParams, AuthorProfileToolParams, AuthorResolveToolParams, AuthorSearchToolParams,
+    CacheClearToolParams,
     DomainListToolParams, DomainSearchToolParams, FieldListToolParams, FieldSearchToolParams,
     FindWorksToolParams, FunderListToolParams, FunderSearchToolParams, GetToolParams,
-    InstitutionListToolParams, InstitutionSearchToolParams, PublisherListToolParams, PublisherSearchToolParams,
-    DbChapterGetParams, DbChapterListParams, DbChapterSearchParams,
-    DbChunkGetParams, DbChunkListParams, DbChunkSearchParams,
+    InstitutionListToolParams, InstitutionSearchToolParams, LibrarySearchToolParams,
+    OpenAlexRawToolParams,
+    PublisherListToolParams, PublisherSearchToolParams,
+    DbChapterGetParams, DbChapterListParams, DbChapterSearchParams, DbCitationContextsParams,
+    DbCitationGraphParams,
+    DbChunkGetParams, DbChunkListParams, DbChunkLocateParams, DbChunkSearchParams, DbEquationSearchParams,
     DbExhibitGetParams, DbExhibitSearchParams,
     DbSectionGetParams, DbSectionListParams, DbSectionSearchParams, DbTagListParams,
-    DbWorkGetParams, DbWorkListParams, DbWorkOutlineParams, DbWorkSearchParams,
-    SelectionAddToolParams, SelectionCreateToolParams,
-    SelectionDeleteToolParams, SelectionGetToolParams, SelectionListToolParams,
-    SelectionRemoveToolParams, SourceListToolParams, SourceSearchToolParams,
+    DbWorkGetParams, DbWorkListParams, DbWorkOutlineParams, DbWorkRemoveParams, DbWorkSearchParams,
+    DbWorkClusterParams, DbWorkSimilarParams, DbWorkTimelineParams,
+    DbAbstractIndexParams, DbAbstractSearchParams, DbDraftGroundParams, DbClaimVerifyParams,
+    SelectionAddBulkToolParams, SelectionAddCitersToolParams, SelectionAddQueryToolParams, SelectionAddReferencesToolParams, SelectionAddToolParams, SelectionAnnotateToolParams, SelectionApcReportToolParams, SelectionCreateToolParams,
+    SelectionCiteToolParams, SelectionDeleteToolParams, SelectionExportToolParams, SelectionGetToolParams,
+    SelectionListToolParams,
+    SelectionPrismaToolParams, SelectionRemoveToolParams, SelectionScreenToolParams,
+    SelectionSdgReportToolParams, SelectionSiteToolParams, SelectionScopeToolParams, SelectionTagToolParams,
+    SelectionFromCollectionToolParams, SelectionSyncToZoteroToolParams,
+    SelectionCitationGraphToolParams, SelectionTimelineToolParams, DigestGenerateToolParams, DigestNotifyToolParams,
+    PaperStatusGetToolParams, PaperStatusSetToolParams,
+    SelectionScreeningConflictsToolParams, SelectionScreeningExportToolParams,
+    SelectionScreeningImportToolParams, SelectionScreeningStatusToolParams, SourceListToolParams, SourceSearchToolParams,
     SubfieldListToolParams, SubfieldSearchToolParams, TopicListToolParams, TopicSearchToolParams,
-    WorkListToolParams, WorkSearchToolParams, WorkTextToolParams,
+    WorkApcSummaryToolParams, WorkCitationGraphToolParams, WorkListToolParams,
+    WorkPublishedVersionToolParams, WorkQualityToolParams, WorkRelatedToolParams, WorkSearchReferencesToolParams, ReviewContextToolParams, FormatCitationToolParams,
+    WorkSearchToolParams, WorkStatsToolParams, WorkMetadataDiffToolParams,
+    WorkExtractIdentifiersToolParams, WorkReferencesExtractedToolParams, WorkTextToolParams,
     ZoteroAnnotationListToolParams, ZoteroAttachmentListToolParams, ZoteroAttachmentSearchToolParams,
-    ZoteroCollectionListToolParams, ZoteroCollectionNotesToolParams,
+    ZoteroCollectionAttachmentsToolParams, ZoteroCollectionListToolParams,
+    ZoteroCollectionNotesToolParams,
     ZoteroCollectionSubcollectionsToolParams, ZoteroCollectionTagsToolParams,
-    ZoteroCollectionWorksToolParams, ZoteroDeletedListToolParams,
-    ZoteroKeyToolParams, ZoteroNoParamsToolParams,
-    ZoteroNoteListToolParams, ZoteroNoteSearchToolParams,
+    ZoteroCollectionWorksToolParams, ZoteroDeletedListToolParams, ZoteroFulltextSearchToolParams,
+    ZoteroItemTypeListToolParams, ZoteroItemTypeToolParams,
+    ZoteroKeyToolParams, ZoteroLibraryKeyToolParams, ZoteroNoParamsToolParams,
+    ZoteroNoteListToolParams, ZoteroNoteSearchToolParams, ZoteroRawToolParams,
     ZoteroSettingGetToolParams, ZoteroTagGetToolParams, ZoteroTagListToolParams, ZoteroTagSearchToolParams,
-    ZoteroWorkChildrenToolParams, ZoteroWorkListToolParams, ZoteroWorkSearchToolParams, ZoteroWorkTagsToolParams,
+    ZoteroWorkChildrenToolParams, ZoteroWorkCreateToolParams, ZoteroWorkListToolParams,
+    ZoteroWorkSearchToolParams, ZoteroWorkTagsToolParams, ZoteroWorkUpdateToolParams, WorkEnrichToolParams,
+    CollectionScope, TagScope,
 };
 
 #[derive(Clone)]
 pub struct PapersMcp {
     client: OpenAlexClient,
-    zotero: Arc<tokio::sync::Mutex<Option<ZoteroClient>>>,
+    zotero: ZoteroSlot,
+    /// Zotero profile used when a call names none (see [`papers_core::zotero_profiles`]).
+    zotero_profile: Option<String>,
+    /// Fallback for DOIs OpenAlex doesn't know yet (see [`papers_core::crossref`]).
+    crossref: Option<CrossrefClient>,
+    /// TLDRs, influential citations and recommendations for `work_enrich`.
+    semantic_scholar: Option<SemanticScholarClient>,
     db: Option<Arc<papers_db::DbStore>>,
+    /// Tenant data directory; tool calls run inside [`papers_core::tenant::scope`].
+    data_dir: Option<PathBuf>,
+    /// Tenant name, recorded in the audit log.
+    user: Option<String>,
+    /// Where tool calls are recorded, in HTTP mode (see [`papers_core::audit`]).
+    audit: Option<Arc<AuditLog>>,
+    /// Usage counters for `server_stats`, when enabled (see [`crate::metrics`]).
+    metrics: Option<Metrics>,
+    /// Per-backend concurrency limits, shared by all tool calls (see [`crate::limits`]).
+    limits: Limits,
+    /// Whether [`WRITE_TOOLS`] are withheld and `zotero_raw` is GET-only.
+    read_only: bool,
     tool_router: ToolRouter<Self>,
 }
 
 impl PapersMcp {
     pub async fn new() -> Self {
         let mut client = OpenAlexClient::new();
-        if let Ok(cache) = DiskCache::default_location(Duration::from_secs(600)) {
+        if let Ok(cache) = DiskCache::default_location_with_policy(CachePolicy::from_env()) {
             client = client.with_cache(cache);
         }
-        let db = Self::open_db_store().await;
+        let db = Self::open_db_store(&papers_db::DbStore::default_path()).await;
         Self {
             client,
-            zotero: Arc::new(tokio::sync::Mutex::new(None)),
+            zotero: ZoteroSlot::new(),
+            zotero_profile: None,
+            crossref: Some(CrossrefClient::new()),
+            semantic_scholar: Some(SemanticScholarClient::new()),
             db,
-            tool_router: Self::tool_router(),
+            data_dir: None,
+            user: None,
+            audit: None,
+            metrics: None,
+            limits: Limits::from_env(),
+            read_only: read_only_from_env(),
+            tool_router: Self::configured_tool_router(),
         }
     }
 
     pub async fn with_client(client: OpenAlexClient) -> Self {
-        let db = Self::open_db_store().await;
+        let db = Self::open_db_store(&papers_db::DbStore::default_path()).await;
         Self {
             client,
-            zotero: Arc::new(tokio::sync::Mutex::new(None)),
+            zotero: ZoteroSlot::new(),
+            zotero_profile: None,
+            crossref: None,
+            semantic_scholar: None,
             db,
-            tool_router: Self::tool_router(),
+            data_dir: None,
+            user: None,
+            audit: None,
+            metrics: None,
+            limits: Limits::from_env(),
+            read_only: read_only_from_env(),
+            tool_router: Self::configured_tool_router(),
+        }
+    }
+
+    /// Create a server for one tenant of a shared deployment, with its RAG
+    /// store, selections and OpenAlex cache under the tenant's data directory
+    /// (see [`papers_core::tenant`]). A Zotero profile named after the tenant
+    /// becomes its default library.
+    pub async fn for_tenant(tenant: &Tenant) -> Self {
+        let data_dir = tenant.data_dir();
+        let mut client = OpenAlexClient::new();
+        let cache_dir = data_dir.join("cache").join("requests");
+        if let Ok(cache) = DiskCache::with_policy(cache_dir, CachePolicy::from_env()) {
+            client = client.with_cache(cache);
+        }
+        let db = Self::open_db_store(&data_dir.join("rag").to_string_lossy()).await;
+        Self {
+            client,
+            zotero: ZoteroSlot::new(),
+            zotero_profile: Self::tenant_zotero_profile(&tenant.name),
+            crossref: Some(CrossrefClient::new()),
+            semantic_scholar: Some(SemanticScholarClient::new()),
+            db,
+            data_dir: Some(data_dir),
+            user: Some(tenant.name.clone()),
+            audit: None,
+            metrics: None,
+            limits: Limits::from_env(),
+            read_only: read_only_from_env(),
+            tool_router: Self::configured_tool_router(),
+        }
+    }
+
+    /// Record every tool call in `audit`.
+    pub fn with_audit(mut self, audit: Arc<AuditLog>) -> Self {
+        self.audit = Some(audit);
+        self
+    }
+
+    /// Count tool calls, latencies and cache lookups in `metrics`.
+    pub fn with_metrics(mut self, metrics: Metrics) -> Self {
+        if let Some(cache) = self.client.cache() {
+            metrics.watch_cache(cache.clone());
+        }
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Look up DOIs OpenAlex doesn't know in Crossref. Servers built with
+    /// [`with_client`](Self::with_client) have no Crossref fallback until this is called.
+    pub fn with_crossref(mut self, crossref: CrossrefClient) -> Self {
+        self.crossref = Some(crossref);
+        self
+    }
+
+    /// Enable `work_enrich`. Servers built with [`with_client`](Self::with_client)
+    /// have no Semantic Scholar client until this is called.
+    pub fn with_semantic_scholar(mut self, client: SemanticScholarClient) -> Self {
+        self.semantic_scholar = Some(client);
+        self
+    }
+
+    /// Use `limits` instead of [`Limits::from_env`], e.g. to share one set of
+    /// limits between the servers of several tenants.
+    pub fn with_limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Offer only the tools in `enabled` (all when empty), minus those in
+    /// `disabled`. Unknown names are reported on stderr.
+    pub fn with_tool_filter(mut self, enabled: &[String], disabled: &[String]) -> Self {
+        filter_tools(&mut self.tool_router, enabled, disabled);
+        self
+    }
+
+    /// Withhold the [`WRITE_TOOLS`] and refuse `zotero_raw` writes, e.g. for
+    /// agents pointed at a shared library.
+    pub fn with_read_only(mut self) -> Self {
+        for name in WRITE_TOOLS {
+            self.tool_router.remove_route(name);
+        }
+        self.read_only = true;
+        self
+    }
+
+    /// Whether the server was made read-only (see [`Self::with_read_only`]).
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// All tools, filtered by `PAPERS_TOOLS_ENABLED` and `PAPERS_TOOLS_DISABLED`
+    /// (comma-separated names, see [`papers_core::settings`]), without the
+    /// [`WRITE_TOOLS`] when `PAPERS_READ_ONLY` is set.
+    fn configured_tool_router() -> ToolRouter<Self> {
+        let list = |var: &str| -> Vec<String> {
+            std::env::var(var)
+                .unwrap_or_default()
+                .split(',')
+                .map(str::trim)
+                .filter(|name| !name.is_empty())
+                .map(str::to_string)
+                .collect()
+        };
+        let mut router = Self::tool_router();
+        filter_tools(&mut router, &list("PAPERS_TOOLS_ENABLED"), &list("PAPERS_TOOLS_DISABLED"));
+        if read_only_from_env() {
+            for name in WRITE_TOOLS {
+                router.remove_route(name);
+            }
         }
+        router
     }
 
     /// Create a server with an explicit Zotero client (for testing).
     pub fn with_zotero(zotero: ZoteroClient) -> Self {
         Self {
             client: OpenAlexClient::new(),
-            zotero: Arc::new(tokio::sync::Mutex::new(Some(zotero))),
+            zotero: ZoteroSlot::with_client(zotero),
+            zotero_profile: None,
+            crossref: None,
+            semantic_scholar: None,
             db: None,
+            data_dir: None,
+            user: None,
+            audit: None,
+            metrics: None,
+            limits: Limits::default(),
+            read_only: false,
             tool_router: Self::tool_router(),
         }
     }
 
-    async fn open_db_store() -> Option<Arc<papers_db::DbStore>> {
-        let path = papers_db::DbStore::default_path();
-        match papers_db::DbStore::open(&path).await {
+    /// Use Zotero profile `name` when a call names none, instead of
+    /// `PAPERS_ZOTERO_PROFILE` or the `ZOTERO_*` environment variables.
+    pub fn with_zotero_profile(mut self, name: impl Into<String>) -> Self {
+        self.zotero_profile = Some(name.into());
+        self
+    }
+
+    /// The tenant's own Zotero profile, if one is stored under its name.
+    fn tenant_zotero_profile(tenant: &str) -> Option<String> {
+        let store = papers_core::zotero_profiles::ProfileStore::load().ok()?;
+        store.get(tenant).map(|_| tenant.to_string())
+    }
+
+    /// Whether the RAG database opened.
+    pub fn has_db(&self) -> bool {
+        self.db.is_some()
+    }
+
+    /// Start timers for the configured `schedule` entries (see [`crate::scheduler`]),
+    /// read from the tenant's `config.json` for a tenant's server.
+    pub async fn start_scheduler(&self) -> Vec<tokio::task::JoinHandle<()>> {
+        let entries = match &self.data_dir {
+            Some(dir) => papers_core::tenant::scope(dir, async { papers_core::schedule::configured_schedule() }).await,
+            None => papers_core::schedule::configured_schedule(),
+        };
+        crate::scheduler::spawn(
+            entries,
+            self.client.clone(),
+            self.db.clone(),
+            self.data_dir.clone(),
+            self.zotero_profile.clone(),
+        )
+    }
+
+    async fn open_db_store(path: &str) -> Option<Arc<papers_db::DbStore>> {
+        match papers_db::DbStore::open(path).await {
             Ok(store) => {
                 let store = Arc::new(store);
                 // Eagerly load the embedding model so first search is fast
@@ -95,6 +318,95 @@ impl PapersMcp {
         }
     }
 
+    /// One page of paper and chapter resources, `RESOURCE_PAGE_SIZE` papers
+    /// at a time. The cursor is the offset of the next paper.
+    pub async fn list_paper_resources(
+        &self,
+        cursor: Option<&str>,
+    ) -> Result<ListResourcesResult, McpError> {
+        let Some(rag) = self.db.as_ref() else {
+            return Ok(ListResourcesResult::default());
+        };
+        let offset = match cursor {
+            Some(c) => c
+                .parse::<usize>()
+                .map_err(|_| McpError::invalid_params(format!("Invalid cursor: {c}"), None))?,
+            None => 0,
+        };
+        let params = papers_db::ListPapersParams {
+            paper_ids: None,
+            filter_year_min: None,
+            filter_year_max: None,
+            filter_venue: None,
+            filter_tags: None,
+            filter_authors: None,
+            sort_by: None,
+            limit: u16::MAX,
+        };
+        let papers = papers_db::query::list_papers(rag, params)
+            .await
+            .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+        let mut listed = Vec::new();
+        for paper in papers.iter().skip(offset).take(resources::RESOURCE_PAGE_SIZE) {
+            let outline = papers_db::query::get_paper_outline(rag, &paper.paper_id)
+                .await
+                .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+            let uri = resources::paper_uri(&paper.paper_id);
+            let mut resource = RawResource::new(uri, &paper.title);
+            resource.description = Some(format!("Outline of {}", paper.title));
+            resource.mime_type = Some("application/json".into());
+            listed.push(resource.no_annotation());
+            for chapter in &outline.chapters {
+                let uri = resources::chapter_uri(&paper.paper_id, chapter.chapter_idx);
+                let mut resource = RawResource::new(
+                    uri,
+                    format!("{} — {}", paper.title, chapter.chapter_title),
+                );
+                resource.mime_type = Some("text/markdown".into());
+                listed.push(resource.no_annotation());
+            }
+        }
+        let next = offset + resources::RESOURCE_PAGE_SIZE;
+        Ok(ListResourcesResult {
+            meta: None,
+            next_cursor: (next < papers.len()).then(|| next.to_string()),
+            resources: listed,
+        })
+    }
+
+    /// Read a `papers://paper/...` resource: the outline JSON for a paper,
+    /// or the Markdown text of one chapter.
+    pub async fn read_paper_resource(&self, uri: &str) -> Result<ReadResourceResult, McpError> {
+        let not_found = |msg: String| McpError::resource_not_found(msg, None);
+        let resource = resources::parse_resource_uri(uri)
+            .ok_or_else(|| not_found(format!("Unknown resource: {uri}")))?;
+        let rag = self.db.as_ref().ok_or_else(|| not_found("DB not configured.".into()))?;
+        let (mime_type, text) = match resource {
+            PaperResource::Paper { paper_id } => {
+                let outline = papers_db::query::get_paper_outline(rag, &paper_id)
+                    .await
+                    .map_err(|e| not_found(e.to_string()))?;
+                let text = serde_json::to_string_pretty(&outline)
+                    .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+                ("application/json", text)
+            }
+            PaperResource::Chapter { paper_id, chapter_idx } => {
+                let chapter = papers_db::query::get_chapter(rag, &paper_id, chapter_idx)
+                    .await
+                    .map_err(|e| not_found(e.to_string()))?;
+                ("text/markdown", resources::chapter_markdown(&chapter))
+            }
+        };
+        Ok(ReadResourceResult {
+            contents: vec![ResourceContents::TextResourceContents {
+                uri: uri.to_string(),
+                mime_type: Some(mime_type.into()),
+                text,
+                meta: None,
+            }],
+        })
+    }
+
     fn resolve_selection_paper_ids(selection: &str) -> Result<Vec<String>, String> {
         let sel = papers_core::selection::load_selection(selection)
             .map_err(|e| e.to_string())?;
@@ -108,48 +420,125 @@ impl PapersMcp {
         Ok(ids)
     }
 
-    /// Try to get the Zotero client, probing if not yet connected.
-    /// Caches a successful connection for future calls. Returns:
-    /// - `Ok(Some(client))` — connected
-    /// - `Ok(None)` — not configured (env vars absent)
-    /// - `Err(msg)` — installed but not running (`NotRunning` error)
+    /// IDs of the papers in a Zotero collection (key or name), for scoping DB tools.
+    async fn resolve_collection_paper_ids(&self, collection: &str) -> Result<Vec<String>, String> {
+        let z = self.require_zotero().await?;
+        let ids = papers_core::zotero::collection_paper_ids(&z, collection).await.map_err(|e| e.to_string())?;
+        if ids.is_empty() {
+            return Err(format!("collection {collection} has no items"));
+        }
+        Ok(ids)
+    }
+
+    /// Selection a DB tool is scoped to: `selection`, else the default DB scope
+    /// (set by `selection_scope`) unless the call names a paper instead.
+    fn db_scope_selection(selection: Option<String>, names_paper: bool) -> Option<String> {
+        selection.or_else(|| if names_paper { None } else { papers_core::selection::db_scope_name() })
+    }
+
+    /// Chapter and section indices for a DB call scoped to `work_id`, given as indices or as
+    /// titles matched against its outline. Titles need a single paper to match against.
+    async fn resolve_outline_scope(
+        rag: &papers_db::DbStore,
+        work_id: Option<&str>,
+        chapter_idx: Option<u16>,
+        chapter: Option<String>,
+        section_idx: Option<u16>,
+        section: Option<String>,
+    ) -> Result<(Option<u16>, Option<u16>), String> {
+        match work_id {
+            Some(id) => papers_db::resolve_outline_scope(rag, id, chapter_idx, chapter.as_deref(), section_idx, section.as_deref())
+                .await
+                .map_err(|e| e.to_string()),
+            None if chapter.is_some() || section.is_some() => Err("chapter and section titles require work".to_string()),
+            None => Ok((chapter_idx, section_idx)),
+        }
+    }
+
+    /// Resolve an optional selection name or index, falling back to the active selection.
+    fn resolve_selection_name(selection: Option<String>) -> Result<String, String> {
+        use papers_core::selection::{active_selection_name, resolve_selection};
+        match selection {
+            Some(s) => resolve_selection(&s).map_err(|e| e.to_string()),
+            None => active_selection_name().ok_or_else(|| "no active selection; use selection param or create one first".to_string()),
+        }
+    }
+
+    /// Parse an optional screening stage, defaulting to title/abstract.
+    fn parse_screening_stage(stage: Option<String>) -> Result<papers_core::screening::ScreeningStage, String> {
+        match stage {
+            Some(s) => s.parse().map_err(|e: papers_core::screening::ScreeningError| e.to_string()),
+            None => Ok(papers_core::screening::ScreeningStage::TitleAbstract),
+        }
+    }
+
+    /// Probe for Zotero: the server's profile if it has one, else the local
+    /// API if it is running, else the web API.
+    async fn probe_zotero(&self) -> Result<ZoteroClient, String> {
+        papers_core::zotero_profiles::connect(self.zotero_profile.as_deref()).await.map_err(|e| e.to_string())
+    }
+
     /// Try to get a Zotero client for optional enrichment.
     ///
     /// Returns `Ok(None)` for any error — including "installed but not running" — so that
     /// OpenAlex tools like `work_get` can still serve results without Zotero enrichment.
+    /// Probes are single-flight and failures are cached briefly (see [`ZoteroSlot`]).
     async fn get_optional_zotero(&self) -> Result<Option<ZoteroClient>, String> {
-        let mut lock = self.zotero.lock().await;
-        if let Some(z) = lock.as_ref() {
-            return Ok(Some(z.clone()));
-        }
-        match ZoteroClient::from_env_prefer_local().await {
-            Ok(z) => {
-                *lock = Some(z.clone());
-                Ok(Some(z))
-            }
-            Err(_) => Ok(None),
+        if let Ok(z) = CALL_ZOTERO.try_with(ZoteroClient::clone) {
+            return Ok(Some(z));
         }
+        Ok(self.zotero.get(|| self.probe_zotero()).await.ok())
     }
 
     /// Require a Zotero client; returns an error (including the "not running" hint) if unavailable.
     ///
     /// Used by dedicated Zotero tools where Zotero is mandatory.
     async fn require_zotero(&self) -> Result<ZoteroClient, String> {
-        {
-            let lock = self.zotero.lock().await;
-            if let Some(z) = lock.as_ref() {
-                return Ok(z.clone());
-            }
+        if let Ok(z) = CALL_ZOTERO.try_with(ZoteroClient::clone) {
+            return Ok(z);
         }
-        match ZoteroClient::from_env_prefer_local().await {
-            Ok(z) => {
-                let mut lock = self.zotero.lock().await;
-                *lock = Some(z.clone());
-                Ok(z)
-            }
-            Err(e) => Err(e.to_string()),
+        self.zotero.get(|| self.probe_zotero()).await
+    }
+
+    /// Embed the abstracts of entries just added to a selection when
+    /// `auto_index_abstracts` is on and the DB is available. Failures are
+    /// logged, never returned, so the selection edit itself still succeeds.
+    async fn auto_index_abstracts(&self, entries: &[papers_core::selection::SelectionEntry]) {
+        let Some(rag) = self.db.as_ref() else { return };
+        if entries.is_empty() || !papers_core::abstracts::auto_index_enabled() {
+            return;
+        }
+        let batch = papers_core::abstracts::entry_abstracts(&self.client, entries).await;
+        if let Err(e) = papers_db::index_abstracts(rag, &batch.abstracts).await {
+            eprintln!("warning: abstracts not indexed: {e}");
         }
     }
+
+    /// Exhibit JSON, plus the encoded figure when `include_image` is set.
+    async fn exhibit_contents(&self, p: DbExhibitGetParams) -> Result<Vec<Content>, String> {
+        let rag = self.db.as_ref().ok_or_else(|| "DB not configured.".to_string())?;
+        let exhibit = papers_db::query::get_exhibit(rag, &p.exhibit_id).await.map_err(|e| e.to_string())?;
+        let mut value = serde_json::to_value(&exhibit).map_err(|e| e.to_string())?;
+        if !p.include_image {
+            let text = serde_json::to_string_pretty(&value).map_err(|e| e.to_string())?;
+            return Ok(vec![Content::text(text)]);
+        }
+        let path = exhibit
+            .image_path
+            .as_deref()
+            .ok_or_else(|| format!("Exhibit {} has no image", exhibit.exhibit_id))?;
+        let max_dimension = p.max_dimension.unwrap_or(crate::figure::DEFAULT_MAX_DIMENSION);
+        let image = crate::figure::encode_image_file(std::path::Path::new(path), max_dimension)?;
+        value["image"] = serde_json::to_value(&image).map_err(|e| e.to_string())?;
+        let text = serde_json::to_string_pretty(&value).map_err(|e| e.to_string())?;
+        Ok(vec![Content::text(text), Content::image(image.data, image.mime_type)])
+    }
+
+    /// Like [`Self::require_zotero`], scoped to `library` (group ID or name) when given.
+    async fn require_zotero_library(&self, library: Option<&str>) -> Result<ZoteroClient, String> {
+        let z = self.require_zotero().await?;
+        zotero_resolve::scoped_client(z, library).await.map_err(|e| e.to_string())
+    }
 }
 
 /// Returns true if this attachment supports annotation children (PDF, EPUB, or HTML snapshot).
@@ -338,10 +727,15 @@ impl PapersMcp {
 
     /// Get a single work by ID (OpenAlex ID, DOI, PMID, or PMCID).
     /// Response includes `in_zotero` (bool) and `zotero` (object or null) with brief Zotero library info.
+    /// Items are matched by DOI, or by title and year when either side lacks one; `zotero_match_confidence`
+    /// is 1.0 for a DOI match and lower for a title match (`zotero.matched_by` says which), so verify low values.
+    /// The abstract is returned as plain text in `work.abstract`; with `select`, include `abstract` to get it.
+    /// A DOI OpenAlex doesn't know yet (e.g. a days-old preprint) is looked up in Crossref instead;
+    /// the response then has `from_crossref: true` and `work.id` is the DOI URL.
     #[tool]
     pub async fn work_get(&self, Parameters(params): Parameters<GetToolParams>) -> Result<String, String> {
         let zotero = self.get_optional_zotero().await?;
-        match papers_core::api::work_get_response(&self.client, zotero.as_ref(), &params.id, &params.into_get_params()).await {
+        match papers_core::api::work_get_response(&self.client, zotero.as_ref(), self.crossref.as_ref(), &params.id, &params.into_get_params()).await {
             Ok(response) => serde_json::to_string_pretty(&response).map_err(|e| format!("JSON serialization error: {e}")),
             Err(FilterError::Suggestions { query, suggestions }) => {
                 let candidates: Vec<_> = suggestions
@@ -358,6 +752,23 @@ impl PapersMcp {
         }
     }
 
+    /// Get a work enriched with Semantic Scholar data OpenAlex lacks: a one-sentence `tldr`,
+    /// `influential_citation_count` (citing papers that build substantially on it), and
+    /// embedding-based `recommendations` (with DOIs for work_get). Useful for triage.
+    /// Returns the work_get response plus `semantic_scholar`; if Semantic Scholar is unavailable
+    /// (e.g. rate limited), `semantic_scholar_error` says why and the OpenAlex data is still returned.
+    /// Set SEMANTIC_SCHOLAR_API_KEY for a higher rate limit.
+    #[tool]
+    pub async fn work_enrich(&self, Parameters(p): Parameters<WorkEnrichToolParams>) -> Result<String, String> {
+        let s2 = self.semantic_scholar.as_ref().ok_or_else(|| "Semantic Scholar not configured.".to_string())?;
+        let zotero = self.get_optional_zotero().await?;
+        let recommendations = p.recommendations.unwrap_or(papers_core::semantic_scholar::DEFAULT_RECOMMENDATIONS);
+        json_result(
+            papers_core::api::work_enrich(&self.client, zotero.as_ref(), self.crossref.as_ref(), s2, &p.id, recommendations)
+                .await,
+        )
+    }
+
     /// Get a single author by ID (OpenAlex ID or ORCID).
     #[tool]
     pub async fn author_get(&self, Parameters(params): Parameters<GetToolParams>) -> Result<String, String> {
@@ -464,24 +875,228 @@ impl PapersMcp {
         json_result(papers_core::api::work_find(&self.client, &params.into_find_params()).await)
     }
 
+    // ── Work stats ───────────────────────────────────────────────────────
+
+    /// Count works matching a filter, search, and/or shorthand aliases (same as work_list) per
+    /// group in a single OpenAlex group_by request, e.g. publications per year on a topic or the
+    /// top institutions publishing on a subject. `by` picks the dimension (year, type, topic,
+    /// subfield, field, domain, institution, country, author, source, publisher, funder, oa_status,
+    /// language, sdg). Returns a tidy `series` of `{key, label, count}` ready for charting: years
+    /// ascending with empty years as zero, other dimensions the `top` (default 25) largest groups.
+    #[tool]
+    pub async fn work_stats(&self, Parameters(params): Parameters<WorkStatsToolParams>) -> Result<String, String> {
+        let by = params.by.parse::<papers_core::stats::StatsDimension>().map_err(|e| e.to_string())?;
+        let top = params.top.unwrap_or(papers_core::stats::DEFAULT_STATS_TOP);
+        json_result(papers_core::api::work_stats(&self.client, &params.into_work_list_params(), by, top).await)
+    }
+
+    // ── APC spend ────────────────────────────────────────────────────────
+
+    /// Estimate article processing charge (APC) spend for the works matching a filter,
+    /// search, and/or shorthand aliases (same as work_list). Sums OpenAlex `apc_list` and
+    /// `apc_paid` in USD and estimates spend from `apc_paid`, or the list price for gold/hybrid
+    /// OA works; closed, green, bronze and diamond works count as zero. Includes top sources and
+    /// per-year totals. Fetches up to max_works (default 1000); larger sets are extrapolated.
+    #[tool]
+    pub async fn work_apc_summary(&self, Parameters(params): Parameters<WorkApcSummaryToolParams>) -> Result<String, String> {
+        let max_works = params.max_works.unwrap_or(1000);
+        json_result(papers_core::api::work_apc_report(&self.client, &params.into_work_list_params(), max_works).await)
+    }
+
+    // ── Metadata quality ─────────────────────────────────────────────────
+
+    /// Check a work's OpenAlex metadata for common problems before relying on it in analyses or exports:
+    /// missing abstract, no open-access location, unresolved or merged authorships, DOI casing
+    /// inconsistencies, and missing references. Each issue has a `severity` (warning/info) and a `suggestion`.
+    #[tool]
+    pub async fn work_quality(&self, Parameters(params): Parameters<WorkQualityToolParams>) -> Result<String, String> {
+        json_result(papers_core::api::work_quality(&self.client, &params.id).await)
+    }
+
+    // ── Published version ────────────────────────────────────────────────
+
+    /// Link a preprint to its published journal/conference version, or a published work to its
+    /// preprint. Uses other-version locations of the same record, DOIs in location landing pages,
+    /// and a title search scored by title similarity, author overlap, and year order. Returns
+    /// `preprint_id`, `published_id`, and `confidence` (0–1) for the best link, plus all
+    /// `candidates` with their `evidence`. Both IDs are the same when OpenAlex merged the versions.
+    #[tool]
+    pub async fn work_published_version(&self, Parameters(params): Parameters<WorkPublishedVersionToolParams>) -> Result<String, String> {
+        json_result(papers_core::api::work_published_version(&self.client, &params.id).await)
+    }
+
+    // ── Author profile ───────────────────────────────────────────────────
+
+    /// Scholar-style researcher overview in one call: works/citation counts, h-index, i10-index,
+    /// counts_by_year (oldest first), most-cited works, most frequent co-authors (`shared_works`),
+    /// and topic distribution (`share` = fraction of the author's works). Accepts an OpenAlex ID, ORCID, or name.
+    #[tool]
+    pub async fn author_profile(&self, Parameters(params): Parameters<AuthorProfileToolParams>) -> Result<String, String> {
+        json_result(papers_core::api::author_profile(&self.client, &params.id, &params.into_profile_params()).await)
+    }
+
+    /// Institution timeline for an author as contiguous year spans (start_year..=end_year), oldest first.
+    /// Pass `year` to get `at_year` — where the author was that year (e.g. "where was X in 2019").
+    /// Uses the author's OpenAlex affiliations, falling back to institutions on their works.
+    #[tool]
+    pub async fn author_affiliation_history(&self, Parameters(params): Parameters<AuthorAffiliationHistoryToolParams>) -> Result<String, String> {
+        json_result(papers_core::api::author_affiliation_history(&self.client, &params.id, params.year).await)
+    }
+
+    /// Pick the right author among namesakes. Searches authors by `name`, then scores each candidate on
+    /// name match plus optional hints: `affiliation` (current or past institution, acronyms ok), `topic`,
+    /// and `coauthor` (shared works). Returns candidates ranked by `confidence` (0-1) with `evidence`.
+    /// Use before author filters when a name is common.
+    #[tool]
+    pub async fn author_resolve(&self, Parameters(params): Parameters<AuthorResolveToolParams>) -> Result<String, String> {
+        json_result(papers_core::api::author_resolve(&self.client, &params.name, &params.into_resolve_params()).await)
+    }
+
+    // ── Citation graph ───────────────────────────────────────────────────
+
+    /// Works citing a given work, expanded breadth-first up to `depth` hops (max 3) and deduplicated.
+    /// Each result carries `depth` (hops from the root) and `via` (the work it was reached from).
+    /// Most-cited first per expanded work; `truncated` is true when limits cut the traversal short.
+    #[tool]
+    pub async fn work_citations(&self, Parameters(params): Parameters<WorkCitationGraphToolParams>) -> Result<String, String> {
+        json_result(papers_core::api::work_citations(&self.client, &params.id, &params.into_graph_params()).await)
+    }
+
+    /// Works referenced by a given work, expanded breadth-first up to `depth` hops (max 3) and deduplicated.
+    /// Each result carries `depth` (hops from the root) and `via` (the work it was reached from).
+    /// Most-cited first per expanded work; `truncated` is true when limits cut the traversal short.
+    #[tool]
+    pub async fn work_references(&self, Parameters(params): Parameters<WorkCitationGraphToolParams>) -> Result<String, String> {
+        json_result(papers_core::api::work_references(&self.client, &params.id, &params.into_graph_params()).await)
+    }
+
+    // ── Related works ────────────────────────────────────────────────────
+
+    /// "What should I read next": works related to a seed work, or to every work in a selection
+    /// (default: the active selection) when `id` is omitted. Combines OpenAlex `related_works`,
+    /// shared references, and shared topics into a `score`, highest first. Each result lists
+    /// `reasons` saying which seed it relates to and how. Seeds are never recommended.
+    #[tool]
+    pub async fn work_related(&self, Parameters(p): Parameters<WorkRelatedToolParams>) -> Result<String, String> {
+        let params = p.into_related_params();
+        match p.id {
+            Some(id) => json_result(papers_core::api::work_related(&self.client, &id, &params).await),
+            None => {
+                let sel_name = Self::resolve_selection_name(p.selection)?;
+                let sel = papers_core::selection::load_selection(&sel_name).map_err(|e| e.to_string())?;
+                json_result(papers_core::api::selection_related(&self.client, &sel, &params).await)
+            }
+        }
+    }
+
+    /// "Which of the papers cited by X is about Y": searches the works `id` references for
+    /// `query` (OpenAlex full-text relevance over title and abstract), most relevant first.
+    /// `references` is how many cited works were searched.
+    #[tool]
+    pub async fn work_search_references(
+        &self,
+        Parameters(p): Parameters<WorkSearchReferencesToolParams>,
+    ) -> Result<String, String> {
+        json_result(
+            papers_core::api::work_search_references(&self.client, &p.id, &p.query, &p.into_search_params()).await,
+        )
+    }
+
+    /// Format a citation for one work, instead of writing one from memory. `style` is "apa"
+    /// (default, APA 7), "mla" (MLA 9), "chicago" (Chicago author-date), or "bibtex". Returns the
+    /// reference-list entry as plain text (`citation`), the parenthetical in-text citation
+    /// (`in_text`, or `\cite{key}` for BibTeX), and the generated citation `key`.
+    #[tool]
+    pub async fn format_citation(&self, Parameters(p): Parameters<FormatCitationToolParams>) -> Result<String, String> {
+        use papers_core::cite::{CitationStyle, CiteError};
+        let style = match p.style {
+            Some(s) => s.parse().map_err(|e: CiteError| e.to_string())?,
+            None => CitationStyle::Apa,
+        };
+        json_result(papers_core::cite::format_citation(&self.client, &p.id, style).await)
+    }
+
+    /// Related-work landscape for peer review: given a submission's title or abstract, combines
+    /// semantic search (`work_find`, needs OPENALEX_KEY) with full-text searches over all years and
+    /// the last few years. Returns topic `clusters`, `key_prior_works` (most cited), `recent_closest`
+    /// (most relevant recent works), and `possible_missed_citations`: works cited by several related
+    /// works but missing from `references`. Failed searches are listed in `warnings`.
+    #[tool]
+    pub async fn review_context(&self, Parameters(p): Parameters<ReviewContextToolParams>) -> Result<String, String> {
+        let _permit = self.limits.acquire(Backend::OpenAlex).await;
+        let context = papers_core::api::review_context(&self.client, &p.text, &p.references.unwrap_or_default()).await;
+        json_result::<_, String>(Ok(context))
+    }
+
+    // ── Cache tools ──────────────────────────────────────────────────────
+
+    /// OpenAlex response cache: directory, entry count, size, size limit, and per-entity entries
+    /// with their TTL. TTLs come from PAPERS_CACHE_TTL_<ENTITY> (e.g. PAPERS_CACHE_TTL_WORKS).
+    #[tool]
+    pub async fn cache_stats(&self, Parameters(_p): Parameters<ZoteroNoParamsToolParams>) -> Result<String, String> {
+        match papers_core::api::cache_stats(&self.client) {
+            Some(stats) => json_result::<_, String>(Ok(stats)),
+            None => Err("OpenAlex response cache is disabled".to_string()),
+        }
+    }
+
+    /// Usage since the server started: calls, errors, error rate and mean/max latency per tool,
+    /// error rates per backend (openalex, zotero, db), and the OpenAlex cache hit rate.
+    /// Requires PAPERS_METRICS=on.
+    #[tool]
+    pub async fn server_stats(&self, Parameters(_p): Parameters<ZoteroNoParamsToolParams>) -> Result<String, String> {
+        match &self.metrics {
+            Some(metrics) => json_result::<_, String>(Ok(metrics.snapshot())),
+            None => Err("usage metrics are disabled; start the server with PAPERS_METRICS=on".to_string()),
+        }
+    }
+
+    /// Remove cached OpenAlex responses, all or only one entity type, so the next request
+    /// fetches fresh data. Returns the number of entries removed.
+    #[tool]
+    pub async fn cache_clear(&self, Parameters(p): Parameters<CacheClearToolParams>) -> Result<String, String> {
+        json_result(
+            papers_core::api::cache_clear(&self.client, p.entity.as_deref())
+                .map(|removed| serde_json::json!({ "removed": removed })),
+        )
+    }
+
+    // ── Raw passthrough ──────────────────────────────────────────────────
+
+    /// Escape hatch: GET an OpenAlex endpoint with arbitrary query parameters and return the raw
+    /// JSON. Use only when the dedicated tools lack a parameter (e.g. `group_by`, `sample`, new
+    /// filters). `path` must start with an entity (works, authors, sources, institutions, topics,
+    /// keywords, publishers, funders, domains, fields, subfields, concepts), `autocomplete`, or `find`.
+    #[tool]
+    pub async fn openalex_raw(&self, Parameters(p): Parameters<OpenAlexRawToolParams>) -> Result<String, String> {
+        json_result(papers_core::raw::openalex_raw(&self.client, &p.path, &p.query_pairs()).await)
+    }
+
     // ── Zotero tools ─────────────────────────────────────────────────────
 
     /// List bibliographic items in your Zotero library (journalArticle, book, conferencePaper, etc.).
-    /// Excludes notes, attachments, and annotations. Requires ZOTERO_USER_ID and ZOTERO_API_KEY.
+    /// Excludes notes, attachments, and annotations. Set `output_format` (bibtex, csljson, ris, …)
+    /// to export the whole list via Zotero's translators. Requires ZOTERO_USER_ID and ZOTERO_API_KEY.
     #[tool]
     pub async fn zotero_work_list(&self, Parameters(p): Parameters<ZoteroWorkListToolParams>) -> Result<String, String> {
-        let z = self.require_zotero().await?;
+        let z = self.require_zotero_library(p.library.as_deref()).await?;
         let params = papers_zotero::ItemListParams {
             item_type: p.item_type,
             tag: p.tag,
             item_key: p.item_key,
             since: p.since,
             sort: p.sort,
-            direction: p.direction,
+            direction: p.direction.map(String::from),
             limit: p.limit,
             start: p.start,
+            style: p.style,
             ..Default::default()
         };
+        if let Some(format) = &p.output_format {
+            return zotero_resolve::export_top_items(&z, None, &params, format)
+                .await
+                .map_err(|e| e.to_string());
+        }
         json_result(z.list_top_items(&params).await)
     }
 
@@ -496,7 +1111,7 @@ impl PapersMcp {
             qmode: p.everything.then(|| "everything".to_string()),
             tag: p.tag,
             sort: p.sort,
-            direction: p.direction,
+            direction: p.direction.map(String::from),
             limit: p.limit,
             start: p.start,
             ..Default::default()
@@ -504,40 +1119,108 @@ impl PapersMcp {
         json_result(z.list_top_items(&params).await)
     }
 
+    /// Search OpenAlex and the Zotero library at once. Results are merged by DOI, or by
+    /// near-identical title and year, and each hit is flagged `in_zotero`, `in_rag_index`
+    /// (indexed in the local DB), and `in_selection` (with the selection names).
+    /// Zotero hits come first; without Zotero credentials only OpenAlex is searched.
+    #[tool]
+    pub async fn library_search(&self, Parameters(p): Parameters<LibrarySearchToolParams>) -> Result<String, String> {
+        let zotero = self.get_optional_zotero().await?;
+        let mut hits =
+            papers_core::library::library_search(&self.client, zotero.as_ref(), &p.query, p.limit)
+                .await
+                .map_err(|e| e.to_string())?;
+        if let Some(rag) = self.db.as_ref() {
+            for hit in &mut hits {
+                for id in hit.db_ids() {
+                    if papers_db::is_ingested(rag, &id).await {
+                        hit.in_rag_index = true;
+                        break;
+                    }
+                }
+            }
+        }
+        json_result::<_, String>(Ok(hits))
+    }
+
     /// Get a single bibliographic item by Zotero key or title search. Requires ZOTERO_USER_ID and ZOTERO_API_KEY.
     #[tool]
     pub async fn zotero_work_get(&self, Parameters(p): Parameters<ZoteroKeyToolParams>) -> Result<String, String> {
         let z = self.require_zotero().await?;
-        let key = zotero_resolve::resolve_item_key(&z, &p.key).await.map_err(|e| e.to_string())?;
+        let key = Self::resolve_item_key_interactive(&z, &p.key).await?;
         let item = z.get_item(&key).await.map_err(|e| e.to_string())?;
         let mut value = serde_json::to_value(&item).map_err(|e| e.to_string())?;
         value["zotero_uri"] = serde_json::Value::String(format!("zotero://select/library/items/{key}"));
         serde_json::to_string_pretty(&value).map_err(|e| e.to_string())
     }
 
+    /// Add an OpenAlex work to your Zotero library, mapping type, title, creators, DOI, abstract, date,
+    /// venue, and volume/issue/pages. Refuses if an item with the same DOI already exists unless
+    /// `allow_duplicate` is true. Writes go to the Zotero web API and need a key with write access.
+    /// Returns the created item and its `zotero_uri`.
+    #[tool]
+    pub async fn zotero_work_create(&self, Parameters(p): Parameters<ZoteroWorkCreateToolParams>) -> Result<String, String> {
+        let z = self.require_zotero().await?;
+        let item = papers_core::zotero::create_item_from_work(
+            &self.client,
+            &z,
+            &p.id,
+            &p.collections.unwrap_or_default(),
+            &p.tags.unwrap_or_default(),
+            p.allow_duplicate.unwrap_or(false),
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+        let mut value = serde_json::to_value(&item).map_err(|e| e.to_string())?;
+        value["zotero_uri"] = serde_json::Value::String(format!("zotero://select/library/items/{}", item.key));
+        serde_json::to_string_pretty(&value).map_err(|e| e.to_string())
+    }
+
+    /// Update fields of an existing Zotero item by key: title, abstract, DOI, date, URL, extra,
+    /// tags (add/remove), and collections (add). Pass `version` from `zotero_work_get` to refuse the
+    /// write if the item changed in the meantime. Returns the saved item with its new version.
+    #[tool]
+    pub async fn zotero_work_update(&self, Parameters(p): Parameters<ZoteroWorkUpdateToolParams>) -> Result<String, String> {
+        let z = self.require_zotero().await?;
+        json_result(papers_core::zotero::update_item_fields(&z, &p.key, &p.to_update(), p.version).await)
+    }
+
+    /// Compare a Zotero item's title, year, DOI, venue, and authors with the OpenAlex record.
+    /// Each field is reported as `same`, `differs`, `missing_in_zotero`, or `missing_in_openalex`,
+    /// and `patch` holds the Zotero fields that would adopt the OpenAlex values. Pass `apply: true`
+    /// to write that patch (needs a key with write access; refused on read-only servers).
+    #[tool]
+    pub async fn work_metadata_diff(&self, Parameters(p): Parameters<WorkMetadataDiffToolParams>) -> Result<String, String> {
+        if p.apply.unwrap_or(false) && self.is_read_only() {
+            return Err("server is read-only: apply is not allowed".to_string());
+        }
+        let z = self.require_zotero().await?;
+        json_result(
+            papers_core::metadata_diff::work_metadata_diff(&self.client, &z, &p.id, p.apply.unwrap_or(false)).await,
+        )
+    }
+
     /// List the collections a work belongs to. Multi-step: reads item record then resolves collection names.
     /// Requires ZOTERO_USER_ID and ZOTERO_API_KEY.
     #[tool]
     pub async fn zotero_work_collections(&self, Parameters(p): Parameters<ZoteroKeyToolParams>) -> Result<String, String> {
         let z = self.require_zotero().await?;
-        let key = zotero_resolve::resolve_item_key(&z, &p.key).await.map_err(|e| e.to_string())?;
+        let key = Self::resolve_item_key_interactive(&z, &p.key).await?;
         let item = z.get_item(&key).await.map_err(|e| e.to_string())?;
-        let col_keys = item.data.collections.clone();
-        let mut collections = Vec::new();
-        for ck in &col_keys {
-            match z.get_collection(ck).await {
-                Ok(c) => collections.push(c),
-                Err(e) => return Err(e.to_string()),
-            }
-        }
-        json_result::<Vec<_>, String>(Ok(collections))
+        let collections = self
+            .limits
+            .map(Backend::Zotero, &item.data.collections, |ck| z.get_collection(ck))
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>();
+        json_result(collections)
     }
 
     /// List notes attached to a specific work. Requires ZOTERO_USER_ID and ZOTERO_API_KEY.
     #[tool]
     pub async fn zotero_work_notes(&self, Parameters(p): Parameters<ZoteroWorkChildrenToolParams>) -> Result<String, String> {
         let z = self.require_zotero().await?;
-        let key = zotero_resolve::resolve_item_key(&z, &p.key).await.map_err(|e| e.to_string())?;
+        let key = Self::resolve_item_key_interactive(&z, &p.key).await?;
         let params = papers_zotero::ItemListParams { item_type: Some("note".into()), limit: p.limit, start: p.start, ..Default::default() };
         json_result(z.list_item_children(&key, &params).await)
     }
@@ -546,7 +1229,7 @@ impl PapersMcp {
     #[tool]
     pub async fn zotero_work_attachments(&self, Parameters(p): Parameters<ZoteroWorkChildrenToolParams>) -> Result<String, String> {
         let z = self.require_zotero().await?;
-        let key = zotero_resolve::resolve_item_key(&z, &p.key).await.map_err(|e| e.to_string())?;
+        let key = Self::resolve_item_key_interactive(&z, &p.key).await?;
         let params = papers_zotero::ItemListParams { item_type: Some("attachment".into()), limit: p.limit, start: p.start, ..Default::default() };
         json_result(z.list_item_children(&key, &params).await)
     }
@@ -556,17 +1239,10 @@ impl PapersMcp {
     #[tool]
     pub async fn zotero_work_annotations(&self, Parameters(p): Parameters<ZoteroKeyToolParams>) -> Result<String, String> {
         let z = self.require_zotero().await?;
-        let key = zotero_resolve::resolve_item_key(&z, &p.key).await.map_err(|e| e.to_string())?;
+        let key = Self::resolve_item_key_interactive(&z, &p.key).await?;
         let att_params = papers_zotero::ItemListParams { item_type: Some("attachment".into()), ..Default::default() };
         let attachments = z.list_item_children(&key, &att_params).await.map_err(|e| e.to_string())?;
-        let ann_params = papers_zotero::ItemListParams { item_type: Some("annotation".into()), ..Default::default() };
-        let mut all_annotations = Vec::new();
-        for att in &attachments.items {
-            if !is_annotatable_attachment(att) { continue; }
-            if let Ok(r) = z.list_item_children(&att.key, &ann_params).await {
-                all_annotations.extend(r.items);
-            }
-        }
+        let all_annotations = self.annotations_of(&z, &attachments.items).await;
         json_result::<Vec<_>, String>(Ok(all_annotations))
     }
 
@@ -574,7 +1250,7 @@ impl PapersMcp {
     #[tool]
     pub async fn zotero_work_tags(&self, Parameters(p): Parameters<ZoteroWorkTagsToolParams>) -> Result<String, String> {
         let z = self.require_zotero().await?;
-        let key = zotero_resolve::resolve_item_key(&z, &p.key).await.map_err(|e| e.to_string())?;
+        let key = Self::resolve_item_key_interactive(&z, &p.key).await?;
         let params = papers_zotero::TagListParams { q: p.search, qmode: Some("contains".to_string()), limit: p.limit, start: p.start, ..Default::default() };
         json_result(z.list_item_tags(&key, &params).await)
     }
@@ -584,7 +1260,7 @@ impl PapersMcp {
     #[tool]
     pub async fn zotero_attachment_list(&self, Parameters(p): Parameters<ZoteroAttachmentListToolParams>) -> Result<String, String> {
         let z = self.require_zotero().await?;
-        let params = papers_zotero::ItemListParams { item_type: Some("attachment".into()), sort: p.sort, direction: p.direction, limit: p.limit, start: p.start, ..Default::default() };
+        let params = papers_zotero::ItemListParams { item_type: Some("attachment".into()), sort: p.sort, direction: p.direction.map(String::from), limit: p.limit, start: p.start, ..Default::default() };
         json_result(z.list_items(&params).await)
     }
 
@@ -593,7 +1269,7 @@ impl PapersMcp {
     #[tool]
     pub async fn zotero_attachment_search(&self, Parameters(p): Parameters<ZoteroAttachmentSearchToolParams>) -> Result<String, String> {
         let z = self.require_zotero().await?;
-        let params = papers_zotero::ItemListParams { item_type: Some("attachment".into()), q: Some(p.query), sort: p.sort, direction: p.direction, limit: p.limit, start: p.start, ..Default::default() };
+        let params = papers_zotero::ItemListParams { item_type: Some("attachment".into()), q: Some(p.query), sort: p.sort, direction: p.direction.map(String::from), limit: p.limit, start: p.start, ..Default::default() };
         json_result(z.list_items(&params).await)
     }
 
@@ -651,9 +1327,9 @@ impl PapersMcp {
     /// List collections in the Zotero library. Requires ZOTERO_USER_ID and ZOTERO_API_KEY.
     #[tool]
     pub async fn zotero_collection_list(&self, Parameters(p): Parameters<ZoteroCollectionListToolParams>) -> Result<String, String> {
-        let z = self.require_zotero().await?;
-        let params = papers_zotero::CollectionListParams { sort: p.sort, direction: p.direction, limit: p.limit, start: p.start };
-        let result = if p.scope.as_deref() == Some("top") {
+        let z = self.require_zotero_library(p.library.as_deref()).await?;
+        let params = papers_zotero::CollectionListParams { sort: p.sort, direction: p.direction.map(String::from), limit: p.limit, start: p.start };
+        let result = if p.scope.unwrap_or_default() == CollectionScope::Top {
             z.list_top_collections(&params).await
         } else {
             z.list_collections(&params).await
@@ -663,17 +1339,18 @@ impl PapersMcp {
 
     /// Get a single collection by key or name search. Requires ZOTERO_USER_ID and ZOTERO_API_KEY.
     #[tool]
-    pub async fn zotero_collection_get(&self, Parameters(p): Parameters<ZoteroKeyToolParams>) -> Result<String, String> {
-        let z = self.require_zotero().await?;
+    pub async fn zotero_collection_get(&self, Parameters(p): Parameters<ZoteroLibraryKeyToolParams>) -> Result<String, String> {
+        let z = self.require_zotero_library(p.library.as_deref()).await?;
         let key = zotero_resolve::resolve_collection_key(&z, &p.key).await.map_err(|e| e.to_string())?;
         json_result(z.get_collection(&key).await)
     }
 
     /// List bibliographic works within a collection (excludes notes, attachments, annotations).
-    /// Requires ZOTERO_USER_ID and ZOTERO_API_KEY.
+    /// Set `output_format` (bibtex, csljson, ris, …) to export the collection via Zotero's
+    /// translators. Requires ZOTERO_USER_ID and ZOTERO_API_KEY.
     #[tool]
     pub async fn zotero_collection_works(&self, Parameters(p): Parameters<ZoteroCollectionWorksToolParams>) -> Result<String, String> {
-        let z = self.require_zotero().await?;
+        let z = self.require_zotero_library(p.library.as_deref()).await?;
         let key = zotero_resolve::resolve_collection_key(&z, &p.key).await.map_err(|e| e.to_string())?;
         let params = papers_zotero::ItemListParams {
             item_type: p.item_type,
@@ -681,18 +1358,24 @@ impl PapersMcp {
             qmode: p.everything.then(|| "everything".to_string()),
             tag: p.tag,
             sort: p.sort,
-            direction: p.direction,
+            direction: p.direction.map(String::from),
             limit: p.limit,
             start: p.start,
+            style: p.style,
             ..Default::default()
         };
+        if let Some(format) = &p.output_format {
+            return zotero_resolve::export_top_items(&z, Some(&key), &params, format)
+                .await
+                .map_err(|e| e.to_string());
+        }
         json_result(z.list_collection_top_items(&key, &params).await)
     }
 
     /// List attachment items within a collection. Requires ZOTERO_USER_ID and ZOTERO_API_KEY.
     #[tool]
-    pub async fn zotero_collection_attachments(&self, Parameters(p): Parameters<ZoteroWorkChildrenToolParams>) -> Result<String, String> {
-        let z = self.require_zotero().await?;
+    pub async fn zotero_collection_attachments(&self, Parameters(p): Parameters<ZoteroCollectionAttachmentsToolParams>) -> Result<String, String> {
+        let z = self.require_zotero_library(p.library.as_deref()).await?;
         let key = zotero_resolve::resolve_collection_key(&z, &p.key).await.map_err(|e| e.to_string())?;
         let params = papers_zotero::ItemListParams { item_type: Some("attachment".into()), limit: p.limit, start: p.start, ..Default::default() };
         json_result(z.list_collection_items(&key, &params).await)
@@ -701,7 +1384,7 @@ impl PapersMcp {
     /// List note items within a collection. Requires ZOTERO_USER_ID and ZOTERO_API_KEY.
     #[tool]
     pub async fn zotero_collection_notes(&self, Parameters(p): Parameters<ZoteroCollectionNotesToolParams>) -> Result<String, String> {
-        let z = self.require_zotero().await?;
+        let z = self.require_zotero_library(p.library.as_deref()).await?;
         let key = zotero_resolve::resolve_collection_key(&z, &p.key).await.map_err(|e| e.to_string())?;
         let params = papers_zotero::ItemListParams { item_type: Some("note".into()), q: p.search, limit: p.limit, start: p.start, ..Default::default() };
         json_result(z.list_collection_items(&key, &params).await)
@@ -710,35 +1393,28 @@ impl PapersMcp {
     /// List annotations on PDFs within a collection. Multi-step: fetches attachments then
     /// annotations per attachment. Requires ZOTERO_USER_ID and ZOTERO_API_KEY.
     #[tool]
-    pub async fn zotero_collection_annotations(&self, Parameters(p): Parameters<ZoteroKeyToolParams>) -> Result<String, String> {
-        let z = self.require_zotero().await?;
+    pub async fn zotero_collection_annotations(&self, Parameters(p): Parameters<ZoteroLibraryKeyToolParams>) -> Result<String, String> {
+        let z = self.require_zotero_library(p.library.as_deref()).await?;
         let key = zotero_resolve::resolve_collection_key(&z, &p.key).await.map_err(|e| e.to_string())?;
         let att_params = papers_zotero::ItemListParams { item_type: Some("attachment".into()), ..Default::default() };
         let attachments = z.list_collection_items(&key, &att_params).await.map_err(|e| e.to_string())?;
-        let ann_params = papers_zotero::ItemListParams { item_type: Some("annotation".into()), ..Default::default() };
-        let mut all_annotations = Vec::new();
-        for att in &attachments.items {
-            if !is_annotatable_attachment(att) { continue; }
-            if let Ok(r) = z.list_item_children(&att.key, &ann_params).await {
-                all_annotations.extend(r.items);
-            }
-        }
+        let all_annotations = self.annotations_of(&z, &attachments.items).await;
         json_result::<Vec<_>, String>(Ok(all_annotations))
     }
 
     /// List sub-collections of a collection. Requires ZOTERO_USER_ID and ZOTERO_API_KEY.
     #[tool]
     pub async fn zotero_collection_subcollections(&self, Parameters(p): Parameters<ZoteroCollectionSubcollectionsToolParams>) -> Result<String, String> {
-        let z = self.require_zotero().await?;
+        let z = self.require_zotero_library(p.library.as_deref()).await?;
         let key = zotero_resolve::resolve_collection_key(&z, &p.key).await.map_err(|e| e.to_string())?;
-        let params = papers_zotero::CollectionListParams { sort: p.sort, direction: p.direction, limit: p.limit, start: p.start };
+        let params = papers_zotero::CollectionListParams { sort: p.sort, direction: p.direction.map(String::from), limit: p.limit, start: p.start };
         json_result(z.list_subcollections(&key, &params).await)
     }
 
     /// List tags on items within a collection. Requires ZOTERO_USER_ID and ZOTERO_API_KEY.
     #[tool]
     pub async fn zotero_collection_tags(&self, Parameters(p): Parameters<ZoteroCollectionTagsToolParams>) -> Result<String, String> {
-        let z = self.require_zotero().await?;
+        let z = self.require_zotero_library(p.library.as_deref()).await?;
         let key = zotero_resolve::resolve_collection_key(&z, &p.key).await.map_err(|e| e.to_string())?;
         let params = papers_zotero::TagListParams { q: p.search, qmode: Some("contains".to_string()), limit: p.limit, start: p.start, ..Default::default() };
         let result = if p.top == Some(true) {
@@ -755,21 +1431,21 @@ impl PapersMcp {
     #[tool]
     pub async fn zotero_tag_list(&self, Parameters(p): Parameters<ZoteroTagListToolParams>) -> Result<String, String> {
         let z = self.require_zotero().await?;
-        let params = papers_zotero::TagListParams { sort: p.sort, direction: p.direction, limit: p.limit, start: p.start, ..Default::default() };
-        let result = match p.scope.as_deref() {
-            Some("trash") => z.list_trash_tags(&params).await,
-            Some("top") => z.list_top_items_tags(&params).await,
-            _ => z.list_tags(&params).await,
+        let params = papers_zotero::TagListParams { sort: p.sort, direction: p.direction.map(String::from), limit: p.limit, start: p.start, ..Default::default() };
+        let result = match p.scope.unwrap_or_default() {
+            TagScope::Trash => z.list_trash_tags(&params).await,
+            TagScope::Top => z.list_top_items_tags(&params).await,
+            TagScope::All => z.list_tags(&params).await,
         };
         json_result(result)
     }
 
-    /// Search tags in the global library tag index by name (substring match).
+    /// Search tags in the global library tag index by name (substring or prefix match).
     /// Requires ZOTERO_USER_ID and ZOTERO_API_KEY.
     #[tool]
     pub async fn zotero_tag_search(&self, Parameters(p): Parameters<ZoteroTagSearchToolParams>) -> Result<String, String> {
         let z = self.require_zotero().await?;
-        let params = papers_zotero::TagListParams { q: Some(p.query), qmode: Some("contains".to_string()), sort: p.sort, direction: p.direction, limit: p.limit, start: p.start };
+        let params = papers_zotero::TagListParams { q: Some(p.query), qmode: Some(p.qmode.unwrap_or_default().as_str().to_string()), sort: p.sort, direction: p.direction.map(String::from), limit: p.limit, start: p.start };
         json_result(z.list_tags(&params).await)
     }
 
@@ -794,13 +1470,60 @@ impl PapersMcp {
         json_result(z.list_groups().await)
     }
 
+    /// List all Zotero item types (`journalArticle`, `book`, …) with display names. Use before
+    /// creating items to pick a valid `itemType`. Requires ZOTERO_USER_ID and ZOTERO_API_KEY.
+    #[tool]
+    pub async fn zotero_item_type_list(&self, Parameters(p): Parameters<ZoteroItemTypeListToolParams>) -> Result<String, String> {
+        let z = self.require_zotero().await?;
+        json_result(z.list_item_types(p.locale.as_deref()).await)
+    }
+
+    /// List the fields valid for a Zotero item type, in display order (e.g. `ISBN` for books,
+    /// `publicationTitle` for journal articles). Use to build item JSON for writes instead of
+    /// guessing field names. Requires ZOTERO_USER_ID and ZOTERO_API_KEY.
+    #[tool]
+    pub async fn zotero_item_type_fields(&self, Parameters(p): Parameters<ZoteroItemTypeToolParams>) -> Result<String, String> {
+        let z = self.require_zotero().await?;
+        json_result(z.list_item_type_fields(&p.item_type, p.locale.as_deref()).await)
+    }
+
+    /// List the creator types valid for a Zotero item type (`author`, `editor`, …); the first
+    /// is the primary one. Requires ZOTERO_USER_ID and ZOTERO_API_KEY.
+    #[tool]
+    pub async fn zotero_item_type_creator_types(&self, Parameters(p): Parameters<ZoteroItemTypeToolParams>) -> Result<String, String> {
+        let z = self.require_zotero().await?;
+        json_result(z.list_item_type_creator_types(&p.item_type, p.locale.as_deref()).await)
+    }
+
+    /// Escape hatch: send a request to a Zotero API endpoint the dedicated tools don't cover
+    /// (item type fields, creator types, `format=bibtex` exports, advanced item queries) and
+    /// return the status, `Total-Results`, `Last-Modified-Version`, and raw body. Read-only
+    /// (GET) unless the server runs with `PAPERS_ZOTERO_RAW_WRITES=1`.
+    /// Requires ZOTERO_USER_ID and ZOTERO_API_KEY.
+    #[tool]
+    pub async fn zotero_raw(&self, Parameters(p): Parameters<ZoteroRawToolParams>) -> Result<String, String> {
+        let z = self.require_zotero_library(p.library.as_deref()).await?;
+        let req = papers_core::raw::ZoteroRawRequest {
+            query: p.query_pairs(),
+            method: p.method,
+            path: p.path,
+            body: p.body,
+            version: p.version,
+        };
+        if self.read_only && req.method.as_deref().is_some_and(|m| !m.trim().eq_ignore_ascii_case("GET")) {
+            return Err("the server is read-only; zotero_raw only sends GET requests".to_string());
+        }
+        let allow_writes = papers_core::raw::zotero_raw_writes_enabled();
+        json_result(papers_core::raw::zotero_raw(&z, &req, allow_writes).await)
+    }
+
     /// Get Zotero's indexed full-text content for a work's primary PDF attachment.
     /// Resolves the work key, finds its first PDF child attachment, and returns the indexed text
     /// (content, page count, character count). Requires ZOTERO_USER_ID and ZOTERO_API_KEY.
     #[tool]
-    pub async fn zotero_work_fulltext(&self, Parameters(p): Parameters<ZoteroKeyToolParams>) -> Result<String, String> {
-        let z = self.require_zotero().await?;
-        let key = zotero_resolve::resolve_item_key(&z, &p.key).await.map_err(|e| e.to_string())?;
+    pub async fn zotero_work_fulltext(&self, Parameters(p): Parameters<ZoteroLibraryKeyToolParams>) -> Result<String, String> {
+        let z = self.require_zotero_library(p.library.as_deref()).await?;
+        let key = Self::resolve_item_key_interactive(&z, &p.key).await?;
         let att_params = papers_zotero::ItemListParams { item_type: Some("attachment".into()), ..Default::default() };
         let children = z.list_item_children(&key, &att_params).await.map_err(|e| e.to_string())?;
         let pdf = children.items.iter()
@@ -809,13 +1532,27 @@ impl PapersMcp {
         json_result(z.get_item_fulltext(&pdf.key).await)
     }
 
+    /// Search the text of every PDF in the library. Zotero's own `q` search barely covers PDF
+    /// text through the web API, so this searches a local index of the indexed full text of all
+    /// PDF attachments, built on first use and updated incrementally (only attachments changed
+    /// since the last synced library version are fetched) when `sync` is true.
+    /// Returns item keys (the parent item, or the PDF itself when standalone) with the attachment
+    /// key, title, match count, and snippets around the first matches, most matches first.
+    /// Requires ZOTERO_USER_ID and ZOTERO_API_KEY.
+    #[tool]
+    pub async fn zotero_fulltext_search(&self, Parameters(p): Parameters<ZoteroFulltextSearchToolParams>) -> Result<String, String> {
+        let z = self.require_zotero_library(p.library.as_deref()).await?;
+        let limit = p.limit.unwrap_or(20) as usize;
+        json_result(papers_core::zotero_fulltext::search_library(&z, &p.query, limit, p.sync.unwrap_or(false)).await)
+    }
+
     /// Get the CDN view URL for a work's primary PDF attachment.
     /// Resolves the work key, finds its first PDF child, and returns the URL.
     /// Requires ZOTERO_USER_ID and ZOTERO_API_KEY.
     #[tool]
     pub async fn zotero_work_view_url(&self, Parameters(p): Parameters<ZoteroKeyToolParams>) -> Result<String, String> {
         let z = self.require_zotero().await?;
-        let key = zotero_resolve::resolve_item_key(&z, &p.key).await.map_err(|e| e.to_string())?;
+        let key = Self::resolve_item_key_interactive(&z, &p.key).await?;
         let att_params = papers_zotero::ItemListParams { item_type: Some("attachment".into()), ..Default::default() };
         let children = z.list_item_children(&key, &att_params).await.map_err(|e| e.to_string())?;
         let pdf = children.items.iter()
@@ -830,7 +1567,7 @@ impl PapersMcp {
     #[tool]
     pub async fn zotero_work_view(&self, Parameters(p): Parameters<ZoteroKeyToolParams>) -> Result<String, String> {
         let z = self.require_zotero().await?;
-        let key = zotero_resolve::resolve_item_key(&z, &p.key).await.map_err(|e| e.to_string())?;
+        let key = Self::resolve_item_key_interactive(&z, &p.key).await?;
         let att_params = papers_zotero::ItemListParams { item_type: Some("attachment".into()), ..Default::default() };
         let children = z.list_item_children(&key, &att_params).await.map_err(|e| e.to_string())?;
         let pdf = children.items.iter()
@@ -884,11 +1621,20 @@ impl PapersMcp {
     }
 
     /// Get the full text content of a scholarly work by downloading and extracting its PDF.
-    /// Tries multiple sources: local Zotero library, remote Zotero API,
-    /// direct open-access URLs, and the OpenAlex content API.
+    /// Tries multiple sources: local Zotero library, remote Zotero API, PMC open-access
+    /// JATS XML (structured sections, for works with a PMCID), direct open-access URLs, the OpenAlex content API, Unpaywall, and a link resolver
+    /// (which ones, and their settings, come from the user's config), probed concurrently with
+    /// the first file winning. `attempts` lists each source and why it was skipped, failed, or
+    /// cancelled.
+    /// Locally extracted PDF text is scored in `extraction`; junk text is replaced by a DataLab
+    /// conversion when DataLab is configured (`extraction.escalated_to`), or flagged in
+    /// `extraction.warning`.
     /// If no PDF is found, may ask the LLM for help finding one, or prompt the user
     /// to add the paper to Zotero via its DOI page.
     /// Accepts OpenAlex IDs, DOIs, or other work identifiers.
+    /// Text is returned in windows of up to `max_chars` characters (default 40000) with
+    /// `total_chars`, `next_offset` (null at the end), and `sections` (detected headings
+    /// with their offsets); pass `offset` to read further.
     #[tool]
     pub async fn work_text(
         &self,
@@ -896,15 +1642,37 @@ impl PapersMcp {
         Parameters(params): Parameters<WorkTextToolParams>,
     ) -> Result<String, String> {
         let zotero = self.get_optional_zotero().await?;
-        match papers_core::text::work_text(&self.client, zotero.as_ref(), &params.id).await {
-            Ok(result) => json_result::<_, String>(Ok(result)),
-            Err(papers_core::text::WorkTextError::NoPdfFound { work_id, title, doi }) => {
+        let offset = params.offset.unwrap_or(0);
+        let max_chars = params.max_chars.unwrap_or(papers_core::text::DEFAULT_TEXT_PAGE_CHARS);
+        let fetched = {
+            let _permit = self.limits.acquire(Backend::Datalab).await;
+            papers_core::text::with_datalab_progress(
+                Self::datalab_progress_hook(peer.clone()),
+                papers_core::text::work_text(&self.client, zotero.as_ref(), &params.id),
+            )
+            .await
+        };
+        match fetched {
+            Ok(result) => json_result::<_, String>(Ok(result.page(offset, max_chars))),
+            Err(papers_core::text::WorkTextError::NoPdfFound { work_id, title, doi, attempts }) => {
                 // Try the fallback chain: sampling → elicitation → error
                 if let Some(result) = self.work_text_fallback(&peer, &work_id, title.as_deref(), doi.as_deref(), zotero.as_ref()).await {
-                    return result;
+                    return json_result(result.map(|mut r| {
+                        r.attempts.splice(0..0, attempts);
+                        r.page(offset, max_chars)
+                    }));
                 }
                 let display = title.as_deref().unwrap_or(&work_id);
                 let mut msg = format!("No PDF found for \"{display}\".");
+                if !attempts.is_empty() {
+                    msg.push_str("\n\nSources tried:");
+                    for a in &attempts {
+                        msg.push_str(&format!("\n- {}: {}", a.source.as_str(), a.outcome.as_str()));
+                        if let Some(reason) = &a.reason {
+                            msg.push_str(&format!(" ({reason})"));
+                        }
+                    }
+                }
                 if let Some(doi) = &doi {
                     let bare = doi.strip_prefix("https://doi.org/").unwrap_or(doi);
                     msg.push_str(&format!(
@@ -925,16 +1693,65 @@ impl PapersMcp {
         }
     }
 
+    /// Parse the reference list out of a work's full text into structured entries (authors,
+    /// title, year, DOI), each resolved to an OpenAlex work by DOI or title search. Each entry
+    /// is flagged `in_rag_index` (indexed in the local DB) and `in_selection` (with the
+    /// selection names), to see which cited papers are already in the library. Fetches the
+    /// text like work_text, so it needs a cached extraction or an available PDF.
+    #[tool]
+    pub async fn work_references_extracted(&self, Parameters(p): Parameters<WorkReferencesExtractedToolParams>) -> Result<String, String> {
+        let zotero = self.get_optional_zotero().await?;
+        let permit = self.limits.acquire(Backend::Datalab).await;
+        let extracted = papers_core::references::work_references_extracted(
+            &self.client,
+            zotero.as_ref(),
+            &p.id,
+            p.resolve.unwrap_or(true),
+        );
+        let mut result = match CALLER.try_with(Peer::clone) {
+            Ok(peer) => papers_core::text::with_datalab_progress(Self::datalab_progress_hook(peer), extracted).await,
+            Err(_) => extracted.await,
+        }
+        .map_err(|e| e.to_string())?;
+        drop(permit);
+        if let Some(rag) = self.db.as_ref() {
+            for reference in &mut result.references {
+                for id in reference.db_ids() {
+                    if papers_db::is_ingested(rag, &id).await {
+                        reference.in_rag_index = true;
+                        break;
+                    }
+                }
+            }
+        }
+        json_result::<_, String>(Ok(result))
+    }
+
+    /// Scan free text (an email, a pasted reference list) for paper identifiers: DOIs, arXiv
+    /// IDs, OpenAlex work IDs, and probable titles for entries with none of those. Each
+    /// candidate has a `kind`, normalized `value`, `confidence` (0–1; prefixed IDs near 1,
+    /// bare arXiv IDs 0.6, titles at most 0.9), the `context` line, and an `input` to pass
+    /// to selection_add_bulk. Nothing is looked up; pair with selection_add_bulk to add
+    /// "everything in this reference list" in two calls.
+    #[tool]
+    pub async fn work_extract_identifiers(&self, Parameters(p): Parameters<WorkExtractIdentifiersToolParams>) -> Result<String, String> {
+        let min = p.min_confidence.unwrap_or(0.0);
+        let mut candidates = papers_core::identifiers::extract_identifiers(&p.text);
+        candidates.retain(|c| c.confidence >= min);
+        json_result::<_, String>(Ok(candidates))
+    }
+
     // ── Selection tools ───────────────────────────────────────────────────
 
     /// List all named paper selections with item counts.
-    /// Marks the currently active selection.
+    /// Marks the currently active selection and the default DB scope.
     #[tool]
     pub async fn selection_list(&self, Parameters(_p): Parameters<SelectionListToolParams>) -> Result<String, String> {
         use papers_core::selection::{list_selection_names, load_selection, load_state};
         let names = list_selection_names();
         let state = load_state();
         let active = state.active.as_deref();
+        let db_scope = state.db_scope.as_deref();
         let items: Vec<_> = names
             .iter()
             .map(|name| {
@@ -943,6 +1760,7 @@ impl PapersMcp {
                     "name": name,
                     "item_count": count,
                     "is_active": Some(name.as_str()) == active,
+                    "is_db_scope": Some(name.as_str()) == db_scope,
                 })
             })
             .collect();
@@ -950,10 +1768,16 @@ impl PapersMcp {
     }
 
     /// Get a selection's info and all its entries; activates the selection.
-    /// Defaults to the active selection if name is omitted.
+    /// Defaults to the active selection if name is omitted. Pass tag to keep
+    /// only entries with that tag, or group_by_tag to group entries by tag.
+    /// Entries with a recorded reading status (paper_status_set) are listed in
+    /// `reading_status`, keyed by 1-based entry index.
     #[tool]
     pub async fn selection_get(&self, Parameters(p): Parameters<SelectionGetToolParams>) -> Result<String, String> {
-        use papers_core::selection::{active_selection_name, load_selection, load_state, resolve_selection, save_state};
+        use papers_core::selection::{
+            active_selection_name, entries_with_tag, group_by_tag, load_selection, load_state,
+            resolve_selection, save_state,
+        };
         let name = match p.name {
             Some(n) => resolve_selection(&n).map_err(|e| e.to_string())?,
             None => active_selection_name().ok_or_else(|| "no active selection; run selection_list".to_string())?,
@@ -962,13 +1786,33 @@ impl PapersMcp {
         let mut state = load_state();
         state.active = Some(name.clone());
         let _ = save_state(&state);
-        json_result::<_, String>(Ok(serde_json::json!({
-            "name": sel.name,
-            "is_active": true,
-            "entries": sel.entries,
-        })))
-    }
-
+        let mut result = if let Some(tag) = p.tag.as_deref() {
+            serde_json::json!({
+                "name": sel.name,
+                "is_active": true,
+                "tag": tag,
+                "entries": entries_with_tag(&sel, tag),
+            })
+        } else if p.group_by_tag.unwrap_or(false) {
+            serde_json::json!({
+                "name": sel.name,
+                "is_active": true,
+                "groups": group_by_tag(&sel),
+            })
+        } else {
+            serde_json::json!({
+                "name": sel.name,
+                "is_active": true,
+                "entries": sel.entries,
+            })
+        };
+        let reading_status = papers_core::reading_status::load_statuses().map_err(|e| e.to_string())?.for_selection(&sel);
+        if !reading_status.is_empty() {
+            result["reading_status"] = serde_json::json!(reading_status);
+        }
+        json_result::<_, String>(Ok(result))
+    }
+
     /// Create a new named selection and activate it.
     /// Returns an error if a selection with that name already exists.
     #[tool]
@@ -986,16 +1830,23 @@ impl PapersMcp {
         json_result::<_, String>(Ok(serde_json::json!({ "name": p.name, "is_active": true, "entries": [] })))
     }
 
-    /// Delete a named selection. Deactivates it if it was the active selection.
+    /// Delete a named selection. Deactivates it if it was the active selection, and clears
+    /// the default DB scope if it was scoped to it.
     #[tool]
     pub async fn selection_delete(&self, Parameters(p): Parameters<SelectionDeleteToolParams>) -> Result<String, String> {
         use papers_core::selection::{delete_selection, load_state, resolve_selection, save_state};
         let name = resolve_selection(&p.name).map_err(|e| e.to_string())?;
         let mut state = load_state();
         let was_active = state.active.as_deref() == Some(&name);
+        let was_db_scope = state.db_scope.as_deref() == Some(&name);
         delete_selection(&name).map_err(|e| e.to_string())?;
-        if was_active {
-            state.active = None;
+        if was_active || was_db_scope {
+            if was_active {
+                state.active = None;
+            }
+            if was_db_scope {
+                state.db_scope = None;
+            }
             let _ = save_state(&state);
         }
         json_result::<_, String>(Ok(serde_json::json!({ "name": name, "was_active": was_active })))
@@ -1005,18 +1856,35 @@ impl PapersMcp {
     /// Input can be a Zotero key, DOI, OpenAlex Work ID (e.g. W2741809807), or title text.
     /// Zotero is optional; falls back to OpenAlex-only metadata if not configured.
     /// Skips duplicates silently. Defaults to the active selection.
+    /// When a title matches several papers and the client supports elicitation, the user
+    /// is asked to pick one instead of taking the top search hit.
     #[tool]
     pub async fn selection_add(&self, Parameters(p): Parameters<SelectionAddToolParams>) -> Result<String, String> {
         use papers_core::selection::{
-            active_selection_name, entry_matches_doi, entry_matches_key, entry_matches_openalex,
-            load_selection, resolve_paper, resolve_selection, save_selection,
+            active_selection_name, candidate_label, entry_matches_doi, entry_matches_key, entry_matches_openalex,
+            load_selection, paper_candidates, resolve_candidate, resolve_paper, resolve_selection, save_selection,
+            DEFAULT_CANDIDATE_LIMIT,
         };
         let sel_name = match p.selection {
             Some(s) => resolve_selection(&s).map_err(|e| e.to_string())?,
             None => active_selection_name().ok_or_else(|| "no active selection; use selection param or create one first".to_string())?,
         };
         let zotero = self.get_optional_zotero().await?;
-        let entry = resolve_paper(&p.paper, &self.client, zotero.as_ref()).await.map_err(|e| e.to_string())?;
+        let mut picked = None;
+        if let Some(peer) = Self::form_caller() {
+            let mut candidates = paper_candidates(&p.paper, &self.client, zotero.as_ref(), DEFAULT_CANDIDATE_LIMIT).await;
+            if candidates.len() > 1 {
+                let labels: Vec<String> = candidates.iter().map(candidate_label).collect();
+                let message = format!("Several papers match \"{}\". Which one should be added to {sel_name}?", p.paper);
+                if let Some(i) = Self::elicit_choice(&peer, &message, &labels).await? {
+                    picked = Some(resolve_candidate(candidates.swap_remove(i), &self.client, zotero.as_ref()).await);
+                }
+            }
+        }
+        let entry = match picked {
+            Some(entry) => entry,
+            None => resolve_paper(&p.paper, &self.client, zotero.as_ref(), self.crossref.as_ref()).await.map_err(|e| e.to_string())?,
+        };
         let mut sel = load_selection(&sel_name).map_err(|e| e.to_string())?;
         let is_dup = sel.entries.iter().any(|e| {
             entry.zotero_key.as_deref().map(|k| entry_matches_key(e, k)).unwrap_or(false)
@@ -1026,21 +1894,143 @@ impl PapersMcp {
         if !is_dup {
             sel.entries.push(entry.clone());
             save_selection(&sel).map_err(|e| e.to_string())?;
+            self.auto_index_abstracts(std::slice::from_ref(&entry)).await;
         }
         json_result::<_, String>(Ok(entry))
     }
 
+    /// Add many papers to a selection in one call, each identified as for selection_add
+    /// (Zotero key, DOI, OpenAlex Work ID, or title; e.g. the `input`s from
+    /// work_extract_identifiers). Skips papers already in the selection. Returns the labels
+    /// of the entries added, the duplicates skipped, and the inputs that could not be
+    /// resolved. Defaults to the active selection.
+    #[tool]
+    pub async fn selection_add_bulk(&self, Parameters(p): Parameters<SelectionAddBulkToolParams>) -> Result<String, String> {
+        use papers_core::selection::{add_papers, load_selection, save_selection};
+        let sel_name = Self::resolve_selection_name(p.selection.clone())?;
+        let zotero = self.get_optional_zotero().await?;
+        let mut sel = load_selection(&sel_name).map_err(|e| e.to_string())?;
+        let before = sel.entries.len();
+        let summary = {
+            let _permit = self.limits.acquire(Backend::OpenAlex).await;
+            add_papers(&mut sel, &p.papers, &self.client, zotero.as_ref(), self.crossref.as_ref()).await
+        };
+        if !summary.added.is_empty() {
+            save_selection(&sel).map_err(|e| e.to_string())?;
+            self.auto_index_abstracts(&sel.entries[before..]).await;
+        }
+        json_result::<_, String>(Ok(summary))
+    }
+
+    /// Add every work matching a work_list-style query (search, filter, sort, and shorthand
+    /// aliases such as author, topic, year) to a selection in one call, instead of calling
+    /// selection_add per paper. Takes the first `limit` works (default 50, max 500) in sort
+    /// order and skips papers already in the selection. Returns how many works matched and
+    /// the labels of the entries added and of the duplicates skipped. Entries carry OpenAlex
+    /// metadata only (no Zotero lookup). Defaults to the active selection.
+    #[tool]
+    pub async fn selection_add_query(&self, Parameters(p): Parameters<SelectionAddQueryToolParams>) -> Result<String, String> {
+        use papers_core::selection::{load_selection, save_selection};
+        let sel_name = Self::resolve_selection_name(p.selection.clone())?;
+        let mut sel = load_selection(&sel_name).map_err(|e| e.to_string())?;
+        let before = sel.entries.len();
+        let limit = p.limit.unwrap_or(50);
+        let summary = {
+            let _permit = self.limits.acquire(Backend::OpenAlex).await;
+            papers_core::api::selection_add_query(&self.client, &mut sel, &p.into_work_list_params(), limit)
+                .await
+                .map_err(|e| e.to_string())?
+        };
+        if !summary.added.is_empty() {
+            save_selection(&sel).map_err(|e| e.to_string())?;
+            self.auto_index_abstracts(&sel.entries[before..]).await;
+        }
+        json_result::<_, String>(Ok(summary))
+    }
+
+    /// Add the works a paper cites (its `referenced_works`) to a selection: one backward
+    /// snowballing step. Narrow the references with `filter`, `search`, `year`, `topic` or `type`.
+    /// Papers already in the selection are skipped. Returns `references` (how many works the paper
+    /// cites), `matched` (how many pass the filters), and the `added` and `duplicates` labels.
+    #[tool]
+    pub async fn selection_add_references(
+        &self,
+        Parameters(p): Parameters<SelectionAddReferencesToolParams>,
+    ) -> Result<String, String> {
+        use papers_core::selection::{load_selection, save_selection};
+        let sel_name = Self::resolve_selection_name(p.selection.clone())?;
+        let mut sel = load_selection(&sel_name).map_err(|e| e.to_string())?;
+        let before = sel.entries.len();
+        let result = {
+            let _permit = self.limits.acquire(Backend::OpenAlex).await;
+            papers_core::api::selection_add_references(&self.client, &mut sel, &p.id, &p.into_work_list_params())
+                .await
+                .map_err(|e| e.to_string())?
+        };
+        if !result.summary.added.is_empty() {
+            save_selection(&sel).map_err(|e| e.to_string())?;
+            self.auto_index_abstracts(&sel.entries[before..]).await;
+        }
+        json_result::<_, String>(Ok(result))
+    }
+
+    /// Add the works citing a paper to a selection: one forward snowballing step. Keep
+    /// influential citers with `min_citations` and recent ones with `year` (e.g. "2018-2024");
+    /// at most `limit` (default 50) are added, most cited first unless `sort` says otherwise.
+    /// Papers already in the selection are skipped. `matched` is how many citers passed the filters.
+    #[tool]
+    pub async fn selection_add_citers(
+        &self,
+        Parameters(p): Parameters<SelectionAddCitersToolParams>,
+    ) -> Result<String, String> {
+        use papers_core::selection::{load_selection, save_selection};
+        let sel_name = Self::resolve_selection_name(p.selection.clone())?;
+        let mut sel = load_selection(&sel_name).map_err(|e| e.to_string())?;
+        let before = sel.entries.len();
+        let result = {
+            let _permit = self.limits.acquire(Backend::OpenAlex).await;
+            papers_core::api::selection_add_citers(
+                &self.client,
+                &mut sel,
+                &p.id,
+                &p.into_work_list_params(),
+                p.min_citations,
+                p.limit.unwrap_or(50),
+            )
+            .await
+            .map_err(|e| e.to_string())?
+        };
+        if !result.summary.added.is_empty() {
+            save_selection(&sel).map_err(|e| e.to_string())?;
+            self.auto_index_abstracts(&sel.entries[before..]).await;
+        }
+        json_result::<_, String>(Ok(result))
+    }
+
     // ── DB tools ─────────────────────────────────────────────────────────────
 
-    /// Semantic search across indexed paper chunks. Scope with selection, paper, chapter, or section.
+    /// Search across indexed paper chunks. Scope with selection, Zotero collection, paper, chapter,
+    /// or section.
+    /// `mode` picks semantic (default), keyword (BM25), or hybrid ranking; use keyword or hybrid
+    /// for exact terminology such as method names and acronyms. Set `rerank` to re-score a larger
+    /// candidate pool with a cross-encoder.
     /// Returns matched chunks with immediate neighbors (prev/next) for reading context.
     /// Requires papers to be indexed first via `papers db work add`.
     #[tool]
     pub async fn db_chunk_search(&self, Parameters(p): Parameters<DbChunkSearchParams>) -> Result<String, String> {
         let rag = self.db.as_ref().ok_or_else(|| "DB not configured. Run: papers db work add <ITEM_KEY>".to_string())?;
-        let paper_ids = match p.selection.as_deref() {
-            Some(sel) => Some(Self::resolve_selection_paper_ids(sel)?),
-            None => match p.work {
+        let mode = match p.mode.as_deref() {
+            Some(m) => m.parse::<papers_db::SearchMode>()?,
+            None => papers_db::SearchMode::default(),
+        };
+        if p.selection.is_some() && p.collection.is_some() {
+            return Err("pass selection or collection, not both".to_string());
+        }
+        let selection = Self::db_scope_selection(p.selection, p.work.is_some() || p.collection.is_some());
+        let paper_ids = match (p.collection.as_deref(), selection.as_deref()) {
+            (Some(collection), _) => Some(self.resolve_collection_paper_ids(collection).await?),
+            (None, Some(sel)) => Some(Self::resolve_selection_paper_ids(sel)?),
+            (None, None) => match p.work {
                 Some(id) => {
                     let resolved = papers_db::resolve_paper_id(rag, &id).await.map_err(|e| e.to_string())?;
                     Some(vec![resolved])
@@ -1048,16 +2038,31 @@ impl PapersMcp {
                 None => None,
             },
         };
+        let work_id = paper_ids
+            .as_ref()
+            .filter(|_| selection.is_none() && p.collection.is_none())
+            .and_then(|ids| ids.first());
+        let (chapter_idx, section_idx) = Self::resolve_outline_scope(
+            rag,
+            work_id.map(String::as_str),
+            p.chapter_idx,
+            p.chapter,
+            p.section_idx,
+            p.section,
+        )
+        .await?;
         let params = papers_db::SearchParams {
             query: p.query,
             paper_ids,
-            chapter_idx: p.chapter_idx,
-            section_idx: p.section_idx,
+            chapter_idx,
+            section_idx,
             filter_year_min: p.filter_year_min,
             filter_year_max: p.filter_year_max,
             filter_venue: p.filter_venue,
             filter_tags: p.filter_tags,
             filter_depth: p.filter_depth,
+            mode,
+            rerank: p.rerank.unwrap_or(false),
             limit: p.limit.unwrap_or(5),
         };
         json_result(papers_db::query::search(rag, params).await)
@@ -1068,7 +2073,8 @@ impl PapersMcp {
     #[tool]
     pub async fn db_exhibit_search(&self, Parameters(p): Parameters<DbExhibitSearchParams>) -> Result<String, String> {
         let rag = self.db.as_ref().ok_or_else(|| "DB not configured. Run: papers db work add <ITEM_KEY>".to_string())?;
-        let paper_ids = match p.selection.as_deref() {
+        let selection = Self::db_scope_selection(p.selection, p.work.is_some());
+        let paper_ids = match selection.as_deref() {
             Some(sel) => Some(Self::resolve_selection_paper_ids(sel)?),
             None => match p.work {
                 Some(id) => {
@@ -1087,6 +2093,71 @@ impl PapersMcp {
         json_result(papers_db::query::search_exhibits(rag, params).await)
     }
 
+    /// Find where an equation is defined ("where is the update rule defined?"). Returns the
+    /// LaTeX of the matching chunks' display equations together with the surrounding text.
+    #[tool]
+    pub async fn db_equation_search(&self, Parameters(p): Parameters<DbEquationSearchParams>) -> Result<String, String> {
+        let rag = self.db.as_ref().ok_or_else(|| "DB not configured. Run: papers db work add <ITEM_KEY>".to_string())?;
+        let selection = Self::db_scope_selection(p.selection, p.work.is_some());
+        let paper_ids = match selection.as_deref() {
+            Some(sel) => Some(Self::resolve_selection_paper_ids(sel)?),
+            None => match p.work {
+                Some(id) => {
+                    let resolved = papers_db::resolve_paper_id(rag, &id).await.map_err(|e| e.to_string())?;
+                    Some(vec![resolved])
+                }
+                None => None,
+            },
+        };
+        let params = papers_db::SearchEquationsParams {
+            query: p.query,
+            paper_ids,
+            limit: p.limit.unwrap_or(5),
+        };
+        json_result(papers_db::query::search_equations(rag, params).await)
+    }
+
+    /// Every sentence in the indexed papers that cites a given paper, with the citing paper,
+    /// the citation marker, and the reference entry it resolved to. Use for "how do other
+    /// papers in my library describe this work?".
+    #[tool]
+    pub async fn db_citation_contexts(&self, Parameters(p): Parameters<DbCitationContextsParams>) -> Result<String, String> {
+        let rag = self.db.as_ref().ok_or_else(|| "DB not configured.".to_string())?;
+        let selection = Self::db_scope_selection(p.selection, false);
+        let paper_ids = match selection.as_deref() {
+            Some(sel) => Some(Self::resolve_selection_paper_ids(sel)?),
+            None => None,
+        };
+        let params = papers_db::CitationContextsParams {
+            doi: p.doi,
+            title: p.title,
+            paper_ids,
+            limit: p.limit.unwrap_or(50),
+        };
+        json_result(papers_db::query::citation_contexts(rag, params).await)
+    }
+
+    /// Citation graph among indexed papers, as GraphML (default, for Gephi/yEd) or DOT (Graphviz):
+    /// an edge from each paper to every other indexed paper its reference list cites by DOI.
+    /// Returns node and edge counts with the serialized graph in `content`.
+    #[tool]
+    pub async fn db_citation_graph(&self, Parameters(p): Parameters<DbCitationGraphParams>) -> Result<String, String> {
+        use papers_core::graph::{GraphError, GraphExport, GraphFormat};
+        let rag = self.db.as_ref().ok_or_else(|| "DB not configured.".to_string())?;
+        let format = match p.format {
+            Some(f) => f.parse().map_err(|e: GraphError| e.to_string())?,
+            None => GraphFormat::GraphMl,
+        };
+        let selection = Self::db_scope_selection(p.selection, false);
+        let paper_ids = match selection.as_deref() {
+            Some(sel) => Some(Self::resolve_selection_paper_ids(sel)?),
+            None => None,
+        };
+        let graph = papers_db::query::citation_graph(rag, paper_ids).await.map_err(|e| e.to_string())?;
+        let scope = selection.as_deref().unwrap_or("index");
+        json_result::<_, String>(Ok(GraphExport::new(scope, format, &graph, Vec::new())))
+    }
+
     /// Retrieve a specific chunk by ID with its prev/next neighbors for sequential reading.
     /// Use after db_chunk_search to follow prev/next chunk references.
     #[tool]
@@ -1095,30 +2166,95 @@ impl PapersMcp {
         json_result(papers_db::query::get_chunk(rag, &p.chunk_id).await)
     }
 
+    /// Record a chunk as read, so reading can continue there in a later session.
+    /// Call after reading a chunk (db_chunk_get, db_section_get); db_work_list then shows
+    /// each paper's last chunk read and percent complete. An unread paper's status
+    /// (paper_status_get) becomes reading.
+    #[tool]
+    pub async fn db_chunk_mark_read(&self, Parameters(p): Parameters<DbChunkGetParams>) -> Result<String, String> {
+        let rag = self.db.as_ref().ok_or_else(|| "DB not configured.".to_string())?;
+        let state = papers_db::query::mark_read(rag, &p.chunk_id).await.map_err(|e| e.to_string())?;
+        papers_core::reading_status::record_chunk_read(&state.paper_id, &state.last_chunk_id)
+            .map_err(|e| e.to_string())?;
+        json_result::<_, String>(Ok(state))
+    }
+
+    /// Locate a Zotero annotation (highlight) in the DB: returns the chunk(s) holding the
+    /// highlighted text, best first, each with prev/next neighbors, plus the annotation itself.
+    /// The annotation's page breaks ties, and is used alone when the text matches nothing.
+    /// Follow up with db_chunk_get or db_section_get for surrounding context.
+    /// Requires ZOTERO_USER_ID and ZOTERO_API_KEY, and the annotated paper to be indexed.
+    #[tool]
+    pub async fn db_chunk_locate(&self, Parameters(p): Parameters<DbChunkLocateParams>) -> Result<String, String> {
+        let rag = self.db.as_ref().ok_or_else(|| "DB not configured.".to_string())?;
+        let z = self.require_zotero().await?;
+        let annotation = zotero_resolve::resolve_annotation(&z, &p.annotation_key)
+            .await
+            .map_err(|e| e.to_string())?;
+        let paper_id = papers_db::resolve_paper_id(rag, &annotation.item_key).await.map_err(|e| e.to_string())?;
+        let params = papers_db::LocatePassageParams {
+            paper_id,
+            text: annotation.text.clone().unwrap_or_default(),
+            page_index: annotation.page_index,
+            limit: p.limit.unwrap_or(3),
+        };
+        let matches = papers_db::query::locate_passage(rag, params).await.map_err(|e| e.to_string())?;
+        json_result::<_, String>(Ok(serde_json::json!({ "annotation": annotation, "matches": matches })))
+    }
+
     /// Fetch all chunks in a specific section in reading order.
     /// Use when you need complete section content after finding a relevant chunk.
+    /// Address the section by chapter_idx + section_idx, or by its title from the outline.
+    /// Set `include_exhibits` to inline the referenced exhibits' captions, descriptions and
+    /// image paths, saving a db_exhibit_get per exhibit.
     #[tool]
     pub async fn db_section_get(&self, Parameters(p): Parameters<DbSectionGetParams>) -> Result<String, String> {
         let rag = self.db.as_ref().ok_or_else(|| "DB not configured.".to_string())?;
         let paper_id = papers_db::resolve_paper_id(rag, &p.paper_id).await.map_err(|e| e.to_string())?;
-        json_result(papers_db::query::get_section(rag, &paper_id, p.chapter_idx, p.section_idx).await)
+        let scope = Self::resolve_outline_scope(rag, Some(&paper_id), p.chapter_idx, None, p.section_idx, p.section).await?;
+        let (Some(chapter_idx), Some(section_idx)) = scope else {
+            return Err("pass section, or chapter_idx and section_idx".to_string());
+        };
+        let mut section = papers_db::query::get_section(rag, &paper_id, chapter_idx, section_idx)
+            .await
+            .map_err(|e| e.to_string())?;
+        if p.include_exhibits {
+            papers_db::query::inline_exhibits(rag, section.chunks.iter_mut()).await.map_err(|e| e.to_string())?;
+        }
+        json_result::<_, String>(Ok(section))
     }
 
     /// Fetch the full content of an entire chapter, grouped by section.
     /// Use when the user asks about a broad topic within a paper.
+    /// Address the chapter by chapter_idx or by its title from the outline.
+    /// Set `include_exhibits` to inline the referenced exhibits' records, as for db_section_get.
     #[tool]
     pub async fn db_chapter_get(&self, Parameters(p): Parameters<DbChapterGetParams>) -> Result<String, String> {
         let rag = self.db.as_ref().ok_or_else(|| "DB not configured.".to_string())?;
         let paper_id = papers_db::resolve_paper_id(rag, &p.paper_id).await.map_err(|e| e.to_string())?;
-        json_result(papers_db::query::get_chapter(rag, &paper_id, p.chapter_idx).await)
+        let (chapter_idx, _) = Self::resolve_outline_scope(rag, Some(&paper_id), p.chapter_idx, p.chapter, None, None).await?;
+        let Some(chapter_idx) = chapter_idx else {
+            return Err("pass chapter or chapter_idx".to_string());
+        };
+        let mut chapter = papers_db::query::get_chapter(rag, &paper_id, chapter_idx)
+            .await
+            .map_err(|e| e.to_string())?;
+        if p.include_exhibits {
+            let chunks = chapter.sections.iter_mut().flat_map(|s| s.chunks.iter_mut());
+            papers_db::query::inline_exhibits(rag, chunks).await.map_err(|e| e.to_string())?;
+        }
+        json_result::<_, String>(Ok(chapter))
     }
 
     /// Retrieve full details for an exhibit by ID, including the image file path.
-    /// Use when you need the image path to display it, or to see cross-references.
+    /// Set `include_image` to also get the figure itself as image content (downscaled to
+    /// `max_dimension` px and kept under 1 MB), since MCP clients usually can't open local paths.
     #[tool]
-    pub async fn db_exhibit_get(&self, Parameters(p): Parameters<DbExhibitGetParams>) -> Result<String, String> {
-        let rag = self.db.as_ref().ok_or_else(|| "DB not configured.".to_string())?;
-        json_result(papers_db::query::get_exhibit(rag, &p.exhibit_id).await)
+    pub async fn db_exhibit_get(&self, Parameters(p): Parameters<DbExhibitGetParams>) -> Result<CallToolResult, McpError> {
+        Ok(match self.exhibit_contents(p).await {
+            Ok(contents) => CallToolResult::success(contents),
+            Err(e) => CallToolResult::error(vec![Content::text(e)]),
+        })
     }
 
     /// Get the table of contents for a paper (all chapters and sections with chunk counts).
@@ -1130,14 +2266,25 @@ impl PapersMcp {
         json_result(papers_db::query::get_paper_outline(rag, &paper_id).await)
     }
 
-    /// Browse indexed papers with optional metadata filters.
+    /// Browse indexed papers with optional metadata filters, optionally scoped to a selection or a
+    /// Zotero collection.
     /// Use when the user asks what papers are available, or to find a paper by metadata.
+    /// Papers marked read with db_chunk_mark_read include `reading`: the last chunk read
+    /// (to continue from) and percent complete.
+    /// Papers with a recorded reading status (paper_status_set) include `status`: unread,
+    /// reading or read, the rating, and the last chunk read.
     #[tool]
     pub async fn db_work_list(&self, Parameters(p): Parameters<DbWorkListParams>) -> Result<String, String> {
         let rag = self.db.as_ref().ok_or_else(|| "DB not configured.".to_string())?;
-        let paper_ids = match p.selection.as_deref() {
-            Some(sel) => Some(Self::resolve_selection_paper_ids(sel)?),
-            None => None,
+        if p.selection.is_some() && p.collection.is_some() {
+            return Err("pass selection or collection, not both".to_string());
+        }
+        let paper_ids = match p.collection.as_deref() {
+            Some(collection) => Some(self.resolve_collection_paper_ids(collection).await?),
+            None => match Self::db_scope_selection(p.selection, false) {
+                Some(sel) => Some(Self::resolve_selection_paper_ids(&sel)?),
+                None => None,
+            },
         };
         let params = papers_db::ListPapersParams {
             paper_ids,
@@ -1149,7 +2296,20 @@ impl PapersMcp {
             sort_by: p.sort_by,
             limit: p.limit.unwrap_or(50),
         };
-        json_result(papers_db::query::list_papers(rag, params).await)
+        let papers = papers_db::query::list_papers(rag, params).await.map_err(|e| e.to_string())?;
+        let statuses = papers_core::reading_status::load_statuses().map_err(|e| e.to_string())?;
+        let papers: Vec<serde_json::Value> = papers
+            .into_iter()
+            .map(|paper| {
+                let status = statuses.get(&paper.paper_id).cloned();
+                let mut value = serde_json::to_value(paper).unwrap_or_default();
+                if let (Some(status), Some(obj)) = (status, value.as_object_mut()) {
+                    obj.insert("status".into(), serde_json::to_value(status).unwrap_or_default());
+                }
+                value
+            })
+            .collect();
+        json_result::<_, String>(Ok(papers))
     }
 
     /// Get metadata for a single indexed work (title, authors, year, venue, tags, chunk/figure counts).
@@ -1161,12 +2321,31 @@ impl PapersMcp {
         json_result(papers_db::query::get_work(rag, &paper_id).await)
     }
 
+    /// Remove an indexed work (all its chunks and exhibits) from the DB, e.g. a stale
+    /// or duplicate paper. Returns the number of rows removed. The cached extraction is
+    /// kept, so the paper can be re-added with `papers db work add`.
+    #[tool]
+    pub async fn db_work_remove(&self, Parameters(p): Parameters<DbWorkRemoveParams>) -> Result<String, String> {
+        let rag = self.db.as_ref().ok_or_else(|| "DB not configured.".to_string())?;
+        let paper_id = papers_db::resolve_paper_id(rag, &p.paper_id).await.map_err(|e| e.to_string())?;
+        json_result(rag.delete_paper(&paper_id).await)
+    }
+
+    /// DB overview: paper, chunk and exhibit counts, size on disk, and the embedding
+    /// model and dimension.
+    #[tool]
+    pub async fn db_stats(&self, Parameters(_p): Parameters<ZoteroNoParamsToolParams>) -> Result<String, String> {
+        let rag = self.db.as_ref().ok_or_else(|| "DB not configured.".to_string())?;
+        json_result(rag.stats().await)
+    }
+
     /// Semantic search returning one result per matching work (paper).
     /// Use to find which papers are most relevant to a topic before diving into sections or chunks.
     #[tool]
     pub async fn db_work_search(&self, Parameters(p): Parameters<DbWorkSearchParams>) -> Result<String, String> {
         let rag = self.db.as_ref().ok_or_else(|| "DB not configured.".to_string())?;
-        let paper_ids = match p.selection.as_deref() {
+        let selection = Self::db_scope_selection(p.selection, false);
+        let paper_ids = match selection.as_deref() {
             Some(sel) => Some(Self::resolve_selection_paper_ids(sel)?),
             None => None,
         };
@@ -1182,6 +2361,156 @@ impl PapersMcp {
         json_result(papers_db::query::search_works(rag, params).await)
     }
 
+    /// Embed the OpenAlex abstract of every paper in a selection (or in all selections and the
+    /// watched Zotero collections) into the abstracts-only index: one vector per paper, no PDF
+    /// needed. Re-running replaces stored abstracts. Returns `indexed` and the `missing` entries
+    /// (not found in OpenAlex, or without an abstract).
+    #[tool]
+    pub async fn db_abstract_index(&self, Parameters(p): Parameters<DbAbstractIndexParams>) -> Result<String, String> {
+        let rag = self.db.as_ref().ok_or_else(|| "DB not configured.".to_string())?;
+        let zotero = self.get_optional_zotero().await?;
+        let entries = papers_core::abstracts::abstract_entries(zotero.as_ref(), p.selection.as_deref())
+            .await
+            .map_err(|e| e.to_string())?;
+        let batch = papers_core::abstracts::entry_abstracts(&self.client, &entries).await;
+        let indexed = papers_db::index_abstracts(rag, &batch.abstracts).await.map_err(|e| e.to_string())?;
+        json_result::<_, String>(Ok(serde_json::json!({ "indexed": indexed, "missing": batch.missing })))
+    }
+
+    /// Semantic search over paper abstracts (built by `db_abstract_index`), one hit per
+    /// paper. Covers papers whose full text isn't indexed; `ingested` marks hits that also
+    /// have chunks for db_work_search and the chunk tools.
+    #[tool]
+    pub async fn db_abstract_search(&self, Parameters(p): Parameters<DbAbstractSearchParams>) -> Result<String, String> {
+        let rag = self.db.as_ref().ok_or_else(|| "DB not configured.".to_string())?;
+        let selection = Self::db_scope_selection(p.selection, false);
+        let paper_ids = match selection.as_deref() {
+            Some(sel) => Some(Self::resolve_selection_paper_ids(sel)?),
+            None => None,
+        };
+        let params = papers_db::SearchAbstractsParams {
+            query: p.query,
+            paper_ids,
+            filter_year_min: p.filter_year_min,
+            filter_year_max: p.filter_year_max,
+            limit: p.limit.unwrap_or(10),
+        };
+        json_result(papers_db::query::search_abstracts(rag, params).await)
+    }
+
+    /// Fact-check a claim against the indexed corpus: retrieves the passages most similar to
+    /// `statement` and labels each supporting, contradicting, or neutral with a lexical
+    /// heuristic (shared content terms, plus negation, effect-direction and contrast cues),
+    /// quoting the evidence sentence and the cues behind each label. Labels flag passages to
+    /// read, not verdicts. `rerank` picks passages with the cross-encoder.
+    #[tool]
+    pub async fn db_claim_verify(&self, Parameters(p): Parameters<DbClaimVerifyParams>) -> Result<String, String> {
+        let rag = self.db.as_ref().ok_or_else(|| "DB not configured. Run: papers db work add <ITEM_KEY>".to_string())?;
+        let mode = match p.mode.as_deref() {
+            Some(m) => m.parse::<papers_db::SearchMode>()?,
+            None => papers_db::SearchMode::default(),
+        };
+        let selection = Self::db_scope_selection(p.selection, p.work.is_some());
+        let paper_ids = match selection.as_deref() {
+            Some(sel) => Some(Self::resolve_selection_paper_ids(sel)?),
+            None => match p.work {
+                Some(id) => Some(vec![papers_db::resolve_paper_id(rag, &id).await.map_err(|e| e.to_string())?]),
+                None => None,
+            },
+        };
+        let params = papers_db::VerifyParams {
+            statement: p.statement,
+            paper_ids,
+            mode,
+            rerank: p.rerank.unwrap_or(false),
+            limit: p.limit.unwrap_or(8),
+        };
+        json_result(papers_db::query::verify_claim(rag, params).await)
+    }
+
+    /// Find where a draft needs citations: splits `markdown_text` into paragraphs, retrieves
+    /// the indexed passages most similar to each, and returns per paragraph (with its line
+    /// number) the candidate papers to cite, best first, with the best passage of each.
+    /// Paragraphs that already cite something (`[@key]`, `[12]`, `(Author, 2020)`) are
+    /// flagged `cited`; `uncited` counts the others that have candidates. Up to 100
+    /// paragraphs per call.
+    #[tool]
+    pub async fn db_draft_ground(&self, Parameters(p): Parameters<DbDraftGroundParams>) -> Result<String, String> {
+        let rag = self.db.as_ref().ok_or_else(|| "DB not configured. Run: papers db work add <ITEM_KEY>".to_string())?;
+        let mode = match p.mode.as_deref() {
+            Some(m) => m.parse::<papers_db::SearchMode>()?,
+            None => papers_db::SearchMode::default(),
+        };
+        let paper_ids = match Self::db_scope_selection(p.selection, false) {
+            Some(sel) => Some(Self::resolve_selection_paper_ids(&sel)?),
+            None => None,
+        };
+        let params = papers_db::GroundDraftParams {
+            markdown: p.markdown_text,
+            paper_ids,
+            mode,
+            rerank: p.rerank.unwrap_or(false),
+            limit: p.limit.unwrap_or(3),
+        };
+        json_result(papers_db::query::ground_draft(rag, params).await)
+    }
+
+    /// Rank other indexed papers by overall similarity to one paper (cosine of their mean
+    /// chunk embeddings). Use for "what else in my library is like this paper?".
+    #[tool]
+    pub async fn db_work_similar(&self, Parameters(p): Parameters<DbWorkSimilarParams>) -> Result<String, String> {
+        let rag = self.db.as_ref().ok_or_else(|| "DB not configured.".to_string())?;
+        let paper_id = papers_db::resolve_paper_id(rag, &p.paper_id).await.map_err(|e| e.to_string())?;
+        let selection = Self::db_scope_selection(p.selection, false);
+        let paper_ids = match selection.as_deref() {
+            Some(sel) => Some(Self::resolve_selection_paper_ids(sel)?),
+            None => None,
+        };
+        let params = papers_db::SimilarWorksParams {
+            paper_id,
+            paper_ids,
+            limit: p.limit.unwrap_or(10),
+        };
+        json_result(papers_db::query::similar_works(rag, params).await)
+    }
+
+    /// Cluster indexed papers into themes (k-means on their mean chunk embeddings), each
+    /// labelled with distinguishing title terms and listing its papers, most central first.
+    /// Use to organize a large library or selection; omit k to choose the count automatically.
+    #[tool]
+    pub async fn db_work_cluster(&self, Parameters(p): Parameters<DbWorkClusterParams>) -> Result<String, String> {
+        let rag = self.db.as_ref().ok_or_else(|| "DB not configured.".to_string())?;
+        let selection = Self::db_scope_selection(p.selection, false);
+        let paper_ids = match selection.as_deref() {
+            Some(sel) => Some(Self::resolve_selection_paper_ids(sel)?),
+            None => None,
+        };
+        let params = papers_db::ClusterWorksParams { paper_ids, k: p.k };
+        json_result(papers_db::query::cluster_works(rag, params).await)
+    }
+
+    /// Indexed papers bucketed by publication year, oldest first, with title, authors,
+    /// venue, and how many other indexed papers cite each one. The most-cited papers of
+    /// each year are flagged as milestones. Use to render the history of a research area.
+    #[tool]
+    pub async fn db_work_timeline(&self, Parameters(p): Parameters<DbWorkTimelineParams>) -> Result<String, String> {
+        let rag = self.db.as_ref().ok_or_else(|| "DB not configured.".to_string())?;
+        let selection = Self::db_scope_selection(p.selection, false);
+        let paper_ids = match selection.as_deref() {
+            Some(sel) => Some(Self::resolve_selection_paper_ids(sel)?),
+            None => None,
+        };
+        let params = papers_db::WorkTimelineParams {
+            paper_ids,
+            milestones_per_year: p.milestones.unwrap_or(1),
+        };
+        let mut timeline = papers_db::query::work_timeline(rag, params).await.map_err(|e| e.to_string())?;
+        if let Some(sel) = selection {
+            timeline.scope = sel;
+        }
+        json_result::<_, String>(Ok(timeline))
+    }
+
     /// List chunks in a paper with optional chapter/section scope.
     /// Use to browse the indexed content of a paper before reading individual chunks.
     #[tool]
@@ -1208,7 +2537,8 @@ impl PapersMcp {
     #[tool]
     pub async fn db_section_search(&self, Parameters(p): Parameters<DbSectionSearchParams>) -> Result<String, String> {
         let rag = self.db.as_ref().ok_or_else(|| "DB not configured.".to_string())?;
-        let paper_ids = if let Some(sel) = p.selection.as_deref() {
+        let selection = Self::db_scope_selection(p.selection, p.work.is_some());
+        let paper_ids = if let Some(sel) = selection.as_deref() {
             Some(Self::resolve_selection_paper_ids(sel)?)
         } else if let Some(pid) = p.work {
             let resolved = papers_db::resolve_paper_id(rag, &pid).await.map_err(|e| e.to_string())?;
@@ -1216,10 +2546,13 @@ impl PapersMcp {
         } else {
             None
         };
+        let work_id = paper_ids.as_ref().filter(|_| selection.is_none()).and_then(|ids| ids.first());
+        let (chapter_idx, _) =
+            Self::resolve_outline_scope(rag, work_id.map(String::as_str), p.chapter_idx, p.chapter, None, None).await?;
         let params = papers_db::SearchSectionsParams {
             query: p.query,
             paper_ids,
-            chapter_idx: p.chapter_idx,
+            chapter_idx,
             depth: None,
             filter_year_min: p.filter_year_min,
             filter_year_max: p.filter_year_max,
@@ -1251,7 +2584,8 @@ impl PapersMcp {
     #[tool]
     pub async fn db_chapter_search(&self, Parameters(p): Parameters<DbChapterSearchParams>) -> Result<String, String> {
         let rag = self.db.as_ref().ok_or_else(|| "DB not configured.".to_string())?;
-        let paper_ids = if let Some(sel) = p.selection.as_deref() {
+        let selection = Self::db_scope_selection(p.selection, p.work.is_some());
+        let paper_ids = if let Some(sel) = selection.as_deref() {
             Some(Self::resolve_selection_paper_ids(sel)?)
         } else if let Some(pid) = p.work {
             let resolved = papers_db::resolve_paper_id(rag, &pid).await.map_err(|e| e.to_string())?;
@@ -1290,7 +2624,8 @@ impl PapersMcp {
     #[tool]
     pub async fn db_tag_list(&self, Parameters(p): Parameters<DbTagListParams>) -> Result<String, String> {
         let rag = self.db.as_ref().ok_or_else(|| "DB not configured.".to_string())?;
-        let paper_ids = match p.selection.as_deref() {
+        let selection = Self::db_scope_selection(p.selection, false);
+        let paper_ids = match selection.as_deref() {
             Some(sel) => Some(Self::resolve_selection_paper_ids(sel)?),
             None => None,
         };
@@ -1322,9 +2657,406 @@ impl PapersMcp {
         let title = removed.and_then(|e| e.title).unwrap_or_else(|| p.paper.clone());
         json_result::<_, String>(Ok(serde_json::json!({ "removed": title, "selection": sel_name })))
     }
+
+    /// Set or clear the note on a selection entry, e.g. why the paper is in the
+    /// reading list ("baseline for section 4"). Omit note to clear it.
+    /// Defaults to the active selection.
+    #[tool]
+    pub async fn selection_annotate(&self, Parameters(p): Parameters<SelectionAnnotateToolParams>) -> Result<String, String> {
+        let sel_name = Self::resolve_selection_name(p.selection)?;
+        let entry = papers_core::selection::annotate_entry(&sel_name, &p.paper, p.note.as_deref())
+            .map_err(|e| e.to_string())?;
+        json_result::<_, String>(Ok(serde_json::json!({ "selection": sel_name, "entry": entry })))
+    }
+
+    /// Add and/or remove tags on a selection entry. Tags are matched
+    /// case-insensitively; filter or group by them with selection_get.
+    /// Defaults to the active selection.
+    #[tool]
+    pub async fn selection_tag(&self, Parameters(p): Parameters<SelectionTagToolParams>) -> Result<String, String> {
+        let sel_name = Self::resolve_selection_name(p.selection)?;
+        let add = p.add.unwrap_or_default();
+        let remove = p.remove.unwrap_or_default();
+        if add.is_empty() && remove.is_empty() {
+            return Err("pass at least one tag to add or remove".to_string());
+        }
+        let entry = papers_core::selection::tag_entry(&sel_name, &p.paper, &add, &remove)
+            .map_err(|e| e.to_string())?;
+        json_result::<_, String>(Ok(serde_json::json!({ "selection": sel_name, "entry": entry })))
+    }
+
+    /// Set the default scope of the DB search and list tools (db_chunk_search, db_section_search,
+    /// db_chapter_search, db_work_search, db_exhibit_search, db_equation_search, db_work_similar,
+    /// db_work_cluster, db_work_timeline, db_citation_contexts, db_citation_graph, db_work_list, db_tag_list) to a selection (default: the active one), so later calls
+    /// needn't repeat it. Calls that pass `selection` or `work` ignore the default. Pass clear
+    /// to remove it.
+    #[tool]
+    pub async fn selection_scope(&self, Parameters(p): Parameters<SelectionScopeToolParams>) -> Result<String, String> {
+        let scope = if p.clear.unwrap_or(false) {
+            None
+        } else {
+            Some(Self::resolve_selection_name(p.selection)?)
+        };
+        papers_core::selection::set_db_scope(scope.clone()).map_err(|e| e.to_string())?;
+        json_result::<_, String>(Ok(serde_json::json!({ "db_scope": scope })))
+    }
+
+    /// Put every entry of a selection into a Zotero collection, creating the collection if no
+    /// collection has that name. Entries not yet in Zotero get an item created from OpenAlex
+    /// metadata, or are linked to the library's existing copy; their new Zotero keys are saved to
+    /// the selection. Nothing is removed from the collection.
+    /// Returns created items, linked entries, items added to the collection, and failures.
+    /// Defaults to the active selection and a collection named after it. Writes go to the Zotero
+    /// web API and need a key with write access.
+    #[tool]
+    pub async fn selection_sync_to_zotero(&self, Parameters(p): Parameters<SelectionSyncToZoteroToolParams>) -> Result<String, String> {
+        let sel_name = Self::resolve_selection_name(p.selection)?;
+        let z = self.require_zotero().await?;
+        let _permit = self.limits.acquire(Backend::OpenAlex).await;
+        json_result(
+            papers_core::selection_zotero::sync_selection_to_collection(&self.client, &z, &sel_name, p.collection.as_deref())
+                .await,
+        )
+    }
+
+    /// Add the items of a Zotero collection to a selection, creating the selection if needed.
+    /// Items already in the selection are skipped; standalone notes and attachments and
+    /// subcollections are not included. Entries carry Zotero metadata and keys.
+    /// Returns the selection name with added and duplicate entries.
+    #[tool]
+    pub async fn selection_from_collection(&self, Parameters(p): Parameters<SelectionFromCollectionToolParams>) -> Result<String, String> {
+        let z = self.require_zotero().await?;
+        json_result(papers_core::selection_zotero::selection_from_collection(&z, &p.collection, p.name.as_deref()).await)
+    }
+
+    // ── Screening tools ──────────────────────────────────────────────────────
+
+    /// Record a PRISMA screening decision (include/exclude/maybe) for a selection entry.
+    /// Stage is title_abstract (default) or full_text; full-text decisions require a
+    /// title/abstract inclusion. Later decisions supersede earlier ones.
+    /// Defaults to the active selection.
+    #[tool]
+    pub async fn selection_screen(&self, Parameters(p): Parameters<SelectionScreenToolParams>) -> Result<String, String> {
+        use papers_core::screening::{adjudicate_decision, record_decision, ScreeningDecision};
+        let sel_name = Self::resolve_selection_name(p.selection)?;
+        let decision: ScreeningDecision = p.decision.parse().map_err(|e: papers_core::screening::ScreeningError| e.to_string())?;
+        let stage = Self::parse_screening_stage(p.stage)?;
+        let record_fn = if p.adjudicate.unwrap_or(false) { adjudicate_decision } else { record_decision };
+        let (entry, record) = record_fn(&sel_name, &p.paper, stage, decision, p.reason, p.reviewer)
+            .map_err(|e| e.to_string())?;
+        json_result::<_, String>(Ok(serde_json::json!({
+            "selection": sel_name,
+            "title": entry.title,
+            "record": record,
+        })))
+    }
+
+    /// Screening progress for a selection at one stage: decision counts plus
+    /// per-entry decisions. Use pending_only to list entries still to screen.
+    /// Defaults to the active selection.
+    #[tool]
+    pub async fn selection_screening_status(&self, Parameters(p): Parameters<SelectionScreeningStatusToolParams>) -> Result<String, String> {
+        use papers_core::screening::{load_screening, screening_entries, screening_progress};
+        use papers_core::selection::load_selection;
+        let sel_name = Self::resolve_selection_name(p.selection)?;
+        let stage = Self::parse_screening_stage(p.stage)?;
+        let sel = load_selection(&sel_name).map_err(|e| e.to_string())?;
+        let state = load_screening(&sel_name).map_err(|e| e.to_string())?;
+        let statuses = screening_entries(&sel, &state);
+        let progress = screening_progress(&statuses, stage);
+        let pending_only = p.pending_only.unwrap_or(false);
+        let entries: Vec<_> = statuses
+            .into_iter()
+            .filter(|s| s.is_eligible(stage) && !(pending_only && s.record(stage).is_some()))
+            .collect();
+        json_result::<_, String>(Ok(serde_json::json!({
+            "selection": sel_name,
+            "progress": progress,
+            "entries": entries,
+        })))
+    }
+
+    /// Blinded screening list for an independent reviewer: screening key, title, and year only
+    /// (no authors, venue, or prior decisions). Returns CSV with empty decision/reason columns
+    /// for use with selection_screening_import. Defaults to the active selection.
+    #[tool]
+    pub async fn selection_screening_export(&self, Parameters(p): Parameters<SelectionScreeningExportToolParams>) -> Result<String, String> {
+        use papers_core::screening::{blinded_csv, blinded_entries, load_screening};
+        use papers_core::selection::load_selection;
+        let sel_name = Self::resolve_selection_name(p.selection)?;
+        let stage = Self::parse_screening_stage(p.stage)?;
+        let sel = load_selection(&sel_name).map_err(|e| e.to_string())?;
+        let state = load_screening(&sel_name).map_err(|e| e.to_string())?;
+        let entries = blinded_entries(&sel, &state, stage);
+        json_result::<_, String>(Ok(serde_json::json!({
+            "selection": sel_name,
+            "stage": stage,
+            "count": entries.len(),
+            "csv": blinded_csv(&entries),
+        })))
+    }
+
+    /// Import one reviewer's screening decisions from CSV (key, decision, optional reason/reviewer/stage
+    /// columns) and merge them into the screening state. Reports unknown keys and any
+    /// reviewer conflicts. Defaults to the active selection.
+    #[tool]
+    pub async fn selection_screening_import(&self, Parameters(p): Parameters<SelectionScreeningImportToolParams>) -> Result<String, String> {
+        let sel_name = Self::resolve_selection_name(p.selection)?;
+        let stage = Self::parse_screening_stage(p.stage)?;
+        json_result(papers_core::screening::import_decisions_csv(&sel_name, &p.csv, stage, p.reviewer.as_deref()))
+    }
+
+    /// Entries where reviewers' latest screening decisions disagree and have not been adjudicated.
+    /// Settle with selection_screen (adjudicate=true). Defaults to the active selection.
+    #[tool]
+    pub async fn selection_screening_conflicts(&self, Parameters(p): Parameters<SelectionScreeningConflictsToolParams>) -> Result<String, String> {
+        use papers_core::screening::{load_screening, screening_conflicts};
+        use papers_core::selection::load_selection;
+        let sel_name = Self::resolve_selection_name(p.selection)?;
+        let stage = Self::parse_screening_stage(p.stage)?;
+        let sel = load_selection(&sel_name).map_err(|e| e.to_string())?;
+        let state = load_screening(&sel_name).map_err(|e| e.to_string())?;
+        json_result::<_, String>(Ok(screening_conflicts(&sel, &state, stage)))
+    }
+
+    /// PRISMA flow summary for a screened selection: records identified, screened and
+    /// excluded at title/abstract, full-text reports assessed and excluded (with reasons),
+    /// and studies included. Defaults to the active selection.
+    #[tool]
+    pub async fn selection_prisma(&self, Parameters(p): Parameters<SelectionPrismaToolParams>) -> Result<String, String> {
+        let sel_name = Self::resolve_selection_name(p.selection)?;
+        json_result(papers_core::screening::prisma_flow(&sel_name))
+    }
+
+    /// Export a selection as a bibliography: "bibtex" (default) or "csl-json". Each record is
+    /// built from the Zotero item when available, else the OpenAlex work, else the metadata
+    /// stored on the selection entry; per-source counts are included. Citation keys use
+    /// Zotero's citation key when set, otherwise `familyYEARword`. Defaults to the active selection.
+    #[tool]
+    pub async fn selection_export(&self, Parameters(p): Parameters<SelectionExportToolParams>) -> Result<String, String> {
+        use papers_core::export::{ExportError, ExportFormat, export_selection};
+        let sel_name = Self::resolve_selection_name(p.selection)?;
+        let format = match p.format {
+            Some(f) => f.parse().map_err(|e: ExportError| e.to_string())?,
+            None => ExportFormat::Bibtex,
+        };
+        let zotero = self.get_optional_zotero().await?;
+        let _permit = self.limits.acquire(Backend::OpenAlex).await;
+        json_result(export_selection(&sel_name, format, &self.client, zotero.as_ref()).await)
+    }
+
+    /// Fill LaTeX citation keys from a selection. `\cite{?}` placeholders (any `\cite`-family
+    /// command) are matched on the sentence before them, `\cite{?hint}` on the hint (DOI, author
+    /// and year, or title words); existing keys are checked, and author-year mentions like
+    /// "Vaswani et al. (2017)" are matched to keys. Pass the manuscript's .bib text as `bibtex` to
+    /// prefer its keys; selection records cited but missing from it are returned as
+    /// `missing_bibtex` entries to append. Returns the fragment with resolved placeholders
+    /// replaced. Defaults to the active selection.
+    #[tool]
+    pub async fn selection_cite(&self, Parameters(p): Parameters<SelectionCiteToolParams>) -> Result<String, String> {
+        let sel_name = Self::resolve_selection_name(p.selection)?;
+        let zotero = self.get_optional_zotero().await?;
+        let _permit = self.limits.acquire(Backend::OpenAlex).await;
+        json_result(
+            papers_core::latex_cite::fill_selection_citations(&p.latex, &sel_name, p.bibtex.as_deref(), &self.client, zotero.as_ref())
+                .await,
+        )
+    }
+
+    /// Generate a static HTML site for a selection, for sharing a reading list: `index.html`
+    /// with text/year/tag filters and one page per paper (`papers/*.html`) with its abstract,
+    /// Zotero annotations, and, for papers indexed in the local DB, its figures (copied into
+    /// `figures/`). Metadata comes from Zotero when available, else OpenAlex, else the
+    /// selection entry. Defaults to the active selection.
+    #[tool]
+    pub async fn selection_site(&self, Parameters(p): Parameters<SelectionSiteToolParams>) -> Result<String, String> {
+        use papers_core::site::{SiteFigure, site_papers, write_site};
+        let sel_name = Self::resolve_selection_name(p.selection)?;
+        let sel = papers_core::selection::load_selection(&sel_name).map_err(|e| e.to_string())?;
+        let zotero = self.get_optional_zotero().await?;
+        let mut papers = {
+            let _permit = self.limits.acquire(Backend::OpenAlex).await;
+            site_papers(&sel, &self.client, zotero.as_ref()).await
+        };
+        if let Some(rag) = self.db.as_ref() {
+            for paper in &mut papers {
+                for id in paper.db_ids() {
+                    let exhibits =
+                        papers_db::query::list_exhibits(rag, &id).await.unwrap_or_default();
+                    if exhibits.is_empty() {
+                        continue;
+                    }
+                    paper.figures = exhibits
+                        .into_iter()
+                        .map(|e| SiteFigure {
+                            exhibit_type: e.exhibit_type,
+                            caption: e.caption,
+                            image_path: e.image_path.map(Into::into),
+                        })
+                        .collect();
+                    break;
+                }
+            }
+        }
+        json_result(write_site(std::path::Path::new(&p.out_dir), &sel.name, &papers))
+    }
+
+    /// Estimated article processing charge (APC) spend for a selection: totals of OpenAlex
+    /// `apc_list` and `apc_paid` in USD, an estimate that uses `apc_paid` or else the list
+    /// price for gold/hybrid OA works, and breakdowns by source and year.
+    /// Defaults to the active selection.
+    #[tool]
+    pub async fn selection_apc_report(&self, Parameters(p): Parameters<SelectionApcReportToolParams>) -> Result<String, String> {
+        let sel_name = Self::resolve_selection_name(p.selection)?;
+        let sel = papers_core::selection::load_selection(&sel_name).map_err(|e| e.to_string())?;
+        let _permit = self.limits.acquire(Backend::OpenAlex).await;
+        json_result::<_, String>(Ok(papers_core::api::selection_apc_report(&self.client, &sel).await))
+    }
+
+    /// UN Sustainable Development Goal coverage of a selection: for each goal, how many
+    /// works OpenAlex tags with it, their share of the selection, mean tag score, and
+    /// work IDs. Entries that cannot be found in OpenAlex are listed as unresolved.
+    /// Defaults to the active selection.
+    #[tool]
+    pub async fn selection_sdg_report(&self, Parameters(p): Parameters<SelectionSdgReportToolParams>) -> Result<String, String> {
+        let sel_name = Self::resolve_selection_name(p.selection)?;
+        let sel = papers_core::selection::load_selection(&sel_name).map_err(|e| e.to_string())?;
+        let _permit = self.limits.acquire(Backend::OpenAlex).await;
+        json_result::<_, String>(Ok(papers_core::api::selection_sdg_report(&self.client, &sel).await))
+    }
+
+    /// Citation graph among the works in a selection, as GraphML (default, for Gephi/yEd) or
+    /// DOT (Graphviz), built from each work's OpenAlex `referenced_works`. Returns node and edge
+    /// counts with the serialized graph in `content`; entries not found in OpenAlex are listed
+    /// as unresolved. Defaults to the active selection.
+    #[tool]
+    pub async fn selection_citation_graph(&self, Parameters(p): Parameters<SelectionCitationGraphToolParams>) -> Result<String, String> {
+        use papers_core::graph::{GraphError, GraphFormat};
+        let sel_name = Self::resolve_selection_name(p.selection)?;
+        let format = match p.format {
+            Some(f) => f.parse().map_err(|e: GraphError| e.to_string())?,
+            None => GraphFormat::GraphMl,
+        };
+        let sel = papers_core::selection::load_selection(&sel_name).map_err(|e| e.to_string())?;
+        let _permit = self.limits.acquire(Backend::OpenAlex).await;
+        json_result::<_, String>(Ok(papers_core::api::selection_citation_graph(&self.client, &sel, format).await))
+    }
+
+    /// Works in a selection bucketed by publication year, oldest first, with title,
+    /// authors, venue, DOI, and OpenAlex citation count. The most-cited works of each year
+    /// are flagged as milestones. Entries not found in OpenAlex use their stored metadata.
+    /// Defaults to the active selection.
+    #[tool]
+    pub async fn selection_timeline(&self, Parameters(p): Parameters<SelectionTimelineToolParams>) -> Result<String, String> {
+        let sel_name = Self::resolve_selection_name(p.selection)?;
+        let sel = papers_core::selection::load_selection(&sel_name).map_err(|e| e.to_string())?;
+        let milestones = p.milestones.unwrap_or(papers_core::timeline::DEFAULT_MILESTONES_PER_YEAR);
+        let _permit = self.limits.acquire(Backend::OpenAlex).await;
+        json_result::<_, String>(Ok(papers_core::api::selection_timeline(&self.client, &sel, milestones).await))
+    }
+
+    // ── Reading status ───────────────────────────────────────────────────────
+
+    /// Record how far you are with a paper: status (unread, reading, read), a 1-5 rating
+    /// (0 clears it), and the DB chunk where reading left off. Fields left out keep their
+    /// current value. Statuses are shown by db_work_list and selection_get, and
+    /// db_chunk_mark_read moves an unread paper to reading.
+    /// Returns the paper's updated record.
+    #[tool]
+    pub async fn paper_status_set(&self, Parameters(p): Parameters<PaperStatusSetToolParams>) -> Result<String, String> {
+        use papers_core::reading_status::{ReadingStatus, ReadingStatusError, StatusUpdate, paper_key, set_status};
+        let status = p
+            .status
+            .map(|s| s.parse::<ReadingStatus>())
+            .transpose()
+            .map_err(|e: ReadingStatusError| e.to_string())?;
+        let update = StatusUpdate { status, rating: p.rating, last_chunk_id: p.last_chunk_id };
+        let record = set_status(&p.paper_id, update).map_err(|e| e.to_string())?;
+        json_result::<_, String>(Ok(serde_json::json!({
+            "paper_id": paper_key(&p.paper_id),
+            "record": record,
+        })))
+    }
+
+    /// Reading status of a paper (unread unless recorded otherwise), with its rating and
+    /// the DB chunk where reading left off. Without paper_id, lists every paper with a
+    /// recorded status, optionally only those with the given status.
+    /// Use to check what the user has already read before recommending papers.
+    #[tool]
+    pub async fn paper_status_get(&self, Parameters(p): Parameters<PaperStatusGetToolParams>) -> Result<String, String> {
+        use papers_core::reading_status::{ReadingStatus, ReadingStatusError, get_status, load_statuses, paper_key};
+        if let Some(paper_id) = p.paper_id {
+            let record = get_status(&paper_id).map_err(|e| e.to_string())?;
+            return json_result::<_, String>(Ok(serde_json::json!({
+                "paper_id": paper_key(&paper_id),
+                "record": record,
+            })));
+        }
+        let status = p
+            .status
+            .map(|s| s.parse::<ReadingStatus>())
+            .transpose()
+            .map_err(|e: ReadingStatusError| e.to_string())?;
+        let mut statuses = load_statuses().map_err(|e| e.to_string())?;
+        if let Some(status) = status {
+            statuses.papers.retain(|_, record| record.status == status);
+        }
+        json_result::<_, String>(Ok(statuses))
+    }
+
+    // ── Digest ───────────────────────────────────────────────────────────────
+
+    /// One morning call for what is new in the last `days` days (default 1, max 90):
+    /// works published in the window matching each watch query, new works citing the
+    /// papers of the given selections (e.g. your own papers), and items added to Zotero.
+    /// Returns JSON sections plus the same digest rendered as `markdown`. Sections whose
+    /// backend fails are empty and listed in `warnings`. Nothing is sent; see digest_notify.
+    #[tool]
+    pub async fn digest_generate(&self, Parameters(p): Parameters<DigestGenerateToolParams>) -> Result<String, String> {
+        let digest = self.build_digest(p.selections, p.watches, p.days).await?;
+        json_result::<_, String>(Ok(digest))
+    }
+
+    /// Build the digest like digest_generate and announce the works in it (watch hits and
+    /// new citers) that weren't announced before, to the webhooks and email configured in
+    /// config.json. Returns the IDs announced, how many were left out as already announced,
+    /// and the outcome of each delivery.
+    #[tool]
+    pub async fn digest_notify(&self, Parameters(p): Parameters<DigestNotifyToolParams>) -> Result<String, String> {
+        let channels = papers_core::notify::configured_channels();
+        if channels.is_empty() {
+            return Err("no webhooks or email configured in config.json".to_string());
+        }
+        let digest = self.build_digest(p.selections, p.watches, p.days).await?;
+        json_result(papers_core::notify::notify_digest(&digest, &channels).await)
+    }
 }
 
 impl PapersMcp {
+    /// The digest for `digest_generate` and `digest_notify`.
+    async fn build_digest(
+        &self,
+        selections: Option<Vec<String>>,
+        watches: Option<Vec<String>>,
+        days: Option<u32>,
+    ) -> Result<papers_core::digest::Digest, String> {
+        let mut loaded = Vec::new();
+        for input in selections.unwrap_or_default() {
+            let name = papers_core::selection::resolve_selection(&input).map_err(|e| e.to_string())?;
+            loaded.push(papers_core::selection::load_selection(&name).map_err(|e| e.to_string())?);
+        }
+        let zotero = self.get_optional_zotero().await?;
+        let _permit = self.limits.acquire(Backend::OpenAlex).await;
+        Ok(papers_core::api::digest_generate(
+            &self.client,
+            zotero.as_ref(),
+            &loaded,
+            &watches.unwrap_or_default(),
+            days.unwrap_or(papers_core::digest::DEFAULT_DIGEST_DAYS),
+        )
+        .await)
+    }
+
     /// Fallback chain when no PDF is found: sampling → elicitation + polling → None.
     async fn work_text_fallback(
         &self,
@@ -1333,15 +3065,13 @@ impl PapersMcp {
         title: Option<&str>,
         doi: Option<&str>,
         zotero: Option<&ZoteroClient>,
-    ) -> Option<Result<String, String>> {
+    ) -> Option<Result<papers_core::text::WorkTextResult, String>> {
         let display = title.unwrap_or(work_id);
 
-        // Step A: Try sampling — ask the LLM to find a PDF URL
+        // Step A: Try sampling — reuse the PDF URL the LLM found before, or ask it for one
         if let Some(doi) = doi {
-            if peer.supports_sampling_tools() {
-                if let Some(result) = self.try_sampling_pdf(peer, work_id, title, doi).await {
-                    return Some(result);
-                }
+            if let Some(result) = self.try_sampling_pdf(peer, work_id, title, doi).await {
+                return Some(result);
             }
         }
 
@@ -1372,16 +3102,35 @@ impl PapersMcp {
         None
     }
 
-    /// Ask the LLM to find a PDF URL via sampling, then try to download it.
+    /// Ask the LLM to find a PDF URL via sampling, then try to download it. Answers are kept per
+    /// work in [`papers_core::sampled`]: a URL that downloaded before is reused without asking,
+    /// and a recent "none" (or a URL that was not a PDF) is not asked again.
     async fn try_sampling_pdf(
         &self,
         peer: &Peer<RoleServer>,
         work_id: &str,
         title: Option<&str>,
         doi: &str,
-    ) -> Option<Result<String, String>> {
+    ) -> Option<Result<papers_core::text::WorkTextResult, String>> {
+        use papers_core::sampled::{PDF_URL, SampledStore};
         use rmcp::model::{CreateMessageRequestParams, SamplingMessage};
 
+        let store = SampledStore::open_default();
+        match store.get(work_id, PDF_URL) {
+            Some(previous) if previous.value.is_some() => {
+                let url = previous.value.as_deref().unwrap_or_default();
+                if let Some(result) = Self::fetch_sampled_pdf(url, work_id, title, doi).await {
+                    return Some(Ok(result));
+                }
+                // The URL went stale; ask again below.
+            }
+            Some(previous) if !previous.is_stale_miss() => return None,
+            _ => {}
+        }
+        if !peer.supports_sampling_tools() {
+            return None;
+        }
+
         let bare_doi = doi.strip_prefix("https://doi.org/").unwrap_or(doi);
         let display = title.unwrap_or(work_id);
         let prompt = format!(
@@ -1414,15 +3163,30 @@ impl PapersMcp {
             Some(rmcp::model::SamplingMessageContent::Text(t)) => t.text.clone(),
             _ => return None,
         };
-        let text = text.trim();
-
-        if text.eq_ignore_ascii_case("none") || text.is_empty() || !text.starts_with("http") {
-            return None;
+        let url = text.trim();
+        let found = if url.starts_with("http") { Self::fetch_sampled_pdf(url, work_id, title, doi).await } else { None };
+
+        // Remember the answer either way, so the next call for this work skips the model.
+        let model = Some(result.model.as_str()).filter(|m| !m.is_empty());
+        let recorded = found.as_ref().map(|_| url);
+        if let Err(e) = store.put(work_id, PDF_URL, recorded, model) {
+            eprintln!("warning: sampled PDF URL for {work_id} not recorded: {e}");
         }
 
-        // Try downloading the URL
+        found.map(Ok)
+    }
+
+    /// Download `url` and extract its text, if it serves a non-empty PDF. The PDF is kept in the
+    /// DataLab cache under the work ID (see [`papers_core::text::save_downloaded_pdf`]), so the next
+    /// `work_text` call and RAG extraction read it from disk.
+    async fn fetch_sampled_pdf(
+        url: &str,
+        work_id: &str,
+        title: Option<&str>,
+        doi: &str,
+    ) -> Option<papers_core::text::WorkTextResult> {
         let http = reqwest::Client::new();
-        let resp = match http.get(text)
+        let resp = match http.get(url)
             .header("User-Agent", "papers-mcp/0.1")
             .send()
             .await
@@ -1445,18 +3209,42 @@ impl PapersMcp {
             _ => return None,
         };
 
-        let extracted = match papers_core::text::extract_text_bytes(&bytes) {
-            Ok(t) => t,
-            Err(_) => return None,
-        };
+        let source = papers_core::text::PdfSource::DirectUrl { url: url.to_string() };
+        let cache_id = work_id.rsplit('/').next().unwrap_or(work_id);
+        papers_core::text::save_downloaded_pdf(cache_id, &bytes, &source, title, Some(doi));
+        let text = papers_core::text::extract_text_bytes(&bytes).ok()?;
 
-        Some(json_result::<_, String>(Ok(papers_core::text::WorkTextResult {
-            text: extracted,
-            source: papers_core::text::PdfSource::DirectUrl { url: text.to_string() },
+        Some(papers_core::text::WorkTextResult {
+            text,
+            source,
             work_id: work_id.to_string(),
             title: title.map(String::from),
             doi: Some(doi.to_string()),
-        })))
+            attempts: Vec::new(),
+            extraction: None,
+        })
+    }
+
+    /// Forward DataLab job progress to `peer` as progress notifications, one
+    /// per poll. A resumed job (submitted before a crash) says so.
+    fn datalab_progress_hook(peer: Peer<RoleServer>) -> papers_core::text::DatalabProgressHook {
+        use rmcp::model::ProgressNotificationParam;
+
+        Arc::new(move |p: &papers_core::text::JobProgress| {
+            let token = rmcp::model::ProgressToken(rmcp::model::NumberOrString::String(format!("datalab_{}", p.key).into()));
+            let state = if p.resumed { "resumed job" } else { "converting" };
+            let message = format!("DataLab {state} for {}: poll {}, {}s", p.key, p.polls, p.elapsed.as_secs());
+            let progress = ProgressNotificationParam {
+                progress_token: token,
+                progress: p.polls as f64,
+                total: None,
+                message: Some(message),
+            };
+            let peer = peer.clone();
+            tokio::spawn(async move {
+                let _ = peer.notify_progress(progress).await;
+            });
+        })
     }
 
     /// Poll Zotero for a work, sending progress notifications to the client.
@@ -1467,7 +3255,7 @@ impl PapersMcp {
         work_id: &str,
         title: Option<&str>,
         doi: &str,
-    ) -> Result<String, String> {
+    ) -> Result<papers_core::text::WorkTextResult, String> {
         use rmcp::model::ProgressNotificationParam;
 
         let token = rmcp::model::ProgressToken(rmcp::model::NumberOrString::String(format!("poll_{work_id}").into()));
@@ -1505,13 +3293,15 @@ impl PapersMcp {
                         Err(e) => return Err(format!("PDF extraction error: {e}")),
                     };
 
-                    return json_result::<_, String>(Ok(papers_core::text::WorkTextResult {
+                    return Ok(papers_core::text::WorkTextResult {
                         text,
                         source,
                         work_id: work_id.to_string(),
                         title: title.map(String::from),
                         doi: Some(doi.to_string()),
-                    }));
+                        attempts: Vec::new(),
+                        extraction: None,
+                    });
                 }
                 Ok(None) => {}
                 Err(e) => return Err(e.to_string()),
@@ -1532,13 +3322,272 @@ impl PapersMcp {
     }
 }
 
-#[tool_handler]
+tokio::task_local! {
+    /// Client of the tool call being dispatched, so lookups deep inside a tool
+    /// can ask the user to disambiguate without threading the peer through.
+    static CALLER: Peer<RoleServer>;
+
+    /// Zotero client built from the credentials passed with the tool call,
+    /// overriding the server's own (see [`PapersMcp::take_zotero_credentials`]).
+    static CALL_ZOTERO: ZoteroClient;
+}
+
+/// Optional arguments every tool accepts to pick the Zotero library per call.
+pub const ZOTERO_CREDENTIAL_ARGS: [&str; 3] = ["zotero_profile", "zotero_user_id", "zotero_api_key"];
+
+impl PapersMcp {
+    /// Remove the [`ZOTERO_CREDENTIAL_ARGS`] from `arguments` and resolve
+    /// them, so tools never see them. `Ok(None)` when none were passed.
+    /// Profiles are shared by all tenants, so a `tenant` may only name its own.
+    fn take_zotero_credentials(
+        tenant: Option<&str>,
+        arguments: &mut Option<serde_json::Map<String, serde_json::Value>>,
+    ) -> Result<Option<ZoteroClient>, String> {
+        use papers_core::zotero_profiles::{resolve, ProfileStore};
+
+        let Some(args) = arguments.as_mut() else {
+            return Ok(None);
+        };
+        let [profile, user_id, api_key] = ZOTERO_CREDENTIAL_ARGS.map(|name| match args.remove(name) {
+            Some(serde_json::Value::String(s)) if !s.trim().is_empty() => Some(s),
+            _ => None,
+        });
+        if let (Some(tenant), Some(name)) = (tenant, profile.as_deref())
+            && name.trim() != tenant
+        {
+            return Err(format!(
+                "zotero_profile {name:?} is not available to user {tenant:?}; \
+                 use your own profile or pass zotero_user_id and zotero_api_key"
+            ));
+        }
+        let store = match &profile {
+            Some(_) => ProfileStore::load().map_err(|e| e.to_string())?,
+            None => ProfileStore::default(),
+        };
+        let credentials = resolve(&store, profile.as_deref(), user_id.as_deref(), api_key.as_deref())
+            .map_err(|e| e.to_string())?;
+        Ok(credentials.map(|c| c.client()))
+    }
+
+    /// Run a tool call, inside the tenant's scope when there is one.
+    async fn dispatch_tool(
+        &self,
+        mut request: CallToolRequestParams,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, McpError> {
+        let zotero = match Self::take_zotero_credentials(self.user.as_deref(), &mut request.arguments) {
+            Ok(zotero) => zotero,
+            Err(e) => return Ok(CallToolResult::error(vec![Content::text(e)])),
+        };
+        let peer = context.peer.clone();
+        let tcc = ToolCallContext::new(self, request, context);
+        let call = async {
+            match &self.data_dir {
+                Some(dir) => papers_core::tenant::scope(dir, self.tool_router.call(tcc)).await,
+                None => self.tool_router.call(tcc).await,
+            }
+        };
+        match zotero {
+            Some(z) => CALLER.scope(peer, CALL_ZOTERO.scope(z, call)).await,
+            None => CALLER.scope(peer, call).await,
+        }
+    }
+
+    /// [`dispatch_tool`](Self::dispatch_tool), recording the call in the audit log when there is one.
+    async fn dispatch_audited(
+        &self,
+        request: CallToolRequestParams,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, McpError> {
+        let Some(audit) = &self.audit else {
+            return self.dispatch_tool(request, context).await;
+        };
+        let tool = request.name.to_string();
+        let ids = request.arguments.as_ref().map(papers_core::audit::audited_ids).unwrap_or_default();
+        let session = context
+            .extensions
+            .get::<axum::http::request::Parts>()
+            .and_then(|parts| parts.headers.get("mcp-session-id"))
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        let started = std::time::Instant::now();
+        let result = self.dispatch_tool(request, context).await;
+        let ok = matches!(&result, Ok(r) if r.is_error != Some(true));
+        let record = AuditRecord {
+            user: self.user.clone(),
+            session,
+            ..AuditRecord::now(tool, ids, ok, started.elapsed())
+        };
+        if let Err(e) = audit.record(&record) {
+            eprintln!("warning: audit log write failed: {e}");
+        }
+        result
+    }
+
+    /// Client of the current tool call, if it can show elicitation forms.
+    fn form_caller() -> Option<Peer<RoleServer>> {
+        CALLER
+            .try_with(Peer::clone)
+            .ok()
+            .filter(|peer| peer.supported_elicitation_modes().contains(&rmcp::service::ElicitationMode::Form))
+    }
+
+    /// Ask the user to pick one of `labels` via form elicitation.
+    ///
+    /// Returns `Ok(None)` if the form could not be shown, so the caller falls
+    /// back to its best guess, and an error when the user declines to choose.
+    async fn elicit_choice(peer: &Peer<RoleServer>, message: &str, labels: &[String]) -> Result<Option<usize>, String> {
+        use rmcp::model::{CreateElicitationRequestParams, ElicitationAction, ElicitationSchema, EnumSchema};
+
+        let values: Vec<String> = (1..=labels.len()).map(|i| i.to_string()).collect();
+        let Ok(choice) = EnumSchema::builder(values).enum_titles(labels.to_vec()) else {
+            return Ok(None);
+        };
+        let Ok(schema) = ElicitationSchema::builder()
+            .required_enum_schema("choice", choice.title("Match").build())
+            .build()
+        else {
+            return Ok(None);
+        };
+        let result = match peer.create_elicitation(CreateElicitationRequestParams::FormElicitationParams {
+            meta: None,
+            message: message.to_string(),
+            requested_schema: schema,
+        }).await {
+            Ok(r) => r,
+            Err(_) => return Ok(None),
+        };
+        if result.action != ElicitationAction::Accept {
+            return Err("No match chosen; pass a more specific query, DOI, or key.".to_string());
+        }
+        let picked = result.content.as_ref()
+            .and_then(|c| c.get("choice"))
+            .and_then(|v| v.as_str())
+            .and_then(|v| v.parse::<usize>().ok())
+            .filter(|i| (1..=labels.len()).contains(i));
+        Ok(picked.map(|i| i - 1))
+    }
+
+    /// Annotations on the annotatable `attachments`, fetched concurrently under
+    /// the Zotero limit. Attachments whose annotations fail to load are skipped.
+    async fn annotations_of(&self, z: &ZoteroClient, attachments: &[papers_zotero::Item]) -> Vec<papers_zotero::Item> {
+        let params = papers_zotero::ItemListParams { item_type: Some("annotation".into()), ..Default::default() };
+        let annotatable = attachments.iter().filter(|att| is_annotatable_attachment(att));
+        self.limits
+            .map(Backend::Zotero, annotatable, |att| z.list_item_children(&att.key, &params))
+            .await
+            .into_iter()
+            .filter_map(Result::ok)
+            .flat_map(|r| r.items)
+            .collect()
+    }
+
+    /// [`zotero_resolve::resolve_item_key`], but when a title search matches
+    /// several items and the client supports it, the user picks which one.
+    async fn resolve_item_key_interactive(z: &ZoteroClient, input: &str) -> Result<String, String> {
+        let peer = Self::form_caller().filter(|_| !zotero_resolve::looks_like_zotero_key(input));
+        let Some(peer) = peer else {
+            return zotero_resolve::resolve_item_key(z, input).await.map_err(|e| e.to_string());
+        };
+        let limit = papers_core::selection::DEFAULT_CANDIDATE_LIMIT as u32;
+        let candidates = zotero_resolve::item_key_candidates(z, input, limit).await.map_err(|e| e.to_string())?;
+        if candidates.len() > 1 {
+            let labels: Vec<String> = candidates.iter().map(zotero_resolve::item_label).collect();
+            let message = format!("Several Zotero items match \"{input}\". Which one did you mean?");
+            if let Some(i) = Self::elicit_choice(&peer, &message, &labels).await? {
+                return Ok(candidates[i].key.clone());
+            }
+        }
+        candidates
+            .into_iter()
+            .next()
+            .map(|item| item.key)
+            .ok_or_else(|| format!("No item found matching: {input}"))
+    }
+}
+
+/// Environment variable that starts servers read-only (see [`PapersMcp::with_read_only`]).
+pub const READ_ONLY_ENV: &str = "PAPERS_READ_ONLY";
+
+/// Tools that change state beyond the call: the Zotero library, the RAG
+/// database, the request cache, selections, or files on disk. Read-only
+/// servers don't offer them.
+pub const WRITE_TOOLS: &[&str] = &[
+    "zotero_work_create",
+    "zotero_work_update",
+    "db_abstract_index",
+    "db_work_remove",
+    "db_chunk_mark_read",
+    "cache_clear",
+    "selection_create",
+    "selection_delete",
+    "selection_add",
+    "selection_add_bulk",
+    "selection_add_query",
+    "selection_add_references",
+    "selection_add_citers",
+    "selection_remove",
+    "selection_annotate",
+    "selection_tag",
+    "selection_scope",
+    "selection_sync_to_zotero",
+    "selection_from_collection",
+    "selection_screen",
+    "selection_screening_import",
+    "selection_site",
+    "paper_status_set",
+    "digest_notify",
+];
+
+/// Whether [`READ_ONLY_ENV`] is set to `1`, `true`, or `on`.
+pub fn read_only_from_env() -> bool {
+    std::env::var(READ_ONLY_ENV).is_ok_and(|v| matches!(v.trim().to_ascii_lowercase().as_str(), "1" | "true" | "on"))
+}
+
+/// Remove the tools not in `enabled` (unless it is empty) or in `disabled`.
+fn filter_tools(router: &mut ToolRouter<PapersMcp>, enabled: &[String], disabled: &[String]) {
+    for name in enabled.iter().chain(disabled) {
+        if !router.has_route(name) {
+            eprintln!("warning: unknown MCP tool {name:?} in tool settings");
+        }
+    }
+    for tool in router.list_all() {
+        let name = tool.name.as_ref();
+        let offered = enabled.is_empty() || enabled.iter().any(|e| e == name);
+        if !offered || disabled.iter().any(|d| d == name) {
+            router.remove_route(name);
+        }
+    }
+}
+
+/// Advertise the [`ZOTERO_CREDENTIAL_ARGS`] on the `zotero_*` tools. Other
+/// tools accept them too but only use Zotero for enrichment.
+fn with_zotero_credential_args(mut tool: Tool) -> Tool {
+    if !tool.name.starts_with("zotero_") {
+        return tool;
+    }
+    let descriptions = [
+        "Zotero profile to use instead of the server's default (see `papers config zotero-profile`)",
+        "Zotero user ID to use for this call; requires zotero_api_key",
+        "Zotero API key to use for this call; requires zotero_user_id",
+    ];
+    let mut schema = (*tool.input_schema).clone();
+    if let Some(serde_json::Value::Object(properties)) = schema.get_mut("properties") {
+        for (name, description) in ZOTERO_CREDENTIAL_ARGS.into_iter().zip(descriptions) {
+            properties.insert(name.to_string(), serde_json::json!({"type": "string", "description": description}));
+        }
+    }
+    tool.input_schema = Arc::new(schema);
+    tool
+}
+
 impl ServerHandler for PapersMcp {
     fn get_info(&self) -> ServerInfo {
         ServerInfo {
             protocol_version: Default::default(),
             capabilities: ServerCapabilities::builder()
                 .enable_tools()
+                .enable_resources()
                 .build(),
             server_info: rmcp::model::Implementation {
                 name: "papers-mcp".into(),
@@ -1564,10 +3613,96 @@ impl ServerHandler for PapersMcp {
                     Always use alongside `db_chunk_search` when exploring a topic, \
                     as text search won't surface visual content.\n\
                  5. `db_section_get` / `db_chapter_get` — read full content after finding relevant chunks\n\
-                 6. `db_exhibit_get` — get full details and local image path for a specific exhibit\n\
-                 7. `db_chunk_get` — follow prev/next references for sequential reading"
+                 6. `db_exhibit_get` — get full details for an exhibit (`include_image` to see the figure)\n\
+                 7. `db_chunk_get` — follow prev/next references for sequential reading\n\n\
+                 Indexed papers are also resources: `papers://paper/{paper_id}` (outline) and \
+                 `papers://paper/{paper_id}/chapter/{chapter_idx}` (chapter text)."
                     .into(),
             ),
         }
     }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParams,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, McpError> {
+        let tool = request.name.to_string();
+        let started = std::time::Instant::now();
+        let result = self.dispatch_audited(request, context).await;
+        if let Some(metrics) = &self.metrics {
+            let ok = matches!(&result, Ok(r) if r.is_error != Some(true));
+            metrics.record(&tool, ok, started.elapsed());
+        }
+        result
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParams>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListToolsResult, McpError> {
+        Ok(ListToolsResult::with_all_items(self.tool_router.list_all().into_iter().map(with_zotero_credential_args).collect()))
+    }
+
+    fn get_tool(&self, name: &str) -> Option<Tool> {
+        self.tool_router.get(name).cloned().map(with_zotero_credential_args)
+    }
+
+    async fn list_resources(
+        &self,
+        request: Option<PaginatedRequestParams>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListResourcesResult, McpError> {
+        self.list_paper_resources(request.as_ref().and_then(|r| r.cursor.as_deref())).await
+    }
+
+    async fn list_resource_templates(
+        &self,
+        _request: Option<PaginatedRequestParams>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListResourceTemplatesResult, McpError> {
+        let template = RawResourceTemplate {
+            uri_template: resources::CHAPTER_URI_TEMPLATE.into(),
+            name: "Indexed paper chapter".into(),
+            title: None,
+            description: Some("Full text of one chapter of a paper in the local DB".into()),
+            mime_type: Some("text/markdown".into()),
+            icons: None,
+        };
+        Ok(ListResourceTemplatesResult::with_all_items(vec![template.no_annotation()]))
+    }
+
+    async fn read_resource(
+        &self,
+        request: ReadResourceRequestParams,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ReadResourceResult, McpError> {
+        self.read_paper_resource(&request.uri).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn arguments(value: serde_json::Value) -> Option<serde_json::Map<String, serde_json::Value>> {
+        value.as_object().cloned()
+    }
+
+    #[test]
+    fn tenants_cannot_name_another_tenants_zotero_profile() {
+        let mut args = arguments(serde_json::json!({"zotero_profile": "alice", "query": "q"}));
+        let err = PapersMcp::take_zotero_credentials(Some("bob"), &mut args).unwrap_err();
+        assert!(err.contains("\"alice\" is not available to user \"bob\""));
+        let args = args.unwrap();
+        assert!(!args.contains_key("zotero_profile"));
+        assert!(args.contains_key("query"));
+
+        // Explicit credentials are the caller's own and stay allowed.
+        let mut args = arguments(serde_json::json!({"zotero_user_id": "42", "zotero_api_key": "k"}));
+        assert!(PapersMcp::take_zotero_credentials(Some("bob"), &mut args).unwrap().is_some());
+        let mut args = arguments(serde_json::json!({"query": "q"}));
+        assert!(PapersMcp::take_zotero_credentials(Some("bob"), &mut args).unwrap().is_none());
+    }
 }
@@ -0,0 +1,132 @@
+//! The Zotero client shared by all tool calls, probed on first use.
+//!
+//! Probing (local API first, then the web API) can take a while, so it runs
+//! once: concurrent callers wait for the probe in flight rather than starting
+//! their own. A failed probe is remembered for [`PROBE_COOLDOWN`], so while
+//! Zotero is down each tool call fails fast instead of paying for a probe.
+
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use papers_zotero::ZoteroClient;
+use tokio::sync::Mutex;
+
+/// How long a failed probe is reused before the next call probes again.
+pub const PROBE_COOLDOWN: Duration = Duration::from_secs(30);
+
+#[derive(Default)]
+struct State {
+    client: Option<ZoteroClient>,
+    /// When the last probe failed, and its error.
+    failed: Option<(Instant, String)>,
+}
+
+#[derive(Clone)]
+pub struct ZoteroSlot {
+    state: Arc<Mutex<State>>,
+    cooldown: Duration,
+}
+
+impl ZoteroSlot {
+    /// An empty slot; the first [`Self::get`] probes.
+    pub fn new() -> Self {
+        Self { state: Arc::default(), cooldown: PROBE_COOLDOWN }
+    }
+
+    /// A slot that already holds `client` and never probes.
+    pub fn with_client(client: ZoteroClient) -> Self {
+        let state = State { client: Some(client), failed: None };
+        Self { state: Arc::new(Mutex::new(state)), cooldown: PROBE_COOLDOWN }
+    }
+
+    /// Override [`PROBE_COOLDOWN`].
+    pub fn with_cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+
+    /// The connected client, running `probe` if there is none yet.
+    ///
+    /// The lock is held across `probe`, so at most one probe runs at a time
+    /// and everyone waiting shares its result. Within the cooldown after a
+    /// failure, the same error is returned without probing.
+    pub async fn get<F, Fut>(&self, probe: F) -> Result<ZoteroClient, String>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<ZoteroClient, String>>,
+    {
+        let mut state = self.state.lock().await;
+        if let Some(z) = &state.client {
+            return Ok(z.clone());
+        }
+        if let Some((at, err)) = &state.failed
+            && at.elapsed() < self.cooldown
+        {
+            return Err(err.clone());
+        }
+        match probe().await {
+            Ok(z) => {
+                state.client = Some(z.clone());
+                state.failed = None;
+                Ok(z)
+            }
+            Err(e) => {
+                state.failed = Some((Instant::now(), e.clone()));
+                Err(e)
+            }
+        }
+    }
+}
+
+impl Default for ZoteroSlot {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn client() -> ZoteroClient {
+        ZoteroClient::new("test", "test-key")
+    }
+
+    #[tokio::test]
+    async fn concurrent_callers_share_one_probe() {
+        let slot = ZoteroSlot::new();
+        let probes = AtomicUsize::new(0);
+        let probe = || async {
+            probes.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            Ok(client())
+        };
+        let (a, b, c) = tokio::join!(slot.get(probe), slot.get(probe), slot.get(probe));
+        assert!(a.is_ok() && b.is_ok() && c.is_ok());
+        assert_eq!(probes.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn failure_is_reused_during_cooldown() {
+        let slot = ZoteroSlot::new();
+        let probes = AtomicUsize::new(0);
+        let probe = || async {
+            probes.fetch_add(1, Ordering::SeqCst);
+            Err("Zotero is not running".to_string())
+        };
+        assert_eq!(slot.get(probe).await.err().as_deref(), Some("Zotero is not running"));
+        assert_eq!(slot.get(probe).await.err().as_deref(), Some("Zotero is not running"));
+        assert_eq!(probes.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn probes_again_after_cooldown() {
+        let slot = ZoteroSlot::new().with_cooldown(Duration::ZERO);
+        assert!(slot.get(|| async { Err("down".to_string()) }).await.is_err());
+        assert!(slot.get(|| async { Ok(client()) }).await.is_ok());
+        // Connected now: no further probes.
+        assert!(slot.get(|| async { Err("unused".to_string()) }).await.is_ok());
+    }
+}
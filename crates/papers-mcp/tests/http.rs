@@ -0,0 +1,136 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use papers_core::tenant::parse_tenants;
+use papers_mcp::http::{HEALTH_PATH, MCP_PATH, METRICS_PATH, router, tenant_router, with_metrics_route};
+use papers_mcp::metrics::Metrics;
+use papers_mcp::server::PapersMcp;
+use papers_zotero::ZoteroClient;
+use rmcp::transport::streamable_http_server::StreamableHttpServerConfig;
+
+async fn spawn_server() -> String {
+    let server = PapersMcp::with_zotero(ZoteroClient::new("test", "test-key"));
+    serve(router(server, StreamableHttpServerConfig::default())).await
+}
+
+/// Multi-tenant server for `alice:t1,bob:t2`; the counter tracks tenant opens.
+async fn spawn_tenant_server() -> (String, Arc<AtomicUsize>) {
+    let opened = Arc::new(AtomicUsize::new(0));
+    let counter = opened.clone();
+    let app = tenant_router(
+        parse_tenants("alice:t1,bob:t2").unwrap(),
+        StreamableHttpServerConfig::default(),
+        move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            async { PapersMcp::with_zotero(ZoteroClient::new("test", "test-key")) }
+        },
+    );
+    (serve(app).await, opened)
+}
+
+async fn serve(app: axum::Router) -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+    format!("http://{addr}")
+}
+
+#[tokio::test]
+async fn test_healthz_reports_status() {
+    let base = spawn_server().await;
+    let resp = reqwest::get(format!("{base}{HEALTH_PATH}")).await.unwrap();
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(body["status"], "ok");
+    assert_eq!(body["db"], false);
+    assert_eq!(body["version"], env!("CARGO_PKG_VERSION"));
+}
+
+#[tokio::test]
+async fn test_metrics_route_serves_prometheus_text() {
+    let metrics = Metrics::new();
+    let server = PapersMcp::with_zotero(ZoteroClient::new("test", "test-key")).with_metrics(metrics.clone());
+    let app = with_metrics_route(router(server, StreamableHttpServerConfig::default()), metrics.clone());
+    let base = serve(app).await;
+    metrics.record("work_list", false, std::time::Duration::from_millis(20));
+
+    let resp = reqwest::get(format!("{base}{METRICS_PATH}")).await.unwrap();
+    assert_eq!(resp.status(), 200);
+    assert!(resp.headers()["content-type"].to_str().unwrap().starts_with("text/plain"));
+    let body = resp.text().await.unwrap();
+    assert!(body.contains("papers_tool_calls_total{tool=\"work_list\"} 1\n"));
+    assert!(body.contains("papers_backend_errors_total{backend=\"openalex\"} 1\n"));
+}
+
+fn initialize(base: &str) -> reqwest::RequestBuilder {
+    reqwest::Client::new()
+        .post(format!("{base}{MCP_PATH}"))
+        .header("Accept", "application/json, text/event-stream")
+        .json(&serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "initialize",
+            "params": {
+                "protocolVersion": "2025-03-26",
+                "capabilities": {},
+                "clientInfo": { "name": "test", "version": "0" }
+            }
+        }))
+}
+
+#[tokio::test]
+async fn test_mcp_initialize_opens_a_session() {
+    let base = spawn_server().await;
+    let resp = initialize(&base).send().await.unwrap();
+    assert_eq!(resp.status(), 200);
+    assert!(resp.headers().contains_key("mcp-session-id"));
+}
+
+#[tokio::test]
+async fn test_tenant_mode_requires_a_known_token() {
+    let (base, opened) = spawn_tenant_server().await;
+    assert_eq!(initialize(&base).send().await.unwrap().status(), 401);
+    let resp = initialize(&base).bearer_auth("nope").send().await.unwrap();
+    assert_eq!(resp.status(), 401);
+    assert_eq!(opened.load(Ordering::SeqCst), 0);
+
+    let health: serde_json::Value =
+        reqwest::get(format!("{base}{HEALTH_PATH}")).await.unwrap().json().await.unwrap();
+    assert_eq!(health["tenants"], 2);
+    assert!(health.get("db").is_none());
+}
+
+#[tokio::test]
+async fn test_tenant_servers_open_once_per_tenant() {
+    let (base, opened) = spawn_tenant_server().await;
+    for token in ["t1", "t1", "t2"] {
+        let resp = initialize(&base).bearer_auth(token).send().await.unwrap();
+        assert_eq!(resp.status(), 200);
+        assert!(resp.headers().contains_key("mcp-session-id"));
+    }
+    assert_eq!(opened.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_opening_one_tenant_does_not_block_others() {
+    let app = tenant_router(
+        parse_tenants("alice:t1,bob:t2").unwrap(),
+        StreamableHttpServerConfig::default(),
+        |tenant: papers_core::tenant::Tenant| async move {
+            if tenant.name == "alice" {
+                // Alice's server never finishes opening.
+                std::future::pending::<()>().await;
+            }
+            PapersMcp::with_zotero(ZoteroClient::new("test", "test-key"))
+        },
+    );
+    let base = serve(app).await;
+    let stuck = tokio::spawn(initialize(&base).bearer_auth("t1").send());
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    let resp = tokio::time::timeout(std::time::Duration::from_secs(5), initialize(&base).bearer_auth("t2").send())
+        .await
+        .expect("bob waited on alice")
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    stuck.abort();
+}
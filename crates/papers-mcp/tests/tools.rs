@@ -1,4 +1,6 @@
 use papers_core::OpenAlexClient;
+use papers_mcp::limits::Limits;
+use papers_mcp::metrics::Metrics;
 use papers_mcp::server::PapersMcp;
 use papers_zotero::ZoteroClient;
 use rmcp::handler::server::wrapper::Parameters;
@@ -1019,8 +1021,7 @@ async fn test_zotero_tag_search() {
 }
 
 #[tokio::test]
-async fn test_zotero_tag_search_always_uses_contains() {
-    // zotero_tag_search always sends qmode=contains
+async fn test_zotero_tag_search_defaults_to_contains() {
     let mock = MockServer::start().await;
     Mock::given(method("GET"))
         .and(path("/users/test/tags"))
@@ -1034,6 +1035,23 @@ async fn test_zotero_tag_search_always_uses_contains() {
     assert!(result.is_ok());
 }
 
+#[tokio::test]
+async fn test_zotero_tag_search_starts_with() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/test/tags"))
+        .and(query_param("q", "Star"))
+        .and(query_param("qmode", "startsWith"))
+        .respond_with(zotero_array_response(&zotero_tags_body()))
+        .expect(1)
+        .mount(&mock)
+        .await;
+    let server = make_zotero_server(&mock);
+    let params = serde_json::from_value(serde_json::json!({"query": "Star", "qmode": "startsWith"})).unwrap();
+    let result = server.zotero_tag_search(Parameters(params)).await;
+    assert!(result.is_ok());
+}
+
 // ── Get tool tests ───────────────────────────────────────────────────
 
 #[tokio::test]
@@ -1123,6 +1141,241 @@ async fn test_find_works_post_for_long_query() {
     assert!(result.is_ok());
 }
 
+// ── Work stats tool tests ────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_work_stats_returns_tidy_series() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("group_by", "authorships.institutions.lineage"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            r#"{"meta": {"count": 7, "db_response_time_ms": 1, "page": 1, "per_page": 25, "next_cursor": null, "groups_count": 2},
+                "results": [],
+                "group_by": [
+                    {"key": "https://openalex.org/I63966007", "key_display_name": "MIT", "count": 5},
+                    {"key": "https://openalex.org/I97018004", "key_display_name": "Stanford University", "count": 2}
+                ]}"#,
+        ))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let server = make_server(&mock).await;
+    let params = serde_json::from_value(serde_json::json!({"by": "institution", "top": 1})).unwrap();
+    let text = server.work_stats(Parameters(params)).await.unwrap();
+    let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+    assert_eq!(json["by"], "institution");
+    assert_eq!(json["total"], 7);
+    assert_eq!(json["series"], serde_json::json!([{"key": "I63966007", "label": "MIT", "count": 5}]));
+
+    let params = serde_json::from_value(serde_json::json!({"by": "colour"})).unwrap();
+    assert!(server.work_stats(Parameters(params)).await.unwrap_err().contains("colour"));
+}
+
+// ── Metadata quality tool tests ──────────────────────────────────────
+
+#[tokio::test]
+async fn test_work_quality_tool_reports_issues() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/works/W2741809807"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(minimal_work_json()))
+        .mount(&mock)
+        .await;
+
+    let server = make_server(&mock).await;
+    let params = serde_json::from_value(serde_json::json!({"id": "W2741809807"})).unwrap();
+    let text = server.work_quality(Parameters(params)).await.unwrap();
+    let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+    assert_eq!(json["ok"], false);
+    let checks: Vec<&str> = json["issues"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|i| i["check"].as_str().unwrap())
+        .collect();
+    assert!(checks.contains(&"missing_abstract"));
+    assert!(checks.contains(&"missing_references"));
+}
+
+// ── Citation graph tool tests ────────────────────────────────────────
+
+#[tokio::test]
+async fn test_work_references_tool() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("filter", "cited_by:W2741809807"))
+        .and(query_param("per-page", "5"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(minimal_list_json()))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let server = make_server(&mock).await;
+    let params =
+        serde_json::from_value(serde_json::json!({"id": "W2741809807", "per_work": 5})).unwrap();
+    let text = server.work_references(Parameters(params)).await.unwrap();
+    let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+    assert_eq!(json["root"], "W2741809807");
+    assert_eq!(json["direction"], "references");
+    assert_eq!(json["count"], 0);
+}
+
+#[tokio::test]
+async fn test_work_related_tool() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/works/W2741809807"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            r#"{"id": "https://openalex.org/W2741809807", "related_works": ["https://openalex.org/W9"]}"#,
+        ))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("filter", "ids.openalex:W9"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            r#"{"meta": {"count": 1, "db_response_time_ms": 5, "page": 1, "per_page": 200, "next_cursor": null, "groups_count": null},
+                "results": [{"id": "https://openalex.org/W9", "display_name": "Read me next"}], "group_by": []}"#,
+        ))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let server = make_server(&mock).await;
+    let params = serde_json::from_value(serde_json::json!({"id": "W2741809807", "limit": 5})).unwrap();
+    let text = server.work_related(Parameters(params)).await.unwrap();
+    let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+    assert_eq!(json["seeds"][0], "W2741809807");
+    assert_eq!(json["count"], 1);
+    assert_eq!(json["works"][0]["title"], "Read me next");
+    assert_eq!(json["works"][0]["reasons"][0]["kind"], "openalex_related");
+}
+
+#[tokio::test]
+async fn test_work_search_references_tool() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/works/W2741809807"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            r#"{"id": "https://openalex.org/W2741809807", "referenced_works": ["https://openalex.org/W7", "https://openalex.org/W8"]}"#,
+        ))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("filter", "ids.openalex:W7|W8"))
+        .and(query_param("search", "open access"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            r#"{"meta": {"count": 1, "db_response_time_ms": 5, "page": 1, "per_page": 2, "next_cursor": null, "groups_count": null},
+                "results": [{"id": "https://openalex.org/W8", "display_name": "Open access uptake", "relevance_score": 21.5}], "group_by": []}"#,
+        ))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let server = make_server(&mock).await;
+    let params = serde_json::from_value(serde_json::json!({"id": "W2741809807", "query": "open access"})).unwrap();
+    let text = server.work_search_references(Parameters(params)).await.unwrap();
+    let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+    assert_eq!(json["root"], "W2741809807");
+    assert_eq!(json["references"], 2);
+    assert_eq!(json["works"][0]["title"], "Open access uptake");
+    assert_eq!(json["works"][0]["relevance_score"], 21.5);
+}
+
+#[tokio::test]
+async fn test_format_citation_tool() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/works/W2741809807"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            r#"{"id": "https://openalex.org/W2741809807", "display_name": "The state of OA", "publication_year": 2018,
+                "doi": "https://doi.org/10.7717/peerj.4375", "type": "article",
+                "authorships": [{"author": {"display_name": "Heather Piwowar"}}, {"author": {"display_name": "Jason Priem"}},
+                                {"author": {"display_name": "Vincent Larivière"}}],
+                "primary_location": {"source": {"display_name": "PeerJ"}}, "biblio": {"volume": "6", "first_page": "e4375"}}"#,
+        ))
+        .mount(&mock)
+        .await;
+
+    let server = make_server(&mock).await;
+    let params = serde_json::from_value(serde_json::json!({"id": "W2741809807", "style": "mla"})).unwrap();
+    let json: serde_json::Value =
+        serde_json::from_str(&server.format_citation(Parameters(params)).await.unwrap()).unwrap();
+    assert_eq!(
+        json["citation"],
+        "Piwowar, Heather, et al. \"The state of OA.\" PeerJ, vol. 6, 2018, p. e4375. https://doi.org/10.7717/peerj.4375."
+    );
+    assert_eq!(json["in_text"], "(Piwowar et al.)");
+
+    let params = serde_json::from_value(serde_json::json!({"id": "W2741809807", "style": "harvard"})).unwrap();
+    assert!(server.format_citation(Parameters(params)).await.unwrap_err().contains("invalid citation style"));
+}
+
+#[tokio::test]
+async fn test_review_context_tool() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/find/works"))
+        .respond_with(ResponseTemplate::new(403).set_body_string("API key required"))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            r#"{"meta": {"count": 1, "db_response_time_ms": 5, "page": 1, "per_page": 50, "next_cursor": null, "groups_count": null},
+                "results": [{"id": "https://openalex.org/W8", "display_name": "Point-based rendering", "publication_year": 2015,
+                             "doi": "https://doi.org/10.1/pbr"}], "group_by": []}"#,
+        ))
+        .mount(&mock)
+        .await;
+
+    let server = make_server(&mock).await;
+    let params = serde_json::from_value(serde_json::json!({
+        "text": "Point-based rendering of large scenes",
+        "references": ["10.1/pbr"]
+    }))
+    .unwrap();
+    let text = server.review_context(Parameters(params)).await.unwrap();
+    let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+    assert_eq!(json["candidates"], 1);
+    assert_eq!(json["key_prior_works"][0]["title"], "Point-based rendering");
+    assert_eq!(json["key_prior_works"][0]["in_references"], true);
+    assert!(json["warnings"][0].as_str().unwrap().starts_with("semantic search"));
+}
+
+#[tokio::test]
+async fn test_author_resolve_tool() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/authors"))
+        .and(query_param("search", "Wei Wang"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            r#"{"meta": {"count": 2, "db_response_time_ms": 5, "page": 1, "per_page": 10, "next_cursor": null, "groups_count": null},
+                "results": [
+                    {"id": "https://openalex.org/A1", "display_name": "Wei Wang", "works_count": 900,
+                     "last_known_institutions": [{"display_name": "Tsinghua University"}]},
+                    {"id": "https://openalex.org/A2", "display_name": "Wei Wang", "works_count": 40,
+                     "last_known_institutions": [{"display_name": "Massachusetts Institute of Technology"}]}
+                ], "group_by": []}"#,
+        ))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let server = make_server(&mock).await;
+    let params = serde_json::from_value(serde_json::json!({"name": "Wei Wang", "affiliation": "MIT"})).unwrap();
+    let text = server.author_resolve(Parameters(params)).await.unwrap();
+    let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+    assert_eq!(json["query"], "Wei Wang");
+    assert_eq!(json["candidates"][0]["id"], "A2");
+    assert_eq!(json["candidates"][0]["evidence"], serde_json::json!(["name_match", "current_affiliation"]));
+    assert!(json["candidates"][0].get("coauthor_works").is_none());
+}
+
 // ── Error handling tests ─────────────────────────────────────────────
 
 #[tokio::test]
@@ -1264,6 +1517,161 @@ async fn test_zotero_work_fulltext() {
     );
 }
 
+#[tokio::test]
+async fn test_zotero_work_fulltext_group_library() {
+    let mock = MockServer::start().await;
+    // First call: get item to resolve key (direct key lookup)
+    Mock::given(method("GET"))
+        .and(path("/groups/4711/items/ABC12345"))
+        .respond_with(zotero_array_response(&zotero_item_body()))
+        .mount(&mock)
+        .await;
+    // Second call: list children (attachments)
+    Mock::given(method("GET"))
+        .and(path("/groups/4711/items/ABC12345/children"))
+        .respond_with(zotero_array_response(&zotero_attachments_body()))
+        .mount(&mock)
+        .await;
+    // Third call: get fulltext for attachment
+    Mock::given(method("GET"))
+        .and(path("/groups/4711/items/ATT12345/fulltext"))
+        .respond_with(zotero_fulltext_response(&zotero_fulltext_body()))
+        .mount(&mock)
+        .await;
+
+    let server = make_zotero_server(&mock);
+    let params = serde_json::from_value(serde_json::json!({"key": "ABC12345", "library": "4711"})).unwrap();
+    let result = server.zotero_work_fulltext(Parameters(params)).await;
+    let text = result.unwrap();
+    assert!(
+        text.contains("indexed full text"),
+        "Expected fulltext content, got: {text}"
+    );
+}
+
+#[tokio::test]
+async fn test_zotero_fulltext_search() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/test/fulltext"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Last-Modified-Version", "100")
+                .set_body_string(r#"{"ATT12345": 90}"#),
+        )
+        .expect(1)
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/users/test/items"))
+        .and(query_param("itemKey", "ATT12345"))
+        .respond_with(zotero_array_response(&zotero_attachments_body()))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/users/test/items"))
+        .and(query_param("itemKey", "ABC12345"))
+        .respond_with(zotero_array_response(&zotero_items_body()))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/users/test/items/ATT12345/fulltext"))
+        .respond_with(zotero_fulltext_response(&zotero_fulltext_body()))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let dir = std::env::temp_dir()
+        .join("papers-test-cache")
+        .join(format!("mcp-zotero-fulltext-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    let server = make_zotero_server(&mock);
+    let search = |query: &str| serde_json::from_value(serde_json::json!({"query": query})).unwrap();
+
+    let text = papers_core::tenant::scope(&dir, server.zotero_fulltext_search(Parameters(search("INDEXED text"))))
+        .await
+        .unwrap();
+    let result: serde_json::Value = serde_json::from_str(&text).unwrap();
+    assert_eq!(result["sync"]["updated"], 1);
+    assert_eq!(result["results"][0]["item_key"], "ABC12345");
+    assert_eq!(result["results"][0]["attachment_key"], "ATT12345");
+    assert!(result["results"][0]["snippets"][0].as_str().unwrap().contains("indexed full text"));
+
+    // The saved index answers later searches without another sync.
+    let text = papers_core::tenant::scope(&dir, server.zotero_fulltext_search(Parameters(search("absent"))))
+        .await
+        .unwrap();
+    let result: serde_json::Value = serde_json::from_str(&text).unwrap();
+    assert!(result.get("sync").is_none());
+    assert_eq!(result["indexed"], 1);
+    assert_eq!(result["results"].as_array().unwrap().len(), 0);
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[tokio::test]
+async fn test_selection_from_collection() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/test/collections/COL12345"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(zotero_collection_body()))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/users/test/collections/COL12345/items/top"))
+        .respond_with(zotero_array_response(&zotero_items_body()))
+        .mount(&mock)
+        .await;
+
+    let dir = std::env::temp_dir()
+        .join("papers-test-cache")
+        .join(format!("mcp-selection-from-collection-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    let server = make_zotero_server(&mock);
+    let params = serde_json::from_value(serde_json::json!({"collection": "COL12345"})).unwrap();
+    let text = papers_core::tenant::scope(&dir, server.selection_from_collection(Parameters(params)))
+        .await
+        .unwrap();
+    let result: serde_json::Value = serde_json::from_str(&text).unwrap();
+    assert_eq!(result["selection"], "Test-Collection");
+    assert_eq!(result["created_selection"], true);
+    assert_eq!(result["added"].as_array().unwrap().len(), 1);
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[tokio::test]
+async fn test_paper_status_set_and_get() {
+    let mock = MockServer::start().await;
+    let dir = std::env::temp_dir()
+        .join("papers-test-cache")
+        .join(format!("mcp-paper-status-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    let server = make_server(&mock).await;
+    let set = serde_json::from_value(serde_json::json!({
+        "paper_id": "https://doi.org/10.1145/ABC",
+        "status": "read",
+        "rating": 4,
+    }))
+    .unwrap();
+    let text = papers_core::tenant::scope(&dir, server.paper_status_set(Parameters(set))).await.unwrap();
+    let result: serde_json::Value = serde_json::from_str(&text).unwrap();
+    assert_eq!(result["paper_id"], "10.1145/abc");
+    assert_eq!(result["record"]["status"], "read");
+
+    let get = serde_json::from_value(serde_json::json!({"paper_id": "10.1145/abc"})).unwrap();
+    let text = papers_core::tenant::scope(&dir, server.paper_status_get(Parameters(get))).await.unwrap();
+    let result: serde_json::Value = serde_json::from_str(&text).unwrap();
+    assert_eq!(result["record"]["rating"], 4);
+
+    let list = serde_json::from_value(serde_json::json!({"status": "reading"})).unwrap();
+    let text = papers_core::tenant::scope(&dir, server.paper_status_get(Parameters(list))).await.unwrap();
+    let result: serde_json::Value = serde_json::from_str(&text).unwrap();
+    assert!(result["papers"].as_object().unwrap().is_empty());
+
+    let bad = serde_json::from_value(serde_json::json!({"paper_id": "10.1/x", "status": "skimmed"})).unwrap();
+    assert!(server.paper_status_set(Parameters(bad)).await.is_err());
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
 #[tokio::test]
 async fn test_zotero_work_view_url() {
     let mock = MockServer::start().await;
@@ -1422,6 +1830,161 @@ async fn test_zotero_deleted_list_no_params() {
     assert!(result.is_ok());
 }
 
+// ── Cache tools ──────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_cache_stats_and_clear() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/works/W2741809807"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(minimal_work_json()))
+        .expect(2)
+        .mount(&mock)
+        .await;
+
+    let dir = std::env::temp_dir()
+        .join("papers-test-cache")
+        .join(format!("mcp-cache-tools-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    let cache = papers_core::DiskCache::with_policy(dir, papers_core::CachePolicy::default()).unwrap();
+    let client = OpenAlexClient::new().with_base_url(mock.uri()).with_cache(cache);
+    let server = PapersMcp::with_client(client).await;
+
+    let get = || serde_json::from_value(serde_json::json!({"id": "W2741809807"})).unwrap();
+    server.work_get(Parameters(get())).await.unwrap();
+    server.work_get(Parameters(get())).await.unwrap();
+
+    let params = serde_json::from_value(serde_json::json!({})).unwrap();
+    let stats: serde_json::Value =
+        serde_json::from_str(&server.cache_stats(Parameters(params)).await.unwrap()).unwrap();
+    assert_eq!(stats["entries"], 1);
+    let works = stats["by_entity"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|r| r["entity"] == "works")
+        .unwrap();
+    assert_eq!(works["entries"], 1);
+    assert_eq!(works["ttl_secs"], 600);
+
+    let params = serde_json::from_value(serde_json::json!({"entity": "works"})).unwrap();
+    let cleared: serde_json::Value =
+        serde_json::from_str(&server.cache_clear(Parameters(params)).await.unwrap()).unwrap();
+    assert_eq!(cleared["removed"], 1);
+    // Cleared entry is fetched again (mock expects exactly 2 requests)
+    server.work_get(Parameters(get())).await.unwrap();
+
+    let params = serde_json::from_value(serde_json::json!({"entity": "bogus"})).unwrap();
+    assert!(server.cache_clear(Parameters(params)).await.is_err());
+}
+
+#[tokio::test]
+async fn test_cache_stats_without_cache_errors() {
+    let mock = MockServer::start().await;
+    let server = make_server(&mock).await;
+    let params = serde_json::from_value(serde_json::json!({})).unwrap();
+    assert!(server.cache_stats(Parameters(params)).await.is_err());
+}
+
+#[tokio::test]
+async fn test_server_stats_reports_cache_hit_rate() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/works/W2741809807"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(minimal_work_json()))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let dir = std::env::temp_dir()
+        .join("papers-test-cache")
+        .join(format!("mcp-server-stats-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    let cache = papers_core::DiskCache::with_policy(dir.clone(), papers_core::CachePolicy::default()).unwrap();
+    let client = OpenAlexClient::new().with_base_url(mock.uri()).with_cache(cache);
+    let server = PapersMcp::with_client(client).await;
+
+    let params = || serde_json::from_value(serde_json::json!({})).unwrap();
+    assert!(server.server_stats(Parameters(params())).await.unwrap_err().contains("PAPERS_METRICS"));
+
+    let server = server.with_metrics(Metrics::new());
+    let get = || serde_json::from_value(serde_json::json!({"id": "W2741809807"})).unwrap();
+    server.work_get(Parameters(get())).await.unwrap();
+    server.work_get(Parameters(get())).await.unwrap();
+
+    let stats: serde_json::Value =
+        serde_json::from_str(&server.server_stats(Parameters(params())).await.unwrap()).unwrap();
+    assert_eq!(stats["cache"]["hits"], 1);
+    assert_eq!(stats["cache"]["misses"], 1);
+    assert_eq!(stats["cache"]["hit_rate"], 0.5);
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+// ── Raw passthrough ──────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_openalex_raw_passes_query_through() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("group_by", "oa_status"))
+        .and(query_param("per-page", "5"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            r#"{"meta": {"count": 7}, "group_by": [{"key": "gold", "count": 7}], "extra_field": true}"#,
+        ))
+        .mount(&mock)
+        .await;
+    let server = make_server(&mock).await;
+    let params = serde_json::from_value(serde_json::json!({
+        "path": "/works",
+        "query": {"group_by": "oa_status", "per-page": 5}
+    }))
+    .unwrap();
+    let text = server.openalex_raw(Parameters(params)).await.unwrap();
+    let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+    assert_eq!(json["group_by"][0]["key"], "gold");
+    assert_eq!(json["extra_field"], true);
+}
+
+#[tokio::test]
+async fn test_openalex_raw_rejects_unlisted_path() {
+    let mock = MockServer::start().await;
+    let server = make_server(&mock).await;
+    let params =
+        serde_json::from_value(serde_json::json!({"path": "works/../users"})).unwrap();
+    let err = server.openalex_raw(Parameters(params)).await.unwrap_err();
+    assert!(err.contains("not allowed"), "{err}");
+    let params = serde_json::from_value(serde_json::json!({
+        "path": "works",
+        "query": {"api_key": "x"}
+    }))
+    .unwrap();
+    assert!(server.openalex_raw(Parameters(params)).await.is_err());
+}
+
+// ── Library search tests ─────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_library_search_flags_openalex_hits() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("search", "radiance fields"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(minimal_list_json().replace(
+            "\"results\": []",
+            &format!("\"results\": [{}]", minimal_work_json()),
+        )))
+        .mount(&mock)
+        .await;
+    let server = make_server(&mock).await;
+    let params = serde_json::from_value(serde_json::json!({"query": "radiance fields"})).unwrap();
+    let hits: serde_json::Value =
+        serde_json::from_str(&server.library_search(Parameters(params)).await.unwrap()).unwrap();
+    let hit = hits.as_array().unwrap().iter().find(|h| h["openalex_id"] == "W2741809807").unwrap();
+    assert_eq!(hit["doi"], "10.7717/peerj.4375");
+    assert!(hit["in_zotero"].is_boolean() && hit["in_rag_index"].is_boolean());
+}
+
 // ── Tool listing tests ───────────────────────────────────────────────
 
 #[test]
@@ -2346,6 +2909,76 @@ fn test_tool_params_schema() {
     assert!(props.contains_key("group_by"));
 }
 
+#[test]
+fn test_tool_params_schema_constraints_and_examples() {
+    use papers_mcp::params::{WorkListToolParams, ZoteroTagListToolParams};
+    let json = serde_json::to_value(schemars::schema_for!(WorkListToolParams)).unwrap();
+    let props = &json["properties"];
+    assert_eq!(props["per_page"]["minimum"], 1);
+    assert_eq!(props["per_page"]["maximum"], 200);
+    assert_eq!(props["page"]["minimum"], 1);
+    assert_eq!(props["sort"]["examples"], serde_json::json!(["cited_by_count:desc"]));
+    assert_eq!(props["cursor"]["examples"], serde_json::json!(["*"]));
+
+    let json = serde_json::to_value(schemars::schema_for!(ZoteroTagListToolParams)).unwrap();
+    assert_eq!(json["properties"]["limit"]["maximum"], 100);
+    assert_eq!(json["$defs"]["SortDirection"]["enum"], serde_json::json!(["asc", "desc"]));
+    assert_eq!(json["$defs"]["TagScope"]["enum"], serde_json::json!(["all", "top", "trash"]));
+}
+
+#[test]
+fn test_tool_params_enum_fields_reject_unknown_values() {
+    use papers_mcp::params::{SortDirection, TagMatchMode, ZoteroTagSearchToolParams};
+    let p: ZoteroTagSearchToolParams =
+        serde_json::from_value(serde_json::json!({"query": "ml", "direction": "desc", "qmode": "startsWith"})).unwrap();
+    assert_eq!(p.direction, Some(SortDirection::Desc));
+    assert_eq!(p.qmode, Some(TagMatchMode::StartsWith));
+    assert!(serde_json::from_value::<ZoteroTagSearchToolParams>(serde_json::json!({"query": "ml", "direction": "down"})).is_err());
+
+    let json = serde_json::to_value(schemars::schema_for!(ZoteroTagSearchToolParams)).unwrap();
+    assert_eq!(json["$defs"]["TagMatchMode"]["enum"], serde_json::json!(["contains", "startsWith"]));
+}
+
+#[test]
+fn test_tool_params_enum_fields_accept_any_case() {
+    use papers_mcp::params::{
+        DbChunkSearchParams, DbExhibitSearchParams, DbWorkListParams, ExtractionQuality, SortDirection,
+        TagMatchMode, TagScope, WorkTextToolParams, ZoteroTagListToolParams, ZoteroTagSearchToolParams,
+    };
+    let p: ZoteroTagSearchToolParams =
+        serde_json::from_value(serde_json::json!({"query": "ml", "direction": "DESC", "qmode": "StartsWith"})).unwrap();
+    assert_eq!(p.direction, Some(SortDirection::Desc));
+    assert_eq!(p.qmode, Some(TagMatchMode::StartsWith));
+    let p: ZoteroTagListToolParams = serde_json::from_value(serde_json::json!({"scope": "Trash"})).unwrap();
+    assert_eq!(p.scope, Some(TagScope::Trash));
+    let p: WorkTextToolParams = serde_json::from_value(serde_json::json!({"id": "W1", "advanced": "Accurate"})).unwrap();
+    assert_eq!(p.advanced, Some(ExtractionQuality::Accurate));
+    let p: DbChunkSearchParams = serde_json::from_value(serde_json::json!({"query": "q", "filter_depth": "Section"})).unwrap();
+    assert_eq!(p.filter_depth, Some(papers_db::ChunkDepth::Section));
+    let p: DbExhibitSearchParams = serde_json::from_value(serde_json::json!({"query": "q", "filter_exhibit_type": "TABLE"})).unwrap();
+    assert_eq!(p.filter_exhibit_type, Some(papers_db::ExhibitType::Table));
+    let p: DbWorkListParams = serde_json::from_value(serde_json::json!({"sort_by": "Title"})).unwrap();
+    assert_eq!(p.sort_by, Some(papers_db::PaperSort::Title));
+    let p: DbWorkListParams = serde_json::from_value(serde_json::json!({})).unwrap();
+    assert_eq!(p.sort_by, None);
+
+    // A typo is an error naming the accepted values, not a silent default.
+    let err = serde_json::from_value::<DbExhibitSearchParams>(serde_json::json!({"query": "q", "filter_exhibit_type": "tabel"}))
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("expected figure, table, or algorithm"), "{err}");
+    let err = serde_json::from_value::<DbWorkListParams>(serde_json::json!({"sort_by": "date"})).unwrap_err().to_string();
+    assert!(err.contains("expected year or title"), "{err}");
+    assert!(serde_json::from_value::<ZoteroTagListToolParams>(serde_json::json!({"scope": "tops"})).is_err());
+    assert!(serde_json::from_value::<WorkTextToolParams>(serde_json::json!({"id": "W1", "advanced": "best"})).is_err());
+
+    let json = serde_json::to_value(schemars::schema_for!(DbExhibitSearchParams)).unwrap();
+    assert_eq!(
+        json["properties"]["filter_exhibit_type"]["enum"],
+        serde_json::json!(["figure", "table", "algorithm", null])
+    );
+}
+
 // ── Work list filter alias tests ─────────────────────────────────────
 
 fn search_result_json(id: &str) -> String {
@@ -2855,6 +3488,22 @@ async fn test_zotero_work_list() {
     assert!(result.is_ok());
 }
 
+#[tokio::test]
+async fn test_zotero_work_list_output_format_exports_bibtex() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/test/items/top"))
+        .and(query_param("format", "bibtex"))
+        .and(query_param("tag", "gpu"))
+        .respond_with(zotero_array_response("@article{vaswani2017,\n  title = {Attention}\n}\n"))
+        .mount(&mock)
+        .await;
+    let server = make_zotero_server(&mock);
+    let params = serde_json::from_value(serde_json::json!({"tag": "gpu", "output_format": "bibtex"})).unwrap();
+    let result = server.zotero_work_list(Parameters(params)).await.unwrap();
+    assert!(result.starts_with("@article{vaswani2017,"), "{result}");
+}
+
 #[tokio::test]
 async fn test_zotero_work_list_everything_flag() {
     // The `everything` flag is now on zotero_work_search, not zotero_work_list
@@ -2938,6 +3587,62 @@ async fn test_zotero_work_get() {
     assert!(result.is_ok());
 }
 
+#[tokio::test]
+async fn test_zotero_work_update_patches_with_version() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/test/items/ABC12345"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(zotero_item_body()))
+        .mount(&mock)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path("/users/test/items/ABC12345"))
+        .and(wiremock::matchers::header("If-Unmodified-Since-Version", "1"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock)
+        .await;
+    let server = make_zotero_server(&mock);
+    let params = serde_json::from_value(serde_json::json!({
+        "key": "ABC12345",
+        "version": 1,
+        "abstract_note": "Added abstract"
+    }))
+    .unwrap();
+    let result = server.zotero_work_update(Parameters(params)).await;
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_zotero_work_update_rejects_title_search() {
+    let mock = MockServer::start().await;
+    let server = make_zotero_server(&mock);
+    let params =
+        serde_json::from_value(serde_json::json!({"key": "Test Paper", "title": "New"})).unwrap();
+    let err = server.zotero_work_update(Parameters(params)).await.unwrap_err();
+    assert!(err.contains("Zotero item key"));
+}
+
+#[tokio::test]
+async fn test_work_metadata_diff_needs_doi_or_title() {
+    let mock = MockServer::start().await;
+    let untitled = r#"{
+        "key": "ABC12345", "version": 1,
+        "library": {"type": "user", "id": 1, "name": "test", "links": {}},
+        "links": {}, "meta": {},
+        "data": {"key": "ABC12345", "version": 1, "itemType": "journalArticle"}
+    }"#;
+    Mock::given(method("GET"))
+        .and(path("/users/test/items/ABC12345"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(untitled))
+        .mount(&mock)
+        .await;
+    let server = make_zotero_server(&mock);
+    let params = serde_json::from_value(serde_json::json!({"id": "ABC12345"})).unwrap();
+    let err = server.work_metadata_diff(Parameters(params)).await.unwrap_err();
+    assert!(err.contains("neither a DOI nor a title"));
+}
+
 #[tokio::test]
 async fn test_zotero_work_collections() {
     let mock = MockServer::start().await;
@@ -3008,6 +3713,59 @@ async fn test_zotero_work_annotations() {
     assert!(result.is_ok());
 }
 
+fn zotero_child_json(key: &str, item_type: &str, content_type: Option<&str>) -> serde_json::Value {
+    let mut data = serde_json::json!({"key": key, "version": 1, "itemType": item_type});
+    if let Some(ct) = content_type {
+        data["contentType"] = ct.into();
+    }
+    serde_json::json!({
+        "key": key, "version": 1,
+        "library": {"type": "user", "id": 1, "name": "test", "links": {}},
+        "links": {}, "meta": {}, "data": data,
+    })
+}
+
+#[tokio::test]
+async fn test_zotero_work_annotations_fetched_concurrently_in_order() {
+    let mock = MockServer::start().await;
+    let atts = ["ATT00001", "ATT00002", "ATT00003"];
+    let mut children: Vec<_> =
+        atts.iter().map(|k| zotero_child_json(k, "attachment", Some("application/pdf"))).collect();
+    // Linked URLs carry no annotations and are never asked for them.
+    children.push(zotero_child_json("LNK00001", "attachment", Some("text/plain")));
+    Mock::given(method("GET"))
+        .and(path("/users/test/items/ABC12345/children"))
+        .respond_with(zotero_array_response(&serde_json::Value::from(children).to_string()))
+        .mount(&mock)
+        .await;
+    let delay = std::time::Duration::from_millis(400);
+    for (i, att) in atts.iter().enumerate() {
+        let annotation = zotero_child_json(&format!("ANN0000{i}"), "annotation", None);
+        Mock::given(method("GET"))
+            .and(path(format!("/users/test/items/{att}/children")))
+            // The first attachment answers last; results still follow attachment order.
+            .respond_with(
+                zotero_array_response(&format!("[{annotation}]"))
+                    .set_delay(delay * (atts.len() - i) as u32 / 2),
+            )
+            .expect(1)
+            .mount(&mock)
+            .await;
+    }
+    let server = make_zotero_server(&mock).with_limits(Limits::new(8, 4, 1));
+    let params = serde_json::from_value(serde_json::json!({"key": "ABC12345"})).unwrap();
+    let started = std::time::Instant::now();
+    let result = server.zotero_work_annotations(Parameters(params)).await.unwrap();
+    // Serially this would take 0.6 + 0.4 + 0.2 = 1.2s.
+    assert!(started.elapsed() < delay * 2, "took {:?}", started.elapsed());
+    let keys: Vec<String> = serde_json::from_str::<Vec<serde_json::Value>>(&result)
+        .unwrap()
+        .iter()
+        .map(|a| a["key"].as_str().unwrap().to_string())
+        .collect();
+    assert_eq!(keys, ["ANN00000", "ANN00001", "ANN00002"]);
+}
+
 #[tokio::test]
 async fn test_zotero_work_tags() {
     let mock = MockServer::start().await;
@@ -3120,6 +3878,138 @@ async fn test_zotero_collection_list() {
     assert!(result.is_ok());
 }
 
+#[tokio::test]
+async fn test_zotero_item_type_schema_tools() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/itemTypes"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            r#"[{"itemType": "book", "localized": "Book"}]"#,
+        ))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/itemTypeFields"))
+        .and(query_param("itemType", "book"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            r#"[{"field": "ISBN", "localized": "ISBN"}]"#,
+        ))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/itemTypeCreatorTypes"))
+        .and(query_param("itemType", "book"))
+        .and(query_param("locale", "de-DE"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            r#"[{"creatorType": "author", "localized": "Autor"}]"#,
+        ))
+        .mount(&mock)
+        .await;
+    let server = make_zotero_server(&mock);
+
+    let params = serde_json::from_value(serde_json::json!({})).unwrap();
+    let text = server.zotero_item_type_list(Parameters(params)).await.unwrap();
+    assert!(text.contains("\"itemType\": \"book\""), "{text}");
+
+    let params = serde_json::from_value(serde_json::json!({"item_type": "book"})).unwrap();
+    let text = server.zotero_item_type_fields(Parameters(params)).await.unwrap();
+    assert!(text.contains("ISBN"), "{text}");
+
+    let params = serde_json::json!({"item_type": "book", "locale": "de-DE"});
+    let params = serde_json::from_value(params).unwrap();
+    let text = server.zotero_item_type_creator_types(Parameters(params)).await.unwrap();
+    assert!(text.contains("Autor"), "{text}");
+}
+
+#[tokio::test]
+async fn test_zotero_raw_schema_and_library_paths() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/itemTypeFields"))
+        .and(query_param("itemType", "book"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(r#"[{"field": "title"}]"#))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/groups/4711/items/top"))
+        .and(query_param("limit", "5"))
+        .respond_with(zotero_array_response(&zotero_items_body()))
+        .mount(&mock)
+        .await;
+    let server = make_zotero_server(&mock);
+    let params = serde_json::from_value(serde_json::json!({
+        "path": "itemTypeFields",
+        "query": {"itemType": "book"}
+    }))
+    .unwrap();
+    let text = server.zotero_raw(Parameters(params)).await.unwrap();
+    let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+    assert_eq!(json["body"][0]["field"], "title");
+
+    let params = serde_json::from_value(serde_json::json!({
+        "path": "items/top",
+        "query": {"limit": 5},
+        "library": "4711"
+    }))
+    .unwrap();
+    let text = server.zotero_raw(Parameters(params)).await.unwrap();
+    let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+    assert_eq!(json["total_results"], 1);
+}
+
+#[tokio::test]
+async fn test_zotero_raw_rejects_writes_and_unknown_paths() {
+    let mock = MockServer::start().await;
+    let server = make_zotero_server(&mock);
+    let params = serde_json::from_value(serde_json::json!({
+        "method": "DELETE",
+        "path": "items/ABC12345",
+        "version": 3
+    }))
+    .unwrap();
+    let err = server.zotero_raw(Parameters(params)).await.unwrap_err();
+    assert!(err.contains("PAPERS_ZOTERO_RAW_WRITES"), "{err}");
+    let params = serde_json::from_value(serde_json::json!({"path": "keys/current"})).unwrap();
+    let err = server.zotero_raw(Parameters(params)).await.unwrap_err();
+    assert!(err.contains("unknown endpoint"), "{err}");
+    assert!(mock.received_requests().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_zotero_collection_list_group_library_by_name() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/test/groups"))
+        .respond_with(zotero_array_response(
+            r#"[{"id": 4711, "version": 1, "data": {"id": 4711, "version": 1, "name": "Graphics Lab"}}]"#,
+        ))
+        .mount(&mock)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/groups/4711/collections"))
+        .respond_with(zotero_array_response(&zotero_collections_body()))
+        .mount(&mock)
+        .await;
+    let server = make_zotero_server(&mock);
+    let params = serde_json::from_value(serde_json::json!({"library": "graphics lab"})).unwrap();
+    let result = server.zotero_collection_list(Parameters(params)).await;
+    assert!(result.is_ok(), "{result:?}");
+}
+
+#[tokio::test]
+async fn test_zotero_collection_list_unknown_group_errors() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/test/groups"))
+        .respond_with(zotero_array_response("[]"))
+        .mount(&mock)
+        .await;
+    let server = make_zotero_server(&mock);
+    let params = serde_json::from_value(serde_json::json!({"library": "nope"})).unwrap();
+    let err = server.zotero_collection_list(Parameters(params)).await.unwrap_err();
+    assert!(err.contains("No group library found matching: nope"), "{err}");
+}
+
 #[tokio::test]
 async fn test_zotero_collection_get() {
     let mock = MockServer::start().await;
@@ -3148,6 +4038,26 @@ async fn test_zotero_collection_works() {
     assert!(result.is_ok());
 }
 
+#[tokio::test]
+async fn test_zotero_collection_works_output_format_csljson() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/test/collections/COL12345/items/top"))
+        .and(query_param("format", "csljson"))
+        .and(query_param("limit", "10"))
+        .respond_with(zotero_array_response(r#"{"items":[{"id":"a","title":"Attention"}]}"#))
+        .mount(&mock)
+        .await;
+    let server = make_zotero_server(&mock);
+    let params = serde_json::from_value(
+        serde_json::json!({"key": "COL12345", "output_format": "csljson", "limit": 10}),
+    )
+    .unwrap();
+    let result = server.zotero_collection_works(Parameters(params)).await.unwrap();
+    let doc: serde_json::Value = serde_json::from_str(&result).unwrap();
+    assert_eq!(doc["items"][0]["title"], "Attention");
+}
+
 #[tokio::test]
 async fn test_zotero_collection_attachments() {
     let mock = MockServer::start().await;
@@ -3389,3 +4299,142 @@ async fn test_zotero_collection_works_by_name_resolves_key() {
     let result = server.zotero_collection_works(Parameters(params)).await;
     assert!(result.is_ok());
 }
+
+// ── Digest ───────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_digest_generate_lists_watch_hits_with_markdown() {
+    let mock = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("search", "gaussian splatting"))
+        .and(query_param("sort", "publication_date:desc"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(minimal_list_json()))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let server = make_server(&mock).await;
+    let params = serde_json::from_value(serde_json::json!({"watches": ["gaussian splatting"], "days": 7})).unwrap();
+    let digest: serde_json::Value =
+        serde_json::from_str(&server.digest_generate(Parameters(params)).await.unwrap()).unwrap();
+    assert_eq!(digest["days"], 7);
+    assert_eq!(digest["watches"][0]["query"], "gaussian splatting");
+    assert_eq!(digest["watches"][0]["matched"], 42);
+    assert!(digest["markdown"].as_str().unwrap().contains("### gaussian splatting (42 new)"));
+}
+
+#[tokio::test]
+async fn test_digest_notify_announces_new_works_once() {
+    let mock = MockServer::start().await;
+    let works = format!(
+        r#"{{"meta": {{"count": 1, "db_response_time_ms": 10, "page": 1, "per_page": 25}}, "results": [{}]}}"#,
+        minimal_work_json()
+    );
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(works))
+        .mount(&mock)
+        .await;
+    Mock::given(method("POST")).and(path("/hook")).respond_with(ResponseTemplate::new(204)).expect(1).mount(&mock).await;
+
+    let dir = std::env::temp_dir()
+        .join("papers-test-cache")
+        .join(format!("mcp-digest-notify-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    let server = make_server(&mock).await;
+    let params = || serde_json::from_value(serde_json::json!({"watches": ["open access"]})).unwrap();
+    let err = papers_core::tenant::scope(&dir, server.digest_notify(Parameters(params()))).await.unwrap_err();
+    assert!(err.contains("no webhooks or email configured"));
+
+    std::fs::create_dir_all(&dir).unwrap();
+    let config = serde_json::json!({
+        "embedding_model": "embedding-gemma-300m",
+        "webhooks": [{"url": format!("{}/hook", mock.uri())}],
+    });
+    std::fs::write(dir.join("config.json"), config.to_string()).unwrap();
+    for (notified, already) in [(1, 0), (0, 1)] {
+        let text = papers_core::tenant::scope(&dir, server.digest_notify(Parameters(params()))).await.unwrap();
+        let report: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(report["notified"].as_array().unwrap().len(), notified);
+        assert_eq!(report["already_notified"], already);
+    }
+}
+
+// ── Resources ────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_resources_list_empty_without_db() {
+    let mock = MockServer::start().await;
+    let server = make_zotero_server(&mock);
+    let result = server.list_paper_resources(None).await.unwrap();
+    assert!(result.resources.is_empty());
+    assert!(result.next_cursor.is_none());
+}
+
+#[tokio::test]
+async fn test_resources_read_rejects_unknown_uri() {
+    let mock = MockServer::start().await;
+    let server = make_zotero_server(&mock);
+    let err = server.read_paper_resource("file:///etc/passwd").await.unwrap_err();
+    assert!(err.message.contains("Unknown resource"), "{}", err.message);
+    let err = server.read_paper_resource("papers://paper/10.1/x/chapter/0").await.unwrap_err();
+    assert!(err.message.contains("DB not configured"), "{}", err.message);
+}
+
+#[tokio::test]
+async fn test_zotero_tools_advertise_credential_args() {
+    use rmcp::ServerHandler;
+
+    let server = PapersMcp::with_zotero(ZoteroClient::new("test", "test-key"));
+    let tool = server.get_tool("zotero_work_list").unwrap();
+    let properties = tool.input_schema["properties"].as_object().unwrap();
+    for arg in papers_mcp::server::ZOTERO_CREDENTIAL_ARGS {
+        assert!(properties.contains_key(arg), "missing {arg}");
+    }
+    let tool = server.get_tool("work_list").unwrap();
+    assert!(!tool.input_schema["properties"].as_object().unwrap().contains_key("zotero_profile"));
+}
+
+#[tokio::test]
+async fn test_tool_filter_hides_tools() {
+    use rmcp::ServerHandler;
+
+    let names = |list: &[&str]| list.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+    let server = PapersMcp::with_zotero(ZoteroClient::new("test", "test-key"))
+        .with_tool_filter(&[], &names(&["zotero_work_create"]));
+    assert!(server.get_tool("zotero_work_create").is_none());
+    assert!(server.get_tool("zotero_work_list").is_some());
+
+    let server = PapersMcp::with_zotero(ZoteroClient::new("test", "test-key"))
+        .with_tool_filter(&names(&["work_get", "work_list"]), &names(&["work_list"]));
+    assert!(server.get_tool("work_get").is_some());
+    assert!(server.get_tool("work_list").is_none());
+    assert!(server.get_tool("zotero_work_list").is_none());
+}
+
+#[tokio::test]
+async fn test_read_only_hides_write_tools() {
+    use papers_mcp::server::WRITE_TOOLS;
+    use rmcp::ServerHandler;
+
+    let server = PapersMcp::with_zotero(ZoteroClient::new("test", "test-key"));
+    for name in WRITE_TOOLS {
+        assert!(server.get_tool(name).is_some(), "{name} is not a tool");
+    }
+    let server = server.with_read_only();
+    assert!(server.is_read_only());
+    assert!(WRITE_TOOLS.iter().all(|name| server.get_tool(name).is_none()));
+    assert!(server.get_tool("zotero_work_list").is_some());
+    assert!(server.get_tool("selection_export").is_some());
+
+    let mock = MockServer::start().await;
+    let server = make_zotero_server(&mock).with_read_only();
+    let params = serde_json::from_value(serde_json::json!({"method": "PATCH", "path": "items/ABC12345"})).unwrap();
+    let err = server.zotero_raw(Parameters(params)).await.unwrap_err();
+    assert!(err.contains("read-only"), "{err}");
+    let params = serde_json::from_value(serde_json::json!({"id": "ABC12345", "apply": true})).unwrap();
+    let err = server.work_metadata_diff(Parameters(params)).await.unwrap_err();
+    assert!(err.contains("read-only"), "{err}");
+    assert!(mock.received_requests().await.unwrap().is_empty());
+}
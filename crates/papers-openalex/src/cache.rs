@@ -1,15 +1,170 @@
 use serde::{Deserialize, Serialize};
 use std::collections::hash_map::DefaultHasher;
+use std::collections::BTreeMap;
 use std::hash::{Hash, Hasher};
 use std::io;
 use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-/// Disk-based response cache with configurable TTL.
+/// Entity types with their own TTL, matched against URL path segments.
+pub const CACHE_ENTITIES: &[&str] = &[
+    "works",
+    "authors",
+    "sources",
+    "institutions",
+    "topics",
+    "keywords",
+    "publishers",
+    "funders",
+    "domains",
+    "fields",
+    "subfields",
+];
+
+/// Label used in [`CacheStats`] for entries that don't belong to an entity.
+const OTHER_ENTITY: &str = "other";
+
+const MINUTE: u64 = 60;
+const HOUR: u64 = 60 * MINUTE;
+const DAY: u64 = 24 * HOUR;
+
+/// TTL and size limits for a [`DiskCache`].
+///
+/// Each cached response is tagged with the entity type found in its URL path
+/// (`/works/W1`, `/autocomplete/authors`, …). Entity types change at very
+/// different rates: work citation counts move daily while the domain/field
+/// hierarchy is nearly static, so each gets its own TTL. A TTL of zero
+/// disables caching for that entity.
+///
+/// | Entity | Default TTL |
+/// |--------|-------------|
+/// | `works`, anything else | 10 minutes |
+/// | `authors`, `sources`, `institutions`, `publishers`, `funders` | 1 day |
+/// | `topics`, `keywords`, `domains`, `fields`, `subfields` | 7 days |
+///
+/// The default size limit is 512 MB; the oldest entries are evicted first.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CachePolicy {
+    /// TTL for URLs without a known entity and entities not in `ttls`.
+    pub default_ttl: Duration,
+    /// Per-entity TTL overrides, keyed by entity name (`"works"`).
+    pub ttls: BTreeMap<String, Duration>,
+    /// Total size of cached responses before the oldest are evicted.
+    pub max_bytes: Option<u64>,
+}
+
+impl Default for CachePolicy {
+    fn default() -> Self {
+        let mut ttls = BTreeMap::new();
+        ttls.insert("works".to_string(), Duration::from_secs(10 * MINUTE));
+        for entity in ["authors", "sources", "institutions", "publishers", "funders"] {
+            ttls.insert(entity.to_string(), Duration::from_secs(DAY));
+        }
+        for entity in ["topics", "keywords", "domains", "fields", "subfields"] {
+            ttls.insert(entity.to_string(), Duration::from_secs(7 * DAY));
+        }
+        Self {
+            default_ttl: Duration::from_secs(10 * MINUTE),
+            ttls,
+            max_bytes: Some(512 * 1024 * 1024),
+        }
+    }
+}
+
+impl CachePolicy {
+    /// A policy with the same TTL for every entity and no size limit.
+    pub fn uniform(ttl: Duration) -> Self {
+        Self {
+            default_ttl: ttl,
+            ttls: BTreeMap::new(),
+            max_bytes: None,
+        }
+    }
+
+    /// Override the TTL for one entity type.
+    pub fn with_ttl(mut self, entity: &str, ttl: Duration) -> Self {
+        self.ttls.insert(entity.to_string(), ttl);
+        self
+    }
+
+    /// Set or remove the size limit.
+    pub fn with_max_bytes(mut self, max_bytes: Option<u64>) -> Self {
+        self.max_bytes = max_bytes;
+        self
+    }
+
+    /// TTL for responses of `entity` (`None` for URLs without one).
+    pub fn ttl_for(&self, entity: Option<&str>) -> Duration {
+        entity
+            .and_then(|e| self.ttls.get(e))
+            .copied()
+            .unwrap_or(self.default_ttl)
+    }
+
+    /// The default policy with overrides from environment variables:
+    ///
+    /// - `PAPERS_CACHE_TTL` — fallback TTL
+    /// - `PAPERS_CACHE_TTL_<ENTITY>` — e.g. `PAPERS_CACHE_TTL_WORKS`, `PAPERS_CACHE_TTL_DOMAINS`
+    /// - `PAPERS_CACHE_MAX_MB` — size limit in megabytes (`0` for unlimited)
+    ///
+    /// TTLs are seconds or a number with an `s`/`m`/`h`/`d` suffix (`30m`,
+    /// `7d`). Unparseable values are ignored.
+    pub fn from_env() -> Self {
+        Self::from_lookup(|key| std::env::var(key).ok())
+    }
+
+    fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Self {
+        let mut policy = Self::default();
+        if let Some(ttl) = lookup("PAPERS_CACHE_TTL").as_deref().and_then(parse_ttl) {
+            policy.default_ttl = ttl;
+        }
+        for entity in CACHE_ENTITIES {
+            let key = format!("PAPERS_CACHE_TTL_{}", entity.to_uppercase());
+            if let Some(ttl) = lookup(&key).as_deref().and_then(parse_ttl) {
+                policy.ttls.insert(entity.to_string(), ttl);
+            }
+        }
+        if let Some(mb) = lookup("PAPERS_CACHE_MAX_MB").and_then(|v| v.trim().parse::<u64>().ok()) {
+            policy.max_bytes = (mb > 0).then(|| mb * 1024 * 1024);
+        }
+        policy
+    }
+}
+
+/// Parse a TTL: plain seconds, or a number followed by `s`, `m`, `h`, or `d`.
+fn parse_ttl(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    let (num, unit) = match value.char_indices().last()? {
+        (i, c) if c.is_ascii_alphabetic() => (&value[..i], c.to_ascii_lowercase()),
+        _ => (value, 's'),
+    };
+    let n: u64 = num.trim().parse().ok()?;
+    let secs = match unit {
+        's' => n,
+        'm' => n * MINUTE,
+        'h' => n * HOUR,
+        'd' => n * DAY,
+        _ => return None,
+    };
+    Some(Duration::from_secs(secs))
+}
+
+/// Entity type of a request URL: the first path segment naming an entity.
+fn url_entity(url: &str) -> Option<&'static str> {
+    let path = url.split_once("://").map_or(url, |(_, rest)| rest);
+    path.split('/')
+        .skip(1)
+        .find_map(|seg| CACHE_ENTITIES.iter().copied().find(|e| *e == seg))
+}
+
+/// Disk-based response cache with per-entity TTLs.
 ///
 /// Caches HTTP response text as JSON files keyed by a hash of the request URL,
 /// query parameters, and optional POST body. Expired entries are treated as
-/// cache misses and silently ignored.
+/// cache misses and silently ignored. See [`CachePolicy`] for TTLs and size
+/// limits.
 ///
 /// # Atomic writes
 ///
@@ -18,22 +173,86 @@ use std::time::{Duration, SystemTime, UNIX_EPOCH};
 #[derive(Clone, Debug)]
 pub struct DiskCache {
     cache_dir: PathBuf,
-    ttl: Duration,
+    policy: CachePolicy,
+    /// Lookup counters, shared by clones.
+    lookups: Arc<LookupCounters>,
+}
+
+#[derive(Debug, Default)]
+struct LookupCounters {
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+/// Hits and misses of [`DiskCache::get`] since the cache was created, from
+/// [`DiskCache::lookups`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct CacheLookups {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+impl CacheLookups {
+    /// Fraction of lookups that were hits; `None` before the first lookup.
+    pub fn hit_rate(&self) -> Option<f64> {
+        let total = self.hits + self.misses;
+        (total > 0).then(|| self.hits as f64 / total as f64)
+    }
 }
 
 #[derive(Serialize, Deserialize)]
 struct CacheEntry {
     ts: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    entity: Option<String>,
     body: String,
 }
 
+/// Entry counts and sizes for a [`DiskCache`], from [`DiskCache::stats`].
+#[derive(Debug, Clone, Serialize)]
+pub struct CacheStats {
+    pub dir: PathBuf,
+    pub entries: usize,
+    pub bytes: u64,
+    /// Entries past their TTL that have not been pruned yet.
+    pub expired: usize,
+    pub max_bytes: Option<u64>,
+    /// Per-entity breakdown, including every entity with a configured TTL.
+    pub by_entity: Vec<EntityCacheStats>,
+}
+
+/// One entity's row in [`CacheStats`].
+#[derive(Debug, Clone, Serialize)]
+pub struct EntityCacheStats {
+    pub entity: String,
+    pub ttl_secs: u64,
+    pub entries: usize,
+    pub bytes: u64,
+    pub expired: usize,
+}
+
+fn now_secs() -> Option<u64> {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .ok()
+        .map(|d| d.as_secs())
+}
+
 impl DiskCache {
-    /// Create a cache storing entries in `cache_dir` with the given TTL.
+    /// Create a cache storing entries in `cache_dir` with the same TTL for
+    /// every entity and no size limit.
     ///
     /// Creates the directory (and parents) if it doesn't exist.
     pub fn new(cache_dir: PathBuf, ttl: Duration) -> io::Result<Self> {
+        Self::with_policy(cache_dir, CachePolicy::uniform(ttl))
+    }
+
+    /// Create a cache storing entries in `cache_dir` with the given policy.
+    ///
+    /// Creates the directory (and parents) if it doesn't exist.
+    pub fn with_policy(cache_dir: PathBuf, policy: CachePolicy) -> io::Result<Self> {
         std::fs::create_dir_all(&cache_dir)?;
-        let cache = Self { cache_dir, ttl };
+        let cache = Self { cache_dir, policy, lookups: Arc::default() };
         cache.prune();
         Ok(cache)
     }
@@ -46,22 +265,52 @@ impl DiskCache {
     ///
     /// Returns `Err` if no cache directory can be determined or created.
     pub fn default_location(ttl: Duration) -> io::Result<Self> {
+        Self::default_location_with_policy(CachePolicy::uniform(ttl))
+    }
+
+    /// Like [`default_location`](Self::default_location) with a full policy,
+    /// typically [`CachePolicy::from_env`].
+    pub fn default_location_with_policy(policy: CachePolicy) -> io::Result<Self> {
         let base = dirs::cache_dir().ok_or_else(|| {
             io::Error::new(io::ErrorKind::NotFound, "no platform cache directory")
         })?;
-        Self::new(base.join("papers").join("requests"), ttl)
+        Self::with_policy(base.join("papers").join("requests"), policy)
+    }
+
+    /// The policy this cache was created with.
+    pub fn policy(&self) -> &CachePolicy {
+        &self.policy
+    }
+
+    fn is_expired(&self, entry: &CacheEntry, now: u64) -> bool {
+        let ttl = self.policy.ttl_for(entry.entity.as_deref());
+        ttl.is_zero() || now.saturating_sub(entry.ts) > ttl.as_secs()
     }
 
     /// Look up a cached response.
     ///
     /// Returns `None` on cache miss, expired entry, or any I/O / parse error.
     pub fn get(&self, url: &str, query: &[(&str, String)], body: Option<&str>) -> Option<String> {
+        let found = self.lookup(url, query, body);
+        let counter = if found.is_some() { &self.lookups.hits } else { &self.lookups.misses };
+        counter.fetch_add(1, Ordering::Relaxed);
+        found
+    }
+
+    /// Hits and misses of [`get`](Self::get) on this cache and its clones.
+    pub fn lookups(&self) -> CacheLookups {
+        CacheLookups {
+            hits: self.lookups.hits.load(Ordering::Relaxed),
+            misses: self.lookups.misses.load(Ordering::Relaxed),
+        }
+    }
+
+    fn lookup(&self, url: &str, query: &[(&str, String)], body: Option<&str>) -> Option<String> {
         let key = cache_key(url, query, body);
         let path = self.cache_dir.join(format!("{key:016x}.json"));
         let data = std::fs::read_to_string(&path).ok()?;
         let entry: CacheEntry = serde_json::from_str(&data).ok()?;
-        let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs();
-        if now.saturating_sub(entry.ts) > self.ttl.as_secs() {
+        if self.is_expired(&entry, now_secs()?) {
             return None;
         }
         Some(entry.body)
@@ -69,10 +318,17 @@ impl DiskCache {
 
     /// Store a response in the cache.
     ///
-    /// Writes atomically via a `.tmp` file + rename. Errors are silently
-    /// ignored — a failed cache write should never break a request.
+    /// Writes atomically via a `.tmp` file + rename, then evicts the oldest
+    /// entries if the size limit is exceeded. Errors are silently ignored — a
+    /// failed cache write should never break a request.
     pub fn set(&self, url: &str, query: &[(&str, String)], body: Option<&str>, response: &str) {
-        let _ = self.set_inner(url, query, body, response);
+        let entity = url_entity(url);
+        if self.policy.ttl_for(entity).is_zero() {
+            return;
+        }
+        if self.set_inner(url, query, body, entity, response).is_ok() {
+            self.evict_to_size();
+        }
     }
 
     fn set_inner(
@@ -80,6 +336,7 @@ impl DiskCache {
         url: &str,
         query: &[(&str, String)],
         body: Option<&str>,
+        entity: Option<&str>,
         response: &str,
     ) -> io::Result<()> {
         let key = cache_key(url, query, body);
@@ -89,6 +346,7 @@ impl DiskCache {
             .as_secs();
         let entry = CacheEntry {
             ts,
+            entity: entity.map(str::to_string),
             body: response.to_string(),
         };
         let json = serde_json::to_string(&entry)
@@ -100,14 +358,50 @@ impl DiskCache {
         Ok(())
     }
 
-    /// Remove expired entries and leftover `.tmp` files from the cache directory.
+    /// Cache files (`.json`) with their size and modification time.
+    fn json_files(&self) -> Vec<(PathBuf, u64, SystemTime)> {
+        let Ok(entries) = std::fs::read_dir(&self.cache_dir) else {
+            return Vec::new();
+        };
+        entries
+            .flatten()
+            .filter(|e| e.path().extension().is_some_and(|ext| ext == "json"))
+            .filter_map(|e| {
+                let meta = e.metadata().ok()?;
+                Some((e.path(), meta.len(), meta.modified().unwrap_or(UNIX_EPOCH)))
+            })
+            .collect()
+    }
+
+    /// Remove the oldest entries until the cache fits in `max_bytes`.
+    fn evict_to_size(&self) {
+        let Some(max_bytes) = self.policy.max_bytes else {
+            return;
+        };
+        let mut files = self.json_files();
+        let mut total: u64 = files.iter().map(|(_, len, _)| len).sum();
+        if total <= max_bytes {
+            return;
+        }
+        files.sort_by_key(|(_, _, modified)| *modified);
+        for (path, len, _) in files {
+            if total <= max_bytes {
+                break;
+            }
+            if std::fs::remove_file(&path).is_ok() {
+                total = total.saturating_sub(len);
+            }
+        }
+    }
+
+    /// Remove expired entries and leftover `.tmp` files from the cache directory,
+    /// then enforce the size limit.
     ///
     /// Called automatically on construction. Errors on individual files are
     /// silently ignored.
     pub fn prune(&self) {
-        let now = match SystemTime::now().duration_since(UNIX_EPOCH) {
-            Ok(d) => d.as_secs(),
-            Err(_) => return,
+        let Some(now) = now_secs() else {
+            return;
         };
         let entries = match std::fs::read_dir(&self.cache_dir) {
             Ok(e) => e,
@@ -142,10 +436,94 @@ impl DiskCache {
                     continue;
                 }
             };
-            if now.saturating_sub(entry.ts) > self.ttl.as_secs() {
+            if self.is_expired(&entry, now) {
                 let _ = std::fs::remove_file(&path);
             }
         }
+        self.evict_to_size();
+    }
+
+    /// Count entries and bytes, overall and per entity.
+    pub fn stats(&self) -> CacheStats {
+        let now = now_secs().unwrap_or(0);
+        let mut by_entity: BTreeMap<String, EntityCacheStats> = BTreeMap::new();
+        for entity in self.policy.ttls.keys() {
+            by_entity.insert(
+                entity.clone(),
+                EntityCacheStats {
+                    entity: entity.clone(),
+                    ttl_secs: self.policy.ttl_for(Some(entity)).as_secs(),
+                    entries: 0,
+                    bytes: 0,
+                    expired: 0,
+                },
+            );
+        }
+        for (path, len, _) in self.json_files() {
+            let Some(entry) = std::fs::read_to_string(&path)
+                .ok()
+                .and_then(|d| serde_json::from_str::<CacheEntry>(&d).ok())
+            else {
+                continue;
+            };
+            let name = entry.entity.as_deref().unwrap_or(OTHER_ENTITY);
+            let row = by_entity.entry(name.to_string()).or_insert_with(|| EntityCacheStats {
+                entity: name.to_string(),
+                ttl_secs: self.policy.ttl_for(entry.entity.as_deref()).as_secs(),
+                entries: 0,
+                bytes: 0,
+                expired: 0,
+            });
+            row.entries += 1;
+            row.bytes += len;
+            if self.is_expired(&entry, now) {
+                row.expired += 1;
+            }
+        }
+        let by_entity: Vec<EntityCacheStats> = by_entity.into_values().collect();
+        CacheStats {
+            dir: self.cache_dir.clone(),
+            entries: by_entity.iter().map(|r| r.entries).sum(),
+            bytes: by_entity.iter().map(|r| r.bytes).sum(),
+            expired: by_entity.iter().map(|r| r.expired).sum(),
+            max_bytes: self.policy.max_bytes,
+            by_entity,
+        }
+    }
+
+    /// Remove cached responses, all of them or only those of one entity type.
+    /// Returns the number of entries removed.
+    ///
+    /// `entity` must be one of [`CACHE_ENTITIES`] or `"other"`.
+    pub fn clear(&self, entity: Option<&str>) -> io::Result<usize> {
+        if let Some(e) = entity
+            && e != OTHER_ENTITY
+            && !CACHE_ENTITIES.contains(&e)
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "unknown cache entity '{e}' (expected one of: {}, {OTHER_ENTITY})",
+                    CACHE_ENTITIES.join(", ")
+                ),
+            ));
+        }
+        let mut removed = 0;
+        for (path, _, _) in self.json_files() {
+            if let Some(wanted) = entity {
+                let stored = std::fs::read_to_string(&path)
+                    .ok()
+                    .and_then(|d| serde_json::from_str::<CacheEntry>(&d).ok())
+                    .map(|e| e.entity.unwrap_or_else(|| OTHER_ENTITY.to_string()));
+                if stored.as_deref() != Some(wanted) {
+                    continue;
+                }
+            }
+            if std::fs::remove_file(&path).is_ok() {
+                removed += 1;
+            }
+        }
+        Ok(removed)
     }
 }
 
@@ -241,6 +619,20 @@ mod tests {
         assert!(cache.get("http://nonexistent", &q, None).is_none());
     }
 
+    #[test]
+    fn lookups_count_hits_and_misses_across_clones() {
+        let cache = temp_cache(60);
+        let q: Vec<(&str, String)> = vec![];
+        assert_eq!(cache.lookups().hit_rate(), None);
+        cache.set("http://x", &q, None, "data");
+        let clone = cache.clone();
+        assert!(clone.get("http://x", &q, None).is_some());
+        assert!(clone.get("http://y", &q, None).is_none());
+        assert!(cache.get("http://x", &q, None).is_some());
+        assert_eq!(cache.lookups(), CacheLookups { hits: 2, misses: 1 });
+        assert_eq!(clone.lookups().hit_rate(), Some(2.0 / 3.0));
+    }
+
     #[test]
     fn expired_entry_returns_none() {
         let cache = temp_cache(1);
@@ -272,7 +664,7 @@ mod tests {
 
         // Manually write an expired entry
         let key = cache_key("http://old", &q, None);
-        let expired = CacheEntry { ts: 0, body: "old".into() };
+        let expired = CacheEntry { ts: 0, entity: None, body: "old".into() };
         let json = serde_json::to_string(&expired).unwrap();
         std::fs::write(dir.join(format!("{key:016x}.json")), json).unwrap();
 
@@ -314,4 +706,104 @@ mod tests {
         cache.set("http://x", &q, None, "ok");
         assert_eq!(cache.get("http://x", &q, None).as_deref(), Some("ok"));
     }
+
+    fn temp_dir() -> PathBuf {
+        std::env::temp_dir()
+            .join("papers-test-cache")
+            .join(format!("{:x}", rand_u64()))
+    }
+
+    #[test]
+    fn url_entity_from_path() {
+        assert_eq!(url_entity("https://api.openalex.org/works/W1"), Some("works"));
+        assert_eq!(url_entity("https://api.openalex.org/autocomplete/authors"), Some("authors"));
+        assert_eq!(url_entity("http://127.0.0.1:1234/find/works"), Some("works"));
+        assert_eq!(url_entity("https://api.openalex.org/domains"), Some("domains"));
+        assert_eq!(url_entity("https://api.openalex.org/other"), None);
+    }
+
+    #[test]
+    fn parse_ttl_units() {
+        assert_eq!(parse_ttl("90"), Some(Duration::from_secs(90)));
+        assert_eq!(parse_ttl("30m"), Some(Duration::from_secs(1800)));
+        assert_eq!(parse_ttl("2H"), Some(Duration::from_secs(7200)));
+        assert_eq!(parse_ttl("7d"), Some(Duration::from_secs(7 * DAY)));
+        assert_eq!(parse_ttl("0"), Some(Duration::ZERO));
+        assert_eq!(parse_ttl("soon"), None);
+        assert_eq!(parse_ttl("5w"), None);
+    }
+
+    #[test]
+    fn policy_defaults_and_env_overrides() {
+        let default = CachePolicy::default();
+        assert_eq!(default.ttl_for(Some("works")), Duration::from_secs(600));
+        assert_eq!(default.ttl_for(Some("domains")), Duration::from_secs(7 * DAY));
+        assert_eq!(default.ttl_for(None), Duration::from_secs(600));
+
+        let env = |key: &str| match key {
+            "PAPERS_CACHE_TTL" => Some("1h".to_string()),
+            "PAPERS_CACHE_TTL_WORKS" => Some("60".to_string()),
+            "PAPERS_CACHE_TTL_FIELDS" => Some("bogus".to_string()),
+            "PAPERS_CACHE_MAX_MB" => Some("0".to_string()),
+            _ => None,
+        };
+        let policy = CachePolicy::from_lookup(env);
+        assert_eq!(policy.ttl_for(Some("works")), Duration::from_secs(60));
+        assert_eq!(policy.ttl_for(Some("fields")), Duration::from_secs(7 * DAY));
+        assert_eq!(policy.ttl_for(None), Duration::from_secs(HOUR));
+        assert_eq!(policy.max_bytes, None);
+    }
+
+    #[test]
+    fn per_entity_ttl_applies_on_get() {
+        let policy = CachePolicy::uniform(Duration::from_secs(3600))
+            .with_ttl("works", Duration::ZERO);
+        let cache = DiskCache::with_policy(temp_dir(), policy).unwrap();
+        let q: Vec<(&str, String)> = vec![];
+        cache.set("http://x/works/W1", &q, None, "work");
+        cache.set("http://x/domains/1", &q, None, "domain");
+        assert!(cache.get("http://x/works/W1", &q, None).is_none());
+        assert_eq!(cache.get("http://x/domains/1", &q, None).as_deref(), Some("domain"));
+    }
+
+    #[test]
+    fn set_evicts_oldest_over_max_bytes() {
+        let dir = temp_dir();
+        let policy = CachePolicy::uniform(Duration::from_secs(3600)).with_max_bytes(Some(150));
+        let cache = DiskCache::with_policy(dir.clone(), policy).unwrap();
+        let q: Vec<(&str, String)> = vec![];
+        let body = "x".repeat(60);
+        cache.set("http://x/works/W1", &q, None, &body);
+        sleep(Duration::from_millis(20));
+        cache.set("http://x/works/W2", &q, None, &body);
+        assert!(cache.get("http://x/works/W1", &q, None).is_none());
+        assert!(cache.get("http://x/works/W2", &q, None).is_some());
+    }
+
+    #[test]
+    fn stats_and_clear_by_entity() {
+        let cache = DiskCache::with_policy(temp_dir(), CachePolicy::default()).unwrap();
+        let q: Vec<(&str, String)> = vec![];
+        cache.set("http://x/works/W1", &q, None, "a");
+        cache.set("http://x/works/W2", &q, None, "b");
+        cache.set("http://x/authors/A1", &q, None, "c");
+        cache.set("http://x/other", &q, None, "d");
+
+        let stats = cache.stats();
+        assert_eq!(stats.entries, 4);
+        assert_eq!(stats.expired, 0);
+        let row = |name: &str| stats.by_entity.iter().find(|r| r.entity == name).unwrap();
+        assert_eq!(row("works").entries, 2);
+        assert_eq!(row("authors").entries, 1);
+        assert_eq!(row("other").entries, 1);
+        assert_eq!(row("domains").entries, 0);
+        assert_eq!(row("domains").ttl_secs, 7 * DAY);
+
+        assert_eq!(cache.clear(Some("works")).unwrap(), 2);
+        assert!(cache.get("http://x/works/W1", &q, None).is_none());
+        assert!(cache.get("http://x/authors/A1", &q, None).is_some());
+        assert!(cache.clear(Some("bogus")).is_err());
+        assert_eq!(cache.clear(None).unwrap(), 2);
+        assert_eq!(cache.stats().entries, 0);
+    }
 }
@@ -2,11 +2,23 @@ use crate::cache::DiskCache;
 use crate::error::{OpenAlexError, Result};
 use crate::params::{FindWorksParams, GetParams, ListParams};
 use crate::response::{AutocompleteResponse, FindWorksResponse, ListResponse};
+use crate::retry::{RetryPolicy, parse_retry_after};
+use crate::snapshot::Snapshot;
 use crate::types::*;
+use futures::Stream;
 use serde::de::DeserializeOwned;
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
 
 const DEFAULT_BASE_URL: &str = "https://api.openalex.org";
 
+/// Default minimum delay between page requests in `list_all_*` streams.
+/// OpenAlex allows 10 requests per second.
+pub const DEFAULT_PAGE_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Page size used by `list_all_*` when `per_page` is unset (the API maximum).
+const STREAM_PER_PAGE: u32 = 200;
+
 /// Async client for the [OpenAlex REST API](https://docs.openalex.org).
 ///
 /// Provides 30 methods covering all OpenAlex endpoints: 10 list, 10 get,
@@ -73,12 +85,33 @@ const DEFAULT_BASE_URL: &str = "https://api.openalex.org";
 /// # Ok(())
 /// # }
 /// ```
+///
+/// Or let [`list_all_works`](Self::list_all_works) follow the cursor:
+///
+/// ```no_run
+/// # async fn example() -> papers_openalex::Result<()> {
+/// use futures::StreamExt;
+/// use papers_openalex::{OpenAlexClient, ListParams};
+///
+/// let client = OpenAlexClient::new();
+/// let params = ListParams::builder().filter("publication_year:2024").build();
+/// let mut works = std::pin::pin!(client.list_all_works(&params).take(5_000));
+/// while let Some(work) = works.next().await {
+///     let work = work?;
+///     // process each work
+/// }
+/// # Ok(())
+/// # }
+/// ```
 #[derive(Clone)]
 pub struct OpenAlexClient {
     http: reqwest::Client,
     base_url: String,
     api_key: Option<String>,
     cache: Option<DiskCache>,
+    page_interval: Duration,
+    retry: RetryPolicy,
+    snapshot: Option<Snapshot>,
 }
 
 impl Default for OpenAlexClient {
@@ -92,6 +125,9 @@ impl OpenAlexClient {
     /// environment variable. The key is optional for most endpoints but
     /// recommended for higher rate limits.
     ///
+    /// If `PAPERS_OPENALEX_SNAPSHOT` is set, the client serves from that
+    /// offline snapshot index instead (see [`with_snapshot`](Self::with_snapshot)).
+    ///
     /// ```no_run
     /// use papers_openalex::OpenAlexClient;
     /// let client = OpenAlexClient::new();
@@ -102,6 +138,9 @@ impl OpenAlexClient {
             base_url: DEFAULT_BASE_URL.to_string(),
             api_key: std::env::var("OPENALEX_KEY").ok(),
             cache: None,
+            page_interval: DEFAULT_PAGE_INTERVAL,
+            retry: RetryPolicy::default(),
+            snapshot: Snapshot::from_env(),
         }
     }
 
@@ -117,6 +156,9 @@ impl OpenAlexClient {
             base_url: DEFAULT_BASE_URL.to_string(),
             api_key: Some(api_key.into()),
             cache: None,
+            page_interval: DEFAULT_PAGE_INTERVAL,
+            retry: RetryPolicy::default(),
+            snapshot: None,
         }
     }
 
@@ -146,6 +188,49 @@ impl OpenAlexClient {
         self
     }
 
+    /// Minimum delay between page requests in `list_all_*` streams
+    /// (default [`DEFAULT_PAGE_INTERVAL`]).
+    pub fn with_page_interval(mut self, interval: Duration) -> Self {
+        self.page_interval = interval;
+        self
+    }
+
+    /// How rate-limited (429) and transient 5xx responses are retried
+    /// (default [`RetryPolicy::default`]). Pass [`RetryPolicy::none`] to
+    /// return every failure immediately.
+    ///
+    /// ```no_run
+    /// use papers_openalex::{OpenAlexClient, RetryPolicy};
+    /// let client = OpenAlexClient::new().with_retry(RetryPolicy::none());
+    /// ```
+    pub fn with_retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = policy;
+        self
+    }
+
+    /// Serve every request from an offline snapshot index (see
+    /// [`crate::snapshot`]) instead of the API. Requests it can't answer fail
+    /// with [`OpenAlexError::Offline`]; the network is never used.
+    ///
+    /// ```no_run
+    /// use papers_openalex::{OpenAlexClient, Snapshot};
+    /// let client = OpenAlexClient::new().with_snapshot(Snapshot::new("/data/openalex-index"));
+    /// ```
+    pub fn with_snapshot(mut self, snapshot: Snapshot) -> Self {
+        self.snapshot = Some(snapshot);
+        self
+    }
+
+    /// The offline snapshot the client serves from, if any.
+    pub fn snapshot(&self) -> Option<&Snapshot> {
+        self.snapshot.as_ref()
+    }
+
+    /// The response cache, if one was set with [`with_cache`](Self::with_cache).
+    pub fn cache(&self) -> Option<&DiskCache> {
+        self.cache.as_ref()
+    }
+
     // ── Private helpers ────────────────────────────────────────────────
 
     fn append_api_key(&self, pairs: &mut Vec<(&str, String)>) {
@@ -154,11 +239,42 @@ impl OpenAlexClient {
         }
     }
 
+    /// Send the request `build` makes, retrying per the client's
+    /// [`RetryPolicy`], and return the body of the first successful response.
+    async fn send(&self, build: impl Fn() -> reqwest::RequestBuilder) -> Result<String> {
+        let mut retry = 0;
+        loop {
+            let resp = build().send().await?;
+            let status = resp.status().as_u16();
+            if resp.status().is_success() {
+                return Ok(resp.text().await?);
+            }
+            let retry_after = resp
+                .headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|v| v.to_str().ok())
+                .and_then(parse_retry_after);
+            if let Some(delay) = self.retry.delay(retry, status, retry_after) {
+                tokio::time::sleep(delay).await;
+                retry += 1;
+                continue;
+            }
+            if status == 429 {
+                return Err(OpenAlexError::RateLimited { retry_after });
+            }
+            let message = resp.text().await.unwrap_or_default();
+            return Err(OpenAlexError::Api { status, message });
+        }
+    }
+
     async fn get_json<T: DeserializeOwned>(
         &self,
         path: &str,
         mut query: Vec<(&str, String)>,
     ) -> Result<T> {
+        if let Some(snapshot) = &self.snapshot {
+            return serde_json::from_value(snapshot.serve(path, &query)?).map_err(OpenAlexError::Json);
+        }
         self.append_api_key(&mut query);
         let url = format!("{}{}", self.base_url, path);
         if let Some(cache) = &self.cache {
@@ -166,16 +282,7 @@ impl OpenAlexClient {
                 return serde_json::from_str(&text).map_err(OpenAlexError::Json);
             }
         }
-        let resp = self.http.get(&url).query(&query).send().await?;
-        let status = resp.status();
-        if !status.is_success() {
-            let message = resp.text().await.unwrap_or_default();
-            return Err(OpenAlexError::Api {
-                status: status.as_u16(),
-                message,
-            });
-        }
-        let text = resp.text().await?;
+        let text = self.send(|| self.http.get(&url).query(&query)).await?;
         if let Some(cache) = &self.cache {
             cache.set(&url, &query, None, &text);
         }
@@ -188,6 +295,9 @@ impl OpenAlexClient {
         mut query: Vec<(&str, String)>,
         body: serde_json::Value,
     ) -> Result<T> {
+        if self.snapshot.is_some() {
+            return Err(OpenAlexError::Offline(format!("POST {path}")));
+        }
         self.append_api_key(&mut query);
         let url = format!("{}{}", self.base_url, path);
         let body_str = body.to_string();
@@ -196,16 +306,7 @@ impl OpenAlexClient {
                 return serde_json::from_str(&text).map_err(OpenAlexError::Json);
             }
         }
-        let resp = self.http.post(&url).query(&query).json(&body).send().await?;
-        let status = resp.status();
-        if !status.is_success() {
-            let message = resp.text().await.unwrap_or_default();
-            return Err(OpenAlexError::Api {
-                status: status.as_u16(),
-                message,
-            });
-        }
-        let text = resp.text().await?;
+        let text = self.send(|| self.http.post(&url).query(&query).json(&body)).await?;
         if let Some(cache) = &self.cache {
             cache.set(&url, &query, Some(&body_str), &text);
         }
@@ -509,6 +610,165 @@ impl OpenAlexClient {
         self.list_entities("/funders", params).await
     }
 
+    // ── Raw requests ───────────────────────────────────────────────────
+
+    /// `GET` any path under the base URL and return the untyped JSON body.
+    /// Goes through the API key and cache like every other request; callers
+    /// are responsible for restricting `path`.
+    ///
+    /// ```no_run
+    /// # async fn example() -> papers_openalex::Result<()> {
+    /// use papers_openalex::OpenAlexClient;
+    ///
+    /// let client = OpenAlexClient::new();
+    /// let json = client
+    ///     .get_raw("/works", vec![("group_by", "oa_status".to_string())])
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_raw(
+        &self,
+        path: &str,
+        query: Vec<(&str, String)>,
+    ) -> Result<serde_json::Value> {
+        self.get_json(path, query).await
+    }
+
+    // ── Streaming pagination ───────────────────────────────────────────
+
+    /// Stream every result of a list query, following `meta.next_cursor`.
+    ///
+    /// `page` is ignored; `cursor` defaults to `"*"` and `per_page` to 200.
+    /// Page requests are spaced by the client's page interval, and a page that
+    /// is rate limited is retried per the client's [`RetryPolicy`]. The stream
+    /// ends after the last page or the first error.
+    fn list_all_entities<T: DeserializeOwned>(
+        &self,
+        path: &'static str,
+        params: &ListParams,
+    ) -> impl Stream<Item = Result<T>> + use<T> {
+        let mut params = params.clone();
+        params.page = None;
+        params.cursor = Some(params.cursor.unwrap_or_else(|| "*".to_string()));
+        params.per_page = Some(params.per_page.unwrap_or(STREAM_PER_PAGE));
+        let pager = Pager {
+            client: self.clone(),
+            path,
+            params,
+            buffer: VecDeque::new(),
+            done: false,
+            last_request: None,
+        };
+        futures::stream::unfold(pager, |mut pager| async move {
+            loop {
+                if let Some(item) = pager.buffer.pop_front() {
+                    return Some((Ok(item), pager));
+                }
+                if pager.done {
+                    return None;
+                }
+                if let Err(e) = pager.next_page().await {
+                    pager.done = true;
+                    return Some((Err(e), pager));
+                }
+            }
+        })
+    }
+
+    /// Stream every work matching `params`, following cursor pagination.
+    /// See [`list_works`](Self::list_works) for the parameters.
+    ///
+    /// ```no_run
+    /// # async fn example() -> papers_openalex::Result<()> {
+    /// use futures::TryStreamExt;
+    /// use papers_openalex::{OpenAlexClient, ListParams};
+    ///
+    /// let client = OpenAlexClient::new();
+    /// let params = ListParams::builder()
+    ///     .filter("authorships.institutions.id:I63966007,publication_year:2024")
+    ///     .select("id,display_name")
+    ///     .build();
+    /// let works: Vec<_> = client.list_all_works(&params).try_collect().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_all_works(&self, params: &ListParams) -> impl Stream<Item = Result<Work>> + use<> {
+        self.list_all_entities("/works", params)
+    }
+
+    /// Stream every author matching `params`. See [`list_all_works`](Self::list_all_works).
+    pub fn list_all_authors(
+        &self,
+        params: &ListParams,
+    ) -> impl Stream<Item = Result<Author>> + use<> {
+        self.list_all_entities("/authors", params)
+    }
+
+    /// Stream every source matching `params`. See [`list_all_works`](Self::list_all_works).
+    pub fn list_all_sources(
+        &self,
+        params: &ListParams,
+    ) -> impl Stream<Item = Result<Source>> + use<> {
+        self.list_all_entities("/sources", params)
+    }
+
+    /// Stream every institution matching `params`. See [`list_all_works`](Self::list_all_works).
+    pub fn list_all_institutions(
+        &self,
+        params: &ListParams,
+    ) -> impl Stream<Item = Result<Institution>> + use<> {
+        self.list_all_entities("/institutions", params)
+    }
+
+    /// Stream every topic matching `params`. See [`list_all_works`](Self::list_all_works).
+    pub fn list_all_topics(
+        &self,
+        params: &ListParams,
+    ) -> impl Stream<Item = Result<Topic>> + use<> {
+        self.list_all_entities("/topics", params)
+    }
+
+    /// Stream every publisher matching `params`. See [`list_all_works`](Self::list_all_works).
+    pub fn list_all_publishers(
+        &self,
+        params: &ListParams,
+    ) -> impl Stream<Item = Result<Publisher>> + use<> {
+        self.list_all_entities("/publishers", params)
+    }
+
+    /// Stream every funder matching `params`. See [`list_all_works`](Self::list_all_works).
+    pub fn list_all_funders(
+        &self,
+        params: &ListParams,
+    ) -> impl Stream<Item = Result<Funder>> + use<> {
+        self.list_all_entities("/funders", params)
+    }
+
+    /// Stream every domain matching `params`. See [`list_all_works`](Self::list_all_works).
+    pub fn list_all_domains(
+        &self,
+        params: &ListParams,
+    ) -> impl Stream<Item = Result<Domain>> + use<> {
+        self.list_all_entities("/domains", params)
+    }
+
+    /// Stream every field matching `params`. See [`list_all_works`](Self::list_all_works).
+    pub fn list_all_fields(
+        &self,
+        params: &ListParams,
+    ) -> impl Stream<Item = Result<Field>> + use<> {
+        self.list_all_entities("/fields", params)
+    }
+
+    /// Stream every subfield matching `params`. See [`list_all_works`](Self::list_all_works).
+    pub fn list_all_subfields(
+        &self,
+        params: &ListParams,
+    ) -> impl Stream<Item = Result<Subfield>> + use<> {
+        self.list_all_entities("/subfields", params)
+    }
+
     // ── Single entity endpoints ────────────────────────────────────────
 
     /// Get a single scholarly work by ID. Returns full metadata including title,
@@ -1022,6 +1282,34 @@ impl OpenAlexClient {
     }
 }
 
+/// Cursor state for [`OpenAlexClient::list_all_entities`].
+struct Pager<T> {
+    client: OpenAlexClient,
+    path: &'static str,
+    params: ListParams,
+    buffer: VecDeque<T>,
+    done: bool,
+    last_request: Option<Instant>,
+}
+
+impl<T: DeserializeOwned> Pager<T> {
+    /// Fetch the next page into `buffer` and advance the cursor.
+    async fn next_page(&mut self) -> Result<()> {
+        if let Some(last) = self.last_request {
+            let wait = self.client.page_interval.saturating_sub(last.elapsed());
+            if !wait.is_zero() {
+                tokio::time::sleep(wait).await;
+            }
+        }
+        self.last_request = Some(Instant::now());
+        let resp = self.client.list_entities::<T>(self.path, &self.params).await?;
+        self.done = resp.meta.next_cursor.is_none() || resp.results.is_empty();
+        self.params.cursor = resp.meta.next_cursor;
+        self.buffer.extend(resp.results);
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1787,7 +2075,8 @@ mod tests {
             .await;
         let client = OpenAlexClient::new()
             .with_base_url(server.uri())
-            .with_cache(temp_cache());
+            .with_cache(temp_cache())
+            .with_retry(RetryPolicy::none());
         let _ = client.get_work("bad", &GetParams::default()).await;
         let _ = client.get_work("bad", &GetParams::default()).await;
     }
@@ -1823,4 +2112,155 @@ mod tests {
         // Second call served from cache
         client.find_works_post(&params).await.unwrap();
     }
+
+    // ── Streaming pagination tests ─────────────────────────────────────
+
+    fn page_json(ids: &[&str], next_cursor: Option<&str>) -> String {
+        let results: Vec<String> = ids
+            .iter()
+            .map(|id| format!(r#"{{"id": "https://openalex.org/{id}"}}"#))
+            .collect();
+        let cursor = next_cursor.map_or("null".to_string(), |c| format!("\"{c}\""));
+        format!(
+            r#"{{"meta": {{"count": 3, "db_response_time_ms": 1, "page": null, "per_page": 2, "next_cursor": {cursor}}}, "results": [{}], "group_by": []}}"#,
+            results.join(",")
+        )
+    }
+
+    #[tokio::test]
+    async fn test_list_all_works_follows_cursor() {
+        use futures::TryStreamExt;
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/works"))
+            .and(query_param("cursor", "*"))
+            .and(query_param("per-page", "2"))
+            .and(query_param("filter", "publication_year:2024"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_string(page_json(&["W1", "W2"], Some("c2"))),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/works"))
+            .and(query_param("cursor", "c2"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(page_json(&["W3"], None)))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = setup_client(&server).await.with_page_interval(Duration::from_millis(1));
+        let params = ListParams {
+            filter: Some("publication_year:2024".into()),
+            per_page: Some(2),
+            page: Some(7),
+            ..Default::default()
+        };
+        let works: Vec<Work> = client.list_all_works(&params).try_collect().await.unwrap();
+        let ids: Vec<&str> = works.iter().map(|w| w.id.as_str()).collect();
+        assert_eq!(
+            ids,
+            ["https://openalex.org/W1", "https://openalex.org/W2", "https://openalex.org/W3"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_all_retries_rate_limited_page() {
+        use futures::TryStreamExt;
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/authors"))
+            .respond_with(ResponseTemplate::new(429))
+            .up_to_n_times(1)
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/authors"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(page_json(&["A1"], None)))
+            .mount(&server)
+            .await;
+        let client = setup_client(&server).await.with_page_interval(Duration::from_millis(1));
+        let authors: Vec<Author> =
+            client.list_all_authors(&ListParams::default()).try_collect().await.unwrap();
+        assert_eq!(authors.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_list_all_stops_after_error() {
+        use futures::StreamExt;
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/works"))
+            .respond_with(ResponseTemplate::new(500).set_body_string("boom"))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = setup_client(&server).await.with_retry(RetryPolicy::none());
+        let items: Vec<Result<Work>> = client.list_all_works(&ListParams::default()).collect().await;
+        assert_eq!(items.len(), 1);
+        assert!(matches!(items[0], Err(OpenAlexError::Api { status: 500, .. })));
+    }
+
+    fn fast_retry(max_retries: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_retries,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_secs(5),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retries_transient_server_error() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/works/W1"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(2)
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/works/W1"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_string(r#"{"id": "https://openalex.org/W1"}"#),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = setup_client(&server).await.with_retry(fast_retry(3));
+        let work = client.get_work("W1", &GetParams::default()).await.unwrap();
+        assert_eq!(work.id, "https://openalex.org/W1");
+    }
+
+    #[tokio::test]
+    async fn test_rate_limited_after_retries() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/works"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+            .expect(3)
+            .mount(&server)
+            .await;
+        let client = setup_client(&server).await.with_retry(fast_retry(2));
+        let err = client.list_works(&ListParams::default()).await.unwrap_err();
+        assert!(
+            matches!(err, OpenAlexError::RateLimited { retry_after: Some(d) } if d.is_zero()),
+            "{err}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_retry_after_beyond_max_delay_fails_fast() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/works"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "3600"))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = setup_client(&server).await.with_retry(fast_retry(3));
+        let err = client.list_works(&ListParams::default()).await.unwrap_err();
+        assert_eq!(err.to_string(), "rate limited by OpenAlex (retry after 3600s)");
+    }
 }
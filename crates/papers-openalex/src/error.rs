@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 /// Errors returned by [`OpenAlexClient`](crate::OpenAlexClient) methods.
 ///
 /// # Variants
@@ -9,6 +11,11 @@
 /// - [`Api`](OpenAlexError::Api) — the OpenAlex API returned a non-success HTTP
 ///   status code (e.g. 404 for unknown entity, 403 for forbidden, 500 for
 ///   server error)
+/// - [`RateLimited`](OpenAlexError::RateLimited) — HTTP 429 that persisted
+///   through the client's [`RetryPolicy`](crate::RetryPolicy)
+/// - [`Offline`](OpenAlexError::Offline) — the request needs the API, but the
+///   client serves from a local [`Snapshot`](crate::Snapshot)
+/// - [`Snapshot`](OpenAlexError::Snapshot) — reading the snapshot index failed
 ///
 /// # Example
 ///
@@ -43,6 +50,29 @@ pub enum OpenAlexError {
     /// contains the response body text.
     #[error("API error (status {status}): {message}")]
     Api { status: u16, message: String },
+
+    /// The API kept answering HTTP 429 (Too Many Requests) after all retries.
+    ///
+    /// `retry_after` is the wait the last response asked for via its
+    /// `Retry-After` header, if it sent one.
+    #[error("rate limited by OpenAlex{}", retry_hint(.retry_after))]
+    RateLimited { retry_after: Option<Duration> },
+
+    /// The client serves from an offline [`Snapshot`](crate::Snapshot), which
+    /// can't answer this request (search, autocomplete, most filters).
+    #[error("not available from the offline OpenAlex snapshot: {0}")]
+    Offline(String),
+
+    /// Reading the offline snapshot index failed.
+    #[error("OpenAlex snapshot error: {0}")]
+    Snapshot(#[from] std::io::Error),
+}
+
+fn retry_hint(retry_after: &Option<Duration>) -> String {
+    match retry_after {
+        Some(wait) => format!(" (retry after {}s)", wait.as_secs()),
+        None => String::new(),
+    }
 }
 
 /// A [`Result`](std::result::Result) alias with [`OpenAlexError`] as the error
@@ -56,15 +56,33 @@
 //!   results)
 //! - **2 semantic search endpoints** — AI-powered similarity search via GET or
 //!   POST
+//!
+//! Each list endpoint also has a `list_all_*` stream (e.g.
+//! [`OpenAlexClient::list_all_works`]) that follows cursor pagination with
+//! rate limiting.
+//!
+//! Rate-limited (429) and transient 5xx responses are retried with backoff,
+//! honoring `Retry-After`; see [`RetryPolicy`] and
+//! [`OpenAlexClient::with_retry`].
+//!
+//! # Offline snapshots
+//!
+//! With `PAPERS_OPENALEX_SNAPSHOT` pointing at an index built by
+//! [`Snapshot::build`] from a local
+//! [OpenAlex snapshot](https://docs.openalex.org/download-all-data/openalex-snapshot),
+//! the client answers lookups by ID or DOI and ID/DOI-filtered lists from disk
+//! and never touches the network. See [`snapshot`].
 
 pub mod cache;
 pub mod client;
 pub mod error;
 pub mod params;
 pub mod response;
+pub mod retry;
+pub mod snapshot;
 pub mod types;
 
-pub use cache::DiskCache;
+pub use cache::{CacheLookups, CachePolicy, CacheStats, DiskCache, EntityCacheStats};
 pub use client::OpenAlexClient;
 pub use error::{OpenAlexError, Result};
 pub use params::{FindWorksParams, GetParams, ListParams};
@@ -72,4 +90,6 @@ pub use response::{
     AutocompleteResponse, AutocompleteResult, FindWorksResponse, FindWorksResult, GroupByResult,
     ListMeta, ListResponse,
 };
+pub use retry::RetryPolicy;
+pub use snapshot::{Snapshot, SnapshotManifest};
 pub use types::*;
@@ -90,7 +90,8 @@ pub struct ListParams {
     pub seed: Option<u32>,
 
     /// Comma-separated list of fields to include in the response. Reduces
-    /// payload size. Unselected fields will be omitted.
+    /// payload size. Unselected fields will be omitted. `abstract` is
+    /// accepted for works and requested as `abstract_inverted_index`.
     ///
     /// Example: `"id,display_name,cited_by_count"`
     pub select: Option<String>,
@@ -103,6 +104,19 @@ pub struct ListParams {
     pub group_by: Option<String>,
 }
 
+/// Map the `abstract` alias in a `select` list to the API's
+/// `abstract_inverted_index` field.
+fn select_query(select: &str) -> String {
+    let mut fields: Vec<&str> = Vec::new();
+    for field in select.split(',').map(str::trim) {
+        let field = if field == "abstract" { "abstract_inverted_index" } else { field };
+        if !fields.contains(&field) {
+            fields.push(field);
+        }
+    }
+    fields.join(",")
+}
+
 impl ListParams {
     pub(crate) fn to_query_pairs(&self) -> Vec<(&str, String)> {
         let mut pairs = Vec::new();
@@ -131,7 +145,7 @@ impl ListParams {
             pairs.push(("seed", v.to_string()));
         }
         if let Some(v) = &self.select {
-            pairs.push(("select", v.clone()));
+            pairs.push(("select", select_query(v)));
         }
         if let Some(v) = &self.group_by {
             pairs.push(("group_by", v.clone()));
@@ -157,7 +171,8 @@ impl ListParams {
 #[derive(Debug, Default, Clone, bon::Builder)]
 #[builder(on(String, into))]
 pub struct GetParams {
-    /// Comma-separated list of fields to include in the response.
+    /// Comma-separated list of fields to include in the response. `abstract`
+    /// is accepted for works and requested as `abstract_inverted_index`.
     ///
     /// Example: `"id,display_name,cited_by_count"`
     pub select: Option<String>,
@@ -167,7 +182,7 @@ impl GetParams {
     pub(crate) fn to_query_pairs(&self) -> Vec<(&str, String)> {
         let mut pairs = Vec::new();
         if let Some(v) = &self.select {
-            pairs.push(("select", v.clone()));
+            pairs.push(("select", select_query(v)));
         }
         pairs
     }
@@ -277,6 +292,15 @@ mod tests {
         assert!(params.select.is_none());
     }
 
+    #[test]
+    fn test_select_abstract_alias() {
+        let params = GetParams::builder().select("id, abstract,abstract_inverted_index").build();
+        assert_eq!(
+            params.to_query_pairs(),
+            vec![("select", "id,abstract_inverted_index".to_string())]
+        );
+    }
+
     #[test]
     fn test_find_works_params_builder() {
         let params = FindWorksParams::builder()
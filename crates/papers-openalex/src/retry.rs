@@ -0,0 +1,124 @@
+use std::hash::{BuildHasher, RandomState};
+use std::time::Duration;
+
+/// How [`OpenAlexClient`](crate::OpenAlexClient) retries transient failures.
+///
+/// HTTP 429 and the gateway-style 5xx statuses (500, 502, 503, 504) are
+/// retried up to `max_retries` times. A `Retry-After` header (in seconds) is
+/// honored as given; otherwise the delay doubles from `base_delay` on each
+/// retry, capped at `max_delay`, with random jitter so concurrent clients
+/// don't retry in lockstep. When `Retry-After` asks for longer than
+/// `max_delay`, the client gives up at once with
+/// [`OpenAlexError::RateLimited`](crate::OpenAlexError::RateLimited) rather
+/// than block.
+///
+/// ```no_run
+/// use papers_openalex::{OpenAlexClient, RetryPolicy};
+/// use std::time::Duration;
+///
+/// let client = OpenAlexClient::new().with_retry(RetryPolicy {
+///     max_retries: 5,
+///     max_delay: Duration::from_secs(60),
+///     ..RetryPolicy::default()
+/// });
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt; `0` disables retrying.
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled for each one after.
+    pub base_delay: Duration,
+    /// Longest the client will wait before a retry.
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    /// 3 retries, starting at 500 ms, waiting at most 30 s.
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Never retry: every failure is returned as is.
+    pub fn none() -> Self {
+        Self { max_retries: 0, ..Self::default() }
+    }
+
+    /// Whether a response with this status is worth retrying.
+    pub fn is_transient(status: u16) -> bool {
+        matches!(status, 429 | 500 | 502 | 503 | 504)
+    }
+
+    /// How long to wait before retry number `retry` (0-based) of a response
+    /// with `status`, or `None` to give up.
+    pub(crate) fn delay(
+        &self,
+        retry: u32,
+        status: u16,
+        retry_after: Option<Duration>,
+    ) -> Option<Duration> {
+        if retry >= self.max_retries || !Self::is_transient(status) {
+            return None;
+        }
+        if let Some(wait) = retry_after {
+            return (wait <= self.max_delay).then_some(wait);
+        }
+        let backoff = self.base_delay.saturating_mul(2u32.saturating_pow(retry)).min(self.max_delay);
+        // "Equal jitter": half the backoff, plus a random share of the other half.
+        let half = backoff / 2;
+        let nanos = half.as_nanos() as u64;
+        let jitter = if nanos == 0 { 0 } else { RandomState::new().hash_one(retry) % nanos };
+        Some(half + Duration::from_nanos(jitter))
+    }
+}
+
+/// Parse a `Retry-After` header given in seconds. The HTTP-date form is not
+/// used by OpenAlex and is ignored.
+pub(crate) fn parse_retry_after(value: &str) -> Option<Duration> {
+    value.trim().parse().ok().map(Duration::from_secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_doubles_with_jitter_and_caps() {
+        let policy = RetryPolicy {
+            max_retries: 10,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(350),
+        };
+        for (retry, full) in [(0, 100), (1, 200), (2, 350), (5, 350)] {
+            let delay = policy.delay(retry, 503, None).unwrap();
+            let full = Duration::from_millis(full);
+            assert!(delay >= full / 2 && delay <= full, "retry {retry}: {delay:?}");
+        }
+    }
+
+    #[test]
+    fn test_gives_up_on_permanent_errors_and_after_max_retries() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.delay(0, 404, None), None);
+        assert_eq!(policy.delay(0, 501, None), None);
+        assert_eq!(policy.delay(3, 429, None), None);
+        assert_eq!(RetryPolicy::none().delay(0, 429, None), None);
+    }
+
+    #[test]
+    fn test_retry_after_honored_up_to_max_delay() {
+        let policy = RetryPolicy::default();
+        assert_eq!(
+            policy.delay(0, 429, Some(Duration::from_secs(2))),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(policy.delay(0, 429, Some(Duration::from_secs(120))), None);
+        assert_eq!(parse_retry_after(" 7 "), Some(Duration::from_secs(7)));
+        assert_eq!(parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"), None);
+    }
+}
@@ -0,0 +1,617 @@
+//! Offline backend: answer requests from a local OpenAlex snapshot.
+//!
+//! [OpenAlex snapshots](https://docs.openalex.org/download-all-data/openalex-snapshot)
+//! are gzipped JSON Lines files in the S3 layout
+//! `data/<entity>/updated_date=YYYY-MM-DD/part_NNN.gz`. [`Snapshot::build`]
+//! unpacks one into an index directory:
+//!
+//! - `<entity>.jsonl`: every record, uncompressed, one per line
+//! - `<entity>.idx`: `(numeric id, offset, length)` entries sorted by id, so a
+//!   lookup is a binary search over the file plus one read
+//! - `works.doi.idx`: `(DOI hash, numeric id)` entries sorted by hash
+//! - `manifest.json`: record counts, written last so a half-built index is
+//!   never opened
+//!
+//! Point `PAPERS_OPENALEX_SNAPSHOT` at the index directory and
+//! [`OpenAlexClient::new`](crate::OpenAlexClient::new) serves everything from
+//! it without touching the network (see
+//! [`OpenAlexClient::with_snapshot`](crate::OpenAlexClient::with_snapshot)).
+//! Entity lookups by OpenAlex ID or DOI work, and so do list queries filtered
+//! by `ids.openalex` or `doi` (with `sort`, `select` and pagination). Search,
+//! autocomplete, `group_by`, sampling and other filters need the API and fail
+//! with [`OpenAlexError::Offline`].
+
+use std::collections::{BTreeMap, HashSet};
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+use flate2::read::MultiGzDecoder;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::error::{OpenAlexError, Result};
+
+/// Environment variable naming the index directory to serve offline.
+pub const SNAPSHOT_ENV: &str = "PAPERS_OPENALEX_SNAPSHOT";
+
+/// Entities indexed from a snapshot, by their API path (and snapshot directory) name.
+pub const SNAPSHOT_ENTITIES: &[&str] = &[
+    "works",
+    "authors",
+    "sources",
+    "institutions",
+    "topics",
+    "publishers",
+    "funders",
+    "domains",
+    "fields",
+    "subfields",
+];
+
+const MANIFEST: &str = "manifest.json";
+/// `u64` id, `u64` offset, `u32` length.
+const ID_ENTRY: u64 = 20;
+/// `u64` DOI hash, `u64` id.
+const DOI_ENTRY: u64 = 16;
+/// The API's default page size.
+const DEFAULT_PER_PAGE: usize = 25;
+
+/// What an index directory holds.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnapshotManifest {
+    /// The snapshot directory the index was built from.
+    pub source: String,
+    /// Records indexed per entity (distinct IDs).
+    pub entities: BTreeMap<String, u64>,
+}
+
+/// An index directory built by [`Snapshot::build`].
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    dir: PathBuf,
+}
+
+/// The fields of a record needed to index it.
+#[derive(Deserialize)]
+struct Head {
+    id: String,
+    #[serde(default)]
+    doi: Option<String>,
+}
+
+impl Snapshot {
+    /// Serve from the index in `dir`. Nothing is read until the first lookup.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// The index named by `PAPERS_OPENALEX_SNAPSHOT`, if set.
+    pub fn from_env() -> Option<Self> {
+        std::env::var_os(SNAPSHOT_ENV).filter(|v| !v.is_empty()).map(Self::new)
+    }
+
+    /// The index directory.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Read the manifest; fails if the index was never (completely) built.
+    pub fn manifest(&self) -> io::Result<SnapshotManifest> {
+        let text = fs::read_to_string(self.dir.join(MANIFEST)).map_err(|e| {
+            io::Error::new(e.kind(), format!("no snapshot index in {}: {e}", self.dir.display()))
+        })?;
+        serde_json::from_str(&text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// Index the snapshot at `source` (the directory holding `data/`) into
+    /// `dir`, replacing any index already there.
+    ///
+    /// Partitions are read oldest first, so when a record appears in several,
+    /// the newest version wins. Entities missing from the snapshot are skipped.
+    pub fn build(source: &Path, dir: &Path) -> io::Result<SnapshotManifest> {
+        fs::create_dir_all(dir)?;
+        let _ = fs::remove_file(dir.join(MANIFEST));
+        let mut manifest = SnapshotManifest { source: source.display().to_string(), ..Default::default() };
+        for entity in SNAPSHOT_ENTITIES {
+            let files = partition_files(&source.join("data").join(entity))?;
+            if files.is_empty() {
+                continue;
+            }
+            let count = build_entity(entity, &files, dir)?;
+            manifest.entities.insert(entity.to_string(), count);
+        }
+        if manifest.entities.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("no OpenAlex snapshot under {}", source.join("data").display()),
+            ));
+        }
+        let json = serde_json::to_string_pretty(&manifest).map_err(io::Error::other)?;
+        fs::write(dir.join(MANIFEST), json)?;
+        Ok(manifest)
+    }
+
+    /// The record of `entity` with `id`: an OpenAlex ID (`W2741809807`, full
+    /// URL, or `domains/1`-style) or, for works, a DOI.
+    pub fn get(&self, entity: &str, id: &str) -> Result<Option<Value>> {
+        if let Some(doi) = as_doi(id) {
+            if entity != "works" {
+                return Err(OpenAlexError::Offline(format!("looking up {entity} by DOI")));
+            }
+            return self.get_by_doi(&doi);
+        }
+        let num = numeric_id(id)
+            .ok_or_else(|| OpenAlexError::Offline(format!("looking up {entity} by `{id}`")))?;
+        self.record(entity, num)
+    }
+
+    /// The work with `doi` (with or without a `https://doi.org/` prefix).
+    pub fn get_by_doi(&self, doi: &str) -> Result<Option<Value>> {
+        let doi = normalize_doi(doi);
+        let mut idx = self.open_index("works.doi.idx")?;
+        let hash = doi_hash(&doi);
+        let (mut i, n) = lower_bound(&mut idx, DOI_ENTRY, hash)?;
+        let mut entry = [0u8; DOI_ENTRY as usize];
+        while i < n {
+            read_entry(&mut idx, DOI_ENTRY, i, &mut entry)?;
+            if u64_at(&entry, 0) != hash {
+                break;
+            }
+            // Hashes can collide, so check the record's own DOI.
+            if let Some(work) = self.record("works", u64_at(&entry, 8))?
+                && work.get("doi").and_then(Value::as_str).map(normalize_doi).as_deref() == Some(&doi)
+            {
+                return Ok(Some(work));
+            }
+            i += 1;
+        }
+        Ok(None)
+    }
+
+    fn open_index(&self, name: &str) -> Result<File> {
+        File::open(self.dir.join(name)).map_err(|e| match e.kind() {
+            io::ErrorKind::NotFound => OpenAlexError::Offline(format!(
+                "{name} is missing from the snapshot index in {}",
+                self.dir.display()
+            )),
+            _ => e.into(),
+        })
+    }
+
+    fn record(&self, entity: &str, num: u64) -> Result<Option<Value>> {
+        let mut idx = self.open_index(&format!("{entity}.idx"))?;
+        let (i, n) = lower_bound(&mut idx, ID_ENTRY, num)?;
+        if i == n {
+            return Ok(None);
+        }
+        let mut entry = [0u8; ID_ENTRY as usize];
+        read_entry(&mut idx, ID_ENTRY, i, &mut entry)?;
+        if u64_at(&entry, 0) != num {
+            return Ok(None);
+        }
+        let (offset, len) = (u64_at(&entry, 8), u32::from_le_bytes(entry[16..20].try_into().unwrap()));
+        let mut data = self.open_index(&format!("{entity}.jsonl"))?;
+        data.seek(SeekFrom::Start(offset))?;
+        let mut buf = vec![0u8; len as usize];
+        data.read_exact(&mut buf)?;
+        Ok(Some(serde_json::from_slice(&buf)?))
+    }
+
+    /// Answer `GET {path}?{query}` as the API would, or fail with
+    /// [`OpenAlexError::Offline`] for requests the snapshot can't answer.
+    pub(crate) fn serve(&self, path: &str, query: &[(&str, String)]) -> Result<Value> {
+        let (entity, id) = match path.trim_start_matches('/').split_once('/') {
+            Some((entity, id)) => (entity, Some(id)),
+            None => (path.trim_start_matches('/'), None),
+        };
+        if !SNAPSHOT_ENTITIES.contains(&entity) {
+            return Err(OpenAlexError::Offline(format!("GET {path}")));
+        }
+        let Some(id) = id else {
+            return self.list(entity, query);
+        };
+        match self.get(entity, id)? {
+            Some(record) => Ok(project(record, query_param(query, "select"))),
+            None => Err(OpenAlexError::Api {
+                status: 404,
+                message: format!("{id} is not in the offline OpenAlex snapshot"),
+            }),
+        }
+    }
+
+    fn list(&self, entity: &str, query: &[(&str, String)]) -> Result<Value> {
+        let param = |name| query_param(query, name);
+        for unsupported in ["search", "group_by", "sample"] {
+            if param(unsupported).is_some() {
+                return Err(OpenAlexError::Offline(format!("`{unsupported}` on {entity}")));
+            }
+        }
+        let clauses = param("filter")
+            .unwrap_or("")
+            .split(',')
+            .filter(|c| !c.is_empty())
+            .map(Clause::parse)
+            .collect::<Result<Vec<_>>>()?;
+        let Some((first, rest)) = clauses.split_first() else {
+            return Err(OpenAlexError::Offline(format!(
+                "listing {entity} without an `ids.openalex` or `doi` filter"
+            )));
+        };
+
+        let mut seen = HashSet::new();
+        let mut records = Vec::new();
+        for value in &first.values {
+            let record = match first.kind {
+                ClauseKind::Id => numeric_id(value).map(|n| self.record(entity, n)).transpose()?.flatten(),
+                ClauseKind::Doi => self.get(entity, &format!("doi:{value}"))?,
+            };
+            if let Some(record) = record
+                && seen.insert(record.get("id").and_then(Value::as_str).unwrap_or_default().to_string())
+            {
+                records.push(record);
+            }
+        }
+        records.retain(|r| rest.iter().all(|c| c.matches(r)));
+        if let Some(sort) = param("sort") {
+            sort_records(&mut records, sort);
+        }
+
+        let per_page = param("per-page").and_then(|v| v.parse().ok()).unwrap_or(DEFAULT_PER_PAGE).max(1);
+        let cursor = param("cursor");
+        let page = match cursor {
+            Some("*") => 1,
+            Some(c) => c.parse().map_err(|_| OpenAlexError::Api {
+                status: 400,
+                message: format!("invalid cursor `{c}`"),
+            })?,
+            None => param("page").and_then(|v| v.parse().ok()).unwrap_or(1usize).max(1),
+        };
+        let count = records.len();
+        let select = param("select");
+        let results: Vec<Value> = records
+            .into_iter()
+            .skip((page - 1) * per_page)
+            .take(per_page)
+            .map(|r| project(r, select))
+            .collect();
+        let next_cursor = (cursor.is_some() && page * per_page < count).then(|| (page + 1).to_string());
+        Ok(serde_json::json!({
+            "meta": {
+                "count": count,
+                "db_response_time_ms": 0,
+                "page": if cursor.is_some() { None } else { Some(page) },
+                "per_page": per_page,
+                "next_cursor": next_cursor,
+                "groups_count": null,
+            },
+            "results": results,
+            "group_by": [],
+        }))
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ClauseKind {
+    Id,
+    Doi,
+}
+
+/// One `key:v1|v2` filter clause the snapshot can answer.
+struct Clause {
+    kind: ClauseKind,
+    values: Vec<String>,
+}
+
+impl Clause {
+    fn parse(clause: &str) -> Result<Self> {
+        let unsupported = || OpenAlexError::Offline(format!("filter `{clause}`"));
+        let (key, values) = clause.split_once(':').ok_or_else(unsupported)?;
+        let kind = match key {
+            "openalex" | "openalex_id" | "ids.openalex" | "id" => ClauseKind::Id,
+            "doi" | "ids.doi" => ClauseKind::Doi,
+            _ => return Err(unsupported()),
+        };
+        if values.starts_with('!') {
+            return Err(unsupported());
+        }
+        let values = values.split('|').map(|v| v.trim().to_string()).filter(|v| !v.is_empty()).collect();
+        Ok(Self { kind, values })
+    }
+
+    fn matches(&self, record: &Value) -> bool {
+        let field = |name| record.get(name).and_then(Value::as_str);
+        match self.kind {
+            ClauseKind::Id => {
+                let id = field("id").and_then(numeric_id);
+                self.values.iter().any(|v| id.is_some() && numeric_id(v) == id)
+            }
+            ClauseKind::Doi => {
+                let doi = field("doi").map(normalize_doi);
+                self.values.iter().any(|v| doi.as_deref() == Some(normalize_doi(v).as_str()))
+            }
+        }
+    }
+}
+
+/// The data files of one entity, oldest partition first.
+fn partition_files(entity_dir: &Path) -> io::Result<Vec<PathBuf>> {
+    if !entity_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let sorted = |dir: &Path| -> io::Result<Vec<PathBuf>> {
+        let mut paths: Vec<PathBuf> = fs::read_dir(dir)?.map(|e| e.map(|e| e.path())).collect::<io::Result<_>>()?;
+        paths.sort();
+        Ok(paths)
+    };
+    let mut files = Vec::new();
+    for partition in sorted(entity_dir)? {
+        if !partition.is_dir() {
+            continue;
+        }
+        files.extend(sorted(&partition)?.into_iter().filter(|f| f.extension().is_some_and(|e| e == "gz")));
+    }
+    Ok(files)
+}
+
+/// Write `{entity}.jsonl` and its indexes; returns the number of distinct records.
+fn build_entity(entity: &str, files: &[PathBuf], dir: &Path) -> io::Result<u64> {
+    let mut data = BufWriter::new(File::create(dir.join(format!("{entity}.jsonl")))?);
+    let mut ids: Vec<(u64, u64, u32)> = Vec::new();
+    let mut dois: Vec<(u64, u64)> = Vec::new();
+    let mut offset = 0u64;
+    for file in files {
+        for line in BufReader::new(MultiGzDecoder::new(File::open(file)?)).lines() {
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let head: Head = serde_json::from_str(line).map_err(|e| {
+                io::Error::new(io::ErrorKind::InvalidData, format!("{}: {e}", file.display()))
+            })?;
+            let Some(num) = numeric_id(&head.id) else { continue };
+            data.write_all(line.as_bytes())?;
+            data.write_all(b"\n")?;
+            ids.push((num, offset, line.len() as u32));
+            if let Some(doi) = head.doi.as_deref() {
+                dois.push((doi_hash(&normalize_doi(doi)), num));
+            }
+            offset += line.len() as u64 + 1;
+        }
+    }
+    data.flush()?;
+
+    // Newest last: reverse, so the stable sort puts it first and dedup keeps it.
+    ids.reverse();
+    ids.sort_by_key(|e| e.0);
+    ids.dedup_by_key(|e| e.0);
+    let mut idx = BufWriter::new(File::create(dir.join(format!("{entity}.idx")))?);
+    for (num, offset, len) in &ids {
+        idx.write_all(&num.to_le_bytes())?;
+        idx.write_all(&offset.to_le_bytes())?;
+        idx.write_all(&len.to_le_bytes())?;
+    }
+    idx.flush()?;
+
+    if entity == "works" {
+        dois.sort_unstable();
+        dois.dedup();
+        let mut idx = BufWriter::new(File::create(dir.join("works.doi.idx"))?);
+        for (hash, num) in &dois {
+            idx.write_all(&hash.to_le_bytes())?;
+            idx.write_all(&num.to_le_bytes())?;
+        }
+        idx.flush()?;
+    }
+    Ok(ids.len() as u64)
+}
+
+/// Index of the first entry whose key is `>= key`, and the number of entries.
+fn lower_bound(file: &mut File, entry: u64, key: u64) -> io::Result<(u64, u64)> {
+    let n = file.metadata()?.len() / entry;
+    let (mut lo, mut hi) = (0, n);
+    let mut buf = [0u8; 8];
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        file.seek(SeekFrom::Start(mid * entry))?;
+        file.read_exact(&mut buf)?;
+        if u64::from_le_bytes(buf) < key {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    Ok((lo, n))
+}
+
+fn read_entry(file: &mut File, entry: u64, i: u64, buf: &mut [u8]) -> io::Result<()> {
+    file.seek(SeekFrom::Start(i * entry))?;
+    file.read_exact(buf)
+}
+
+fn u64_at(buf: &[u8], at: usize) -> u64 {
+    u64::from_le_bytes(buf[at..at + 8].try_into().unwrap())
+}
+
+/// The number in an OpenAlex ID: `W2741809807`, `https://openalex.org/A5023888391`,
+/// `domains/1`. `None` for external IDs (ORCID, ROR, PMID, ...).
+fn numeric_id(id: &str) -> Option<u64> {
+    let last = id.trim().trim_end_matches('/').rsplit('/').next()?;
+    last.trim_start_matches(|c: char| c.is_ascii_alphabetic()).parse().ok()
+}
+
+/// The DOI in an ID given as `doi:10...`, `https://doi.org/10...`, or a bare `10...`.
+fn as_doi(id: &str) -> Option<String> {
+    let id = id.trim();
+    let lower = id.to_ascii_lowercase();
+    let is_doi = lower.starts_with("doi:") || lower.contains("doi.org/") || lower.starts_with("10.");
+    is_doi.then(|| normalize_doi(id))
+}
+
+fn normalize_doi(doi: &str) -> String {
+    let lower = doi.trim().to_lowercase();
+    let bare = match lower.find("doi.org/") {
+        Some(i) => &lower[i + "doi.org/".len()..],
+        None => lower.strip_prefix("doi:").unwrap_or(&lower),
+    };
+    bare.to_string()
+}
+
+/// FNV-1a: stable across runs, unlike the std hasher.
+fn doi_hash(doi: &str) -> u64 {
+    doi.bytes().fold(0xcbf29ce484222325, |h, b| (h ^ u64::from(b)).wrapping_mul(0x100000001b3))
+}
+
+fn query_param<'a>(query: &'a [(&str, String)], name: &str) -> Option<&'a str> {
+    query.iter().find(|(k, _)| *k == name).map(|(_, v)| v.as_str())
+}
+
+/// Keep only the comma-separated top-level fields in `select`.
+fn project(record: Value, select: Option<&str>) -> Value {
+    match (select, record) {
+        (Some(select), Value::Object(mut fields)) => {
+            Value::Object(select.split(',').filter_map(|name| fields.remove_entry(name.trim())).collect())
+        }
+        (_, record) => record,
+    }
+}
+
+/// Sort by `field[:desc]` keys, comma-separated; missing values sort last.
+fn sort_records(records: &mut [Value], sort: &str) {
+    let keys: Vec<(&str, bool)> = sort
+        .split(',')
+        .map(|k| match k.split_once(':') {
+            Some((field, dir)) => (field, dir == "desc"),
+            None => (k, false),
+        })
+        .collect();
+    records.sort_by(|a, b| {
+        keys.iter()
+            .map(|&(field, desc)| {
+                use std::cmp::Ordering;
+                match (a.get(field).filter(|v| !v.is_null()), b.get(field).filter(|v| !v.is_null())) {
+                    (None, None) => Ordering::Equal,
+                    (None, Some(_)) => Ordering::Greater,
+                    (Some(_), None) => Ordering::Less,
+                    (Some(x), Some(y)) => {
+                        let ord = match (x.as_f64(), y.as_f64()) {
+                            (Some(x), Some(y)) => x.total_cmp(&y),
+                            _ => x.as_str().unwrap_or_default().cmp(y.as_str().unwrap_or_default()),
+                        };
+                        if desc { ord.reverse() } else { ord }
+                    }
+                }
+            })
+            .find(|o| o.is_ne())
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GetParams, ListParams, OpenAlexClient};
+    use flate2::Compression;
+    use flate2::write::GzEncoder;
+
+    fn write_part(source: &Path, entity: &str, date: &str, records: &[Value]) {
+        let dir = source.join("data").join(entity).join(format!("updated_date={date}"));
+        fs::create_dir_all(&dir).unwrap();
+        let mut gz = GzEncoder::new(File::create(dir.join("part_000.gz")).unwrap(), Compression::fast());
+        for r in records {
+            writeln!(gz, "{r}").unwrap();
+        }
+        gz.finish().unwrap();
+    }
+
+    fn work(id: u64, doi: &str, cites: u64, title: &str) -> Value {
+        serde_json::json!({
+            "id": format!("https://openalex.org/W{id}"),
+            "doi": format!("https://doi.org/{doi}"),
+            "display_name": title,
+            "cited_by_count": cites,
+        })
+    }
+
+    /// A snapshot with three works, one of them updated in a later partition.
+    fn build_fixture() -> (tempfile::TempDir, Snapshot) {
+        let tmp = tempfile::tempdir().unwrap();
+        let source = tmp.path().join("openalex");
+        write_part(&source, "works", "2024-01-01", &[
+            work(3, "10.1/c", 5, "Third"),
+            work(1, "10.1/a", 10, "First (old)"),
+        ]);
+        write_part(&source, "works", "2024-02-01", &[
+            work(1, "10.1/A", 12, "First"),
+            work(2, "10.1/b", 40, "Second"),
+        ]);
+        write_part(&source, "domains", "2024-01-01", &[
+            serde_json::json!({"id": "https://openalex.org/domains/1", "display_name": "Life Sciences"}),
+        ]);
+        let index = tmp.path().join("index");
+        Snapshot::build(&source, &index).unwrap();
+        (tmp, Snapshot::new(index))
+    }
+
+    #[test]
+    fn test_build_keeps_newest_version() {
+        let (_tmp, snapshot) = build_fixture();
+        let manifest = snapshot.manifest().unwrap();
+        assert_eq!(manifest.entities["works"], 3);
+        assert_eq!(manifest.entities["domains"], 1);
+        assert!(!manifest.entities.contains_key("authors"));
+
+        let w1 = snapshot.get("works", "W1").unwrap().unwrap();
+        assert_eq!(w1["display_name"], "First");
+        let by_doi = snapshot.get("works", "https://doi.org/10.1/a").unwrap().unwrap();
+        assert_eq!(by_doi["id"], "https://openalex.org/W1");
+        assert_eq!(snapshot.get("domains", "https://openalex.org/domains/1").unwrap().unwrap()["display_name"], "Life Sciences");
+        assert!(snapshot.get("works", "W99").unwrap().is_none());
+        assert!(matches!(snapshot.get("authors", "orcid:0000-0001-2345-6789"), Err(OpenAlexError::Offline(_))));
+    }
+
+    #[test]
+    fn test_id_and_doi_parsing() {
+        assert_eq!(numeric_id("https://openalex.org/W2741809807"), Some(2741809807));
+        assert_eq!(numeric_id("a5023888391"), Some(5023888391));
+        assert_eq!(numeric_id("https://openalex.org/subfields/1702"), Some(1702));
+        assert_eq!(numeric_id("https://ror.org/02mhbdp94"), None);
+        assert_eq!(as_doi("DOI:10.1/X").as_deref(), Some("10.1/x"));
+        assert_eq!(as_doi("https://dx.doi.org/10.1/x").as_deref(), Some("10.1/x"));
+        assert_eq!(as_doi("W1"), None);
+    }
+
+    #[tokio::test]
+    async fn test_client_serves_from_snapshot() {
+        let (_tmp, snapshot) = build_fixture();
+        // An unroutable base URL: any network request would fail.
+        let client = OpenAlexClient::new().with_base_url("http://127.0.0.1:9").with_snapshot(snapshot);
+
+        let work = client.get_work("doi:10.1/b", &GetParams::default()).await.unwrap();
+        assert_eq!(work.display_name.as_deref(), Some("Second"));
+        let missing = client.get_work("W99", &GetParams::default()).await;
+        assert!(matches!(missing, Err(OpenAlexError::Api { status: 404, .. })));
+
+        let params = ListParams {
+            filter: Some("ids.openalex:W1|W2|W3|W99".into()),
+            sort: Some("cited_by_count:desc".into()),
+            select: Some("id,cited_by_count".into()),
+            per_page: Some(2),
+            ..Default::default()
+        };
+        let page = client.list_works(&params).await.unwrap();
+        assert_eq!(page.meta.count, 3);
+        let ids: Vec<_> = page.results.iter().map(|w| w.id.as_str()).collect();
+        assert_eq!(ids, ["https://openalex.org/W2", "https://openalex.org/W1"]);
+        assert!(page.results[0].display_name.is_none());
+
+        use futures::TryStreamExt;
+        let all: Vec<_> = client.list_all_works(&params).try_collect().await.unwrap();
+        assert_eq!(all.len(), 3);
+
+        let search = ListParams { search: Some("graphs".into()), ..Default::default() };
+        assert!(matches!(client.list_works(&search).await, Err(OpenAlexError::Offline(_))));
+        assert!(matches!(client.autocomplete_works("gra").await, Err(OpenAlexError::Offline(_))));
+    }
+}
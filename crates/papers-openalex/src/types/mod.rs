@@ -11,7 +11,7 @@ pub mod field;
 pub mod subfield;
 
 pub use common::*;
-pub use work::Work;
+pub use work::{Work, reconstruct_abstract};
 pub use author::Author;
 pub use source::Source;
 pub use institution::Institution;
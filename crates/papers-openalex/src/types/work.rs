@@ -52,19 +52,39 @@ fn replace_tag(text: &str, tag: &str, latex_op: char) -> String {
     result
 }
 
+/// Rebuild plain text from an OpenAlex `abstract_inverted_index` (word →
+/// positions), converting `<sup>`/`<sub>` markup to inline LaTeX.
+///
+/// ```
+/// use std::collections::HashMap;
+/// let index = HashMap::from([("world".to_string(), vec![1]), ("Hello".to_string(), vec![0])]);
+/// assert_eq!(papers_openalex::reconstruct_abstract(&index), "Hello world");
+/// ```
+pub fn reconstruct_abstract(index: &HashMap<String, Vec<u32>>) -> String {
+    let mut positions: Vec<(u32, &str)> = index
+        .iter()
+        .flat_map(|(word, pos)| pos.iter().map(move |p| (*p, word.as_str())))
+        .collect();
+    positions.sort_by_key(|(p, _)| *p);
+    let raw = positions.into_iter().map(|(_, w)| w).collect::<Vec<_>>().join(" ");
+    convert_math_tags(raw)
+}
+
+/// Accepts the API's inverted index, or the plain string this crate
+/// serializes, so a `Work` survives a JSON round trip.
 fn deserialize_abstract<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
 where
     D: Deserializer<'de>,
 {
-    let map: Option<HashMap<String, Vec<u32>>> = Option::deserialize(deserializer)?;
-    Ok(map.map(|index| {
-        let mut positions: Vec<(u32, String)> = index
-            .into_iter()
-            .flat_map(|(word, pos)| pos.into_iter().map(move |p| (p, word.clone())))
-            .collect();
-        positions.sort_by_key(|(p, _)| *p);
-        let raw = positions.into_iter().map(|(_, w)| w).collect::<Vec<_>>().join(" ");
-        convert_math_tags(raw)
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum AbstractRepr {
+        Index(HashMap<String, Vec<u32>>),
+        Text(String),
+    }
+    Ok(Option::<AbstractRepr>::deserialize(deserializer)?.map(|repr| match repr {
+        AbstractRepr::Index(index) => reconstruct_abstract(&index),
+        AbstractRepr::Text(text) => text,
     }))
 }
 
@@ -100,7 +120,9 @@ where
 ///
 /// [`abstract_text`](Work::abstract_text) contains the reconstructed abstract
 /// as a plain string. OpenAlex stores abstracts as an inverted index internally;
-/// this crate converts that to readable text automatically on deserialization.
+/// this crate converts that to readable text automatically on deserialization
+/// (see [`reconstruct_abstract`]) and serializes it as a plain `abstract`
+/// field. Request it with `select=abstract` (or `abstract_inverted_index`).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Work {
     /// OpenAlex ID URI (e.g. `"https://openalex.org/W2741809807"`).
@@ -190,6 +212,9 @@ pub struct Work {
     /// Total number of times this work has been cited.
     pub cited_by_count: Option<i64>,
 
+    /// Relevance to the query; only set on list results with a `search`.
+    pub relevance_score: Option<f64>,
+
     /// Citation percentile ranking relative to works published in the same
     /// year.
     pub citation_normalized_percentile: Option<CitationPercentile>,
@@ -252,7 +277,13 @@ pub struct Work {
     pub related_works: Option<Vec<String>>,
 
     /// Abstract text, reconstructed from the OpenAlex inverted index format.
-    #[serde(rename = "abstract_inverted_index", deserialize_with = "deserialize_abstract", default)]
+    /// Serialized as `abstract`.
+    #[serde(
+        rename(serialize = "abstract", deserialize = "abstract_inverted_index"),
+        alias = "abstract",
+        deserialize_with = "deserialize_abstract",
+        default
+    )]
     pub abstract_text: Option<String>,
 
     /// Citation and publication counts broken down by year.
@@ -314,6 +345,18 @@ mod tests {
         assert!(work.abstract_text.is_none());
     }
 
+    #[test]
+    fn test_abstract_serialized_as_plain_text_and_round_trips() {
+        let json = r#"{"id":"https://openalex.org/W1","abstract_inverted_index":{"world.":[1],"Hello":[0]}}"#;
+        let work: Work = serde_json::from_str(json).unwrap();
+        let value = serde_json::to_value(&work).unwrap();
+        assert_eq!(value["abstract"], "Hello world.");
+        assert!(value.get("abstract_inverted_index").is_none());
+
+        let back: Work = serde_json::from_value(value).unwrap();
+        assert_eq!(back.abstract_text.as_deref(), Some("Hello world."));
+    }
+
     #[test]
     fn test_convert_math_tags() {
         // sup: "n <sup ...>2</sup>" → "n$^{2}$"
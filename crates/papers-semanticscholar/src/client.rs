@@ -0,0 +1,218 @@
+use crate::error::{Result, SemanticScholarError};
+use crate::types::{Recommendations, S2Paper};
+
+const DEFAULT_BASE_URL: &str = "https://api.semanticscholar.org";
+
+/// Fields requested by [`SemanticScholarClient::get_paper`].
+pub const PAPER_FIELDS: &str =
+    "paperId,externalIds,title,year,url,citationCount,influentialCitationCount,tldr";
+
+/// Fields requested by [`SemanticScholarClient::recommendations`]; the
+/// recommendations API doesn't serve TLDRs.
+pub const RECOMMENDATION_FIELDS: &str = "paperId,externalIds,title,year,url,citationCount";
+
+/// Most recommendations the API returns for one paper.
+pub const MAX_RECOMMENDATIONS: u32 = 500;
+
+/// Async client for the Semantic Scholar Academic Graph and Recommendations APIs.
+///
+/// An API key is optional. When one is configured (see
+/// [`SemanticScholarClient::new`]) it is sent in the `x-api-key` header.
+///
+/// ```no_run
+/// # async fn example() -> papers_semanticscholar::Result<()> {
+/// use papers_semanticscholar::SemanticScholarClient;
+///
+/// let client = SemanticScholarClient::new().with_api_key("your-key");
+/// let paper = client.get_paper("DOI:10.1038/nature12373").await?;
+/// println!("{:?}", paper.influential_citation_count);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct SemanticScholarClient {
+    http: reqwest::Client,
+    base_url: String,
+    api_key: Option<String>,
+}
+
+impl Default for SemanticScholarClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SemanticScholarClient {
+    /// Create a new client, reading the API key from the
+    /// `SEMANTIC_SCHOLAR_API_KEY` environment variable.
+    pub fn new() -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            api_key: std::env::var("SEMANTIC_SCHOLAR_API_KEY").ok().filter(|k| !k.trim().is_empty()),
+        }
+    }
+
+    /// Send `key` with every request instead of sharing the keyless rate limit.
+    pub fn with_api_key(mut self, key: impl Into<String>) -> Self {
+        self.api_key = Some(key.into());
+        self
+    }
+
+    /// Override the base URL. Useful for testing with a mock server.
+    pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = url.into();
+        self
+    }
+
+    /// GET /graph/v1/paper/{id} — one paper with its TLDR and citation counts.
+    ///
+    /// `id` is a Semantic Scholar paper ID or a prefixed external ID such as
+    /// `DOI:10.1038/nature12373`, `ARXIV:1706.03762` or `PMID:19872477`.
+    /// Returns [`SemanticScholarError::NotFound`] if Semantic Scholar has no record of it.
+    pub async fn get_paper(&self, id: &str) -> Result<S2Paper> {
+        let url = format!("{}/graph/v1/paper/{}", self.base_url, encode_id(id));
+        self.get_json(&url, id, &[("fields", PAPER_FIELDS)]).await
+    }
+
+    /// GET /recommendations/v1/papers/forpaper/{id} — up to `limit` papers
+    /// similar to `id` by Semantic Scholar's SPECTER embeddings.
+    ///
+    /// `limit` is capped at [`MAX_RECOMMENDATIONS`].
+    pub async fn recommendations(&self, id: &str, limit: u32) -> Result<Vec<S2Paper>> {
+        let url = format!("{}/recommendations/v1/papers/forpaper/{}", self.base_url, encode_id(id));
+        let limit = limit.min(MAX_RECOMMENDATIONS).to_string();
+        let response: Recommendations =
+            self.get_json(&url, id, &[("fields", RECOMMENDATION_FIELDS), ("limit", &limit)]).await?;
+        Ok(response.recommended_papers)
+    }
+
+    async fn get_json<T: serde::de::DeserializeOwned>(
+        &self,
+        url: &str,
+        id: &str,
+        query: &[(&str, &str)],
+    ) -> Result<T> {
+        let mut req = self.http.get(url).query(query);
+        if let Some(key) = &self.api_key {
+            req = req.header("x-api-key", key);
+        }
+        let resp = req.send().await?;
+        let status = resp.status();
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Err(SemanticScholarError::NotFound(id.to_string()));
+        }
+        if !status.is_success() {
+            let message = resp.text().await.unwrap_or_default();
+            return Err(SemanticScholarError::Api { status: status.as_u16(), message });
+        }
+        let text = resp.text().await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+}
+
+/// Percent-encode a paper ID for use in a URL path. `/` and `:` are left
+/// alone since DOIs and ID prefixes are expected unescaped.
+fn encode_id(id: &str) -> String {
+    let mut out = String::with_capacity(id.len());
+    for b in id.trim().bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' | b':' => {
+                out.push(b as char)
+            }
+            _ => out.push_str(&format!("%{b:02X}")),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn paper_json() -> serde_json::Value {
+        serde_json::json!({
+            "paperId": "204e3073870fae3d05bcbc2f6a8e263d9b72e776",
+            "externalIds": {"DOI": "10.48550/arXiv.1706.03762", "ArXiv": "1706.03762", "CorpusId": 13756489},
+            "title": "Attention is All you Need",
+            "year": 2017,
+            "url": "https://www.semanticscholar.org/paper/204e3073870fae3d05bcbc2f6a8e263d9b72e776",
+            "citationCount": 120000,
+            "influentialCitationCount": 15000,
+            "tldr": {"model": "tldr@v2.0.0", "text": "A new simple network architecture, the Transformer, based solely on attention mechanisms."}
+        })
+    }
+
+    #[tokio::test]
+    async fn test_get_paper() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/graph/v1/paper/DOI:10.48550/arXiv.1706.03762"))
+            .and(query_param("fields", PAPER_FIELDS))
+            .respond_with(ResponseTemplate::new(200).set_body_json(paper_json()))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = SemanticScholarClient::new().with_base_url(server.uri());
+        let paper = client.get_paper("DOI:10.48550/arXiv.1706.03762").await.unwrap();
+        assert_eq!(paper.year, Some(2017));
+        assert_eq!(paper.influential_citation_count, Some(15000));
+        assert_eq!(paper.external_ids.unwrap().corpus_id, Some(13756489));
+        assert!(paper.tldr.unwrap().text.starts_with("A new simple network"));
+    }
+
+    #[tokio::test]
+    async fn test_get_paper_sends_api_key() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(header("x-api-key", "secret"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(paper_json()))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = SemanticScholarClient::new().with_base_url(server.uri()).with_api_key("secret");
+        client.get_paper("204e3073870fae3d05bcbc2f6a8e263d9b72e776").await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_get_paper_not_found() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({"error": "Paper not found"})))
+            .mount(&server)
+            .await;
+        let client = SemanticScholarClient::new().with_base_url(server.uri());
+        let err = client.get_paper("DOI:10.9999/missing").await.unwrap_err();
+        assert!(matches!(err, SemanticScholarError::NotFound(id) if id == "DOI:10.9999/missing"));
+    }
+
+    #[tokio::test]
+    async fn test_recommendations() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/recommendations/v1/papers/forpaper/204e3073870fae3d05bcbc2f6a8e263d9b72e776"))
+            .and(query_param("limit", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "recommendedPapers": [
+                    {"paperId": "a1", "title": "BERT", "year": 2019, "externalIds": {"DOI": "10.18653/v1/N19-1423"}},
+                    {"paperId": "b2", "title": "No DOI", "year": null, "citationCount": 3}
+                ]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = SemanticScholarClient::new().with_base_url(server.uri());
+        let papers = client.recommendations("204e3073870fae3d05bcbc2f6a8e263d9b72e776", 2).await.unwrap();
+        assert_eq!(papers.len(), 2);
+        assert_eq!(papers[0].external_ids.as_ref().unwrap().doi.as_deref(), Some("10.18653/v1/N19-1423"));
+        assert!(papers[1].external_ids.is_none() && papers[1].tldr.is_none());
+    }
+
+    #[test]
+    fn test_encode_id() {
+        assert_eq!(encode_id("DOI:10.1002/(SICI)1097-4571"), "DOI:10.1002/%28SICI%291097-4571");
+        assert_eq!(encode_id(" ARXIV:1706.03762 "), "ARXIV:1706.03762");
+    }
+}
@@ -0,0 +1,18 @@
+/// Errors returned by [`SemanticScholarClient`](crate::SemanticScholarClient) methods.
+#[derive(thiserror::Error, Debug)]
+pub enum SemanticScholarError {
+    #[error("HTTP error: {0}")]
+    Http(#[from] reqwest::Error),
+
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("API error ({status}): {message}")]
+    Api { status: u16, message: String },
+
+    #[error("paper {0} not found in Semantic Scholar")]
+    NotFound(String),
+}
+
+/// A [`Result`](std::result::Result) alias with [`SemanticScholarError`] as the error type.
+pub type Result<T> = std::result::Result<T, SemanticScholarError>;
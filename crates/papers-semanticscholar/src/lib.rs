@@ -0,0 +1,34 @@
+//! Async Rust client for the [Semantic Scholar](https://www.semanticscholar.org)
+//! Academic Graph and Recommendations APIs.
+//!
+//! Semantic Scholar adds a few signals OpenAlex doesn't have: a one-sentence
+//! machine-generated TLDR, a count of *influential* citations (citing papers
+//! that build substantially on the work), and embedding-based recommendations.
+//!
+//! # Quick start
+//!
+//! ```no_run
+//! # async fn example() -> papers_semanticscholar::Result<()> {
+//! use papers_semanticscholar::SemanticScholarClient;
+//!
+//! let client = SemanticScholarClient::new();
+//! let paper = client.get_paper("DOI:10.48550/arXiv.1706.03762").await?;
+//! println!("{:?}: {:?}", paper.title, paper.tldr.map(|t| t.text));
+//! let related = client.recommendations(&paper.paper_id, 5).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # API key
+//!
+//! The API works without a key at a low shared rate limit. Set the
+//! `SEMANTIC_SCHOLAR_API_KEY` environment variable (or call
+//! [`SemanticScholarClient::with_api_key`]) to use your own quota.
+
+pub mod client;
+pub mod error;
+pub mod types;
+
+pub use client::SemanticScholarClient;
+pub use error::{Result, SemanticScholarError};
+pub use types::{ExternalIds, S2Paper, Tldr};
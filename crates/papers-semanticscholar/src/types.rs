@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+/// A paper from `GET /graph/v1/paper/{id}` or the recommendations API.
+///
+/// Only the fields requested by [`PAPER_FIELDS`](crate::client::PAPER_FIELDS)
+/// are modeled; all but `paperId` may be absent or null.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct S2Paper {
+    /// Semantic Scholar's 40-character paper ID.
+    pub paper_id: String,
+
+    #[serde(default)]
+    pub external_ids: Option<ExternalIds>,
+
+    pub title: Option<String>,
+
+    pub year: Option<u16>,
+
+    /// Paper page on semanticscholar.org.
+    pub url: Option<String>,
+
+    pub citation_count: Option<u64>,
+
+    /// Citing papers Semantic Scholar judges to build substantially on this one.
+    pub influential_citation_count: Option<u64>,
+
+    /// One-sentence machine-generated summary; only available for some papers.
+    pub tldr: Option<Tldr>,
+}
+
+/// Identifiers of a paper in other databases.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExternalIds {
+    #[serde(rename = "DOI")]
+    pub doi: Option<String>,
+
+    #[serde(rename = "ArXiv")]
+    pub arxiv: Option<String>,
+
+    #[serde(rename = "PubMed")]
+    pub pubmed: Option<String>,
+
+    #[serde(rename = "CorpusId")]
+    pub corpus_id: Option<u64>,
+}
+
+/// A TLDR summary and the model that wrote it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Tldr {
+    pub model: Option<String>,
+    pub text: String,
+}
+
+/// Response of `GET /recommendations/v1/papers/forpaper/{id}`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Recommendations {
+    #[serde(default)]
+    pub recommended_papers: Vec<S2Paper>,
+}
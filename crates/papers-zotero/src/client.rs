@@ -1,13 +1,16 @@
 use crate::cache::DiskCache;
 use crate::error::{Result, ZoteroError};
 use crate::params::{CollectionListParams, DeletedParams, FulltextParams, ItemListParams, TagListParams};
-use crate::response::{PagedResponse, VersionedResponse};
+use crate::response::{PagedResponse, RawResponse, TextResponse, VersionedResponse};
 use crate::types::*;
 use serde::de::DeserializeOwned;
 use std::collections::HashMap;
 
 const DEFAULT_BASE_URL: &str = "https://api.zotero.org";
 
+/// Base URL of the Zotero desktop app's local API (read-only).
+const LOCAL_BASE_URL: &str = "http://127.0.0.1:23119/api";
+
 /// Returns the path to the Zotero executable if it is found on disk, or
 /// `None` if Zotero does not appear to be installed.
 fn find_zotero_exe() -> Option<String> {
@@ -43,6 +46,21 @@ fn find_zotero_exe() -> Option<String> {
     candidates.into_iter().find(|p| std::path::Path::new(p).exists())
 }
 
+/// Which Zotero library a client reads from and writes to.
+///
+/// Library-scoped endpoints are served under `/users/<id>` for the personal
+/// library and under `/groups/<id>` for a group library. Endpoints that are
+/// inherently per-user (such as [`ZoteroClient::list_groups`]) always use the
+/// user prefix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LibraryScope {
+    /// The personal library of the authenticated user.
+    #[default]
+    User,
+    /// A group library, by numeric group ID.
+    Group(u64),
+}
+
 /// Async client for the [Zotero Web API v3](https://www.zotero.org/support/dev/web_api/v3/start).
 ///
 /// Provides 25+ methods covering all read endpoints for items, collections,
@@ -84,6 +102,7 @@ pub struct ZoteroClient {
     base_url: String,
     user_id: String,
     api_key: String,
+    library: LibraryScope,
     cache: Option<DiskCache>,
 }
 
@@ -95,6 +114,7 @@ impl ZoteroClient {
             base_url: DEFAULT_BASE_URL.to_string(),
             user_id: user_id.into(),
             api_key: api_key.into(),
+            library: LibraryScope::User,
             cache: None,
         }
     }
@@ -132,8 +152,7 @@ impl ZoteroClient {
             message: "ZOTERO_API_KEY environment variable not set".into(),
         })?;
 
-        const LOCAL_BASE: &str = "http://127.0.0.1:23119/api";
-        let probe_url = format!("{LOCAL_BASE}/users/{user_id}/items?limit=0");
+        let probe_url = format!("{LOCAL_BASE_URL}/users/{user_id}/items?limit=0");
         let local_ok = reqwest::Client::new()
             .get(&probe_url)
             .timeout(std::time::Duration::from_millis(500))
@@ -144,7 +163,7 @@ impl ZoteroClient {
 
         if local_ok {
             // Local API is up — no cache needed, it's all in-process on this machine.
-            Ok(Self::new(user_id, api_key).with_base_url(LOCAL_BASE))
+            Ok(Self::new(user_id, api_key).with_base_url(LOCAL_BASE_URL))
         } else {
             // If Zotero is installed but not running, surface an actionable error
             // rather than silently falling back to the slower remote API.
@@ -175,18 +194,62 @@ impl ZoteroClient {
         self
     }
 
+    /// Whether this client talks to the local Zotero API, which only serves
+    /// read requests.
+    pub fn is_local(&self) -> bool {
+        self.base_url == LOCAL_BASE_URL
+    }
+
+    /// Return a client suitable for write requests.
+    ///
+    /// The local API rejects writes, so a local client is redirected to the
+    /// web API with the same credentials. Any other client is returned as-is.
+    pub fn for_writes(&self) -> Self {
+        if self.is_local() {
+            Self::new(self.user_id.clone(), self.api_key.clone()).with_library(self.library)
+        } else {
+            self.clone()
+        }
+    }
+
+    /// Scope library requests to `library` instead of the user's own library.
+    ///
+    /// ```no_run
+    /// use papers_zotero::{LibraryScope, ZoteroClient};
+    ///
+    /// let lab = ZoteroClient::from_env().unwrap().with_library(LibraryScope::Group(123456));
+    /// ```
+    pub fn with_library(mut self, library: LibraryScope) -> Self {
+        self.library = library;
+        self
+    }
+
+    /// The library this client is scoped to.
+    pub fn library(&self) -> LibraryScope {
+        self.library
+    }
+
     /// Enable disk caching of successful responses.
     pub fn with_cache(mut self, cache: DiskCache) -> Self {
         self.cache = Some(cache);
         self
     }
 
-    // ── Private helpers ────────────────────────────────────────────────
-
-    fn user_prefix(&self) -> String {
+    /// Path prefix for per-user endpoints (`/users/<id>`).
+    pub fn user_prefix(&self) -> String {
         format!("/users/{}", self.user_id)
     }
 
+    /// Path prefix for library-scoped endpoints (`/users/<id>` or `/groups/<id>`).
+    pub fn library_prefix(&self) -> String {
+        match self.library {
+            LibraryScope::User => self.user_prefix(),
+            LibraryScope::Group(id) => format!("/groups/{id}"),
+        }
+    }
+
+    // ── Private helpers ────────────────────────────────────────────────
+
     /// GET request returning a JSON array with header-based pagination.
     async fn get_json_array<T: DeserializeOwned>(
         &self,
@@ -347,6 +410,32 @@ impl ZoteroClient {
         })
     }
 
+    /// GET request returning the body as text plus pagination headers.
+    /// Used for export formats; not cached.
+    async fn get_text(&self, path: &str, query: Vec<(&str, String)>) -> Result<TextResponse> {
+        let url = format!("{}{}", self.base_url, path);
+        let resp = self
+            .http
+            .get(&url)
+            .query(&query)
+            .header("Zotero-API-Version", "3")
+            .header("Zotero-API-Key", &self.api_key)
+            .send()
+            .await?;
+        let status = resp.status();
+        if !status.is_success() {
+            let message = resp.text().await.unwrap_or_default();
+            return Err(ZoteroError::Api { status: status.as_u16(), message });
+        }
+        let header_u64 = |name: &str| {
+            resp.headers().get(name).and_then(|v| v.to_str().ok()).and_then(|v| v.parse().ok())
+        };
+        let total_results = header_u64("Total-Results");
+        let last_modified_version = header_u64("Last-Modified-Version");
+        let text = resp.text().await?;
+        Ok(TextResponse { text, total_results, last_modified_version })
+    }
+
     /// GET request returning raw bytes (for file downloads).
     /// Does not use caching (files are too large).
     async fn get_binary(&self, path: &str) -> Result<Vec<u8>> {
@@ -375,7 +464,7 @@ impl ZoteroClient {
     ///
     /// `GET /users/<id>/items`
     pub async fn list_items(&self, params: &ItemListParams) -> Result<PagedResponse<Item>> {
-        let path = format!("{}/items", self.user_prefix());
+        let path = format!("{}/items", self.library_prefix());
         self.get_json_array(&path, params.to_query_pairs()).await
     }
 
@@ -383,15 +472,31 @@ impl ZoteroClient {
     ///
     /// `GET /users/<id>/items/top`
     pub async fn list_top_items(&self, params: &ItemListParams) -> Result<PagedResponse<Item>> {
-        let path = format!("{}/items/top", self.user_prefix());
+        let path = format!("{}/items/top", self.library_prefix());
         self.get_json_array(&path, params.to_query_pairs()).await
     }
 
+    /// Export top-level items with one of Zotero's translators.
+    ///
+    /// `GET /users/<id>/items/top?format=<format>`
+    ///
+    /// `format` is an export format such as `bibtex`, `biblatex`, `csljson`,
+    /// or `ris` (or `bib` for a formatted bibliography in `params.style`); it
+    /// overrides `params.format`. Pages like [`list_top_items`](Self::list_top_items).
+    pub async fn export_top_items(
+        &self,
+        params: &ItemListParams,
+        format: &str,
+    ) -> Result<TextResponse> {
+        let path = format!("{}/items/top", self.library_prefix());
+        self.get_text(&path, export_query(params, format)).await
+    }
+
     /// List items in the trash.
     ///
     /// `GET /users/<id>/items/trash`
     pub async fn list_trash_items(&self, params: &ItemListParams) -> Result<PagedResponse<Item>> {
-        let path = format!("{}/items/trash", self.user_prefix());
+        let path = format!("{}/items/trash", self.library_prefix());
         self.get_json_array(&path, params.to_query_pairs()).await
     }
 
@@ -399,7 +504,7 @@ impl ZoteroClient {
     ///
     /// `GET /users/<id>/items/<key>`
     pub async fn get_item(&self, key: &str) -> Result<Item> {
-        let path = format!("{}/items/{}", self.user_prefix(), key);
+        let path = format!("{}/items/{}", self.library_prefix(), key);
         self.get_json_single(&path, vec![]).await
     }
 
@@ -411,7 +516,7 @@ impl ZoteroClient {
         key: &str,
         params: &ItemListParams,
     ) -> Result<PagedResponse<Item>> {
-        let path = format!("{}/items/{}/children", self.user_prefix(), key);
+        let path = format!("{}/items/{}/children", self.library_prefix(), key);
         self.get_json_array(&path, params.to_query_pairs()).await
     }
 
@@ -422,7 +527,7 @@ impl ZoteroClient {
         &self,
         params: &ItemListParams,
     ) -> Result<PagedResponse<Item>> {
-        let path = format!("{}/publications/items", self.user_prefix());
+        let path = format!("{}/publications/items", self.library_prefix());
         self.get_json_array(&path, params.to_query_pairs()).await
     }
 
@@ -436,7 +541,7 @@ impl ZoteroClient {
     ) -> Result<PagedResponse<Item>> {
         let path = format!(
             "{}/collections/{}/items",
-            self.user_prefix(),
+            self.library_prefix(),
             collection_key
         );
         self.get_json_array(&path, params.to_query_pairs()).await
@@ -453,12 +558,31 @@ impl ZoteroClient {
     ) -> Result<PagedResponse<Item>> {
         let path = format!(
             "{}/collections/{}/items/top",
-            self.user_prefix(),
+            self.library_prefix(),
             collection_key
         );
         self.get_json_array(&path, params.to_query_pairs()).await
     }
 
+    /// Export top-level items in a collection with one of Zotero's translators.
+    ///
+    /// `GET /users/<id>/collections/<key>/items/top?format=<format>`
+    ///
+    /// See [`export_top_items`](Self::export_top_items) for `format`.
+    pub async fn export_collection_top_items(
+        &self,
+        collection_key: &str,
+        params: &ItemListParams,
+        format: &str,
+    ) -> Result<TextResponse> {
+        let path = format!(
+            "{}/collections/{}/items/top",
+            self.library_prefix(),
+            collection_key
+        );
+        self.get_text(&path, export_query(params, format)).await
+    }
+
     /// Download the file content of an attachment item.
     ///
     /// `GET /users/<id>/items/<key>/file`
@@ -466,7 +590,7 @@ impl ZoteroClient {
     /// Returns raw bytes. The reqwest client follows the S3 redirect
     /// automatically.
     pub async fn download_item_file(&self, key: &str) -> Result<Vec<u8>> {
-        let path = format!("{}/items/{}/file", self.user_prefix(), key);
+        let path = format!("{}/items/{}/file", self.library_prefix(), key);
         self.get_binary(&path).await
     }
 
@@ -491,7 +615,7 @@ impl ZoteroClient {
             "tags": [],
             "collections": []
         }]);
-        let path = format!("{}/items", self.user_prefix());
+        let path = format!("{}/items", self.library_prefix());
         let resp = self.post_json_write(&path, &item).await?;
         resp.successful
             .get("0")
@@ -525,7 +649,7 @@ impl ZoteroClient {
             .unwrap_or(0);
 
         // Step 2: register upload
-        let path = format!("{}/items/{}/file", self.user_prefix(), attachment_key);
+        let path = format!("{}/items/{}/file", self.library_prefix(), attachment_key);
         let url = format!("{}{}", self.base_url, path);
         let register_body = format!(
             "md5={}&filename={}&filesize={}&mtime={}",
@@ -618,7 +742,7 @@ impl ZoteroClient {
         &self,
         params: &CollectionListParams,
     ) -> Result<PagedResponse<Collection>> {
-        let path = format!("{}/collections", self.user_prefix());
+        let path = format!("{}/collections", self.library_prefix());
         self.get_json_array(&path, params.to_query_pairs()).await
     }
 
@@ -629,7 +753,7 @@ impl ZoteroClient {
         &self,
         params: &CollectionListParams,
     ) -> Result<PagedResponse<Collection>> {
-        let path = format!("{}/collections/top", self.user_prefix());
+        let path = format!("{}/collections/top", self.library_prefix());
         self.get_json_array(&path, params.to_query_pairs()).await
     }
 
@@ -637,7 +761,7 @@ impl ZoteroClient {
     ///
     /// `GET /users/<id>/collections/<key>`
     pub async fn get_collection(&self, key: &str) -> Result<Collection> {
-        let path = format!("{}/collections/{}", self.user_prefix(), key);
+        let path = format!("{}/collections/{}", self.library_prefix(), key);
         self.get_json_single(&path, vec![]).await
     }
 
@@ -649,7 +773,7 @@ impl ZoteroClient {
         key: &str,
         params: &CollectionListParams,
     ) -> Result<PagedResponse<Collection>> {
-        let path = format!("{}/collections/{}/collections", self.user_prefix(), key);
+        let path = format!("{}/collections/{}/collections", self.library_prefix(), key);
         self.get_json_array(&path, params.to_query_pairs()).await
     }
 
@@ -659,7 +783,7 @@ impl ZoteroClient {
     ///
     /// `GET /users/<id>/searches`
     pub async fn list_searches(&self) -> Result<PagedResponse<SavedSearch>> {
-        let path = format!("{}/searches", self.user_prefix());
+        let path = format!("{}/searches", self.library_prefix());
         self.get_json_array(&path, vec![]).await
     }
 
@@ -667,7 +791,7 @@ impl ZoteroClient {
     ///
     /// `GET /users/<id>/searches/<key>`
     pub async fn get_search(&self, key: &str) -> Result<SavedSearch> {
-        let path = format!("{}/searches/{}", self.user_prefix(), key);
+        let path = format!("{}/searches/{}", self.library_prefix(), key);
         self.get_json_single(&path, vec![]).await
     }
 
@@ -677,7 +801,7 @@ impl ZoteroClient {
     ///
     /// `GET /users/<id>/tags`
     pub async fn list_tags(&self, params: &TagListParams) -> Result<PagedResponse<Tag>> {
-        let path = format!("{}/tags", self.user_prefix());
+        let path = format!("{}/tags", self.library_prefix());
         self.get_json_array(&path, params.to_query_pairs()).await
     }
 
@@ -686,7 +810,7 @@ impl ZoteroClient {
     /// `GET /users/<id>/tags/<urlencoded-name>`
     pub async fn get_tag(&self, name: &str) -> Result<PagedResponse<Tag>> {
         let encoded = urlencoded(name);
-        let path = format!("{}/tags/{}", self.user_prefix(), encoded);
+        let path = format!("{}/tags/{}", self.library_prefix(), encoded);
         self.get_json_array(&path, vec![]).await
     }
 
@@ -698,7 +822,7 @@ impl ZoteroClient {
         key: &str,
         params: &TagListParams,
     ) -> Result<PagedResponse<Tag>> {
-        let path = format!("{}/items/{}/tags", self.user_prefix(), key);
+        let path = format!("{}/items/{}/tags", self.library_prefix(), key);
         self.get_json_array(&path, params.to_query_pairs()).await
     }
 
@@ -706,7 +830,7 @@ impl ZoteroClient {
     ///
     /// `GET /users/<id>/items/tags`
     pub async fn list_items_tags(&self, params: &TagListParams) -> Result<PagedResponse<Tag>> {
-        let path = format!("{}/items/tags", self.user_prefix());
+        let path = format!("{}/items/tags", self.library_prefix());
         self.get_json_array(&path, params.to_query_pairs()).await
     }
 
@@ -714,7 +838,7 @@ impl ZoteroClient {
     ///
     /// `GET /users/<id>/items/top/tags`
     pub async fn list_top_items_tags(&self, params: &TagListParams) -> Result<PagedResponse<Tag>> {
-        let path = format!("{}/items/top/tags", self.user_prefix());
+        let path = format!("{}/items/top/tags", self.library_prefix());
         self.get_json_array(&path, params.to_query_pairs()).await
     }
 
@@ -722,7 +846,7 @@ impl ZoteroClient {
     ///
     /// `GET /users/<id>/items/trash/tags`
     pub async fn list_trash_tags(&self, params: &TagListParams) -> Result<PagedResponse<Tag>> {
-        let path = format!("{}/items/trash/tags", self.user_prefix());
+        let path = format!("{}/items/trash/tags", self.library_prefix());
         self.get_json_array(&path, params.to_query_pairs()).await
     }
 
@@ -736,7 +860,7 @@ impl ZoteroClient {
     ) -> Result<PagedResponse<Tag>> {
         let path = format!(
             "{}/collections/{}/tags",
-            self.user_prefix(),
+            self.library_prefix(),
             collection_key
         );
         self.get_json_array(&path, params.to_query_pairs()).await
@@ -752,7 +876,7 @@ impl ZoteroClient {
     ) -> Result<PagedResponse<Tag>> {
         let path = format!(
             "{}/collections/{}/items/tags",
-            self.user_prefix(),
+            self.library_prefix(),
             collection_key
         );
         self.get_json_array(&path, params.to_query_pairs()).await
@@ -768,7 +892,7 @@ impl ZoteroClient {
     ) -> Result<PagedResponse<Tag>> {
         let path = format!(
             "{}/collections/{}/items/top/tags",
-            self.user_prefix(),
+            self.library_prefix(),
             collection_key
         );
         self.get_json_array(&path, params.to_query_pairs()).await
@@ -784,7 +908,7 @@ impl ZoteroClient {
         &self,
         params: &TagListParams,
     ) -> Result<PagedResponse<Tag>> {
-        let path = format!("{}/publications/items/tags", self.user_prefix());
+        let path = format!("{}/publications/items/tags", self.library_prefix());
         self.get_json_array(&path, params.to_query_pairs()).await
     }
 
@@ -813,7 +937,7 @@ impl ZoteroClient {
         &self,
         params: &FulltextParams,
     ) -> Result<VersionedResponse<HashMap<String, u64>>> {
-        let path = format!("{}/fulltext", self.user_prefix());
+        let path = format!("{}/fulltext", self.library_prefix());
         self.get_json_versioned(&path, params.to_query_pairs()).await
     }
 
@@ -829,7 +953,7 @@ impl ZoteroClient {
     /// available (e.g. older Zotero 7.0.x builds), automatically falls back to
     /// reading the `.zotero-ft-cache` file from local Zotero storage.
     pub async fn get_item_fulltext(&self, key: &str) -> Result<VersionedResponse<ItemFulltext>> {
-        let path = format!("{}/items/{}/fulltext", self.user_prefix(), key);
+        let path = format!("{}/items/{}/fulltext", self.library_prefix(), key);
         match self.get_json_versioned(&path, vec![]).await {
             Ok(r) => Ok(r),
             Err(ZoteroError::Api { status: 404, .. }) => {
@@ -901,7 +1025,7 @@ impl ZoteroClient {
         &self,
         params: &DeletedParams,
     ) -> Result<VersionedResponse<DeletedObjects>> {
-        let path = format!("{}/deleted", self.user_prefix());
+        let path = format!("{}/deleted", self.library_prefix());
         self.get_json_versioned(&path, params.to_query_pairs()).await
     }
 
@@ -916,7 +1040,7 @@ impl ZoteroClient {
     pub async fn get_settings(
         &self,
     ) -> Result<VersionedResponse<HashMap<String, SettingEntry>>> {
-        let path = format!("{}/settings", self.user_prefix());
+        let path = format!("{}/settings", self.library_prefix());
         self.get_json_versioned(&path, vec![]).await
     }
 
@@ -926,7 +1050,7 @@ impl ZoteroClient {
     ///
     /// Returns 404 if the setting key does not exist.
     pub async fn get_setting(&self, key: &str) -> Result<VersionedResponse<SettingEntry>> {
-        let path = format!("{}/settings/{}", self.user_prefix(), key);
+        let path = format!("{}/settings/{}", self.library_prefix(), key);
         self.get_json_versioned(&path, vec![]).await
     }
 
@@ -943,7 +1067,7 @@ impl ZoteroClient {
     ///
     /// [`download_item_file`]: ZoteroClient::download_item_file
     pub async fn get_item_file_view(&self, key: &str) -> Result<Vec<u8>> {
-        let path = format!("{}/items/{}/file/view", self.user_prefix(), key);
+        let path = format!("{}/items/{}/file/view", self.library_prefix(), key);
         let url = format!("{}{}", self.base_url, path);
         let resp = self
             .http
@@ -991,11 +1115,60 @@ impl ZoteroClient {
     /// redirect. Useful when you need the URL itself rather than the content —
     /// e.g. to pass to a browser or PDF viewer.
     pub async fn get_item_file_view_url(&self, key: &str) -> Result<String> {
-        let path = format!("{}/items/{}/file/view/url", self.user_prefix(), key);
+        let path = format!("{}/items/{}/file/view/url", self.library_prefix(), key);
         let bytes = self.get_binary(&path).await?;
         Ok(String::from_utf8_lossy(&bytes).into_owned())
     }
 
+    // ── Raw requests ───────────────────────────────────────────────────
+
+    /// Send an arbitrary request to `path` (absolute from the API root, e.g.
+    /// `/itemTypes` or `/users/<id>/items/top`) and return the untyped response.
+    ///
+    /// `version` is sent as `If-Unmodified-Since-Version` when given. Responses
+    /// are not cached, and no check is made that `method` is read-only — callers
+    /// decide which requests to allow.
+    pub async fn raw_request(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        query: Vec<(&str, String)>,
+        body: Option<&serde_json::Value>,
+        version: Option<u64>,
+    ) -> Result<RawResponse> {
+        let url = format!("{}{}", self.base_url, path);
+        let mut req = self
+            .http
+            .request(method, &url)
+            .query(&query)
+            .header("Zotero-API-Version", "3")
+            .header("Zotero-API-Key", &self.api_key);
+        if let Some(version) = version {
+            req = req.header("If-Unmodified-Since-Version", version.to_string());
+        }
+        if let Some(body) = body {
+            req = req.json(body);
+        }
+        let resp = req.send().await?;
+        let status = resp.status();
+        if !status.is_success() {
+            let message = resp.text().await.unwrap_or_default();
+            return Err(ZoteroError::Api { status: status.as_u16(), message });
+        }
+        let header_u64 = |name: &str| {
+            resp.headers().get(name).and_then(|v| v.to_str().ok()).and_then(|v| v.parse().ok())
+        };
+        let total_results = header_u64("Total-Results");
+        let last_modified_version = header_u64("Last-Modified-Version");
+        let text = resp.text().await?;
+        let body = if text.trim().is_empty() {
+            serde_json::Value::Null
+        } else {
+            serde_json::from_str(&text).unwrap_or(serde_json::Value::String(text))
+        };
+        Ok(RawResponse { status: status.as_u16(), total_results, last_modified_version, body })
+    }
+
     // ── Write helpers ──────────────────────────────────────────────────
 
     /// POST a JSON body, expecting a `200 OK` with a [`WriteResponse`] body.
@@ -1152,10 +1325,32 @@ impl ZoteroClient {
     /// # }
     /// ```
     pub async fn create_items(&self, items: Vec<serde_json::Value>) -> Result<WriteResponse> {
-        let path = format!("{}/items", self.user_prefix());
+        let path = format!("{}/items", self.library_prefix());
         self.post_json_write(&path, &serde_json::Value::Array(items)).await
     }
 
+    /// Create a single item and return the saved object.
+    ///
+    /// `POST /users/<id>/items`
+    ///
+    /// Convenience wrapper over [`create_items`](Self::create_items). A
+    /// per-object failure is returned as [`ZoteroError::Api`] carrying the
+    /// code and message reported by the server.
+    pub async fn create_item(&self, item: serde_json::Value) -> Result<Item> {
+        let resp = self.create_items(vec![item]).await?;
+        if let Some(failed) = resp.failed.get("0") {
+            return Err(ZoteroError::Api {
+                status: failed.code,
+                message: failed.message.clone(),
+            });
+        }
+        let saved = resp.successful.get("0").cloned().ok_or_else(|| ZoteroError::Api {
+            status: 0,
+            message: "write response did not include the created item".into(),
+        })?;
+        Ok(serde_json::from_value(saved)?)
+    }
+
     /// Fully replace a single item.
     ///
     /// `PUT /users/<id>/items/<key>`
@@ -1169,7 +1364,7 @@ impl ZoteroClient {
         version: u64,
         data: serde_json::Value,
     ) -> Result<()> {
-        let path = format!("{}/items/{}", self.user_prefix(), key);
+        let path = format!("{}/items/{}", self.library_prefix(), key);
         self.put_no_content(&path, version, &data).await
     }
 
@@ -1184,7 +1379,7 @@ impl ZoteroClient {
         version: u64,
         data: serde_json::Value,
     ) -> Result<()> {
-        let path = format!("{}/items/{}", self.user_prefix(), key);
+        let path = format!("{}/items/{}", self.library_prefix(), key);
         self.patch_no_content(&path, version, &data).await
     }
 
@@ -1194,7 +1389,7 @@ impl ZoteroClient {
     ///
     /// `version` must match the item's current version.
     pub async fn delete_item(&self, key: &str, version: u64) -> Result<()> {
-        let path = format!("{}/items/{}", self.user_prefix(), key);
+        let path = format!("{}/items/{}", self.library_prefix(), key);
         self.delete_no_content(&path, version).await
     }
 
@@ -1205,7 +1400,7 @@ impl ZoteroClient {
     /// `library_version` must be the current library version (from a prior
     /// list or write response).
     pub async fn delete_items(&self, keys: &[String], library_version: u64) -> Result<()> {
-        let path = format!("{}/items", self.user_prefix());
+        let path = format!("{}/items", self.library_prefix());
         self.delete_multiple_no_content(&path, "itemKey", keys, library_version).await
     }
 
@@ -1221,7 +1416,7 @@ impl ZoteroClient {
         &self,
         collections: Vec<serde_json::Value>,
     ) -> Result<WriteResponse> {
-        let path = format!("{}/collections", self.user_prefix());
+        let path = format!("{}/collections", self.library_prefix());
         self.post_json_write(&path, &serde_json::Value::Array(collections)).await
     }
 
@@ -1234,7 +1429,7 @@ impl ZoteroClient {
         version: u64,
         data: serde_json::Value,
     ) -> Result<()> {
-        let path = format!("{}/collections/{}", self.user_prefix(), key);
+        let path = format!("{}/collections/{}", self.library_prefix(), key);
         self.put_no_content(&path, version, &data).await
     }
 
@@ -1242,7 +1437,7 @@ impl ZoteroClient {
     ///
     /// `DELETE /users/<id>/collections/<key>`
     pub async fn delete_collection(&self, key: &str, version: u64) -> Result<()> {
-        let path = format!("{}/collections/{}", self.user_prefix(), key);
+        let path = format!("{}/collections/{}", self.library_prefix(), key);
         self.delete_no_content(&path, version).await
     }
 
@@ -1254,7 +1449,7 @@ impl ZoteroClient {
         keys: &[String],
         library_version: u64,
     ) -> Result<()> {
-        let path = format!("{}/collections", self.user_prefix());
+        let path = format!("{}/collections", self.library_prefix());
         self.delete_multiple_no_content(&path, "collectionKey", keys, library_version).await
     }
 
@@ -1270,7 +1465,7 @@ impl ZoteroClient {
         &self,
         searches: Vec<serde_json::Value>,
     ) -> Result<WriteResponse> {
-        let path = format!("{}/searches", self.user_prefix());
+        let path = format!("{}/searches", self.library_prefix());
         self.post_json_write(&path, &serde_json::Value::Array(searches)).await
     }
 
@@ -1282,7 +1477,7 @@ impl ZoteroClient {
         keys: &[String],
         library_version: u64,
     ) -> Result<()> {
-        let path = format!("{}/searches", self.user_prefix());
+        let path = format!("{}/searches", self.library_prefix());
         self.delete_multiple_no_content(&path, "searchKey", keys, library_version).await
     }
 
@@ -1294,7 +1489,7 @@ impl ZoteroClient {
     ///
     /// Tags are URL-encoded and joined with ` || `.
     pub async fn delete_tags(&self, tags: &[String], library_version: u64) -> Result<()> {
-        let path = format!("{}/tags", self.user_prefix());
+        let path = format!("{}/tags", self.library_prefix());
         let url = format!("{}{}", self.base_url, path);
         let tag_param = tags
             .iter()
@@ -1318,6 +1513,65 @@ impl ZoteroClient {
         Ok(())
     }
 
+    // ── Schema endpoints ───────────────────────────────────────────────
+
+    /// List all item types.
+    ///
+    /// `GET /itemTypes`
+    ///
+    /// `locale` (e.g. `"de-DE"`) sets the language of the `localized` names.
+    pub async fn list_item_types(&self, locale: Option<&str>) -> Result<Vec<ItemTypeInfo>> {
+        self.get_json_single("/itemTypes", locale_query(locale)).await
+    }
+
+    /// List all item fields across every item type.
+    ///
+    /// `GET /itemFields`
+    pub async fn list_item_fields(&self, locale: Option<&str>) -> Result<Vec<FieldInfo>> {
+        self.get_json_single("/itemFields", locale_query(locale)).await
+    }
+
+    /// List the fields valid for `item_type`, in display order.
+    ///
+    /// `GET /itemTypeFields?itemType=<type>`
+    pub async fn list_item_type_fields(
+        &self,
+        item_type: &str,
+        locale: Option<&str>,
+    ) -> Result<Vec<FieldInfo>> {
+        let mut query = vec![("itemType", item_type.to_string())];
+        query.extend(locale_query(locale));
+        self.get_json_single("/itemTypeFields", query).await
+    }
+
+    /// List the creator types valid for `item_type`; the first is the primary type.
+    ///
+    /// `GET /itemTypeCreatorTypes?itemType=<type>`
+    pub async fn list_item_type_creator_types(
+        &self,
+        item_type: &str,
+        locale: Option<&str>,
+    ) -> Result<Vec<CreatorTypeInfo>> {
+        let mut query = vec![("itemType", item_type.to_string())];
+        query.extend(locale_query(locale));
+        self.get_json_single("/itemTypeCreatorTypes", query).await
+    }
+
+    /// List the localized creator name fields (`firstName`, `lastName`, `name`).
+    ///
+    /// `GET /creatorFields`
+    pub async fn list_creator_fields(&self, locale: Option<&str>) -> Result<Vec<FieldInfo>> {
+        self.get_json_single("/creatorFields", locale_query(locale)).await
+    }
+
+    /// Get an empty item template for `item_type`, ready to fill in and pass to
+    /// [`create_items`](Self::create_items).
+    ///
+    /// `GET /items/new?itemType=<type>`
+    pub async fn get_item_template(&self, item_type: &str) -> Result<serde_json::Value> {
+        self.get_json_single("/items/new", vec![("itemType", item_type.to_string())]).await
+    }
+
     // ── Key info endpoint ──────────────────────────────────────────────
 
     /// Get information about the current API key.
@@ -1341,6 +1595,19 @@ impl ZoteroClient {
     }
 }
 
+/// Item list query with `format` replaced by an export format.
+fn export_query<'a>(params: &'a ItemListParams, format: &str) -> Vec<(&'a str, String)> {
+    let mut query = params.to_query_pairs();
+    query.retain(|(k, _)| *k != "format");
+    query.push(("format", format.to_string()));
+    query
+}
+
+/// `locale` query parameter for schema endpoints, if given.
+fn locale_query(locale: Option<&str>) -> Vec<(&'static str, String)> {
+    locale.map(|l| vec![("locale", l.to_string())]).unwrap_or_default()
+}
+
 /// Minimal percent-encoding for tag names in URL paths.
 fn urlencoded(s: &str) -> String {
     let mut out = String::with_capacity(s.len());
@@ -1762,6 +2029,98 @@ mod tests {
         assert!(resp.items.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_group_scope_uses_group_prefix() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/groups/777/items/top"))
+            .respond_with(array_response(&item_list_json()))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/users/12345/groups"))
+            .respond_with(array_response("[]"))
+            .mount(&server)
+            .await;
+        let client = setup_client(&server).await.with_library(LibraryScope::Group(777));
+        assert_eq!(client.library(), LibraryScope::Group(777));
+        let resp = client.list_top_items(&ItemListParams::default()).await.unwrap();
+        assert_eq!(resp.items.len(), 1);
+        // Group membership is still listed for the authenticated user.
+        client.list_groups().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_raw_request_json_and_text_bodies() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/itemTypes"))
+            .and(query_param("locale", "de-DE"))
+            .respond_with(array_response(r#"[{"itemType": "book", "localized": "Buch"}]"#))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/users/12345/items/ABC12345"))
+            .and(query_param("format", "bibtex"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("@book{x}"))
+            .mount(&server)
+            .await;
+        let client = setup_client(&server).await;
+        let resp = client
+            .raw_request(
+                reqwest::Method::GET,
+                "/itemTypes",
+                vec![("locale", "de-DE".into())],
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(resp.status, 200);
+        assert_eq!(resp.total_results, Some(42));
+        assert_eq!(resp.body[0]["localized"], "Buch");
+        let path = format!("{}/items/ABC12345", client.library_prefix());
+        let resp = client
+            .raw_request(reqwest::Method::GET, &path, vec![("format", "bibtex".into())], None, None)
+            .await
+            .unwrap();
+        assert_eq!(resp.body, serde_json::Value::String("@book{x}".into()));
+    }
+
+    #[tokio::test]
+    async fn test_raw_request_sends_version_header() {
+        let server = MockServer::start().await;
+        Mock::given(method("DELETE"))
+            .and(path("/users/12345/items/ABC12345"))
+            .and(header("If-Unmodified-Since-Version", "7"))
+            .respond_with(ResponseTemplate::new(204))
+            .mount(&server)
+            .await;
+        let client = setup_client(&server).await;
+        let resp = client
+            .raw_request(
+                reqwest::Method::DELETE,
+                "/users/12345/items/ABC12345",
+                vec![],
+                None,
+                Some(7),
+            )
+            .await
+            .unwrap();
+        assert_eq!(resp.status, 204);
+        assert!(resp.body.is_null());
+    }
+
+    #[test]
+    fn test_for_writes_keeps_library_scope() {
+        let local = ZoteroClient::new("12345", "k")
+            .with_base_url(LOCAL_BASE_URL)
+            .with_library(LibraryScope::Group(9));
+        let web = local.for_writes();
+        assert!(!web.is_local());
+        assert_eq!(web.library(), LibraryScope::Group(9));
+    }
+
     // ── Error tests ───────────────────────────────────────────────────
 
     #[tokio::test]
@@ -2302,6 +2661,88 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_export_collection_top_items_bibtex() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/users/12345/collections/COL12345/items/top"))
+            .and(query_param("format", "bibtex"))
+            .and(query_param("limit", "100"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string("@article{a,\n  title = {A}\n}\n")
+                    .insert_header("Total-Results", "1"),
+            )
+            .mount(&server)
+            .await;
+        let client = setup_client(&server).await;
+        let params = ItemListParams {
+            format: Some("json".into()),
+            limit: Some(100),
+            ..Default::default()
+        };
+        let resp = client.export_collection_top_items("COL12345", &params, "bibtex").await.unwrap();
+        assert!(resp.text.starts_with("@article{a,"));
+        assert_eq!(resp.total_results, Some(1));
+    }
+
+    // ── Schema tests ──────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_list_item_types_with_locale() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/itemTypes"))
+            .and(query_param("locale", "fr-FR"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"[{"itemType": "book", "localized": "Livre"}]"#,
+            ))
+            .mount(&server)
+            .await;
+        let client = setup_client(&server).await;
+        let types = client.list_item_types(Some("fr-FR")).await.unwrap();
+        assert_eq!(types[0].item_type, "book");
+        assert_eq!(types[0].localized, "Livre");
+    }
+
+    #[tokio::test]
+    async fn test_item_type_fields_creator_types_and_template() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/itemTypeFields"))
+            .and(query_param("itemType", "book"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"[{"field": "title", "localized": "Title"},
+                    {"field": "ISBN", "localized": "ISBN"}]"#,
+            ))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/itemTypeCreatorTypes"))
+            .and(query_param("itemType", "book"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"[{"creatorType": "author", "localized": "Author"}]"#,
+            ))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/items/new"))
+            .and(query_param("itemType", "book"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"{"itemType": "book", "title": "", "creators": [], "ISBN": ""}"#,
+            ))
+            .mount(&server)
+            .await;
+        let client = setup_client(&server).await;
+        let fields = client.list_item_type_fields("book", None).await.unwrap();
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[1].field, "ISBN");
+        let creators = client.list_item_type_creator_types("book", None).await.unwrap();
+        assert_eq!(creators[0].creator_type, "author");
+        let template = client.get_item_template("book").await.unwrap();
+        assert_eq!(template["ISBN"], "");
+    }
+
     // ── Key info tests ────────────────────────────────────────────────
 
     /// Real response shape (captured from live API, /keys/current):
@@ -2413,6 +2854,55 @@ mod tests {
         assert_eq!(resp.failed["1"].code, 400);
     }
 
+    #[tokio::test]
+    async fn test_create_item() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/users/12345/items"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(item_write_response_json("NEW12345")))
+            .mount(&server)
+            .await;
+        let client = setup_client(&server).await;
+        let item = client
+            .create_item(serde_json::json!({"itemType": "note", "note": "test"}))
+            .await
+            .unwrap();
+        assert_eq!(item.key, "NEW12345");
+        assert_eq!(item.version, 1);
+    }
+
+    #[tokio::test]
+    async fn test_create_item_failure() {
+        let server = MockServer::start().await;
+        let body = r#"{"successful":{},"unchanged":{},"failed":{"0":{"key":null,"code":400,"message":"Invalid item type"}}}"#;
+        Mock::given(method("POST"))
+            .and(path("/users/12345/items"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(body))
+            .mount(&server)
+            .await;
+        let client = setup_client(&server).await;
+        let err = client
+            .create_item(serde_json::json!({"itemType": "badType"}))
+            .await
+            .unwrap_err();
+        match err {
+            ZoteroError::Api { status, message } => {
+                assert_eq!(status, 400);
+                assert_eq!(message, "Invalid item type");
+            }
+            _ => panic!("Expected Api error"),
+        }
+    }
+
+    #[test]
+    fn test_for_writes_redirects_local_client() {
+        let local = ZoteroClient::new("1", "k").with_base_url(LOCAL_BASE_URL);
+        assert!(local.is_local());
+        assert!(!local.for_writes().is_local());
+        let remote = ZoteroClient::new("1", "k").with_base_url("http://mock");
+        assert_eq!(remote.for_writes().base_url, "http://mock");
+    }
+
     #[tokio::test]
     async fn test_update_item() {
         let server = MockServer::start().await;
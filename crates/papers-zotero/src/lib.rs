@@ -57,6 +57,8 @@
 //! - **1 deleted endpoint** — get deleted object keys since a version
 //! - **2 settings endpoints** — get all settings, get single setting
 //! - **1 group endpoint** — list user groups
+//! - **6 schema endpoints** — item types, item fields, fields and creator
+//!   types per item type, creator fields, new-item templates
 //! - **2 key endpoints** — get API key info by value or by current request
 //!
 //! **Write:**
@@ -66,6 +68,10 @@
 //!   delete multiple
 //! - **2 search write endpoints** — create, delete multiple
 //! - **1 tag write endpoint** — delete multiple tags
+//!
+//! Library-scoped endpoints read the user library by default; use
+//! [`ZoteroClient::with_library`] with [`LibraryScope::Group`] to target a
+//! group library instead.
 
 pub mod cache;
 pub mod client;
@@ -75,8 +81,8 @@ pub mod response;
 pub mod types;
 
 pub use cache::DiskCache;
-pub use client::ZoteroClient;
+pub use client::{LibraryScope, ZoteroClient};
 pub use error::{Result, ZoteroError};
 pub use params::{CollectionListParams, DeletedParams, FulltextParams, ItemListParams, TagListParams};
-pub use response::{PagedResponse, VersionedResponse};
+pub use response::{PagedResponse, RawResponse, TextResponse, VersionedResponse};
 pub use types::*;
@@ -59,3 +59,36 @@ pub struct PagedResponse<T> {
     /// incremental sync via the `since` parameter.
     pub last_modified_version: Option<u64>,
 }
+
+/// Untyped response from [`ZoteroClient::raw_request`](crate::ZoteroClient::raw_request).
+///
+/// `body` is the parsed JSON when the response is JSON, the text as a JSON
+/// string otherwise (e.g. `format=bibtex`), and `null` for empty bodies.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RawResponse {
+    /// HTTP status code.
+    pub status: u16,
+
+    /// Value of the `Total-Results` header, if present.
+    pub total_results: Option<u64>,
+
+    /// Value of the `Last-Modified-Version` header, if present.
+    pub last_modified_version: Option<u64>,
+
+    /// Response body.
+    pub body: serde_json::Value,
+}
+
+/// Text response with pagination headers, returned by export formats
+/// (`format=bibtex`, `csljson`, `ris`, …) that aren't Zotero item JSON.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TextResponse {
+    /// Response body as returned by Zotero's translator.
+    pub text: String,
+
+    /// Total number of matching items (from `Total-Results` header).
+    pub total_results: Option<u64>,
+
+    /// Library version (from `Last-Modified-Version` header).
+    pub last_modified_version: Option<u64>,
+}
@@ -4,6 +4,7 @@ pub mod deleted;
 pub mod fulltext;
 pub mod group;
 pub mod item;
+pub mod schema;
 pub mod search;
 pub mod settings;
 pub mod tag;
@@ -15,6 +16,7 @@ pub use deleted::DeletedObjects;
 pub use fulltext::ItemFulltext;
 pub use group::{Group, GroupData, GroupMeta};
 pub use item::{Item, ItemData, ItemMeta};
+pub use schema::{CreatorTypeInfo, FieldInfo, ItemTypeInfo};
 pub use search::{SavedSearch, SearchCondition, SearchData};
 pub use settings::SettingEntry;
 pub use tag::{Tag, TagMeta};
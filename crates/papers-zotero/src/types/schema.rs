@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+
+/// An item type from `GET /itemTypes`.
+///
+/// ```json
+/// { "itemType": "journalArticle", "localized": "Journal Article" }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ItemTypeInfo {
+    #[serde(rename = "itemType")]
+    pub item_type: String,
+    pub localized: String,
+}
+
+/// An item or creator field from `GET /itemFields`, `GET /itemTypeFields`, or
+/// `GET /creatorFields`.
+///
+/// ```json
+/// { "field": "publicationTitle", "localized": "Publication" }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FieldInfo {
+    pub field: String,
+    pub localized: String,
+}
+
+/// A creator type from `GET /itemTypeCreatorTypes`.
+///
+/// ```json
+/// { "creatorType": "author", "localized": "Author" }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreatorTypeInfo {
+    #[serde(rename = "creatorType")]
+    pub creator_type: String,
+    pub localized: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_schema_entries() {
+        let types: Vec<ItemTypeInfo> =
+            serde_json::from_str(r#"[{"itemType": "book", "localized": "Book"}]"#).unwrap();
+        assert_eq!(types[0].item_type, "book");
+        let fields: Vec<FieldInfo> =
+            serde_json::from_str(r#"[{"field": "title", "localized": "Title"}]"#).unwrap();
+        assert_eq!(fields[0].localized, "Title");
+        let creators: Vec<CreatorTypeInfo> =
+            serde_json::from_str(r#"[{"creatorType": "editor", "localized": "Editor"}]"#)
+                .unwrap();
+        assert_eq!(creators[0].creator_type, "editor");
+    }
+}